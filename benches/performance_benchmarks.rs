@@ -73,6 +73,7 @@ fn bench_cache_operations(c: &mut Criterion) {
                         choices: vec![],
                         usage: None,
                         system_fingerprint: None,
+                        provider_specific_fields: None,
                     };
 
                     rt.block_on(async {
@@ -466,6 +467,9 @@ fn bench_serialization(c: &mut Criterion) {
         top_logprobs: None,
         modalities: None,
         audio: None,
+        cache: None,
+        tags: None,
+        metadata: None,
     };
 
     group.bench_function("serialize_request", |b| {
@@ -28,6 +28,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }],
         usage: None,
         system_fingerprint: None,
+        provider_specific_fields: None,
     };
 
     println!("Testing Groq fake streaming...\n");
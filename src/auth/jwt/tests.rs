@@ -18,6 +18,7 @@ mod tests {
                 default_role: "user".to_string(),
                 admin_roles: vec!["admin".to_string()],
             },
+            oidc: None,
         };
 
         JwtHandler::new(&config).await.unwrap()
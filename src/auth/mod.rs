@@ -7,6 +7,7 @@
 // Submodules
 pub mod api_key;
 pub mod jwt;
+pub mod oidc;
 pub mod rbac;
 
 // Internal submodules
@@ -0,0 +1,142 @@
+//! JWKS signing key cache
+
+use crate::config::OidcConfig;
+use crate::utils::error::{GatewayError, Result};
+use jsonwebtoken::DecodingKey;
+use jsonwebtoken::jwk::{AlgorithmParameters, Jwk, JwkSet};
+use parking_lot::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Minimum time between fetches, so tokens with unknown key IDs cannot make
+/// every request refetch the JWKS
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default)]
+struct CachedKeys {
+    keys: Vec<Jwk>,
+    fetched_at: Option<Instant>,
+}
+
+/// Signing keys of an identity provider, refetched when they expire or a
+/// token names a key not yet seen
+#[derive(Debug)]
+pub(super) struct KeyCache {
+    jwks_url: String,
+    ttl: Duration,
+    client: reqwest::Client,
+    cached: RwLock<CachedKeys>,
+    refresh: Mutex<()>,
+}
+
+impl KeyCache {
+    pub(super) fn new(config: &OidcConfig) -> Self {
+        Self {
+            jwks_url: config.jwks_url.clone(),
+            ttl: Duration::from_secs(config.jwks_cache_ttl),
+            client: reqwest::Client::new(),
+            cached: RwLock::new(CachedKeys::default()),
+            refresh: Mutex::new(()),
+        }
+    }
+
+    /// Decoding key for a key ID
+    ///
+    /// Tokens without a key ID are accepted when the JWKS holds a single key.
+    pub(super) async fn get(&self, kid: Option<&str>) -> Result<DecodingKey> {
+        if let Some(jwk) = self.find(kid, false) {
+            return decoding_key(&jwk);
+        }
+
+        let _refresh = self.refresh.lock().await;
+        // Another request may have refreshed the keys while this one waited
+        if let Some(jwk) = self.find(kid, false) {
+            return decoding_key(&jwk);
+        }
+
+        if self.may_refresh() {
+            if let Err(e) = self.fetch().await {
+                // Keep verifying with expired keys while the provider is down
+                warn!("Failed to fetch JWKS from {}: {}", self.jwks_url, e);
+                if let Some(jwk) = self.find(kid, true) {
+                    return decoding_key(&jwk);
+                }
+                return Err(e);
+            }
+        }
+
+        let jwk = self.find(kid, true).ok_or_else(|| {
+            GatewayError::auth(format!(
+                "Unknown token signing key: {}",
+                kid.unwrap_or("none")
+            ))
+        })?;
+        decoding_key(&jwk)
+    }
+
+    /// Cached key with the given ID, ignoring expiry if `stale` is set
+    fn find(&self, kid: Option<&str>, stale: bool) -> Option<Jwk> {
+        let cached = self.cached.read();
+        let fresh = cached
+            .fetched_at
+            .is_some_and(|fetched_at| fetched_at.elapsed() < self.ttl);
+        if !fresh && !stale {
+            return None;
+        }
+
+        match kid {
+            Some(kid) => cached
+                .keys
+                .iter()
+                .find(|jwk| jwk.common.key_id.as_deref() == Some(kid))
+                .cloned(),
+            None => match cached.keys.as_slice() {
+                [jwk] => Some(jwk.clone()),
+                _ => None,
+            },
+        }
+    }
+
+    fn may_refresh(&self) -> bool {
+        self.cached
+            .read()
+            .fetched_at
+            .is_none_or(|fetched_at| fetched_at.elapsed() >= MIN_REFRESH_INTERVAL.min(self.ttl))
+    }
+
+    async fn fetch(&self) -> Result<()> {
+        let jwks: JwkSet = self
+            .client
+            .get(&self.jwks_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| GatewayError::external(format!("Failed to fetch JWKS: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| GatewayError::external(format!("Invalid JWKS: {}", e)))?;
+
+        debug!(
+            "Fetched {} signing keys from {}",
+            jwks.keys.len(),
+            self.jwks_url
+        );
+        *self.cached.write() = CachedKeys {
+            keys: jwks.keys,
+            fetched_at: Some(Instant::now()),
+        };
+        Ok(())
+    }
+}
+
+/// Decoding key for a public JWK
+///
+/// Symmetric keys are refused: anyone able to read the JWKS could sign with
+/// them.
+fn decoding_key(jwk: &Jwk) -> Result<DecodingKey> {
+    if matches!(jwk.algorithm, AlgorithmParameters::OctetKey(_)) {
+        return Err(GatewayError::auth("Symmetric JWKS keys are not accepted"));
+    }
+    DecodingKey::from_jwk(jwk).map_err(GatewayError::Jwt)
+}
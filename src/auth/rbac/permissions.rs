@@ -22,6 +22,25 @@ impl RbacSystem {
         Ok(permissions.into_iter().collect())
     }
 
+    /// Get all permissions granted by the named roles
+    ///
+    /// Unknown role names grant nothing.
+    pub fn get_roles_permissions(&self, role_names: &[String]) -> Vec<String> {
+        let mut permissions = HashSet::new();
+        for role in role_names.iter().filter_map(|name| self.roles.get(name)) {
+            permissions.extend(self.get_role_permissions(role));
+        }
+
+        permissions.into_iter().collect()
+    }
+
+    /// Check if any of the named roles is an admin role
+    pub fn has_admin_role(&self, role_names: &[String]) -> bool {
+        role_names
+            .iter()
+            .any(|name| self.config.admin_roles.contains(name))
+    }
+
     /// Check if user has specific permissions
     pub fn check_permissions(
         &self,
@@ -244,6 +244,19 @@ mod tests {
         assert!(!permissions.contains(&"api_keys.read".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_roles_permissions_and_admin_role() {
+        let rbac = create_test_rbac().await;
+
+        let roles = vec!["viewer".to_string(), "unknown".to_string()];
+        let permissions = rbac.get_roles_permissions(&roles);
+        assert!(!permissions.is_empty());
+        assert!(!permissions.contains(&"users.delete".to_string()));
+        assert!(!rbac.has_admin_role(&roles));
+
+        assert!(rbac.has_admin_role(&["admin".to_string()]));
+    }
+
     #[test]
     fn test_role_creation() {
         let role = Role {
@@ -1,11 +1,12 @@
 //! Core authentication system implementation
 
+use super::oidc::{OidcIdentity, OidcValidator};
 use super::types::{AuthMethod, AuthResult, AuthzResult};
 use crate::config::AuthConfig;
 use crate::core::models::RequestContext;
 use crate::core::models::user::types::{User, UserRole};
 use crate::storage::StorageLayer;
-use crate::utils::error::Result;
+use crate::utils::error::{GatewayError, Result};
 use std::sync::Arc;
 use tracing::{debug, info};
 
@@ -22,6 +23,8 @@ pub struct AuthSystem {
     pub(super) api_key: Arc<crate::auth::api_key::creation::ApiKeyHandler>,
     /// RBAC system
     pub(super) rbac: Arc<crate::auth::rbac::RbacSystem>,
+    /// OIDC token validator, if configured
+    pub(super) oidc: Option<Arc<OidcValidator>>,
 }
 
 impl AuthSystem {
@@ -41,6 +44,12 @@ impl AuthSystem {
         // Initialize RBAC system
         let rbac = Arc::new(crate::auth::rbac::RbacSystem::new(&config.rbac).await?);
 
+        // Initialize OIDC validator (if configured)
+        let oidc = config
+            .oidc
+            .clone()
+            .map(|oidc| Arc::new(OidcValidator::new(oidc)));
+
         info!("Authentication system initialized successfully");
 
         Ok(Self {
@@ -49,6 +58,7 @@ impl AuthSystem {
             jwt,
             api_key,
             rbac,
+            oidc,
         })
     }
 
@@ -77,6 +87,32 @@ impl AuthSystem {
         }
     }
 
+    /// Authenticate using a token from the OIDC identity provider
+    ///
+    /// Fills the context's user and team from the token's claims and grants
+    /// the permissions of its roles.
+    pub async fn authenticate_oidc(
+        &self,
+        token: &str,
+        context: &mut RequestContext,
+    ) -> Result<OidcIdentity> {
+        let oidc = self
+            .oidc
+            .as_ref()
+            .ok_or_else(|| GatewayError::auth("OIDC authentication is not configured"))?;
+
+        let mut identity = oidc.verify(token).await?;
+        identity.permissions = self.rbac.get_roles_permissions(&identity.roles);
+        context.user_id = identity.user_id;
+        context.team_id = identity.team_id;
+        Ok(identity)
+    }
+
+    /// Whether OIDC authentication is configured
+    pub fn oidc_enabled(&self) -> bool {
+        self.oidc.is_some()
+    }
+
     /// Authenticate using JWT token
     async fn authenticate_jwt(
         &self,
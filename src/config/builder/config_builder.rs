@@ -2,7 +2,9 @@
 
 use super::types::ConfigBuilder;
 use crate::config::{
-    AuthConfig, Config, GatewayConfig, ProviderConfig, ServerConfig, StorageConfig,
+    AuthConfig, CacheConfig, CallbacksConfig, Config, EnterpriseConfig, GatewayConfig,
+    GuardrailsConfig, ModelListEntry, MonitoringConfig, PassThroughEndpoint, ProviderConfig,
+    RateLimitConfig, RouterConfig, SecretsConfig, ServerConfig, StorageConfig,
 };
 use crate::utils::data::type_utils::Builder;
 use crate::utils::error::{GatewayError, Result};
@@ -16,6 +18,16 @@ impl ConfigBuilder {
             auth: None,
             storage: None,
             providers: Vec::new(),
+            model_list: Vec::new(),
+            router: None,
+            monitoring: None,
+            cache: None,
+            rate_limit: None,
+            enterprise: None,
+            pass_through: Vec::new(),
+            callbacks: None,
+            guardrails: None,
+            secrets: None,
             features: HashMap::new(),
         }
     }
@@ -50,6 +62,66 @@ impl ConfigBuilder {
         self
     }
 
+    /// Add a deployment in the LiteLLM proxy `model_list` format
+    pub fn add_model_list_entry(mut self, entry: ModelListEntry) -> Self {
+        self.model_list.push(entry);
+        self
+    }
+
+    /// Set the router configuration
+    pub fn with_router(mut self, config: RouterConfig) -> Self {
+        self.router = Some(config);
+        self
+    }
+
+    /// Set the monitoring configuration
+    pub fn with_monitoring(mut self, config: MonitoringConfig) -> Self {
+        self.monitoring = Some(config);
+        self
+    }
+
+    /// Set the caching configuration
+    pub fn with_cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+
+    /// Set the rate limiting configuration
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limit = Some(config);
+        self
+    }
+
+    /// Set the enterprise features configuration
+    pub fn with_enterprise(mut self, config: EnterpriseConfig) -> Self {
+        self.enterprise = Some(config);
+        self
+    }
+
+    /// Add a provider pass-through endpoint
+    pub fn add_pass_through(mut self, endpoint: PassThroughEndpoint) -> Self {
+        self.pass_through.push(endpoint);
+        self
+    }
+
+    /// Set the logging callbacks configuration
+    pub fn with_callbacks(mut self, config: CallbacksConfig) -> Self {
+        self.callbacks = Some(config);
+        self
+    }
+
+    /// Set the guardrails configuration
+    pub fn with_guardrails(mut self, config: GuardrailsConfig) -> Self {
+        self.guardrails = Some(config);
+        self
+    }
+
+    /// Set the secret stores configuration
+    pub fn with_secrets(mut self, config: SecretsConfig) -> Self {
+        self.secrets = Some(config);
+        self
+    }
+
     /// Enable a feature
     pub fn enable_feature(mut self, feature: impl Into<String>) -> Self {
         self.features.insert(feature.into(), true);
@@ -64,24 +136,34 @@ impl ConfigBuilder {
 
     /// Build the configuration with validation
     pub fn build(self) -> Result<Config> {
-        let gateway = GatewayConfig {
+        let mut gateway = GatewayConfig {
             server: self.server.unwrap_or_default(),
             auth: self.auth.unwrap_or_default(),
             storage: self.storage.unwrap_or_default(),
             providers: self.providers,
-            router: crate::config::RouterConfig::default(),
-            monitoring: crate::config::MonitoringConfig::default(),
-            cache: crate::config::CacheConfig::default(),
-            rate_limit: crate::config::RateLimitConfig::default(),
-            enterprise: crate::config::EnterpriseConfig::default(),
+            model_list: self.model_list,
+            router: self.router.unwrap_or_default(),
+            monitoring: self.monitoring.unwrap_or_default(),
+            cache: self.cache.unwrap_or_default(),
+            rate_limit: self.rate_limit.unwrap_or_default(),
+            enterprise: self.enterprise.unwrap_or_default(),
+            pass_through: self.pass_through,
+            callbacks: self.callbacks.unwrap_or_default(),
+            guardrails: self.guardrails.unwrap_or_default(),
+            secrets: self.secrets.unwrap_or_default(),
         };
+        gateway.apply_model_list();
 
-        let config = Config { gateway };
+        let config = Config {
+            gateway,
+            location: None,
+        };
 
         // Validate the configuration
         if let Err(e) = config.gateway.validate() {
             return Err(GatewayError::Config(e));
         }
+        config.validate()?;
 
         Ok(config)
     }
@@ -106,6 +106,7 @@ impl ProviderConfigBuilder {
             name: name.into_string(),
             provider_type: provider_type.into_string(),
             api_key: self.api_key.unwrap_or_default(),
+            api_keys: Vec::new(),
             base_url: self.base_url,
             api_version: None,
             organization: None,
@@ -120,8 +121,13 @@ impl ProviderConfigBuilder {
             health_check: crate::config::HealthCheckConfig::default(),
             settings: std::collections::HashMap::new(),
             models: self.models,
+            model_name: None,
             enabled: self.enabled,
             tags: Vec::new(),
+            default_params: std::collections::HashMap::new(),
+            forced_params: std::collections::HashMap::new(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
         })
     }
 }
@@ -83,6 +83,9 @@ impl ServerConfigBuilder {
                 max_age: 3600,
                 allow_credentials: false,
             },
+            realtime_max_sessions_per_key: crate::config::default_realtime_max_sessions_per_key(),
+            load_shedding: crate::config::LoadSheddingConfig::default(),
+            config_watch_interval: crate::config::default_config_watch_interval(),
         }
     }
 }
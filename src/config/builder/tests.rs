@@ -24,6 +24,29 @@ mod tests {
         assert_eq!(config.gateway.providers.len(), 1);
     }
 
+    #[test]
+    fn test_config_builder_without_file() {
+        let entry = serde_yaml::from_str(
+            "model_name: gpt-4o\nlitellm_params:\n  model: openai/gpt-4o\n  api_key: sk-test-key\n",
+        )
+        .unwrap();
+        let mut router = crate::config::RouterConfig::default();
+        router
+            .model_aliases
+            .insert("default".to_string(), "gpt-4o".to_string());
+
+        let config = crate::config::Config::builder()
+            .with_router(router)
+            .add_model_list_entry(entry)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.providers().len(), 1);
+        assert_eq!(config.providers()[0].models, ["gpt-4o"]);
+        assert_eq!(config.router().model_aliases["default"], "gpt-4o");
+        assert!(config.gateway.model_list.is_empty());
+    }
+
     #[test]
     fn test_provider_builder() {
         let provider = ProviderConfigBuilder::new()
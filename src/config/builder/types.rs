@@ -11,6 +11,16 @@ pub struct ConfigBuilder {
     pub(super) auth: Option<super::super::AuthConfig>,
     pub(super) storage: Option<super::super::StorageConfig>,
     pub(super) providers: Vec<super::super::ProviderConfig>,
+    pub(super) model_list: Vec<super::super::ModelListEntry>,
+    pub(super) router: Option<super::super::RouterConfig>,
+    pub(super) monitoring: Option<super::super::MonitoringConfig>,
+    pub(super) cache: Option<super::super::CacheConfig>,
+    pub(super) rate_limit: Option<super::super::RateLimitConfig>,
+    pub(super) enterprise: Option<super::super::EnterpriseConfig>,
+    pub(super) pass_through: Vec<super::super::PassThroughEndpoint>,
+    pub(super) callbacks: Option<super::super::CallbacksConfig>,
+    pub(super) guardrails: Option<super::super::GuardrailsConfig>,
+    pub(super) secrets: Option<super::super::SecretsConfig>,
     pub(super) features: HashMap<String, bool>,
 }
 
@@ -0,0 +1,162 @@
+//! Configuration file checks
+//!
+//! Backs the `validate-config` subcommand: a configuration file is loaded as the
+//! gateway would load it, then every provider's credentials are resolved and
+//! its settings validated, and optionally the provider is pinged.
+
+use super::secrets::SecretResolver;
+use super::source::INCLUDE_KEY;
+use super::{Config, ConfigLocation, GatewayConfig, Validate};
+use crate::core::providers::Provider;
+use crate::core::types::health::HealthStatus;
+use crate::utils::error::Result;
+use serde::Serialize;
+
+/// Outcome of checking a configuration file
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigCheck {
+    /// Checks of each provider, in configuration order
+    pub providers: Vec<ProviderCheck>,
+}
+
+impl ConfigCheck {
+    /// Whether every provider passed
+    pub fn is_ok(&self) -> bool {
+        self.providers.iter().all(ProviderCheck::is_ok)
+    }
+}
+
+/// Outcome of checking one provider
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderCheck {
+    /// Provider name
+    pub name: String,
+    /// Provider type
+    pub provider_type: String,
+    /// Why the provider's configuration is invalid
+    pub error: Option<String>,
+    /// Health reported by the provider, if pinged
+    pub health: Option<HealthStatus>,
+}
+
+impl ProviderCheck {
+    /// Whether the provider is valid and, if pinged, not unhealthy
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.health != Some(HealthStatus::Unhealthy)
+    }
+}
+
+/// Check a configuration file or remote configuration
+///
+/// Errors loading the configuration, including invalid settings outside
+/// providers, are returned; provider errors are reported in the check. With
+/// `ping`, valid providers are created and health checked.
+pub async fn check_config(location: &ConfigLocation, ping: bool) -> Result<ConfigCheck> {
+    let mut config = Config::from_location(location).await?;
+    let secrets = SecretResolver::new(&config.gateway.secrets);
+
+    let mut providers = Vec::with_capacity(config.gateway.providers.len());
+    for provider in &mut config.gateway.providers {
+        let mut check = ProviderCheck {
+            name: provider.name.clone(),
+            provider_type: provider.provider_type.clone(),
+            error: None,
+            health: None,
+        };
+
+        if let Err(e) = provider.validate() {
+            check.error = Some(e);
+        } else if let Err(e) = secrets
+            .resolve_providers(std::slice::from_mut(provider))
+            .await
+        {
+            check.error = Some(e.to_string());
+        } else if ping {
+            match Provider::from_provider_config(provider).await {
+                Ok(created) => check.health = Some(created.health_check().await),
+                Err(e) => check.error = Some(e.to_string()),
+            }
+        }
+        providers.push(check);
+    }
+
+    Ok(ConfigCheck { providers })
+}
+
+/// JSON Schema of configuration files, for editor completion and checks
+pub fn json_schema() -> serde_json::Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(GatewayConfig))
+        .unwrap_or_else(|_| serde_json::json!({}));
+    // Included files are merged before the configuration is deserialized
+    if let Some(properties) = schema
+        .get_mut("properties")
+        .and_then(|properties| properties.as_object_mut())
+    {
+        properties.insert(
+            INCLUDE_KEY.to_string(),
+            serde_json::json!({
+                "description": "Files merged beneath this one, relative to it",
+                "anyOf": [
+                    { "type": "string" },
+                    { "type": "array", "items": { "type": "string" } }
+                ]
+            }),
+        );
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_check_config_reports_each_provider() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        std::fs::write(
+            &path,
+            r#"
+providers:
+  - name: openai
+    provider_type: openai
+    api_key: sk-test-key
+  - name: broken
+    provider_type: anthropic
+    api_key: ""
+"#,
+        )
+        .unwrap();
+
+        let check = check_config(&path.into(), false).await.unwrap();
+        assert!(!check.is_ok());
+        assert_eq!(check.providers.len(), 2);
+        assert!(check.providers[0].is_ok());
+        assert!(check.providers[0].health.is_none());
+        let error = check.providers[1].error.as_deref().unwrap();
+        assert!(error.contains("API key cannot be empty"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_check_config_rejects_invalid_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        std::fs::write(&path, "server:\n  port: not-a-port\n").unwrap();
+        assert!(check_config(&path.into(), false).await.is_err());
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let schema = json_schema();
+        let properties = &schema["properties"];
+        for key in ["server", "providers", "model_list", "router", "include"] {
+            assert!(properties.get(key).is_some(), "{}", key);
+        }
+        assert!(
+            schema
+                .to_string()
+                .contains("Provider type (openai, anthropic, etc.)"),
+            "provider docs are descriptions"
+        );
+    }
+}
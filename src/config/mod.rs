@@ -3,13 +3,18 @@
 //! This module handles loading, validation, and management of all gateway configuration.
 
 pub mod builder;
+pub mod check;
 pub mod models;
+pub mod remote;
+pub mod secrets;
+pub mod source;
 pub mod validation;
 // pub mod loader;
 
+pub use builder::types::{ConfigBuilder, ProviderConfigBuilder, ServerConfigBuilder};
 pub use models::*;
+pub use source::ConfigLocation;
 pub use validation::Validate;
-// pub use builder::*;  // Commented out until actually used
 // pub use loader::*;
 
 use crate::utils::error::{GatewayError, Result};
@@ -21,23 +26,45 @@ use tracing::{debug, info};
 pub struct Config {
     /// Gateway configuration
     pub gateway: GatewayConfig,
+    /// Where the configuration was loaded from
+    location: Option<ConfigLocation>,
 }
 
 #[allow(dead_code)]
 impl Config {
+    /// Build a configuration in code, without a file
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+
     /// Load configuration from file
+    ///
+    /// The file is YAML, JSON or TOML. Includes are merged and environment variables interpolated, see
+    /// [`source`], and a LiteLLM proxy `model_list` becomes providers.
     pub async fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let path = path.as_ref();
-        info!("Loading configuration from: {:?}", path);
+        Self::from_location(&ConfigLocation::File(path.as_ref().to_path_buf())).await
+    }
+
+    /// Load configuration from a file path, `https://` URL or
+    /// `s3://<bucket>/<key>` object, see [`remote`]
+    pub async fn load(location: &str) -> Result<Self> {
+        Self::from_location(&ConfigLocation::parse(location)?).await
+    }
+
+    /// Load configuration from a file, URL or S3 object
+    pub async fn from_location(location: &ConfigLocation) -> Result<Self> {
+        info!("Loading configuration from: {}", location);
 
-        let content = tokio::fs::read_to_string(path)
-            .await
-            .map_err(|e| GatewayError::Config(format!("Failed to read config file: {}", e)))?;
+        let value = source::load_location(location).await?;
 
-        let gateway: GatewayConfig = serde_yaml::from_str(&content)
+        let mut gateway: GatewayConfig = serde_yaml::from_value(value)
             .map_err(|e| GatewayError::Config(format!("Failed to parse config: {}", e)))?;
+        gateway.apply_model_list();
 
-        let config = Self { gateway };
+        let config = Self {
+            gateway,
+            location: Some(location.clone()),
+        };
 
         // Configuration
         config.validate()?;
@@ -51,12 +78,20 @@ impl Config {
         info!("Loading configuration from environment variables");
 
         let gateway = GatewayConfig::from_env()?;
-        let config = Self { gateway };
+        let config = Self {
+            gateway,
+            location: None,
+        };
 
         config.validate()?;
         Ok(config)
     }
 
+    /// Where the configuration was loaded from, if not built in code
+    pub fn location(&self) -> Option<&ConfigLocation> {
+        self.location.as_ref()
+    }
+
     /// Get server configuration
     pub fn server(&self) -> &ServerConfig {
         &self.gateway.server
@@ -110,6 +145,17 @@ impl Config {
             .validate()
             .map_err(|e| GatewayError::Config(format!("CORS config error: {}", e)))?;
 
+        // Validate router configuration
+        self.gateway
+            .router
+            .validate()
+            .and_then(|()| self.gateway.validate_traffic_splits())
+            .map_err(|e| GatewayError::Config(format!("Router config error: {}", e)))?;
+
+        // Validate guardrails configuration, creating each guardrail
+        crate::core::guardrails::Guardrails::from_config(&self.gateway.guardrails)
+            .map_err(|e| GatewayError::Config(format!("Guardrails config error: {}", e)))?;
+
         // Warn about insecure configurations
         crate::config::models::auth::warn_insecure_config(&self.gateway.auth);
 
@@ -190,12 +236,102 @@ monitoring:
         assert_eq!(config.providers()[0].name, "openai");
     }
 
+    #[tokio::test]
+    async fn test_config_from_toml_and_json_files() {
+        let toml_content = r#"
+[server]
+host = "127.0.0.1"
+port = 8080
+
+[[providers]]
+name = "openai"
+provider_type = "openai"
+api_key = "test-key"
+"#;
+        let mut toml_file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        toml_file.write_all(toml_content.as_bytes()).unwrap();
+        let from_toml = Config::from_file(toml_file.path()).await.unwrap();
+
+        let mut json_file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        json_file
+            .write_all(from_toml.to_json().unwrap().as_bytes())
+            .unwrap();
+        let from_json = Config::from_file(json_file.path()).await.unwrap();
+
+        for config in [from_toml, from_json] {
+            assert_eq!(config.server().host, "127.0.0.1");
+            assert_eq!(config.server().port, 8080);
+            assert_eq!(config.providers()[0].name, "openai");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_config_from_litellm_proxy_file() {
+        let config_content = r#"
+model_list:
+  - model_name: gpt-4o
+    litellm_params:
+      model: openai/gpt-4o
+      api_key: sk-test-key
+  - model_name: gpt-4o
+    litellm_params:
+      model: azure/gpt-4o-eu
+      api_base: https://eu.openai.azure.com
+      api_key: azure-key
+
+litellm_settings:
+  drop_params: true
+
+general_settings:
+  master_key: sk-1234
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(config_content.as_bytes()).unwrap();
+
+        let config = Config::from_file(temp_file.path()).await.unwrap();
+
+        assert!(config.gateway.model_list.is_empty());
+        let providers = config.providers();
+        assert_eq!(providers.len(), 2);
+        assert_eq!(providers[0].name, "gpt-4o");
+        assert_eq!(providers[1].name, "gpt-4o-2");
+        assert_eq!(providers[1].provider_type, "azure");
+        assert_eq!(providers[1].models, ["gpt-4o-eu"]);
+        assert_eq!(providers[1].model_name.as_deref(), Some("gpt-4o"));
+    }
+
     #[test]
     fn test_default_config() {
         let config = Config::default();
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_config_traffic_split_targets() {
+        let mut config = Config::default();
+        config.gateway.providers.push(ProviderConfig {
+            name: "openai".to_string(),
+            provider_type: "openai".to_string(),
+            api_key: "test-api-key".to_string(),
+            models: vec!["gpt-4o".to_string(), "ft:gpt-4o:acme".to_string()],
+            ..ProviderConfig::default()
+        });
+        config.gateway.router.traffic_splits.insert(
+            "gpt-4o".to_string(),
+            [
+                ("gpt-4o".to_string(), 95),
+                ("ft:gpt-4o:acme".to_string(), 5),
+            ]
+            .into(),
+        );
+        assert!(config.validate().is_ok());
+
+        config.gateway.providers[0].models.pop();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("ft:gpt-4o:acme"), "{}", err);
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = Config::default();
@@ -3,11 +3,14 @@
 use super::*;
 use rand::distributions::Alphanumeric;
 use rand::{Rng, thread_rng};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tracing::warn;
+use uuid::Uuid;
 
 /// Authentication configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AuthConfig {
     /// Enable JWT authentication
     #[serde(default = "default_true")]
@@ -26,6 +29,8 @@ pub struct AuthConfig {
     /// RBAC configuration
     #[serde(default)]
     pub rbac: RbacConfig,
+    /// JWTs issued by an OIDC identity provider, checked against its JWKS
+    pub oidc: Option<OidcConfig>,
 }
 
 impl Default for AuthConfig {
@@ -37,6 +42,7 @@ impl Default for AuthConfig {
             jwt_expiration: default_jwt_expiration(),
             api_key_header: default_api_key_header(),
             rbac: RbacConfig::default(),
+            oidc: None,
         }
     }
 }
@@ -61,6 +67,9 @@ impl AuthConfig {
             self.api_key_header = other.api_key_header;
         }
         self.rbac = self.rbac.merge(other.rbac);
+        if other.oidc.is_some() {
+            self.oidc = other.oidc;
+        }
         self
     }
 
@@ -115,7 +124,7 @@ impl AuthConfig {
 }
 
 /// RBAC configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RbacConfig {
     /// Enable RBAC
     #[serde(default)]
@@ -155,6 +164,56 @@ impl RbacConfig {
     }
 }
 
+/// OIDC JWT authentication
+///
+/// Bearer tokens signed by the identity provider are verified against the
+/// keys published at `jwks_url`. Claims name the user, their team and their
+/// roles; roles grant the permissions of the RBAC role of the same name.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OidcConfig {
+    /// JWKS URL, e.g. "https://idp.example.com/.well-known/jwks.json"
+    pub jwks_url: String,
+    /// Required issuer (`iss`)
+    pub issuer: Option<String>,
+    /// Accepted audiences (`aud`); any audience when empty
+    #[serde(default)]
+    pub audiences: Vec<String>,
+    /// Seconds signing keys are cached
+    #[serde(default = "default_jwks_cache_ttl")]
+    pub jwks_cache_ttl: u64,
+    /// Claim holding the user ID
+    #[serde(default = "default_user_claim")]
+    pub user_claim: String,
+    /// Claim holding the team; dotted paths reach nested claims
+    #[serde(default = "default_team_claim")]
+    pub team_claim: String,
+    /// Claim holding the roles; dotted paths reach nested claims
+    #[serde(default = "default_roles_claim")]
+    pub roles_claim: String,
+    /// Gateway team IDs by team claim value
+    #[serde(default)]
+    pub teams: HashMap<String, Uuid>,
+    /// RBAC role names by identity provider role
+    #[serde(default)]
+    pub role_mappings: HashMap<String, String>,
+}
+
+fn default_jwks_cache_ttl() -> u64 {
+    3600
+}
+
+fn default_user_claim() -> String {
+    "sub".to_string()
+}
+
+fn default_team_claim() -> String {
+    "org".to_string()
+}
+
+fn default_roles_claim() -> String {
+    "roles".to_string()
+}
+
 fn default_true() -> bool {
     true
 }
@@ -281,6 +340,7 @@ mod tests {
             jwt_expiration: 7200,
             api_key_header: "Authorization".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.enable_jwt);
         assert!(!config.enable_api_key);
@@ -296,6 +356,7 @@ mod tests {
             jwt_expiration: 1800,
             api_key_header: "X-Token".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         let json = serde_json::to_value(&config).unwrap();
         assert_eq!(json["enable_jwt"], true);
@@ -311,6 +372,7 @@ mod tests {
             jwt_expiration: 3600,
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.validate().is_err());
     }
@@ -324,6 +386,7 @@ mod tests {
             jwt_expiration: 3600,
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.validate().is_err());
     }
@@ -337,6 +400,7 @@ mod tests {
             jwt_expiration: 3600,
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.validate().is_err());
     }
@@ -350,6 +414,7 @@ mod tests {
             jwt_expiration: 100, // less than 300
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.validate().is_err());
     }
@@ -363,6 +428,7 @@ mod tests {
             jwt_expiration: 86400 * 31, // more than 30 days
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.validate().is_err());
     }
@@ -376,6 +442,7 @@ mod tests {
             jwt_expiration: 3600,
             api_key_header: "".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(config.validate().is_err());
     }
@@ -398,6 +465,7 @@ mod tests {
             jwt_expiration: 3600,
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         assert!(!disabled.is_production_ready());
     }
@@ -412,6 +480,7 @@ mod tests {
             jwt_expiration: 3600,
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         let merged = base.merge(other);
         assert!(merged.jwt_secret.contains("CustomSecret123"));
@@ -427,6 +496,7 @@ mod tests {
             jwt_expiration: 7200,
             api_key_header: "X-API-Key".to_string(),
             rbac: RbacConfig::default(),
+            oidc: None,
         };
         let merged = base.merge(other);
         assert_eq!(merged.jwt_expiration, 7200);
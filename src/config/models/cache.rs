@@ -1,10 +1,11 @@
 //! Cache configuration
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Cache configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CacheConfig {
     /// Enable caching
     #[serde(default)]
@@ -15,12 +16,31 @@ pub struct CacheConfig {
     /// Maximum cache size
     #[serde(default = "default_cache_max_size")]
     pub max_size: usize,
+    /// Seconds responses stay in the in-memory tier in front of Redis, at
+    /// most their TTL
+    #[serde(default)]
+    pub memory_ttl: Option<u64>,
     /// Enable semantic caching
     #[serde(default)]
     pub semantic_cache: bool,
     /// Similarity threshold for semantic cache
     #[serde(default = "default_similarity_threshold")]
     pub similarity_threshold: f64,
+    /// Embedding model the semantic cache embeds prompts with
+    #[serde(default = "default_cache_embedding_model")]
+    pub embedding_model: String,
+    /// Provider serving the embedding model, by default OpenAI or else Azure
+    #[serde(default)]
+    pub embedding_provider: Option<String>,
+    /// Namespace responses are cached in, unless a request sets its own
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Cache responses in a namespace per API key, so that keys never get
+    /// each other's responses; callers authenticated without a key, e.g.
+    /// via OIDC, get a namespace per user or OIDC subject, and
+    /// unauthenticated callers are not cached
+    #[serde(default)]
+    pub per_key: bool,
 }
 
 impl Default for CacheConfig {
@@ -29,8 +49,13 @@ impl Default for CacheConfig {
             enabled: false,
             ttl: default_cache_ttl(),
             max_size: default_cache_max_size(),
+            memory_ttl: None,
             semantic_cache: false,
             similarity_threshold: default_similarity_threshold(),
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         }
     }
 }
@@ -48,12 +73,27 @@ impl CacheConfig {
         if other.max_size != default_cache_max_size() {
             self.max_size = other.max_size;
         }
+        if other.memory_ttl.is_some() {
+            self.memory_ttl = other.memory_ttl;
+        }
         if other.semantic_cache {
             self.semantic_cache = other.semantic_cache;
         }
         if other.similarity_threshold != default_similarity_threshold() {
             self.similarity_threshold = other.similarity_threshold;
         }
+        if other.embedding_model != default_cache_embedding_model() {
+            self.embedding_model = other.embedding_model;
+        }
+        if other.embedding_provider.is_some() {
+            self.embedding_provider = other.embedding_provider;
+        }
+        if other.namespace.is_some() {
+            self.namespace = other.namespace;
+        }
+        if other.per_key {
+            self.per_key = other.per_key;
+        }
         self
     }
 }
@@ -70,6 +110,11 @@ mod tests {
         assert_eq!(config.max_size, 1000);
         assert!(!config.semantic_cache);
         assert!((config.similarity_threshold - 0.95).abs() < f64::EPSILON);
+        assert!(config.memory_ttl.is_none());
+        assert_eq!(config.embedding_model, "text-embedding-ada-002");
+        assert!(config.embedding_provider.is_none());
+        assert!(config.namespace.is_none());
+        assert!(!config.per_key);
     }
 
     #[test]
@@ -78,8 +123,13 @@ mod tests {
             enabled: true,
             ttl: 7200,
             max_size: 5000,
+            memory_ttl: None,
             semantic_cache: true,
             similarity_threshold: 0.9,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         assert!(config.enabled);
         assert_eq!(config.ttl, 7200);
@@ -92,8 +142,13 @@ mod tests {
             enabled: true,
             ttl: 1800,
             max_size: 2000,
+            memory_ttl: None,
             semantic_cache: false,
             similarity_threshold: 0.85,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         let json = serde_json::to_value(&config).unwrap();
         assert_eq!(json["enabled"], true);
@@ -108,6 +163,11 @@ mod tests {
         assert!(config.enabled);
         assert_eq!(config.ttl, 900);
         assert!(config.semantic_cache);
+
+        let json = r#"{"enabled": true, "namespace": "prod", "per_key": true}"#;
+        let config: CacheConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.namespace.as_deref(), Some("prod"));
+        assert!(config.per_key);
     }
 
     #[test]
@@ -117,8 +177,13 @@ mod tests {
             enabled: true,
             ttl: 3600,
             max_size: 1000,
+            memory_ttl: None,
             semantic_cache: false,
             similarity_threshold: 0.95,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         let merged = base.merge(other);
         assert!(merged.enabled);
@@ -131,8 +196,13 @@ mod tests {
             enabled: false,
             ttl: 1800,
             max_size: 1000,
+            memory_ttl: None,
             semantic_cache: false,
             similarity_threshold: 0.95,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         let merged = base.merge(other);
         assert_eq!(merged.ttl, 1800);
@@ -145,8 +215,13 @@ mod tests {
             enabled: false,
             ttl: 3600,
             max_size: 1000,
+            memory_ttl: None,
             semantic_cache: true,
             similarity_threshold: 0.95,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         let merged = base.merge(other);
         assert!(merged.semantic_cache);
@@ -159,8 +234,13 @@ mod tests {
             enabled: false,
             ttl: 3600,
             max_size: 1000,
+            memory_ttl: None,
             semantic_cache: false,
             similarity_threshold: 0.8,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         let merged = base.merge(other);
         assert!((merged.similarity_threshold - 0.8).abs() < f64::EPSILON);
@@ -172,8 +252,13 @@ mod tests {
             enabled: true,
             ttl: 3600,
             max_size: 2000,
+            memory_ttl: None,
             semantic_cache: true,
             similarity_threshold: 0.9,
+            embedding_model: default_cache_embedding_model(),
+            embedding_provider: None,
+            namespace: None,
+            per_key: false,
         };
         let cloned = config.clone();
         assert_eq!(config.enabled, cloned.enabled);
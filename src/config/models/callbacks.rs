@@ -0,0 +1,261 @@
+//! Logging callback configuration
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Logging callbacks run after each request
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CallbacksConfig {
+    /// Langfuse generation logging
+    #[serde(default)]
+    pub langfuse: Option<LangfuseConfig>,
+    /// Datadog LLM Observability
+    #[serde(default)]
+    pub datadog: Option<DatadogConfig>,
+}
+
+impl CallbacksConfig {
+    /// Merge callback configurations
+    pub fn merge(mut self, other: Self) -> Self {
+        if other.langfuse.is_some() {
+            self.langfuse = other.langfuse;
+        }
+        if other.datadog.is_some() {
+            self.datadog = other.datadog;
+        }
+        self
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(langfuse) = &self.langfuse {
+            langfuse.validate()?;
+        }
+        if let Some(datadog) = &self.datadog {
+            datadog.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Langfuse logging configuration
+///
+/// Requests made with an API key listed in `api_keys` are logged to that
+/// key's project; all other requests go to the global project, if any.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LangfuseConfig {
+    /// Global project public key
+    #[serde(default)]
+    pub public_key: Option<String>,
+    /// Global project secret key
+    #[serde(default)]
+    pub secret_key: Option<String>,
+    /// Langfuse host
+    #[serde(default = "default_langfuse_host")]
+    pub host: String,
+    /// Projects by gateway API key ID
+    #[serde(default)]
+    pub api_keys: HashMap<String, LangfuseProject>,
+    /// Whether prompts and completions are sent, or only usage and metadata
+    #[serde(default = "default_true")]
+    pub log_content: bool,
+    /// Maximum events per ingestion request
+    #[serde(default = "default_langfuse_batch_size")]
+    pub batch_size: usize,
+    /// Maximum seconds an event waits before it is sent
+    #[serde(default = "default_langfuse_flush_interval")]
+    pub flush_interval: u64,
+}
+
+impl Default for LangfuseConfig {
+    fn default() -> Self {
+        Self {
+            public_key: None,
+            secret_key: None,
+            host: default_langfuse_host(),
+            api_keys: HashMap::new(),
+            log_content: true,
+            batch_size: default_langfuse_batch_size(),
+            flush_interval: default_langfuse_flush_interval(),
+        }
+    }
+}
+
+impl LangfuseConfig {
+    /// Global project, if both keys are set
+    pub fn global_project(&self) -> Option<LangfuseProject> {
+        Some(LangfuseProject {
+            public_key: self.public_key.clone()?,
+            secret_key: self.secret_key.clone()?,
+            host: None,
+        })
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.public_key.is_some() != self.secret_key.is_some() {
+            return Err("Langfuse public_key and secret_key must be set together".to_string());
+        }
+        if self.batch_size == 0 {
+            return Err("Langfuse batch_size must be greater than 0".to_string());
+        }
+        for (key_id, project) in &self.api_keys {
+            if project.public_key.is_empty() || project.secret_key.is_empty() {
+                return Err(format!(
+                    "Langfuse project for API key {} needs public_key and secret_key",
+                    key_id
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Langfuse project credentials
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct LangfuseProject {
+    /// Project public key
+    pub public_key: String,
+    /// Project secret key
+    pub secret_key: String,
+    /// Host override for this project
+    #[serde(default)]
+    pub host: Option<String>,
+}
+
+/// Datadog LLM Observability configuration
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DatadogConfig {
+    /// Datadog API key
+    pub api_key: String,
+    /// Datadog site, e.g. "datadoghq.com" or "datadoghq.eu"
+    #[serde(default = "default_datadog_site")]
+    pub site: String,
+    /// ML application name spans are grouped under
+    #[serde(default = "default_datadog_ml_app")]
+    pub ml_app: String,
+    /// Environment tag, e.g. "prod"
+    #[serde(default)]
+    pub env: Option<String>,
+    /// Whether prompts and completions are sent, or only usage and metadata
+    #[serde(default = "default_true")]
+    pub log_content: bool,
+    /// Maximum spans per submission
+    #[serde(default = "default_datadog_batch_size")]
+    pub batch_size: usize,
+    /// Maximum seconds a span waits before it is sent
+    #[serde(default = "default_datadog_flush_interval")]
+    pub flush_interval: u64,
+    /// Spans buffered before new ones are dropped
+    #[serde(default = "default_datadog_queue_size")]
+    pub queue_size: usize,
+}
+
+impl Default for DatadogConfig {
+    fn default() -> Self {
+        Self {
+            api_key: String::new(),
+            site: default_datadog_site(),
+            ml_app: default_datadog_ml_app(),
+            env: None,
+            log_content: true,
+            batch_size: default_datadog_batch_size(),
+            flush_interval: default_datadog_flush_interval(),
+            queue_size: default_datadog_queue_size(),
+        }
+    }
+}
+
+impl DatadogConfig {
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.api_key.is_empty() {
+            return Err("Datadog api_key is required".to_string());
+        }
+        if self.site.is_empty() {
+            return Err("Datadog site cannot be empty".to_string());
+        }
+        if self.batch_size == 0 || self.queue_size == 0 {
+            return Err("Datadog batch_size and queue_size must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn default_langfuse_host() -> String {
+    "https://cloud.langfuse.com".to_string()
+}
+
+fn default_langfuse_batch_size() -> usize {
+    100
+}
+
+fn default_langfuse_flush_interval() -> u64 {
+    5
+}
+
+fn default_datadog_site() -> String {
+    "datadoghq.com".to_string()
+}
+
+fn default_datadog_ml_app() -> String {
+    "litellm-rs".to_string()
+}
+
+fn default_datadog_batch_size() -> usize {
+    100
+}
+
+fn default_datadog_flush_interval() -> u64 {
+    10
+}
+
+fn default_datadog_queue_size() -> usize {
+    10_000
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_langfuse_config_deserialization() {
+        let config: CallbacksConfig = serde_yaml::from_str(
+            "langfuse:\n  public_key: pk-lf-1\n  secret_key: sk-lf-1\n  api_keys:\n    key-1:\n      public_key: pk-lf-2\n      secret_key: sk-lf-2\n",
+        )
+        .unwrap();
+        let langfuse = config.langfuse.unwrap();
+        assert_eq!(langfuse.host, "https://cloud.langfuse.com");
+        assert_eq!(langfuse.global_project().unwrap().public_key, "pk-lf-1");
+        assert_eq!(langfuse.api_keys["key-1"].secret_key, "sk-lf-2");
+        assert!(langfuse.log_content);
+        assert!(langfuse.validate().is_ok());
+    }
+
+    #[test]
+    fn test_langfuse_config_validation() {
+        let config = LangfuseConfig {
+            public_key: Some("pk-lf-1".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        assert!(config.global_project().is_none());
+    }
+
+    #[test]
+    fn test_datadog_config_deserialization() {
+        let config: CallbacksConfig =
+            serde_yaml::from_str("datadog:\n  api_key: dd-key\n  site: datadoghq.eu\n").unwrap();
+        let datadog = config.datadog.unwrap();
+        assert_eq!(datadog.site, "datadoghq.eu");
+        assert_eq!(datadog.ml_app, "litellm-rs");
+        assert_eq!(datadog.batch_size, 100);
+        assert!(datadog.validate().is_ok());
+        assert!(DatadogConfig::default().validate().is_err());
+    }
+}
@@ -1,9 +1,10 @@
 //! Enterprise configuration
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Enterprise configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct EnterpriseConfig {
     /// Enable enterprise features
     #[serde(default)]
@@ -39,7 +40,7 @@ impl EnterpriseConfig {
 }
 
 /// SSO configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SsoConfig {
     /// SSO provider
     pub provider: String,
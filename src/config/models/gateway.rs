@@ -3,23 +3,34 @@
 #![allow(missing_docs)]
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Main gateway configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct GatewayConfig {
     /// Server configuration
+    #[serde(default)]
     pub server: ServerConfig,
     /// Provider configurations
+    #[serde(default)]
     pub providers: Vec<ProviderConfig>,
+    /// Deployments in the Python LiteLLM proxy's `model_list` format, moved
+    /// into `providers` when the configuration is loaded
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub model_list: Vec<ModelListEntry>,
     /// Router configuration
+    #[serde(default)]
     pub router: RouterConfig,
     /// Storage configuration
+    #[serde(default)]
     pub storage: StorageConfig,
     /// Authentication configuration
+    #[serde(default)]
     pub auth: AuthConfig,
     /// Monitoring configuration
+    #[serde(default)]
     pub monitoring: MonitoringConfig,
     /// Caching configuration
     #[serde(default)]
@@ -30,6 +41,18 @@ pub struct GatewayConfig {
     /// Enterprise features configuration
     #[serde(default)]
     pub enterprise: EnterpriseConfig,
+    /// Provider pass-through endpoints
+    #[serde(default)]
+    pub pass_through: Vec<PassThroughEndpoint>,
+    /// Logging callbacks
+    #[serde(default)]
+    pub callbacks: CallbacksConfig,
+    /// Guardrails run around provider calls
+    #[serde(default)]
+    pub guardrails: GuardrailsConfig,
+    /// Secret stores provider API keys may reference
+    #[serde(default)]
+    pub secrets: SecretsConfig,
 }
 
 #[allow(dead_code)]
@@ -38,6 +61,7 @@ impl GatewayConfig {
         Ok(Self {
             server: ServerConfig::default(),
             providers: vec![],
+            model_list: vec![],
             router: RouterConfig::default(),
             storage: StorageConfig::default(),
             auth: AuthConfig::default(),
@@ -45,12 +69,23 @@ impl GatewayConfig {
             cache: CacheConfig::default(),
             rate_limit: RateLimitConfig::default(),
             enterprise: EnterpriseConfig::default(),
+            pass_through: vec![],
+            callbacks: CallbacksConfig::default(),
+            guardrails: GuardrailsConfig::default(),
+            secrets: SecretsConfig::default(),
         })
     }
 }
 
 #[allow(dead_code)]
 impl GatewayConfig {
+    /// Move the `model_list` deployments into `providers`
+    pub fn apply_model_list(&mut self) {
+        let model_list = std::mem::take(&mut self.model_list);
+        let providers = model_list_providers(&model_list, &self.providers);
+        self.providers.extend(providers);
+    }
+
     /// Merge two configurations, with other taking precedence
     pub fn merge(mut self, other: Self) -> Self {
         self.server = self.server.merge(other.server);
@@ -67,6 +102,7 @@ impl GatewayConfig {
         }
 
         self.providers = provider_map.into_values().collect();
+        self.model_list.extend(other.model_list);
         self.router = self.router.merge(other.router);
         self.storage = self.storage.merge(other.storage);
         self.auth = self.auth.merge(other.auth);
@@ -74,6 +110,12 @@ impl GatewayConfig {
         self.cache = self.cache.merge(other.cache);
         self.rate_limit = self.rate_limit.merge(other.rate_limit);
         self.enterprise = self.enterprise.merge(other.enterprise);
+        if !other.pass_through.is_empty() {
+            self.pass_through = other.pass_through;
+        }
+        self.callbacks = self.callbacks.merge(other.callbacks);
+        self.guardrails = self.guardrails.merge(other.guardrails);
+        self.secrets = self.secrets.merge(other.secrets);
 
         self
     }
@@ -106,6 +148,23 @@ impl GatewayConfig {
             }
         }
 
+        self.callbacks.validate()?;
+
+        // Validate pass-through endpoints
+        let mut pass_through_paths = std::collections::HashSet::new();
+        for endpoint in &self.pass_through {
+            endpoint.validate()?;
+            if !pass_through_paths.insert(&endpoint.path) {
+                return Err(format!("Duplicate pass-through path: {}", endpoint.path));
+            }
+            if !provider_names.contains(&endpoint.provider) {
+                return Err(format!(
+                    "Pass-through endpoint {} references unknown provider: {}",
+                    endpoint.path, endpoint.provider
+                ));
+            }
+        }
+
         // Validate storage config
         if self.storage.database.url.is_empty() {
             return Err("Database URL is required".to_string());
@@ -266,6 +325,23 @@ mod tests {
         assert!(result.unwrap_err().contains("Duplicate"));
     }
 
+    #[test]
+    fn test_gateway_config_validate_pass_through_provider() {
+        let mut config = create_valid_config();
+        config.pass_through.push(PassThroughEndpoint {
+            path: "/cohere".to_string(),
+            provider: "cohere".to_string(),
+            target: None,
+            headers: HashMap::new(),
+            auth: true,
+        });
+        let result = config.validate();
+        assert!(result.unwrap_err().contains("unknown provider"));
+
+        config.pass_through[0].provider = "test-provider".to_string();
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_gateway_config_validate_empty_api_key() {
         let mut config = create_valid_config();
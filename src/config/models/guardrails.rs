@@ -0,0 +1,196 @@
+//! Guardrail configuration
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Guardrails run around provider calls
+///
+/// Every call runs the `default` guardrails, then those of its model group,
+/// then those of the API key it was made with; a guardrail listed more than
+/// once runs once, at its first position.
+///
+/// ```yaml
+/// guardrails:
+///   guardrails:
+///     - name: content-safety
+///       guardrail: azure_content_safety
+///       mode: block
+///       settings:
+///         endpoint: https://contoso.cognitiveservices.azure.com
+///         api_key: os.environ/AZURE_CONTENT_SAFETY_API_KEY
+///   default: [content-safety]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GuardrailsConfig {
+    /// Guardrails pipelines refer to by name
+    #[serde(default)]
+    pub guardrails: Vec<GuardrailConfig>,
+    /// Guardrails run on every call
+    #[serde(default)]
+    pub default: Vec<String>,
+    /// Further guardrails run on calls to a model group
+    #[serde(default)]
+    pub model_groups: HashMap<String, Vec<String>>,
+    /// Further guardrails run on calls made with an API key, by key ID
+    #[serde(default)]
+    pub api_keys: HashMap<String, Vec<String>>,
+}
+
+impl GuardrailsConfig {
+    /// Whether any guardrail can run
+    pub fn is_enabled(&self) -> bool {
+        !self.guardrails.is_empty()
+    }
+
+    /// Merge guardrail configurations
+    pub fn merge(self, other: Self) -> Self {
+        if other.is_enabled() { other } else { self }
+    }
+
+    /// Validate the configuration
+    ///
+    /// `known` tells whether a guardrail implementation exists.
+    pub fn validate(&self, known: impl Fn(&str) -> bool) -> Result<(), String> {
+        let mut names = HashSet::new();
+        for guardrail in &self.guardrails {
+            if guardrail.name.is_empty() {
+                return Err("Guardrail name cannot be empty".to_string());
+            }
+            if !names.insert(guardrail.name.as_str()) {
+                return Err(format!("Duplicate guardrail name: {}", guardrail.name));
+            }
+            if !known(&guardrail.guardrail) {
+                return Err(format!(
+                    "Guardrail {} has unknown implementation: {}",
+                    guardrail.name, guardrail.guardrail
+                ));
+            }
+        }
+
+        let pipelines = std::iter::once(("default", &self.default))
+            .chain(self.model_groups.iter().map(|(k, v)| (k.as_str(), v)))
+            .chain(self.api_keys.iter().map(|(k, v)| (k.as_str(), v)));
+        for (pipeline, guardrails) in pipelines {
+            if let Some(missing) = guardrails.iter().find(|g| !names.contains(g.as_str())) {
+                return Err(format!(
+                    "Guardrail pipeline {} refers to unknown guardrail: {}",
+                    pipeline, missing
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A configured guardrail
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GuardrailConfig {
+    /// Name pipelines refer to
+    pub name: String,
+    /// Implementation, e.g. `azure_content_safety`
+    pub guardrail: String,
+    /// What happens to flagged content
+    #[serde(default)]
+    pub mode: GuardrailMode,
+    /// Check prompts before the provider is called
+    #[serde(default = "default_true")]
+    pub pre_call: bool,
+    /// Check completions after the provider responded
+    #[serde(default = "default_true")]
+    pub post_call: bool,
+    /// Check streamed completions as they arrive
+    #[serde(default = "default_true")]
+    pub streaming: bool,
+    /// Settings of the implementation
+    #[serde(default)]
+    pub settings: HashMap<String, serde_json::Value>,
+}
+
+impl GuardrailConfig {
+    /// Guardrail with default settings, checking every stage
+    pub fn new(name: impl Into<String>, guardrail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            guardrail: guardrail.into(),
+            mode: GuardrailMode::default(),
+            pre_call: true,
+            post_call: true,
+            streaming: true,
+            settings: HashMap::new(),
+        }
+    }
+}
+
+/// What happens to content a guardrail flags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailMode {
+    /// Reject the call with a content-filter error
+    #[default]
+    Block,
+    /// Replace the flagged text and let the call through; content the
+    /// guardrail cannot mask is blocked
+    #[serde(alias = "replace")]
+    Mask,
+    /// Attach the finding to the response's `guardrails` field and let the
+    /// call through unchanged; findings in streams are logged
+    Annotate,
+    /// Log the finding and let the call through unchanged
+    LogOnly,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guardrails_config_deserialization() {
+        let config: GuardrailsConfig = serde_yaml::from_str(
+            r#"
+guardrails:
+  - name: pii
+    guardrail: test
+    mode: mask
+    pre_call: false
+  - name: moderation
+    guardrail: test
+default: [moderation]
+model_groups:
+  gpt-4o: [pii]
+"#,
+        )
+        .unwrap();
+        assert!(config.is_enabled());
+        assert_eq!(config.guardrails[0].mode, GuardrailMode::Mask);
+        assert!(!config.guardrails[0].pre_call);
+        assert!(config.guardrails[0].post_call);
+        assert_eq!(config.guardrails[1].mode, GuardrailMode::Block);
+        assert!(config.validate(|guardrail| guardrail == "test").is_ok());
+        assert!(config.validate(|_| false).is_err());
+    }
+
+    #[test]
+    fn test_guardrails_config_validate_references() {
+        let config = GuardrailsConfig {
+            guardrails: vec![GuardrailConfig::new("pii", "test")],
+            api_keys: HashMap::from([("key-1".to_string(), vec!["missing".to_string()])]),
+            ..Default::default()
+        };
+        let err = config.validate(|_| true).unwrap_err();
+        assert!(err.contains("unknown guardrail: missing"), "{}", err);
+
+        let config = GuardrailsConfig {
+            guardrails: vec![
+                GuardrailConfig::new("pii", "test"),
+                GuardrailConfig::new("pii", "test"),
+            ],
+            ..Default::default()
+        };
+        assert!(config.validate(|_| true).unwrap_err().contains("Duplicate"));
+    }
+}
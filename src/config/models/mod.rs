@@ -6,26 +6,36 @@
 
 pub mod auth;
 pub mod cache;
+pub mod callbacks;
 pub mod enterprise;
 pub mod file_storage;
 pub mod gateway;
+pub mod guardrails;
+pub mod model_list;
 pub mod monitoring;
+pub mod pass_through;
 pub mod provider;
 pub mod rate_limit;
 pub mod router;
+pub mod secrets;
 pub mod server;
 pub mod storage;
 
 // Re-export all configuration types
 pub use auth::*;
 pub use cache::*;
+pub use callbacks::*;
 pub use enterprise::*;
 pub use file_storage::*;
 pub use gateway::*;
+pub use guardrails::*;
+pub use model_list::*;
 pub use monitoring::*;
+pub use pass_through::*;
 pub use provider::*;
 pub use rate_limit::*;
 pub use router::*;
+pub use secrets::*;
 pub use server::*;
 pub use storage::*;
 
@@ -49,6 +59,16 @@ pub fn default_max_body_size() -> usize {
     10 * 1024 * 1024 // 10MB
 }
 
+/// Default maximum concurrent realtime sessions per API key
+pub fn default_realtime_max_sessions_per_key() -> usize {
+    4
+}
+
+/// Default seconds between checks of the configuration file for changes
+pub fn default_config_watch_interval() -> u64 {
+    5
+}
+
 /// Default maximum retry attempts
 pub fn default_max_retries() -> u32 {
     3
@@ -79,6 +99,10 @@ pub fn default_similarity_threshold() -> f64 {
     0.95
 }
 
+pub fn default_cache_embedding_model() -> String {
+    "text-embedding-ada-002".to_string()
+}
+
 pub fn default_health_check_interval() -> u64 {
     30
 }
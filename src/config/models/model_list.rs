@@ -0,0 +1,236 @@
+//! Python LiteLLM proxy `model_list` configuration
+//!
+//! Lets a LiteLLM proxy config be used unchanged:
+//!
+//! ```yaml
+//! model_list:
+//!   - model_name: gpt-4
+//!     litellm_params:
+//!       model: azure/gpt-4-eu
+//!       api_base: https://eu.openai.azure.com
+//!       api_key: os.environ/AZURE_API_KEY
+//!       rpm: 600
+//!     model_info:
+//!       id: azure-eu
+//! ```
+//!
+//! Each entry becomes a provider serving its model under `model_name`.
+
+use super::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Deployment in a LiteLLM proxy `model_list`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ModelListEntry {
+    /// Model name requests use, shared by deployments balanced together
+    pub model_name: String,
+    /// Provider model and connection parameters
+    pub litellm_params: LiteLLMParams,
+    /// Metadata about the deployment
+    #[serde(default)]
+    pub model_info: ModelListInfo,
+}
+
+/// `litellm_params` of a `model_list` entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LiteLLMParams {
+    /// Provider and model, e.g. `azure/gpt-4-eu`; OpenAI without a provider
+    pub model: String,
+    /// API key
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Base URL
+    #[serde(default)]
+    pub api_base: Option<String>,
+    /// API version
+    #[serde(default)]
+    pub api_version: Option<String>,
+    /// Organization ID
+    #[serde(default)]
+    pub organization: Option<String>,
+    /// Maximum requests per minute
+    #[serde(default)]
+    pub rpm: Option<u32>,
+    /// Maximum tokens per minute
+    #[serde(default)]
+    pub tpm: Option<u32>,
+    /// Maximum concurrent requests
+    #[serde(default)]
+    pub max_parallel_requests: Option<u32>,
+    /// Request timeout in seconds
+    #[serde(default)]
+    pub timeout: Option<f64>,
+    /// Maximum retries
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    /// Weight for load balancing
+    #[serde(default)]
+    pub weight: Option<f32>,
+    /// Tags for tag-based routing
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Provider-specific parameters, e.g. `vertex_project` or `aws_region_name`
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// `model_info` of a `model_list` entry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ModelListInfo {
+    /// Deployment ID, used as the provider name
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Other metadata, e.g. `mode` or `base_model`
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl ModelListEntry {
+    /// Provider type and model of `litellm_params.model`
+    pub fn provider_and_model(&self) -> (&str, &str) {
+        self.litellm_params
+            .model
+            .split_once('/')
+            .unwrap_or(("openai", &self.litellm_params.model))
+    }
+
+    /// Provider serving the entry's deployment
+    pub fn to_provider_config(&self, name: String) -> ProviderConfig {
+        let params = &self.litellm_params;
+        let (provider_type, model) = self.provider_and_model();
+
+        // Providers read their specific settings under LiteLLM's names
+        let mut settings = params.extra.clone();
+        let optional = [
+            ("api_base", &params.api_base),
+            ("api_version", &params.api_version),
+            ("organization", &params.organization),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                settings.insert(key.to_string(), serde_json::Value::String(value.clone()));
+            }
+        }
+
+        let defaults = ProviderConfig::default();
+        ProviderConfig {
+            name,
+            provider_type: provider_type.to_string(),
+            api_key: params.api_key.clone().unwrap_or_default(),
+            base_url: params.api_base.clone(),
+            api_version: params.api_version.clone(),
+            organization: params.organization.clone(),
+            weight: params.weight.unwrap_or(defaults.weight),
+            rpm: params.rpm.unwrap_or(defaults.rpm),
+            tpm: params.tpm.unwrap_or(defaults.tpm),
+            max_concurrent_requests: params
+                .max_parallel_requests
+                .unwrap_or(defaults.max_concurrent_requests),
+            timeout: params
+                .timeout
+                .map_or(defaults.timeout, |timeout| timeout.ceil() as u64),
+            max_retries: params.max_retries.unwrap_or(defaults.max_retries),
+            settings,
+            models: vec![model.to_string()],
+            model_name: Some(self.model_name.clone()),
+            tags: params.tags.clone(),
+            ..defaults
+        }
+    }
+}
+
+/// Providers serving a `model_list`'s deployments
+///
+/// Providers are named after the entry's `model_info.id`, or else its
+/// `model_name`, with a `-2`, `-3`, etc. suffix when the name is taken by
+/// one of `existing` or an earlier entry.
+pub fn model_list_providers(
+    entries: &[ModelListEntry],
+    existing: &[ProviderConfig],
+) -> Vec<ProviderConfig> {
+    let mut names: HashSet<String> = existing.iter().map(|p| p.name.clone()).collect();
+    entries
+        .iter()
+        .map(|entry| {
+            let base = entry.model_info.id.as_ref().unwrap_or(&entry.model_name);
+            let name = std::iter::once(base.clone())
+                .chain((2..).map(|n| format!("{}-{}", base, n)))
+                .find(|name| !names.contains(name))
+                .unwrap_or_else(|| base.clone());
+            names.insert(name.clone());
+            entry.to_provider_config(name)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODEL_LIST: &str = r#"
+- model_name: gpt-4
+  litellm_params:
+    model: azure/gpt-4-eu
+    api_base: https://eu.openai.azure.com
+    api_key: azure-key
+    api_version: "2024-06-01"
+    rpm: 600
+    timeout: 2.5
+  model_info:
+    id: azure-eu
+    mode: chat
+- model_name: gpt-4
+  litellm_params:
+    model: gpt-4
+    api_key: sk-openai
+    tags: [fallback]
+- model_name: claude
+  litellm_params:
+    model: anthropic/claude-3-5-sonnet-20241022
+    api_key: sk-ant
+- model_name: claude
+  litellm_params:
+    model: bedrock/anthropic.claude-3-5-sonnet-20241022-v2:0
+    aws_region_name: us-east-1
+"#;
+
+    #[test]
+    fn test_model_list_providers() {
+        let entries: Vec<ModelListEntry> = serde_yaml::from_str(MODEL_LIST).unwrap();
+        assert_eq!(entries[0].model_info.extra["mode"], "chat");
+
+        let existing = [ProviderConfig {
+            name: "claude".to_string(),
+            ..ProviderConfig::default()
+        }];
+        let providers = model_list_providers(&entries, &existing);
+        let names: Vec<_> = providers.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["azure-eu", "gpt-4", "claude-2", "claude-3"]);
+
+        let azure = &providers[0];
+        assert_eq!(azure.provider_type, "azure");
+        assert_eq!(azure.models, ["gpt-4-eu"]);
+        assert_eq!(azure.model_name.as_deref(), Some("gpt-4"));
+        assert_eq!(azure.api_key, "azure-key");
+        assert_eq!(azure.rpm, 600);
+        assert_eq!(azure.timeout, 3);
+        assert_eq!(azure.settings["api_base"], "https://eu.openai.azure.com");
+        assert_eq!(azure.settings["api_version"], "2024-06-01");
+
+        let openai = &providers[1];
+        assert_eq!(openai.provider_type, "openai");
+        assert_eq!(openai.models, ["gpt-4"]);
+        assert_eq!(openai.tags, ["fallback"]);
+        assert_eq!(openai.rpm, ProviderConfig::default().rpm);
+
+        let bedrock = &providers[3];
+        assert_eq!(bedrock.provider_type, "bedrock");
+        assert_eq!(
+            bedrock.models,
+            ["anthropic.claude-3-5-sonnet-20241022-v2:0"]
+        );
+        assert_eq!(bedrock.settings["aws_region_name"], "us-east-1");
+    }
+}
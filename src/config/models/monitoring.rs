@@ -1,10 +1,12 @@
 //! Monitoring configuration
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Monitoring configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct MonitoringConfig {
     /// Metrics configuration
     #[serde(default)]
@@ -15,6 +17,9 @@ pub struct MonitoringConfig {
     /// Health check configuration
     #[serde(default)]
     pub health: HealthConfig,
+    /// Alerting configuration
+    #[serde(default)]
+    pub alerting: AlertingConfig,
 }
 
 #[allow(dead_code)]
@@ -24,12 +29,15 @@ impl MonitoringConfig {
         self.metrics = self.metrics.merge(other.metrics);
         self.tracing = self.tracing.merge(other.tracing);
         self.health = self.health.merge(other.health);
+        if other.alerting.enabled {
+            self.alerting = other.alerting;
+        }
         self
     }
 }
 
 /// Metrics configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MetricsConfig {
     /// Enable metrics
     #[serde(default = "default_true")]
@@ -70,16 +78,19 @@ impl MetricsConfig {
 }
 
 /// Tracing configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TracingConfig {
     /// Enable tracing
     #[serde(default)]
     pub enabled: bool,
-    /// Tracing endpoint
+    /// OTLP/HTTP collector endpoint, e.g. "http://localhost:4318"
     pub endpoint: Option<String>,
     /// Service name
     #[serde(default = "default_service_name")]
     pub service_name: String,
+    /// Headers sent with each export, e.g. collector API keys
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
 }
 
 impl Default for TracingConfig {
@@ -88,6 +99,7 @@ impl Default for TracingConfig {
             enabled: false,
             endpoint: None,
             service_name: default_service_name(),
+            headers: HashMap::new(),
         }
     }
 }
@@ -105,12 +117,13 @@ impl TracingConfig {
         if other.service_name != default_service_name() {
             self.service_name = other.service_name;
         }
+        self.headers.extend(other.headers);
         self
     }
 }
 
 /// Health check configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HealthConfig {
     /// Health check path
     #[serde(default = "default_health_path")]
@@ -242,6 +255,7 @@ mod tests {
             enabled: true,
             endpoint: Some("http://jaeger:14268".to_string()),
             service_name: "my-gateway".to_string(),
+            headers: HashMap::new(),
         };
         assert!(config.enabled);
         assert_eq!(config.endpoint, Some("http://jaeger:14268".to_string()));
@@ -253,6 +267,7 @@ mod tests {
             enabled: true,
             endpoint: Some("http://otel:4317".to_string()),
             service_name: "api-gateway".to_string(),
+            headers: HashMap::new(),
         };
         let json = serde_json::to_value(&config).unwrap();
         assert_eq!(json["enabled"], true);
@@ -274,6 +289,7 @@ mod tests {
             enabled: true,
             endpoint: None,
             service_name: "litellm-gateway".to_string(),
+            headers: HashMap::new(),
         };
         let merged = base.merge(other);
         assert!(merged.enabled);
@@ -286,6 +302,7 @@ mod tests {
             enabled: false,
             endpoint: Some("http://collector:4317".to_string()),
             service_name: "litellm-gateway".to_string(),
+            headers: HashMap::new(),
         };
         let merged = base.merge(other);
         assert_eq!(merged.endpoint, Some("http://collector:4317".to_string()));
@@ -383,6 +400,7 @@ mod tests {
             metrics: MetricsConfig::default(),
             tracing: TracingConfig::default(),
             health: HealthConfig::default(),
+            alerting: AlertingConfig::default(),
         };
         assert_eq!(config.metrics.port, 9090);
     }
@@ -407,9 +425,11 @@ mod tests {
             },
             tracing: TracingConfig::default(),
             health: HealthConfig::default(),
+            alerting: AlertingConfig::default(),
         };
         let merged = base.merge(other);
         assert!(!merged.metrics.enabled);
+        assert!(!merged.alerting.enabled);
     }
 
     #[test]
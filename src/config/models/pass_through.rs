@@ -0,0 +1,112 @@
+//! Provider pass-through configuration
+
+use crate::server::routes::RESERVED_PREFIXES;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Pass-through endpoint forwarding provider-native API calls
+///
+/// Requests under `path` are sent to the provider's own API with the
+/// credentials of a configured provider, e.g. `/gemini/v1beta/models` to
+/// `https://generativelanguage.googleapis.com/v1beta/models`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PassThroughEndpoint {
+    /// Gateway route prefix, e.g. "/gemini"
+    pub path: String,
+    /// Name of the configured provider whose credentials are used
+    pub provider: String,
+    /// Upstream base URL (defaults to the provider's base URL or public API)
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Extra headers sent upstream
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Whether callers must authenticate with the gateway
+    #[serde(default = "default_true")]
+    pub auth: bool,
+}
+
+impl PassThroughEndpoint {
+    /// Validate the endpoint
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.path.starts_with('/') || self.path.len() < 2 {
+            return Err(format!(
+                "Pass-through path must start with '/' and name a prefix: {}",
+                self.path
+            ));
+        }
+        let path = self.path.trim_end_matches('/');
+        let nested =
+            |path: &str, prefix: &str| path == prefix || path.starts_with(&format!("{}/", prefix));
+        if RESERVED_PREFIXES
+            .iter()
+            .any(|reserved| nested(path, reserved) || nested(reserved, path))
+        {
+            return Err(format!(
+                "Pass-through path conflicts with a gateway route: {}",
+                self.path
+            ));
+        }
+        if self.provider.is_empty() {
+            return Err(format!(
+                "Pass-through endpoint {} must name a provider",
+                self.path
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pass_through_endpoint_deserialization() {
+        let endpoint: PassThroughEndpoint = serde_yaml::from_str(
+            "path: /gemini\nprovider: gemini\nheaders:\n  x-goog-user-project: my-project\n",
+        )
+        .unwrap();
+        assert_eq!(endpoint.path, "/gemini");
+        assert!(endpoint.auth);
+        assert!(endpoint.target.is_none());
+        assert!(endpoint.validate().is_ok());
+    }
+
+    #[test]
+    fn test_pass_through_endpoint_validation() {
+        let mut endpoint = PassThroughEndpoint {
+            path: "/v1/cohere".to_string(),
+            provider: "cohere".to_string(),
+            target: None,
+            headers: HashMap::new(),
+            auth: true,
+        };
+        assert!(endpoint.validate().is_err());
+
+        endpoint.path = "cohere".to_string();
+        assert!(endpoint.validate().is_err());
+
+        // Every gateway scope is reserved, as are prefixes of one
+        for path in [
+            "/admin/cohere",
+            "/spend",
+            "/metrics",
+            "/api",
+            "/api/v1/pricing/x",
+        ] {
+            endpoint.path = path.to_string();
+            assert!(endpoint.validate().is_err(), "{} is reserved", path);
+        }
+
+        endpoint.path = "/cohere".to_string();
+        assert!(endpoint.validate().is_ok());
+        endpoint.path = "/api-cohere".to_string();
+        assert!(endpoint.validate().is_ok());
+    }
+}
@@ -1,11 +1,12 @@
 //! Provider configuration
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Provider configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProviderConfig {
     /// Provider name
     pub name: String,
@@ -13,6 +14,9 @@ pub struct ProviderConfig {
     pub provider_type: String,
     /// API key
     pub api_key: String,
+    /// More API keys for the same deployments, each with its own rate limits
+    #[serde(default)]
+    pub api_keys: Vec<String>,
     /// Base URL
     pub base_url: Option<String>,
     /// API version
@@ -51,9 +55,28 @@ pub struct ProviderConfig {
     /// Supported models
     #[serde(default)]
     pub models: Vec<String>,
+    /// Model name requests use for the provider's models, if not their own
+    /// names, e.g. `gpt-4` for an Azure deployment named `gpt-4-eu`
+    #[serde(default)]
+    pub model_name: Option<String>,
     /// Tags for grouping providers
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Request parameters set on requests to the provider's deployments
+    /// that do not set them, e.g. `temperature: 0`
+    #[serde(default)]
+    pub default_params: HashMap<String, serde_json::Value>,
+    /// Request parameters forced on requests to the provider's deployments,
+    /// e.g. `max_tokens: 1024` to cap them
+    #[serde(default)]
+    pub forced_params: HashMap<String, serde_json::Value>,
+    /// Whether to drop OpenAI parameters the provider does not support;
+    /// unset uses the router's setting
+    #[serde(default)]
+    pub drop_params: Option<bool>,
+    /// Parameters always dropped from requests to the provider
+    #[serde(default)]
+    pub additional_drop_params: Vec<String>,
     /// Whether provider is enabled
     #[serde(default = "default_true")]
     pub enabled: bool,
@@ -65,6 +88,7 @@ impl Default for ProviderConfig {
             name: String::new(),
             provider_type: String::new(),
             api_key: String::new(),
+            api_keys: Vec::new(),
             base_url: None,
             api_version: None,
             organization: None,
@@ -79,14 +103,19 @@ impl Default for ProviderConfig {
             health_check: HealthCheckConfig::default(),
             settings: HashMap::new(),
             models: Vec::new(),
+            model_name: None,
             tags: Vec::new(),
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
             enabled: true,
         }
     }
 }
 
 /// Retry configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RetryConfig {
     /// Base delay in milliseconds
     #[serde(default = "default_base_delay")]
@@ -114,7 +143,7 @@ impl Default for RetryConfig {
 }
 
 /// Health check configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HealthCheckConfig {
     /// Health check interval in seconds
     #[serde(default = "default_health_check_interval")]
@@ -144,6 +173,17 @@ impl Default for HealthCheckConfig {
     }
 }
 
+impl ProviderConfig {
+    /// API keys of the provider: `api_key`, if set, then `api_keys`
+    pub fn keys(&self) -> Vec<&str> {
+        std::iter::once(&self.api_key)
+            .chain(&self.api_keys)
+            .filter(|key| !key.is_empty())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
 fn default_true() -> bool {
     true
 }
@@ -279,6 +319,7 @@ mod tests {
             name: "openai-main".to_string(),
             provider_type: "openai".to_string(),
             api_key: "sk-xxx".to_string(),
+            api_keys: Vec::new(),
             base_url: Some("https://api.openai.com/v1".to_string()),
             api_version: Some("2024-01".to_string()),
             organization: Some("org-123".to_string()),
@@ -293,7 +334,12 @@ mod tests {
             health_check: HealthCheckConfig::default(),
             settings: HashMap::new(),
             models: vec!["gpt-4".to_string()],
+            model_name: None,
             tags: vec!["production".to_string()],
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: vec![],
             enabled: true,
         };
         assert_eq!(config.name, "openai-main");
@@ -311,6 +357,7 @@ mod tests {
             name: "custom".to_string(),
             provider_type: "custom".to_string(),
             api_key: "key".to_string(),
+            api_keys: Vec::new(),
             base_url: None,
             api_version: None,
             organization: None,
@@ -325,7 +372,12 @@ mod tests {
             health_check: HealthCheckConfig::default(),
             settings,
             models: vec![],
+            model_name: None,
             tags: vec![],
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: vec![],
             enabled: true,
         };
         assert_eq!(config.settings.len(), 2);
@@ -337,6 +389,7 @@ mod tests {
             name: "test-provider".to_string(),
             provider_type: "anthropic".to_string(),
             api_key: "sk-ant-xxx".to_string(),
+            api_keys: Vec::new(),
             base_url: None,
             api_version: None,
             organization: None,
@@ -351,7 +404,12 @@ mod tests {
             health_check: HealthCheckConfig::default(),
             settings: HashMap::new(),
             models: vec!["claude-3".to_string()],
+            model_name: None,
             tags: vec!["backup".to_string()],
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: vec![],
             enabled: true,
         };
         let json = serde_json::to_value(&config).unwrap();
@@ -399,6 +457,22 @@ mod tests {
         assert!(config.tags.contains(&"primary".to_string()));
     }
 
+    #[test]
+    fn test_provider_config_keys() {
+        let config = ProviderConfig {
+            api_key: "key-1".to_string(),
+            api_keys: vec!["key-2".to_string(), "key-3".to_string()],
+            ..ProviderConfig::default()
+        };
+        assert_eq!(config.keys(), vec!["key-1", "key-2", "key-3"]);
+
+        let config = ProviderConfig {
+            api_keys: vec!["key-2".to_string()],
+            ..ProviderConfig::default()
+        };
+        assert_eq!(config.keys(), vec!["key-2"]);
+    }
+
     #[test]
     fn test_provider_config_with_models() {
         let config = ProviderConfig {
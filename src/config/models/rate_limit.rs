@@ -1,23 +1,27 @@
 //! Rate limiting configuration
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Rate limiting configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RateLimitConfig {
     /// Enable rate limiting
     #[serde(default)]
     pub enabled: bool,
-    /// Default requests per minute
+    /// Default requests per minute of each API key
     #[serde(default = "default_rpm")]
     pub default_rpm: u32,
-    /// Default tokens per minute
+    /// Default tokens per minute of each API key
     #[serde(default = "default_tpm")]
     pub default_tpm: u32,
     /// Rate limiting strategy
     #[serde(default)]
     pub strategy: RateLimitStrategy,
+    /// Limits of individual API keys and models
+    #[serde(default)]
+    pub limits: Vec<RateLimitRule>,
 }
 
 impl Default for RateLimitConfig {
@@ -27,6 +31,7 @@ impl Default for RateLimitConfig {
             default_rpm: default_rpm(),
             default_tpm: default_tpm(),
             strategy: RateLimitStrategy::default(),
+            limits: Vec::new(),
         }
     }
 }
@@ -45,12 +50,35 @@ impl RateLimitConfig {
             self.default_tpm = other.default_tpm;
         }
         self.strategy = other.strategy;
+        if !other.limits.is_empty() {
+            self.limits = other.limits;
+        }
         self
     }
 }
 
+/// Requests and tokens per minute allowed for one API key or model
+///
+/// Each rule sets one of `api_key` or `model`. Key rules replace the default
+/// limits for that key; model rules are shared by all keys calling the model.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RateLimitRule {
+    /// API key the limits apply to
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Model the limits apply to
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Requests per minute
+    #[serde(default)]
+    pub rpm: Option<u32>,
+    /// Tokens per minute
+    #[serde(default)]
+    pub tpm: Option<u32>,
+}
+
 /// Rate limiting strategy
-#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RateLimitStrategy {
     /// Token bucket algorithm
@@ -141,6 +169,7 @@ mod tests {
             default_rpm: 500,
             default_tpm: 50_000,
             strategy: RateLimitStrategy::SlidingWindow,
+            ..Default::default()
         };
         assert!(config.enabled);
         assert_eq!(config.default_rpm, 500);
@@ -157,6 +186,7 @@ mod tests {
             default_rpm: 600,
             default_tpm: 60_000,
             strategy: RateLimitStrategy::FixedWindow,
+            ..Default::default()
         };
         let json = serde_json::to_value(&config).unwrap();
         assert_eq!(json["enabled"], true);
@@ -199,6 +229,7 @@ mod tests {
             default_rpm: 1000,
             default_tpm: 100_000,
             strategy: RateLimitStrategy::TokenBucket,
+            ..Default::default()
         };
         let merged = base.merge(other);
         assert!(merged.enabled);
@@ -212,6 +243,7 @@ mod tests {
             default_rpm: 500,
             default_tpm: 100_000,
             strategy: RateLimitStrategy::TokenBucket,
+            ..Default::default()
         };
         let merged = base.merge(other);
         assert_eq!(merged.default_rpm, 500);
@@ -225,6 +257,7 @@ mod tests {
             default_rpm: 1000,
             default_tpm: 50_000,
             strategy: RateLimitStrategy::TokenBucket,
+            ..Default::default()
         };
         let merged = base.merge(other);
         assert_eq!(merged.default_tpm, 50_000);
@@ -238,6 +271,7 @@ mod tests {
             default_rpm: 1000,
             default_tpm: 100_000,
             strategy: RateLimitStrategy::SlidingWindow,
+            ..Default::default()
         };
         let merged = base.merge(other);
         assert_eq!(merged.strategy, RateLimitStrategy::SlidingWindow);
@@ -262,6 +296,7 @@ mod tests {
             default_rpm: 750,
             default_tpm: 75_000,
             strategy: RateLimitStrategy::FixedWindow,
+            ..Default::default()
         };
         let cloned = config.clone();
         assert_eq!(config.enabled, cloned.enabled);
@@ -269,4 +304,37 @@ mod tests {
         assert_eq!(config.default_tpm, cloned.default_tpm);
         assert_eq!(config.strategy, cloned.strategy);
     }
+
+    // ==================== RateLimitRule Tests ====================
+
+    #[test]
+    fn test_rate_limit_rules_deserialization() {
+        let json = r#"{
+            "enabled": true,
+            "limits": [
+                { "api_key": "sk-team-a", "rpm": 60 },
+                { "model": "gpt-4o", "rpm": 500, "tpm": 300000 }
+            ]
+        }"#;
+        let config: RateLimitConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.limits.len(), 2);
+        assert_eq!(config.limits[0].api_key.as_deref(), Some("sk-team-a"));
+        assert_eq!(config.limits[0].tpm, None);
+        assert_eq!(config.limits[1].model.as_deref(), Some("gpt-4o"));
+        assert_eq!(config.limits[1].tpm, Some(300_000));
+    }
+
+    #[test]
+    fn test_rate_limit_config_merge_limits() {
+        let base = RateLimitConfig {
+            limits: vec![RateLimitRule {
+                model: Some("gpt-4o".to_string()),
+                rpm: Some(10),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let merged = base.clone().merge(RateLimitConfig::default());
+        assert_eq!(merged.limits, base.limits);
+    }
 }
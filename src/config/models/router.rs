@@ -1,10 +1,11 @@
 //! Router configuration
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Router configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct RouterConfig {
     /// Routing strategy
     #[serde(default)]
@@ -15,6 +16,51 @@ pub struct RouterConfig {
     /// Load balancer configuration
     #[serde(default)]
     pub load_balancer: LoadBalancerConfig,
+    /// Routing strategies of model groups by model name, by their LiteLLM
+    /// names, e.g. "gpt-4o" -> "lowest-latency"
+    #[serde(default)]
+    pub model_group_strategies: std::collections::HashMap<String, String>,
+    /// Model aliases, e.g. "gpt4" -> "gpt-4"
+    #[serde(default)]
+    pub model_aliases: std::collections::HashMap<String, String>,
+    /// Models each team may use, by team ID; unlisted teams may use any model
+    ///
+    /// Entries ending in `*` match by prefix, e.g. "openai/*".
+    #[serde(default)]
+    pub team_models: std::collections::HashMap<String, Vec<String>>,
+    /// Models to fall back on, in order, once every deployment of a model
+    /// failed or is rate limited
+    ///
+    /// Written as in LiteLLM, e.g. `[{gpt-4o: [claude-3-5-sonnet, gemini-1.5-pro]}]`.
+    #[serde(default)]
+    pub fallbacks: Vec<std::collections::HashMap<String, Vec<String>>>,
+    /// Models with larger context windows to fall back on, in order, when a
+    /// request does not fit in a model's context window
+    ///
+    /// Written like `fallbacks`, e.g. `[{gpt-4: [gpt-4-turbo]}]`; models
+    /// without any use `fallbacks`.
+    #[serde(default)]
+    pub context_window_fallbacks: Vec<std::collections::HashMap<String, Vec<String>>>,
+    /// Models serving a model group's traffic with their percentages, by
+    /// model name, for gradual rollouts
+    ///
+    /// Written e.g. `{gpt-4o: {gpt-4o: 95, ft:gpt-4o:acme: 5}}`; a user keeps
+    /// getting the same model.
+    #[serde(default)]
+    pub traffic_splits: std::collections::HashMap<String, std::collections::BTreeMap<String, u32>>,
+    /// Milliseconds after which a request still running is duplicated to a
+    /// second deployment, e.g. the p95 latency; 0 disables hedging
+    #[serde(default)]
+    pub hedge_after_ms: u64,
+    /// Drop OpenAI parameters a provider does not support rather than send
+    /// them, unless a provider or request says otherwise
+    #[serde(default)]
+    pub drop_params: bool,
+    /// Milliseconds from the start of a stream during which it is restarted
+    /// on another deployment if it fails; streams always fail over before
+    /// they emitted content
+    #[serde(default)]
+    pub stream_failover_grace_ms: u64,
 }
 
 #[allow(dead_code)]
@@ -24,12 +70,33 @@ impl RouterConfig {
         self.strategy = other.strategy;
         self.circuit_breaker = self.circuit_breaker.merge(other.circuit_breaker);
         self.load_balancer = self.load_balancer.merge(other.load_balancer);
+        self.model_group_strategies
+            .extend(other.model_group_strategies);
+        self.model_aliases.extend(other.model_aliases);
+        self.team_models.extend(other.team_models);
+        self.fallbacks.extend(other.fallbacks);
+        self.context_window_fallbacks
+            .extend(other.context_window_fallbacks);
+        self.traffic_splits.extend(other.traffic_splits);
+        self.hedge_after_ms = other.hedge_after_ms;
+        self.drop_params = other.drop_params;
+        self.stream_failover_grace_ms = other.stream_failover_grace_ms;
         self
     }
+
+    /// Whether a team may use a model
+    pub fn team_allows(&self, team_id: &str, model: &str) -> bool {
+        self.team_models.get(team_id).is_none_or(|patterns| {
+            patterns.iter().any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => model.starts_with(prefix),
+                None => pattern == model,
+            })
+        })
+    }
 }
 
 /// Routing strategy configuration
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RoutingStrategyConfig {
     /// Round-robin routing
@@ -64,7 +131,7 @@ pub enum RoutingStrategyConfig {
 }
 
 /// Circuit breaker configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CircuitBreakerConfig {
     /// Failure threshold
     #[serde(default = "default_failure_threshold")]
@@ -112,7 +179,7 @@ impl CircuitBreakerConfig {
 }
 
 /// Load balancer configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LoadBalancerConfig {
     /// Health check enabled
     #[serde(default = "default_true")]
@@ -409,6 +476,15 @@ mod tests {
             strategy: RoutingStrategyConfig::LeastLatency,
             circuit_breaker: CircuitBreakerConfig::default(),
             load_balancer: LoadBalancerConfig::default(),
+            model_group_strategies: Default::default(),
+            model_aliases: Default::default(),
+            team_models: Default::default(),
+            fallbacks: Default::default(),
+            context_window_fallbacks: Default::default(),
+            traffic_splits: Default::default(),
+            hedge_after_ms: 0,
+            drop_params: false,
+            stream_failover_grace_ms: 0,
         };
         matches!(config.strategy, RoutingStrategyConfig::LeastLatency);
     }
@@ -419,6 +495,15 @@ mod tests {
             strategy: RoutingStrategyConfig::LeastCost,
             circuit_breaker: CircuitBreakerConfig::default(),
             load_balancer: LoadBalancerConfig::default(),
+            model_group_strategies: Default::default(),
+            model_aliases: Default::default(),
+            team_models: Default::default(),
+            fallbacks: Default::default(),
+            context_window_fallbacks: Default::default(),
+            traffic_splits: Default::default(),
+            hedge_after_ms: 0,
+            drop_params: false,
+            stream_failover_grace_ms: 0,
         };
         let json = serde_json::to_value(&config).unwrap();
         assert_eq!(json["strategy"]["type"], "least_cost");
@@ -435,6 +520,52 @@ mod tests {
         matches!(config.strategy, RoutingStrategyConfig::Random);
     }
 
+    #[test]
+    fn test_router_config_fallbacks_deserialization() {
+        let yaml = r#"
+fallbacks:
+  - gpt-4o: [claude-3-5-sonnet, gemini-1.5-pro]
+context_window_fallbacks:
+  - gpt-4: [gpt-4-turbo]
+"#;
+        let config: RouterConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.fallbacks.len(), 1);
+        assert_eq!(config.context_window_fallbacks[0]["gpt-4"], vec!["gpt-4-turbo"]);
+        assert_eq!(
+            config.fallbacks[0]["gpt-4o"],
+            vec!["claude-3-5-sonnet".to_string(), "gemini-1.5-pro".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_router_config_model_group_strategies() {
+        let yaml = "model_group_strategies:\n  gpt-4o: lowest-latency\n";
+        let base: RouterConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(base.model_group_strategies["gpt-4o"], "lowest-latency");
+
+        let yaml = "model_group_strategies:\n  claude-3-5-sonnet: lowest-cost\n";
+        let other: RouterConfig = serde_yaml::from_str(yaml).unwrap();
+        let merged = base.merge(other);
+        assert_eq!(merged.model_group_strategies.len(), 2);
+        assert_eq!(
+            merged.model_group_strategies["claude-3-5-sonnet"],
+            "lowest-cost"
+        );
+    }
+
+    #[test]
+    fn test_router_config_traffic_splits_deserialization() {
+        let yaml = r#"
+traffic_splits:
+  gpt-4o:
+    gpt-4o: 95
+    ft:gpt-4o:acme: 5
+"#;
+        let config: RouterConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.traffic_splits["gpt-4o"]["gpt-4o"], 95);
+        assert_eq!(config.traffic_splits["gpt-4o"]["ft:gpt-4o:acme"], 5);
+    }
+
     #[test]
     fn test_router_config_merge() {
         let base = RouterConfig::default();
@@ -442,6 +573,15 @@ mod tests {
             strategy: RoutingStrategyConfig::LeastLatency,
             circuit_breaker: CircuitBreakerConfig::default(),
             load_balancer: LoadBalancerConfig::default(),
+            model_group_strategies: Default::default(),
+            model_aliases: Default::default(),
+            team_models: Default::default(),
+            fallbacks: Default::default(),
+            context_window_fallbacks: Default::default(),
+            traffic_splits: Default::default(),
+            hedge_after_ms: 0,
+            drop_params: false,
+            stream_failover_grace_ms: 0,
         };
         let merged = base.merge(other);
         matches!(merged.strategy, RoutingStrategyConfig::LeastLatency);
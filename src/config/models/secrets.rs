@@ -0,0 +1,105 @@
+//! Secret manager configuration
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Secret stores provider API keys may reference
+///
+/// Settings left unset fall back to the environment variables each store's
+/// own tooling reads.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SecretsConfig {
+    /// Seconds between refreshes of resolved secrets; 0 resolves them only
+    /// at startup
+    #[serde(default)]
+    pub refresh_interval: u64,
+    /// HashiCorp Vault (`vault://`)
+    #[serde(default)]
+    pub vault: VaultSecretsConfig,
+    /// AWS Secrets Manager (`aws-sm://`)
+    #[serde(default)]
+    pub aws: AwsSecretsConfig,
+    /// Google Cloud Secret Manager (`gcp-sm://`)
+    #[serde(default)]
+    pub gcp: GcpSecretsConfig,
+    /// Azure Key Vault (`azure-kv://`)
+    #[serde(default)]
+    pub azure: AzureSecretsConfig,
+}
+
+impl SecretsConfig {
+    /// Merge secret manager configurations
+    pub fn merge(self, other: Self) -> Self {
+        if other != Self::default() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+/// HashiCorp Vault settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct VaultSecretsConfig {
+    /// Vault address (`VAULT_ADDR`)
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Vault token (`VAULT_TOKEN`)
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Vault Enterprise namespace (`VAULT_NAMESPACE`)
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// AWS Secrets Manager settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AwsSecretsConfig {
+    /// Region (`AWS_REGION`, `AWS_DEFAULT_REGION`, then `us-east-1`)
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Access key ID (`AWS_ACCESS_KEY_ID`)
+    #[serde(default)]
+    pub access_key_id: Option<String>,
+    /// Secret access key (`AWS_SECRET_ACCESS_KEY`)
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+    /// Session token of temporary credentials (`AWS_SESSION_TOKEN`)
+    #[serde(default)]
+    pub session_token: Option<String>,
+    /// Endpoint replacing the regional one, e.g. a VPC endpoint
+    #[serde(default)]
+    pub endpoint: Option<String>,
+}
+
+/// Google Cloud Secret Manager settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GcpSecretsConfig {
+    /// Project of secrets named without one (`GOOGLE_CLOUD_PROJECT`)
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Credentials JSON file; application default credentials if unset
+    #[serde(default)]
+    pub credentials_file: Option<String>,
+    /// Endpoint replacing `https://secretmanager.googleapis.com`
+    #[serde(default)]
+    pub endpoint: Option<String>,
+}
+
+/// Azure Key Vault settings
+///
+/// A service principal is used when tenant, client ID and client secret are
+/// all known, and a managed identity otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AzureSecretsConfig {
+    /// Tenant of the service principal (`AZURE_TENANT_ID`)
+    #[serde(default)]
+    pub tenant_id: Option<String>,
+    /// Client ID of the service principal or user-assigned managed identity
+    /// (`AZURE_CLIENT_ID`)
+    #[serde(default)]
+    pub client_id: Option<String>,
+    /// Client secret of the service principal (`AZURE_CLIENT_SECRET`)
+    #[serde(default)]
+    pub client_secret: Option<String>,
+}
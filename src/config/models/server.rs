@@ -1,11 +1,12 @@
 //! Server configuration
 
 use super::*;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 /// Server configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ServerConfig {
     /// Server host
     #[serde(default = "default_host")]
@@ -29,6 +30,16 @@ pub struct ServerConfig {
     /// CORS configuration
     #[serde(default)]
     pub cors: CorsConfig,
+    /// Maximum concurrent `/v1/realtime` sessions per API key (0 disables the limit)
+    #[serde(default = "default_realtime_max_sessions_per_key")]
+    pub realtime_max_sessions_per_key: usize,
+    /// In-flight request limits and load shedding
+    #[serde(default)]
+    pub load_shedding: LoadSheddingConfig,
+    /// Seconds between checks of the configuration for changes (0
+    /// disables watching; SIGHUP and `/admin/config/reload` still reload)
+    #[serde(default = "default_config_watch_interval")]
+    pub config_watch_interval: u64,
 }
 
 impl Default for ServerConfig {
@@ -42,6 +53,9 @@ impl Default for ServerConfig {
             dev_mode: false,
             tls: None,
             cors: CorsConfig::default(),
+            realtime_max_sessions_per_key: default_realtime_max_sessions_per_key(),
+            load_shedding: LoadSheddingConfig::default(),
+            config_watch_interval: default_config_watch_interval(),
         }
     }
 }
@@ -72,6 +86,13 @@ impl ServerConfig {
             self.tls = other.tls;
         }
         self.cors = self.cors.merge(other.cors);
+        if other.realtime_max_sessions_per_key != default_realtime_max_sessions_per_key() {
+            self.realtime_max_sessions_per_key = other.realtime_max_sessions_per_key;
+        }
+        self.load_shedding = self.load_shedding.merge(other.load_shedding);
+        if other.config_watch_interval != default_config_watch_interval() {
+            self.config_watch_interval = other.config_watch_interval;
+        }
         self
     }
 
@@ -108,12 +129,14 @@ impl ServerConfig {
             tls.validate()?;
         }
 
+        self.load_shedding.validate()?;
+
         Ok(())
     }
 }
 
 /// TLS configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TlsConfig {
     /// Certificate file path
     pub cert_file: String,
@@ -161,7 +184,7 @@ impl TlsConfig {
 }
 
 /// CORS configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CorsConfig {
     /// Enable CORS
     #[serde(default = "default_true")]
@@ -241,6 +264,64 @@ impl CorsConfig {
     }
 }
 
+/// Gateway-wide limit on requests in flight
+///
+/// Requests over the limit wait in a queue for a slot; once the queue is full
+/// or a request has waited `queue_timeout_ms`, it is shed with a 503.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LoadSheddingConfig {
+    /// Maximum requests handled at once (0 disables the limit)
+    #[serde(default)]
+    pub max_in_flight: usize,
+    /// Maximum requests waiting for a slot
+    #[serde(default = "default_max_queued")]
+    pub max_queued: usize,
+    /// Milliseconds a request waits for a slot before it is shed
+    #[serde(default = "default_queue_timeout_ms")]
+    pub queue_timeout_ms: u64,
+}
+
+impl Default for LoadSheddingConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 0,
+            max_queued: default_max_queued(),
+            queue_timeout_ms: default_queue_timeout_ms(),
+        }
+    }
+}
+
+impl LoadSheddingConfig {
+    /// Merge load shedding configurations
+    pub fn merge(mut self, other: Self) -> Self {
+        if other.max_in_flight != 0 {
+            self.max_in_flight = other.max_in_flight;
+        }
+        if other.max_queued != default_max_queued() {
+            self.max_queued = other.max_queued;
+        }
+        if other.queue_timeout_ms != default_queue_timeout_ms() {
+            self.queue_timeout_ms = other.queue_timeout_ms;
+        }
+        self
+    }
+
+    /// Check if load shedding is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.max_in_flight > 0
+    }
+
+    /// Validate load shedding configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.is_enabled() && self.max_queued > 0 && self.queue_timeout_ms == 0 {
+            return Err(
+                "Load shedding queue timeout cannot be 0 when requests are queued".to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
 fn default_true() -> bool {
     true
 }
@@ -267,6 +348,14 @@ fn default_cors_max_age() -> u32 {
     3600
 }
 
+fn default_max_queued() -> usize {
+    100
+}
+
+fn default_queue_timeout_ms() -> u64 {
+    5000
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +385,9 @@ mod tests {
             dev_mode: true,
             tls: None,
             cors: CorsConfig::default(),
+            realtime_max_sessions_per_key: 2,
+            load_shedding: LoadSheddingConfig::default(),
+            config_watch_interval: default_config_watch_interval(),
         };
         assert_eq!(config.host, "127.0.0.1");
         assert_eq!(config.port, 3000);
@@ -727,4 +819,45 @@ mod tests {
         assert_eq!(config.enabled, cloned.enabled);
         assert_eq!(config.max_age, cloned.max_age);
     }
+
+    // ==================== LoadSheddingConfig Tests ====================
+
+    #[test]
+    fn test_load_shedding_config_default() {
+        let config = LoadSheddingConfig::default();
+        assert!(!config.is_enabled());
+        assert_eq!(config.max_queued, 100);
+        assert_eq!(config.queue_timeout_ms, 5000);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_load_shedding_config_validate_zero_timeout() {
+        let config = LoadSheddingConfig {
+            max_in_flight: 10,
+            queue_timeout_ms: 0,
+            ..LoadSheddingConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let without_queue = LoadSheddingConfig {
+            max_queued: 0,
+            ..config
+        };
+        assert!(without_queue.validate().is_ok());
+    }
+
+    #[test]
+    fn test_server_config_merge_load_shedding() {
+        let other = ServerConfig {
+            load_shedding: LoadSheddingConfig {
+                max_in_flight: 64,
+                ..LoadSheddingConfig::default()
+            },
+            ..ServerConfig::default()
+        };
+        let merged = ServerConfig::default().merge(other);
+        assert_eq!(merged.load_shedding.max_in_flight, 64);
+        assert_eq!(merged.load_shedding.max_queued, 100);
+    }
 }
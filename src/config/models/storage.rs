@@ -1,12 +1,13 @@
 //! Storage configuration
 
-use super::file_storage::VectorDbConfig;
+use super::file_storage::{FileStorageConfig, VectorDbConfig};
 use super::*;
 use super::{default_connection_timeout, default_redis_max_connections};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Storage configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct StorageConfig {
     /// Database configuration
     pub database: DatabaseConfig,
@@ -15,6 +16,21 @@ pub struct StorageConfig {
     /// Vector database configuration (optional)
     #[serde(default)]
     pub vector_db: Option<VectorDbConfig>,
+    /// Storage of uploaded files and logged payloads
+    #[serde(default)]
+    pub files: FileStorageConfig,
+    /// Request audit log configuration
+    #[serde(default)]
+    pub request_logs: RequestLogConfig,
+    /// Spend tracking configuration
+    #[serde(default)]
+    pub spend: SpendConfig,
+    /// Data retention job configuration
+    #[serde(default)]
+    pub retention: RetentionConfig,
+    /// Encryption of sensitive columns at rest
+    #[serde(default)]
+    pub encryption: EncryptionConfig,
 }
 
 #[allow(dead_code)]
@@ -26,12 +42,30 @@ impl StorageConfig {
         if other.vector_db.is_some() {
             self.vector_db = other.vector_db;
         }
+        let default_files = FileStorageConfig::default();
+        if other.files.storage_type != default_files.storage_type
+            || other.files.local_path != default_files.local_path
+        {
+            self.files = other.files;
+        }
+        if other.request_logs.enabled {
+            self.request_logs = other.request_logs;
+        }
+        if other.spend.enabled {
+            self.spend = other.spend;
+        }
+        if other.retention != RetentionConfig::default() {
+            self.retention = other.retention;
+        }
+        if other.encryption.enabled {
+            self.encryption = other.encryption;
+        }
         self
     }
 }
 
 /// Database configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DatabaseConfig {
     /// Database URL
     pub url: String,
@@ -63,6 +97,11 @@ impl Default for DatabaseConfig {
 
 #[allow(dead_code)]
 impl DatabaseConfig {
+    /// Whether the URL selects SQLite rather than PostgreSQL
+    pub fn is_sqlite(&self) -> bool {
+        self.url.starts_with("sqlite:")
+    }
+
     /// Merge database configurations
     pub fn merge(mut self, other: Self) -> Self {
         if !other.url.is_empty() && other.url != "postgresql://localhost/litellm" {
@@ -82,7 +121,7 @@ impl DatabaseConfig {
 }
 
 /// Redis configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RedisConfig {
     /// Redis URL
     pub url: String,
@@ -98,6 +137,18 @@ pub struct RedisConfig {
     /// Enable cluster mode
     #[serde(default)]
     pub cluster: bool,
+    /// Cluster seed nodes (the URL alone when empty)
+    #[serde(default)]
+    pub nodes: Vec<String>,
+    /// Connect to the master of a Sentinel-managed deployment
+    #[serde(default)]
+    pub sentinel: Option<RedisSentinelConfig>,
+    /// Route read-only commands to cluster replicas
+    #[serde(default)]
+    pub read_from_replicas: bool,
+    /// Seconds between reconnects picking up cluster or Sentinel topology changes (0 disables)
+    #[serde(default = "default_redis_topology_refresh_interval")]
+    pub topology_refresh_interval: u64,
 }
 
 impl Default for RedisConfig {
@@ -108,10 +159,25 @@ impl Default for RedisConfig {
             max_connections: default_redis_max_connections(),
             connection_timeout: default_connection_timeout(),
             cluster: false,
+            nodes: Vec::new(),
+            sentinel: None,
+            read_from_replicas: false,
+            topology_refresh_interval: default_redis_topology_refresh_interval(),
         }
     }
 }
 
+/// Redis Sentinel configuration
+///
+/// Credentials and database of the master are taken from the Redis URL.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RedisSentinelConfig {
+    /// Name of the monitored master
+    pub master_name: String,
+    /// Sentinel URLs
+    pub nodes: Vec<String>,
+}
+
 #[allow(dead_code)]
 impl RedisConfig {
     /// Merge Redis configurations
@@ -128,6 +194,18 @@ impl RedisConfig {
         if other.cluster {
             self.cluster = other.cluster;
         }
+        if !other.nodes.is_empty() {
+            self.nodes = other.nodes;
+        }
+        if other.sentinel.is_some() {
+            self.sentinel = other.sentinel;
+        }
+        if other.read_from_replicas {
+            self.read_from_replicas = other.read_from_replicas;
+        }
+        if other.topology_refresh_interval != default_redis_topology_refresh_interval() {
+            self.topology_refresh_interval = other.topology_refresh_interval;
+        }
         self
     }
 }
@@ -136,6 +214,249 @@ fn default_redis_enabled() -> bool {
     true
 }
 
+fn default_redis_topology_refresh_interval() -> u64 {
+    60
+}
+
+/// Request audit log configuration
+///
+/// When enabled, every API call is written to the `request_logs` table.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RequestLogConfig {
+    /// Enable request logging
+    #[serde(default)]
+    pub enabled: bool,
+    /// Store redacted request and response payloads
+    #[serde(default)]
+    pub log_payloads: bool,
+    /// Days log entries are kept; 0 keeps them forever
+    #[serde(default = "default_request_log_retention_days")]
+    pub retention_days: u32,
+    /// Maximum entries per database write
+    #[serde(default = "default_request_log_batch_size")]
+    pub batch_size: usize,
+}
+
+impl Default for RequestLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            log_payloads: false,
+            retention_days: default_request_log_retention_days(),
+            batch_size: default_request_log_batch_size(),
+        }
+    }
+}
+
+fn default_request_log_retention_days() -> u32 {
+    30
+}
+
+fn default_request_log_batch_size() -> usize {
+    100
+}
+
+/// Spend tracking configuration
+///
+/// When enabled, the cost of every model call is added to per-day totals by
+/// API key, team, user and model in the `daily_spend` table.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpendConfig {
+    /// Enable spend tracking
+    #[serde(default)]
+    pub enabled: bool,
+    /// Seconds spend is aggregated in memory before it is written
+    #[serde(default = "default_spend_flush_interval")]
+    pub flush_interval: u64,
+    /// Days daily spend rows are kept; 0 keeps them forever
+    #[serde(default)]
+    pub retention_days: u32,
+    /// Spend limits, enforced against tracked spend
+    #[serde(default)]
+    pub budgets: Vec<BudgetConfig>,
+}
+
+impl Default for SpendConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            flush_interval: default_spend_flush_interval(),
+            retention_days: 0,
+            budgets: Vec::new(),
+        }
+    }
+}
+
+fn default_spend_flush_interval() -> u64 {
+    10
+}
+
+/// Spend limit for an API key, team or provider
+///
+/// Exactly one of `api_key`, `team_id` and `provider` is set. Spend counts
+/// from the start of the current window (UTC midnight, Monday or the first of
+/// the month) and resets when the next window starts.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BudgetConfig {
+    /// API key the budget applies to
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Team the budget applies to
+    #[serde(default)]
+    pub team_id: Option<String>,
+    /// Provider the budget applies to
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Maximum spend per window in USD
+    pub max_budget: f64,
+    /// Window after which spend resets
+    #[serde(default)]
+    pub window: BudgetWindow,
+}
+
+/// Period a budget applies to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetWindow {
+    /// Resets at UTC midnight
+    #[default]
+    Day,
+    /// Resets on Monday
+    Week,
+    /// Resets on the first of the month
+    Month,
+}
+
+impl BudgetWindow {
+    /// First day of the window containing `date`
+    pub fn start(self, date: chrono::NaiveDate) -> chrono::NaiveDate {
+        use chrono::Datelike;
+        match self {
+            BudgetWindow::Day => date,
+            BudgetWindow::Week => date.week(chrono::Weekday::Mon).first_day(),
+            BudgetWindow::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the window after the one containing `date`
+    pub fn next_start(self, date: chrono::NaiveDate) -> chrono::NaiveDate {
+        let start = self.start(date);
+        match self {
+            BudgetWindow::Day => start + chrono::Days::new(1),
+            BudgetWindow::Week => start + chrono::Days::new(7),
+            BudgetWindow::Month => start + chrono::Months::new(1),
+        }
+    }
+
+    /// Most days a window spans
+    pub fn max_days(self) -> u32 {
+        match self {
+            BudgetWindow::Day => 1,
+            BudgetWindow::Week => 7,
+            BudgetWindow::Month => 31,
+        }
+    }
+}
+
+/// Data retention job configuration
+///
+/// A background job deletes request log entries and daily spend rows older
+/// than their `retention_days`, and purges expired cache entries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RetentionConfig {
+    /// Seconds between retention runs; 0 runs only on demand
+    #[serde(default = "default_retention_interval")]
+    pub interval: u64,
+    /// Maximum rows removed per delete statement
+    #[serde(default = "default_retention_batch_size")]
+    pub batch_size: u64,
+    /// Purge expired entries from in-memory and semantic caches
+    #[serde(default = "default_true")]
+    pub purge_expired_cache: bool,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            interval: default_retention_interval(),
+            batch_size: default_retention_batch_size(),
+            purge_expired_cache: true,
+        }
+    }
+}
+
+fn default_retention_interval() -> u64 {
+    3600
+}
+
+fn default_retention_batch_size() -> u64 {
+    1000
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Encryption of sensitive columns at rest
+///
+/// When enabled, provider API keys and logged request and response payloads
+/// are encrypted with AES-256-GCM before they are written to the database.
+/// Provider API keys are only stored while encryption is enabled.
+/// Each value records the ID of its key, so keys are rotated by adding a key,
+/// making it active and re-encrypting with `POST /admin/encryption/rotate`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EncryptionConfig {
+    /// Enable encryption
+    #[serde(default)]
+    pub enabled: bool,
+    /// ID of the key new values are encrypted with
+    #[serde(default)]
+    pub active_key: String,
+    /// Keys stored values may be encrypted with
+    #[serde(default)]
+    pub keys: Vec<EncryptionKeyConfig>,
+}
+
+/// Data encryption key
+///
+/// Exactly one of `key`, `key_file` and `kms` is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EncryptionKeyConfig {
+    /// Key ID, stored with every value encrypted with the key
+    pub id: String,
+    /// Key material, at least 32 bytes
+    #[serde(default)]
+    pub key: Option<String>,
+    /// File holding the key material
+    #[serde(default)]
+    pub key_file: Option<String>,
+    /// Key material encrypted by AWS KMS, decrypted at startup
+    #[serde(default)]
+    pub kms: Option<KmsKeyConfig>,
+}
+
+/// Data key encrypted by AWS KMS
+///
+/// The key is typically generated with `aws kms generate-data-key`, keeping
+/// its `CiphertextBlob`. Credentials come from the AWS credential chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct KmsKeyConfig {
+    /// Base64-encoded ciphertext blob of the data key
+    pub ciphertext: String,
+    /// KMS key ID, ARN or alias the data key was encrypted with
+    #[serde(default)]
+    pub key_id: Option<String>,
+    /// AWS region (default: `AWS_REGION`, then `us-east-1`)
+    #[serde(default)]
+    pub region: Option<String>,
+    /// AWS profile to read credentials from
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// KMS endpoint, for VPC endpoints or testing
+    #[serde(default)]
+    pub endpoint: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +566,7 @@ mod tests {
             max_connections: 200,
             connection_timeout: 60,
             cluster: true,
+            ..Default::default()
         };
         assert!(config.cluster);
         assert_eq!(config.max_connections, 200);
@@ -258,6 +580,7 @@ mod tests {
             max_connections: 50,
             connection_timeout: 15,
             cluster: false,
+            ..Default::default()
         };
         let json = serde_json::to_value(&config).unwrap();
         assert_eq!(json["url"], "redis://cache:6379");
@@ -281,6 +604,7 @@ mod tests {
             max_connections: 100,
             connection_timeout: 30,
             cluster: false,
+            ..Default::default()
         };
         let merged = base.merge(other);
         assert_eq!(merged.url, "redis://new-redis:6379");
@@ -295,9 +619,26 @@ mod tests {
             max_connections: 100,
             connection_timeout: 30,
             cluster: true,
+            nodes: vec!["redis://node1:6379".to_string()],
+            read_from_replicas: true,
+            ..Default::default()
         };
         let merged = base.merge(other);
         assert!(merged.cluster);
+        assert_eq!(merged.nodes, vec!["redis://node1:6379"]);
+        assert!(merged.read_from_replicas);
+        assert_eq!(merged.topology_refresh_interval, 60);
+    }
+
+    #[test]
+    fn test_redis_config_sentinel_deserialization() {
+        let yaml = "url: redis://:secret@localhost/1\nsentinel:\n  master_name: mymaster\n  nodes:\n    - redis://sentinel1:26379\n    - redis://sentinel2:26379\ntopology_refresh_interval: 0";
+        let config: RedisConfig = serde_yaml::from_str(yaml).unwrap();
+        let sentinel = config.sentinel.unwrap();
+        assert_eq!(sentinel.master_name, "mymaster");
+        assert_eq!(sentinel.nodes.len(), 2);
+        assert!(!config.cluster);
+        assert_eq!(config.topology_refresh_interval, 0);
     }
 
     #[test]
@@ -316,6 +657,40 @@ mod tests {
         assert_eq!(config.database.url, "postgresql://localhost/litellm");
         assert_eq!(config.redis.url, "redis://localhost:6379");
         assert!(config.vector_db.is_none());
+        assert!(!config.request_logs.enabled);
+        assert_eq!(config.request_logs.retention_days, 30);
+        assert!(!config.spend.enabled);
+        assert_eq!(config.spend.flush_interval, 10);
+        assert_eq!(config.spend.retention_days, 0);
+        assert!(config.spend.budgets.is_empty());
+        assert_eq!(config.retention.interval, 3600);
+        assert_eq!(config.retention.batch_size, 1000);
+        assert!(config.retention.purge_expired_cache);
+    }
+
+    #[test]
+    fn test_budget_window_bounds() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let ymd = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(BudgetWindow::Day.start(date), date);
+        assert_eq!(BudgetWindow::Day.next_start(date), ymd(2024, 3, 1));
+        assert_eq!(BudgetWindow::Week.start(date), ymd(2024, 2, 26));
+        assert_eq!(BudgetWindow::Week.next_start(date), ymd(2024, 3, 4));
+        assert_eq!(BudgetWindow::Month.start(date), ymd(2024, 2, 1));
+        assert_eq!(BudgetWindow::Month.next_start(date), ymd(2024, 3, 1));
+    }
+
+    #[test]
+    fn test_budget_config_deserialize() {
+        let config: BudgetConfig =
+            serde_yaml::from_str("api_key: gw-1234567890abcdef\nmax_budget: 10.0\nwindow: month")
+                .unwrap();
+        assert_eq!(config.api_key.as_deref(), Some("gw-1234567890abcdef"));
+        assert_eq!(config.window, BudgetWindow::Month);
+
+        let config: BudgetConfig = serde_yaml::from_str("provider: openai\nmax_budget: 5").unwrap();
+        assert_eq!(config.window, BudgetWindow::Day);
     }
 
     #[test]
@@ -324,6 +699,11 @@ mod tests {
             database: DatabaseConfig::default(),
             redis: RedisConfig::default(),
             vector_db: None,
+            files: FileStorageConfig::default(),
+            request_logs: RequestLogConfig::default(),
+            spend: SpendConfig::default(),
+            retention: RetentionConfig::default(),
+            encryption: EncryptionConfig::default(),
         };
         assert!(config.vector_db.is_none());
     }
@@ -349,6 +729,11 @@ mod tests {
             },
             redis: RedisConfig::default(),
             vector_db: None,
+            files: FileStorageConfig::default(),
+            request_logs: RequestLogConfig::default(),
+            spend: SpendConfig::default(),
+            retention: RetentionConfig::default(),
+            encryption: EncryptionConfig::default(),
         };
         let merged = base.merge(other);
         assert_eq!(merged.database.url, "postgresql://new/db");
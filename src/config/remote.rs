@@ -0,0 +1,240 @@
+//! Remote configuration
+//!
+//! Configuration may be loaded from an `https://` URL or an
+//! `s3://<bucket>/<key>` object instead of a file, so a fleet of gateways can
+//! share one centrally managed configuration:
+//!
+//! ```no_run
+//! # async fn run() -> litellm_rs::Result<()> {
+//! use litellm_rs::{Config, Gateway};
+//!
+//! let config = Config::load("s3://gateway-config/prod/gateway.yaml").await?;
+//! Gateway::new(config).await?.run().await
+//! # }
+//! ```
+//!
+//! Remote configurations are polled with their ETag, so an unchanged
+//! configuration is not downloaded again, and reloaded when it changes.
+//!
+//! S3 objects are read in `AWS_REGION` with credentials from the same chain
+//! as Bedrock: the environment, shared profile, web identity, container
+//! endpoint or instance metadata. Without any, they are read anonymously.
+//! `AWS_ENDPOINT_URL_S3` or `AWS_ENDPOINT_URL` points at an S3-compatible
+//! store, addressed path-style.
+
+use super::source::ConfigLocation;
+use crate::core::providers::bedrock::{AwsCredentialChain, SigV4Signer, uri_encode};
+use crate::utils::error::{GatewayError, Result};
+use crate::utils::net::http::get_shared_client;
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tracing::debug;
+
+/// SHA-256 of an empty request body
+const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// A fetched remote configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetched {
+    /// Configuration content
+    pub content: String,
+    /// ETag of the content, if the server sent one
+    pub etag: Option<String>,
+}
+
+/// Fetch a remote configuration
+///
+/// Returns `None` when the configuration's ETag is still `etag`.
+pub async fn fetch(location: &ConfigLocation, etag: Option<&str>) -> Result<Option<Fetched>> {
+    let request = match location {
+        ConfigLocation::File(path) => {
+            return Err(GatewayError::Config(format!(
+                "{} is not a remote configuration",
+                path.display()
+            )));
+        }
+        ConfigLocation::Url(url) => get_shared_client().get(url.clone()),
+        ConfigLocation::S3 { bucket, key } => S3Access::resolve().await.request(bucket, key)?,
+    };
+    send(location, request, etag).await
+}
+
+/// Send a request for a remote configuration, conditional on its ETag
+async fn send(
+    location: &ConfigLocation,
+    request: reqwest::RequestBuilder,
+    etag: Option<&str>,
+) -> Result<Option<Fetched>> {
+    let request = match etag {
+        Some(etag) => request.header(IF_NONE_MATCH, etag),
+        None => request,
+    };
+    let failed =
+        |e: String| GatewayError::Config(format!("Failed to fetch config {}: {}", location, e));
+
+    let response = request.send().await.map_err(|e| failed(e.to_string()))?;
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        return Err(failed(format!("status {}", status.as_u16())));
+    }
+
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(str::to_string);
+    let content = response.text().await.map_err(|e| failed(e.to_string()))?;
+    Ok(Some(Fetched { content, etag }))
+}
+
+/// Endpoint and credentials for reading S3 objects
+#[derive(Debug, Clone)]
+struct S3Access {
+    /// Endpoint of an S3-compatible store, addressed path-style
+    endpoint: Option<String>,
+    region: String,
+    /// Access key ID, secret access key and session token
+    credentials: Option<(String, String, Option<String>)>,
+}
+
+impl S3Access {
+    /// Endpoint and region from the standard AWS environment variables, with
+    /// credentials from the AWS credential chain
+    async fn resolve() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let region = var("AWS_REGION")
+            .or_else(|| var("AWS_DEFAULT_REGION"))
+            .unwrap_or_else(|| "us-east-1".to_string());
+        let credentials = match credential_chain(&region).credentials().await {
+            Ok(credentials) => Some((
+                credentials.access_key_id,
+                credentials.secret_access_key,
+                credentials.session_token,
+            )),
+            Err(e) => {
+                debug!("Reading S3 config anonymously: {}", e);
+                None
+            }
+        };
+        Self {
+            endpoint: var("AWS_ENDPOINT_URL_S3").or_else(|| var("AWS_ENDPOINT_URL")),
+            region,
+            credentials,
+        }
+    }
+
+    /// GetObject request, SigV4-signed when there are credentials
+    fn request(&self, bucket: &str, key: &str) -> Result<reqwest::RequestBuilder> {
+        let key = key.split('/').map(uri_encode).collect::<Vec<_>>().join("/");
+        let url = match &self.endpoint {
+            Some(endpoint) => format!("{}/{}/{}", endpoint.trim_end_matches('/'), bucket, key),
+            None => format!(
+                "https://{}.s3.{}.amazonaws.com/{}",
+                bucket, self.region, key
+            ),
+        };
+        let request = get_shared_client().get(&url);
+        let Some((access_key, secret_key, session_token)) = self.credentials.clone() else {
+            return Ok(request);
+        };
+
+        let headers =
+            HashMap::from([("x-amz-content-sha256".to_string(), EMPTY_SHA256.to_string())]);
+        let signed = SigV4Signer::new(access_key, secret_key, session_token, self.region.clone())
+            .with_service("s3")
+            .sign_request("GET", &url, &headers, "", chrono::Utc::now())
+            .map_err(|e| GatewayError::Config(format!("Failed to sign S3 request: {}", e)))?;
+        Ok(signed
+            .into_iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("host"))
+            .fold(request, |request, (name, value)| {
+                request.header(name, value)
+            }))
+    }
+}
+
+/// Credential chain of S3 configurations, kept so temporary credentials are
+/// reused across polls until they expire
+fn credential_chain(region: &str) -> &'static AwsCredentialChain {
+    static CHAIN: OnceLock<AwsCredentialChain> = OnceLock::new();
+    CHAIN.get_or_init(|| AwsCredentialChain::new(region, None, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_fetch_url_with_etag() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/gateway.yaml"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/gateway.yaml"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_string("server:\n  port: 9000\n"),
+            )
+            .mount(&server)
+            .await;
+
+        let location = ConfigLocation::parse(&format!("{}/gateway.yaml", server.uri())).unwrap();
+        let fetched = fetch(&location, None).await.unwrap().unwrap();
+        assert_eq!(fetched.content, "server:\n  port: 9000\n");
+        assert_eq!(fetched.etag.as_deref(), Some("\"v1\""));
+        assert!(fetch(&location, Some("\"v1\"")).await.unwrap().is_none());
+
+        let missing = ConfigLocation::parse(&format!("{}/missing.yaml", server.uri())).unwrap();
+        Mock::given(path("/missing.yaml"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let err = fetch(&missing, None).await.unwrap_err();
+        assert!(err.to_string().contains("status 404"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_s3_object() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/config/prod/gateway%20v2.yaml"))
+            .and(header("x-amz-content-sha256", EMPTY_SHA256))
+            .and(header_exists("authorization"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"abc\"")
+                    .set_body_string("providers: []\n"),
+            )
+            .mount(&server)
+            .await;
+
+        let access = S3Access {
+            endpoint: Some(server.uri()),
+            region: "eu-west-1".to_string(),
+            credentials: Some(("AKID".to_string(), "secret".to_string(), None)),
+        };
+        let location = ConfigLocation::parse("s3://config/prod/gateway v2.yaml").unwrap();
+        let request = access.request("config", "prod/gateway v2.yaml").unwrap();
+        let fetched = send(&location, request, None).await.unwrap().unwrap();
+        assert_eq!(fetched.content, "providers: []\n");
+        assert_eq!(fetched.etag.as_deref(), Some("\"abc\""));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_file_is_rejected() {
+        let location = ConfigLocation::parse("config/gateway.yaml").unwrap();
+        assert!(fetch(&location, None).await.is_err());
+    }
+}
@@ -0,0 +1,698 @@
+//! Secret manager references in configuration
+//!
+//! Provider API keys, and string provider settings, may name a secret held
+//! in an external store instead of containing it:
+//!
+//! - `vault://<path>#<field>`: HashiCorp Vault, read from `/v1/<path>`
+//!   (KV v2 paths include `data/`, e.g. `vault://secret/data/openai#api_key`)
+//! - `aws-sm://<secret id>`: AWS Secrets Manager, by name or ARN
+//! - `gcp-sm://<secret>[/<version>]`: Google Cloud Secret Manager, in the
+//!   configured project, or `gcp-sm://projects/<project>/secrets/<secret>`
+//! - `azure-kv://<vault>/<secret>[/<version>]`: Azure Key Vault
+//!
+//! `#<field>` selects a field of a secret holding a JSON object. Resolved
+//! values are cached, so a secret referenced several times is fetched once,
+//! and re-fetched every `secrets.refresh_interval` seconds when set.
+
+use crate::config::{ProviderConfig, SecretsConfig};
+use crate::core::providers::azure::{AzureAdCredential, AzureAdTokenCache};
+use crate::core::providers::bedrock::SigV4Signer;
+use crate::core::providers::vertex_ai::VertexAuth;
+use crate::utils::error::{GatewayError, Result};
+use base64::Engine;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OnceCell, RwLock, watch};
+use tracing::{debug, info, warn};
+
+/// Default Google Cloud Secret Manager endpoint
+const GCP_SECRET_MANAGER_ENDPOINT: &str = "https://secretmanager.googleapis.com";
+
+/// Resource of Azure Key Vault tokens
+const AZURE_KEY_VAULT_RESOURCE: &str = "https://vault.azure.net";
+
+/// Azure Key Vault REST API version
+const AZURE_KEY_VAULT_API_VERSION: &str = "7.4";
+
+/// Store holding a referenced secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretStore {
+    /// HashiCorp Vault
+    Vault,
+    /// AWS Secrets Manager
+    AwsSecretsManager,
+    /// Google Cloud Secret Manager
+    GcpSecretManager,
+    /// Azure Key Vault
+    AzureKeyVault,
+}
+
+impl SecretStore {
+    /// Reference scheme of the store
+    pub fn scheme(&self) -> &'static str {
+        match self {
+            Self::Vault => "vault",
+            Self::AwsSecretsManager => "aws-sm",
+            Self::GcpSecretManager => "gcp-sm",
+            Self::AzureKeyVault => "azure-kv",
+        }
+    }
+
+    fn from_scheme(scheme: &str) -> Option<Self> {
+        [
+            Self::Vault,
+            Self::AwsSecretsManager,
+            Self::GcpSecretManager,
+            Self::AzureKeyVault,
+        ]
+        .into_iter()
+        .find(|store| store.scheme() == scheme)
+    }
+}
+
+/// Reference to a secret in an external store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretReference {
+    /// Store holding the secret
+    pub store: SecretStore,
+    /// Location of the secret in the store
+    pub path: String,
+    /// Field of a JSON secret, if one is selected
+    pub field: Option<String>,
+}
+
+impl SecretReference {
+    /// Parse a configured value, which is not a reference unless it starts
+    /// with a secret store scheme
+    pub fn parse(value: &str) -> Result<Option<Self>> {
+        let Some((scheme, rest)) = value.split_once("://") else {
+            return Ok(None);
+        };
+        let Some(store) = SecretStore::from_scheme(scheme) else {
+            return Ok(None);
+        };
+
+        let (path, field) = match rest.split_once('#') {
+            Some((path, field)) => (path, Some(field.to_string())),
+            None => (rest, None),
+        };
+        let path = path.trim_matches('/');
+        let segments = path.split('/').count();
+        let valid = !path.is_empty()
+            && field.as_ref().is_none_or(|field| !field.is_empty())
+            && match store {
+                SecretStore::Vault => field.is_some(),
+                SecretStore::AwsSecretsManager => true,
+                SecretStore::GcpSecretManager if path.starts_with("projects/") => {
+                    segments == 4 || segments == 6
+                }
+                SecretStore::GcpSecretManager => segments <= 2,
+                SecretStore::AzureKeyVault => segments == 2 || segments == 3,
+            };
+        if !valid {
+            return Err(GatewayError::Config(format!(
+                "Invalid secret reference {}://{}, expected {}",
+                scheme,
+                rest,
+                match store {
+                    SecretStore::Vault => "vault://<path>#<field>",
+                    SecretStore::AwsSecretsManager => "aws-sm://<secret id>",
+                    SecretStore::GcpSecretManager => "gcp-sm://<secret>[/<version>]",
+                    SecretStore::AzureKeyVault => "azure-kv://<vault>/<secret>[/<version>]",
+                }
+            )));
+        }
+
+        Ok(Some(Self {
+            store,
+            path: path.to_string(),
+            field,
+        }))
+    }
+
+    /// Select the configured value from a fetched secret
+    fn select(&self, secret: Value) -> Result<String> {
+        let secret = match (&self.field, secret) {
+            (Some(_), Value::String(text)) => serde_json::from_str(&text).map_err(|_| {
+                GatewayError::Integration(format!("Secret {} is not a JSON object", self))
+            })?,
+            (_, secret) => secret,
+        };
+
+        let value = match (&self.field, secret) {
+            (None, Value::String(text)) => return Ok(text),
+            (Some(field), Value::Object(mut fields)) => fields.remove(field).ok_or_else(|| {
+                GatewayError::Integration(format!("Secret {} has no such field", self))
+            })?,
+            (None, Value::Object(fields)) if fields.len() == 1 => fields
+                .into_iter()
+                .next()
+                .map(|(_, value)| value)
+                .unwrap_or_default(),
+            _ => {
+                return Err(GatewayError::Integration(format!(
+                    "Secret {} has several fields, select one with #<field>",
+                    self
+                )));
+            }
+        };
+        match value {
+            Value::String(text) => Ok(text),
+            Value::Number(number) => Ok(number.to_string()),
+            _ => Err(GatewayError::Integration(format!(
+                "Secret {} is not a string",
+                self
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for SecretReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}://{}", self.store.scheme(), self.path)?;
+        if let Some(field) = &self.field {
+            write!(f, "#{}", field)?;
+        }
+        Ok(())
+    }
+}
+
+/// Resolves and caches secret references
+pub struct SecretResolver {
+    config: SecretsConfig,
+    http_client: reqwest::Client,
+    /// Resolved values by reference
+    cache: RwLock<HashMap<String, String>>,
+    gcp_auth: OnceCell<VertexAuth>,
+    azure_tokens: AzureAdTokenCache,
+    /// Incremented whenever a refresh changes a secret
+    changes: watch::Sender<u64>,
+}
+
+impl SecretResolver {
+    /// Create a resolver for the configured secret stores
+    pub fn new(config: &SecretsConfig) -> Self {
+        Self {
+            config: config.clone(),
+            http_client: reqwest::Client::new(),
+            cache: RwLock::new(HashMap::new()),
+            gcp_auth: OnceCell::new(),
+            azure_tokens: AzureAdTokenCache::for_resource(AZURE_KEY_VAULT_RESOURCE),
+            changes: watch::channel(0).0,
+        }
+    }
+
+    /// Resolve a configured value, returning values that are not secret
+    /// references as they are
+    pub async fn resolve(&self, value: &str) -> Result<String> {
+        let Some(reference) = SecretReference::parse(value)? else {
+            return Ok(value.to_string());
+        };
+        if let Some(secret) = self.cache.read().await.get(value) {
+            return Ok(secret.clone());
+        }
+
+        let secret = self.fetch(&reference).await?;
+        self.cache
+            .write()
+            .await
+            .insert(value.to_string(), secret.clone());
+        Ok(secret)
+    }
+
+    /// Replace secret references in provider API keys and string settings
+    /// with the secrets they name
+    pub async fn resolve_providers(&self, providers: &mut [ProviderConfig]) -> Result<()> {
+        for provider in providers {
+            provider.api_key = self.resolve(&provider.api_key).await?;
+            for key in &mut provider.api_keys {
+                *key = self.resolve(key).await?;
+            }
+            for value in provider.settings.values_mut() {
+                if let Value::String(setting) = value {
+                    *setting = self.resolve(setting).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-fetch every resolved secret, returning how many changed
+    ///
+    /// Secrets that fail to refresh keep their last value.
+    pub async fn refresh(&self) -> usize {
+        let references: Vec<String> = self.cache.read().await.keys().cloned().collect();
+        let mut changed = 0;
+        for value in references {
+            let Ok(Some(reference)) = SecretReference::parse(&value) else {
+                continue;
+            };
+            match self.fetch(&reference).await {
+                Ok(secret) => {
+                    let previous = self.cache.write().await.insert(value, secret.clone());
+                    if previous.as_ref() != Some(&secret) {
+                        info!("Secret {} changed", reference);
+                        changed += 1;
+                    }
+                }
+                Err(e) => warn!("Failed to refresh secret {}: {}", reference, e),
+            }
+        }
+        if changed > 0 {
+            self.changes.send_modify(|generation| *generation += 1);
+        }
+        changed
+    }
+
+    /// Refresh secrets every `refresh_interval` seconds, if set
+    pub fn start(self: &Arc<Self>) {
+        if self.config.refresh_interval == 0 {
+            return;
+        }
+        let resolver = Arc::clone(self);
+        tokio::spawn(async move {
+            let period = Duration::from_secs(resolver.config.refresh_interval);
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                interval.tick().await;
+                resolver.refresh().await;
+            }
+        });
+    }
+
+    /// Watch for refreshes that change a secret
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.changes.subscribe()
+    }
+
+    async fn fetch(&self, reference: &SecretReference) -> Result<String> {
+        debug!("Fetching secret {}", reference);
+        let secret = match reference.store {
+            SecretStore::Vault => self.fetch_vault(&reference.path).await,
+            SecretStore::AwsSecretsManager => self.fetch_aws(&reference.path).await,
+            SecretStore::GcpSecretManager => self.fetch_gcp(&reference.path).await,
+            SecretStore::AzureKeyVault => self.fetch_azure(&reference.path).await,
+        }
+        .map_err(|e| {
+            GatewayError::Integration(format!("Failed to fetch secret {}: {}", reference, e))
+        })?;
+        reference.select(secret)
+    }
+
+    /// Read a Vault secret, unwrapping KV v2 responses
+    async fn fetch_vault(&self, path: &str) -> std::result::Result<Value, String> {
+        let vault = &self.config.vault;
+        let address = setting(&vault.address, "VAULT_ADDR")
+            .ok_or("Vault address is not configured (secrets.vault.address or VAULT_ADDR)")?;
+        let token = setting(&vault.token, "VAULT_TOKEN")
+            .ok_or("Vault token is not configured (secrets.vault.token or VAULT_TOKEN)")?;
+
+        let mut request = self
+            .http_client
+            .get(format!("{}/v1/{}", address.trim_end_matches('/'), path))
+            .header("X-Vault-Token", token);
+        if let Some(namespace) = setting(&vault.namespace, "VAULT_NAMESPACE") {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+        let mut body = send(request).await?;
+
+        let mut data = body
+            .get_mut("data")
+            .map(Value::take)
+            .ok_or("Vault response has no data")?;
+        if data.get("metadata").is_some()
+            && let Some(fields) = data.get_mut("data").filter(|fields| fields.is_object())
+        {
+            return Ok(fields.take());
+        }
+        Ok(data)
+    }
+
+    /// Read an AWS Secrets Manager secret with a SigV4-signed request
+    async fn fetch_aws(&self, secret_id: &str) -> std::result::Result<Value, String> {
+        let aws = &self.config.aws;
+        let region = setting(&aws.region, "AWS_REGION")
+            .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+            .unwrap_or_else(|| "us-east-1".to_string());
+        let access_key = setting(&aws.access_key_id, "AWS_ACCESS_KEY_ID")
+            .ok_or("AWS credentials are not configured")?;
+        let secret_key = setting(&aws.secret_access_key, "AWS_SECRET_ACCESS_KEY")
+            .ok_or("AWS credentials are not configured")?;
+        let session_token = setting(&aws.session_token, "AWS_SESSION_TOKEN");
+        let url = aws
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://secretsmanager.{}.amazonaws.com/", region));
+
+        let body = serde_json::json!({ "SecretId": secret_id }).to_string();
+        let headers = HashMap::from([
+            (
+                "content-type".to_string(),
+                "application/x-amz-json-1.1".to_string(),
+            ),
+            (
+                "x-amz-target".to_string(),
+                "secretsmanager.GetSecretValue".to_string(),
+            ),
+        ]);
+        let signed = SigV4Signer::new(access_key, secret_key, session_token, region)
+            .with_service("secretsmanager")
+            .sign_request("POST", &url, &headers, &body, chrono::Utc::now())?;
+
+        let request = signed
+            .into_iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("host"))
+            .fold(self.http_client.post(&url), |request, (name, value)| {
+                request.header(name, value)
+            })
+            .body(body);
+        let response = send(request).await?;
+
+        if let Some(text) = response.get("SecretString").and_then(Value::as_str) {
+            return Ok(Value::String(text.to_string()));
+        }
+        let binary = response
+            .get("SecretBinary")
+            .and_then(Value::as_str)
+            .ok_or("AWS response has no secret value")?;
+        decode_text(binary)
+    }
+
+    /// Access a Google Cloud Secret Manager secret version
+    async fn fetch_gcp(&self, path: &str) -> std::result::Result<Value, String> {
+        let gcp = &self.config.gcp;
+        let mut name = if path.starts_with("projects/") {
+            path.to_string()
+        } else {
+            let project = setting(&gcp.project, "GOOGLE_CLOUD_PROJECT")
+                .ok_or("Google Cloud project is not configured")?;
+            let (secret, version) = path.split_once('/').unwrap_or((path, "latest"));
+            format!(
+                "projects/{}/secrets/{}/versions/{}",
+                project, secret, version
+            )
+        };
+        if !name.contains("/versions/") {
+            name.push_str("/versions/latest");
+        }
+
+        let auth = self
+            .gcp_auth
+            .get_or_try_init(|| async {
+                match &gcp.credentials_file {
+                    Some(path) => VertexAuth::load_credentials_from_file(path)
+                        .await
+                        .map(VertexAuth::new),
+                    None => VertexAuth::from_env().await,
+                }
+            })
+            .await
+            .map_err(|e| format!("Google Cloud credentials: {}", e))?;
+        let token = auth
+            .get_access_token()
+            .await
+            .map_err(|e| format!("Google Cloud credentials: {}", e))?;
+
+        let endpoint = gcp
+            .endpoint
+            .as_deref()
+            .unwrap_or(GCP_SECRET_MANAGER_ENDPOINT);
+        let request = self
+            .http_client
+            .get(format!(
+                "{}/v1/{}:access",
+                endpoint.trim_end_matches('/'),
+                name
+            ))
+            .bearer_auth(token);
+        let response = send(request).await?;
+
+        let data = response
+            .pointer("/payload/data")
+            .and_then(Value::as_str)
+            .ok_or("Google Cloud response has no payload")?;
+        decode_text(data)
+    }
+
+    /// Read an Azure Key Vault secret with an Entra ID token
+    async fn fetch_azure(&self, path: &str) -> std::result::Result<Value, String> {
+        let azure = &self.config.azure;
+        let (vault, secret) = path.split_once('/').ok_or("Missing secret name")?;
+        let credential = match (
+            setting(&azure.tenant_id, "AZURE_TENANT_ID"),
+            setting(&azure.client_id, "AZURE_CLIENT_ID"),
+            setting(&azure.client_secret, "AZURE_CLIENT_SECRET"),
+        ) {
+            (Some(tenant_id), Some(client_id), Some(client_secret)) => {
+                AzureAdCredential::ClientSecret {
+                    tenant_id,
+                    client_id,
+                    client_secret,
+                }
+            }
+            (_, client_id, _) => AzureAdCredential::ManagedIdentity { client_id },
+        };
+        let token = self
+            .azure_tokens
+            .get_token(&credential)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let request = self
+            .http_client
+            .get(format!(
+                "https://{}.vault.azure.net/secrets/{}",
+                vault, secret
+            ))
+            .query(&[("api-version", AZURE_KEY_VAULT_API_VERSION)])
+            .bearer_auth(token);
+        let response = send(request).await?;
+
+        response
+            .get("value")
+            .cloned()
+            .filter(Value::is_string)
+            .ok_or_else(|| "Azure response has no secret value".to_string())
+    }
+}
+
+/// Configured setting, or the environment variable it falls back to
+fn setting(value: &Option<String>, var: &str) -> Option<String> {
+    value.clone().or_else(|| std::env::var(var).ok())
+}
+
+/// Send a request to a secret store and parse its JSON response
+async fn send(request: reqwest::RequestBuilder) -> std::result::Result<Value, String> {
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("status {}", status.as_u16()));
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+/// Decode a base64-encoded UTF-8 secret
+fn decode_text(data: &str) -> std::result::Result<Value, String> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map_err(|e| format!("Invalid base64 secret: {}", e))?;
+    String::from_utf8(bytes)
+        .map(Value::String)
+        .map_err(|_| "Secret is not UTF-8 text".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AwsSecretsConfig, VaultSecretsConfig};
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_parse_references() {
+        assert_eq!(SecretReference::parse("sk-plain").unwrap(), None);
+        assert_eq!(SecretReference::parse("https://example.com").unwrap(), None);
+
+        let reference = SecretReference::parse("vault://secret/data/openai#api_key")
+            .unwrap()
+            .unwrap();
+        assert_eq!(reference.store, SecretStore::Vault);
+        assert_eq!(reference.path, "secret/data/openai");
+        assert_eq!(reference.field.as_deref(), Some("api_key"));
+        assert_eq!(reference.to_string(), "vault://secret/data/openai#api_key");
+
+        let reference = SecretReference::parse("azure-kv://my-vault/openai-key")
+            .unwrap()
+            .unwrap();
+        assert_eq!(reference.store, SecretStore::AzureKeyVault);
+        assert_eq!(reference.field, None);
+
+        assert!(
+            SecretReference::parse("aws-sm://prod/openai#key")
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            SecretReference::parse("gcp-sm://openai-key/3")
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            SecretReference::parse("gcp-sm://projects/p/secrets/openai-key")
+                .unwrap()
+                .is_some()
+        );
+
+        assert!(SecretReference::parse("vault://secret/data/openai").is_err());
+        assert!(SecretReference::parse("aws-sm://").is_err());
+        assert!(SecretReference::parse("aws-sm://openai#").is_err());
+        assert!(SecretReference::parse("azure-kv://my-vault").is_err());
+        assert!(SecretReference::parse("gcp-sm://a/b/c").is_err());
+    }
+
+    #[test]
+    fn test_select_field() {
+        let reference = |value| SecretReference::parse(value).unwrap().unwrap();
+        let json = Value::String(r#"{"key":"sk-1","org":"org-1"}"#.to_string());
+
+        assert_eq!(
+            reference("aws-sm://openai#key")
+                .select(json.clone())
+                .unwrap(),
+            "sk-1"
+        );
+        assert_eq!(
+            reference("aws-sm://openai").select(json.clone()).unwrap(),
+            r#"{"key":"sk-1","org":"org-1"}"#
+        );
+        assert!(reference("aws-sm://openai#missing").select(json).is_err());
+
+        let fields = serde_json::json!({"key": "sk-1"});
+        assert_eq!(
+            reference("azure-kv://v/openai").select(fields).unwrap(),
+            "sk-1"
+        );
+        let fields = serde_json::json!({"key": "sk-1", "org": "org-1"});
+        assert!(reference("azure-kv://v/openai").select(fields).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_vault_secret() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/openai"))
+            .and(header("X-Vault-Token", "vault-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "data": {"api_key": "sk-from-vault"},
+                    "metadata": {"version": 1}
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resolver = SecretResolver::new(&SecretsConfig {
+            vault: VaultSecretsConfig {
+                address: Some(server.uri()),
+                token: Some("vault-token".to_string()),
+                namespace: None,
+            },
+            ..Default::default()
+        });
+
+        let mut providers = vec![ProviderConfig {
+            api_key: "vault://secret/data/openai#api_key".to_string(),
+            api_keys: vec!["sk-plain".to_string()],
+            ..Default::default()
+        }];
+        resolver.resolve_providers(&mut providers).await.unwrap();
+        assert_eq!(providers[0].api_key, "sk-from-vault");
+        assert_eq!(providers[0].api_keys, vec!["sk-plain"]);
+
+        // Served from the cache
+        assert_eq!(
+            resolver
+                .resolve("vault://secret/data/openai#api_key")
+                .await
+                .unwrap(),
+            "sk-from-vault"
+        );
+        assert!(
+            resolver
+                .resolve("vault://secret/data/other#key")
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_aws_secret() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("x-amz-target", "secretsmanager.GetSecretValue"))
+            .and(header_exists("authorization"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"SecretString": "sk-from-aws"})),
+            )
+            .mount(&server)
+            .await;
+
+        let resolver = SecretResolver::new(&SecretsConfig {
+            aws: AwsSecretsConfig {
+                region: Some("us-west-2".to_string()),
+                access_key_id: Some("AKIDEXAMPLE".to_string()),
+                secret_access_key: Some("secret".to_string()),
+                session_token: None,
+                endpoint: Some(format!("{}/", server.uri())),
+            },
+            ..Default::default()
+        });
+        assert_eq!(
+            resolver.resolve("aws-sm://prod/openai").await.unwrap(),
+            "sk-from-aws"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reports_changed_secrets() {
+        let server = MockServer::start().await;
+        let secret = |value: &str| {
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"data": {"api_key": value}}))
+        };
+        Mock::given(method("GET"))
+            .respond_with(secret("sk-old"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let resolver = SecretResolver::new(&SecretsConfig {
+            vault: VaultSecretsConfig {
+                address: Some(server.uri()),
+                token: Some("vault-token".to_string()),
+                namespace: None,
+            },
+            ..Default::default()
+        });
+        let mut changes = resolver.subscribe();
+        let value = "vault://kv/openai#api_key";
+        assert_eq!(resolver.resolve(value).await.unwrap(), "sk-old");
+
+        Mock::given(method("GET"))
+            .respond_with(secret("sk-new"))
+            .mount(&server)
+            .await;
+        assert_eq!(resolver.refresh().await, 1);
+        assert!(changes.has_changed().unwrap());
+        assert_eq!(resolver.resolve(value).await.unwrap(), "sk-new");
+
+        changes.mark_unchanged();
+        assert_eq!(resolver.refresh().await, 0);
+        assert!(!changes.has_changed().unwrap());
+    }
+}
@@ -0,0 +1,662 @@
+//! Configuration file sources
+//!
+//! Configuration files may pull in other files and read environment
+//! variables, so one configuration can be shared across environments:
+//!
+//! ```yaml
+//! include:
+//!   - providers.yaml
+//!   - "overrides/${DEPLOY_ENV}.yaml"
+//!
+//! storage:
+//!   database:
+//!     url: "${DATABASE_URL:-sqlite://gateway.db}"
+//!
+//! providers:
+//!   - name: openai
+//!     provider_type: openai
+//!     api_key: os.environ/OPENAI_API_KEY
+//! ```
+//!
+//! - `${NAME}` in a string value is replaced by the variable, and
+//!   `${NAME:-default}` falls back to `default` when it is unset or empty.
+//!   `$${` is a literal `${`.
+//! - A string value `os.environ/NAME` is replaced by the variable.
+//! - `include:` lists files, relative to the including file, merged beneath
+//!   it: mappings are merged key by key with the including file taking
+//!   precedence, and lists are concatenated after its own entries.
+//!
+//! Files are YAML, JSON or TOML, told apart by their extension or else
+//! their content, and may include files in other formats. Configurations
+//! may also be loaded from a URL or S3 object, see [`super::remote`].
+//!
+//! Unset variables without a default are replaced by an empty string and
+//! logged. Interpolation only applies to string values, so the result is
+//! always a string.
+
+use super::remote;
+use crate::utils::error::{GatewayError, Result};
+use serde_yaml::Value;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use tracing::warn;
+
+/// Key listing the files a configuration file includes
+pub(crate) const INCLUDE_KEY: &str = "include";
+
+/// Prefix of a value read from an environment variable
+const ENV_PREFIX: &str = "os.environ/";
+
+/// Environment variable allowing configurations from `http://` URLs on
+/// other hosts than this one
+const ALLOW_HTTP_VAR: &str = "LITELLM_ALLOW_HTTP_CONFIG";
+
+/// Load a configuration file with its includes, interpolating environment
+/// variables
+pub async fn load(path: &Path) -> Result<Value> {
+    load_location(&ConfigLocation::File(path.to_path_buf())).await
+}
+
+/// Load a configuration from a file, URL or S3 object with its includes,
+/// interpolating environment variables
+pub async fn load_location(location: &ConfigLocation) -> Result<Value> {
+    load_from(location.clone(), Vec::new())
+        .await
+        .map(|(value, _)| value)
+}
+
+/// Locations a configuration is read from: the configuration itself and
+/// every file it includes, directly or not
+pub async fn sources(location: &ConfigLocation) -> Result<Vec<ConfigLocation>> {
+    load_from(location.clone(), Vec::new())
+        .await
+        .map(|(_, sources)| sources)
+}
+
+/// A configuration loaded with its includes, along with the locations read
+type Loaded = Pin<Box<dyn Future<Output = Result<(Value, Vec<ConfigLocation>)>> + Send>>;
+
+/// Load a configuration with its includes, along with the locations read
+fn load_from(location: ConfigLocation, mut including: Vec<ConfigLocation>) -> Loaded {
+    Box::pin(async move {
+        let content = read(&location).await?;
+        let mut value = Format::detect(location.file_path(), &content)
+            .parse(&content)
+            .map_err(|e| {
+                GatewayError::Config(format!("Failed to parse config file {}: {}", location, e))
+            })?;
+        interpolate_value(&mut value, &env_var)?;
+
+        let includes = match &mut value {
+            Value::Mapping(mapping) => mapping.remove(INCLUDE_KEY),
+            _ => None,
+        };
+        let mut sources = vec![location.clone()];
+        let Some(includes) = includes else {
+            return Ok((value, sources));
+        };
+
+        let canonical = match &location {
+            ConfigLocation::File(path) => tokio::fs::canonicalize(path)
+                .await
+                .map_or_else(|_| location.clone(), ConfigLocation::File),
+            _ => location.clone(),
+        };
+        if including.contains(&canonical) {
+            return Err(GatewayError::Config(format!(
+                "Config file {} is included in a cycle",
+                location
+            )));
+        }
+        including.push(canonical);
+
+        for include in include_paths(includes)? {
+            let (included, included_sources) =
+                load_from(location.join(&include)?, including.clone()).await?;
+            merge(&mut value, included);
+            sources.extend(included_sources);
+        }
+        Ok((value, sources))
+    })
+}
+
+/// Content of a configuration file or remote configuration
+async fn read(location: &ConfigLocation) -> Result<String> {
+    match location {
+        ConfigLocation::File(path) => tokio::fs::read_to_string(path).await.map_err(|e| {
+            GatewayError::Config(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        }),
+        _ => remote::fetch(location, None)
+            .await?
+            .map(|fetched| fetched.content)
+            .ok_or_else(|| GatewayError::Config(format!("Config {} was not returned", location))),
+    }
+}
+
+/// Where a configuration is loaded from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLocation {
+    /// Local file
+    File(PathBuf),
+    /// `https://` URL, or `http://` URL of this host or allowed with
+    /// `LITELLM_ALLOW_HTTP_CONFIG`
+    Url(url::Url),
+    /// S3 object
+    S3 {
+        /// Bucket name
+        bucket: String,
+        /// Object key
+        key: String,
+    },
+}
+
+impl ConfigLocation {
+    /// Location of a file path, `http(s)://` URL or `s3://<bucket>/<key>`
+    ///
+    /// Configurations hold provider keys, so plain `http://` is only
+    /// accepted for this host, unless `LITELLM_ALLOW_HTTP_CONFIG` is `true`.
+    pub fn parse(location: &str) -> Result<Self> {
+        if let Some(object) = location.strip_prefix("s3://") {
+            return match object.split_once('/') {
+                Some((bucket, key)) if !bucket.is_empty() && !key.is_empty() => Ok(Self::S3 {
+                    bucket: bucket.to_string(),
+                    key: key.to_string(),
+                }),
+                _ => Err(GatewayError::Config(format!(
+                    "S3 config location {} must be s3://<bucket>/<key>",
+                    location
+                ))),
+            };
+        }
+        if location.starts_with("http://") || location.starts_with("https://") {
+            let url = url::Url::parse(location).map_err(|e| {
+                GatewayError::Config(format!("Invalid config URL {}: {}", location, e))
+            })?;
+            if url.scheme() == "http" && !is_loopback(&url) && !http_allowed() {
+                return Err(GatewayError::Config(format!(
+                    "Config URL {} is not encrypted; use https:// or set {}=true",
+                    location, ALLOW_HTTP_VAR
+                )));
+            }
+            return Ok(Self::Url(url));
+        }
+        Ok(Self::File(PathBuf::from(location)))
+    }
+
+    /// Whether the configuration is fetched over the network
+    pub fn is_remote(&self) -> bool {
+        !matches!(self, Self::File(_))
+    }
+
+    /// Location of a file included by this one
+    ///
+    /// Relative includes are resolved against this location; URLs and S3
+    /// objects are used as they are.
+    pub fn join(&self, include: &str) -> Result<Self> {
+        let included = Self::parse(include)?;
+        if included.is_remote() {
+            return Ok(included);
+        }
+        match self {
+            Self::File(path) => Ok(Self::File(
+                path.parent().unwrap_or(Path::new("")).join(include),
+            )),
+            Self::Url(url) => url.join(include).map(Self::Url).map_err(|e| {
+                GatewayError::Config(format!("Invalid include {} of {}: {}", include, url, e))
+            }),
+            Self::S3 { bucket, key } => {
+                let key = match key.rsplit_once('/') {
+                    Some((prefix, _)) => format!("{}/{}", prefix, include),
+                    None => include.to_string(),
+                };
+                Ok(Self::S3 {
+                    bucket: bucket.clone(),
+                    key,
+                })
+            }
+        }
+    }
+
+    /// Path whose extension tells the configuration's format
+    fn file_path(&self) -> &Path {
+        match self {
+            Self::File(path) => path,
+            Self::Url(url) => Path::new(url.path()),
+            Self::S3 { key, .. } => Path::new(key),
+        }
+    }
+}
+
+/// Whether a URL points at this host
+fn is_loopback(url: &url::Url) -> bool {
+    match url.host() {
+        Some(url::Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
+/// Whether `http://` configurations may be fetched from other hosts
+fn http_allowed() -> bool {
+    std::env::var(ALLOW_HTTP_VAR).is_ok_and(|value| value.eq_ignore_ascii_case("true"))
+}
+
+impl std::fmt::Display for ConfigLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{}", url),
+            Self::S3 { bucket, key } => write!(f, "s3://{}/{}", bucket, key),
+        }
+    }
+}
+
+impl From<PathBuf> for ConfigLocation {
+    fn from(path: PathBuf) -> Self {
+        Self::File(path)
+    }
+}
+
+impl From<&Path> for ConfigLocation {
+    fn from(path: &Path) -> Self {
+        Self::File(path.to_path_buf())
+    }
+}
+
+/// Configuration file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// YAML, also used for JSON without a `.json` extension
+    Yaml,
+    /// JSON
+    Json,
+    /// TOML
+    Toml,
+}
+
+impl Format {
+    /// Format of a file from its extension, or else its content
+    ///
+    /// Content that does not look like YAML or JSON is read as TOML.
+    pub fn detect(path: &Path, content: &str) -> Self {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("yaml" | "yml") => Self::Yaml,
+            Some("json") => Self::Json,
+            Some("toml") => Self::Toml,
+            _ if serde_yaml::from_str::<Value>(content).is_err()
+                && toml::from_str::<toml::Table>(content).is_ok() =>
+            {
+                Self::Toml
+            }
+            _ => Self::Yaml,
+        }
+    }
+
+    /// Parse a configuration in this format
+    pub fn parse(self, content: &str) -> std::result::Result<Value, String> {
+        match self {
+            Self::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+            Self::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            Self::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Files listed under `include:`, either one path or a list of paths
+fn include_paths(includes: Value) -> Result<Vec<String>> {
+    let invalid = || GatewayError::Config("include must be a path or a list of paths".to_string());
+    match includes {
+        Value::Null => Ok(Vec::new()),
+        Value::String(path) => Ok(vec![path]),
+        Value::Sequence(paths) => paths
+            .into_iter()
+            .map(|path| match path {
+                Value::String(path) => Ok(path),
+                _ => Err(invalid()),
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
+/// Merge `other` beneath `value`
+///
+/// Mappings are merged key by key, lists are concatenated and any other
+/// value in `value` is kept.
+fn merge(value: &mut Value, other: Value) {
+    match (value, other) {
+        (Value::Mapping(mapping), Value::Mapping(other)) => {
+            for (key, other) in other {
+                match mapping.get_mut(&key) {
+                    Some(value) => merge(value, other),
+                    None => {
+                        mapping.insert(key, other);
+                    }
+                }
+            }
+        }
+        (Value::Sequence(sequence), Value::Sequence(other)) => sequence.extend(other),
+        (value @ Value::Null, other) => *value = other,
+        _ => {}
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Interpolate environment variables in every string value
+fn interpolate_value(value: &mut Value, lookup: &impl Fn(&str) -> Option<String>) -> Result<()> {
+    match value {
+        Value::String(s) => {
+            *s = match s.strip_prefix(ENV_PREFIX) {
+                Some(name) => lookup_or_empty(name, lookup),
+                None => interpolate(s, lookup)?,
+            };
+        }
+        Value::Sequence(sequence) => {
+            for value in sequence {
+                interpolate_value(value, lookup)?;
+            }
+        }
+        Value::Mapping(mapping) => {
+            for (_, value) in mapping.iter_mut() {
+                interpolate_value(value, lookup)?;
+            }
+        }
+        Value::Tagged(tagged) => interpolate_value(&mut tagged.value, lookup)?,
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// Replace `${NAME}` and `${NAME:-default}` in a string
+pub fn interpolate(input: &str, lookup: &impl Fn(&str) -> Option<String>) -> Result<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            output.push_str(&rest[..start - 1]);
+            output.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        output.push_str(&rest[..start]);
+
+        let reference = &rest[start + 2..];
+        let end = reference
+            .find('}')
+            .ok_or_else(|| GatewayError::Config("Unterminated ${ in a config value".to_string()))?;
+        let (name, default) = match reference[..end].split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (&reference[..end], None),
+        };
+        if !is_variable_name(name) {
+            return Err(GatewayError::Config(format!(
+                "Invalid environment variable name {:?} in a config value",
+                name
+            )));
+        }
+
+        match default {
+            Some(default) => match lookup(name) {
+                Some(value) if !value.is_empty() => output.push_str(&value),
+                _ => output.push_str(default),
+            },
+            None => output.push_str(&lookup_or_empty(name, lookup)),
+        }
+        rest = &reference[end + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn lookup_or_empty(name: &str, lookup: &impl Fn(&str) -> Option<String>) -> String {
+    lookup(name).unwrap_or_else(|| {
+        warn!(
+            "Environment variable {} referenced in the configuration is not set",
+            name
+        );
+        String::new()
+    })
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOST" => Some("db.internal".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(
+            interpolate("postgres://${HOST}:5432/gateway", &lookup).unwrap(),
+            "postgres://db.internal:5432/gateway"
+        );
+        assert_eq!(
+            interpolate("${MISSING:-fallback}/${EMPTY:-x}", &lookup).unwrap(),
+            "fallback/x"
+        );
+        assert_eq!(interpolate("a${MISSING}b", &lookup).unwrap(), "ab");
+        assert_eq!(interpolate("$${HOST} $5", &lookup).unwrap(), "${HOST} $5");
+        assert!(interpolate("${HOST", &lookup).is_err());
+        assert!(interpolate("${1HOST}", &lookup).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_value() {
+        let mut value: Value = serde_yaml::from_str(
+            r#"
+url: "redis://${HOST}"
+api_key: os.environ/HOST
+port: 6379
+models: ["${MISSING:-gpt-4o}"]
+"#,
+        )
+        .unwrap();
+        interpolate_value(&mut value, &lookup).unwrap();
+        assert_eq!(value["url"], "redis://db.internal");
+        assert_eq!(value["api_key"], "db.internal");
+        assert_eq!(value["port"], 6379);
+        assert_eq!(value["models"][0], "gpt-4o");
+    }
+
+    #[tokio::test]
+    async fn test_load_merges_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("shared")).unwrap();
+        std::fs::write(
+            dir.path().join("gateway.yaml"),
+            r#"
+include: shared/providers.yaml
+server:
+  port: 9000
+providers:
+  - name: local
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("shared/providers.yaml"),
+            r#"
+include: [defaults.yaml]
+providers:
+  - name: openai
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("shared/defaults.yaml"),
+            r#"
+server:
+  host: "127.0.0.1"
+  port: 8000
+"#,
+        )
+        .unwrap();
+
+        let value = load(&dir.path().join("gateway.yaml")).await.unwrap();
+        assert_eq!(value["server"]["host"], "127.0.0.1");
+        assert_eq!(value["server"]["port"], 9000);
+        assert_eq!(value["providers"][0]["name"], "local");
+        assert_eq!(value["providers"][1]["name"], "openai");
+        assert!(value.get(INCLUDE_KEY).is_none());
+
+        let location = ConfigLocation::File(dir.path().join("gateway.yaml"));
+        assert_eq!(
+            sources(&location).await.unwrap(),
+            vec![
+                location.clone(),
+                ConfigLocation::File(dir.path().join("shared/providers.yaml")),
+                ConfigLocation::File(dir.path().join("shared/defaults.yaml")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_detect() {
+        let detect = |path: &str, content: &str| Format::detect(Path::new(path), content);
+        assert_eq!(detect("gateway.yaml", ""), Format::Yaml);
+        assert_eq!(detect("gateway.JSON", ""), Format::Json);
+        assert_eq!(detect("gateway.toml", ""), Format::Toml);
+        assert_eq!(
+            detect("gateway.conf", "server:\n  port: 8080\n"),
+            Format::Yaml
+        );
+        assert_eq!(
+            detect("gateway.conf", r#"{"server": {"port": 8080}}"#),
+            Format::Yaml
+        );
+        assert_eq!(
+            detect("gateway.conf", "[server]\nport = 8080\n"),
+            Format::Toml
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_json_and_toml() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("gateway.toml"),
+            r#"
+include = ["providers.json"]
+
+[server]
+host = "${LITELLM_RS_TEST_UNSET:-127.0.0.1}"
+port = 9000
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("providers.json"),
+            r#"{"providers": [{"name": "openai", "api_key": "sk-test-key"}]}"#,
+        )
+        .unwrap();
+
+        let value = load(&dir.path().join("gateway.toml")).await.unwrap();
+        assert_eq!(value["server"]["host"], "127.0.0.1");
+        assert_eq!(value["server"]["port"], 9000);
+        assert_eq!(value["providers"][0]["name"], "openai");
+    }
+
+    #[test]
+    fn test_config_location() {
+        let s3 = ConfigLocation::parse("s3://config/prod/gateway.yaml").unwrap();
+        assert_eq!(
+            s3,
+            ConfigLocation::S3 {
+                bucket: "config".to_string(),
+                key: "prod/gateway.yaml".to_string()
+            }
+        );
+        assert_eq!(
+            s3.join("providers.yaml").unwrap().to_string(),
+            "s3://config/prod/providers.yaml"
+        );
+        assert!(ConfigLocation::parse("s3://config").is_err());
+        assert!(ConfigLocation::parse("s3:///gateway.yaml").is_err());
+
+        let url = ConfigLocation::parse("https://config.internal/gateway/prod.yaml").unwrap();
+        assert!(url.is_remote());
+        assert_eq!(
+            url.join("../shared.toml").unwrap().to_string(),
+            "https://config.internal/shared.toml"
+        );
+        assert_eq!(
+            url.join("s3://config/a.yaml").unwrap(),
+            ConfigLocation::parse("s3://config/a.yaml").unwrap()
+        );
+
+        // Plain HTTP is only accepted for this host
+        assert!(ConfigLocation::parse("http://config.internal/gateway.yaml").is_err());
+        assert!(ConfigLocation::parse("http://127.0.0.1:8080/gateway.yaml").is_ok());
+        assert!(ConfigLocation::parse("http://localhost/gateway.yaml").is_ok());
+        assert!(url.join("http://config.internal/shared.yaml").is_err());
+
+        let file = ConfigLocation::parse("config/gateway.yaml").unwrap();
+        assert!(!file.is_remote());
+        assert_eq!(
+            file.join("providers.yaml").unwrap(),
+            ConfigLocation::File(PathBuf::from("config/providers.yaml"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_remote_config_with_include() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/prod/gateway.yaml"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("include: providers.json\nserver:\n  port: 9000\n"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/prod/providers.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"providers": [{"name": "openai"}]}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let location =
+            ConfigLocation::parse(&format!("{}/prod/gateway.yaml", server.uri())).unwrap();
+        let value = load_location(&location).await.unwrap();
+        assert_eq!(value["server"]["port"], 9000);
+        assert_eq!(value["providers"][0]["name"], "openai");
+    }
+
+    #[tokio::test]
+    async fn test_load_rejects_include_cycles() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.yaml"), "include: b.yaml\n").unwrap();
+        std::fs::write(dir.path().join("b.yaml"), "include: a.yaml\n").unwrap();
+
+        let err = load(&dir.path().join("a.yaml")).await.unwrap_err();
+        assert!(err.to_string().contains("included in a cycle"), "{}", err);
+    }
+}
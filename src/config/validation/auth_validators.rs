@@ -1,7 +1,7 @@
 //! Authentication configuration validators
 //!
 //! This module provides validation implementations for authentication-related
-//! configuration structures including AuthConfig and RbacConfig.
+//! configuration structures including AuthConfig, RbacConfig and OidcConfig.
 
 use super::trait_def::Validate;
 use crate::config::models::*;
@@ -38,6 +38,10 @@ impl Validate for AuthConfig {
 
         self.rbac.validate()?;
 
+        if let Some(oidc) = &self.oidc {
+            oidc.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -51,3 +55,21 @@ impl Validate for RbacConfig {
         Ok(())
     }
 }
+
+impl Validate for OidcConfig {
+    fn validate(&self) -> Result<(), String> {
+        if !self.jwks_url.starts_with("https://") && !self.jwks_url.starts_with("http://") {
+            return Err(format!("Invalid OIDC JWKS URL: {}", self.jwks_url));
+        }
+
+        if self.user_claim.is_empty() {
+            return Err("OIDC user claim cannot be empty".to_string());
+        }
+
+        if self.jwks_cache_ttl == 0 {
+            return Err("OIDC JWKS cache TTL must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -22,6 +22,10 @@ impl Validate for CacheConfig {
             return Err("Semantic cache similarity threshold must be between 0 and 1".to_string());
         }
 
+        if self.semantic_cache && self.embedding_model.is_empty() {
+            return Err("Semantic cache embedding model cannot be empty".to_string());
+        }
+
         Ok(())
     }
 }
@@ -36,6 +40,28 @@ impl Validate for RateLimitConfig {
             return Err("Default TPM must be greater than 0".to_string());
         }
 
+        for rule in &self.limits {
+            rule.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for RateLimitRule {
+    fn validate(&self) -> Result<(), String> {
+        if self.api_key.is_some() == self.model.is_some() {
+            return Err("Rate limit must set exactly one of api_key and model".to_string());
+        }
+
+        if self.rpm.is_none() && self.tpm.is_none() {
+            return Err("Rate limit must set rpm or tpm".to_string());
+        }
+
+        if self.rpm == Some(0) || self.tpm == Some(0) {
+            return Err("Rate limit rpm and tpm must be greater than 0".to_string());
+        }
+
         Ok(())
     }
 }
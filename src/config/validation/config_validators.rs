@@ -6,6 +6,8 @@
 use super::ssrf::validate_url_against_ssrf;
 use super::trait_def::Validate;
 use crate::config::models::*;
+use crate::config::secrets::SecretReference;
+use crate::core::providers::ProviderType;
 use std::collections::HashSet;
 use tracing::debug;
 
@@ -30,18 +32,47 @@ impl Validate for GatewayConfig {
         }
 
         self.router.validate()?;
+        self.validate_traffic_splits()?;
         self.storage.validate()?;
         self.auth.validate()?;
         self.monitoring.validate()?;
         self.cache.validate()?;
         self.rate_limit.validate()?;
         self.enterprise.validate()?;
+        self.secrets.validate()?;
 
         debug!("Gateway configuration validation completed");
         Ok(())
     }
 }
 
+impl GatewayConfig {
+    /// Check that traffic splits only name models a provider serves
+    ///
+    /// Providers without a model list serve whatever models they list at
+    /// startup, so splits are only checked when every provider has one.
+    pub(crate) fn validate_traffic_splits(&self) -> Result<(), String> {
+        let providers: Vec<_> = self.providers.iter().filter(|p| p.enabled).collect();
+        if providers.iter().any(|provider| provider.models.is_empty()) {
+            return Ok(());
+        }
+        let models: HashSet<&String> = providers
+            .iter()
+            .flat_map(|provider| &provider.models)
+            .chain(self.router.model_aliases.keys())
+            .collect();
+        for (model, splits) in &self.router.traffic_splits {
+            if let Some(target) = splits.keys().find(|target| !models.contains(target)) {
+                return Err(format!(
+                    "Traffic split of model {} names model {}, which no provider serves",
+                    model, target
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Validate for ServerConfig {
     fn validate(&self) -> Result<(), String> {
         debug!("Validating server configuration");
@@ -122,17 +153,26 @@ impl Validate for ProviderConfig {
             "ollama",
             "custom",
         ];
-        if !supported_types.contains(&self.provider_type.as_str()) {
-            return Err(format!(
-                "Unsupported provider type: {}. Supported types: {:?}",
-                self.provider_type, supported_types
-            ));
+        let provider_type = ProviderType::from(self.provider_type.as_str());
+        if !supported_types.contains(&self.provider_type.as_str())
+            && matches!(provider_type, ProviderType::Custom(_))
+        {
+            return Err(format!("Unsupported provider type: {}", self.provider_type));
         }
 
-        if self.api_key.is_empty() {
+        if self.keys().is_empty() {
             return Err(format!("Provider {} API key cannot be empty", self.name));
         }
 
+        if self.api_keys.iter().any(|key| key.is_empty()) {
+            return Err(format!("Provider {} API keys cannot be empty", self.name));
+        }
+
+        for key in self.keys() {
+            SecretReference::parse(key)
+                .map_err(|e| format!("Provider {} API key: {}", self.name, e))?;
+        }
+
         if self.weight <= 0.0 {
             return Err(format!(
                 "Provider {} weight must be greater than 0",
@@ -185,3 +225,19 @@ impl Validate for ProviderConfig {
         Ok(())
     }
 }
+
+impl Validate for SecretsConfig {
+    fn validate(&self) -> Result<(), String> {
+        let endpoints = [
+            ("Vault address", &self.vault.address),
+            ("AWS Secrets Manager endpoint", &self.aws.endpoint),
+            ("Google Cloud Secret Manager endpoint", &self.gcp.endpoint),
+        ];
+        for (name, endpoint) in endpoints {
+            if let Some(endpoint) = endpoint {
+                url::Url::parse(endpoint).map_err(|e| format!("Invalid {}: {}", name, e))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This module provides validation implementations for monitoring-related
 //! configuration structures including MonitoringConfig, MetricsConfig,
-//! TracingConfig, and HealthConfig.
+//! TracingConfig, HealthConfig, and AlertingConfig.
 
 use super::trait_def::Validate;
 use crate::config::models::*;
@@ -15,6 +15,7 @@ impl Validate for MonitoringConfig {
         self.metrics.validate()?;
         self.tracing.validate()?;
         self.health.validate()?;
+        self.alerting.validate()?;
 
         Ok(())
     }
@@ -65,3 +66,37 @@ impl Validate for HealthConfig {
         Ok(())
     }
 }
+
+impl Validate for AlertingConfig {
+    fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.webhook_urls().next().is_none() {
+            return Err("Alerting requires a slack_webhook or at least one webhook".to_string());
+        }
+
+        if let Some(url) = self.webhook_urls().find(|url| !url.starts_with("http")) {
+            return Err(format!("Invalid alerting webhook URL: {}", url));
+        }
+
+        if self
+            .budget_thresholds
+            .iter()
+            .any(|threshold| !threshold.is_finite() || *threshold <= 0.0)
+        {
+            return Err("Budget alert thresholds must be positive".to_string());
+        }
+
+        if !(self.error_rate_threshold > 0.0 && self.error_rate_threshold <= 1.0) {
+            return Err("Error rate threshold must be between 0 and 1".to_string());
+        }
+
+        if self.error_rate_window == 0 {
+            return Err("Error rate window must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@
 
 use super::trait_def::Validate;
 use crate::config::models::*;
+use crate::core::router::config::RoutingStrategy;
 use tracing::debug;
 
 impl Validate for RouterConfig {
@@ -14,6 +15,28 @@ impl Validate for RouterConfig {
         self.circuit_breaker.validate()?;
         self.load_balancer.validate()?;
 
+        let fallbacks = self.fallbacks.iter().chain(&self.context_window_fallbacks);
+        for (model, fallbacks) in fallbacks.flatten() {
+            if fallbacks.contains(model) {
+                return Err(format!("Model {} cannot fall back on itself", model));
+            }
+        }
+
+        for (model, strategy) in &self.model_group_strategies {
+            strategy
+                .parse::<RoutingStrategy>()
+                .map_err(|e| format!("Model group {}: {}", model, e))?;
+        }
+
+        for (model, splits) in &self.traffic_splits {
+            if splits.values().all(|percent| *percent == 0) {
+                return Err(format!(
+                    "Traffic split of model {} must send traffic to a model",
+                    model
+                ));
+            }
+        }
+
         Ok(())
     }
 }
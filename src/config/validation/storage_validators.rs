@@ -1,7 +1,8 @@
 //! Storage configuration validators
 //!
 //! This module provides validation implementations for storage-related configuration
-//! structures including StorageConfig, DatabaseConfig, RedisConfig, and VectorDbConfig.
+//! structures including StorageConfig, DatabaseConfig, RedisConfig, VectorDbConfig,
+//! FileStorageConfig, S3Config, SpendConfig, RetentionConfig and EncryptionConfig.
 
 use super::trait_def::Validate;
 use crate::config::models::*;
@@ -18,6 +19,173 @@ impl Validate for StorageConfig {
             vector_db.validate()?;
         }
 
+        self.files.validate()?;
+        self.spend.validate()?;
+        self.retention.validate()?;
+        self.encryption.validate()?;
+
+        Ok(())
+    }
+}
+
+impl Validate for FileStorageConfig {
+    fn validate(&self) -> Result<(), String> {
+        match self.storage_type.as_str() {
+            "local" => {
+                if self.local_path.as_deref().is_none_or(str::is_empty) {
+                    return Err("Local file storage requires local_path".to_string());
+                }
+            }
+            "s3" => match &self.s3 {
+                Some(s3) => s3.validate()?,
+                None => return Err("S3 file storage requires an s3 section".to_string()),
+            },
+            other => {
+                return Err(format!(
+                    "Unsupported file storage type: {}. Supported types: [\"local\", \"s3\"]",
+                    other
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for S3Config {
+    fn validate(&self) -> Result<(), String> {
+        if self.bucket.is_empty() {
+            return Err("S3 bucket cannot be empty".to_string());
+        }
+
+        if self.region.is_empty() {
+            return Err("S3 region cannot be empty".to_string());
+        }
+
+        if self.access_key_id.is_empty() != self.secret_access_key.is_empty() {
+            return Err("S3 access_key_id and secret_access_key must be set together".to_string());
+        }
+
+        match self.server_side_encryption.as_deref() {
+            None | Some("AES256") => {
+                if self.sse_kms_key_id.is_some() {
+                    return Err("S3 sse_kms_key_id requires aws:kms encryption".to_string());
+                }
+            }
+            Some("aws:kms") => {}
+            Some(other) => {
+                return Err(format!(
+                    "Unsupported S3 server-side encryption: {}. Supported: AES256, aws:kms",
+                    other
+                ));
+            }
+        }
+
+        if self.presigned_url_ttl == 0 || self.presigned_url_ttl > 604_800 {
+            return Err("S3 presigned_url_ttl must be between 1 and 604800 seconds".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for SpendConfig {
+    fn validate(&self) -> Result<(), String> {
+        if !self.budgets.is_empty() && !self.enabled {
+            return Err("Budgets require spend tracking to be enabled".to_string());
+        }
+
+        for budget in &self.budgets {
+            budget.validate()?;
+            if self.retention_days > 0 && self.retention_days < budget.window.max_days() {
+                return Err(format!(
+                    "Spend retention_days must cover budget windows ({} days)",
+                    budget.window.max_days()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for RetentionConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.batch_size == 0 {
+            return Err("Retention batch_size must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for EncryptionConfig {
+    fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let mut ids = std::collections::HashSet::new();
+        for key in &self.keys {
+            key.validate()?;
+            if !ids.insert(key.id.as_str()) {
+                return Err(format!("Duplicate encryption key ID: {}", key.id));
+            }
+        }
+
+        if !ids.contains(self.active_key.as_str()) {
+            return Err(format!(
+                "Encryption active_key {:?} does not match any key ID",
+                self.active_key
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for EncryptionKeyConfig {
+    fn validate(&self) -> Result<(), String> {
+        let valid_id = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '.';
+        if self.id.is_empty() || !self.id.chars().all(valid_id) {
+            return Err(format!(
+                "Encryption key ID {:?} must be non-empty and contain only letters, digits, '-' and '.'",
+                self.id
+            ));
+        }
+
+        match (&self.key, &self.key_file, &self.kms) {
+            (Some(key), None, None) if key.len() < 32 => Err(format!(
+                "Encryption key {} must be at least 32 bytes",
+                self.id
+            )),
+            (None, None, Some(kms)) if kms.ciphertext.is_empty() => Err(format!(
+                "Encryption key {} must set the KMS ciphertext",
+                self.id
+            )),
+            (Some(_), None, None) | (None, Some(_), None) | (None, None, Some(_)) => Ok(()),
+            _ => Err(format!(
+                "Encryption key {} must set exactly one of key, key_file and kms",
+                self.id
+            )),
+        }
+    }
+}
+
+impl Validate for BudgetConfig {
+    fn validate(&self) -> Result<(), String> {
+        let targets = [&self.api_key, &self.team_id, &self.provider]
+            .iter()
+            .filter(|target| target.is_some())
+            .count();
+        if targets != 1 {
+            return Err("Budget must set exactly one of api_key, team_id and provider".to_string());
+        }
+
+        if !self.max_budget.is_finite() || self.max_budget < 0.0 {
+            return Err("Budget max_budget must be a non-negative number".to_string());
+        }
+
         Ok(())
     }
 }
@@ -28,8 +196,11 @@ impl Validate for DatabaseConfig {
             return Err("Database URL cannot be empty".to_string());
         }
 
-        if !self.url.starts_with("postgresql://") && !self.url.starts_with("postgres://") {
-            return Err("Only PostgreSQL databases are supported".to_string());
+        if !self.url.starts_with("postgresql://")
+            && !self.url.starts_with("postgres://")
+            && !self.is_sqlite()
+        {
+            return Err("Only PostgreSQL and SQLite databases are supported".to_string());
         }
 
         if self.max_connections == 0 {
@@ -66,6 +237,28 @@ impl Validate for RedisConfig {
             return Err("Redis connection timeout must be greater than 0".to_string());
         }
 
+        let is_redis_url =
+            |url: &String| url.starts_with("redis://") || url.starts_with("rediss://");
+        if !self.nodes.iter().all(is_redis_url) {
+            return Err("Redis nodes must start with redis:// or rediss://".to_string());
+        }
+
+        if let Some(sentinel) = &self.sentinel {
+            if self.cluster {
+                return Err("Redis cluster and sentinel modes are mutually exclusive".to_string());
+            }
+            if sentinel.master_name.is_empty() {
+                return Err("Redis sentinel master_name cannot be empty".to_string());
+            }
+            if sentinel.nodes.is_empty() || !sentinel.nodes.iter().all(is_redis_url) {
+                return Err("Redis sentinel nodes must be redis:// or rediss:// URLs".to_string());
+            }
+        }
+
+        if self.read_from_replicas && !self.cluster {
+            return Err("Redis read_from_replicas requires cluster mode".to_string());
+        }
+
         Ok(())
     }
 }
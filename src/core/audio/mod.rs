@@ -21,8 +21,8 @@ use translation::TranslationService;
 
 // Import types for AudioService method signatures
 use types::{
-    SpeechRequest, SpeechResponse, TranscriptionRequest, TranscriptionResponse, TranslationRequest,
-    TranslationResponse,
+    SpeechRequest, SpeechResponse, SpeechStreamResponse, TranscriptionRequest,
+    TranscriptionResponse, TranslationRequest, TranslationResponse,
 };
 
 /// Audio service for handling audio API requests
@@ -56,4 +56,9 @@ impl AudioService {
     pub async fn speech(&self, request: SpeechRequest) -> Result<SpeechResponse> {
         self.speech_service.speech(request).await
     }
+
+    /// Convert text to speech, streaming the audio as it is generated
+    pub async fn speech_stream(&self, request: SpeechRequest) -> Result<SpeechStreamResponse> {
+        self.speech_service.speech_stream(request).await
+    }
 }
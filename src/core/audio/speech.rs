@@ -1,12 +1,15 @@
 //! Text-to-speech functionality
 
 use crate::core::providers::{Provider, ProviderRegistry};
+use crate::core::types::RequestContext;
 use crate::utils::error::{GatewayError, Result};
+use bytes::Bytes;
+use futures::StreamExt;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{debug, info};
 
 use super::transcription::parse_model_string;
-use super::types::{SpeechRequest, SpeechResponse};
+use super::types::{SpeechRequest, SpeechResponse, SpeechStreamResponse, format_to_content_type};
 
 /// Audio service for handling text-to-speech requests
 pub struct SpeechService {
@@ -21,6 +24,57 @@ impl SpeechService {
 
     /// Convert text to speech
     pub async fn speech(&self, request: SpeechRequest) -> Result<SpeechResponse> {
+        let (provider, request) = self.resolve(request)?;
+
+        match provider {
+            Provider::OpenAI(_) | Provider::Azure(_) | Provider::MiniMax(_) => {
+                debug!("Using {} for speech", provider.name());
+                Ok(provider
+                    .create_speech(request, RequestContext::new())
+                    .await?)
+            }
+            _ => Err(GatewayError::internal(format!(
+                "Provider {} does not support text-to-speech",
+                provider.name()
+            ))),
+        }
+    }
+
+    /// Convert text to speech, streaming audio chunks where the provider can
+    ///
+    /// Providers without a streaming endpoint return the whole file as a
+    /// single chunk.
+    pub async fn speech_stream(&self, request: SpeechRequest) -> Result<SpeechStreamResponse> {
+        let (provider, request) = self.resolve(request)?;
+
+        match provider {
+            Provider::OpenAI(_) | Provider::Azure(_) => {
+                debug!("Streaming speech from {}", provider.name());
+                let content_type =
+                    format_to_content_type(request.response_format.as_deref().unwrap_or("mp3"))
+                        .to_string();
+                let stream = provider
+                    .create_speech_stream(request, RequestContext::new())
+                    .await?
+                    .map(|chunk| chunk.map_err(GatewayError::from));
+                Ok(SpeechStreamResponse {
+                    stream: Box::pin(stream),
+                    content_type,
+                })
+            }
+            _ => {
+                let response = self.speech(request).await?;
+                let audio = Bytes::from(response.audio);
+                Ok(SpeechStreamResponse {
+                    stream: Box::pin(futures::stream::once(async move { Ok(audio) })),
+                    content_type: response.content_type,
+                })
+            }
+        }
+    }
+
+    /// Validate the request and find the provider named by its model
+    fn resolve(&self, request: SpeechRequest) -> Result<(&Provider, SpeechRequest)> {
         info!(
             "Generating speech: model={}, voice={}, text_len={}",
             request.model,
@@ -35,11 +89,13 @@ impl SpeechService {
             ));
         }
 
-        let (provider_name, _actual_model) = parse_model_string(&request.model);
+        let (provider_name, actual_model) = parse_model_string(&request.model);
+        let actual_model = actual_model.to_string();
 
-        let providers = self.provider_registry.all();
-        let provider = providers
-            .iter()
+        let provider = self
+            .provider_registry
+            .all()
+            .into_iter()
             .find(|p| p.name() == provider_name)
             .ok_or_else(|| {
                 GatewayError::internal(format!(
@@ -48,17 +104,10 @@ impl SpeechService {
                 ))
             })?;
 
-        match provider {
-            Provider::OpenAI(_openai) => {
-                // OpenAI TTS implementation would go here
-                Err(GatewayError::internal(
-                    "OpenAI text-to-speech not yet implemented",
-                ))
-            }
-            _ => Err(GatewayError::internal(format!(
-                "Provider {} does not support text-to-speech",
-                provider.name()
-            ))),
-        }
+        let request = SpeechRequest {
+            model: actual_model,
+            ..request
+        };
+        Ok((provider, request))
     }
 }
@@ -2,8 +2,11 @@
 
 #[cfg(test)]
 mod tests {
+    use super::super::AudioService;
     use super::super::transcription::parse_model_string;
-    use super::super::types::{format_to_content_type, supported_audio_formats};
+    use super::super::types::{SpeechRequest, format_to_content_type, supported_audio_formats};
+    use crate::core::providers::ProviderRegistry;
+    use std::sync::Arc;
 
     #[test]
     fn test_parse_model_string() {
@@ -36,4 +39,17 @@ mod tests {
         assert!(formats.contains(&"wav"));
         assert!(formats.contains(&"webm"));
     }
+
+    #[tokio::test]
+    async fn test_speech_stream_requires_provider() {
+        let service = AudioService::new(Arc::new(ProviderRegistry::new()));
+        let request = SpeechRequest {
+            input: "Hello".to_string(),
+            model: "openai/tts-1".to_string(),
+            voice: "alloy".to_string(),
+            ..Default::default()
+        };
+        let result = service.speech_stream(request).await;
+        assert!(result.is_err());
+    }
 }
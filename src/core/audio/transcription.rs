@@ -1,6 +1,7 @@
 //! Audio transcription functionality
 
 use crate::core::providers::{Provider, ProviderRegistry};
+use crate::core::types::{AudioTranscriptionRequest, RequestContext};
 use crate::utils::error::{GatewayError, Result};
 use std::sync::Arc;
 use tracing::{debug, info};
@@ -89,11 +90,57 @@ impl TranscriptionService {
                     }),
                 })
             }
-            Provider::OpenAI(_openai) => {
-                // OpenAI transcription - similar implementation
-                Err(GatewayError::internal(
-                    "OpenAI audio transcription not yet implemented",
-                ))
+            Provider::OpenAI(_) | Provider::Azure(_) => {
+                debug!("Using {} for transcription", provider.name());
+                let response = provider
+                    .create_transcription(
+                        AudioTranscriptionRequest {
+                            file: request.file,
+                            filename: Some(request.filename),
+                            model: actual_model.to_string(),
+                            language: request.language,
+                            prompt: request.prompt,
+                            response_format: request.response_format,
+                            temperature: request.temperature,
+                            timestamp_granularities: request.timestamp_granularities,
+                        },
+                        RequestContext::new(),
+                    )
+                    .await
+                    .map_err(|e| {
+                        GatewayError::internal(format!(
+                            "{} transcription error: {}",
+                            provider.name(),
+                            e
+                        ))
+                    })?;
+
+                Ok(TranscriptionResponse {
+                    text: response.text,
+                    task: response.task,
+                    language: response.language,
+                    duration: response.duration,
+                    words: response.words.map(|words| {
+                        words
+                            .into_iter()
+                            .map(|w| WordInfo {
+                                word: w.word,
+                                start: w.start,
+                                end: w.end,
+                            })
+                            .collect()
+                    }),
+                    segments: response.segments.map(|segs| {
+                        segs.into_iter()
+                            .map(|s| SegmentInfo {
+                                id: s.id,
+                                start: s.start,
+                                end: s.end,
+                                text: s.text,
+                            })
+                            .collect()
+                    }),
+                })
             }
             _ => Err(GatewayError::internal(format!(
                 "Provider {} does not support audio transcription",
@@ -2,7 +2,12 @@
 //!
 //! Provides unified audio types for speech-to-text and text-to-speech operations.
 
+use bytes::Bytes;
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::utils::error::Result;
 
 /// Audio transcription request (OpenAI compatible)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -141,7 +146,7 @@ pub struct TranslationResponse {
 }
 
 /// Text-to-speech request (OpenAI compatible)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SpeechRequest {
     /// Text to convert to speech
     pub input: String,
@@ -159,6 +164,10 @@ pub struct SpeechRequest {
     /// Speed of speech (0.25 to 4.0)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f32>,
+
+    /// Tone and delivery instructions (gpt-4o-mini-tts only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
 }
 
 /// Text-to-speech response
@@ -170,6 +179,15 @@ pub struct SpeechResponse {
     pub content_type: String,
 }
 
+/// Text-to-speech response delivered as audio chunks while they are generated
+pub struct SpeechStreamResponse {
+    /// Audio data chunks
+    pub stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+
+    /// Content type (e.g., "audio/mpeg", "audio/opus")
+    pub content_type: String,
+}
+
 /// Supported audio formats
 pub fn supported_audio_formats() -> &'static [&'static str] {
     &[
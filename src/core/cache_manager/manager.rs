@@ -52,6 +52,7 @@ impl CacheManager {
     }
 
     /// Get a cached response
+    #[tracing::instrument(name = "cache.get", skip_all)]
     pub async fn get(&self, key: &CacheKey) -> Result<Option<ChatCompletionResponse>> {
         // Try L1 cache first
         {
@@ -103,6 +104,7 @@ impl CacheManager {
     }
 
     /// Store a response in the cache
+    #[tracing::instrument(name = "cache.put", skip_all)]
     pub async fn put(&self, key: CacheKey, response: ChatCompletionResponse) -> Result<()> {
         let size_bytes = self.estimate_size(&response);
         let entry = CacheEntry::new(response, self.config.default_ttl, size_bytes);
@@ -41,6 +41,7 @@ mod tests {
             choices: vec![],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         cache.put(key.clone(), response.clone()).await?;
@@ -1,6 +1,11 @@
 //! Type conversion functions
 
+use super::helpers::user_message;
 use super::types::{Choice, CompletionOptions, CompletionResponse};
+use crate::core::types::requests::CompletionRequest as TextCompletionRequest;
+use crate::core::types::responses::{
+    CompletionChoice as TextCompletionChoice, CompletionResponse as TextCompletionResponse,
+};
 use crate::core::types::{ChatMessage, ChatRequest, ChatResponse, Usage};
 use crate::utils::error::Result;
 
@@ -21,10 +26,10 @@ pub fn convert_to_chat_completion_request(
         presence_penalty: options.presence_penalty,
         stop: options.stop,
         stream: options.stream,
-        tools: None,
-        tool_choice: None,
+        tools: options.tools,
+        tool_choice: options.tool_choice,
         parallel_tool_calls: None,
-        response_format: None,
+        response_format: options.response_format,
         user: options.user,
         seed: options.seed,
         n: options.n,
@@ -57,9 +62,60 @@ pub fn convert_from_chat_completion_response(response: ChatResponse) -> Result<C
         model: response.model,
         choices,
         usage: response.usage,
+        provider_specific_fields: response.provider_specific_fields,
     })
 }
 
+/// Convert a text completion request to a chat request
+///
+/// The prompt becomes a single user message. Returns the model, messages and
+/// options to pass to `Router::complete`.
+pub fn convert_text_completion_to_chat(
+    request: TextCompletionRequest,
+) -> (String, Vec<ChatMessage>, CompletionOptions) {
+    let options = CompletionOptions {
+        temperature: request.temperature,
+        max_tokens: request.max_tokens,
+        top_p: request.top_p,
+        frequency_penalty: request.frequency_penalty,
+        presence_penalty: request.presence_penalty,
+        stop: request.stop,
+        user: request.user,
+        n: request.n,
+        ..Default::default()
+    };
+
+    (request.model, vec![user_message(request.prompt)], options)
+}
+
+/// Convert a chat completion response to a text completion
+pub fn convert_to_text_completion_response(response: CompletionResponse) -> TextCompletionResponse {
+    let choices = response
+        .choices
+        .into_iter()
+        .map(|choice| TextCompletionChoice {
+            index: choice.index,
+            text: choice
+                .message
+                .content
+                .map(|content| content.to_string())
+                .unwrap_or_default(),
+            finish_reason: choice.finish_reason,
+            logprobs: None,
+        })
+        .collect();
+
+    TextCompletionResponse {
+        id: response.id,
+        object: "text_completion".to_string(),
+        created: response.created,
+        model: response.model,
+        choices,
+        usage: response.usage,
+        system_fingerprint: None,
+    }
+}
+
 /// Convert from usage response
 pub fn convert_usage(usage: &crate::core::types::Usage) -> Usage {
     Usage {
@@ -69,5 +125,8 @@ pub fn convert_usage(usage: &crate::core::types::Usage) -> Usage {
         prompt_tokens_details: None,
         completion_tokens_details: None,
         thinking_usage: usage.thinking_usage.clone(),
+        cache_creation_input_tokens: None,
+        cache_read_input_tokens: None,
+        billable_characters: None,
     }
 }
@@ -4,8 +4,11 @@
 // implementation together while respecting the module structure.
 // Note: Types are imported via mod.rs's pub use statements before this include.
 
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
 use crate::core::providers::{Provider, ProviderRegistry, ProviderType};
-use crate::core::types::{ChatRequest, RequestContext};
+use crate::core::types::{
+    AudioTranscriptionRequest, AudioTranscriptionResponse, ChatRequest, RequestContext,
+};
 use crate::utils::error::{GatewayError, Result};
 use async_trait::async_trait;
 use futures::stream::StreamExt;
@@ -82,6 +85,18 @@ impl DefaultRouter {
             }
         }
 
+        // Add Azure OpenAI provider if an endpoint and API key are available
+        if (std::env::var("AZURE_OPENAI_ENDPOINT").is_ok()
+            || std::env::var("AZURE_ENDPOINT").is_ok())
+            && (std::env::var("AZURE_OPENAI_KEY").is_ok() || std::env::var("AZURE_API_KEY").is_ok())
+        {
+            use crate::core::providers::azure::{AzureConfig, AzureOpenAIProvider};
+
+            if let Ok(azure_provider) = AzureOpenAIProvider::new(AzureConfig::new()) {
+                provider_registry.register(Provider::Azure(azure_provider));
+            }
+        }
+
         // Add OpenRouter provider if API key is available
         if let Ok(api_key) = std::env::var("OPENROUTER_API_KEY") {
             use crate::core::providers::openrouter::{OpenRouterConfig, OpenRouterProvider};
@@ -164,6 +179,142 @@ impl DefaultRouter {
             }
         }
 
+        // Add Together AI provider if API key is available
+        if std::env::var("TOGETHER_AI_API_KEY").is_ok() || std::env::var("TOGETHER_API_KEY").is_ok()
+        {
+            use crate::core::providers::together_ai::{TogetherAIConfig, TogetherAIProvider};
+
+            if let Ok(together_provider) = TogetherAIProvider::new(TogetherAIConfig::from_env()) {
+                provider_registry.register(Provider::TogetherAI(together_provider));
+            }
+        }
+
+        // Add Perplexity provider if API key is available
+        if std::env::var("PERPLEXITY_API_KEY").is_ok() {
+            use crate::core::providers::perplexity::{PerplexityConfig, PerplexityProvider};
+
+            if let Ok(provider) = PerplexityProvider::new(PerplexityConfig::from_env()) {
+                provider_registry.register(Provider::Perplexity(provider));
+            }
+        }
+
+        // Add Hugging Face provider if a token or dedicated endpoint is configured
+        if std::env::var("HUGGINGFACE_API_KEY").is_ok()
+            || std::env::var("HF_TOKEN").is_ok()
+            || std::env::var("HUGGINGFACE_API_BASE").is_ok()
+        {
+            use crate::core::providers::huggingface::{HuggingFaceConfig, HuggingFaceProvider};
+
+            if let Ok(provider) = HuggingFaceProvider::new(HuggingFaceConfig::from_env()) {
+                provider_registry.register(Provider::HuggingFace(provider));
+            }
+        }
+
+        // Add Cohere provider if API key is available
+        if std::env::var("COHERE_API_KEY").is_ok() {
+            use crate::core::providers::cohere::{CohereConfig, CohereProvider};
+
+            if let Ok(cohere_provider) = CohereProvider::new(CohereConfig::from_env()) {
+                provider_registry.register(Provider::Cohere(cohere_provider));
+            }
+        }
+
+        // Add Replicate provider if API key is available
+        if std::env::var("REPLICATE_API_KEY").is_ok()
+            || std::env::var("REPLICATE_API_TOKEN").is_ok()
+        {
+            use crate::core::providers::replicate::{ReplicateConfig, ReplicateProvider};
+
+            if let Ok(provider) = ReplicateProvider::new(ReplicateConfig::from_env()) {
+                provider_registry.register(Provider::Replicate(provider));
+            }
+        }
+
+        // Add Nvidia NIM provider if an API key or self-hosted endpoint is configured
+        if std::env::var("NVIDIA_NIM_API_KEY").is_ok()
+            || std::env::var("NVIDIA_API_KEY").is_ok()
+            || std::env::var("NVIDIA_NIM_API_BASE").is_ok()
+        {
+            use crate::core::providers::nvidia_nim::{NvidiaNimConfig, NvidiaNimProvider};
+
+            if let Ok(provider) = NvidiaNimProvider::new(NvidiaNimConfig::from_env()) {
+                provider_registry.register(Provider::NvidiaNim(provider));
+            }
+        }
+
+        // Add Cerebras provider if API key is available
+        if std::env::var("CEREBRAS_API_KEY").is_ok() {
+            use crate::core::providers::cerebras::{CerebrasConfig, CerebrasProvider};
+
+            if let Ok(provider) = CerebrasProvider::new(CerebrasConfig::from_env()) {
+                provider_registry.register(Provider::Cerebras(provider));
+            }
+        }
+
+        // Add SambaNova provider if API key is available
+        if std::env::var("SAMBANOVA_API_KEY").is_ok() {
+            use crate::core::providers::sambanova::{SambaNovaConfig, SambaNovaProvider};
+
+            if let Ok(provider) = SambaNovaProvider::new(SambaNovaConfig::from_env()) {
+                provider_registry.register(Provider::SambaNova(provider));
+            }
+        }
+
+        // Add DashScope provider if API key is available
+        if std::env::var("DASHSCOPE_API_KEY").is_ok() {
+            use crate::core::providers::dashscope::{DashScopeConfig, DashScopeProvider};
+
+            if let Ok(provider) = DashScopeProvider::new(DashScopeConfig::from_env()) {
+                provider_registry.register(Provider::DashScope(provider));
+            }
+        }
+
+        // Add Zhipu provider if API key is available
+        if std::env::var("ZHIPU_API_KEY").is_ok() || std::env::var("ZHIPUAI_API_KEY").is_ok() {
+            use crate::core::providers::zhipu::{ZhipuConfig, ZhipuProvider};
+
+            if let Ok(provider) = ZhipuProvider::new(ZhipuConfig::from_env()) {
+                provider_registry.register(Provider::Zhipu(provider));
+            }
+        }
+
+        // Add MiniMax provider if API key is available
+        if std::env::var("MINIMAX_API_KEY").is_ok() {
+            use crate::core::providers::minimax::{MiniMaxConfig, MiniMaxProvider};
+
+            if let Ok(provider) = MiniMaxProvider::new(MiniMaxConfig::from_env()) {
+                provider_registry.register(Provider::MiniMax(provider));
+            }
+        }
+
+        // Add a generic OpenAI-compatible endpoint (vLLM, LM Studio, ...) if configured
+        if std::env::var("CUSTOM_OPENAI_API_BASE").is_ok() {
+            use crate::core::providers::custom_openai::{CustomOpenAIConfig, CustomOpenAIProvider};
+
+            let config = CustomOpenAIConfig::from_env();
+            let discover = config.models.is_empty();
+            if let Ok(mut provider) = CustomOpenAIProvider::new(config) {
+                if discover {
+                    if let Err(e) = provider.refresh_models().await {
+                        debug!("custom_openai model discovery failed: {}", e);
+                    }
+                }
+                provider_registry.register(Provider::CustomOpenAI(provider));
+            }
+        }
+
+        // Add Ollama provider if a local daemon address is configured
+        if std::env::var("OLLAMA_API_BASE").is_ok() {
+            use crate::core::providers::ollama::{OllamaConfig, OllamaProvider};
+
+            if let Ok(mut ollama_provider) = OllamaProvider::new(OllamaConfig::default()) {
+                if let Err(e) = ollama_provider.refresh_models().await {
+                    debug!("Ollama model discovery failed: {}", e);
+                }
+                provider_registry.register(Provider::Ollama(ollama_provider));
+            }
+        }
+
         Ok(Self {
             provider_registry: Arc::new(provider_registry),
         })
@@ -206,6 +357,33 @@ impl Router for ErrorRouter {
             self.error
         )))
     }
+
+    async fn text_complete(
+        &self,
+        _request: TextCompletionRequest,
+    ) -> Result<TextCompletionResponse> {
+        Err(GatewayError::internal(format!(
+            "Router initialization failed: {}",
+            self.error
+        )))
+    }
+
+    async fn transcribe(
+        &self,
+        _request: AudioTranscriptionRequest,
+    ) -> Result<AudioTranscriptionResponse> {
+        Err(GatewayError::internal(format!(
+            "Router initialization failed: {}",
+            self.error
+        )))
+    }
+
+    async fn speech(&self, _request: SpeechRequest) -> Result<SpeechResponse> {
+        Err(GatewayError::internal(format!(
+            "Router initialization failed: {}",
+            self.error
+        )))
+    }
 }
 
 /// Global router instance
@@ -258,27 +436,32 @@ pub async fn completion_stream(
         .await
 }
 
-/// Convert ChatChunk (from provider) to CompletionChunk (for streaming API)
-fn convert_chat_chunk_to_completion_chunk(
-    chunk: crate::core::types::ChatChunk,
-) -> stream::CompletionChunk {
-    stream::CompletionChunk {
-        id: chunk.id,
-        object: chunk.object,
-        created: chunk.created,
-        model: chunk.model,
-        choices: chunk
-            .choices
-            .into_iter()
-            .map(|c| stream::StreamChoice {
-                index: c.index,
-                delta: stream::StreamDelta {
-                    role: c.delta.role.map(|r| r.to_string()),
-                    content: c.delta.content,
-                    tool_calls: None,
-                },
-                finish_reason: c.finish_reason,
-            })
-            .collect(),
-    }
+/// Text completion function (legacy prompt-based completions)
+///
+/// Models with a native completions endpoint (`gpt-3.5-turbo-instruct`) are
+/// passed through; chat-only models receive the prompt as a user message.
+pub async fn text_completion(request: TextCompletionRequest) -> Result<TextCompletionResponse> {
+    let router = get_global_router().await;
+    router.text_complete(request).await
+}
+
+/// Audio transcription function
+///
+/// `model` may carry a provider prefix (`azure/<deployment>`); bare model
+/// names such as `whisper-1` are sent to OpenAI.
+pub async fn transcription(
+    request: AudioTranscriptionRequest,
+) -> Result<AudioTranscriptionResponse> {
+    let router = get_global_router().await;
+    router.transcribe(request).await
+}
+
+/// Text-to-speech function
+///
+/// `model` may carry a provider prefix (`azure/<deployment>`,
+/// `minimax/speech-02-hd`); bare model names such as `tts-1` are sent to
+/// OpenAI.
+pub async fn speech(request: SpeechRequest) -> Result<SpeechResponse> {
+    let router = get_global_router().await;
+    router.speech(request).await
 }
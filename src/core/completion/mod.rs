@@ -22,21 +22,33 @@ mod conversion;
 mod helpers;
 mod router_trait;
 mod stream;
+mod structured_output;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export main types
-pub use conversion::{convert_from_chat_completion_response, convert_to_chat_completion_request};
+pub use conversion::{
+    convert_from_chat_completion_response, convert_text_completion_to_chat,
+    convert_to_chat_completion_request, convert_to_text_completion_response,
+};
 pub use helpers::{
     assistant_message, convert_messages_to_chat_messages, system_message, user_message,
 };
 pub use router_trait::{Message, Router};
-pub use stream::{CompletionChunk, CompletionStream, StreamChoice, StreamDelta};
+pub use stream::{
+    CompletionChunk, CompletionStream, StreamChoice, StreamDelta,
+    convert_chat_chunk_to_completion_chunk,
+};
+pub use structured_output::{
+    DEFAULT_JSON_SCHEMA_RETRIES, SchemaValidationError, complete_with_schema, validate_response,
+};
 pub use types::{Choice, CompletionOptions, CompletionResponse, FunctionCall, ToolCall};
 
 // Re-export types with proper paths
+pub use crate::core::types::requests::CompletionRequest as TextCompletionRequest;
+pub use crate::core::types::responses::CompletionResponse as TextCompletionResponse;
 pub use crate::core::types::{ContentPart, MessageContent, MessageRole};
 
 /// LiteLLM Error type alias
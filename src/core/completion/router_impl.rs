@@ -4,6 +4,7 @@
 
 #[async_trait]
 impl Router for DefaultRouter {
+    #[tracing::instrument(name = "router.complete", skip_all, fields(model = %model))]
     async fn complete(
         &self,
         model: &str,
@@ -15,6 +16,286 @@ impl Router for DefaultRouter {
         let chat_request =
             convert_to_chat_completion_request(model, chat_messages, options.clone())?;
 
+        self.route_validated_completion(model, chat_request, options).await
+    }
+
+    #[tracing::instrument(name = "router.complete_stream", skip_all, fields(model = %model))]
+    async fn complete_stream(
+        &self,
+        model: &str,
+        messages: Vec<Message>,
+        options: CompletionOptions,
+    ) -> Result<CompletionStream> {
+        // Convert to internal types
+        let chat_messages = convert_messages_to_chat_messages(messages);
+        let mut chat_request =
+            convert_to_chat_completion_request(model, chat_messages, options.clone())?;
+        chat_request.stream = true;
+
+        // Create request context
+        let context = RequestContext::new();
+
+        // Find provider
+        let providers = self.provider_registry.all();
+
+        // Check if model explicitly specifies a provider
+        let selected_provider = Self::select_provider_by_name(
+            &providers,
+            "openrouter",
+            model,
+            "openrouter/",
+            &chat_request,
+        )
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "deepseek", model, "deepseek/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "anthropic",
+                model,
+                "anthropic/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "azure_ai", model, "azure_ai/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "groq", model, "groq/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "ollama", model, "ollama/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "together_ai",
+                model,
+                "together_ai/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "cohere", model, "cohere/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "perplexity",
+                model,
+                "perplexity/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "huggingface",
+                model,
+                "huggingface/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "replicate",
+                model,
+                "replicate/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "nvidia_nim",
+                model,
+                "nvidia_nim/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "cerebras", model, "cerebras/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "sambanova",
+                model,
+                "sambanova/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "dashscope",
+                model,
+                "dashscope/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "zhipu", model, "zhipu/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "zhipu", model, "glm/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "minimax", model, "minimax/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "custom_openai",
+                model,
+                "custom_openai/",
+                &chat_request,
+            )
+        });
+
+        // Get the provider and execute streaming
+        if let Some((provider, request)) = selected_provider {
+            let stream = provider
+                .chat_completion_stream(request, context)
+                .await
+                .map_err(|e| GatewayError::internal(format!("Streaming error: {}", e)))?;
+
+            // Convert ChatChunk stream to ChatCompletionChunk stream
+            let converted_stream = stream.map(|result| {
+                result
+                    .map(convert_chat_chunk_to_completion_chunk)
+                    .map_err(|e| GatewayError::internal(format!("Stream chunk error: {}", e)))
+            });
+
+            return Ok(Box::pin(converted_stream));
+        }
+
+        Err(GatewayError::internal(
+            "No suitable provider found for streaming",
+        ))
+    }
+
+    async fn text_complete(
+        &self,
+        mut request: TextCompletionRequest,
+    ) -> Result<TextCompletionResponse> {
+        // Models with a native completions endpoint (gpt-3.5-turbo-instruct)
+        // skip the chat translation; unprefixed models belong to OpenAI
+        let (provider_name, actual_model) = match request.model.split_once('/') {
+            Some((provider, model)) => (provider.to_string(), model.to_string()),
+            None => ("openai".to_string(), request.model.clone()),
+        };
+
+        let providers = self.provider_registry.all();
+        if let Some(provider) = providers
+            .iter()
+            .find(|p| p.name() == provider_name && p.supports_text_completion(&actual_model))
+        {
+            debug!(
+                provider = %provider_name,
+                model = %actual_model,
+                "Routing native text completion"
+            );
+
+            request.model = actual_model;
+            let response = provider.text_completion(request).await?;
+            return Ok(response);
+        }
+
+        let (model, messages, options) = convert_text_completion_to_chat(request);
+        let response = self.complete(&model, messages, options).await?;
+        Ok(convert_to_text_completion_response(response))
+    }
+
+    async fn transcribe(
+        &self,
+        mut request: AudioTranscriptionRequest,
+    ) -> Result<AudioTranscriptionResponse> {
+        // Unprefixed models (whisper-1, gpt-4o-transcribe) go to OpenAI
+        let (provider_name, actual_model) = match request.model.split_once('/') {
+            Some((provider, model)) => (provider.to_string(), model.to_string()),
+            None => ("openai".to_string(), request.model.clone()),
+        };
+
+        let providers = self.provider_registry.all();
+        let provider = providers
+            .iter()
+            .find(|p| p.name() == provider_name)
+            .ok_or_else(|| {
+                GatewayError::not_found(format!(
+                    "No provider configured for audio transcription: {}",
+                    provider_name
+                ))
+            })?;
+
+        debug!(
+            provider = %provider_name,
+            model = %actual_model,
+            "Routing audio transcription"
+        );
+
+        request.model = actual_model;
+        let response = provider
+            .create_transcription(request, RequestContext::new())
+            .await?;
+        Ok(response)
+    }
+
+    async fn speech(&self, mut request: SpeechRequest) -> Result<SpeechResponse> {
+        // Unprefixed models (tts-1, gpt-4o-mini-tts) go to OpenAI
+        let (provider_name, actual_model) = match request.model.split_once('/') {
+            Some((provider, model)) => (provider.to_string(), model.to_string()),
+            None => ("openai".to_string(), request.model.clone()),
+        };
+
+        let providers = self.provider_registry.all();
+        let provider = providers
+            .iter()
+            .find(|p| p.name() == provider_name)
+            .ok_or_else(|| {
+                GatewayError::not_found(format!(
+                    "No provider configured for text-to-speech: {}",
+                    provider_name
+                ))
+            })?;
+
+        debug!(
+            provider = %provider_name,
+            model = %actual_model,
+            "Routing text-to-speech"
+        );
+
+        request.model = actual_model;
+        let response = provider
+            .create_speech(request, RequestContext::new())
+            .await?;
+        Ok(response)
+    }
+}
+
+impl DefaultRouter {
+    /// Route a completion, retrying output that does not match its JSON schema
+    async fn route_validated_completion(
+        &self,
+        model: &str,
+        chat_request: ChatRequest,
+        options: CompletionOptions,
+    ) -> Result<CompletionResponse> {
+        complete_with_schema(chat_request, &options, |request| {
+            self.route_completion(model, request, options.clone())
+        })
+        .await
+    }
+
+    /// Route a converted request to a provider
+    async fn route_completion(
+        &self,
+        model: &str,
+        chat_request: ChatRequest,
+        options: CompletionOptions,
+    ) -> Result<CompletionResponse> {
         // Create request context with override parameters from options
         let mut context = RequestContext::new();
 
@@ -143,6 +424,96 @@ impl Router for DefaultRouter {
         })
         .or_else(|| {
             Self::select_provider_by_name(&providers, "groq", model, "groq/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "ollama", model, "ollama/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "together_ai",
+                model,
+                "together_ai/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "cohere", model, "cohere/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "perplexity",
+                model,
+                "perplexity/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "huggingface",
+                model,
+                "huggingface/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "replicate",
+                model,
+                "replicate/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "nvidia_nim",
+                model,
+                "nvidia_nim/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "cerebras", model, "cerebras/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "sambanova",
+                model,
+                "sambanova/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "dashscope",
+                model,
+                "dashscope/",
+                &chat_request,
+            )
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "zhipu", model, "zhipu/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "zhipu", model, "glm/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(&providers, "minimax", model, "minimax/", &chat_request)
+        })
+        .or_else(|| {
+            Self::select_provider_by_name(
+                &providers,
+                "custom_openai",
+                model,
+                "custom_openai/",
+                &chat_request,
+            )
         });
 
         // Handle special cases
@@ -177,71 +548,4 @@ impl Router for DefaultRouter {
             "No suitable provider found for model",
         ))
     }
-
-    async fn complete_stream(
-        &self,
-        model: &str,
-        messages: Vec<Message>,
-        options: CompletionOptions,
-    ) -> Result<CompletionStream> {
-        // Convert to internal types
-        let chat_messages = convert_messages_to_chat_messages(messages);
-        let mut chat_request =
-            convert_to_chat_completion_request(model, chat_messages, options.clone())?;
-        chat_request.stream = true;
-
-        // Create request context
-        let context = RequestContext::new();
-
-        // Find provider
-        let providers = self.provider_registry.all();
-
-        // Check if model explicitly specifies a provider
-        let selected_provider = Self::select_provider_by_name(
-            &providers,
-            "openrouter",
-            model,
-            "openrouter/",
-            &chat_request,
-        )
-        .or_else(|| {
-            Self::select_provider_by_name(&providers, "deepseek", model, "deepseek/", &chat_request)
-        })
-        .or_else(|| {
-            Self::select_provider_by_name(
-                &providers,
-                "anthropic",
-                model,
-                "anthropic/",
-                &chat_request,
-            )
-        })
-        .or_else(|| {
-            Self::select_provider_by_name(&providers, "azure_ai", model, "azure_ai/", &chat_request)
-        })
-        .or_else(|| {
-            Self::select_provider_by_name(&providers, "groq", model, "groq/", &chat_request)
-        });
-
-        // Get the provider and execute streaming
-        if let Some((provider, request)) = selected_provider {
-            let stream = provider
-                .chat_completion_stream(request, context)
-                .await
-                .map_err(|e| GatewayError::internal(format!("Streaming error: {}", e)))?;
-
-            // Convert ChatChunk stream to ChatCompletionChunk stream
-            let converted_stream = stream.map(|result| {
-                result
-                    .map(convert_chat_chunk_to_completion_chunk)
-                    .map_err(|e| GatewayError::internal(format!("Stream chunk error: {}", e)))
-            });
-
-            return Ok(Box::pin(converted_stream));
-        }
-
-        Err(GatewayError::internal(
-            "No suitable provider found for streaming",
-        ))
-    }
 }
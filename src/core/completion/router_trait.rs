@@ -1,9 +1,13 @@
 //! Router trait definition
 
+use super::conversion::{convert_text_completion_to_chat, convert_to_text_completion_response};
 use super::stream::CompletionStream;
 use super::types::{CompletionOptions, CompletionResponse};
-use crate::core::types::ChatMessage;
-use crate::utils::error::Result;
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
+use crate::core::types::requests::CompletionRequest as TextCompletionRequest;
+use crate::core::types::responses::CompletionResponse as TextCompletionResponse;
+use crate::core::types::{AudioTranscriptionRequest, AudioTranscriptionResponse, ChatMessage};
+use crate::utils::error::{GatewayError, Result};
 use async_trait::async_trait;
 
 /// Unified message format (OpenAI compatible)
@@ -27,4 +31,33 @@ pub trait Router: Send + Sync + 'static {
         messages: Vec<Message>,
         options: CompletionOptions,
     ) -> Result<CompletionStream>;
+
+    /// Complete a text prompt (legacy completions)
+    ///
+    /// The prompt is sent to `complete` as a single user message.
+    async fn text_complete(
+        &self,
+        request: TextCompletionRequest,
+    ) -> Result<TextCompletionResponse> {
+        let (model, messages, options) = convert_text_completion_to_chat(request);
+        let response = self.complete(&model, messages, options).await?;
+        Ok(convert_to_text_completion_response(response))
+    }
+
+    /// Transcribe audio to text
+    async fn transcribe(
+        &self,
+        _request: AudioTranscriptionRequest,
+    ) -> Result<AudioTranscriptionResponse> {
+        Err(GatewayError::not_implemented(
+            "Audio transcription is not supported by this router",
+        ))
+    }
+
+    /// Convert text to speech
+    async fn speech(&self, _request: SpeechRequest) -> Result<SpeechResponse> {
+        Err(GatewayError::not_implemented(
+            "Text-to-speech is not supported by this router",
+        ))
+    }
 }
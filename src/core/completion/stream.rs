@@ -1,8 +1,10 @@
 //! Completion streaming types
 
 use crate::core::streaming::types::ChatCompletionChunk;
+use crate::core::types::ChatChunk;
 use crate::core::types::FinishReason;
 use futures::stream::BoxStream;
+use std::collections::HashMap;
 
 /// Streaming completion response
 pub type CompletionStream =
@@ -16,6 +18,8 @@ pub struct CompletionChunk {
     pub created: i64,
     pub model: String,
     pub choices: Vec<StreamChoice>,
+    /// Provider-specific fields carried over from the chat chunk
+    pub provider_specific_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Choice in a streaming chunk
@@ -31,7 +35,31 @@ pub struct StreamChoice {
 pub struct StreamDelta {
     pub role: Option<String>,
     pub content: Option<String>,
-    pub tool_calls: Option<Vec<crate::core::completion::types::ToolCall>>,
+    pub tool_calls: Option<Vec<crate::core::types::responses::ToolCallDelta>>,
+}
+
+/// Convert ChatChunk (from provider) to CompletionChunk (for streaming API)
+pub fn convert_chat_chunk_to_completion_chunk(chunk: ChatChunk) -> CompletionChunk {
+    CompletionChunk {
+        id: chunk.id,
+        object: chunk.object,
+        created: chunk.created,
+        model: chunk.model,
+        choices: chunk
+            .choices
+            .into_iter()
+            .map(|c| StreamChoice {
+                index: c.index,
+                delta: StreamDelta {
+                    role: c.delta.role.map(|r| r.to_string()),
+                    content: c.delta.content,
+                    tool_calls: c.delta.tool_calls,
+                },
+                finish_reason: c.finish_reason,
+            })
+            .collect(),
+        provider_specific_fields: chunk.provider_specific_fields,
+    }
 }
 
 /// Convert internal stream chunk to completion chunk
@@ -49,11 +77,15 @@ pub fn convert_stream_chunk(chunk: ChatCompletionChunk) -> CompletionChunk {
                 delta: StreamDelta {
                     role: c.delta.role.map(|r| r.to_string()),
                     content: c.delta.content,
-                    tool_calls: None,
+                    tool_calls: c
+                        .delta
+                        .tool_calls
+                        .map(|calls| calls.into_iter().map(Into::into).collect()),
                 },
                 finish_reason: c.finish_reason.and_then(|s| parse_finish_reason(&s)),
             })
             .collect(),
+        provider_specific_fields: chunk.provider_specific_fields,
     }
 }
 
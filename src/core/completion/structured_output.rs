@@ -0,0 +1,268 @@
+//! Structured output validation
+//!
+//! When a request's `response_format` carries a JSON schema, completions are
+//! checked against it. Output that is not valid JSON or does not match the
+//! schema is sent back to the model with a corrective system message, up to
+//! [`CompletionOptions::json_schema_retries`] times, after which the call
+//! fails with a [`SchemaValidationError`]. Streamed completions are not
+//! validated.
+
+use super::helpers::{assistant_message, system_message};
+use super::types::{CompletionOptions, CompletionResponse};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::ChatRequest;
+use crate::utils::data::utils::DataUtils;
+use crate::utils::error::Result;
+use serde_json::Value;
+use std::future::Future;
+use tracing::warn;
+
+/// Retries of a completion that does not match its JSON schema, by default
+pub const DEFAULT_JSON_SCHEMA_RETRIES: u32 = 2;
+
+/// Completion that does not match the JSON schema of its request
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Response does not match JSON schema {schema} after {attempts} attempt(s): {reason}")]
+pub struct SchemaValidationError {
+    /// Name of the schema
+    pub schema: String,
+    /// Completions requested, including retries
+    pub attempts: u32,
+    /// Why the last completion is invalid
+    pub reason: String,
+    /// Content of the last completion
+    pub content: String,
+}
+
+/// Check the content of each choice of a response against a schema
+///
+/// Returns why the first invalid choice is invalid. Choices calling tools are
+/// not checked.
+pub fn validate_response(
+    response: &CompletionResponse,
+    schema: &Value,
+) -> std::result::Result<(), String> {
+    match first_invalid(response, schema) {
+        Some((_, reason)) => Err(reason),
+        None => Ok(()),
+    }
+}
+
+/// Content of the first invalid choice and why it is invalid
+fn first_invalid(response: &CompletionResponse, schema: &Value) -> Option<(String, String)> {
+    response
+        .choices
+        .iter()
+        .filter(|choice| choice.message.tool_calls.is_none())
+        .find_map(|choice| {
+            let content = choice
+                .message
+                .content
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or_default();
+            let reason = match serde_json::from_str::<Value>(content.trim()) {
+                Ok(value) => match DataUtils::validate_json_schema(&value, schema) {
+                    Ok(()) => return None,
+                    Err(ProviderError::InvalidRequest { message, .. }) => message,
+                    Err(e) => e.to_string(),
+                },
+                Err(e) => format!("not valid JSON: {}", e),
+            };
+            Some((content, reason))
+        })
+}
+
+/// Complete a request, retrying completions that do not match its JSON schema
+///
+/// Each retry adds the invalid completion and a system message telling the
+/// model what is wrong with it to the conversation. Requests without a JSON
+/// schema are completed once.
+pub async fn complete_with_schema<F, Fut>(
+    mut request: ChatRequest,
+    options: &CompletionOptions,
+    mut complete: F,
+) -> Result<CompletionResponse>
+where
+    F: FnMut(ChatRequest) -> Fut,
+    Fut: Future<Output = Result<CompletionResponse>>,
+{
+    let Some(format) = request.response_format.clone() else {
+        return complete(request).await;
+    };
+    let Some(schema) = format.schema() else {
+        return complete(request).await;
+    };
+    let name = format.schema_name().unwrap_or("response");
+    let retries = options
+        .json_schema_retries
+        .unwrap_or(DEFAULT_JSON_SCHEMA_RETRIES);
+
+    let mut attempts = 0;
+    loop {
+        let response = complete(request.clone()).await?;
+        attempts += 1;
+        let Some((content, reason)) = first_invalid(&response, schema) else {
+            return Ok(response);
+        };
+        if attempts > retries {
+            return Err(SchemaValidationError {
+                schema: name.to_string(),
+                attempts,
+                reason,
+                content,
+            }
+            .into());
+        }
+
+        warn!(
+            "Completion of {} does not match JSON schema {} (attempt {}): {}",
+            request.model, name, attempts, reason
+        );
+        request.messages.push(assistant_message(content));
+        request.messages.push(system_message(format!(
+            "Your previous response does not match the required JSON schema: {}. \
+             Respond again with only a JSON value that matches the schema.",
+            reason
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::completion::Choice;
+    use crate::core::completion::user_message;
+    use crate::core::types::{MessageRole, ResponseFormat};
+    use crate::utils::error::GatewayError;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    fn request() -> ChatRequest {
+        ChatRequest {
+            model: "gpt-4o".to_string(),
+            messages: vec![user_message("Name a color")],
+            response_format: Some(ResponseFormat {
+                format_type: "json_schema".to_string(),
+                json_schema: Some(json!({
+                    "name": "color",
+                    "schema": {
+                        "type": "object",
+                        "properties": {"color": {"type": "string"}},
+                        "required": ["color"]
+                    }
+                })),
+                response_type: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn response(content: &str) -> CompletionResponse {
+        CompletionResponse {
+            id: "resp".to_string(),
+            object: "chat.completion".to_string(),
+            created: 0,
+            model: "gpt-4o".to_string(),
+            choices: vec![Choice {
+                index: 0,
+                message: assistant_message(content),
+                finish_reason: None,
+            }],
+            usage: None,
+            provider_specific_fields: None,
+        }
+    }
+
+    /// Completes with `contents` in turn, recording the requests
+    async fn complete(
+        contents: &[&str],
+        options: CompletionOptions,
+    ) -> (Result<CompletionResponse>, Vec<ChatRequest>) {
+        let requests = Mutex::new(Vec::new());
+        let result = complete_with_schema(request(), &options, |request| {
+            let mut requests = requests.lock().unwrap();
+            let content = contents[requests.len().min(contents.len() - 1)];
+            requests.push(request);
+            async move { Ok(response(content)) }
+        })
+        .await;
+        (result, requests.into_inner().unwrap())
+    }
+
+    #[test]
+    fn test_validate_response() {
+        let schema = request().response_format.unwrap().schema().unwrap().clone();
+        assert!(validate_response(&response(r#" {"color": "red"} "#), &schema).is_ok());
+        let reason = validate_response(&response(r#"{"colour": "red"}"#), &schema).unwrap_err();
+        assert!(reason.contains("required property 'color'"), "{}", reason);
+        let reason = validate_response(&response("red"), &schema).unwrap_err();
+        assert!(reason.starts_with("not valid JSON"), "{}", reason);
+    }
+
+    #[tokio::test]
+    async fn test_retries_with_corrective_message() {
+        let (result, requests) = complete(
+            &["red", r#"{"color": 1}"#, r#"{"color": "red"}"#],
+            CompletionOptions::default(),
+        )
+        .await;
+        assert_eq!(
+            result.unwrap().choices[0]
+                .message
+                .content
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            r#"{"color": "red"}"#
+        );
+
+        assert_eq!(requests.len(), 3);
+        let retry = &requests[2].messages;
+        assert_eq!(retry.len(), 5);
+        assert_eq!(retry[3].role, MessageRole::Assistant);
+        assert_eq!(
+            retry[3].content.as_ref().unwrap().to_string(),
+            r#"{"color": 1}"#
+        );
+        assert_eq!(retry[4].role, MessageRole::System);
+        let correction = retry[4].content.as_ref().unwrap().to_string();
+        assert!(
+            correction.contains("$.color: expected type 'string'"),
+            "{}",
+            correction
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fails_after_retries() {
+        let options = CompletionOptions {
+            json_schema_retries: Some(1),
+            ..Default::default()
+        };
+        let (result, requests) = complete(&["red"], options).await;
+        assert_eq!(requests.len(), 2);
+        match result.unwrap_err() {
+            GatewayError::SchemaValidation(e) => {
+                assert_eq!(e.schema, "color");
+                assert_eq!(e.attempts, 2);
+                assert_eq!(e.content, "red");
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_skips_requests_without_schema() {
+        let mut request = request();
+        request.response_format.as_mut().unwrap().format_type = "json_object".to_string();
+        let mut calls = 0;
+        complete_with_schema(request, &CompletionOptions::default(), |_| {
+            calls += 1;
+            async { Ok(response("red")) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls, 1);
+    }
+}
@@ -42,3 +42,49 @@ fn test_assistant_message_creation() {
         panic!("Expected text content");
     }
 }
+
+#[test]
+fn test_text_completion_conversion() {
+    let request = TextCompletionRequest {
+        model: "groq/llama-3.1-8b-instant".to_string(),
+        prompt: "Once upon a time".to_string(),
+        temperature: Some(0.5),
+        max_tokens: Some(16),
+        top_p: None,
+        frequency_penalty: None,
+        presence_penalty: None,
+        stop: Some(vec!["\n".to_string()]),
+        n: None,
+        stream: false,
+        user: None,
+    };
+
+    let (model, messages, options) = convert_text_completion_to_chat(request);
+    assert_eq!(model, "groq/llama-3.1-8b-instant");
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].role, MessageRole::User);
+    assert_eq!(options.max_tokens, Some(16));
+    assert_eq!(options.stop, Some(vec!["\n".to_string()]));
+
+    let response = CompletionResponse {
+        id: "chatcmpl-123".to_string(),
+        object: "chat.completion".to_string(),
+        created: 1700000000,
+        model: "llama-3.1-8b-instant".to_string(),
+        choices: vec![Choice {
+            index: 0,
+            message: assistant_message(", there was a crab."),
+            finish_reason: Some(FinishReason::Stop),
+        }],
+        usage: None,
+        provider_specific_fields: None,
+    };
+
+    let text = convert_to_text_completion_response(response);
+    assert_eq!(text.object, "text_completion");
+    assert_eq!(text.choices[0].text, ", there was a crab.");
+    assert!(matches!(
+        text.choices[0].finish_reason,
+        Some(FinishReason::Stop)
+    ));
+}
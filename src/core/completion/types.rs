@@ -1,6 +1,6 @@
 //! Completion types - Python LiteLLM compatible
 
-use crate::core::types::{ChatMessage, FinishReason, Tool, ToolChoice, Usage};
+use crate::core::types::{ChatMessage, FinishReason, ResponseFormat, Tool, ToolChoice, Usage};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -40,6 +40,14 @@ pub struct CompletionOptions {
     pub tools: Option<Vec<Tool>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_choice: Option<ToolChoice>,
+    /// Output format; completions of a JSON schema format are validated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+    /// Retries of a completion that does not match its JSON schema,
+    /// [`DEFAULT_JSON_SCHEMA_RETRIES`](super::structured_output::DEFAULT_JSON_SCHEMA_RETRIES)
+    /// when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_schema_retries: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,6 +83,10 @@ pub struct CompletionResponse {
     pub model: String,
     pub choices: Vec<Choice>,
     pub usage: Option<Usage>,
+    /// Provider-specific fields carried over from the chat response, plus
+    /// guardrail annotations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_specific_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Response choice
@@ -148,6 +160,7 @@ mod tests {
             model: "gpt-4".to_string(),
             choices: vec![],
             usage: None,
+            provider_specific_fields: None,
         };
 
         assert_eq!(response.id, "cmpl-123");
@@ -161,7 +174,9 @@ mod tests {
             index: 0,
             message: ChatMessage {
                 role: MessageRole::Assistant,
-                content: Some(crate::core::types::MessageContent::Text("Hello".to_string())),
+                content: Some(crate::core::types::MessageContent::Text(
+                    "Hello".to_string(),
+                )),
                 ..Default::default()
             },
             finish_reason: Some(FinishReason::Stop),
@@ -64,9 +64,10 @@ pub fn generic_cost_per_token(
     breakdown.output_cost = calculate_output_cost(usage, output_cost_per_1k);
 
     // Calculate cache costs if applicable
-    if let Some(cached_tokens) = usage.cached_tokens {
+    if usage.cached_tokens.is_some() || usage.cache_creation_tokens.is_some() {
         breakdown.cache_cost = calculate_cache_cost(
-            cached_tokens,
+            usage.cached_tokens.unwrap_or(0),
+            usage.cache_creation_tokens.unwrap_or(0),
             cache_creation_cost_per_1k,
             cache_read_cost_per_1k,
         );
@@ -105,6 +106,7 @@ pub fn get_model_pricing(model: &str, provider: &str) -> Result<ModelPricing, Co
         "vertex_ai" | "vertexai" => get_vertex_ai_pricing(model),
         "deepseek" => get_deepseek_pricing(model),
         "moonshot" => get_moonshot_pricing(model),
+        "xai" => get_xai_pricing(model),
         _ => Err(CostError::ProviderNotSupported {
             provider: provider.to_string(),
         }),
@@ -112,12 +114,13 @@ pub fn get_model_pricing(model: &str, provider: &str) -> Result<ModelPricing, Co
 }
 
 /// Calculate input cost
+///
+/// Tokens read from or written to the prompt cache are priced separately.
 fn calculate_input_cost(usage: &UsageTokens, cost_per_1k: f64) -> f64 {
-    let non_cached_tokens = if let Some(cached) = usage.cached_tokens {
-        usage.prompt_tokens.saturating_sub(cached)
-    } else {
-        usage.prompt_tokens
-    };
+    let non_cached_tokens = usage
+        .prompt_tokens
+        .saturating_sub(usage.cached_tokens.unwrap_or(0))
+        .saturating_sub(usage.cache_creation_tokens.unwrap_or(0));
 
     (non_cached_tokens as f64 / 1000.0) * cost_per_1k
 }
@@ -128,9 +131,13 @@ fn calculate_output_cost(usage: &UsageTokens, cost_per_1k: f64) -> f64 {
 }
 
 /// Calculate cache cost
-fn calculate_cache_cost(cached_tokens: u32, _creation_cost: f64, read_cost: f64) -> f64 {
-    // Assume all cached tokens are read (typical case)
-    (cached_tokens as f64 / 1000.0) * read_cost
+fn calculate_cache_cost(
+    cached_tokens: u32,
+    creation_tokens: u32,
+    creation_cost: f64,
+    read_cost: f64,
+) -> f64 {
+    (cached_tokens as f64 / 1000.0) * read_cost + (creation_tokens as f64 / 1000.0) * creation_cost
 }
 
 /// Calculate audio cost
@@ -280,6 +287,8 @@ fn get_anthropic_pricing(model: &str) -> Result<ModelPricing, CostError> {
             model: model.to_string(),
             input_cost_per_1k_tokens: 0.003,
             output_cost_per_1k_tokens: 0.015,
+            cache_read_input_token_cost: Some(0.0003),
+            cache_creation_input_token_cost: Some(0.00375),
             currency: "USD".to_string(),
             updated_at: Utc::now(),
             ..Default::default()
@@ -288,6 +297,8 @@ fn get_anthropic_pricing(model: &str) -> Result<ModelPricing, CostError> {
             model: model.to_string(),
             input_cost_per_1k_tokens: 0.001,
             output_cost_per_1k_tokens: 0.005,
+            cache_read_input_token_cost: Some(0.0001),
+            cache_creation_input_token_cost: Some(0.00125),
             currency: "USD".to_string(),
             updated_at: Utc::now(),
             ..Default::default()
@@ -296,6 +307,8 @@ fn get_anthropic_pricing(model: &str) -> Result<ModelPricing, CostError> {
             model: model.to_string(),
             input_cost_per_1k_tokens: 0.00025,
             output_cost_per_1k_tokens: 0.00125,
+            cache_read_input_token_cost: Some(0.00003),
+            cache_creation_input_token_cost: Some(0.0003),
             currency: "USD".to_string(),
             updated_at: Utc::now(),
             ..Default::default()
@@ -327,6 +340,8 @@ fn get_vertex_ai_pricing(model: &str) -> Result<ModelPricing, CostError> {
             model: model.to_string(),
             input_cost_per_1k_tokens: 0.00125,
             output_cost_per_1k_tokens: 0.00375,
+            // Context cache reads are billed at a quarter of the input rate
+            cache_read_input_token_cost: Some(0.0003125),
             currency: "USD".to_string(),
             updated_at: Utc::now(),
             ..Default::default()
@@ -335,6 +350,7 @@ fn get_vertex_ai_pricing(model: &str) -> Result<ModelPricing, CostError> {
             model: model.to_string(),
             input_cost_per_1k_tokens: 0.000075,
             output_cost_per_1k_tokens: 0.0003,
+            cache_read_input_token_cost: Some(0.00001875),
             currency: "USD".to_string(),
             updated_at: Utc::now(),
             ..Default::default()
@@ -412,6 +428,36 @@ fn get_moonshot_pricing(model: &str) -> Result<ModelPricing, CostError> {
     Ok(pricing)
 }
 
+fn get_xai_pricing(model: &str) -> Result<ModelPricing, CostError> {
+    use crate::core::providers::xai::get_model_info;
+    use chrono::Utc;
+    use std::collections::HashMap;
+
+    let model_id = model.strip_prefix("xai/").unwrap_or(model);
+    let info = get_model_info(model_id).ok_or_else(|| CostError::ModelNotSupported {
+        model: model.to_string(),
+        provider: "xai".to_string(),
+    })?;
+
+    // Grok image models return 1024x768 images at a flat rate
+    let cost_per_image = info
+        .output_cost_per_image
+        .map(|cost| HashMap::from([("1024x768".to_string(), cost)]));
+
+    Ok(ModelPricing {
+        model: model.to_string(),
+        input_cost_per_1k_tokens: info.input_cost_per_million / 1000.0,
+        output_cost_per_1k_tokens: info.output_cost_per_million / 1000.0,
+        reasoning_cost_per_token: info
+            .reasoning_cost_per_million
+            .map(|cost| cost / 1_000_000.0),
+        cost_per_image,
+        currency: "USD".to_string(),
+        updated_at: Utc::now(),
+        ..Default::default()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -569,6 +615,7 @@ mod tests {
         let pricing = pricing.unwrap();
         assert_eq!(pricing.input_cost_per_1k_tokens, 0.00125);
         assert_eq!(pricing.output_cost_per_1k_tokens, 0.00375);
+        assert_eq!(pricing.cache_read_input_token_cost, Some(0.0003125));
     }
 
     #[test]
@@ -616,6 +663,19 @@ mod tests {
         assert_eq!(pricing.output_cost_per_1k_tokens, 0.06);
     }
 
+    #[test]
+    fn test_get_xai_pricing() {
+        let pricing = get_model_pricing("xai/grok-2-image", "xai").unwrap();
+        let cost_per_image = pricing.cost_per_image.unwrap();
+        assert_eq!(cost_per_image.get("1024x768"), Some(&0.07));
+
+        let pricing = get_model_pricing("grok-2-1212", "xai").unwrap();
+        assert!(pricing.input_cost_per_1k_tokens > 0.0);
+        assert!(pricing.cost_per_image.is_none());
+
+        assert!(get_model_pricing("grok-unknown", "xai").is_err());
+    }
+
     #[test]
     fn test_get_azure_pricing() {
         let pricing = get_model_pricing("gpt-4o", "azure");
@@ -676,14 +736,34 @@ mod tests {
     // Tests for calculate_cache_cost
     #[test]
     fn test_calculate_cache_cost() {
-        let cost = calculate_cache_cost(1000, 0.5, 0.1);
+        let cost = calculate_cache_cost(1000, 0, 0.5, 0.1);
         // Using read cost: 1000 / 1000 * 0.1 = 0.1
         assert_eq!(cost, 0.1);
     }
 
+    #[test]
+    fn test_calculate_cache_cost_with_creation() {
+        let cost = calculate_cache_cost(1000, 2000, 0.5, 0.1);
+        // 1000 / 1000 * 0.1 + 2000 / 1000 * 0.5 = 1.1
+        assert!((cost - 1.1).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_generic_cost_per_token_anthropic_cache() {
+        let mut usage = create_usage(10000, 0);
+        usage.cached_tokens = Some(4000);
+        usage.cache_creation_tokens = Some(2000);
+        let result =
+            generic_cost_per_token("claude-3-5-sonnet-20241022", &usage, "anthropic").unwrap();
+        // 4000 uncached at 0.003 per 1K
+        assert!((result.input_cost - 0.012).abs() < 1e-10);
+        // 4000 read at 0.0003 + 2000 written at 0.00375
+        assert!((result.cache_cost - (0.0012 + 0.0075)).abs() < 1e-10);
+    }
+
     #[test]
     fn test_calculate_cache_cost_zero_tokens() {
-        let cost = calculate_cache_cost(0, 0.5, 0.1);
+        let cost = calculate_cache_cost(0, 0, 0.5, 0.1);
         assert_eq!(cost, 0.0);
     }
 
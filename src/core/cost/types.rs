@@ -18,6 +18,8 @@ pub struct UsageTokens {
     pub total_tokens: u32,
     /// Cached tokens (for prompt caching)
     pub cached_tokens: Option<u32>,
+    /// Tokens written to the prompt cache
+    pub cache_creation_tokens: Option<u32>,
     /// Audio tokens (for speech models)
     pub audio_tokens: Option<u32>,
     /// Image tokens (for vision models)
@@ -26,6 +28,32 @@ pub struct UsageTokens {
     pub reasoning_tokens: Option<u32>,
 }
 
+impl From<&crate::core::types::responses::Usage> for UsageTokens {
+    fn from(usage: &crate::core::types::responses::Usage) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+            cached_tokens: usage.cache_read_input_tokens.or_else(|| {
+                usage
+                    .prompt_tokens_details
+                    .as_ref()
+                    .and_then(|d| d.cached_tokens)
+            }),
+            cache_creation_tokens: usage.cache_creation_input_tokens,
+            audio_tokens: usage
+                .prompt_tokens_details
+                .as_ref()
+                .and_then(|d| d.audio_tokens),
+            image_tokens: None,
+            reasoning_tokens: usage
+                .completion_tokens_details
+                .as_ref()
+                .and_then(|d| d.reasoning_tokens),
+        }
+    }
+}
+
 impl UsageTokens {
     pub fn new(prompt_tokens: u32, completion_tokens: u32) -> Self {
         Self {
@@ -33,6 +61,7 @@ impl UsageTokens {
             completion_tokens,
             total_tokens: prompt_tokens + completion_tokens,
             cached_tokens: None,
+            cache_creation_tokens: None,
             audio_tokens: None,
             image_tokens: None,
             reasoning_tokens: None,
@@ -0,0 +1,15 @@
+//! Files API for uploading and managing provider-hosted files
+//!
+//! Batch and fine-tuning jobs reference their input by file ID, so files are
+//! proxied to the provider that will run the job (OpenAI or Azure OpenAI).
+//! Gemini (Google AI Studio) files are referenced from chat requests instead.
+
+mod service;
+mod tests;
+
+pub mod types;
+
+pub use service::FilesService;
+pub use types::{
+    FileContent, FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+};
@@ -0,0 +1,133 @@
+//! Files service routing file operations to the hosting provider
+
+use crate::core::providers::azure::AzureOpenAIProvider;
+use crate::core::providers::gemini::GeminiProvider;
+use crate::core::providers::openai::OpenAIProvider;
+use crate::core::providers::{Provider, ProviderRegistry};
+use crate::utils::error::{GatewayError, Result};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use super::types::{
+    DEFAULT_FILES_PROVIDER, FileContent, FileDeleteResponse, FileListQuery, FileListResponse,
+    FileObject, FileUploadRequest,
+};
+
+/// A provider that hosts files
+enum FilesProvider<'a> {
+    OpenAI(&'a OpenAIProvider),
+    Azure(&'a AzureOpenAIProvider),
+    Gemini(&'a GeminiProvider),
+}
+
+/// Files service for handling `/files` requests
+pub struct FilesService {
+    provider_registry: Arc<ProviderRegistry>,
+}
+
+impl FilesService {
+    /// Create a new files service
+    pub fn new(provider_registry: Arc<ProviderRegistry>) -> Self {
+        Self { provider_registry }
+    }
+
+    /// Find the provider that stores the files
+    ///
+    /// File IDs are only meaningful to the provider that issued them, so the
+    /// caller names it (`custom_llm_provider`), defaulting to OpenAI.
+    fn resolve(&self, provider_name: Option<&str>) -> Result<FilesProvider<'_>> {
+        let provider_name = provider_name.unwrap_or(DEFAULT_FILES_PROVIDER);
+
+        let provider = self
+            .provider_registry
+            .all()
+            .into_iter()
+            .find(|p| p.name() == provider_name)
+            .ok_or_else(|| {
+                GatewayError::not_found(format!("No provider found for files: {}", provider_name))
+            })?;
+
+        match provider {
+            Provider::OpenAI(p) => Ok(FilesProvider::OpenAI(p)),
+            Provider::Azure(p) => Ok(FilesProvider::Azure(p)),
+            Provider::Gemini(p) => Ok(FilesProvider::Gemini(p)),
+            _ => Err(GatewayError::validation(format!(
+                "Provider {} does not support the files API",
+                provider_name
+            ))),
+        }
+    }
+
+    /// Upload a file
+    pub async fn upload(
+        &self,
+        request: FileUploadRequest,
+        provider_name: Option<&str>,
+    ) -> Result<FileObject> {
+        info!(
+            "Uploading file: filename={}, purpose={}, size={}",
+            request.filename,
+            request.purpose,
+            request.file.len()
+        );
+
+        request.validate().map_err(GatewayError::validation)?;
+
+        let file = match self.resolve(provider_name)? {
+            FilesProvider::OpenAI(p) => p.upload_file(request).await?,
+            FilesProvider::Azure(p) => p.files().upload_file(request).await?,
+            FilesProvider::Gemini(p) => p.files().upload_file(request).await?,
+        };
+
+        debug!("Uploaded file {}", file.id);
+        Ok(file)
+    }
+
+    /// List files
+    pub async fn list(
+        &self,
+        query: FileListQuery,
+        provider_name: Option<&str>,
+    ) -> Result<FileListResponse> {
+        match self.resolve(provider_name)? {
+            FilesProvider::OpenAI(p) => Ok(p.list_files(query).await?),
+            FilesProvider::Azure(p) => Ok(p.files().list_files(query).await?),
+            FilesProvider::Gemini(p) => Ok(p.files().list_files(query).await?),
+        }
+    }
+
+    /// Retrieve a file's metadata
+    pub async fn retrieve(&self, file_id: &str, provider_name: Option<&str>) -> Result<FileObject> {
+        match self.resolve(provider_name)? {
+            FilesProvider::OpenAI(p) => Ok(p.retrieve_file(file_id).await?),
+            FilesProvider::Azure(p) => Ok(p.files().retrieve_file(file_id).await?),
+            FilesProvider::Gemini(p) => Ok(p.files().retrieve_file(file_id).await?),
+        }
+    }
+
+    /// Delete a file
+    pub async fn delete(
+        &self,
+        file_id: &str,
+        provider_name: Option<&str>,
+    ) -> Result<FileDeleteResponse> {
+        info!("Deleting file {}", file_id);
+
+        match self.resolve(provider_name)? {
+            FilesProvider::OpenAI(p) => Ok(p.delete_file(file_id).await?),
+            FilesProvider::Azure(p) => Ok(p.files().delete_file(file_id).await?),
+            FilesProvider::Gemini(p) => Ok(p.files().delete_file(file_id).await?),
+        }
+    }
+
+    /// Download a file's contents
+    pub async fn content(&self, file_id: &str, provider_name: Option<&str>) -> Result<FileContent> {
+        match self.resolve(provider_name)? {
+            FilesProvider::OpenAI(p) => Ok(p.file_content(file_id).await?),
+            FilesProvider::Azure(p) => Ok(p.files().file_content(file_id).await?),
+            FilesProvider::Gemini(_) => Err(GatewayError::validation(
+                "Gemini does not support downloading file contents".to_string(),
+            )),
+        }
+    }
+}
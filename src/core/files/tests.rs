@@ -0,0 +1,94 @@
+//! Tests for files module
+
+#[cfg(test)]
+mod tests {
+    use super::super::FilesService;
+    use super::super::types::{
+        FILE_PURPOSES, FileDeleteResponse, FileListQuery, FileListResponse, FileUploadRequest,
+    };
+    use crate::core::providers::ProviderRegistry;
+    use std::sync::Arc;
+
+    fn upload_request() -> FileUploadRequest {
+        FileUploadRequest {
+            file: b"{}\n".to_vec(),
+            filename: "batch.jsonl".to_string(),
+            purpose: "batch".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_upload_request_validation() {
+        assert!(upload_request().validate().is_ok());
+
+        let no_name = FileUploadRequest {
+            filename: String::new(),
+            ..upload_request()
+        };
+        assert!(no_name.validate().is_err());
+
+        for purpose in FILE_PURPOSES {
+            let request = FileUploadRequest {
+                purpose: purpose.to_string(),
+                ..upload_request()
+            };
+            assert!(request.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_list_query_string() {
+        assert_eq!(FileListQuery::default().to_query_string(), "");
+
+        let query = FileListQuery {
+            purpose: Some("batch".to_string()),
+            order: Some("desc".to_string()),
+            after: Some("file a".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.to_query_string(),
+            "purpose=batch&after=file+a&order=desc"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_file_list() {
+        let body = r#"{
+            "object": "list",
+            "data": [{
+                "id": "file-abc123",
+                "object": "file",
+                "bytes": 120000,
+                "created_at": 1677610602,
+                "filename": "batch.jsonl",
+                "purpose": "batch",
+                "status": "processed"
+            }],
+            "has_more": false
+        }"#;
+        let list: FileListResponse = serde_json::from_str(body).unwrap();
+        assert_eq!(list.data.len(), 1);
+        assert_eq!(list.data[0].id, "file-abc123");
+        assert_eq!(list.data[0].status.as_deref(), Some("processed"));
+        assert!(list.data[0].expires_at.is_none());
+
+        // `object` defaults when a provider leaves it out
+        let deleted: FileDeleteResponse =
+            serde_json::from_str(r#"{"id":"file-abc123","deleted":true}"#).unwrap();
+        assert_eq!(deleted.object, "file");
+        assert!(deleted.deleted);
+    }
+
+    #[tokio::test]
+    async fn test_files_require_provider() {
+        let service = FilesService::new(Arc::new(ProviderRegistry::new()));
+        assert!(service.upload(upload_request(), None).await.is_err());
+        assert!(
+            service
+                .retrieve("file-abc123", Some("azure"))
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Files API type definitions
+//!
+//! OpenAI-compatible file objects shared by the providers that host files.
+
+use serde::{Deserialize, Serialize};
+
+/// Purposes accepted by `POST /files`
+pub const FILE_PURPOSES: &[&str] = &[
+    "assistants",
+    "batch",
+    "fine-tune",
+    "vision",
+    "user_data",
+    "evals",
+];
+
+/// Maximum size of a single uploaded file (512MB)
+pub const MAX_FILE_SIZE: usize = 512 * 1024 * 1024;
+
+/// Provider used when a request does not name one
+pub const DEFAULT_FILES_PROVIDER: &str = "openai";
+
+/// A file stored with a provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileObject {
+    /// File identifier, referenced by batch and fine-tuning requests
+    pub id: String,
+
+    /// Always "file"
+    #[serde(default = "default_file_object")]
+    pub object: String,
+
+    /// Size in bytes
+    #[serde(default)]
+    pub bytes: u64,
+
+    /// Unix timestamp of the upload
+    #[serde(default)]
+    pub created_at: i64,
+
+    /// Original filename
+    #[serde(default)]
+    pub filename: String,
+
+    /// Intended purpose, e.g. "batch" or "fine-tune"
+    #[serde(default)]
+    pub purpose: String,
+
+    /// Processing status ("uploaded", "processed", "error")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Details of a processing failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<String>,
+
+    /// Unix timestamp after which the file is deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
+fn default_file_object() -> String {
+    "file".to_string()
+}
+
+fn default_list_object() -> String {
+    "list".to_string()
+}
+
+/// Response of `GET /files`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileListResponse {
+    /// Always "list"
+    #[serde(default = "default_list_object")]
+    pub object: String,
+
+    /// Files on this page
+    pub data: Vec<FileObject>,
+
+    /// Whether more files follow this page
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Response of `DELETE /files/{file_id}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileDeleteResponse {
+    /// Deleted file identifier
+    pub id: String,
+
+    /// Always "file"
+    #[serde(default = "default_file_object")]
+    pub object: String,
+
+    /// Whether the file was deleted
+    pub deleted: bool,
+}
+
+/// File upload request
+#[derive(Debug, Clone, Default)]
+pub struct FileUploadRequest {
+    /// File contents
+    pub file: Vec<u8>,
+
+    /// Filename reported to the provider
+    pub filename: String,
+
+    /// Intended purpose, one of [`FILE_PURPOSES`]
+    pub purpose: String,
+}
+
+impl FileUploadRequest {
+    /// Check the request before it is sent to a provider
+    pub fn validate(&self) -> Result<(), String> {
+        if self.file.is_empty() {
+            return Err("File cannot be empty".to_string());
+        }
+
+        if self.file.len() > MAX_FILE_SIZE {
+            return Err("File too large (max 512MB)".to_string());
+        }
+
+        if self.filename.is_empty() {
+            return Err("Filename is required".to_string());
+        }
+
+        if !FILE_PURPOSES.contains(&self.purpose.as_str()) {
+            return Err(format!(
+                "Unsupported purpose '{}', expected one of: {}",
+                self.purpose,
+                FILE_PURPOSES.join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Query parameters of `GET /files`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileListQuery {
+    /// Only return files with this purpose
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+
+    /// Page size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor: the last file ID of the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+
+    /// Sort order by `created_at`: "asc" or "desc"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl FileListQuery {
+    /// Render as a URL query string, without the leading `?`
+    pub fn to_query_string(&self) -> String {
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(purpose) = &self.purpose {
+            serializer.append_pair("purpose", purpose);
+        }
+        if let Some(limit) = self.limit {
+            serializer.append_pair("limit", &limit.to_string());
+        }
+        if let Some(after) = &self.after {
+            serializer.append_pair("after", after);
+        }
+        if let Some(order) = &self.order {
+            serializer.append_pair("order", order);
+        }
+        serializer.finish()
+    }
+}
+
+/// Raw contents of a stored file
+#[derive(Debug, Clone)]
+pub struct FileContent {
+    /// File bytes
+    pub content: Vec<u8>,
+
+    /// Content type reported by the provider
+    pub content_type: String,
+}
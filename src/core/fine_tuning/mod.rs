@@ -0,0 +1,15 @@
+//! Fine-tuning API for creating and monitoring provider fine-tuning jobs
+//!
+//! Jobs train on files uploaded through [`crate::core::files`] and run on the
+//! provider that holds those files (OpenAI or Azure OpenAI).
+
+mod service;
+mod tests;
+
+pub mod types;
+
+pub use service::FineTuningService;
+pub use types::{
+    FineTuningEvent, FineTuningEventList, FineTuningJob, FineTuningJobList, FineTuningJobRequest,
+    FineTuningListQuery, FineTuningStatus,
+};
@@ -0,0 +1,127 @@
+//! Fine-tuning service routing job operations to the training provider
+
+use crate::core::providers::azure::AzureOpenAIProvider;
+use crate::core::providers::openai::OpenAIProvider;
+use crate::core::providers::{Provider, ProviderRegistry};
+use crate::utils::error::{GatewayError, Result};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use super::types::{
+    DEFAULT_FINE_TUNING_PROVIDER, FineTuningEventList, FineTuningJob, FineTuningJobList,
+    FineTuningJobRequest, FineTuningListQuery,
+};
+
+/// A provider that runs fine-tuning jobs
+enum FineTuningProvider<'a> {
+    OpenAI(&'a OpenAIProvider),
+    Azure(&'a AzureOpenAIProvider),
+}
+
+/// Fine-tuning service for handling `/fine_tuning/jobs` requests
+pub struct FineTuningService {
+    provider_registry: Arc<ProviderRegistry>,
+}
+
+impl FineTuningService {
+    /// Create a new fine-tuning service
+    pub fn new(provider_registry: Arc<ProviderRegistry>) -> Self {
+        Self { provider_registry }
+    }
+
+    /// Find the provider that runs the jobs
+    ///
+    /// Jobs and their training files live with a single provider, so the
+    /// caller names it (`custom_llm_provider`), defaulting to OpenAI.
+    fn resolve(&self, provider_name: Option<&str>) -> Result<FineTuningProvider<'_>> {
+        let provider_name = provider_name.unwrap_or(DEFAULT_FINE_TUNING_PROVIDER);
+
+        let provider = self
+            .provider_registry
+            .all()
+            .into_iter()
+            .find(|p| p.name() == provider_name)
+            .ok_or_else(|| {
+                GatewayError::not_found(format!(
+                    "No provider found for fine-tuning: {}",
+                    provider_name
+                ))
+            })?;
+
+        match provider {
+            Provider::OpenAI(p) => Ok(FineTuningProvider::OpenAI(p)),
+            Provider::Azure(p) => Ok(FineTuningProvider::Azure(p)),
+            _ => Err(GatewayError::validation(format!(
+                "Provider {} does not support the fine-tuning API",
+                provider_name
+            ))),
+        }
+    }
+
+    /// Create a fine-tuning job
+    pub async fn create(
+        &self,
+        request: FineTuningJobRequest,
+        provider_name: Option<&str>,
+    ) -> Result<FineTuningJob> {
+        info!(
+            "Creating fine-tuning job: model={}, training_file={}",
+            request.model, request.training_file
+        );
+
+        let job = match self.resolve(provider_name)? {
+            FineTuningProvider::OpenAI(p) => p.create_fine_tuning_job(request).await?,
+            FineTuningProvider::Azure(p) => p.fine_tuning().create_job(request).await?,
+        };
+
+        debug!("Created fine-tuning job {}", job.id);
+        Ok(job)
+    }
+
+    /// List fine-tuning jobs
+    pub async fn list(
+        &self,
+        query: FineTuningListQuery,
+        provider_name: Option<&str>,
+    ) -> Result<FineTuningJobList> {
+        match self.resolve(provider_name)? {
+            FineTuningProvider::OpenAI(p) => Ok(p.list_fine_tuning_jobs(query).await?),
+            FineTuningProvider::Azure(p) => Ok(p.fine_tuning().list_jobs(query).await?),
+        }
+    }
+
+    /// Retrieve a fine-tuning job
+    pub async fn retrieve(
+        &self,
+        job_id: &str,
+        provider_name: Option<&str>,
+    ) -> Result<FineTuningJob> {
+        match self.resolve(provider_name)? {
+            FineTuningProvider::OpenAI(p) => Ok(p.retrieve_fine_tuning_job(job_id).await?),
+            FineTuningProvider::Azure(p) => Ok(p.fine_tuning().retrieve_job(job_id).await?),
+        }
+    }
+
+    /// Cancel a fine-tuning job
+    pub async fn cancel(&self, job_id: &str, provider_name: Option<&str>) -> Result<FineTuningJob> {
+        info!("Cancelling fine-tuning job {}", job_id);
+
+        match self.resolve(provider_name)? {
+            FineTuningProvider::OpenAI(p) => Ok(p.cancel_fine_tuning_job(job_id).await?),
+            FineTuningProvider::Azure(p) => Ok(p.fine_tuning().cancel_job(job_id).await?),
+        }
+    }
+
+    /// List status events of a fine-tuning job
+    pub async fn list_events(
+        &self,
+        job_id: &str,
+        query: FineTuningListQuery,
+        provider_name: Option<&str>,
+    ) -> Result<FineTuningEventList> {
+        match self.resolve(provider_name)? {
+            FineTuningProvider::OpenAI(p) => Ok(p.list_fine_tuning_events(job_id, query).await?),
+            FineTuningProvider::Azure(p) => Ok(p.fine_tuning().list_events(job_id, query).await?),
+        }
+    }
+}
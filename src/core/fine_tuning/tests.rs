@@ -0,0 +1,90 @@
+//! Tests for fine-tuning module
+
+#[cfg(test)]
+mod tests {
+    use super::super::FineTuningService;
+    use super::super::types::{
+        EventType, FineTuningEventList, FineTuningJobList, FineTuningJobRequest,
+        FineTuningListQuery, FineTuningStatus,
+    };
+    use crate::core::providers::ProviderRegistry;
+    use std::sync::Arc;
+
+    fn job_request() -> FineTuningJobRequest {
+        FineTuningJobRequest {
+            training_file: "file-abc123".to_string(),
+            validation_file: None,
+            model: "gpt-4o-mini-2024-07-18".to_string(),
+            hyperparameters: None,
+            suffix: None,
+            metadata: None,
+            integrations: None,
+            seed: None,
+        }
+    }
+
+    #[test]
+    fn test_list_query_string() {
+        assert_eq!(FineTuningListQuery::default().to_query_string(), "");
+
+        let query = FineTuningListQuery {
+            after: Some("ftjob-abc".to_string()),
+            limit: Some(10),
+        };
+        assert_eq!(query.to_query_string(), "after=ftjob-abc&limit=10");
+    }
+
+    #[test]
+    fn test_deserialize_job_list() {
+        let body = r#"{
+            "object": "list",
+            "data": [{
+                "id": "ftjob-abc123",
+                "object": "fine_tuning.job",
+                "created_at": 1721764800,
+                "model": "gpt-4o-mini-2024-07-18",
+                "status": "validating_files",
+                "training_file": "file-abc123"
+            }],
+            "has_more": true
+        }"#;
+        let list: FineTuningJobList = serde_json::from_str(body).unwrap();
+        assert_eq!(list.data.len(), 1);
+        assert_eq!(list.data[0].id, "ftjob-abc123");
+        assert_eq!(list.data[0].status, FineTuningStatus::ValidatingFiles);
+        assert!(list.has_more);
+    }
+
+    #[test]
+    fn test_azure_pending_status_is_queued() {
+        let status: FineTuningStatus = serde_json::from_str(r#""pending""#).unwrap();
+        assert_eq!(status, FineTuningStatus::Queued);
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""queued""#);
+    }
+
+    #[test]
+    fn test_deserialize_event_list() {
+        // Azure leaves out the event `type`
+        let body = r#"{
+            "data": [{
+                "id": "ftevent-abc123",
+                "object": "fine_tuning.job.event",
+                "created_at": 1721764800,
+                "level": "info",
+                "message": "Job started"
+            }]
+        }"#;
+        let events: FineTuningEventList = serde_json::from_str(body).unwrap();
+        assert_eq!(events.object, "list");
+        assert_eq!(events.data[0].message, "Job started");
+        assert!(matches!(events.data[0].event_type, EventType::Message));
+        assert!(!events.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_fine_tuning_requires_provider() {
+        let service = FineTuningService::new(Arc::new(ProviderRegistry::new()));
+        assert!(service.create(job_request(), None).await.is_err());
+        assert!(service.cancel("ftjob-abc123", Some("azure")).await.is_err());
+    }
+}
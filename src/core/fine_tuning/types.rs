@@ -0,0 +1,283 @@
+//! Fine-tuning API type definitions
+//!
+//! OpenAI-compatible job objects shared by every provider that runs
+//! fine-tuning jobs. Providers with their own job format (Vertex AI) convert
+//! into these types.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Provider used when a request does not name one
+pub const DEFAULT_FINE_TUNING_PROVIDER: &str = "openai";
+
+/// Fine-tuning job creation request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningJobRequest {
+    /// The ID of an uploaded file that contains training data
+    pub training_file: String,
+
+    /// The ID of an uploaded file that contains validation data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_file: Option<String>,
+
+    /// The name of the model to fine-tune
+    pub model: String,
+
+    /// The hyperparameters used for the fine-tuning job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperparameters: Option<FineTuningHyperparameters>,
+
+    /// A string of up to 18 characters for the suffix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+
+    /// Set of key-value pairs for metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// List of integrations to enable for this fine-tuning job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Vec<Integration>>,
+
+    /// Seed for deterministic training
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i32>,
+}
+
+/// Hyperparameters for fine-tuning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningHyperparameters {
+    /// The number of epochs to train the model for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_epochs: Option<u32>,
+
+    /// Batch size to use for training
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<u32>,
+
+    /// Learning rate multiplier to use for training
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_rate_multiplier: Option<f64>,
+}
+
+/// Integration configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Integration {
+    /// The type of integration
+    #[serde(rename = "type")]
+    pub integration_type: String,
+
+    /// Configuration for Weights & Biases integration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wandb: Option<WandBConfig>,
+}
+
+/// Weights & Biases configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WandBConfig {
+    /// The name of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    /// Display name for the run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Entity (team) to use for the run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+
+    /// Tags for the run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Fine-tuning job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningJob {
+    /// The object identifier
+    pub id: String,
+
+    /// The object type (always "fine_tuning.job")
+    pub object: String,
+
+    /// The Unix timestamp for when the fine-tuning job was created
+    pub created_at: i64,
+
+    /// The Unix timestamp for when the fine-tuning job was finished
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<i64>,
+
+    /// The base model that is being fine-tuned
+    pub model: String,
+
+    /// The fine-tuned model name (available after completion)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fine_tuned_model: Option<String>,
+
+    /// The organization that owns the fine-tuning job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+
+    /// The current status of the fine-tuning job
+    pub status: FineTuningStatus,
+
+    /// The hyperparameters used for the fine-tuning job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperparameters: Option<FineTuningHyperparameters>,
+
+    /// The file ID used for training
+    pub training_file: String,
+
+    /// The file ID used for validation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_file: Option<String>,
+
+    /// The compiled results file ID(s) for the fine-tuning job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_files: Option<Vec<String>>,
+
+    /// The total number of billable tokens processed by this fine-tuning job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trained_tokens: Option<u64>,
+
+    /// The suffix used to identify the fine-tuned model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+
+    /// Error information if the job failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<FineTuningError>,
+
+    /// Estimated finish time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_finish: Option<i64>,
+
+    /// List of integrations enabled for this job
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Vec<Integration>>,
+
+    /// Seed used for training
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i32>,
+}
+
+/// Fine-tuning job status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningStatus {
+    ValidatingFiles,
+    /// Azure reports jobs waiting to start as "created" or "pending"
+    #[serde(alias = "created", alias = "pending")]
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+/// Fine-tuning error details
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningError {
+    /// Error code
+    pub code: String,
+
+    /// Error message
+    pub message: String,
+
+    /// Additional error parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+}
+
+/// Fine-tuning job events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningEvent {
+    pub id: String,
+    pub object: String,
+    pub created_at: i64,
+    pub level: EventLevel,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    #[serde(rename = "type", default)]
+    pub event_type: EventType,
+}
+
+/// Event level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+/// Event type
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventType {
+    #[default]
+    Message,
+    Metrics,
+}
+
+fn default_list_object() -> String {
+    "list".to_string()
+}
+
+/// Response of `GET /fine_tuning/jobs`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningJobList {
+    /// Always "list"
+    #[serde(default = "default_list_object")]
+    pub object: String,
+
+    /// Jobs on this page
+    pub data: Vec<FineTuningJob>,
+
+    /// Whether more jobs follow this page
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Response of `GET /fine_tuning/jobs/{job_id}/events`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FineTuningEventList {
+    /// Always "list"
+    #[serde(default = "default_list_object")]
+    pub object: String,
+
+    /// Events on this page
+    pub data: Vec<FineTuningEvent>,
+
+    /// Whether more events follow this page
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Pagination parameters of the job and event listings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FineTuningListQuery {
+    /// Cursor: the last ID of the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+
+    /// Page size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl FineTuningListQuery {
+    /// Render as a URL query string, without the leading `?`
+    pub fn to_query_string(&self) -> String {
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(after) = &self.after {
+            serializer.append_pair("after", after);
+        }
+        if let Some(limit) = self.limit {
+            serializer.append_pair("limit", &limit.to_string());
+        }
+        serializer.finish()
+    }
+}
@@ -0,0 +1,182 @@
+//! Azure AI Content Safety configuration
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::types::HarmCategory;
+use crate::core::guardrails::GuardrailAction;
+
+/// Content Safety API version used when none is configured
+pub const DEFAULT_API_VERSION: &str = "2024-09-01";
+
+/// Content at or above this severity is flagged unless a category overrides it
+pub const DEFAULT_SEVERITY_THRESHOLD: u8 = 4;
+
+/// Configuration for the Azure AI Content Safety guardrail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AzureContentSafetyConfig {
+    /// Resource endpoint, e.g. `https://my-resource.cognitiveservices.azure.com`
+    pub endpoint: String,
+
+    /// Resource key sent as `Ocp-Apim-Subscription-Key`
+    pub api_key: String,
+
+    #[serde(default = "default_api_version")]
+    pub api_version: String,
+
+    /// Minimum severity that counts as a violation
+    #[serde(default = "default_severity_threshold")]
+    pub severity_threshold: u8,
+
+    /// Per-category thresholds overriding `severity_threshold`
+    #[serde(default)]
+    pub category_thresholds: HashMap<HarmCategory, u8>,
+
+    /// Screen prompt messages before the provider call
+    #[serde(default = "default_true")]
+    pub check_input: bool,
+
+    /// Screen completion messages after the provider call
+    #[serde(default = "default_true")]
+    pub check_output: bool,
+
+    #[serde(default)]
+    pub action: GuardrailAction,
+
+    /// Custom blocklists created on the Content Safety resource
+    #[serde(default)]
+    pub blocklist_names: Vec<String>,
+
+    /// Request timeout in seconds
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+}
+
+fn default_api_version() -> String {
+    DEFAULT_API_VERSION.to_string()
+}
+
+fn default_severity_threshold() -> u8 {
+    DEFAULT_SEVERITY_THRESHOLD
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_timeout() -> u64 {
+    10
+}
+
+impl AzureContentSafetyConfig {
+    /// Create a configuration with default thresholds
+    pub fn new(endpoint: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            api_key: api_key.into(),
+            api_version: default_api_version(),
+            severity_threshold: DEFAULT_SEVERITY_THRESHOLD,
+            category_thresholds: HashMap::new(),
+            check_input: true,
+            check_output: true,
+            action: GuardrailAction::default(),
+            blocklist_names: Vec::new(),
+            timeout: default_timeout(),
+        }
+    }
+
+    /// Threshold that applies to a category
+    pub fn threshold_for(&self, category: HarmCategory) -> u8 {
+        self.category_thresholds
+            .get(&category)
+            .copied()
+            .unwrap_or(self.severity_threshold)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.endpoint.starts_with("http://") && !self.endpoint.starts_with("https://") {
+            return Err(
+                "Azure Content Safety endpoint must start with http:// or https://".to_string(),
+            );
+        }
+
+        if self.api_key.is_empty() {
+            return Err("Azure Content Safety API key is required".to_string());
+        }
+
+        // Severities run from 0 to 7; anything higher could never trigger
+        let thresholds =
+            std::iter::once(&self.severity_threshold).chain(self.category_thresholds.values());
+        for threshold in thresholds {
+            if *threshold > 7 {
+                return Err(format!(
+                    "Severity threshold {} is out of range (0-7)",
+                    threshold
+                ));
+            }
+        }
+
+        if self.timeout == 0 {
+            return Err("Timeout must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> AzureContentSafetyConfig {
+        AzureContentSafetyConfig::new("https://contoso.cognitiveservices.azure.com", "key")
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = test_config();
+        assert_eq!(config.api_version, DEFAULT_API_VERSION);
+        assert_eq!(config.action, GuardrailAction::Block);
+        assert!(config.check_input && config.check_output);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_threshold_for() {
+        let mut config = test_config();
+        config.category_thresholds.insert(HarmCategory::SelfHarm, 2);
+        assert_eq!(config.threshold_for(HarmCategory::SelfHarm), 2);
+        assert_eq!(config.threshold_for(HarmCategory::Hate), 4);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut config = test_config();
+        config.api_key = String::new();
+        assert!(config.validate().unwrap_err().contains("API key"));
+
+        let mut config = test_config();
+        config.endpoint = "contoso.cognitiveservices.azure.com".to_string();
+        assert!(config.validate().is_err());
+
+        let mut config = test_config();
+        config.category_thresholds.insert(HarmCategory::Violence, 8);
+        assert!(config.validate().unwrap_err().contains("out of range"));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let config: AzureContentSafetyConfig = serde_json::from_value(serde_json::json!({
+            "endpoint": "https://contoso.cognitiveservices.azure.com",
+            "api_key": "key",
+            "action": "annotate",
+            "category_thresholds": {"Sexual": 2},
+            "check_input": false
+        }))
+        .unwrap();
+        assert_eq!(config.action, GuardrailAction::Annotate);
+        assert_eq!(config.threshold_for(HarmCategory::Sexual), 2);
+        assert!(!config.check_input);
+        assert!(config.check_output);
+    }
+}
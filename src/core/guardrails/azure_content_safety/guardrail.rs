@@ -0,0 +1,435 @@
+//! Azure AI Content Safety guardrail implementation
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::warn;
+
+use super::config::AzureContentSafetyConfig;
+use super::types::{
+    AnalyzeTextRequest, AnalyzeTextResponse, CategoryAnalysis, CategoryViolation,
+    ContentSafetyReport, HarmCategory,
+};
+use crate::core::guardrails::{Guardrail, GuardrailAction, GuardrailStage, GuardrailViolation};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{ChatMessage, ChatRequest};
+use crate::utils::error::{GatewayError, Result};
+
+/// Name reported in content-filter errors
+const GUARDRAIL_NAME: &str = "azure_content_safety";
+
+/// Longest text accepted by a single `text:analyze` call, in characters
+const MAX_TEXT_LENGTH: usize = 10_000;
+
+/// Screens chat messages with Azure AI Content Safety
+#[derive(Debug, Clone)]
+pub struct AzureContentSafetyGuardrail {
+    config: AzureContentSafetyConfig,
+    client: Client,
+}
+
+impl AzureContentSafetyGuardrail {
+    pub fn new(config: AzureContentSafetyConfig) -> Result<Self> {
+        config.validate().map_err(GatewayError::Config)?;
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout))
+            .build()
+            .map_err(|e| GatewayError::Network(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self { config, client })
+    }
+
+    pub fn config(&self) -> &AzureContentSafetyConfig {
+        &self.config
+    }
+
+    fn analyze_url(&self) -> String {
+        format!(
+            "{}/contentsafety/text:analyze?api-version={}",
+            self.config.endpoint.trim_end_matches('/'),
+            self.config.api_version
+        )
+    }
+
+    /// Analyze a single piece of text of at most [`MAX_TEXT_LENGTH`] characters
+    pub async fn analyze_text(&self, text: &str) -> Result<AnalyzeTextResponse> {
+        let body = AnalyzeTextRequest {
+            text,
+            categories: &HarmCategory::ALL,
+            blocklist_names: &self.config.blocklist_names,
+            halt_on_blocklist_hit: false,
+            output_type: "FourSeverityLevels",
+        };
+
+        let response = self
+            .client
+            .post(self.analyze_url())
+            .header("Ocp-Apim-Subscription-Key", &self.config.api_key)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    GatewayError::timeout(format!("Azure Content Safety: {}", e))
+                } else {
+                    GatewayError::network(format!("Azure Content Safety: {}", e))
+                }
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(GatewayError::external_service(format!(
+                "Azure Content Safety returned {}: {}",
+                status.as_u16(),
+                parse_error_message(&error_text)
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| GatewayError::parsing(format!("Azure Content Safety response: {}", e)))
+    }
+
+    /// Analyze arbitrarily long text, splitting it into chunks the API accepts
+    pub async fn analyze(&self, stage: GuardrailStage, text: &str) -> Result<ContentSafetyReport> {
+        let responses = futures::future::try_join_all(
+            split_text(text, MAX_TEXT_LENGTH)
+                .into_iter()
+                .map(|chunk| self.analyze_text(chunk)),
+        )
+        .await?;
+
+        Ok(self.evaluate(stage, &responses))
+    }
+
+    /// Merge chunk results and compare them with the configured thresholds
+    pub fn evaluate(
+        &self,
+        stage: GuardrailStage,
+        responses: &[AnalyzeTextResponse],
+    ) -> ContentSafetyReport {
+        let mut max_severity: HashMap<HarmCategory, u8> = HashMap::new();
+        let mut blocklist_matches = Vec::new();
+
+        for response in responses {
+            for analysis in &response.categories_analysis {
+                let severity = max_severity.entry(analysis.category).or_insert(0);
+                *severity = (*severity).max(analysis.severity);
+            }
+            blocklist_matches.extend(response.blocklists_match.iter().cloned());
+        }
+
+        let mut categories = Vec::new();
+        let mut violations = Vec::new();
+        for category in HarmCategory::ALL {
+            if let Some(&severity) = max_severity.get(&category) {
+                categories.push(CategoryAnalysis { category, severity });
+
+                let threshold = self.config.threshold_for(category);
+                if severity >= threshold {
+                    violations.push(CategoryViolation {
+                        category,
+                        severity,
+                        threshold,
+                    });
+                }
+            }
+        }
+
+        ContentSafetyReport {
+            stage,
+            categories,
+            violations,
+            blocklist_matches,
+        }
+    }
+
+    /// Apply the configured action to a report
+    ///
+    /// Blocked content becomes a content-filter error. In annotate mode the
+    /// flagged report is returned so the caller can attach it to the response.
+    pub fn enforce(&self, report: ContentSafetyReport) -> Result<Option<ContentSafetyReport>> {
+        if !report.is_flagged() {
+            return Ok(None);
+        }
+
+        match self.config.action {
+            GuardrailAction::Block => Err(ProviderError::content_filtered(
+                GUARDRAIL_NAME,
+                report.reason(),
+                Some(report.policy_violations()),
+                Some(report.stage == GuardrailStage::Input),
+            )
+            .into()),
+            GuardrailAction::Annotate => {
+                warn!("Azure Content Safety {}", report.reason());
+                Ok(Some(report))
+            }
+        }
+    }
+
+    /// Screen messages and apply the configured action
+    pub async fn check_messages<'a>(
+        &self,
+        stage: GuardrailStage,
+        messages: impl IntoIterator<Item = &'a ChatMessage>,
+    ) -> Result<Option<ContentSafetyReport>> {
+        let text = messages_text(messages);
+        if text.is_empty() {
+            return Ok(None);
+        }
+
+        let report = self.analyze(stage, &text).await?;
+        self.enforce(report)
+    }
+
+    /// Screen the prompt before it is sent to the provider
+    pub async fn check_request(
+        &self,
+        request: &ChatRequest,
+    ) -> Result<Option<ContentSafetyReport>> {
+        if !self.config.check_input {
+            return Ok(None);
+        }
+        self.check_messages(GuardrailStage::Input, &request.messages)
+            .await
+    }
+
+    /// Screen completion messages before they are returned
+    pub async fn check_response<'a>(
+        &self,
+        messages: impl IntoIterator<Item = &'a ChatMessage>,
+    ) -> Result<Option<ContentSafetyReport>> {
+        if !self.config.check_output {
+            return Ok(None);
+        }
+        self.check_messages(GuardrailStage::Output, messages).await
+    }
+}
+
+#[async_trait]
+impl Guardrail for AzureContentSafetyGuardrail {
+    fn name(&self) -> &str {
+        GUARDRAIL_NAME
+    }
+
+    async fn pre_call(&self, messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+        self.violation(GuardrailStage::Input, messages).await
+    }
+
+    async fn post_call(&self, messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+        self.violation(GuardrailStage::Output, messages).await
+    }
+}
+
+impl AzureContentSafetyGuardrail {
+    /// Flagged report of messages as a pipeline violation, which cannot be masked
+    async fn violation(
+        &self,
+        stage: GuardrailStage,
+        messages: &[ChatMessage],
+    ) -> Result<Option<GuardrailViolation>> {
+        let text = messages_text(messages);
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let report = self.analyze(stage, &text).await?;
+        Ok(report
+            .is_flagged()
+            .then(|| GuardrailViolation::new(report.reason())))
+    }
+}
+
+/// Text content of messages, one message per line
+fn messages_text<'a>(messages: impl IntoIterator<Item = &'a ChatMessage>) -> String {
+    messages
+        .into_iter()
+        .filter_map(|m| m.content.as_ref())
+        .map(|c| c.to_string())
+        .filter(|t| !t.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Split text into chunks of at most `max_chars` characters
+fn split_text(text: &str, max_chars: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut count = 0;
+    for (index, _) in text.char_indices() {
+        if count == max_chars {
+            chunks.push(&text[start..index]);
+            start = index;
+            count = 0;
+        }
+        count += 1;
+    }
+    if start < text.len() {
+        chunks.push(&text[start..]);
+    }
+    chunks
+}
+
+/// Extract the message from a Content Safety error body
+fn parse_error_message(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::guardrails::azure_content_safety::BlocklistMatch;
+    use crate::core::types::{MessageContent, MessageRole};
+
+    fn guardrail(action: GuardrailAction) -> AzureContentSafetyGuardrail {
+        let mut config =
+            AzureContentSafetyConfig::new("https://contoso.cognitiveservices.azure.com/", "key");
+        config.action = action;
+        config.category_thresholds.insert(HarmCategory::SelfHarm, 2);
+        AzureContentSafetyGuardrail::new(config).unwrap()
+    }
+
+    fn analysis(hate: u8, self_harm: u8, violence: u8) -> AnalyzeTextResponse {
+        AnalyzeTextResponse {
+            blocklists_match: vec![],
+            categories_analysis: vec![
+                CategoryAnalysis {
+                    category: HarmCategory::Hate,
+                    severity: hate,
+                },
+                CategoryAnalysis {
+                    category: HarmCategory::SelfHarm,
+                    severity: self_harm,
+                },
+                CategoryAnalysis {
+                    category: HarmCategory::Violence,
+                    severity: violence,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_config() {
+        let config = AzureContentSafetyConfig::new("https://contoso.example", "");
+        assert!(AzureContentSafetyGuardrail::new(config).is_err());
+    }
+
+    #[test]
+    fn test_analyze_url() {
+        assert_eq!(
+            guardrail(GuardrailAction::Block).analyze_url(),
+            "https://contoso.cognitiveservices.azure.com/contentsafety/text:analyze?api-version=2024-09-01"
+        );
+    }
+
+    #[test]
+    fn test_evaluate_takes_max_severity_across_chunks() {
+        let guardrail = guardrail(GuardrailAction::Block);
+        let report = guardrail.evaluate(
+            GuardrailStage::Output,
+            &[analysis(2, 0, 4), analysis(0, 2, 0)],
+        );
+
+        assert_eq!(report.categories.len(), 3);
+        assert_eq!(
+            report.violations,
+            vec![
+                CategoryViolation {
+                    category: HarmCategory::SelfHarm,
+                    severity: 2,
+                    threshold: 2,
+                },
+                CategoryViolation {
+                    category: HarmCategory::Violence,
+                    severity: 4,
+                    threshold: 4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enforce_clean_report() {
+        let guardrail = guardrail(GuardrailAction::Block);
+        let report = guardrail.evaluate(GuardrailStage::Input, &[analysis(2, 0, 0)]);
+        assert!(guardrail.enforce(report).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_enforce_block() {
+        let guardrail = guardrail(GuardrailAction::Block);
+        let report = guardrail.evaluate(GuardrailStage::Input, &[analysis(6, 0, 0)]);
+        match guardrail.enforce(report) {
+            Err(GatewayError::BadRequest(message)) => {
+                assert!(message.contains("azure_content_safety"));
+                assert!(message.contains("Hate severity 6"));
+            }
+            other => panic!("expected content-filter error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_enforce_annotate() {
+        let guardrail = guardrail(GuardrailAction::Annotate);
+        let mut response = analysis(0, 0, 0);
+        response.blocklists_match.push(BlocklistMatch {
+            blocklist_name: "competitors".to_string(),
+            blocklist_item_id: "1".to_string(),
+            blocklist_item_text: "acme".to_string(),
+        });
+        let report = guardrail.evaluate(GuardrailStage::Output, &[response]);
+
+        let report = guardrail.enforce(report).unwrap().unwrap();
+        assert!(report.violations.is_empty());
+        assert_eq!(report.policy_violations(), vec!["blocklist:competitors"]);
+    }
+
+    #[test]
+    fn test_messages_text() {
+        let messages = vec![
+            ChatMessage {
+                role: MessageRole::System,
+                content: Some(MessageContent::Text("Be nice".to_string())),
+                ..Default::default()
+            },
+            ChatMessage {
+                role: MessageRole::Assistant,
+                content: None,
+                ..Default::default()
+            },
+            ChatMessage {
+                role: MessageRole::User,
+                content: Some(MessageContent::Text("Hello".to_string())),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(messages_text(&messages), "Be nice\nHello");
+    }
+
+    #[test]
+    fn test_split_text() {
+        assert!(split_text("", 4).is_empty());
+        assert_eq!(split_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(split_text("héllo", 2), vec!["hé", "ll", "o"]);
+    }
+
+    #[test]
+    fn test_parse_error_message() {
+        let body = r#"{"error":{"code":"InvalidRequestBody","message":"Text is empty"}}"#;
+        assert_eq!(parse_error_message(body), "Text is empty");
+        assert_eq!(parse_error_message("Bad Gateway"), "Bad Gateway");
+    }
+}
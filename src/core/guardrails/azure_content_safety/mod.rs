@@ -0,0 +1,17 @@
+//! Azure AI Content Safety guardrail
+//!
+//! Sends prompt and completion text to the Content Safety `text:analyze`
+//! endpoint and compares the returned severities (0, 2, 4 or 6 per harm
+//! category) with configurable thresholds. Custom blocklist hits always count
+//! as a violation.
+
+pub mod config;
+pub mod guardrail;
+pub mod types;
+
+pub use config::AzureContentSafetyConfig;
+pub use guardrail::AzureContentSafetyGuardrail;
+pub use types::{
+    AnalyzeTextRequest, AnalyzeTextResponse, BlocklistMatch, CategoryAnalysis, CategoryViolation,
+    ContentSafetyReport, HarmCategory,
+};
@@ -0,0 +1,243 @@
+//! Azure AI Content Safety wire types and analysis reports
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use crate::core::guardrails::GuardrailStage;
+
+/// Harm categories scored by the text analysis API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HarmCategory {
+    Hate,
+    SelfHarm,
+    Sexual,
+    Violence,
+}
+
+impl HarmCategory {
+    pub const ALL: [Self; 4] = [Self::Hate, Self::SelfHarm, Self::Sexual, Self::Violence];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hate => "Hate",
+            Self::SelfHarm => "SelfHarm",
+            Self::Sexual => "Sexual",
+            Self::Violence => "Violence",
+        }
+    }
+}
+
+/// `text:analyze` request body
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzeTextRequest<'a> {
+    pub text: &'a str,
+    pub categories: &'a [HarmCategory],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub blocklist_names: &'a [String],
+    /// Keep scoring categories even when a blocklist matched, so the report
+    /// is complete
+    pub halt_on_blocklist_hit: bool,
+    pub output_type: &'static str,
+}
+
+/// `text:analyze` response body
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzeTextResponse {
+    #[serde(default)]
+    pub blocklists_match: Vec<BlocklistMatch>,
+    #[serde(default)]
+    pub categories_analysis: Vec<CategoryAnalysis>,
+}
+
+/// A custom blocklist item found in the text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlocklistMatch {
+    pub blocklist_name: String,
+    pub blocklist_item_id: String,
+    pub blocklist_item_text: String,
+}
+
+/// Severity of one harm category
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryAnalysis {
+    pub category: HarmCategory,
+    #[serde(default)]
+    pub severity: u8,
+}
+
+/// A category whose severity reached its threshold
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryViolation {
+    pub category: HarmCategory,
+    pub severity: u8,
+    pub threshold: u8,
+}
+
+/// Outcome of screening one side of a provider call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentSafetyReport {
+    pub stage: GuardrailStage,
+    /// Highest severity seen per category across all analyzed chunks
+    pub categories: Vec<CategoryAnalysis>,
+    pub violations: Vec<CategoryViolation>,
+    pub blocklist_matches: Vec<BlocklistMatch>,
+}
+
+impl ContentSafetyReport {
+    /// Whether any threshold was reached or blocklist matched
+    pub fn is_flagged(&self) -> bool {
+        !self.violations.is_empty() || !self.blocklist_matches.is_empty()
+    }
+
+    /// Names of violated categories and matched blocklists
+    pub fn policy_violations(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .violations
+            .iter()
+            .map(|v| v.category.as_str().to_string())
+            .collect();
+        for m in &self.blocklist_matches {
+            let name = format!("blocklist:{}", m.blocklist_name);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Human-readable summary used in error messages
+    pub fn reason(&self) -> String {
+        let mut parts: Vec<String> = self
+            .violations
+            .iter()
+            .map(|v| {
+                format!(
+                    "{} severity {} (threshold {})",
+                    v.category.as_str(),
+                    v.severity,
+                    v.threshold
+                )
+            })
+            .collect();
+        if !self.blocklist_matches.is_empty() {
+            parts.push(format!(
+                "{} blocklist match(es)",
+                self.blocklist_matches.len()
+            ));
+        }
+        format!("{} flagged: {}", self.stage.as_str(), parts.join(", "))
+    }
+
+    /// Attach the report to response fields under
+    /// `guardrails.azure_content_safety.<stage>`
+    pub fn annotate(&self, fields: &mut Option<HashMap<String, Value>>) {
+        let guardrails = fields
+            .get_or_insert_with(HashMap::new)
+            .entry("guardrails".to_string())
+            .or_insert_with(|| json!({}));
+        if let Some(guardrails) = guardrails.as_object_mut() {
+            let entry = guardrails
+                .entry("azure_content_safety")
+                .or_insert_with(|| json!({}));
+            if let Some(entry) = entry.as_object_mut() {
+                entry.insert(
+                    self.stage.as_str().to_string(),
+                    serde_json::to_value(self).unwrap_or_default(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_serialization() {
+        let blocklists = vec!["competitors".to_string()];
+        let request = AnalyzeTextRequest {
+            text: "hello",
+            categories: &HarmCategory::ALL,
+            blocklist_names: &blocklists,
+            halt_on_blocklist_hit: false,
+            output_type: "FourSeverityLevels",
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["text"], "hello");
+        assert_eq!(
+            body["categories"],
+            json!(["Hate", "SelfHarm", "Sexual", "Violence"])
+        );
+        assert_eq!(body["blocklistNames"], json!(["competitors"]));
+        assert_eq!(body["haltOnBlocklistHit"], false);
+        assert_eq!(body["outputType"], "FourSeverityLevels");
+
+        let request = AnalyzeTextRequest {
+            blocklist_names: &[],
+            ..request
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert!(body.get("blocklistNames").is_none());
+    }
+
+    #[test]
+    fn test_response_deserialization() {
+        let response: AnalyzeTextResponse = serde_json::from_value(json!({
+            "blocklistsMatch": [{
+                "blocklistName": "competitors",
+                "blocklistItemId": "6b2b7b5e",
+                "blocklistItemText": "acme"
+            }],
+            "categoriesAnalysis": [
+                {"category": "Hate", "severity": 0},
+                {"category": "Violence", "severity": 4}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(response.blocklists_match[0].blocklist_item_text, "acme");
+        assert_eq!(
+            response.categories_analysis[1].category,
+            HarmCategory::Violence
+        );
+        assert_eq!(response.categories_analysis[1].severity, 4);
+
+        let empty: AnalyzeTextResponse = serde_json::from_value(json!({})).unwrap();
+        assert!(empty.categories_analysis.is_empty());
+    }
+
+    #[test]
+    fn test_report_annotate() {
+        let report = ContentSafetyReport {
+            stage: GuardrailStage::Output,
+            categories: vec![CategoryAnalysis {
+                category: HarmCategory::Hate,
+                severity: 4,
+            }],
+            violations: vec![CategoryViolation {
+                category: HarmCategory::Hate,
+                severity: 4,
+                threshold: 4,
+            }],
+            blocklist_matches: vec![],
+        };
+        assert!(report.is_flagged());
+        assert_eq!(report.policy_violations(), vec!["Hate"]);
+        assert_eq!(
+            report.reason(),
+            "output flagged: Hate severity 4 (threshold 4)"
+        );
+
+        let mut fields = None;
+        report.annotate(&mut fields);
+        let fields = fields.unwrap();
+        assert_eq!(
+            fields["guardrails"]["azure_content_safety"]["output"]["violations"][0]["severity"],
+            4
+        );
+    }
+}
@@ -0,0 +1,375 @@
+//! Banned content guardrail
+//!
+//! Scans completions for banned terms and regular expressions. Depending on
+//! the guardrail's mode, a completion with banned content is blocked, has the
+//! content replaced (`mask`, also spelled `replace`) or is annotated:
+//!
+//! ```yaml
+//! guardrails:
+//!   guardrails:
+//!     - name: banned-content
+//!       guardrail: banned_content
+//!       mode: replace
+//!       settings:
+//!         terms: [acme, "project falcon"]
+//!         patterns: ['\b\d{3}-\d{2}-\d{4}\b']
+//!         replacement: "[FILTERED]"
+//! ```
+//!
+//! Streamed completions are scanned with a rolling buffer: the last `window`
+//! characters are held back until more text arrives, so banned content split
+//! across chunks is replaced before any of it is sent. Matches longer than
+//! the window may be sent in part before they are caught.
+
+use async_trait::async_trait;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::guardrail::{Guardrail, GuardrailViolation};
+use crate::core::types::ChatMessage;
+use crate::utils::error::{GatewayError, Result};
+
+/// Name of the implementation in guardrail configurations
+pub const GUARDRAIL_NAME: &str = "banned_content";
+
+/// Configuration for the banned content guardrail
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BannedContentConfig {
+    /// Banned terms, matched as whole words regardless of case
+    #[serde(default)]
+    pub terms: Vec<String>,
+
+    /// Banned regular expressions
+    #[serde(default)]
+    pub patterns: Vec<String>,
+
+    /// Text banned content is replaced with
+    #[serde(default = "default_replacement")]
+    pub replacement: String,
+
+    /// Characters of streamed completions held back
+    #[serde(default = "default_window")]
+    pub window: usize,
+}
+
+fn default_replacement() -> String {
+    "[FILTERED]".to_string()
+}
+
+fn default_window() -> usize {
+    64
+}
+
+impl BannedContentConfig {
+    pub fn new(terms: Vec<String>, patterns: Vec<String>) -> Self {
+        Self {
+            terms,
+            patterns,
+            replacement: default_replacement(),
+            window: default_window(),
+        }
+    }
+}
+
+/// Flags completions containing banned terms or patterns
+#[derive(Debug, Clone)]
+pub struct BannedContentGuardrail {
+    config: BannedContentConfig,
+    /// Every term and pattern, as alternatives
+    regex: Regex,
+}
+
+impl BannedContentGuardrail {
+    pub fn new(config: BannedContentConfig) -> Result<Self> {
+        if config
+            .terms
+            .iter()
+            .chain(&config.patterns)
+            .all(String::is_empty)
+        {
+            return Err(GatewayError::Config(
+                "Banned content guardrail needs at least one term or pattern".to_string(),
+            ));
+        }
+
+        for pattern in &config.patterns {
+            Regex::new(pattern).map_err(|e| {
+                GatewayError::Config(format!("Invalid banned pattern {}: {}", pattern, e))
+            })?;
+        }
+        let alternatives: Vec<String> = config
+            .terms
+            .iter()
+            .filter(|term| !term.is_empty())
+            .map(|term| term_pattern(term))
+            .chain(
+                config
+                    .patterns
+                    .iter()
+                    .filter(|pattern| !pattern.is_empty())
+                    .map(|pattern| format!("(?:{})", pattern)),
+            )
+            .collect();
+        let regex = Regex::new(&alternatives.join("|"))
+            .map_err(|e| GatewayError::Config(format!("Invalid banned content: {}", e)))?;
+
+        Ok(Self { config, regex })
+    }
+
+    pub fn config(&self) -> &BannedContentConfig {
+        &self.config
+    }
+
+    /// Violation for the banned content in `texts`, if any
+    fn scan<'a>(&self, texts: impl IntoIterator<Item = &'a str>) -> Option<GuardrailViolation> {
+        let mut matches: Vec<&str> = Vec::new();
+        let mut count = 0;
+        for text in texts {
+            for found in self.regex.find_iter(text).filter(|m| !m.is_empty()) {
+                count += 1;
+                if !matches.contains(&found.as_str()) {
+                    matches.push(found.as_str());
+                }
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+
+        // Longer matches are replaced first, before any match they contain
+        matches.sort_by_key(|found| std::cmp::Reverse(found.len()));
+        let reason = format!("{} banned content match(es)", count);
+        Some(
+            matches
+                .into_iter()
+                .fold(GuardrailViolation::new(reason), |violation, found| {
+                    violation.with_replacement(found, &self.config.replacement)
+                }),
+        )
+    }
+}
+
+/// Case-insensitive pattern of a whole-word term
+fn term_pattern(term: &str) -> String {
+    let word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let boundary = |c| if word(c) { r"\b" } else { "" };
+    format!(
+        "(?i:{}{}{})",
+        boundary(term.chars().next()),
+        regex::escape(term),
+        boundary(term.chars().last())
+    )
+}
+
+#[async_trait]
+impl Guardrail for BannedContentGuardrail {
+    fn name(&self) -> &str {
+        GUARDRAIL_NAME
+    }
+
+    async fn post_call(&self, messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+        Ok(self.scan(messages_text(messages).iter().map(String::as_str)))
+    }
+
+    fn stream_window(&self) -> usize {
+        self.config.window
+    }
+
+    async fn on_stream(&self, text: &str, _finished: bool) -> Result<Option<GuardrailViolation>> {
+        Ok(self.scan([text]))
+    }
+}
+
+/// Text content of messages
+fn messages_text(messages: &[ChatMessage]) -> Vec<String> {
+    messages
+        .iter()
+        .filter_map(|m| m.content.as_ref())
+        .map(|c| c.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GuardrailConfig, GuardrailMode, GuardrailsConfig};
+    use crate::core::completion::{
+        Choice, CompletionChunk, CompletionResponse, StreamChoice, StreamDelta,
+    };
+    use crate::core::guardrails::Guardrails;
+    use crate::core::types::{MessageContent, MessageRole};
+    use futures::StreamExt;
+    use std::collections::HashMap;
+
+    fn guardrail() -> BannedContentGuardrail {
+        BannedContentGuardrail::new(BannedContentConfig::new(
+            vec!["Acme".to_string(), "c++".to_string()],
+            vec![r"\b\d{3}-\d{2}-\d{4}\b".to_string()],
+        ))
+        .unwrap()
+    }
+
+    fn pipeline(mode: GuardrailMode, window: usize) -> crate::core::guardrails::GuardrailPipeline {
+        let settings = serde_json::json!({
+            "terms": ["project falcon"],
+            "window": window,
+        });
+        let config = GuardrailsConfig {
+            guardrails: vec![GuardrailConfig {
+                mode,
+                settings: serde_json::from_value(settings).unwrap(),
+                ..GuardrailConfig::new("banned", GUARDRAIL_NAME)
+            }],
+            default: vec!["banned".to_string()],
+            ..Default::default()
+        };
+        Guardrails::from_config(&config)
+            .unwrap()
+            .pipeline("gpt-4o", None)
+    }
+
+    fn assistant(text: &str) -> ChatMessage {
+        ChatMessage {
+            role: MessageRole::Assistant,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    fn chunk(text: &str, finished: bool) -> CompletionChunk {
+        CompletionChunk {
+            id: "chunk".to_string(),
+            object: "chat.completion.chunk".to_string(),
+            created: 0,
+            model: "gpt-4o".to_string(),
+            choices: vec![StreamChoice {
+                index: 0,
+                delta: StreamDelta {
+                    content: Some(text.to_string()),
+                    ..Default::default()
+                },
+                finish_reason: finished.then_some(crate::core::types::FinishReason::Stop),
+            }],
+            provider_specific_fields: None,
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_config() {
+        assert!(BannedContentGuardrail::new(BannedContentConfig::new(vec![], vec![])).is_err());
+        let config = BannedContentConfig::new(vec![], vec!["(unclosed".to_string()]);
+        let err = BannedContentGuardrail::new(config).unwrap_err();
+        assert!(err.to_string().contains("(unclosed"), "{}", err);
+    }
+
+    #[test]
+    fn test_scan() {
+        let guardrail = guardrail();
+        let violation = guardrail
+            .scan(["ACME sells C++ tools to acme, SSN 123-45-6789"])
+            .unwrap();
+        assert_eq!(violation.reason, "4 banned content match(es)");
+        assert_eq!(
+            violation.mask("ACME sells C++ tools to acme, SSN 123-45-6789"),
+            "[FILTERED] sells [FILTERED] tools to [FILTERED], SSN [FILTERED]"
+        );
+
+        // Terms match whole words only
+        assert!(guardrail.scan(["Acmeville", "cc++"]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_modes() {
+        let text = "Project Falcon launches in May";
+
+        let err = pipeline(GuardrailMode::Block, 64)
+            .post_call(&mut [assistant(text)])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("banned content"), "{}", err);
+
+        let mut messages = [assistant(text)];
+        pipeline(GuardrailMode::Mask, 64)
+            .post_call(&mut messages)
+            .await
+            .unwrap();
+        assert_eq!(
+            messages[0].content.as_ref().unwrap().to_string(),
+            "[FILTERED] launches in May"
+        );
+
+        let mut response = CompletionResponse {
+            id: "resp".to_string(),
+            object: "chat.completion".to_string(),
+            created: 0,
+            model: "gpt-4o".to_string(),
+            choices: vec![Choice {
+                index: 0,
+                message: assistant(text),
+                finish_reason: None,
+            }],
+            usage: None,
+            provider_specific_fields: None,
+        };
+        pipeline(GuardrailMode::Annotate, 64)
+            .check_response(&mut response)
+            .await
+            .unwrap();
+        let fields: HashMap<_, _> = response.provider_specific_fields.unwrap();
+        assert_eq!(
+            fields["guardrails"]["banned"]["output"]["reason"],
+            "1 banned content match(es)"
+        );
+        assert_eq!(
+            response.choices[0]
+                .message
+                .content
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            text
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_rolling_buffer() {
+        let chunks = ["Our next launch is Pro", "ject Fal", "con, due in May.", ""];
+        let last = chunks.len() - 1;
+        let stream = futures::stream::iter(
+            chunks
+                .into_iter()
+                .enumerate()
+                .map(move |(i, text)| Ok(chunk(text, i == last))),
+        );
+
+        let guarded = pipeline(GuardrailMode::Mask, 16).guard_stream(Box::pin(stream));
+        let sent: Vec<String> = guarded
+            .map(|chunk| {
+                chunk.unwrap().choices[0]
+                    .delta
+                    .content
+                    .clone()
+                    .unwrap_or_default()
+            })
+            .collect()
+            .await;
+
+        assert_eq!(sent.concat(), "Our next launch is [FILTERED], due in May.");
+        // Text within the window is held back until the stream finishes
+        assert_eq!(sent[0], "Our ne");
+    }
+
+    #[tokio::test]
+    async fn test_stream_flushes_unfinished_choices() {
+        let stream = futures::stream::iter([Ok(chunk("all clear", false))]);
+        let guarded = pipeline(GuardrailMode::Block, 64).guard_stream(Box::pin(stream));
+        let sent: Vec<_> = guarded.map(Result::unwrap).collect().await;
+
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].choices[0].delta.content, None);
+        assert_eq!(
+            sent[1].choices[0].delta.content.as_deref(),
+            Some("all clear")
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Guardrail trait
+
+use crate::core::types::{ChatMessage, MessageContent, MessageRole};
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// A check run around provider calls
+///
+/// Every hook has a default that finds nothing, so guardrails implement only
+/// the stages they check. Hooks report what they find; whether the call is
+/// blocked, masked or only logged is decided by the configured
+/// [`GuardrailMode`](crate::config::GuardrailMode).
+#[async_trait]
+pub trait Guardrail: Send + Sync {
+    /// Name used in logs
+    fn name(&self) -> &str;
+
+    /// Check the prompt before it is sent to the provider
+    async fn pre_call(&self, _messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+        Ok(None)
+    }
+
+    /// Check completion messages before they are returned
+    async fn post_call(&self, _messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+        Ok(None)
+    }
+
+    /// Characters of a streamed completion held back before they are sent
+    ///
+    /// Held-back text can still be masked, so flagged text spanning chunks is
+    /// masked before any of it is sent.
+    fn stream_window(&self) -> usize {
+        0
+    }
+
+    /// Check a streamed completion as it arrives
+    ///
+    /// `text` is the completion so far, masked, and `finished` tells whether
+    /// the stream ended. By default the finished completion is checked with
+    /// [`post_call`](Self::post_call).
+    async fn on_stream(&self, text: &str, finished: bool) -> Result<Option<GuardrailViolation>> {
+        if !finished {
+            return Ok(None);
+        }
+        let message = ChatMessage {
+            role: MessageRole::Assistant,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        };
+        self.post_call(std::slice::from_ref(&message)).await
+    }
+}
+
+/// Content a guardrail flagged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardrailViolation {
+    /// Why the content was flagged
+    pub reason: String,
+    /// Flagged text and what it is masked with
+    pub replacements: Vec<(String, String)>,
+}
+
+impl GuardrailViolation {
+    /// Violation that cannot be masked
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            replacements: Vec::new(),
+        }
+    }
+
+    /// Mask `text` with `replacement`
+    pub fn with_replacement(
+        mut self,
+        text: impl Into<String>,
+        replacement: impl Into<String>,
+    ) -> Self {
+        self.replacements.push((text.into(), replacement.into()));
+        self
+    }
+
+    /// Whether the flagged content can be masked
+    pub fn can_mask(&self) -> bool {
+        !self.replacements.is_empty()
+    }
+
+    /// Mask the flagged text in `text`
+    pub fn mask(&self, text: &str) -> String {
+        self.replacements
+            .iter()
+            .filter(|(from, _)| !from.is_empty())
+            .fold(text.to_string(), |text, (from, to)| text.replace(from, to))
+    }
+}
@@ -0,0 +1,27 @@
+//! Content guardrails
+//!
+//! Checks that run around provider calls: the prompt is screened before it is
+//! sent and the completion is screened before it is returned, or as it
+//! streams. A [`Guardrail`] reports what it finds; the [`GuardrailPipeline`]
+//! of a call, made up from the `guardrails` configuration of its model group
+//! and API key, blocks, masks or logs the findings according to each
+//! guardrail's [`GuardrailMode`](crate::config::GuardrailMode).
+//!
+//! Guardrails used directly rather than from a pipeline follow their own
+//! [`GuardrailAction`]: flagged content is either rejected with a
+//! content-filter error or passed through with the findings attached to the
+//! response.
+
+pub mod azure_content_safety;
+pub mod banned_content;
+mod guardrail;
+mod pipeline;
+mod types;
+
+pub use azure_content_safety::{AzureContentSafetyConfig, AzureContentSafetyGuardrail};
+pub use banned_content::{BannedContentConfig, BannedContentGuardrail};
+pub use guardrail::{Guardrail, GuardrailViolation};
+pub use pipeline::{
+    GuardrailFactory, GuardrailPipeline, GuardrailRegistry, Guardrails, StreamGuard, settings_as,
+};
+pub use types::{GuardrailAction, GuardrailStage};
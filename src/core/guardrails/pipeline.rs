@@ -0,0 +1,706 @@
+//! Guardrail pipelines
+//!
+//! [`Guardrails`] holds the guardrails of a [`GuardrailsConfig`] and picks the
+//! [`GuardrailPipeline`] of each call from its model group and API key. The
+//! pipeline runs its guardrails in order and applies each one's mode to what
+//! it finds.
+
+use super::azure_content_safety::{AzureContentSafetyConfig, AzureContentSafetyGuardrail};
+use super::banned_content::{self, BannedContentConfig, BannedContentGuardrail};
+use super::guardrail::{Guardrail, GuardrailViolation};
+use super::types::GuardrailStage;
+use crate::config::{GuardrailConfig, GuardrailMode, GuardrailsConfig};
+use crate::core::completion::{
+    CompletionChunk, CompletionResponse, CompletionStream, StreamChoice, StreamDelta,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{ChatMessage, ContentPart, MessageContent};
+use crate::utils::error::{GatewayError, Result};
+use futures::StreamExt;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+/// Name reported in content-filter errors
+const PIPELINE_NAME: &str = "guardrails";
+
+/// Creates a guardrail from its settings
+pub type GuardrailFactory =
+    Box<dyn Fn(&HashMap<String, Value>) -> Result<Arc<dyn Guardrail>> + Send + Sync>;
+
+/// Guardrail implementations by name
+pub struct GuardrailRegistry {
+    factories: HashMap<String, GuardrailFactory>,
+}
+
+impl Default for GuardrailRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            factories: HashMap::new(),
+        };
+        registry.register("azure_content_safety", |settings| {
+            let config: AzureContentSafetyConfig = settings_as(settings)?;
+            Ok(Arc::new(AzureContentSafetyGuardrail::new(config)?))
+        });
+        registry.register(banned_content::GUARDRAIL_NAME, |settings| {
+            let config: BannedContentConfig = settings_as(settings)?;
+            Ok(Arc::new(BannedContentGuardrail::new(config)?))
+        });
+        registry
+    }
+}
+
+impl GuardrailRegistry {
+    /// Registry of the built-in implementations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an implementation, replacing one of the same name
+    pub fn register<F>(&mut self, guardrail: impl Into<String>, factory: F)
+    where
+        F: Fn(&HashMap<String, Value>) -> Result<Arc<dyn Guardrail>> + Send + Sync + 'static,
+    {
+        self.factories.insert(guardrail.into(), Box::new(factory));
+    }
+
+    /// Whether an implementation is registered
+    pub fn contains(&self, guardrail: &str) -> bool {
+        self.factories.contains_key(guardrail)
+    }
+
+    /// Create the configured guardrails
+    pub fn build(&self, config: &GuardrailsConfig) -> Result<Guardrails> {
+        config
+            .validate(|guardrail| self.contains(guardrail))
+            .map_err(GatewayError::Config)?;
+
+        let mut steps = HashMap::new();
+        for guardrail in &config.guardrails {
+            let factory = &self.factories[&guardrail.guardrail];
+            let created = factory(&guardrail.settings).map_err(|e| {
+                GatewayError::Config(format!("Guardrail {}: {}", guardrail.name, e))
+            })?;
+            steps.insert(
+                guardrail.name.clone(),
+                Arc::new(Step::new(guardrail, created)),
+            );
+        }
+
+        Ok(Guardrails {
+            steps,
+            default: config.default.clone(),
+            model_groups: config.model_groups.clone(),
+            api_keys: config.api_keys.clone(),
+        })
+    }
+}
+
+/// Deserialize guardrail settings
+pub fn settings_as<T: serde::de::DeserializeOwned>(settings: &HashMap<String, Value>) -> Result<T> {
+    let settings = Value::Object(settings.clone().into_iter().collect());
+    serde_json::from_value(settings)
+        .map_err(|e| GatewayError::Config(format!("Invalid guardrail settings: {}", e)))
+}
+
+/// A configured guardrail
+struct Step {
+    name: String,
+    mode: GuardrailMode,
+    pre_call: bool,
+    post_call: bool,
+    streaming: bool,
+    guardrail: Arc<dyn Guardrail>,
+}
+
+impl Step {
+    fn new(config: &GuardrailConfig, guardrail: Arc<dyn Guardrail>) -> Self {
+        Self {
+            name: config.name.clone(),
+            mode: config.mode,
+            pre_call: config.pre_call,
+            post_call: config.post_call,
+            streaming: config.streaming,
+            guardrail,
+        }
+    }
+
+    /// Content-filter error for a blocked violation
+    fn blocked(&self, stage: GuardrailStage, violation: &GuardrailViolation) -> GatewayError {
+        ProviderError::content_filtered(
+            PIPELINE_NAME,
+            format!("{}: {}", self.name, violation.reason),
+            Some(vec![self.name.clone()]),
+            Some(stage == GuardrailStage::Input),
+        )
+        .into()
+    }
+
+    /// Apply the mode to a violation
+    ///
+    /// Returns whether the content is to be masked.
+    fn enforce(&self, stage: GuardrailStage, violation: &GuardrailViolation) -> Result<bool> {
+        match self.mode {
+            GuardrailMode::Mask if violation.can_mask() => {
+                debug!(
+                    "Guardrail {} masked {} content: {}",
+                    self.name,
+                    stage.as_str(),
+                    violation.reason
+                );
+                Ok(true)
+            }
+            GuardrailMode::Block | GuardrailMode::Mask => Err(self.blocked(stage, violation)),
+            GuardrailMode::Annotate | GuardrailMode::LogOnly => {
+                warn!(
+                    "Guardrail {} flagged {} content: {}",
+                    self.name,
+                    stage.as_str(),
+                    violation.reason
+                );
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// The configured guardrails
+#[derive(Clone, Default)]
+pub struct Guardrails {
+    steps: HashMap<String, Arc<Step>>,
+    default: Vec<String>,
+    model_groups: HashMap<String, Vec<String>>,
+    api_keys: HashMap<String, Vec<String>>,
+}
+
+impl std::fmt::Debug for Guardrails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Guardrails")
+            .field("guardrails", &self.steps.keys().collect::<Vec<_>>())
+            .field("default", &self.default)
+            .field("model_groups", &self.model_groups)
+            .field("api_keys", &self.api_keys)
+            .finish()
+    }
+}
+
+impl Guardrails {
+    /// Create the guardrails of a configuration with the built-in implementations
+    pub fn from_config(config: &GuardrailsConfig) -> Result<Self> {
+        GuardrailRegistry::default().build(config)
+    }
+
+    /// Whether no guardrail is configured
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Pipeline of a call to a model group, made with an API key
+    pub fn pipeline(&self, model: &str, api_key_id: Option<&str>) -> GuardrailPipeline {
+        let names = self
+            .default
+            .iter()
+            .chain(self.model_groups.get(model).into_iter().flatten())
+            .chain(
+                api_key_id
+                    .and_then(|key| self.api_keys.get(key))
+                    .into_iter()
+                    .flatten(),
+            );
+
+        let mut seen = HashSet::new();
+        let steps = names
+            .filter(|name| seen.insert(name.as_str()))
+            .filter_map(|name| self.steps.get(name).cloned())
+            .collect();
+        GuardrailPipeline {
+            steps,
+            annotations: Arc::default(),
+        }
+    }
+}
+
+/// Guardrails run on one call, in order
+#[derive(Clone, Default)]
+pub struct GuardrailPipeline {
+    steps: Vec<Arc<Step>>,
+    /// Findings of annotating guardrails, by guardrail and stage
+    annotations: Arc<Mutex<Map<String, Value>>>,
+}
+
+impl std::fmt::Debug for GuardrailPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = self.steps.iter().map(|step| &step.name).collect();
+        f.debug_tuple("GuardrailPipeline").field(&names).finish()
+    }
+}
+
+impl GuardrailPipeline {
+    /// Whether no guardrail runs
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Check the prompt, masking it in place
+    ///
+    /// A blocked prompt is a content-filter error.
+    pub async fn pre_call(&self, messages: &mut [ChatMessage]) -> Result<()> {
+        self.check(GuardrailStage::Input, messages).await
+    }
+
+    /// Check completion messages, masking them in place
+    ///
+    /// A blocked completion is a content-filter error.
+    pub async fn post_call(&self, messages: &mut [ChatMessage]) -> Result<()> {
+        self.check(GuardrailStage::Output, messages).await
+    }
+
+    async fn check(&self, stage: GuardrailStage, messages: &mut [ChatMessage]) -> Result<()> {
+        for step in &self.steps {
+            let violation = match stage {
+                GuardrailStage::Input if step.pre_call => step.guardrail.pre_call(messages),
+                GuardrailStage::Output if step.post_call => step.guardrail.post_call(messages),
+                _ => continue,
+            };
+            let Some(violation) = violation.await? else {
+                continue;
+            };
+            if step.enforce(stage, &violation)? {
+                messages
+                    .iter_mut()
+                    .for_each(|m| mask_message(m, &violation));
+            } else if step.mode == GuardrailMode::Annotate {
+                self.annotate(step, stage, &violation);
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the finding of an annotating guardrail
+    fn annotate(&self, step: &Step, stage: GuardrailStage, violation: &GuardrailViolation) {
+        let mut annotations = self.annotations.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = annotations
+            .entry(step.name.clone())
+            .or_insert_with(|| json!({}));
+        entry[stage.as_str()] = json!({ "reason": violation.reason });
+    }
+
+    /// Check the messages of each choice of a response, masking them in place
+    ///
+    /// Findings of annotating guardrails, including those on the prompt, are
+    /// attached to the response's `guardrails` field.
+    pub async fn check_response(&self, response: &mut CompletionResponse) -> Result<()> {
+        for choice in &mut response.choices {
+            self.post_call(std::slice::from_mut(&mut choice.message))
+                .await?;
+        }
+
+        let annotations = self.annotations.lock().unwrap_or_else(|e| e.into_inner());
+        if !annotations.is_empty() {
+            let guardrails = response
+                .provider_specific_fields
+                .get_or_insert_with(HashMap::new)
+                .entry("guardrails".to_string())
+                .or_insert_with(|| json!({}));
+            if let Some(guardrails) = guardrails.as_object_mut() {
+                guardrails.extend(annotations.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Guard for a streamed completion
+    pub fn stream(&self) -> StreamGuard {
+        let steps: Vec<_> = self
+            .steps
+            .iter()
+            .filter(|step| step.streaming)
+            .cloned()
+            .collect();
+        StreamGuard {
+            window: steps
+                .iter()
+                .map(|step| step.guardrail.stream_window())
+                .max()
+                .unwrap_or(0),
+            steps,
+            choices: BTreeMap::new(),
+            template: None,
+            logged: HashSet::new(),
+        }
+    }
+
+    /// Run the guardrails on a completion stream
+    ///
+    /// Chunks are checked and masked as they arrive; a blocked stream ends
+    /// with a content-filter error.
+    pub fn guard_stream(&self, mut stream: CompletionStream) -> CompletionStream {
+        let mut guard = self.stream();
+        if guard.steps.is_empty() {
+            return stream;
+        }
+        Box::pin(async_stream::stream! {
+            while let Some(chunk) = stream.next().await {
+                let chunk = match chunk {
+                    Ok(mut chunk) => guard.check_chunk(&mut chunk).await.map(|()| chunk),
+                    Err(e) => Err(e),
+                };
+                let failed = chunk.is_err();
+                yield chunk;
+                if failed {
+                    return;
+                }
+            }
+            match guard.finish().await {
+                Ok(Some(chunk)) => yield Ok(chunk),
+                Ok(None) => {}
+                Err(e) => yield Err(e),
+            }
+        })
+    }
+}
+
+/// Streamed text of a choice
+#[derive(Default)]
+struct ChoiceText {
+    /// Completion so far, masked
+    text: String,
+    /// Bytes of `text` sent
+    sent: usize,
+    /// Whether the choice finished
+    finished: bool,
+}
+
+/// Checks a streamed completion chunk by chunk
+///
+/// Guardrails see each choice's completion so far. The last
+/// [`stream_window`](Guardrail::stream_window) characters are held back until
+/// more text arrives or the choice finishes, so masking applies to them as
+/// well as to the chunk being checked; text already sent stays as it was. A
+/// blocked stream is a content-filter error, after which no more chunks are
+/// to be sent.
+pub struct StreamGuard {
+    steps: Vec<Arc<Step>>,
+    /// Characters held back
+    window: usize,
+    choices: BTreeMap<u32, ChoiceText>,
+    /// Chunk whose ID and model the final chunk reuses
+    template: Option<CompletionChunk>,
+    /// Guardrails that already logged a finding, per choice
+    logged: HashSet<(u32, String)>,
+}
+
+impl StreamGuard {
+    /// Check the next chunk, masking its content in place
+    ///
+    /// The content becomes the text that is ready to be sent.
+    pub async fn check_chunk(&mut self, chunk: &mut CompletionChunk) -> Result<()> {
+        if self.steps.is_empty() {
+            return Ok(());
+        }
+        if self.template.is_none() {
+            self.template = Some(CompletionChunk {
+                choices: Vec::new(),
+                ..chunk.clone()
+            });
+        }
+        for choice in &mut chunk.choices {
+            let delta = choice.delta.content.take().unwrap_or_default();
+            let finished = choice.finish_reason.is_some();
+            let ready = self.check(choice.index, &delta, finished).await?;
+            choice.delta.content = (!ready.is_empty()).then_some(ready);
+        }
+        Ok(())
+    }
+
+    /// Check choices the stream ended without finishing
+    ///
+    /// Returns a chunk with their held-back text, if any.
+    pub async fn finish(&mut self) -> Result<Option<CompletionChunk>> {
+        let unfinished: Vec<u32> = self
+            .choices
+            .iter()
+            .filter(|(_, choice)| !choice.finished)
+            .map(|(index, _)| *index)
+            .collect();
+
+        let mut choices = Vec::new();
+        for index in unfinished {
+            let ready = self.check(index, "", true).await?;
+            if !ready.is_empty() {
+                choices.push(StreamChoice {
+                    index,
+                    delta: StreamDelta {
+                        content: Some(ready),
+                        ..Default::default()
+                    },
+                    finish_reason: None,
+                });
+            }
+        }
+
+        Ok(match (self.template.take(), choices.is_empty()) {
+            (Some(template), false) => Some(CompletionChunk {
+                choices,
+                ..template
+            }),
+            _ => None,
+        })
+    }
+
+    /// Check a choice's next text, returning the text ready to be sent
+    async fn check(&mut self, index: u32, delta: &str, finished: bool) -> Result<String> {
+        let choice = self.choices.entry(index).or_default();
+        choice.text.push_str(delta);
+        choice.finished = finished;
+
+        for step in &self.steps {
+            let Some(violation) = step.guardrail.on_stream(&choice.text, finished).await? else {
+                continue;
+            };
+            // Report a finding once rather than for every following chunk
+            if matches!(step.mode, GuardrailMode::Annotate | GuardrailMode::LogOnly)
+                && !self.logged.insert((index, step.name.clone()))
+            {
+                continue;
+            }
+            if step.enforce(GuardrailStage::Output, &violation)? {
+                let unsent = violation.mask(&choice.text[choice.sent..]);
+                choice.text.truncate(choice.sent);
+                choice.text.push_str(&unsent);
+            }
+        }
+
+        let end = if finished {
+            choice.text.len()
+        } else {
+            held_back_from(&choice.text, choice.sent, self.window)
+        };
+        let ready = choice.text[choice.sent..end].to_string();
+        choice.sent = end;
+        Ok(ready)
+    }
+}
+
+/// Start of the last `window` characters of `text`, but not before `sent`
+fn held_back_from(text: &str, sent: usize, window: usize) -> usize {
+    if window == 0 {
+        return text.len();
+    }
+    text[sent..]
+        .char_indices()
+        .rev()
+        .nth(window - 1)
+        .map_or(sent, |(offset, _)| sent + offset)
+}
+
+/// Mask the text of a message
+fn mask_message(message: &mut ChatMessage, violation: &GuardrailViolation) {
+    match &mut message.content {
+        Some(MessageContent::Text(text)) => *text = violation.mask(text),
+        Some(MessageContent::Parts(parts)) => {
+            for part in parts {
+                if let ContentPart::Text { text, .. } = part {
+                    *text = violation.mask(text);
+                }
+            }
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::MessageRole;
+    use async_trait::async_trait;
+
+    /// Flags `word`, masking it with asterisks
+    struct WordGuardrail {
+        word: String,
+    }
+
+    #[async_trait]
+    impl Guardrail for WordGuardrail {
+        fn name(&self) -> &str {
+            "word"
+        }
+
+        async fn pre_call(&self, messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+            self.post_call(messages).await
+        }
+
+        async fn post_call(&self, messages: &[ChatMessage]) -> Result<Option<GuardrailViolation>> {
+            let found = messages
+                .iter()
+                .filter_map(|m| m.content.as_ref())
+                .any(|c| c.to_string().contains(&self.word));
+            Ok(found.then(|| self.violation()))
+        }
+
+        async fn on_stream(
+            &self,
+            text: &str,
+            _finished: bool,
+        ) -> Result<Option<GuardrailViolation>> {
+            Ok(text.contains(&self.word).then(|| self.violation()))
+        }
+    }
+
+    impl WordGuardrail {
+        fn violation(&self) -> GuardrailViolation {
+            GuardrailViolation::new(format!("contains {}", self.word))
+                .with_replacement(&self.word, "*".repeat(self.word.len()))
+        }
+    }
+
+    fn guardrails(config: GuardrailsConfig) -> Guardrails {
+        let mut registry = GuardrailRegistry::new();
+        registry.register("word", |settings| {
+            let word = settings["word"].as_str().unwrap().to_string();
+            Ok(Arc::new(WordGuardrail { word }))
+        });
+        registry.build(&config).unwrap()
+    }
+
+    fn word(name: &str, word: &str, mode: GuardrailMode) -> GuardrailConfig {
+        GuardrailConfig {
+            mode,
+            settings: HashMap::from([("word".to_string(), Value::from(word))]),
+            ..GuardrailConfig::new(name, "word")
+        }
+    }
+
+    fn user(text: &str) -> ChatMessage {
+        ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    fn chunk(text: &str) -> CompletionChunk {
+        CompletionChunk {
+            id: "chunk".to_string(),
+            object: "chat.completion.chunk".to_string(),
+            created: 0,
+            model: "gpt-4o".to_string(),
+            choices: vec![StreamChoice {
+                index: 0,
+                delta: StreamDelta {
+                    role: None,
+                    content: Some(text.to_string()),
+                    tool_calls: None,
+                },
+                finish_reason: None,
+            }],
+            provider_specific_fields: None,
+        }
+    }
+
+    #[test]
+    fn test_pipeline_selection() {
+        let guardrails = guardrails(GuardrailsConfig {
+            guardrails: vec![
+                word("a", "a", GuardrailMode::Block),
+                word("b", "b", GuardrailMode::Block),
+                word("c", "c", GuardrailMode::Block),
+            ],
+            default: vec!["a".to_string()],
+            model_groups: HashMap::from([(
+                "gpt-4o".to_string(),
+                vec!["b".to_string(), "a".to_string()],
+            )]),
+            api_keys: HashMap::from([("key-1".to_string(), vec!["c".to_string()])]),
+        });
+
+        let names = |pipeline: GuardrailPipeline| {
+            pipeline
+                .steps
+                .iter()
+                .map(|step| step.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(guardrails.pipeline("claude", None)), ["a"]);
+        assert_eq!(
+            names(guardrails.pipeline("gpt-4o", Some("key-1"))),
+            ["a", "b", "c"]
+        );
+        assert!(Guardrails::default().pipeline("gpt-4o", None).is_empty());
+    }
+
+    #[test]
+    fn test_build_rejects_unknown_guardrail() {
+        let config = GuardrailsConfig {
+            guardrails: vec![GuardrailConfig::new("pii", "presidio")],
+            ..Default::default()
+        };
+        let err = Guardrails::from_config(&config).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown implementation"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pre_call_modes() {
+        let config = |mode| GuardrailsConfig {
+            guardrails: vec![word("secret", "secret", mode)],
+            default: vec!["secret".to_string()],
+            ..Default::default()
+        };
+
+        let pipeline = guardrails(config(GuardrailMode::Block)).pipeline("gpt-4o", None);
+        let mut messages = vec![user("the secret is 42")];
+        let err = pipeline.pre_call(&mut messages).await.unwrap_err();
+        assert!(
+            err.to_string().contains("secret: contains secret"),
+            "{}",
+            err
+        );
+        pipeline.pre_call(&mut [user("hello")]).await.unwrap();
+
+        let pipeline = guardrails(config(GuardrailMode::Mask)).pipeline("gpt-4o", None);
+        pipeline.pre_call(&mut messages).await.unwrap();
+        assert_eq!(
+            messages[0].content.as_ref().unwrap().to_string(),
+            "the ****** is 42"
+        );
+
+        let pipeline = guardrails(config(GuardrailMode::LogOnly)).pipeline("gpt-4o", None);
+        let mut messages = vec![user("the secret is 42")];
+        pipeline.post_call(&mut messages).await.unwrap();
+        assert_eq!(
+            messages[0].content.as_ref().unwrap().to_string(),
+            "the secret is 42"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_guard() {
+        let config = |mode| GuardrailsConfig {
+            guardrails: vec![word("secret", "secret", mode)],
+            default: vec!["secret".to_string()],
+            ..Default::default()
+        };
+
+        let pipeline = guardrails(config(GuardrailMode::Mask)).pipeline("gpt-4o", None);
+        let mut guard = pipeline.stream();
+        let mut first = chunk("the ");
+        guard.check_chunk(&mut first).await.unwrap();
+        let mut second = chunk("secret is 42");
+        guard.check_chunk(&mut second).await.unwrap();
+        assert_eq!(
+            second.choices[0].delta.content.as_deref(),
+            Some("****** is 42")
+        );
+        guard.finish().await.unwrap();
+
+        let pipeline = guardrails(config(GuardrailMode::Block)).pipeline("gpt-4o", None);
+        let mut guard = pipeline.stream();
+        guard.check_chunk(&mut chunk("the sec")).await.unwrap();
+        assert!(guard.check_chunk(&mut chunk("ret")).await.is_err());
+    }
+}
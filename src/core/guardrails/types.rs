@@ -0,0 +1,70 @@
+//! Types shared by guardrail integrations
+
+use serde::{Deserialize, Serialize};
+
+/// What to do with content that a guardrail flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailAction {
+    /// Reject the request or response with a content-filter error
+    #[default]
+    Block,
+    /// Let the content through and attach the findings to the response
+    Annotate,
+}
+
+impl std::str::FromStr for GuardrailAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "block" => Ok(Self::Block),
+            "annotate" => Ok(Self::Annotate),
+            other => Err(format!("Unknown guardrail action: {}", other)),
+        }
+    }
+}
+
+/// Which side of the provider call is being checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailStage {
+    /// Prompt messages, before the provider is called
+    Input,
+    /// Completion messages, after the provider responded
+    Output,
+}
+
+impl GuardrailStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Output => "output",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guardrail_action_from_str() {
+        assert_eq!(
+            "block".parse::<GuardrailAction>(),
+            Ok(GuardrailAction::Block)
+        );
+        assert_eq!(
+            "Annotate".parse::<GuardrailAction>(),
+            Ok(GuardrailAction::Annotate)
+        );
+        assert!("drop".parse::<GuardrailAction>().is_err());
+    }
+
+    #[test]
+    fn test_guardrail_action_serde() {
+        let action: GuardrailAction = serde_json::from_str("\"annotate\"").unwrap();
+        assert_eq!(action, GuardrailAction::Annotate);
+        assert_eq!(GuardrailAction::default(), GuardrailAction::Block);
+    }
+}
@@ -12,7 +12,10 @@ pub mod batch;
 pub mod cache_manager;
 pub mod completion; // Core completion API
 pub mod cost; // Unified cost calculation system
+pub mod files; // Files API (uploads referenced by batch and fine-tuning jobs)
+pub mod fine_tuning; // Fine-tuning jobs API (OpenAI, Azure OpenAI)
 pub mod function_calling; // Function calling support for AI providers
+pub mod guardrails; // Content safety checks around provider calls
 pub mod health; // Health monitoring system
 pub mod mcp; // MCP (Model Context Protocol) Gateway
 pub mod models;
@@ -20,10 +23,12 @@ pub mod observability; // Advanced observability and monitoring
 pub mod providers;
 pub mod rate_limiter; // Rate limiting system
 pub mod rerank; // Rerank API for RAG systems
+pub mod response_cache; // Exact-match response caching
 pub mod router;
 pub mod security;
 pub mod semantic_cache;
 pub mod streaming;
+pub mod tokenizers; // Token counting with provider-specific tokenizers
 pub mod traits;
 pub mod types;
 // User and team management - disabled until database methods are implemented
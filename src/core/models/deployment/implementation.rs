@@ -178,8 +178,10 @@ mod tests {
             name: "test-provider".to_string(),
             provider_type: "openai".to_string(),
             api_key: "test-key".to_string(),
+            api_keys: Vec::new(),
             base_url: None,
             models: vec!["gpt-4".to_string()],
+            model_name: None,
             timeout: 30,
             max_retries: 3,
             organization: None,
@@ -194,6 +196,10 @@ mod tests {
             health_check: crate::config::HealthCheckConfig::default(),
             settings: HashMap::new(),
             tags: vec!["test".to_string()],
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
         };
 
         let deployment = Deployment::new(config);
@@ -208,6 +214,7 @@ mod tests {
             name: "test-provider".to_string(),
             provider_type: "openai".to_string(),
             api_key: "test-key".to_string(),
+            api_keys: Vec::new(),
             base_url: None,
             api_version: None,
             organization: None,
@@ -222,7 +229,12 @@ mod tests {
             health_check: crate::config::HealthCheckConfig::default(),
             settings: HashMap::new(),
             models: vec![],
+            model_name: None,
             tags: vec![],
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
             enabled: true,
         };
 
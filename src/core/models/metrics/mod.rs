@@ -7,6 +7,8 @@ pub mod cache;
 pub mod cost;
 pub mod error;
 pub mod request;
+pub mod request_log;
+pub mod spend;
 pub mod token;
 
 // Re-export all public types
@@ -15,4 +17,6 @@ pub use cache::*;
 pub use cost::*;
 pub use error::*;
 pub use request::*;
+pub use request_log::*;
+pub use spend::*;
 pub use token::*;
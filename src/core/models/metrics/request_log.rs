@@ -0,0 +1,46 @@
+//! Request audit log models
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// One call as recorded in the request audit log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestLogEntry {
+    /// Request ID
+    pub request_id: String,
+    /// Masked API key the request was made with
+    pub key_alias: Option<String>,
+    /// HTTP method
+    pub method: String,
+    /// Request path
+    pub path: String,
+    /// Model requested
+    pub model: Option<String>,
+    /// Provider serving the model
+    pub provider: Option<String>,
+    /// Prompt tokens
+    pub prompt_tokens: u32,
+    /// Completion tokens
+    pub completion_tokens: u32,
+    /// Cost in USD
+    pub cost: f64,
+    /// Time until the response started, in milliseconds
+    pub latency_ms: u64,
+    /// HTTP status code
+    pub status_code: u16,
+    /// Error message for failed calls
+    pub error: Option<String>,
+    /// Request payload, redacted
+    pub request_payload: Option<Value>,
+    /// Response payload, redacted
+    pub response_payload: Option<Value>,
+    /// When the request was received
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl RequestLogEntry {
+    /// Total tokens
+    pub fn total_tokens(&self) -> u32 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
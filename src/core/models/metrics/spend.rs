@@ -0,0 +1,96 @@
+//! Spend tracking models
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Spend of one API key, team, user and model on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpendRecord {
+    /// Day the spend was incurred (UTC)
+    pub date: NaiveDate,
+    /// Masked API key
+    pub api_key: Option<String>,
+    /// SHA-256 hash of the API key, which budgets are kept by
+    #[serde(skip)]
+    pub key_hash: Option<String>,
+    /// Team ID
+    pub team_id: Option<String>,
+    /// User ID
+    pub user_id: Option<String>,
+    /// Model
+    pub model: String,
+    /// Cost in USD
+    pub spend: f64,
+    /// Prompt tokens
+    pub prompt_tokens: u64,
+    /// Completion tokens
+    pub completion_tokens: u64,
+    /// Number of requests
+    pub requests: u64,
+}
+
+/// Filter for spend queries; unset fields match everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpendFilter {
+    /// First day, inclusive
+    pub start_date: Option<NaiveDate>,
+    /// Last day, inclusive
+    pub end_date: Option<NaiveDate>,
+    /// Masked API key
+    pub api_key: Option<String>,
+    /// SHA-256 hash of the API key
+    #[serde(skip)]
+    pub key_hash: Option<String>,
+    /// Team ID
+    pub team_id: Option<String>,
+    /// User ID
+    pub user_id: Option<String>,
+    /// Model
+    pub model: Option<String>,
+}
+
+/// Spend totals
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpendTotals {
+    /// Cost in USD
+    pub spend: f64,
+    /// Prompt tokens
+    pub prompt_tokens: u64,
+    /// Completion tokens
+    pub completion_tokens: u64,
+    /// Number of requests
+    pub requests: u64,
+}
+
+impl SpendTotals {
+    /// Add a record to the totals
+    pub fn add(&mut self, record: &SpendRecord) {
+        self.spend += record.spend;
+        self.prompt_tokens += record.prompt_tokens;
+        self.completion_tokens += record.completion_tokens;
+        self.requests += record.requests;
+    }
+}
+
+/// Spend on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailySpend {
+    /// Day (UTC)
+    pub date: NaiveDate,
+    /// Totals for the day
+    #[serde(flatten)]
+    pub totals: SpendTotals,
+    /// Spend per model
+    pub models: BTreeMap<String, f64>,
+}
+
+/// Spend of one API key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeySpend {
+    /// Masked API key
+    pub api_key: String,
+    /// Totals for the key
+    #[serde(flatten)]
+    pub totals: SpendTotals,
+}
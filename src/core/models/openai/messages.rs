@@ -75,6 +75,12 @@ pub enum ContentPart {
         /// Audio content details
         audio: AudioContent,
     },
+    /// Input audio content part
+    #[serde(rename = "input_audio")]
+    InputAudio {
+        /// Audio content details
+        input_audio: AudioContent,
+    },
 }
 
 /// Image URL content
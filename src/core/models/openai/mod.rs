@@ -21,8 +21,8 @@ pub mod tools;
 pub use audio::{AudioContent, AudioDelta, AudioParams};
 pub use messages::{ChatMessage, ContentPart, ImageUrl, MessageContent, MessageRole};
 pub use requests::{
-    ChatCompletionRequest, CompletionRequest, EmbeddingRequest, ImageGenerationRequest,
-    ResponseFormat, StreamOptions,
+    CacheOptions, ChatCompletionRequest, CompletionRequest, EmbeddingRequest,
+    ImageGenerationRequest, ResponseFormat, StreamOptions,
 };
 pub use responses::{
     ChatChoice, ChatChoiceDelta, ChatCompletionChoice, ChatCompletionChunk, ChatCompletionResponse,
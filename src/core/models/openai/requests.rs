@@ -61,6 +61,15 @@ pub struct ChatCompletionRequest {
     pub modalities: Option<Vec<String>>,
     /// Audio parameters
     pub audio: Option<AudioParams>,
+    /// Response cache controls of the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheOptions>,
+    /// Routing tags of the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Request metadata of the gateway, such as `user_id` and `tags`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
 }
 
 impl Default for ChatCompletionRequest {
@@ -90,10 +99,27 @@ impl Default for ChatCompletionRequest {
             top_logprobs: None,
             modalities: None,
             audio: None,
+            cache: None,
+            tags: None,
+            metadata: None,
         }
     }
 }
 
+/// Response cache controls of a request, as LiteLLM clients send them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheOptions {
+    /// Skip the cached response and call the provider
+    #[serde(rename = "no-cache", default)]
+    pub no_cache: bool,
+    /// Seconds the response is cached, overriding the configured TTL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// Namespace the response is cached in, overriding the configured one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
 /// Stream options
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamOptions {
@@ -4,6 +4,7 @@
 //! embeddings, image generation, and model listings, including streaming variants.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use super::audio::AudioDelta;
 use super::messages::{ChatMessage, MessageRole};
@@ -26,6 +27,10 @@ pub struct ChatCompletionResponse {
     pub choices: Vec<ChatChoice>,
     /// Usage statistics
     pub usage: Option<Usage>,
+    /// Provider-specific fields, e.g. Perplexity `citations` and
+    /// `search_results`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_specific_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Chat choice
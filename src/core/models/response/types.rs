@@ -140,6 +140,7 @@ mod tests {
             system_fingerprint: None,
             choices: vec![],
             usage: None,
+            provider_specific_fields: None,
         };
 
         let data = ResponseData::ChatCompletion(chat_response);
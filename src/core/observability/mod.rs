@@ -7,6 +7,7 @@ mod destinations;
 mod histogram;
 mod logging;
 mod metrics;
+mod otlp;
 mod redaction;
 mod tracing;
 mod types;
@@ -20,7 +21,14 @@ pub use destinations::{AlertChannel, AlertRule, LogDestination, TraceExporter};
 pub use histogram::{BoundedHistogram, HISTOGRAM_MAX_SAMPLES};
 pub use logging::LogAggregator;
 pub use metrics::{DataDogClient, MetricsCollector, OtelExporter, PrometheusMetrics};
-pub use redaction::{RedactionConfig, redact_headers, redact_json_value, redact_value};
+pub use otlp::{
+    OtlpLayer, TRACEPARENT_FIELD, TracedStream, continue_trace, current_traceparent, init_exporter,
+    otlp_layer,
+};
+pub use redaction::{
+    RedactionConfig, Scrubbing, ScrubbingWriter, redact_headers, redact_json_value, redact_value,
+    scrub_secrets,
+};
 pub use tracing::PerformanceTracer;
 pub use types::{
     AlertCondition, AlertSeverity, AlertState, ErrorDetails, LogEntry, LogLevel, MetricValue,
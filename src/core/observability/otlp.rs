@@ -0,0 +1,292 @@
+//! OpenTelemetry trace export over OTLP/HTTP
+//!
+//! `tracing` spans are recorded as OpenTelemetry spans by
+//! `tracing-opentelemetry` and exported in batches by the `opentelemetry-otlp`
+//! exporter once [`init_exporter`] has been called. The layer from
+//! [`otlp_layer`] is installed at startup and takes the tracer when the
+//! exporter starts. Incoming traces continue through the gateway via
+//! [`continue_trace`] and on to providers via [`current_traceparent`].
+
+use super::redaction::scrub_secrets;
+use futures::Stream;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{Status, TraceError, TracerProvider as _};
+use opentelemetry::{KeyValue, Value};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::export::trace::SpanData;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{
+    BatchSpanProcessor, EvictedQueue, Span as SdkSpan, SpanProcessor, TracerProvider,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::OnceLock;
+use std::task::{Context, Poll};
+use tracing::{Span, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{Layer, Registry, reload};
+
+/// Header holding the W3C trace context
+pub const TRACEPARENT_FIELD: &str = "traceparent";
+
+/// OTLP path of trace exports, appended to the collector base URL
+const TRACES_PATH: &str = "/v1/traces";
+
+/// Layer recording spans once the exporter runs
+type ExportLayer = Option<Box<dyn Layer<Registry> + Send + Sync>>;
+
+/// Layer installed directly on the [`Registry`], exporting spans once
+/// [`init_exporter`] has been called
+pub type OtlpLayer = reload::Layer<ExportLayer, Registry>;
+
+/// Handle installing the export layer, set by [`otlp_layer`]
+static LAYER: OnceLock<reload::Handle<ExportLayer, Registry>> = OnceLock::new();
+
+/// Tracer provider of the running exporter
+static PROVIDER: OnceLock<TracerProvider> = OnceLock::new();
+
+/// Create the layer exporting spans, to install directly on the registry
+///
+/// Spans are not recorded until [`init_exporter`] is called.
+pub fn otlp_layer() -> OtlpLayer {
+    let (layer, handle) = reload::Layer::new(None);
+    let _ = LAYER.set(handle);
+    layer
+}
+
+/// Start exporting spans to an OTLP/HTTP collector
+///
+/// `endpoint` is the collector base URL (e.g. `http://localhost:4318`) or
+/// its full `/v1/traces` URL. Must be called from within a Tokio runtime
+/// after [`otlp_layer`] was installed; later calls are ignored.
+pub fn init_exporter(endpoint: &str, service_name: &str, headers: HashMap<String, String>) {
+    let Some(handle) = LAYER.get() else {
+        return;
+    };
+    if PROVIDER.get().is_some() {
+        return;
+    }
+
+    let provider = match tracer_provider(endpoint, service_name, headers) {
+        Ok(provider) => provider,
+        Err(e) => {
+            warn!("OTLP exporter to {} not started: {}", endpoint, e);
+            return;
+        }
+    };
+    let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+    if PROVIDER.set(provider).is_err() {
+        return;
+    }
+
+    let layer = tracing_opentelemetry::layer().with_tracer(tracer);
+    if let Err(e) = handle.reload(Some(
+        Box::new(layer) as Box<dyn Layer<Registry> + Send + Sync>
+    )) {
+        warn!("OTLP exporter to {} not started: {}", endpoint, e);
+    }
+}
+
+/// Tracer provider batching spans to an OTLP/HTTP collector
+fn tracer_provider(
+    endpoint: &str,
+    service_name: &str,
+    headers: HashMap<String, String>,
+) -> Result<TracerProvider, TraceError> {
+    let exporter = opentelemetry_otlp::new_exporter()
+        .http()
+        .with_endpoint(
+            endpoint
+                .trim_end_matches('/')
+                .trim_end_matches(TRACES_PATH)
+                .trim_end_matches('/'),
+        )
+        .with_headers(headers)
+        .build_span_exporter()?;
+    let processor =
+        BatchSpanProcessor::builder(exporter, opentelemetry_sdk::runtime::Tokio).build();
+    let resource = Resource::new([
+        KeyValue::new("service.name", service_name.to_string()),
+        KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+    ]);
+
+    Ok(TracerProvider::builder()
+        .with_span_processor(ScrubbingProcessor(processor))
+        .with_config(opentelemetry_sdk::trace::config().with_resource(resource))
+        .build())
+}
+
+/// Span processor scrubbing secrets from span attributes, events and status
+/// before they reach the exporter, as the log writer does for log lines
+#[derive(Debug)]
+struct ScrubbingProcessor<P>(P);
+
+impl<P: SpanProcessor> SpanProcessor for ScrubbingProcessor<P> {
+    fn on_start(&self, span: &mut SdkSpan, cx: &opentelemetry::Context) {
+        self.0.on_start(span, cx);
+    }
+
+    fn on_end(&self, mut span: SpanData) {
+        scrub_attributes(&mut span.attributes);
+        let events = std::mem::replace(&mut span.events, EvictedQueue::new(u32::MAX));
+        let mut events: Vec<_> = events
+            .into_iter()
+            .map(|mut event| {
+                // Events are named after their message
+                if let Cow::Owned(scrubbed) = scrub_secrets(&event.name) {
+                    event.name = scrubbed.into();
+                }
+                scrub_attributes(&mut event.attributes);
+                event
+            })
+            .collect();
+        span.events.append_vec(&mut events);
+        if let Status::Error { description } = &mut span.status
+            && let Cow::Owned(scrubbed) = scrub_secrets(description)
+        {
+            *description = scrubbed.into();
+        }
+        self.0.on_end(span);
+    }
+
+    fn force_flush(&self) -> opentelemetry::trace::TraceResult<()> {
+        self.0.force_flush()
+    }
+
+    fn shutdown(&mut self) -> opentelemetry::trace::TraceResult<()> {
+        self.0.shutdown()
+    }
+}
+
+fn scrub_attributes(attributes: &mut [KeyValue]) {
+    for attribute in attributes {
+        if let Value::String(value) = &attribute.value
+            && let Cow::Owned(scrubbed) = scrub_secrets(value.as_str())
+        {
+            attribute.value = scrubbed.into();
+        }
+    }
+}
+
+/// Continue the trace of an incoming `traceparent` header in `span`
+pub fn continue_trace(span: &Span, traceparent: &str) {
+    let carrier = HashMap::from([(TRACEPARENT_FIELD.to_string(), traceparent.to_string())]);
+    let context = TraceContextPropagator::new().extract(&carrier);
+    span.set_parent(context);
+}
+
+/// `traceparent` header for calls made from the current span
+pub fn current_traceparent() -> Option<String> {
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(&Span::current().context(), &mut carrier);
+    carrier.remove(TRACEPARENT_FIELD)
+}
+
+/// Stream that polls its inner stream within a span
+///
+/// Used for streamed responses, which are polled after the handler returns.
+pub struct TracedStream<S> {
+    inner: Pin<Box<S>>,
+    span: Span,
+}
+
+impl<S> TracedStream<S> {
+    /// Poll `inner` within `span`
+    pub fn new(inner: S, span: Span) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            span,
+        }
+    }
+}
+
+impl<S: Stream> Stream for TracedStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let _entered = this.span.enter();
+        this.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn test_spans_continue_incoming_trace() {
+        let provider = TracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, || {
+            let request = tracing::info_span!("http.request");
+            continue_trace(&request, TRACEPARENT);
+            let _request = request.enter();
+            let call = tracing::info_span!("provider.http", otel.kind = "client");
+            let _call = call.enter();
+
+            let outgoing = current_traceparent().unwrap();
+            assert!(outgoing.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+            assert!(!outgoing.contains("00f067aa0ba902b7"));
+            assert!(outgoing.ends_with("-01"));
+        });
+    }
+
+    #[test]
+    fn test_no_traceparent_without_exporter() {
+        let subscriber = tracing_subscriber::registry();
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("http.request").entered();
+            assert!(current_traceparent().is_none());
+        });
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_export_scrubs_secrets() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(TRACES_PATH))
+            .and(header("x-collector-token", "token"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let provider = tracer_provider(
+            &format!("{}{}", server.uri(), TRACES_PATH),
+            "litellm-rs-test",
+            HashMap::from([("x-collector-token".to_string(), "token".to_string())]),
+        )
+        .unwrap();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!(
+                "provider.http",
+                otel.kind = "client",
+                http.error = "Incorrect API key provided: sk-proj-abcdefghijklmnop1234",
+            )
+            .entered();
+            tracing::error!("Authorization: Bearer abc.def.ghi123 rejected");
+        });
+        let _ = tokio::task::spawn_blocking(move || provider.force_flush())
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("litellm-rs-test"));
+        assert!(body.contains("provider.http"));
+        assert!(body.contains("[REDACTED]"));
+        assert!(!body.contains("sk-proj-abcdefghijklmnop1234"));
+        assert!(!body.contains("abc.def.ghi123"));
+    }
+}
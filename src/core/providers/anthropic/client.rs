@@ -8,18 +8,36 @@ use reqwest::{Client, ClientBuilder, Response};
 use serde_json::{Value, json};
 use tokio::time::timeout;
 
+use crate::core::providers::shared::{DocumentTransformer, ImageTransformer, ToolTransformer};
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::{
+    CacheControl, ThinkingConfig, ThinkingContent, ThinkingUsage,
     requests::{ChatMessage, ChatRequest, ContentPart, MessageRole},
-    responses::{ChatChoice, ChatResponse, Usage},
+    responses::{ChatChoice, ChatResponse, CompletionTokensDetails, PromptTokensDetails, Usage},
 };
 
 use super::config::AnthropicConfig;
 use super::error::{
     anthropic_api_error, anthropic_auth_error, anthropic_network_error, anthropic_parse_error,
-    anthropic_rate_limit_error,
+    anthropic_rate_limit_error, anthropic_validation_error,
 };
-use super::models::{ModelFeature, get_anthropic_registry};
+use super::models::{CostCalculator, ModelFeature, get_anthropic_registry};
+
+/// Beta flag for prompt caching
+const PROMPT_CACHING_BETA: &str = "prompt-caching-2024-07-31";
+
+/// Smallest thinking budget the API accepts
+const MIN_THINKING_BUDGET: u32 = 1024;
+
+/// Output tokens left for the answer when `max_tokens` is derived from the
+/// thinking budget
+const DEFAULT_ANSWER_TOKENS: u32 = 4096;
+
+/// Tool Claude is made to call when a JSON response is requested
+///
+/// Anthropic has no JSON mode, so the response schema becomes the tool's
+/// input schema and the tool input becomes the message content.
+pub(super) const JSON_TOOL_NAME: &str = "json_tool_call";
 
 /// Anthropic API client
 #[derive(Debug, Clone)]
@@ -61,7 +79,28 @@ impl AnthropicClient {
         let response = self.send_request("/v1/messages", anthropic_request).await?;
 
         // Response
-        self.transform_chat_response(response)
+        let mut response = self.transform_chat_response(response)?;
+        if json_mode(&request) {
+            json_tool_call_to_content(&mut response);
+        }
+
+        if let Some(config) = request.thinking.as_ref().filter(|c| thinking_enabled(c)) {
+            if let Some(usage) = response.usage.as_mut() {
+                if let Some(thinking_usage) = usage.thinking_usage.as_mut() {
+                    thinking_usage.budget_tokens = Some(thinking_budget(config));
+                }
+            }
+            if !config.include_thinking {
+                for choice in &mut response.choices {
+                    choice.message.thinking = None;
+                }
+                if let Some(fields) = response.provider_specific_fields.as_mut() {
+                    fields.remove("thinking_blocks");
+                }
+            }
+        }
+
+        Ok(response)
     }
 
     /// Request
@@ -81,7 +120,10 @@ impl AnthropicClient {
     /// Request
     async fn send_request(&self, endpoint: &str, body: Value) -> Result<Value, ProviderError> {
         let url = format!("{}{}", self.config.base_url.trim_end_matches('/'), endpoint);
-        let headers = self.build_headers();
+        let mut headers = self.build_headers();
+        if uses_cache_control(&body) {
+            add_beta_header(&mut headers, PROMPT_CACHING_BETA);
+        }
 
         let response = timeout(
             Duration::from_secs(self.config.request_timeout),
@@ -105,7 +147,10 @@ impl AnthropicClient {
         body: Value,
     ) -> Result<Response, ProviderError> {
         let url = format!("{}{}", self.config.base_url.trim_end_matches('/'), endpoint);
-        let headers = self.build_headers();
+        let mut headers = self.build_headers();
+        if uses_cache_control(&body) {
+            add_beta_header(&mut headers, PROMPT_CACHING_BETA);
+        }
 
         let response = timeout(
             Duration::from_secs(self.config.request_timeout),
@@ -240,16 +285,58 @@ impl AnthropicClient {
         // Transform message format
         let anthropic_messages = self.transform_messages(messages, model_spec)?;
 
+        // Extended thinking
+        let thinking = match request.thinking.as_ref().filter(|c| thinking_enabled(c)) {
+            Some(config) => {
+                if !model_spec.features.contains(&ModelFeature::ThinkingMode) {
+                    return Err(anthropic_validation_error(format!(
+                        "Model {} does not support extended thinking",
+                        request.model
+                    )));
+                }
+                let budget = thinking_budget(config);
+                if budget < MIN_THINKING_BUDGET {
+                    return Err(anthropic_validation_error(format!(
+                        "thinking budget_tokens must be at least {}",
+                        MIN_THINKING_BUDGET
+                    )));
+                }
+                if let Some(max_tokens) = request.max_tokens {
+                    if max_tokens <= budget {
+                        return Err(anthropic_validation_error(format!(
+                            "max_tokens ({}) must be greater than thinking budget_tokens ({})",
+                            max_tokens, budget
+                        )));
+                    }
+                }
+                Some(budget)
+            }
+            None => None,
+        };
+
+        let max_tokens = match (request.max_tokens, thinking) {
+            (Some(max_tokens), _) => max_tokens,
+            (None, Some(budget)) => budget + DEFAULT_ANSWER_TOKENS,
+            (None, None) => 4096,
+        };
+
         // Request
         let mut anthropic_request = json!({
             "model": request.model,
-            "max_tokens": request.max_tokens.unwrap_or(4096),
+            "max_tokens": max_tokens,
             "messages": anthropic_messages,
         });
 
+        if let Some(budget) = thinking {
+            anthropic_request["thinking"] = json!({
+                "type": "enabled",
+                "budget_tokens": budget,
+            });
+        }
+
         // Add system message
         if let Some(system) = system_message {
-            anthropic_request["system"] = json!(system);
+            anthropic_request["system"] = system;
         }
 
         // Add optional parameters
@@ -268,12 +355,40 @@ impl AnthropicClient {
         // Add tool support
         if let Some(tools) = &request.tools {
             if model_spec.features.contains(&ModelFeature::ToolCalling) {
-                let anthropic_tools = self.transform_tools(tools)?;
-                anthropic_request["tools"] = json!(anthropic_tools);
+                anthropic_request["tools"] = json!(ToolTransformer::to_anthropic_tools(tools));
 
                 // Add tool_choice
                 if let Some(tool_choice) = &request.tool_choice {
-                    anthropic_request["tool_choice"] = self.transform_tool_choice(tool_choice)?;
+                    anthropic_request["tool_choice"] =
+                        ToolTransformer::to_anthropic_tool_choice(tool_choice);
+                }
+            }
+        }
+
+        // JSON mode through a tool taking the response as input
+        if json_mode(request) {
+            let input_schema = request
+                .response_format
+                .as_ref()
+                .and_then(|format| format.schema())
+                .cloned()
+                .unwrap_or_else(|| json!({"type": "object"}));
+            let json_tool = json!({
+                "name": JSON_TOOL_NAME,
+                "description": "Respond with JSON matching the input schema",
+                "input_schema": input_schema,
+            });
+            match anthropic_request["tools"].as_array_mut() {
+                // The model picks between the JSON tool and the caller's tools
+                Some(tools) => tools.push(json_tool),
+                None => {
+                    anthropic_request["tools"] = json!([json_tool]);
+                    // Extended thinking does not allow forcing a tool
+                    anthropic_request["tool_choice"] = if thinking.is_some() {
+                        json!({"type": "auto"})
+                    } else {
+                        json!({"type": "tool", "name": JSON_TOOL_NAME})
+                    };
                 }
             }
         }
@@ -282,11 +397,14 @@ impl AnthropicClient {
     }
 
     /// Separate system messages from user messages
+    ///
+    /// The system prompt is sent as a string, or as text blocks when any part
+    /// of it carries a cache breakpoint.
     fn separate_system_messages(
         &self,
         messages: &[ChatMessage],
-    ) -> Result<(Option<String>, Vec<ChatMessage>), ProviderError> {
-        let mut system_parts = Vec::new();
+    ) -> Result<(Option<Value>, Vec<ChatMessage>), ProviderError> {
+        let mut system_blocks = Vec::new();
         let mut user_messages = Vec::new();
 
         for message in messages {
@@ -295,16 +413,26 @@ impl AnthropicClient {
                     if let Some(content) = &message.content {
                         match content {
                             crate::core::types::MessageContent::Text(text) => {
-                                system_parts.push(text.clone());
+                                system_blocks.push(self.text_block(text, None));
                             }
                             crate::core::types::MessageContent::Parts(parts) => {
                                 for part in parts {
-                                    if let ContentPart::Text { text } = part {
-                                        system_parts.push(text.clone());
+                                    if let ContentPart::Text {
+                                        text,
+                                        cache_control,
+                                    } = part
+                                    {
+                                        system_blocks
+                                            .push(self.text_block(text, cache_control.as_ref()));
                                     }
                                 }
                             }
                         }
+                        if let (Some(cache_control), Some(last)) =
+                            (&message.cache_control, system_blocks.last_mut())
+                        {
+                            self.set_cache_control(last, Some(cache_control));
+                        }
                     }
                 }
                 _ => {
@@ -313,15 +441,43 @@ impl AnthropicClient {
             }
         }
 
-        let system_message = if system_parts.is_empty() {
+        let system_message = if system_blocks.is_empty() {
             None
+        } else if system_blocks
+            .iter()
+            .any(|block| block.get("cache_control").is_some())
+        {
+            Some(json!(system_blocks))
         } else {
-            Some(system_parts.join("\n"))
+            let texts: Vec<&str> = system_blocks
+                .iter()
+                .filter_map(|block| block.get("text").and_then(|t| t.as_str()))
+                .collect();
+            Some(json!(texts.join("\n")))
         };
 
         Ok((system_message, user_messages))
     }
 
+    /// Text content block
+    fn text_block(&self, text: &str, cache_control: Option<&CacheControl>) -> Value {
+        let mut block = json!({
+            "type": "text",
+            "text": text
+        });
+        self.set_cache_control(&mut block, cache_control);
+        block
+    }
+
+    /// Mark a content block as a cache breakpoint, unless caching is disabled
+    fn set_cache_control(&self, block: &mut Value, cache_control: Option<&CacheControl>) {
+        if let Some(cache_control) = cache_control {
+            if self.config.enable_cache_control {
+                block["cache_control"] = json!(cache_control);
+            }
+        }
+    }
+
     /// Transform messages to Anthropic format
     fn transform_messages(
         &self,
@@ -331,78 +487,76 @@ impl AnthropicClient {
         let mut anthropic_messages = Vec::new();
 
         for message in messages {
+            // Tool results are blocks of a user message, the results of
+            // parallel calls blocks of the same message
+            if message.role == MessageRole::Tool {
+                let mut block = ToolTransformer::to_anthropic_tool_result(&message);
+                self.set_cache_control(&mut block, message.cache_control.as_ref());
+                let results = anthropic_messages.last_mut().and_then(|last: &mut Value| {
+                    let blocks = last["content"].as_array_mut()?;
+                    blocks
+                        .iter()
+                        .all(|block| block["type"] == "tool_result")
+                        .then_some(blocks)
+                });
+                match results {
+                    Some(blocks) => blocks.push(block),
+                    None => anthropic_messages.push(json!({"role": "user", "content": [block]})),
+                }
+                continue;
+            }
+
             let role = match message.role {
                 MessageRole::User => "user",
                 MessageRole::Assistant => "assistant",
-                MessageRole::Tool => "user",     // Response
-                MessageRole::Function => "user", // Response
-                MessageRole::System => continue, // Already handled
+                MessageRole::Tool | MessageRole::Function => "user", // Response
+                MessageRole::System => continue,                     // Already handled
             };
 
             let content = if let Some(content) = message.content {
                 match content {
                     crate::core::types::MessageContent::Text(text) => {
-                        json!(text)
+                        // A breakpoint needs a block to attach to
+                        if message.cache_control.is_some() {
+                            json!([self.text_block(&text, None)])
+                        } else {
+                            json!(text)
+                        }
                     }
                     crate::core::types::MessageContent::Parts(parts) => {
                         let mut anthropic_parts = Vec::new();
 
                         for part in parts {
                             match part {
-                                ContentPart::Text { text } => {
-                                    anthropic_parts.push(json!({
-                                        "type": "text",
-                                        "text": text
-                                    }));
+                                ContentPart::Text {
+                                    text,
+                                    cache_control,
+                                } => {
+                                    anthropic_parts
+                                        .push(self.text_block(&text, cache_control.as_ref()));
                                 }
-                                ContentPart::ImageUrl { image_url } => {
+                                part @ (ContentPart::ImageUrl { .. }
+                                | ContentPart::Image { .. }) => {
                                     if model_spec
                                         .features
                                         .contains(&ModelFeature::MultimodalSupport)
+                                        && let Some(image) = part.image()
                                     {
-                                        // Handle
-                                        if image_url.url.starts_with("data:") {
-                                            // Base64 format image
-                                            let parts: Vec<&str> =
-                                                image_url.url.split(',').collect();
-                                            if parts.len() == 2 {
-                                                let media_type = parts[0]
-                                                    .strip_prefix("data:")
-                                                    .and_then(|s| s.split(';').next())
-                                                    .unwrap_or("image/jpeg");
-
-                                                anthropic_parts.push(json!({
-                                                    "type": "image",
-                                                    "source": {
-                                                        "type": "base64",
-                                                        "media_type": media_type,
-                                                        "data": parts[1]
-                                                    }
-                                                }));
-                                            }
-                                        } else {
-                                            // URL format image - requires download and conversion
-                                            // TODO: implement URL image download and conversion
-                                            return Err(anthropic_api_error(
-                                                400,
-                                                "URL images not yet supported, use base64 format",
-                                            ));
-                                        }
+                                        anthropic_parts
+                                            .push(ImageTransformer::to_anthropic(&image));
                                     }
                                 }
-                                ContentPart::Document { source, .. } => {
+                                ContentPart::Document {
+                                    source,
+                                    cache_control,
+                                } => {
                                     if model_spec
                                         .features
                                         .contains(&ModelFeature::MultimodalSupport)
                                     {
-                                        anthropic_parts.push(json!({
-                                            "type": "document",
-                                            "source": {
-                                                "type": "base64",
-                                                "media_type": source.media_type,
-                                                "data": source.data
-                                            }
-                                        }));
+                                        let mut block = DocumentTransformer::to_anthropic(&source);
+                                        self.set_cache_control(&mut block, cache_control.as_ref());
+                                        anthropic_parts.push(block);
                                     }
                                 }
                                 _ => {
@@ -423,68 +577,45 @@ impl AnthropicClient {
                 "content": content
             });
 
-            // Add tool_call
+            // Tool calls follow the text they come with
             if let Some(tool_calls) = &message.tool_calls {
-                let mut anthropic_tool_calls = Vec::new();
-                for tool_call in tool_calls {
-                    anthropic_tool_calls.push(json!({
-                        "type": "tool_use",
-                        "id": tool_call.id,
-                        "name": tool_call.function.name,
-                        "input": serde_json::from_str::<Value>(&tool_call.function.arguments)
-                            .unwrap_or(json!({}))
-                    }));
-                }
-                anthropic_message["content"] = json!(anthropic_tool_calls);
+                let mut blocks = match anthropic_message["content"].take() {
+                    Value::Array(blocks) => blocks,
+                    Value::String(text) if !text.is_empty() => vec![self.text_block(&text, None)],
+                    _ => Vec::new(),
+                };
+                blocks.extend(
+                    tool_calls
+                        .iter()
+                        .map(ToolTransformer::to_anthropic_tool_use),
+                );
+                anthropic_message["content"] = Value::Array(blocks);
             }
 
-            anthropic_messages.push(anthropic_message);
-        }
+            // Signed thinking from an earlier turn must lead the assistant
+            // message so tool use can continue
+            if let Some(block) = message.thinking.as_ref().and_then(thinking_block) {
+                let blocks = match anthropic_message["content"].take() {
+                    Value::Array(blocks) => blocks,
+                    Value::String(text) if !text.is_empty() => vec![self.text_block(&text, None)],
+                    _ => Vec::new(),
+                };
+                anthropic_message["content"] =
+                    Value::Array(std::iter::once(block).chain(blocks).collect());
+            }
 
-        Ok(anthropic_messages)
-    }
+            // A message-level breakpoint applies to its last block
+            if let Some(last) = anthropic_message["content"]
+                .as_array_mut()
+                .and_then(|blocks| blocks.last_mut())
+            {
+                self.set_cache_control(last, message.cache_control.as_ref());
+            }
 
-    /// Transform tool definitions
-    fn transform_tools(
-        &self,
-        tools: &[crate::core::types::Tool],
-    ) -> Result<Vec<Value>, ProviderError> {
-        let mut anthropic_tools = Vec::new();
-
-        for tool in tools {
-            anthropic_tools.push(json!({
-                "name": tool.function.name,
-                "description": tool.function.description.as_ref().unwrap_or(&String::new()),
-                "input_schema": tool.function.parameters.as_ref().unwrap_or(&json!({}))
-            }));
+            anthropic_messages.push(anthropic_message);
         }
 
-        Ok(anthropic_tools)
-    }
-
-    /// Transform tool choice
-    fn transform_tool_choice(
-        &self,
-        tool_choice: &crate::core::types::ToolChoice,
-    ) -> Result<Value, ProviderError> {
-        match tool_choice {
-            crate::core::types::ToolChoice::String(choice) => match choice.as_str() {
-                "auto" => Ok(json!({"type": "auto"})),
-                "none" => Ok(json!({"type": "none"})),
-                "required" => Ok(json!({"type": "any"})),
-                _ => Ok(json!({"type": "auto"})),
-            },
-            crate::core::types::ToolChoice::Specific { function, .. } => {
-                if let Some(func) = function {
-                    Ok(json!({
-                        "type": "tool",
-                        "name": func.name
-                    }))
-                } else {
-                    Ok(json!({"type": "auto"}))
-                }
-            }
-        }
+        Ok(anthropic_messages)
     }
 
     /// Response
@@ -515,6 +646,9 @@ impl AnthropicClient {
 
         let mut message_content = String::new();
         let mut tool_calls = Vec::new();
+        let mut thinking_text = String::new();
+        let mut thinking_signature = None;
+        let mut thinking_blocks = Vec::new();
 
         for item in content {
             match item.get("type").and_then(|t| t.as_str()) {
@@ -523,26 +657,36 @@ impl AnthropicClient {
                         message_content.push_str(text);
                     }
                 }
-                Some("tool_use") => {
-                    if let (Some(id), Some(name), Some(input)) = (
-                        item.get("id").and_then(|v| v.as_str()),
-                        item.get("name").and_then(|v| v.as_str()),
-                        item.get("input"),
-                    ) {
-                        tool_calls.push(crate::core::types::ToolCall {
-                            id: id.to_string(),
-                            tool_type: "function".to_string(),
-                            function: crate::core::types::FunctionCall {
-                                name: name.to_string(),
-                                arguments: input.to_string(),
-                            },
-                        });
+                Some("thinking") => {
+                    if let Some(thinking) = item.get("thinking").and_then(|t| t.as_str()) {
+                        thinking_text.push_str(thinking);
+                    }
+                    if let Some(signature) = item.get("signature").and_then(|s| s.as_str()) {
+                        thinking_signature = Some(signature.to_string());
                     }
+                    thinking_blocks.push(item.clone());
+                }
+                Some("redacted_thinking") => {
+                    thinking_blocks.push(item.clone());
+                }
+                Some("tool_use") => {
+                    tool_calls.extend(ToolTransformer::from_anthropic_tool_use(item));
                 }
                 _ => {}
             }
         }
 
+        let thinking = if !thinking_text.is_empty() {
+            Some(ThinkingContent::Text {
+                text: thinking_text.clone(),
+                signature: thinking_signature,
+            })
+        } else if !thinking_blocks.is_empty() {
+            Some(ThinkingContent::redacted(None))
+        } else {
+            None
+        };
+
         // Build message
         let message = ChatMessage {
             role: MessageRole::Assistant,
@@ -551,7 +695,7 @@ impl AnthropicClient {
             } else {
                 Some(crate::core::types::MessageContent::Text(message_content))
             },
-            thinking: None,
+            thinking,
             name: None,
             tool_calls: if tool_calls.is_empty() {
                 None
@@ -560,6 +704,7 @@ impl AnthropicClient {
             },
             tool_call_id: None,
             function_call: None,
+            cache_control: None,
         };
 
         // Build choice
@@ -579,28 +724,22 @@ impl AnthropicClient {
         };
 
         // Build usage
-        let usage = response.get("usage").map(|usage_data| Usage {
-            prompt_tokens: usage_data
-                .get("input_tokens")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32,
-            completion_tokens: usage_data
-                .get("output_tokens")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32,
-            total_tokens: (usage_data
-                .get("input_tokens")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0)
-                + usage_data
-                    .get("output_tokens")
-                    .and_then(|v| v.as_u64())
-                    .unwrap_or(0)) as u32,
-            completion_tokens_details: None,
-            prompt_tokens_details: None,
-            thinking_usage: None,
+        let usage = response.get("usage").map(|u| {
+            let mut usage = parse_usage(u);
+            set_reasoning_tokens(&mut usage, &thinking_text);
+            usage
         });
 
+        // Raw blocks, including redacted ones, so callers can send them back
+        let provider_specific_fields = if thinking_blocks.is_empty() {
+            None
+        } else {
+            Some(std::collections::HashMap::from([(
+                "thinking_blocks".to_string(),
+                Value::Array(thinking_blocks),
+            )]))
+        };
+
         Ok(ChatResponse {
             id,
             object: "chat.completion".to_string(),
@@ -609,10 +748,151 @@ impl AnthropicClient {
             choices: vec![choice],
             usage,
             system_fingerprint: None,
+            provider_specific_fields,
         })
     }
 }
 
+/// Whether a request asks for a JSON response
+pub(super) fn json_mode(request: &ChatRequest) -> bool {
+    request
+        .response_format
+        .as_ref()
+        .is_some_and(|format| format.is_json())
+}
+
+/// Turn calls of the JSON tool into message content
+fn json_tool_call_to_content(response: &mut ChatResponse) {
+    for choice in &mut response.choices {
+        let Some(tool_calls) = choice.message.tool_calls.as_mut() else {
+            continue;
+        };
+        let Some(position) = tool_calls
+            .iter()
+            .position(|call| call.function.name == JSON_TOOL_NAME)
+        else {
+            continue;
+        };
+        let call = tool_calls.remove(position);
+        choice.message.content = Some(crate::core::types::MessageContent::Text(
+            call.function.arguments,
+        ));
+        if tool_calls.is_empty() {
+            choice.message.tool_calls = None;
+            choice.finish_reason = Some(crate::core::types::FinishReason::Stop);
+        }
+    }
+}
+
+/// Convert Anthropic usage to OpenAI-style usage
+///
+/// Anthropic's `input_tokens` excludes cached prompt tokens, so cache writes
+/// and reads are added back into `prompt_tokens`; cache reads are also
+/// reported as `prompt_tokens_details.cached_tokens`.
+pub(super) fn parse_usage(usage: &Value) -> Usage {
+    let count = |key: &str| usage.get(key).and_then(|v| v.as_u64()).map(|v| v as u32);
+
+    let input_tokens = count("input_tokens").unwrap_or(0);
+    let output_tokens = count("output_tokens").unwrap_or(0);
+    let cache_creation_input_tokens = count("cache_creation_input_tokens");
+    let cache_read_input_tokens = count("cache_read_input_tokens");
+
+    let prompt_tokens = input_tokens
+        + cache_creation_input_tokens.unwrap_or(0)
+        + cache_read_input_tokens.unwrap_or(0);
+
+    Usage {
+        prompt_tokens,
+        completion_tokens: output_tokens,
+        total_tokens: prompt_tokens + output_tokens,
+        prompt_tokens_details: cache_read_input_tokens.map(|cached| PromptTokensDetails {
+            cached_tokens: Some(cached),
+            audio_tokens: None,
+        }),
+        completion_tokens_details: None,
+        thinking_usage: None,
+        cache_creation_input_tokens,
+        cache_read_input_tokens,
+        billable_characters: None,
+    }
+}
+
+/// Record thinking tokens on usage
+///
+/// Anthropic bills thinking as output tokens without breaking it out, so the
+/// share is estimated from the returned thinking text.
+pub(super) fn set_reasoning_tokens(usage: &mut Usage, thinking: &str) {
+    if thinking.is_empty() {
+        return;
+    }
+    let reasoning_tokens = CostCalculator::estimate_tokens(thinking).min(usage.completion_tokens);
+    usage.completion_tokens_details = Some(CompletionTokensDetails {
+        reasoning_tokens: Some(reasoning_tokens),
+        audio_tokens: None,
+    });
+    usage.thinking_usage = Some(ThinkingUsage::new(reasoning_tokens).with_provider("anthropic"));
+}
+
+/// Whether thinking is requested
+///
+/// Also accepts Anthropic's own `{"type": "enabled"}` shape, which lands in
+/// `extra_params`.
+fn thinking_enabled(config: &ThinkingConfig) -> bool {
+    config.enabled || config.extra_params.get("type").and_then(|t| t.as_str()) == Some("enabled")
+}
+
+/// Thinking budget, falling back to the effort level's suggestion
+fn thinking_budget(config: &ThinkingConfig) -> u32 {
+    config
+        .budget_tokens
+        .unwrap_or_else(|| config.effort.unwrap_or_default().suggested_budget())
+}
+
+/// Request block for thinking returned on an earlier turn
+///
+/// Only signed thinking can be sent back.
+fn thinking_block(thinking: &ThinkingContent) -> Option<Value> {
+    match thinking {
+        ThinkingContent::Text {
+            text,
+            signature: Some(signature),
+        } => Some(json!({
+            "type": "thinking",
+            "thinking": text,
+            "signature": signature,
+        })),
+        _ => None,
+    }
+}
+
+/// Whether a request body marks any block as a cache breakpoint
+fn uses_cache_control(body: &Value) -> bool {
+    let has_breakpoint = |blocks: Option<&Value>| {
+        blocks
+            .and_then(|b| b.as_array())
+            .is_some_and(|blocks| blocks.iter().any(|b| b.get("cache_control").is_some()))
+    };
+
+    has_breakpoint(body.get("system"))
+        || has_breakpoint(body.get("tools"))
+        || body
+            .get("messages")
+            .and_then(|m| m.as_array())
+            .is_some_and(|messages| messages.iter().any(|m| has_breakpoint(m.get("content"))))
+}
+
+/// Add a flag to the `anthropic-beta` header, keeping any configured ones
+fn add_beta_header(headers: &mut reqwest::header::HeaderMap, beta: &str) {
+    let value = match headers.get("anthropic-beta").and_then(|v| v.to_str().ok()) {
+        Some(existing) if existing.split(',').any(|b| b.trim() == beta) => return,
+        Some(existing) if !existing.is_empty() => format!("{},{}", existing, beta),
+        _ => beta.to_string(),
+    };
+    if let Ok(value) = value.parse() {
+        headers.insert("anthropic-beta", value);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -637,4 +917,375 @@ mod tests {
         assert!(headers.contains_key("content-type"));
         assert!(headers.contains_key("user-agent"));
     }
+
+    fn cached_message(role: MessageRole, text: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: Some(crate::core::types::MessageContent::Text(text.to_string())),
+            cache_control: Some(CacheControl::ephemeral()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cache_control_request() {
+        let client = AnthropicClient::new(AnthropicConfig::new_test("test-key")).unwrap();
+        let request = ChatRequest {
+            model: "claude-3-5-sonnet-20241022".to_string(),
+            messages: vec![
+                cached_message(MessageRole::System, "Long system prompt"),
+                ChatMessage {
+                    role: MessageRole::User,
+                    content: Some(crate::core::types::MessageContent::Parts(vec![
+                        ContentPart::Text {
+                            text: "Long document".to_string(),
+                            cache_control: Some(CacheControl {
+                                cache_type: "ephemeral".to_string(),
+                                ttl: Some("1h".to_string()),
+                            }),
+                        },
+                        ContentPart::Text {
+                            text: "Question".to_string(),
+                            cache_control: None,
+                        },
+                    ])),
+                    ..Default::default()
+                },
+                cached_message(MessageRole::Assistant, "Answer"),
+            ],
+            ..Default::default()
+        };
+
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(
+            body["system"],
+            json!([{
+                "type": "text",
+                "text": "Long system prompt",
+                "cache_control": {"type": "ephemeral"}
+            }])
+        );
+        assert_eq!(
+            body["messages"][0]["content"][0]["cache_control"],
+            json!({"type": "ephemeral", "ttl": "1h"})
+        );
+        assert!(
+            body["messages"][0]["content"][1]
+                .get("cache_control")
+                .is_none()
+        );
+        assert_eq!(
+            body["messages"][1]["content"],
+            json!([{"type": "text", "text": "Answer", "cache_control": {"type": "ephemeral"}}])
+        );
+        assert!(uses_cache_control(&body));
+    }
+
+    #[test]
+    fn test_cache_control_disabled() {
+        let config = AnthropicConfig::new_test("test-key").with_cache_control(false);
+        let client = AnthropicClient::new(config).unwrap();
+        let request = ChatRequest {
+            model: "claude-3-5-sonnet-20241022".to_string(),
+            messages: vec![
+                cached_message(MessageRole::System, "System"),
+                cached_message(MessageRole::User, "Hello"),
+            ],
+            ..Default::default()
+        };
+
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(body["system"], json!("System"));
+        assert!(!uses_cache_control(&body));
+    }
+
+    #[test]
+    fn test_add_beta_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        add_beta_header(&mut headers, PROMPT_CACHING_BETA);
+        assert_eq!(headers["anthropic-beta"], PROMPT_CACHING_BETA);
+
+        add_beta_header(&mut headers, PROMPT_CACHING_BETA);
+        assert_eq!(headers["anthropic-beta"], PROMPT_CACHING_BETA);
+
+        headers.insert("anthropic-beta", "computer-use-2024-10-22".parse().unwrap());
+        add_beta_header(&mut headers, PROMPT_CACHING_BETA);
+        assert_eq!(
+            headers["anthropic-beta"],
+            "computer-use-2024-10-22,prompt-caching-2024-07-31"
+        );
+    }
+
+    fn thinking_request(thinking: ThinkingConfig, max_tokens: Option<u32>) -> ChatRequest {
+        ChatRequest {
+            model: "claude-sonnet-4-20251022".to_string(),
+            messages: vec![ChatMessage {
+                role: MessageRole::User,
+                content: Some(crate::core::types::MessageContent::Text("Hi".to_string())),
+                ..Default::default()
+            }],
+            max_tokens,
+            thinking: Some(thinking),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_thinking_request() {
+        let client = AnthropicClient::new(AnthropicConfig::new_test("test-key")).unwrap();
+
+        let request = thinking_request(ThinkingConfig::new().enabled().with_budget(2048), None);
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(
+            body["thinking"],
+            json!({"type": "enabled", "budget_tokens": 2048})
+        );
+        assert_eq!(body["max_tokens"], 2048 + DEFAULT_ANSWER_TOKENS);
+
+        // Anthropic's native shape and effort-derived budgets
+        let native: ThinkingConfig =
+            serde_json::from_value(json!({"type": "enabled", "budget_tokens": 4000})).unwrap();
+        let body = client
+            .transform_chat_request(&thinking_request(native, Some(8000)))
+            .unwrap();
+        assert_eq!(body["thinking"]["budget_tokens"], 4000);
+        assert_eq!(body["max_tokens"], 8000);
+
+        let request = thinking_request(ThinkingConfig::high_effort(), None);
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(body["thinking"]["budget_tokens"], 16000);
+
+        let request = thinking_request(ThinkingConfig::new().with_budget(2048), None);
+        let body = client.transform_chat_request(&request).unwrap();
+        assert!(body.get("thinking").is_none());
+    }
+
+    #[test]
+    fn test_thinking_request_validation() {
+        let client = AnthropicClient::new(AnthropicConfig::new_test("test-key")).unwrap();
+
+        let request = thinking_request(ThinkingConfig::new().enabled().with_budget(512), None);
+        assert!(client.transform_chat_request(&request).is_err());
+
+        let request = thinking_request(
+            ThinkingConfig::new().enabled().with_budget(4096),
+            Some(4096),
+        );
+        assert!(client.transform_chat_request(&request).is_err());
+
+        let mut request = thinking_request(ThinkingConfig::medium_effort(), None);
+        request.model = "claude-3-haiku-20240307".to_string();
+        assert!(client.transform_chat_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_thinking_round_trip() {
+        let client = AnthropicClient::new(AnthropicConfig::new_test("test-key")).unwrap();
+        let response = client
+            .transform_chat_response(json!({
+                "id": "msg_01",
+                "model": "claude-sonnet-4-20251022",
+                "content": [
+                    {"type": "thinking", "thinking": "The user greets me.", "signature": "sig"},
+                    {"type": "redacted_thinking", "data": "opaque"},
+                    {"type": "text", "text": "Hello!"}
+                ],
+                "stop_reason": "end_turn",
+                "usage": {"input_tokens": 10, "output_tokens": 30}
+            }))
+            .unwrap();
+
+        let message = &response.choices[0].message;
+        assert_eq!(
+            message.thinking,
+            Some(ThinkingContent::text_with_signature(
+                "The user greets me.",
+                "sig"
+            ))
+        );
+        let usage = response.usage.as_ref().unwrap();
+        assert_eq!(usage.thinking_tokens(), Some(5));
+        assert_eq!(
+            usage
+                .completion_tokens_details
+                .as_ref()
+                .unwrap()
+                .reasoning_tokens,
+            Some(5)
+        );
+        let fields = response.provider_specific_fields.as_ref().unwrap();
+        assert_eq!(fields["thinking_blocks"].as_array().unwrap().len(), 2);
+
+        // The signed block leads the assistant turn when sent back
+        let mut request = thinking_request(ThinkingConfig::new().enabled().with_budget(2048), None);
+        request.messages.push(message.clone());
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(
+            body["messages"][1]["content"],
+            json!([
+                {"type": "thinking", "thinking": "The user greets me.", "signature": "sig"},
+                {"type": "text", "text": "Hello!"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_tool_round_trip() {
+        use crate::core::types::{FunctionDefinition, Tool, ToolChoice, ToolType};
+
+        let client = AnthropicClient::new(AnthropicConfig::new_test("test-key")).unwrap();
+        let response = client
+            .transform_chat_response(json!({
+                "id": "msg_01",
+                "model": "claude-3-5-sonnet-20241022",
+                "content": [
+                    {"type": "text", "text": "Checking both."},
+                    {"type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {"city": "Paris"}},
+                    {"type": "tool_use", "id": "toolu_02", "name": "get_weather", "input": {"city": "Rome"}}
+                ],
+                "stop_reason": "tool_use"
+            }))
+            .unwrap();
+        let message = response.choices[0].message.clone();
+        let tool_calls = message.tool_calls.as_ref().unwrap();
+        assert_eq!(tool_calls.len(), 2);
+        assert_eq!(tool_calls[1].id, "toolu_02");
+        assert_eq!(tool_calls[1].function.arguments, r#"{"city":"Rome"}"#);
+
+        let tool_result = |id: &str, text: &str| ChatMessage {
+            role: MessageRole::Tool,
+            content: Some(crate::core::types::MessageContent::Text(text.to_string())),
+            tool_call_id: Some(id.to_string()),
+            ..Default::default()
+        };
+        let request = ChatRequest {
+            model: "claude-3-5-sonnet-20241022".to_string(),
+            messages: vec![
+                ChatMessage {
+                    role: MessageRole::User,
+                    content: Some(crate::core::types::MessageContent::Text(
+                        "Weather in Paris and Rome?".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                message,
+                tool_result("toolu_01", "Sunny"),
+                tool_result("toolu_02", "Rainy"),
+            ],
+            tools: Some(vec![Tool {
+                tool_type: ToolType::Function,
+                function: FunctionDefinition {
+                    name: "get_weather".to_string(),
+                    description: Some("Current weather".to_string()),
+                    parameters: Some(
+                        json!({"type": "object", "properties": {"city": {"type": "string"}}}),
+                    ),
+                },
+            }]),
+            tool_choice: Some(ToolChoice::String("required".to_string())),
+            ..Default::default()
+        };
+
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(body["tools"][0]["name"], "get_weather");
+        assert_eq!(
+            body["tools"][0]["input_schema"]["properties"]["city"]["type"],
+            "string"
+        );
+        assert_eq!(body["tool_choice"], json!({"type": "any"}));
+        assert_eq!(
+            body["messages"][1]["content"],
+            json!([
+                {"type": "text", "text": "Checking both."},
+                {"type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {"city": "Paris"}},
+                {"type": "tool_use", "id": "toolu_02", "name": "get_weather", "input": {"city": "Rome"}}
+            ])
+        );
+        // The results of parallel calls share one message
+        assert_eq!(body["messages"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            body["messages"][2],
+            json!({"role": "user", "content": [
+                {"type": "tool_result", "tool_use_id": "toolu_01", "content": "Sunny"},
+                {"type": "tool_result", "tool_use_id": "toolu_02", "content": "Rainy"}
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_parse_usage_with_cache() {
+        let usage = parse_usage(&json!({
+            "input_tokens": 20,
+            "output_tokens": 50,
+            "cache_creation_input_tokens": 1000,
+            "cache_read_input_tokens": 3000
+        }));
+        assert_eq!(usage.prompt_tokens, 4020);
+        assert_eq!(usage.total_tokens, 4070);
+        assert_eq!(usage.cache_creation_input_tokens, Some(1000));
+        assert_eq!(usage.cache_read_input_tokens, Some(3000));
+        assert_eq!(
+            usage.prompt_tokens_details.unwrap().cached_tokens,
+            Some(3000)
+        );
+
+        let usage = parse_usage(&json!({"input_tokens": 10, "output_tokens": 5}));
+        assert_eq!(usage.prompt_tokens, 10);
+        assert!(usage.cache_read_input_tokens.is_none());
+        assert!(usage.prompt_tokens_details.is_none());
+    }
+
+    #[test]
+    fn test_json_mode_through_tool() {
+        let config = AnthropicConfig::new_test("test-key");
+        let client = AnthropicClient::new(config).unwrap();
+        let schema = json!({
+            "type": "object",
+            "properties": {"color": {"type": "string"}},
+            "required": ["color"]
+        });
+        let request = ChatRequest {
+            model: "claude-3-5-sonnet-20241022".to_string(),
+            messages: vec![cached_message(MessageRole::User, "Name a color")],
+            response_format: Some(crate::core::types::ResponseFormat {
+                format_type: "json_schema".to_string(),
+                json_schema: Some(json!({"name": "color", "schema": schema})),
+                response_type: None,
+            }),
+            ..Default::default()
+        };
+
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(body["tools"][0]["name"], JSON_TOOL_NAME);
+        assert_eq!(body["tools"][0]["input_schema"], schema);
+        assert_eq!(
+            body["tool_choice"],
+            json!({"type": "tool", "name": JSON_TOOL_NAME})
+        );
+
+        let mut response = client
+            .transform_chat_response(json!({
+                "id": "msg_1",
+                "model": "claude-3-5-sonnet-20241022",
+                "content": [{
+                    "type": "tool_use",
+                    "id": "toolu_1",
+                    "name": JSON_TOOL_NAME,
+                    "input": {"color": "red"}
+                }],
+                "stop_reason": "tool_use"
+            }))
+            .unwrap();
+        json_tool_call_to_content(&mut response);
+        let choice = &response.choices[0];
+        assert_eq!(
+            choice.message.content.as_ref().unwrap().to_string(),
+            r#"{"color":"red"}"#
+        );
+        assert!(choice.message.tool_calls.is_none());
+        assert_eq!(
+            choice.finish_reason,
+            Some(crate::core::types::FinishReason::Stop)
+        );
+    }
 }
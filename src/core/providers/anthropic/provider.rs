@@ -18,7 +18,7 @@ use crate::core::types::{
     responses::{ChatChunk, ChatResponse},
 };
 
-use super::client::AnthropicClient;
+use super::client::{AnthropicClient, json_mode};
 use super::config::AnthropicConfig;
 use super::models::{ModelFeature, get_anthropic_registry};
 use super::streaming::AnthropicStream;
@@ -154,13 +154,16 @@ impl AnthropicProvider {
         headers
     }
 
-    /// Calculate cost
+    /// Calculate cost, pricing prompt cache writes and reads separately
     fn calculate_cost(&self, request: &ChatRequest, response: &ChatResponse) -> Option<f64> {
         if let Some(usage) = &response.usage {
-            super::models::CostCalculator::calculate_cost(
+            super::models::CostCalculator::calculate_extended_cost(
                 &request.model,
                 usage.prompt_tokens,
                 usage.completion_tokens,
+                usage.cache_read_input_tokens,
+                usage.cache_creation_input_tokens,
+                false,
             )
         } else {
             None
@@ -200,6 +203,8 @@ impl LLMProvider for AnthropicProvider {
             "tool_choice",
             "stream",
             "stop",
+            "thinking",
+            "response_format",
         ]
     }
 
@@ -308,8 +313,9 @@ impl LLMProvider for AnthropicProvider {
             ));
         }
 
+        let json_mode = json_mode(&request);
         let response = self.client.chat_stream(request.clone()).await?;
-        let stream = AnthropicStream::from_response(response, request.model);
+        let stream = AnthropicStream::from_response(response, request.model, json_mode);
 
         Ok(Box::pin(stream))
     }
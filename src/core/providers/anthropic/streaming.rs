@@ -2,6 +2,7 @@
 //!
 //! Independent streaming response processing with SSE parsing and real-time data conversion
 
+use std::collections::{HashMap, HashSet};
 use std::pin::Pin;
 
 use futures::{Stream, StreamExt};
@@ -11,10 +12,12 @@ use serde_json::Value;
 
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::{
+    ThinkingDelta,
     requests::MessageRole,
-    responses::{ChatChunk, ChatDelta, ChatStreamChoice, Usage},
+    responses::{ChatChunk, ChatDelta, ChatStreamChoice, FunctionCallDelta, ToolCallDelta},
 };
 
+use super::client::JSON_TOOL_NAME;
 use super::error::anthropic_stream_error;
 
 /// SSE event types
@@ -88,6 +91,36 @@ impl SSEParser {
     }
 }
 
+/// State carried between SSE events of one response
+#[derive(Debug, Default)]
+struct StreamState {
+    message_id: String,
+    /// Thinking text seen so far, used to estimate reasoning tokens
+    thinking: String,
+    /// Content block index to OpenAI tool call index
+    tool_calls: HashMap<u64, u32>,
+    /// Whether a JSON response format is forced through a tool
+    json_mode: bool,
+    /// Content blocks calling the JSON tool, streamed as content
+    json_blocks: HashSet<u64>,
+}
+
+impl StreamState {
+    /// Tool call index for a content block, assigned in arrival order
+    fn tool_call_index(&mut self, block_index: u64) -> u32 {
+        let next = self.tool_calls.len() as u32;
+        *self.tool_calls.entry(block_index).or_insert(next)
+    }
+
+    #[cfg(test)]
+    fn with_message_id(message_id: &str) -> Self {
+        Self {
+            message_id: message_id.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
 pin_project! {
     /// Anthropic streaming processor
     pub struct AnthropicStream {
@@ -98,11 +131,16 @@ pin_project! {
 
 impl AnthropicStream {
     /// Create stream from response
-    pub fn from_response(response: Response, model: String) -> Self {
+    ///
+    /// With `json_mode`, calls of the JSON tool are streamed as content.
+    pub fn from_response(response: Response, model: String, json_mode: bool) -> Self {
         let stream = async_stream::stream! {
             let mut response_stream = response.bytes_stream();
             let mut buffer = String::new();
-            let mut message_id = String::new();
+            let mut state = StreamState {
+                json_mode,
+                ..Default::default()
+            };
             let created_time = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
@@ -120,7 +158,7 @@ impl AnthropicStream {
                             buffer = buffer[newline_pos + 1..].to_string();
 
                             if let Some(event) = SSEParser::parse_event(&line) {
-                                match Self::process_event(event, &model, &mut message_id, created_time) {
+                                match Self::process_event(event, &model, &mut state, created_time) {
                                     Ok(Some(chat_chunk)) => yield Ok(chat_chunk),
                                     Ok(None) => continue,
                                     Err(e) => yield Err(e),
@@ -145,7 +183,7 @@ impl AnthropicStream {
     fn process_event(
         event: SSEEvent,
         model: &str,
-        message_id: &mut String,
+        state: &mut StreamState,
         created_time: i64,
     ) -> Result<Option<ChatChunk>, ProviderError> {
         match event {
@@ -153,12 +191,12 @@ impl AnthropicStream {
                 // Extract message ID
                 if let Some(message) = data.get("message") {
                     if let Some(id) = message.get("id").and_then(|v| v.as_str()) {
-                        *message_id = id.to_string();
+                        state.message_id = id.to_string();
                     }
                 }
 
                 Ok(Some(ChatChunk {
-                    id: message_id.clone(),
+                    id: state.message_id.clone(),
                     object: "chat.completion.chunk".to_string(),
                     created: created_time,
                     model: model.to_string(),
@@ -176,18 +214,63 @@ impl AnthropicStream {
                     }],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
 
             SSEEvent::ContentBlockDelta(data) => {
-                let content = data
-                    .get("delta")
-                    .and_then(|d| d.get("text"))
-                    .and_then(|t| t.as_str())
-                    .unwrap_or("");
+                let delta = data.get("delta");
+                let mut thinking = None;
+                let mut content = None;
+                let mut tool_calls = None;
+
+                match delta.and_then(|d| d.get("type")).and_then(|t| t.as_str()) {
+                    Some("input_json_delta") => {
+                        let partial_json = delta
+                            .and_then(|d| d.get("partial_json"))
+                            .and_then(|p| p.as_str())
+                            .unwrap_or("");
+                        if partial_json.is_empty() {
+                            return Ok(None);
+                        }
+                        let block_index = data.get("index").and_then(|i| i.as_u64()).unwrap_or(0);
+                        if state.json_blocks.contains(&block_index) {
+                            content = Some(partial_json.to_string());
+                        } else {
+                            tool_calls = Some(vec![ToolCallDelta {
+                                index: state.tool_call_index(block_index),
+                                id: None,
+                                tool_type: None,
+                                function: Some(FunctionCallDelta {
+                                    name: None,
+                                    arguments: Some(partial_json.to_string()),
+                                }),
+                            }]);
+                        }
+                    }
+                    Some("thinking_delta") => {
+                        let text = delta
+                            .and_then(|d| d.get("thinking"))
+                            .and_then(|t| t.as_str())
+                            .unwrap_or("");
+                        state.thinking.push_str(text);
+                        thinking = Some(ThinkingDelta::new(text));
+                    }
+                    // The signature only matters when the full block is sent back
+                    Some("signature_delta") => return Ok(None),
+                    _ => {
+                        content = Some(
+                            delta
+                                .and_then(|d| d.get("text"))
+                                .and_then(|t| t.as_str())
+                                .unwrap_or("")
+                                .to_string(),
+                        );
+                    }
+                }
 
                 Ok(Some(ChatChunk {
-                    id: message_id.clone(),
+                    id: state.message_id.clone(),
                     object: "chat.completion.chunk".to_string(),
                     created: created_time,
                     model: model.to_string(),
@@ -195,9 +278,9 @@ impl AnthropicStream {
                         index: 0,
                         delta: ChatDelta {
                             role: None,
-                            content: Some(content.to_string()),
-                            thinking: None,
-                            tool_calls: None,
+                            content,
+                            thinking,
+                            tool_calls,
                             function_call: None,
                         },
                         finish_reason: None,
@@ -205,22 +288,16 @@ impl AnthropicStream {
                     }],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
 
             SSEEvent::MessageDelta(data) => {
                 // Extract usage information and stop_reason
-                let usage = data.get("usage").map(|u| Usage {
-                    prompt_tokens: u.get("input_tokens").and_then(|v| v.as_u64()).unwrap_or(0)
-                        as u32,
-                    completion_tokens: u.get("output_tokens").and_then(|v| v.as_u64()).unwrap_or(0)
-                        as u32,
-                    total_tokens: (u.get("input_tokens").and_then(|v| v.as_u64()).unwrap_or(0)
-                        + u.get("output_tokens").and_then(|v| v.as_u64()).unwrap_or(0))
-                        as u32,
-                    completion_tokens_details: None,
-                    prompt_tokens_details: None,
-                    thinking_usage: None,
+                let usage = data.get("usage").map(|u| {
+                    let mut usage = super::client::parse_usage(u);
+                    super::client::set_reasoning_tokens(&mut usage, &state.thinking);
+                    usage
                 });
 
                 let finish_reason = data
@@ -230,12 +307,18 @@ impl AnthropicStream {
                     .map(|reason| match reason {
                         "end_turn" => crate::core::types::FinishReason::Stop,
                         "max_tokens" => crate::core::types::FinishReason::Length,
+                        // Only the JSON tool was called
+                        "tool_use"
+                            if state.tool_calls.is_empty() && !state.json_blocks.is_empty() =>
+                        {
+                            crate::core::types::FinishReason::Stop
+                        }
                         "tool_use" => crate::core::types::FinishReason::ToolCalls,
                         _ => crate::core::types::FinishReason::Stop,
                     });
 
                 Ok(Some(ChatChunk {
-                    id: message_id.clone(),
+                    id: state.message_id.clone(),
                     object: "chat.completion.chunk".to_string(),
                     created: created_time,
                     model: model.to_string(),
@@ -253,24 +336,71 @@ impl AnthropicStream {
                     }],
                     usage,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
 
             SSEEvent::MessageStop(_) => {
                 // Final end chunk
                 Ok(Some(ChatChunk {
-                    id: message_id.clone(),
+                    id: state.message_id.clone(),
                     object: "chat.completion.chunk".to_string(),
                     created: created_time,
                     model: model.to_string(),
                     choices: vec![],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
+                }))
+            }
+
+            SSEEvent::ContentBlockStart(data) => {
+                // Only tool use blocks announce anything; their arguments
+                // follow as input_json_delta events
+                let block = match data.get("content_block") {
+                    Some(block) if block["type"] == "tool_use" => block,
+                    _ => return Ok(None),
+                };
+                let block_index = data.get("index").and_then(|i| i.as_u64()).unwrap_or(0);
+                if state.json_mode && block["name"] == JSON_TOOL_NAME {
+                    state.json_blocks.insert(block_index);
+                    return Ok(None);
+                }
+                let tool_call = ToolCallDelta {
+                    index: state.tool_call_index(block_index),
+                    id: block.get("id").and_then(|id| id.as_str()).map(String::from),
+                    tool_type: Some("function".to_string()),
+                    function: Some(FunctionCallDelta {
+                        name: block.get("name").and_then(|n| n.as_str()).map(String::from),
+                        arguments: Some(String::new()),
+                    }),
+                };
+
+                Ok(Some(ChatChunk {
+                    id: state.message_id.clone(),
+                    object: "chat.completion.chunk".to_string(),
+                    created: created_time,
+                    model: model.to_string(),
+                    choices: vec![ChatStreamChoice {
+                        index: 0,
+                        delta: ChatDelta {
+                            role: None,
+                            content: None,
+                            thinking: None,
+                            tool_calls: Some(vec![tool_call]),
+                            function_call: None,
+                        },
+                        finish_reason: None,
+                        logprobs: None,
+                    }],
+                    usage: None,
+                    system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
 
-            SSEEvent::ContentBlockStart(_) | SSEEvent::ContentBlockStop(_) => {
-                // These events don't need to generate chunks
+            SSEEvent::ContentBlockStop(_) => {
+                // Block boundaries don't need to generate chunks
                 Ok(None)
             }
 
@@ -311,66 +441,18 @@ impl StreamUtils {
     pub async fn collect_stream_to_response(
         mut stream: AnthropicStream,
     ) -> Result<crate::core::types::ChatResponse, ProviderError> {
-        let mut content_parts = Vec::new();
-        let mut final_usage = None;
-        let mut response_id = String::new();
-        let mut model = String::new();
-        let mut created = 0;
-
-        while let Some(chunk_result) = stream.next().await {
-            match chunk_result {
-                Ok(chunk) => {
-                    if response_id.is_empty() {
-                        response_id = chunk.id.clone();
-                        model = chunk.model.clone();
-                        created = chunk.created;
-                    }
-
-                    for choice in chunk.choices {
-                        if let Some(content) = choice.delta.content {
-                            content_parts.push(content);
-                        }
-                    }
-
-                    if let Some(usage) = chunk.usage {
-                        final_usage = Some(usage);
-                    }
-                }
-                Err(e) => return Err(e),
-            }
+        let mut chunks = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            chunks.push(chunk?);
         }
 
-        let final_content = content_parts.join("");
-        let message = crate::core::types::ChatMessage {
-            role: MessageRole::Assistant,
-            content: if final_content.is_empty() {
-                None
-            } else {
-                Some(crate::core::types::MessageContent::Text(final_content))
-            },
-            thinking: None,
-            name: None,
-            tool_calls: None,
-            tool_call_id: None,
-            function_call: None,
-        };
-
-        let choice = crate::core::types::ChatChoice {
-            index: 0,
-            message,
-            finish_reason: Some(crate::core::types::FinishReason::Stop),
-            logprobs: None,
-        };
-
-        Ok(crate::core::types::ChatResponse {
-            id: response_id,
-            object: "chat.completion".to_string(),
-            created,
-            model,
-            choices: vec![choice],
-            usage: final_usage,
-            system_fingerprint: None,
-        })
+        let mut response = crate::core::types::ChatResponse::from_chunks(chunks);
+        for choice in &mut response.choices {
+            choice
+                .finish_reason
+                .get_or_insert(crate::core::types::FinishReason::Stop);
+        }
+        Ok(response)
     }
 
     /// Validate stream chunk
@@ -504,9 +586,9 @@ mod tests {
             }
         }));
 
-        let mut message_id = "msg_123".to_string();
+        let mut state = StreamState::with_message_id("msg_123");
         let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 1234567890);
+            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 1234567890);
 
         assert!(result.is_ok());
         let chunk_opt = result.unwrap();
@@ -531,9 +613,9 @@ mod tests {
             }
         }));
 
-        let mut message_id = String::new();
+        let mut state = StreamState::default();
         let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 1234567890);
+            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 1234567890);
 
         assert!(result.is_ok());
         let chunk_opt = result.unwrap();
@@ -541,7 +623,7 @@ mod tests {
 
         let chunk = chunk_opt.unwrap();
         assert_eq!(chunk.choices[0].delta.role, Some(MessageRole::Assistant));
-        assert_eq!(message_id, "msg_test_123");
+        assert_eq!(state.message_id, "msg_test_123");
     }
 
     #[test]
@@ -557,9 +639,9 @@ mod tests {
             }
         }));
 
-        let mut message_id = "msg_123".to_string();
+        let mut state = StreamState::with_message_id("msg_123");
         let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 1234567890);
+            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 1234567890);
 
         assert!(result.is_ok());
         let chunk_opt = result.unwrap();
@@ -580,8 +662,8 @@ mod tests {
             "type": "message_delta",
             "delta": { "stop_reason": "end_turn" }
         }));
-        let mut message_id = "msg_123".to_string();
-        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
         let chunk = result.unwrap().unwrap();
         assert_eq!(chunk.choices[0].finish_reason, Some(crate::core::types::FinishReason::Stop));
 
@@ -590,7 +672,7 @@ mod tests {
             "type": "message_delta",
             "delta": { "stop_reason": "max_tokens" }
         }));
-        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
         let chunk = result.unwrap().unwrap();
         assert_eq!(chunk.choices[0].finish_reason, Some(crate::core::types::FinishReason::Length));
 
@@ -599,7 +681,7 @@ mod tests {
             "type": "message_delta",
             "delta": { "stop_reason": "tool_use" }
         }));
-        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
         let chunk = result.unwrap().unwrap();
         assert_eq!(chunk.choices[0].finish_reason, Some(crate::core::types::FinishReason::ToolCalls));
     }
@@ -610,9 +692,9 @@ mod tests {
             "type": "message_stop"
         }));
 
-        let mut message_id = "msg_123".to_string();
+        let mut state = StreamState::with_message_id("msg_123");
         let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 1234567890);
+            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 1234567890);
 
         assert!(result.is_ok());
         let chunk_opt = result.unwrap();
@@ -629,9 +711,8 @@ mod tests {
             "index": 0
         }));
 
-        let mut message_id = "msg_123".to_string();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_none()); // Should skip
@@ -644,9 +725,8 @@ mod tests {
             "index": 0
         }));
 
-        let mut message_id = "msg_123".to_string();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_none()); // Should skip
@@ -654,9 +734,9 @@ mod tests {
 
     #[test]
     fn test_event_processing_ping_skip() {
-        let mut message_id = "msg_123".to_string();
+        let mut state = StreamState::with_message_id("msg_123");
         let result =
-            AnthropicStream::process_event(SSEEvent::Ping, "claude-3-5-sonnet", &mut message_id, 0);
+            AnthropicStream::process_event(SSEEvent::Ping, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_none()); // Should skip
@@ -666,9 +746,8 @@ mod tests {
     fn test_event_processing_unknown_skip() {
         let event = SSEEvent::Unknown("unknown_event".to_string());
 
-        let mut message_id = "msg_123".to_string();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_none()); // Should skip
@@ -683,9 +762,8 @@ mod tests {
             }
         }));
 
-        let mut message_id = "msg_123".to_string();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_err());
     }
@@ -702,6 +780,7 @@ mod tests {
             choices: vec![],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let result = StreamUtils::validate_stream_chunk(&chunk);
@@ -718,6 +797,7 @@ mod tests {
             choices: vec![],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let result = StreamUtils::validate_stream_chunk(&chunk);
@@ -734,6 +814,7 @@ mod tests {
             choices: vec![],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let result = StreamUtils::validate_stream_chunk(&chunk);
@@ -765,9 +846,8 @@ mod tests {
             }
         }));
 
-        let mut message_id = "msg_123".to_string();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         let chunk = result.unwrap().unwrap();
@@ -781,27 +861,187 @@ mod tests {
             "delta": {}
         }));
 
-        let mut message_id = "msg_123".to_string();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::with_message_id("msg_123");
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         let chunk = result.unwrap().unwrap();
         assert_eq!(chunk.choices[0].delta.content, Some("".to_string()));
     }
 
+    #[test]
+    fn test_event_processing_thinking_delta() {
+        let model = "claude-sonnet-4-20251022";
+        let mut state = StreamState::with_message_id("msg_123");
+
+        let event = SSEEvent::ContentBlockDelta(serde_json::json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": {"type": "thinking_delta", "thinking": "Let me work through this step by step."}
+        }));
+        let chunk = AnthropicStream::process_event(event, model, &mut state, 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            chunk.choices[0].delta.thinking_content(),
+            Some("Let me work through this step by step.")
+        );
+        assert!(chunk.choices[0].delta.content.is_none());
+
+        let event = SSEEvent::ContentBlockDelta(serde_json::json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": {"type": "signature_delta", "signature": "EqQBCgIYAhIM"}
+        }));
+        let result = AnthropicStream::process_event(event, model, &mut state, 0);
+        assert!(result.unwrap().is_none());
+
+        let event = SSEEvent::MessageDelta(serde_json::json!({
+            "type": "message_delta",
+            "delta": {"stop_reason": "end_turn"},
+            "usage": {"output_tokens": 40}
+        }));
+        let chunk = AnthropicStream::process_event(event, model, &mut state, 0)
+            .unwrap()
+            .unwrap();
+        let usage = chunk.usage.unwrap();
+        assert_eq!(usage.thinking_tokens(), Some(10));
+        assert_eq!(
+            usage.completion_tokens_details.unwrap().reasoning_tokens,
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_event_processing_tool_use() {
+        let model = "claude-3-5-sonnet";
+        let mut state = StreamState::with_message_id("msg_123");
+        let mut tool_calls = Vec::new();
+
+        let events = [
+            SSEEvent::ContentBlockStart(serde_json::json!({
+                "type": "content_block_start",
+                "index": 0,
+                "content_block": {"type": "text", "text": ""}
+            })),
+            SSEEvent::ContentBlockStart(serde_json::json!({
+                "type": "content_block_start",
+                "index": 1,
+                "content_block": {"type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {}}
+            })),
+            SSEEvent::ContentBlockDelta(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 1,
+                "delta": {"type": "input_json_delta", "partial_json": ""}
+            })),
+            SSEEvent::ContentBlockDelta(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 1,
+                "delta": {"type": "input_json_delta", "partial_json": "{\"city\": \"Par"}
+            })),
+            SSEEvent::ContentBlockDelta(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 1,
+                "delta": {"type": "input_json_delta", "partial_json": "is\"}"}
+            })),
+            SSEEvent::ContentBlockStart(serde_json::json!({
+                "type": "content_block_start",
+                "index": 2,
+                "content_block": {"type": "tool_use", "id": "toolu_02", "name": "get_time", "input": {}}
+            })),
+        ];
+        for event in events {
+            if let Some(chunk) =
+                AnthropicStream::process_event(event, model, &mut state, 0).unwrap()
+            {
+                assert!(chunk.choices[0].delta.content.is_none());
+                tool_calls.extend(chunk.choices[0].delta.tool_calls.clone().unwrap());
+            }
+        }
+
+        assert_eq!(tool_calls.len(), 4);
+        assert_eq!(tool_calls[0].index, 0);
+        assert_eq!(tool_calls[0].id.as_deref(), Some("toolu_01"));
+        assert_eq!(tool_calls[0].tool_type.as_deref(), Some("function"));
+        assert_eq!(
+            tool_calls[0].function.as_ref().unwrap().name.as_deref(),
+            Some("get_weather")
+        );
+        let arguments: String = tool_calls[1..3]
+            .iter()
+            .map(|c| {
+                assert_eq!(c.index, 0);
+                assert!(c.id.is_none());
+                c.function.as_ref().unwrap().arguments.clone().unwrap()
+            })
+            .collect();
+        assert_eq!(arguments, "{\"city\": \"Paris\"}");
+        assert_eq!(tool_calls[3].index, 1);
+        assert_eq!(tool_calls[3].id.as_deref(), Some("toolu_02"));
+    }
+
+    #[test]
+    fn test_event_processing_json_tool_as_content() {
+        let model = "claude-3-5-sonnet";
+        let mut state = StreamState {
+            json_mode: true,
+            ..StreamState::with_message_id("msg_123")
+        };
+
+        let events = [
+            SSEEvent::ContentBlockStart(serde_json::json!({
+                "type": "content_block_start",
+                "index": 0,
+                "content_block": {"type": "tool_use", "id": "toolu_01", "name": JSON_TOOL_NAME, "input": {}}
+            })),
+            SSEEvent::ContentBlockDelta(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": {"type": "input_json_delta", "partial_json": "{\"color\": "}
+            })),
+            SSEEvent::ContentBlockDelta(serde_json::json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": {"type": "input_json_delta", "partial_json": "\"red\"}"}
+            })),
+            SSEEvent::MessageDelta(serde_json::json!({
+                "type": "message_delta",
+                "delta": {"stop_reason": "tool_use"}
+            })),
+        ];
+        let chunks: Vec<ChatChunk> = events
+            .into_iter()
+            .filter_map(|event| {
+                AnthropicStream::process_event(event, model, &mut state, 0).unwrap()
+            })
+            .collect();
+
+        assert_eq!(chunks.len(), 3);
+        let content: String = chunks[..2]
+            .iter()
+            .map(|chunk| {
+                assert!(chunk.choices[0].delta.tool_calls.is_none());
+                chunk.choices[0].delta.content.clone().unwrap()
+            })
+            .collect();
+        assert_eq!(content, "{\"color\": \"red\"}");
+        assert_eq!(
+            chunks[2].choices[0].finish_reason,
+            Some(crate::core::types::FinishReason::Stop)
+        );
+    }
+
     #[test]
     fn test_message_start_missing_message() {
         let event = SSEEvent::MessageStart(serde_json::json!({
             "type": "message_start"
         }));
 
-        let mut message_id = String::new();
-        let result =
-            AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut message_id, 0);
+        let mut state = StreamState::default();
+        let result = AnthropicStream::process_event(event, "claude-3-5-sonnet", &mut state, 0);
 
         assert!(result.is_ok());
         // message_id should remain empty since there's no message field
-        assert!(message_id.is_empty());
+        assert!(state.message_id.is_empty());
     }
 }
@@ -210,8 +210,11 @@ pub trait BaseAssistantHandler {
 }
 use super::client::AzureClient;
 use super::config::AzureConfig;
-use super::error::AzureError;
+use super::error::{AzureError, azure_api_error, azure_config_error};
 use super::utils::AzureUtils;
+use crate::core::providers::base::HttpMethod;
+use crate::core::providers::openai::assistants::OpenAIAssistantsUtils;
+use crate::core::providers::unified_provider::ProviderError;
 
 #[derive(Debug)]
 pub struct AzureAssistantHandler {
@@ -577,6 +580,41 @@ impl BaseAssistantHandler for AzureAssistantHandler {
 pub struct AzureAssistantUtils;
 
 impl AzureAssistantUtils {
+    /// API version for Assistants API requests
+    ///
+    /// The Assistants API is only served by preview API versions.
+    pub fn api_version(config: &AzureConfig) -> &str {
+        if config.api_version.ends_with("-preview") {
+            &config.api_version
+        } else {
+            AZURE_ASSISTANTS_API_VERSION
+        }
+    }
+
+    /// URL of an `/openai/assistants` or `/openai/threads` endpoint
+    pub fn passthrough_url(
+        config: &AzureConfig,
+        path: &str,
+        query: &str,
+    ) -> Result<String, AzureError> {
+        let azure_endpoint = config
+            .get_effective_azure_endpoint()
+            .ok_or_else(|| azure_config_error("Azure endpoint not configured"))?;
+
+        let mut url = format!(
+            "{}/openai/{}?api-version={}",
+            azure_endpoint.trim_end_matches('/'),
+            path,
+            Self::api_version(config)
+        );
+        if !query.is_empty() {
+            url.push('&');
+            url.push_str(query);
+        }
+
+        Ok(url)
+    }
+
     pub fn get_supported_assistant_models() -> Vec<&'static str> {
         vec!["gpt-4", "gpt-4-turbo", "gpt-4o", "gpt-35-turbo"]
     }
@@ -602,3 +640,96 @@ impl AzureAssistantUtils {
         Ok(())
     }
 }
+
+/// API version used when the configured one predates the Assistants API
+pub const AZURE_ASSISTANTS_API_VERSION: &str = "2024-05-01-preview";
+
+/// Untyped Assistants API requests, used by the gateway's passthrough routes
+#[derive(Debug, Clone)]
+pub struct AzureAssistantsPassthrough {
+    config: std::sync::Arc<AzureConfig>,
+    client: reqwest::Client,
+}
+
+impl AzureAssistantsPassthrough {
+    /// Create new passthrough handler
+    pub fn new(config: AzureConfig) -> Result<Self, AzureError> {
+        let client = reqwest::Client::builder()
+            .timeout(crate::core::traits::provider::ProviderConfig::timeout(
+                &config,
+            ))
+            .build()
+            .map_err(|e| azure_config_error(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            config: std::sync::Arc::new(config),
+            client,
+        })
+    }
+
+    /// Send an Assistants API request (`assistants/...` or `threads/...`)
+    ///
+    /// `query` is an already-encoded query string.
+    pub async fn request(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        query: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, AzureError> {
+        OpenAIAssistantsUtils::validate_path(path)?;
+        let url = AzureAssistantUtils::passthrough_url(&self.config, path, query)?;
+
+        let mut request = match method {
+            HttpMethod::GET => self.client.get(&url),
+            HttpMethod::POST => self.client.post(&url),
+            HttpMethod::PUT => self.client.put(&url),
+            HttpMethod::DELETE => self.client.delete(&url),
+        };
+
+        let (name, value) = self.config.get_auth_header().await?;
+        request = request.header(name, value);
+        for (key, value) in &self.config.custom_headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(azure_api_error(status.as_u16(), body));
+        }
+
+        serde_json::from_str(&body)
+            .map_err(|e| ProviderError::response_parsing("azure", e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passthrough_url() {
+        let mut config = AzureConfig::new();
+        config.azure_endpoint = Some("https://example.openai.azure.com/".to_string());
+
+        let url =
+            AzureAssistantUtils::passthrough_url(&config, "threads/thread_abc/runs", "limit=5")
+                .unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/threads/thread_abc/runs?api-version=2024-05-01-preview&limit=5"
+        );
+
+        config.api_version = "2025-01-01-preview".to_string();
+        let url = AzureAssistantUtils::passthrough_url(&config, "assistants", "").unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/assistants?api-version=2025-01-01-preview"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Azure OpenAI Audio Handler
+//!
+//! Whisper speech-to-text and text-to-speech for Azure OpenAI deployments
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use reqwest::header::HeaderMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
+use crate::core::providers::openai::audio::OpenAIAudioUtils;
+use crate::core::types::{
+    common::RequestContext, requests::AudioTranscriptionRequest,
+    responses::AudioTranscriptionResponse,
+};
+
+use super::config::AzureConfig;
+use super::error::{AzureError, azure_api_error, azure_config_error, azure_header_error};
+use super::utils::{AzureEndpointType, AzureUtils};
+use crate::core::traits::provider::ProviderConfig;
+
+/// Azure OpenAI audio handler
+#[derive(Debug, Clone)]
+pub struct AzureAudioHandler {
+    config: Arc<AzureConfig>,
+    client: reqwest::Client,
+}
+
+impl AzureAudioHandler {
+    /// Create new audio handler
+    pub fn new(config: AzureConfig) -> Result<Self, AzureError> {
+        let client = reqwest::Client::builder()
+            .timeout(ProviderConfig::timeout(&config))
+            .build()
+            .map_err(|e| azure_config_error(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            config: Arc::new(config),
+            client,
+        })
+    }
+
+    /// Build request headers
+    ///
+    /// No Content-Type here: reqwest sets the multipart boundary itself.
+    async fn build_headers(&self) -> Result<HeaderMap, AzureError> {
+        let mut headers = HeaderMap::new();
+
+        let (name, value) = self.config.get_auth_header().await?;
+        headers.insert(name, value);
+
+        for (key, value) in &self.config.custom_headers {
+            let header_name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+                .map_err(|e| azure_header_error(format!("Invalid header name: {}", e)))?;
+            let header_value = value
+                .parse()
+                .map_err(|e| azure_header_error(format!("Invalid header value: {}", e)))?;
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(headers)
+    }
+
+    /// Transcribe audio with a Whisper deployment
+    pub async fn create_transcription(
+        &self,
+        request: AudioTranscriptionRequest,
+        _context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, AzureError> {
+        OpenAIAudioUtils::validate_transcription_request(&request)?;
+
+        let url = AzureAudioUtils::deployment_url(
+            &self.config,
+            &request.model,
+            AzureEndpointType::AudioTranscriptions,
+        )?;
+        let headers = self.build_headers().await?;
+
+        let response_format = request.response_format.clone();
+        // The deployment in the URL selects the model
+        let form = OpenAIAudioUtils::build_transcription_form(request, None)?;
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .multipart(form)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(azure_api_error(status.as_u16(), body));
+        }
+
+        OpenAIAudioUtils::parse_transcription_response(&body, response_format.as_deref())
+    }
+
+    /// Send a text-to-speech request, returning the successful audio response
+    async fn send_speech_request(
+        &self,
+        request: &SpeechRequest,
+    ) -> Result<reqwest::Response, AzureError> {
+        OpenAIAudioUtils::validate_speech_request(request)?;
+
+        let url = AzureAudioUtils::deployment_url(
+            &self.config,
+            &request.model,
+            AzureEndpointType::AudioSpeech,
+        )?;
+        let headers = self.build_headers().await?;
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .json(request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(azure_api_error(status.as_u16(), body));
+        }
+
+        Ok(response)
+    }
+
+    /// Generate speech with a TTS deployment
+    pub async fn create_speech(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<SpeechResponse, AzureError> {
+        let response = self.send_speech_request(&request).await?;
+        let audio = response.bytes().await?;
+
+        Ok(SpeechResponse {
+            audio: audio.to_vec(),
+            content_type: OpenAIAudioUtils::speech_content_type(&request).to_string(),
+        })
+    }
+
+    /// Generate speech with a TTS deployment, yielding audio chunks as they arrive
+    pub async fn create_speech_stream(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, AzureError>> + Send>>, AzureError> {
+        let response = self.send_speech_request(&request).await?;
+        let stream = response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(AzureError::from));
+
+        Ok(Box::pin(stream))
+    }
+}
+
+/// Azure audio utilities
+pub struct AzureAudioUtils;
+
+impl AzureAudioUtils {
+    /// Deployment URL for an audio endpoint
+    pub fn deployment_url(
+        config: &AzureConfig,
+        model: &str,
+        endpoint_type: AzureEndpointType,
+    ) -> Result<String, AzureError> {
+        let deployment = config.get_effective_deployment_name(model);
+        let azure_endpoint = config
+            .get_effective_azure_endpoint()
+            .ok_or_else(|| azure_config_error("Azure endpoint not configured"))?;
+
+        Ok(AzureUtils::build_azure_url(
+            &azure_endpoint,
+            &deployment,
+            &config.api_version,
+            endpoint_type,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deployment_url() {
+        let mut config = AzureConfig::new();
+        config.azure_endpoint = Some("https://example.openai.azure.com".to_string());
+        config.api_version = "2024-06-01".to_string();
+        config.deployment_name = Some("whisper".to_string());
+
+        let url = AzureAudioUtils::deployment_url(
+            &config,
+            "whisper-1",
+            AzureEndpointType::AudioTranscriptions,
+        )
+        .unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/deployments/whisper/audio/transcriptions?api-version=2024-06-01"
+        );
+
+        config.deployment_name = Some("tts".to_string());
+        let url = AzureAudioUtils::deployment_url(&config, "tts-1", AzureEndpointType::AudioSpeech)
+            .unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/deployments/tts/audio/speech?api-version=2024-06-01"
+        );
+    }
+}
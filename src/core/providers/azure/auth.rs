@@ -0,0 +1,341 @@
+//! Azure Entra ID (Azure AD) Authentication
+//!
+//! Bearer tokens for Azure OpenAI, acquired with a service principal (client
+//! credentials flow) or a managed identity and cached until shortly before
+//! they expire.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use super::config::AzureConfig;
+use super::error::{AzureError, azure_ad_error, azure_config_error};
+
+/// OAuth scope of Azure OpenAI (client credentials flow)
+pub const COGNITIVE_SERVICES_SCOPE: &str = "https://cognitiveservices.azure.com/.default";
+
+/// Resource of Azure OpenAI (managed identity endpoints)
+pub const COGNITIVE_SERVICES_RESOURCE: &str = "https://cognitiveservices.azure.com";
+
+/// Default Entra ID authority
+const DEFAULT_AUTHORITY_HOST: &str = "https://login.microsoftonline.com";
+
+/// Azure Instance Metadata Service token endpoint (VMs, AKS)
+const IMDS_TOKEN_ENDPOINT: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+
+/// Entra ID credential used to acquire tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AzureAdCredential {
+    /// Service principal with a client secret
+    ClientSecret {
+        tenant_id: String,
+        client_id: String,
+        client_secret: String,
+    },
+    /// System-assigned managed identity, or a user-assigned one by client ID
+    ManagedIdentity { client_id: Option<String> },
+}
+
+impl AzureAdCredential {
+    /// Resolve the credential described by a configuration
+    ///
+    /// `azure_ad_token_provider` selects the flow: `client_credentials` (also
+    /// `client_secret`, `service_principal`) or `managed_identity`. Without it,
+    /// a complete set of `tenant_id`, `client_id` and `client_secret` selects
+    /// the client credentials flow. Missing values fall back to the
+    /// `AZURE_TENANT_ID`, `AZURE_CLIENT_ID` and `AZURE_CLIENT_SECRET`
+    /// environment variables.
+    pub fn from_config(config: &AzureConfig) -> Result<Option<Self>, AzureError> {
+        let from_env =
+            |value: &Option<String>, var: &str| value.clone().or_else(|| std::env::var(var).ok());
+        let tenant_id = from_env(&config.tenant_id, "AZURE_TENANT_ID");
+        let client_id = from_env(&config.client_id, "AZURE_CLIENT_ID");
+        let client_secret = from_env(&config.client_secret, "AZURE_CLIENT_SECRET");
+
+        match config.azure_ad_token_provider.as_deref() {
+            Some("client_credentials" | "client_secret" | "service_principal") => {
+                match (tenant_id, client_id, client_secret) {
+                    (Some(tenant_id), Some(client_id), Some(client_secret)) => {
+                        Ok(Some(Self::ClientSecret {
+                            tenant_id,
+                            client_id,
+                            client_secret,
+                        }))
+                    }
+                    _ => Err(azure_config_error(
+                        "tenant_id, client_id and client_secret are required for the client credentials flow",
+                    )),
+                }
+            }
+            Some("managed_identity") => Ok(Some(Self::ManagedIdentity { client_id })),
+            Some(other) => Err(azure_config_error(format!(
+                "Unknown azure_ad_token_provider '{}', expected client_credentials or managed_identity",
+                other
+            ))),
+            None => match (&config.tenant_id, &config.client_id, &config.client_secret) {
+                (Some(tenant_id), Some(client_id), Some(client_secret)) => {
+                    Ok(Some(Self::ClientSecret {
+                        tenant_id: tenant_id.clone(),
+                        client_id: client_id.clone(),
+                        client_secret: client_secret.clone(),
+                    }))
+                }
+                _ => Ok(None),
+            },
+        }
+    }
+}
+
+/// Cached Entra ID access token
+#[derive(Debug, Clone)]
+pub struct AzureAdToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl AzureAdToken {
+    /// Check if token is expired
+    pub fn is_expired(&self) -> bool {
+        Utc::now() >= self.expires_at - Duration::minutes(5) // 5 min buffer
+    }
+}
+
+/// Token cache shared by every clone of an [`AzureConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct AzureAdTokenCache {
+    token: Arc<RwLock<Option<AzureAdToken>>>,
+    http_client: reqwest::Client,
+    /// Resource tokens are requested for, Azure OpenAI if unset
+    resource: Option<&'static str>,
+}
+
+impl AzureAdTokenCache {
+    /// Cache of tokens for another resource, e.g. `https://vault.azure.net`
+    pub fn for_resource(resource: &'static str) -> Self {
+        Self {
+            resource: Some(resource),
+            ..Self::default()
+        }
+    }
+
+    fn resource(&self) -> &'static str {
+        self.resource.unwrap_or(COGNITIVE_SERVICES_RESOURCE)
+    }
+
+    /// Get a valid token, acquiring a new one when the cached one expires
+    pub async fn get_token(&self, credential: &AzureAdCredential) -> Result<String, AzureError> {
+        {
+            let cache = self.token.read().await;
+            if let Some(token) = cache.as_ref() {
+                if !token.is_expired() {
+                    return Ok(token.token.clone());
+                }
+            }
+        }
+
+        let mut cache = self.token.write().await;
+        // Another request may have refreshed the token while we waited
+        if let Some(token) = cache.as_ref() {
+            if !token.is_expired() {
+                return Ok(token.token.clone());
+            }
+        }
+
+        let token = self.fetch_token(credential).await?;
+        let access_token = token.token.clone();
+        *cache = Some(token);
+        Ok(access_token)
+    }
+
+    async fn fetch_token(
+        &self,
+        credential: &AzureAdCredential,
+    ) -> Result<AzureAdToken, AzureError> {
+        match credential {
+            AzureAdCredential::ClientSecret {
+                tenant_id,
+                client_id,
+                client_secret,
+            } => {
+                self.fetch_client_secret_token(tenant_id, client_id, client_secret)
+                    .await
+            }
+            AzureAdCredential::ManagedIdentity { client_id } => {
+                self.fetch_managed_identity_token(client_id.as_deref())
+                    .await
+            }
+        }
+    }
+
+    /// Client credentials flow against the tenant's token endpoint
+    async fn fetch_client_secret_token(
+        &self,
+        tenant_id: &str,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<AzureAdToken, AzureError> {
+        let authority = std::env::var("AZURE_AUTHORITY_HOST")
+            .unwrap_or_else(|_| DEFAULT_AUTHORITY_HOST.to_string());
+        let url = format!(
+            "{}/{}/oauth2/v2.0/token",
+            authority.trim_end_matches('/'),
+            tenant_id
+        );
+
+        debug!("Requesting Azure AD token for client {}", client_id);
+
+        let scope = match self.resource {
+            Some(resource) => format!("{}/.default", resource),
+            None => COGNITIVE_SERVICES_SCOPE.to_string(),
+        };
+        let params = [
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("scope", &scope),
+        ];
+        let response = self
+            .http_client
+            .post(&url)
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| azure_ad_error(format!("Token request failed: {}", e)))?;
+
+        Self::parse_token_response(response).await
+    }
+
+    /// Managed identity, through App Service / Functions or IMDS
+    async fn fetch_managed_identity_token(
+        &self,
+        client_id: Option<&str>,
+    ) -> Result<AzureAdToken, AzureError> {
+        let mut query = vec![("resource", self.resource())];
+        if let Some(client_id) = client_id {
+            query.push(("client_id", client_id));
+        }
+
+        let request = match (
+            std::env::var("IDENTITY_ENDPOINT"),
+            std::env::var("IDENTITY_HEADER"),
+        ) {
+            (Ok(endpoint), Ok(header)) => {
+                query.push(("api-version", "2019-08-01"));
+                self.http_client
+                    .get(endpoint)
+                    .header("X-IDENTITY-HEADER", header)
+            }
+            _ => {
+                query.push(("api-version", "2018-02-01"));
+                self.http_client
+                    .get(IMDS_TOKEN_ENDPOINT)
+                    .header("Metadata", "true")
+            }
+        };
+
+        debug!("Requesting Azure AD token for managed identity");
+
+        let response = request
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| azure_ad_error(format!("Managed identity request failed: {}", e)))?;
+
+        Self::parse_token_response(response).await
+    }
+
+    async fn parse_token_response(response: reqwest::Response) -> Result<AzureAdToken, AzureError> {
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| azure_ad_error(format!("Failed to read token response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(azure_ad_error(format!(
+                "Token request failed with status {}: {}",
+                status.as_u16(),
+                body
+            )));
+        }
+
+        parse_token(&body)
+    }
+}
+
+/// Parse a token endpoint response
+///
+/// Managed identity endpoints send `expires_in` as a string.
+fn parse_token(body: &str) -> Result<AzureAdToken, AzureError> {
+    #[derive(Deserialize)]
+    struct TokenResponse {
+        access_token: String,
+        expires_in: Option<serde_json::Value>,
+    }
+
+    let response: TokenResponse = serde_json::from_str(body)
+        .map_err(|e| azure_ad_error(format!("Invalid token response: {}", e)))?;
+
+    let expires_in = response
+        .expires_in
+        .and_then(|value| match value {
+            serde_json::Value::Number(n) => n.as_i64(),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        })
+        .unwrap_or(3600);
+
+    Ok(AzureAdToken {
+        token: response.access_token,
+        expires_at: Utc::now() + Duration::seconds(expires_in),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credential_from_config() {
+        let mut config = AzureConfig::new();
+        config.tenant_id = Some("tenant".to_string());
+        config.client_id = Some("client".to_string());
+        config.client_secret = Some("secret".to_string());
+        assert!(matches!(
+            AzureAdCredential::from_config(&config).unwrap(),
+            Some(AzureAdCredential::ClientSecret { .. })
+        ));
+
+        config.azure_ad_token_provider = Some("managed_identity".to_string());
+        assert_eq!(
+            AzureAdCredential::from_config(&config).unwrap(),
+            Some(AzureAdCredential::ManagedIdentity {
+                client_id: Some("client".to_string())
+            })
+        );
+
+        config.azure_ad_token_provider = Some("device_code".to_string());
+        assert!(AzureAdCredential::from_config(&config).is_err());
+
+        assert_eq!(
+            AzureAdCredential::from_config(&AzureConfig::new()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let token = parse_token(r#"{"access_token": "abc", "expires_in": 3599}"#).unwrap();
+        assert_eq!(token.token, "abc");
+        assert!(!token.is_expired());
+
+        // Managed identity responses use strings
+        let token = parse_token(r#"{"access_token": "def", "expires_in": "120"}"#).unwrap();
+        assert_eq!(token.token, "def");
+        assert!(token.is_expired());
+
+        assert!(parse_token(r#"{"error": "invalid_client"}"#).is_err());
+    }
+}
@@ -44,20 +44,9 @@ impl AzureChatHandler {
     async fn build_headers(&self) -> Result<HeaderMap, AzureError> {
         let mut headers = HeaderMap::new();
 
-        // Add API key
-        if let Some(api_key) = self.config.get_effective_api_key().await {
-            headers.insert(
-                "api-key",
-                api_key
-                    .parse()
-                    .map_err(|e| azure_header_error(format!("Invalid API key: {}", e)))?,
-            );
-        } else {
-            return Err(ProviderError::authentication(
-                "azure",
-                "No API key available".to_string(),
-            ));
-        }
+        // Add API key or Azure AD token
+        let (name, value) = self.config.get_auth_header().await?;
+        headers.insert(name, value);
 
         headers.insert(
             "Content-Type",
@@ -361,6 +350,7 @@ impl AzureChatHandler {
                             serde_json::from_value(message["tool_calls"].clone()).ok()
                         }),
                         tool_call_id: message["tool_call_id"].as_str().map(|s| s.to_string()),
+                        cache_control: None,
                     },
                     finish_reason: choice["finish_reason"].as_str().map(|reason| match reason {
                         "stop" => FinishReason::Stop,
@@ -382,6 +372,9 @@ impl AzureChatHandler {
             prompt_tokens_details: None,
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         });
 
         let timestamp = response["created"].as_i64().unwrap_or_else(|| {
@@ -401,6 +394,7 @@ impl AzureChatHandler {
             system_fingerprint: response["system_fingerprint"]
                 .as_str()
                 .map(|s| s.to_string()),
+            provider_specific_fields: None,
         })
     }
 
@@ -462,6 +456,7 @@ impl AzureChatHandler {
             choices,
             usage: None,
             system_fingerprint: chunk["system_fingerprint"].as_str().map(|s| s.to_string()),
+            provider_specific_fields: None,
         })
     }
 }
@@ -88,6 +88,14 @@ impl AzureConfigFactory {
             config.deployment_name = Some(deployment);
         }
 
+        if let Ok(token) = std::env::var("AZURE_OPENAI_AD_TOKEN") {
+            config.azure_ad_token = Some(token);
+        }
+
+        if let Ok(provider) = std::env::var("AZURE_AD_TOKEN_PROVIDER") {
+            config.azure_ad_token_provider = Some(provider);
+        }
+
         config
     }
 
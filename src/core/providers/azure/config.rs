@@ -2,9 +2,14 @@
 //!
 //! Configuration for Azure OpenAI Service
 
+use reqwest::header::{HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::auth::{AzureAdCredential, AzureAdTokenCache};
+use super::error::{AzureError, azure_header_error};
+use crate::core::providers::unified_provider::ProviderError;
+
 /// Azure OpenAI configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AzureConfig {
@@ -14,8 +19,20 @@ pub struct AzureConfig {
     pub azure_endpoint: Option<String>,
     /// API version
     pub api_version: String,
-    /// Azure AD token provider
+    /// Static Azure AD (Entra ID) bearer token
+    #[serde(default)]
+    pub azure_ad_token: Option<String>,
+    /// Azure AD token provider: "client_credentials" or "managed_identity"
     pub azure_ad_token_provider: Option<String>,
+    /// Azure AD tenant ID (client credentials flow)
+    #[serde(default)]
+    pub tenant_id: Option<String>,
+    /// Azure AD client ID (client credentials flow, user-assigned managed identity)
+    #[serde(default)]
+    pub client_id: Option<String>,
+    /// Azure AD client secret (client credentials flow)
+    #[serde(default)]
+    pub client_secret: Option<String>,
     /// Deployment name
     pub deployment_name: Option<String>,
     /// Resource group
@@ -24,6 +41,9 @@ pub struct AzureConfig {
     pub subscription_id: Option<String>,
     /// Custom headers
     pub custom_headers: HashMap<String, String>,
+    /// Azure AD token cache, shared between clones
+    #[serde(skip)]
+    pub ad_token_cache: AzureAdTokenCache,
 }
 
 impl Default for AzureConfig {
@@ -32,11 +52,16 @@ impl Default for AzureConfig {
             api_key: None,
             azure_endpoint: None,
             api_version: "2024-02-01".to_string(),
+            azure_ad_token: None,
             azure_ad_token_provider: None,
+            tenant_id: None,
+            client_id: None,
+            client_secret: None,
             deployment_name: None,
             resource_group: None,
             subscription_id: None,
             custom_headers: HashMap::new(),
+            ad_token_cache: AzureAdTokenCache::default(),
         }
     }
 }
@@ -71,9 +96,36 @@ impl AzureConfig {
         self
     }
 
-    /// Get effective API key (from config, environment, or Azure AD)
+    /// Set a static Azure AD token
+    pub fn with_azure_ad_token(mut self, token: String) -> Self {
+        self.azure_ad_token = Some(token);
+        self
+    }
+
+    /// Authenticate with a service principal (client credentials flow)
+    pub fn with_client_credentials(
+        mut self,
+        tenant_id: String,
+        client_id: String,
+        client_secret: String,
+    ) -> Self {
+        self.azure_ad_token_provider = Some("client_credentials".to_string());
+        self.tenant_id = Some(tenant_id);
+        self.client_id = Some(client_id);
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Authenticate with a managed identity, user-assigned when `client_id` is set
+    pub fn with_managed_identity(mut self, client_id: Option<String>) -> Self {
+        self.azure_ad_token_provider = Some("managed_identity".to_string());
+        self.client_id = client_id;
+        self
+    }
+
+    /// Get effective API key (from config or environment)
     pub async fn get_effective_api_key(&self) -> Option<String> {
-        // Priority: config -> environment -> Azure AD token
+        // Priority: config -> environment
         if let Some(key) = &self.api_key {
             return Some(key.clone());
         }
@@ -86,13 +138,50 @@ impl AzureConfig {
             return Some(key);
         }
 
-        // Try Azure AD token (would need Azure AD integration)
-        if self.azure_ad_token_provider.is_some() {
-            // For now, return None - would implement Azure AD token acquisition
-            return None;
+        None
+    }
+
+    /// Get effective Azure AD token (static token, or acquired and cached)
+    pub async fn get_effective_ad_token(&self) -> Result<Option<String>, AzureError> {
+        if let Some(token) = &self.azure_ad_token {
+            return Ok(Some(token.clone()));
         }
 
-        None
+        match AzureAdCredential::from_config(self)? {
+            Some(credential) => Ok(Some(self.ad_token_cache.get_token(&credential).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the authentication header
+    ///
+    /// Priority: configured API key -> Azure AD token -> API key from the
+    /// environment -> `AZURE_OPENAI_AD_TOKEN`. API keys go in `api-key`,
+    /// Azure AD tokens in `Authorization: Bearer`.
+    pub async fn get_auth_header(&self) -> Result<(HeaderName, HeaderValue), AzureError> {
+        let api_key_header = |key: String| (HeaderName::from_static("api-key"), key);
+        let bearer_header =
+            |token: String| (reqwest::header::AUTHORIZATION, format!("Bearer {}", token));
+
+        let (name, value) = if let Some(key) = &self.api_key {
+            api_key_header(key.clone())
+        } else if let Some(token) = self.get_effective_ad_token().await? {
+            bearer_header(token)
+        } else if let Some(key) = self.get_effective_api_key().await {
+            api_key_header(key)
+        } else if let Ok(token) = std::env::var("AZURE_OPENAI_AD_TOKEN") {
+            bearer_header(token)
+        } else {
+            return Err(ProviderError::authentication(
+                "azure",
+                "No API key or Azure AD credentials available",
+            ));
+        };
+
+        let value = value
+            .parse()
+            .map_err(|e| azure_header_error(format!("Invalid credentials: {}", e)))?;
+        Ok((name, value))
     }
 
     /// Get effective Azure endpoint
@@ -196,6 +285,37 @@ mod tests {
         assert_eq!(config_no_deployment.get_effective_deployment_name("gpt-4"), "gpt-4");
     }
 
+    #[tokio::test]
+    async fn test_azure_config_auth_header() {
+        let config = AzureConfig::new().with_api_key("test-key".to_string());
+        let (name, value) = config.get_auth_header().await.unwrap();
+        assert_eq!(name, "api-key");
+        assert_eq!(value, "test-key");
+
+        let config = AzureConfig::new().with_azure_ad_token("ad-token".to_string());
+        let (name, value) = config.get_auth_header().await.unwrap();
+        assert_eq!(name, reqwest::header::AUTHORIZATION);
+        assert_eq!(value, "Bearer ad-token");
+    }
+
+    #[test]
+    fn test_azure_config_client_credentials() {
+        let config = AzureConfig::new().with_client_credentials(
+            "tenant".to_string(),
+            "client".to_string(),
+            "secret".to_string(),
+        );
+        assert_eq!(
+            config.azure_ad_token_provider.as_deref(),
+            Some("client_credentials")
+        );
+        assert_eq!(config.tenant_id.as_deref(), Some("tenant"));
+
+        // Secrets are not serialized with the token cache
+        let value = serde_json::to_value(&config).unwrap();
+        assert!(value.get("ad_token_cache").is_none());
+    }
+
     #[test]
     fn test_azure_config_effective_endpoint() {
         let config =
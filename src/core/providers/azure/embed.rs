@@ -39,20 +39,9 @@ impl AzureEmbeddingHandler {
     async fn build_headers(&self) -> Result<HeaderMap, AzureError> {
         let mut headers = HeaderMap::new();
 
-        // Add API key
-        if let Some(api_key) = self.config.get_effective_api_key().await {
-            headers.insert(
-                "api-key",
-                api_key
-                    .parse()
-                    .map_err(|e| azure_header_error(format!("Invalid API key: {}", e)))?,
-            );
-        } else {
-            return Err(ProviderError::authentication(
-                "azure",
-                "No API key available",
-            ));
-        }
+        // Add API key or Azure AD token
+        let (name, value) = self.config.get_auth_header().await?;
+        headers.insert(name, value);
 
         headers.insert(
             "Content-Type",
@@ -232,6 +221,9 @@ impl AzureEmbeddingUtils {
                 completion_tokens_details: None,
                 prompt_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             });
 
         Ok(EmbeddingResponse {
@@ -0,0 +1,212 @@
+//! Azure OpenAI Files Handler
+//!
+//! Resource-level file storage used by Azure batch and fine-tuning jobs
+
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+
+use crate::core::files::types::{
+    FileContent, FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+};
+use crate::core::providers::openai::files::{DEFAULT_FILE_CONTENT_TYPE, OpenAIFilesUtils};
+
+use super::config::AzureConfig;
+use super::error::{AzureError, azure_api_error, azure_config_error, azure_header_error};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::provider::ProviderConfig;
+
+/// Azure OpenAI files handler
+#[derive(Debug, Clone)]
+pub struct AzureFilesHandler {
+    config: Arc<AzureConfig>,
+    client: reqwest::Client,
+}
+
+impl AzureFilesHandler {
+    /// Create new files handler
+    pub fn new(config: AzureConfig) -> Result<Self, AzureError> {
+        let client = reqwest::Client::builder()
+            .timeout(ProviderConfig::timeout(&config))
+            .build()
+            .map_err(|e| azure_config_error(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            config: Arc::new(config),
+            client,
+        })
+    }
+
+    /// Build request headers
+    async fn build_headers(&self) -> Result<HeaderMap, AzureError> {
+        let mut headers = HeaderMap::new();
+
+        let (name, value) = self.config.get_auth_header().await?;
+        headers.insert(name, value);
+
+        for (key, value) in &self.config.custom_headers {
+            let header_name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+                .map_err(|e| azure_header_error(format!("Invalid header name: {}", e)))?;
+            let header_value = value
+                .parse()
+                .map_err(|e| azure_header_error(format!("Invalid header value: {}", e)))?;
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(headers)
+    }
+
+    /// Upload a file to the Azure OpenAI resource
+    pub async fn upload_file(&self, request: FileUploadRequest) -> Result<FileObject, AzureError> {
+        OpenAIFilesUtils::validate_upload_request(&request)?;
+
+        let url = AzureFilesUtils::files_url(&self.config, "", None)?;
+        let headers = self.build_headers().await?;
+        let form = OpenAIFilesUtils::build_upload_form(request)?;
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .multipart(form)
+            .send()
+            .await?;
+
+        Self::parse_response(response).await
+    }
+
+    /// List files uploaded to the resource
+    pub async fn list_files(&self, query: FileListQuery) -> Result<FileListResponse, AzureError> {
+        let url = AzureFilesUtils::files_url(&self.config, "", Some(&query))?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// Retrieve a file's metadata
+    pub async fn retrieve_file(&self, file_id: &str) -> Result<FileObject, AzureError> {
+        OpenAIFilesUtils::validate_file_id(file_id)?;
+        let url = AzureFilesUtils::files_url(&self.config, &format!("/{}", file_id), None)?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// Delete a file
+    pub async fn delete_file(&self, file_id: &str) -> Result<FileDeleteResponse, AzureError> {
+        OpenAIFilesUtils::validate_file_id(file_id)?;
+        let url = AzureFilesUtils::files_url(&self.config, &format!("/{}", file_id), None)?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.delete(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// Download a file's contents
+    pub async fn file_content(&self, file_id: &str) -> Result<FileContent, AzureError> {
+        OpenAIFilesUtils::validate_file_id(file_id)?;
+        let url = AzureFilesUtils::files_url(&self.config, &format!("/{}/content", file_id), None)?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(azure_api_error(status.as_u16(), body));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(DEFAULT_FILE_CONTENT_TYPE)
+            .to_string();
+        let content = response.bytes().await?;
+
+        Ok(FileContent {
+            content: content.to_vec(),
+            content_type,
+        })
+    }
+
+    /// Deserialize a JSON response, surfacing API errors
+    async fn parse_response<T: serde::de::DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<T, AzureError> {
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(azure_api_error(status.as_u16(), body));
+        }
+
+        serde_json::from_str(&body)
+            .map_err(|e| ProviderError::response_parsing("azure", e.to_string()))
+    }
+}
+
+/// Azure files utilities
+pub struct AzureFilesUtils;
+
+impl AzureFilesUtils {
+    /// URL of a `/openai/files` endpoint
+    ///
+    /// Files belong to the resource rather than a deployment, so no
+    /// deployment name appears in the path.
+    pub fn files_url(
+        config: &AzureConfig,
+        path: &str,
+        query: Option<&FileListQuery>,
+    ) -> Result<String, AzureError> {
+        let azure_endpoint = config
+            .get_effective_azure_endpoint()
+            .ok_or_else(|| azure_config_error("Azure endpoint not configured"))?;
+
+        let mut url = format!(
+            "{}/openai/files{}?api-version={}",
+            azure_endpoint.trim_end_matches('/'),
+            path,
+            config.api_version
+        );
+        if let Some(query) = query {
+            let query_string = query.to_query_string();
+            if !query_string.is_empty() {
+                url.push('&');
+                url.push_str(&query_string);
+            }
+        }
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files_url() {
+        let mut config = AzureConfig::new();
+        config.azure_endpoint = Some("https://example.openai.azure.com/".to_string());
+        config.api_version = "2024-10-21".to_string();
+
+        let url = AzureFilesUtils::files_url(&config, "/file-abc/content", None).unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/files/file-abc/content?api-version=2024-10-21"
+        );
+
+        let query = FileListQuery {
+            purpose: Some("batch".to_string()),
+            ..Default::default()
+        };
+        let url = AzureFilesUtils::files_url(&config, "", Some(&query)).unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/files?api-version=2024-10-21&purpose=batch"
+        );
+
+        config.azure_endpoint = None;
+        assert!(AzureFilesUtils::files_url(&config, "", None).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Azure OpenAI Fine-tuning Handler
+//!
+//! Resource-level fine-tuning jobs, trained on files uploaded through
+//! [`super::files::AzureFilesHandler`]
+
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+
+use crate::core::fine_tuning::types::{
+    FineTuningEventList, FineTuningJob, FineTuningJobList, FineTuningJobRequest,
+    FineTuningListQuery,
+};
+use crate::core::providers::openai::fine_tuning::OpenAIFineTuningUtils;
+
+use super::config::AzureConfig;
+use super::error::{AzureError, azure_api_error, azure_config_error, azure_header_error};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::provider::ProviderConfig;
+
+/// Azure OpenAI fine-tuning handler
+#[derive(Debug, Clone)]
+pub struct AzureFineTuningHandler {
+    config: Arc<AzureConfig>,
+    client: reqwest::Client,
+}
+
+impl AzureFineTuningHandler {
+    /// Create new fine-tuning handler
+    pub fn new(config: AzureConfig) -> Result<Self, AzureError> {
+        let client = reqwest::Client::builder()
+            .timeout(ProviderConfig::timeout(&config))
+            .build()
+            .map_err(|e| azure_config_error(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            config: Arc::new(config),
+            client,
+        })
+    }
+
+    /// Build request headers
+    async fn build_headers(&self) -> Result<HeaderMap, AzureError> {
+        let mut headers = HeaderMap::new();
+
+        let (name, value) = self.config.get_auth_header().await?;
+        headers.insert(name, value);
+
+        for (key, value) in &self.config.custom_headers {
+            let header_name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+                .map_err(|e| azure_header_error(format!("Invalid header name: {}", e)))?;
+            let header_value = value
+                .parse()
+                .map_err(|e| azure_header_error(format!("Invalid header value: {}", e)))?;
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(headers)
+    }
+
+    /// Create a fine-tuning job
+    ///
+    /// `model` is the base model name (e.g. `gpt-4o-mini-2024-07-18`), not a
+    /// deployment name.
+    pub async fn create_job(
+        &self,
+        request: FineTuningJobRequest,
+    ) -> Result<FineTuningJob, AzureError> {
+        OpenAIFineTuningUtils::validate_parameters(&request)?;
+
+        let url = AzureFineTuningUtils::jobs_url(&self.config, "", None)?;
+        let headers = self.build_headers().await?;
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .json(&request)
+            .send()
+            .await?;
+
+        Self::parse_response(response).await
+    }
+
+    /// List fine-tuning jobs of the resource
+    pub async fn list_jobs(
+        &self,
+        query: FineTuningListQuery,
+    ) -> Result<FineTuningJobList, AzureError> {
+        let url = AzureFineTuningUtils::jobs_url(&self.config, "", Some(&query))?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// Retrieve a fine-tuning job
+    pub async fn retrieve_job(&self, job_id: &str) -> Result<FineTuningJob, AzureError> {
+        OpenAIFineTuningUtils::validate_job_id(job_id)?;
+        let url = AzureFineTuningUtils::jobs_url(&self.config, &format!("/{}", job_id), None)?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// Cancel a running fine-tuning job
+    pub async fn cancel_job(&self, job_id: &str) -> Result<FineTuningJob, AzureError> {
+        OpenAIFineTuningUtils::validate_job_id(job_id)?;
+        let url =
+            AzureFineTuningUtils::jobs_url(&self.config, &format!("/{}/cancel", job_id), None)?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.post(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// List status events of a fine-tuning job
+    pub async fn list_events(
+        &self,
+        job_id: &str,
+        query: FineTuningListQuery,
+    ) -> Result<FineTuningEventList, AzureError> {
+        OpenAIFineTuningUtils::validate_job_id(job_id)?;
+        let url = AzureFineTuningUtils::jobs_url(
+            &self.config,
+            &format!("/{}/events", job_id),
+            Some(&query),
+        )?;
+        let headers = self.build_headers().await?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        Self::parse_response(response).await
+    }
+
+    /// Deserialize a JSON response, surfacing API errors
+    async fn parse_response<T: serde::de::DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<T, AzureError> {
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(azure_api_error(status.as_u16(), body));
+        }
+
+        serde_json::from_str(&body)
+            .map_err(|e| ProviderError::response_parsing("azure", e.to_string()))
+    }
+}
+
+/// Azure fine-tuning utilities
+pub struct AzureFineTuningUtils;
+
+impl AzureFineTuningUtils {
+    /// URL of an `/openai/fine_tuning/jobs` endpoint
+    pub fn jobs_url(
+        config: &AzureConfig,
+        path: &str,
+        query: Option<&FineTuningListQuery>,
+    ) -> Result<String, AzureError> {
+        let azure_endpoint = config
+            .get_effective_azure_endpoint()
+            .ok_or_else(|| azure_config_error("Azure endpoint not configured"))?;
+
+        let mut url = format!(
+            "{}/openai/fine_tuning/jobs{}?api-version={}",
+            azure_endpoint.trim_end_matches('/'),
+            path,
+            config.api_version
+        );
+        if let Some(query) = query {
+            let query_string = query.to_query_string();
+            if !query_string.is_empty() {
+                url.push('&');
+                url.push_str(&query_string);
+            }
+        }
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs_url() {
+        let mut config = AzureConfig::new();
+        config.azure_endpoint = Some("https://example.openai.azure.com/".to_string());
+        config.api_version = "2024-10-21".to_string();
+
+        let url = AzureFineTuningUtils::jobs_url(&config, "/ftjob-abc/cancel", None).unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/fine_tuning/jobs/ftjob-abc/cancel?api-version=2024-10-21"
+        );
+
+        let query = FineTuningListQuery {
+            limit: Some(5),
+            ..Default::default()
+        };
+        let url = AzureFineTuningUtils::jobs_url(&config, "", Some(&query)).unwrap();
+        assert_eq!(
+            url,
+            "https://example.openai.azure.com/openai/fine_tuning/jobs?api-version=2024-10-21&limit=5"
+        );
+
+        config.azure_endpoint = None;
+        assert!(AzureFineTuningUtils::jobs_url(&config, "", None).is_err());
+    }
+}
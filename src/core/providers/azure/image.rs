@@ -1,9 +1,15 @@
 //! Azure OpenAI Image Generation Handler
 //!
 //! Complete image generation functionality for Azure OpenAI Service (DALL-E)
+//!
+//! DALL-E 3 and gpt-image models are served synchronously from the
+//! deployment's `images/generations` endpoint. DALL-E 2 on preview API
+//! versions before 2023-12-01 uses the resource-level asynchronous
+//! `images/generations:submit` operation, which is polled until it finishes.
 
 use reqwest::header::HeaderMap;
 use serde_json::{Value, json};
+use std::time::{Duration, Instant};
 
 use crate::core::types::{
     common::RequestContext,
@@ -17,6 +23,18 @@ use super::utils::{AzureEndpointType, AzureUtils};
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::traits::provider::ProviderConfig;
 
+/// Earliest API version with synchronous image generation (DALL-E 3)
+pub const MIN_SYNC_IMAGE_API_VERSION: &str = "2023-12-01-preview";
+
+/// API version used when the configured one predates synchronous generation
+pub const DEFAULT_IMAGE_API_VERSION: &str = "2024-02-01";
+
+/// Interval between polls of an asynchronous DALL-E 2 operation
+const OPERATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Longest wait for an asynchronous DALL-E 2 operation
+const OPERATION_MAX_WAIT: Duration = Duration::from_secs(120);
+
 /// Azure OpenAI image generation handler
 #[derive(Debug, Clone)]
 pub struct AzureImageHandler {
@@ -39,20 +57,9 @@ impl AzureImageHandler {
     async fn build_headers(&self) -> Result<HeaderMap, AzureError> {
         let mut headers = HeaderMap::new();
 
-        // Add API key
-        if let Some(api_key) = self.config.get_effective_api_key().await {
-            headers.insert(
-                "api-key",
-                api_key
-                    .parse()
-                    .map_err(|e| azure_header_error(format!("Invalid API key: {}", e)))?,
-            );
-        } else {
-            return Err(ProviderError::authentication(
-                "azure",
-                "No API key available",
-            ));
-        }
+        // Add API key or Azure AD token
+        let (name, value) = self.config.get_auth_header().await?;
+        headers.insert(name, value);
 
         headers.insert(
             "Content-Type",
@@ -85,7 +92,7 @@ impl AzureImageHandler {
 
         // Get deployment name (for DALL-E models)
         let model_name = request.model.as_deref().unwrap_or("dall-e-3");
-        let deployment = self.config.get_effective_deployment_name(model_name);
+        let api_version = AzureImageUtils::api_version(&self.config, &request);
 
         // Get Azure endpoint
         let azure_endpoint = self
@@ -93,17 +100,24 @@ impl AzureImageHandler {
             .get_effective_azure_endpoint()
             .ok_or_else(|| azure_config_error("Azure endpoint not configured"))?;
 
+        // Transform request
+        let azure_request = AzureImageUtils::transform_request(&request)?;
+
+        if AzureImageUtils::uses_async_operation(model_name, &api_version) {
+            return self
+                .generate_image_async(&azure_endpoint, &api_version, azure_request)
+                .await;
+        }
+
         // Build URL
+        let deployment = self.config.get_effective_deployment_name(model_name);
         let url = AzureUtils::build_azure_url(
             &azure_endpoint,
             &deployment,
-            &self.config.api_version,
+            &api_version,
             AzureEndpointType::Images,
         );
 
-        // Transform request
-        let azure_request = AzureImageUtils::transform_request(&request)?;
-
         // Build headers
         let headers = self.build_headers().await?;
 
@@ -116,6 +130,87 @@ impl AzureImageHandler {
             .send()
             .await?;
 
+        // Parse response
+        let response_json = Self::parse_response(response).await?;
+
+        // Transform response
+        AzureImageUtils::transform_response(response_json)
+    }
+
+    /// Generate images with the asynchronous DALL-E 2 operation
+    async fn generate_image_async(
+        &self,
+        azure_endpoint: &str,
+        api_version: &str,
+        azure_request: Value,
+    ) -> Result<ImageGenerationResponse, AzureError> {
+        let url = format!(
+            "{}/openai/images/generations:submit?api-version={}",
+            azure_endpoint.trim_end_matches('/'),
+            api_version
+        );
+
+        let headers = self.build_headers().await?;
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers.clone())
+            .json(&azure_request)
+            .send()
+            .await?;
+
+        let operation_url = response
+            .headers()
+            .get("operation-location")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let mut operation = Self::parse_response(response).await?;
+        let operation_url = operation_url.ok_or_else(|| {
+            ProviderError::response_parsing("azure", "Missing operation-location header")
+        })?;
+
+        let started = Instant::now();
+        loop {
+            match operation["status"].as_str() {
+                Some("succeeded") => break,
+                Some(status @ ("failed" | "canceled" | "deleted")) => {
+                    return Err(azure_api_error(
+                        500,
+                        format!(
+                            "Image generation {}: {}",
+                            status,
+                            super::error::extract_azure_error_message(&operation)
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+
+            if started.elapsed() >= OPERATION_MAX_WAIT {
+                return Err(ProviderError::timeout(
+                    "azure",
+                    format!(
+                        "Image generation did not finish within {}s",
+                        OPERATION_MAX_WAIT.as_secs()
+                    ),
+                ));
+            }
+
+            tokio::time::sleep(OPERATION_POLL_INTERVAL).await;
+            let response = self
+                .client
+                .get(&operation_url)
+                .headers(headers.clone())
+                .send()
+                .await?;
+            operation = Self::parse_response(response).await?;
+        }
+
+        AzureImageUtils::transform_response(operation["result"].take())
+    }
+
+    /// Read a JSON response, surfacing API errors
+    async fn parse_response(response: reqwest::Response) -> Result<Value, AzureError> {
         // Check status
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -126,11 +221,7 @@ impl AzureImageHandler {
             return Err(azure_api_error(status, error_body));
         }
 
-        // Parse response
-        let response_json: Value = response.json().await?;
-
-        // Transform response
-        AzureImageUtils::transform_response(response_json)
+        Ok(response.json().await?)
     }
 
     /// Edit image (for DALL-E 2)
@@ -236,7 +327,15 @@ impl AzureImageUtils {
 
         // Validate quality
         if let Some(quality) = &request.quality {
-            if !["standard", "hd"].contains(&quality.as_str()) {
+            let model_name = request.model.as_deref().unwrap_or("dall-e-3");
+            if Self::is_gpt_image_model(model_name) {
+                if !["low", "medium", "high", "auto"].contains(&quality.as_str()) {
+                    return Err(azure_config_error(format!(
+                        "Invalid quality '{}'. Must be 'low', 'medium', 'high' or 'auto'",
+                        quality
+                    )));
+                }
+            } else if !["standard", "hd"].contains(&quality.as_str()) {
                 return Err(azure_config_error(format!(
                     "Invalid quality '{}'. Must be 'standard' or 'hd'",
                     quality
@@ -266,6 +365,39 @@ impl AzureImageUtils {
         Ok(())
     }
 
+    /// API version for an image request
+    ///
+    /// An `api_version` extra parameter overrides the configured version.
+    /// DALL-E 3 and gpt-image models need synchronous generation, so older
+    /// versions are raised to [`DEFAULT_IMAGE_API_VERSION`].
+    pub fn api_version(config: &AzureConfig, request: &ImageGenerationRequest) -> String {
+        let api_version = request
+            .extra_params
+            .get("api_version")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&config.api_version);
+        let model = request.model.as_deref().unwrap_or("dall-e-3");
+
+        if api_version < MIN_SYNC_IMAGE_API_VERSION && !Self::is_dalle2_model(model) {
+            DEFAULT_IMAGE_API_VERSION.to_string()
+        } else {
+            api_version.to_string()
+        }
+    }
+
+    /// Whether a request goes through the asynchronous DALL-E 2 operation
+    pub fn uses_async_operation(model: &str, api_version: &str) -> bool {
+        Self::is_dalle2_model(model) && api_version < MIN_SYNC_IMAGE_API_VERSION
+    }
+
+    fn is_dalle2_model(model: &str) -> bool {
+        model.to_lowercase().contains("dall-e-2")
+    }
+
+    fn is_gpt_image_model(model: &str) -> bool {
+        model.to_lowercase().contains("gpt-image")
+    }
+
     /// Transform request to Azure format
     pub fn transform_request(request: &ImageGenerationRequest) -> Result<Value, AzureError> {
         let mut body = json!({
@@ -297,6 +429,13 @@ impl AzureImageUtils {
             body["user"] = json!(user);
         }
 
+        // Pass through model-specific parameters (e.g. gpt-image output_format)
+        for (key, value) in &request.extra_params {
+            if key != "api_version" {
+                body[key] = value.clone();
+            }
+        }
+
         Ok(body)
     }
 
@@ -356,3 +495,92 @@ impl AzureImageUtils {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(model: &str) -> ImageGenerationRequest {
+        ImageGenerationRequest {
+            prompt: "A lighthouse at dusk".to_string(),
+            model: Some(model.to_string()),
+            n: None,
+            size: None,
+            quality: None,
+            response_format: None,
+            style: None,
+            user: None,
+            extra_params: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_api_version() {
+        let mut config = AzureConfig::new();
+        config.api_version = "2023-06-01-preview".to_string();
+
+        // DALL-E 3 needs synchronous generation
+        assert_eq!(
+            AzureImageUtils::api_version(&config, &request("dall-e-3")),
+            DEFAULT_IMAGE_API_VERSION
+        );
+        assert_eq!(
+            AzureImageUtils::api_version(&config, &request("dall-e-2")),
+            "2023-06-01-preview"
+        );
+
+        let mut req = request("dall-e-3");
+        req.extra_params
+            .insert("api_version".to_string(), json!("2024-10-21"));
+        assert_eq!(AzureImageUtils::api_version(&config, &req), "2024-10-21");
+        assert!(
+            AzureImageUtils::transform_request(&req)
+                .unwrap()
+                .get("api_version")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_uses_async_operation() {
+        assert!(AzureImageUtils::uses_async_operation(
+            "dall-e-2",
+            "2023-06-01-preview"
+        ));
+        assert!(!AzureImageUtils::uses_async_operation(
+            "dall-e-2",
+            "2024-02-01"
+        ));
+        assert!(!AzureImageUtils::uses_async_operation(
+            "dall-e-3",
+            "2023-06-01-preview"
+        ));
+    }
+
+    #[test]
+    fn test_validate_quality() {
+        let mut req = request("dall-e-3");
+        req.quality = Some("hd".to_string());
+        assert!(AzureImageUtils::validate_request(&req).is_ok());
+
+        req.quality = Some("high".to_string());
+        assert!(AzureImageUtils::validate_request(&req).is_err());
+
+        req.model = Some("gpt-image-1".to_string());
+        assert!(AzureImageUtils::validate_request(&req).is_ok());
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let response = AzureImageUtils::transform_response(json!({
+            "created": 1700000000,
+            "data": [{"url": "https://example.com/image.png", "revised_prompt": "A lighthouse"}]
+        }))
+        .unwrap();
+        assert_eq!(response.created, 1700000000);
+        assert_eq!(
+            response.data[0].url.as_deref(),
+            Some("https://example.com/image.png")
+        );
+    }
+}
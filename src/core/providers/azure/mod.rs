@@ -3,17 +3,23 @@
 //! Azure OpenAI integration for LiteLLM using LLMProvider trait
 
 pub mod assistants;
+pub mod audio;
+pub mod auth;
 pub mod batches;
 pub mod chat;
 pub mod client;
 pub mod config;
 pub mod embed;
 pub mod error;
+pub mod files;
+pub mod fine_tuning;
 pub mod image;
+pub mod realtime;
 pub mod responses;
 pub mod utils;
 
 // Re-export core utilities
+pub use auth::{AzureAdCredential, AzureAdToken, AzureAdTokenCache};
 pub use client::{AzureClient, AzureConfigFactory, AzureRateLimitInfo};
 pub use config::{AzureConfig, AzureModelInfo};
 pub use error::{
@@ -28,7 +34,10 @@ pub use crate::core::cost::providers::azure::{
 };
 
 // Re-export assistant functionality
-pub use assistants::{AzureAssistantHandler, AzureAssistantUtils};
+pub use assistants::{AzureAssistantHandler, AzureAssistantUtils, AzureAssistantsPassthrough};
+
+// Re-export audio functionality
+pub use audio::{AzureAudioHandler, AzureAudioUtils};
 
 // Re-export batch functionality
 pub use batches::{AzureBatchHandler, AzureBatchUtils};
@@ -39,21 +48,35 @@ pub use chat::{AzureChatHandler, AzureChatUtils};
 // Re-export embedding functionality
 pub use embed::{AzureEmbeddingHandler, AzureEmbeddingUtils};
 
+// Re-export files functionality
+pub use files::{AzureFilesHandler, AzureFilesUtils};
+
+// Re-export fine-tuning functionality
+pub use fine_tuning::{AzureFineTuningHandler, AzureFineTuningUtils};
+
 // Re-export image functionality
 pub use image::{AzureImageHandler, AzureImageUtils};
 
+// Re-export realtime functionality
+pub use realtime::AzureRealtimeUtils;
+
 // Re-export response processing functionality
 pub use responses::{AzureResponseHandler, AzureResponseProcessor, AzureResponseUtils};
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use futures::Stream;
 use serde_json::Value;
 use std::pin::Pin;
 
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest, ImageGenerationRequest},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse, ImageGenerationResponse},
+    requests::{AudioTranscriptionRequest, ChatRequest, EmbeddingRequest, ImageGenerationRequest},
+    responses::{
+        AudioTranscriptionResponse, ChatChunk, ChatResponse, EmbeddingResponse,
+        ImageGenerationResponse,
+    },
 };
 
 use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
@@ -65,6 +88,12 @@ pub struct AzureOpenAIProvider {
     chat_handler: AzureChatHandler,
     embedding_handler: AzureEmbeddingHandler,
     image_handler: AzureImageHandler,
+    // These handlers keep their config behind an `Arc` so they add little to
+    // the provider's size
+    audio_handler: AzureAudioHandler,
+    files_handler: AzureFilesHandler,
+    fine_tuning_handler: AzureFineTuningHandler,
+    assistants_passthrough: AzureAssistantsPassthrough,
     cost_calculator: AzureCostCalculator,
 }
 
@@ -74,6 +103,10 @@ impl AzureOpenAIProvider {
         let chat_handler = AzureChatHandler::new(config.clone())?;
         let embedding_handler = AzureEmbeddingHandler::new(config.clone())?;
         let image_handler = AzureImageHandler::new(config.clone())?;
+        let audio_handler = AzureAudioHandler::new(config.clone())?;
+        let files_handler = AzureFilesHandler::new(config.clone())?;
+        let fine_tuning_handler = AzureFineTuningHandler::new(config.clone())?;
+        let assistants_passthrough = AzureAssistantsPassthrough::new(config.clone())?;
         let cost_calculator = AzureCostCalculator::new();
 
         Ok(Self {
@@ -81,6 +114,10 @@ impl AzureOpenAIProvider {
             chat_handler,
             embedding_handler,
             image_handler,
+            audio_handler,
+            files_handler,
+            fine_tuning_handler,
+            assistants_passthrough,
             cost_calculator,
         })
     }
@@ -95,6 +132,29 @@ impl AzureOpenAIProvider {
         &self.config
     }
 
+    /// Get the handler for the resource's `/files` endpoints
+    pub fn files(&self) -> &AzureFilesHandler {
+        &self.files_handler
+    }
+
+    /// Get the handler for the resource's `/fine_tuning/jobs` endpoints
+    pub fn fine_tuning(&self) -> &AzureFineTuningHandler {
+        &self.fine_tuning_handler
+    }
+
+    /// Get the handler for the resource's Assistants API endpoints
+    pub fn assistants(&self) -> &AzureAssistantsPassthrough {
+        &self.assistants_passthrough
+    }
+
+    /// Upstream endpoint of a realtime WebSocket session on a deployment
+    pub async fn realtime_connection(
+        &self,
+        model: &str,
+    ) -> Result<crate::core::providers::openai::realtime::RealtimeConnection, AzureError> {
+        AzureRealtimeUtils::connection(&self.config, model).await
+    }
+
     /// Get cost calculator
     pub fn get_cost_calculator(&self) -> &AzureCostCalculator {
         &self.cost_calculator
@@ -137,6 +197,8 @@ impl LLMProvider for AzureOpenAIProvider {
             ProviderCapability::ChatCompletionStream,
             ProviderCapability::Embeddings,
             ProviderCapability::ImageGeneration,
+            ProviderCapability::AudioTranscription,
+            ProviderCapability::TextToSpeech,
             ProviderCapability::FunctionCalling,
             ProviderCapability::ToolCalling,
         ];
@@ -251,6 +313,34 @@ impl LLMProvider for AzureOpenAIProvider {
         self.image_handler.generate_image(request, context).await
     }
 
+    async fn audio_transcription(
+        &self,
+        request: AudioTranscriptionRequest,
+        context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, Self::Error> {
+        self.audio_handler
+            .create_transcription(request, context)
+            .await
+    }
+
+    async fn speech(
+        &self,
+        request: SpeechRequest,
+        context: RequestContext,
+    ) -> Result<SpeechResponse, Self::Error> {
+        self.audio_handler.create_speech(request, context).await
+    }
+
+    async fn speech_stream(
+        &self,
+        request: SpeechRequest,
+        context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, Self::Error>> + Send>>, Self::Error> {
+        self.audio_handler
+            .create_speech_stream(request, context)
+            .await
+    }
+
     async fn health_check(&self) -> HealthStatus {
         if self.config.api_key.is_some() {
             HealthStatus::Healthy
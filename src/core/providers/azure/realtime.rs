@@ -0,0 +1,85 @@
+//! Azure OpenAI Realtime
+//!
+//! WebSocket endpoint of real-time (`gpt-4o-realtime-preview`) deployments.
+
+use super::config::AzureConfig;
+use super::error::{AzureError, azure_config_error, azure_header_error};
+use crate::core::providers::openai::realtime::{OpenAIRealtimeUtils, RealtimeConnection};
+
+/// API version used when the configured one predates the realtime API
+pub const AZURE_REALTIME_API_VERSION: &str = "2024-10-01-preview";
+
+/// Azure realtime utilities
+pub struct AzureRealtimeUtils;
+
+impl AzureRealtimeUtils {
+    /// API version for realtime sessions
+    ///
+    /// The realtime API is only served by preview API versions.
+    pub fn api_version(config: &AzureConfig) -> &str {
+        if config.api_version.ends_with("-preview") {
+            &config.api_version
+        } else {
+            AZURE_REALTIME_API_VERSION
+        }
+    }
+
+    /// Upstream endpoint of a realtime session on a deployment
+    pub async fn connection(
+        config: &AzureConfig,
+        model: &str,
+    ) -> Result<RealtimeConnection, AzureError> {
+        let endpoint = config
+            .get_effective_azure_endpoint()
+            .ok_or_else(|| azure_config_error("Azure endpoint is required"))?;
+
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("api-version", Self::api_version(config))
+            .append_pair("deployment", &config.get_effective_deployment_name(model))
+            .finish();
+
+        let (name, value) = config.get_auth_header().await?;
+        let value = value
+            .to_str()
+            .map_err(|e| azure_header_error(format!("Invalid credentials: {}", e)))?
+            .to_string();
+
+        Ok(RealtimeConnection {
+            url: OpenAIRealtimeUtils::websocket_url(
+                &endpoint,
+                &format!("/openai/realtime?{}", query),
+            ),
+            headers: vec![(name.to_string(), value)],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_realtime_connection() {
+        let config = AzureConfig::new()
+            .with_api_key("test-key".to_string())
+            .with_azure_endpoint("https://test.openai.azure.com/".to_string());
+
+        let connection = AzureRealtimeUtils::connection(&config, "gpt-4o-realtime-preview")
+            .await
+            .unwrap();
+        assert_eq!(
+            connection.url,
+            "wss://test.openai.azure.com/openai/realtime?api-version=2024-10-01-preview&deployment=gpt-4o-realtime-preview"
+        );
+        assert_eq!(
+            connection.headers,
+            vec![("api-key".to_string(), "test-key".to_string())]
+        );
+
+        let config = config.with_api_version("2025-04-01-preview".to_string());
+        assert_eq!(
+            AzureRealtimeUtils::api_version(&config),
+            "2025-04-01-preview"
+        );
+    }
+}
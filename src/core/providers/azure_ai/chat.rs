@@ -372,6 +372,9 @@ impl AzureAIChatUtils {
             prompt_tokens_details: None,
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         });
 
         Ok(ChatResponse {
@@ -384,6 +387,7 @@ impl AzureAIChatUtils {
             system_fingerprint: response["system_fingerprint"]
                 .as_str()
                 .map(|s| s.to_string()),
+            provider_specific_fields: None,
         })
     }
 
@@ -413,6 +417,7 @@ impl AzureAIChatUtils {
             function_call: None, // TODO: Handle function calls
             tool_calls: None,    // TODO: Handle tool calls
             tool_call_id: message_data["tool_call_id"].as_str().map(|s| s.to_string()),
+            cache_control: None,
         };
 
         let finish_reason = match choice["finish_reason"].as_str() {
@@ -451,6 +456,7 @@ impl AzureAIChatUtils {
                         choices: vec![],
                         usage: None,
                         system_fingerprint: None,
+                        provider_specific_fields: None,
                     });
                 }
 
@@ -475,6 +481,7 @@ impl AzureAIChatUtils {
             choices: vec![],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -530,6 +537,7 @@ impl AzureAIChatUtils {
             choices,
             usage: None, // Usage typically not provided in streaming chunks
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 }
@@ -549,6 +557,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -760,6 +769,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         };
@@ -781,6 +791,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: Some("call_123".to_string()),
+                cache_control: None,
             }],
             ..Default::default()
         };
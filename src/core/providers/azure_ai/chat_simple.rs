@@ -116,6 +116,7 @@ impl AzureAIChatHandler {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             finish_reason: Some(FinishReason::Stop),
             logprobs: None,
@@ -134,8 +135,12 @@ impl AzureAIChatHandler {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }),
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -171,6 +176,7 @@ impl AzureAIChatHandler {
             }],
             usage: response.usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let stream = futures::stream::once(async move { Ok(chunk) });
@@ -213,6 +213,9 @@ impl AzureAIEmbeddingUtils {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             });
 
         Ok(EmbeddingResponse {
@@ -323,6 +326,7 @@ impl EmbeddingModelCapabilities {
 mod tests {
     use super::*;
     use crate::core::providers::azure_ai::config::AzureAIConfig;
+    use std::collections::HashMap;
 
     #[test]
     fn test_embedding_utils_validation() {
@@ -335,6 +339,7 @@ mod tests {
             dimensions: None,
             user: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         // Valid request should pass
@@ -382,6 +387,7 @@ mod tests {
             dimensions: Some(1536),
             user: Some("test-user".to_string()),
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         let result = AzureAIEmbeddingUtils::transform_request(&request);
@@ -99,6 +99,19 @@ impl BaseConfig {
                     "deepinfra" => "https://api.deepinfra.com/v1/openai",
                     "vertex_ai" => "https://generativelanguage.googleapis.com",
                     "openrouter" => "https://openrouter.ai/api/v1",
+                    "together_ai" => "https://api.together.xyz/v1",
+                    "cohere" => "https://api.cohere.com",
+                    "perplexity" => "https://api.perplexity.ai",
+                    "replicate" => "https://api.replicate.com/v1",
+                    "nvidia_nim" => "https://integrate.api.nvidia.com/v1",
+                    "cerebras" => "https://api.cerebras.ai/v1",
+                    "sambanova" => "https://api.sambanova.ai/v1",
+                    "voyage" => "https://api.voyageai.com/v1",
+                    "jina" => "https://api.jina.ai/v1",
+                    "dashscope" => "https://dashscope-intl.aliyuncs.com/compatible-mode/v1",
+                    "zhipu" => "https://open.bigmodel.cn/api/paas/v4",
+                    "minimax" => "https://api.minimax.io/v1",
+                    "stability" => "https://api.stability.ai",
                     _ => "https://api.openai.com/v1", // Default
                 }
                 .to_string(),
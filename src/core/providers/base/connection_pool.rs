@@ -4,6 +4,10 @@ use std::time::Duration;
 
 use reqwest::Client;
 use serde_json;
+use tracing::Instrument;
+use tracing::field::Empty;
+
+use crate::core::observability::{TRACEPARENT_FIELD, current_traceparent};
 
 use crate::core::providers::unified_provider::ProviderError;
 
@@ -87,6 +91,7 @@ impl GlobalPoolManager {
     ///
     /// Uses `HeaderPair` (Cow-based) for headers to avoid allocations for static strings.
     /// Use `header("Key", value)` for static keys or `header_owned(key, value)` for dynamic keys.
+    /// The request runs in a `provider.http` span whose trace context is sent upstream.
     pub async fn execute_request(
         &self,
         url: &str,
@@ -95,6 +100,13 @@ impl GlobalPoolManager {
         body: Option<serde_json::Value>,
     ) -> Result<reqwest::Response, ProviderError> {
         let client = self.pool.client();
+        let span = tracing::info_span!(
+            "provider.http",
+            otel.kind = "client",
+            http.method = ?method,
+            http.url = url,
+            http.status_code = Empty,
+        );
 
         let mut request_builder = match method {
             HttpMethod::GET => client.get(url),
@@ -115,10 +127,17 @@ impl GlobalPoolManager {
                 .json(&body_data);
         }
 
-        request_builder
+        if let Some(traceparent) = span.in_scope(current_traceparent) {
+            request_builder = request_builder.header(TRACEPARENT_FIELD, traceparent);
+        }
+
+        let response = request_builder
             .send()
+            .instrument(span.clone())
             .await
-            .map_err(|e| ProviderError::network("common", e.to_string()))
+            .map_err(|e| ProviderError::network("common", e.to_string()))?;
+        span.record("http.status_code", response.status().as_u16());
+        Ok(response)
     }
 
     /// Get the underlying client for direct use
@@ -4,6 +4,7 @@
 
 pub mod config;
 pub mod connection_pool;
+pub mod openai_compatible;
 pub mod pricing;
 pub mod sse;
 
@@ -11,6 +12,9 @@ pub use config::BaseConfig;
 pub use connection_pool::{
     ConnectionPool, GlobalPoolManager, HeaderPair, HttpMethod, PoolConfig, header, header_owned,
 };
+pub use openai_compatible::{
+    OpenAICompatibleClient, OpenAICompatibleErrorMapper, OpenAICompatibleProvider,
+};
 pub use pricing::{PricingDatabase, get_pricing_db};
 pub use sse::{
     AnthropicTransformer, OpenAICompatibleTransformer, SSEEvent, SSEEventType, SSETransformer,
@@ -0,0 +1,718 @@
+//! Shared OpenAI-compatible provider
+//!
+//! Many hosted APIs speak OpenAI's chat completions protocol with small
+//! departures: another name for the token limit, fields they reject, extra
+//! response metadata, a different way to authenticate. A provider describes
+//! those departures by implementing [`OpenAICompatibleClient`], and
+//! [`OpenAICompatibleProvider`] does the rest.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::Stream;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use super::pricing::Usage as PricingUsage;
+use super::sse::{OpenAICompatibleTransformer, SSETransformer, UnifiedSSEStream};
+use super::{
+    BaseConfig, GlobalPoolManager, HeaderPair, HttpMethod, get_pricing_db, header, header_owned,
+};
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::{ChatRequest, EmbeddingRequest},
+    responses::{ChatChunk, ChatResponse, EmbeddingData, EmbeddingResponse, Usage},
+};
+
+/// API logic of an OpenAI-compatible provider
+///
+/// The defaults describe an API that follows OpenAI exactly; implementors
+/// override the constants and hooks where theirs departs from it.
+#[async_trait]
+pub trait OpenAICompatibleClient: Debug + Clone + Send + Sync + Sized + 'static {
+    /// Provider configuration
+    type Config: ProviderConfig + AsRef<BaseConfig> + Debug + Clone + Send + Sync + 'static;
+
+    /// Provider name, also the routing prefix of its models
+    const NAME: &'static str;
+
+    /// Capabilities of the provider
+    const CAPABILITIES: &'static [ProviderCapability];
+
+    /// OpenAI parameters accepted by the chat endpoint
+    const SUPPORTED_PARAMS: &'static [&'static str];
+
+    /// Request fields the API rejects instead of ignoring
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &["thinking", "functions", "function_call"];
+
+    /// The one of `max_tokens` and `max_completion_tokens` the API
+    /// understands; the other is renamed to it. `None` forwards both.
+    const MAX_TOKENS_FIELD: Option<&'static str> = Some("max_tokens");
+
+    /// Path of the chat completions endpoint
+    const CHAT_PATH: &'static str = "/chat/completions";
+
+    /// Response and stream chunk fields kept in `provider_specific_fields`
+    const PROVIDER_FIELDS: &'static [&'static str] = &[];
+
+    /// Create the client for a validated configuration
+    fn new(config: &Self::Config) -> Result<Self, ProviderError>;
+
+    /// Strip the routing prefix, so `{NAME}/model` becomes `model`
+    fn normalize_model(model: &str) -> &str {
+        model
+            .strip_prefix(Self::NAME)
+            .and_then(|m| m.strip_prefix('/'))
+            .unwrap_or(model)
+    }
+
+    /// Models advertised by the provider
+    ///
+    /// By default, the models with known pricing in the unified pricing
+    /// database.
+    fn supported_models(_config: &Self::Config) -> Vec<ModelInfo> {
+        let pricing_db = get_pricing_db();
+        let mut model_ids = pricing_db.get_provider_models(Self::NAME);
+        model_ids.sort();
+
+        model_ids
+            .iter()
+            .filter_map(|id| {
+                let mut info = pricing_db.to_model_info(id, Self::NAME)?;
+                info.id = Self::normalize_model(id).to_string();
+
+                let is_embedding = pricing_db
+                    .get_model_info(id)
+                    .and_then(|p| p.mode.as_deref())
+                    == Some("embedding");
+                info.capabilities = if is_embedding {
+                    info.supports_streaming = false;
+                    vec![ProviderCapability::Embeddings]
+                } else {
+                    let mut capabilities = vec![
+                        ProviderCapability::ChatCompletion,
+                        ProviderCapability::ChatCompletionStream,
+                    ];
+                    if info.supports_tools {
+                        capabilities.push(ProviderCapability::ToolCalling);
+                    }
+                    capabilities
+                };
+                Some(info)
+            })
+            .collect()
+    }
+
+    /// Whether `model` is served, given the advertised `models`
+    fn supports_model(models: &[ModelInfo], model: &str) -> bool {
+        let model = Self::normalize_model(model);
+        models.iter().any(|m| m.id == model)
+    }
+
+    /// `Authorization` header of a request, if any
+    fn authorization(&self, config: &Self::Config) -> Result<Option<String>, ProviderError> {
+        Ok(config
+            .as_ref()
+            .api_key
+            .as_ref()
+            .map(|api_key| format!("Bearer {}", api_key)))
+    }
+
+    /// Full URL of an API path
+    fn endpoint(config: &Self::Config, path: &str) -> String {
+        format!(
+            "{}{}",
+            config
+                .as_ref()
+                .get_effective_api_base(Self::NAME)
+                .trim_end_matches('/'),
+            path
+        )
+    }
+
+    /// Adjust a chat request body after the common rewrites
+    fn prepare_request(_body: &mut Map<String, Value>) {}
+
+    /// Fail on an error reported inside a successful response or stream chunk
+    fn check_response(_value: &Value) -> Result<(), ProviderError> {
+        Ok(())
+    }
+
+    /// Adjust a stream chunk before it is parsed
+    fn prepare_chunk(_value: &mut Value) {}
+
+    /// Map an error response onto a provider error
+    fn map_http_error(status_code: u16, response_body: &str) -> ProviderError {
+        map_http_error(Self::NAME, status_code, response_body)
+    }
+
+    /// `input_type` of an embedding request with a `task_type`; by default the
+    /// hint has no equivalent and is dropped
+    fn embedding_input_type(_task_type: &str) -> Option<&str> {
+        None
+    }
+
+    /// Readiness path probed by health checks
+    ///
+    /// Without one, the provider counts as healthy when it has an API key.
+    fn health_path(_config: &Self::Config) -> Option<&'static str> {
+        None
+    }
+
+    /// Cost of a request, from the unified pricing database by default
+    fn calculate_cost(model: &str, usage: &PricingUsage) -> f64 {
+        get_pricing_db().calculate(Self::normalize_model(model), usage)
+    }
+
+    /// Synthesize speech
+    async fn speech(
+        _provider: &OpenAICompatibleProvider<Self>,
+        _request: SpeechRequest,
+    ) -> Result<SpeechResponse, ProviderError> {
+        Err(ProviderError::not_supported(Self::NAME, "speech"))
+    }
+
+    /// Build the chat request body
+    fn transform_chat_request(request: ChatRequest) -> Result<Value, ProviderError> {
+        let mut body = serde_json::to_value(&request)
+            .map_err(|e| ProviderError::serialization(Self::NAME, e.to_string()))?;
+
+        if let Some(obj) = body.as_object_mut() {
+            obj.insert(
+                "model".to_string(),
+                json!(Self::normalize_model(&request.model)),
+            );
+
+            if let Some(field) = Self::MAX_TOKENS_FIELD {
+                for alias in ["max_tokens", "max_completion_tokens"] {
+                    if alias != field
+                        && let Some(max_tokens) = obj.remove(alias)
+                    {
+                        obj.entry(field).or_insert(max_tokens);
+                    }
+                }
+            }
+
+            for field in Self::UNSUPPORTED_FIELDS {
+                obj.remove(*field);
+            }
+
+            Self::prepare_request(obj);
+        }
+
+        Ok(body)
+    }
+
+    /// Parse a chat completion response
+    ///
+    /// Several APIs leave out `object`, so it defaults to `chat.completion`.
+    fn transform_chat_response(raw_response: &[u8]) -> Result<ChatResponse, ProviderError> {
+        let mut value: Value = serde_json::from_slice(raw_response)
+            .map_err(|e| ProviderError::response_parsing(Self::NAME, e.to_string()))?;
+        Self::check_response(&value)?;
+
+        let provider_fields = take_fields(&mut value, Self::PROVIDER_FIELDS);
+        if let Some(obj) = value.as_object_mut() {
+            obj.entry("object")
+                .or_insert_with(|| json!("chat.completion"));
+        }
+
+        let mut response: ChatResponse = serde_json::from_value(value)
+            .map_err(|e| ProviderError::response_parsing(Self::NAME, e.to_string()))?;
+        if provider_fields.is_some() {
+            response.provider_specific_fields = provider_fields;
+        }
+        Ok(response)
+    }
+
+    /// Build the `/embeddings` request body
+    fn transform_embedding_request(request: &EmbeddingRequest) -> Result<Value, ProviderError> {
+        let mut body = serde_json::to_value(request)
+            .map_err(|e| ProviderError::serialization(Self::NAME, e.to_string()))?;
+
+        if let Some(obj) = body.as_object_mut() {
+            obj.insert(
+                "model".to_string(),
+                json!(Self::normalize_model(&request.model)),
+            );
+
+            let input_type = obj
+                .remove("task_type")
+                .and_then(|t| Some(Self::embedding_input_type(t.as_str()?)?.to_string()));
+            if let Some(input_type) = input_type {
+                obj.entry("input_type").or_insert(json!(input_type));
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Parse an embeddings response
+    ///
+    /// Embedding usage has no `completion_tokens`, so the body is read field
+    /// by field rather than deserialized into [`EmbeddingResponse`].
+    fn transform_embedding_response(
+        response: Value,
+        model: &str,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        let data = response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| {
+                ProviderError::response_parsing(Self::NAME, "Missing 'data' in response")
+            })?
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let embedding: Vec<f32> = item
+                    .get("embedding")
+                    .cloned()
+                    .map(serde_json::from_value)
+                    .transpose()
+                    .map_err(|e| ProviderError::response_parsing(Self::NAME, e.to_string()))?
+                    .unwrap_or_default();
+                Ok(EmbeddingData {
+                    object: "embedding".to_string(),
+                    index: item
+                        .get("index")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(i as u64) as u32,
+                    embedding,
+                })
+            })
+            .collect::<Result<Vec<_>, ProviderError>>()?;
+
+        let usage = response.get("usage").map(|u| {
+            let prompt_tokens = u.get("prompt_tokens").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+            Usage::new(prompt_tokens, 0)
+        });
+
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data,
+            model: response
+                .get("model")
+                .and_then(|m| m.as_str())
+                .unwrap_or_else(|| Self::normalize_model(model))
+                .to_string(),
+            usage,
+            embeddings: None,
+        })
+    }
+}
+
+/// Map an error response the way OpenAI-compatible APIs use status codes
+pub fn map_http_error(
+    provider: &'static str,
+    status_code: u16,
+    response_body: &str,
+) -> ProviderError {
+    let message = error_message(response_body);
+
+    match status_code {
+        400 | 422 => ProviderError::invalid_request(provider, message),
+        401 | 403 => ProviderError::authentication(provider, message),
+        404 => ProviderError::model_not_found(provider, message),
+        429 => ProviderError::rate_limit(provider, None),
+        _ => ProviderError::api_error(provider, status_code, message),
+    }
+}
+
+/// Message of an error response
+///
+/// Errors normally follow OpenAI's `{"error": {"message": "..."}}`, but some
+/// APIs answer with a flat `{"message": "..."}`, a bare `{"error": "..."}`,
+/// or problem details, `{"title": "...", "detail": "..."}`.
+pub fn error_message(response_body: &str) -> String {
+    serde_json::from_str::<Value>(response_body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/error/message")
+                .or_else(|| v.get("error"))
+                .or_else(|| v.get("message"))
+                .or_else(|| v.get("detail"))
+                .or_else(|| v.get("title"))
+                .and_then(|m| m.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| response_body.to_string())
+}
+
+/// Remove `fields` from a response or chunk
+fn take_fields(value: &mut Value, fields: &[&str]) -> Option<HashMap<String, Value>> {
+    if fields.is_empty() {
+        return None;
+    }
+    let obj = value.as_object_mut()?;
+    let taken: HashMap<_, _> = fields
+        .iter()
+        .filter_map(|key| Some((key.to_string(), obj.remove(*key)?)))
+        .collect();
+    (!taken.is_empty()).then_some(taken)
+}
+
+/// Error mapper delegating to [`OpenAICompatibleClient::map_http_error`]
+#[derive(Debug)]
+pub struct OpenAICompatibleErrorMapper<C>(PhantomData<fn() -> C>);
+
+impl<C> Default for OpenAICompatibleErrorMapper<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: OpenAICompatibleClient> ErrorMapper<ProviderError> for OpenAICompatibleErrorMapper<C> {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        C::map_http_error(status_code, response_body)
+    }
+}
+
+/// Stream transformer applying a client's chunk hooks before the
+/// OpenAI-compatible parser
+#[derive(Debug, Clone)]
+pub struct OpenAICompatibleStreamTransformer<C> {
+    inner: OpenAICompatibleTransformer,
+    client: PhantomData<fn() -> C>,
+}
+
+impl<C: OpenAICompatibleClient> OpenAICompatibleStreamTransformer<C> {
+    pub fn new() -> Self {
+        Self {
+            inner: OpenAICompatibleTransformer::new(C::NAME),
+            client: PhantomData,
+        }
+    }
+}
+
+impl<C: OpenAICompatibleClient> Default for OpenAICompatibleStreamTransformer<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: OpenAICompatibleClient> SSETransformer for OpenAICompatibleStreamTransformer<C> {
+    fn provider_name(&self) -> &'static str {
+        C::NAME
+    }
+
+    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
+        let mut value: Value = serde_json::from_str(data).map_err(|e| {
+            ProviderError::response_parsing(C::NAME, format!("Failed to parse SSE JSON: {}", e))
+        })?;
+        C::check_response(&value)?;
+        C::prepare_chunk(&mut value);
+
+        let provider_fields = take_fields(&mut value, C::PROVIDER_FIELDS);
+        let mut chunk = self.inner.transform_value(&value)?;
+        if let Some(chunk) = chunk.as_mut() {
+            chunk.provider_specific_fields = provider_fields;
+        }
+        Ok(chunk)
+    }
+}
+
+pub type OpenAICompatibleStream<C> = UnifiedSSEStream<
+    Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>,
+    OpenAICompatibleStreamTransformer<C>,
+>;
+
+/// Helper function to create an OpenAI-compatible stream
+pub fn create_stream<C: OpenAICompatibleClient>(
+    stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
+) -> OpenAICompatibleStream<C> {
+    UnifiedSSEStream::new(Box::pin(stream), OpenAICompatibleStreamTransformer::new())
+}
+
+/// Provider for an OpenAI-compatible API described by `C`
+#[derive(Debug, Clone)]
+pub struct OpenAICompatibleProvider<C: OpenAICompatibleClient> {
+    config: C::Config,
+    client: C,
+    pool_manager: Arc<GlobalPoolManager>,
+    supported_models: Vec<ModelInfo>,
+}
+
+impl<C: OpenAICompatibleClient> OpenAICompatibleProvider<C> {
+    /// Create a provider from configuration
+    ///
+    /// No network calls are made.
+    pub fn new(config: C::Config) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration(C::NAME, e))?;
+        let client = C::new(&config)?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration(C::NAME, e.to_string()))?,
+        );
+
+        Ok(Self {
+            supported_models: C::supported_models(&config),
+            config,
+            client,
+            pool_manager,
+        })
+    }
+
+    /// Provider configuration
+    pub fn config(&self) -> &C::Config {
+        &self.config
+    }
+
+    /// Replace the advertised models
+    pub(crate) fn set_models(&mut self, models: Vec<ModelInfo>) {
+        self.supported_models = models;
+    }
+
+    /// Generate headers for API requests
+    pub(crate) fn get_request_headers(&self) -> Result<Vec<HeaderPair>, ProviderError> {
+        let mut headers = Vec::with_capacity(2);
+
+        if let Some(authorization) = self.client.authorization(&self.config)? {
+            headers.push(header("Authorization", authorization));
+        }
+
+        for (key, value) in &self.config.as_ref().headers {
+            headers.push(header_owned(key.clone(), value.clone()));
+        }
+
+        Ok(headers)
+    }
+
+    pub(crate) fn endpoint(&self, path: &str) -> String {
+        C::endpoint(&self.config, path)
+    }
+
+    /// Send a request and map non-success statuses through the error mapper
+    pub(crate) async fn send(
+        &self,
+        path: &str,
+        method: HttpMethod,
+        body: Option<Value>,
+    ) -> Result<reqwest::Response, ProviderError> {
+        let response = self
+            .pool_manager
+            .execute_request(
+                &self.endpoint(path),
+                method,
+                self.get_request_headers()?,
+                body,
+            )
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(C::map_http_error(status.as_u16(), &error_text));
+        }
+
+        Ok(response)
+    }
+
+    /// POST a JSON body
+    pub(crate) async fn post(
+        &self,
+        path: &str,
+        body: Value,
+    ) -> Result<reqwest::Response, ProviderError> {
+        self.send(path, HttpMethod::POST, Some(body)).await
+    }
+}
+
+#[async_trait]
+impl<C: OpenAICompatibleClient> LLMProvider for OpenAICompatibleProvider<C> {
+    type Config = C::Config;
+    type Error = ProviderError;
+    type ErrorMapper = OpenAICompatibleErrorMapper<C>;
+
+    fn name(&self) -> &'static str {
+        C::NAME
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        C::CAPABILITIES
+    }
+
+    fn models(&self) -> &[ModelInfo] {
+        &self.supported_models
+    }
+
+    fn supports_model(&self, model: &str) -> bool {
+        C::supports_model(&self.supported_models, model)
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        C::SUPPORTED_PARAMS
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        Ok(params)
+    }
+
+    async fn transform_request(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        C::transform_chat_request(request)
+    }
+
+    async fn transform_response(
+        &self,
+        raw_response: &[u8],
+        _model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        C::transform_chat_response(raw_response)
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        OpenAICompatibleErrorMapper::default()
+    }
+
+    async fn chat_completion(
+        &self,
+        request: ChatRequest,
+        context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        let model = request.model.clone();
+        let mut body = C::transform_chat_request(request)?;
+        body["stream"] = Value::Bool(false);
+
+        let response = self.post(C::CHAT_PATH, body).await?;
+        let response_bytes = response
+            .bytes()
+            .await
+            .map_err(|e| ProviderError::network(C::NAME, e.to_string()))?;
+
+        self.transform_response(&response_bytes, &model, &context.request_id)
+            .await
+    }
+
+    async fn chat_completion_stream(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ChatChunk, Self::Error>> + Send>>, Self::Error>
+    {
+        let mut body = C::transform_chat_request(request)?;
+        body["stream"] = Value::Bool(true);
+
+        let response = self.post(C::CHAT_PATH, body).await?;
+        Ok(Box::pin(create_stream::<C>(response.bytes_stream())))
+    }
+
+    async fn embeddings(
+        &self,
+        request: EmbeddingRequest,
+        _context: RequestContext,
+    ) -> Result<EmbeddingResponse, Self::Error> {
+        if !C::CAPABILITIES.contains(&ProviderCapability::Embeddings) {
+            return Err(ProviderError::not_supported(C::NAME, "embeddings"));
+        }
+        let body = C::transform_embedding_request(&request)?;
+
+        let response = self.post("/embeddings", body).await?;
+        let value: Value = response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing(C::NAME, e.to_string()))?;
+
+        C::transform_embedding_response(value, &request.model)
+    }
+
+    async fn speech(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<SpeechResponse, Self::Error> {
+        C::speech(self, request).await
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        match C::health_path(&self.config) {
+            Some(path) => match self.send(path, HttpMethod::GET, None).await {
+                Ok(_) => HealthStatus::Healthy,
+                Err(_) => HealthStatus::Unhealthy,
+            },
+            None if self.config.as_ref().api_key.is_some() => HealthStatus::Healthy,
+            None => HealthStatus::Unhealthy,
+        }
+    }
+
+    async fn calculate_cost(
+        &self,
+        model: &str,
+        input_tokens: u32,
+        output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        let usage = PricingUsage {
+            prompt_tokens: input_tokens,
+            completion_tokens: output_tokens,
+            total_tokens: input_tokens + output_tokens,
+            reasoning_tokens: None,
+        };
+
+        Ok(C::calculate_cost(model, &usage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_http_error() {
+        let err = map_http_error("test", 401, r#"{"error":{"message":"Invalid API key"}}"#);
+        match err {
+            ProviderError::Authentication { message, .. } => assert_eq!(message, "Invalid API key"),
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+        assert!(matches!(
+            map_http_error("test", 404, "model not found"),
+            ProviderError::ModelNotFound { .. }
+        ));
+        assert!(matches!(
+            map_http_error("test", 429, ""),
+            ProviderError::RateLimit { .. }
+        ));
+        assert!(matches!(
+            map_http_error("test", 503, "overloaded"),
+            ProviderError::ApiError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_error_message_formats() {
+        assert_eq!(error_message(r#"{"error":{"message":"nested"}}"#), "nested");
+        assert_eq!(error_message(r#"{"error":"bare"}"#), "bare");
+        assert_eq!(
+            error_message(r#"{"message":"flat","type":"invalid_request_error"}"#),
+            "flat"
+        );
+        assert_eq!(error_message(r#"{"detail":"Not Found"}"#), "Not Found");
+        assert_eq!(error_message(r#"{"title":"Unauthorized"}"#), "Unauthorized");
+        assert_eq!(error_message("upstream timeout"), "upstream timeout");
+    }
+
+    #[test]
+    fn test_take_fields() {
+        let mut value = json!({"id": "x", "citations": ["https://example.com"]});
+        let fields = take_fields(&mut value, &["citations", "images"]).unwrap();
+        assert_eq!(fields["citations"][0], "https://example.com");
+        assert!(value.get("citations").is_none());
+        assert!(take_fields(&mut value, &["citations"]).is_none());
+    }
+}
@@ -359,6 +359,1090 @@ impl Default for PricingDatabase {
             },
         );
 
+        // Together AI models (keyed by Together model id)
+        models.insert(
+            "meta-llama/Llama-3.3-70B-Instruct-Turbo".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000088, // $0.88 per 1M tokens
+                output_cost_per_token: 0.00000088,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "meta-llama/Meta-Llama-3.1-8B-Instruct-Turbo".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000018, // $0.18 per 1M tokens
+                output_cost_per_token: 0.00000018,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "meta-llama/Meta-Llama-3.1-405B-Instruct-Turbo".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000035, // $3.50 per 1M tokens
+                output_cost_per_token: 0.0000035,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(130815),
+                max_input_tokens: Some(130815),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "Qwen/Qwen2.5-72B-Instruct-Turbo".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000012, // $1.20 per 1M tokens
+                output_cost_per_token: 0.0000012,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32768),
+                max_input_tokens: Some(32768),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "mistralai/Mixtral-8x7B-Instruct-v0.1".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000006, // $0.60 per 1M tokens
+                output_cost_per_token: 0.0000006,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32768),
+                max_input_tokens: Some(32768),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "deepseek-ai/DeepSeek-V3".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000125, // $1.25 per 1M tokens
+                output_cost_per_token: 0.00000125,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "BAAI/bge-large-en-v1.5".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000000016, // $0.016 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(512),
+                max_input_tokens: Some(512),
+                max_output_tokens: None,
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "togethercomputer/m2-bert-80M-8k-retrieval".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000000008, // $0.008 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("together_ai".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Cohere models
+        models.insert(
+            "command-a-03-2025".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000025, // $2.50 / $10.00 per 1M tokens
+                output_cost_per_token: 0.00001,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(256000),
+                max_input_tokens: Some(256000),
+                max_output_tokens: Some(8000),
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "command-r-plus-08-2024".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000025, // $2.50 / $10.00 per 1M tokens
+                output_cost_per_token: 0.00001,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "command-r-08-2024".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000015, // $0.15 / $0.60 per 1M tokens
+                output_cost_per_token: 0.0000006,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "command-r7b-12-2024".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000000375, // $0.0375 / $0.15 per 1M tokens
+                output_cost_per_token: 0.00000015,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "embed-english-v3.0".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000001, // $0.10 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(512),
+                max_input_tokens: Some(512),
+                max_output_tokens: None,
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "embed-multilingual-v3.0".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000001, // $0.10 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(512),
+                max_input_tokens: Some(512),
+                max_output_tokens: None,
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "rerank-v3.5".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // billed per search unit
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(4096),
+                max_input_tokens: Some(4096),
+                max_output_tokens: None,
+                litellm_provider: Some("cohere".to_string()),
+                mode: Some("rerank".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Perplexity Sonar models
+        models.insert(
+            "sonar".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000001, // $1.00 / $1.00 per 1M tokens
+                output_cost_per_token: 0.000001,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: None,
+                litellm_provider: Some("perplexity".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "sonar-pro".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000003, // $3.00 / $15.00 per 1M tokens
+                output_cost_per_token: 0.000015,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(200000),
+                max_input_tokens: Some(200000),
+                max_output_tokens: Some(8000),
+                litellm_provider: Some("perplexity".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "sonar-reasoning".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000001, // $1.00 / $5.00 per 1M tokens
+                output_cost_per_token: 0.000005,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: None,
+                litellm_provider: Some("perplexity".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "sonar-reasoning-pro".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000002, // $2.00 / $8.00 per 1M tokens
+                output_cost_per_token: 0.000008,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: None,
+                litellm_provider: Some("perplexity".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "sonar-deep-research".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000002, // $2.00 / $8.00 per 1M tokens
+                output_cost_per_token: 0.000008,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: None,
+                litellm_provider: Some("perplexity".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Replicate official language models
+        models.insert(
+            "meta/meta-llama-3-70b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000065, // $0.65 / $2.75 per 1M tokens
+                output_cost_per_token: 0.00000275,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("replicate".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "meta/meta-llama-3-8b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000005, // $0.05 / $0.25 per 1M tokens
+                output_cost_per_token: 0.00000025,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("replicate".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "meta/meta-llama-3.1-405b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000095, // $9.50 / $9.50 per 1M tokens
+                output_cost_per_token: 0.0000095,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: None,
+                litellm_provider: Some("replicate".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Nvidia NIM API catalog models (billed against account credits)
+        models.insert(
+            "meta/llama-3.1-8b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "meta/llama-3.1-70b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "meta/llama-3.1-405b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "nvidia/llama-3.1-nemotron-70b-instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "mistralai/mixtral-8x22b-instruct-v0.1".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(65536),
+                max_input_tokens: Some(65536),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "nvidia/nv-embedqa-e5-v5".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(512),
+                max_input_tokens: Some(512),
+                max_output_tokens: None,
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "nvidia/llama-3.2-nv-embedqa-1b-v2".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Credit-based, no per-token price
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("nvidia_nim".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Cerebras models
+        models.insert(
+            "llama3.1-8b".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000001, // $0.10 / $0.10 per 1M tokens
+                output_cost_per_token: 0.0000001,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("cerebras".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "llama-3.3-70b".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000085, // $0.85 / $1.20 per 1M tokens
+                output_cost_per_token: 0.0000012,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("cerebras".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "qwen-3-32b".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000004, // $0.40 / $0.80 per 1M tokens
+                output_cost_per_token: 0.0000008,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(128000),
+                max_input_tokens: Some(128000),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("cerebras".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "gpt-oss-120b".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000025, // $0.25 / $0.69 per 1M tokens
+                output_cost_per_token: 0.00000069,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(32768),
+                litellm_provider: Some("cerebras".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        // SambaNova Cloud models
+        models.insert(
+            "Meta-Llama-3.1-8B-Instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000001, // $0.10 / $0.20 per 1M tokens
+                output_cost_per_token: 0.0000002,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(16384),
+                max_input_tokens: Some(16384),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("sambanova".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "Meta-Llama-3.3-70B-Instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000006, // $0.60 / $1.20 per 1M tokens
+                output_cost_per_token: 0.0000012,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("sambanova".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "Llama-4-Maverick-17B-128E-Instruct".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000063, // $0.63 / $1.80 per 1M tokens
+                output_cost_per_token: 0.0000018,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("sambanova".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(true),
+            },
+        );
+
+        models.insert(
+            "DeepSeek-R1".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000005, // $5.00 / $7.00 per 1M tokens
+                output_cost_per_token: 0.000007,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32768),
+                max_input_tokens: Some(32768),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("sambanova".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "DeepSeek-V3-0324".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000003, // $3.00 / $4.50 per 1M tokens
+                output_cost_per_token: 0.0000045,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32768),
+                max_input_tokens: Some(32768),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("sambanova".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "Qwen3-32B".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000004, // $0.40 / $0.80 per 1M tokens
+                output_cost_per_token: 0.0000008,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: Some(4096),
+                litellm_provider: Some("sambanova".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Voyage AI embedding and rerank models
+        models.insert(
+            "voyage-3.5".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000006, // $0.06 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "voyage-3.5-lite".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // $0.02 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "voyage-3-large".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000018, // $0.18 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "voyage-code-3".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000018, // $0.18 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "voyage-finance-2".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000012, // $0.12 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "voyage-law-2".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000012, // $0.12 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(16000),
+                max_input_tokens: Some(16000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "rerank-2.5".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000005, // $0.05 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("rerank".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "rerank-2.5-lite".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // $0.02 per 1M tokens
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32000),
+                max_input_tokens: Some(32000),
+                max_output_tokens: None,
+                litellm_provider: Some("voyage".to_string()),
+                mode: Some("rerank".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Jina AI embedding and reranker models
+        models.insert(
+            "jina-embeddings-v3".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // None
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("jina".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "jina-embeddings-v2-base-en".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // None
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("jina".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "jina-embeddings-v2-base-code".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // None
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(8192),
+                max_input_tokens: Some(8192),
+                max_output_tokens: None,
+                litellm_provider: Some("jina".to_string()),
+                mode: Some("embedding".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "jina-reranker-v2-base-multilingual".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // None
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(1024),
+                max_input_tokens: Some(1024),
+                max_output_tokens: None,
+                litellm_provider: Some("jina".to_string()),
+                mode: Some("rerank".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "jina-reranker-v1-base-en".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000002, // None
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(1024),
+                max_input_tokens: Some(1024),
+                max_output_tokens: None,
+                litellm_provider: Some("jina".to_string()),
+                mode: Some("rerank".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(false),
+            },
+        );
+
+        // Alibaba Cloud DashScope (Qwen) models, international endpoint
+        models.insert(
+            "qwen-max".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000016, // None
+                output_cost_per_token: 0.0000064,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(32768),
+                max_input_tokens: Some(32768),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("dashscope".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "qwen-plus".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000004, // None
+                output_cost_per_token: 0.0000012,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("dashscope".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "qwen-turbo".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000005, // None
+                output_cost_per_token: 0.0000002,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(1000000),
+                max_input_tokens: Some(1000000),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("dashscope".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "qwen3-coder-plus".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.000001, // None
+                output_cost_per_token: 0.000005,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(1048576),
+                max_input_tokens: Some(1048576),
+                max_output_tokens: Some(65536),
+                litellm_provider: Some("dashscope".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "qwen-vl-max".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000008, // None
+                output_cost_per_token: 0.0000032,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("dashscope".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(true),
+            },
+        );
+
+        models.insert(
+            "qwen-vl-plus".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.00000021, // None
+                output_cost_per_token: 0.00000063,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(8192),
+                litellm_provider: Some("dashscope".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(false),
+                supports_vision: Some(true),
+            },
+        );
+
+        // Zhipu AI GLM models
+        models.insert(
+            "glm-4.6".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000006, // None
+                output_cost_per_token: 0.0000022,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(204800),
+                max_input_tokens: Some(204800),
+                max_output_tokens: Some(131072),
+                litellm_provider: Some("zhipu".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "glm-4.5".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000006, // None
+                output_cost_per_token: 0.0000022,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(98304),
+                litellm_provider: Some("zhipu".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "glm-4.5-air".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000002, // None
+                output_cost_per_token: 0.0000011,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(98304),
+                litellm_provider: Some("zhipu".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "glm-4.5v".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000006, // None
+                output_cost_per_token: 0.0000018,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(65536),
+                max_input_tokens: Some(65536),
+                max_output_tokens: Some(16384),
+                litellm_provider: Some("zhipu".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(true),
+            },
+        );
+
+        models.insert(
+            "glm-4-flash".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0, // Free tier
+                output_cost_per_token: 0.0,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(131072),
+                max_input_tokens: Some(131072),
+                max_output_tokens: Some(4095),
+                litellm_provider: Some("zhipu".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        // MiniMax text models
+        models.insert(
+            "MiniMax-M2".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000003, // None
+                output_cost_per_token: 0.0000012,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(204800),
+                max_input_tokens: Some(204800),
+                max_output_tokens: Some(131072),
+                litellm_provider: Some("minimax".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "MiniMax-M1".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000004, // None
+                output_cost_per_token: 0.0000022,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(1000000),
+                max_input_tokens: Some(1000000),
+                max_output_tokens: Some(40000),
+                litellm_provider: Some("minimax".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
+        models.insert(
+            "MiniMax-Text-01".to_string(),
+            ModelPricing {
+                input_cost_per_token: 0.0000002, // None
+                output_cost_per_token: 0.0000011,
+                output_cost_per_reasoning_token: 0.0,
+                max_tokens: Some(1000192),
+                max_input_tokens: Some(1000192),
+                max_output_tokens: None,
+                litellm_provider: Some("minimax".to_string()),
+                mode: Some("chat".to_string()),
+                supports_function_calling: Some(true),
+                supports_vision: Some(false),
+            },
+        );
+
         Self { models }
     }
 }
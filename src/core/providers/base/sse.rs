@@ -307,22 +307,9 @@ impl OpenAICompatibleTransformer {
     pub fn new(provider: &'static str) -> Self {
         Self { provider }
     }
-}
-
-impl SSETransformer for OpenAICompatibleTransformer {
-    fn provider_name(&self) -> &'static str {
-        self.provider
-    }
-
-    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
-        // Parse JSON
-        let json_value: Value = serde_json::from_str(data).map_err(|e| {
-            ProviderError::response_parsing(
-                self.provider,
-                format!("Failed to parse SSE JSON: {}", e),
-            )
-        })?;
 
+    /// Transform an already parsed chunk
+    pub fn transform_value(&self, json_value: &Value) -> Result<Option<ChatChunk>, ProviderError> {
         // Extract fields
         let id = json_value
             .get("id")
@@ -399,10 +386,29 @@ impl SSETransformer for OpenAICompatibleTransformer {
                 .get("system_fingerprint")
                 .and_then(|v| v.as_str())
                 .map(|s| s.to_string()),
+            provider_specific_fields: None,
         }))
     }
 }
 
+impl SSETransformer for OpenAICompatibleTransformer {
+    fn provider_name(&self) -> &'static str {
+        self.provider
+    }
+
+    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
+        // Parse JSON
+        let json_value: Value = serde_json::from_str(data).map_err(|e| {
+            ProviderError::response_parsing(
+                self.provider,
+                format!("Failed to parse SSE JSON: {}", e),
+            )
+        })?;
+
+        self.transform_value(&json_value)
+    }
+}
+
 /// Provider-specific transformers can extend the base
 #[derive(Debug, Clone)]
 pub struct AnthropicTransformer;
@@ -464,6 +470,7 @@ impl SSETransformer for AnthropicTransformer {
                     choices: vec![choice],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
             "message_stop" => Ok(None),
@@ -2,8 +2,11 @@
 //!
 //! Modern unified API for chat completions in Bedrock
 
+use crate::core::providers::bedrock::guardrails::{self, GuardrailTrace};
+use crate::core::providers::shared::ToolTransformer;
 use crate::core::providers::unified_provider::ProviderError;
-use crate::core::types::requests::ChatRequest;
+use crate::core::types::requests::{ChatMessage, ChatRequest};
+use crate::core::types::responses::{ChatChoice, ChatResponse, FinishReason, Usage};
 use crate::core::types::{MessageContent, MessageRole};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -18,7 +21,7 @@ pub struct ConverseRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inference_config: Option<InferenceConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tool_config: Option<ToolConfig>,
+    pub tool_config: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guardrail_config: Option<GuardrailConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,8 +45,11 @@ pub struct SystemMessage {
 }
 
 /// Content block for messages
+///
+/// Each block is an object with a single key naming its kind, such as
+/// `{"text": "..."}` or `{"toolUse": {...}}`.
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(untagged, rename_all_fields = "camelCase")]
 pub enum ContentBlock {
     Text { text: String },
     Image { image: ImageBlock },
@@ -79,7 +85,14 @@ pub struct DocumentBlock {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum DocumentSource {
-    Bytes { bytes: String },
+    Bytes(String),
+    S3Location(S3Location),
+}
+
+/// Object in S3
+#[derive(Debug, Serialize, Deserialize)]
+pub struct S3Location {
+    pub uri: String,
 }
 
 /// Tool use block
@@ -103,7 +116,7 @@ pub struct ToolResultBlock {
 
 /// Tool result content
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(untagged)]
 pub enum ToolResultContent {
     Text { text: String },
     Image { image: ImageBlock },
@@ -130,45 +143,6 @@ pub struct InferenceConfig {
     pub stop_sequences: Option<Vec<String>>,
 }
 
-/// Tool configuration
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ToolConfig {
-    pub tools: Vec<ToolSpec>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tool_choice: Option<ToolChoice>,
-}
-
-/// Tool specification
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ToolSpec {
-    pub tool_spec: ToolSpecDefinition,
-}
-
-/// Tool specification definition
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ToolSpecDefinition {
-    pub name: String,
-    pub description: String,
-    pub input_schema: InputSchema,
-}
-
-/// Input schema for tools
-#[derive(Debug, Serialize, Deserialize)]
-pub struct InputSchema {
-    pub json: Value,
-}
-
-/// Tool choice
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub enum ToolChoice {
-    Auto,
-    Any,
-    Tool { name: String },
-}
-
 /// Guardrail configuration
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -176,16 +150,28 @@ pub struct GuardrailConfig {
     pub guardrail_identifier: String,
     pub guardrail_version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trace: Option<bool>,
+    pub trace: Option<GuardrailTrace>,
+}
+
+impl From<&guardrails::GuardrailConfig> for GuardrailConfig {
+    fn from(config: &guardrails::GuardrailConfig) -> Self {
+        Self {
+            guardrail_identifier: config.guardrail_id.clone(),
+            guardrail_version: config.guardrail_version.clone(),
+            trace: config.trace,
+        }
+    }
 }
 
 /// Execute a converse API request
 pub async fn execute_converse(
     client: &crate::core::providers::bedrock::client::BedrockClient,
     request: &ChatRequest,
+    guardrail: Option<&guardrails::GuardrailConfig>,
 ) -> Result<Value, ProviderError> {
     // Transform ChatRequest to ConverseRequest
-    let converse_request = transform_to_converse(request)?;
+    let mut converse_request = transform_to_converse(request)?;
+    converse_request.guardrail_config = guardrail.map(GuardrailConfig::from);
 
     // Send request using the client
     let response = client
@@ -207,6 +193,10 @@ pub async fn execute_converse(
 fn transform_to_converse(request: &ChatRequest) -> Result<ConverseRequest, ProviderError> {
     let mut messages = Vec::new();
     let mut system_messages = Vec::new();
+    // Bedrock requires each document to be named
+    let mut document_count = 0;
+
+    check_document_urls(request)?;
 
     for msg in &request.messages {
         match msg.role {
@@ -222,6 +212,7 @@ fn transform_to_converse(request: &ChatRequest) -> Result<ConverseRequest, Provi
                                 .filter_map(|part| {
                                     if let crate::core::types::requests::ContentPart::Text {
                                         text,
+                                        ..
                                     } = part
                                     {
                                         Some(text.clone())
@@ -248,18 +239,20 @@ fn transform_to_converse(request: &ChatRequest) -> Result<ConverseRequest, Provi
                 }
                 .to_string();
 
-                let content = if let Some(msg_content) = &msg.content {
-                    match msg_content {
-                        MessageContent::Text(text) => {
-                            vec![ContentBlock::Text { text: text.clone() }]
-                        }
-                        MessageContent::Parts(parts) => {
-                            parts
-                                .iter()
-                                .filter_map(|part| {
-                                    match part {
+                let mut content =
+                    if let Some(msg_content) = &msg.content {
+                        match msg_content {
+                            MessageContent::Text(text) => {
+                                vec![ContentBlock::Text { text: text.clone() }]
+                            }
+                            MessageContent::Parts(parts) => {
+                                parts
+                                    .iter()
+                                    .filter_map(|part| {
+                                        match part {
                                         crate::core::types::requests::ContentPart::Text {
                                             text,
+                                            ..
                                         } => Some(ContentBlock::Text { text: text.clone() }),
                                         crate::core::types::requests::ContentPart::Image {
                                             ..
@@ -280,37 +273,82 @@ fn transform_to_converse(request: &ChatRequest) -> Result<ConverseRequest, Provi
                                             None
                                         }
                                         crate::core::types::requests::ContentPart::Document {
+                                            source,
                                             ..
                                         } => {
-                                            // TODO: Handle document content
-                                            None
+                                            document_count += 1;
+                                            Some(ContentBlock::Document {
+                                                document: DocumentBlock {
+                                                    format: document_format(&source.media_type),
+                                                    name: format!("Document {}", document_count),
+                                                    source: match &source.url {
+                                                        Some(url) => DocumentSource::S3Location(
+                                                            S3Location { uri: url.clone() },
+                                                        ),
+                                                        None => DocumentSource::Bytes(
+                                                            source.data.clone(),
+                                                        ),
+                                                    },
+                                                },
+                                            })
                                         }
                                         crate::core::types::requests::ContentPart::ToolResult {
-                                            ..
-                                        } => {
-                                            // TODO: Handle tool result content
-                                            None
-                                        }
+                                            tool_use_id,
+                                            content,
+                                            is_error,
+                                        } => Some(ContentBlock::ToolResult {
+                                            tool_result: ToolResultBlock {
+                                                tool_use_id: tool_use_id.clone(),
+                                                content: vec![ToolResultContent::Text {
+                                                    text: match content {
+                                                        Value::String(text) => text.clone(),
+                                                        content => content.to_string(),
+                                                    },
+                                                }],
+                                                status: is_error
+                                                    .filter(|is_error| *is_error)
+                                                    .map(|_| "error".to_string()),
+                                            },
+                                        }),
                                         crate::core::types::requests::ContentPart::ToolUse {
-                                            ..
-                                        } => {
-                                            // TODO: Handle tool use content
-                                            None
-                                        }
+                                            id,
+                                            name,
+                                            input,
+                                        } => Some(ContentBlock::ToolUse {
+                                            tool_use: ToolUseBlock {
+                                                tool_use_id: id.clone(),
+                                                name: name.clone(),
+                                                input: input.clone(),
+                                            },
+                                        }),
                                     }
-                                })
-                                .collect()
+                                    })
+                                    .collect()
+                            }
                         }
-                    }
-                } else {
-                    vec![]
-                };
+                    } else {
+                        vec![]
+                    };
+
+                // Tool calls follow the text they come with
+                if let Some(tool_calls) = &msg.tool_calls {
+                    content.retain(
+                        |block| !matches!(block, ContentBlock::Text { text } if text.is_empty()),
+                    );
+                    content.extend(tool_calls.iter().map(|call| ContentBlock::ToolUse {
+                        tool_use: ToolUseBlock {
+                            tool_use_id: call.id.clone(),
+                            name: call.function.name.clone(),
+                            input: ToolTransformer::arguments(call),
+                        },
+                    }));
+                }
 
                 messages.push(ConverseMessage { role, content });
             }
-            _ => {
-                // Skip function/tool messages for now
-                // TODO: Handle tool messages
+            MessageRole::Tool => push_tool_result(&mut messages, msg),
+            MessageRole::Function => {
+                // Legacy function results have no call ID to refer to
             }
         }
     }
@@ -323,32 +361,9 @@ fn transform_to_converse(request: &ChatRequest) -> Result<ConverseRequest, Provi
         stop_sequences: request.stop.clone(),
     });
 
-    // Build tool config if tools are present
-    let tool_config = if let Some(tools) = &request.tools {
-        let tool_specs: Vec<ToolSpec> = tools
-            .iter()
-            .map(|tool| ToolSpec {
-                tool_spec: ToolSpecDefinition {
-                    name: tool.function.name.clone(),
-                    description: tool.function.description.clone().unwrap_or_default(),
-                    input_schema: InputSchema {
-                        json: tool
-                            .function
-                            .parameters
-                            .clone()
-                            .unwrap_or(Value::Object(Default::default())),
-                    },
-                },
-            })
-            .collect();
-
-        Some(ToolConfig {
-            tools: tool_specs,
-            tool_choice: None, // TODO: Map tool_choice
-        })
-    } else {
-        None
-    };
+    let tool_config = request.tools.as_deref().and_then(|tools| {
+        ToolTransformer::to_converse_tool_config(tools, request.tool_choice.as_ref())
+    });
 
     Ok(ConverseRequest {
         messages,
@@ -359,7 +374,314 @@ fn transform_to_converse(request: &ChatRequest) -> Result<ConverseRequest, Provi
         },
         inference_config,
         tool_config,
-        guardrail_config: None, // TODO: Add guardrail support
+        guardrail_config: None,
         additional_model_request_fields: None,
     })
 }
+
+/// Bedrock document format for a media type
+fn document_format(media_type: &str) -> String {
+    match media_type {
+        "text/plain" => "txt",
+        "text/markdown" => "md",
+        "text/csv" => "csv",
+        "text/html" => "html",
+        "application/msword" => "doc",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => "docx",
+        "application/vnd.ms-excel" => "xls",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => "xlsx",
+        _ => "pdf",
+    }
+    .to_string()
+}
+
+/// Reject documents given by a URL Bedrock cannot read
+fn check_document_urls(request: &ChatRequest) -> Result<(), ProviderError> {
+    let unreadable = request
+        .messages
+        .iter()
+        .filter_map(|msg| match &msg.content {
+            Some(MessageContent::Parts(parts)) => Some(parts),
+            _ => None,
+        })
+        .flatten()
+        .any(|part| {
+            matches!(
+                part,
+                crate::core::types::requests::ContentPart::Document { source, .. }
+                    if source.url.as_ref().is_some_and(|url| !url.starts_with("s3://"))
+            )
+        });
+    if unreadable {
+        return Err(ProviderError::not_supported(
+            "bedrock",
+            "document URLs other than s3://",
+        ));
+    }
+    Ok(())
+}
+
+/// Add a tool message as a `toolResult` block of a user message
+///
+/// The results of parallel calls share one message.
+fn push_tool_result(messages: &mut Vec<ConverseMessage>, msg: &ChatMessage) {
+    let block = ContentBlock::ToolResult {
+        tool_result: ToolResultBlock {
+            tool_use_id: msg.tool_call_id.clone().unwrap_or_default(),
+            content: vec![ToolResultContent::Text {
+                text: ToolTransformer::result_text(msg),
+            }],
+            status: None,
+        },
+    };
+    match messages.last_mut() {
+        Some(last)
+            if last.role == "user"
+                && last
+                    .content
+                    .iter()
+                    .all(|block| matches!(block, ContentBlock::ToolResult { .. })) =>
+        {
+            last.content.push(block)
+        }
+        _ => messages.push(ConverseMessage {
+            role: "user".to_string(),
+            content: vec![block],
+        }),
+    }
+}
+
+/// Transform a Converse API response to OpenAI format
+///
+/// Responses have the same shape for every model family.
+pub fn transform_converse_response(response: &Value, model: &str) -> ChatResponse {
+    let blocks = response
+        .pointer("/output/message/content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let text: String = blocks
+        .iter()
+        .filter_map(|block| block.get("text").and_then(Value::as_str))
+        .collect();
+    let tool_calls: Vec<_> = blocks
+        .iter()
+        .filter_map(|block| {
+            let tool_use = block.get("toolUse")?;
+            Some(ToolTransformer::tool_call(
+                tool_use.get("toolUseId")?.as_str()?,
+                tool_use.get("name")?.as_str()?,
+                tool_use.get("input")?,
+            ))
+        })
+        .collect();
+
+    let finish_reason = match response.get("stopReason").and_then(Value::as_str) {
+        Some("max_tokens") => FinishReason::Length,
+        Some("tool_use") => FinishReason::ToolCalls,
+        Some("guardrail_intervened" | "content_filtered") => FinishReason::ContentFilter,
+        _ => FinishReason::Stop,
+    };
+
+    let usage = response.get("usage").map(|usage| {
+        let tokens = |key| usage.get(key).and_then(Value::as_u64).unwrap_or(0) as u32;
+        Usage {
+            prompt_tokens: tokens("inputTokens"),
+            completion_tokens: tokens("outputTokens"),
+            total_tokens: tokens("inputTokens") + tokens("outputTokens"),
+            ..Default::default()
+        }
+    });
+
+    ChatResponse {
+        id: format!("bedrock-{}", uuid::Uuid::new_v4()),
+        object: "chat.completion".to_string(),
+        created: chrono::Utc::now().timestamp(),
+        model: model.to_string(),
+        choices: vec![ChatChoice {
+            index: 0,
+            message: ChatMessage {
+                role: MessageRole::Assistant,
+                content: (!text.is_empty() || tool_calls.is_empty())
+                    .then_some(MessageContent::Text(text)),
+                tool_calls: (!tool_calls.is_empty()).then_some(tool_calls),
+                ..Default::default()
+            },
+            finish_reason: Some(finish_reason),
+            logprobs: None,
+        }],
+        usage,
+        system_fingerprint: None,
+        provider_specific_fields: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{FunctionCall, FunctionDefinition, Tool, ToolCall, ToolType};
+    use serde_json::json;
+
+    fn message(role: MessageRole, text: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    fn tool_call(id: &str, city: &str) -> ToolCall {
+        ToolCall {
+            id: id.to_string(),
+            tool_type: "function".to_string(),
+            function: FunctionCall {
+                name: "get_weather".to_string(),
+                arguments: json!({"city": city}).to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_transform_tool_calls_to_converse() {
+        let request = ChatRequest {
+            model: "anthropic.claude-3-5-sonnet-20241022-v2:0".to_string(),
+            messages: vec![
+                message(MessageRole::User, "Weather in Paris and Rome?"),
+                ChatMessage {
+                    content: None,
+                    tool_calls: Some(vec![tool_call("t1", "Paris"), tool_call("t2", "Rome")]),
+                    ..message(MessageRole::Assistant, "")
+                },
+                ChatMessage {
+                    tool_call_id: Some("t1".to_string()),
+                    ..message(MessageRole::Tool, "Sunny")
+                },
+                ChatMessage {
+                    tool_call_id: Some("t2".to_string()),
+                    ..message(MessageRole::Tool, "Rainy")
+                },
+            ],
+            tools: Some(vec![Tool {
+                tool_type: ToolType::Function,
+                function: FunctionDefinition {
+                    name: "get_weather".to_string(),
+                    description: None,
+                    parameters: Some(json!({"type": "object"})),
+                },
+            }]),
+            tool_choice: Some(crate::core::types::ToolChoice::String(
+                "required".to_string(),
+            )),
+            ..Default::default()
+        };
+
+        let body = serde_json::to_value(transform_to_converse(&request).unwrap()).unwrap();
+        assert_eq!(
+            body["messages"],
+            json!([
+                {"role": "user", "content": [{"text": "Weather in Paris and Rome?"}]},
+                {"role": "assistant", "content": [
+                    {"toolUse": {"toolUseId": "t1", "name": "get_weather", "input": {"city": "Paris"}}},
+                    {"toolUse": {"toolUseId": "t2", "name": "get_weather", "input": {"city": "Rome"}}}
+                ]},
+                {"role": "user", "content": [
+                    {"toolResult": {"toolUseId": "t1", "content": [{"text": "Sunny"}]}},
+                    {"toolResult": {"toolUseId": "t2", "content": [{"text": "Rainy"}]}}
+                ]}
+            ])
+        );
+        assert_eq!(
+            body["toolConfig"],
+            json!({
+                "tools": [{"toolSpec": {
+                    "name": "get_weather",
+                    "description": "",
+                    "inputSchema": {"json": {"type": "object"}}
+                }}],
+                "toolChoice": {"any": {}}
+            })
+        );
+
+        // Tools are left out when the model must not call them
+        let request = ChatRequest {
+            tool_choice: Some(crate::core::types::ToolChoice::String("none".to_string())),
+            ..request
+        };
+        assert!(
+            transform_to_converse(&request)
+                .unwrap()
+                .tool_config
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_transform_documents_to_converse() {
+        use crate::core::types::requests::ContentPart;
+
+        let mut request = ChatRequest {
+            model: "anthropic.claude-3-5-sonnet-20241022-v2:0".to_string(),
+            messages: vec![ChatMessage {
+                content: Some(MessageContent::Parts(vec![
+                    ContentPart::Text {
+                        text: "Compare these".to_string(),
+                        cache_control: None,
+                    },
+                    ContentPart::document_base64("application/pdf", "JVBERi0="),
+                    ContentPart::document_url("s3://bucket/report.pdf"),
+                ])),
+                ..message(MessageRole::User, "")
+            }],
+            ..Default::default()
+        };
+
+        let body = serde_json::to_value(transform_to_converse(&request).unwrap()).unwrap();
+        assert_eq!(
+            body["messages"][0]["content"],
+            json!([
+                {"text": "Compare these"},
+                {"document": {"format": "pdf", "name": "Document 1", "source": {"bytes": "JVBERi0="}}},
+                {"document": {
+                    "format": "pdf",
+                    "name": "Document 2",
+                    "source": {"s3Location": {"uri": "s3://bucket/report.pdf"}}
+                }}
+            ])
+        );
+
+        request.messages[0].content = Some(MessageContent::Parts(vec![ContentPart::document_url(
+            "https://example.com/report.pdf",
+        )]));
+        assert!(matches!(
+            transform_to_converse(&request),
+            Err(ProviderError::NotSupported { .. })
+        ));
+    }
+
+    #[test]
+    fn test_transform_converse_response() {
+        let response = transform_converse_response(
+            &json!({
+                "output": {"message": {"role": "assistant", "content": [
+                    {"text": "Checking."},
+                    {"toolUse": {"toolUseId": "t1", "name": "get_weather", "input": {"city": "Paris"}}}
+                ]}},
+                "stopReason": "tool_use",
+                "usage": {"inputTokens": 12, "outputTokens": 8, "totalTokens": 20}
+            }),
+            "anthropic.claude-3-5-sonnet-20241022-v2:0",
+        );
+
+        let choice = &response.choices[0];
+        assert_eq!(choice.finish_reason, Some(FinishReason::ToolCalls));
+        assert_eq!(
+            choice.message.content.as_ref().unwrap().to_string(),
+            "Checking."
+        );
+        let calls = choice.message.tool_calls.as_ref().unwrap();
+        assert_eq!(calls[0].id, "t1");
+        assert_eq!(calls[0].function.arguments, r#"{"city":"Paris"}"#);
+        assert_eq!(response.usage.unwrap().total_tokens, 20);
+    }
+}
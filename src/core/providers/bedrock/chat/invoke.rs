@@ -3,6 +3,7 @@
 //! Legacy API for model-specific chat completions in Bedrock
 
 use super::transformations;
+use crate::core::providers::bedrock::guardrails::{self, GuardrailConfig};
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::requests::ChatRequest;
 use serde_json::Value;
@@ -11,6 +12,7 @@ use serde_json::Value;
 pub async fn execute_invoke(
     client: &crate::core::providers::bedrock::client::BedrockClient,
     request: &ChatRequest,
+    guardrail: Option<&GuardrailConfig>,
 ) -> Result<Value, ProviderError> {
     // Get model configuration
     let model_config =
@@ -19,8 +21,15 @@ pub async fn execute_invoke(
     // Transform request based on model family
     let body = transformations::transform_for_model(request, model_config)?;
 
+    // Guardrails are attached through headers on the Invoke API
+    let headers = guardrail
+        .map(guardrails::invoke_guardrail_headers)
+        .unwrap_or_default();
+
     // Send request using the client
-    let response = client.send_request(&request.model, "invoke", &body).await?;
+    let response = client
+        .send_request_with_headers(&request.model, "invoke", &body, &headers)
+        .await?;
 
     // Parse response and return as Value
     response
@@ -6,6 +6,7 @@ pub mod converse;
 pub mod invoke;
 pub mod transformations;
 
+use super::guardrails::GuardrailConfig;
 use super::model_config::{BedrockApiType, get_model_config};
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::requests::ChatRequest;
@@ -15,15 +16,16 @@ use serde_json::Value;
 pub async fn route_chat_request(
     client: &super::client::BedrockClient,
     request: &ChatRequest,
+    guardrail: Option<&GuardrailConfig>,
 ) -> Result<Value, ProviderError> {
     let model_config = get_model_config(&request.model)?;
 
     match model_config.api_type {
         BedrockApiType::Converse | BedrockApiType::ConverseStream => {
-            converse::execute_converse(client, request).await
+            converse::execute_converse(client, request, guardrail).await
         }
         BedrockApiType::Invoke | BedrockApiType::InvokeStream => {
-            invoke::execute_invoke(client, request).await
+            invoke::execute_invoke(client, request, guardrail).await
         }
     }
 }
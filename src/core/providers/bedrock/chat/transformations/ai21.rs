@@ -31,7 +31,7 @@ fn transform_jamba_request(request: &ChatRequest) -> Result<Value, ProviderError
             Some(MessageContent::Parts(parts)) => parts
                 .iter()
                 .filter_map(|part| {
-                    if let crate::core::types::requests::ContentPart::Text { text } = part {
+                    if let crate::core::types::requests::ContentPart::Text { text, .. } = part {
                         Some(text.clone())
                     } else {
                         None
@@ -116,6 +116,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -174,6 +175,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 ChatMessage {
                     role: MessageRole::User,
@@ -183,6 +185,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
             ],
             ..Default::default()
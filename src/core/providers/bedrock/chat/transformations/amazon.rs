@@ -1,6 +1,7 @@
 //! Amazon Titan and Nova Model Transformations
 
 use crate::core::providers::bedrock::model_config::ModelConfig;
+use crate::core::providers::shared::ToolTransformer;
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::requests::ChatRequest;
 use serde_json::{Value, json};
@@ -58,8 +59,10 @@ pub fn transform_nova_request(
                         MessageContent::Parts(parts) => parts
                             .iter()
                             .filter_map(|part| {
-                                if let crate::core::types::requests::ContentPart::Text { text } =
-                                    part
+                                if let crate::core::types::requests::ContentPart::Text {
+                                    text,
+                                    ..
+                                } = part
                                 {
                                     Some(text.clone())
                                 } else {
@@ -72,7 +75,7 @@ pub fn transform_nova_request(
                 }
             }
             MessageRole::User | MessageRole::Assistant => {
-                messages.push(json!({
+                let mut message = json!({
                     "role": match msg.role {
                         MessageRole::User => "user",
                         MessageRole::Assistant => "assistant",
@@ -85,7 +88,7 @@ pub fn transform_nova_request(
                         Some(MessageContent::Parts(parts)) => {
                             parts.iter().filter_map(|part| {
                                 match part {
-                                    crate::core::types::requests::ContentPart::Text { text } => {
+                                    crate::core::types::requests::ContentPart::Text { text, .. } => {
                                         Some(json!({"text": text}))
                                     }
                                     crate::core::types::requests::ContentPart::Image { .. } => {
@@ -104,23 +107,62 @@ pub fn transform_nova_request(
                                         // TODO: Handle document content
                                         None
                                     }
-                                    crate::core::types::requests::ContentPart::ToolResult { .. } => {
-                                        // TODO: Handle tool result content
-                                        None
-                                    }
-                                    crate::core::types::requests::ContentPart::ToolUse { .. } => {
-                                        // TODO: Handle tool use content
-                                        None
-                                    }
+                                    crate::core::types::requests::ContentPart::ToolResult {
+                                        tool_use_id,
+                                        content,
+                                        ..
+                                    } => Some(json!({
+                                        "toolResult": {
+                                            "toolUseId": tool_use_id,
+                                            "content": [{"text": match content {
+                                                Value::String(text) => text.clone(),
+                                                content => content.to_string(),
+                                            }}],
+                                        }
+                                    })),
+                                    crate::core::types::requests::ContentPart::ToolUse {
+                                        id,
+                                        name,
+                                        input,
+                                    } => Some(json!({
+                                        "toolUse": {"toolUseId": id, "name": name, "input": input}
+                                    })),
                                 }
                             }).collect()
                         }
                         None => vec![],
                     }
-                }));
+                });
+                // Tool calls follow the text they come with
+                if let (Some(tool_calls), Some(content)) =
+                    (&msg.tool_calls, message["content"].as_array_mut())
+                {
+                    content.retain(|block| block.get("text") != Some(&json!("")));
+                    content.extend(tool_calls.iter().map(ToolTransformer::to_converse_tool_use));
+                }
+                messages.push(message);
             }
-            _ => {
-                // Skip function/tool messages for now
+            MessageRole::Tool => {
+                // The results of parallel calls share one user message
+                let result = ToolTransformer::to_converse_tool_result(msg);
+                let last_results = messages
+                    .last_mut()
+                    .filter(|last| {
+                        last["role"] == "user"
+                            && last["content"].as_array().is_some_and(|content| {
+                                content
+                                    .iter()
+                                    .all(|block| block.get("toolResult").is_some())
+                            })
+                    })
+                    .and_then(|last| last["content"].as_array_mut());
+                match last_results {
+                    Some(content) => content.push(result),
+                    None => messages.push(json!({"role": "user", "content": [result]})),
+                }
+            }
+            MessageRole::Function => {
+                // Legacy function results have no call ID to refer to
             }
         }
     }
@@ -135,6 +177,12 @@ pub fn transform_nova_request(
         }]);
     }
 
+    if let Some(tool_config) = request.tools.as_deref().and_then(|tools| {
+        ToolTransformer::to_converse_tool_config(tools, request.tool_choice.as_ref())
+    }) {
+        body["toolConfig"] = tool_config;
+    }
+
     // Add inference configuration
     let mut inference_config = json!({});
 
@@ -177,6 +225,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -295,6 +344,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 ChatMessage {
                     role: MessageRole::User,
@@ -304,6 +354,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
             ],
             ..Default::default()
@@ -333,4 +384,68 @@ mod tests {
         assert_eq!(value["inferenceConfig"]["temperature"], 0.5);
         assert_eq!(value["inferenceConfig"]["topP"], 0.5);
     }
+
+    #[test]
+    fn test_transform_nova_request_with_tools() {
+        use crate::core::types::{FunctionCall, FunctionDefinition, Tool, ToolCall, ToolType};
+
+        let request = ChatRequest {
+            model: "amazon.nova-pro-v1".to_string(),
+            messages: vec![
+                ChatMessage {
+                    role: MessageRole::Assistant,
+                    content: None,
+                    tool_calls: Some(vec![ToolCall {
+                        id: "t1".to_string(),
+                        tool_type: "function".to_string(),
+                        function: FunctionCall {
+                            name: "get_weather".to_string(),
+                            arguments: json!({"city": "Paris"}).to_string(),
+                        },
+                    }]),
+                    ..Default::default()
+                },
+                ChatMessage {
+                    role: MessageRole::Tool,
+                    content: Some(MessageContent::Text("Sunny".to_string())),
+                    tool_call_id: Some("t1".to_string()),
+                    ..Default::default()
+                },
+            ],
+            tools: Some(vec![Tool {
+                tool_type: ToolType::Function,
+                function: FunctionDefinition {
+                    name: "get_weather".to_string(),
+                    description: Some("Current weather".to_string()),
+                    parameters: None,
+                },
+            }]),
+            tool_choice: Some(crate::core::types::ToolChoice::String("auto".to_string())),
+            ..Default::default()
+        };
+
+        let value = transform_nova_request(&request, &create_nova_model_config()).unwrap();
+        assert_eq!(
+            value["messages"],
+            json!([
+                {"role": "assistant", "content": [
+                    {"toolUse": {"toolUseId": "t1", "name": "get_weather", "input": {"city": "Paris"}}}
+                ]},
+                {"role": "user", "content": [
+                    {"toolResult": {"toolUseId": "t1", "content": [{"text": "Sunny"}]}}
+                ]}
+            ])
+        );
+        assert_eq!(
+            value["toolConfig"],
+            json!({
+                "tools": [{"toolSpec": {
+                    "name": "get_weather",
+                    "description": "Current weather",
+                    "inputSchema": {"json": {}}
+                }}],
+                "toolChoice": {"auto": {}}
+            })
+        );
+    }
 }
@@ -50,7 +50,7 @@ fn extract_system_message(request: &ChatRequest) -> Option<String> {
             MessageContent::Parts(parts) => parts
                 .iter()
                 .filter_map(|part| {
-                    if let crate::core::types::requests::ContentPart::Text { text } = part {
+                    if let crate::core::types::requests::ContentPart::Text { text, .. } = part {
                         Some(text.clone())
                     } else {
                         None
@@ -78,6 +78,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -171,6 +172,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 ChatMessage {
                     role: MessageRole::User,
@@ -180,6 +182,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
             ],
             ..Default::default()
@@ -204,6 +207,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         };
@@ -233,6 +237,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         };
@@ -33,7 +33,7 @@ fn transform_command_r_request(request: &ChatRequest) -> Result<Value, ProviderE
             Some(MessageContent::Parts(parts)) => parts
                 .iter()
                 .filter_map(|part| {
-                    if let crate::core::types::requests::ContentPart::Text { text } = part {
+                    if let crate::core::types::requests::ContentPart::Text { text, .. } = part {
                         Some(text.clone())
                     } else {
                         None
@@ -146,6 +146,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -204,6 +205,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 ChatMessage {
                     role: MessageRole::User,
@@ -213,6 +215,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
             ],
             ..Default::default()
@@ -238,6 +241,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 ChatMessage {
                     role: MessageRole::Assistant,
@@ -247,6 +251,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 ChatMessage {
                     role: MessageRole::User,
@@ -256,6 +261,7 @@ mod tests {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
             ],
             ..Default::default()
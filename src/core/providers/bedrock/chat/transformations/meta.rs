@@ -74,7 +74,7 @@ fn format_llama2_prompt(messages: &[crate::core::types::ChatMessage]) -> String
             Some(MessageContent::Parts(parts)) => parts
                 .iter()
                 .filter_map(|part| {
-                    if let crate::core::types::requests::ContentPart::Text { text } = part {
+                    if let crate::core::types::requests::ContentPart::Text { text, .. } = part {
                         Some(text.clone())
                     } else {
                         None
@@ -127,6 +127,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -240,6 +241,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }];
 
         let prompt = format_llama2_prompt(&messages);
@@ -258,6 +260,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::User,
@@ -267,6 +270,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
         ];
 
@@ -288,6 +292,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::Assistant,
@@ -297,6 +302,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::User,
@@ -306,6 +312,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
         ];
 
@@ -325,6 +332,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }];
 
         let prompt = format_llama2_prompt(&messages);
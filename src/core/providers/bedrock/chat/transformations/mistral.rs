@@ -45,7 +45,7 @@ fn format_mistral_prompt(messages: &[crate::core::types::ChatMessage]) -> String
             Some(MessageContent::Parts(parts)) => parts
                 .iter()
                 .filter_map(|part| {
-                    if let crate::core::types::requests::ContentPart::Text { text } = part {
+                    if let crate::core::types::requests::ContentPart::Text { text, .. } = part {
                         Some(text.clone())
                     } else {
                         None
@@ -100,6 +100,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         }
@@ -189,6 +190,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }];
 
         let prompt = format_mistral_prompt(&messages);
@@ -206,6 +208,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::User,
@@ -215,6 +218,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
         ];
 
@@ -235,6 +239,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::Assistant,
@@ -244,6 +249,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::User,
@@ -253,6 +259,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
         ];
 
@@ -272,6 +279,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }];
 
         let prompt = format_mistral_prompt(&messages);
@@ -55,7 +55,7 @@ pub fn messages_to_prompt(messages: &[crate::core::types::ChatMessage]) -> Strin
                 parts
                     .iter()
                     .filter_map(|part| {
-                        if let crate::core::types::requests::ContentPart::Text { text } = part {
+                        if let crate::core::types::requests::ContentPart::Text { text, .. } = part {
                             Some(text.clone())
                         } else {
                             None
@@ -96,6 +96,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }
     }
 
@@ -108,6 +109,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }
     }
 
@@ -120,6 +122,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }
     }
 
@@ -165,6 +168,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: Some("call_123".to_string()),
+            cache_control: None,
         }];
         let prompt = messages_to_prompt(&messages);
         assert!(prompt.contains("Tool: Tool result"));
@@ -180,6 +184,7 @@ mod tests {
             function_call: None,
             tool_calls: None,
             tool_call_id: None,
+            cache_control: None,
         }];
         let prompt = messages_to_prompt(&messages);
         // Empty content messages should be skipped
@@ -6,12 +6,17 @@
 use reqwest::{Client, Response};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, error};
 
 use super::config::BedrockConfig;
 use super::error::{BedrockError, BedrockErrorMapper};
-use super::sigv4::SigV4Signer;
-use super::utils::{AwsAuth, validate_region};
+use super::guardrails::GuardrailConfig;
+use super::sigv4::{SigV4Signer, uri_encode};
+use super::utils::{
+    AwsAuth, AwsCredentialChain, CredentialSource, ResolvedCredentials, resolve_inference_profile,
+    validate_region,
+};
 use crate::core::providers::base_provider::{BaseHttpClient, BaseProviderConfig};
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::traits::error_mapper::trait_def::ErrorMapper;
@@ -20,8 +25,10 @@ use crate::core::traits::error_mapper::trait_def::ErrorMapper;
 #[derive(Debug, Clone)]
 pub struct BedrockClient {
     base_client: BaseHttpClient,
-    auth: AwsAuth,
-    signer: SigV4Signer,
+    region: String,
+    credentials: Arc<AwsCredentialChain>,
+    guardrail: Option<GuardrailConfig>,
+    cross_region_inference: bool,
     error_mapper: BedrockErrorMapper,
 }
 
@@ -45,29 +52,39 @@ impl BedrockClient {
         let base_client = BaseHttpClient::new(base_config)
             .map_err(|e| ProviderError::configuration("bedrock", e.to_string()))?;
 
-        // Create AWS auth
-        let auth = AwsAuth::new(
-            config.aws_access_key_id.clone(),
-            config.aws_secret_access_key.clone(),
-            config.aws_session_token.clone(),
-            config.aws_region.clone(),
-        );
-
-        // Validate auth
-        auth.validate()?;
+        // Explicit keys are validated up front; otherwise the credential
+        // chain resolves them on the first request
+        let static_credentials = if config.has_static_credentials() {
+            AwsAuth::new(
+                config.aws_access_key_id.clone(),
+                config.aws_secret_access_key.clone(),
+                config.aws_session_token.clone(),
+                config.aws_region.clone(),
+            )
+            .validate()?;
+
+            Some(ResolvedCredentials::new(
+                config.aws_access_key_id,
+                config.aws_secret_access_key,
+                config.aws_session_token,
+                CredentialSource::Static,
+            ))
+        } else {
+            None
+        };
 
-        // Create SigV4 signer
-        let signer = SigV4Signer::new(
-            config.aws_access_key_id,
-            config.aws_secret_access_key,
-            config.aws_session_token,
-            config.aws_region,
+        let credentials = AwsCredentialChain::new(
+            config.aws_region.clone(),
+            static_credentials,
+            config.aws_profile_name,
         );
 
         Ok(Self {
             base_client,
-            auth,
-            signer,
+            region: config.aws_region,
+            credentials: Arc::new(credentials),
+            guardrail: config.guardrail_config,
+            cross_region_inference: config.cross_region_inference,
             error_mapper: BedrockErrorMapper,
         })
     }
@@ -77,14 +94,40 @@ impl BedrockClient {
         self.base_client.inner()
     }
 
-    /// Get AWS auth reference
-    pub fn auth(&self) -> &AwsAuth {
-        &self.auth
+    /// AWS region requests are sent to
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Credential chain used to sign requests
+    pub fn credential_chain(&self) -> &AwsCredentialChain {
+        &self.credentials
+    }
+
+    /// Guardrail applied to chat requests that don't specify one
+    pub fn default_guardrail(&self) -> Option<&GuardrailConfig> {
+        self.guardrail.as_ref()
+    }
+
+    /// Model id requests for `model_id` are sent to
+    ///
+    /// With cross-region inference enabled, models served through inference
+    /// profiles get the geography prefix of the client's region.
+    pub fn resolve_model_id(&self, model_id: &str) -> String {
+        if self.cross_region_inference {
+            resolve_inference_profile(model_id, &self.region)
+        } else {
+            model_id.to_string()
+        }
     }
 
     /// Build Bedrock API URL for a model and operation
+    ///
+    /// The model id is percent-encoded, since versioned ids contain `:` and
+    /// inference profile ARNs contain `/`.
     pub fn build_url(&self, model_id: &str, operation: &str) -> String {
-        let region = &self.auth.credentials().region;
+        let region = &self.region;
+        let model_id = uri_encode(&self.resolve_model_id(model_id));
 
         // Different URL patterns for different operations
         match operation {
@@ -125,18 +168,27 @@ impl BedrockClient {
     }
 
     /// Create signed headers for AWS SigV4
+    ///
+    /// `extra_headers` are sent with the request and covered by the signature.
     pub async fn create_signed_headers(
         &self,
         url: &str,
         body: &str,
         method: &str,
+        extra_headers: &HashMap<String, String>,
     ) -> Result<reqwest::header::HeaderMap, BedrockError> {
         let timestamp = chrono::Utc::now();
-        let headers = HashMap::new(); // Start with empty headers
 
-        let signed_headers = self
-            .signer
-            .sign_request(method, url, &headers, body, timestamp)
+        let credentials = self.credentials.credentials().await?;
+        let signer = SigV4Signer::new(
+            credentials.access_key_id,
+            credentials.secret_access_key,
+            credentials.session_token,
+            self.region.clone(),
+        );
+
+        let signed_headers = signer
+            .sign_request(method, url, extra_headers, body, timestamp)
             .map_err(|e| {
                 ProviderError::configuration("bedrock", format!("Signing failed: {}", e))
             })?;
@@ -161,6 +213,18 @@ impl BedrockClient {
         model_id: &str,
         operation: &str,
         body: &Value,
+    ) -> Result<Response, BedrockError> {
+        self.send_request_with_headers(model_id, operation, body, &HashMap::new())
+            .await
+    }
+
+    /// Send a request to Bedrock API with additional headers
+    pub async fn send_request_with_headers(
+        &self,
+        model_id: &str,
+        operation: &str,
+        body: &Value,
+        extra_headers: &HashMap<String, String>,
     ) -> Result<Response, BedrockError> {
         let url = self.build_url(model_id, operation);
         let body_str = serde_json::to_string(body)
@@ -170,7 +234,9 @@ impl BedrockClient {
         debug!("Request body: {}", body_str);
 
         // Create signed headers
-        let headers = self.create_signed_headers(&url, &body_str, "POST").await?;
+        let headers = self
+            .create_signed_headers(&url, &body_str, "POST", extra_headers)
+            .await?;
 
         // Send request
         let response = self
@@ -202,6 +268,7 @@ impl BedrockClient {
         model_id: &str,
         operation: &str,
         body: &Value,
+        extra_headers: &HashMap<String, String>,
     ) -> Result<Response, BedrockError> {
         let url = self.build_url(model_id, operation);
         let body_str = serde_json::to_string(body)
@@ -210,7 +277,9 @@ impl BedrockClient {
         debug!("Bedrock streaming request to {}", url);
 
         // Create signed headers
-        let headers = self.create_signed_headers(&url, &body_str, "POST").await?;
+        let headers = self
+            .create_signed_headers(&url, &body_str, "POST", extra_headers)
+            .await?;
 
         // Send streaming request
         let response = self
@@ -244,7 +313,9 @@ impl BedrockClient {
         debug!("Bedrock GET request to {}", url);
 
         // Create signed headers
-        let headers = self.create_signed_headers(&url, body, "GET").await?;
+        let headers = self
+            .create_signed_headers(&url, body, "GET", &HashMap::new())
+            .await?;
 
         // Send GET request
         let response = self
@@ -288,9 +359,12 @@ mod tests {
             aws_access_key_id: "AKIATEST123456789012".to_string(),
             aws_secret_access_key: "test-secret-key".to_string(),
             aws_session_token: None,
+            aws_profile_name: None,
             aws_region: "us-east-1".to_string(),
             timeout_seconds: 30,
             max_retries: 3,
+            guardrail_config: None,
+            cross_region_inference: false,
         };
 
         let client = BedrockClient::new(config).unwrap();
@@ -318,6 +392,53 @@ mod tests {
             url,
             "https://bedrock-runtime.us-east-1.amazonaws.com/model/anthropic.claude-3-sonnet-20240229/converse"
         );
+
+        // Versioned model ids are encoded
+        let url = client.build_url("anthropic.claude-3-5-sonnet-20240620-v1:0", "converse");
+        assert_eq!(
+            url,
+            "https://bedrock-runtime.us-east-1.amazonaws.com/model/anthropic.claude-3-5-sonnet-20240620-v1%3A0/converse"
+        );
+    }
+
+    #[test]
+    fn test_cross_region_url_building() {
+        let config = BedrockConfig {
+            aws_access_key_id: "AKIATEST123456789012".to_string(),
+            aws_secret_access_key: "test-secret-key".to_string(),
+            aws_region: "eu-central-1".to_string(),
+            ..Default::default()
+        };
+
+        let client = BedrockClient::new(config).unwrap();
+
+        let url = client.build_url("anthropic.claude-3-5-sonnet-20240620-v1:0", "converse");
+        assert_eq!(
+            url,
+            "https://bedrock-runtime.eu-central-1.amazonaws.com/model/eu.anthropic.claude-3-5-sonnet-20240620-v1%3A0/converse"
+        );
+
+        // Explicit profiles and ARNs are sent as given
+        let url = client.build_url("us.anthropic.claude-3-haiku-20240307-v1:0", "converse");
+        assert_eq!(
+            url,
+            "https://bedrock-runtime.eu-central-1.amazonaws.com/model/us.anthropic.claude-3-haiku-20240307-v1%3A0/converse"
+        );
+        let url = client.build_url(
+            "arn:aws:bedrock:eu-central-1:123456789012:application-inference-profile/a1b2c3",
+            "converse",
+        );
+        assert_eq!(
+            url,
+            "https://bedrock-runtime.eu-central-1.amazonaws.com/model/arn%3Aaws%3Abedrock%3Aeu-central-1%3A123456789012%3Aapplication-inference-profile%2Fa1b2c3/converse"
+        );
+
+        // Models without inference profiles keep their id
+        let url = client.build_url("amazon.titan-embed-text-v2:0", "invoke");
+        assert_eq!(
+            url,
+            "https://bedrock-runtime.eu-central-1.amazonaws.com/model/amazon.titan-embed-text-v2%3A0/invoke"
+        );
     }
 
     #[tokio::test]
@@ -326,17 +447,21 @@ mod tests {
             aws_access_key_id: "AKIATEST123456789012".to_string(),
             aws_secret_access_key: "test-secret-key".to_string(),
             aws_session_token: None,
+            aws_profile_name: None,
             aws_region: "us-east-1".to_string(),
             timeout_seconds: 30,
             max_retries: 3,
+            guardrail_config: None,
+            cross_region_inference: true,
         };
 
         let client = BedrockClient::new(config);
         assert!(client.is_ok());
 
         let client = client.unwrap();
-        assert_eq!(client.auth().credentials().region, "us-east-1");
-        assert!(!client.auth().is_temporary_credentials());
+        assert_eq!(client.region(), "us-east-1");
+        let credentials = client.credential_chain().credentials().await.unwrap();
+        assert_eq!(credentials.source, CredentialSource::Static);
     }
 
     #[test]
@@ -345,12 +470,33 @@ mod tests {
             aws_access_key_id: "AKIATEST123456789012".to_string(),
             aws_secret_access_key: "test-secret-key".to_string(),
             aws_session_token: None,
+            aws_profile_name: None,
             aws_region: "invalid-region".to_string(),
             timeout_seconds: 30,
             max_retries: 3,
+            guardrail_config: None,
+            cross_region_inference: true,
         };
 
         let client = BedrockClient::new(config);
         assert!(client.is_err());
     }
+
+    #[test]
+    fn test_client_without_static_credentials() {
+        let config = BedrockConfig {
+            aws_access_key_id: String::new(),
+            aws_secret_access_key: String::new(),
+            aws_session_token: None,
+            aws_profile_name: Some("bedrock".to_string()),
+            aws_region: "us-west-2".to_string(),
+            timeout_seconds: 30,
+            max_retries: 3,
+            guardrail_config: None,
+            cross_region_inference: true,
+        };
+
+        let client = BedrockClient::new(config).unwrap();
+        assert_eq!(client.region(), "us-west-2");
+    }
 }
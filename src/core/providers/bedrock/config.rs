@@ -3,24 +3,46 @@
 //! Configuration management for AWS Bedrock provider including
 //! AWS credentials, regions, and model-specific settings.
 
+use super::guardrails::GuardrailConfig;
 use crate::core::traits::ProviderConfig;
 use serde::{Deserialize, Serialize};
 
 /// AWS Bedrock provider configuration
+///
+/// When the access key pair is left empty, credentials come from the AWS
+/// credential chain (environment, shared profile, web identity, container or
+/// instance metadata).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BedrockConfig {
     /// AWS access key ID
+    #[serde(default)]
     pub aws_access_key_id: String,
     /// AWS secret access key
+    #[serde(default)]
     pub aws_secret_access_key: String,
     /// AWS session token (optional, for temporary credentials)
+    #[serde(default)]
     pub aws_session_token: Option<String>,
+    /// Shared config profile to resolve credentials from
+    #[serde(default)]
+    pub aws_profile_name: Option<String>,
     /// AWS region
     pub aws_region: String,
     /// Request timeout in seconds
     pub timeout_seconds: u64,
     /// Maximum retries for failed requests
     pub max_retries: u32,
+    /// Guardrail applied to chat requests that don't specify their own
+    #[serde(default)]
+    pub guardrail_config: Option<GuardrailConfig>,
+    /// Send plain model ids of supported models through the cross-region
+    /// inference profile of the configured region's geography
+    #[serde(default = "default_cross_region_inference")]
+    pub cross_region_inference: bool,
+}
+
+fn default_cross_region_inference() -> bool {
+    true
 }
 
 impl Default for BedrockConfig {
@@ -29,19 +51,30 @@ impl Default for BedrockConfig {
             aws_access_key_id: String::new(),
             aws_secret_access_key: String::new(),
             aws_session_token: None,
+            aws_profile_name: None,
             aws_region: "us-east-1".to_string(),
             timeout_seconds: 30,
             max_retries: 3,
+            guardrail_config: None,
+            cross_region_inference: true,
         }
     }
 }
 
+impl BedrockConfig {
+    /// Whether explicit keys are configured
+    pub fn has_static_credentials(&self) -> bool {
+        !self.aws_access_key_id.is_empty() && !self.aws_secret_access_key.is_empty()
+    }
+}
+
 impl ProviderConfig for BedrockConfig {
     fn validate(&self) -> Result<(), String> {
-        if self.aws_access_key_id.is_empty() {
+        // Both keys empty means the credential chain is used
+        if self.aws_access_key_id.is_empty() && !self.aws_secret_access_key.is_empty() {
             return Err("AWS access key ID is required".to_string());
         }
-        if self.aws_secret_access_key.is_empty() {
+        if !self.aws_access_key_id.is_empty() && self.aws_secret_access_key.is_empty() {
             return Err("AWS secret access key is required".to_string());
         }
         if self.aws_region.is_empty() {
@@ -53,6 +86,11 @@ impl ProviderConfig for BedrockConfig {
         if self.max_retries > 10 {
             return Err("Max retries should not exceed 10".to_string());
         }
+        if let Some(guardrail) = &self.guardrail_config {
+            if guardrail.guardrail_id.is_empty() || guardrail.guardrail_version.is_empty() {
+                return Err("Guardrail identifier and version are required".to_string());
+            }
+        }
         Ok(())
     }
 
@@ -94,13 +132,18 @@ mod tests {
     #[test]
     fn test_config_validation() {
         let mut config = BedrockConfig::default();
-        assert!(config.validate().is_err()); // No credentials
+        assert!(config.validate().is_ok()); // Credential chain
+        assert!(!config.has_static_credentials());
 
         config.aws_access_key_id = "test_key".to_string();
         assert!(config.validate().is_err()); // No secret
 
         config.aws_secret_access_key = "test_secret".to_string();
         assert!(config.validate().is_ok());
+        assert!(config.has_static_credentials());
+
+        config.aws_access_key_id = String::new();
+        assert!(config.validate().is_err()); // No key ID
     }
 
     #[test]
@@ -2,16 +2,35 @@
 //!
 //! Handles text and multimodal embeddings for Titan and Cohere models
 
+use crate::core::providers::bedrock::client::BedrockClient;
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::requests::EmbeddingRequest;
 use crate::core::types::responses::{EmbeddingData, EmbeddingResponse, Usage};
 use serde::{Deserialize, Serialize};
 
+/// Response header carrying the number of input tokens
+const INPUT_TOKEN_COUNT_HEADER: &str = "x-amzn-bedrock-input-token-count";
+
+/// Output sizes accepted by Titan Text Embeddings V2
+const TITAN_V2_DIMENSIONS: &[u32] = &[256, 512, 1024];
+
+/// Output sizes accepted by Titan Multimodal Embeddings
+const TITAN_MULTIMODAL_DIMENSIONS: &[u32] = &[256, 384, 1024];
+
+/// Output sizes accepted by Cohere Embed v4
+const COHERE_V4_DIMENSIONS: &[u32] = &[256, 512, 1024, 1536];
+
 /// Titan embedding request
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TitanEmbeddingRequest {
     pub input_text: String,
+    /// Output size, Titan Text Embeddings V2 only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<u32>,
+    /// Whether to return unit vectors, Titan Text Embeddings V2 only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalize: Option<bool>,
 }
 
 /// Titan embedding response
@@ -49,26 +68,49 @@ pub struct CohereEmbeddingRequest {
     pub input_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub truncate: Option<String>,
+    /// Output size, Cohere Embed v4 only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_dimension: Option<u32>,
 }
 
 /// Cohere embedding response
 #[derive(Debug, Deserialize)]
 pub struct CohereEmbeddingResponse {
-    pub embeddings: Vec<Vec<f32>>,
+    pub embeddings: CohereEmbeddings,
+    #[serde(default)]
     pub id: String,
+    #[serde(default)]
     pub response_type: String,
+    #[serde(default)]
     pub texts: Vec<String>,
 }
 
+/// Cohere embeddings, either a plain list or keyed by embedding type
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CohereEmbeddings {
+    Floats(Vec<Vec<f32>>),
+    ByType { float: Vec<Vec<f32>> },
+}
+
+impl CohereEmbeddings {
+    pub fn into_floats(self) -> Vec<Vec<f32>> {
+        match self {
+            Self::Floats(embeddings) => embeddings,
+            Self::ByType { float } => float,
+        }
+    }
+}
+
 /// Execute embedding request
 pub async fn execute_embedding(
-    client: &crate::core::providers::bedrock::client::BedrockClient,
+    client: &BedrockClient,
     request: &EmbeddingRequest,
 ) -> Result<EmbeddingResponse, ProviderError> {
     let model = &request.model;
 
     if model.contains("titan-embed") {
-        if model.contains("multimodal") {
+        if model.contains("multimodal") || model.contains("image") {
             execute_titan_multimodal_embedding(client, request).await
         } else {
             execute_titan_embedding(client, request).await
@@ -83,81 +125,237 @@ pub async fn execute_embedding(
     }
 }
 
-/// Execute Titan text embedding
-async fn execute_titan_embedding(
-    client: &crate::core::providers::bedrock::client::BedrockClient,
+/// Input texts of a request, which must not be empty
+fn input_texts(request: &EmbeddingRequest) -> Result<Vec<String>, ProviderError> {
+    let texts: Vec<String> = request.input.iter().cloned().collect();
+    if texts.is_empty() {
+        return Err(ProviderError::invalid_request(
+            "bedrock",
+            "No input text provided",
+        ));
+    }
+    Ok(texts)
+}
+
+/// The `normalize` parameter, if given
+fn normalize_param(request: &EmbeddingRequest) -> Result<Option<bool>, ProviderError> {
+    match request.extra_params.get("normalize") {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Bool(normalize)) => Ok(Some(*normalize)),
+        Some(other) => Err(ProviderError::invalid_request(
+            "bedrock",
+            format!("normalize must be a boolean, got {}", other),
+        )),
+    }
+}
+
+fn check_dimensions(model: &str, dimensions: u32, allowed: &[u32]) -> Result<(), ProviderError> {
+    if allowed.contains(&dimensions) {
+        Ok(())
+    } else {
+        Err(ProviderError::invalid_request(
+            "bedrock",
+            format!(
+                "{} does not support {} dimensions; supported: {:?}",
+                model, dimensions, allowed
+            ),
+        ))
+    }
+}
+
+/// Build a Titan text embedding request for one input
+pub fn titan_request(
     request: &EmbeddingRequest,
-) -> Result<EmbeddingResponse, ProviderError> {
-    // Titan only supports single text input
-    let input_text = match &request.input {
-        crate::core::types::requests::EmbeddingInput::Text(text) => text.clone(),
-        crate::core::types::requests::EmbeddingInput::Array(texts) => {
-            if texts.is_empty() {
-                return Err(ProviderError::invalid_request(
-                    "bedrock",
-                    "No input text provided",
-                ));
-            }
-            texts[0].clone()
+    input_text: String,
+) -> Result<TitanEmbeddingRequest, ProviderError> {
+    let normalize = normalize_param(request)?;
+
+    // Only V2 takes output options; V1 always returns 1536 dimensions
+    if !request.model.contains("titan-embed-text-v2") {
+        if request.dimensions.is_some() || normalize.is_some() {
+            return Err(ProviderError::invalid_request(
+                "bedrock",
+                format!(
+                    "{} does not support the dimensions or normalize parameters",
+                    request.model
+                ),
+            ));
         }
-    };
+        return Ok(TitanEmbeddingRequest {
+            input_text,
+            dimensions: None,
+            normalize: None,
+        });
+    }
 
-    let titan_request = TitanEmbeddingRequest { input_text };
-    let body = serde_json::to_value(titan_request)?;
+    if let Some(dimensions) = request.dimensions {
+        check_dimensions(&request.model, dimensions, TITAN_V2_DIMENSIONS)?;
+    }
 
-    let response = client.send_request(&request.model, "invoke", &body).await?;
-    let titan_response: TitanEmbeddingResponse = response
-        .json()
-        .await
-        .map_err(|e| ProviderError::response_parsing("bedrock", e.to_string()))?;
+    Ok(TitanEmbeddingRequest {
+        input_text,
+        dimensions: request.dimensions,
+        normalize,
+    })
+}
+
+/// Build a Cohere embedding request
+pub fn cohere_request(request: &EmbeddingRequest) -> Result<CohereEmbeddingRequest, ProviderError> {
+    let texts = input_texts(request)?;
+
+    let input_type = request
+        .extra_params
+        .get("input_type")
+        .and_then(|v| v.as_str())
+        .or(request.task_type.as_deref())
+        .map(cohere_input_type)
+        .unwrap_or_else(|| "search_document".to_string());
 
-    // Convert to OpenAI format
-    let embedding_data = EmbeddingData {
-        index: 0,
-        embedding: titan_response.embedding,
-        object: "embedding".to_string(),
+    let truncate = request
+        .extra_params
+        .get("truncate")
+        .and_then(|v| v.as_str())
+        .map(str::to_uppercase)
+        .unwrap_or_else(|| "END".to_string());
+
+    let output_dimension = match request.dimensions {
+        Some(dimensions) if request.model.contains("embed-v4") => {
+            check_dimensions(&request.model, dimensions, COHERE_V4_DIMENSIONS)?;
+            Some(dimensions)
+        }
+        Some(_) => {
+            return Err(ProviderError::invalid_request(
+                "bedrock",
+                format!(
+                    "{} does not support the dimensions parameter",
+                    request.model
+                ),
+            ));
+        }
+        None => None,
     };
 
-    let usage = titan_response.input_text_token_count.map(|tokens| Usage {
+    Ok(CohereEmbeddingRequest {
+        texts,
+        input_type: Some(input_type),
+        truncate: Some(truncate),
+        output_dimension,
+    })
+}
+
+/// Map task types used by other providers onto Cohere input types
+fn cohere_input_type(task_type: &str) -> String {
+    match task_type.to_ascii_lowercase().as_str() {
+        "retrieval_query" | "search_query" | "query" => "search_query".to_string(),
+        "retrieval_document" | "search_document" | "document" => "search_document".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Scale a vector to unit length
+fn l2_normalize(embedding: &mut [f32]) {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in embedding.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
+fn input_token_count(response: &reqwest::Response) -> Option<u32> {
+    response
+        .headers()
+        .get(INPUT_TOKEN_COUNT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
+
+/// Build an OpenAI-style response from embeddings in input order
+fn embedding_response(
+    model: &str,
+    embeddings: Vec<Vec<f32>>,
+    input_tokens: Option<u32>,
+) -> EmbeddingResponse {
+    let data: Vec<EmbeddingData> = embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(index, embedding)| EmbeddingData {
+            index: index as u32,
+            embedding,
+            object: "embedding".to_string(),
+        })
+        .collect();
+
+    let usage = input_tokens.map(|tokens| Usage {
         prompt_tokens: tokens,
         completion_tokens: 0,
         total_tokens: tokens,
         prompt_tokens_details: None,
         completion_tokens_details: None,
         thinking_usage: None,
+        cache_creation_input_tokens: None,
+        cache_read_input_tokens: None,
+        billable_characters: None,
     });
 
-    Ok(EmbeddingResponse {
+    EmbeddingResponse {
         object: "list".to_string(),
-        data: vec![embedding_data.clone()],
-        model: request.model.clone(),
+        data: data.clone(),
+        model: model.to_string(),
         usage,
-        embeddings: Some(vec![embedding_data]),
-    })
+        embeddings: Some(data),
+    }
+}
+
+/// Execute Titan text embedding
+///
+/// Titan embeds one text per call, so array inputs are sent concurrently.
+async fn execute_titan_embedding(
+    client: &BedrockClient,
+    request: &EmbeddingRequest,
+) -> Result<EmbeddingResponse, ProviderError> {
+    let bodies = input_texts(request)?
+        .into_iter()
+        .map(|text| {
+            titan_request(request, text)
+                .and_then(|body| serde_json::to_value(body).map_err(ProviderError::from))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let responses = futures::future::try_join_all(bodies.iter().map(|body| async move {
+        let response = client.send_request(&request.model, "invoke", body).await?;
+        response
+            .json::<TitanEmbeddingResponse>()
+            .await
+            .map_err(|e| ProviderError::response_parsing("bedrock", e.to_string()))
+    }))
+    .await?;
+
+    let input_tokens = responses
+        .iter()
+        .map(|r| r.input_text_token_count)
+        .sum::<Option<u32>>();
+    let embeddings = responses.into_iter().map(|r| r.embedding).collect();
+
+    Ok(embedding_response(&request.model, embeddings, input_tokens))
 }
 
 /// Execute Titan multimodal embedding
 async fn execute_titan_multimodal_embedding(
-    client: &crate::core::providers::bedrock::client::BedrockClient,
+    client: &BedrockClient,
     request: &EmbeddingRequest,
 ) -> Result<EmbeddingResponse, ProviderError> {
     // Extract text input
-    let input_text = match &request.input {
-        crate::core::types::requests::EmbeddingInput::Text(text) => Some(text.clone()),
-        crate::core::types::requests::EmbeddingInput::Array(texts) => {
-            if !texts.is_empty() {
-                Some(texts[0].clone())
-            } else {
-                None
-            }
-        }
-    };
+    let input_text = request.input.iter().next().cloned();
+
+    let dimensions = request.dimensions.unwrap_or(1024);
+    check_dimensions(&request.model, dimensions, TITAN_MULTIMODAL_DIMENSIONS)?;
 
     let titan_request = TitanMultimodalEmbeddingRequest {
         input_text,
         input_image: None, // TODO: Support image input
         embedding_config: Some(EmbeddingConfig {
-            output_embedding_length: request.dimensions.unwrap_or(1024),
+            output_embedding_length: dimensions,
         }),
     };
 
@@ -168,72 +366,185 @@ async fn execute_titan_multimodal_embedding(
         .await
         .map_err(|e| ProviderError::response_parsing("bedrock", e.to_string()))?;
 
-    // Convert to OpenAI format
-    let embedding_data = EmbeddingData {
-        index: 0,
-        embedding: titan_response.embedding,
-        object: "embedding".to_string(),
-    };
-
-    let usage = titan_response.input_text_token_count.map(|tokens| Usage {
-        prompt_tokens: tokens,
-        completion_tokens: 0,
-        total_tokens: tokens,
-        prompt_tokens_details: None,
-        completion_tokens_details: None,
-        thinking_usage: None,
-    });
-
-    Ok(EmbeddingResponse {
-        object: "list".to_string(),
-        data: vec![embedding_data.clone()],
-        model: request.model.clone(),
-        usage,
-        embeddings: Some(vec![embedding_data]),
-    })
+    Ok(embedding_response(
+        &request.model,
+        vec![titan_response.embedding],
+        titan_response.input_text_token_count,
+    ))
 }
 
 /// Execute Cohere embedding
 async fn execute_cohere_embedding(
-    client: &crate::core::providers::bedrock::client::BedrockClient,
+    client: &BedrockClient,
     request: &EmbeddingRequest,
 ) -> Result<EmbeddingResponse, ProviderError> {
-    // Convert input to text array
-    let texts = match &request.input {
-        crate::core::types::requests::EmbeddingInput::Text(text) => vec![text.clone()],
-        crate::core::types::requests::EmbeddingInput::Array(texts) => texts.clone(),
-    };
-
-    let cohere_request = CohereEmbeddingRequest {
-        texts,
-        input_type: Some("search_document".to_string()),
-        truncate: Some("END".to_string()),
-    };
+    let cohere_request = cohere_request(request)?;
+    // Cohere has no normalize option, so it is applied here
+    let normalize = normalize_param(request)?.unwrap_or(false);
 
     let body = serde_json::to_value(cohere_request)?;
     let response = client.send_request(&request.model, "invoke", &body).await?;
+    let input_tokens = input_token_count(&response);
     let cohere_response: CohereEmbeddingResponse = response
         .json()
         .await
         .map_err(|e| ProviderError::response_parsing("bedrock", e.to_string()))?;
 
-    // Convert to OpenAI format
-    let data: Vec<EmbeddingData> = cohere_response
-        .embeddings
-        .into_iter()
-        .enumerate()
-        .map(|(index, embedding)| EmbeddingData {
-            index: index as u32,
-            embedding,
-            object: "embedding".to_string(),
-        })
-        .collect();
+    let mut embeddings = cohere_response.embeddings.into_floats();
+    if normalize {
+        embeddings.iter_mut().for_each(|e| l2_normalize(e));
+    }
 
-    Ok(EmbeddingResponse {
-        object: "list".to_string(),
-        data: data.clone(),
-        model: request.model.clone(),
-        usage: None, // Cohere doesn't provide usage info
-        embeddings: Some(data),
-    })
+    Ok(embedding_response(&request.model, embeddings, input_tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::EmbeddingInput;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn embedding_request(model: &str, input: EmbeddingInput) -> EmbeddingRequest {
+        EmbeddingRequest {
+            model: model.to_string(),
+            input,
+            user: None,
+            encoding_format: None,
+            dimensions: None,
+            task_type: None,
+            extra_params: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_titan_v2_request() {
+        let mut request = embedding_request(
+            "amazon.titan-embed-text-v2:0",
+            EmbeddingInput::Text("hello".to_string()),
+        );
+        request.dimensions = Some(512);
+        request
+            .extra_params
+            .insert("normalize".to_string(), json!(false));
+
+        let body =
+            serde_json::to_value(titan_request(&request, "hello".to_string()).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({"inputText": "hello", "dimensions": 512, "normalize": false})
+        );
+
+        request.dimensions = Some(768);
+        assert!(titan_request(&request, "hello".to_string()).is_err());
+
+        request.dimensions = None;
+        request
+            .extra_params
+            .insert("normalize".to_string(), json!("yes"));
+        assert!(titan_request(&request, "hello".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_titan_v1_request() {
+        let mut request = embedding_request(
+            "amazon.titan-embed-text-v1",
+            EmbeddingInput::Text("hello".to_string()),
+        );
+        let body =
+            serde_json::to_value(titan_request(&request, "hello".to_string()).unwrap()).unwrap();
+        assert_eq!(body, json!({"inputText": "hello"}));
+
+        request.dimensions = Some(256);
+        assert!(titan_request(&request, "hello".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_cohere_request() {
+        let mut request = embedding_request(
+            "cohere.embed-english-v3",
+            EmbeddingInput::Array(vec!["a".to_string(), "b".to_string()]),
+        );
+        let body = serde_json::to_value(cohere_request(&request).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({"texts": ["a", "b"], "input_type": "search_document", "truncate": "END"})
+        );
+
+        request.task_type = Some("RETRIEVAL_QUERY".to_string());
+        request
+            .extra_params
+            .insert("truncate".to_string(), json!("none"));
+        let body = serde_json::to_value(cohere_request(&request).unwrap()).unwrap();
+        assert_eq!(body["input_type"], "search_query");
+        assert_eq!(body["truncate"], "NONE");
+
+        request.dimensions = Some(512);
+        assert!(cohere_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_cohere_v4_dimensions() {
+        let mut request = embedding_request(
+            "cohere.embed-v4:0",
+            EmbeddingInput::Text("hello".to_string()),
+        );
+        request.dimensions = Some(1024);
+        let body = serde_json::to_value(cohere_request(&request).unwrap()).unwrap();
+        assert_eq!(body["output_dimension"], 1024);
+
+        request.dimensions = Some(100);
+        assert!(cohere_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_cohere_response_formats() {
+        let response: CohereEmbeddingResponse = serde_json::from_value(json!({
+            "id": "abc",
+            "response_type": "embeddings_floats",
+            "texts": ["a"],
+            "embeddings": [[0.1, 0.2]]
+        }))
+        .unwrap();
+        assert_eq!(response.embeddings.into_floats(), vec![vec![0.1, 0.2]]);
+
+        let response: CohereEmbeddingResponse = serde_json::from_value(json!({
+            "response_type": "embeddings_by_type",
+            "embeddings": {"float": [[0.3], [0.4]]}
+        }))
+        .unwrap();
+        assert_eq!(
+            response.embeddings.into_floats(),
+            vec![vec![0.3], vec![0.4]]
+        );
+    }
+
+    #[test]
+    fn test_empty_input_rejected() {
+        let request = embedding_request("cohere.embed-english-v3", EmbeddingInput::Array(vec![]));
+        assert!(cohere_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_l2_normalize() {
+        let mut embedding = vec![3.0, 4.0];
+        l2_normalize(&mut embedding);
+        assert_eq!(embedding, vec![0.6, 0.8]);
+
+        let mut zeros = vec![0.0, 0.0];
+        l2_normalize(&mut zeros);
+        assert_eq!(zeros, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_embedding_response() {
+        let response = embedding_response(
+            "cohere.embed-english-v3",
+            vec![vec![0.1], vec![0.2]],
+            Some(7),
+        );
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].index, 1);
+        assert_eq!(response.usage.unwrap().prompt_tokens, 7);
+    }
 }
@@ -1,18 +1,198 @@
 //! Bedrock Guardrails Module
 //!
-//! Provides content filtering, PII detection, and policy enforcement
+//! Provides content filtering, PII detection, and policy enforcement, both
+//! through the standalone `ApplyGuardrail` API and by attaching a guardrail to
+//! chat requests.
 
 use crate::core::providers::unified_provider::ProviderError;
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
 
 /// Guardrail configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GuardrailConfig {
+    #[serde(alias = "guardrailIdentifier")]
     pub guardrail_id: String,
     pub guardrail_version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trace: Option<bool>,
+    pub trace: Option<GuardrailTrace>,
+}
+
+/// How much guardrail trace information Bedrock returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailTrace {
+    Enabled,
+    Disabled,
+    EnabledFull,
+}
+
+impl GuardrailTrace {
+    /// Accepts the Converse values and, for convenience, booleans
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(true) => Some(Self::Enabled),
+            Value::Bool(false) => Some(Self::Disabled),
+            Value::String(s) => match s.to_ascii_lowercase().as_str() {
+                "enabled" => Some(Self::Enabled),
+                "disabled" => Some(Self::Disabled),
+                "enabled_full" => Some(Self::EnabledFull),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Value of the `X-Amzn-Bedrock-Trace` header used by the Invoke API
+    pub fn header_value(&self) -> &'static str {
+        match self {
+            Self::Enabled => "ENABLED",
+            Self::Disabled => "DISABLED",
+            Self::EnabledFull => "ENABLED_FULL",
+        }
+    }
+}
+
+/// First string value among `keys`
+fn first_str<'a>(params: &'a serde_json::Map<String, Value>, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|k| params.get(*k).and_then(|v| v.as_str()))
+}
+
+/// Guardrail to attach to a chat request
+///
+/// Request parameters take precedence over the deployment default. They can
+/// be given as a `guardrailConfig` object, as in the Converse API, or as flat
+/// `guardrailIdentifier` / `guardrailVersion` / `guardrailTrace` keys, in
+/// camelCase or snake_case.
+pub fn resolve_guardrail(
+    extra_params: &HashMap<String, Value>,
+    default: Option<&GuardrailConfig>,
+) -> Result<Option<GuardrailConfig>, ProviderError> {
+    let nested = extra_params
+        .get("guardrailConfig")
+        .or_else(|| extra_params.get("guardrail_config"));
+    let params: serde_json::Map<String, Value> = match nested {
+        Some(Value::Object(obj)) => obj.clone(),
+        Some(_) => {
+            return Err(ProviderError::invalid_request(
+                "bedrock",
+                "guardrailConfig must be an object",
+            ));
+        }
+        None => extra_params
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+    };
+
+    let id = first_str(
+        &params,
+        &[
+            "guardrailIdentifier",
+            "guardrail_identifier",
+            "guardrailId",
+            "guardrail_id",
+        ],
+    );
+    let version = first_str(&params, &["guardrailVersion", "guardrail_version"]);
+    let trace_keys: &[&str] = if nested.is_some() {
+        &["trace", "guardrailTrace", "guardrail_trace"]
+    } else {
+        &["guardrailTrace", "guardrail_trace"]
+    };
+    let trace = trace_keys
+        .iter()
+        .find_map(|k| params.get(*k))
+        .map(|v| {
+            GuardrailTrace::from_value(v).ok_or_else(|| {
+                ProviderError::invalid_request(
+                    "bedrock",
+                    format!(
+                        "Invalid guardrail trace {}; expected enabled, disabled or enabled_full",
+                        v
+                    ),
+                )
+            })
+        })
+        .transpose()?;
+
+    let config = match (id, default) {
+        (Some(id), _) => GuardrailConfig {
+            guardrail_id: id.to_string(),
+            guardrail_version: version.map(String::from).ok_or_else(|| {
+                ProviderError::invalid_request(
+                    "bedrock",
+                    "guardrailVersion is required with guardrailIdentifier",
+                )
+            })?,
+            trace,
+        },
+        (None, Some(default)) => GuardrailConfig {
+            guardrail_id: default.guardrail_id.clone(),
+            guardrail_version: version
+                .map(String::from)
+                .unwrap_or_else(|| default.guardrail_version.clone()),
+            trace: trace.or(default.trace),
+        },
+        (None, None) => return Ok(None),
+    };
+
+    Ok(Some(config))
+}
+
+/// Headers that attach a guardrail to Invoke API requests
+pub fn invoke_guardrail_headers(config: &GuardrailConfig) -> HashMap<String, String> {
+    let mut headers = HashMap::new();
+    headers.insert(
+        "x-amzn-bedrock-guardrailidentifier".to_string(),
+        config.guardrail_id.clone(),
+    );
+    headers.insert(
+        "x-amzn-bedrock-guardrailversion".to_string(),
+        config.guardrail_version.clone(),
+    );
+    if let Some(trace) = config.trace {
+        headers.insert(
+            "x-amzn-bedrock-trace".to_string(),
+            trace.header_value().to_string(),
+        );
+    }
+    headers
+}
+
+/// Guardrail outcome reported in a chat response
+///
+/// Converse responses signal an intervention through
+/// `stopReason: guardrail_intervened` and carry the trace under
+/// `trace.guardrail`; Invoke responses use the
+/// `amazon-bedrock-guardrailAction` and `amazon-bedrock-trace` body fields.
+/// Returns `{"action": "INTERVENED" | "NONE", "trace": ...}` when a guardrail
+/// was applied or the response mentions one.
+pub fn guardrail_metadata(response: &Value, applied: bool) -> Option<Value> {
+    let converse_intervened =
+        response.get("stopReason").and_then(|r| r.as_str()) == Some("guardrail_intervened");
+    let action = response
+        .get("amazon-bedrock-guardrailAction")
+        .and_then(|a| a.as_str())
+        .map(String::from)
+        .or_else(|| converse_intervened.then(|| "INTERVENED".to_string()));
+    let trace = response
+        .pointer("/trace/guardrail")
+        .or_else(|| response.pointer("/amazon-bedrock-trace/guardrail"))
+        .cloned();
+
+    if !applied && action.is_none() && trace.is_none() {
+        return None;
+    }
+
+    let mut metadata = json!({ "action": action.unwrap_or_else(|| "NONE".to_string()) });
+    if let Some(trace) = trace {
+        metadata["trace"] = trace;
+    }
+    Some(metadata)
 }
 
 /// Guardrail apply request
@@ -230,3 +410,134 @@ impl<'a> GuardrailClient<'a> {
         Ok(matches!(response.action, GuardrailAction::None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_guardrail_nested() {
+        let extra = params(json!({
+            "guardrailConfig": {
+                "guardrailIdentifier": "gr-abc123",
+                "guardrailVersion": "1",
+                "trace": "enabled_full"
+            }
+        }));
+        let config = resolve_guardrail(&extra, None).unwrap().unwrap();
+        assert_eq!(config.guardrail_id, "gr-abc123");
+        assert_eq!(config.guardrail_version, "1");
+        assert_eq!(config.trace, Some(GuardrailTrace::EnabledFull));
+    }
+
+    #[test]
+    fn test_resolve_guardrail_flat() {
+        let extra = params(json!({
+            "guardrail_identifier": "gr-abc123",
+            "guardrail_version": "DRAFT",
+            "guardrailTrace": true,
+            "trace": "ignored"
+        }));
+        let config = resolve_guardrail(&extra, None).unwrap().unwrap();
+        assert_eq!(config.guardrail_version, "DRAFT");
+        assert_eq!(config.trace, Some(GuardrailTrace::Enabled));
+    }
+
+    #[test]
+    fn test_resolve_guardrail_default() {
+        let default = GuardrailConfig {
+            guardrail_id: "gr-default".to_string(),
+            guardrail_version: "2".to_string(),
+            trace: Some(GuardrailTrace::Enabled),
+        };
+
+        assert!(resolve_guardrail(&HashMap::new(), None).unwrap().is_none());
+        assert_eq!(
+            resolve_guardrail(&HashMap::new(), Some(&default)).unwrap(),
+            Some(default.clone())
+        );
+
+        // Request values override the deployment's
+        let extra = params(json!({"guardrailVersion": "3", "guardrailTrace": "disabled"}));
+        let config = resolve_guardrail(&extra, Some(&default)).unwrap().unwrap();
+        assert_eq!(config.guardrail_id, "gr-default");
+        assert_eq!(config.guardrail_version, "3");
+        assert_eq!(config.trace, Some(GuardrailTrace::Disabled));
+    }
+
+    #[test]
+    fn test_resolve_guardrail_errors() {
+        let extra = params(json!({"guardrailIdentifier": "gr-abc123"}));
+        assert!(resolve_guardrail(&extra, None).is_err());
+
+        let extra = params(json!({"guardrailConfig": "gr-abc123"}));
+        assert!(resolve_guardrail(&extra, None).is_err());
+
+        let extra = params(json!({
+            "guardrailIdentifier": "gr-abc123",
+            "guardrailVersion": "1",
+            "guardrailTrace": "verbose"
+        }));
+        assert!(resolve_guardrail(&extra, None).is_err());
+    }
+
+    #[test]
+    fn test_guardrail_config_deserialize() {
+        let config: GuardrailConfig = serde_json::from_value(json!({
+            "guardrailIdentifier": "gr-abc123",
+            "guardrailVersion": "1",
+            "trace": "enabled"
+        }))
+        .unwrap();
+        assert_eq!(config.guardrail_id, "gr-abc123");
+        assert_eq!(config.trace, Some(GuardrailTrace::Enabled));
+    }
+
+    #[test]
+    fn test_invoke_guardrail_headers() {
+        let config = GuardrailConfig {
+            guardrail_id: "gr-abc123".to_string(),
+            guardrail_version: "1".to_string(),
+            trace: Some(GuardrailTrace::EnabledFull),
+        };
+        let headers = invoke_guardrail_headers(&config);
+        assert_eq!(headers["x-amzn-bedrock-guardrailidentifier"], "gr-abc123");
+        assert_eq!(headers["x-amzn-bedrock-guardrailversion"], "1");
+        assert_eq!(headers["x-amzn-bedrock-trace"], "ENABLED_FULL");
+    }
+
+    #[test]
+    fn test_guardrail_metadata_converse() {
+        let response = json!({
+            "output": {"message": {"role": "assistant", "content": [{"text": "Sorry, I can't help with that."}]}},
+            "stopReason": "guardrail_intervened",
+            "trace": {"guardrail": {"inputAssessment": {"gr-abc123": {"contentPolicy": {}}}}}
+        });
+        let metadata = guardrail_metadata(&response, true).unwrap();
+        assert_eq!(metadata["action"], "INTERVENED");
+        assert!(metadata["trace"]["inputAssessment"].is_object());
+
+        let response = json!({"stopReason": "end_turn"});
+        assert_eq!(
+            guardrail_metadata(&response, true).unwrap(),
+            json!({"action": "NONE"})
+        );
+        assert!(guardrail_metadata(&response, false).is_none());
+    }
+
+    #[test]
+    fn test_guardrail_metadata_invoke() {
+        let response = json!({
+            "content": [{"type": "text", "text": "Sorry"}],
+            "amazon-bedrock-guardrailAction": "INTERVENED",
+            "amazon-bedrock-trace": {"guardrail": {"input": {}}}
+        });
+        let metadata = guardrail_metadata(&response, true).unwrap();
+        assert_eq!(metadata["action"], "INTERVENED");
+        assert_eq!(metadata["trace"], json!({"input": {}}));
+    }
+}
@@ -33,9 +33,11 @@ pub use model_config::{
 };
 pub use provider::BedrockProvider;
 pub use sigv4::SigV4Signer;
+pub(crate) use sigv4::uri_encode;
 pub use utils::{
-    AWS_REGIONS, AwsAuth, AwsCredentials, CostCalculator, ModelPricing,
-    is_model_available_in_region, validate_region,
+    AWS_REGIONS, AwsAuth, AwsCredentialChain, AwsCredentials, CostCalculator, CredentialSource,
+    ModelPricing, ResolvedCredentials, base_model_id, is_model_available_in_region,
+    resolve_inference_profile, validate_region,
 };
 
 // Re-export feature modules
@@ -3,6 +3,7 @@
 //! Defines model families, capabilities, and routing configuration
 //! for all supported Bedrock models.
 
+use super::utils::base_model_id;
 use crate::core::providers::unified_provider::ProviderError;
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -232,6 +233,21 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelConfig>> = LazyLock::n
         },
     );
 
+    configs.insert(
+        "amazon.titan-embed-text-v2:0",
+        ModelConfig {
+            family: BedrockModelFamily::TitanEmbedding,
+            api_type: BedrockApiType::Invoke,
+            supports_streaming: false,
+            supports_function_calling: false,
+            supports_multimodal: false,
+            max_context_length: 8192,
+            max_output_length: None,
+            input_cost_per_1k: 0.00002,
+            output_cost_per_1k: 0.0,
+        },
+    );
+
     // Nova models
     configs.insert(
         "amazon.nova-micro-v1:0",
@@ -627,12 +643,61 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelConfig>> = LazyLock::n
         },
     );
 
+    // Cohere embedding models
+    configs.insert(
+        "cohere.embed-english-v3",
+        ModelConfig {
+            family: BedrockModelFamily::Cohere,
+            api_type: BedrockApiType::Invoke,
+            supports_streaming: false,
+            supports_function_calling: false,
+            supports_multimodal: false,
+            max_context_length: 512,
+            max_output_length: None,
+            input_cost_per_1k: 0.0001,
+            output_cost_per_1k: 0.0,
+        },
+    );
+
+    configs.insert(
+        "cohere.embed-multilingual-v3",
+        ModelConfig {
+            family: BedrockModelFamily::Cohere,
+            api_type: BedrockApiType::Invoke,
+            supports_streaming: false,
+            supports_function_calling: false,
+            supports_multimodal: false,
+            max_context_length: 512,
+            max_output_length: None,
+            input_cost_per_1k: 0.0001,
+            output_cost_per_1k: 0.0,
+        },
+    );
+
+    configs.insert(
+        "cohere.embed-v4:0",
+        ModelConfig {
+            family: BedrockModelFamily::Cohere,
+            api_type: BedrockApiType::Invoke,
+            supports_streaming: false,
+            supports_function_calling: false,
+            supports_multimodal: false,
+            max_context_length: 128000,
+            max_output_length: None,
+            input_cost_per_1k: 0.00012,
+            output_cost_per_1k: 0.0,
+        },
+    );
+
     configs
 });
 
 /// Get model configuration for a specific model ID
+///
+/// Cross-region inference profile ids and ARNs resolve to the configuration
+/// of their foundation model.
 pub fn get_model_config(model_id: &str) -> Result<&'static ModelConfig, ProviderError> {
-    MODEL_CONFIGS.get(model_id).ok_or_else(|| {
+    MODEL_CONFIGS.get(base_model_id(model_id)).ok_or_else(|| {
         ProviderError::model_not_found("bedrock", format!("Model {} not supported", model_id))
     })
 }
@@ -713,6 +778,18 @@ mod tests {
         assert_eq!(nova_config.family, BedrockModelFamily::Nova);
     }
 
+    #[test]
+    fn test_inference_profile_lookup() {
+        let config = get_model_config("us.anthropic.claude-3-opus-20240229").unwrap();
+        assert_eq!(config.family, BedrockModelFamily::Claude);
+
+        let config = get_model_config(
+            "arn:aws:bedrock:eu-west-1:123456789012:inference-profile/eu.amazon.nova-pro-v1:0",
+        )
+        .unwrap();
+        assert_eq!(config.family, BedrockModelFamily::Nova);
+    }
+
     #[test]
     fn test_api_types() {
         let claude_config = get_model_config("anthropic.claude-3-opus-20240229").unwrap();
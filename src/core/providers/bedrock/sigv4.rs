@@ -37,6 +37,12 @@ impl SigV4Signer {
         }
     }
 
+    /// Use a different signing service name (defaults to `bedrock`)
+    pub fn with_service(mut self, service: impl Into<String>) -> Self {
+        self.service = service.into();
+        self
+    }
+
     /// Sign an HTTP request with AWS SigV4
     pub fn sign_request(
         &self,
@@ -51,8 +57,13 @@ impl SigV4Signer {
 
         let host = parsed_url.host_str().ok_or("Missing host in URL")?;
 
-        let path = parsed_url.path();
-        let query = parsed_url.query().unwrap_or("");
+        // S3 signs the path as sent, other services encode it again
+        let path = if self.service == "s3" {
+            parsed_url.path().to_string()
+        } else {
+            canonical_uri(parsed_url.path())
+        };
+        let query = canonical_query_string(parsed_url.query().unwrap_or(""));
 
         // Format timestamp
         let amz_date = timestamp.format("%Y%m%dT%H%M%SZ").to_string();
@@ -74,7 +85,7 @@ impl SigV4Signer {
         // Build canonical headers string
         let canonical_headers_str = sorted_headers
             .iter()
-            .map(|(k, v)| format!("{}:{}", k.to_lowercase(), v.trim()))
+            .map(|(k, v)| format!("{}:{}", k.to_lowercase(), normalize_header_value(v)))
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -154,6 +165,53 @@ impl SigV4Signer {
     }
 }
 
+/// URI-encode a string as SigV4 requires: everything except unreserved
+/// characters becomes an uppercase `%XX` escape
+pub(crate) fn uri_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Canonical URI of a request path
+///
+/// Services other than S3 expect each segment of the already-encoded path to
+/// be encoded again, so a model id sent as `v1%3A0` is signed as `v1%253A0`.
+fn canonical_uri(path: &str) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+    path.split('/')
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Canonical query string: decoded pairs re-encoded and sorted by name, then value
+fn canonical_query_string(query: &str) -> String {
+    let mut pairs: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .map(|(k, v)| (uri_encode(&k), uri_encode(&v)))
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Trim a header value and collapse runs of spaces
+fn normalize_header_value(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +273,83 @@ mod tests {
         assert!(signed_headers.contains_key("Authorization"));
         assert!(signed_headers.contains_key("x-amz-date"));
     }
+
+    #[test]
+    fn test_sign_request_aws_test_vector() {
+        // `get-vanilla` from the AWS SigV4 test suite
+        let signer = SigV4Signer::new(
+            "AKIDEXAMPLE".to_string(),
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            None,
+            "us-east-1".to_string(),
+        )
+        .with_service("service");
+
+        let timestamp = Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap();
+        let signed_headers = signer
+            .sign_request(
+                "GET",
+                "https://example.amazonaws.com/",
+                &HashMap::new(),
+                "",
+                timestamp,
+            )
+            .unwrap();
+
+        assert_eq!(
+            signed_headers["Authorization"],
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn test_sign_request_with_session_token() {
+        let signer = SigV4Signer::new(
+            "ASIATEST".to_string(),
+            "testsecret".to_string(),
+            Some("session-token".to_string()),
+            "us-east-1".to_string(),
+        );
+
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let signed_headers = signer
+            .sign_request(
+                "POST",
+                "https://bedrock-runtime.us-east-1.amazonaws.com/model/test/converse",
+                &HashMap::new(),
+                "{}",
+                timestamp,
+            )
+            .unwrap();
+
+        assert_eq!(signed_headers["x-amz-security-token"], "session-token");
+        assert!(
+            signed_headers["Authorization"]
+                .contains("SignedHeaders=host;x-amz-date;x-amz-security-token")
+        );
+    }
+
+    #[test]
+    fn test_canonical_uri() {
+        assert_eq!(canonical_uri(""), "/");
+        assert_eq!(canonical_uri("/"), "/");
+        assert_eq!(
+            canonical_uri("/model/anthropic.claude-3-5-sonnet-20240620-v1%3A0/converse"),
+            "/model/anthropic.claude-3-5-sonnet-20240620-v1%253A0/converse"
+        );
+    }
+
+    #[test]
+    fn test_canonical_query_string() {
+        assert_eq!(canonical_query_string(""), "");
+        assert_eq!(canonical_query_string("b=2&a=x%20y&a=1"), "a=1&a=x%20y&b=2");
+    }
+
+    #[test]
+    fn test_uri_encode() {
+        assert_eq!(uri_encode("abc-_.~"), "abc-_.~");
+        assert_eq!(uri_encode("a:b/c d"), "a%3Ab%2Fc%20d");
+    }
 }
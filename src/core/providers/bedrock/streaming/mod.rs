@@ -205,6 +205,7 @@ impl BedrockStream {
                     }],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
             Some("message_stop") => Ok(Some(ChatChunk {
@@ -226,6 +227,7 @@ impl BedrockStream {
                 }],
                 usage: None,
                 system_fingerprint: None,
+                provider_specific_fields: None,
             })),
             _ => Ok(None),
         }
@@ -260,6 +262,7 @@ impl BedrockStream {
                 }],
                 usage: None,
                 system_fingerprint: None,
+                provider_specific_fields: None,
             }))
         } else {
             Ok(None)
@@ -294,6 +297,7 @@ impl BedrockStream {
                 }],
                 usage: None,
                 system_fingerprint: None,
+                provider_specific_fields: None,
             }))
         } else {
             Ok(None)
@@ -331,6 +335,7 @@ impl BedrockStream {
                 }],
                 usage: None,
                 system_fingerprint: None,
+                provider_specific_fields: None,
             }))
         } else {
             Ok(None)
@@ -3,6 +3,7 @@
 //! Provides accurate pricing information and cost calculation
 //! for all supported Bedrock models.
 
+use super::region::base_model_id;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
@@ -196,6 +197,48 @@ static MODEL_PRICING: LazyLock<HashMap<&'static str, ModelPricing>> = LazyLock::
         },
     );
 
+    // Embedding models
+    pricing.insert(
+        "amazon.titan-embed-text-v1",
+        ModelPricing {
+            input_cost_per_1k: 0.0001,
+            output_cost_per_1k: 0.0,
+            currency: "USD",
+        },
+    );
+    pricing.insert(
+        "amazon.titan-embed-text-v2:0",
+        ModelPricing {
+            input_cost_per_1k: 0.00002,
+            output_cost_per_1k: 0.0,
+            currency: "USD",
+        },
+    );
+    pricing.insert(
+        "cohere.embed-english-v3",
+        ModelPricing {
+            input_cost_per_1k: 0.0001,
+            output_cost_per_1k: 0.0,
+            currency: "USD",
+        },
+    );
+    pricing.insert(
+        "cohere.embed-multilingual-v3",
+        ModelPricing {
+            input_cost_per_1k: 0.0001,
+            output_cost_per_1k: 0.0,
+            currency: "USD",
+        },
+    );
+    pricing.insert(
+        "cohere.embed-v4:0",
+        ModelPricing {
+            input_cost_per_1k: 0.00012,
+            output_cost_per_1k: 0.0,
+            currency: "USD",
+        },
+    );
+
     // Mistral models
     pricing.insert(
         "mistral.mistral-7b-instruct-v0:2",
@@ -337,7 +380,7 @@ pub struct CostCalculator;
 impl CostCalculator {
     /// Calculate cost for a specific model and token usage
     pub fn calculate_cost(model_id: &str, input_tokens: u32, output_tokens: u32) -> Option<f64> {
-        MODEL_PRICING.get(model_id).map(|pricing| {
+        MODEL_PRICING.get(base_model_id(model_id)).map(|pricing| {
             let input_cost = (input_tokens as f64 / 1000.0) * pricing.input_cost_per_1k;
             let output_cost = (output_tokens as f64 / 1000.0) * pricing.output_cost_per_1k;
             input_cost + output_cost
@@ -346,7 +389,7 @@ impl CostCalculator {
 
     /// Get pricing information for a model
     pub fn get_model_pricing(model_id: &str) -> Option<&'static ModelPricing> {
-        MODEL_PRICING.get(model_id)
+        MODEL_PRICING.get(base_model_id(model_id))
     }
 
     /// Get all available models with pricing
@@ -360,7 +403,7 @@ impl CostCalculator {
         input_tokens: u32,
         output_tokens: u32,
     ) -> Option<CostBreakdown> {
-        MODEL_PRICING.get(model_id).map(|pricing| {
+        MODEL_PRICING.get(base_model_id(model_id)).map(|pricing| {
             let input_cost = (input_tokens as f64 / 1000.0) * pricing.input_cost_per_1k;
             let output_cost = (output_tokens as f64 / 1000.0) * pricing.output_cost_per_1k;
 
@@ -5,9 +5,14 @@
 
 pub mod auth;
 pub mod cost;
+pub mod credentials;
 pub mod region;
 
 // Re-export main types and functions
 pub use auth::{AwsAuth, AwsCredentials};
 pub use cost::{CostCalculator, ModelPricing};
-pub use region::{AWS_REGIONS, is_model_available_in_region, validate_region};
+pub use credentials::{AwsCredentialChain, CredentialSource, ResolvedCredentials};
+pub use region::{
+    AWS_REGIONS, base_model_id, is_model_available_in_region, resolve_inference_profile,
+    validate_region,
+};
@@ -1,7 +1,7 @@
 //! AWS Region Management for Bedrock
 //!
 //! Handles AWS region validation, model availability checks,
-//! and region-specific configuration.
+//! region-specific configuration, and cross-region inference profiles.
 
 use crate::core::providers::unified_provider::ProviderError;
 use std::collections::HashMap;
@@ -181,6 +181,108 @@ pub fn get_ap_regions() -> &'static [&'static str] {
     ]
 }
 
+/// Geography prefixes of system-defined cross-region inference profiles
+///
+/// `us-gov` must be checked before `us`.
+const INFERENCE_PROFILE_PREFIXES: &[&str] = &["us-gov", "us", "eu", "apac", "global"];
+
+/// Model id prefixes of models offered through cross-region inference profiles
+const CROSS_REGION_MODELS: &[&str] = &[
+    "anthropic.claude-3",
+    "anthropic.claude-opus-4",
+    "anthropic.claude-sonnet-4",
+    "amazon.nova",
+    "meta.llama3-1",
+    "meta.llama3-2",
+    "meta.llama3-3",
+    "meta.llama4",
+    "deepseek.r1",
+    "mistral.pixtral",
+];
+
+/// Inference profile prefix for the geography a region belongs to
+pub fn inference_profile_prefix(region: &str) -> Option<&'static str> {
+    if region.starts_with("us-gov-") {
+        Some("us-gov")
+    } else if region.starts_with("us-") {
+        Some("us")
+    } else if region.starts_with("eu-") {
+        Some("eu")
+    } else if region.starts_with("ap-") {
+        Some("apac")
+    } else {
+        None
+    }
+}
+
+/// Whether a model id already names an inference profile or an ARN
+pub fn is_inference_profile(model_id: &str) -> bool {
+    model_id.starts_with("arn:")
+        || INFERENCE_PROFILE_PREFIXES.iter().any(|prefix| {
+            model_id
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('.'))
+        })
+}
+
+/// Foundation model id behind an inference profile id or ARN
+///
+/// `us.anthropic.claude-3-5-sonnet-20241022` and
+/// `arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.anthropic.claude-3-5-sonnet-20241022`
+/// both map to `anthropic.claude-3-5-sonnet-20241022`. Application inference
+/// profile and provisioned model ARNs don't name their model and are returned
+/// unchanged.
+pub fn base_model_id(model_id: &str) -> &str {
+    let id = if model_id.starts_with("arn:") {
+        match model_id.split_once('/') {
+            Some((resource, id))
+                if resource.ends_with(":inference-profile")
+                    || resource.ends_with(":foundation-model") =>
+            {
+                id
+            }
+            _ => return model_id,
+        }
+    } else {
+        model_id
+    };
+
+    INFERENCE_PROFILE_PREFIXES
+        .iter()
+        .find_map(|prefix| {
+            id.strip_prefix(prefix)
+                .and_then(|rest| rest.strip_prefix('.'))
+        })
+        .unwrap_or(id)
+}
+
+/// Whether a model is served through cross-region inference profiles
+pub fn supports_cross_region_inference(model_id: &str) -> bool {
+    let base = base_model_id(model_id);
+    CROSS_REGION_MODELS
+        .iter()
+        .any(|prefix| base.starts_with(prefix))
+}
+
+/// Model id to send for a region
+///
+/// Plain ids of models served through cross-region inference get the
+/// geography prefix of `region`, e.g. `anthropic.claude-3-5-sonnet-20241022`
+/// becomes `eu.anthropic.claude-3-5-sonnet-20241022` in `eu-west-1`. Profile
+/// ids, ARNs and other models are left alone.
+pub fn resolve_inference_profile(model_id: &str, region: &str) -> String {
+    if model_id.is_empty()
+        || is_inference_profile(model_id)
+        || !supports_cross_region_inference(model_id)
+    {
+        return model_id.to_string();
+    }
+    match inference_profile_prefix(region) {
+        Some(prefix) => format!("{}.{}", prefix, model_id),
+        None => model_id.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +336,78 @@ mod tests {
         assert!(get_eu_regions().contains(&"eu-west-1"));
         assert!(get_ap_regions().contains(&"ap-southeast-1"));
     }
+
+    #[test]
+    fn test_inference_profile_prefix() {
+        assert_eq!(inference_profile_prefix("us-west-2"), Some("us"));
+        assert_eq!(inference_profile_prefix("us-gov-west-1"), Some("us-gov"));
+        assert_eq!(inference_profile_prefix("eu-central-1"), Some("eu"));
+        assert_eq!(inference_profile_prefix("ap-northeast-1"), Some("apac"));
+        assert_eq!(inference_profile_prefix("sa-east-1"), None);
+    }
+
+    #[test]
+    fn test_base_model_id() {
+        assert_eq!(
+            base_model_id("us.anthropic.claude-3-5-sonnet-20241022"),
+            "anthropic.claude-3-5-sonnet-20241022"
+        );
+        assert_eq!(
+            base_model_id("us-gov.anthropic.claude-3-haiku-20240307"),
+            "anthropic.claude-3-haiku-20240307"
+        );
+        assert_eq!(
+            base_model_id(
+                "arn:aws:bedrock:eu-west-1:123456789012:inference-profile/eu.amazon.nova-pro-v1:0"
+            ),
+            "amazon.nova-pro-v1:0"
+        );
+        assert_eq!(
+            base_model_id(
+                "arn:aws:bedrock:us-east-1::foundation-model/amazon.titan-text-express-v1"
+            ),
+            "amazon.titan-text-express-v1"
+        );
+        let app_profile =
+            "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/a1b2c3";
+        assert_eq!(base_model_id(app_profile), app_profile);
+        assert_eq!(
+            base_model_id("amazon.titan-text-express-v1"),
+            "amazon.titan-text-express-v1"
+        );
+    }
+
+    #[test]
+    fn test_resolve_inference_profile() {
+        assert_eq!(
+            resolve_inference_profile("anthropic.claude-3-5-sonnet-20241022", "us-east-1"),
+            "us.anthropic.claude-3-5-sonnet-20241022"
+        );
+        assert_eq!(
+            resolve_inference_profile("anthropic.claude-3-5-sonnet-20241022", "eu-west-3"),
+            "eu.anthropic.claude-3-5-sonnet-20241022"
+        );
+        assert_eq!(
+            resolve_inference_profile("amazon.nova-lite-v1:0", "ap-southeast-2"),
+            "apac.amazon.nova-lite-v1:0"
+        );
+
+        // Already a profile, unsupported model or region without profiles
+        assert_eq!(
+            resolve_inference_profile("eu.anthropic.claude-3-haiku-20240307", "us-east-1"),
+            "eu.anthropic.claude-3-haiku-20240307"
+        );
+        assert_eq!(
+            resolve_inference_profile("amazon.titan-text-express-v1", "us-east-1"),
+            "amazon.titan-text-express-v1"
+        );
+        assert_eq!(
+            resolve_inference_profile("anthropic.claude-v2", "us-east-1"),
+            "anthropic.claude-v2"
+        );
+        assert_eq!(
+            resolve_inference_profile("anthropic.claude-3-5-sonnet-20241022", "sa-east-1"),
+            "anthropic.claude-3-5-sonnet-20241022"
+        );
+    }
 }
@@ -0,0 +1,176 @@
+//! Cerebras Client
+//!
+//! How the Cerebras API departs from OpenAI's chat completions
+
+use crate::core::providers::base::OpenAICompatibleClient;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::ProviderCapability;
+
+use super::CerebrasConfig;
+
+/// Cerebras API client logic
+#[derive(Debug, Clone)]
+pub struct CerebrasClient;
+
+impl OpenAICompatibleClient for CerebrasClient {
+    type Config = CerebrasConfig;
+
+    const NAME: &'static str = "cerebras";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::ToolCalling,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "stop",
+        "stream",
+        "stream_options",
+        "tools",
+        "tool_choice",
+        "parallel_tool_calls",
+        "response_format",
+        "seed",
+        "logprobs",
+        "top_logprobs",
+        "user",
+    ];
+
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &[
+        "frequency_penalty",
+        "presence_penalty",
+        "logit_bias",
+        "thinking",
+        "functions",
+        "function_call",
+    ];
+
+    // `max_tokens` is deprecated in favour of `max_completion_tokens`
+    const MAX_TOKENS_FIELD: Option<&'static str> = Some("max_completion_tokens");
+
+    fn new(_config: &CerebrasConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::base::openai_compatible::create_stream;
+    use crate::core::providers::cerebras::CerebrasProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{ChatMessage, ChatRequest, MessageContent, MessageRole};
+    use bytes::Bytes;
+    use futures::{StreamExt, stream};
+
+    fn test_provider() -> CerebrasProvider {
+        let mut config = CerebrasConfig::new("cerebras");
+        config.base.api_key = Some("csk-test".to_string());
+        CerebrasProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("cerebras/llama-3.3-70b");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_tokens = Some(256);
+        request.frequency_penalty = Some(0.5);
+
+        let body = CerebrasClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "llama-3.3-70b");
+        assert_eq!(body["max_completion_tokens"], 256);
+        assert!(body.get("max_tokens").is_none());
+        assert!(body.get("frequency_penalty").is_none());
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = br#"{
+            "id": "chatcmpl-5d3e",
+            "object": "chat.completion",
+            "created": 1718000000,
+            "model": "llama3.1-8b",
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hi"}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 9, "completion_tokens": 1, "total_tokens": 10},
+            "time_info": {"queue_time": 0.0001, "completion_time": 0.002}
+        }"#;
+
+        let response = CerebrasClient::transform_chat_response(raw).unwrap();
+        assert_eq!(response.first_content(), Some("Hi"));
+        assert_eq!(response.usage.unwrap().total_tokens, 10);
+    }
+
+    #[test]
+    fn test_error_top_level_message() {
+        let err = CerebrasClient::map_http_error(
+            401,
+            r#"{"message":"Wrong API Key","type":"invalid_request_error","param":"api_key","code":"wrong_api_key"}"#,
+        );
+        match err {
+            ProviderError::Authentication { message, .. } => assert_eq!(message, "Wrong API Key"),
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "data: {\"id\":\"chatcmpl-5d3e\",\"object\":\"chat.completion.chunk\",\"created\":1718000000,\"model\":\"llama3.1-8b\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"},\"finish_reason\":null}]}\n\n",
+            )),
+            Ok(Bytes::from("data: [DONE]\n\n")),
+        ];
+
+        let mut cerebras_stream = create_stream::<CerebrasClient>(stream::iter(test_data));
+
+        let chunk = cerebras_stream.next().await.unwrap().unwrap();
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+        assert!(cerebras_stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = CerebrasConfig::new("cerebras");
+        config.base.api_key = None;
+        assert!(CerebrasProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "cerebras");
+        assert!(provider.supports_streaming());
+        assert!(provider.supports_model("cerebras/llama-3.3-70b"));
+        assert!(!provider.supports_model("gpt-4"));
+        assert!(provider.models().iter().any(|m| m.id == "llama3.1-8b"));
+        assert!(provider.models().iter().all(|m| m.provider == "cerebras"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://api.cerebras.ai/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("cerebras/llama-3.3-70b", 1_000_000, 1_000_000)
+            .await
+            .unwrap();
+        assert!((cost - 2.05).abs() < 1e-9);
+    }
+}
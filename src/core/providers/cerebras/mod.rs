@@ -0,0 +1,14 @@
+//! Cerebras Provider
+//!
+//! OpenAI-compatible chat and streaming for models served on api.cerebras.ai
+
+pub mod client;
+pub mod config;
+
+pub use client::CerebrasClient;
+pub use config::CerebrasConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// Cerebras provider
+pub type CerebrasProvider = OpenAICompatibleProvider<CerebrasClient>;
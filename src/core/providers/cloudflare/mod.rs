@@ -12,5 +12,5 @@ mod provider;
 // Re-export main types for external use
 pub use config::CloudflareConfig;
 pub use error::{CloudflareError, CloudflareErrorMapper};
-pub use model_info::{CloudflareModel, get_model_info};
+pub use model_info::{CloudflareModel, ModelTask, get_model_info};
 pub use provider::CloudflareProvider;
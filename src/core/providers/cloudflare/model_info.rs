@@ -30,6 +30,26 @@ pub enum CloudflareModel {
     // Code models
     CodeLlama7B,
     DeepseekCoder6_7B,
+
+    // Embedding models
+    BgeSmallEn,
+    BgeBaseEn,
+    BgeLargeEn,
+
+    // Image models
+    StableDiffusionXLBase,
+    StableDiffusionXLLightning,
+}
+
+/// Workers AI task a model performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModelTask {
+    /// Chat and text generation
+    Chat,
+    /// Text embeddings
+    Embeddings,
+    /// Image generation from a prompt
+    ImageGeneration,
 }
 
 /// Model configuration
@@ -49,6 +69,8 @@ pub struct ModelInfo {
     pub supports_vision: bool,
     /// Whether the model supports streaming
     pub supports_streaming: bool,
+    /// Task the model performs
+    pub task: ModelTask,
     /// Input cost per million tokens (in USD)
     pub input_cost_per_million: f64,
     /// Output cost per million tokens (in USD)
@@ -70,6 +92,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0, // Free on Cloudflare Workers
             output_cost_per_million: 0.0,
         },
@@ -85,6 +108,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -100,6 +124,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -116,6 +141,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -131,6 +157,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -147,6 +174,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -163,6 +191,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -178,6 +207,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -194,6 +224,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -209,6 +240,89 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             supports_tools: false,
             supports_vision: false,
             supports_streaming: true,
+            task: ModelTask::Chat,
+            input_cost_per_million: 0.0,
+            output_cost_per_million: 0.0,
+        },
+    );
+
+    // Embedding models
+    configs.insert(
+        "@cf/baai/bge-small-en-v1.5",
+        ModelInfo {
+            model_id: "@cf/baai/bge-small-en-v1.5",
+            display_name: "BGE Small EN v1.5",
+            context_length: 512,
+            max_output_tokens: 0,
+            supports_tools: false,
+            supports_vision: false,
+            supports_streaming: false,
+            task: ModelTask::Embeddings,
+            input_cost_per_million: 0.0,
+            output_cost_per_million: 0.0,
+        },
+    );
+
+    configs.insert(
+        "@cf/baai/bge-base-en-v1.5",
+        ModelInfo {
+            model_id: "@cf/baai/bge-base-en-v1.5",
+            display_name: "BGE Base EN v1.5",
+            context_length: 512,
+            max_output_tokens: 0,
+            supports_tools: false,
+            supports_vision: false,
+            supports_streaming: false,
+            task: ModelTask::Embeddings,
+            input_cost_per_million: 0.0,
+            output_cost_per_million: 0.0,
+        },
+    );
+
+    configs.insert(
+        "@cf/baai/bge-large-en-v1.5",
+        ModelInfo {
+            model_id: "@cf/baai/bge-large-en-v1.5",
+            display_name: "BGE Large EN v1.5",
+            context_length: 512,
+            max_output_tokens: 0,
+            supports_tools: false,
+            supports_vision: false,
+            supports_streaming: false,
+            task: ModelTask::Embeddings,
+            input_cost_per_million: 0.0,
+            output_cost_per_million: 0.0,
+        },
+    );
+
+    // Image models
+    configs.insert(
+        "@cf/stabilityai/stable-diffusion-xl-base-1.0",
+        ModelInfo {
+            model_id: "@cf/stabilityai/stable-diffusion-xl-base-1.0",
+            display_name: "Stable Diffusion XL Base 1.0",
+            context_length: 2048,
+            max_output_tokens: 0,
+            supports_tools: false,
+            supports_vision: false,
+            supports_streaming: false,
+            task: ModelTask::ImageGeneration,
+            input_cost_per_million: 0.0,
+            output_cost_per_million: 0.0,
+        },
+    );
+
+    configs.insert(
+        "@cf/bytedance/stable-diffusion-xl-lightning",
+        ModelInfo {
+            model_id: "@cf/bytedance/stable-diffusion-xl-lightning",
+            display_name: "Stable Diffusion XL Lightning",
+            context_length: 2048,
+            max_output_tokens: 0,
+            supports_tools: false,
+            supports_vision: false,
+            supports_streaming: false,
+            task: ModelTask::ImageGeneration,
             input_cost_per_million: 0.0,
             output_cost_per_million: 0.0,
         },
@@ -229,6 +343,25 @@ pub fn get_available_models() -> Vec<&'static str> {
     MODEL_CONFIGS.keys().copied().collect()
 }
 
+/// Get the task of a model
+///
+/// Unknown models are assumed to generate text, except for the `@cf/baai/bge-*`
+/// embedding and `@cf/stabilityai/*` image families.
+pub fn get_model_task(model_id: &str) -> ModelTask {
+    if let Some(info) = get_model_info(model_id) {
+        return info.task;
+    }
+
+    let model_id = model_id.strip_prefix("cloudflare/").unwrap_or(model_id);
+    if model_id.starts_with("@cf/baai/bge-") {
+        ModelTask::Embeddings
+    } else if model_id.starts_with("@cf/stabilityai/") {
+        ModelTask::ImageGeneration
+    } else {
+        ModelTask::Chat
+    }
+}
+
 /// Calculate cost (always 0 for Cloudflare Workers AI as it's free within limits)
 pub fn calculate_cost(model_id: &str, _input_tokens: u32, _output_tokens: u32) -> Option<f64> {
     // Cloudflare Workers AI is free within usage limits
@@ -255,6 +388,15 @@ impl CloudflareModel {
             CloudflareModel::DeepseekCoder6_7B => {
                 "@cf/deepseek-ai/deepseek-coder-6.7b-instruct-awq"
             }
+            CloudflareModel::BgeSmallEn => "@cf/baai/bge-small-en-v1.5",
+            CloudflareModel::BgeBaseEn => "@cf/baai/bge-base-en-v1.5",
+            CloudflareModel::BgeLargeEn => "@cf/baai/bge-large-en-v1.5",
+            CloudflareModel::StableDiffusionXLBase => {
+                "@cf/stabilityai/stable-diffusion-xl-base-1.0"
+            }
+            CloudflareModel::StableDiffusionXLLightning => {
+                "@cf/bytedance/stable-diffusion-xl-lightning"
+            }
         }
     }
 
@@ -287,6 +429,27 @@ mod tests {
         assert!(models.contains(&"@cf/mistral/mistral-7b-instruct-v0.1"));
     }
 
+    #[test]
+    fn test_model_task() {
+        assert_eq!(
+            get_model_task("@cf/meta/llama-3-8b-instruct"),
+            ModelTask::Chat
+        );
+        assert_eq!(
+            get_model_task("cloudflare/@cf/baai/bge-base-en-v1.5"),
+            ModelTask::Embeddings
+        );
+        assert_eq!(get_model_task("@cf/baai/bge-m3"), ModelTask::Embeddings);
+        assert_eq!(
+            CloudflareModel::StableDiffusionXLBase.info().unwrap().task,
+            ModelTask::ImageGeneration
+        );
+        assert_eq!(
+            get_model_task("@cf/stabilityai/stable-diffusion-3"),
+            ModelTask::ImageGeneration
+        );
+    }
+
     #[test]
     fn test_cost_calculation() {
         // Cloudflare Workers AI is free
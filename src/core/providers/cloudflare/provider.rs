@@ -1,8 +1,11 @@
 //! Main Cloudflare Workers AI Provider Implementation
 //!
 //! Implements the LLMProvider trait for Cloudflare's Workers AI models.
+//! Chat, embedding (`@cf/baai/bge-*`) and image (`@cf/stabilityai/*`) models
+//! all run through the account-scoped `ai/run/{model}` endpoint.
 
 use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
 use futures::Stream;
 use std::collections::HashMap;
 use std::pin::Pin;
@@ -11,23 +14,34 @@ use tracing::debug;
 
 use super::config::CloudflareConfig;
 use super::error::{CloudflareError, CloudflareErrorMapper};
-use super::model_info::{calculate_cost, get_available_models, get_model_info};
+use super::model_info::{
+    ModelTask, calculate_cost, get_available_models, get_model_info, get_model_task,
+};
 use crate::core::providers::base::{GlobalPoolManager, HttpMethod, header};
 use crate::core::traits::{
-    ProviderConfig as _, provider::llm_provider::trait_definition::LLMProvider,
+    ProviderConfig as _, error_mapper::trait_def::ErrorMapper,
+    provider::llm_provider::trait_definition::LLMProvider,
 };
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse, FinishReason},
+    requests::{ChatRequest, EmbeddingRequest, ImageGenerationRequest},
+    responses::{
+        ChatChunk, ChatResponse, EmbeddingData, EmbeddingResponse, FinishReason, ImageData,
+        ImageGenerationResponse,
+    },
 };
 
 /// Static capabilities for Cloudflare provider
 const CLOUDFLARE_CAPABILITIES: &[ProviderCapability] = &[
     ProviderCapability::ChatCompletion,
     ProviderCapability::ChatCompletionStream,
+    ProviderCapability::Embeddings,
+    ProviderCapability::ImageGeneration,
 ];
 
+/// Image model used when a request names none
+const DEFAULT_IMAGE_MODEL: &str = "@cf/stabilityai/stable-diffusion-xl-base-1.0";
+
 /// Cloudflare Workers AI provider implementation
 #[derive(Debug, Clone)]
 pub struct CloudflareProvider {
@@ -54,10 +68,14 @@ impl CloudflareProvider {
             .iter()
             .filter_map(|id| get_model_info(id))
             .map(|info| {
-                let capabilities = vec![
-                    ProviderCapability::ChatCompletion,
-                    ProviderCapability::ChatCompletionStream,
-                ];
+                let capabilities = match info.task {
+                    ModelTask::Chat => vec![
+                        ProviderCapability::ChatCompletion,
+                        ProviderCapability::ChatCompletionStream,
+                    ],
+                    ModelTask::Embeddings => vec![ProviderCapability::Embeddings],
+                    ModelTask::ImageGeneration => vec![ProviderCapability::ImageGeneration],
+                };
 
                 ModelInfo {
                     id: format!("cloudflare/{}", info.model_id),
@@ -99,22 +117,30 @@ impl CloudflareProvider {
         Self::new(config).await
     }
 
-    /// Execute an HTTP request
-    async fn execute_request(
-        &self,
-        endpoint: &str,
-        body: serde_json::Value,
-    ) -> Result<serde_json::Value, CloudflareError> {
+    /// URL of a model's run endpoint
+    ///
+    /// Model IDs keep their `@cf/{author}/{name}` path segments:
+    /// `{api_base}/accounts/{account_id}/ai/run/@cf/{author}/{name}`.
+    fn run_url(&self, model: &str) -> Result<String, CloudflareError> {
         let account_id = self.config.get_account_id().ok_or_else(|| {
             CloudflareError::ConfigurationError("Account ID is required".to_string())
         })?;
 
-        let url = format!(
+        Ok(format!(
             "{}/accounts/{}/ai/run/{}",
-            self.config.get_api_base(),
+            self.config.get_api_base().trim_end_matches('/'),
             account_id,
-            endpoint
-        );
+            model.strip_prefix("cloudflare/").unwrap_or(model)
+        ))
+    }
+
+    /// Run a model, returning the successful response
+    async fn send_request(
+        &self,
+        model: &str,
+        body: serde_json::Value,
+    ) -> Result<reqwest::Response, CloudflareError> {
+        let url = self.run_url(model)?;
 
         let mut headers = Vec::with_capacity(2);
         if let Some(api_token) = self.config.get_api_token() {
@@ -128,6 +154,23 @@ impl CloudflareProvider {
             .await
             .map_err(|e| CloudflareError::NetworkError(e.to_string()))?;
 
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(CloudflareErrorMapper.map_http_error(status.as_u16(), &body));
+        }
+
+        Ok(response)
+    }
+
+    /// Execute an HTTP request
+    async fn execute_request(
+        &self,
+        endpoint: &str,
+        body: serde_json::Value,
+    ) -> Result<serde_json::Value, CloudflareError> {
+        let response = self.send_request(endpoint, body).await?;
+
         let response_bytes = response
             .bytes()
             .await
@@ -137,6 +180,89 @@ impl CloudflareProvider {
             .map_err(|e| CloudflareError::ApiError(format!("Failed to parse response: {}", e)))
     }
 
+    /// Surface the `errors` of a response with `success: false`
+    fn check_success(response: &serde_json::Value) -> Result<(), CloudflareError> {
+        if response["success"].as_bool() != Some(false) {
+            return Ok(());
+        }
+
+        let message = response["errors"]
+            .as_array()
+            .and_then(|errors| errors.first())
+            .and_then(|error| error["message"].as_str())
+            .unwrap_or("Unknown error");
+        Err(CloudflareError::WorkersAIError(message.to_string()))
+    }
+
+    /// Convert an embedding model response (`result.data`, one vector per input)
+    fn transform_embedding_response(
+        response: serde_json::Value,
+        model: &str,
+    ) -> Result<EmbeddingResponse, CloudflareError> {
+        Self::check_success(&response)?;
+
+        let vectors = response["result"]["data"].as_array().ok_or_else(|| {
+            CloudflareError::ApiError("Missing embeddings in response".to_string())
+        })?;
+
+        let data = vectors
+            .iter()
+            .enumerate()
+            .map(|(index, vector)| {
+                let embedding = vector
+                    .as_array()
+                    .map(|values| {
+                        values
+                            .iter()
+                            .filter_map(|v| v.as_f64())
+                            .map(|v| v as f32)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                EmbeddingData {
+                    object: "embedding".to_string(),
+                    index: index as u32,
+                    embedding,
+                }
+            })
+            .collect();
+
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data,
+            model: model.to_string(),
+            usage: None,
+            embeddings: None,
+        })
+    }
+
+    /// Transform an image request into a text-to-image model input
+    ///
+    /// `size` ("WxH") becomes `width` and `height`; extra parameters such as
+    /// `negative_prompt`, `num_steps`, `guidance` and `seed` pass through.
+    fn transform_image_request(
+        request: &ImageGenerationRequest,
+    ) -> Result<serde_json::Value, CloudflareError> {
+        let mut body = serde_json::json!({ "prompt": request.prompt });
+
+        if let Some(size) = &request.size {
+            let (width, height) = size
+                .split_once('x')
+                .and_then(|(w, h)| Some((w.parse::<u32>().ok()?, h.parse::<u32>().ok()?)))
+                .ok_or_else(|| {
+                    CloudflareError::InvalidRequestError(format!("Invalid image size: {}", size))
+                })?;
+            body["width"] = serde_json::json!(width);
+            body["height"] = serde_json::json!(height);
+        }
+
+        for (key, value) in &request.extra_params {
+            body[key] = value.clone();
+        }
+
+        Ok(body)
+    }
+
     /// Transform OpenAI-style request to Cloudflare format
     fn transform_to_cloudflare_format(&self, request: &ChatRequest) -> serde_json::Value {
         // Cloudflare uses a simpler format
@@ -257,12 +383,14 @@ impl LLMProvider for CloudflareProvider {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 finish_reason: Some(FinishReason::Stop),
                 logprobs: None,
             }],
             usage: None, // Cloudflare doesn't provide usage stats
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -312,12 +440,14 @@ impl LLMProvider for CloudflareProvider {
                     function_call: None,
                     tool_calls: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 finish_reason: Some(FinishReason::Stop),
                 logprobs: None,
             }],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -341,13 +471,85 @@ impl LLMProvider for CloudflareProvider {
 
     async fn embeddings(
         &self,
-        _request: EmbeddingRequest,
+        request: EmbeddingRequest,
         _context: RequestContext,
     ) -> Result<EmbeddingResponse, Self::Error> {
-        // Cloudflare also supports embeddings models, but we'll implement text generation first
-        Err(CloudflareError::InvalidRequestError(
-            "Embeddings are not yet implemented for Cloudflare provider".to_string(),
-        ))
+        debug!("Cloudflare embedding request: model={}", request.model);
+
+        if get_model_task(&request.model) != ModelTask::Embeddings {
+            return Err(CloudflareError::InvalidRequestError(format!(
+                "Model {} is not an embedding model",
+                request.model
+            )));
+        }
+
+        let texts: Vec<&String> = request.input.iter().collect();
+        let body = serde_json::json!({ "text": texts });
+
+        let response = self.execute_request(&request.model, body).await?;
+        Self::transform_embedding_response(response, &request.model)
+    }
+
+    async fn image_generation(
+        &self,
+        request: ImageGenerationRequest,
+        _context: RequestContext,
+    ) -> Result<ImageGenerationResponse, Self::Error> {
+        let model = request.model.as_deref().unwrap_or(DEFAULT_IMAGE_MODEL);
+        debug!("Cloudflare image request: model={}", model);
+
+        if get_model_task(model) != ModelTask::ImageGeneration {
+            return Err(CloudflareError::InvalidRequestError(format!(
+                "Model {} is not an image generation model",
+                model
+            )));
+        }
+        if request.n.is_some_and(|n| n != 1) {
+            return Err(CloudflareError::InvalidRequestError(
+                "Workers AI generates one image per request".to_string(),
+            ));
+        }
+        if request.response_format.as_deref() == Some("url") {
+            return Err(CloudflareError::InvalidRequestError(
+                "Workers AI returns image data; use response_format b64_json".to_string(),
+            ));
+        }
+
+        let body = Self::transform_image_request(&request)?;
+        let response = self.send_request(model, body).await?;
+
+        let is_json = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("application/json"));
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| CloudflareError::NetworkError(e.to_string()))?;
+
+        // Stable Diffusion models return PNG bytes; others wrap base64 in JSON
+        let b64_json = if is_json {
+            let response: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| {
+                CloudflareError::ApiError(format!("Failed to parse response: {}", e))
+            })?;
+            Self::check_success(&response)?;
+            response["result"]["image"]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| CloudflareError::ApiError("Missing image in response".to_string()))?
+        } else {
+            STANDARD.encode(&bytes)
+        };
+
+        Ok(ImageGenerationResponse {
+            created: chrono::Utc::now().timestamp() as u64,
+            data: vec![ImageData {
+                url: None,
+                b64_json: Some(b64_json),
+                revised_prompt: None,
+            }],
+        })
     }
 
     async fn health_check(&self) -> HealthStatus {
@@ -425,6 +627,74 @@ mod tests {
     fn test_capabilities() {
         assert!(CLOUDFLARE_CAPABILITIES.contains(&ProviderCapability::ChatCompletion));
         assert!(CLOUDFLARE_CAPABILITIES.contains(&ProviderCapability::ChatCompletionStream));
+        assert!(CLOUDFLARE_CAPABILITIES.contains(&ProviderCapability::Embeddings));
+        assert!(CLOUDFLARE_CAPABILITIES.contains(&ProviderCapability::ImageGeneration));
+    }
+
+    #[tokio::test]
+    async fn test_run_url() {
+        let provider = CloudflareProvider::with_credentials("account123", "token")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            provider
+                .run_url("cloudflare/@cf/baai/bge-base-en-v1.5")
+                .unwrap(),
+            "https://api.cloudflare.com/client/v4/accounts/account123/ai/run/@cf/baai/bge-base-en-v1.5"
+        );
+    }
+
+    #[test]
+    fn test_transform_embedding_response() {
+        let response = serde_json::json!({
+            "result": {"shape": [2, 3], "data": [[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]]},
+            "success": true,
+            "errors": []
+        });
+
+        let embeddings = CloudflareProvider::transform_embedding_response(
+            response,
+            "@cf/baai/bge-small-en-v1.5",
+        )
+        .unwrap();
+        assert_eq!(embeddings.data.len(), 2);
+        assert_eq!(embeddings.data[1].index, 1);
+        assert_eq!(embeddings.data[1].embedding.len(), 3);
+
+        let failed = serde_json::json!({
+            "result": null,
+            "success": false,
+            "errors": [{"code": 5006, "message": "Error: oneOf at '/' not met"}]
+        });
+        assert!(matches!(
+            CloudflareProvider::transform_embedding_response(failed, "@cf/baai/bge-small-en-v1.5"),
+            Err(CloudflareError::WorkersAIError(_))
+        ));
+    }
+
+    #[test]
+    fn test_transform_image_request() {
+        let mut request = ImageGenerationRequest {
+            prompt: "A lighthouse at dusk".to_string(),
+            model: Some(DEFAULT_IMAGE_MODEL.to_string()),
+            n: None,
+            size: Some("1024x768".to_string()),
+            quality: None,
+            response_format: None,
+            style: None,
+            user: None,
+            extra_params: HashMap::from([("num_steps".to_string(), serde_json::json!(20))]),
+        };
+
+        let body = CloudflareProvider::transform_image_request(&request).unwrap();
+        assert_eq!(body["prompt"], "A lighthouse at dusk");
+        assert_eq!(body["width"], 1024);
+        assert_eq!(body["height"], 768);
+        assert_eq!(body["num_steps"], 20);
+
+        request.size = Some("large".to_string());
+        assert!(CloudflareProvider::transform_image_request(&request).is_err());
     }
 
     #[test]
@@ -450,6 +720,7 @@ mod tests {
                 tool_calls: None,
                 tool_call_id: None,
                 thinking: None,
+                cache_control: None,
             }],
             temperature: Some(0.7),
             max_tokens: Some(100),
@@ -0,0 +1,410 @@
+//! Cohere v2 Chat Transformation
+//!
+//! Maps the unified chat types onto `/v2/chat` and back.
+
+use serde_json::{Map, Value, json};
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::{
+        ChatMessage, ChatRequest, ContentPart, FunctionCall, MessageContent, MessageRole, ToolCall,
+        ToolChoice,
+    },
+    responses::{ChatChoice, ChatResponse, FinishReason, Usage},
+};
+
+/// OpenAI parameters understood by `/v2/chat`
+pub const COHERE_CHAT_PARAMS: &[&str] = &[
+    "temperature",
+    "max_tokens",
+    "max_completion_tokens",
+    "top_p",
+    "stop",
+    "seed",
+    "frequency_penalty",
+    "presence_penalty",
+    "stream",
+    "tools",
+    "tool_choice",
+    "response_format",
+    "logprobs",
+];
+
+/// Strip the routing prefix so `cohere/command-r` becomes `command-r`
+pub fn normalize_model(model: &str) -> &str {
+    model.strip_prefix("cohere/").unwrap_or(model)
+}
+
+/// Cohere v2 chat transformation
+pub struct CohereChatTransformation;
+
+impl CohereChatTransformation {
+    /// Build the `/v2/chat` request body
+    pub fn transform_request(request: &ChatRequest) -> Result<Value, ProviderError> {
+        let messages = request
+            .messages
+            .iter()
+            .map(Self::transform_message)
+            .collect::<Vec<_>>();
+
+        let mut body = Map::new();
+        body.insert("model".to_string(), json!(normalize_model(&request.model)));
+        body.insert("messages".to_string(), json!(messages));
+        body.insert("stream".to_string(), json!(request.stream));
+
+        if let Some(temperature) = request.temperature {
+            body.insert("temperature".to_string(), json!(temperature));
+        }
+        if let Some(max_tokens) = request.max_completion_tokens.or(request.max_tokens) {
+            body.insert("max_tokens".to_string(), json!(max_tokens));
+        }
+        if let Some(top_p) = request.top_p {
+            body.insert("p".to_string(), json!(top_p));
+        }
+        if let Some(stop) = &request.stop {
+            body.insert("stop_sequences".to_string(), json!(stop));
+        }
+        if let Some(seed) = request.seed {
+            body.insert("seed".to_string(), json!(seed));
+        }
+        if let Some(frequency_penalty) = request.frequency_penalty {
+            body.insert("frequency_penalty".to_string(), json!(frequency_penalty));
+        }
+        if let Some(presence_penalty) = request.presence_penalty {
+            body.insert("presence_penalty".to_string(), json!(presence_penalty));
+        }
+        if let Some(logprobs) = request.logprobs {
+            body.insert("logprobs".to_string(), json!(logprobs));
+        }
+
+        // Cohere's v2 tool definitions use the OpenAI shape
+        if let Some(tools) = &request.tools {
+            body.insert(
+                "tools".to_string(),
+                serde_json::to_value(tools)
+                    .map_err(|e| ProviderError::serialization("cohere", e.to_string()))?,
+            );
+        }
+
+        if let Some(tool_choice) = &request.tool_choice {
+            match tool_choice {
+                ToolChoice::String(choice) => match choice.as_str() {
+                    "required" => {
+                        body.insert("tool_choice".to_string(), json!("REQUIRED"));
+                    }
+                    "none" => {
+                        body.insert("tool_choice".to_string(), json!("NONE"));
+                    }
+                    _ => {}
+                },
+                ToolChoice::Specific { .. } => {
+                    return Err(ProviderError::not_supported(
+                        "cohere",
+                        "Cohere does not support forcing a specific tool",
+                    ));
+                }
+            }
+        }
+
+        if let Some(format) = &request.response_format {
+            match format.format_type.as_str() {
+                "json_object" => {
+                    body.insert(
+                        "response_format".to_string(),
+                        json!({"type": "json_object"}),
+                    );
+                }
+                "json_schema" => {
+                    let schema = format
+                        .json_schema
+                        .as_ref()
+                        .map(|s| s.get("schema").cloned().unwrap_or_else(|| s.clone()));
+                    let mut response_format = json!({"type": "json_object"});
+                    if let Some(schema) = schema {
+                        response_format["json_schema"] = schema;
+                    }
+                    body.insert("response_format".to_string(), response_format);
+                }
+                _ => {}
+            }
+        }
+
+        // Native parameters such as `k` or `safety_mode`
+        for (key, value) in &request.extra_params {
+            body.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+
+        Ok(Value::Object(body))
+    }
+
+    fn transform_message(message: &ChatMessage) -> Value {
+        let role = match message.role {
+            MessageRole::System => "system",
+            MessageRole::User => "user",
+            MessageRole::Assistant => "assistant",
+            MessageRole::Tool | MessageRole::Function => "tool",
+        };
+
+        let content = match &message.content {
+            Some(MessageContent::Text(text)) => json!(text),
+            Some(MessageContent::Parts(parts)) => {
+                let parts: Vec<Value> = parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        ContentPart::Text { text, .. } => {
+                            Some(json!({"type": "text", "text": text}))
+                        }
+                        ContentPart::ImageUrl { image_url } => Some(json!({
+                            "type": "image_url",
+                            "image_url": {"url": image_url.url},
+                        })),
+                        _ => None,
+                    })
+                    .collect();
+                json!(parts)
+            }
+            None => Value::Null,
+        };
+
+        let mut value = json!({ "role": role });
+        if !content.is_null() {
+            value["content"] = content;
+        }
+
+        if let Some(tool_calls) = &message.tool_calls {
+            value["tool_calls"] = json!(tool_calls);
+        }
+        if let Some(tool_call_id) = &message.tool_call_id {
+            value["tool_call_id"] = json!(tool_call_id);
+        }
+
+        value
+    }
+
+    /// Convert a `/v2/chat` response
+    pub fn transform_response(response: Value, model: &str) -> Result<ChatResponse, ProviderError> {
+        let message = response.get("message").ok_or_else(|| {
+            ProviderError::response_parsing("cohere", "Missing 'message' in response")
+        })?;
+
+        let text = message
+            .get("content")
+            .and_then(|c| c.as_array())
+            .map(|parts| {
+                parts
+                    .iter()
+                    .filter(|p| p.get("type").and_then(|t| t.as_str()) == Some("text"))
+                    .filter_map(|p| p.get("text").and_then(|t| t.as_str()))
+                    .collect::<Vec<_>>()
+                    .join("")
+            })
+            .unwrap_or_default();
+
+        let tool_calls = message
+            .get("tool_calls")
+            .and_then(|calls| calls.as_array())
+            .filter(|calls| !calls.is_empty())
+            .map(|calls| calls.iter().map(Self::parse_tool_call).collect::<Vec<_>>());
+
+        let finish_reason = response
+            .get("finish_reason")
+            .and_then(|r| r.as_str())
+            .map(parse_finish_reason);
+
+        Ok(ChatResponse {
+            id: response
+                .get("id")
+                .and_then(|id| id.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            object: "chat.completion".to_string(),
+            created: chrono::Utc::now().timestamp(),
+            model: normalize_model(model).to_string(),
+            choices: vec![ChatChoice {
+                index: 0,
+                message: ChatMessage {
+                    role: MessageRole::Assistant,
+                    content: Some(MessageContent::Text(text)),
+                    tool_calls,
+                    ..Default::default()
+                },
+                finish_reason,
+                logprobs: None,
+            }],
+            usage: response.get("usage").and_then(parse_usage),
+            system_fingerprint: None,
+            provider_specific_fields: None,
+        })
+    }
+
+    fn parse_tool_call(call: &Value) -> ToolCall {
+        let function = call.get("function").cloned().unwrap_or_default();
+        ToolCall {
+            id: call
+                .get("id")
+                .and_then(|id| id.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            tool_type: "function".to_string(),
+            function: FunctionCall {
+                name: function
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                arguments: match function.get("arguments") {
+                    Some(Value::String(args)) => args.clone(),
+                    Some(other) => other.to_string(),
+                    None => "{}".to_string(),
+                },
+            },
+        }
+    }
+}
+
+/// Map Cohere's upper-case finish reasons
+pub fn parse_finish_reason(reason: &str) -> FinishReason {
+    match reason {
+        "MAX_TOKENS" => FinishReason::Length,
+        "TOOL_CALL" => FinishReason::ToolCalls,
+        _ => FinishReason::Stop,
+    }
+}
+
+/// Read `usage.tokens`, falling back to `usage.billed_units`
+pub fn parse_usage(usage: &Value) -> Option<Usage> {
+    let tokens = usage.get("tokens").or_else(|| usage.get("billed_units"))?;
+    let count = |key: &str| {
+        tokens
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .map(|v| v as u32)
+            .unwrap_or(0)
+    };
+    Some(Usage::new(count("input_tokens"), count("output_tokens")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::ResponseFormat;
+
+    fn user(text: &str) -> ChatMessage {
+        ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("cohere/command-r-plus-08-2024");
+        request.messages = vec![
+            ChatMessage {
+                role: MessageRole::System,
+                content: Some(MessageContent::Text("Be brief".to_string())),
+                ..Default::default()
+            },
+            user("Hello"),
+        ];
+        request.top_p = Some(0.9);
+        request.stop = Some(vec!["END".to_string()]);
+        request.max_completion_tokens = Some(100);
+        request.tool_choice = Some(ToolChoice::String("required".to_string()));
+
+        let body = CohereChatTransformation::transform_request(&request).unwrap();
+        assert_eq!(body["model"], "command-r-plus-08-2024");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][1]["content"], "Hello");
+        assert!((body["p"].as_f64().unwrap() - 0.9).abs() < 1e-6);
+        assert_eq!(body["stop_sequences"][0], "END");
+        assert_eq!(body["max_tokens"], 100);
+        assert_eq!(body["tool_choice"], "REQUIRED");
+    }
+
+    #[test]
+    fn test_transform_request_json_schema() {
+        let mut request = ChatRequest::new("command-r");
+        request.messages = vec![user("JSON please")];
+        request.response_format = Some(ResponseFormat {
+            format_type: "json_schema".to_string(),
+            json_schema: Some(json!({"name": "x", "schema": {"type": "object"}})),
+            response_type: None,
+        });
+
+        let body = CohereChatTransformation::transform_request(&request).unwrap();
+        assert_eq!(body["response_format"]["type"], "json_object");
+        assert_eq!(body["response_format"]["json_schema"]["type"], "object");
+    }
+
+    #[test]
+    fn test_transform_tool_result_message() {
+        let message = ChatMessage {
+            role: MessageRole::Tool,
+            content: Some(MessageContent::Text("{\"temp\":20}".to_string())),
+            tool_call_id: Some("call_1".to_string()),
+            ..Default::default()
+        };
+
+        let value = CohereChatTransformation::transform_message(&message);
+        assert_eq!(value["role"], "tool");
+        assert_eq!(value["tool_call_id"], "call_1");
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "id": "c14c80c3",
+            "finish_reason": "COMPLETE",
+            "message": {
+                "role": "assistant",
+                "content": [{"type": "text", "text": "Hello!"}]
+            },
+            "usage": {
+                "billed_units": {"input_tokens": 5, "output_tokens": 2},
+                "tokens": {"input_tokens": 71, "output_tokens": 2}
+            }
+        });
+
+        let response = CohereChatTransformation::transform_response(raw, "command-r").unwrap();
+        assert_eq!(response.id, "c14c80c3");
+        assert_eq!(response.first_content(), Some("Hello!"));
+        assert_eq!(response.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert_eq!(response.usage.unwrap().prompt_tokens, 71);
+    }
+
+    #[test]
+    fn test_transform_response_tool_calls() {
+        let raw = json!({
+            "id": "abc",
+            "finish_reason": "TOOL_CALL",
+            "message": {
+                "role": "assistant",
+                "tool_plan": "I will look up the weather",
+                "tool_calls": [{
+                    "id": "get_weather_1",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}
+                }]
+            }
+        });
+
+        let response = CohereChatTransformation::transform_response(raw, "command-r").unwrap();
+        assert_eq!(
+            response.choices[0].finish_reason,
+            Some(FinishReason::ToolCalls)
+        );
+        let calls = response.first_tool_calls().unwrap();
+        assert_eq!(calls[0].id, "get_weather_1");
+        assert_eq!(calls[0].function.arguments, "{\"city\":\"Paris\"}");
+    }
+
+    #[test]
+    fn test_parse_finish_reason() {
+        assert_eq!(parse_finish_reason("COMPLETE"), FinishReason::Stop);
+        assert_eq!(parse_finish_reason("STOP_SEQUENCE"), FinishReason::Stop);
+        assert_eq!(parse_finish_reason("MAX_TOKENS"), FinishReason::Length);
+        assert_eq!(parse_finish_reason("TOOL_CALL"), FinishReason::ToolCalls);
+    }
+}
@@ -0,0 +1,73 @@
+//! Cohere Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(CohereConfig {});
+
+impl CohereConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `COHERE_*`, falling back to `CO_API_KEY` as used by Cohere's SDKs.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("cohere");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("CO_API_KEY").ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for CohereConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("cohere")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cohere_config_default_api_base() {
+        let config = CohereConfig::new("cohere");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.cohere.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_cohere_validate_missing_api_key() {
+        let mut config = CohereConfig::new("cohere");
+        config.base.api_key = None;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_provider_config_trait() {
+        let mut config = CohereConfig::new("cohere");
+        config.base.api_key = Some("co-test".to_string());
+
+        assert!(config.validate().is_ok());
+        assert_eq!(config.api_key(), Some("co-test"));
+        assert_eq!(config.api_base(), Some("https://api.cohere.com"));
+        assert_eq!(config.max_retries(), 3);
+    }
+}
@@ -0,0 +1,146 @@
+//! Cohere v2 Embed Transformation
+
+use serde_json::{Value, json};
+
+use super::chat::normalize_model;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::EmbeddingRequest,
+    responses::{EmbeddingData, EmbeddingResponse, Usage},
+};
+
+/// `input_type` used when the request does not specify one
+pub const DEFAULT_INPUT_TYPE: &str = "search_document";
+
+/// Cohere v2 embed transformation
+pub struct CohereEmbedTransformation;
+
+impl CohereEmbedTransformation {
+    /// Map the unified `task_type` onto Cohere's `input_type`
+    ///
+    /// Accepts both Cohere names and the Vertex-style upper-case task types.
+    pub fn input_type(task_type: Option<&str>) -> &'static str {
+        match task_type.map(|t| t.to_lowercase()).as_deref() {
+            Some("search_query") | Some("retrieval_query") => "search_query",
+            Some("classification") => "classification",
+            Some("clustering") => "clustering",
+            Some("image") => "image",
+            _ => DEFAULT_INPUT_TYPE,
+        }
+    }
+
+    /// Build the `/v2/embed` request body
+    pub fn transform_request(request: &EmbeddingRequest) -> Value {
+        let mut body = json!({
+            "model": normalize_model(&request.model),
+            "texts": request.input.to_vec(),
+            "input_type": Self::input_type(request.task_type.as_deref()),
+            "embedding_types": ["float"],
+        });
+
+        if let Some(dimensions) = request.dimensions {
+            body["output_dimension"] = json!(dimensions);
+        }
+
+        body
+    }
+
+    /// Convert a `/v2/embed` response
+    pub fn transform_response(
+        response: Value,
+        model: &str,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        let vectors: Vec<Vec<f32>> = response
+            .pointer("/embeddings/float")
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| ProviderError::response_parsing("cohere", e.to_string()))?
+            .ok_or_else(|| {
+                ProviderError::response_parsing("cohere", "Missing 'embeddings.float' in response")
+            })?;
+
+        let data = vectors
+            .into_iter()
+            .enumerate()
+            .map(|(index, embedding)| EmbeddingData {
+                object: "embedding".to_string(),
+                index: index as u32,
+                embedding,
+            })
+            .collect();
+
+        let usage = response
+            .pointer("/meta/billed_units/input_tokens")
+            .and_then(|t| t.as_f64())
+            .map(|tokens| Usage::new(tokens as u32, 0));
+
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data,
+            model: normalize_model(model).to_string(),
+            usage,
+            embeddings: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::EmbeddingInput;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_transform_request() {
+        let request = EmbeddingRequest {
+            model: "cohere/embed-english-v3.0".to_string(),
+            input: EmbeddingInput::Text("hello".to_string()),
+            user: None,
+            encoding_format: None,
+            dimensions: None,
+            task_type: Some("RETRIEVAL_QUERY".to_string()),
+            extra_params: HashMap::new(),
+        };
+
+        let body = CohereEmbedTransformation::transform_request(&request);
+        assert_eq!(body["model"], "embed-english-v3.0");
+        assert_eq!(body["texts"][0], "hello");
+        assert_eq!(body["input_type"], "search_query");
+        assert_eq!(body["embedding_types"][0], "float");
+    }
+
+    #[test]
+    fn test_default_input_type() {
+        assert_eq!(
+            CohereEmbedTransformation::input_type(None),
+            "search_document"
+        );
+        assert_eq!(
+            CohereEmbedTransformation::input_type(Some("clustering")),
+            "clustering"
+        );
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "id": "da6e531f",
+            "embeddings": {"float": [[0.1, 0.2], [0.3, 0.4]]},
+            "texts": ["a", "b"],
+            "meta": {"billed_units": {"input_tokens": 2}}
+        });
+
+        let response =
+            CohereEmbedTransformation::transform_response(raw, "embed-english-v3.0").unwrap();
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].index, 1);
+        assert_eq!(response.usage.unwrap().prompt_tokens, 2);
+    }
+
+    #[test]
+    fn test_transform_response_missing_embeddings() {
+        let result = CohereEmbedTransformation::transform_response(json!({}), "embed-english-v3.0");
+        assert!(result.is_err());
+    }
+}
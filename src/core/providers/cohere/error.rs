@@ -0,0 +1,64 @@
+//! Cohere Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Cohere API
+#[derive(Debug)]
+pub struct CohereErrorMapper;
+
+impl ErrorMapper<ProviderError> for CohereErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let message = extract_error_message(response_body);
+
+        match status_code {
+            400 | 422 => ProviderError::invalid_request("cohere", message),
+            401 | 403 => ProviderError::authentication("cohere", message),
+            404 => ProviderError::model_not_found("cohere", message),
+            429 => ProviderError::rate_limit("cohere", None),
+            _ => ProviderError::api_error("cohere", status_code, message),
+        }
+    }
+}
+
+/// Cohere reports errors as `{"message": "..."}`
+pub(crate) fn extract_error_message(response_body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_body)
+        .ok()
+        .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+        .unwrap_or_else(|| response_body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cohere_error_mapper_401() {
+        let err = CohereErrorMapper.map_http_error(401, r#"{"message":"invalid api token"}"#);
+        match err {
+            ProviderError::Authentication { message, .. } => {
+                assert_eq!(message, "invalid api token")
+            }
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cohere_error_mapper_400() {
+        let err = CohereErrorMapper.map_http_error(400, r#"{"message":"invalid request"}"#);
+        assert!(matches!(err, ProviderError::InvalidRequest { .. }));
+    }
+
+    #[test]
+    fn test_cohere_error_mapper_429() {
+        let err = CohereErrorMapper.map_http_error(429, "");
+        assert!(matches!(err, ProviderError::RateLimit { .. }));
+    }
+
+    #[test]
+    fn test_cohere_error_mapper_500() {
+        let err = CohereErrorMapper.map_http_error(500, "internal");
+        assert!(matches!(err, ProviderError::ApiError { .. }));
+    }
+}
@@ -0,0 +1,16 @@
+//! Cohere Provider
+//!
+//! Native Cohere v2 API integration: chat (with streaming and tool calls),
+//! embeddings via `/v2/embed`, and document reranking via `/v2/rerank`.
+
+pub mod chat;
+pub mod config;
+pub mod embed;
+pub mod error;
+pub mod provider;
+pub mod rerank;
+pub mod streaming;
+
+pub use config::CohereConfig;
+pub use error::CohereErrorMapper;
+pub use provider::CohereProvider;
@@ -0,0 +1,344 @@
+//! Cohere Provider Implementation
+
+use async_trait::async_trait;
+use futures::Stream;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::core::providers::base::{
+    GlobalPoolManager, HeaderPair, HttpMethod, get_pricing_db, header, header_owned,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::rerank::{RerankRequest, RerankResponse};
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::{ChatRequest, EmbeddingRequest},
+    responses::{ChatChunk, ChatResponse, EmbeddingResponse},
+};
+
+use super::chat::{COHERE_CHAT_PARAMS, CohereChatTransformation, normalize_model};
+use super::embed::CohereEmbedTransformation;
+use super::rerank::CohereRerankTransformation;
+use super::streaming::create_cohere_stream;
+use super::{CohereConfig, CohereErrorMapper};
+
+#[derive(Debug, Clone)]
+pub struct CohereProvider {
+    config: CohereConfig,
+    pool_manager: Arc<GlobalPoolManager>,
+    supported_models: Vec<ModelInfo>,
+}
+
+impl CohereProvider {
+    /// Generate headers for Cohere API requests
+    fn get_request_headers(&self) -> Vec<HeaderPair> {
+        let mut headers = Vec::with_capacity(2);
+
+        if let Some(api_key) = &self.config.base.api_key {
+            headers.push(header("Authorization", format!("Bearer {}", api_key)));
+        }
+
+        for (key, value) in &self.config.base.headers {
+            headers.push(header_owned(key.clone(), value.clone()));
+        }
+
+        headers
+    }
+
+    pub fn new(config: CohereConfig) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration("cohere", e))?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration("cohere", e.to_string()))?,
+        );
+
+        Ok(Self {
+            config,
+            pool_manager,
+            supported_models: Self::build_models(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, ProviderError> {
+        Self::new(CohereConfig::from_env())
+    }
+
+    /// Models with known pricing, tagged by endpoint
+    fn build_models() -> Vec<ModelInfo> {
+        let pricing_db = get_pricing_db();
+        let mut model_ids = pricing_db.get_provider_models("cohere");
+        model_ids.sort();
+
+        model_ids
+            .iter()
+            .filter_map(|id| {
+                let mut info = pricing_db.to_model_info(id, "cohere")?;
+                info.id = normalize_model(id).to_string();
+                let mode = pricing_db.get_model_info(id).and_then(|p| p.mode.clone());
+                info.capabilities = match mode.as_deref() {
+                    Some("embedding") => vec![ProviderCapability::Embeddings],
+                    Some("rerank") => vec![ProviderCapability::Rerank],
+                    _ => vec![
+                        ProviderCapability::ChatCompletion,
+                        ProviderCapability::ChatCompletionStream,
+                        ProviderCapability::ToolCalling,
+                    ],
+                };
+                info.supports_streaming = mode.as_deref().is_none_or(|m| m == "chat");
+                Some(info)
+            })
+            .collect()
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}{}",
+            self.config
+                .base
+                .get_effective_api_base("cohere")
+                .trim_end_matches('/'),
+            path
+        )
+    }
+
+    /// POST a JSON body and map non-success statuses through the error mapper
+    async fn post(&self, path: &str, body: Value) -> Result<reqwest::Response, ProviderError> {
+        let response = self
+            .pool_manager
+            .execute_request(
+                &self.endpoint(path),
+                HttpMethod::POST,
+                self.get_request_headers(),
+                Some(body),
+            )
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(CohereErrorMapper.map_http_error(status.as_u16(), &error_text));
+        }
+
+        Ok(response)
+    }
+
+    async fn post_json(&self, path: &str, body: Value) -> Result<Value, ProviderError> {
+        self.post(path, body)
+            .await?
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("cohere", e.to_string()))
+    }
+
+    /// Rerank documents by relevance to a query via `/v2/rerank`
+    pub async fn rerank(&self, request: RerankRequest) -> Result<RerankResponse, ProviderError> {
+        let body = CohereRerankTransformation::transform_request(&request)?;
+        let response = self.post_json("/v2/rerank", body).await?;
+        CohereRerankTransformation::transform_response(response, &request)
+    }
+}
+
+#[async_trait]
+impl LLMProvider for CohereProvider {
+    type Config = CohereConfig;
+    type Error = ProviderError;
+    type ErrorMapper = CohereErrorMapper;
+
+    fn name(&self) -> &'static str {
+        "cohere"
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        &[
+            ProviderCapability::ChatCompletion,
+            ProviderCapability::ChatCompletionStream,
+            ProviderCapability::Embeddings,
+            ProviderCapability::ToolCalling,
+            ProviderCapability::Rerank,
+        ]
+    }
+
+    fn models(&self) -> &[ModelInfo] {
+        &self.supported_models
+    }
+
+    fn supports_model(&self, model: &str) -> bool {
+        let model = normalize_model(model);
+        self.supported_models.iter().any(|m| m.id == model)
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        COHERE_CHAT_PARAMS
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        let mut mapped = HashMap::new();
+
+        for (key, value) in params {
+            let key = match key.as_str() {
+                "top_p" => "p".to_string(),
+                "stop" => "stop_sequences".to_string(),
+                "max_completion_tokens" => "max_tokens".to_string(),
+                _ => key,
+            };
+            mapped.insert(key, value);
+        }
+
+        Ok(mapped)
+    }
+
+    async fn transform_request(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        CohereChatTransformation::transform_request(&request)
+    }
+
+    async fn transform_response(
+        &self,
+        raw_response: &[u8],
+        model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        let response: Value = serde_json::from_slice(raw_response)
+            .map_err(|e| ProviderError::response_parsing("cohere", e.to_string()))?;
+        CohereChatTransformation::transform_response(response, model)
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        CohereErrorMapper
+    }
+
+    async fn chat_completion(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        let mut body = CohereChatTransformation::transform_request(&request)?;
+        body["stream"] = Value::Bool(false);
+
+        let response = self.post_json("/v2/chat", body).await?;
+        CohereChatTransformation::transform_response(response, &request.model)
+    }
+
+    async fn chat_completion_stream(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ChatChunk, Self::Error>> + Send>>, Self::Error>
+    {
+        let mut body = CohereChatTransformation::transform_request(&request)?;
+        body["stream"] = Value::Bool(true);
+
+        let response = self.post("/v2/chat", body).await?;
+        Ok(Box::pin(create_cohere_stream(
+            response.bytes_stream(),
+            normalize_model(&request.model),
+        )))
+    }
+
+    async fn embeddings(
+        &self,
+        request: EmbeddingRequest,
+        _context: RequestContext,
+    ) -> Result<EmbeddingResponse, Self::Error> {
+        let body = CohereEmbedTransformation::transform_request(&request);
+        let response = self.post_json("/v2/embed", body).await?;
+        CohereEmbedTransformation::transform_response(response, &request.model)
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        if self.config.base.api_key.is_some() {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+
+    async fn calculate_cost(
+        &self,
+        model: &str,
+        input_tokens: u32,
+        output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        let usage = crate::core::providers::base::pricing::Usage {
+            prompt_tokens: input_tokens,
+            completion_tokens: output_tokens,
+            total_tokens: input_tokens + output_tokens,
+            reasoning_tokens: None,
+        };
+
+        Ok(get_pricing_db().calculate(normalize_model(model), &usage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_provider() -> CohereProvider {
+        let mut config = CohereConfig::new("cohere");
+        config.base.api_key = Some("co-test".to_string());
+        CohereProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = CohereConfig::new("cohere");
+        config.base.api_key = None;
+        assert!(CohereProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_capabilities_include_rerank() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "cohere");
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::Rerank)
+        );
+    }
+
+    #[test]
+    fn test_models_tagged_by_endpoint() {
+        let provider = test_provider();
+        let rerank = provider
+            .models()
+            .iter()
+            .find(|m| m.id == "rerank-v3.5")
+            .unwrap();
+        assert_eq!(rerank.capabilities, vec![ProviderCapability::Rerank]);
+        assert!(!rerank.supports_streaming);
+
+        assert!(provider.supports_model("cohere/command-r-08-2024"));
+        assert!(!provider.supports_model("gpt-4"));
+    }
+
+    #[tokio::test]
+    async fn test_map_openai_params() {
+        let provider = test_provider();
+        let mut params = HashMap::new();
+        params.insert("top_p".to_string(), serde_json::json!(0.5));
+        params.insert("stop".to_string(), serde_json::json!(["x"]));
+
+        let mapped = provider
+            .map_openai_params(params, "command-r")
+            .await
+            .unwrap();
+        assert!(mapped.contains_key("p"));
+        assert!(mapped.contains_key("stop_sequences"));
+    }
+}
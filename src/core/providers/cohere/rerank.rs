@@ -0,0 +1,149 @@
+//! Cohere v2 Rerank Transformation
+
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use super::chat::normalize_model;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::rerank::{RerankRequest, RerankResponse, RerankResult, RerankUsage};
+
+/// Cohere v2 rerank transformation
+pub struct CohereRerankTransformation;
+
+impl CohereRerankTransformation {
+    /// Build the `/v2/rerank` request body
+    pub fn transform_request(request: &RerankRequest) -> Result<Value, ProviderError> {
+        if request.documents.is_empty() {
+            return Err(ProviderError::invalid_request(
+                "cohere",
+                "Rerank requires at least one document",
+            ));
+        }
+
+        let documents: Vec<&str> = request.documents.iter().map(|d| d.get_text()).collect();
+
+        let mut body = json!({
+            "model": normalize_model(&request.model),
+            "query": request.query,
+            "documents": documents,
+        });
+
+        if let Some(top_n) = request.top_n {
+            body["top_n"] = json!(top_n);
+        }
+        if let Some(max_tokens_per_doc) = request.extra_params.get("max_tokens_per_doc") {
+            body["max_tokens_per_doc"] = max_tokens_per_doc.clone();
+        }
+
+        Ok(body)
+    }
+
+    /// Convert a `/v2/rerank` response
+    ///
+    /// v2 never echoes documents back, so they are re-attached from the request
+    /// when `return_documents` is set.
+    pub fn transform_response(
+        response: Value,
+        request: &RerankRequest,
+    ) -> Result<RerankResponse, ProviderError> {
+        let return_documents = request.return_documents.unwrap_or(true);
+
+        let results = response
+            .get("results")
+            .and_then(|r| r.as_array())
+            .ok_or_else(|| {
+                ProviderError::response_parsing("cohere", "Missing 'results' in rerank response")
+            })?
+            .iter()
+            .map(|r| {
+                let index = r.get("index").and_then(|i| i.as_u64()).unwrap_or(0) as usize;
+                RerankResult {
+                    index,
+                    relevance_score: r
+                        .get("relevance_score")
+                        .and_then(|s| s.as_f64())
+                        .unwrap_or(0.0),
+                    document: if return_documents {
+                        request.documents.get(index).cloned()
+                    } else {
+                        None
+                    },
+                }
+            })
+            .collect();
+
+        let usage = response
+            .pointer("/meta/billed_units")
+            .map(|units| RerankUsage {
+                search_units: units
+                    .get("search_units")
+                    .and_then(|s| s.as_f64())
+                    .map(|s| s as u32),
+                ..Default::default()
+            });
+
+        Ok(RerankResponse {
+            id: response
+                .get("id")
+                .and_then(|id| id.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            results,
+            model: normalize_model(&request.model).to_string(),
+            usage,
+            meta: HashMap::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rerank::RerankDocument;
+
+    fn request() -> RerankRequest {
+        RerankRequest {
+            model: "cohere/rerank-v3.5".to_string(),
+            query: "capital of France".to_string(),
+            documents: vec![
+                RerankDocument::text("Berlin is in Germany"),
+                RerankDocument::text("Paris is the capital of France"),
+            ],
+            top_n: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let body = CohereRerankTransformation::transform_request(&request()).unwrap();
+        assert_eq!(body["model"], "rerank-v3.5");
+        assert_eq!(body["documents"][1], "Paris is the capital of France");
+        assert_eq!(body["top_n"], 1);
+    }
+
+    #[test]
+    fn test_transform_request_requires_documents() {
+        let mut req = request();
+        req.documents.clear();
+        assert!(CohereRerankTransformation::transform_request(&req).is_err());
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "id": "07734bd2",
+            "results": [{"index": 1, "relevance_score": 0.98}],
+            "meta": {"billed_units": {"search_units": 1}}
+        });
+
+        let response = CohereRerankTransformation::transform_response(raw, &request()).unwrap();
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].index, 1);
+        assert_eq!(
+            response.results[0].document.as_ref().unwrap().get_text(),
+            "Paris is the capital of France"
+        );
+        assert_eq!(response.usage.unwrap().search_units, Some(1));
+    }
+}
@@ -0,0 +1,248 @@
+//! Cohere v2 Streaming Support
+//!
+//! Cohere streams typed SSE events (`content-delta`, `tool-call-start`,
+//! `message-end`, ...) rather than OpenAI chunks, so it plugs its own
+//! transformer into the unified SSE parser.
+
+use bytes::Bytes;
+use futures::Stream;
+use serde_json::Value;
+use std::pin::Pin;
+
+use super::chat::{parse_finish_reason, parse_usage};
+use crate::core::providers::base::sse::{SSETransformer, UnifiedSSEStream};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::MessageRole,
+    responses::{
+        ChatChunk, ChatDelta, ChatStreamChoice, FinishReason, FunctionCallDelta, ToolCallDelta,
+        Usage,
+    },
+};
+
+/// Transformer for Cohere v2 stream events
+#[derive(Debug, Clone)]
+pub struct CohereStreamTransformer {
+    id: String,
+    model: String,
+}
+
+impl CohereStreamTransformer {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            id: format!("chatcmpl-{}", uuid::Uuid::new_v4().simple()),
+            model: model.into(),
+        }
+    }
+
+    fn chunk(
+        &self,
+        delta: ChatDelta,
+        finish_reason: Option<FinishReason>,
+        usage: Option<Usage>,
+    ) -> ChatChunk {
+        ChatChunk {
+            id: self.id.clone(),
+            object: "chat.completion.chunk".to_string(),
+            created: chrono::Utc::now().timestamp(),
+            model: self.model.clone(),
+            choices: vec![ChatStreamChoice {
+                index: 0,
+                delta,
+                finish_reason,
+                logprobs: None,
+            }],
+            usage,
+            system_fingerprint: None,
+            provider_specific_fields: None,
+        }
+    }
+}
+
+fn empty_delta() -> ChatDelta {
+    ChatDelta {
+        role: None,
+        content: None,
+        thinking: None,
+        tool_calls: None,
+        function_call: None,
+    }
+}
+
+impl SSETransformer for CohereStreamTransformer {
+    fn provider_name(&self) -> &'static str {
+        "cohere"
+    }
+
+    fn is_end_marker(&self, _data: &str) -> bool {
+        // The stream simply closes after `message-end`
+        false
+    }
+
+    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
+        let event: Value = serde_json::from_str(data).map_err(|e| {
+            ProviderError::response_parsing("cohere", format!("Failed to parse SSE JSON: {}", e))
+        })?;
+
+        let event_type = event.get("type").and_then(|t| t.as_str()).unwrap_or("");
+        let message = event.pointer("/delta/message");
+
+        let chunk = match event_type {
+            "message-start" => Some(self.chunk(
+                ChatDelta {
+                    role: Some(MessageRole::Assistant),
+                    ..empty_delta()
+                },
+                None,
+                None,
+            )),
+            "content-delta" => message
+                .and_then(|m| m.pointer("/content/text"))
+                .and_then(|t| t.as_str())
+                .map(|text| {
+                    self.chunk(
+                        ChatDelta {
+                            content: Some(text.to_string()),
+                            ..empty_delta()
+                        },
+                        None,
+                        None,
+                    )
+                }),
+            "tool-call-start" | "tool-call-delta" => {
+                let call = message.and_then(|m| m.get("tool_calls"));
+                let function = call.and_then(|c| c.get("function"));
+                let tool_call = ToolCallDelta {
+                    index: event.get("index").and_then(|i| i.as_u64()).unwrap_or(0) as u32,
+                    id: call
+                        .and_then(|c| c.get("id"))
+                        .and_then(|id| id.as_str())
+                        .map(String::from),
+                    tool_type: (event_type == "tool-call-start").then(|| "function".to_string()),
+                    function: Some(FunctionCallDelta {
+                        name: function
+                            .and_then(|f| f.get("name"))
+                            .and_then(|n| n.as_str())
+                            .map(String::from),
+                        arguments: function
+                            .and_then(|f| f.get("arguments"))
+                            .and_then(|a| a.as_str())
+                            .map(String::from),
+                    }),
+                };
+                Some(self.chunk(
+                    ChatDelta {
+                        tool_calls: Some(vec![tool_call]),
+                        ..empty_delta()
+                    },
+                    None,
+                    None,
+                ))
+            }
+            "message-end" => {
+                let delta = event.get("delta");
+                let finish_reason = delta
+                    .and_then(|d| d.get("finish_reason"))
+                    .and_then(|r| r.as_str())
+                    .map(parse_finish_reason)
+                    .or(Some(FinishReason::Stop));
+                let usage = delta.and_then(|d| d.get("usage")).and_then(parse_usage);
+                Some(self.chunk(empty_delta(), finish_reason, usage))
+            }
+            // content-start/end, tool-plan-delta, tool-call-end and citations carry no delta
+            _ => None,
+        };
+
+        Ok(chunk)
+    }
+}
+
+pub type CohereStream = UnifiedSSEStream<
+    Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>,
+    CohereStreamTransformer,
+>;
+
+/// Helper function to create a Cohere stream
+pub fn create_cohere_stream(
+    stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
+    model: &str,
+) -> CohereStream {
+    UnifiedSSEStream::new(Box::pin(stream), CohereStreamTransformer::new(model))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{StreamExt, stream};
+
+    #[tokio::test]
+    async fn test_cohere_stream_text() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "event: message-start\ndata: {\"id\":\"abc\",\"type\":\"message-start\",\"delta\":{\"message\":{\"role\":\"assistant\"}}}\n\n",
+            )),
+            Ok(Bytes::from(
+                "event: content-start\ndata: {\"type\":\"content-start\",\"index\":0,\"delta\":{\"message\":{\"content\":{\"type\":\"text\",\"text\":\"\"}}}}\n\nevent: content-delta\ndata: {\"type\":\"content-delta\",\"index\":0,\"delta\":{\"message\":{\"content\":{\"text\":\"Hello\"}}}}\n\n",
+            )),
+            Ok(Bytes::from(
+                "event: message-end\ndata: {\"type\":\"message-end\",\"delta\":{\"finish_reason\":\"COMPLETE\",\"usage\":{\"tokens\":{\"input_tokens\":10,\"output_tokens\":1}}}}\n\n",
+            )),
+        ];
+
+        let mut cohere_stream = create_cohere_stream(stream::iter(test_data), "command-r");
+
+        let start = cohere_stream.next().await.unwrap().unwrap();
+        assert_eq!(start.choices[0].delta.role, Some(MessageRole::Assistant));
+
+        let content = cohere_stream.next().await.unwrap().unwrap();
+        assert_eq!(content.choices[0].delta.content.as_deref(), Some("Hello"));
+
+        let end = cohere_stream.next().await.unwrap().unwrap();
+        assert_eq!(end.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert_eq!(end.usage.unwrap().total_tokens, 11);
+
+        assert!(cohere_stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_tool_call_events() {
+        let transformer = CohereStreamTransformer::new("command-r");
+
+        let start = transformer
+            .transform_chunk(
+                r#"{"type":"tool-call-start","index":0,"delta":{"message":{"tool_calls":{"id":"get_weather_1","type":"function","function":{"name":"get_weather","arguments":""}}}}}"#,
+            )
+            .unwrap()
+            .unwrap();
+        let call = &start.choices[0].delta.tool_calls.as_ref().unwrap()[0];
+        assert_eq!(call.id.as_deref(), Some("get_weather_1"));
+        assert_eq!(
+            call.function.as_ref().unwrap().name.as_deref(),
+            Some("get_weather")
+        );
+
+        let delta = transformer
+            .transform_chunk(
+                r#"{"type":"tool-call-delta","index":0,"delta":{"message":{"tool_calls":{"function":{"arguments":"{\"city\""}}}}}"#,
+            )
+            .unwrap()
+            .unwrap();
+        let call = &delta.choices[0].delta.tool_calls.as_ref().unwrap()[0];
+        assert!(call.id.is_none());
+        assert_eq!(
+            call.function.as_ref().unwrap().arguments.as_deref(),
+            Some("{\"city\"")
+        );
+    }
+
+    #[test]
+    fn test_ignored_events() {
+        let transformer = CohereStreamTransformer::new("command-r");
+        let result = transformer
+            .transform_chunk(
+                r#"{"type":"tool-plan-delta","delta":{"message":{"tool_plan":"I will"}}}"#,
+            )
+            .unwrap();
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,312 @@
+//! Custom OpenAI-compatible Client
+//!
+//! Requests are forwarded as they are, without renaming or dropping fields,
+//! to whatever server `api_base` points at.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::core::providers::base::pricing::Usage as PricingUsage;
+use crate::core::providers::base::{HttpMethod, OpenAICompatibleClient, OpenAICompatibleProvider};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::{ModelInfo, ProviderCapability};
+
+use super::CustomOpenAIConfig;
+
+/// Custom OpenAI-compatible client logic
+#[derive(Debug, Clone)]
+pub struct CustomOpenAIClient;
+
+impl CustomOpenAIClient {
+    /// Describe a model served by the endpoint
+    ///
+    /// Nothing is known about self-hosted models beyond their id, so they are
+    /// assumed to support streaming and tools and are priced at zero.
+    pub fn model_info(id: &str) -> ModelInfo {
+        ModelInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            provider: "custom_openai".to_string(),
+            max_context_length: 0,
+            max_output_length: None,
+            supports_streaming: true,
+            supports_tools: true,
+            supports_multimodal: false,
+            input_cost_per_1k_tokens: Some(0.0),
+            output_cost_per_1k_tokens: Some(0.0),
+            currency: "USD".to_string(),
+            capabilities: vec![
+                ProviderCapability::ChatCompletion,
+                ProviderCapability::ChatCompletionStream,
+                ProviderCapability::Embeddings,
+                ProviderCapability::ToolCalling,
+            ],
+            created_at: None,
+            updated_at: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Model ids from a `GET /models` response
+    pub fn parse_model_list(response: &Value) -> Vec<String> {
+        response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .map(|models| {
+                models
+                    .iter()
+                    .filter_map(|m| m.get("id").and_then(|id| id.as_str()))
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl OpenAICompatibleClient for CustomOpenAIClient {
+    type Config = CustomOpenAIConfig;
+
+    const NAME: &'static str = "custom_openai";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::Embeddings,
+        ProviderCapability::ToolCalling,
+    ];
+
+    // Everything is forwarded unchanged
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "n",
+        "stop",
+        "stream",
+        "stream_options",
+        "presence_penalty",
+        "frequency_penalty",
+        "logit_bias",
+        "logprobs",
+        "top_logprobs",
+        "tools",
+        "tool_choice",
+        "parallel_tool_calls",
+        "response_format",
+        "seed",
+        "user",
+    ];
+
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &[];
+
+    const MAX_TOKENS_FIELD: Option<&'static str> = None;
+
+    fn new(_config: &CustomOpenAIConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+
+    /// Configured `models` are advertised as-is;
+    /// [`refresh_models`](OpenAICompatibleProvider::refresh_models) can fill
+    /// the list from the endpoint instead
+    fn supported_models(config: &CustomOpenAIConfig) -> Vec<ModelInfo> {
+        config
+            .models
+            .iter()
+            .map(|id| Self::model_info(id))
+            .collect()
+    }
+
+    /// With no known model list any `custom_openai/` model is accepted
+    fn supports_model(models: &[ModelInfo], model: &str) -> bool {
+        if models.is_empty() {
+            return model.starts_with("custom_openai/");
+        }
+        let model = Self::normalize_model(model);
+        models.iter().any(|m| m.id == model)
+    }
+
+    /// Self-hosted servers may be down, so the endpoint is probed
+    fn health_path(_config: &CustomOpenAIConfig) -> Option<&'static str> {
+        Some("/models")
+    }
+
+    /// Self-hosted inference has no per-token price
+    fn calculate_cost(_model: &str, _usage: &PricingUsage) -> f64 {
+        0.0
+    }
+}
+
+impl OpenAICompatibleProvider<CustomOpenAIClient> {
+    /// List the models the endpoint currently serves
+    pub async fn fetch_models(&self) -> Result<Vec<ModelInfo>, ProviderError> {
+        let response = self.send("/models", HttpMethod::GET, None).await?;
+        let value: Value = response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("custom_openai", e.to_string()))?;
+
+        Ok(CustomOpenAIClient::parse_model_list(&value)
+            .iter()
+            .map(|id| CustomOpenAIClient::model_info(id))
+            .collect())
+    }
+
+    /// Replace the model list with the endpoint's current one
+    pub async fn refresh_models(&mut self) -> Result<(), ProviderError> {
+        let models = self.fetch_models().await?;
+        self.set_models(models);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::custom_openai::CustomOpenAIProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{
+        ChatMessage, ChatRequest, EmbeddingInput, EmbeddingRequest, MessageContent, MessageRole,
+    };
+    use serde_json::json;
+
+    fn test_provider(models: &[&str]) -> CustomOpenAIProvider {
+        let mut config = CustomOpenAIConfig::new("custom_openai");
+        config.base.api_base = Some("http://localhost:8000/v1/".to_string());
+        config.models = models.iter().map(|m| m.to_string()).collect();
+        CustomOpenAIProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("custom_openai/meta-llama/Llama-3.1-8B-Instruct");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(256);
+        request.logprobs = Some(true);
+        request.extra_params.insert("top_k".to_string(), json!(40));
+
+        let body = CustomOpenAIClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "meta-llama/Llama-3.1-8B-Instruct");
+        assert_eq!(body["max_completion_tokens"], 256);
+        assert_eq!(body["logprobs"], true);
+        assert_eq!(body["top_k"], 40);
+    }
+
+    #[test]
+    fn test_transform_response_without_object() {
+        let raw = br#"{
+            "id": "chatcmpl-3f2a",
+            "created": 1718000000,
+            "model": "qwen2.5-7b-instruct",
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hi"}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 9, "completion_tokens": 1, "total_tokens": 10}
+        }"#;
+
+        let response = CustomOpenAIClient::transform_chat_response(raw).unwrap();
+        assert_eq!(response.object, "chat.completion");
+        assert_eq!(response.first_content(), Some("Hi"));
+    }
+
+    #[test]
+    fn test_transform_embedding_request() {
+        let request = EmbeddingRequest {
+            model: "custom_openai/BAAI/bge-m3".to_string(),
+            input: EmbeddingInput::Text("hello".to_string()),
+            encoding_format: None,
+            dimensions: None,
+            user: None,
+            task_type: Some("RETRIEVAL_QUERY".to_string()),
+            extra_params: HashMap::new(),
+        };
+
+        let body = CustomOpenAIClient::transform_embedding_request(&request).unwrap();
+        assert_eq!(body["model"], "BAAI/bge-m3");
+        assert_eq!(body["input"], "hello");
+        assert!(body.get("task_type").is_none());
+    }
+
+    #[test]
+    fn test_transform_embedding_response() {
+        let response = json!({
+            "object": "list",
+            "data": [{"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}],
+            "model": "BAAI/bge-m3",
+            "usage": {"prompt_tokens": 3, "total_tokens": 3}
+        });
+
+        let parsed =
+            CustomOpenAIClient::transform_embedding_response(response, "BAAI/bge-m3").unwrap();
+        assert_eq!(parsed.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(parsed.usage.unwrap().prompt_tokens, 3);
+    }
+
+    #[test]
+    fn test_parse_model_list() {
+        let response = json!({
+            "object": "list",
+            "data": [
+                {"id": "qwen2.5-7b-instruct", "object": "model", "owned_by": "vllm"},
+                {"id": "BAAI/bge-m3", "object": "model"}
+            ]
+        });
+        assert_eq!(
+            CustomOpenAIClient::parse_model_list(&response),
+            vec!["qwen2.5-7b-instruct", "BAAI/bge-m3"]
+        );
+        assert!(CustomOpenAIClient::parse_model_list(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_provider_requires_api_base() {
+        let mut config = CustomOpenAIConfig::new("custom_openai");
+        config.base.api_base = None;
+        assert!(CustomOpenAIProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_without_api_key() {
+        let provider = test_provider(&[]);
+        assert!(provider.get_request_headers().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider(&[]);
+        assert_eq!(provider.name(), "custom_openai");
+        assert!(provider.supports_streaming());
+        assert!(provider.supports_model("custom_openai/anything"));
+        assert!(!provider.supports_model("gpt-4"));
+    }
+
+    #[test]
+    fn test_configured_models() {
+        let provider = test_provider(&["qwen2.5-7b-instruct"]);
+        assert_eq!(provider.models().len(), 1);
+        assert!(provider.supports_model("custom_openai/qwen2.5-7b-instruct"));
+        assert!(provider.supports_model("qwen2.5-7b-instruct"));
+        assert!(!provider.supports_model("custom_openai/other"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider(&[]);
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "http://localhost:8000/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider(&[]);
+        let cost = provider
+            .calculate_cost("custom_openai/qwen2.5-7b-instruct", 1000, 1000)
+            .await
+            .unwrap();
+        assert_eq!(cost, 0.0);
+    }
+}
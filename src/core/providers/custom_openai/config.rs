@@ -0,0 +1,137 @@
+//! Custom OpenAI-compatible Configuration
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::providers::base::BaseConfig;
+use crate::core::traits::ProviderConfig;
+
+/// Configuration for a generic OpenAI-compatible endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomOpenAIConfig {
+    #[serde(flatten)]
+    pub base: BaseConfig,
+
+    /// Models served by the endpoint; when empty they are discovered from
+    /// `GET /models`
+    #[serde(default)]
+    pub models: Vec<String>,
+}
+
+impl CustomOpenAIConfig {
+    /// Create configuration for a provider name
+    ///
+    /// Unlike the hosted providers there is no default endpoint, so `api_base`
+    /// stays unset unless `{PROVIDER}_API_BASE` is present.
+    pub fn new(provider: &str) -> Self {
+        Self {
+            base: BaseConfig::from_env(provider),
+            models: Vec::new(),
+        }
+    }
+
+    /// Create configuration from environment variables (`CUSTOM_OPENAI_*`)
+    ///
+    /// `CUSTOM_OPENAI_MODELS` is a comma-separated model list.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("custom_openai");
+        if let Ok(models) = std::env::var("CUSTOM_OPENAI_MODELS") {
+            config.models = models
+                .split(',')
+                .map(|m| m.trim().to_string())
+                .filter(|m| !m.is_empty())
+                .collect();
+        }
+        config
+    }
+}
+
+impl AsRef<BaseConfig> for CustomOpenAIConfig {
+    fn as_ref(&self) -> &BaseConfig {
+        &self.base
+    }
+}
+
+impl ProviderConfig for CustomOpenAIConfig {
+    /// Self-hosted servers usually run without authentication, so only the
+    /// endpoint is required
+    fn validate(&self) -> Result<(), String> {
+        let api_base = self
+            .base
+            .api_base
+            .as_deref()
+            .ok_or_else(|| "custom_openai API base is required".to_string())?;
+        if !api_base.starts_with("http://") && !api_base.starts_with("https://") {
+            return Err("custom_openai API base must start with http:// or https://".to_string());
+        }
+
+        if self.base.timeout == 0 {
+            return Err("Timeout must be greater than 0".to_string());
+        }
+
+        if self.base.max_retries > 10 {
+            return Err("Max retries should not exceed 10".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local_config() -> CustomOpenAIConfig {
+        let mut config = CustomOpenAIConfig::new("custom_openai");
+        config.base.api_base = Some("http://localhost:8000/v1".to_string());
+        config
+    }
+
+    #[test]
+    fn test_custom_openai_validate_without_api_key() {
+        let mut config = local_config();
+        config.base.api_key = None;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_custom_openai_validate_requires_api_base() {
+        let mut config = local_config();
+        config.base.api_base = None;
+        let result = config.validate();
+        assert!(result.unwrap_err().contains("API base"));
+    }
+
+    #[test]
+    fn test_custom_openai_validate_rejects_bad_scheme() {
+        let mut config = local_config();
+        config.base.api_base = Some("localhost:8000/v1".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_custom_openai_config_deserialize() {
+        let config: CustomOpenAIConfig = serde_json::from_value(serde_json::json!({
+            "api_base": "http://localhost:1234/v1",
+            "models": ["qwen2.5-7b-instruct"]
+        }))
+        .unwrap();
+        assert_eq!(config.models, vec!["qwen2.5-7b-instruct"]);
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,16 @@
+//! Custom OpenAI-compatible Provider
+//!
+//! Talks plain OpenAI wire format to any endpoint given by `api_base`, such as
+//! vLLM, LM Studio, llama.cpp server or LocalAI, so self-hosted deployments
+//! need configuration rather than a provider module of their own.
+
+pub mod client;
+pub mod config;
+
+pub use client::CustomOpenAIClient;
+pub use config::CustomOpenAIConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// Custom OpenAI-compatible provider
+pub type CustomOpenAIProvider = OpenAICompatibleProvider<CustomOpenAIClient>;
@@ -0,0 +1,264 @@
+//! DashScope Client
+//!
+//! How DashScope's compatible mode departs from OpenAI's chat completions.
+//! Qwen-specific fields such as `enable_thinking`, `enable_search` or `top_k`
+//! pass through `extra_params` untouched.
+
+use serde_json::{Map, Value, json};
+
+use crate::core::providers::base::OpenAICompatibleClient;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::ProviderCapability;
+
+use super::DashScopeConfig;
+
+/// DashScope API client logic
+#[derive(Debug, Clone)]
+pub struct DashScopeClient;
+
+impl DashScopeClient {
+    /// Rewrite content parts into the OpenAI shapes Qwen-VL accepts
+    ///
+    /// Inline base64 images (`type: image`) become `image_url` parts with a
+    /// data URL; anything else is left for the API to validate.
+    fn normalize_content_parts(messages: &mut [Value]) {
+        let parts = messages
+            .iter_mut()
+            .filter_map(|m| m.get_mut("content").and_then(|c| c.as_array_mut()))
+            .flatten();
+
+        for part in parts {
+            if part.get("type").and_then(|t| t.as_str()) != Some("image") {
+                continue;
+            }
+
+            let image_url = part.get("image_url").cloned().unwrap_or_else(|| {
+                let media_type = part
+                    .pointer("/source/media_type")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("image/jpeg");
+                let data = part
+                    .pointer("/source/data")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default();
+                let mut image_url = json!({
+                    "url": format!("data:{};base64,{}", media_type, data),
+                });
+                if let Some(detail) = part.get("detail") {
+                    image_url["detail"] = detail.clone();
+                }
+                image_url
+            });
+
+            *part = json!({ "type": "image_url", "image_url": image_url });
+        }
+    }
+}
+
+impl OpenAICompatibleClient for DashScopeClient {
+    type Config = DashScopeConfig;
+
+    const NAME: &'static str = "dashscope";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::ToolCalling,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "stop",
+        "n",
+        "stream",
+        "stream_options",
+        "frequency_penalty",
+        "presence_penalty",
+        "logprobs",
+        "top_logprobs",
+        "tools",
+        "tool_choice",
+        "parallel_tool_calls",
+        "response_format",
+        "seed",
+        "user",
+    ];
+
+    // Rejected by the compatible-mode endpoint instead of ignored
+    const UNSUPPORTED_FIELDS: &'static [&'static str] =
+        &["logit_bias", "functions", "function_call", "thinking"];
+
+    fn new(_config: &DashScopeConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+
+    fn prepare_request(body: &mut Map<String, Value>) {
+        if let Some(messages) = body.get_mut("messages").and_then(|m| m.as_array_mut()) {
+            Self::normalize_content_parts(messages);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::dashscope::DashScopeProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{
+        ChatMessage, ChatRequest, ContentPart, ImageSource, ImageUrl, MessageContent, MessageRole,
+    };
+
+    fn test_provider() -> DashScopeProvider {
+        let mut config = DashScopeConfig::new("dashscope");
+        config.base.api_key = Some("test-key".to_string());
+        DashScopeProvider::new(config).unwrap()
+    }
+
+    fn user(content: MessageContent) -> ChatMessage {
+        ChatMessage {
+            role: MessageRole::User,
+            content: Some(content),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("dashscope/qwen-plus");
+        request.messages = vec![user(MessageContent::Text("Hello".to_string()))];
+        request.max_completion_tokens = Some(256);
+        request
+            .extra_params
+            .insert("enable_thinking".to_string(), json!(false));
+
+        let body = DashScopeClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "qwen-plus");
+        assert_eq!(body["max_tokens"], 256);
+        assert!(body.get("max_completion_tokens").is_none());
+        assert_eq!(body["enable_thinking"], false);
+    }
+
+    #[test]
+    fn test_transform_request_vision() {
+        let mut request = ChatRequest::new("dashscope/qwen-vl-max");
+        request.messages = vec![user(MessageContent::Parts(vec![
+            ContentPart::Text {
+                text: "Compare these".to_string(),
+                cache_control: None,
+            },
+            ContentPart::ImageUrl {
+                image_url: ImageUrl {
+                    url: "https://example.com/a.png".to_string(),
+                    detail: None,
+                },
+            },
+            ContentPart::Image {
+                source: ImageSource {
+                    media_type: "image/png".to_string(),
+                    data: "iVBORw0KGgo=".to_string(),
+                },
+                detail: Some("high".to_string()),
+                image_url: None,
+            },
+        ]))];
+
+        let body = DashScopeClient::transform_chat_request(request).unwrap();
+        let parts = &body["messages"][0]["content"];
+        assert_eq!(parts[1]["image_url"]["url"], "https://example.com/a.png");
+        assert_eq!(parts[2]["type"], "image_url");
+        assert_eq!(
+            parts[2]["image_url"]["url"],
+            "data:image/png;base64,iVBORw0KGgo="
+        );
+        assert_eq!(parts[2]["image_url"]["detail"], "high");
+        assert!(parts[2].get("source").is_none());
+    }
+
+    #[test]
+    fn test_transform_response_tool_calls() {
+        let raw = br#"{
+            "id": "chatcmpl-6ada9ed2-7f33-9de2-8bb0-78bd4035025a",
+            "object": "chat.completion",
+            "created": 1735120033,
+            "model": "qwen-plus",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": "",
+                    "tool_calls": [{
+                        "id": "call_6596dafa2a6a46f7a217da",
+                        "type": "function",
+                        "function": {"name": "get_weather", "arguments": "{\"location\": \"Hangzhou\"}"}
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }],
+            "usage": {"prompt_tokens": 212, "completion_tokens": 19, "total_tokens": 231}
+        }"#;
+
+        let response = DashScopeClient::transform_chat_response(raw).unwrap();
+        let tool_calls = response.choices[0].message.tool_calls.as_ref().unwrap();
+        assert_eq!(tool_calls[0].function.name, "get_weather");
+        assert_eq!(response.usage.unwrap().total_tokens, 231);
+    }
+
+    #[test]
+    fn test_error_native_format() {
+        let err = DashScopeClient::map_http_error(
+            400,
+            r#"{"code":"InvalidParameter","message":"Range of input length should be [1, 30720]","request_id":"4c5e2a71"}"#,
+        );
+        match err {
+            ProviderError::InvalidRequest { message, .. } => {
+                assert_eq!(message, "Range of input length should be [1, 30720]")
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = DashScopeConfig::new("dashscope");
+        config.base.api_key = None;
+        assert!(DashScopeProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "dashscope");
+        assert!(provider.supports_streaming());
+        assert!(provider.supports_model("dashscope/qwen-plus"));
+        assert!(!provider.supports_model("gpt-4"));
+        assert!(
+            provider
+                .models()
+                .iter()
+                .any(|m| m.id == "qwen-vl-max" && m.supports_multimodal)
+        );
+        assert!(provider.models().iter().all(|m| m.provider == "dashscope"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://dashscope-intl.aliyuncs.com/compatible-mode/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("dashscope/qwen-plus", 1_000_000, 1_000_000)
+            .await
+            .unwrap();
+        assert!((cost - 1.6).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,68 @@
+//! DashScope Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(DashScopeConfig {});
+
+impl DashScopeConfig {
+    /// Create configuration from environment variables (`DASHSCOPE_*`)
+    ///
+    /// Defaults to the international endpoint; set `DASHSCOPE_API_BASE` to
+    /// `https://dashscope.aliyuncs.com/compatible-mode/v1` for mainland China.
+    pub fn from_env() -> Self {
+        Self::new("dashscope")
+    }
+}
+
+impl ProviderConfig for DashScopeConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("dashscope")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashscope_config_default_api_base() {
+        let config = DashScopeConfig::new("dashscope");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://dashscope-intl.aliyuncs.com/compatible-mode/v1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_dashscope_validate_missing_api_key() {
+        let mut config = DashScopeConfig::new("dashscope");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_dashscope_validate_success() {
+        let mut config = DashScopeConfig::new("dashscope");
+        config.base.api_key = Some("test-key".to_string());
+        assert!(config.validate().is_ok());
+    }
+}
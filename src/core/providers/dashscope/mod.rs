@@ -0,0 +1,15 @@
+//! Alibaba Cloud DashScope Provider
+//!
+//! Qwen models (including Qwen-VL) through DashScope's OpenAI-compatible mode
+//! endpoint, with streaming and tool calling
+
+pub mod client;
+pub mod config;
+
+pub use client::DashScopeClient;
+pub use config::DashScopeConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// DashScope provider
+pub type DashScopeProvider = OpenAICompatibleProvider<DashScopeClient>;
@@ -413,6 +413,7 @@ impl LLMProvider for DeepInfraProvider {
             choices: vec![], // TODO: Parse actual choices
             usage: None,     // TODO: Parse usage
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         Ok(chat_response)
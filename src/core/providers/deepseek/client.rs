@@ -6,6 +6,7 @@ use serde_json::{Value, json};
 
 use super::models::get_deepseek_registry;
 use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::thinking::{ThinkingContent, ThinkingUsage};
 use crate::core::types::{common::ModelInfo, requests::ChatRequest, responses::ChatResponse};
 
 /// DeepSeek API client logic
@@ -14,9 +15,22 @@ pub struct DeepSeekClient;
 impl DeepSeekClient {
     /// Request
     pub fn transform_chat_request(request: ChatRequest) -> Value {
+        // The API rejects reasoning sent back from earlier turns
+        let messages: Vec<Value> = request
+            .messages
+            .iter()
+            .map(|message| {
+                let mut value = serde_json::to_value(message).unwrap_or_default();
+                if let Some(obj) = value.as_object_mut() {
+                    obj.remove("thinking");
+                }
+                value
+            })
+            .collect();
+
         json!({
             "model": request.model,
-            "messages": request.messages,
+            "messages": messages,
             "temperature": request.temperature,
             "max_tokens": request.max_tokens,
             "top_p": request.top_p,
@@ -52,7 +66,8 @@ impl DeepSeekClient {
         }
 
         // First try direct deserialization
-        if let Ok(chat_response) = serde_json::from_value::<ChatResponse>(response.clone()) {
+        if let Ok(mut chat_response) = serde_json::from_value::<ChatResponse>(response.clone()) {
+            Self::attach_reasoning(&response, &mut chat_response);
             return Ok(chat_response);
         }
 
@@ -107,8 +122,40 @@ impl DeepSeekClient {
         }
 
         // Try deserialization again
-        serde_json::from_value(Value::Object(response_obj))
-            .map_err(|e| ProviderError::response_parsing("deepseek", e.to_string()))
+        let mut chat_response: ChatResponse =
+            serde_json::from_value(Value::Object(response_obj))
+                .map_err(|e| ProviderError::response_parsing("deepseek", e.to_string()))?;
+        Self::attach_reasoning(&response, &mut chat_response);
+        Ok(chat_response)
+    }
+
+    /// Copy `reasoning_content` and reasoning token usage into the response
+    ///
+    /// `deepseek-reasoner` returns its chain of thought next to `content`,
+    /// which the standard message type has no field for.
+    fn attach_reasoning(response: &Value, chat_response: &mut ChatResponse) {
+        let choices = response["choices"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (choice, raw) in chat_response.choices.iter_mut().zip(choices) {
+            if let Some(text) = raw["message"]["reasoning_content"]
+                .as_str()
+                .filter(|text| !text.is_empty())
+            {
+                choice.message.thinking = Some(ThinkingContent::text(text));
+            }
+        }
+
+        if let Some(usage) = chat_response.usage.as_mut() {
+            let reasoning_tokens = usage
+                .completion_tokens_details
+                .as_ref()
+                .and_then(|details| details.reasoning_tokens);
+            if let Some(tokens) = reasoning_tokens {
+                usage.thinking_usage = Some(ThinkingUsage::new(tokens).with_provider("deepseek"));
+            }
+        }
     }
 
     /// Model
@@ -149,6 +196,7 @@ mod tests {
                 tool_calls: None,
                 tool_call_id: None,
                 function_call: None,
+                cache_control: None,
             }],
             temperature: Some(0.7),
             max_tokens: Some(100),
@@ -181,6 +229,55 @@ mod tests {
         assert!((temp - 0.7).abs() < 0.001);
     }
 
+    #[test]
+    fn test_transform_reasoner_response() {
+        let response = json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1737000000,
+            "model": "deepseek-reasoner",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": "9.11 is smaller.",
+                    "reasoning_content": "Compare the tenths digit: 1 < 9."
+                },
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 12,
+                "completion_tokens": 40,
+                "total_tokens": 52,
+                "completion_tokens_details": {"reasoning_tokens": 32}
+            }
+        });
+
+        let chat_response = DeepSeekClient::transform_chat_response(response).unwrap();
+        let message = &chat_response.choices[0].message;
+        assert_eq!(
+            message.thinking.as_ref().and_then(|t| t.as_text()),
+            Some("Compare the tenths digit: 1 < 9.")
+        );
+
+        let usage = chat_response.usage.unwrap();
+        assert_eq!(usage.thinking_tokens(), Some(32));
+        assert_eq!(
+            usage.thinking_usage.unwrap().provider.as_deref(),
+            Some("deepseek")
+        );
+
+        // Reasoning is not sent back on the next turn
+        let request = ChatRequest {
+            model: "deepseek-reasoner".to_string(),
+            messages: vec![message.clone()],
+            ..Default::default()
+        };
+        let transformed = DeepSeekClient::transform_chat_request(request);
+        assert!(transformed["messages"][0].get("thinking").is_none());
+        assert_eq!(transformed["messages"][0]["content"], "9.11 is smaller.");
+    }
+
     #[test]
     fn test_supported_models() {
         let models = DeepSeekClient::supported_models();
@@ -1,25 +1,90 @@
 //! DeepSeek Streaming Support
 //!
 //! Uses the unified SSE parser for consistent streaming across providers.
+//! `deepseek-reasoner` streams its chain of thought as `reasoning_content`
+//! deltas before the answer; [`DeepSeekStreamTransformer`] turns those into
+//! thinking deltas.
 
 use bytes::Bytes;
 use futures::Stream;
+use serde_json::{Value, json};
 use std::pin::Pin;
 
-use crate::core::providers::base::sse::{OpenAICompatibleTransformer, UnifiedSSEStream};
+use crate::core::providers::base::sse::{
+    OpenAICompatibleTransformer, SSETransformer, UnifiedSSEStream,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::responses::ChatChunk;
+use crate::core::types::thinking::ThinkingUsage;
 
 /// DeepSeek uses OpenAI-compatible SSE format
 pub type DeepSeekStream = UnifiedSSEStream<
     Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>,
-    OpenAICompatibleTransformer,
+    DeepSeekStreamTransformer,
 >;
 
 /// Helper function to create DeepSeek stream
 pub fn create_deepseek_stream(
     stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
 ) -> DeepSeekStream {
-    let transformer = OpenAICompatibleTransformer::new("deepseek");
-    UnifiedSSEStream::new(Box::pin(stream), transformer)
+    UnifiedSSEStream::new(Box::pin(stream), DeepSeekStreamTransformer::new())
+}
+
+/// Transformer for DeepSeek stream chunks
+#[derive(Debug, Clone)]
+pub struct DeepSeekStreamTransformer {
+    inner: OpenAICompatibleTransformer,
+}
+
+impl DeepSeekStreamTransformer {
+    pub fn new() -> Self {
+        Self {
+            inner: OpenAICompatibleTransformer::new("deepseek"),
+        }
+    }
+}
+
+impl Default for DeepSeekStreamTransformer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SSETransformer for DeepSeekStreamTransformer {
+    fn provider_name(&self) -> &'static str {
+        "deepseek"
+    }
+
+    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
+        let mut value: Value = serde_json::from_str(data).map_err(|e| {
+            ProviderError::response_parsing("deepseek", format!("Failed to parse SSE JSON: {}", e))
+        })?;
+
+        if let Some(choices) = value.get_mut("choices").and_then(|c| c.as_array_mut()) {
+            for choice in choices {
+                if let Some(delta) = choice.get_mut("delta").and_then(|d| d.as_object_mut()) {
+                    if let Some(Value::String(reasoning)) = delta.remove("reasoning_content") {
+                        delta.insert("thinking".to_string(), json!({ "content": reasoning }));
+                    }
+                }
+            }
+        }
+
+        let mut chunk = self.inner.transform_chunk(&value.to_string())?;
+
+        // The final chunk carries usage, including reasoning tokens
+        if let Some(usage) = chunk.as_mut().and_then(|c| c.usage.as_mut()) {
+            let reasoning_tokens = usage
+                .completion_tokens_details
+                .as_ref()
+                .and_then(|details| details.reasoning_tokens);
+            if let Some(tokens) = reasoning_tokens {
+                usage.thinking_usage = Some(ThinkingUsage::new(tokens).with_provider("deepseek"));
+            }
+        }
+
+        Ok(chunk)
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +143,33 @@ mod tests {
         let end = deepseek_stream.next().await;
         assert!(end.is_none());
     }
+
+    #[tokio::test]
+    async fn test_deepseek_reasoner_stream() {
+        use futures::stream;
+
+        let test_data = vec![
+            Ok(Bytes::from(
+                "data: {\"id\":\"test-2\",\"object\":\"chat.completion.chunk\",\"created\":1737000000,\"model\":\"deepseek-reasoner\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":null,\"reasoning_content\":\"Compare digits.\"},\"finish_reason\":null}]}\n\n",
+            )),
+            Ok(Bytes::from(
+                "data: {\"id\":\"test-2\",\"object\":\"chat.completion.chunk\",\"created\":1737000000,\"model\":\"deepseek-reasoner\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"9.11\",\"reasoning_content\":null},\"finish_reason\":\"stop\"}],\"usage\":{\"prompt_tokens\":12,\"completion_tokens\":20,\"total_tokens\":32,\"completion_tokens_details\":{\"reasoning_tokens\":16}}}\n\n",
+            )),
+            Ok(Bytes::from("data: [DONE]\n\n")),
+        ];
+
+        let mut deepseek_stream = create_deepseek_stream(stream::iter(test_data));
+
+        let chunk = deepseek_stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.choices[0].delta.thinking_content(),
+            Some("Compare digits.")
+        );
+        assert!(chunk.choices[0].delta.content.is_none());
+
+        let chunk = deepseek_stream.next().await.unwrap().unwrap();
+        assert!(!chunk.choices[0].delta.has_thinking());
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("9.11"));
+        assert_eq!(chunk.usage.unwrap().thinking_tokens(), Some(16));
+    }
 }
@@ -12,10 +12,15 @@ use reqwest::{
 use serde_json::{Value, json};
 use tokio::time::timeout;
 
+use crate::core::providers::shared::{
+    DocumentTransformer, ImageTransformer, SchemaTransformer, ToolTransformer,
+};
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::{
-    requests::{ChatMessage, ChatRequest, ContentPart, MessageContent, MessageRole},
-    responses::{ChatChoice, ChatResponse, Usage},
+    requests::{
+        ChatMessage, ChatRequest, ContentPart, EmbeddingRequest, MessageContent, MessageRole,
+    },
+    responses::{ChatChoice, ChatResponse, EmbeddingData, EmbeddingResponse, Usage},
 };
 
 use super::config::GeminiConfig;
@@ -23,6 +28,7 @@ use super::error::{
     GeminiErrorMapper, gemini_auth_error, gemini_multimodal_error, gemini_network_error,
     gemini_parse_error,
 };
+use super::files::{GeminiFilesHandler, GeminiFilesUtils};
 
 /// Gemini API client
 #[derive(Debug, Clone)]
@@ -84,6 +90,31 @@ impl GeminiClient {
             .await
     }
 
+    /// Create embeddings with `batchEmbedContents`
+    pub async fn embed(
+        &self,
+        request: &EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        let body = self.transform_embedding_request(request);
+        let response = self
+            .send_request(&request.model, "batchEmbedContents", body)
+            .await?;
+        self.transform_embedding_response(response, &request.model)
+    }
+
+    /// Files API handler sharing this client's configuration
+    pub fn files(&self) -> GeminiFilesHandler {
+        GeminiFilesHandler::new(self.config.clone(), self.http_client.clone())
+    }
+
+    /// URI of a file uploaded through the Files API, if `url` is one
+    fn uploaded_file_uri<'a>(&self, url: &'a str) -> Option<&'a str> {
+        let files_url = GeminiFilesUtils::files_url(&self.config, "");
+        url.strip_prefix(files_url.as_str())
+            .filter(|rest| rest.starts_with('/'))
+            .map(|_| url)
+    }
+
     /// Request
     async fn send_request(
         &self,
@@ -227,10 +258,44 @@ impl GeminiClient {
 
     /// Request
     pub fn transform_chat_request(&self, request: &ChatRequest) -> Result<Value, ProviderError> {
-        let mut contents = Vec::new();
+        let mut contents: Vec<Value> = Vec::new();
+        let tool_call_names = ToolTransformer::tool_call_names(&request.messages);
 
         for message in &request.messages {
-            let content = self.transform_message_content(message)?;
+            // Tool results are functionResponse parts, the results of
+            // parallel calls parts of the same content
+            if message.role == MessageRole::Tool {
+                let name = message
+                    .tool_call_id
+                    .as_deref()
+                    .and_then(|id| tool_call_names.get(id).copied())
+                    .or(message.name.as_deref())
+                    .unwrap_or_default();
+                let part = ToolTransformer::to_gemini_function_response(name, message);
+                let responses = contents.last_mut().and_then(|last| {
+                    let parts = last["parts"].as_array_mut()?;
+                    parts
+                        .iter()
+                        .all(|part| part.get("functionResponse").is_some())
+                        .then_some(parts)
+                });
+                match responses {
+                    Some(parts) => parts.push(part),
+                    None => contents.push(json!({"role": "user", "parts": [part]})),
+                }
+                continue;
+            }
+
+            let mut content = self.transform_message_content(message)?;
+            // Tool calls follow the text they come with
+            if let Some(tool_calls) = &message.tool_calls {
+                content.retain(|part| part["text"] != "");
+                content.extend(
+                    tool_calls
+                        .iter()
+                        .map(ToolTransformer::to_gemini_function_call),
+                );
+            }
             let role = match message.role {
                 MessageRole::System => {
                     // Gemini doesn't directly support system role, need to convert to user message prefix
@@ -238,8 +303,7 @@ impl GeminiClient {
                 }
                 MessageRole::User => "user",
                 MessageRole::Assistant => "model",
-                MessageRole::Tool => "function", // Function call result
-                MessageRole::Function => "function", // Function call result
+                MessageRole::Tool | MessageRole::Function => "function", // Function call result
             };
 
             contents.push(json!({
@@ -292,6 +356,21 @@ impl GeminiClient {
             }
         }
 
+        // JSON mode, with the schema in Gemini's OpenAPI dialect
+        if let Some(format) = request.response_format.as_ref().filter(|f| f.is_json()) {
+            generation_config["responseMimeType"] = json!("application/json");
+            if let Some(schema) = format.schema() {
+                generation_config["responseSchema"] = SchemaTransformer::to_openapi_schema(schema);
+            }
+        }
+
+        if let Some(tools) = request.tools.as_ref().filter(|tools| !tools.is_empty()) {
+            gemini_request["tools"] = ToolTransformer::to_gemini_tools(tools);
+            if let Some(tool_choice) = &request.tool_choice {
+                gemini_request["toolConfig"] = ToolTransformer::to_gemini_tool_config(tool_choice);
+            }
+        }
+
         // Only add generationConfig if it has values (safely check if object is non-empty)
         if generation_config
             .as_object()
@@ -334,25 +413,24 @@ impl GeminiClient {
                 // Handle
                 for part in content_parts {
                     match part {
-                        ContentPart::Text { text } => {
+                        ContentPart::Text { text, .. } => {
                             parts.push(json!({
                                 "text": text
                             }));
                         }
                         ContentPart::ImageUrl { image_url } => {
                             // Gemini supports inline image data
-                            if image_url.url.starts_with("data:") {
-                                // parsedata URL
-                                if let Some((mime_type, data)) =
-                                    self.parse_data_url(&image_url.url)?
-                                {
-                                    parts.push(json!({
-                                        "inlineData": {
-                                            "mimeType": mime_type,
-                                            "data": data
-                                        }
-                                    }));
-                                }
+                            if let Some(inline) =
+                                part.image().as_ref().and_then(ImageTransformer::to_gemini)
+                            {
+                                parts.push(inline);
+                            } else if let Some(file_uri) = self.uploaded_file_uri(&image_url.url) {
+                                // File uploaded through the Files API
+                                parts.push(json!({
+                                    "fileData": {
+                                        "fileUri": file_uri
+                                    }
+                                }));
                             } else {
                                 // External image URL - Gemini doesn't support directly, need to download first
                                 return Err(gemini_multimodal_error(
@@ -360,24 +438,32 @@ impl GeminiClient {
                                 ));
                             }
                         }
-                        ContentPart::Audio { .. } => {
-                            return Err(gemini_multimodal_error(
-                                "Audio content not yet implemented",
-                            ));
+                        ContentPart::Audio { audio } => {
+                            if let Some(file_uri) = self.uploaded_file_uri(&audio.data) {
+                                parts.push(json!({
+                                    "fileData": {
+                                        "mimeType": audio.mime_type(),
+                                        "fileUri": file_uri
+                                    }
+                                }));
+                            } else {
+                                parts.push(json!({
+                                    "inlineData": {
+                                        "mimeType": audio.mime_type(),
+                                        "data": audio.data
+                                    }
+                                }));
+                            }
                         }
-                        ContentPart::Image { source, .. } => {
-                            // Handle
-                            parts.push(json!({
-                                "inlineData": {
-                                    "mimeType": source.media_type,
-                                    "data": source.data
-                                }
-                            }));
+                        ContentPart::Image { .. } => {
+                            if let Some(inline) =
+                                part.image().as_ref().and_then(ImageTransformer::to_gemini)
+                            {
+                                parts.push(inline);
+                            }
                         }
-                        ContentPart::Document { .. } => {
-                            return Err(gemini_multimodal_error(
-                                "Document content not yet supported in Gemini",
-                            ));
+                        ContentPart::Document { source, .. } => {
+                            parts.push(DocumentTransformer::to_gemini(source));
                         }
                         ContentPart::ToolResult { .. } => {
                             return Err(gemini_multimodal_error(
@@ -411,27 +497,72 @@ impl GeminiClient {
         Ok(parts)
     }
 
-    /// parsedata URL
-    fn parse_data_url(&self, data_url: &str) -> Result<Option<(String, String)>, ProviderError> {
-        if !data_url.starts_with("data:") {
-            return Ok(None);
-        }
-
-        let parts: Vec<&str> = data_url.splitn(2, ',').collect();
-        if parts.len() != 2 {
-            return Err(gemini_parse_error("Invalid data URL format"));
-        }
+    /// Build a `batchEmbedContents` body, one request per input
+    pub fn transform_embedding_request(&self, request: &EmbeddingRequest) -> Value {
+        let model = format!("models/{}", request.model);
+        let requests: Vec<Value> = request
+            .input
+            .iter()
+            .map(|text| {
+                let mut embed_request = json!({
+                    "model": model,
+                    "content": {
+                        "parts": [{ "text": text }]
+                    }
+                });
+                if let Some(task_type) = &request.task_type {
+                    embed_request["taskType"] = json!(task_type);
+                }
+                if let Some(dimensions) = request.dimensions {
+                    embed_request["outputDimensionality"] = json!(dimensions);
+                }
+                embed_request
+            })
+            .collect();
 
-        let header = parts[0];
-        let data = parts[1];
+        json!({ "requests": requests })
+    }
 
-        // Parse MIME type
-        let mime_parts: Vec<&str> = header.split(';').collect();
-        let mime_type = mime_parts[0]
-            .strip_prefix("data:")
-            .unwrap_or("application/octet-stream");
+    /// Transform a `batchEmbedContents` response
+    ///
+    /// The API does not report token usage for embeddings.
+    pub fn transform_embedding_response(
+        &self,
+        response: Value,
+        model: &str,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        let embeddings = response
+            .get("embeddings")
+            .and_then(|e| e.as_array())
+            .ok_or_else(|| gemini_parse_error("No embeddings in response"))?;
 
-        Ok(Some((mime_type.to_string(), data.to_string())))
+        let data = embeddings
+            .iter()
+            .enumerate()
+            .map(|(index, embedding)| {
+                let values = embedding
+                    .get("values")
+                    .and_then(|v| v.as_array())
+                    .ok_or_else(|| gemini_parse_error("Invalid embedding structure"))?;
+                Ok(EmbeddingData {
+                    object: "embedding".to_string(),
+                    index: index as u32,
+                    embedding: values
+                        .iter()
+                        .filter_map(|v| v.as_f64())
+                        .map(|v| v as f32)
+                        .collect(),
+                })
+            })
+            .collect::<Result<Vec<_>, ProviderError>>()?;
+
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data,
+            model: model.to_string(),
+            usage: None,
+            embeddings: None,
+        })
     }
 
     /// Response
@@ -454,12 +585,14 @@ impl GeminiClient {
                 .and_then(|p| p.as_array())
                 .ok_or_else(|| gemini_parse_error("Invalid candidate content structure"))?;
 
-            // Extract text content
+            // Extract text content and function calls
             let mut text_parts = Vec::new();
+            let mut tool_calls = Vec::new();
             for part in content {
                 if let Some(text) = part.get("text").and_then(|t| t.as_str()) {
                     text_parts.push(text);
                 }
+                tool_calls.extend(ToolTransformer::from_gemini_function_call(part));
             }
             let message_content = text_parts.join("");
 
@@ -475,21 +608,37 @@ impl GeminiClient {
                     _ => "stop",
                 })
                 .unwrap_or("stop");
+            // Gemini stops normally after calling functions
+            let finish_reason = if tool_calls.is_empty() {
+                finish_reason
+            } else {
+                "tool_calls"
+            };
 
             choices.push(ChatChoice {
                 index: index as u32,
                 message: crate::core::types::requests::ChatMessage {
                     role: MessageRole::Assistant,
-                    content: Some(MessageContent::Text(message_content)),
+                    content: if message_content.is_empty() && !tool_calls.is_empty() {
+                        None
+                    } else {
+                        Some(MessageContent::Text(message_content))
+                    },
                     thinking: None,
                     name: None,
-                    tool_calls: None,
+                    tool_calls: if tool_calls.is_empty() {
+                        None
+                    } else {
+                        Some(tool_calls)
+                    },
                     tool_call_id: None,
                     function_call: None,
+                    cache_control: None,
                 },
                 finish_reason: Some(match finish_reason {
                     "stop" => crate::core::types::responses::FinishReason::Stop,
                     "length" => crate::core::types::responses::FinishReason::Length,
+                    "tool_calls" => crate::core::types::responses::FinishReason::ToolCalls,
                     "content_filter" => crate::core::types::responses::FinishReason::ContentFilter,
                     _ => crate::core::types::responses::FinishReason::Stop,
                 }),
@@ -514,6 +663,9 @@ impl GeminiClient {
             prompt_tokens_details: None,
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         });
 
         // Use current timestamp, defaulting to 0 if system time is before UNIX_EPOCH
@@ -535,6 +687,7 @@ impl GeminiClient {
             choices,
             usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 }
@@ -555,12 +708,48 @@ mod tests {
         let config = GeminiConfig::new_google_ai("test-key");
         let client = GeminiClient::new(config).unwrap();
 
-        let data_url = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8/5+hHgAHggJ/PchI7wAAAABJRU5ErkJggg==";
-        let result = client.parse_data_url(data_url).unwrap();
+        let data = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8/5+hHgAHggJ/PchI7wAAAABJRU5ErkJggg==";
+        let message = ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(vec![
+                ContentPart::image_url(format!("data:image/png;base64,{}", data)),
+                // Without a media type, it is detected from the data
+                ContentPart::image_url(format!("data:;base64,{}", data)),
+            ])),
+            ..Default::default()
+        };
 
-        assert!(result.is_some());
-        let (mime_type, _data) = result.unwrap();
-        assert_eq!(mime_type, "image/png");
+        let parts = client.transform_message_content(&message).unwrap();
+        for part in &parts {
+            assert_eq!(part["inlineData"]["mimeType"], "image/png");
+            assert_eq!(part["inlineData"]["data"], data);
+        }
+    }
+
+    #[test]
+    fn test_audio_parts() {
+        let config = GeminiConfig::new_google_ai("test-key");
+        let client = GeminiClient::new(config).unwrap();
+        let file_uri = format!("{}/abc123", GeminiFilesUtils::files_url(&client.config, ""));
+
+        let message = ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(vec![
+                ContentPart::input_audio("wav", "UklGRiQAAABXQVZF"),
+                ContentPart::input_audio("audio/mpeg", file_uri.clone()),
+            ])),
+            ..Default::default()
+        };
+
+        let parts = client.transform_message_content(&message).unwrap();
+        assert_eq!(
+            parts[0],
+            json!({"inlineData": {"mimeType": "audio/wav", "data": "UklGRiQAAABXQVZF"}})
+        );
+        assert_eq!(
+            parts[1],
+            json!({"fileData": {"mimeType": "audio/mp3", "fileUri": file_uri}})
+        );
     }
 
     #[test]
@@ -576,6 +765,7 @@ mod tests {
             tool_calls: None,
             tool_call_id: None,
             function_call: None,
+            cache_control: None,
         };
 
         let parts = client.transform_message_content(&message).unwrap();
@@ -583,6 +773,135 @@ mod tests {
         assert_eq!(parts[0]["text"], "Hello, world!");
     }
 
+    #[test]
+    fn test_json_schema_response_format() {
+        let config = GeminiConfig::new_google_ai("test-key");
+        let client = GeminiClient::new(config).unwrap();
+
+        let request = ChatRequest {
+            model: "gemini-1.5-flash".to_string(),
+            messages: vec![ChatMessage {
+                role: MessageRole::User,
+                content: Some(MessageContent::Text("Name a color".to_string())),
+                ..Default::default()
+            }],
+            response_format: Some(crate::core::types::ResponseFormat {
+                format_type: "json_schema".to_string(),
+                json_schema: Some(json!({
+                    "name": "color",
+                    "strict": true,
+                    "schema": {
+                        "type": "object",
+                        "properties": {"color": {"type": "string"}},
+                        "required": ["color"],
+                        "additionalProperties": false
+                    }
+                })),
+                response_type: None,
+            }),
+            ..Default::default()
+        };
+
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(
+            body["generationConfig"]["responseMimeType"],
+            "application/json"
+        );
+        assert_eq!(
+            body["generationConfig"]["responseSchema"],
+            json!({
+                "type": "object",
+                "properties": {"color": {"type": "string"}},
+                "required": ["color"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_tool_round_trip() {
+        use crate::core::types::{FunctionDefinition, Tool, ToolChoice, ToolType};
+
+        let config = GeminiConfig::new_google_ai("test-key");
+        let client = GeminiClient::new(config).unwrap();
+        let text = |role, text: &str| ChatMessage {
+            role,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        };
+        let mut request = ChatRequest {
+            model: "gemini-1.5-flash".to_string(),
+            messages: vec![text(MessageRole::User, "Weather in Paris?")],
+            tools: Some(vec![Tool {
+                tool_type: ToolType::Function,
+                function: FunctionDefinition {
+                    name: "get_weather".to_string(),
+                    description: Some("Current weather".to_string()),
+                    parameters: Some(json!({
+                        "type": "object",
+                        "properties": {"city": {"type": "string"}},
+                        "additionalProperties": false
+                    })),
+                },
+            }]),
+            tool_choice: Some(ToolChoice::String("required".to_string())),
+            ..Default::default()
+        };
+
+        let response = client
+            .transform_chat_response(
+                json!({
+                    "candidates": [{
+                        "content": {"role": "model", "parts": [
+                            {"functionCall": {"name": "get_weather", "args": {"city": "Paris"}}}
+                        ]},
+                        "finishReason": "STOP"
+                    }]
+                }),
+                &request,
+            )
+            .unwrap();
+        let choice = &response.choices[0];
+        assert_eq!(
+            choice.finish_reason,
+            Some(crate::core::types::responses::FinishReason::ToolCalls)
+        );
+        assert!(choice.message.content.is_none());
+        let call = &choice.message.tool_calls.as_ref().unwrap()[0];
+        assert_eq!(call.function.name, "get_weather");
+        assert_eq!(call.function.arguments, r#"{"city":"Paris"}"#);
+
+        request.messages.push(choice.message.clone());
+        request.messages.push(ChatMessage {
+            tool_call_id: Some(call.id.clone()),
+            ..text(MessageRole::Tool, r#"{"forecast": "sunny"}"#)
+        });
+        let body = client.transform_chat_request(&request).unwrap();
+        assert_eq!(
+            body["tools"],
+            json!([{"functionDeclarations": [{
+                "name": "get_weather",
+                "description": "Current weather",
+                "parameters": {"type": "object", "properties": {"city": {"type": "string"}}}
+            }]}])
+        );
+        assert_eq!(
+            body["toolConfig"],
+            json!({"functionCallingConfig": {"mode": "ANY"}})
+        );
+        assert_eq!(
+            body["contents"][1],
+            json!({"role": "model", "parts": [
+                {"functionCall": {"name": "get_weather", "args": {"city": "Paris"}}}
+            ]})
+        );
+        assert_eq!(
+            body["contents"][2],
+            json!({"role": "user", "parts": [
+                {"functionResponse": {"name": "get_weather", "response": {"forecast": "sunny"}}}
+            ]})
+        );
+    }
+
     #[test]
     fn test_multimodal_message() {
         let config = GeminiConfig::new_google_ai("test-key");
@@ -593,6 +912,7 @@ mod tests {
             content: Some(MessageContent::Parts(vec![
                 ContentPart::Text {
                     text: "What's in this image?".to_string(),
+                    cache_control: None,
                 },
                 ContentPart::Image {
                     source: crate::core::types::requests::ImageSource {
@@ -608,6 +928,7 @@ mod tests {
             tool_calls: None,
             tool_call_id: None,
             function_call: None,
+            cache_control: None,
         };
 
         let parts = client.transform_message_content(&message).unwrap();
@@ -615,4 +936,65 @@ mod tests {
         assert_eq!(parts[0]["text"], "What's in this image?");
         assert!(parts[1].get("inlineData").is_some());
     }
+
+    #[test]
+    fn test_embedding_transformation() {
+        let config = GeminiConfig::new_google_ai("test-key");
+        let client = GeminiClient::new(config).unwrap();
+
+        let request = EmbeddingRequest {
+            model: "text-embedding-004".to_string(),
+            input: crate::core::types::requests::EmbeddingInput::Array(vec![
+                "first".to_string(),
+                "second".to_string(),
+            ]),
+            user: None,
+            encoding_format: None,
+            dimensions: Some(256),
+            task_type: Some("RETRIEVAL_DOCUMENT".to_string()),
+            extra_params: std::collections::HashMap::new(),
+        };
+
+        let body = client.transform_embedding_request(&request);
+        let requests = body["requests"].as_array().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0]["model"], "models/text-embedding-004");
+        assert_eq!(requests[1]["content"]["parts"][0]["text"], "second");
+        assert_eq!(requests[0]["taskType"], "RETRIEVAL_DOCUMENT");
+        assert_eq!(requests[0]["outputDimensionality"], 256);
+
+        let response = client
+            .transform_embedding_response(
+                json!({"embeddings": [{"values": [0.1, 0.2]}, {"values": [0.3, 0.4]}]}),
+                "text-embedding-004",
+            )
+            .unwrap();
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].index, 1);
+        assert_eq!(response.data[1].embedding, vec![0.3, 0.4]);
+    }
+
+    #[test]
+    fn test_uploaded_file_part() {
+        let config = GeminiConfig::new_google_ai("test-key");
+        let client = GeminiClient::new(config).unwrap();
+
+        let message = ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(vec![ContentPart::ImageUrl {
+                image_url: crate::core::types::requests::ImageUrl {
+                    url: "https://generativelanguage.googleapis.com/v1beta/files/abc123"
+                        .to_string(),
+                    detail: None,
+                },
+            }])),
+            ..Default::default()
+        };
+
+        let parts = client.transform_message_content(&message).unwrap();
+        assert_eq!(
+            parts[0]["fileData"]["fileUri"],
+            "https://generativelanguage.googleapis.com/v1beta/files/abc123"
+        );
+    }
 }
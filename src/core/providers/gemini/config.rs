@@ -197,22 +197,30 @@ impl GeminiConfig {
     }
 
     /// Get
+    ///
+    /// Streaming endpoints ask for server-sent events (`alt=sse`), which is
+    /// what [`super::streaming::GeminiStream`] parses.
     pub fn get_endpoint(&self, model: &str, operation: &str) -> String {
         if self.use_vertex_ai {
             // Vertex AI endpoint format
-            format!(
+            let url = format!(
                 "{}/v1/projects/{}/locations/{}/publishers/google/models/{}:{}",
                 self.base_url,
                 self.project_id.as_ref().unwrap_or(&"".to_string()),
                 self.location.as_ref().unwrap_or(&"".to_string()),
                 model,
                 operation
-            )
+            );
+            if operation == "streamGenerateContent" {
+                format!("{}?alt=sse", url)
+            } else {
+                url
+            }
         } else {
             // Google AI Studio endpoint format
             match operation {
                 "streamGenerateContent" => format!(
-                    "{}/{}/models/{}:streamGenerateContent?key={}",
+                    "{}/{}/models/{}:streamGenerateContent?alt=sse&key={}",
                     self.base_url,
                     self.api_version,
                     model,
@@ -408,6 +416,9 @@ mod tests {
         assert!(endpoint.contains("generativelanguage.googleapis.com"));
         assert!(endpoint.contains("gemini-pro:generateContent"));
         assert!(endpoint.contains("key=test-key-1234567890123456"));
+
+        let endpoint = config.get_endpoint("gemini-pro", "streamGenerateContent");
+        assert!(endpoint.contains(":streamGenerateContent?alt=sse&key="));
     }
 
     #[test]
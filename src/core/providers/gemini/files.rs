@@ -0,0 +1,378 @@
+//! Gemini Files Handler
+//!
+//! Google AI Studio Files API. Uploaded files are kept for 48 hours and are
+//! referenced from chat requests by URI (`{base_url}/{api_version}/files/{id}`)
+//! passed as an `image_url` content part.
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::core::files::types::{
+    FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+};
+use crate::core::providers::openai::files::OpenAIFilesUtils;
+use crate::core::providers::unified_provider::ProviderError;
+
+use super::config::GeminiConfig;
+use super::error::{
+    GeminiErrorMapper, gemini_config_error, gemini_network_error, gemini_parse_error,
+    gemini_validation_error,
+};
+
+/// File resource returned by the Files API
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiFile {
+    /// Resource name, `files/{id}`
+    pub name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    /// Size in bytes, sent as a string
+    #[serde(default)]
+    pub size_bytes: Option<String>,
+    #[serde(default)]
+    pub create_time: Option<String>,
+    #[serde(default)]
+    pub expiration_time: Option<String>,
+    #[serde(default)]
+    pub uri: Option<String>,
+    /// "PROCESSING", "ACTIVE" or "FAILED"
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub error: Option<serde_json::Value>,
+}
+
+impl GeminiFile {
+    /// Convert to an OpenAI-style file object
+    pub fn into_file_object(self, purpose: &str) -> FileObject {
+        let parse_time = |time: &Option<String>| {
+            time.as_deref()
+                .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                .map(|t| t.timestamp())
+        };
+
+        let status = self.state.as_deref().map(|state| {
+            match state {
+                "ACTIVE" => "processed",
+                "FAILED" => "error",
+                _ => "uploaded",
+            }
+            .to_string()
+        });
+
+        FileObject {
+            id: GeminiFilesUtils::file_id(&self.name).to_string(),
+            object: "file".to_string(),
+            bytes: self
+                .size_bytes
+                .as_deref()
+                .and_then(|size| size.parse().ok())
+                .unwrap_or(0),
+            created_at: parse_time(&self.create_time).unwrap_or(0),
+            filename: self.display_name.clone().unwrap_or_default(),
+            purpose: purpose.to_string(),
+            status,
+            status_details: self.error.as_ref().map(|e| e.to_string()),
+            expires_at: parse_time(&self.expiration_time),
+        }
+    }
+}
+
+/// Page of files
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiFileList {
+    #[serde(default)]
+    pub files: Vec<GeminiFile>,
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+}
+
+/// Gemini files handler
+#[derive(Debug, Clone)]
+pub struct GeminiFilesHandler {
+    config: GeminiConfig,
+    http_client: Client,
+}
+
+impl GeminiFilesHandler {
+    /// Create new files handler
+    pub fn new(config: GeminiConfig, http_client: Client) -> Self {
+        Self {
+            config,
+            http_client,
+        }
+    }
+
+    /// API key, which the Files API requires
+    fn api_key(&self) -> Result<&str, ProviderError> {
+        if self.config.use_vertex_ai {
+            return Err(ProviderError::not_supported(
+                "gemini",
+                "Files API is only available with a Google AI Studio API key",
+            ));
+        }
+
+        self.config
+            .api_key
+            .as_deref()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| gemini_config_error("API key is required for the Files API"))
+    }
+
+    /// Upload a file with a resumable upload session
+    pub async fn upload_file(
+        &self,
+        request: FileUploadRequest,
+    ) -> Result<FileObject, ProviderError> {
+        let api_key = self.api_key()?;
+        let mime_type = OpenAIFilesUtils::file_mime_type(&request.filename);
+
+        // Start the session; the upload URL comes back in a header
+        let response = self
+            .http_client
+            .post(GeminiFilesUtils::upload_url(&self.config))
+            .header("x-goog-api-key", api_key)
+            .header("X-Goog-Upload-Protocol", "resumable")
+            .header("X-Goog-Upload-Command", "start")
+            .header(
+                "X-Goog-Upload-Header-Content-Length",
+                request.file.len().to_string(),
+            )
+            .header("X-Goog-Upload-Header-Content-Type", mime_type)
+            .json(&json!({ "file": { "display_name": request.filename } }))
+            .send()
+            .await
+            .map_err(|e| gemini_network_error(format!("Network error: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(GeminiErrorMapper::from_http_status(status.as_u16(), &body));
+        }
+
+        let upload_url = response
+            .headers()
+            .get("x-goog-upload-url")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| gemini_parse_error("Missing upload URL in response"))?;
+
+        let response = self
+            .http_client
+            .post(&upload_url)
+            .header("X-Goog-Upload-Offset", "0")
+            .header("X-Goog-Upload-Command", "upload, finalize")
+            .body(request.file)
+            .send()
+            .await
+            .map_err(|e| gemini_network_error(format!("Network error: {}", e)))?;
+
+        #[derive(Deserialize)]
+        struct UploadResponse {
+            file: GeminiFile,
+        }
+
+        let uploaded: UploadResponse = Self::parse_response(response).await?;
+        Ok(uploaded.file.into_file_object(&request.purpose))
+    }
+
+    /// List uploaded files
+    ///
+    /// `after` is passed through as the API's page token.
+    pub async fn list_files(
+        &self,
+        query: FileListQuery,
+    ) -> Result<FileListResponse, ProviderError> {
+        let api_key = self.api_key()?;
+
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(limit) = query.limit {
+            params.append_pair("pageSize", &limit.to_string());
+        }
+        if let Some(after) = &query.after {
+            params.append_pair("pageToken", after);
+        }
+        let params = params.finish();
+
+        let mut url = GeminiFilesUtils::files_url(&self.config, "");
+        if !params.is_empty() {
+            url.push('?');
+            url.push_str(&params);
+        }
+
+        let response = self
+            .http_client
+            .get(&url)
+            .header("x-goog-api-key", api_key)
+            .send()
+            .await
+            .map_err(|e| gemini_network_error(format!("Network error: {}", e)))?;
+
+        let list: GeminiFileList = Self::parse_response(response).await?;
+        Ok(FileListResponse {
+            object: "list".to_string(),
+            has_more: list.next_page_token.is_some(),
+            data: list
+                .files
+                .into_iter()
+                .map(|file| file.into_file_object("user_data"))
+                .collect(),
+        })
+    }
+
+    /// Retrieve a file's metadata
+    pub async fn retrieve_file(&self, file_id: &str) -> Result<FileObject, ProviderError> {
+        let api_key = self.api_key()?;
+        let file_id = GeminiFilesUtils::validate_file_id(file_id)?;
+
+        let response = self
+            .http_client
+            .get(GeminiFilesUtils::files_url(&self.config, file_id))
+            .header("x-goog-api-key", api_key)
+            .send()
+            .await
+            .map_err(|e| gemini_network_error(format!("Network error: {}", e)))?;
+
+        let file: GeminiFile = Self::parse_response(response).await?;
+        Ok(file.into_file_object("user_data"))
+    }
+
+    /// Delete a file
+    pub async fn delete_file(&self, file_id: &str) -> Result<FileDeleteResponse, ProviderError> {
+        let api_key = self.api_key()?;
+        let file_id = GeminiFilesUtils::validate_file_id(file_id)?;
+
+        let response = self
+            .http_client
+            .delete(GeminiFilesUtils::files_url(&self.config, file_id))
+            .header("x-goog-api-key", api_key)
+            .send()
+            .await
+            .map_err(|e| gemini_network_error(format!("Network error: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(GeminiErrorMapper::from_http_status(status.as_u16(), &body));
+        }
+
+        Ok(FileDeleteResponse {
+            id: file_id.to_string(),
+            object: "file".to_string(),
+            deleted: true,
+        })
+    }
+
+    /// Deserialize a JSON response, surfacing API errors
+    async fn parse_response<T: serde::de::DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<T, ProviderError> {
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| gemini_network_error(format!("Failed to read response: {}", e)))?;
+        if !status.is_success() {
+            return Err(GeminiErrorMapper::from_http_status(status.as_u16(), &body));
+        }
+
+        serde_json::from_str(&body)
+            .map_err(|e| gemini_parse_error(format!("Failed to parse response JSON: {}", e)))
+    }
+}
+
+/// Gemini files utilities
+pub struct GeminiFilesUtils;
+
+impl GeminiFilesUtils {
+    /// URL of the upload endpoint
+    pub fn upload_url(config: &GeminiConfig) -> String {
+        format!(
+            "{}/upload/{}/files",
+            config.base_url.trim_end_matches('/'),
+            config.api_version
+        )
+    }
+
+    /// URL of the files collection, or of a single file when `file_id` is set
+    pub fn files_url(config: &GeminiConfig, file_id: &str) -> String {
+        let base = format!(
+            "{}/{}/files",
+            config.base_url.trim_end_matches('/'),
+            config.api_version
+        );
+        if file_id.is_empty() {
+            base
+        } else {
+            format!("{}/{}", base, file_id)
+        }
+    }
+
+    /// File ID of a `files/{id}` resource name
+    pub fn file_id(name: &str) -> &str {
+        name.strip_prefix("files/").unwrap_or(name)
+    }
+
+    /// Check a file ID (or `files/{id}` name), returning the bare ID
+    pub fn validate_file_id(file_id: &str) -> Result<&str, ProviderError> {
+        let id = Self::file_id(file_id);
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(gemini_validation_error(format!(
+                "Invalid file ID: {}",
+                file_id
+            )));
+        }
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files_urls() {
+        let config = GeminiConfig::new_google_ai("test-api-key-12345678901234567890");
+        assert_eq!(
+            GeminiFilesUtils::upload_url(&config),
+            "https://generativelanguage.googleapis.com/upload/v1beta/files"
+        );
+        assert_eq!(
+            GeminiFilesUtils::files_url(&config, "abc123"),
+            "https://generativelanguage.googleapis.com/v1beta/files/abc123"
+        );
+        assert_eq!(
+            GeminiFilesUtils::validate_file_id("files/abc-123").unwrap(),
+            "abc-123"
+        );
+        assert!(GeminiFilesUtils::validate_file_id("../models").is_err());
+    }
+
+    #[test]
+    fn test_into_file_object() {
+        let file: GeminiFile = serde_json::from_value(json!({
+            "name": "files/abc123",
+            "displayName": "report.pdf",
+            "mimeType": "application/pdf",
+            "sizeBytes": "2048",
+            "createTime": "2024-05-01T12:00:00Z",
+            "expirationTime": "2024-05-03T12:00:00Z",
+            "uri": "https://generativelanguage.googleapis.com/v1beta/files/abc123",
+            "state": "ACTIVE"
+        }))
+        .unwrap();
+
+        let object = file.into_file_object("user_data");
+        assert_eq!(object.id, "abc123");
+        assert_eq!(object.bytes, 2048);
+        assert_eq!(object.filename, "report.pdf");
+        assert_eq!(object.created_at, 1714564800);
+        assert_eq!(object.expires_at, Some(1714737600));
+        assert_eq!(object.status.as_deref(), Some("processed"));
+    }
+}
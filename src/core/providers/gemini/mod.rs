@@ -1,6 +1,8 @@
 //! Google Gemini Provider
 //!
-//! Support for Google AI Studio and Vertex AI Gemini model series
+//! Support for Google AI Studio and Vertex AI Gemini model series. With a
+//! Google AI Studio API key no GCP project or application default
+//! credentials are needed.
 //!
 //! # Supported Models
 //! - Gemini 2.0 Flash (latest)
@@ -14,10 +16,13 @@
 //! - Context caching
 //! - Batch processing
 //! - Real-time streaming responses
+//! - Embeddings (`batchEmbedContents`)
+//! - File uploads (Google AI Studio Files API)
 
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod files;
 pub mod models;
 pub mod provider;
 pub mod streaming;
@@ -26,6 +31,7 @@ pub mod streaming;
 pub use client::GeminiClient;
 pub use config::GeminiConfig;
 pub use error::GeminiError;
+pub use files::GeminiFilesHandler;
 pub use models::{GeminiModelFamily, ModelFeature, get_gemini_registry};
 pub use provider::GeminiProvider;
 pub use streaming::GeminiStream;
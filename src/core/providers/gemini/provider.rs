@@ -21,11 +21,12 @@ use crate::core::types::{
 use super::client::GeminiClient;
 use super::config::GeminiConfig;
 use super::error::{GeminiError, GeminiErrorMapper, gemini_model_error, gemini_validation_error};
+use super::files::GeminiFilesHandler;
 use super::models::{ModelFeature, get_gemini_registry};
 use super::streaming::GeminiStream;
 
 /// Gemini Provider - Unified implementation
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GeminiProvider {
     config: GeminiConfig,
     client: GeminiClient,
@@ -114,6 +115,11 @@ impl GeminiProvider {
         Ok(())
     }
 
+    /// Files API handler (Google AI Studio only)
+    pub fn files(&self) -> GeminiFilesHandler {
+        self.client.files()
+    }
+
     /// Get
     pub fn calculate_cost(
         &self,
@@ -140,6 +146,8 @@ impl LLMProvider for GeminiProvider {
             ProviderCapability::ChatCompletion,
             ProviderCapability::ChatCompletionStream,
             ProviderCapability::ToolCalling,
+            ProviderCapability::Embeddings,
+            ProviderCapability::FileUpload,
             // ProviderCapability::Vision, // TODO: Add to enum
         ]
     }
@@ -165,7 +173,7 @@ impl LLMProvider for GeminiProvider {
     }
 
     fn supports_embeddings(&self) -> bool {
-        false // TODO: Can be supported through dedicated embedding models
+        true // Through dedicated embedding models, e.g. text-embedding-004
     }
 
     fn supports_vision(&self) -> bool {
@@ -181,6 +189,7 @@ impl LLMProvider for GeminiProvider {
             "stream",
             "tools",
             "tool_choice",
+            "response_format",
         ]
     }
 
@@ -315,13 +324,17 @@ impl LLMProvider for GeminiProvider {
 
     async fn embeddings(
         &self,
-        _request: EmbeddingRequest,
+        request: EmbeddingRequest,
         _context: RequestContext,
     ) -> Result<EmbeddingResponse, Self::Error> {
-        Err(ProviderError::NotSupported {
-            provider: "gemini",
-            feature: "embeddings: not yet implemented for Gemini provider".to_string(),
-        })
+        if !request.model.contains("embedding") {
+            return Err(gemini_model_error(format!(
+                "Not an embedding model: {}",
+                request.model
+            )));
+        }
+
+        self.client.embed(&request).await
     }
 
     async fn image_generation(
@@ -415,7 +428,7 @@ mod tests {
         assert!(provider.supports_streaming());
         assert!(provider.supports_tools());
         assert!(provider.supports_vision());
-        assert!(!provider.supports_embeddings());
+        assert!(provider.supports_embeddings());
         assert!(!provider.supports_image_generation());
     }
 
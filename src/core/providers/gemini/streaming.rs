@@ -2,6 +2,7 @@
 //!
 //! Independent streaming response processing, supporting SSE parsing and real-time data transformation
 
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -10,10 +11,11 @@ use pin_project_lite::pin_project;
 use reqwest::Response;
 use serde_json::Value;
 
+use crate::core::providers::shared::ToolTransformer;
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::{
     requests::MessageRole,
-    responses::{ChatChunk, ChatDelta, ChatStreamChoice, Usage},
+    responses::{ChatChunk, ChatDelta, ChatStreamChoice, FunctionCallDelta, ToolCallDelta, Usage},
 };
 
 use super::error::gemini_stream_error;
@@ -132,12 +134,29 @@ impl GeminiSSEParser {
                     }
                     let delta_content = text_parts.join("");
 
+                    // Function calls arrive whole, each in one part
+                    let tool_calls: Vec<ToolCallDelta> = content
+                        .iter()
+                        .filter_map(ToolTransformer::from_gemini_function_call)
+                        .enumerate()
+                        .map(|(index, call)| ToolCallDelta {
+                            index: index as u32,
+                            id: Some(call.id),
+                            tool_type: Some(call.tool_type),
+                            function: Some(FunctionCallDelta {
+                                name: Some(call.function.name),
+                                arguments: Some(call.function.arguments),
+                            }),
+                        })
+                        .collect();
+
                     // Check
                     let finish_reason =
                         candidate
                             .get("finishReason")
                             .and_then(|r| r.as_str())
                             .map(|r| match r {
+                                "STOP" if !tool_calls.is_empty() => "tool_calls",
                                 "STOP" => "stop",
                                 "MAX_TOKENS" => "length",
                                 "SAFETY" => "content_filter",
@@ -159,7 +178,11 @@ impl GeminiSSEParser {
                         },
                         thinking: None,
                         function_call: None,
-                        tool_calls: None,
+                        tool_calls: if tool_calls.is_empty() {
+                            None
+                        } else {
+                            Some(tool_calls)
+                        },
                     };
 
                     choices.push(ChatStreamChoice {
@@ -168,6 +191,7 @@ impl GeminiSSEParser {
                         finish_reason: finish_reason.map(|s| match s {
                             "stop" => crate::core::types::responses::FinishReason::Stop,
                             "length" => crate::core::types::responses::FinishReason::Length,
+                            "tool_calls" => crate::core::types::responses::FinishReason::ToolCalls,
                             "content_filter" => {
                                 crate::core::types::responses::FinishReason::ContentFilter
                             }
@@ -194,6 +218,9 @@ impl GeminiSSEParser {
                     prompt_tokens_details: None,
                     completion_tokens_details: None,
                     thinking_usage: None,
+                    cache_creation_input_tokens: None,
+                    cache_read_input_tokens: None,
+                    billable_characters: None,
                 });
 
                 if choices.is_empty() && usage.is_none() {
@@ -208,6 +235,7 @@ impl GeminiSSEParser {
                     choices,
                     usage,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
             GeminiSSEEvent::Error(error) => {
@@ -234,6 +262,7 @@ impl GeminiSSEParser {
                     }],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }))
             }
             GeminiSSEEvent::Ping => Ok(None), // Skip ping events
@@ -289,6 +318,7 @@ impl GeminiStream {
                                         choices: vec![],
                                         usage: None,
                                         system_fingerprint: None,
+                                        provider_specific_fields: None,
                                     }),
                                     new_state,
                                 ));
@@ -323,6 +353,7 @@ impl GeminiStream {
                                 choices: vec![],
                                 usage: None,
                                 system_fingerprint: None,
+                                provider_specific_fields: None,
                             }),
                             new_state,
                         ))
@@ -360,10 +391,33 @@ impl GeminiStream {
         );
 
         Self {
-            inner: Box::pin(stream),
+            inner: Box::pin(Self::number_tool_calls(stream)),
         }
     }
 
+    /// Number function calls across the stream
+    ///
+    /// Gemini sends each call whole and numbers calls within a chunk only, so
+    /// calls in later chunks would otherwise merge with earlier ones.
+    fn number_tool_calls(
+        stream: impl Stream<Item = Result<ChatChunk, ProviderError>> + Send + 'static,
+    ) -> impl Stream<Item = Result<ChatChunk, ProviderError>> + Send + 'static {
+        let mut seen: HashMap<u32, u32> = HashMap::new();
+        stream.map(move |chunk| {
+            let mut chunk = chunk?;
+            for choice in &mut chunk.choices {
+                if let Some(tool_calls) = choice.delta.tool_calls.as_mut() {
+                    let offset = seen.entry(choice.index).or_default();
+                    for call in tool_calls.iter_mut() {
+                        call.index += *offset;
+                    }
+                    *offset += tool_calls.len() as u32;
+                }
+            }
+            Ok(chunk)
+        })
+    }
+
     /// Create from test data
     #[cfg(test)]
     pub fn from_test_data(data: Vec<String>, model: String) -> Self {
@@ -394,7 +448,7 @@ impl GeminiStream {
             .filter_map(|item| async move { item });
 
         Self {
-            inner: Box::pin(stream),
+            inner: Box::pin(Self::number_tool_calls(stream)),
         }
     }
 }
@@ -513,6 +567,28 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_stream_numbers_tool_calls_across_chunks() {
+        let test_data = vec![
+            r#"data: {"candidates": [{"content": {"parts": [{"functionCall": {"name": "get_weather", "args": {"city": "Paris"}}}]}}]}"#.to_string(),
+            r#"data: {"candidates": [{"content": {"parts": [{"functionCall": {"name": "get_weather", "args": {"city": "Rome"}}}]}, "finishReason": "STOP"}]}"#.to_string(),
+        ];
+
+        let stream = GeminiStream::from_test_data(test_data, "gemini-pro".to_string());
+        let chunks: Vec<_> = stream.map(Result::unwrap).collect().await;
+        let response = crate::core::types::responses::ChatResponse::from_chunks(chunks);
+
+        let calls = response.first_tool_calls().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].function.arguments, r#"{"city":"Paris"}"#);
+        assert_eq!(calls[1].function.arguments, r#"{"city":"Rome"}"#);
+        assert_ne!(calls[0].id, calls[1].id);
+        assert_eq!(
+            response.choices[0].finish_reason,
+            Some(crate::core::types::responses::FinishReason::ToolCalls)
+        );
+    }
+
     #[test]
     fn test_sse_empty_line() {
         let event = GeminiSSEParser::parse_event("");
@@ -576,6 +652,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_chunk_transformation_with_function_calls() {
+        let response = json!({
+            "candidates": [{
+                "content": {
+                    "parts": [
+                        {"functionCall": {"name": "get_weather", "args": {"city": "Paris"}}},
+                        {"functionCall": {"id": "call_2", "name": "get_time", "args": {}}}
+                    ]
+                },
+                "finishReason": "STOP"
+            }]
+        });
+
+        let event = GeminiSSEEvent::GenerateContentResponse(response);
+        let chunk = GeminiSSEParser::transform_to_chat_chunk(&event, "gemini-pro", "test-id")
+            .unwrap()
+            .unwrap();
+
+        let choice = &chunk.choices[0];
+        assert_eq!(
+            choice.finish_reason.as_ref().unwrap(),
+            &crate::core::types::responses::FinishReason::ToolCalls
+        );
+        let tool_calls = choice.delta.tool_calls.as_ref().unwrap();
+        assert_eq!(tool_calls.len(), 2);
+        let function = tool_calls[0].function.as_ref().unwrap();
+        assert_eq!(function.name.as_deref(), Some("get_weather"));
+        assert_eq!(function.arguments.as_deref(), Some(r#"{"city":"Paris"}"#));
+        assert_eq!(tool_calls[1].index, 1);
+        assert_eq!(tool_calls[1].id.as_deref(), Some("call_2"));
+    }
+
     #[test]
     fn test_chunk_transformation_with_finish_reason_max_tokens() {
         let response = json!({
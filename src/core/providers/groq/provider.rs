@@ -18,8 +18,8 @@ use crate::core::traits::{
 };
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest, MessageRole},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse},
+    requests::{AudioTranscriptionRequest, ChatRequest, EmbeddingRequest, MessageRole},
+    responses::{AudioTranscriptionResponse, ChatChunk, ChatResponse, EmbeddingResponse},
 };
 
 /// Static capabilities for Groq provider
@@ -27,6 +27,7 @@ const GROQ_CAPABILITIES: &[ProviderCapability] = &[
     ProviderCapability::ChatCompletion,
     ProviderCapability::ChatCompletionStream,
     ProviderCapability::ToolCalling,
+    ProviderCapability::AudioTranscription,
 ];
 
 /// Groq provider implementation
@@ -53,10 +54,14 @@ impl GroqProvider {
             .iter()
             .filter_map(|id| get_model_info(id))
             .map(|info| {
-                let mut capabilities = vec![
-                    ProviderCapability::ChatCompletion,
-                    ProviderCapability::ChatCompletionStream,
-                ];
+                let mut capabilities = if info.is_audio {
+                    vec![ProviderCapability::AudioTranscription]
+                } else {
+                    vec![
+                        ProviderCapability::ChatCompletion,
+                        ProviderCapability::ChatCompletionStream,
+                    ]
+                };
                 if info.supports_tools {
                     capabilities.push(ProviderCapability::ToolCalling);
                 }
@@ -68,7 +73,7 @@ impl GroqProvider {
                     provider: "groq".to_string(),
                     max_context_length: info.context_length,
                     max_output_length: Some(info.max_output_tokens),
-                    supports_streaming: true,
+                    supports_streaming: !info.is_audio,
                     supports_tools: info.supports_tools,
                     supports_multimodal: info.supports_vision,
                     input_cost_per_1k_tokens: Some(info.input_cost_per_million / 1000.0),
@@ -160,6 +165,7 @@ impl GroqProvider {
     ) -> Result<super::stt::TranscriptionResponse, GroqError> {
         let request = super::stt::SpeechToTextRequest {
             file,
+            filename: None,
             model: model.unwrap_or_else(|| "whisper-large-v3-turbo".to_string()),
             language,
             prompt: None,
@@ -168,20 +174,23 @@ impl GroqProvider {
             timestamp_granularities: None,
         };
 
-        // Create multipart form
+        self.transcribe(request).await
+    }
+
+    /// Upload audio to `/audio/transcriptions`
+    async fn transcribe(
+        &self,
+        request: super::stt::SpeechToTextRequest,
+    ) -> Result<super::stt::TranscriptionResponse, GroqError> {
+        super::stt::validate_request(&request)?;
+
+        let response_format = request.response_format.clone();
         let form = super::stt::create_multipart_form(request)?;
 
         let url = format!("{}/audio/transcriptions", self.config.get_api_base());
-        let mut headers = Vec::new();
+        let mut req = self.pool_manager.client().post(&url);
         if let Some(api_key) = &self.config.get_api_key() {
-            headers.push(("Authorization".to_string(), format!("Bearer {}", api_key)));
-        }
-
-        // Use reqwest client for multipart
-        let client = reqwest::Client::new();
-        let mut req = client.post(&url);
-        for (key, value) in headers {
-            req = req.header(key, value);
+            req = req.header("Authorization", format!("Bearer {}", api_key));
         }
 
         let response = req
@@ -211,22 +220,7 @@ impl GroqProvider {
             .await
             .map_err(|e| GroqError::ApiError(format!("Failed to read response: {}", e)))?;
 
-        // Try to parse as JSON first
-        if let Ok(json_response) =
-            serde_json::from_str::<super::stt::TranscriptionResponse>(&response_text)
-        {
-            Ok(json_response)
-        } else {
-            // If not JSON, assume it's plain text response
-            Ok(super::stt::TranscriptionResponse {
-                text: response_text,
-                task: Some("transcribe".to_string()),
-                language: None,
-                duration: None,
-                words: None,
-                segments: None,
-            })
-        }
+        super::stt::parse_response(&response_text, response_format.as_deref())
     }
 }
 
@@ -387,8 +381,9 @@ impl LLMProvider for GroqProvider {
 
         // Execute streaming request using reqwest directly for SSE
         let url = format!("{}/chat/completions", self.config.get_api_base());
-        let client = reqwest::Client::new();
-        let response = client
+        let response = self
+            .pool_manager
+            .client()
             .post(&url)
             .header("Authorization", format!("Bearer {}", api_key))
             .header("Content-Type", "application/json")
@@ -426,6 +421,17 @@ impl LLMProvider for GroqProvider {
         ))
     }
 
+    async fn audio_transcription(
+        &self,
+        request: AudioTranscriptionRequest,
+        _context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, Self::Error> {
+        debug!("Groq transcription request: model={}", request.model);
+
+        let response = self.transcribe(request.into()).await?;
+        Ok(response.into())
+    }
+
     async fn health_check(&self) -> HealthStatus {
         // Simple health check - try to get models list
         let url = format!("{}/models", self.config.get_api_base());
@@ -2,27 +2,77 @@
 //!
 //! Uses the unified SSE parser for consistent streaming across providers.
 //! Also provides fake streaming support when response_format is used (Groq limitation).
+//! Groq reports the usage of a stream under `x_groq.usage` in its last chunk.
 
 use super::error::GroqError;
-use crate::core::providers::base::sse::{OpenAICompatibleTransformer, UnifiedSSEStream};
+use crate::core::providers::base::sse::{
+    OpenAICompatibleTransformer, SSETransformer, UnifiedSSEStream,
+};
+use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::requests::{MessageContent, MessageRole};
 use crate::core::types::responses::{ChatChunk, ChatDelta, ChatResponse, ChatStreamChoice};
 use bytes::Bytes;
 use futures::Stream;
+use serde_json::Value;
 use std::pin::Pin;
 
 /// Groq uses OpenAI-compatible SSE format
 pub type GroqStreamInner = UnifiedSSEStream<
     Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>,
-    OpenAICompatibleTransformer,
+    GroqStreamTransformer,
 >;
 
 /// Helper function to create Groq stream
 pub fn create_groq_stream(
     stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
 ) -> GroqStreamInner {
-    let transformer = OpenAICompatibleTransformer::new("groq");
-    UnifiedSSEStream::new(Box::pin(stream), transformer)
+    UnifiedSSEStream::new(Box::pin(stream), GroqStreamTransformer::new())
+}
+
+/// Transformer for Groq stream chunks
+#[derive(Debug, Clone)]
+pub struct GroqStreamTransformer {
+    inner: OpenAICompatibleTransformer,
+}
+
+impl GroqStreamTransformer {
+    pub fn new() -> Self {
+        Self {
+            inner: OpenAICompatibleTransformer::new("groq"),
+        }
+    }
+}
+
+impl Default for GroqStreamTransformer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SSETransformer for GroqStreamTransformer {
+    fn provider_name(&self) -> &'static str {
+        "groq"
+    }
+
+    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
+        let mut value: Value = serde_json::from_str(data).map_err(|e| {
+            ProviderError::response_parsing("groq", format!("Failed to parse SSE JSON: {}", e))
+        })?;
+
+        if let Some(obj) = value.as_object_mut() {
+            let usage = obj
+                .get_mut("x_groq")
+                .and_then(|x_groq| x_groq.as_object_mut())
+                .and_then(|x_groq| x_groq.remove("usage"));
+            if let Some(usage) = usage {
+                if obj.get("usage").is_none_or(Value::is_null) {
+                    obj.insert("usage".to_string(), usage);
+                }
+            }
+        }
+
+        self.inner.transform_chunk(&value.to_string())
+    }
 }
 
 /// Wrapper stream that converts ProviderError to GroqError for backward compatibility
@@ -93,6 +143,7 @@ fn response_to_chunks(response: ChatResponse) -> Vec<ChatChunk> {
             logprobs: None,
         }],
         usage: None,
+        provider_specific_fields: None,
     });
 
     // Create content chunks
@@ -128,6 +179,7 @@ fn response_to_chunks(response: ChatResponse) -> Vec<ChatChunk> {
                         logprobs: None,
                     }],
                     usage: None,
+                    provider_specific_fields: None,
                 });
             }
         }
@@ -152,8 +204,28 @@ fn response_to_chunks(response: ChatResponse) -> Vec<ChatChunk> {
                 logprobs: None,
             }],
             usage: response.usage.clone(),
+            provider_specific_fields: None,
         });
     }
 
     chunks
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::base::sse::UnifiedSSEParser;
+
+    #[test]
+    fn test_x_groq_usage() {
+        let mut parser = UnifiedSSEParser::new(GroqStreamTransformer::new());
+
+        let data = b"data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1730000000,\"model\":\"llama-3.1-8b-instant\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}],\"x_groq\":{\"id\":\"req_1\",\"usage\":{\"prompt_tokens\":12,\"completion_tokens\":5,\"total_tokens\":17}}}\n\n";
+
+        let chunks = parser.process_bytes(data).unwrap();
+        assert_eq!(chunks.len(), 1);
+        let usage = chunks[0].usage.as_ref().unwrap();
+        assert_eq!(usage.prompt_tokens, 12);
+        assert_eq!(usage.total_tokens, 17);
+    }
+}
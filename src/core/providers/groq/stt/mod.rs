@@ -3,14 +3,25 @@
 //! Provides audio transcription capabilities using Groq's Whisper models.
 
 use super::error::GroqError;
+use super::model_info::get_model_info;
+use crate::core::providers::openai::audio::OpenAIAudioUtils;
+use crate::core::types::requests::AudioTranscriptionRequest;
+use crate::core::types::responses::{AudioTranscriptionResponse, SegmentInfo, WordInfo};
 use serde::{Deserialize, Serialize};
 
+/// Filename used when a request does not name the audio file
+const DEFAULT_AUDIO_FILENAME: &str = "audio.mp3";
+
 /// Speech-to-text request
 #[derive(Debug, Clone, Serialize)]
 pub struct SpeechToTextRequest {
     /// Audio file to transcribe (base64 encoded or raw bytes)
     pub file: Vec<u8>,
 
+    /// Original filename, which determines the audio format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+
     /// Model to use for transcription
     /// Options: "whisper-large-v3", "whisper-large-v3-turbo", "distil-whisper-large-v3-en"
     pub model: String,
@@ -37,6 +48,21 @@ pub struct SpeechToTextRequest {
     pub timestamp_granularities: Option<Vec<String>>,
 }
 
+impl From<AudioTranscriptionRequest> for SpeechToTextRequest {
+    fn from(request: AudioTranscriptionRequest) -> Self {
+        Self {
+            file: request.file,
+            filename: request.filename,
+            model: request.model,
+            language: request.language,
+            prompt: request.prompt,
+            response_format: request.response_format,
+            temperature: request.temperature,
+            timestamp_granularities: request.timestamp_granularities,
+        }
+    }
+}
+
 /// Transcription response
 #[derive(Debug, Clone, Deserialize)]
 pub struct TranscriptionResponse {
@@ -64,6 +90,42 @@ pub struct TranscriptionResponse {
     pub segments: Option<Vec<SegmentTimestamp>>,
 }
 
+impl From<TranscriptionResponse> for AudioTranscriptionResponse {
+    fn from(response: TranscriptionResponse) -> Self {
+        Self {
+            text: response.text,
+            task: response.task,
+            language: response.language,
+            duration: response.duration.map(f64::from),
+            words: response.words.map(|words| {
+                words
+                    .into_iter()
+                    .map(|w| WordInfo {
+                        word: w.word,
+                        start: w.start.into(),
+                        end: w.end.into(),
+                    })
+                    .collect()
+            }),
+            segments: response.segments.map(|segments| {
+                segments
+                    .into_iter()
+                    .map(|s| SegmentInfo {
+                        id: s.id,
+                        start: s.start.into(),
+                        end: s.end.into(),
+                        text: s.text,
+                        temperature: s.temperature,
+                        avg_logprob: s.avg_logprob.map(f64::from),
+                        compression_ratio: s.compression_ratio.map(f64::from),
+                        no_speech_prob: s.no_speech_prob.map(f64::from),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
 /// Word-level timestamp information
 #[derive(Debug, Clone, Deserialize)]
 pub struct WordTimestamp {
@@ -113,6 +175,73 @@ pub struct SegmentTimestamp {
     pub tokens: Option<Vec<u32>>,
 }
 
+/// Validate a transcription request before upload
+pub fn validate_request(request: &SpeechToTextRequest) -> Result<(), GroqError> {
+    if request.file.is_empty() {
+        return Err(GroqError::InvalidRequestError(
+            "Audio file cannot be empty".to_string(),
+        ));
+    }
+    if request.file.len() > MAX_FILE_SIZE {
+        return Err(GroqError::InvalidRequestError(
+            "Audio file too large (max 25MB)".to_string(),
+        ));
+    }
+
+    if !get_model_info(&request.model).is_some_and(|info| info.is_audio) {
+        return Err(GroqError::InvalidRequestError(format!(
+            "Model {} is not a transcription model; use whisper-large-v3 or whisper-large-v3-turbo",
+            request.model
+        )));
+    }
+
+    if let Some(extension) = request
+        .filename
+        .as_deref()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase())
+    {
+        if !supported_audio_formats().contains(&extension.as_str()) {
+            return Err(GroqError::InvalidRequestError(format!(
+                "Unsupported audio format: {}",
+                extension
+            )));
+        }
+    }
+
+    if request.timestamp_granularities.is_some()
+        && request.response_format.as_deref() != Some("verbose_json")
+    {
+        return Err(GroqError::InvalidRequestError(
+            "timestamp_granularities requires response_format verbose_json".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parse a transcription response body
+///
+/// `text`, `srt` and `vtt` formats return the raw body rather than JSON.
+pub fn parse_response(
+    body: &str,
+    response_format: Option<&str>,
+) -> Result<TranscriptionResponse, GroqError> {
+    match response_format {
+        None | Some("json") | Some("verbose_json") => serde_json::from_str(body).map_err(|e| {
+            GroqError::ApiError(format!("Failed to parse transcription response: {}", e))
+        }),
+        Some(_) => Ok(TranscriptionResponse {
+            text: body.to_string(),
+            task: Some("transcribe".to_string()),
+            language: None,
+            duration: None,
+            words: None,
+            segments: None,
+        }),
+    }
+}
+
 /// Create multipart form for audio upload
 pub fn create_multipart_form(
     request: SpeechToTextRequest,
@@ -122,9 +251,13 @@ pub fn create_multipart_form(
     let mut form = multipart::Form::new();
 
     // Add audio file
+    let filename = request
+        .filename
+        .unwrap_or_else(|| DEFAULT_AUDIO_FILENAME.to_string());
+    let mime_type = OpenAIAudioUtils::audio_mime_type(&filename);
     let file_part = multipart::Part::bytes(request.file)
-        .file_name("audio.mp3") // Default filename
-        .mime_str("audio/mpeg")
+        .file_name(filename)
+        .mime_str(mime_type)
         .map_err(|e| GroqError::InvalidRequestError(format!("Invalid MIME type: {}", e)))?;
     form = form.part("file", file_part);
 
@@ -155,8 +288,53 @@ pub fn create_multipart_form(
 
 /// Supported audio formats
 pub fn supported_audio_formats() -> &'static [&'static str] {
-    &["mp3", "mp4", "mpeg", "mpga", "m4a", "wav", "webm"]
+    &[
+        "flac", "mp3", "mp4", "mpeg", "mpga", "m4a", "ogg", "wav", "webm",
+    ]
 }
 
 /// Maximum file size in bytes (25MB)
 pub const MAX_FILE_SIZE: usize = 25 * 1024 * 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(model: &str) -> SpeechToTextRequest {
+        SpeechToTextRequest::from(AudioTranscriptionRequest {
+            file: vec![0u8; 16],
+            filename: Some("meeting.m4a".to_string()),
+            model: model.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_validate_request() {
+        assert!(validate_request(&request("whisper-large-v3")).is_ok());
+        assert!(validate_request(&request("llama-3.1-8b-instant")).is_err());
+
+        let mut aiff = request("whisper-large-v3-turbo");
+        aiff.filename = Some("voice.aiff".to_string());
+        assert!(validate_request(&aiff).is_err());
+
+        let mut words = request("whisper-large-v3");
+        words.timestamp_granularities = Some(vec!["word".to_string()]);
+        assert!(validate_request(&words).is_err());
+        words.response_format = Some("verbose_json".to_string());
+        assert!(validate_request(&words).is_ok());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let body = r#"{"task":"transcribe","language":"english","duration":2.5,"text":"Hello there","segments":[{"id":0,"start":0.0,"end":2.5,"text":"Hello there","avg_logprob":-0.2}],"x_groq":{"id":"req_1"}}"#;
+        let response: AudioTranscriptionResponse =
+            parse_response(body, Some("verbose_json")).unwrap().into();
+        assert_eq!(response.text, "Hello there");
+        assert_eq!(response.duration, Some(2.5));
+        assert_eq!(response.segments.unwrap()[0].end, 2.5);
+
+        let text = parse_response("Hello there", Some("text")).unwrap();
+        assert_eq!(text.text, "Hello there");
+    }
+}
@@ -40,9 +40,26 @@ mod tests {
             assert!(capabilities.contains(&ProviderCapability::ChatCompletion));
             assert!(capabilities.contains(&ProviderCapability::ChatCompletionStream));
             assert!(capabilities.contains(&ProviderCapability::ToolCalling));
+            assert!(capabilities.contains(&ProviderCapability::AudioTranscription));
         });
     }
 
+    #[tokio::test]
+    async fn test_whisper_model_capabilities() {
+        let provider = GroqProvider::with_api_key("test-key").await.unwrap();
+        let whisper = provider
+            .models()
+            .iter()
+            .find(|m| m.id == "whisper-large-v3")
+            .unwrap();
+
+        assert_eq!(
+            whisper.capabilities,
+            vec![ProviderCapability::AudioTranscription]
+        );
+        assert!(!whisper.supports_streaming);
+    }
+
     #[test]
     fn test_model_info() {
         tokio::runtime::Runtime::new().unwrap().block_on(async {
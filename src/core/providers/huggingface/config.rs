@@ -0,0 +1,191 @@
+//! Hugging Face Provider Configuration
+
+use crate::core::traits::ProviderConfig;
+use serde::{Deserialize, Serialize};
+
+/// Inference Providers router used when no dedicated endpoint is configured
+pub const HF_ROUTER_API_BASE: &str = "https://router.huggingface.co";
+
+/// Inference task used to talk to a model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HuggingFaceTask {
+    /// OpenAI-compatible Messages API (`/v1/chat/completions`)
+    #[default]
+    Conversational,
+    /// Raw TGI `generate` API with a flattened prompt
+    TextGeneration,
+}
+
+impl HuggingFaceTask {
+    /// Parse a task name as used in model routes (`huggingface/<task>/<model>`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "conversational" => Some(Self::Conversational),
+            "text-generation" | "text-generation-inference" | "tgi" => Some(Self::TextGeneration),
+            _ => None,
+        }
+    }
+}
+
+/// Hugging Face provider configuration
+///
+/// Without `api_base` requests go to the serverless Inference Providers
+/// router; with it they go to a dedicated Inference Endpoint or a
+/// self-hosted Text Generation Inference (TGI) server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HuggingFaceConfig {
+    /// Hugging Face access token (optional for unauthenticated TGI servers)
+    pub api_key: Option<String>,
+
+    /// Dedicated endpoint or TGI server URL
+    pub api_base: Option<String>,
+
+    /// Request timeout in seconds
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+
+    /// Maximum number of retries
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Task used when the model route does not name one
+    #[serde(default)]
+    pub task: HuggingFaceTask,
+}
+
+impl Default for HuggingFaceConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_base: None,
+            timeout: default_timeout(),
+            max_retries: default_max_retries(),
+            task: HuggingFaceTask::default(),
+        }
+    }
+}
+
+impl HuggingFaceConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `HUGGINGFACE_API_KEY` (falling back to the hub's `HF_TOKEN`) and
+    /// `HUGGINGFACE_API_BASE`.
+    pub fn from_env() -> Self {
+        Self {
+            api_key: std::env::var("HUGGINGFACE_API_KEY")
+                .or_else(|_| std::env::var("HF_TOKEN"))
+                .ok(),
+            api_base: std::env::var("HUGGINGFACE_API_BASE").ok(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether requests go to a dedicated endpoint rather than the router
+    pub fn is_dedicated_endpoint(&self) -> bool {
+        self.api_base.is_some()
+    }
+
+    /// Get the API base URL without a trailing slash
+    pub fn get_api_base(&self) -> String {
+        self.api_base
+            .as_deref()
+            .unwrap_or(HF_ROUTER_API_BASE)
+            .trim_end_matches('/')
+            .to_string()
+    }
+}
+
+impl ProviderConfig for HuggingFaceConfig {
+    fn validate(&self) -> Result<(), String> {
+        // The router always needs a token; a private TGI server may not
+        if self.api_key.is_none() && self.api_base.is_none() {
+            return Err("huggingface API key is required".to_string());
+        }
+
+        if self.timeout == 0 {
+            return Err("Timeout must be greater than 0".to_string());
+        }
+
+        if let Some(api_base) = &self.api_base {
+            if !api_base.starts_with("http://") && !api_base.starts_with("https://") {
+                return Err("Hugging Face API base must start with http:// or https://".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout)
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+fn default_timeout() -> u64 {
+    // Cold serverless models can take a while to load
+    120
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_router_requires_api_key() {
+        let config = HuggingFaceConfig::default();
+        assert!(config.validate().unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_tgi_without_api_key() {
+        let config = HuggingFaceConfig {
+            api_base: Some("http://localhost:8080/".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert!(config.is_dedicated_endpoint());
+        assert_eq!(config.get_api_base(), "http://localhost:8080");
+    }
+
+    #[test]
+    fn test_default_api_base_is_router() {
+        let config = HuggingFaceConfig {
+            api_key: Some("hf_test".to_string()),
+            ..Default::default()
+        };
+        assert!(!config.is_dedicated_endpoint());
+        assert_eq!(config.get_api_base(), HF_ROUTER_API_BASE);
+    }
+
+    #[test]
+    fn test_task_names() {
+        assert_eq!(
+            HuggingFaceTask::from_name("tgi"),
+            Some(HuggingFaceTask::TextGeneration)
+        );
+        assert_eq!(
+            HuggingFaceTask::from_name("conversational"),
+            Some(HuggingFaceTask::Conversational)
+        );
+        assert_eq!(HuggingFaceTask::from_name("meta-llama"), None);
+
+        let task: HuggingFaceTask = serde_json::from_str("\"text-generation\"").unwrap();
+        assert_eq!(task, HuggingFaceTask::TextGeneration);
+    }
+}
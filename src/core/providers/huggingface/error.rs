@@ -0,0 +1,77 @@
+//! Hugging Face Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Hugging Face router and TGI servers
+#[derive(Debug)]
+pub struct HuggingFaceErrorMapper;
+
+impl ErrorMapper<ProviderError> for HuggingFaceErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let message = extract_error_message(response_body);
+
+        match status_code {
+            400 | 422 => ProviderError::invalid_request("huggingface", message),
+            401 | 403 => ProviderError::authentication("huggingface", message),
+            404 => ProviderError::model_not_found("huggingface", message),
+            429 => ProviderError::rate_limit("huggingface", None),
+            // Serverless models answer 503 while they are being loaded
+            503 => ProviderError::provider_unavailable("huggingface", message),
+            _ => ProviderError::api_error("huggingface", status_code, message),
+        }
+    }
+}
+
+/// TGI reports `{"error": "...", "error_type": "..."}` while the router uses
+/// OpenAI's `{"error": {"message": "..."}}`
+pub(crate) fn extract_error_message(response_body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_body)
+        .ok()
+        .and_then(|v| {
+            let error = v.get("error")?;
+            error
+                .as_str()
+                .or_else(|| error.get("message").and_then(|m| m.as_str()))
+                .map(String::from)
+        })
+        .unwrap_or_else(|| response_body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tgi_error_format() {
+        let err = HuggingFaceErrorMapper.map_http_error(
+            422,
+            r#"{"error":"Input validation error: `inputs` must not be empty","error_type":"validation"}"#,
+        );
+        match err {
+            ProviderError::InvalidRequest { message, .. } => {
+                assert!(message.starts_with("Input validation error"))
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_router_error_format() {
+        let err = HuggingFaceErrorMapper
+            .map_http_error(401, r#"{"error":{"message":"Invalid credentials"}}"#);
+        match err {
+            ProviderError::Authentication { message, .. } => {
+                assert_eq!(message, "Invalid credentials")
+            }
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_model_loading() {
+        let err = HuggingFaceErrorMapper.map_http_error(503, r#"{"error":"Model is loading"}"#);
+        assert!(matches!(err, ProviderError::ProviderUnavailable { .. }));
+        assert!(err.is_retryable());
+    }
+}
@@ -0,0 +1,15 @@
+//! Hugging Face Provider
+//!
+//! Chat and streaming for Hub models via the serverless Inference Providers
+//! router, dedicated Inference Endpoints, or self-hosted Text Generation
+//! Inference (TGI) servers.
+
+pub mod config;
+pub mod error;
+pub mod provider;
+pub mod streaming;
+pub mod transformation;
+
+pub use config::{HuggingFaceConfig, HuggingFaceTask};
+pub use error::HuggingFaceErrorMapper;
+pub use provider::HuggingFaceProvider;
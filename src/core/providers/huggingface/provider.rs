@@ -0,0 +1,378 @@
+//! Hugging Face Provider Implementation
+//!
+//! Serves Hub models through the serverless Inference Providers router, or
+//! any dedicated Inference Endpoint / Text Generation Inference server.
+
+use async_trait::async_trait;
+use futures::Stream;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::core::providers::base::{GlobalPoolManager, HeaderPair, HttpMethod, header};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::ChatRequest,
+    responses::{ChatChunk, ChatResponse},
+};
+
+use super::config::HuggingFaceTask;
+use super::streaming::{create_chat_stream, create_tgi_stream};
+use super::transformation::{
+    resolve_model, transform_conversational_request, transform_text_generation_request,
+    transform_text_generation_response,
+};
+use super::{HuggingFaceConfig, HuggingFaceErrorMapper};
+
+/// OpenAI parameters understood by both the Messages and `generate` APIs
+const HUGGINGFACE_SUPPORTED_PARAMS: &[&str] = &[
+    "temperature",
+    "top_p",
+    "max_tokens",
+    "max_completion_tokens",
+    "stop",
+    "seed",
+    "frequency_penalty",
+    "stream",
+    "tools",
+    "tool_choice",
+    "response_format",
+];
+
+#[derive(Debug, Clone)]
+pub struct HuggingFaceProvider {
+    config: HuggingFaceConfig,
+    pool_manager: Arc<GlobalPoolManager>,
+}
+
+impl HuggingFaceProvider {
+    pub fn new(config: HuggingFaceConfig) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration("huggingface", e))?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration("huggingface", e.to_string()))?,
+        );
+
+        Ok(Self {
+            config,
+            pool_manager,
+        })
+    }
+
+    pub fn from_env() -> Result<Self, ProviderError> {
+        Self::new(HuggingFaceConfig::from_env())
+    }
+
+    /// Generate headers for Hugging Face API requests
+    fn get_request_headers(&self) -> Vec<HeaderPair> {
+        let mut headers = Vec::with_capacity(1);
+
+        if let Some(api_key) = &self.config.api_key {
+            headers.push(header("Authorization", format!("Bearer {}", api_key)));
+        }
+
+        headers
+    }
+
+    /// Resolve the URL for a task
+    ///
+    /// Dedicated endpoints serve a single model, so the model id only appears
+    /// in router URLs for the text-generation task.
+    fn endpoint(&self, task: HuggingFaceTask, model_id: &str, stream: bool) -> String {
+        let base = self.config.get_api_base();
+
+        match task {
+            HuggingFaceTask::Conversational => {
+                let base = base.trim_end_matches("/v1");
+                format!("{}/v1/chat/completions", base)
+            }
+            HuggingFaceTask::TextGeneration if self.config.is_dedicated_endpoint() => {
+                let route = if stream {
+                    "generate_stream"
+                } else {
+                    "generate"
+                };
+                format!("{}/{}", base, route)
+            }
+            HuggingFaceTask::TextGeneration => {
+                format!("{}/hf-inference/models/{}", base, model_id)
+            }
+        }
+    }
+
+    /// Build the request URL and body for a chat request
+    fn prepare(
+        &self,
+        request: &ChatRequest,
+        stream: bool,
+    ) -> Result<(String, Value, HuggingFaceTask), ProviderError> {
+        let (task, model_id) = resolve_model(&request.model, self.config.task);
+
+        let body = match task {
+            HuggingFaceTask::Conversational => {
+                let mut body = transform_conversational_request(request, model_id)?;
+                body["stream"] = Value::Bool(stream);
+                body
+            }
+            HuggingFaceTask::TextGeneration => transform_text_generation_request(request, stream),
+        };
+
+        Ok((self.endpoint(task, model_id, stream), body, task))
+    }
+
+    /// POST a JSON body and map non-success statuses through the error mapper
+    async fn post(&self, url: &str, body: Value) -> Result<reqwest::Response, ProviderError> {
+        let response = self
+            .pool_manager
+            .execute_request(
+                url,
+                HttpMethod::POST,
+                self.get_request_headers(),
+                Some(body),
+            )
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(HuggingFaceErrorMapper.map_http_error(status.as_u16(), &error_text));
+        }
+
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl LLMProvider for HuggingFaceProvider {
+    type Config = HuggingFaceConfig;
+    type Error = ProviderError;
+    type ErrorMapper = HuggingFaceErrorMapper;
+
+    fn name(&self) -> &'static str {
+        "huggingface"
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        &[
+            ProviderCapability::ChatCompletion,
+            ProviderCapability::ChatCompletionStream,
+            ProviderCapability::ToolCalling,
+        ]
+    }
+
+    /// Any Hub model can be requested, so no static list is kept
+    fn models(&self) -> &[ModelInfo] {
+        &[]
+    }
+
+    fn supports_model(&self, model: &str) -> bool {
+        model.starts_with("huggingface/") || self.config.is_dedicated_endpoint()
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        HUGGINGFACE_SUPPORTED_PARAMS
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        Ok(params
+            .into_iter()
+            .filter(|(key, _)| HUGGINGFACE_SUPPORTED_PARAMS.contains(&key.as_str()))
+            .collect())
+    }
+
+    async fn transform_request(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        let (_, body, _) = self.prepare(&request, false)?;
+        Ok(body)
+    }
+
+    async fn transform_response(
+        &self,
+        raw_response: &[u8],
+        model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        match resolve_model(model, self.config.task) {
+            (HuggingFaceTask::Conversational, _) => serde_json::from_slice(raw_response)
+                .map_err(|e| ProviderError::response_parsing("huggingface", e.to_string())),
+            (HuggingFaceTask::TextGeneration, model_id) => {
+                transform_text_generation_response(raw_response, model_id)
+            }
+        }
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        HuggingFaceErrorMapper
+    }
+
+    async fn chat_completion(
+        &self,
+        request: ChatRequest,
+        context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        let (url, body, _) = self.prepare(&request, false)?;
+
+        let response = self.post(&url, body).await?;
+        let response_bytes = response
+            .bytes()
+            .await
+            .map_err(|e| ProviderError::network("huggingface", e.to_string()))?;
+
+        self.transform_response(&response_bytes, &request.model, &context.request_id)
+            .await
+    }
+
+    async fn chat_completion_stream(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ChatChunk, Self::Error>> + Send>>, Self::Error>
+    {
+        let (url, body, task) = self.prepare(&request, true)?;
+
+        let response = self.post(&url, body).await?;
+        match task {
+            HuggingFaceTask::Conversational => {
+                Ok(Box::pin(create_chat_stream(response.bytes_stream())))
+            }
+            HuggingFaceTask::TextGeneration => {
+                let (_, model_id) = resolve_model(&request.model, task);
+                Ok(Box::pin(create_tgi_stream(
+                    response.bytes_stream(),
+                    model_id,
+                )))
+            }
+        }
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        if !self.config.is_dedicated_endpoint() {
+            return if self.config.api_key.is_some() {
+                HealthStatus::Healthy
+            } else {
+                HealthStatus::Unhealthy
+            };
+        }
+
+        // TGI exposes a readiness probe
+        let url = format!("{}/health", self.config.get_api_base());
+        match self
+            .pool_manager
+            .execute_request(&url, HttpMethod::GET, self.get_request_headers(), None)
+            .await
+        {
+            Ok(response) if response.status().is_success() => HealthStatus::Healthy,
+            _ => HealthStatus::Unhealthy,
+        }
+    }
+
+    async fn calculate_cost(
+        &self,
+        _model: &str,
+        _input_tokens: u32,
+        _output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        // Inference is billed by compute time, not per token
+        Ok(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::ChatMessage;
+
+    fn router_provider() -> HuggingFaceProvider {
+        HuggingFaceProvider::new(HuggingFaceConfig {
+            api_key: Some("hf_test".to_string()),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn tgi_provider() -> HuggingFaceProvider {
+        HuggingFaceProvider::new(HuggingFaceConfig {
+            api_base: Some("http://localhost:8080".to_string()),
+            task: HuggingFaceTask::TextGeneration,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_router_endpoints() {
+        let provider = router_provider();
+        assert_eq!(
+            provider.endpoint(
+                HuggingFaceTask::Conversational,
+                "meta-llama/Llama-3.1-8B-Instruct",
+                false
+            ),
+            "https://router.huggingface.co/v1/chat/completions"
+        );
+        assert_eq!(
+            provider.endpoint(HuggingFaceTask::TextGeneration, "gpt2", true),
+            "https://router.huggingface.co/hf-inference/models/gpt2"
+        );
+    }
+
+    #[test]
+    fn test_tgi_endpoints() {
+        let provider = tgi_provider();
+        assert_eq!(
+            provider.endpoint(HuggingFaceTask::TextGeneration, "tgi", false),
+            "http://localhost:8080/generate"
+        );
+        assert_eq!(
+            provider.endpoint(HuggingFaceTask::TextGeneration, "tgi", true),
+            "http://localhost:8080/generate_stream"
+        );
+        assert_eq!(
+            provider.endpoint(HuggingFaceTask::Conversational, "tgi", false),
+            "http://localhost:8080/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn test_prepare_uses_route_task() {
+        let provider = router_provider();
+        let mut request = ChatRequest::new("huggingface/text-generation/gpt2");
+        request.messages = vec![ChatMessage::default()];
+
+        let (url, body, task) = provider.prepare(&request, false).unwrap();
+        assert_eq!(task, HuggingFaceTask::TextGeneration);
+        assert!(url.ends_with("/hf-inference/models/gpt2"));
+        assert!(body.get("inputs").is_some());
+    }
+
+    #[test]
+    fn test_supports_model() {
+        assert!(router_provider().supports_model("huggingface/Qwen/Qwen2.5-7B-Instruct"));
+        assert!(!router_provider().supports_model("Qwen/Qwen2.5-7B-Instruct"));
+        assert!(tgi_provider().supports_model("tgi"));
+    }
+
+    #[tokio::test]
+    async fn test_transform_response_by_task() {
+        let provider = tgi_provider();
+        let response = provider
+            .transform_response(br#"{"generated_text":"Hi"}"#, "tgi", "req")
+            .await
+            .unwrap();
+        assert_eq!(response.first_content(), Some("Hi"));
+    }
+}
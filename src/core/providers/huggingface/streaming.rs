@@ -0,0 +1,166 @@
+//! Hugging Face Streaming Support
+//!
+//! The conversational task streams OpenAI-compatible SSE. TGI's
+//! `generate_stream` emits one token per event instead, which
+//! [`TgiStreamTransformer`] converts into chat chunks.
+
+use bytes::Bytes;
+use futures::Stream;
+use serde::Deserialize;
+use std::pin::Pin;
+
+use super::transformation::{TgiDetails, details_to_usage, parse_finish_reason};
+use crate::core::providers::base::sse::{
+    OpenAICompatibleTransformer, SSETransformer, UnifiedSSEStream,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::responses::{ChatChunk, ChatDelta, ChatStreamChoice};
+
+type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>;
+
+pub type HuggingFaceChatStream = UnifiedSSEStream<ByteStream, OpenAICompatibleTransformer>;
+
+pub type TgiStream = UnifiedSSEStream<ByteStream, TgiStreamTransformer>;
+
+/// Create a stream for the conversational (Messages API) task
+pub fn create_chat_stream(
+    stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
+) -> HuggingFaceChatStream {
+    let transformer = OpenAICompatibleTransformer::new("huggingface");
+    UnifiedSSEStream::new(Box::pin(stream), transformer)
+}
+
+/// Create a stream for TGI's `generate_stream`
+pub fn create_tgi_stream(
+    stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
+    model: &str,
+) -> TgiStream {
+    UnifiedSSEStream::new(Box::pin(stream), TgiStreamTransformer::new(model))
+}
+
+#[derive(Debug, Deserialize)]
+struct TgiToken {
+    text: String,
+    #[serde(default)]
+    special: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TgiStreamEvent {
+    token: TgiToken,
+    /// Only present on the final event
+    #[serde(default)]
+    details: Option<TgiDetails>,
+}
+
+/// Transformer for TGI token events
+#[derive(Debug, Clone)]
+pub struct TgiStreamTransformer {
+    id: String,
+    model: String,
+}
+
+impl TgiStreamTransformer {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            id: format!("chatcmpl-{}", uuid::Uuid::new_v4().simple()),
+            model: model.into(),
+        }
+    }
+}
+
+impl SSETransformer for TgiStreamTransformer {
+    fn provider_name(&self) -> &'static str {
+        "huggingface"
+    }
+
+    fn transform_chunk(&self, data: &str) -> Result<Option<ChatChunk>, ProviderError> {
+        let event: TgiStreamEvent = serde_json::from_str(data).map_err(|e| {
+            ProviderError::response_parsing(
+                "huggingface",
+                format!("Failed to parse SSE JSON: {}", e),
+            )
+        })?;
+
+        // End-of-sequence and other special tokens carry no visible text
+        let content = (!event.token.special).then_some(event.token.text);
+        let finish_reason = event
+            .details
+            .as_ref()
+            .map(|d| parse_finish_reason(d.finish_reason.as_deref().unwrap_or_default()));
+
+        if content.is_none() && finish_reason.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(ChatChunk {
+            id: self.id.clone(),
+            object: "chat.completion.chunk".to_string(),
+            created: chrono::Utc::now().timestamp(),
+            model: self.model.clone(),
+            choices: vec![ChatStreamChoice {
+                index: 0,
+                delta: ChatDelta {
+                    role: None,
+                    content,
+                    thinking: None,
+                    tool_calls: None,
+                    function_call: None,
+                },
+                finish_reason,
+                logprobs: None,
+            }],
+            usage: event.details.as_ref().and_then(details_to_usage),
+            system_fingerprint: None,
+            provider_specific_fields: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::responses::FinishReason;
+    use futures::{StreamExt, stream};
+
+    #[tokio::test]
+    async fn test_chat_stream() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "data: {\"id\":\"\",\"object\":\"chat.completion.chunk\",\"created\":1718000000,\"model\":\"meta-llama/Llama-3.1-8B-Instruct\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Hi\"},\"finish_reason\":null}]}\n\n",
+            )),
+            Ok(Bytes::from("data: [DONE]\n\n")),
+        ];
+
+        let mut chat_stream = create_chat_stream(stream::iter(test_data));
+
+        let chunk = chat_stream.next().await.unwrap().unwrap();
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+        assert!(chat_stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tgi_stream() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "data:{\"index\":1,\"token\":{\"id\":9906,\"text\":\"Hello\",\"logprob\":-0.1,\"special\":false},\"generated_text\":null,\"details\":null}\n\n",
+            )),
+            Ok(Bytes::from(
+                "data:{\"index\":2,\"token\":{\"id\":2,\"text\":\"</s>\",\"logprob\":0.0,\"special\":true},\"generated_text\":\"Hello\",\"details\":{\"finish_reason\":\"eos_token\",\"generated_tokens\":2,\"seed\":null}}\n\n",
+            )),
+        ];
+
+        let mut tgi_stream = create_tgi_stream(stream::iter(test_data), "tgi");
+
+        let first = tgi_stream.next().await.unwrap().unwrap();
+        assert_eq!(first.choices[0].delta.content.as_deref(), Some("Hello"));
+        assert!(first.choices[0].finish_reason.is_none());
+
+        let last = tgi_stream.next().await.unwrap().unwrap();
+        assert!(last.choices[0].delta.content.is_none());
+        assert_eq!(last.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert_eq!(last.usage.unwrap().completion_tokens, 2);
+
+        assert!(tgi_stream.next().await.is_none());
+    }
+}
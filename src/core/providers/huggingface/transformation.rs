@@ -0,0 +1,286 @@
+//! Hugging Face Request/Response Transformation
+//!
+//! The conversational task speaks the OpenAI Messages API; the
+//! text-generation task flattens the conversation into a prompt for TGI's
+//! `generate` API.
+
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+
+use super::config::HuggingFaceTask;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::{ChatMessage, ChatRequest, MessageRole},
+    responses::{ChatChoice, ChatResponse, FinishReason, Usage},
+};
+
+/// Split a route like `huggingface/text-generation/bigcode/starcoder2-15b`
+/// into its task and Hub model id
+pub fn resolve_model(model: &str, default_task: HuggingFaceTask) -> (HuggingFaceTask, &str) {
+    let model = model.strip_prefix("huggingface/").unwrap_or(model);
+
+    if let Some((first, rest)) = model.split_once('/') {
+        if let Some(task) = HuggingFaceTask::from_name(first) {
+            return (task, rest);
+        }
+    }
+
+    (default_task, model)
+}
+
+/// Build a Messages API request body for the conversational task
+pub fn transform_conversational_request(
+    request: &ChatRequest,
+    model_id: &str,
+) -> Result<Value, ProviderError> {
+    let mut body = serde_json::to_value(request)
+        .map_err(|e| ProviderError::serialization("huggingface", e.to_string()))?;
+
+    if let Some(obj) = body.as_object_mut() {
+        obj.insert("model".to_string(), json!(model_id));
+
+        if let Some(max_completion_tokens) = obj.remove("max_completion_tokens") {
+            obj.entry("max_tokens").or_insert(max_completion_tokens);
+        }
+
+        obj.remove("thinking");
+        obj.remove("functions");
+        obj.remove("function_call");
+    }
+
+    Ok(body)
+}
+
+/// Flatten a conversation into a plain prompt ending with an open assistant turn
+pub fn format_prompt(messages: &[ChatMessage]) -> String {
+    let mut prompt = String::new();
+
+    for message in messages {
+        let role = match message.role {
+            MessageRole::System => "System",
+            MessageRole::User => "User",
+            MessageRole::Assistant => "Assistant",
+            MessageRole::Tool | MessageRole::Function => "Tool",
+        };
+        let content = message
+            .content
+            .as_ref()
+            .map(|c| c.to_string())
+            .unwrap_or_default();
+        prompt.push_str(&format!("{}: {}\n\n", role, content));
+    }
+
+    prompt.push_str("Assistant:");
+    prompt
+}
+
+/// Build a TGI `generate` request body for the text-generation task
+pub fn transform_text_generation_request(request: &ChatRequest, stream: bool) -> Value {
+    let mut parameters = Map::new();
+    parameters.insert("return_full_text".to_string(), json!(false));
+    parameters.insert("details".to_string(), json!(true));
+
+    if let Some(max_tokens) = request.max_completion_tokens.or(request.max_tokens) {
+        parameters.insert("max_new_tokens".to_string(), json!(max_tokens));
+    }
+    // TGI rejects a temperature of exactly zero; greedy decoding is the default
+    if let Some(temperature) = request.temperature.filter(|t| *t > 0.0) {
+        parameters.insert("temperature".to_string(), json!(temperature));
+        parameters.insert("do_sample".to_string(), json!(true));
+    }
+    if let Some(top_p) = request.top_p {
+        parameters.insert("top_p".to_string(), json!(top_p));
+    }
+    if let Some(stop) = &request.stop {
+        parameters.insert("stop".to_string(), json!(stop));
+    }
+    if let Some(seed) = request.seed {
+        parameters.insert("seed".to_string(), json!(seed));
+    }
+    if let Some(penalty) = request.frequency_penalty {
+        parameters.insert("frequency_penalty".to_string(), json!(penalty));
+    }
+    // Native options such as `top_k` or `repetition_penalty`
+    for (key, value) in &request.extra_params {
+        parameters.insert(key.clone(), value.clone());
+    }
+
+    json!({
+        "inputs": format_prompt(&request.messages),
+        "parameters": parameters,
+        "stream": stream,
+    })
+}
+
+/// Generation details returned when `details: true`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TgiDetails {
+    #[serde(default)]
+    pub finish_reason: Option<String>,
+    #[serde(default)]
+    pub generated_tokens: Option<u32>,
+    #[serde(default)]
+    pub prefill: Vec<Value>,
+}
+
+/// A single `generate` result
+#[derive(Debug, Clone, Deserialize)]
+pub struct TgiGeneration {
+    pub generated_text: String,
+    #[serde(default)]
+    pub details: Option<TgiDetails>,
+}
+
+/// Map TGI's finish reasons onto the unified ones
+pub fn parse_finish_reason(reason: &str) -> FinishReason {
+    match reason {
+        "length" => FinishReason::Length,
+        _ => FinishReason::Stop,
+    }
+}
+
+/// Convert TGI generation details into usage
+pub fn details_to_usage(details: &TgiDetails) -> Option<Usage> {
+    details
+        .generated_tokens
+        .map(|generated| Usage::new(details.prefill.len() as u32, generated))
+}
+
+/// Parse a `generate` response
+///
+/// TGI returns a single object while the serverless API wraps it in an array.
+pub fn transform_text_generation_response(
+    raw_response: &[u8],
+    model: &str,
+) -> Result<ChatResponse, ProviderError> {
+    let value: Value = serde_json::from_slice(raw_response)
+        .map_err(|e| ProviderError::response_parsing("huggingface", e.to_string()))?;
+    let value = match value {
+        Value::Array(mut items) if !items.is_empty() => items.swap_remove(0),
+        other => other,
+    };
+    let generation: TgiGeneration = serde_json::from_value(value)
+        .map_err(|e| ProviderError::response_parsing("huggingface", e.to_string()))?;
+
+    let details = generation.details.unwrap_or_default();
+
+    Ok(ChatResponse {
+        model: model.to_string(),
+        choices: vec![ChatChoice {
+            index: 0,
+            message: ChatMessage {
+                role: MessageRole::Assistant,
+                content: Some(generation.generated_text.trim_start().to_string().into()),
+                ..Default::default()
+            },
+            finish_reason: Some(
+                details
+                    .finish_reason
+                    .as_deref()
+                    .map(parse_finish_reason)
+                    .unwrap_or(FinishReason::Stop),
+            ),
+            logprobs: None,
+        }],
+        usage: details_to_usage(&details),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::MessageContent;
+
+    fn message(role: MessageRole, text: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_model() {
+        assert_eq!(
+            resolve_model(
+                "huggingface/meta-llama/Llama-3.1-8B-Instruct",
+                HuggingFaceTask::Conversational
+            ),
+            (
+                HuggingFaceTask::Conversational,
+                "meta-llama/Llama-3.1-8B-Instruct"
+            )
+        );
+        assert_eq!(
+            resolve_model(
+                "huggingface/text-generation/bigcode/starcoder2-15b",
+                HuggingFaceTask::Conversational
+            ),
+            (HuggingFaceTask::TextGeneration, "bigcode/starcoder2-15b")
+        );
+        assert_eq!(
+            resolve_model("tgi", HuggingFaceTask::TextGeneration),
+            (HuggingFaceTask::TextGeneration, "tgi")
+        );
+    }
+
+    #[test]
+    fn test_conversational_request() {
+        let mut request = ChatRequest::new("huggingface/meta-llama/Llama-3.1-8B-Instruct");
+        request.messages = vec![message(MessageRole::User, "Hi")];
+        request.max_completion_tokens = Some(64);
+
+        let body =
+            transform_conversational_request(&request, "meta-llama/Llama-3.1-8B-Instruct").unwrap();
+        assert_eq!(body["model"], "meta-llama/Llama-3.1-8B-Instruct");
+        assert_eq!(body["max_tokens"], 64);
+        assert_eq!(body["messages"][0]["content"], "Hi");
+    }
+
+    #[test]
+    fn test_text_generation_request() {
+        let mut request = ChatRequest::new("bigcode/starcoder2-15b");
+        request.messages = vec![
+            message(MessageRole::System, "Be brief."),
+            message(MessageRole::User, "Hi"),
+        ];
+        request.max_tokens = Some(32);
+        request.temperature = Some(0.0);
+        request.stop = Some(vec!["\nUser:".to_string()]);
+
+        let body = transform_text_generation_request(&request, true);
+        assert_eq!(
+            body["inputs"],
+            "System: Be brief.\n\nUser: Hi\n\nAssistant:"
+        );
+        assert_eq!(body["parameters"]["max_new_tokens"], 32);
+        assert_eq!(body["parameters"]["return_full_text"], false);
+        assert!(body["parameters"].get("temperature").is_none());
+        assert_eq!(body["parameters"]["stop"][0], "\nUser:");
+        assert_eq!(body["stream"], true);
+    }
+
+    #[test]
+    fn test_text_generation_response_tgi() {
+        let raw = br#"{"generated_text":" Hello!","details":{"finish_reason":"length","generated_tokens":2,"prefill":[]}}"#;
+        let response = transform_text_generation_response(raw, "tgi").unwrap();
+
+        assert_eq!(response.first_content(), Some("Hello!"));
+        assert_eq!(
+            response.choices[0].finish_reason,
+            Some(FinishReason::Length)
+        );
+        assert_eq!(response.usage.unwrap().completion_tokens, 2);
+    }
+
+    #[test]
+    fn test_text_generation_response_serverless() {
+        let raw = br#"[{"generated_text":"Hello!"}]"#;
+        let response = transform_text_generation_response(raw, "gpt2").unwrap();
+
+        assert_eq!(response.first_content(), Some("Hello!"));
+        assert_eq!(response.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert!(response.usage.is_none());
+    }
+}
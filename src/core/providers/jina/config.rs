@@ -0,0 +1,64 @@
+//! Jina AI Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(JinaConfig {});
+
+impl JinaConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `JINA_*`, falling back to the `JINA_AI_API_KEY` name.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("jina");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("JINA_AI_API_KEY").ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for JinaConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("jina")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jina_config_default_api_base() {
+        let config = JinaConfig::new("jina");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.jina.ai/v1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_jina_validate_missing_api_key() {
+        let mut config = JinaConfig::new("jina");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+}
@@ -0,0 +1,189 @@
+//! Jina AI Embeddings Transformation
+
+use serde_json::{Value, json};
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::EmbeddingRequest,
+    responses::{EmbeddingData, EmbeddingResponse, Usage},
+};
+
+/// Jina-specific request fields forwarded from `extra_params` untouched
+const PASSTHROUGH_PARAMS: &[&str] = &["task", "late_chunking", "normalized", "truncate"];
+
+/// Strip the routing prefix so `jina/jina-embeddings-v3` becomes `jina-embeddings-v3`
+pub fn normalize_model(model: &str) -> &str {
+    model.strip_prefix("jina/").unwrap_or(model)
+}
+
+/// Jina AI embeddings transformation
+pub struct JinaEmbedTransformation;
+
+impl JinaEmbedTransformation {
+    /// Map the unified `task_type` onto a jina-embeddings-v3 `task`
+    ///
+    /// Jina's own task names pass through; the Vertex-style upper-case task
+    /// types are translated to their closest LoRA adapter.
+    pub fn task(task_type: Option<&str>) -> Option<String> {
+        let task_type = task_type?;
+        let task = match task_type.to_uppercase().as_str() {
+            "RETRIEVAL_QUERY" => "retrieval.query",
+            "RETRIEVAL_DOCUMENT" => "retrieval.passage",
+            "CLASSIFICATION" => "classification",
+            "CLUSTERING" => "separation",
+            "SEMANTIC_SIMILARITY" => "text-matching",
+            _ => return Some(task_type.to_string()),
+        };
+        Some(task.to_string())
+    }
+
+    /// Build the `/v1/embeddings` request body
+    ///
+    /// An explicit `task` in `extra_params` wins over `task_type`. Output is
+    /// always requested as floats to fit [`EmbeddingResponse`].
+    pub fn transform_request(request: &EmbeddingRequest) -> Value {
+        let mut body = json!({
+            "model": normalize_model(&request.model),
+            "input": request.input.to_vec(),
+            "embedding_type": "float",
+        });
+
+        if let Some(task) = Self::task(request.task_type.as_deref()) {
+            body["task"] = json!(task);
+        }
+        if let Some(dimensions) = request.dimensions {
+            body["dimensions"] = json!(dimensions);
+        }
+        for key in PASSTHROUGH_PARAMS {
+            if let Some(value) = request.extra_params.get(*key) {
+                body[*key] = value.clone();
+            }
+        }
+
+        body
+    }
+
+    /// Convert a `/v1/embeddings` response
+    pub fn transform_response(
+        response: Value,
+        model: &str,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        let data = response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| ProviderError::response_parsing("jina", "Missing 'data' in response"))?
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let embedding: Vec<f32> = item
+                    .get("embedding")
+                    .cloned()
+                    .map(serde_json::from_value)
+                    .transpose()
+                    .map_err(|e| ProviderError::response_parsing("jina", e.to_string()))?
+                    .unwrap_or_default();
+                Ok(EmbeddingData {
+                    object: "embedding".to_string(),
+                    index: item
+                        .get("index")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(i as u64) as u32,
+                    embedding,
+                })
+            })
+            .collect::<Result<Vec<_>, ProviderError>>()?;
+
+        let usage = response.get("usage").and_then(|u| {
+            u.get("prompt_tokens")
+                .or_else(|| u.get("total_tokens"))
+                .and_then(|t| t.as_u64())
+                .map(|tokens| Usage::new(tokens as u32, 0))
+        });
+
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data,
+            model: normalize_model(model).to_string(),
+            usage,
+            embeddings: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::EmbeddingInput;
+    use std::collections::HashMap;
+
+    fn request() -> EmbeddingRequest {
+        EmbeddingRequest {
+            model: "jina/jina-embeddings-v3".to_string(),
+            input: EmbeddingInput::Text("hello".to_string()),
+            user: None,
+            encoding_format: None,
+            dimensions: Some(256),
+            task_type: Some("RETRIEVAL_DOCUMENT".to_string()),
+            extra_params: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let body = JinaEmbedTransformation::transform_request(&request());
+        assert_eq!(body["model"], "jina-embeddings-v3");
+        assert_eq!(body["input"][0], "hello");
+        assert_eq!(body["task"], "retrieval.passage");
+        assert_eq!(body["dimensions"], 256);
+        assert_eq!(body["embedding_type"], "float");
+        assert!(body.get("late_chunking").is_none());
+    }
+
+    #[test]
+    fn test_transform_request_passthrough() {
+        let mut req = request();
+        req.extra_params
+            .insert("task".to_string(), json!("code.query"));
+        req.extra_params
+            .insert("late_chunking".to_string(), json!(true));
+        req.extra_params.insert("foo".to_string(), json!("bar"));
+
+        let body = JinaEmbedTransformation::transform_request(&req);
+        assert_eq!(body["task"], "code.query");
+        assert_eq!(body["late_chunking"], true);
+        assert!(body.get("foo").is_none());
+    }
+
+    #[test]
+    fn test_task() {
+        assert_eq!(JinaEmbedTransformation::task(None), None);
+        assert_eq!(
+            JinaEmbedTransformation::task(Some("CLUSTERING")).as_deref(),
+            Some("separation")
+        );
+        assert_eq!(
+            JinaEmbedTransformation::task(Some("retrieval.query")).as_deref(),
+            Some("retrieval.query")
+        );
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "model": "jina-embeddings-v3",
+            "object": "list",
+            "usage": {"total_tokens": 8, "prompt_tokens": 8},
+            "data": [
+                {"object": "embedding", "index": 0, "embedding": [0.1, 0.2]},
+                {"object": "embedding", "index": 1, "embedding": [0.3, 0.4]}
+            ]
+        });
+
+        let response =
+            JinaEmbedTransformation::transform_response(raw, "jina/jina-embeddings-v3").unwrap();
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].embedding, vec![0.3, 0.4]);
+        assert_eq!(response.model, "jina-embeddings-v3");
+        assert_eq!(response.usage.unwrap().prompt_tokens, 8);
+    }
+}
@@ -0,0 +1,63 @@
+//! Jina AI Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Jina AI API
+#[derive(Debug)]
+pub struct JinaErrorMapper;
+
+impl ErrorMapper<ProviderError> for JinaErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let message = extract_error_message(response_body);
+
+        match status_code {
+            400 | 422 => ProviderError::invalid_request("jina", message),
+            401 | 403 => ProviderError::authentication("jina", message),
+            404 => ProviderError::model_not_found("jina", message),
+            429 => ProviderError::rate_limit("jina", None),
+            502..=504 => ProviderError::provider_unavailable("jina", message),
+            _ => ProviderError::api_error("jina", status_code, message),
+        }
+    }
+}
+
+/// Jina reports errors as `{"detail": "..."}`, with validation failures
+/// prefixed by the failing field
+fn extract_error_message(response_body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_body)
+        .ok()
+        .and_then(|v| v.get("detail").and_then(|m| m.as_str()).map(String::from))
+        .unwrap_or_else(|| response_body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jina_error_mapper_400() {
+        let err = JinaErrorMapper.map_http_error(
+            400,
+            r#"{"detail":"[RID: 5e1c] Model jina-embeddings-v9 not found"}"#,
+        );
+        match err {
+            ProviderError::InvalidRequest { message, .. } => {
+                assert_eq!(message, "[RID: 5e1c] Model jina-embeddings-v9 not found")
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_jina_error_mapper_401() {
+        let err = JinaErrorMapper.map_http_error(401, r#"{"detail":"Invalid API key"}"#);
+        assert!(matches!(err, ProviderError::Authentication { .. }));
+    }
+
+    #[test]
+    fn test_jina_error_mapper_503() {
+        let err = JinaErrorMapper.map_http_error(503, "");
+        assert!(matches!(err, ProviderError::ProviderUnavailable { .. }));
+    }
+}
@@ -0,0 +1,14 @@
+//! Jina AI Provider
+//!
+//! Embeddings (jina-embeddings-v3 and earlier) via `/v1/embeddings` and
+//! document reranking via `/v1/rerank`. Chat completion is not offered.
+
+pub mod config;
+pub mod embed;
+pub mod error;
+pub mod provider;
+pub mod rerank;
+
+pub use config::JinaConfig;
+pub use error::JinaErrorMapper;
+pub use provider::JinaProvider;
@@ -0,0 +1,309 @@
+//! Jina AI Provider Implementation
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::providers::base::{
+    GlobalPoolManager, HeaderPair, HttpMethod, get_pricing_db, header, header_owned,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::rerank::{RerankRequest, RerankResponse};
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::{ChatRequest, EmbeddingRequest},
+    responses::{ChatResponse, EmbeddingResponse},
+};
+
+use super::embed::{JinaEmbedTransformation, normalize_model};
+use super::rerank::JinaRerankTransformation;
+use super::{JinaConfig, JinaErrorMapper};
+
+#[derive(Debug, Clone)]
+pub struct JinaProvider {
+    config: JinaConfig,
+    pool_manager: Arc<GlobalPoolManager>,
+    supported_models: Vec<ModelInfo>,
+}
+
+impl JinaProvider {
+    /// Generate headers for Jina AI API requests
+    fn get_request_headers(&self) -> Vec<HeaderPair> {
+        let mut headers = Vec::with_capacity(2);
+
+        if let Some(api_key) = &self.config.base.api_key {
+            headers.push(header("Authorization", format!("Bearer {}", api_key)));
+        }
+
+        for (key, value) in &self.config.base.headers {
+            headers.push(header_owned(key.clone(), value.clone()));
+        }
+
+        headers
+    }
+
+    pub fn new(config: JinaConfig) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration("jina", e))?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration("jina", e.to_string()))?,
+        );
+
+        Ok(Self {
+            config,
+            pool_manager,
+            supported_models: Self::build_models(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, ProviderError> {
+        Self::new(JinaConfig::from_env())
+    }
+
+    /// Models with known pricing, tagged by endpoint
+    fn build_models() -> Vec<ModelInfo> {
+        let pricing_db = get_pricing_db();
+        let mut model_ids = pricing_db.get_provider_models("jina");
+        model_ids.sort();
+
+        model_ids
+            .iter()
+            .filter_map(|id| {
+                let mut info = pricing_db.to_model_info(id, "jina")?;
+                info.supports_streaming = false;
+                let mode = pricing_db.get_model_info(id).and_then(|p| p.mode.clone());
+                info.capabilities = match mode.as_deref() {
+                    Some("rerank") => vec![ProviderCapability::Rerank],
+                    _ => vec![ProviderCapability::Embeddings],
+                };
+                Some(info)
+            })
+            .collect()
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}{}",
+            self.config
+                .base
+                .get_effective_api_base("jina")
+                .trim_end_matches('/'),
+            path
+        )
+    }
+
+    /// POST a JSON body and decode the response, mapping non-success statuses
+    /// through the error mapper
+    async fn post_json(&self, path: &str, body: Value) -> Result<Value, ProviderError> {
+        let response = self
+            .pool_manager
+            .execute_request(
+                &self.endpoint(path),
+                HttpMethod::POST,
+                self.get_request_headers(),
+                Some(body),
+            )
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(JinaErrorMapper.map_http_error(status.as_u16(), &error_text));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("jina", e.to_string()))
+    }
+
+    /// Rerank documents by relevance to a query via `/v1/rerank`
+    pub async fn rerank(&self, request: RerankRequest) -> Result<RerankResponse, ProviderError> {
+        let body = JinaRerankTransformation::transform_request(&request)?;
+        let response = self.post_json("/rerank", body).await?;
+        JinaRerankTransformation::transform_response(response, &request)
+    }
+}
+
+#[async_trait]
+impl LLMProvider for JinaProvider {
+    type Config = JinaConfig;
+    type Error = ProviderError;
+    type ErrorMapper = JinaErrorMapper;
+
+    fn name(&self) -> &'static str {
+        "jina"
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        &[ProviderCapability::Embeddings, ProviderCapability::Rerank]
+    }
+
+    fn models(&self) -> &[ModelInfo] {
+        &self.supported_models
+    }
+
+    fn supports_model(&self, model: &str) -> bool {
+        let model = normalize_model(model);
+        self.supported_models.iter().any(|m| m.id == model)
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        &["dimensions", "task", "late_chunking"]
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        Ok(params
+            .into_iter()
+            .filter_map(|(key, value)| match key.as_str() {
+                "dimensions" | "task" | "late_chunking" => Some((key, value)),
+                _ => None,
+            })
+            .collect())
+    }
+
+    async fn transform_request(
+        &self,
+        _request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        Err(ProviderError::not_supported("jina", "chat completion"))
+    }
+
+    async fn transform_response(
+        &self,
+        _raw_response: &[u8],
+        _model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        Err(ProviderError::not_supported("jina", "chat completion"))
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        JinaErrorMapper
+    }
+
+    async fn chat_completion(
+        &self,
+        _request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        Err(ProviderError::not_supported("jina", "chat completion"))
+    }
+
+    async fn embeddings(
+        &self,
+        request: EmbeddingRequest,
+        _context: RequestContext,
+    ) -> Result<EmbeddingResponse, Self::Error> {
+        let body = JinaEmbedTransformation::transform_request(&request);
+        let response = self.post_json("/embeddings", body).await?;
+        JinaEmbedTransformation::transform_response(response, &request.model)
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        if self.config.base.api_key.is_some() {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+
+    async fn calculate_cost(
+        &self,
+        model: &str,
+        input_tokens: u32,
+        output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        let usage = crate::core::providers::base::pricing::Usage {
+            prompt_tokens: input_tokens,
+            completion_tokens: output_tokens,
+            total_tokens: input_tokens + output_tokens,
+            reasoning_tokens: None,
+        };
+
+        Ok(get_pricing_db().calculate(normalize_model(model), &usage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_provider() -> JinaProvider {
+        let mut config = JinaConfig::new("jina");
+        config.base.api_key = Some("jina_test".to_string());
+        JinaProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "jina");
+        assert!(provider.supports_embeddings());
+        assert!(!provider.supports_streaming());
+        assert!(provider.supports_model("jina/jina-embeddings-v3"));
+        assert!(!provider.supports_model("gpt-4"));
+    }
+
+    #[test]
+    fn test_models_tagged_by_endpoint() {
+        let provider = test_provider();
+        let rerank = provider
+            .models()
+            .iter()
+            .find(|m| m.id == "jina-reranker-v2-base-multilingual")
+            .unwrap();
+        assert_eq!(rerank.capabilities, vec![ProviderCapability::Rerank]);
+    }
+
+    #[tokio::test]
+    async fn test_chat_not_supported() {
+        let provider = test_provider();
+        let err = provider
+            .chat_completion(
+                ChatRequest::new("jina-embeddings-v3"),
+                RequestContext::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ProviderError::NotSupported { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_map_openai_params() {
+        let provider = test_provider();
+        let mut params = HashMap::new();
+        params.insert("dimensions".to_string(), serde_json::json!(256));
+        params.insert("late_chunking".to_string(), serde_json::json!(true));
+        params.insert("user".to_string(), serde_json::json!("u1"));
+
+        let mapped = provider
+            .map_openai_params(params, "jina-embeddings-v3")
+            .await
+            .unwrap();
+        assert_eq!(mapped.len(), 2);
+        assert_eq!(mapped["dimensions"], 256);
+        assert_eq!(mapped["late_chunking"], true);
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("jina/jina-embeddings-v3", 1_000_000, 0)
+            .await
+            .unwrap();
+        assert!((cost - 0.02).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,149 @@
+//! Jina AI Rerank Transformation
+
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use super::embed::normalize_model;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::rerank::{RerankRequest, RerankResponse, RerankResult, RerankUsage};
+
+/// Jina AI rerank transformation
+pub struct JinaRerankTransformation;
+
+impl JinaRerankTransformation {
+    /// Build the `/v1/rerank` request body
+    ///
+    /// Document text is never echoed back, since it is re-attached from the
+    /// request.
+    pub fn transform_request(request: &RerankRequest) -> Result<Value, ProviderError> {
+        if request.documents.is_empty() {
+            return Err(ProviderError::invalid_request(
+                "jina",
+                "Rerank requires at least one document",
+            ));
+        }
+
+        let documents: Vec<&str> = request.documents.iter().map(|d| d.get_text()).collect();
+
+        let mut body = json!({
+            "model": normalize_model(&request.model),
+            "query": request.query,
+            "documents": documents,
+        });
+
+        if let Some(top_n) = request.top_n {
+            body["top_n"] = json!(top_n);
+        }
+        body["return_documents"] = json!(false);
+
+        Ok(body)
+    }
+
+    /// Convert a `/v1/rerank` response
+    ///
+    /// Documents are re-attached from the request so structured documents keep
+    /// their metadata.
+    pub fn transform_response(
+        response: Value,
+        request: &RerankRequest,
+    ) -> Result<RerankResponse, ProviderError> {
+        let return_documents = request.return_documents.unwrap_or(true);
+
+        let results = response
+            .get("results")
+            .and_then(|r| r.as_array())
+            .ok_or_else(|| {
+                ProviderError::response_parsing("jina", "Missing 'results' in rerank response")
+            })?
+            .iter()
+            .map(|r| {
+                let index = r.get("index").and_then(|i| i.as_u64()).unwrap_or(0) as usize;
+                RerankResult {
+                    index,
+                    relevance_score: r
+                        .get("relevance_score")
+                        .and_then(|s| s.as_f64())
+                        .unwrap_or(0.0),
+                    document: if return_documents {
+                        request.documents.get(index).cloned()
+                    } else {
+                        None
+                    },
+                }
+            })
+            .collect();
+
+        let usage = response.get("usage").map(|u| RerankUsage {
+            query_tokens: u
+                .get("prompt_tokens")
+                .and_then(|t| t.as_u64())
+                .map(|t| t as u32),
+            total_tokens: u
+                .get("total_tokens")
+                .and_then(|t| t.as_u64())
+                .map(|t| t as u32),
+            ..Default::default()
+        });
+
+        Ok(RerankResponse {
+            id: format!("rerank-{}", uuid::Uuid::new_v4().simple()),
+            results,
+            model: normalize_model(&request.model).to_string(),
+            usage,
+            meta: HashMap::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rerank::RerankDocument;
+
+    fn request() -> RerankRequest {
+        RerankRequest {
+            model: "jina/jina-reranker-v2-base-multilingual".to_string(),
+            query: "capital of France".to_string(),
+            documents: vec![
+                RerankDocument::text("Berlin is in Germany"),
+                RerankDocument::text("Paris is the capital of France"),
+            ],
+            top_n: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let body = JinaRerankTransformation::transform_request(&request()).unwrap();
+        assert_eq!(body["model"], "jina-reranker-v2-base-multilingual");
+        assert_eq!(body["documents"][1], "Paris is the capital of France");
+        assert_eq!(body["top_n"], 1);
+        assert_eq!(body["return_documents"], false);
+    }
+
+    #[test]
+    fn test_transform_request_requires_documents() {
+        let mut req = request();
+        req.documents.clear();
+        assert!(JinaRerankTransformation::transform_request(&req).is_err());
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "model": "jina-reranker-v2-base-multilingual",
+            "usage": {"total_tokens": 26, "prompt_tokens": 26},
+            "results": [{"index": 1, "relevance_score": 0.91}]
+        });
+
+        let response = JinaRerankTransformation::transform_response(raw, &request()).unwrap();
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].index, 1);
+        assert_eq!(
+            response.results[0].document.as_ref().unwrap().get_text(),
+            "Paris is the capital of France"
+        );
+        assert_eq!(response.usage.unwrap().total_tokens, Some(26));
+    }
+}
@@ -229,6 +229,7 @@ impl LlamaChatTransformation {
             choices,
             usage,
             system_fingerprint,
+            provider_specific_fields: None,
         })
     }
 
@@ -325,6 +326,7 @@ impl LlamaChatTransformation {
             tool_calls,
             tool_call_id,
             function_call,
+            cache_control: None,
         })
     }
 
@@ -354,6 +356,9 @@ impl LlamaChatTransformation {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }
         })
     }
@@ -384,6 +384,7 @@ impl LLMProvider for LlamaProvider {
                     choices: vec![],
                     usage: None,
                     system_fingerprint: None,
+                    provider_specific_fields: None,
                 }
             })
         });
@@ -0,0 +1,331 @@
+//! MiniMax Client
+//!
+//! How `chatcompletion_v2` departs from OpenAI's chat completions.
+//! MiniMax-specific fields such as `mask_sensitive_info` pass through
+//! `extra_params` untouched.
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+use super::MiniMaxConfig;
+use super::error::check_base_resp;
+use super::speech::{MiniMaxSpeechTransformation, SPEECH_MODELS};
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
+use crate::core::providers::base::{
+    OpenAICompatibleClient, OpenAICompatibleProvider, openai_compatible,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::{ModelInfo, ProviderCapability};
+
+/// MiniMax API client logic
+#[derive(Debug, Clone)]
+pub struct MiniMaxClient;
+
+#[async_trait]
+impl OpenAICompatibleClient for MiniMaxClient {
+    type Config = MiniMaxConfig;
+
+    const NAME: &'static str = "minimax";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::ToolCalling,
+        ProviderCapability::TextToSpeech,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "n",
+        "stream",
+        "stream_options",
+        "tools",
+        "tool_choice",
+        "response_format",
+        "user",
+    ];
+
+    // Rejected by `chatcompletion_v2` instead of ignored
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &[
+        "frequency_penalty",
+        "presence_penalty",
+        "logit_bias",
+        "logprobs",
+        "top_logprobs",
+        "seed",
+        "functions",
+        "function_call",
+    ];
+
+    const CHAT_PATH: &'static str = "/text/chatcompletion_v2";
+
+    fn new(_config: &MiniMaxConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+
+    fn supports_model(models: &[ModelInfo], model: &str) -> bool {
+        let model = Self::normalize_model(model);
+        SPEECH_MODELS.contains(&model) || models.iter().any(|m| m.id == model)
+    }
+
+    /// Full URL for `path`, with the `GroupId` query parameter when configured
+    fn endpoint(config: &MiniMaxConfig, path: &str) -> String {
+        let base = config.base.get_effective_api_base(Self::NAME);
+        let url = format!("{}{}", base.trim_end_matches('/'), path);
+
+        match &config.group_id {
+            Some(group_id) => {
+                let query: String = url::form_urlencoded::Serializer::new(String::new())
+                    .append_pair("GroupId", group_id)
+                    .finish();
+                format!("{}?{}", url, query)
+            }
+            None => url,
+        }
+    }
+
+    fn check_response(value: &Value) -> Result<(), ProviderError> {
+        check_base_resp(value)
+    }
+
+    /// The final chunk repeats the whole reply under `message` instead of a
+    /// `delta`; only its finish reason and usage are kept
+    fn prepare_chunk(value: &mut Value) {
+        if let Some(choices) = value.get_mut("choices").and_then(|c| c.as_array_mut()) {
+            for choice in choices {
+                if let Some(obj) = choice.as_object_mut() {
+                    if obj.remove("message").is_some() {
+                        obj.entry("delta").or_insert(json!({}));
+                    }
+                }
+            }
+        }
+    }
+
+    fn map_http_error(status_code: u16, response_body: &str) -> ProviderError {
+        if let Ok(body) = serde_json::from_str::<Value>(response_body) {
+            if let Err(err) = check_base_resp(&body) {
+                return err;
+            }
+        }
+        openai_compatible::map_http_error(Self::NAME, status_code, response_body)
+    }
+
+    /// Synthesize speech via `/t2a_v2`
+    async fn speech(
+        provider: &OpenAICompatibleProvider<Self>,
+        request: SpeechRequest,
+    ) -> Result<SpeechResponse, ProviderError> {
+        if provider.config().group_id.is_none() {
+            return Err(ProviderError::configuration(
+                Self::NAME,
+                "group_id is required for text-to-speech",
+            ));
+        }
+
+        let body = MiniMaxSpeechTransformation::transform_request(&request)?;
+        let response: Value = provider
+            .post("/t2a_v2", body)
+            .await?
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing(Self::NAME, e.to_string()))?;
+
+        let format = request.response_format.as_deref().unwrap_or("mp3");
+        MiniMaxSpeechTransformation::transform_response(response, format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::base::openai_compatible::create_stream;
+    use crate::core::providers::minimax::MiniMaxProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{ChatMessage, ChatRequest, MessageContent, MessageRole};
+    use crate::core::types::responses::FinishReason;
+    use bytes::Bytes;
+    use futures::{StreamExt, stream};
+
+    fn test_provider() -> MiniMaxProvider {
+        let mut config = MiniMaxConfig::new("minimax");
+        config.base.api_key = Some("test-key".to_string());
+        MiniMaxProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("minimax/MiniMax-M2");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(256);
+        request.presence_penalty = Some(0.5);
+        request
+            .extra_params
+            .insert("mask_sensitive_info".to_string(), json!(false));
+
+        let body = MiniMaxClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "MiniMax-M2");
+        assert_eq!(body["max_tokens"], 256);
+        assert!(body.get("presence_penalty").is_none());
+        assert_eq!(body["mask_sensitive_info"], false);
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = br#"{
+            "id": "04ecb5d9b1921ae0fb0e8da9017a5474",
+            "object": "chat.completion",
+            "created": 1731493296,
+            "model": "MiniMax-M2",
+            "choices": [{
+                "index": 0,
+                "finish_reason": "stop",
+                "message": {"role": "assistant", "name": "MiniMax AI", "content": "Hi", "audio_content": ""}
+            }],
+            "usage": {"prompt_tokens": 9, "completion_tokens": 1, "total_tokens": 10},
+            "input_sensitive": false,
+            "output_sensitive": false,
+            "base_resp": {"status_code": 0, "status_msg": ""}
+        }"#;
+
+        let response = MiniMaxClient::transform_chat_response(raw).unwrap();
+        assert_eq!(response.first_content(), Some("Hi"));
+        assert_eq!(response.usage.unwrap().total_tokens, 10);
+    }
+
+    #[test]
+    fn test_transform_response_base_resp_error() {
+        let raw = br#"{
+            "id": "",
+            "choices": null,
+            "base_resp": {"status_code": 1008, "status_msg": "insufficient balance"}
+        }"#;
+
+        let err = MiniMaxClient::transform_chat_response(raw).unwrap_err();
+        assert!(matches!(err, ProviderError::QuotaExceeded { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "data: {\"id\":\"04ecb5d9b1921ae0fb0e8da9017a5474\",\"object\":\"chat.completion.chunk\",\"created\":1731493296,\"model\":\"MiniMax-M2\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Hi\"}}]}\n\n",
+            )),
+            Ok(Bytes::from(
+                "data: {\"id\":\"04ecb5d9b1921ae0fb0e8da9017a5474\",\"object\":\"chat.completion\",\"created\":1731493296,\"model\":\"MiniMax-M2\",\"choices\":[{\"index\":0,\"finish_reason\":\"stop\",\"message\":{\"role\":\"assistant\",\"content\":\"Hi\"}}],\"usage\":{\"prompt_tokens\":9,\"completion_tokens\":1,\"total_tokens\":10},\"base_resp\":{\"status_code\":0,\"status_msg\":\"\"}}\n\n",
+            )),
+        ];
+
+        let mut minimax_stream = create_stream::<MiniMaxClient>(stream::iter(test_data));
+
+        let chunk = minimax_stream.next().await.unwrap().unwrap();
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+
+        let chunk = minimax_stream.next().await.unwrap().unwrap();
+        assert!(chunk.choices[0].delta.content.is_none());
+        assert_eq!(chunk.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert_eq!(chunk.usage.unwrap().total_tokens, 10);
+    }
+
+    #[tokio::test]
+    async fn test_stream_base_resp_error() {
+        let test_data = vec![Ok(Bytes::from(
+            "data: {\"id\":\"\",\"choices\":null,\"base_resp\":{\"status_code\":1002,\"status_msg\":\"rate limit exceeded\"}}\n\n",
+        ))];
+
+        let mut minimax_stream = create_stream::<MiniMaxClient>(stream::iter(test_data));
+
+        let err = minimax_stream.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, ProviderError::RateLimit { .. }));
+    }
+
+    #[test]
+    fn test_map_http_error() {
+        assert!(matches!(
+            MiniMaxClient::map_http_error(401, "unauthorized"),
+            ProviderError::Authentication { .. }
+        ));
+        assert!(matches!(
+            MiniMaxClient::map_http_error(429, ""),
+            ProviderError::RateLimit { .. }
+        ));
+        assert!(matches!(
+            MiniMaxClient::map_http_error(
+                500,
+                r#"{"base_resp":{"status_code":1008,"status_msg":"insufficient balance"}}"#
+            ),
+            ProviderError::QuotaExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = MiniMaxConfig::new("minimax");
+        config.base.api_key = None;
+        assert!(MiniMaxProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "minimax");
+        assert!(provider.supports_streaming());
+        assert!(provider.supports_model("minimax/MiniMax-M2"));
+        assert!(provider.supports_model("minimax/speech-02-hd"));
+        assert!(!provider.supports_model("gpt-4"));
+        assert!(provider.models().iter().any(|m| m.id == "MiniMax-M2"));
+        assert!(provider.models().iter().all(|m| m.provider == "minimax"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint(MiniMaxClient::CHAT_PATH),
+            "https://api.minimax.io/v1/text/chatcompletion_v2"
+        );
+
+        let mut config = MiniMaxConfig::new("minimax");
+        config.base.api_key = Some("test-key".to_string());
+        config.group_id = Some("1782658868262748467".to_string());
+        let provider = MiniMaxProvider::new(config).unwrap();
+        assert_eq!(
+            provider.endpoint("/t2a_v2"),
+            "https://api.minimax.io/v1/t2a_v2?GroupId=1782658868262748467"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_speech_requires_group_id() {
+        let provider = test_provider();
+        let request = SpeechRequest {
+            input: "Hello".to_string(),
+            model: "speech-02-hd".to_string(),
+            voice: "male-qn-qingse".to_string(),
+            response_format: None,
+            speed: None,
+            instructions: None,
+        };
+        assert!(matches!(
+            MiniMaxClient::speech(&provider, request).await,
+            Err(ProviderError::Configuration { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("minimax/MiniMax-M2", 1_000_000, 1_000_000)
+            .await
+            .unwrap();
+        assert!((cost - 1.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,117 @@
+//! MiniMax Configuration
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::providers::base::BaseConfig;
+use crate::core::traits::ProviderConfig;
+
+/// MiniMax provider configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MiniMaxConfig {
+    /// Base configuration shared across all providers
+    #[serde(flatten)]
+    pub base: BaseConfig,
+
+    /// Account group id, sent as the `GroupId` query parameter
+    ///
+    /// Required by text-to-speech; chat works without it.
+    #[serde(default)]
+    pub group_id: Option<String>,
+}
+
+impl MiniMaxConfig {
+    pub fn new(provider: &str) -> Self {
+        Self {
+            base: BaseConfig::for_provider(provider),
+            group_id: None,
+        }
+    }
+
+    /// Create configuration from environment variables
+    ///
+    /// Reads `MINIMAX_*`; the account's group id comes from `MINIMAX_GROUP_ID`.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("minimax");
+        config.group_id = std::env::var("MINIMAX_GROUP_ID").ok();
+        config
+    }
+}
+
+impl AsRef<BaseConfig> for MiniMaxConfig {
+    fn as_ref(&self) -> &BaseConfig {
+        &self.base
+    }
+}
+
+impl ProviderConfig for MiniMaxConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("minimax")?;
+
+        if self.group_id.as_deref() == Some("") {
+            return Err("minimax group_id must not be empty".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimax_config_default_api_base() {
+        let config = MiniMaxConfig::new("minimax");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.minimax.io/v1".to_string())
+        );
+        assert!(config.group_id.is_none());
+    }
+
+    #[test]
+    fn test_minimax_validate_missing_api_key() {
+        let mut config = MiniMaxConfig::new("minimax");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_minimax_validate_empty_group_id() {
+        let mut config = MiniMaxConfig::new("minimax");
+        config.base.api_key = Some("test-key".to_string());
+        config.group_id = Some(String::new());
+        assert!(config.validate().is_err());
+
+        config.group_id = Some("1782658868262748467".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_minimax_config_deserialize_group_id() {
+        let config: MiniMaxConfig = serde_json::from_value(serde_json::json!({
+            "api_key": "test-key",
+            "group_id": "1782658868262748467"
+        }))
+        .unwrap();
+        assert_eq!(config.group_id.as_deref(), Some("1782658868262748467"));
+    }
+}
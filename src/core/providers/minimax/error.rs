@@ -0,0 +1,78 @@
+//! MiniMax Error Handling
+//!
+//! MiniMax answers most failures with HTTP 200 and a non-zero
+//! `base_resp.status_code`, so successful responses have to be checked too.
+
+use serde_json::Value;
+
+use crate::core::providers::unified_provider::ProviderError;
+
+/// Map a `base_resp` status code onto a provider error
+pub fn map_status_code(status_code: i64, message: &str) -> ProviderError {
+    match status_code {
+        1001 => ProviderError::timeout("minimax", message),
+        1002 | 1039 => ProviderError::rate_limit_simple("minimax", message),
+        1004 | 2049 => ProviderError::authentication("minimax", message),
+        1008 => ProviderError::quota_exceeded("minimax", message),
+        1026 | 1027 => ProviderError::content_filtered("minimax", message, None, Some(false)),
+        2013 => ProviderError::invalid_request("minimax", message),
+        _ => ProviderError::api_error("minimax", 500, format!("{} ({})", message, status_code)),
+    }
+}
+
+/// Fail if a response body carries a non-zero `base_resp.status_code`
+pub fn check_base_resp(body: &Value) -> Result<(), ProviderError> {
+    match body
+        .pointer("/base_resp/status_code")
+        .and_then(|c| c.as_i64())
+    {
+        None | Some(0) => Ok(()),
+        Some(code) => {
+            let message = body
+                .pointer("/base_resp/status_msg")
+                .and_then(|m| m.as_str())
+                .unwrap_or_default();
+            Err(map_status_code(code, message))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_base_resp() {
+        assert!(
+            check_base_resp(&json!({"base_resp": {"status_code": 0, "status_msg": ""}})).is_ok()
+        );
+        assert!(check_base_resp(&json!({"id": "x"})).is_ok());
+
+        let err = check_base_resp(&json!({
+            "base_resp": {"status_code": 1004, "status_msg": "login fail: Please carry the API secret key in the 'Authorization' field of the request header"}
+        }))
+        .unwrap_err();
+        assert!(matches!(err, ProviderError::Authentication { .. }));
+    }
+
+    #[test]
+    fn test_map_status_code() {
+        assert!(matches!(
+            map_status_code(1008, "insufficient balance"),
+            ProviderError::QuotaExceeded { .. }
+        ));
+        assert!(matches!(
+            map_status_code(1002, "rate limit exceeded"),
+            ProviderError::RateLimit { .. }
+        ));
+        assert!(matches!(
+            map_status_code(1026, "input new_sensitive"),
+            ProviderError::ContentFiltered { .. }
+        ));
+        assert!(matches!(
+            map_status_code(2013, "invalid params"),
+            ProviderError::InvalidRequest { .. }
+        ));
+    }
+}
@@ -0,0 +1,17 @@
+//! MiniMax Provider
+//!
+//! Chat and streaming for MiniMax text models via `chatcompletion_v2`, plus
+//! text-to-speech via `t2a_v2`
+
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod speech;
+
+pub use client::MiniMaxClient;
+pub use config::MiniMaxConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// MiniMax provider
+pub type MiniMaxProvider = OpenAICompatibleProvider<MiniMaxClient>;
@@ -0,0 +1,151 @@
+//! MiniMax Text-to-Speech
+//!
+//! `t2a_v2` returns the synthesized audio hex-encoded inside a JSON body.
+
+use serde_json::{Value, json};
+
+use super::MiniMaxClient;
+use super::error::check_base_resp;
+use crate::core::audio::types::{SpeechRequest, SpeechResponse, format_to_content_type};
+use crate::core::providers::base::OpenAICompatibleClient;
+use crate::core::providers::unified_provider::ProviderError;
+
+/// Speech models served by `t2a_v2`
+pub const SPEECH_MODELS: &[&str] = &[
+    "speech-2.5-hd-preview",
+    "speech-2.5-turbo-preview",
+    "speech-02-hd",
+    "speech-02-turbo",
+    "speech-01-hd",
+    "speech-01-turbo",
+];
+
+/// Audio formats `t2a_v2` can produce
+const SUPPORTED_FORMATS: &[&str] = &["mp3", "pcm", "flac", "wav"];
+
+/// MiniMax text-to-speech transformation
+pub struct MiniMaxSpeechTransformation;
+
+impl MiniMaxSpeechTransformation {
+    /// Build the `/t2a_v2` request body
+    ///
+    /// `voice` is used as the MiniMax `voice_id` (e.g. `male-qn-qingse`).
+    pub fn transform_request(request: &SpeechRequest) -> Result<Value, ProviderError> {
+        let format = request.response_format.as_deref().unwrap_or("mp3");
+        if !SUPPORTED_FORMATS.contains(&format) {
+            return Err(ProviderError::invalid_request(
+                "minimax",
+                format!(
+                    "Unsupported response_format '{}', expected one of {}",
+                    format,
+                    SUPPORTED_FORMATS.join(", ")
+                ),
+            ));
+        }
+
+        let speed = request.speed.unwrap_or(1.0);
+        if !(0.5..=2.0).contains(&speed) {
+            return Err(ProviderError::invalid_request(
+                "minimax",
+                "speed must be between 0.5 and 2.0",
+            ));
+        }
+
+        Ok(json!({
+            "model": MiniMaxClient::normalize_model(&request.model),
+            "text": request.input,
+            "stream": false,
+            "voice_setting": {
+                "voice_id": request.voice,
+                "speed": speed,
+            },
+            "audio_setting": {
+                "format": format,
+            },
+        }))
+    }
+
+    /// Decode a `/t2a_v2` response into raw audio bytes
+    pub fn transform_response(
+        response: Value,
+        format: &str,
+    ) -> Result<SpeechResponse, ProviderError> {
+        check_base_resp(&response)?;
+
+        let audio_hex = response
+            .pointer("/data/audio")
+            .and_then(|a| a.as_str())
+            .ok_or_else(|| {
+                ProviderError::response_parsing("minimax", "Missing 'data.audio' in response")
+            })?;
+        let audio = hex::decode(audio_hex)
+            .map_err(|e| ProviderError::response_parsing("minimax", e.to_string()))?;
+
+        Ok(SpeechResponse {
+            audio,
+            content_type: format_to_content_type(format).to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> SpeechRequest {
+        SpeechRequest {
+            input: "Hello".to_string(),
+            model: "minimax/speech-02-hd".to_string(),
+            voice: "male-qn-qingse".to_string(),
+            response_format: Some("flac".to_string()),
+            speed: Some(1.25),
+            instructions: None,
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let body = MiniMaxSpeechTransformation::transform_request(&request()).unwrap();
+        assert_eq!(body["model"], "speech-02-hd");
+        assert_eq!(body["text"], "Hello");
+        assert_eq!(body["voice_setting"]["voice_id"], "male-qn-qingse");
+        assert_eq!(body["voice_setting"]["speed"], 1.25);
+        assert_eq!(body["audio_setting"]["format"], "flac");
+    }
+
+    #[test]
+    fn test_transform_request_validation() {
+        let mut req = request();
+        req.response_format = Some("opus".to_string());
+        assert!(MiniMaxSpeechTransformation::transform_request(&req).is_err());
+
+        let mut req = request();
+        req.speed = Some(4.0);
+        assert!(MiniMaxSpeechTransformation::transform_request(&req).is_err());
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "data": {"audio": "49443303", "status": 2},
+            "extra_info": {"audio_format": "mp3", "usage_characters": 5},
+            "trace_id": "01b8bf9bb7433cc75c18eee6cfa8fe21",
+            "base_resp": {"status_code": 0, "status_msg": "success"}
+        });
+
+        let response = MiniMaxSpeechTransformation::transform_response(raw, "mp3").unwrap();
+        assert_eq!(response.audio, vec![0x49, 0x44, 0x33, 0x03]);
+        assert_eq!(response.content_type, "audio/mpeg");
+    }
+
+    #[test]
+    fn test_transform_response_error() {
+        let raw = json!({
+            "base_resp": {"status_code": 2013, "status_msg": "invalid params, voice_id"}
+        });
+        assert!(matches!(
+            MiniMaxSpeechTransformation::transform_response(raw, "mp3"),
+            Err(ProviderError::InvalidRequest { .. })
+        ));
+    }
+}
@@ -7,7 +7,7 @@ use tracing::debug;
 use crate::core::providers::mistral::MistralError;
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::types::{
-    ChatMessage, FinishReason, MessageContent, MessageRole,
+    ChatMessage, FinishReason, MessageContent, MessageRole, ResponseFormat,
     requests::{ChatRequest, FunctionCall, ToolCall as RequestToolCall},
     responses::{ChatChoice, ChatResponse, Usage},
 };
@@ -85,10 +85,8 @@ impl MistralChatTransformation {
         }
 
         // Handle response format
-        if let Some(format) = request.response_format {
-            if let Ok(format_val) = serde_json::to_value(format) {
-                transformed["response_format"] = format_val;
-            }
+        if let Some(format) = &request.response_format {
+            transformed["response_format"] = self.transform_response_format(format);
         }
 
         // Handle tools and function calling
@@ -111,6 +109,31 @@ impl MistralChatTransformation {
         Ok(transformed)
     }
 
+    /// Transform an OpenAI response format to Mistral's
+    ///
+    /// Mistral takes the same shape, but requires a schema name and has no
+    /// `response_type`.
+    fn transform_response_format(&self, format: &ResponseFormat) -> Value {
+        match format.schema() {
+            Some(schema) => {
+                let mut json_schema = json!({
+                    "name": format.schema_name().unwrap_or("response"),
+                    "schema": schema,
+                    "strict": format.is_strict(),
+                });
+                if let Some(description) = format
+                    .json_schema
+                    .as_ref()
+                    .and_then(|json_schema| json_schema.get("description"))
+                {
+                    json_schema["description"] = description.clone();
+                }
+                json!({"type": "json_schema", "json_schema": json_schema})
+            }
+            None => json!({"type": format.format_type}),
+        }
+    }
+
     /// Normalize model name for Mistral API
     fn normalize_model_name(&self, model: &str) -> String {
         // Remove common prefixes from model name
@@ -198,6 +221,7 @@ impl MistralChatTransformation {
             choices,
             usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -298,6 +322,7 @@ impl MistralChatTransformation {
             tool_calls,
             tool_call_id,
             thinking: None,
+            cache_control: None,
         })
     }
 
@@ -327,6 +352,9 @@ impl MistralChatTransformation {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }
         })
     }
@@ -382,6 +410,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         };
@@ -407,6 +436,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             temperature: Some(0.5),
             max_tokens: Some(100),
@@ -426,6 +456,29 @@ mod tests {
         assert_eq!(value["stream"], true);
     }
 
+    #[test]
+    fn test_transform_request_with_json_schema() {
+        let transformation = MistralChatTransformation::new();
+        let request = ChatRequest {
+            model: "mistral-large".to_string(),
+            response_format: Some(ResponseFormat {
+                format_type: "json_schema".to_string(),
+                json_schema: Some(json!({"schema": {"type": "object"}})),
+                response_type: Some("json_object".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        let value = transformation.transform_request(request).unwrap();
+        assert_eq!(
+            value["response_format"],
+            json!({
+                "type": "json_schema",
+                "json_schema": {"name": "response", "schema": {"type": "object"}, "strict": false}
+            })
+        );
+    }
+
     #[test]
     fn test_transform_messages_roles() {
         let transformation = MistralChatTransformation::new();
@@ -438,6 +491,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::User,
@@ -447,6 +501,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::Assistant,
@@ -456,6 +511,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             ChatMessage {
                 role: MessageRole::Tool,
@@ -465,6 +521,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: Some("call_123".to_string()),
+                cache_control: None,
             },
         ];
 
@@ -506,7 +563,10 @@ mod tests {
         assert_eq!(chat_response.id, "cmpl-123");
         assert_eq!(chat_response.model, "mistral-large");
         assert_eq!(chat_response.choices.len(), 1);
-        assert_eq!(chat_response.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert_eq!(
+            chat_response.choices[0].finish_reason,
+            Some(FinishReason::Stop)
+        );
         assert!(chat_response.usage.is_some());
         let usage = chat_response.usage.unwrap();
         assert_eq!(usage.prompt_tokens, 10);
@@ -90,6 +90,9 @@ impl MistralEmbeddingHandler {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }
         });
 
@@ -110,6 +113,7 @@ mod tests {
     use super::*;
     use crate::core::types::requests::EmbeddingRequest;
     use crate::core::types::requests::EmbeddingInput;
+    use std::collections::HashMap;
 
     fn create_test_config() -> MistralConfig {
         MistralConfig {
@@ -139,6 +143,7 @@ mod tests {
             dimensions: None,
             user: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         let result = handler.transform_request(request);
@@ -160,6 +165,7 @@ mod tests {
             dimensions: None,
             user: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         let result = handler.transform_request(request);
@@ -183,6 +189,7 @@ mod tests {
             dimensions: None,
             user: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         let result = handler.transform_request(request);
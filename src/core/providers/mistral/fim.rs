@@ -0,0 +1,140 @@
+//! Fill-in-the-middle (FIM) completion for Codestral models
+//!
+//! `/fim/completions` generates the code between `prompt` and an optional
+//! `suffix`. Responses use the chat completion shape and are converted to
+//! text completions.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::providers::mistral::MistralError;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::responses::{CompletionChoice, CompletionResponse, FinishReason, Usage};
+
+/// FIM completion request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FimCompletionRequest {
+    /// Codestral model, e.g. "codestral-latest"
+    pub model: String,
+    /// Code before the cursor
+    pub prompt: String,
+    /// Code after the cursor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub random_seed: Option<u64>,
+}
+
+impl FimCompletionRequest {
+    /// Create a request for the code between `prompt` and `suffix`
+    pub fn new(model: impl Into<String>, prompt: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            prompt: prompt.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the code after the cursor
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+}
+
+/// Check if a model supports FIM completion
+pub fn is_fim_model(model: &str) -> bool {
+    model.starts_with("codestral")
+}
+
+/// Convert a `/fim/completions` response to a text completion
+pub fn transform_response(response: Value) -> Result<CompletionResponse, MistralError> {
+    let choices = response["choices"]
+        .as_array()
+        .ok_or_else(|| ProviderError::response_parsing("mistral", "Missing choices"))?
+        .iter()
+        .enumerate()
+        .map(|(i, choice)| CompletionChoice {
+            index: choice["index"].as_u64().unwrap_or(i as u64) as u32,
+            text: choice["message"]["content"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            finish_reason: match choice["finish_reason"].as_str() {
+                Some("stop") => Some(FinishReason::Stop),
+                Some("length" | "model_length") => Some(FinishReason::Length),
+                _ => None,
+            },
+            logprobs: None,
+        })
+        .collect();
+
+    let usage = response
+        .get("usage")
+        .and_then(|usage| serde_json::from_value::<Usage>(usage.clone()).ok());
+
+    Ok(CompletionResponse {
+        id: response["id"].as_str().unwrap_or_default().to_string(),
+        object: "text_completion".to_string(),
+        created: response["created"].as_i64().unwrap_or_default(),
+        model: response["model"].as_str().unwrap_or_default().to_string(),
+        choices,
+        usage,
+        system_fingerprint: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_fim_request_serialization() {
+        let request = FimCompletionRequest::new("codestral-latest", "def fib(n):")
+            .with_suffix("print(fib(10))");
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["prompt"], "def fib(n):");
+        assert_eq!(value["suffix"], "print(fib(10))");
+        assert!(value.get("temperature").is_none());
+        assert!(is_fim_model(&request.model));
+        assert!(!is_fim_model("mistral-large"));
+    }
+
+    #[test]
+    fn test_transform_fim_response() {
+        let response = json!({
+            "id": "5b35cc2e69bf4ba9a11373ee1f1937f8",
+            "object": "chat.completion",
+            "created": 1702256327,
+            "model": "codestral-latest",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "\n    return n if n < 2 else fib(n - 1) + fib(n - 2)\n"},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 8, "completion_tokens": 24, "total_tokens": 32}
+        });
+
+        let completion = transform_response(response).unwrap();
+        assert_eq!(completion.object, "text_completion");
+        assert_eq!(completion.choices.len(), 1);
+        assert!(completion.choices[0].text.contains("fib(n - 1)"));
+        assert!(matches!(
+            completion.choices[0].finish_reason,
+            Some(FinishReason::Stop)
+        ));
+        assert_eq!(completion.usage.unwrap().total_tokens, 32);
+    }
+}
@@ -24,12 +24,15 @@ use crate::core::traits::{
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
     requests::{ChatRequest, EmbeddingRequest},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse},
+    responses::{ChatChunk, ChatResponse, CompletionResponse, EmbeddingResponse},
 };
 
 // Re-export submodules (these remain as-is for now)
 pub mod chat;
 pub mod embedding;
+pub mod fim;
+
+pub use fim::FimCompletionRequest;
 
 // Static capabilities
 const MISTRAL_CAPABILITIES: &[ProviderCapability] = &[
@@ -37,6 +40,7 @@ const MISTRAL_CAPABILITIES: &[ProviderCapability] = &[
     ProviderCapability::ChatCompletionStream,
     ProviderCapability::ToolCalling,
     ProviderCapability::Embeddings,
+    ProviderCapability::FimCompletion,
 ];
 
 /// Mistral provider configuration
@@ -224,6 +228,26 @@ impl MistralProvider {
                 updated_at: None,
                 metadata: HashMap::new(),
             },
+            ModelInfo {
+                id: "codestral-latest".to_string(),
+                name: "Codestral".to_string(),
+                provider: "mistral".to_string(),
+                max_context_length: 256000,
+                max_output_length: None,
+                supports_streaming: true,
+                supports_tools: true,
+                supports_multimodal: false,
+                input_cost_per_1k_tokens: Some(0.0003),
+                output_cost_per_1k_tokens: Some(0.0009),
+                currency: "USD".to_string(),
+                capabilities: vec![
+                    ProviderCapability::ChatCompletion,
+                    ProviderCapability::FimCompletion,
+                ],
+                created_at: None,
+                updated_at: None,
+                metadata: HashMap::new(),
+            },
             ModelInfo {
                 id: "mistral-embed".to_string(),
                 name: "Mistral Embed".to_string(),
@@ -254,6 +278,56 @@ impl MistralProvider {
     fn is_embedding_model(&self, model: &str) -> bool {
         model.contains("embed")
     }
+
+    /// Complete the code between a prompt and a suffix via `/fim/completions`
+    pub async fn fim_completion(
+        &self,
+        request: FimCompletionRequest,
+    ) -> Result<CompletionResponse, MistralError> {
+        debug!("Mistral FIM request: model={}", request.model);
+
+        if !fim::is_fim_model(&request.model) {
+            return Err(ProviderError::invalid_request(
+                "mistral",
+                format!(
+                    "Model {} does not support FIM completion; use codestral-latest",
+                    request.model
+                ),
+            ));
+        }
+
+        let url = UrlBuilder::new(&self.config.api_base)
+            .with_path("/fim/completions")
+            .build();
+
+        let headers = HeaderBuilder::new()
+            .with_bearer_token(&self.config.api_key)
+            .with_content_type("application/json")
+            .build_reqwest()
+            .map_err(|e| ProviderError::invalid_request("mistral", e.to_string()))?;
+
+        let response = self
+            .base_client
+            .inner()
+            .post(&url)
+            .headers(headers)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| ProviderError::network("mistral", e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ProviderError::api_error("mistral", status, body));
+        }
+
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("mistral", e.to_string()))?;
+        fim::transform_response(body)
+    }
 }
 
 #[async_trait]
@@ -592,6 +666,23 @@ mod tests {
                 .capabilities()
                 .contains(&ProviderCapability::ChatCompletionStream)
         );
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::FimCompletion)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fim_completion_rejects_chat_model() {
+        let config = MistralConfig {
+            api_key: "test_key".to_string(),
+            ..Default::default()
+        };
+        let provider = MistralProvider::new(config).await.unwrap();
+
+        let request = FimCompletionRequest::new("mistral-large", "def add(a, b):");
+        assert!(provider.fim_completion(request).await.is_err());
     }
 
     #[test]
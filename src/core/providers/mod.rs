@@ -11,21 +11,34 @@ pub mod azure;
 pub mod azure_ai;
 pub mod bedrock;
 pub mod cloudflare;
+pub mod cohere;
+pub mod custom_openai;
+pub mod dashscope;
 pub mod deepinfra;
 pub mod deepseek;
 pub mod gemini;
 pub mod groq;
+pub mod huggingface;
+pub mod jina;
 pub mod meta_llama;
+pub mod minimax;
 pub mod mistral;
 pub mod moonshot;
+pub mod nvidia_nim;
+pub mod ollama;
 pub mod openai;
 pub mod openrouter;
+pub mod perplexity;
+pub mod together_ai;
 pub mod v0;
 pub mod vertex_ai;
+pub mod voyage;
 pub mod xai;
+pub mod zhipu;
 
 // Shared utilities and architecture
 pub mod capabilities;
+pub mod cerebras;
 pub mod macros; // Macros for reducing boilerplate
 pub mod shared; // Shared utilities for all providers // Compile-time capability verification
 pub mod thinking; // Thinking/reasoning provider trait
@@ -33,14 +46,20 @@ pub mod thinking; // Thinking/reasoning provider trait
 // Registry and unified provider
 pub mod base_provider;
 pub mod provider_registry;
+pub mod replicate;
+pub mod sambanova;
+pub mod stability;
 pub mod unified_provider;
 
 // Export main types
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
 pub use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
 use crate::core::types::common::{ProviderCapability, RequestContext};
-use crate::core::types::requests::{ChatRequest, EmbeddingRequest, ImageGenerationRequest};
+use crate::core::types::requests::{
+    AudioTranscriptionRequest, ChatRequest, EmbeddingRequest, ImageGenerationRequest,
+};
 use crate::core::types::responses::{
-    ChatChunk, ChatResponse, EmbeddingResponse, ImageGenerationResponse,
+    AudioTranscriptionResponse, ChatChunk, ChatResponse, EmbeddingResponse, ImageGenerationResponse,
 };
 use chrono::{DateTime, Utc};
 pub use provider_registry::ProviderRegistry;
@@ -64,6 +83,7 @@ pub enum ProviderType {
     Bedrock,
     OpenRouter,
     VertexAI,
+    Gemini,
     Azure,
     AzureAI,
     DeepSeek,
@@ -75,6 +95,22 @@ pub enum ProviderType {
     Groq,
     XAI,
     Cloudflare,
+    Ollama,
+    TogetherAI,
+    Cohere,
+    Perplexity,
+    HuggingFace,
+    Replicate,
+    NvidiaNim,
+    Cerebras,
+    SambaNova,
+    Voyage,
+    Jina,
+    DashScope,
+    Zhipu,
+    MiniMax,
+    Stability,
+    CustomOpenAI,
     Custom(String),
 }
 
@@ -86,6 +122,7 @@ impl From<&str> for ProviderType {
             "bedrock" | "aws-bedrock" => ProviderType::Bedrock,
             "openrouter" => ProviderType::OpenRouter,
             "vertex_ai" | "vertexai" | "vertex-ai" => ProviderType::VertexAI,
+            "gemini" | "google_ai_studio" | "google_ai" | "gemini_api" => ProviderType::Gemini,
             "azure" | "azure-openai" => ProviderType::Azure,
             "azure_ai" | "azureai" | "azure-ai" => ProviderType::AzureAI,
             "deepseek" | "deep-seek" => ProviderType::DeepSeek,
@@ -97,6 +134,22 @@ impl From<&str> for ProviderType {
             "groq" => ProviderType::Groq,
             "xai" => ProviderType::XAI,
             "cloudflare" | "cf" | "workers-ai" => ProviderType::Cloudflare,
+            "ollama" => ProviderType::Ollama,
+            "together_ai" | "together" | "togetherai" | "together-ai" => ProviderType::TogetherAI,
+            "cohere" | "cohere_chat" => ProviderType::Cohere,
+            "perplexity" | "perplexityai" => ProviderType::Perplexity,
+            "huggingface" | "hf" | "tgi" => ProviderType::HuggingFace,
+            "replicate" => ProviderType::Replicate,
+            "nvidia_nim" | "nvidia" | "nim" | "nvidia-nim" => ProviderType::NvidiaNim,
+            "cerebras" => ProviderType::Cerebras,
+            "sambanova" | "samba_nova" | "sambanova_cloud" => ProviderType::SambaNova,
+            "voyage" | "voyage_ai" | "voyageai" => ProviderType::Voyage,
+            "jina" | "jina_ai" | "jinaai" => ProviderType::Jina,
+            "dashscope" | "qwen" | "alibaba" | "aliyun" => ProviderType::DashScope,
+            "zhipu" | "zhipuai" | "glm" | "bigmodel" => ProviderType::Zhipu,
+            "minimax" | "minimax_ai" => ProviderType::MiniMax,
+            "stability" | "stability_ai" | "stabilityai" => ProviderType::Stability,
+            "custom_openai" | "openai_compatible" | "openai_like" => ProviderType::CustomOpenAI,
             _ => ProviderType::Custom(s.to_string()),
         }
     }
@@ -110,6 +163,7 @@ impl std::fmt::Display for ProviderType {
             ProviderType::Bedrock => write!(f, "bedrock"),
             ProviderType::OpenRouter => write!(f, "openrouter"),
             ProviderType::VertexAI => write!(f, "vertex_ai"),
+            ProviderType::Gemini => write!(f, "gemini"),
             ProviderType::Azure => write!(f, "azure"),
             ProviderType::AzureAI => write!(f, "azure_ai"),
             ProviderType::DeepSeek => write!(f, "deepseek"),
@@ -121,6 +175,22 @@ impl std::fmt::Display for ProviderType {
             ProviderType::Groq => write!(f, "groq"),
             ProviderType::XAI => write!(f, "xai"),
             ProviderType::Cloudflare => write!(f, "cloudflare"),
+            ProviderType::Ollama => write!(f, "ollama"),
+            ProviderType::TogetherAI => write!(f, "together_ai"),
+            ProviderType::Cohere => write!(f, "cohere"),
+            ProviderType::Perplexity => write!(f, "perplexity"),
+            ProviderType::HuggingFace => write!(f, "huggingface"),
+            ProviderType::Replicate => write!(f, "replicate"),
+            ProviderType::NvidiaNim => write!(f, "nvidia_nim"),
+            ProviderType::Cerebras => write!(f, "cerebras"),
+            ProviderType::SambaNova => write!(f, "sambanova"),
+            ProviderType::Voyage => write!(f, "voyage"),
+            ProviderType::Jina => write!(f, "jina"),
+            ProviderType::DashScope => write!(f, "dashscope"),
+            ProviderType::Zhipu => write!(f, "zhipu"),
+            ProviderType::MiniMax => write!(f, "minimax"),
+            ProviderType::Stability => write!(f, "stability"),
+            ProviderType::CustomOpenAI => write!(f, "custom_openai"),
             ProviderType::Custom(name) => write!(f, "{}", name),
         }
     }
@@ -143,12 +213,29 @@ macro_rules! dispatch_provider {
             Provider::MetaLlama(p) => p.$method(),
             Provider::OpenRouter(p) => p.$method(),
             Provider::VertexAI(p) => p.$method(),
+            Provider::Gemini(p) => p.$method(),
             Provider::V0(p) => p.$method(),
             Provider::DeepInfra(p) => p.$method(),
             Provider::AzureAI(p) => p.$method(),
             Provider::Groq(p) => p.$method(),
             Provider::XAI(p) => p.$method(),
             Provider::Cloudflare(p) => p.$method(),
+            Provider::Ollama(p) => p.$method(),
+            Provider::TogetherAI(p) => p.$method(),
+            Provider::Cohere(p) => p.$method(),
+            Provider::Perplexity(p) => p.$method(),
+            Provider::HuggingFace(p) => p.$method(),
+            Provider::Replicate(p) => p.$method(),
+            Provider::NvidiaNim(p) => p.$method(),
+            Provider::Cerebras(p) => p.$method(),
+            Provider::SambaNova(p) => p.$method(),
+            Provider::Voyage(p) => p.$method(),
+            Provider::Jina(p) => p.$method(),
+            Provider::DashScope(p) => p.$method(),
+            Provider::Zhipu(p) => p.$method(),
+            Provider::MiniMax(p) => p.$method(),
+            Provider::Stability(p) => p.$method(),
+            Provider::CustomOpenAI(p) => p.$method(),
         }
     };
 
@@ -164,12 +251,29 @@ macro_rules! dispatch_provider {
             Provider::MetaLlama(p) => p.$method($($arg),+),
             Provider::OpenRouter(p) => p.$method($($arg),+),
             Provider::VertexAI(p) => p.$method($($arg),+),
+            Provider::Gemini(p) => p.$method($($arg),+),
             Provider::V0(p) => p.$method($($arg),+),
             Provider::DeepInfra(p) => p.$method($($arg),+),
             Provider::AzureAI(p) => p.$method($($arg),+),
             Provider::Groq(p) => p.$method($($arg),+),
             Provider::XAI(p) => p.$method($($arg),+),
             Provider::Cloudflare(p) => p.$method($($arg),+),
+            Provider::Ollama(p) => p.$method($($arg),+),
+            Provider::TogetherAI(p) => p.$method($($arg),+),
+            Provider::Cohere(p) => p.$method($($arg),+),
+            Provider::Perplexity(p) => p.$method($($arg),+),
+            Provider::HuggingFace(p) => p.$method($($arg),+),
+            Provider::Replicate(p) => p.$method($($arg),+),
+            Provider::NvidiaNim(p) => p.$method($($arg),+),
+            Provider::Cerebras(p) => p.$method($($arg),+),
+            Provider::SambaNova(p) => p.$method($($arg),+),
+            Provider::Voyage(p) => p.$method($($arg),+),
+            Provider::Jina(p) => p.$method($($arg),+),
+            Provider::DashScope(p) => p.$method($($arg),+),
+            Provider::Zhipu(p) => p.$method($($arg),+),
+            Provider::MiniMax(p) => p.$method($($arg),+),
+            Provider::Stability(p) => p.$method($($arg),+),
+            Provider::CustomOpenAI(p) => p.$method($($arg),+),
         }
     };
 }
@@ -188,12 +292,29 @@ macro_rules! dispatch_provider_async {
             Provider::MetaLlama(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::OpenRouter(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::VertexAI(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Gemini(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::V0(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::DeepInfra(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::AzureAI(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::Groq(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::XAI(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
             Provider::Cloudflare(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Ollama(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::TogetherAI(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Cohere(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Perplexity(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::HuggingFace(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Replicate(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::NvidiaNim(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Cerebras(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::SambaNova(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Voyage(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Jina(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::DashScope(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Zhipu(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::MiniMax(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::Stability(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
+            Provider::CustomOpenAI(p) => LLMProvider::$method(p, $($arg),*).await.map_err(ProviderError::from),
         }
     };
 }
@@ -212,12 +333,29 @@ macro_rules! dispatch_provider_value {
             Provider::MetaLlama(p) => LLMProvider::$method(p),
             Provider::OpenRouter(p) => LLMProvider::$method(p),
             Provider::VertexAI(p) => LLMProvider::$method(p),
+            Provider::Gemini(p) => LLMProvider::$method(p),
             Provider::V0(p) => LLMProvider::$method(p),
             Provider::DeepInfra(p) => LLMProvider::$method(p),
             Provider::AzureAI(p) => LLMProvider::$method(p),
             Provider::Groq(p) => LLMProvider::$method(p),
             Provider::XAI(p) => LLMProvider::$method(p),
             Provider::Cloudflare(p) => LLMProvider::$method(p),
+            Provider::Ollama(p) => LLMProvider::$method(p),
+            Provider::TogetherAI(p) => LLMProvider::$method(p),
+            Provider::Cohere(p) => LLMProvider::$method(p),
+            Provider::Perplexity(p) => LLMProvider::$method(p),
+            Provider::HuggingFace(p) => LLMProvider::$method(p),
+            Provider::Replicate(p) => LLMProvider::$method(p),
+            Provider::NvidiaNim(p) => LLMProvider::$method(p),
+            Provider::Cerebras(p) => LLMProvider::$method(p),
+            Provider::SambaNova(p) => LLMProvider::$method(p),
+            Provider::Voyage(p) => LLMProvider::$method(p),
+            Provider::Jina(p) => LLMProvider::$method(p),
+            Provider::DashScope(p) => LLMProvider::$method(p),
+            Provider::Zhipu(p) => LLMProvider::$method(p),
+            Provider::MiniMax(p) => LLMProvider::$method(p),
+            Provider::Stability(p) => LLMProvider::$method(p),
+            Provider::CustomOpenAI(p) => LLMProvider::$method(p),
         }
     };
 
@@ -233,12 +371,29 @@ macro_rules! dispatch_provider_value {
             Provider::MetaLlama(p) => LLMProvider::$method(p, $($arg),+),
             Provider::OpenRouter(p) => LLMProvider::$method(p, $($arg),+),
             Provider::VertexAI(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Gemini(p) => LLMProvider::$method(p, $($arg),+),
             Provider::V0(p) => LLMProvider::$method(p, $($arg),+),
             Provider::DeepInfra(p) => LLMProvider::$method(p, $($arg),+),
             Provider::AzureAI(p) => LLMProvider::$method(p, $($arg),+),
             Provider::Groq(p) => LLMProvider::$method(p, $($arg),+),
             Provider::XAI(p) => LLMProvider::$method(p, $($arg),+),
             Provider::Cloudflare(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Ollama(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::TogetherAI(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Cohere(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Perplexity(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::HuggingFace(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Replicate(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::NvidiaNim(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Cerebras(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::SambaNova(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Voyage(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Jina(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::DashScope(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Zhipu(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::MiniMax(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::Stability(p) => LLMProvider::$method(p, $($arg),+),
+            Provider::CustomOpenAI(p) => LLMProvider::$method(p, $($arg),+),
         }
     };
 }
@@ -277,12 +432,29 @@ macro_rules! dispatch_provider_async_direct {
             Provider::MetaLlama(p) => LLMProvider::$method(p).await,
             Provider::OpenRouter(p) => LLMProvider::$method(p).await,
             Provider::VertexAI(p) => LLMProvider::$method(p).await,
+            Provider::Gemini(p) => LLMProvider::$method(p).await,
             Provider::V0(p) => LLMProvider::$method(p).await,
             Provider::DeepInfra(p) => LLMProvider::$method(p).await,
             Provider::AzureAI(p) => LLMProvider::$method(p).await,
             Provider::Groq(p) => LLMProvider::$method(p).await,
             Provider::XAI(p) => LLMProvider::$method(p).await,
             Provider::Cloudflare(p) => LLMProvider::$method(p).await,
+            Provider::Ollama(p) => LLMProvider::$method(p).await,
+            Provider::TogetherAI(p) => LLMProvider::$method(p).await,
+            Provider::Cohere(p) => LLMProvider::$method(p).await,
+            Provider::Perplexity(p) => LLMProvider::$method(p).await,
+            Provider::HuggingFace(p) => LLMProvider::$method(p).await,
+            Provider::Replicate(p) => LLMProvider::$method(p).await,
+            Provider::NvidiaNim(p) => LLMProvider::$method(p).await,
+            Provider::Cerebras(p) => LLMProvider::$method(p).await,
+            Provider::SambaNova(p) => LLMProvider::$method(p).await,
+            Provider::Voyage(p) => LLMProvider::$method(p).await,
+            Provider::Jina(p) => LLMProvider::$method(p).await,
+            Provider::DashScope(p) => LLMProvider::$method(p).await,
+            Provider::Zhipu(p) => LLMProvider::$method(p).await,
+            Provider::MiniMax(p) => LLMProvider::$method(p).await,
+            Provider::Stability(p) => LLMProvider::$method(p).await,
+            Provider::CustomOpenAI(p) => LLMProvider::$method(p).await,
         }
     };
 }
@@ -291,6 +463,8 @@ macro_rules! dispatch_provider_async_direct {
 ///
 /// This enum provides zero-cost abstractions and type safety for all providers.
 /// Each variant contains a concrete provider implementation.
+// Providers are created once and shared, so variant size does not matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum Provider {
     OpenAI(openai::OpenAIProvider),
@@ -303,12 +477,29 @@ pub enum Provider {
     MetaLlama(meta_llama::LlamaProvider),
     OpenRouter(openrouter::OpenRouterProvider),
     VertexAI(vertex_ai::VertexAIProvider),
+    Gemini(gemini::GeminiProvider),
     V0(v0::V0Provider),
     DeepInfra(deepinfra::DeepInfraProvider),
     AzureAI(azure_ai::AzureAIProvider),
     Groq(groq::GroqProvider),
     XAI(xai::XAIProvider),
     Cloudflare(cloudflare::CloudflareProvider),
+    Ollama(ollama::OllamaProvider),
+    TogetherAI(together_ai::TogetherAIProvider),
+    Cohere(cohere::CohereProvider),
+    Perplexity(perplexity::PerplexityProvider),
+    HuggingFace(huggingface::HuggingFaceProvider),
+    Replicate(replicate::ReplicateProvider),
+    NvidiaNim(nvidia_nim::NvidiaNimProvider),
+    Cerebras(cerebras::CerebrasProvider),
+    SambaNova(sambanova::SambaNovaProvider),
+    Voyage(voyage::VoyageProvider),
+    Jina(jina::JinaProvider),
+    DashScope(dashscope::DashScopeProvider),
+    Zhipu(zhipu::ZhipuProvider),
+    MiniMax(minimax::MiniMaxProvider),
+    Stability(stability::StabilityProvider),
+    CustomOpenAI(custom_openai::CustomOpenAIProvider),
 }
 
 impl Provider {
@@ -325,12 +516,29 @@ impl Provider {
             Provider::MetaLlama(_) => "meta_llama",
             Provider::OpenRouter(_) => "openrouter",
             Provider::VertexAI(_) => "vertex_ai",
+            Provider::Gemini(_) => "gemini",
             Provider::V0(_) => "v0",
             Provider::DeepInfra(_) => "deepinfra",
             Provider::AzureAI(_) => "azure_ai",
             Provider::Groq(_) => "groq",
             Provider::XAI(_) => "xai",
             Provider::Cloudflare(_) => "cloudflare",
+            Provider::Ollama(_) => "ollama",
+            Provider::TogetherAI(_) => "together_ai",
+            Provider::Cohere(_) => "cohere",
+            Provider::Perplexity(_) => "perplexity",
+            Provider::HuggingFace(_) => "huggingface",
+            Provider::Replicate(_) => "replicate",
+            Provider::NvidiaNim(_) => "nvidia_nim",
+            Provider::Cerebras(_) => "cerebras",
+            Provider::SambaNova(_) => "sambanova",
+            Provider::Voyage(_) => "voyage",
+            Provider::Jina(_) => "jina",
+            Provider::DashScope(_) => "dashscope",
+            Provider::Zhipu(_) => "zhipu",
+            Provider::MiniMax(_) => "minimax",
+            Provider::Stability(_) => "stability",
+            Provider::CustomOpenAI(_) => "custom_openai",
         }
     }
 
@@ -347,12 +555,29 @@ impl Provider {
             Provider::MetaLlama(_) => ProviderType::MetaLlama,
             Provider::OpenRouter(_) => ProviderType::OpenRouter,
             Provider::VertexAI(_) => ProviderType::VertexAI,
+            Provider::Gemini(_) => ProviderType::Gemini,
             Provider::V0(_) => ProviderType::V0,
             Provider::DeepInfra(_) => ProviderType::DeepInfra,
             Provider::AzureAI(_) => ProviderType::AzureAI,
             Provider::Groq(_) => ProviderType::Groq,
             Provider::XAI(_) => ProviderType::XAI,
             Provider::Cloudflare(_) => ProviderType::Cloudflare,
+            Provider::Ollama(_) => ProviderType::Ollama,
+            Provider::TogetherAI(_) => ProviderType::TogetherAI,
+            Provider::Cohere(_) => ProviderType::Cohere,
+            Provider::Perplexity(_) => ProviderType::Perplexity,
+            Provider::HuggingFace(_) => ProviderType::HuggingFace,
+            Provider::Replicate(_) => ProviderType::Replicate,
+            Provider::NvidiaNim(_) => ProviderType::NvidiaNim,
+            Provider::Cerebras(_) => ProviderType::Cerebras,
+            Provider::SambaNova(_) => ProviderType::SambaNova,
+            Provider::Voyage(_) => ProviderType::Voyage,
+            Provider::Jina(_) => ProviderType::Jina,
+            Provider::DashScope(_) => ProviderType::DashScope,
+            Provider::Zhipu(_) => ProviderType::Zhipu,
+            Provider::MiniMax(_) => ProviderType::MiniMax,
+            Provider::Stability(_) => ProviderType::Stability,
+            Provider::CustomOpenAI(_) => ProviderType::CustomOpenAI,
         }
     }
 
@@ -362,6 +587,31 @@ impl Provider {
         dispatch_provider_value!(self, supports_model, model)
     }
 
+    /// Get the OpenAI parameters the provider supports for a model
+    pub fn get_supported_openai_params(&self, model: &str) -> &'static [&'static str] {
+        use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+        dispatch_provider_value!(self, get_supported_openai_params, model)
+    }
+
+    /// Check if provider accepts document content parts
+    pub fn supports_documents(&self) -> bool {
+        matches!(
+            self,
+            Provider::Anthropic(_) | Provider::Bedrock(_) | Provider::Gemini(_)
+        )
+    }
+
+    /// Reject content parts the provider cannot accept before sending them
+    fn check_content(&self, request: &ChatRequest) -> Result<(), UnifiedProviderError> {
+        if request.has_documents() && !self.supports_documents() {
+            return Err(UnifiedProviderError::not_supported(
+                self.name(),
+                "document content",
+            ));
+        }
+        Ok(())
+    }
+
     /// Get provider capabilities
     pub fn capabilities(&self) -> &'static [ProviderCapability] {
         // All providers implement capabilities, using generic macro
@@ -377,12 +627,18 @@ impl Provider {
     }
 
     /// Execute chat completion
+    #[tracing::instrument(
+        name = "provider.chat_completion",
+        skip_all,
+        fields(provider = self.name(), model = %request.model)
+    )]
     pub async fn chat_completion(
         &self,
         request: ChatRequest,
         context: RequestContext,
     ) -> Result<ChatResponse, UnifiedProviderError> {
         use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+        self.check_content(&request)?;
         dispatch_provider_async!(self, chat_completion, request, context)
     }
 
@@ -434,6 +690,7 @@ impl Provider {
         use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
         use futures::StreamExt;
 
+        self.check_content(&request)?;
         match self {
             Provider::OpenAI(p) => {
                 let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
@@ -464,6 +721,71 @@ impl Provider {
                 let mapped = stream.map(|result| result.map_err(UnifiedProviderError::from));
                 Ok(Box::pin(mapped))
             }
+            Provider::Ollama(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::TogetherAI(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::Cohere(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::Perplexity(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::HuggingFace(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::Replicate(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::NvidiaNim(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::Cerebras(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::SambaNova(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::DashScope(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::Zhipu(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::MiniMax(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
+            Provider::CustomOpenAI(p) => {
+                let stream = LLMProvider::chat_completion_stream(p, request, context).await?;
+                let mapped = stream.map(|result| result);
+                Ok(Box::pin(mapped))
+            }
             _ => Err(UnifiedProviderError::not_implemented(
                 "unknown",
                 format!("Streaming not implemented for {}", self.name()),
@@ -482,6 +804,18 @@ impl Provider {
         match self {
             Provider::OpenAI(p) => LLMProvider::embeddings(p, request, context).await,
             Provider::Azure(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Bedrock(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Ollama(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::TogetherAI(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Cohere(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Voyage(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::NvidiaNim(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::CustomOpenAI(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Jina(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Gemini(p) => LLMProvider::embeddings(p, request, context).await,
+            Provider::Cloudflare(p) => LLMProvider::embeddings(p, request, context)
+                .await
+                .map_err(UnifiedProviderError::from),
             _ => Err(UnifiedProviderError::not_implemented(
                 "unknown",
                 format!("Embeddings not supported by {}", self.name()),
@@ -499,6 +833,15 @@ impl Provider {
 
         match self {
             Provider::OpenAI(p) => LLMProvider::image_generation(p, request, context).await,
+            Provider::Azure(p) => LLMProvider::image_generation(p, request, context).await,
+            Provider::XAI(p) => LLMProvider::image_generation(p, request, context)
+                .await
+                .map_err(UnifiedProviderError::from),
+            Provider::Cloudflare(p) => LLMProvider::image_generation(p, request, context)
+                .await
+                .map_err(UnifiedProviderError::from),
+            Provider::Replicate(p) => LLMProvider::image_generation(p, request, context).await,
+            Provider::Stability(p) => LLMProvider::image_generation(p, request, context).await,
             _ => Err(UnifiedProviderError::not_implemented(
                 "unknown",
                 format!("Image generation not supported by {}", self.name()),
@@ -506,6 +849,159 @@ impl Provider {
         }
     }
 
+    /// Transcribe audio to text
+    pub async fn create_transcription(
+        &self,
+        request: AudioTranscriptionRequest,
+        context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, UnifiedProviderError> {
+        use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+
+        match self {
+            Provider::OpenAI(p) => LLMProvider::audio_transcription(p, request, context).await,
+            Provider::Azure(p) => LLMProvider::audio_transcription(p, request, context).await,
+            Provider::Groq(p) => LLMProvider::audio_transcription(p, request, context)
+                .await
+                .map_err(UnifiedProviderError::from),
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Audio transcription not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Convert text to speech
+    pub async fn create_speech(
+        &self,
+        request: SpeechRequest,
+        context: RequestContext,
+    ) -> Result<SpeechResponse, UnifiedProviderError> {
+        use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+
+        match self {
+            Provider::OpenAI(p) => LLMProvider::speech(p, request, context).await,
+            Provider::Azure(p) => LLMProvider::speech(p, request, context).await,
+            Provider::MiniMax(p) => LLMProvider::speech(p, request, context).await,
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Text-to-speech not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Convert text to speech, streaming audio chunks as they are produced
+    pub async fn create_speech_stream(
+        &self,
+        request: SpeechRequest,
+        context: RequestContext,
+    ) -> Result<
+        std::pin::Pin<
+            Box<dyn futures::Stream<Item = Result<bytes::Bytes, UnifiedProviderError>> + Send>,
+        >,
+        UnifiedProviderError,
+    > {
+        use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+
+        match self {
+            Provider::OpenAI(p) => LLMProvider::speech_stream(p, request, context).await,
+            Provider::Azure(p) => LLMProvider::speech_stream(p, request, context).await,
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Streaming text-to-speech not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Rerank documents by relevance to a query
+    pub async fn rerank(
+        &self,
+        request: crate::core::rerank::RerankRequest,
+    ) -> Result<crate::core::rerank::RerankResponse, UnifiedProviderError> {
+        match self {
+            Provider::Cohere(p) => p.rerank(request).await,
+            Provider::Voyage(p) => p.rerank(request).await,
+            Provider::Jina(p) => p.rerank(request).await,
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Rerank not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Complete code between a prompt and a suffix (fill-in-the-middle)
+    pub async fn fim_completion(
+        &self,
+        request: mistral::FimCompletionRequest,
+    ) -> Result<crate::core::types::responses::CompletionResponse, UnifiedProviderError> {
+        match self {
+            Provider::Mistral(p) => p.fim_completion(request).await,
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("FIM completion not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Pass an Assistants API request (`assistants/...`, `threads/...`) through
+    pub async fn assistants_request(
+        &self,
+        method: base::HttpMethod,
+        path: &str,
+        query: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, UnifiedProviderError> {
+        match self {
+            Provider::OpenAI(p) => p.assistants_request(method, path, query, body).await,
+            Provider::Azure(p) => p.assistants().request(method, path, query, body).await,
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Assistants API not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Upstream WebSocket endpoint of a realtime session
+    pub async fn realtime_connection(
+        &self,
+        model: &str,
+    ) -> Result<openai::realtime::RealtimeConnection, UnifiedProviderError> {
+        match self {
+            Provider::OpenAI(p) => Ok(p.realtime_connection(model)),
+            Provider::Azure(p) => p.realtime_connection(model).await,
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Realtime API not supported by {}", self.name()),
+            )),
+        }
+    }
+
+    /// Check if a model is served by the provider's native text completions endpoint
+    pub fn supports_text_completion(&self, model: &str) -> bool {
+        match self {
+            Provider::OpenAI(_) => openai::completions::is_completion_model(model),
+            _ => false,
+        }
+    }
+
+    /// Complete a prompt with the provider's native text completions endpoint
+    pub async fn text_completion(
+        &self,
+        request: crate::core::types::requests::CompletionRequest,
+    ) -> Result<crate::core::types::responses::CompletionResponse, UnifiedProviderError> {
+        match self {
+            Provider::OpenAI(p) => {
+                let request =
+                    openai::completions::OpenAICompletionTransformer::transform_request(request)?;
+                let response = p.text_completion(request).await?;
+                openai::completions::OpenAICompletionTransformer::transform_response(response)
+            }
+            _ => Err(UnifiedProviderError::not_implemented(
+                "unknown",
+                format!("Text completion not supported by {}", self.name()),
+            )),
+        }
+    }
+
     /// Alias for chat_completion (for backward compatibility)
     pub async fn completion(
         &self,
@@ -568,7 +1064,24 @@ pub async fn create_provider(
         "meta_llama" => ProviderType::MetaLlama,
         "openrouter" => ProviderType::OpenRouter,
         "vertex_ai" => ProviderType::VertexAI,
+        "gemini" => ProviderType::Gemini,
         "v0" => ProviderType::V0,
+        "ollama" => ProviderType::Ollama,
+        "together_ai" => ProviderType::TogetherAI,
+        "cohere" => ProviderType::Cohere,
+        "perplexity" => ProviderType::Perplexity,
+        "huggingface" => ProviderType::HuggingFace,
+        "replicate" => ProviderType::Replicate,
+        "nvidia_nim" => ProviderType::NvidiaNim,
+        "cerebras" => ProviderType::Cerebras,
+        "sambanova" => ProviderType::SambaNova,
+        "voyage" => ProviderType::Voyage,
+        "jina" => ProviderType::Jina,
+        "dashscope" => ProviderType::DashScope,
+        "zhipu" => ProviderType::Zhipu,
+        "minimax" => ProviderType::MiniMax,
+        "stability" => ProviderType::Stability,
+        "custom_openai" => ProviderType::CustomOpenAI,
         name => {
             return Err(ProviderError::not_implemented(
                 "unknown",
@@ -603,6 +1116,26 @@ impl Provider {
         ))
     }
 
+    /// Create the provider of a gateway provider configuration
+    ///
+    /// The configured API key is used unless the settings set one.
+    pub async fn from_provider_config(
+        config: &crate::config::ProviderConfig,
+    ) -> Result<Self, ProviderError> {
+        let mut settings = config.settings.clone();
+        if !settings.contains_key("api_key") && !config.api_key.is_empty() {
+            settings.insert(
+                "api_key".to_string(),
+                serde_json::Value::String(config.api_key.clone()),
+            );
+        }
+        Self::from_config_async(
+            config.provider_type.as_str().into(),
+            serde_json::Value::Object(settings.into_iter().collect()),
+        )
+        .await
+    }
+
     /// Create provider from configuration asynchronously
     ///
     /// This is the preferred method for creating providers from configuration.
@@ -688,6 +1221,272 @@ impl Provider {
                     .map_err(|e| ProviderError::initialization("cloudflare", e.to_string()))?;
                 Ok(Provider::Cloudflare(provider))
             }
+            ProviderType::Ollama => {
+                // No API key required; optional when the daemon sits behind a proxy
+                let ollama_config = ollama::OllamaConfig {
+                    api_key: macros::get_config_str(&config, "api_key").map(String::from),
+                    api_base: macros::get_config_str(&config, "api_base")
+                        .or_else(|| macros::get_config_str(&config, "base_url"))
+                        .map(String::from),
+                    keep_alive: macros::get_config_str(&config, "keep_alive").map(String::from),
+                    ..Default::default()
+                };
+                let mut provider = ollama::OllamaProvider::new(ollama_config)?;
+                // Model discovery is best-effort so the gateway can start before the daemon
+                if let Err(e) = provider.refresh_models().await {
+                    tracing::warn!("Failed to list Ollama models: {}", e);
+                }
+                Ok(Provider::Ollama(provider))
+            }
+            ProviderType::TogetherAI => {
+                let api_key = macros::require_config_str(&config, "api_key", "together_ai")?;
+                let mut together_config = together_ai::TogetherAIConfig::new("together_ai");
+                together_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    together_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = together_ai::TogetherAIProvider::new(together_config)?;
+                Ok(Provider::TogetherAI(provider))
+            }
+            ProviderType::Cohere => {
+                let api_key = macros::require_config_str(&config, "api_key", "cohere")?;
+                let mut cohere_config = cohere::CohereConfig::new("cohere");
+                cohere_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    cohere_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = cohere::CohereProvider::new(cohere_config)?;
+                Ok(Provider::Cohere(provider))
+            }
+            ProviderType::Perplexity => {
+                let api_key = macros::require_config_str(&config, "api_key", "perplexity")?;
+                let mut perplexity_config = perplexity::PerplexityConfig::new("perplexity");
+                perplexity_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    perplexity_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = perplexity::PerplexityProvider::new(perplexity_config)?;
+                Ok(Provider::Perplexity(provider))
+            }
+            ProviderType::HuggingFace => {
+                // A self-hosted TGI server may not require a token
+                let task = macros::get_config_str(&config, "task")
+                    .map(|name| {
+                        huggingface::HuggingFaceTask::from_name(name).ok_or_else(|| {
+                            ProviderError::configuration(
+                                "huggingface",
+                                format!("Unknown Hugging Face task: {}", name),
+                            )
+                        })
+                    })
+                    .transpose()?
+                    .unwrap_or_default();
+                let huggingface_config = huggingface::HuggingFaceConfig {
+                    api_key: macros::get_config_str(&config, "api_key").map(String::from),
+                    api_base: macros::get_config_str(&config, "api_base").map(String::from),
+                    task,
+                    ..Default::default()
+                };
+                let provider = huggingface::HuggingFaceProvider::new(huggingface_config)?;
+                Ok(Provider::HuggingFace(provider))
+            }
+            ProviderType::Replicate => {
+                let api_key = macros::require_config_str(&config, "api_key", "replicate")?;
+                let mut replicate_config = replicate::ReplicateConfig::new("replicate");
+                replicate_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    replicate_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = replicate::ReplicateProvider::new(replicate_config)?;
+                Ok(Provider::Replicate(provider))
+            }
+            ProviderType::NvidiaNim => {
+                // A self-hosted NIM may not require a key
+                let mut nvidia_nim_config = nvidia_nim::NvidiaNimConfig::new("nvidia_nim");
+                if let Some(api_key) = macros::get_config_str(&config, "api_key") {
+                    nvidia_nim_config.base.api_key = Some(api_key.to_string());
+                }
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    nvidia_nim_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = nvidia_nim::NvidiaNimProvider::new(nvidia_nim_config)?;
+                Ok(Provider::NvidiaNim(provider))
+            }
+            ProviderType::Cerebras => {
+                let api_key = macros::require_config_str(&config, "api_key", "cerebras")?;
+                let mut cerebras_config = cerebras::CerebrasConfig::new("cerebras");
+                cerebras_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    cerebras_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = cerebras::CerebrasProvider::new(cerebras_config)?;
+                Ok(Provider::Cerebras(provider))
+            }
+            ProviderType::SambaNova => {
+                let api_key = macros::require_config_str(&config, "api_key", "sambanova")?;
+                let mut sambanova_config = sambanova::SambaNovaConfig::new("sambanova");
+                sambanova_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    sambanova_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = sambanova::SambaNovaProvider::new(sambanova_config)?;
+                Ok(Provider::SambaNova(provider))
+            }
+            ProviderType::Voyage => {
+                let api_key = macros::require_config_str(&config, "api_key", "voyage")?;
+                let mut voyage_config = voyage::VoyageConfig::new("voyage");
+                voyage_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    voyage_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = voyage::VoyageProvider::new(voyage_config)?;
+                Ok(Provider::Voyage(provider))
+            }
+            ProviderType::Jina => {
+                let api_key = macros::require_config_str(&config, "api_key", "jina")?;
+                let mut jina_config = jina::JinaConfig::new("jina");
+                jina_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    jina_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = jina::JinaProvider::new(jina_config)?;
+                Ok(Provider::Jina(provider))
+            }
+            ProviderType::DashScope => {
+                let api_key = macros::require_config_str(&config, "api_key", "dashscope")?;
+                let mut dashscope_config = dashscope::DashScopeConfig::new("dashscope");
+                dashscope_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    dashscope_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = dashscope::DashScopeProvider::new(dashscope_config)?;
+                Ok(Provider::DashScope(provider))
+            }
+            ProviderType::Zhipu => {
+                let api_key = macros::require_config_str(&config, "api_key", "zhipu")?;
+                let mut zhipu_config = zhipu::ZhipuConfig::new("zhipu");
+                zhipu_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    zhipu_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = zhipu::ZhipuProvider::new(zhipu_config)?;
+                Ok(Provider::Zhipu(provider))
+            }
+            ProviderType::MiniMax => {
+                let api_key = macros::require_config_str(&config, "api_key", "minimax")?;
+                let mut minimax_config = minimax::MiniMaxConfig::new("minimax");
+                minimax_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    minimax_config.base.api_base = Some(api_base.to_string());
+                }
+                minimax_config.group_id =
+                    macros::get_config_str(&config, "group_id").map(String::from);
+                let provider = minimax::MiniMaxProvider::new(minimax_config)?;
+                Ok(Provider::MiniMax(provider))
+            }
+            ProviderType::Stability => {
+                let api_key = macros::require_config_str(&config, "api_key", "stability")?;
+                let mut stability_config = stability::StabilityConfig::new("stability");
+                stability_config.base.api_key = Some(api_key.to_string());
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    stability_config.base.api_base = Some(api_base.to_string());
+                }
+                let provider = stability::StabilityProvider::new(stability_config)?;
+                Ok(Provider::Stability(provider))
+            }
+            ProviderType::Azure => {
+                // Authenticates with api_key, or with Azure AD (Entra ID) credentials
+                let api_base = macros::require_config_str(&config, "api_base", "azure")?;
+                let mut azure_config =
+                    azure::AzureConfig::new().with_azure_endpoint(api_base.to_string());
+                let optional = |key: &str| macros::get_config_str(&config, key).map(String::from);
+                azure_config.api_key = optional("api_key");
+                if let Some(api_version) = optional("api_version") {
+                    azure_config.api_version = api_version;
+                }
+                azure_config.deployment_name = optional("deployment_name");
+                azure_config.azure_ad_token = optional("azure_ad_token");
+                azure_config.azure_ad_token_provider = optional("azure_ad_token_provider");
+                azure_config.tenant_id = optional("tenant_id");
+                azure_config.client_id = optional("client_id");
+                azure_config.client_secret = optional("client_secret");
+                // Reject an unknown token provider or incomplete credentials up front
+                azure::AzureAdCredential::from_config(&azure_config)?;
+                let provider = azure::AzureOpenAIProvider::new(azure_config)?;
+                Ok(Provider::Azure(provider))
+            }
+            ProviderType::Gemini => {
+                // Google AI Studio: a plain API key, no GCP project or ADC
+                let api_key = macros::require_config_str(&config, "api_key", "gemini")?;
+                let mut gemini_config = gemini::GeminiConfig::new_google_ai(api_key);
+                if let Some(api_base) = macros::get_config_str(&config, "api_base") {
+                    gemini_config.base_url = api_base.trim_end_matches('/').to_string();
+                }
+                let provider = gemini::GeminiProvider::new(gemini_config)?;
+                Ok(Provider::Gemini(provider))
+            }
+            ProviderType::CustomOpenAI => {
+                // Self-hosted servers rarely need a key, but always need an endpoint
+                let api_base = macros::require_config_str(&config, "api_base", "custom_openai")?;
+                let mut custom_config = custom_openai::CustomOpenAIConfig::new("custom_openai");
+                custom_config.base.api_base = Some(api_base.to_string());
+                if let Some(api_key) = macros::get_config_str(&config, "api_key") {
+                    custom_config.base.api_key = Some(api_key.to_string());
+                }
+                if let Some(models) = config.get("models").and_then(|m| m.as_array()) {
+                    custom_config.models = models
+                        .iter()
+                        .filter_map(|m| m.as_str().map(String::from))
+                        .collect();
+                }
+                let discover = custom_config.models.is_empty();
+                let mut provider = custom_openai::CustomOpenAIProvider::new(custom_config)?;
+                // Discovery is best-effort so the gateway can start before the server
+                if discover {
+                    if let Err(e) = provider.refresh_models().await {
+                        tracing::warn!("Failed to list custom_openai models: {}", e);
+                    }
+                }
+                Ok(Provider::CustomOpenAI(provider))
+            }
+            ProviderType::Bedrock => {
+                // Keys are optional; without them the AWS credential chain is used
+                let region = macros::get_config_str(&config, "aws_region_name")
+                    .or_else(|| macros::get_config_str(&config, "aws_region"))
+                    .map(String::from)
+                    .or_else(|| std::env::var("AWS_REGION").ok())
+                    .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+                    .unwrap_or_else(|| "us-east-1".to_string());
+                let mut bedrock_config = bedrock::BedrockConfig {
+                    aws_region: region,
+                    ..Default::default()
+                };
+                if let Some(key) = macros::get_config_str(&config, "aws_access_key_id") {
+                    bedrock_config.aws_access_key_id = key.to_string();
+                }
+                if let Some(secret) = macros::get_config_str(&config, "aws_secret_access_key") {
+                    bedrock_config.aws_secret_access_key = secret.to_string();
+                }
+                bedrock_config.aws_session_token =
+                    macros::get_config_str(&config, "aws_session_token").map(String::from);
+                bedrock_config.aws_profile_name =
+                    macros::get_config_str(&config, "aws_profile_name").map(String::from);
+                // Same keys as per-request guardrail parameters
+                if let Some(obj) = config.as_object() {
+                    let params: std::collections::HashMap<String, serde_json::Value> =
+                        obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                    bedrock_config.guardrail_config =
+                        bedrock::guardrails::resolve_guardrail(&params, None)?;
+                }
+                if let Some(enabled) = config
+                    .get("cross_region_inference")
+                    .and_then(|v| v.as_bool())
+                {
+                    bedrock_config.cross_region_inference = enabled;
+                }
+                let provider = bedrock::BedrockProvider::new(bedrock_config).await?;
+                Ok(Provider::Bedrock(provider))
+            }
             _ => Err(ProviderError::not_implemented(
                 "unknown",
                 format!("Factory for {:?} not yet implemented", provider_type),
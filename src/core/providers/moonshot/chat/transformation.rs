@@ -139,7 +139,8 @@ impl MoonshotChatTransformation {
                             let text_parts: Vec<String> = parts
                                 .iter()
                                 .filter_map(|part| {
-                                    if let crate::core::types::ContentPart::Text { text } = part {
+                                    if let crate::core::types::ContentPart::Text { text, .. } = part
+                                    {
                                         Some(text.clone())
                                     } else {
                                         None
@@ -238,6 +239,7 @@ impl MoonshotChatTransformation {
             choices,
             usage,
             system_fingerprint,
+            provider_specific_fields: None,
         })
     }
 
@@ -328,6 +330,7 @@ impl MoonshotChatTransformation {
             function_call,
             tool_calls,
             tool_call_id,
+            cache_control: None,
         })
     }
 
@@ -369,6 +372,9 @@ impl MoonshotChatTransformation {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }
         })
     }
@@ -446,6 +452,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             ..Default::default()
         };
@@ -470,6 +477,7 @@ mod tests {
                 function_call: None,
                 tool_calls: None,
                 tool_call_id: None,
+                cache_control: None,
             }],
             temperature: Some(0.5),
             max_tokens: Some(100),
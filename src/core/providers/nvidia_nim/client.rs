@@ -0,0 +1,257 @@
+//! Nvidia NIM Client
+//!
+//! How NIM endpoints depart from OpenAI's chat completions and embeddings
+
+use crate::core::providers::base::{OpenAICompatibleClient, openai_compatible};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::{ModelInfo, ProviderCapability};
+
+use super::NvidiaNimConfig;
+
+/// Nvidia NIM API client logic
+#[derive(Debug, Clone)]
+pub struct NvidiaNimClient;
+
+impl OpenAICompatibleClient for NvidiaNimClient {
+    type Config = NvidiaNimConfig;
+
+    const NAME: &'static str = "nvidia_nim";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::Embeddings,
+        ProviderCapability::ToolCalling,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "frequency_penalty",
+        "presence_penalty",
+        "stop",
+        "stream",
+        "stream_options",
+        "tools",
+        "tool_choice",
+        "response_format",
+        "seed",
+        "n",
+        "logprobs",
+        "top_logprobs",
+    ];
+
+    fn new(_config: &NvidiaNimConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+
+    /// A self-hosted NIM serves whatever model it was built for, so anything
+    /// routed with the `nvidia_nim/` prefix is accepted
+    ///
+    /// `nvidia/` is left alone by the routing prefix: it is the namespace of
+    /// NVIDIA's own models.
+    fn supports_model(models: &[ModelInfo], model: &str) -> bool {
+        model.starts_with("nvidia_nim/") || models.iter().any(|m| m.id == model)
+    }
+
+    /// Retrieval embedding models are asymmetric and need to know whether the
+    /// input is a search query or a document; `task_type` carries either the
+    /// NIM name or the Vertex-style one
+    fn embedding_input_type(task_type: &str) -> Option<&str> {
+        Some(match task_type {
+            "RETRIEVAL_QUERY" => "query",
+            "RETRIEVAL_DOCUMENT" => "passage",
+            other => other,
+        })
+    }
+
+    /// NIM containers expose a readiness probe next to the OpenAI routes
+    fn health_path(config: &NvidiaNimConfig) -> Option<&'static str> {
+        config.is_self_hosted().then_some("/health/ready")
+    }
+
+    fn map_http_error(status_code: u16, response_body: &str) -> ProviderError {
+        match status_code {
+            // The hosted catalog returns 402 once the account's credits run out
+            402 => ProviderError::quota_exceeded(
+                Self::NAME,
+                openai_compatible::error_message(response_body),
+            ),
+            // A self-hosted NIM answers 503 while the model is still loading
+            503 => ProviderError::provider_unavailable(
+                Self::NAME,
+                openai_compatible::error_message(response_body),
+            ),
+            _ => openai_compatible::map_http_error(Self::NAME, status_code, response_body),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::nvidia_nim::NvidiaNimProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{
+        ChatMessage, ChatRequest, EmbeddingInput, EmbeddingRequest, MessageContent, MessageRole,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn test_provider() -> NvidiaNimProvider {
+        let mut config = NvidiaNimConfig::new("nvidia_nim");
+        config.base.api_key = Some("nvapi-test".to_string());
+        NvidiaNimProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_model() {
+        assert_eq!(
+            NvidiaNimClient::normalize_model("nvidia_nim/meta/llama-3.1-8b-instruct"),
+            "meta/llama-3.1-8b-instruct"
+        );
+        assert_eq!(
+            NvidiaNimClient::normalize_model("nvidia/llama-3.1-nemotron-70b-instruct"),
+            "nvidia/llama-3.1-nemotron-70b-instruct"
+        );
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("nvidia_nim/meta/llama-3.1-70b-instruct");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(256);
+
+        let body = NvidiaNimClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "meta/llama-3.1-70b-instruct");
+        assert_eq!(body["max_tokens"], 256);
+        assert!(body.get("max_completion_tokens").is_none());
+    }
+
+    #[test]
+    fn test_transform_embedding_request() {
+        let request = EmbeddingRequest {
+            model: "nvidia_nim/nvidia/nv-embedqa-e5-v5".to_string(),
+            input: EmbeddingInput::Array(vec!["a".to_string(), "b".to_string()]),
+            user: None,
+            encoding_format: Some("float".to_string()),
+            dimensions: None,
+            task_type: Some("RETRIEVAL_DOCUMENT".to_string()),
+            extra_params: HashMap::new(),
+        };
+
+        let body = NvidiaNimClient::transform_embedding_request(&request).unwrap();
+        assert_eq!(body["model"], "nvidia/nv-embedqa-e5-v5");
+        assert_eq!(body["input"][1], "b");
+        assert_eq!(body["input_type"], "passage");
+        assert_eq!(body["encoding_format"], "float");
+        assert!(body.get("dimensions").is_none());
+        assert!(body.get("task_type").is_none());
+    }
+
+    #[test]
+    fn test_transform_embedding_response() {
+        let raw = json!({
+            "object": "list",
+            "model": "nvidia/nv-embedqa-e5-v5",
+            "data": [{"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}],
+            "usage": {"prompt_tokens": 4, "total_tokens": 4}
+        });
+
+        let response =
+            NvidiaNimClient::transform_embedding_response(raw, "nvidia/nv-embedqa-e5-v5").unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(response.usage.unwrap().prompt_tokens, 4);
+    }
+
+    #[test]
+    fn test_error_problem_details() {
+        let err = NvidiaNimClient::map_http_error(
+            401,
+            r#"{"status":401,"title":"Unauthorized","detail":"Authentication failed"}"#,
+        );
+        match err {
+            ProviderError::Authentication { message, .. } => {
+                assert_eq!(message, "Authentication failed")
+            }
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_402_and_503() {
+        let err = NvidiaNimClient::map_http_error(402, "");
+        assert!(matches!(err, ProviderError::QuotaExceeded { .. }));
+
+        let err = NvidiaNimClient::map_http_error(503, "model is loading");
+        assert!(matches!(err, ProviderError::ProviderUnavailable { .. }));
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = NvidiaNimConfig::new("nvidia_nim");
+        config.base.api_key = None;
+        assert!(NvidiaNimProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "nvidia_nim");
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::Embeddings)
+        );
+        assert!(provider.supports_model("meta/llama-3.1-70b-instruct"));
+        assert!(provider.supports_model("nvidia_nim/my-org/custom-model"));
+        assert!(!provider.supports_model("gpt-4"));
+    }
+
+    #[test]
+    fn test_supported_models() {
+        let models = test_provider().models().to_vec();
+        assert!(models.iter().any(|m| m.id == "meta/llama-3.1-70b-instruct"));
+        assert!(models.iter().all(|m| m.provider == "nvidia_nim"));
+        assert!(
+            models
+                .iter()
+                .any(|m| m.capabilities.contains(&ProviderCapability::Embeddings))
+        );
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://integrate.api.nvidia.com/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn test_self_hosted_endpoint() {
+        let mut config = NvidiaNimConfig::new("nvidia_nim");
+        config.base.api_key = None;
+        config.base.api_base = Some("http://nim.internal:8000/v1/".to_string());
+        let provider = NvidiaNimProvider::new(config).unwrap();
+
+        assert!(provider.get_request_headers().unwrap().is_empty());
+        assert_eq!(
+            provider.endpoint("/health/ready"),
+            "http://nim.internal:8000/v1/health/ready"
+        );
+        assert_eq!(
+            NvidiaNimClient::health_path(provider.config()),
+            Some("/health/ready")
+        );
+    }
+}
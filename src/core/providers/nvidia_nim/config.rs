@@ -0,0 +1,103 @@
+//! Nvidia NIM Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+/// Hosted NIM endpoints of the NVIDIA API catalog
+pub const NVIDIA_NIM_HOSTED_API_BASE: &str = "https://integrate.api.nvidia.com/v1";
+
+define_provider_config!(NvidiaNimConfig {});
+
+impl NvidiaNimConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `NVIDIA_NIM_*`, falling back to the `NVIDIA_API_KEY` name used by
+    /// NVIDIA's own SDKs.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("nvidia_nim");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("NVIDIA_API_KEY").ok();
+        }
+        config
+    }
+
+    /// Whether requests go to a self-hosted NIM rather than the API catalog
+    pub fn is_self_hosted(&self) -> bool {
+        self.base
+            .api_base
+            .as_deref()
+            .is_some_and(|base| base.trim_end_matches('/') != NVIDIA_NIM_HOSTED_API_BASE)
+    }
+}
+
+impl ProviderConfig for NvidiaNimConfig {
+    fn validate(&self) -> Result<(), String> {
+        // The API catalog always needs a key; a self-hosted NIM may not
+        if self.is_self_hosted() {
+            if self.base.timeout == 0 {
+                return Err("Timeout must be greater than 0".to_string());
+            }
+            if self.base.max_retries > 10 {
+                return Err("Max retries should not exceed 10".to_string());
+            }
+            return Ok(());
+        }
+
+        self.base.validate("nvidia_nim")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nvidia_nim_config_default_api_base() {
+        let config = NvidiaNimConfig::new("nvidia_nim");
+        assert_eq!(
+            config.base.api_base.as_deref(),
+            Some(NVIDIA_NIM_HOSTED_API_BASE)
+        );
+        assert!(!config.is_self_hosted());
+    }
+
+    #[test]
+    fn test_nvidia_nim_validate_hosted_requires_api_key() {
+        let mut config = NvidiaNimConfig::new("nvidia_nim");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+
+        config.base.api_key = Some("nvapi-test".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_nvidia_nim_validate_self_hosted_without_api_key() {
+        let mut config = NvidiaNimConfig::new("nvidia_nim");
+        config.base.api_key = None;
+        config.base.api_base = Some("http://localhost:8000/v1".to_string());
+        assert!(config.is_self_hosted());
+        assert!(config.validate().is_ok());
+
+        config.base.timeout = 0;
+        assert!(config.validate().is_err());
+    }
+}
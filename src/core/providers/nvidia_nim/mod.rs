@@ -0,0 +1,15 @@
+//! Nvidia NIM Provider
+//!
+//! OpenAI-compatible chat, streaming and embeddings for the NVIDIA API catalog
+//! (integrate.api.nvidia.com) and for self-hosted NIM containers.
+
+pub mod client;
+pub mod config;
+
+pub use client::NvidiaNimClient;
+pub use config::NvidiaNimConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// Nvidia NIM provider
+pub type NvidiaNimProvider = OpenAICompatibleProvider<NvidiaNimClient>;
@@ -0,0 +1,177 @@
+//! Ollama Provider Configuration
+
+use crate::core::traits::ProviderConfig;
+use serde::{Deserialize, Serialize};
+
+/// Default Ollama daemon address
+pub const DEFAULT_OLLAMA_API_BASE: &str = "http://localhost:11434";
+
+/// Ollama provider configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaConfig {
+    /// Optional API key (only needed when Ollama sits behind an authenticating proxy)
+    pub api_key: Option<String>,
+
+    /// API base URL (defaults to http://localhost:11434)
+    pub api_base: Option<String>,
+
+    /// Request timeout in seconds
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+
+    /// Maximum number of retries
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// How long the daemon keeps a model loaded after a request (e.g. "5m", "-1")
+    #[serde(default)]
+    pub keep_alive: Option<String>,
+
+    /// Enable debug mode
+    #[serde(default)]
+    pub debug: bool,
+}
+
+impl Default for OllamaConfig {
+    fn default() -> Self {
+        Self {
+            api_key: std::env::var("OLLAMA_API_KEY").ok(),
+            api_base: None,
+            timeout: default_timeout(),
+            max_retries: default_max_retries(),
+            keep_alive: None,
+            debug: false,
+        }
+    }
+}
+
+impl ProviderConfig for OllamaConfig {
+    fn validate(&self) -> Result<(), String> {
+        // Local models do not need an API key
+        if self.timeout == 0 {
+            return Err("Timeout must be greater than 0".to_string());
+        }
+
+        if let Some(api_base) = &self.api_base {
+            if !api_base.starts_with("http://") && !api_base.starts_with("https://") {
+                return Err("Ollama API base must start with http:// or https://".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout)
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+impl OllamaConfig {
+    /// Get the API base URL without a trailing slash
+    pub fn get_api_base(&self) -> String {
+        self.api_base
+            .clone()
+            .or_else(|| std::env::var("OLLAMA_API_BASE").ok())
+            .unwrap_or_else(|| DEFAULT_OLLAMA_API_BASE.to_string())
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    /// Get the API key
+    pub fn get_api_key(&self) -> Option<String> {
+        self.api_key
+            .clone()
+            .or_else(|| std::env::var("OLLAMA_API_KEY").ok())
+    }
+}
+
+fn default_timeout() -> u64 {
+    // Local models can take a while to load on first use
+    120
+}
+
+fn default_max_retries() -> u32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> OllamaConfig {
+        OllamaConfig {
+            api_key: None,
+            api_base: None,
+            timeout: 120,
+            max_retries: 1,
+            keep_alive: None,
+            debug: false,
+        }
+    }
+
+    #[test]
+    fn test_validate_without_api_key() {
+        let config = test_config();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_zero_timeout() {
+        let config = OllamaConfig {
+            timeout: 0,
+            ..test_config()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Timeout"));
+    }
+
+    #[test]
+    fn test_validate_invalid_api_base() {
+        let config = OllamaConfig {
+            api_base: Some("localhost:11434".to_string()),
+            ..test_config()
+        };
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_get_api_base_custom_trims_slash() {
+        let config = OllamaConfig {
+            api_base: Some("http://gpu-box:11434/".to_string()),
+            ..test_config()
+        };
+
+        assert_eq!(config.get_api_base(), "http://gpu-box:11434");
+    }
+
+    #[test]
+    fn test_provider_config_trait() {
+        let config = OllamaConfig {
+            api_key: Some("proxy-key".to_string()),
+            api_base: Some("http://127.0.0.1:11434".to_string()),
+            timeout: 30,
+            max_retries: 2,
+            ..test_config()
+        };
+
+        assert_eq!(config.api_key(), Some("proxy-key"));
+        assert_eq!(config.api_base(), Some("http://127.0.0.1:11434"));
+        assert_eq!(config.timeout(), std::time::Duration::from_secs(30));
+        assert_eq!(config.max_retries(), 2);
+    }
+}
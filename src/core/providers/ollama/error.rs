@@ -0,0 +1,81 @@
+//! Ollama Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Ollama HTTP API
+#[derive(Debug)]
+pub struct OllamaErrorMapper;
+
+impl ErrorMapper<ProviderError> for OllamaErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let message = extract_error_message(response_body);
+
+        match status_code {
+            400 => ProviderError::invalid_request("ollama", message),
+            401 | 403 => ProviderError::authentication("ollama", message),
+            // Ollama answers 404 when the model has not been pulled yet
+            404 => ProviderError::model_not_found("ollama", message),
+            429 => ProviderError::rate_limit("ollama", None),
+            500..=599 => ProviderError::api_error("ollama", status_code, message),
+            _ => ProviderError::api_error("ollama", status_code, message),
+        }
+    }
+}
+
+/// Ollama reports errors as `{"error": "..."}`
+pub(crate) fn extract_error_message(response_body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_body)
+        .ok()
+        .and_then(|v| v.get("error").and_then(|e| e.as_str()).map(String::from))
+        .unwrap_or_else(|| {
+            if response_body.is_empty() {
+                "Unknown error from Ollama".to_string()
+            } else {
+                response_body.to_string()
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ollama_error_mapper_404() {
+        let mapper = OllamaErrorMapper;
+        let err = mapper.map_http_error(404, r#"{"error":"model 'llama9' not found"}"#);
+        match err {
+            ProviderError::ModelNotFound { model, .. } => assert!(model.contains("llama9")),
+            other => panic!("Expected ModelNotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ollama_error_mapper_400() {
+        let mapper = OllamaErrorMapper;
+        let err = mapper.map_http_error(400, r#"{"error":"invalid options"}"#);
+        assert!(matches!(err, ProviderError::InvalidRequest { .. }));
+    }
+
+    #[test]
+    fn test_ollama_error_mapper_401() {
+        let mapper = OllamaErrorMapper;
+        let err = mapper.map_http_error(401, "");
+        assert!(matches!(err, ProviderError::Authentication { .. }));
+    }
+
+    #[test]
+    fn test_ollama_error_mapper_500() {
+        let mapper = OllamaErrorMapper;
+        let err = mapper.map_http_error(500, "out of memory");
+        assert!(matches!(err, ProviderError::ApiError { .. }));
+    }
+
+    #[test]
+    fn test_extract_error_message() {
+        assert_eq!(extract_error_message(r#"{"error":"boom"}"#), "boom");
+        assert_eq!(extract_error_message("plain text"), "plain text");
+        assert_eq!(extract_error_message(""), "Unknown error from Ollama");
+    }
+}
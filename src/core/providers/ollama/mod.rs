@@ -0,0 +1,15 @@
+//! Ollama Provider
+//!
+//! Local model serving through the Ollama HTTP API (chat, streaming,
+//! embeddings and model discovery via `/api/tags`). No API key is required.
+
+pub mod config;
+pub mod error;
+pub mod provider;
+pub mod streaming;
+pub mod transformation;
+
+pub use config::OllamaConfig;
+pub use error::OllamaErrorMapper;
+pub use provider::OllamaProvider;
+pub use streaming::OllamaStream;
@@ -0,0 +1,380 @@
+//! Ollama Provider Implementation
+//!
+//! Talks to a local (or remote) Ollama daemon through its native HTTP API.
+
+use async_trait::async_trait;
+use futures::Stream;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::core::providers::base::{GlobalPoolManager, HeaderPair, HttpMethod, header};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::{ChatRequest, EmbeddingRequest},
+    responses::{ChatChunk, ChatResponse, EmbeddingResponse},
+};
+
+use super::streaming::create_ollama_stream;
+use super::transformation::{
+    OllamaChatResponse, OllamaEmbedResponse, OllamaTagsResponse, strip_model_prefix,
+    tag_to_model_info, transform_chat_request, transform_chat_response,
+    transform_embedding_request, transform_embedding_response,
+};
+use super::{OllamaConfig, OllamaErrorMapper};
+
+/// OpenAI parameters that map onto Ollama request fields or options
+const OLLAMA_SUPPORTED_PARAMS: &[&str] = &[
+    "temperature",
+    "top_p",
+    "max_tokens",
+    "max_completion_tokens",
+    "stop",
+    "seed",
+    "frequency_penalty",
+    "presence_penalty",
+    "stream",
+    "tools",
+    "response_format",
+];
+
+#[derive(Debug, Clone)]
+pub struct OllamaProvider {
+    config: OllamaConfig,
+    pool_manager: Arc<GlobalPoolManager>,
+    /// Models discovered from `/api/tags`
+    models: Vec<ModelInfo>,
+}
+
+impl OllamaProvider {
+    /// Create a new provider
+    ///
+    /// No network calls are made; use [`refresh_models`](Self::refresh_models)
+    /// to populate the model list from the daemon.
+    pub fn new(config: OllamaConfig) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration("ollama", e))?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration("ollama", e.to_string()))?,
+        );
+
+        Ok(Self {
+            config,
+            pool_manager,
+            models: Vec::new(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, ProviderError> {
+        Self::new(OllamaConfig::default())
+    }
+
+    /// Generate headers for Ollama API requests
+    fn get_request_headers(&self) -> Vec<HeaderPair> {
+        let mut headers = Vec::with_capacity(1);
+
+        if let Some(api_key) = self.config.get_api_key() {
+            headers.push(header("Authorization", format!("Bearer {}", api_key)));
+        }
+
+        headers
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}{}", self.config.get_api_base(), path)
+    }
+
+    /// Send a request and map non-success statuses through the error mapper
+    async fn send(
+        &self,
+        path: &str,
+        method: HttpMethod,
+        body: Option<Value>,
+    ) -> Result<reqwest::Response, ProviderError> {
+        let response = self
+            .pool_manager
+            .execute_request(
+                &self.endpoint(path),
+                method,
+                self.get_request_headers(),
+                body,
+            )
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(OllamaErrorMapper.map_http_error(status.as_u16(), &error_text));
+        }
+
+        Ok(response)
+    }
+
+    /// List the models currently pulled on the daemon
+    pub async fn fetch_models(&self) -> Result<Vec<ModelInfo>, ProviderError> {
+        let response = self.send("/api/tags", HttpMethod::GET, None).await?;
+        let tags: OllamaTagsResponse = response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("ollama", e.to_string()))?;
+
+        Ok(tags.models.iter().map(tag_to_model_info).collect())
+    }
+
+    /// Replace the cached model list with the daemon's current one
+    pub async fn refresh_models(&mut self) -> Result<(), ProviderError> {
+        self.models = self.fetch_models().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LLMProvider for OllamaProvider {
+    type Config = OllamaConfig;
+    type Error = ProviderError;
+    type ErrorMapper = OllamaErrorMapper;
+
+    fn name(&self) -> &'static str {
+        "ollama"
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        &[
+            ProviderCapability::ChatCompletion,
+            ProviderCapability::ChatCompletionStream,
+            ProviderCapability::Embeddings,
+            ProviderCapability::ToolCalling,
+        ]
+    }
+
+    fn models(&self) -> &[ModelInfo] {
+        &self.models
+    }
+
+    /// Tags are listed as `name:tag`, so a bare `llama3.2` matches `llama3.2:latest`
+    fn supports_model(&self, model: &str) -> bool {
+        let model = strip_model_prefix(model);
+        self.models.iter().any(|m| {
+            m.id == model
+                || m.id
+                    .strip_suffix(":latest")
+                    .is_some_and(|base| base == model)
+        })
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        OLLAMA_SUPPORTED_PARAMS
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        let mut mapped = HashMap::new();
+
+        for (key, value) in params {
+            match key.as_str() {
+                "max_tokens" | "max_completion_tokens" => {
+                    mapped.insert("num_predict".to_string(), value);
+                }
+                _ if OLLAMA_SUPPORTED_PARAMS.contains(&key.as_str()) => {
+                    mapped.insert(key, value);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(mapped)
+    }
+
+    async fn transform_request(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        transform_chat_request(&request, self.config.keep_alive.as_deref())
+    }
+
+    async fn transform_response(
+        &self,
+        raw_response: &[u8],
+        model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        let response: OllamaChatResponse = serde_json::from_slice(raw_response)
+            .map_err(|e| ProviderError::response_parsing("ollama", e.to_string()))?;
+        Ok(transform_chat_response(response, model))
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        OllamaErrorMapper
+    }
+
+    async fn chat_completion(
+        &self,
+        request: ChatRequest,
+        context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        let mut body = transform_chat_request(&request, self.config.keep_alive.as_deref())?;
+        body["stream"] = Value::Bool(false);
+
+        let response = self.send("/api/chat", HttpMethod::POST, Some(body)).await?;
+        let response_bytes = response
+            .bytes()
+            .await
+            .map_err(|e| ProviderError::network("ollama", e.to_string()))?;
+
+        self.transform_response(&response_bytes, &request.model, &context.request_id)
+            .await
+    }
+
+    async fn chat_completion_stream(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ChatChunk, Self::Error>> + Send>>, Self::Error>
+    {
+        let mut body = transform_chat_request(&request, self.config.keep_alive.as_deref())?;
+        body["stream"] = Value::Bool(true);
+
+        let response = self.send("/api/chat", HttpMethod::POST, Some(body)).await?;
+        Ok(Box::pin(create_ollama_stream(response.bytes_stream())))
+    }
+
+    async fn embeddings(
+        &self,
+        request: EmbeddingRequest,
+        _context: RequestContext,
+    ) -> Result<EmbeddingResponse, Self::Error> {
+        let body = transform_embedding_request(&request, self.config.keep_alive.as_deref());
+
+        let response = self
+            .send("/api/embed", HttpMethod::POST, Some(body))
+            .await?;
+        let embed: OllamaEmbedResponse = response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("ollama", e.to_string()))?;
+
+        Ok(transform_embedding_response(embed, &request.model))
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        match self.send("/api/tags", HttpMethod::GET, None).await {
+            Ok(_) => HealthStatus::Healthy,
+            Err(_) => HealthStatus::Unhealthy,
+        }
+    }
+
+    async fn calculate_cost(
+        &self,
+        _model: &str,
+        _input_tokens: u32,
+        _output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        // Local inference has no per-token cost
+        Ok(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::ollama::transformation::{OllamaTag, OllamaTagDetails};
+
+    fn test_provider() -> OllamaProvider {
+        OllamaProvider::new(OllamaConfig {
+            api_key: None,
+            api_base: Some("http://localhost:11434".to_string()),
+            timeout: 30,
+            max_retries: 1,
+            keep_alive: None,
+            debug: false,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_provider_creation_without_api_key() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "ollama");
+        assert!(provider.models().is_empty());
+        assert!(provider.get_request_headers().is_empty());
+    }
+
+    #[test]
+    fn test_provider_rejects_invalid_config() {
+        let result = OllamaProvider::new(OllamaConfig {
+            timeout: 0,
+            ..OllamaConfig::default()
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let provider = test_provider();
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::Embeddings)
+        );
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::ChatCompletionStream)
+        );
+    }
+
+    #[test]
+    fn test_supports_model_matches_latest_tag() {
+        let mut provider = test_provider();
+        provider.models = vec![tag_to_model_info(&OllamaTag {
+            name: "llama3.2:latest".to_string(),
+            model: None,
+            modified_at: None,
+            size: None,
+            digest: None,
+            details: Some(OllamaTagDetails::default()),
+        })];
+
+        assert!(provider.supports_model("llama3.2"));
+        assert!(provider.supports_model("ollama/llama3.2:latest"));
+        assert!(!provider.supports_model("llama3.1"));
+    }
+
+    #[tokio::test]
+    async fn test_map_openai_params() {
+        let provider = test_provider();
+        let mut params = HashMap::new();
+        params.insert("max_tokens".to_string(), serde_json::json!(100));
+        params.insert("temperature".to_string(), serde_json::json!(0.2));
+        params.insert("logit_bias".to_string(), serde_json::json!({}));
+
+        let mapped = provider
+            .map_openai_params(params, "llama3.2")
+            .await
+            .unwrap();
+        assert_eq!(mapped.get("num_predict"), Some(&serde_json::json!(100)));
+        assert!(mapped.contains_key("temperature"));
+        assert!(!mapped.contains_key("logit_bias"));
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost_is_free() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("llama3.2", 1000, 1000)
+            .await
+            .unwrap();
+        assert_eq!(cost, 0.0);
+    }
+}
@@ -0,0 +1,212 @@
+//! Ollama Streaming Support
+//!
+//! Ollama streams newline-delimited JSON rather than SSE, so it gets its own
+//! line-buffering stream instead of the shared SSE parser.
+
+use bytes::Bytes;
+use futures::Stream;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use super::error::extract_error_message;
+use super::transformation::{OllamaChatResponse, transform_stream_line};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::responses::ChatChunk;
+
+/// Stream of chat chunks parsed from Ollama's NDJSON output
+pub struct OllamaStream<S>
+where
+    S: Stream<Item = Result<Bytes, reqwest::Error>> + Send + Unpin,
+{
+    inner: S,
+    /// Raw bytes of the current incomplete line (kept as bytes so UTF-8 sequences split across chunks survive)
+    buffer: Vec<u8>,
+    pending: VecDeque<Result<ChatChunk, ProviderError>>,
+    id: String,
+    sent_role: bool,
+    finished: bool,
+}
+
+impl<S> OllamaStream<S>
+where
+    S: Stream<Item = Result<Bytes, reqwest::Error>> + Send + Unpin,
+{
+    pub fn new(stream: S) -> Self {
+        Self {
+            inner: stream,
+            buffer: Vec::new(),
+            pending: VecDeque::new(),
+            id: format!("chatcmpl-{}", uuid::Uuid::new_v4().simple()),
+            sent_role: false,
+            finished: false,
+        }
+    }
+
+    /// Parse a single NDJSON line
+    fn process_line(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() {
+            return;
+        }
+
+        let value: serde_json::Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(e) => {
+                self.pending.push_back(Err(ProviderError::response_parsing(
+                    "ollama",
+                    format!("Invalid stream line: {}", e),
+                )));
+                return;
+            }
+        };
+
+        // Errors can arrive mid-stream, e.g. when the model runs out of memory
+        if value.get("error").is_some() {
+            self.pending.push_back(Err(ProviderError::streaming_error(
+                "ollama",
+                "chat",
+                None,
+                None,
+                extract_error_message(line),
+            )));
+            return;
+        }
+
+        match serde_json::from_value::<OllamaChatResponse>(value) {
+            Ok(response) => {
+                let chunk = transform_stream_line(response, &self.id, !self.sent_role);
+                self.sent_role = true;
+                self.pending.push_back(Ok(chunk));
+            }
+            Err(e) => self.pending.push_back(Err(ProviderError::response_parsing(
+                "ollama",
+                format!("Invalid stream line: {}", e),
+            ))),
+        }
+    }
+
+    /// Process every complete line currently in the buffer
+    fn drain_lines(&mut self) {
+        while let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            self.process_line(&String::from_utf8_lossy(&line));
+        }
+    }
+}
+
+impl<S> Stream for OllamaStream<S>
+where
+    S: Stream<Item = Result<Bytes, reqwest::Error>> + Send + Unpin,
+{
+    type Item = Result<ChatChunk, ProviderError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(item) = this.pending.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    this.buffer.extend_from_slice(&bytes);
+                    this.drain_lines();
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    return Poll::Ready(Some(Err(ProviderError::network(
+                        "ollama",
+                        format!("Stream error: {}", e),
+                    ))));
+                }
+                Poll::Ready(None) => {
+                    // Flush a trailing line that was not newline-terminated
+                    let rest = std::mem::take(&mut this.buffer);
+                    this.process_line(&String::from_utf8_lossy(&rest));
+                    this.finished = true;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Boxed byte stream as returned by `reqwest::Response::bytes_stream`
+pub type OllamaByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, reqwest::Error>> + Send>>;
+
+/// Helper function to create an Ollama stream
+pub fn create_ollama_stream(
+    stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + Send + 'static,
+) -> OllamaStream<OllamaByteStream> {
+    OllamaStream::new(Box::pin(stream))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::responses::FinishReason;
+    use futures::{StreamExt, stream};
+
+    #[tokio::test]
+    async fn test_ollama_stream() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "{\"model\":\"llama3.2\",\"message\":{\"role\":\"assistant\",\"content\":\"Hel\"},\"done\":false}\n",
+            )),
+            Ok(Bytes::from(
+                "{\"model\":\"llama3.2\",\"message\":{\"role\":\"assistant\",\"content\":\"lo\"},\"done\":false}\n{\"model\":\"llama3.2\",\"message\":{\"role\":\"assistant\",\"content\":\"\"},\"done\":true,\"done_reason\":\"stop\",\"prompt_eval_count\":4,\"eval_count\":2}\n",
+            )),
+        ];
+
+        let mut stream = create_ollama_stream(stream::iter(test_data));
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.choices[0].delta.content.as_deref(), Some("Hel"));
+        assert!(first.choices[0].delta.role.is_some());
+
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(second.choices[0].delta.content.as_deref(), Some("lo"));
+        assert!(second.choices[0].delta.role.is_none());
+        assert_eq!(first.id, second.id);
+
+        let last = stream.next().await.unwrap().unwrap();
+        assert_eq!(last.choices[0].finish_reason, Some(FinishReason::Stop));
+        assert_eq!(last.usage.unwrap().total_tokens, 6);
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ollama_stream_split_line() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "{\"model\":\"llama3.2\",\"message\":{\"role\":\"assis",
+            )),
+            Ok(Bytes::from("tant\",\"content\":\"Hi\"},\"done\":true}")),
+        ];
+
+        let mut stream = create_ollama_stream(stream::iter(test_data));
+
+        let chunk = stream.next().await.unwrap().unwrap();
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ollama_stream_error_line() {
+        let test_data = vec![Ok(Bytes::from("{\"error\":\"model crashed\"}\n"))];
+
+        let mut stream = create_ollama_stream(stream::iter(test_data));
+
+        let err = stream.next().await.unwrap().unwrap_err();
+        match err {
+            ProviderError::Streaming { message, .. } => assert_eq!(message, "model crashed"),
+            other => panic!("Expected Streaming error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,869 @@
+//! Ollama request/response transformation
+//!
+//! Converts between the unified OpenAI-style types and Ollama's native
+//! `/api/chat`, `/api/embed` and `/api/tags` payloads.
+
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    common::{ModelInfo, ProviderCapability},
+    requests::{
+        ChatMessage, ChatRequest, ContentPart, EmbeddingRequest, FunctionCall, MessageContent,
+        MessageRole, ToolCall,
+    },
+    responses::{
+        ChatChoice, ChatChunk, ChatDelta, ChatResponse, ChatStreamChoice, EmbeddingData,
+        EmbeddingResponse, FinishReason, FunctionCallDelta, ToolCallDelta, Usage,
+    },
+    thinking::{ThinkingContent, ThinkingDelta},
+};
+
+/// Context length assumed for local models when the daemon does not report one
+pub const DEFAULT_CONTEXT_LENGTH: u32 = 4096;
+
+/// Native Ollama sampling options that may be passed through `extra_params`
+const OLLAMA_OPTION_KEYS: &[&str] = &[
+    "num_ctx",
+    "num_keep",
+    "num_gpu",
+    "num_thread",
+    "top_k",
+    "min_p",
+    "typical_p",
+    "repeat_last_n",
+    "repeat_penalty",
+    "mirostat",
+    "mirostat_eta",
+    "mirostat_tau",
+    "tfs_z",
+    "penalize_newline",
+];
+
+/// Message returned by `/api/chat`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OllamaMessage {
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub thinking: Option<String>,
+    #[serde(default)]
+    pub tool_calls: Option<Vec<OllamaToolCall>>,
+}
+
+/// Tool call as emitted by Ollama (arguments are a JSON object, not a string)
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaToolCall {
+    pub function: OllamaFunctionCall,
+}
+
+/// Function invocation inside an Ollama tool call
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaFunctionCall {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+/// Response body (or streamed line) of `/api/chat`
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaChatResponse {
+    #[serde(default)]
+    pub model: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub message: Option<OllamaMessage>,
+    #[serde(default)]
+    pub done: bool,
+    #[serde(default)]
+    pub done_reason: Option<String>,
+    #[serde(default)]
+    pub prompt_eval_count: Option<u32>,
+    #[serde(default)]
+    pub eval_count: Option<u32>,
+}
+
+/// Response body of `/api/embed`
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaEmbedResponse {
+    #[serde(default)]
+    pub model: String,
+    pub embeddings: Vec<Vec<f32>>,
+    #[serde(default)]
+    pub prompt_eval_count: Option<u32>,
+}
+
+/// Entry of the `/api/tags` model listing
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaTag {
+    pub name: String,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub modified_at: Option<String>,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub digest: Option<String>,
+    #[serde(default)]
+    pub details: Option<OllamaTagDetails>,
+}
+
+/// Model details reported by `/api/tags`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OllamaTagDetails {
+    #[serde(default)]
+    pub format: Option<String>,
+    #[serde(default)]
+    pub family: Option<String>,
+    #[serde(default)]
+    pub families: Option<Vec<String>>,
+    #[serde(default)]
+    pub parameter_size: Option<String>,
+    #[serde(default)]
+    pub quantization_level: Option<String>,
+}
+
+/// Response body of `/api/tags`
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaTagsResponse {
+    #[serde(default)]
+    pub models: Vec<OllamaTag>,
+}
+
+/// Strip the routing prefix so `ollama/llama3.2` becomes `llama3.2`
+pub fn strip_model_prefix(model: &str) -> &str {
+    model
+        .strip_prefix("ollama/")
+        .or_else(|| model.strip_prefix("ollama_chat/"))
+        .unwrap_or(model)
+}
+
+/// Build the `/api/chat` request body
+pub fn transform_chat_request(
+    request: &ChatRequest,
+    keep_alive: Option<&str>,
+) -> Result<Value, ProviderError> {
+    let messages = request
+        .messages
+        .iter()
+        .map(transform_message)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut body = json!({
+        "model": strip_model_prefix(&request.model),
+        "messages": messages,
+        "stream": request.stream,
+    });
+
+    let options = build_options(request);
+    if !options.is_empty() {
+        body["options"] = Value::Object(options);
+    }
+
+    if let Some(tools) = &request.tools {
+        body["tools"] = serde_json::to_value(tools)
+            .map_err(|e| ProviderError::serialization("ollama", e.to_string()))?;
+    }
+
+    if let Some(format) = &request.response_format {
+        match format.format_type.as_str() {
+            "json_object" => body["format"] = json!("json"),
+            "json_schema" => {
+                // OpenAI wraps the schema as {"name", "schema", "strict"}; Ollama wants the bare schema
+                let schema = format
+                    .json_schema
+                    .as_ref()
+                    .map(|s| s.get("schema").cloned().unwrap_or_else(|| s.clone()))
+                    .unwrap_or_else(|| json!("json"));
+                body["format"] = schema;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(thinking) = &request.thinking {
+        body["think"] = json!(thinking.enabled);
+    }
+
+    let keep_alive = request
+        .extra_params
+        .get("keep_alive")
+        .cloned()
+        .or_else(|| keep_alive.map(|k| json!(k)));
+    if let Some(keep_alive) = keep_alive {
+        body["keep_alive"] = keep_alive;
+    }
+
+    Ok(body)
+}
+
+/// Map OpenAI sampling parameters onto Ollama's `options` object
+fn build_options(request: &ChatRequest) -> Map<String, Value> {
+    let mut options = Map::new();
+
+    if let Some(temperature) = request.temperature {
+        options.insert("temperature".to_string(), json!(temperature));
+    }
+    if let Some(top_p) = request.top_p {
+        options.insert("top_p".to_string(), json!(top_p));
+    }
+    if let Some(max_tokens) = request.max_completion_tokens.or(request.max_tokens) {
+        options.insert("num_predict".to_string(), json!(max_tokens));
+    }
+    if let Some(stop) = &request.stop {
+        options.insert("stop".to_string(), json!(stop));
+    }
+    if let Some(seed) = request.seed {
+        options.insert("seed".to_string(), json!(seed));
+    }
+    if let Some(frequency_penalty) = request.frequency_penalty {
+        options.insert("frequency_penalty".to_string(), json!(frequency_penalty));
+    }
+    if let Some(presence_penalty) = request.presence_penalty {
+        options.insert("presence_penalty".to_string(), json!(presence_penalty));
+    }
+
+    for key in OLLAMA_OPTION_KEYS {
+        if let Some(value) = request.extra_params.get(*key) {
+            options.insert((*key).to_string(), value.clone());
+        }
+    }
+
+    options
+}
+
+/// Convert a single chat message to Ollama's message format
+fn transform_message(message: &ChatMessage) -> Result<Value, ProviderError> {
+    let role = match message.role {
+        MessageRole::System => "system",
+        MessageRole::User => "user",
+        MessageRole::Assistant => "assistant",
+        MessageRole::Tool | MessageRole::Function => "tool",
+    };
+
+    let mut text = String::new();
+    let mut images = Vec::new();
+
+    match &message.content {
+        Some(MessageContent::Text(content)) => text.push_str(content),
+        Some(MessageContent::Parts(parts)) => {
+            for part in parts {
+                match part {
+                    ContentPart::Text {
+                        text: part_text, ..
+                    } => {
+                        if !text.is_empty() {
+                            text.push('\n');
+                        }
+                        text.push_str(part_text);
+                    }
+                    ContentPart::ImageUrl { image_url } => {
+                        images.push(image_data_from_url(&image_url.url)?);
+                    }
+                    ContentPart::Image { source, .. } => images.push(source.data.clone()),
+                    _ => {
+                        return Err(ProviderError::not_supported(
+                            "ollama",
+                            "Only text and image content parts are supported",
+                        ));
+                    }
+                }
+            }
+        }
+        None => {}
+    }
+
+    let mut value = json!({
+        "role": role,
+        "content": text,
+    });
+
+    if !images.is_empty() {
+        value["images"] = json!(images);
+    }
+
+    if let Some(tool_calls) = &message.tool_calls {
+        let calls: Vec<Value> = tool_calls
+            .iter()
+            .map(|call| {
+                let arguments = serde_json::from_str::<Value>(&call.function.arguments)
+                    .unwrap_or_else(|_| json!({}));
+                json!({
+                    "function": {
+                        "name": call.function.name,
+                        "arguments": arguments,
+                    }
+                })
+            })
+            .collect();
+        value["tool_calls"] = json!(calls);
+    }
+
+    if let Some(name) = &message.name {
+        if matches!(message.role, MessageRole::Tool | MessageRole::Function) {
+            value["tool_name"] = json!(name);
+        }
+    }
+
+    Ok(value)
+}
+
+/// Ollama only accepts inline base64 images
+fn image_data_from_url(url: &str) -> Result<String, ProviderError> {
+    if let Some(rest) = url.strip_prefix("data:") {
+        if let Some((_, data)) = rest.split_once(";base64,") {
+            return Ok(data.to_string());
+        }
+    }
+
+    Err(ProviderError::invalid_request(
+        "ollama",
+        "Ollama requires images as base64 data URLs",
+    ))
+}
+
+fn parse_created_at(created_at: Option<&str>) -> i64 {
+    created_at
+        .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+        .map(|dt| dt.timestamp())
+        .unwrap_or_else(|| chrono::Utc::now().timestamp())
+}
+
+fn parse_finish_reason(done_reason: Option<&str>, has_tool_calls: bool) -> FinishReason {
+    if has_tool_calls {
+        return FinishReason::ToolCalls;
+    }
+    match done_reason {
+        Some("length") => FinishReason::Length,
+        _ => FinishReason::Stop,
+    }
+}
+
+fn usage_from_counts(prompt: Option<u32>, completion: Option<u32>) -> Option<Usage> {
+    if prompt.is_none() && completion.is_none() {
+        return None;
+    }
+    Some(Usage::new(prompt.unwrap_or(0), completion.unwrap_or(0)))
+}
+
+fn generate_tool_call_id() -> String {
+    format!("call_{}", uuid::Uuid::new_v4().simple())
+}
+
+/// Convert Ollama tool calls into unified tool calls
+fn convert_tool_calls(calls: &[OllamaToolCall]) -> Vec<ToolCall> {
+    calls
+        .iter()
+        .map(|call| ToolCall {
+            id: generate_tool_call_id(),
+            tool_type: "function".to_string(),
+            function: FunctionCall {
+                name: call.function.name.clone(),
+                arguments: call.function.arguments.to_string(),
+            },
+        })
+        .collect()
+}
+
+/// Convert a non-streaming `/api/chat` response
+pub fn transform_chat_response(
+    response: OllamaChatResponse,
+    requested_model: &str,
+) -> ChatResponse {
+    let message = response.message.unwrap_or_default();
+    let tool_calls = message
+        .tool_calls
+        .as_deref()
+        .filter(|calls| !calls.is_empty())
+        .map(convert_tool_calls);
+    let finish_reason = parse_finish_reason(response.done_reason.as_deref(), tool_calls.is_some());
+
+    let model = if response.model.is_empty() {
+        strip_model_prefix(requested_model).to_string()
+    } else {
+        response.model
+    };
+
+    ChatResponse {
+        id: format!("chatcmpl-{}", uuid::Uuid::new_v4().simple()),
+        object: "chat.completion".to_string(),
+        created: parse_created_at(response.created_at.as_deref()),
+        model,
+        choices: vec![ChatChoice {
+            index: 0,
+            message: ChatMessage {
+                role: MessageRole::Assistant,
+                content: Some(MessageContent::Text(message.content)),
+                thinking: message
+                    .thinking
+                    .filter(|t| !t.is_empty())
+                    .map(ThinkingContent::text),
+                name: None,
+                tool_calls,
+                tool_call_id: None,
+                function_call: None,
+                cache_control: None,
+            },
+            finish_reason: Some(finish_reason),
+            logprobs: None,
+        }],
+        usage: usage_from_counts(response.prompt_eval_count, response.eval_count),
+        system_fingerprint: None,
+        provider_specific_fields: None,
+    }
+}
+
+/// Convert one streamed `/api/chat` line into a chunk
+///
+/// `include_role` is set for the first chunk of a stream.
+pub fn transform_stream_line(line: OllamaChatResponse, id: &str, include_role: bool) -> ChatChunk {
+    let message = line.message.unwrap_or_default();
+
+    let tool_calls: Option<Vec<ToolCallDelta>> = message
+        .tool_calls
+        .as_deref()
+        .filter(|calls| !calls.is_empty())
+        .map(|calls| {
+            calls
+                .iter()
+                .enumerate()
+                .map(|(index, call)| ToolCallDelta {
+                    index: index as u32,
+                    id: Some(generate_tool_call_id()),
+                    tool_type: Some("function".to_string()),
+                    function: Some(FunctionCallDelta {
+                        name: Some(call.function.name.clone()),
+                        arguments: Some(call.function.arguments.to_string()),
+                    }),
+                })
+                .collect()
+        });
+
+    let finish_reason = line
+        .done
+        .then(|| parse_finish_reason(line.done_reason.as_deref(), tool_calls.is_some()));
+
+    ChatChunk {
+        id: id.to_string(),
+        object: "chat.completion.chunk".to_string(),
+        created: parse_created_at(line.created_at.as_deref()),
+        model: line.model,
+        choices: vec![ChatStreamChoice {
+            index: 0,
+            delta: ChatDelta {
+                role: include_role.then_some(MessageRole::Assistant),
+                content: (!message.content.is_empty()).then_some(message.content),
+                thinking: message
+                    .thinking
+                    .filter(|t| !t.is_empty())
+                    .map(ThinkingDelta::new),
+                tool_calls,
+                function_call: None,
+            },
+            finish_reason,
+            logprobs: None,
+        }],
+        usage: if line.done {
+            usage_from_counts(line.prompt_eval_count, line.eval_count)
+        } else {
+            None
+        },
+        system_fingerprint: None,
+        provider_specific_fields: None,
+    }
+}
+
+/// Build the `/api/embed` request body
+pub fn transform_embedding_request(request: &EmbeddingRequest, keep_alive: Option<&str>) -> Value {
+    let mut body = json!({
+        "model": strip_model_prefix(&request.model),
+        "input": request.input.to_vec(),
+    });
+
+    if let Some(dimensions) = request.dimensions {
+        body["dimensions"] = json!(dimensions);
+    }
+    if let Some(keep_alive) = keep_alive {
+        body["keep_alive"] = json!(keep_alive);
+    }
+
+    body
+}
+
+/// Convert an `/api/embed` response
+pub fn transform_embedding_response(
+    response: OllamaEmbedResponse,
+    requested_model: &str,
+) -> EmbeddingResponse {
+    let data: Vec<EmbeddingData> = response
+        .embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(index, embedding)| EmbeddingData {
+            object: "embedding".to_string(),
+            index: index as u32,
+            embedding,
+        })
+        .collect();
+
+    let model = if response.model.is_empty() {
+        strip_model_prefix(requested_model).to_string()
+    } else {
+        response.model
+    };
+
+    EmbeddingResponse {
+        object: "list".to_string(),
+        data,
+        model,
+        usage: response
+            .prompt_eval_count
+            .map(|tokens| Usage::new(tokens, 0)),
+        embeddings: None,
+    }
+}
+
+/// Convert an `/api/tags` entry into model metadata
+pub fn tag_to_model_info(tag: &OllamaTag) -> ModelInfo {
+    let details = tag.details.clone().unwrap_or_default();
+    let is_embedding_model = tag.name.contains("embed")
+        || details
+            .family
+            .as_deref()
+            .is_some_and(|family| family.contains("bert"));
+
+    let capabilities = if is_embedding_model {
+        vec![ProviderCapability::Embeddings]
+    } else {
+        vec![
+            ProviderCapability::ChatCompletion,
+            ProviderCapability::ChatCompletionStream,
+        ]
+    };
+
+    let mut metadata = HashMap::new();
+    if let Some(size) = tag.size {
+        metadata.insert("size".to_string(), json!(size));
+    }
+    if let Some(digest) = &tag.digest {
+        metadata.insert("digest".to_string(), json!(digest));
+    }
+    if let Some(family) = &details.family {
+        metadata.insert("family".to_string(), json!(family));
+    }
+    if let Some(parameter_size) = &details.parameter_size {
+        metadata.insert("parameter_size".to_string(), json!(parameter_size));
+    }
+    if let Some(quantization_level) = &details.quantization_level {
+        metadata.insert("quantization_level".to_string(), json!(quantization_level));
+    }
+
+    ModelInfo {
+        id: tag.name.clone(),
+        name: tag.model.clone().unwrap_or_else(|| tag.name.clone()),
+        provider: "ollama".to_string(),
+        max_context_length: DEFAULT_CONTEXT_LENGTH,
+        max_output_length: None,
+        supports_streaming: !is_embedding_model,
+        supports_tools: false,
+        supports_multimodal: details
+            .families
+            .as_ref()
+            .is_some_and(|families| families.iter().any(|f| f == "clip" || f == "mllama")),
+        // Local inference is free
+        input_cost_per_1k_tokens: Some(0.0),
+        output_cost_per_1k_tokens: Some(0.0),
+        currency: "USD".to_string(),
+        capabilities,
+        created_at: None,
+        updated_at: tag
+            .modified_at
+            .as_deref()
+            .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+            .map(std::time::SystemTime::from),
+        metadata,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::{ImageUrl, ResponseFormat};
+
+    fn user_message(text: &str) -> ChatMessage {
+        ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_strip_model_prefix() {
+        assert_eq!(strip_model_prefix("ollama/llama3.2"), "llama3.2");
+        assert_eq!(strip_model_prefix("ollama_chat/qwen2.5"), "qwen2.5");
+        assert_eq!(strip_model_prefix("mistral"), "mistral");
+    }
+
+    #[test]
+    fn test_transform_chat_request_options() {
+        let mut request = ChatRequest::new("ollama/llama3.2");
+        request.messages = vec![user_message("Hello")];
+        request.temperature = Some(0.5);
+        request.max_tokens = Some(64);
+        request.stop = Some(vec!["\n".to_string()]);
+        request
+            .extra_params
+            .insert("num_ctx".to_string(), json!(8192));
+
+        let body = transform_chat_request(&request, Some("10m")).unwrap();
+        assert_eq!(body["model"], "llama3.2");
+        assert_eq!(body["stream"], false);
+        assert_eq!(body["messages"][0]["role"], "user");
+        assert_eq!(body["messages"][0]["content"], "Hello");
+        assert_eq!(body["options"]["num_predict"], 64);
+        assert_eq!(body["options"]["num_ctx"], 8192);
+        assert_eq!(body["options"]["stop"][0], "\n");
+        assert_eq!(body["keep_alive"], "10m");
+    }
+
+    #[test]
+    fn test_transform_chat_request_without_options() {
+        let mut request = ChatRequest::new("llama3.2");
+        request.messages = vec![user_message("Hi")];
+
+        let body = transform_chat_request(&request, None).unwrap();
+        assert!(body.get("options").is_none());
+        assert!(body.get("keep_alive").is_none());
+    }
+
+    #[test]
+    fn test_transform_chat_request_json_schema() {
+        let mut request = ChatRequest::new("llama3.2");
+        request.messages = vec![user_message("Give me JSON")];
+        request.response_format = Some(ResponseFormat {
+            format_type: "json_schema".to_string(),
+            json_schema: Some(json!({
+                "name": "answer",
+                "schema": {"type": "object", "properties": {"a": {"type": "string"}}}
+            })),
+            response_type: None,
+        });
+
+        let body = transform_chat_request(&request, None).unwrap();
+        assert_eq!(body["format"]["type"], "object");
+    }
+
+    #[test]
+    fn test_transform_message_with_image() {
+        let message = ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(vec![
+                ContentPart::Text {
+                    text: "What is this?".to_string(),
+                    cache_control: None,
+                },
+                ContentPart::ImageUrl {
+                    image_url: ImageUrl {
+                        url: "data:image/png;base64,aGVsbG8=".to_string(),
+                        detail: None,
+                    },
+                },
+            ])),
+            ..Default::default()
+        };
+
+        let value = transform_message(&message).unwrap();
+        assert_eq!(value["content"], "What is this?");
+        assert_eq!(value["images"][0], "aGVsbG8=");
+    }
+
+    #[test]
+    fn test_transform_message_rejects_remote_image() {
+        let message = ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(vec![ContentPart::ImageUrl {
+                image_url: ImageUrl {
+                    url: "https://example.com/cat.png".to_string(),
+                    detail: None,
+                },
+            }])),
+            ..Default::default()
+        };
+
+        assert!(transform_message(&message).is_err());
+    }
+
+    #[test]
+    fn test_transform_assistant_tool_call_arguments_to_object() {
+        let message = ChatMessage {
+            role: MessageRole::Assistant,
+            tool_calls: Some(vec![ToolCall {
+                id: "call_1".to_string(),
+                tool_type: "function".to_string(),
+                function: FunctionCall {
+                    name: "get_weather".to_string(),
+                    arguments: r#"{"city":"Paris"}"#.to_string(),
+                },
+            }]),
+            ..Default::default()
+        };
+
+        let value = transform_message(&message).unwrap();
+        assert_eq!(
+            value["tool_calls"][0]["function"]["arguments"]["city"],
+            "Paris"
+        );
+    }
+
+    #[test]
+    fn test_transform_chat_response() {
+        let raw = json!({
+            "model": "llama3.2",
+            "created_at": "2024-07-22T20:33:28.123648Z",
+            "message": {"role": "assistant", "content": "Hi there"},
+            "done": true,
+            "done_reason": "stop",
+            "prompt_eval_count": 12,
+            "eval_count": 5
+        });
+        let parsed: OllamaChatResponse = serde_json::from_value(raw).unwrap();
+        let response = transform_chat_response(parsed, "ollama/llama3.2");
+
+        assert_eq!(response.model, "llama3.2");
+        assert_eq!(response.first_content(), Some("Hi there"));
+        assert_eq!(response.choices[0].finish_reason, Some(FinishReason::Stop));
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.prompt_tokens, 12);
+        assert_eq!(usage.completion_tokens, 5);
+        assert_eq!(usage.total_tokens, 17);
+    }
+
+    #[test]
+    fn test_transform_chat_response_tool_calls() {
+        let raw = json!({
+            "model": "llama3.2",
+            "message": {
+                "role": "assistant",
+                "content": "",
+                "tool_calls": [{"function": {"name": "get_weather", "arguments": {"city": "Paris"}}}]
+            },
+            "done": true
+        });
+        let parsed: OllamaChatResponse = serde_json::from_value(raw).unwrap();
+        let response = transform_chat_response(parsed, "llama3.2");
+
+        assert_eq!(
+            response.choices[0].finish_reason,
+            Some(FinishReason::ToolCalls)
+        );
+        let calls = response.first_tool_calls().unwrap();
+        assert_eq!(calls[0].function.name, "get_weather");
+        assert_eq!(calls[0].function.arguments, r#"{"city":"Paris"}"#);
+        assert!(calls[0].id.starts_with("call_"));
+    }
+
+    #[test]
+    fn test_transform_stream_line() {
+        let raw = json!({
+            "model": "llama3.2",
+            "message": {"role": "assistant", "content": "Hel"},
+            "done": false
+        });
+        let parsed: OllamaChatResponse = serde_json::from_value(raw).unwrap();
+        let chunk = transform_stream_line(parsed, "chatcmpl-1", true);
+
+        assert_eq!(chunk.id, "chatcmpl-1");
+        assert_eq!(chunk.choices[0].delta.role, Some(MessageRole::Assistant));
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hel"));
+        assert!(chunk.choices[0].finish_reason.is_none());
+        assert!(chunk.usage.is_none());
+    }
+
+    #[test]
+    fn test_transform_stream_final_line() {
+        let raw = json!({
+            "model": "llama3.2",
+            "message": {"role": "assistant", "content": ""},
+            "done": true,
+            "done_reason": "length",
+            "prompt_eval_count": 3,
+            "eval_count": 7
+        });
+        let parsed: OllamaChatResponse = serde_json::from_value(raw).unwrap();
+        let chunk = transform_stream_line(parsed, "chatcmpl-1", false);
+
+        assert!(chunk.choices[0].delta.role.is_none());
+        assert!(chunk.choices[0].delta.content.is_none());
+        assert_eq!(chunk.choices[0].finish_reason, Some(FinishReason::Length));
+        assert_eq!(chunk.usage.unwrap().total_tokens, 10);
+    }
+
+    #[test]
+    fn test_transform_embedding_round_trip() {
+        let request = EmbeddingRequest {
+            model: "ollama/nomic-embed-text".to_string(),
+            input: crate::core::types::requests::EmbeddingInput::Text("hello".to_string()),
+            user: None,
+            encoding_format: None,
+            dimensions: None,
+            task_type: None,
+            extra_params: HashMap::new(),
+        };
+        let body = transform_embedding_request(&request, None);
+        assert_eq!(body["model"], "nomic-embed-text");
+        assert_eq!(body["input"][0], "hello");
+
+        let raw = json!({
+            "model": "nomic-embed-text",
+            "embeddings": [[0.1, 0.2, 0.3]],
+            "prompt_eval_count": 2
+        });
+        let parsed: OllamaEmbedResponse = serde_json::from_value(raw).unwrap();
+        let response = transform_embedding_response(parsed, &request.model);
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].embedding.len(), 3);
+        assert_eq!(response.usage.unwrap().prompt_tokens, 2);
+    }
+
+    #[test]
+    fn test_tag_to_model_info() {
+        let raw = json!({
+            "models": [
+                {
+                    "name": "llama3.2:latest",
+                    "model": "llama3.2:latest",
+                    "modified_at": "2024-10-01T12:00:00Z",
+                    "size": 2019393189u64,
+                    "digest": "a80c4f17acd5",
+                    "details": {"family": "llama", "parameter_size": "3.2B", "quantization_level": "Q4_K_M"}
+                },
+                {
+                    "name": "nomic-embed-text:latest",
+                    "details": {"family": "nomic-bert"}
+                }
+            ]
+        });
+        let parsed: OllamaTagsResponse = serde_json::from_value(raw).unwrap();
+        let models: Vec<ModelInfo> = parsed.models.iter().map(tag_to_model_info).collect();
+
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].id, "llama3.2:latest");
+        assert_eq!(models[0].provider, "ollama");
+        assert_eq!(models[0].input_cost_per_1k_tokens, Some(0.0));
+        assert!(models[0].updated_at.is_some());
+        assert_eq!(models[0].metadata["parameter_size"], "3.2B");
+        assert!(
+            models[1]
+                .capabilities
+                .contains(&ProviderCapability::Embeddings)
+        );
+        assert!(!models[1].supports_streaming);
+    }
+}
@@ -0,0 +1,58 @@
+//! OpenAI Assistants API Module
+//!
+//! Untyped access to `/assistants` and `/threads` (runs, messages, steps),
+//! used by the gateway to pass requests through with its own credentials.
+
+use crate::core::providers::unified_provider::ProviderError;
+
+/// `OpenAI-Beta` header value of the Assistants API
+pub const ASSISTANTS_BETA: &str = "assistants=v2";
+
+/// Assistants API utilities
+pub struct OpenAIAssistantsUtils;
+
+impl OpenAIAssistantsUtils {
+    /// Check an Assistants API path relative to the API base
+    ///
+    /// The path must start with `assistants` or `threads` and contain only
+    /// ID-like segments.
+    pub fn validate_path(path: &str) -> Result<(), ProviderError> {
+        let mut segments = path.split('/');
+        let valid_root = matches!(segments.next(), Some("assistants" | "threads"));
+        let valid_segments = segments.all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        });
+
+        if valid_root && valid_segments {
+            Ok(())
+        } else {
+            Err(ProviderError::invalid_request(
+                "openai",
+                format!("Invalid Assistants API path: {}", path),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_path() {
+        assert!(OpenAIAssistantsUtils::validate_path("assistants").is_ok());
+        assert!(OpenAIAssistantsUtils::validate_path("threads/thread_abc123/runs").is_ok());
+        assert!(
+            OpenAIAssistantsUtils::validate_path(
+                "threads/thread_abc/runs/run_1/submit_tool_outputs"
+            )
+            .is_ok()
+        );
+        assert!(OpenAIAssistantsUtils::validate_path("files").is_err());
+        assert!(OpenAIAssistantsUtils::validate_path("threads/../files").is_err());
+        assert!(OpenAIAssistantsUtils::validate_path("threads//runs").is_err());
+    }
+}
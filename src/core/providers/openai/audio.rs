@@ -0,0 +1,340 @@
+//! OpenAI Audio Module
+//!
+//! Speech-to-text (Whisper) and text-to-speech request handling
+
+use reqwest::multipart::{Form, Part};
+
+use crate::core::audio::types::{SpeechRequest, format_to_content_type};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::requests::AudioTranscriptionRequest;
+use crate::core::types::responses::AudioTranscriptionResponse;
+
+/// Maximum audio upload size accepted by the transcription endpoint (25MB)
+pub const MAX_AUDIO_FILE_SIZE: usize = 25 * 1024 * 1024;
+
+/// Filename sent when the caller did not provide one
+const DEFAULT_AUDIO_FILENAME: &str = "audio.mp3";
+
+/// Response formats accepted by the transcription endpoint
+const TRANSCRIPTION_RESPONSE_FORMATS: &[&str] = &["json", "text", "srt", "verbose_json", "vtt"];
+
+/// Maximum input length for `/audio/speech`
+pub const MAX_SPEECH_INPUT_CHARS: usize = 4096;
+
+/// Audio formats `/audio/speech` can produce
+const SPEECH_RESPONSE_FORMATS: &[&str] = &["mp3", "opus", "aac", "flac", "wav", "pcm"];
+
+/// OpenAI audio utilities
+pub struct OpenAIAudioUtils;
+
+impl OpenAIAudioUtils {
+    /// Validate a transcription request before uploading it
+    pub fn validate_transcription_request(
+        request: &AudioTranscriptionRequest,
+    ) -> Result<(), ProviderError> {
+        if request.file.is_empty() {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                "Audio file cannot be empty",
+            ));
+        }
+
+        if request.file.len() > MAX_AUDIO_FILE_SIZE {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                "Audio file too large (max 25MB)",
+            ));
+        }
+
+        if let Some(temperature) = request.temperature {
+            if !(0.0..=1.0).contains(&temperature) {
+                return Err(ProviderError::invalid_request(
+                    "openai",
+                    "temperature must be between 0 and 1",
+                ));
+            }
+        }
+
+        if let Some(format) = &request.response_format {
+            if !TRANSCRIPTION_RESPONSE_FORMATS.contains(&format.as_str()) {
+                return Err(ProviderError::invalid_request(
+                    "openai",
+                    format!("Unsupported response_format: {}", format),
+                ));
+            }
+        }
+
+        if request.timestamp_granularities.is_some()
+            && request.response_format.as_deref() != Some("verbose_json")
+        {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                "timestamp_granularities requires response_format 'verbose_json'",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// MIME type for an audio filename, based on its extension
+    pub fn audio_mime_type(filename: &str) -> &'static str {
+        let extension = filename
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "mp3" | "mpga" | "mpeg" => "audio/mpeg",
+            "mp4" => "audio/mp4",
+            "m4a" => "audio/m4a",
+            "wav" => "audio/wav",
+            "webm" => "audio/webm",
+            "ogg" | "oga" => "audio/ogg",
+            "flac" => "audio/flac",
+            _ => "application/octet-stream",
+        }
+    }
+
+    /// Build the multipart form for `/audio/transcriptions`
+    ///
+    /// `model` is omitted when `None`, since Azure selects the model through
+    /// the deployment in the URL.
+    pub fn build_transcription_form(
+        request: AudioTranscriptionRequest,
+        model: Option<String>,
+    ) -> Result<Form, ProviderError> {
+        let filename = request
+            .filename
+            .unwrap_or_else(|| DEFAULT_AUDIO_FILENAME.to_string());
+        let mime_type = Self::audio_mime_type(&filename);
+        let file_part = Part::bytes(request.file)
+            .file_name(filename)
+            .mime_str(mime_type)
+            .map_err(|e| {
+                ProviderError::invalid_request("openai", format!("Invalid MIME type: {}", e))
+            })?;
+
+        let mut form = Form::new().part("file", file_part);
+        if let Some(model) = model {
+            form = form.text("model", model);
+        }
+        if let Some(language) = request.language {
+            form = form.text("language", language);
+        }
+        if let Some(prompt) = request.prompt {
+            form = form.text("prompt", prompt);
+        }
+        if let Some(response_format) = request.response_format {
+            form = form.text("response_format", response_format);
+        }
+        if let Some(temperature) = request.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        if let Some(granularities) = request.timestamp_granularities {
+            for granularity in granularities {
+                form = form.text("timestamp_granularities[]", granularity);
+            }
+        }
+
+        Ok(form)
+    }
+
+    /// Parse a transcription response body
+    ///
+    /// `text`, `srt` and `vtt` formats return the raw body rather than JSON,
+    /// so it becomes the transcription text as-is.
+    pub fn parse_transcription_response(
+        body: &str,
+        response_format: Option<&str>,
+    ) -> Result<AudioTranscriptionResponse, ProviderError> {
+        match response_format {
+            None | Some("json") | Some("verbose_json") => serde_json::from_str(body)
+                .map_err(|e| ProviderError::response_parsing("openai", e.to_string())),
+            Some(_) => Ok(AudioTranscriptionResponse {
+                text: body.to_string(),
+                task: Some("transcribe".to_string()),
+                language: None,
+                duration: None,
+                words: None,
+                segments: None,
+            }),
+        }
+    }
+
+    /// Validate a text-to-speech request
+    pub fn validate_speech_request(request: &SpeechRequest) -> Result<(), ProviderError> {
+        if request.input.is_empty() {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                "Speech input cannot be empty",
+            ));
+        }
+
+        if request.input.chars().count() > MAX_SPEECH_INPUT_CHARS {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                format!(
+                    "Speech input too long (max {} characters)",
+                    MAX_SPEECH_INPUT_CHARS
+                ),
+            ));
+        }
+
+        if request.voice.is_empty() {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                "Speech voice cannot be empty",
+            ));
+        }
+
+        if let Some(speed) = request.speed {
+            if !(0.25..=4.0).contains(&speed) {
+                return Err(ProviderError::invalid_request(
+                    "openai",
+                    "speed must be between 0.25 and 4.0",
+                ));
+            }
+        }
+
+        if let Some(format) = &request.response_format {
+            if !SPEECH_RESPONSE_FORMATS.contains(&format.as_str()) {
+                return Err(ProviderError::invalid_request(
+                    "openai",
+                    format!("Unsupported response_format: {}", format),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Content type of the audio a speech request produces (mp3 by default)
+    pub fn speech_content_type(request: &SpeechRequest) -> &'static str {
+        format_to_content_type(request.response_format.as_deref().unwrap_or("mp3"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> AudioTranscriptionRequest {
+        AudioTranscriptionRequest {
+            file: vec![1, 2, 3],
+            filename: Some("meeting.wav".to_string()),
+            model: "whisper-1".to_string(),
+            language: None,
+            prompt: None,
+            response_format: None,
+            temperature: None,
+            timestamp_granularities: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_transcription_request() {
+        assert!(OpenAIAudioUtils::validate_transcription_request(&request()).is_ok());
+
+        let empty = AudioTranscriptionRequest {
+            file: vec![],
+            ..request()
+        };
+        assert!(OpenAIAudioUtils::validate_transcription_request(&empty).is_err());
+
+        let bad_format = AudioTranscriptionRequest {
+            response_format: Some("xml".to_string()),
+            ..request()
+        };
+        assert!(OpenAIAudioUtils::validate_transcription_request(&bad_format).is_err());
+
+        let granularities = AudioTranscriptionRequest {
+            timestamp_granularities: Some(vec!["word".to_string()]),
+            ..request()
+        };
+        assert!(OpenAIAudioUtils::validate_transcription_request(&granularities).is_err());
+
+        let verbose = AudioTranscriptionRequest {
+            response_format: Some("verbose_json".to_string()),
+            ..granularities
+        };
+        assert!(OpenAIAudioUtils::validate_transcription_request(&verbose).is_ok());
+    }
+
+    #[test]
+    fn test_audio_mime_type() {
+        assert_eq!(OpenAIAudioUtils::audio_mime_type("a.MP3"), "audio/mpeg");
+        assert_eq!(OpenAIAudioUtils::audio_mime_type("a.wav"), "audio/wav");
+        assert_eq!(
+            OpenAIAudioUtils::audio_mime_type("noext"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_build_transcription_form() {
+        let request = AudioTranscriptionRequest {
+            language: Some("en".to_string()),
+            temperature: Some(0.2),
+            ..request()
+        };
+        let form =
+            OpenAIAudioUtils::build_transcription_form(request, Some("whisper-1".to_string()));
+        assert!(form.is_ok());
+    }
+
+    #[test]
+    fn test_parse_transcription_response() {
+        let body = r#"{"task":"transcribe","language":"english","duration":1.5,"text":"Hello","words":[{"word":"Hello","start":0.0,"end":0.4}]}"#;
+        let response =
+            OpenAIAudioUtils::parse_transcription_response(body, Some("verbose_json")).unwrap();
+        assert_eq!(response.text, "Hello");
+        assert_eq!(response.language.as_deref(), Some("english"));
+        assert_eq!(response.words.unwrap()[0].end, 0.4);
+
+        let response =
+            OpenAIAudioUtils::parse_transcription_response("1\n00:00 --> 00:01\nHi", Some("srt"))
+                .unwrap();
+        assert!(response.text.ends_with("Hi"));
+
+        assert!(OpenAIAudioUtils::parse_transcription_response("Hi", None).is_err());
+    }
+
+    #[test]
+    fn test_validate_speech_request() {
+        let request = SpeechRequest {
+            input: "Hello".to_string(),
+            model: "tts-1".to_string(),
+            voice: "alloy".to_string(),
+            ..Default::default()
+        };
+        assert!(OpenAIAudioUtils::validate_speech_request(&request).is_ok());
+        assert_eq!(
+            OpenAIAudioUtils::speech_content_type(&request),
+            "audio/mpeg"
+        );
+
+        let too_fast = SpeechRequest {
+            speed: Some(4.5),
+            ..request.clone()
+        };
+        assert!(OpenAIAudioUtils::validate_speech_request(&too_fast).is_err());
+
+        let too_long = SpeechRequest {
+            input: "a".repeat(MAX_SPEECH_INPUT_CHARS + 1),
+            ..request.clone()
+        };
+        assert!(OpenAIAudioUtils::validate_speech_request(&too_long).is_err());
+
+        let opus = SpeechRequest {
+            response_format: Some("opus".to_string()),
+            ..request.clone()
+        };
+        assert!(OpenAIAudioUtils::validate_speech_request(&opus).is_ok());
+        assert_eq!(OpenAIAudioUtils::speech_content_type(&opus), "audio/opus");
+
+        let ogg = SpeechRequest {
+            response_format: Some("ogg".to_string()),
+            ..request
+        };
+        assert!(OpenAIAudioUtils::validate_speech_request(&ogg).is_err());
+    }
+}
@@ -3,33 +3,47 @@
 //! Unified client following the new provider architecture
 
 use async_trait::async_trait;
-use futures::Stream;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::pin::Pin;
 use std::sync::Arc;
 
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
+use crate::core::files::types::{
+    FileContent, FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+};
+use crate::core::fine_tuning::types::{
+    FineTuningEventList, FineTuningJobList, FineTuningListQuery,
+};
 use crate::core::providers::base::{
     GlobalPoolManager, HeaderPair, HttpMethod, header, header_owned,
 };
 use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse},
+    requests::{AudioTranscriptionRequest, ChatRequest, EmbeddingRequest, MessageRole},
+    responses::{AudioTranscriptionResponse, ChatChunk, ChatResponse, EmbeddingResponse},
 };
 
 use super::{
     advanced_chat::{AdvancedChatRequest, AdvancedChatUtils},
+    assistants::{ASSISTANTS_BETA, OpenAIAssistantsUtils},
+    audio::OpenAIAudioUtils,
     // New functionality modules
     completions::validate_completion_request,
     config::{OpenAIConfig, OpenAIFeature},
     error::OpenAIError,
-    fine_tuning::{OpenAIFineTuningRequest, OpenAIFineTuningUtils},
+    files::{DEFAULT_FILE_CONTENT_TYPE, OpenAIFilesUtils},
+    fine_tuning::{OpenAIFineTuningJob, OpenAIFineTuningRequest, OpenAIFineTuningUtils},
     image_edit::{OpenAIImageEditRequest, OpenAIImageEditUtils},
     image_variations::{OpenAIImageVariationsRequest, OpenAIImageVariationsUtils},
-    models::{OpenAIModelRegistry, get_openai_registry},
-    realtime::{OpenAIRealtimeUtils, RealtimeSessionConfig},
+    models::{
+        OpenAIModelRegistry, get_openai_registry, is_o_series_model, is_o1_preview_model,
+        supports_reasoning_effort,
+    },
+    realtime::{OpenAIRealtimeUtils, RealtimeConnection, RealtimeSessionConfig},
     vector_stores::{OpenAIVectorStoreRequest, OpenAIVectorStoreUtils},
 };
 
@@ -191,9 +205,21 @@ impl OpenAIProvider {
 
     /// Transform ChatRequest to OpenAI API format
     fn transform_chat_request(&self, request: ChatRequest) -> Result<Value, OpenAIError> {
+        let model = self.config.get_model_mapping(&request.model);
+        let o_series = is_o_series_model(&model);
+
+        let mut messages = request.messages;
+        if is_o1_preview_model(&model) {
+            for message in &mut messages {
+                if message.role == MessageRole::System {
+                    message.role = MessageRole::User;
+                }
+            }
+        }
+
         let mut openai_request = serde_json::json!({
-            "model": self.config.get_model_mapping(&request.model),
-            "messages": request.messages
+            "model": model,
+            "messages": messages
         });
 
         // Add optional parameters
@@ -247,6 +273,27 @@ impl OpenAIProvider {
         // Add extra parameters from config
         // Skip extra_params as BaseConfig doesn't have it
 
+        if o_series {
+            let reasoning_effort = request
+                .extra_params
+                .get("reasoning_effort")
+                .cloned()
+                .or_else(|| {
+                    request
+                        .thinking
+                        .as_ref()
+                        .filter(|t| t.enabled)
+                        .and_then(|t| t.effort)
+                        .map(|effort| Value::String(effort.as_str().to_string()))
+                });
+            if let Some(reasoning_effort) = reasoning_effort {
+                openai_request["reasoning_effort"] = reasoning_effort;
+            }
+            if let Some(params) = openai_request.as_object_mut() {
+                map_o_series_params(params, &model);
+            }
+        }
+
         Ok(openai_request)
     }
 
@@ -328,6 +375,7 @@ impl LLMProvider for OpenAIProvider {
             ProviderCapability::Embeddings,
             ProviderCapability::ImageGeneration,
             ProviderCapability::AudioTranscription,
+            ProviderCapability::TextToSpeech,
             ProviderCapability::ToolCalling,
             ProviderCapability::FunctionCalling,
             // New capabilities
@@ -369,6 +417,30 @@ impl LLMProvider for OpenAIProvider {
         self.execute_chat_completion_stream(request).await
     }
 
+    async fn audio_transcription(
+        &self,
+        request: AudioTranscriptionRequest,
+        _context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, Self::Error> {
+        self.transcribe_audio(request).await
+    }
+
+    async fn speech(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<SpeechResponse, Self::Error> {
+        self.create_speech(request).await
+    }
+
+    async fn speech_stream(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, Self::Error>> + Send>>, Self::Error> {
+        self.create_speech_stream(request).await
+    }
+
     async fn health_check(&self) -> HealthStatus {
         let url = format!("{}/models?limit=1", self.config.get_api_base());
         let client = reqwest::Client::new();
@@ -452,13 +524,7 @@ impl LLMProvider for OpenAIProvider {
                     "n",
                     "logit_bias",
                 ],
-                super::models::OpenAIModelFamily::O1 => &[
-                    "messages",
-                    "model",
-                    "max_completion_tokens",
-                    "stream",
-                    "user",
-                ],
+                ref family if family.is_o_series() => O_SERIES_SUPPORTED_PARAMS,
                 _ => &[
                     "messages",
                     "model",
@@ -469,6 +535,8 @@ impl LLMProvider for OpenAIProvider {
                     "user",
                 ],
             }
+        } else if is_o_series_model(model) {
+            O_SERIES_SUPPORTED_PARAMS
         } else {
             &[
                 "messages",
@@ -485,10 +553,15 @@ impl LLMProvider for OpenAIProvider {
     async fn map_openai_params(
         &self,
         params: HashMap<String, Value>,
-        _model: &str,
+        model: &str,
     ) -> Result<HashMap<String, Value>, Self::Error> {
-        // OpenAI provider uses standard OpenAI parameters, no mapping needed
-        Ok(params)
+        // Standard OpenAI parameters pass through, except for o-series models
+        if !is_o_series_model(model) {
+            return Ok(params);
+        }
+        let mut params: serde_json::Map<String, Value> = params.into_iter().collect();
+        map_o_series_params(&mut params, model);
+        Ok(params.into_iter().collect())
     }
 
     async fn transform_request(
@@ -624,13 +697,13 @@ impl OpenAIProvider {
     }
 
     /// Audio transcription (Whisper)
+    ///
+    /// Uploads the audio as multipart form data, which the shared connection
+    /// pool's JSON request path cannot carry.
     pub async fn transcribe_audio(
         &self,
-        _file: Vec<u8>,
-        model: Option<String>,
-        language: Option<String>,
-        response_format: Option<String>,
-    ) -> Result<Value, OpenAIError> {
+        request: AudioTranscriptionRequest,
+    ) -> Result<AudioTranscriptionResponse, OpenAIError> {
         if !self
             .config
             .is_feature_enabled(OpenAIFeature::AudioTranscription)
@@ -641,34 +714,230 @@ impl OpenAIProvider {
             });
         }
 
-        // This would need multipart form handling - simplified for now
-        let request = serde_json::json!({
-            "model": model.unwrap_or_else(|| "whisper-1".to_string()),
-            "language": language,
-            "response_format": response_format
-        });
+        OpenAIAudioUtils::validate_transcription_request(&request)?;
+
+        let response_format = request.response_format.clone();
+        let model = request.model.clone();
+        let form = OpenAIAudioUtils::build_transcription_form(request, Some(model))?;
 
-        // In a real implementation, this would handle file upload
         let url = format!("{}/audio/transcriptions", self.config.get_api_base());
+        let mut request_builder = self.pool_manager.client().post(&url);
+        for (key, value) in self.get_request_headers() {
+            request_builder = request_builder.header(key.as_ref(), value.as_ref());
+        }
 
-        let headers = self.get_request_headers();
-        let body = Some(request);
+        let response =
+            request_builder
+                .multipart(form)
+                .send()
+                .await
+                .map_err(|e| OpenAIError::Network {
+                    provider: "openai",
+                    message: e.to_string(),
+                })?;
+
+        let status = response.status();
+        let body = response.text().await.map_err(|e| OpenAIError::Network {
+            provider: "openai",
+            message: e.to_string(),
+        })?;
+
+        if !status.is_success() {
+            return Err(OpenAIError::openai_api_error(status.as_u16(), body));
+        }
+
+        OpenAIAudioUtils::parse_transcription_response(&body, response_format.as_deref())
+    }
+
+    /// Send a text-to-speech request, returning the successful audio response
+    async fn send_speech_request(
+        &self,
+        request: &SpeechRequest,
+    ) -> Result<reqwest::Response, OpenAIError> {
+        if !self.config.is_feature_enabled(OpenAIFeature::AudioModels) {
+            return Err(OpenAIError::NotSupported {
+                provider: "openai",
+                feature: "Text-to-speech is disabled in configuration".to_string(),
+            });
+        }
+
+        OpenAIAudioUtils::validate_speech_request(request)?;
 
+        let body = serde_json::to_value(request).map_err(|e| OpenAIError::InvalidRequest {
+            provider: "openai",
+            message: e.to_string(),
+        })?;
+
+        let url = format!("{}/audio/speech", self.config.get_api_base());
         let response = self
             .pool_manager
-            .execute_request(&url, HttpMethod::POST, headers, body)
+            .execute_request(
+                &url,
+                HttpMethod::POST,
+                self.get_request_headers(),
+                Some(body),
+            )
             .await
             .map_err(|e| OpenAIError::Network {
                 provider: "openai",
                 message: e.to_string(),
             })?;
 
-        let response_bytes = response.bytes().await.map_err(|e| OpenAIError::Network {
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(OpenAIError::openai_api_error(status.as_u16(), body));
+        }
+
+        Ok(response)
+    }
+
+    /// Text-to-speech (tts-1, tts-1-hd, gpt-4o-mini-tts)
+    pub async fn create_speech(
+        &self,
+        request: SpeechRequest,
+    ) -> Result<SpeechResponse, OpenAIError> {
+        let response = self.send_speech_request(&request).await?;
+        let audio = response.bytes().await.map_err(|e| OpenAIError::Network {
             provider: "openai",
             message: e.to_string(),
         })?;
 
-        serde_json::from_slice(&response_bytes).map_err(|e| OpenAIError::ResponseParsing {
+        Ok(SpeechResponse {
+            audio: audio.to_vec(),
+            content_type: OpenAIAudioUtils::speech_content_type(&request).to_string(),
+        })
+    }
+
+    /// Text-to-speech, yielding audio chunks as they arrive
+    pub async fn create_speech_stream(
+        &self,
+        request: SpeechRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, OpenAIError>> + Send>>, OpenAIError> {
+        let response = self.send_speech_request(&request).await?;
+        let stream = response.bytes_stream().map(|chunk| {
+            chunk.map_err(|e| OpenAIError::Network {
+                provider: "openai",
+                message: e.to_string(),
+            })
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Upload a file for use with batches, fine-tuning or assistants
+    pub async fn upload_file(&self, request: FileUploadRequest) -> Result<FileObject, OpenAIError> {
+        OpenAIFilesUtils::validate_upload_request(&request)?;
+        let form = OpenAIFilesUtils::build_upload_form(request)?;
+
+        let url = format!("{}/files", self.config.get_api_base());
+        let mut request_builder = self.pool_manager.client().post(&url);
+        for (key, value) in self.get_request_headers() {
+            request_builder = request_builder.header(key.as_ref(), value.as_ref());
+        }
+
+        let response =
+            request_builder
+                .multipart(form)
+                .send()
+                .await
+                .map_err(|e| OpenAIError::Network {
+                    provider: "openai",
+                    message: e.to_string(),
+                })?;
+
+        Self::parse_api_response(response).await
+    }
+
+    /// List uploaded files
+    pub async fn list_files(&self, query: FileListQuery) -> Result<FileListResponse, OpenAIError> {
+        let path = OpenAIFilesUtils::list_path(&query);
+        let response = self.send_api_request(&path, HttpMethod::GET, None).await?;
+        Self::parse_api_response(response).await
+    }
+
+    /// Retrieve a file's metadata
+    pub async fn retrieve_file(&self, file_id: &str) -> Result<FileObject, OpenAIError> {
+        OpenAIFilesUtils::validate_file_id(file_id)?;
+        let path = format!("files/{}", file_id);
+        let response = self.send_api_request(&path, HttpMethod::GET, None).await?;
+        Self::parse_api_response(response).await
+    }
+
+    /// Delete a file
+    pub async fn delete_file(&self, file_id: &str) -> Result<FileDeleteResponse, OpenAIError> {
+        OpenAIFilesUtils::validate_file_id(file_id)?;
+        let path = format!("files/{}", file_id);
+        let response = self
+            .send_api_request(&path, HttpMethod::DELETE, None)
+            .await?;
+        Self::parse_api_response(response).await
+    }
+
+    /// Download a file's contents, e.g. the output of a finished batch
+    pub async fn file_content(&self, file_id: &str) -> Result<FileContent, OpenAIError> {
+        OpenAIFilesUtils::validate_file_id(file_id)?;
+        let path = format!("files/{}/content", file_id);
+        let response = self.send_api_request(&path, HttpMethod::GET, None).await?;
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(DEFAULT_FILE_CONTENT_TYPE)
+            .to_string();
+        let content = response.bytes().await.map_err(|e| OpenAIError::Network {
+            provider: "openai",
+            message: e.to_string(),
+        })?;
+
+        Ok(FileContent {
+            content: content.to_vec(),
+            content_type,
+        })
+    }
+
+    /// Send a JSON API request, returning the successful response
+    async fn send_api_request(
+        &self,
+        path: &str,
+        method: HttpMethod,
+        body: Option<Value>,
+    ) -> Result<reqwest::Response, OpenAIError> {
+        let url = format!("{}/{}", self.config.get_api_base(), path);
+        let response = self
+            .pool_manager
+            .execute_request(&url, method, self.get_request_headers(), body)
+            .await
+            .map_err(|e| OpenAIError::Network {
+                provider: "openai",
+                message: e.to_string(),
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(OpenAIError::openai_api_error(status.as_u16(), body));
+        }
+
+        Ok(response)
+    }
+
+    /// Deserialize a JSON API response, surfacing API errors
+    async fn parse_api_response<T: serde::de::DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<T, OpenAIError> {
+        let status = response.status();
+        let body = response.text().await.map_err(|e| OpenAIError::Network {
+            provider: "openai",
+            message: e.to_string(),
+        })?;
+
+        if !status.is_success() {
+            return Err(OpenAIError::openai_api_error(status.as_u16(), body));
+        }
+
+        serde_json::from_str(&body).map_err(|e| OpenAIError::ResponseParsing {
             provider: "openai",
             message: e.to_string(),
         })
@@ -722,88 +991,64 @@ impl OpenAIProvider {
     pub async fn create_fine_tuning_job(
         &self,
         request: OpenAIFineTuningRequest,
-    ) -> Result<super::fine_tuning::OpenAIFineTuningJob, OpenAIError> {
+    ) -> Result<OpenAIFineTuningJob, OpenAIError> {
         // Validate request
-        OpenAIFineTuningUtils::validate_request(&request).map_err(|e| {
-            OpenAIError::InvalidRequest {
-                provider: "openai",
-                message: e.to_string(),
-            }
-        })?;
+        OpenAIFineTuningUtils::validate_request(&request)?;
 
-        // Execute request
-        let url = format!("{}/fine_tuning/jobs", self.config.get_api_base());
         let request_value =
             serde_json::to_value(request).map_err(|e| OpenAIError::InvalidRequest {
                 provider: "openai",
                 message: e.to_string(),
             })?;
 
-        let headers = self.get_request_headers();
-        let body = Some(request_value);
-
         let response = self
-            .pool_manager
-            .execute_request(&url, HttpMethod::POST, headers, body)
-            .await
-            .map_err(|e| OpenAIError::Network {
-                provider: "openai",
-                message: e.to_string(),
-            })?;
-
-        let response_bytes = response.bytes().await.map_err(|e| OpenAIError::Network {
-            provider: "openai",
-            message: e.to_string(),
-        })?;
-
-        serde_json::from_slice(&response_bytes).map_err(|e| OpenAIError::ResponseParsing {
-            provider: "openai",
-            message: e.to_string(),
-        })
+            .send_api_request("fine_tuning/jobs", HttpMethod::POST, Some(request_value))
+            .await?;
+        Self::parse_api_response(response).await
     }
 
     /// List fine-tuning jobs
     pub async fn list_fine_tuning_jobs(
         &self,
-        after: Option<String>,
-        limit: Option<u32>,
-    ) -> Result<Value, OpenAIError> {
-        let mut query_params = Vec::new();
-        if let Some(after) = after {
-            query_params.push(format!("after={}", after));
-        }
-        if let Some(limit) = limit {
-            query_params.push(format!("limit={}", limit));
-        }
-
-        let endpoint = if query_params.is_empty() {
-            "fine_tuning/jobs".to_string()
-        } else {
-            format!("fine_tuning/jobs?{}", query_params.join("&"))
-        };
-
-        let url = format!("{}/{}", self.config.get_api_base(), endpoint);
-        let client = reqwest::Client::new();
-        let mut req = client.get(&url);
-
-        if let Some(api_key) = &self.config.base.api_key {
-            req = req.header("Authorization", format!("Bearer {}", api_key));
-        }
+        query: FineTuningListQuery,
+    ) -> Result<FineTuningJobList, OpenAIError> {
+        let path = OpenAIFineTuningUtils::jobs_path("", &query);
+        let response = self.send_api_request(&path, HttpMethod::GET, None).await?;
+        Self::parse_api_response(response).await
+    }
 
-        let response = req.send().await.map_err(|e| OpenAIError::Network {
-            provider: "openai",
-            message: e.to_string(),
-        })?;
+    /// Retrieve a fine-tuning job
+    pub async fn retrieve_fine_tuning_job(
+        &self,
+        job_id: &str,
+    ) -> Result<OpenAIFineTuningJob, OpenAIError> {
+        OpenAIFineTuningUtils::validate_job_id(job_id)?;
+        let path = format!("fine_tuning/jobs/{}", job_id);
+        let response = self.send_api_request(&path, HttpMethod::GET, None).await?;
+        Self::parse_api_response(response).await
+    }
 
-        let response_bytes = response.bytes().await.map_err(|e| OpenAIError::Network {
-            provider: "openai",
-            message: e.to_string(),
-        })?;
+    /// Cancel a running fine-tuning job
+    pub async fn cancel_fine_tuning_job(
+        &self,
+        job_id: &str,
+    ) -> Result<OpenAIFineTuningJob, OpenAIError> {
+        OpenAIFineTuningUtils::validate_job_id(job_id)?;
+        let path = format!("fine_tuning/jobs/{}/cancel", job_id);
+        let response = self.send_api_request(&path, HttpMethod::POST, None).await?;
+        Self::parse_api_response(response).await
+    }
 
-        serde_json::from_slice(&response_bytes).map_err(|e| OpenAIError::ResponseParsing {
-            provider: "openai",
-            message: e.to_string(),
-        })
+    /// List status events of a fine-tuning job
+    pub async fn list_fine_tuning_events(
+        &self,
+        job_id: &str,
+        query: FineTuningListQuery,
+    ) -> Result<FineTuningEventList, OpenAIError> {
+        OpenAIFineTuningUtils::validate_job_id(job_id)?;
+        let path = OpenAIFineTuningUtils::jobs_path(&format!("/{}/events", job_id), &query);
+        let response = self.send_api_request(&path, HttpMethod::GET, None).await?;
+        Self::parse_api_response(response).await
     }
 
     /// Edit image
@@ -1012,6 +1257,61 @@ impl OpenAIProvider {
         }))
     }
 
+    /// Send an Assistants API request (`assistants/...` or `threads/...`)
+    ///
+    /// `query` is an already-encoded query string.
+    pub async fn assistants_request(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        query: &str,
+        body: Option<Value>,
+    ) -> Result<Value, OpenAIError> {
+        OpenAIAssistantsUtils::validate_path(path)?;
+
+        let mut url = format!("{}/{}", self.config.get_api_base(), path);
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(query);
+        }
+
+        let mut headers = self.get_request_headers();
+        headers.push(header("OpenAI-Beta", ASSISTANTS_BETA.to_string()));
+
+        let response = self
+            .pool_manager
+            .execute_request(&url, method, headers, body)
+            .await
+            .map_err(|e| OpenAIError::Network {
+                provider: "openai",
+                message: e.to_string(),
+            })?;
+
+        Self::parse_api_response(response).await
+    }
+
+    /// Upstream endpoint of a real-time WebSocket session
+    pub fn realtime_connection(&self, model: &str) -> RealtimeConnection {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("model", model)
+            .finish();
+
+        let mut headers: Vec<(String, String)> = self
+            .get_request_headers()
+            .into_iter()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        headers.push(("OpenAI-Beta".to_string(), "realtime=v1".to_string()));
+
+        RealtimeConnection {
+            url: OpenAIRealtimeUtils::websocket_url(
+                &self.config.get_api_base(),
+                &format!("/realtime?{}", query),
+            ),
+            headers,
+        }
+    }
+
     /// Advanced chat completion with structured outputs and reasoning
     pub async fn advanced_chat_completion(
         &self,
@@ -1107,6 +1407,54 @@ impl crate::core::traits::error_mapper::trait_def::ErrorMapper<OpenAIError> for
     }
 }
 
+/// Parameters accepted for o-series models
+const O_SERIES_SUPPORTED_PARAMS: &[&str] = &[
+    "messages",
+    "model",
+    "max_tokens",
+    "max_completion_tokens",
+    "reasoning_effort",
+    "stop",
+    "stream",
+    "tools",
+    "tool_choice",
+    "parallel_tool_calls",
+    "response_format",
+    "user",
+    "seed",
+    "n",
+];
+
+/// Sampling parameters o-series models reject
+const O_SERIES_UNSUPPORTED_PARAMS: &[&str] = &[
+    "top_p",
+    "frequency_penalty",
+    "presence_penalty",
+    "logprobs",
+    "top_logprobs",
+    "logit_bias",
+];
+
+/// Rewrite parameters for an o-series model
+///
+/// `max_tokens` becomes `max_completion_tokens`, and parameters the model
+/// rejects are dropped instead of failing the request. `temperature` is kept
+/// only at its fixed value of 1.
+fn map_o_series_params(params: &mut serde_json::Map<String, Value>, model: &str) {
+    if let Some(max_tokens) = params.remove("max_tokens") {
+        params.entry("max_completion_tokens").or_insert(max_tokens);
+    }
+    if params.get("temperature").and_then(|t| t.as_f64()) != Some(1.0) {
+        params.remove("temperature");
+    }
+    for param in O_SERIES_UNSUPPORTED_PARAMS {
+        params.remove(*param);
+    }
+    if !supports_reasoning_effort(model) {
+        params.remove("reasoning_effort");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1138,4 +1486,96 @@ mod tests {
             &ProviderCapability::ChatCompletion
         ));
     }
+
+    fn test_provider() -> OpenAIProvider {
+        OpenAIProvider {
+            pool_manager: Arc::new(GlobalPoolManager::default()),
+            config: OpenAIConfig::default(),
+            model_registry: get_openai_registry(),
+        }
+    }
+
+    #[test]
+    fn test_o_series_request() {
+        let provider = test_provider();
+        let mut request = ChatRequest::new("o3-mini")
+            .add_system_message("Be brief")
+            .add_user_message("Hi")
+            .with_temperature(0.2)
+            .with_max_tokens(512);
+        request.top_p = Some(0.9);
+        request
+            .extra_params
+            .insert("reasoning_effort".to_string(), serde_json::json!("high"));
+
+        let body = provider.transform_chat_request(request).unwrap();
+        assert_eq!(body["max_completion_tokens"], 512);
+        assert_eq!(body["reasoning_effort"], "high");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert!(body.get("max_tokens").is_none());
+        assert!(body.get("temperature").is_none());
+        assert!(body.get("top_p").is_none());
+
+        // Thinking effort stands in for reasoning_effort; o1-mini takes neither
+        // it nor system messages
+        let request = ChatRequest::new("o1-mini")
+            .add_system_message("Be brief")
+            .with_temperature(1.0)
+            .with_thinking(crate::core::types::ThinkingConfig::low_effort());
+        let body = provider.transform_chat_request(request).unwrap();
+        assert_eq!(body["messages"][0]["role"], "user");
+        assert_eq!(body["temperature"], 1.0);
+        assert!(body.get("reasoning_effort").is_none());
+
+        let request = ChatRequest::new("o4-mini")
+            .with_thinking(crate::core::types::ThinkingConfig::low_effort());
+        let body = provider.transform_chat_request(request).unwrap();
+        assert_eq!(body["reasoning_effort"], "low");
+
+        let request = ChatRequest::new("gpt-4o")
+            .with_temperature(0.2)
+            .with_max_tokens(512);
+        let body = provider.transform_chat_request(request).unwrap();
+        assert_eq!(body["max_tokens"], 512);
+        assert!(body.get("temperature").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_map_o_series_params() {
+        let provider = test_provider();
+        let params: HashMap<String, Value> = [
+            ("max_tokens", serde_json::json!(100)),
+            ("temperature", serde_json::json!(0.7)),
+            ("presence_penalty", serde_json::json!(0.5)),
+            ("reasoning_effort", serde_json::json!("medium")),
+            ("stream", serde_json::json!(true)),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+
+        let mapped = provider
+            .map_openai_params(params.clone(), "o3")
+            .await
+            .unwrap();
+        assert_eq!(mapped["max_completion_tokens"], 100);
+        assert_eq!(mapped["reasoning_effort"], "medium");
+        assert_eq!(mapped["stream"], true);
+        assert!(!mapped.contains_key("max_tokens"));
+        assert!(!mapped.contains_key("temperature"));
+        assert!(!mapped.contains_key("presence_penalty"));
+
+        let mapped = provider
+            .map_openai_params(params.clone(), "o1-preview")
+            .await
+            .unwrap();
+        assert!(!mapped.contains_key("reasoning_effort"));
+
+        let mapped = provider.map_openai_params(params, "gpt-4o").await.unwrap();
+        assert_eq!(mapped.len(), 5);
+
+        let supported = provider.get_supported_openai_params("o3-mini");
+        assert!(supported.contains(&"reasoning_effort"));
+        assert!(!supported.contains(&"temperature"));
+    }
 }
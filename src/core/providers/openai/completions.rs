@@ -185,6 +185,9 @@ impl OpenAICompletionTransformer {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }),
             system_fingerprint: None, // Not available in OpenAI completions API
         })
@@ -0,0 +1,153 @@
+//! OpenAI Files Module
+//!
+//! Upload forms and response handling for the `/files` endpoints
+
+use reqwest::multipart::{Form, Part};
+
+use crate::core::files::types::{FileListQuery, FileUploadRequest};
+use crate::core::providers::unified_provider::ProviderError;
+
+/// Content type assumed when the provider does not report one
+pub const DEFAULT_FILE_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// OpenAI files utilities
+pub struct OpenAIFilesUtils;
+
+impl OpenAIFilesUtils {
+    /// Validate an upload request
+    pub fn validate_upload_request(request: &FileUploadRequest) -> Result<(), ProviderError> {
+        request
+            .validate()
+            .map_err(|e| ProviderError::invalid_request("openai", e))
+    }
+
+    /// Build the multipart form for `POST /files`
+    pub fn build_upload_form(request: FileUploadRequest) -> Result<Form, ProviderError> {
+        let mime_type = Self::file_mime_type(&request.filename);
+        let file_part = Part::bytes(request.file)
+            .file_name(request.filename)
+            .mime_str(mime_type)
+            .map_err(|e| {
+                ProviderError::invalid_request("openai", format!("Invalid MIME type: {}", e))
+            })?;
+
+        Ok(Form::new()
+            .text("purpose", request.purpose)
+            .part("file", file_part))
+    }
+
+    /// MIME type for an uploaded filename, based on its extension
+    pub fn file_mime_type(filename: &str) -> &'static str {
+        let extension = filename
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "jsonl" => "application/jsonl",
+            "json" => "application/json",
+            "txt" | "md" => "text/plain",
+            "csv" => "text/csv",
+            "pdf" => "application/pdf",
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            _ => DEFAULT_FILE_CONTENT_TYPE,
+        }
+    }
+
+    /// Path of `GET /files`, including the query string when there is one
+    pub fn list_path(query: &FileListQuery) -> String {
+        let query_string = query.to_query_string();
+        if query_string.is_empty() {
+            "files".to_string()
+        } else {
+            format!("files?{}", query_string)
+        }
+    }
+
+    /// Reject file IDs that would escape the `/files/{id}` path
+    pub fn validate_file_id(file_id: &str) -> Result<(), ProviderError> {
+        if file_id.is_empty()
+            || !file_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                format!("Invalid file ID: {}", file_id),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> FileUploadRequest {
+        FileUploadRequest {
+            file: b"{\"custom_id\":\"1\"}\n".to_vec(),
+            filename: "batch.jsonl".to_string(),
+            purpose: "batch".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_upload_request() {
+        assert!(OpenAIFilesUtils::validate_upload_request(&request()).is_ok());
+
+        let empty = FileUploadRequest {
+            file: vec![],
+            ..request()
+        };
+        assert!(OpenAIFilesUtils::validate_upload_request(&empty).is_err());
+
+        let bad_purpose = FileUploadRequest {
+            purpose: "training".to_string(),
+            ..request()
+        };
+        assert!(OpenAIFilesUtils::validate_upload_request(&bad_purpose).is_err());
+    }
+
+    #[test]
+    fn test_file_mime_type() {
+        assert_eq!(
+            OpenAIFilesUtils::file_mime_type("batch.JSONL"),
+            "application/jsonl"
+        );
+        assert_eq!(
+            OpenAIFilesUtils::file_mime_type("noext"),
+            DEFAULT_FILE_CONTENT_TYPE
+        );
+        assert!(OpenAIFilesUtils::build_upload_form(request()).is_ok());
+    }
+
+    #[test]
+    fn test_list_path() {
+        assert_eq!(
+            OpenAIFilesUtils::list_path(&FileListQuery::default()),
+            "files"
+        );
+
+        let query = FileListQuery {
+            purpose: Some("fine-tune".to_string()),
+            limit: Some(10),
+            after: Some("file-abc".to_string()),
+            order: None,
+        };
+        assert_eq!(
+            OpenAIFilesUtils::list_path(&query),
+            "files?purpose=fine-tune&limit=10&after=file-abc"
+        );
+    }
+
+    #[test]
+    fn test_validate_file_id() {
+        assert!(OpenAIFilesUtils::validate_file_id("file-abc123").is_ok());
+        assert!(OpenAIFilesUtils::validate_file_id("assistant_file_1").is_ok());
+        assert!(OpenAIFilesUtils::validate_file_id("").is_err());
+        assert!(OpenAIFilesUtils::validate_file_id("../batches").is_err());
+        assert!(OpenAIFilesUtils::validate_file_id("file?x=1").is_err());
+    }
+}
@@ -3,218 +3,20 @@
 //! Fine-tuning job management following the unified architecture
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
+use crate::core::fine_tuning::types::FineTuningListQuery;
 use crate::core::providers::unified_provider::ProviderError;
 
-/// OpenAI Fine-tuning Job creation request
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIFineTuningRequest {
-    /// The ID of an uploaded file that contains training data
-    pub training_file: String,
-
-    /// The ID of an uploaded file that contains validation data
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_file: Option<String>,
-
-    /// The name of the model to fine-tune
-    pub model: String,
-
-    /// The hyperparameters used for the fine-tuning job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub hyperparameters: Option<FineTuningHyperparameters>,
-
-    /// A string of up to 18 characters for the suffix
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub suffix: Option<String>,
-
-    /// Set of key-value pairs for metadata
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<HashMap<String, String>>,
-
-    /// List of integrations to enable for this fine-tuning job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub integrations: Option<Vec<Integration>>,
-
-    /// Seed for deterministic training
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub seed: Option<i32>,
-}
-
-/// Hyperparameters for fine-tuning
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FineTuningHyperparameters {
-    /// The number of epochs to train the model for
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub n_epochs: Option<u32>,
+pub use crate::core::fine_tuning::types::{
+    EventLevel, EventType, FineTuningError, FineTuningEvent, FineTuningHyperparameters,
+    FineTuningJob, FineTuningJobRequest, FineTuningStatus, Integration, WandBConfig,
+};
 
-    /// Batch size to use for training
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub batch_size: Option<u32>,
-
-    /// Learning rate multiplier to use for training
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub learning_rate_multiplier: Option<f64>,
-}
-
-/// Integration configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Integration {
-    /// The type of integration
-    #[serde(rename = "type")]
-    pub integration_type: String,
-
-    /// Configuration for Weights & Biases integration
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub wandb: Option<WandBConfig>,
-}
-
-/// Weights & Biases configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WandBConfig {
-    /// The name of the project
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project: Option<String>,
-
-    /// Display name for the run
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-
-    /// Entity (team) to use for the run
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub entity: Option<String>,
-
-    /// Tags for the run
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<String>>,
-}
+/// OpenAI Fine-tuning Job creation request
+pub type OpenAIFineTuningRequest = FineTuningJobRequest;
 
 /// OpenAI Fine-tuning Job response
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OpenAIFineTuningJob {
-    /// The object identifier
-    pub id: String,
-
-    /// The object type (always "fine_tuning.job")
-    pub object: String,
-
-    /// The Unix timestamp for when the fine-tuning job was created
-    pub created_at: i64,
-
-    /// The Unix timestamp for when the fine-tuning job was finished
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<i64>,
-
-    /// The base model that is being fine-tuned
-    pub model: String,
-
-    /// The fine-tuned model name (available after completion)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fine_tuned_model: Option<String>,
-
-    /// The organization that owns the fine-tuning job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub organization_id: Option<String>,
-
-    /// The current status of the fine-tuning job
-    pub status: FineTuningStatus,
-
-    /// The hyperparameters used for the fine-tuning job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub hyperparameters: Option<FineTuningHyperparameters>,
-
-    /// The file ID used for training
-    pub training_file: String,
-
-    /// The file ID used for validation
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_file: Option<String>,
-
-    /// The compiled results file ID(s) for the fine-tuning job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_files: Option<Vec<String>>,
-
-    /// The total number of billable tokens processed by this fine-tuning job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub trained_tokens: Option<u64>,
-
-    /// The suffix used to identify the fine-tuned model
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub suffix: Option<String>,
-
-    /// Error information if the job failed
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<FineTuningError>,
-
-    /// Estimated finish time
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_finish: Option<i64>,
-
-    /// List of integrations enabled for this job
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub integrations: Option<Vec<Integration>>,
-
-    /// Seed used for training
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub seed: Option<i32>,
-}
-
-/// Fine-tuning job status
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum FineTuningStatus {
-    ValidatingFiles,
-    Queued,
-    Running,
-    Succeeded,
-    Failed,
-    Cancelled,
-}
-
-/// Fine-tuning error details
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FineTuningError {
-    /// Error code
-    pub code: String,
-
-    /// Error message
-    pub message: String,
-
-    /// Additional error parameters
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub param: Option<String>,
-}
-
-/// Fine-tuning job events
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FineTuningEvent {
-    pub id: String,
-    pub object: String,
-    pub created_at: i64,
-    pub level: EventLevel,
-    pub message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<serde_json::Value>,
-    #[serde(rename = "type")]
-    pub event_type: EventType,
-}
-
-/// Event level
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum EventLevel {
-    Info,
-    Warn,
-    Error,
-}
-
-/// Event type
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum EventType {
-    Message,
-    Metrics,
-}
+pub type OpenAIFineTuningJob = FineTuningJob;
 
 /// Fine-tuning checkpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -333,6 +135,20 @@ impl OpenAIFineTuningUtils {
             });
         }
 
+        Self::validate_parameters(request)
+    }
+
+    /// Validate the model-independent parts of a fine-tuning request
+    ///
+    /// Azure names its base models differently, so it only checks these.
+    pub fn validate_parameters(request: &FineTuningJobRequest) -> Result<(), ProviderError> {
+        if request.training_file.is_empty() {
+            return Err(ProviderError::InvalidRequest {
+                provider: "openai",
+                message: "training_file is required".to_string(),
+            });
+        }
+
         // Check suffix length
         if let Some(suffix) = &request.suffix {
             if suffix.len() > 40 {
@@ -389,6 +205,33 @@ impl OpenAIFineTuningUtils {
         Ok(())
     }
 
+    /// Reject job IDs that would escape the `/fine_tuning/jobs/{id}` path
+    pub fn validate_job_id(job_id: &str) -> Result<(), ProviderError> {
+        if job_id.is_empty()
+            || !job_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ProviderError::invalid_request(
+                "openai",
+                format!("Invalid fine-tuning job ID: {}", job_id),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Path of a `/fine_tuning/jobs` endpoint, including the query string
+    /// when there is one
+    pub fn jobs_path(path: &str, query: &FineTuningListQuery) -> String {
+        let query_string = query.to_query_string();
+        if query_string.is_empty() {
+            format!("fine_tuning/jobs{}", path)
+        } else {
+            format!("fine_tuning/jobs{}?{}", path, query_string)
+        }
+    }
+
     /// Estimate cost for fine-tuning
     pub fn estimate_cost(model: &str, num_tokens: u64) -> Result<f64, ProviderError> {
         let cost_per_1k_tokens = match model {
@@ -464,6 +307,27 @@ mod tests {
         assert!(OpenAIFineTuningUtils::validate_request(&invalid_suffix).is_err());
     }
 
+    #[test]
+    fn test_jobs_path() {
+        let query = FineTuningListQuery::default();
+        assert_eq!(
+            OpenAIFineTuningUtils::jobs_path("", &query),
+            "fine_tuning/jobs"
+        );
+
+        let query = FineTuningListQuery {
+            limit: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            OpenAIFineTuningUtils::jobs_path("/ftjob-abc/events", &query),
+            "fine_tuning/jobs/ftjob-abc/events?limit=20"
+        );
+
+        assert!(OpenAIFineTuningUtils::validate_job_id("ftjob-abc").is_ok());
+        assert!(OpenAIFineTuningUtils::validate_job_id("../files").is_err());
+    }
+
     #[test]
     fn test_estimate_cost() {
         let cost = OpenAIFineTuningUtils::estimate_cost("gpt-3.5-turbo", 10000).unwrap();
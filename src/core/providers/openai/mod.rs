@@ -16,7 +16,10 @@ pub mod capabilities;
 
 // New functionality modules
 pub mod advanced_chat;
+pub mod assistants;
+pub mod audio;
 pub mod completions;
+pub mod files;
 pub mod fine_tuning;
 pub mod image_edit;
 pub mod image_variations;
@@ -115,6 +115,42 @@ pub enum OpenAIModelFamily {
     Realtime,   // Realtime API models
 }
 
+impl OpenAIModelFamily {
+    /// Whether this is an o-series reasoning family
+    pub fn is_o_series(&self) -> bool {
+        matches!(
+            self,
+            Self::O1 | Self::O1Pro | Self::O3 | Self::O3Mini | Self::O4Mini
+        )
+    }
+}
+
+/// Check whether a model ID names an o-series reasoning model (o1, o3, o4)
+///
+/// Matches by prefix so dated snapshots and models missing from the
+/// registry are recognised too.
+pub fn is_o_series_model(model: &str) -> bool {
+    let model = model.strip_prefix("openai/").unwrap_or(model);
+    ["o1", "o3", "o4"].iter().any(|prefix| {
+        model
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+    })
+}
+
+/// Check whether a model is one of the first o1 previews
+///
+/// These predate `reasoning_effort` and reject system messages.
+pub fn is_o1_preview_model(model: &str) -> bool {
+    let model = model.strip_prefix("openai/").unwrap_or(model);
+    model.starts_with("o1-mini") || model.starts_with("o1-preview")
+}
+
+/// Check whether a model accepts `reasoning_effort`
+pub fn supports_reasoning_effort(model: &str) -> bool {
+    is_o_series_model(model) && !is_o1_preview_model(model)
+}
+
 /// Model-specific configuration
 #[derive(Debug, Clone)]
 pub struct OpenAIModelConfig {
@@ -230,7 +266,7 @@ impl OpenAIModelRegistry {
         }
 
         // O-series reasoning models
-        if model_id.starts_with("o1-") {
+        if is_o_series_model(model_id) {
             features.push(OpenAIModelFeature::ReasoningMode);
         }
 
@@ -929,6 +965,26 @@ pub fn get_openai_registry() -> &'static OpenAIModelRegistry {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_o_series_model() {
+        assert!(is_o_series_model("o1"));
+        assert!(is_o_series_model("o1-mini-2024-09-12"));
+        assert!(is_o_series_model("o3-mini"));
+        assert!(is_o_series_model("openai/o4-mini"));
+        assert!(!is_o_series_model("gpt-4o"));
+        assert!(!is_o_series_model("omni-moderation-latest"));
+        assert!(!is_o_series_model("o10"));
+
+        assert!(supports_reasoning_effort("o3-mini"));
+        assert!(supports_reasoning_effort("o1"));
+        assert!(!supports_reasoning_effort("o1-mini"));
+        assert!(!supports_reasoning_effort("o1-preview"));
+        assert!(!supports_reasoning_effort("gpt-4o"));
+
+        assert!(OpenAIModelFamily::O3Mini.is_o_series());
+        assert!(!OpenAIModelFamily::GPT4O.is_o_series());
+    }
+
     #[test]
     fn test_model_registry_creation() {
         let registry = OpenAIModelRegistry::new();
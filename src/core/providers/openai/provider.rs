@@ -3,14 +3,19 @@
 //! Main provider implementation integrating all OpenAI capabilities
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use futures::Stream;
 use std::pin::Pin;
 
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
 use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest, ImageGenerationRequest},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse, ImageGenerationResponse},
+    requests::{AudioTranscriptionRequest, ChatRequest, EmbeddingRequest, ImageGenerationRequest},
+    responses::{
+        AudioTranscriptionResponse, ChatChunk, ChatResponse, EmbeddingResponse,
+        ImageGenerationResponse,
+    },
 };
 
 use super::{
@@ -141,6 +146,30 @@ impl LLMProvider for OpenAIProvider {
         })
     }
 
+    async fn audio_transcription(
+        &self,
+        request: AudioTranscriptionRequest,
+        _context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, Self::Error> {
+        self.client.transcribe_audio(request).await
+    }
+
+    async fn speech(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<SpeechResponse, Self::Error> {
+        self.client.create_speech(request).await
+    }
+
+    async fn speech_stream(
+        &self,
+        request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, Self::Error>> + Send>>, Self::Error> {
+        self.client.create_speech_stream(request).await
+    }
+
     async fn health_check(&self) -> HealthStatus {
         self.client.health_check().await
     }
@@ -204,14 +233,9 @@ impl OpenAIProvider {
     /// Audio transcription using Whisper
     pub async fn transcribe_audio(
         &self,
-        file: Vec<u8>,
-        model: Option<String>,
-        language: Option<String>,
-        response_format: Option<String>,
-    ) -> Result<serde_json::Value, OpenAIError> {
-        self.client
-            .transcribe_audio(file, model, language, response_format)
-            .await
+        request: AudioTranscriptionRequest,
+    ) -> Result<AudioTranscriptionResponse, OpenAIError> {
+        self.client.transcribe_audio(request).await
     }
 
     /// List available models from OpenAI API
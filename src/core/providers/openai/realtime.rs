@@ -353,6 +353,15 @@ pub struct RealtimeResponse {
     pub max_output_tokens: Option<u32>,
 }
 
+/// Upstream WebSocket endpoint of a real-time session
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealtimeConnection {
+    /// `wss://` URL of the session
+    pub url: String,
+    /// Handshake headers, including credentials
+    pub headers: Vec<(String, String)>,
+}
+
 /// Real-time utilities
 pub struct OpenAIRealtimeUtils;
 
@@ -370,6 +379,19 @@ impl OpenAIRealtimeUtils {
         Self::get_supported_models().contains(&model_id)
     }
 
+    /// WebSocket URL of `path` on an HTTP API base
+    pub fn websocket_url(api_base: &str, path: &str) -> String {
+        let base = api_base.trim_end_matches('/');
+        let base = if let Some(host) = base.strip_prefix("https://") {
+            format!("wss://{}", host)
+        } else if let Some(host) = base.strip_prefix("http://") {
+            format!("ws://{}", host)
+        } else {
+            base.to_string()
+        };
+        format!("{}{}", base, path)
+    }
+
     /// Create default session configuration
     pub fn create_session_config(
         model: String,
@@ -558,6 +580,21 @@ mod tests {
         assert!(!OpenAIRealtimeUtils::supports_realtime("gpt-3.5-turbo"));
     }
 
+    #[test]
+    fn test_websocket_url() {
+        assert_eq!(
+            OpenAIRealtimeUtils::websocket_url(
+                "https://api.openai.com/v1/",
+                "/realtime?model=gpt-4o-realtime-preview"
+            ),
+            "wss://api.openai.com/v1/realtime?model=gpt-4o-realtime-preview"
+        );
+        assert_eq!(
+            OpenAIRealtimeUtils::websocket_url("http://localhost:8080", "/realtime"),
+            "ws://localhost:8080/realtime"
+        );
+    }
+
     #[test]
     fn test_create_session_config() {
         let config = OpenAIRealtimeUtils::create_session_config(
@@ -109,7 +109,7 @@ impl OpenAIRequestTransformer {
     /// Transform content part
     fn transform_content_part(part: ContentPart) -> Result<OpenAIContentPart, OpenAIError> {
         match part {
-            ContentPart::Text { text } => Ok(OpenAIContentPart::Text { text }),
+            ContentPart::Text { text, .. } => Ok(OpenAIContentPart::Text { text }),
             ContentPart::ImageUrl { image_url } => Ok(OpenAIContentPart::ImageUrl {
                 image_url: OpenAIImageUrl {
                     url: image_url.url,
@@ -118,8 +118,8 @@ impl OpenAIRequestTransformer {
             }),
             ContentPart::Audio { audio } => Ok(OpenAIContentPart::InputAudio {
                 input_audio: OpenAIInputAudio {
+                    format: audio.format_name(),
                     data: audio.data,
-                    format: audio.format.unwrap_or("mp3".to_string()),
                 },
             }),
             ContentPart::Image {
@@ -243,6 +243,7 @@ impl OpenAIResponseTransformer {
             choices,
             usage: response.usage.map(Self::transform_usage),
             system_fingerprint: response.system_fingerprint,
+            provider_specific_fields: None,
         })
     }
 
@@ -262,6 +263,7 @@ impl OpenAIResponseTransformer {
             choices,
             usage: chunk.usage.map(Self::transform_usage),
             system_fingerprint: chunk.system_fingerprint,
+            provider_specific_fields: None,
         })
     }
 
@@ -364,6 +366,7 @@ impl OpenAIResponseTransformer {
             function_call: message
                 .function_call
                 .map(Self::transform_function_call_from_response),
+            cache_control: None,
         })
     }
 
@@ -390,7 +393,10 @@ impl OpenAIResponseTransformer {
         part: OpenAIContentPart,
     ) -> Result<ContentPart, OpenAIError> {
         match part {
-            OpenAIContentPart::Text { text } => Ok(ContentPart::Text { text }),
+            OpenAIContentPart::Text { text } => Ok(ContentPart::Text {
+                text,
+                cache_control: None,
+            }),
             OpenAIContentPart::ImageUrl { image_url } => Ok(ContentPart::ImageUrl {
                 image_url: ImageUrl {
                     url: image_url.url,
@@ -445,6 +451,9 @@ impl OpenAIResponseTransformer {
                     audio_tokens: details.audio_tokens,
                 }
             }),
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         }
     }
 
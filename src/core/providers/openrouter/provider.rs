@@ -321,6 +321,7 @@ impl OpenRouterProvider {
             choices: response_choices,
             usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -0,0 +1,199 @@
+//! Perplexity Client
+//!
+//! How the Perplexity API departs from OpenAI's chat completions. Search
+//! options such as `search_domain_filter`, `search_recency_filter` or
+//! `web_search_options` pass through `extra_params`.
+
+use crate::core::providers::base::OpenAICompatibleClient;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::ProviderCapability;
+
+use super::PerplexityConfig;
+
+/// Search metadata returned alongside Perplexity completions
+pub const SEARCH_RESPONSE_FIELDS: &[&str] =
+    &["citations", "search_results", "images", "related_questions"];
+
+/// Perplexity API client logic
+#[derive(Debug, Clone)]
+pub struct PerplexityClient;
+
+impl OpenAICompatibleClient for PerplexityClient {
+    type Config = PerplexityConfig;
+
+    const NAME: &'static str = "perplexity";
+
+    // Sonar models have no function calling
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "frequency_penalty",
+        "presence_penalty",
+        "stream",
+        "response_format",
+        "web_search_options",
+    ];
+
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &[
+        "tools",
+        "tool_choice",
+        "parallel_tool_calls",
+        "functions",
+        "function_call",
+        "thinking",
+    ];
+
+    const PROVIDER_FIELDS: &'static [&'static str] = SEARCH_RESPONSE_FIELDS;
+
+    fn new(_config: &PerplexityConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::base::openai_compatible::create_stream;
+    use crate::core::providers::perplexity::PerplexityProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{ChatMessage, ChatRequest, MessageContent, MessageRole};
+    use bytes::Bytes;
+    use futures::{StreamExt, stream};
+    use serde_json::json;
+
+    fn test_provider() -> PerplexityProvider {
+        let mut config = PerplexityConfig::new("perplexity");
+        config.base.api_key = Some("test-key".to_string());
+        PerplexityProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("perplexity/sonar-pro");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Latest Rust release?".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(512);
+        request
+            .extra_params
+            .insert("search_recency_filter".to_string(), json!("week"));
+
+        let body = PerplexityClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "sonar-pro");
+        assert_eq!(body["max_tokens"], 512);
+        assert_eq!(body["search_recency_filter"], "week");
+        assert!(body.get("tools").is_none());
+    }
+
+    #[test]
+    fn test_transform_response_surfaces_citations() {
+        let raw = json!({
+            "id": "3c90c3cc",
+            "object": "chat.completion",
+            "created": 1724369245,
+            "model": "sonar",
+            "choices": [{
+                "index": 0,
+                "finish_reason": "stop",
+                "message": {"role": "assistant", "content": "Rust 1.90 [1]"}
+            }],
+            "usage": {"prompt_tokens": 8, "completion_tokens": 5, "total_tokens": 13},
+            "citations": ["https://blog.rust-lang.org/"],
+            "search_results": [{"title": "Rust Blog", "url": "https://blog.rust-lang.org/"}]
+        });
+
+        let response =
+            PerplexityClient::transform_chat_response(raw.to_string().as_bytes()).unwrap();
+        assert_eq!(response.first_content(), Some("Rust 1.90 [1]"));
+
+        let fields = response.provider_specific_fields.unwrap();
+        assert_eq!(fields["citations"][0], "https://blog.rust-lang.org/");
+        assert_eq!(fields["search_results"][0]["title"], "Rust Blog");
+        assert!(!fields.contains_key("images"));
+    }
+
+    #[test]
+    fn test_transform_response_without_citations() {
+        let raw = json!({
+            "id": "3c90c3cc",
+            "object": "chat.completion",
+            "created": 1724369245,
+            "model": "sonar",
+            "choices": []
+        });
+
+        let response =
+            PerplexityClient::transform_chat_response(raw.to_string().as_bytes()).unwrap();
+        assert!(response.provider_specific_fields.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_keeps_citations() {
+        let test_data = vec![
+            Ok(Bytes::from(
+                "data: {\"id\":\"3c90c3cc\",\"object\":\"chat.completion.chunk\",\"created\":1718000000,\"model\":\"sonar\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"},\"finish_reason\":null}],\"citations\":[\"https://example.com\"]}\n\n",
+            )),
+            Ok(Bytes::from("data: [DONE]\n\n")),
+        ];
+
+        let mut perplexity_stream = create_stream::<PerplexityClient>(stream::iter(test_data));
+
+        let chunk = perplexity_stream.next().await.unwrap().unwrap();
+        assert_eq!(chunk.choices[0].delta.content.as_deref(), Some("Hi"));
+        assert_eq!(
+            chunk.provider_specific_fields.unwrap()["citations"],
+            json!(["https://example.com"])
+        );
+        assert!(perplexity_stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = PerplexityConfig::new("perplexity");
+        config.base.api_key = None;
+        assert!(PerplexityProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "perplexity");
+        assert!(
+            !provider
+                .capabilities()
+                .contains(&ProviderCapability::ToolCalling)
+        );
+        assert!(provider.supports_model("perplexity/sonar-pro"));
+        assert!(!provider.supports_model("gpt-4"));
+        assert!(provider.models().iter().any(|m| m.id == "sonar"));
+        assert!(provider.models().iter().all(|m| m.provider == "perplexity"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://api.perplexity.ai/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("perplexity/sonar-pro", 1_000_000, 1_000_000)
+            .await
+            .unwrap();
+        assert!((cost - 18.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,69 @@
+//! Perplexity Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(PerplexityConfig {});
+
+impl PerplexityConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `PERPLEXITY_*`, falling back to `PERPLEXITYAI_API_KEY`.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("perplexity");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("PERPLEXITYAI_API_KEY").ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for PerplexityConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("perplexity")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perplexity_config_default_api_base() {
+        let config = PerplexityConfig::new("perplexity");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.perplexity.ai".to_string())
+        );
+    }
+
+    #[test]
+    fn test_perplexity_validate_missing_api_key() {
+        let mut config = PerplexityConfig::new("perplexity");
+        config.base.api_key = None;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_perplexity_validate_success() {
+        let mut config = PerplexityConfig::new("perplexity");
+        config.base.api_key = Some("pplx-test".to_string());
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,16 @@
+//! Perplexity Provider
+//!
+//! OpenAI-compatible chat for the search-grounded Sonar models on
+//! api.perplexity.ai. Citations and search results are returned in the
+//! `provider_specific_fields` of responses and stream chunks.
+
+pub mod client;
+pub mod config;
+
+pub use client::PerplexityClient;
+pub use config::PerplexityConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// Perplexity provider
+pub type PerplexityProvider = OpenAICompatibleProvider<PerplexityClient>;
@@ -0,0 +1,93 @@
+//! Replicate Configuration
+
+use std::time::Duration;
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(ReplicateConfig {});
+
+impl ReplicateConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `REPLICATE_*`, falling back to the `REPLICATE_API_TOKEN` name
+    /// used by Replicate's own clients.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("replicate");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("REPLICATE_API_TOKEN").ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for ReplicateConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("replicate")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+/// How long to wait for a prediction to finish
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollingConfig {
+    /// Seconds the create call blocks server-side (`Prefer: wait=N`, max 60)
+    pub sync_wait: u64,
+    /// Delay between status polls once the create call returns
+    pub poll_interval: Duration,
+    /// Give up (and cancel the prediction) after this long
+    pub max_wait: Duration,
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            sync_wait: 60,
+            poll_interval: Duration::from_millis(500),
+            max_wait: Duration::from_secs(600),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replicate_config_default_api_base() {
+        let config = ReplicateConfig::new("replicate");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.replicate.com/v1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replicate_validate_missing_api_key() {
+        let mut config = ReplicateConfig::new("replicate");
+        config.base.api_key = None;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_polling_defaults() {
+        let polling = PollingConfig::default();
+        assert!(polling.sync_wait <= 60);
+        assert!(polling.poll_interval < polling.max_wait);
+    }
+}
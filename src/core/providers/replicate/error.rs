@@ -0,0 +1,75 @@
+//! Replicate Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Replicate API
+#[derive(Debug)]
+pub struct ReplicateErrorMapper;
+
+impl ErrorMapper<ProviderError> for ReplicateErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let message = extract_error_message(response_body);
+
+        match status_code {
+            400 | 422 => ProviderError::invalid_request("replicate", message),
+            401 | 403 => ProviderError::authentication("replicate", message),
+            404 => ProviderError::model_not_found("replicate", message),
+            // Out of prepaid credit
+            402 => ProviderError::quota_exceeded("replicate", message),
+            429 => ProviderError::rate_limit("replicate", None),
+            _ => ProviderError::api_error("replicate", status_code, message),
+        }
+    }
+}
+
+/// Replicate reports errors as problem details: `{"title": "...", "detail": "..."}`
+fn extract_error_message(response_body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_body)
+        .ok()
+        .and_then(|v| {
+            v.get("detail")
+                .or_else(|| v.get("title"))
+                .and_then(|m| m.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| response_body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replicate_error_mapper_422() {
+        let err = ReplicateErrorMapper.map_http_error(
+            422,
+            r#"{"title":"Input validation failed","detail":"- input: prompt is required","status":422}"#,
+        );
+        match err {
+            ProviderError::InvalidRequest { message, .. } => {
+                assert_eq!(message, "- input: prompt is required")
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_replicate_error_mapper_401() {
+        let err =
+            ReplicateErrorMapper.map_http_error(401, r#"{"title":"Unauthenticated","status":401}"#);
+        assert!(matches!(err, ProviderError::Authentication { .. }));
+    }
+
+    #[test]
+    fn test_replicate_error_mapper_402() {
+        let err = ReplicateErrorMapper.map_http_error(402, "");
+        assert!(matches!(err, ProviderError::QuotaExceeded { .. }));
+    }
+
+    #[test]
+    fn test_replicate_error_mapper_429() {
+        let err = ReplicateErrorMapper.map_http_error(429, "");
+        assert!(matches!(err, ProviderError::RateLimit { .. }));
+    }
+}
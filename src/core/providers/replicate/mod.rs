@@ -0,0 +1,15 @@
+//! Replicate Provider
+//!
+//! Runs language and text-to-image models on replicate.com. Every call is an
+//! asynchronous prediction that is waited on until it finishes.
+
+pub mod config;
+pub mod error;
+pub mod prediction;
+pub mod provider;
+pub mod transformation;
+
+pub use config::{PollingConfig, ReplicateConfig};
+pub use error::ReplicateErrorMapper;
+pub use prediction::{Prediction, PredictionPoller, PredictionStatus};
+pub use provider::ReplicateProvider;
@@ -0,0 +1,238 @@
+//! Replicate Predictions
+//!
+//! Replicate runs every model as an asynchronous prediction: the create call
+//! returns immediately (or after at most `Prefer: wait` seconds) and the
+//! result has to be fetched until the prediction reaches a terminal state.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::future::Future;
+use std::time::Instant;
+
+use super::config::PollingConfig;
+use crate::core::providers::unified_provider::ProviderError;
+
+/// Lifecycle state of a prediction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PredictionStatus {
+    Starting,
+    Processing,
+    Succeeded,
+    Failed,
+    Canceled,
+    /// Deadline-based cancellation
+    Aborted,
+}
+
+impl PredictionStatus {
+    /// Whether the prediction will not change any more
+    pub fn is_terminal(self) -> bool {
+        !matches!(self, Self::Starting | Self::Processing)
+    }
+}
+
+/// Links returned with every prediction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PredictionUrls {
+    #[serde(default)]
+    pub get: Option<String>,
+    #[serde(default)]
+    pub cancel: Option<String>,
+    #[serde(default)]
+    pub stream: Option<String>,
+}
+
+/// Metrics reported once a prediction has run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PredictionMetrics {
+    #[serde(default)]
+    pub predict_time: Option<f64>,
+    #[serde(default)]
+    pub input_token_count: Option<u32>,
+    #[serde(default)]
+    pub output_token_count: Option<u32>,
+}
+
+/// A Replicate prediction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Prediction {
+    pub id: String,
+    #[serde(default)]
+    pub model: Option<String>,
+    pub status: PredictionStatus,
+    #[serde(default)]
+    pub output: Option<Value>,
+    #[serde(default)]
+    pub error: Option<Value>,
+    #[serde(default)]
+    pub urls: PredictionUrls,
+    #[serde(default)]
+    pub metrics: Option<PredictionMetrics>,
+}
+
+impl Prediction {
+    /// Turn a finished prediction into its output, or an error if it did not succeed
+    pub fn into_output(self) -> Result<(Value, Option<PredictionMetrics>), ProviderError> {
+        match self.status {
+            PredictionStatus::Succeeded => Ok((self.output.unwrap_or(Value::Null), self.metrics)),
+            PredictionStatus::Failed => {
+                let message = match self.error {
+                    Some(Value::String(message)) => message,
+                    Some(other) => other.to_string(),
+                    None => "prediction failed".to_string(),
+                };
+                Err(ProviderError::api_error("replicate", 500, message))
+            }
+            PredictionStatus::Canceled | PredictionStatus::Aborted => {
+                Err(ProviderError::cancelled(
+                    "replicate",
+                    "prediction",
+                    Some(format!("prediction {} was canceled", self.id)),
+                ))
+            }
+            PredictionStatus::Starting | PredictionStatus::Processing => {
+                Err(ProviderError::api_error(
+                    "replicate",
+                    500,
+                    format!("prediction {} has not finished", self.id),
+                ))
+            }
+        }
+    }
+}
+
+/// Drives a prediction to a terminal state
+#[derive(Debug, Clone, Default)]
+pub struct PredictionPoller {
+    config: PollingConfig,
+}
+
+impl PredictionPoller {
+    pub fn new(config: PollingConfig) -> Self {
+        Self { config }
+    }
+
+    /// Fetch the prediction until it finishes or `max_wait` elapses
+    ///
+    /// `fetch` is given the prediction's `urls.get` link. On timeout the
+    /// prediction id is returned in the error so the caller can cancel it.
+    pub async fn wait<F, Fut>(
+        &self,
+        mut prediction: Prediction,
+        mut fetch: F,
+    ) -> Result<Prediction, ProviderError>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<Prediction, ProviderError>>,
+    {
+        let started = Instant::now();
+
+        while !prediction.status.is_terminal() {
+            if started.elapsed() >= self.config.max_wait {
+                return Err(ProviderError::timeout(
+                    "replicate",
+                    format!(
+                        "prediction {} did not finish within {}s",
+                        prediction.id,
+                        self.config.max_wait.as_secs()
+                    ),
+                ));
+            }
+
+            let url = prediction.urls.get.clone().ok_or_else(|| {
+                ProviderError::response_parsing("replicate", "prediction has no 'urls.get' link")
+            })?;
+
+            tokio::time::sleep(self.config.poll_interval).await;
+            prediction = fetch(url).await?;
+        }
+
+        Ok(prediction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    fn prediction(status: &str) -> Prediction {
+        serde_json::from_value(json!({
+            "id": "rrr4z55ocneqzikepnug6xezpe",
+            "model": "meta/meta-llama-3-8b-instruct",
+            "status": status,
+            "output": if status == "succeeded" { json!(["Hel", "lo"]) } else { Value::Null },
+            "urls": {
+                "get": "https://api.replicate.com/v1/predictions/rrr4z55ocneqzikepnug6xezpe",
+                "cancel": "https://api.replicate.com/v1/predictions/rrr4z55ocneqzikepnug6xezpe/cancel"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn fast_poller(max_wait: Duration) -> PredictionPoller {
+        PredictionPoller::new(PollingConfig {
+            sync_wait: 0,
+            poll_interval: Duration::from_millis(1),
+            max_wait,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wait_polls_until_terminal() {
+        let calls = AtomicU32::new(0);
+        let result = fast_poller(Duration::from_secs(5))
+            .wait(prediction("starting"), |_| {
+                let n = calls.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(prediction(if n == 0 { "processing" } else { "succeeded" })) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, PredictionStatus::Succeeded);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_returns_immediately_when_finished() {
+        let result = fast_poller(Duration::from_secs(5))
+            .wait(prediction("succeeded"), |_| async {
+                panic!("should not poll a finished prediction")
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.output, Some(json!(["Hel", "lo"])));
+    }
+
+    #[tokio::test]
+    async fn test_wait_times_out() {
+        let err = fast_poller(Duration::ZERO)
+            .wait(prediction("processing"), |_| async {
+                Ok(prediction("processing"))
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ProviderError::Timeout { .. }));
+    }
+
+    #[test]
+    fn test_into_output() {
+        let (output, _) = prediction("succeeded").into_output().unwrap();
+        assert_eq!(output, json!(["Hel", "lo"]));
+
+        let mut failed = prediction("failed");
+        failed.error = Some(json!("CUDA out of memory"));
+        match failed.into_output().unwrap_err() {
+            ProviderError::ApiError { message, .. } => assert_eq!(message, "CUDA out of memory"),
+            other => panic!("Expected ApiError, got {:?}", other),
+        }
+
+        assert!(matches!(
+            prediction("canceled").into_output().unwrap_err(),
+            ProviderError::Cancelled { .. }
+        ));
+    }
+}
@@ -0,0 +1,417 @@
+//! Replicate Provider Implementation
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::providers::base::{
+    GlobalPoolManager, HeaderPair, HttpMethod, get_pricing_db, header, header_owned,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::{ChatRequest, ImageGenerationRequest},
+    responses::{ChatResponse, ImageGenerationResponse},
+};
+
+use super::config::PollingConfig;
+use super::prediction::{Prediction, PredictionPoller};
+use super::transformation::{
+    PredictionTarget, transform_chat_input, transform_chat_output, transform_image_input,
+    transform_image_output,
+};
+use super::{ReplicateConfig, ReplicateErrorMapper};
+
+/// OpenAI parameters mapped onto the inputs of Replicate's language models
+const REPLICATE_SUPPORTED_PARAMS: &[&str] = &[
+    "temperature",
+    "max_tokens",
+    "max_completion_tokens",
+    "top_p",
+    "stop",
+    "seed",
+    "presence_penalty",
+    "frequency_penalty",
+];
+
+#[derive(Debug, Clone)]
+pub struct ReplicateProvider {
+    config: ReplicateConfig,
+    polling: PollingConfig,
+    pool_manager: Arc<GlobalPoolManager>,
+    supported_models: Vec<ModelInfo>,
+}
+
+impl ReplicateProvider {
+    /// Generate headers for Replicate API requests
+    fn get_request_headers(&self) -> Vec<HeaderPair> {
+        let mut headers = Vec::with_capacity(2);
+
+        if let Some(api_key) = &self.config.base.api_key {
+            headers.push(header("Authorization", format!("Bearer {}", api_key)));
+        }
+
+        for (key, value) in &self.config.base.headers {
+            headers.push(header_owned(key.clone(), value.clone()));
+        }
+
+        headers
+    }
+
+    pub fn new(config: ReplicateConfig) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration("replicate", e))?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration("replicate", e.to_string()))?,
+        );
+
+        Ok(Self {
+            config,
+            polling: PollingConfig::default(),
+            pool_manager,
+            supported_models: Self::build_models(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, ProviderError> {
+        Self::new(ReplicateConfig::from_env())
+    }
+
+    /// Override how long predictions are waited on
+    pub fn with_polling_config(mut self, polling: PollingConfig) -> Self {
+        self.polling = polling;
+        self
+    }
+
+    /// Models with known pricing in the unified pricing database
+    fn build_models() -> Vec<ModelInfo> {
+        let pricing_db = get_pricing_db();
+        let mut model_ids = pricing_db.get_provider_models("replicate");
+        model_ids.sort();
+
+        model_ids
+            .iter()
+            .filter_map(|id| {
+                let mut info = pricing_db.to_model_info(id, "replicate")?;
+                info.supports_streaming = false;
+                info.capabilities = match pricing_db.get_model_info(id).and_then(|p| p.mode.clone())
+                {
+                    Some(mode) if mode == "image_generation" => {
+                        vec![ProviderCapability::ImageGeneration]
+                    }
+                    _ => vec![ProviderCapability::ChatCompletion],
+                };
+                Some(info)
+            })
+            .collect()
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}{}",
+            self.config
+                .base
+                .get_effective_api_base("replicate")
+                .trim_end_matches('/'),
+            path
+        )
+    }
+
+    /// Send a request and decode the prediction it returns
+    async fn send(
+        &self,
+        url: &str,
+        method: HttpMethod,
+        headers: Vec<HeaderPair>,
+        body: Option<Value>,
+    ) -> Result<Prediction, ProviderError> {
+        let response = self
+            .pool_manager
+            .execute_request(url, method, headers, body)
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(ReplicateErrorMapper.map_http_error(status.as_u16(), &error_text));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ProviderError::response_parsing("replicate", e.to_string()))
+    }
+
+    /// Create a prediction and wait for it to finish
+    ///
+    /// The create call blocks server-side for up to `sync_wait` seconds, which
+    /// is enough for most language models; anything slower is polled until
+    /// `max_wait`, after which the prediction is canceled.
+    async fn run_prediction(
+        &self,
+        target: &PredictionTarget<'_>,
+        input: Value,
+    ) -> Result<Prediction, ProviderError> {
+        let mut headers = self.get_request_headers();
+        if self.polling.sync_wait > 0 {
+            headers.push(header("Prefer", format!("wait={}", self.polling.sync_wait)));
+        }
+
+        let prediction = self
+            .send(
+                &self.endpoint(&target.create_path()),
+                HttpMethod::POST,
+                headers,
+                Some(target.body(input)),
+            )
+            .await?;
+        let cancel_url = prediction.urls.cancel.clone();
+
+        let result = PredictionPoller::new(self.polling)
+            .wait(prediction, |url| async move {
+                self.send(&url, HttpMethod::GET, self.get_request_headers(), None)
+                    .await
+            })
+            .await;
+
+        if let (Err(ProviderError::Timeout { .. }), Some(cancel_url)) = (&result, cancel_url) {
+            // Best effort: stop paying for a prediction nobody is waiting on
+            if let Err(e) = self
+                .send(
+                    &cancel_url,
+                    HttpMethod::POST,
+                    self.get_request_headers(),
+                    None,
+                )
+                .await
+            {
+                tracing::warn!("Failed to cancel Replicate prediction: {}", e);
+            }
+        }
+
+        result
+    }
+}
+
+#[async_trait]
+impl LLMProvider for ReplicateProvider {
+    type Config = ReplicateConfig;
+    type Error = ProviderError;
+    type ErrorMapper = ReplicateErrorMapper;
+
+    fn name(&self) -> &'static str {
+        "replicate"
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        &[
+            ProviderCapability::ChatCompletion,
+            ProviderCapability::ImageGeneration,
+        ]
+    }
+
+    fn models(&self) -> &[ModelInfo] {
+        &self.supported_models
+    }
+
+    /// Any public model or version can be run, so only the route prefix is checked
+    fn supports_model(&self, model: &str) -> bool {
+        model.starts_with("replicate/") || self.supported_models.iter().any(|m| m.id == model)
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        REPLICATE_SUPPORTED_PARAMS
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        let mut mapped = HashMap::new();
+
+        for (key, value) in params {
+            match key.as_str() {
+                "max_completion_tokens" => {
+                    mapped.insert("max_tokens".to_string(), value);
+                }
+                "stop" => {
+                    mapped.insert("stop_sequences".to_string(), value);
+                }
+                _ if REPLICATE_SUPPORTED_PARAMS.contains(&key.as_str()) => {
+                    mapped.insert(key, value);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(mapped)
+    }
+
+    async fn transform_request(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        let target = PredictionTarget::parse(&request.model);
+        Ok(target.body(transform_chat_input(&request)))
+    }
+
+    async fn transform_response(
+        &self,
+        raw_response: &[u8],
+        model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        let prediction: Prediction = serde_json::from_slice(raw_response)
+            .map_err(|e| ProviderError::response_parsing("replicate", e.to_string()))?;
+        let id = prediction.id.clone();
+        let (output, metrics) = prediction.into_output()?;
+
+        Ok(transform_chat_output(&output, metrics.as_ref(), &id, model))
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        ReplicateErrorMapper
+    }
+
+    async fn chat_completion(
+        &self,
+        request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        let target = PredictionTarget::parse(&request.model);
+        let prediction = self
+            .run_prediction(&target, transform_chat_input(&request))
+            .await?;
+
+        let id = prediction.id.clone();
+        let (output, metrics) = prediction.into_output()?;
+        Ok(transform_chat_output(
+            &output,
+            metrics.as_ref(),
+            &id,
+            &request.model,
+        ))
+    }
+
+    async fn image_generation(
+        &self,
+        request: ImageGenerationRequest,
+        _context: RequestContext,
+    ) -> Result<ImageGenerationResponse, Self::Error> {
+        let model = request.model.as_deref().ok_or_else(|| {
+            ProviderError::invalid_request("replicate", "An image model is required")
+        })?;
+        let target = PredictionTarget::parse(model);
+
+        let prediction = self
+            .run_prediction(&target, transform_image_input(&request)?)
+            .await?;
+        let (output, _) = prediction.into_output()?;
+
+        Ok(transform_image_output(&output))
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        if self.config.base.api_key.is_some() {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+
+    async fn calculate_cost(
+        &self,
+        model: &str,
+        input_tokens: u32,
+        output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        let usage = crate::core::providers::base::pricing::Usage {
+            prompt_tokens: input_tokens,
+            completion_tokens: output_tokens,
+            total_tokens: input_tokens + output_tokens,
+            reasoning_tokens: None,
+        };
+
+        let model = model.strip_prefix("replicate/").unwrap_or(model);
+        Ok(get_pricing_db().calculate(model, &usage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn test_provider() -> ReplicateProvider {
+        let mut config = ReplicateConfig::new("replicate");
+        config.base.api_key = Some("r8_test".to_string());
+        ReplicateProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = ReplicateConfig::new("replicate");
+        config.base.api_key = None;
+        assert!(ReplicateProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "replicate");
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::ImageGeneration)
+        );
+        assert!(!provider.supports_streaming());
+    }
+
+    #[test]
+    fn test_supports_model() {
+        let provider = test_provider();
+        assert!(provider.supports_model("replicate/meta/meta-llama-3-8b-instruct"));
+        assert!(provider.supports_model("meta/meta-llama-3-70b-instruct"));
+        assert!(!provider.supports_model("gpt-4"));
+    }
+
+    #[test]
+    fn test_with_polling_config() {
+        let polling = PollingConfig {
+            sync_wait: 0,
+            poll_interval: Duration::from_secs(1),
+            max_wait: Duration::from_secs(30),
+        };
+        let provider = test_provider().with_polling_config(polling);
+        assert_eq!(provider.polling, polling);
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        let target = PredictionTarget::parse("meta/meta-llama-3-8b-instruct");
+        assert_eq!(
+            provider.endpoint(&target.create_path()),
+            "https://api.replicate.com/v1/models/meta/meta-llama-3-8b-instruct/predictions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transform_response_failed_prediction() {
+        let provider = test_provider();
+        let raw = br#"{"id":"p1","status":"failed","error":"out of memory","urls":{}}"#;
+        let err = provider
+            .transform_response(raw, "meta/meta-llama-3-8b-instruct", "req")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ProviderError::ApiError { .. }));
+    }
+}
@@ -0,0 +1,350 @@
+//! Replicate Request/Response Transformation
+//!
+//! Replicate models take a free-form `input` object defined by each model's
+//! schema. Chat requests are mapped onto the inputs used by the official
+//! language models, and image requests onto those of the text-to-image ones.
+
+use serde_json::{Map, Value, json};
+
+use super::prediction::PredictionMetrics;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::{ChatMessage, ChatRequest, ImageGenerationRequest, MessageRole},
+    responses::{
+        ChatChoice, ChatResponse, FinishReason, ImageData, ImageGenerationResponse, Usage,
+    },
+};
+
+/// Where a prediction for a model is created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredictionTarget<'a> {
+    /// Official model, run at its latest version: `owner/name`
+    Model(&'a str),
+    /// Pinned version of a community model: `owner/name:version` or a bare version id
+    Version(&'a str),
+}
+
+impl<'a> PredictionTarget<'a> {
+    /// Resolve a model name, ignoring a leading `replicate/`
+    pub fn parse(model: &'a str) -> Self {
+        let model = model.strip_prefix("replicate/").unwrap_or(model);
+
+        match model.split_once(':') {
+            Some((_, version)) => Self::Version(version),
+            None if !model.contains('/') => Self::Version(model),
+            None => Self::Model(model),
+        }
+    }
+
+    /// Path of the create endpoint, relative to the API base
+    pub fn create_path(&self) -> String {
+        match self {
+            Self::Model(model) => format!("/models/{}/predictions", model),
+            Self::Version(_) => "/predictions".to_string(),
+        }
+    }
+
+    /// Wrap model input in a create request body
+    pub fn body(&self, input: Value) -> Value {
+        match self {
+            Self::Model(_) => json!({ "input": input }),
+            Self::Version(version) => json!({ "version": version, "input": input }),
+        }
+    }
+}
+
+/// Flatten the conversation into a prompt, moving system messages to `system_prompt`
+fn build_prompt(messages: &[ChatMessage]) -> (String, Option<String>) {
+    let text = |m: &ChatMessage| {
+        m.content
+            .as_ref()
+            .map(|c| c.to_string())
+            .unwrap_or_default()
+    };
+
+    let system: Vec<String> = messages
+        .iter()
+        .filter(|m| m.role == MessageRole::System)
+        .map(text)
+        .collect();
+    let turns: Vec<&ChatMessage> = messages
+        .iter()
+        .filter(|m| m.role != MessageRole::System)
+        .collect();
+
+    // A single user turn is passed through untouched so the model's own
+    // prompt template applies
+    let prompt = match turns.as_slice() {
+        [only] if only.role == MessageRole::User => text(only),
+        _ => {
+            let mut prompt = String::new();
+            for message in &turns {
+                let role = match message.role {
+                    MessageRole::Assistant => "Assistant",
+                    _ => "User",
+                };
+                prompt.push_str(&format!("{}: {}\n", role, text(message)));
+            }
+            prompt.push_str("Assistant:");
+            prompt
+        }
+    };
+
+    let system_prompt = (!system.is_empty()).then(|| system.join("\n"));
+    (prompt, system_prompt)
+}
+
+/// Build prediction input for a language model
+pub fn transform_chat_input(request: &ChatRequest) -> Value {
+    let (prompt, system_prompt) = build_prompt(&request.messages);
+
+    let mut input = Map::new();
+    input.insert("prompt".to_string(), json!(prompt));
+    if let Some(system_prompt) = system_prompt {
+        input.insert("system_prompt".to_string(), json!(system_prompt));
+    }
+    if let Some(max_tokens) = request.max_completion_tokens.or(request.max_tokens) {
+        input.insert("max_tokens".to_string(), json!(max_tokens));
+    }
+    if let Some(temperature) = request.temperature {
+        input.insert("temperature".to_string(), json!(temperature));
+    }
+    if let Some(top_p) = request.top_p {
+        input.insert("top_p".to_string(), json!(top_p));
+    }
+    if let Some(stop) = &request.stop {
+        input.insert("stop_sequences".to_string(), json!(stop.join(",")));
+    }
+    if let Some(seed) = request.seed {
+        input.insert("seed".to_string(), json!(seed));
+    }
+    if let Some(penalty) = request.presence_penalty {
+        input.insert("presence_penalty".to_string(), json!(penalty));
+    }
+    if let Some(penalty) = request.frequency_penalty {
+        input.insert("frequency_penalty".to_string(), json!(penalty));
+    }
+    // Model-specific inputs such as `top_k` or `prompt_template`
+    for (key, value) in &request.extra_params {
+        input.insert(key.clone(), value.clone());
+    }
+
+    Value::Object(input)
+}
+
+/// Language models stream tokens into an array; a few return a single string
+fn output_text(output: &Value) -> String {
+    match output {
+        Value::String(text) => text.clone(),
+        Value::Array(parts) => parts.iter().filter_map(|p| p.as_str()).collect(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Build a chat response from a finished prediction's output
+pub fn transform_chat_output(
+    output: &Value,
+    metrics: Option<&PredictionMetrics>,
+    prediction_id: &str,
+    model: &str,
+) -> ChatResponse {
+    let usage = metrics.and_then(|m| match (m.input_token_count, m.output_token_count) {
+        (None, None) => None,
+        (input, output) => Some(Usage::new(input.unwrap_or(0), output.unwrap_or(0))),
+    });
+
+    ChatResponse {
+        id: prediction_id.to_string(),
+        model: model.to_string(),
+        choices: vec![ChatChoice {
+            index: 0,
+            message: ChatMessage {
+                role: MessageRole::Assistant,
+                content: Some(output_text(output).into()),
+                ..Default::default()
+            },
+            finish_reason: Some(FinishReason::Stop),
+            logprobs: None,
+        }],
+        usage,
+        ..Default::default()
+    }
+}
+
+/// Build prediction input for a text-to-image model
+///
+/// `size` (`WIDTHxHEIGHT`) is forwarded as separate `width`/`height` inputs.
+pub fn transform_image_input(request: &ImageGenerationRequest) -> Result<Value, ProviderError> {
+    let mut input = Map::new();
+    input.insert("prompt".to_string(), json!(request.prompt));
+
+    if let Some(n) = request.n {
+        input.insert("num_outputs".to_string(), json!(n));
+    }
+    if let Some(size) = &request.size {
+        let (width, height) = size
+            .split_once('x')
+            .and_then(|(w, h)| Some((w.parse::<u32>().ok()?, h.parse::<u32>().ok()?)))
+            .ok_or_else(|| {
+                ProviderError::invalid_request(
+                    "replicate",
+                    format!("Invalid image size '{}', expected WIDTHxHEIGHT", size),
+                )
+            })?;
+        input.insert("width".to_string(), json!(width));
+        input.insert("height".to_string(), json!(height));
+    }
+
+    Ok(Value::Object(input))
+}
+
+/// Image models return a single URL or a list of URLs
+pub fn transform_image_output(output: &Value) -> ImageGenerationResponse {
+    let urls: Vec<&str> = match output {
+        Value::String(url) => vec![url.as_str()],
+        Value::Array(items) => items.iter().filter_map(|u| u.as_str()).collect(),
+        _ => Vec::new(),
+    };
+
+    ImageGenerationResponse {
+        created: chrono::Utc::now().timestamp() as u64,
+        data: urls
+            .into_iter()
+            .map(|url| ImageData {
+                url: Some(url.to_string()),
+                b64_json: None,
+                revised_prompt: None,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::MessageContent;
+    use std::collections::HashMap;
+
+    fn message(role: MessageRole, text: &str) -> ChatMessage {
+        ChatMessage {
+            role,
+            content: Some(MessageContent::Text(text.to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_prediction_target() {
+        let target = PredictionTarget::parse("replicate/meta/meta-llama-3-8b-instruct");
+        assert_eq!(
+            target,
+            PredictionTarget::Model("meta/meta-llama-3-8b-instruct")
+        );
+        assert_eq!(
+            target.create_path(),
+            "/models/meta/meta-llama-3-8b-instruct/predictions"
+        );
+        assert!(target.body(json!({})).get("version").is_none());
+
+        let target = PredictionTarget::parse("stability-ai/sdxl:39ed52f2a78e");
+        assert_eq!(target, PredictionTarget::Version("39ed52f2a78e"));
+        assert_eq!(target.create_path(), "/predictions");
+        assert_eq!(target.body(json!({}))["version"], "39ed52f2a78e");
+    }
+
+    #[test]
+    fn test_chat_input() {
+        let mut request = ChatRequest::new("meta/meta-llama-3-8b-instruct");
+        request.messages = vec![
+            message(MessageRole::System, "Be brief."),
+            message(MessageRole::User, "Hi"),
+        ];
+        request.max_tokens = Some(64);
+        request.stop = Some(vec!["<|eot_id|>".to_string(), "\n\n".to_string()]);
+
+        let input = transform_chat_input(&request);
+        assert_eq!(input["prompt"], "Hi");
+        assert_eq!(input["system_prompt"], "Be brief.");
+        assert_eq!(input["max_tokens"], 64);
+        assert_eq!(input["stop_sequences"], "<|eot_id|>,\n\n");
+    }
+
+    #[test]
+    fn test_multi_turn_prompt() {
+        let mut request = ChatRequest::new("meta/meta-llama-3-8b-instruct");
+        request.messages = vec![
+            message(MessageRole::User, "Hi"),
+            message(MessageRole::Assistant, "Hello!"),
+            message(MessageRole::User, "How are you?"),
+        ];
+
+        let input = transform_chat_input(&request);
+        assert_eq!(
+            input["prompt"],
+            "User: Hi\nAssistant: Hello!\nUser: How are you?\nAssistant:"
+        );
+        assert!(input.get("system_prompt").is_none());
+    }
+
+    #[test]
+    fn test_chat_output() {
+        let metrics = PredictionMetrics {
+            predict_time: Some(0.4),
+            input_token_count: Some(12),
+            output_token_count: Some(2),
+        };
+        let response = transform_chat_output(
+            &json!(["Hel", "lo"]),
+            Some(&metrics),
+            "rrr4z55ocneqzikepnug6xezpe",
+            "meta/meta-llama-3-8b-instruct",
+        );
+
+        assert_eq!(response.id, "rrr4z55ocneqzikepnug6xezpe");
+        assert_eq!(response.first_content(), Some("Hello"));
+        assert_eq!(response.usage.unwrap().total_tokens, 14);
+    }
+
+    #[test]
+    fn test_image_input() {
+        let request = ImageGenerationRequest {
+            prompt: "a lighthouse".to_string(),
+            model: Some("black-forest-labs/flux-schnell".to_string()),
+            n: Some(2),
+            size: Some("1024x768".to_string()),
+            quality: None,
+            response_format: None,
+            style: None,
+            user: None,
+            extra_params: HashMap::new(),
+        };
+
+        let input = transform_image_input(&request).unwrap();
+        assert_eq!(input["num_outputs"], 2);
+        assert_eq!(input["width"], 1024);
+        assert_eq!(input["height"], 768);
+
+        let bad = ImageGenerationRequest {
+            size: Some("large".to_string()),
+            ..request
+        };
+        assert!(transform_image_input(&bad).is_err());
+    }
+
+    #[test]
+    fn test_image_output() {
+        let response = transform_image_output(&json!([
+            "https://replicate.delivery/a.webp",
+            "https://replicate.delivery/b.webp"
+        ]));
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(
+            response.data[1].url.as_deref(),
+            Some("https://replicate.delivery/b.webp")
+        );
+
+        let single = transform_image_output(&json!("https://replicate.delivery/c.png"));
+        assert_eq!(single.data.len(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! SambaNova Client
+//!
+//! How the SambaNova Cloud API departs from OpenAI's chat completions
+
+use crate::core::providers::base::OpenAICompatibleClient;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::ProviderCapability;
+
+use super::SambaNovaConfig;
+
+/// SambaNova API client logic
+#[derive(Debug, Clone)]
+pub struct SambaNovaClient;
+
+impl OpenAICompatibleClient for SambaNovaClient {
+    type Config = SambaNovaConfig;
+
+    const NAME: &'static str = "sambanova";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::ToolCalling,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "stop",
+        "stream",
+        "stream_options",
+        "tools",
+        "tool_choice",
+        "parallel_tool_calls",
+        "response_format",
+        "seed",
+        "user",
+    ];
+
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &[
+        "frequency_penalty",
+        "presence_penalty",
+        "logit_bias",
+        "logprobs",
+        "top_logprobs",
+        "n",
+        "thinking",
+        "functions",
+        "function_call",
+    ];
+
+    fn new(_config: &SambaNovaConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::sambanova::SambaNovaProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{ChatMessage, ChatRequest, MessageContent, MessageRole};
+    use serde_json::json;
+
+    fn test_provider() -> SambaNovaProvider {
+        let mut config = SambaNovaConfig::new("sambanova");
+        config.base.api_key = Some("test-key".to_string());
+        SambaNovaProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("sambanova/Meta-Llama-3.3-70B-Instruct");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(256);
+        request.logprobs = Some(true);
+        request.extra_params.insert("top_k".to_string(), json!(40));
+
+        let body = SambaNovaClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "Meta-Llama-3.3-70B-Instruct");
+        assert_eq!(body["max_tokens"], 256);
+        assert!(body.get("max_completion_tokens").is_none());
+        assert!(body.get("logprobs").is_none());
+        assert_eq!(body["top_k"], 40);
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = br#"{
+            "id": "0f3c7a52-7c1d-4c9e-9d55-1d2f1b7f8e61",
+            "object": "chat.completion",
+            "created": 1718000000,
+            "model": "Meta-Llama-3.3-70B-Instruct",
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": "Hi"}, "finish_reason": "stop"}],
+            "usage": {"prompt_tokens": 9, "completion_tokens": 1, "total_tokens": 10, "time_to_first_token": 0.1}
+        }"#;
+
+        let response = SambaNovaClient::transform_chat_response(raw).unwrap();
+        assert_eq!(response.first_content(), Some("Hi"));
+        assert_eq!(response.usage.unwrap().total_tokens, 10);
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = SambaNovaConfig::new("sambanova");
+        config.base.api_key = None;
+        assert!(SambaNovaProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "sambanova");
+        assert!(provider.supports_streaming());
+        assert!(provider.supports_model("sambanova/Meta-Llama-3.3-70B-Instruct"));
+        assert!(!provider.supports_model("gpt-4"));
+        assert!(provider.models().iter().all(|m| m.provider == "sambanova"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://api.sambanova.ai/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost(
+                "sambanova/Meta-Llama-3.3-70B-Instruct",
+                1_000_000,
+                1_000_000,
+            )
+            .await
+            .unwrap();
+        assert!((cost - 1.8).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,65 @@
+//! SambaNova Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(SambaNovaConfig {});
+
+impl SambaNovaConfig {
+    /// Create configuration from environment variables (`SAMBANOVA_*`)
+    pub fn from_env() -> Self {
+        Self::new("sambanova")
+    }
+}
+
+impl ProviderConfig for SambaNovaConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("sambanova")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sambanova_config_default_api_base() {
+        let config = SambaNovaConfig::new("sambanova");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.sambanova.ai/v1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sambanova_validate_missing_api_key() {
+        let mut config = SambaNovaConfig::new("sambanova");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_sambanova_validate_success() {
+        let mut config = SambaNovaConfig::new("sambanova");
+        config.base.api_key = Some("test-key".to_string());
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,14 @@
+//! SambaNova Provider
+//!
+//! OpenAI-compatible chat and streaming for models served on SambaNova Cloud (api.sambanova.ai)
+
+pub mod client;
+pub mod config;
+
+pub use client::SambaNovaClient;
+pub use config::SambaNovaConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// SambaNova provider
+pub type SambaNovaProvider = OpenAICompatibleProvider<SambaNovaClient>;
@@ -5,14 +5,17 @@
 
 use reqwest::{Client, Response, StatusCode};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 use std::collections::HashMap;
 use std::time::Duration;
 use tracing::warn;
 
 use crate::core::providers::unified_provider::ProviderError;
-use crate::core::types::requests::{MessageContent, MessageRole};
+use crate::core::types::requests::{
+    ChatMessage, ContentPart, DocumentSource, ImageRef, MessageContent, MessageRole,
+};
 use crate::core::types::responses::{FinishReason, Usage};
+use crate::core::types::{FunctionCall, Tool, ToolCall, ToolChoice};
 
 // ============================================================================
 // HTTP Client Builder
@@ -292,6 +295,564 @@ impl MessageTransformer {
     }
 }
 
+// ============================================================================
+// JSON Schema Transformation Utilities
+// ============================================================================
+
+pub struct SchemaTransformer;
+
+impl SchemaTransformer {
+    /// Keywords of the OpenAPI schema subset Gemini accepts
+    const OPENAPI_KEYWORDS: &'static [&'static str] = &[
+        "type",
+        "format",
+        "title",
+        "description",
+        "nullable",
+        "enum",
+        "maxItems",
+        "minItems",
+        "properties",
+        "required",
+        "minProperties",
+        "maxProperties",
+        "minLength",
+        "maxLength",
+        "pattern",
+        "example",
+        "anyOf",
+        "propertyOrdering",
+        "default",
+        "items",
+        "minimum",
+        "maximum",
+    ];
+
+    /// Replace local `$ref`s into `$defs` or `definitions` with their targets
+    ///
+    /// Recursive references are left in place.
+    pub fn inline_refs(schema: &Value) -> Value {
+        Self::inline_refs_from(schema, schema, &mut Vec::new())
+    }
+
+    fn inline_refs_from(schema: &Value, root: &Value, seen: &mut Vec<String>) -> Value {
+        match schema {
+            Value::Object(map) => {
+                if let Some(reference) = map.get("$ref").and_then(Value::as_str)
+                    && !seen.iter().any(|r| r == reference)
+                    && let Some(target) = reference
+                        .strip_prefix('#')
+                        .and_then(|pointer| root.pointer(pointer))
+                {
+                    seen.push(reference.to_string());
+                    let mut inlined = Self::inline_refs_from(target, root, seen);
+                    seen.pop();
+                    // Keywords next to the reference, such as a description, win
+                    if let Value::Object(inlined_map) = &mut inlined {
+                        let siblings = map.iter().filter(|(key, _)| {
+                            !matches!(key.as_str(), "$ref" | "$defs" | "definitions")
+                        });
+                        for (key, value) in siblings {
+                            inlined_map
+                                .insert(key.clone(), Self::inline_refs_from(value, root, seen));
+                        }
+                    }
+                    return inlined;
+                }
+                Value::Object(
+                    map.iter()
+                        .filter(|(key, _)| !matches!(key.as_str(), "$defs" | "definitions"))
+                        .map(|(key, value)| {
+                            (key.clone(), Self::inline_refs_from(value, root, seen))
+                        })
+                        .collect(),
+                )
+            }
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| Self::inline_refs_from(item, root, seen))
+                    .collect(),
+            ),
+            _ => schema.clone(),
+        }
+    }
+
+    /// Convert a JSON schema to the OpenAPI subset Gemini's `responseSchema` accepts
+    ///
+    /// References are inlined, nullable type unions such as
+    /// `["string", "null"]` become `nullable`, `const` becomes a one-value
+    /// `enum` and unsupported keywords such as `additionalProperties` are
+    /// dropped.
+    pub fn to_openapi_schema(schema: &Value) -> Value {
+        Self::openapi_subset(&Self::inline_refs(schema))
+    }
+
+    fn openapi_subset(schema: &Value) -> Value {
+        let Value::Object(map) = schema else {
+            return schema.clone();
+        };
+
+        let mut converted = serde_json::Map::new();
+        for (key, value) in map {
+            match key.as_str() {
+                "type" => match value {
+                    Value::Array(types) => {
+                        let non_null: Vec<&Value> = types
+                            .iter()
+                            .filter(|t| t.as_str() != Some("null"))
+                            .collect();
+                        if non_null.len() < types.len() {
+                            converted.insert("nullable".to_string(), Value::Bool(true));
+                        }
+                        if let [single] = non_null.as_slice() {
+                            converted.insert(key.clone(), (*single).clone());
+                        }
+                    }
+                    _ => {
+                        converted.insert(key.clone(), value.clone());
+                    }
+                },
+                "const" => {
+                    converted.insert("enum".to_string(), Value::Array(vec![value.clone()]));
+                }
+                "properties" => {
+                    let properties = value
+                        .as_object()
+                        .map(|properties| {
+                            properties
+                                .iter()
+                                .map(|(name, property)| {
+                                    (name.clone(), Self::openapi_subset(property))
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    converted.insert(key.clone(), Value::Object(properties));
+                }
+                "items" => {
+                    converted.insert(key.clone(), Self::openapi_subset(value));
+                }
+                "anyOf" | "oneOf" => {
+                    let options: Vec<Value> = value
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter(|option| {
+                            if option.get("type").and_then(Value::as_str) == Some("null") {
+                                converted.insert("nullable".to_string(), Value::Bool(true));
+                                return false;
+                            }
+                            true
+                        })
+                        .map(Self::openapi_subset)
+                        .collect();
+                    match <[Value; 1]>::try_from(options) {
+                        // A nullable single schema needs no union
+                        Ok([Value::Object(option)]) => {
+                            for (key, value) in option {
+                                converted.entry(key).or_insert(value);
+                            }
+                        }
+                        Ok([option]) => {
+                            converted.insert("anyOf".to_string(), Value::Array(vec![option]));
+                        }
+                        Err(options) if !options.is_empty() => {
+                            converted.insert("anyOf".to_string(), Value::Array(options));
+                        }
+                        Err(_) => {}
+                    }
+                }
+                key if Self::OPENAPI_KEYWORDS.contains(&key) => {
+                    converted.insert(key.to_string(), value.clone());
+                }
+                _ => {}
+            }
+        }
+        Value::Object(converted)
+    }
+}
+
+// ============================================================================
+// Tool Calling Transformation Utilities
+// ============================================================================
+
+/// What a tool choice asks of the model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolChoiceMode<'a> {
+    /// Call tools or not, as the model sees fit
+    Auto,
+    /// Call no tools
+    None,
+    /// Call at least one tool
+    Required,
+    /// Call the named function
+    Function(&'a str),
+}
+
+impl<'a> From<&'a ToolChoice> for ToolChoiceMode<'a> {
+    fn from(choice: &'a ToolChoice) -> Self {
+        match choice {
+            ToolChoice::String(choice) => match choice.as_str() {
+                "none" => Self::None,
+                "required" | "any" => Self::Required,
+                _ => Self::Auto,
+            },
+            ToolChoice::Specific {
+                function: Some(function),
+                ..
+            } => Self::Function(&function.name),
+            ToolChoice::Specific { function: None, .. } => Self::Auto,
+        }
+    }
+}
+
+/// Converts OpenAI tools, tool choices, tool calls and tool results to
+/// provider formats and back
+///
+/// In the OpenAI format, an assistant message carries `tool_calls` and each
+/// result is a separate `tool` message with the call's `tool_call_id`.
+/// Anthropic and Bedrock Converse put calls and results in content blocks,
+/// Gemini in `functionCall` and `functionResponse` parts.
+pub struct ToolTransformer;
+
+impl ToolTransformer {
+    /// Arguments of a tool call, an empty object if they are not JSON
+    pub fn arguments(call: &ToolCall) -> Value {
+        serde_json::from_str(&call.function.arguments).unwrap_or_else(|_| json!({}))
+    }
+
+    /// Text of a tool result message
+    pub fn result_text(message: &ChatMessage) -> String {
+        match &message.content {
+            Some(MessageContent::Text(text)) => text.clone(),
+            Some(MessageContent::Parts(parts)) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    ContentPart::Text { text, .. } => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => String::new(),
+        }
+    }
+
+    /// OpenAI tool call from a provider's call
+    pub fn tool_call(id: impl Into<String>, name: impl Into<String>, input: &Value) -> ToolCall {
+        ToolCall {
+            id: id.into(),
+            tool_type: "function".to_string(),
+            function: FunctionCall {
+                name: name.into(),
+                arguments: input.to_string(),
+            },
+        }
+    }
+
+    /// Function names of the tool calls in a conversation, by call ID
+    ///
+    /// Gemini identifies a result by the function's name, which OpenAI tool
+    /// messages do not repeat.
+    pub fn tool_call_names(messages: &[ChatMessage]) -> HashMap<&str, &str> {
+        messages
+            .iter()
+            .filter_map(|message| message.tool_calls.as_ref())
+            .flatten()
+            .map(|call| (call.id.as_str(), call.function.name.as_str()))
+            .collect()
+    }
+
+    /// Anthropic tool definitions
+    pub fn to_anthropic_tools(tools: &[Tool]) -> Vec<Value> {
+        tools
+            .iter()
+            .map(|tool| {
+                let mut anthropic_tool = json!({
+                    "name": tool.function.name,
+                    "input_schema": tool
+                        .function
+                        .parameters
+                        .clone()
+                        .unwrap_or_else(|| json!({"type": "object"})),
+                });
+                if let Some(description) = &tool.function.description {
+                    anthropic_tool["description"] = json!(description);
+                }
+                anthropic_tool
+            })
+            .collect()
+    }
+
+    /// Anthropic `tool_choice`
+    pub fn to_anthropic_tool_choice(choice: &ToolChoice) -> Value {
+        match ToolChoiceMode::from(choice) {
+            ToolChoiceMode::Auto => json!({"type": "auto"}),
+            ToolChoiceMode::None => json!({"type": "none"}),
+            ToolChoiceMode::Required => json!({"type": "any"}),
+            ToolChoiceMode::Function(name) => json!({"type": "tool", "name": name}),
+        }
+    }
+
+    /// Anthropic `tool_use` block of a tool call
+    pub fn to_anthropic_tool_use(call: &ToolCall) -> Value {
+        json!({
+            "type": "tool_use",
+            "id": call.id,
+            "name": call.function.name,
+            "input": Self::arguments(call),
+        })
+    }
+
+    /// Anthropic `tool_result` block of a tool message
+    pub fn to_anthropic_tool_result(message: &ChatMessage) -> Value {
+        json!({
+            "type": "tool_result",
+            "tool_use_id": message.tool_call_id.as_deref().unwrap_or_default(),
+            "content": Self::result_text(message),
+        })
+    }
+
+    /// Tool call of an Anthropic `tool_use` block
+    pub fn from_anthropic_tool_use(block: &Value) -> Option<ToolCall> {
+        Some(Self::tool_call(
+            block.get("id")?.as_str()?,
+            block.get("name")?.as_str()?,
+            block.get("input")?,
+        ))
+    }
+
+    /// Gemini `tools`, with parameters in Gemini's OpenAPI schema dialect
+    pub fn to_gemini_tools(tools: &[Tool]) -> Value {
+        let declarations: Vec<Value> = tools
+            .iter()
+            .map(|tool| {
+                let mut declaration = json!({"name": tool.function.name});
+                if let Some(description) = &tool.function.description {
+                    declaration["description"] = json!(description);
+                }
+                // Gemini rejects functions with empty object parameters
+                if let Some(parameters) = tool.function.parameters.as_ref().filter(|p| {
+                    p.get("properties")
+                        .and_then(Value::as_object)
+                        .is_some_and(|properties| !properties.is_empty())
+                }) {
+                    declaration["parameters"] = SchemaTransformer::to_openapi_schema(parameters);
+                }
+                declaration
+            })
+            .collect();
+        json!([{"functionDeclarations": declarations}])
+    }
+
+    /// Gemini `toolConfig`
+    pub fn to_gemini_tool_config(choice: &ToolChoice) -> Value {
+        let config = match ToolChoiceMode::from(choice) {
+            ToolChoiceMode::Auto => json!({"mode": "AUTO"}),
+            ToolChoiceMode::None => json!({"mode": "NONE"}),
+            ToolChoiceMode::Required => json!({"mode": "ANY"}),
+            ToolChoiceMode::Function(name) => {
+                json!({"mode": "ANY", "allowedFunctionNames": [name]})
+            }
+        };
+        json!({"functionCallingConfig": config})
+    }
+
+    /// Gemini `functionCall` part of a tool call
+    pub fn to_gemini_function_call(call: &ToolCall) -> Value {
+        json!({
+            "functionCall": {
+                "name": call.function.name,
+                "args": Self::arguments(call),
+            }
+        })
+    }
+
+    /// Gemini `functionResponse` part of a tool message
+    ///
+    /// Gemini takes an object as the response, so other results are wrapped
+    /// in `{"content": ...}`.
+    pub fn to_gemini_function_response(name: &str, message: &ChatMessage) -> Value {
+        let text = Self::result_text(message);
+        let response = match serde_json::from_str::<Value>(&text) {
+            Ok(object @ Value::Object(_)) => object,
+            Ok(value) => json!({"content": value}),
+            Err(_) => json!({"content": text}),
+        };
+        json!({
+            "functionResponse": {
+                "name": name,
+                "response": response,
+            }
+        })
+    }
+
+    /// Tool call of a Gemini `functionCall` part
+    ///
+    /// Calls without an ID are given one, so their results can refer to them.
+    pub fn from_gemini_function_call(part: &Value) -> Option<ToolCall> {
+        let call = part.get("functionCall")?;
+        let id = call
+            .get("id")
+            .and_then(Value::as_str)
+            .map(String::from)
+            .unwrap_or_else(|| format!("call_{}", uuid::Uuid::new_v4().simple()));
+        Some(Self::tool_call(
+            id,
+            call.get("name")?.as_str()?,
+            call.get("args").unwrap_or(&json!({})),
+        ))
+    }
+
+    /// Bedrock Converse `toolConfig`
+    ///
+    /// Converse cannot forbid tool use, so there is none when the tool
+    /// choice is `none` and the tools are left out instead.
+    pub fn to_converse_tool_config(tools: &[Tool], choice: Option<&ToolChoice>) -> Option<Value> {
+        let mode = choice.map(ToolChoiceMode::from);
+        if tools.is_empty() || mode == Some(ToolChoiceMode::None) {
+            return None;
+        }
+
+        let tools: Vec<Value> = tools
+            .iter()
+            .map(|tool| {
+                json!({
+                    "toolSpec": {
+                        "name": tool.function.name,
+                        "description": tool.function.description.clone().unwrap_or_default(),
+                        "inputSchema": {
+                            "json": tool.function.parameters.clone().unwrap_or_else(|| json!({})),
+                        },
+                    }
+                })
+            })
+            .collect();
+        let mut config = json!({"tools": tools});
+        match mode {
+            Some(ToolChoiceMode::Auto) => config["toolChoice"] = json!({"auto": {}}),
+            Some(ToolChoiceMode::Required) => config["toolChoice"] = json!({"any": {}}),
+            Some(ToolChoiceMode::Function(name)) => {
+                config["toolChoice"] = json!({"tool": {"name": name}});
+            }
+            Some(ToolChoiceMode::None) | None => {}
+        }
+        Some(config)
+    }
+
+    /// Bedrock Converse `toolUse` block of a tool call
+    pub fn to_converse_tool_use(call: &ToolCall) -> Value {
+        json!({
+            "toolUse": {
+                "toolUseId": call.id,
+                "name": call.function.name,
+                "input": Self::arguments(call),
+            }
+        })
+    }
+
+    /// Bedrock Converse `toolResult` block of a tool message
+    pub fn to_converse_tool_result(message: &ChatMessage) -> Value {
+        json!({
+            "toolResult": {
+                "toolUseId": message.tool_call_id.as_deref().unwrap_or_default(),
+                "content": [{"text": Self::result_text(message)}],
+            }
+        })
+    }
+}
+
+// ============================================================================
+// Image Transformation Utilities
+// ============================================================================
+
+pub struct ImageTransformer;
+
+impl ImageTransformer {
+    /// Anthropic image block
+    pub fn to_anthropic(image: &ImageRef) -> Value {
+        match image {
+            ImageRef::Base64 { media_type, data } => json!({
+                "type": "image",
+                "source": {
+                    "type": "base64",
+                    "media_type": media_type,
+                    "data": data
+                }
+            }),
+            ImageRef::Url(url) => json!({
+                "type": "image",
+                "source": {
+                    "type": "url",
+                    "url": url
+                }
+            }),
+        }
+    }
+
+    /// Gemini `inlineData` part, `None` for remote images
+    ///
+    /// Gemini only fetches files it hosts, so remote images need uploading.
+    pub fn to_gemini(image: &ImageRef) -> Option<Value> {
+        match image {
+            ImageRef::Base64 { media_type, data } => Some(json!({
+                "inlineData": {
+                    "mimeType": media_type,
+                    "data": data
+                }
+            })),
+            ImageRef::Url(_) => None,
+        }
+    }
+}
+
+// ============================================================================
+// Document Transformation Utilities
+// ============================================================================
+
+pub struct DocumentTransformer;
+
+impl DocumentTransformer {
+    /// Anthropic document block
+    pub fn to_anthropic(source: &DocumentSource) -> Value {
+        match &source.url {
+            Some(url) => json!({
+                "type": "document",
+                "source": {
+                    "type": "url",
+                    "url": url
+                }
+            }),
+            None => json!({
+                "type": "document",
+                "source": {
+                    "type": "base64",
+                    "media_type": source.media_type,
+                    "data": source.data
+                }
+            }),
+        }
+    }
+
+    /// Gemini `fileData` part for a URL, else an `inlineData` part
+    pub fn to_gemini(source: &DocumentSource) -> Value {
+        match &source.url {
+            Some(url) => json!({
+                "fileData": {
+                    "mimeType": source.media_type,
+                    "fileUri": url
+                }
+            }),
+            None => json!({
+                "inlineData": {
+                    "mimeType": source.media_type,
+                    "data": source.data
+                }
+            }),
+        }
+    }
+}
+
 // ============================================================================
 // Common Request/Response Types
 // ============================================================================
@@ -451,6 +1012,9 @@ pub mod test_utils {
             completion_tokens_details: None,
             prompt_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         }
     }
 }
@@ -475,6 +1039,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_transformer() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "steps": {"type": "array", "items": {"$ref": "#/$defs/step"}},
+                "note": {"type": ["string", "null"], "description": "Optional"},
+                "kind": {"const": "plan"},
+                "owner": {"anyOf": [{"$ref": "#/$defs/person"}, {"type": "null"}]}
+            },
+            "required": ["steps"],
+            "additionalProperties": false,
+            "$defs": {
+                "step": {"type": "string", "description": "A step"},
+                "person": {"type": "object", "properties": {"name": {"type": "string"}}}
+            }
+        });
+
+        assert_eq!(
+            SchemaTransformer::to_openapi_schema(&schema),
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "steps": {"type": "array", "items": {"type": "string", "description": "A step"}},
+                    "note": {"type": "string", "nullable": true, "description": "Optional"},
+                    "kind": {"enum": ["plan"]},
+                    "owner": {
+                        "nullable": true,
+                        "type": "object",
+                        "properties": {"name": {"type": "string"}}
+                    }
+                },
+                "required": ["steps"]
+            })
+        );
+
+        // Recursive references stay references
+        let tree = serde_json::json!({
+            "$defs": {"node": {"type": "object", "properties": {"child": {"$ref": "#/$defs/node"}}}},
+            "$ref": "#/$defs/node"
+        });
+        assert_eq!(
+            SchemaTransformer::inline_refs(&tree)["properties"]["child"],
+            serde_json::json!({"$ref": "#/$defs/node"})
+        );
+    }
+
+    #[test]
+    fn test_image_transformer() {
+        let png = ContentPart::image_bytes(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR");
+        let image = png.image().unwrap();
+        assert!(matches!(
+            image,
+            ImageRef::Base64 {
+                media_type: "image/png",
+                ..
+            }
+        ));
+
+        let anthropic = ImageTransformer::to_anthropic(&image);
+        assert_eq!(anthropic["source"]["type"], "base64");
+        assert_eq!(anthropic["source"]["media_type"], "image/png");
+
+        let gemini = ImageTransformer::to_gemini(&image).unwrap();
+        assert_eq!(gemini["inlineData"]["mimeType"], "image/png");
+        assert_eq!(gemini["inlineData"]["data"], anthropic["source"]["data"]);
+
+        let remote = ContentPart::image_url("https://example.com/cat.jpg");
+        let image = remote.image().unwrap();
+        assert_eq!(
+            ImageTransformer::to_anthropic(&image)["source"],
+            serde_json::json!({"type": "url", "url": "https://example.com/cat.jpg"})
+        );
+        assert!(ImageTransformer::to_gemini(&image).is_none());
+    }
+
+    #[test]
+    fn test_document_transformer() {
+        let ContentPart::Document { source, .. } =
+            ContentPart::document_url("https://example.com/report.pdf")
+        else {
+            panic!("expected document part");
+        };
+        assert_eq!(
+            DocumentTransformer::to_anthropic(&source)["source"],
+            serde_json::json!({"type": "url", "url": "https://example.com/report.pdf"})
+        );
+        assert_eq!(
+            DocumentTransformer::to_gemini(&source),
+            serde_json::json!({
+                "fileData": {
+                    "mimeType": "application/pdf",
+                    "fileUri": "https://example.com/report.pdf"
+                }
+            })
+        );
+
+        let ContentPart::Document { source, .. } =
+            ContentPart::document_base64("application/pdf", "JVBERi0=")
+        else {
+            panic!("expected document part");
+        };
+        assert_eq!(
+            DocumentTransformer::to_anthropic(&source)["source"]["data"],
+            "JVBERi0="
+        );
+        assert_eq!(
+            DocumentTransformer::to_gemini(&source)["inlineData"]["mimeType"],
+            "application/pdf"
+        );
+    }
+
     #[test]
     fn test_token_cost_calculator() {
         let calculator = TokenCostCalculator::new(0.01, 0.02);
@@ -485,6 +1161,9 @@ mod tests {
             completion_tokens_details: None,
             prompt_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         };
         let cost = calculator.calculate_cost(&usage);
         assert_eq!(cost, 0.02); // 0.01 + 0.01
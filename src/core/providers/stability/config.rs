@@ -0,0 +1,65 @@
+//! Stability Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(StabilityConfig {});
+
+impl StabilityConfig {
+    /// Create configuration from environment variables (`STABILITY_*`)
+    pub fn from_env() -> Self {
+        Self::new("stability")
+    }
+}
+
+impl ProviderConfig for StabilityConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("stability")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stability_config_default_api_base() {
+        let config = StabilityConfig::new("stability");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.stability.ai".to_string())
+        );
+    }
+
+    #[test]
+    fn test_stability_validate_missing_api_key() {
+        let mut config = StabilityConfig::new("stability");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_stability_validate_success() {
+        let mut config = StabilityConfig::new("stability");
+        config.base.api_key = Some("test-key".to_string());
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,107 @@
+//! Stability AI Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Stability AI API
+#[derive(Debug)]
+pub struct StabilityErrorMapper;
+
+impl ErrorMapper<ProviderError> for StabilityErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let (name, message) = parse_error_body(response_body);
+
+        match status_code {
+            400 | 413 | 422 => ProviderError::invalid_request("stability", message),
+            401 => ProviderError::authentication("stability", message),
+            // Out of credits
+            402 => ProviderError::quota_exceeded("stability", message),
+            403 if name.as_deref() == Some("content_moderation") => {
+                ProviderError::content_filtered("stability", message, None, Some(true))
+            }
+            403 => ProviderError::authentication("stability", message),
+            404 => ProviderError::model_not_found("stability", message),
+            429 => ProviderError::rate_limit("stability", None),
+            _ => ProviderError::api_error("stability", status_code, message),
+        }
+    }
+}
+
+/// Error bodies carry a `name` plus either an `errors` list (v2beta) or a
+/// single `message` (v1)
+fn parse_error_body(response_body: &str) -> (Option<String>, String) {
+    let value = match serde_json::from_str::<serde_json::Value>(response_body) {
+        Ok(value) => value,
+        Err(_) => return (None, response_body.to_string()),
+    };
+
+    let name = value.get("name").and_then(|n| n.as_str()).map(String::from);
+    let message = value
+        .get("errors")
+        .and_then(|e| e.as_array())
+        .map(|errors| {
+            errors
+                .iter()
+                .filter_map(|e| e.as_str())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+        .filter(|m| !m.is_empty())
+        .or_else(|| {
+            value
+                .get("message")
+                .and_then(|m| m.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| response_body.to_string());
+
+    (name, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stability_error_mapper_v2beta_errors() {
+        let err = StabilityErrorMapper.map_http_error(
+            400,
+            r#"{"id":"a1b2","name":"bad_request","errors":["prompt: cannot be empty","aspect_ratio: invalid"]}"#,
+        );
+        match err {
+            ProviderError::InvalidRequest { message, .. } => {
+                assert_eq!(message, "prompt: cannot be empty; aspect_ratio: invalid")
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stability_error_mapper_v1_message() {
+        let err = StabilityErrorMapper.map_http_error(
+            401,
+            r#"{"id":"c3d4","name":"unauthorized","message":"missing authorization header"}"#,
+        );
+        match err {
+            ProviderError::Authentication { message, .. } => {
+                assert_eq!(message, "missing authorization header")
+            }
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stability_error_mapper_content_moderation() {
+        let err = StabilityErrorMapper.map_http_error(
+            403,
+            r#"{"id":"e5f6","name":"content_moderation","errors":["Your request was flagged"]}"#,
+        );
+        assert!(matches!(err, ProviderError::ContentFiltered { .. }));
+    }
+
+    #[test]
+    fn test_stability_error_mapper_402() {
+        let err = StabilityErrorMapper.map_http_error(402, "");
+        assert!(matches!(err, ProviderError::QuotaExceeded { .. }));
+    }
+}
@@ -0,0 +1,14 @@
+//! Stability AI Provider
+//!
+//! Text-to-image generation on platform.stability.ai. Stable Diffusion 3.x,
+//! Stable Image Core and Stable Image Ultra are served by the v2beta multipart
+//! API; SDXL is only available through the v1 JSON generation API.
+
+pub mod config;
+pub mod error;
+pub mod provider;
+pub mod transformation;
+
+pub use config::StabilityConfig;
+pub use error::StabilityErrorMapper;
+pub use provider::StabilityProvider;
@@ -0,0 +1,383 @@
+//! Stability AI Provider Implementation
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::providers::base::{
+    GlobalPoolManager, HeaderPair, HttpMethod, header, header_owned,
+};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+use crate::core::traits::{ProviderConfig, provider::llm_provider::trait_definition::LLMProvider};
+use crate::core::types::{
+    common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
+    requests::{ChatRequest, ImageGenerationRequest},
+    responses::{ChatResponse, ImageGenerationResponse},
+};
+
+use super::transformation::{
+    GeneratedImage, StabilityArtifactsResponse, StabilityEndpoint, StabilityImageResponse,
+    output_format, transform_engine_body, transform_form_fields, transform_image_output,
+};
+use super::{StabilityConfig, StabilityErrorMapper};
+
+/// Image models and their price per generated image in USD
+const STABILITY_MODELS: &[(&str, &str, f64)] = &[
+    ("sd3.5-large", "Stable Diffusion 3.5 Large", 0.065),
+    (
+        "sd3.5-large-turbo",
+        "Stable Diffusion 3.5 Large Turbo",
+        0.04,
+    ),
+    ("sd3.5-medium", "Stable Diffusion 3.5 Medium", 0.035),
+    ("sd3-large", "Stable Diffusion 3 Large", 0.065),
+    ("sd3-large-turbo", "Stable Diffusion 3 Large Turbo", 0.04),
+    ("sd3-medium", "Stable Diffusion 3 Medium", 0.035),
+    ("stable-image-ultra", "Stable Image Ultra", 0.08),
+    ("stable-image-core", "Stable Image Core", 0.03),
+    (
+        "stable-diffusion-xl-1024-v1-0",
+        "Stable Diffusion XL 1.0",
+        0.002,
+    ),
+];
+
+#[derive(Debug, Clone)]
+pub struct StabilityProvider {
+    config: StabilityConfig,
+    pool_manager: Arc<GlobalPoolManager>,
+    supported_models: Vec<ModelInfo>,
+}
+
+impl StabilityProvider {
+    /// Generate headers for Stability API requests
+    ///
+    /// JSON is requested so images come back base64-encoded rather than as
+    /// raw bytes.
+    fn get_request_headers(&self) -> Vec<HeaderPair> {
+        let mut headers = Vec::with_capacity(3);
+
+        if let Some(api_key) = &self.config.base.api_key {
+            headers.push(header("Authorization", format!("Bearer {}", api_key)));
+        }
+        headers.push(header("Accept", "application/json".to_string()));
+
+        for (key, value) in &self.config.base.headers {
+            headers.push(header_owned(key.clone(), value.clone()));
+        }
+
+        headers
+    }
+
+    pub fn new(config: StabilityConfig) -> Result<Self, ProviderError> {
+        config
+            .validate()
+            .map_err(|e| ProviderError::configuration("stability", e))?;
+
+        let pool_manager = Arc::new(
+            GlobalPoolManager::new()
+                .map_err(|e| ProviderError::configuration("stability", e.to_string()))?,
+        );
+
+        Ok(Self {
+            config,
+            pool_manager,
+            supported_models: Self::build_models(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, ProviderError> {
+        Self::new(StabilityConfig::from_env())
+    }
+
+    fn build_models() -> Vec<ModelInfo> {
+        STABILITY_MODELS
+            .iter()
+            .map(|(id, name, price)| {
+                let mut metadata = HashMap::new();
+                metadata.insert("cost_per_image".to_string(), Value::from(*price));
+
+                ModelInfo {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    provider: "stability".to_string(),
+                    max_context_length: 10000,
+                    max_output_length: None,
+                    supports_streaming: false,
+                    supports_tools: false,
+                    supports_multimodal: false,
+                    input_cost_per_1k_tokens: None,
+                    output_cost_per_1k_tokens: None,
+                    currency: "USD".to_string(),
+                    capabilities: vec![ProviderCapability::ImageGeneration],
+                    created_at: None,
+                    updated_at: None,
+                    metadata,
+                }
+            })
+            .collect()
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}{}",
+            self.config
+                .base
+                .get_effective_api_base("stability")
+                .trim_end_matches('/'),
+            path
+        )
+    }
+
+    async fn check_response(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, ProviderError> {
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(StabilityErrorMapper.map_http_error(status.as_u16(), &error_text));
+        }
+        Ok(response)
+    }
+
+    /// Generate a single image through a v2beta multipart endpoint
+    async fn generate_form(
+        &self,
+        url: &str,
+        fields: &[(String, String)],
+    ) -> Result<GeneratedImage, ProviderError> {
+        let mut form = reqwest::multipart::Form::new();
+        for (key, value) in fields {
+            form = form.text(key.clone(), value.clone());
+        }
+
+        let mut request = self
+            .pool_manager
+            .client()
+            .post(url)
+            .timeout(self.config.base.timeout_duration());
+        for (key, value) in self.get_request_headers() {
+            request = request.header(key.as_ref(), value.as_ref());
+        }
+
+        let response = request
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| ProviderError::network("stability", e.to_string()))?;
+        let response = self.check_response(response).await?;
+
+        response
+            .json::<StabilityImageResponse>()
+            .await
+            .map_err(|e| ProviderError::response_parsing("stability", e.to_string()))?
+            .into_image()
+    }
+
+    /// Generate images through a v1 JSON engine
+    async fn generate_engine(
+        &self,
+        url: &str,
+        body: Value,
+    ) -> Result<Vec<GeneratedImage>, ProviderError> {
+        let mut headers = self.get_request_headers();
+        headers.push(header("Content-Type", "application/json".to_string()));
+
+        let response = self
+            .pool_manager
+            .execute_request(url, HttpMethod::POST, headers, Some(body))
+            .await?;
+        let response = self.check_response(response).await?;
+
+        response
+            .json::<StabilityArtifactsResponse>()
+            .await
+            .map_err(|e| ProviderError::response_parsing("stability", e.to_string()))?
+            .into_images()
+    }
+}
+
+#[async_trait]
+impl LLMProvider for StabilityProvider {
+    type Config = StabilityConfig;
+    type Error = ProviderError;
+    type ErrorMapper = StabilityErrorMapper;
+
+    fn name(&self) -> &'static str {
+        "stability"
+    }
+
+    fn capabilities(&self) -> &'static [ProviderCapability] {
+        &[ProviderCapability::ImageGeneration]
+    }
+
+    fn models(&self) -> &[ModelInfo] {
+        &self.supported_models
+    }
+
+    fn supports_model(&self, model: &str) -> bool {
+        let model = model.strip_prefix("stability/").unwrap_or(model);
+        self.supported_models.iter().any(|m| m.id == model)
+    }
+
+    fn get_supported_openai_params(&self, _model: &str) -> &'static [&'static str] {
+        &["n", "size", "style", "response_format"]
+    }
+
+    async fn map_openai_params(
+        &self,
+        params: HashMap<String, Value>,
+        _model: &str,
+    ) -> Result<HashMap<String, Value>, Self::Error> {
+        Ok(params)
+    }
+
+    async fn transform_request(
+        &self,
+        _request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<Value, Self::Error> {
+        Err(ProviderError::not_supported("stability", "chat completion"))
+    }
+
+    async fn transform_response(
+        &self,
+        _raw_response: &[u8],
+        _model: &str,
+        _request_id: &str,
+    ) -> Result<ChatResponse, Self::Error> {
+        Err(ProviderError::not_supported("stability", "chat completion"))
+    }
+
+    fn get_error_mapper(&self) -> Self::ErrorMapper {
+        StabilityErrorMapper
+    }
+
+    async fn chat_completion(
+        &self,
+        _request: ChatRequest,
+        _context: RequestContext,
+    ) -> Result<ChatResponse, Self::Error> {
+        Err(ProviderError::not_supported("stability", "chat completion"))
+    }
+
+    async fn image_generation(
+        &self,
+        request: ImageGenerationRequest,
+        _context: RequestContext,
+    ) -> Result<ImageGenerationResponse, Self::Error> {
+        let model = request.model.as_deref().ok_or_else(|| {
+            ProviderError::invalid_request("stability", "An image model is required")
+        })?;
+        let endpoint = StabilityEndpoint::parse(model)?;
+        let url = self.endpoint(&endpoint.path());
+
+        let (images, format) = if endpoint.is_multipart() {
+            // One image per call; `n` is served by issuing the calls concurrently
+            let fields = transform_form_fields(&endpoint, &request)?;
+            let calls =
+                (0..request.n.unwrap_or(1).max(1)).map(|_| self.generate_form(&url, &fields));
+            let images = futures::future::try_join_all(calls).await?;
+            (images, output_format(&request))
+        } else {
+            let images = self
+                .generate_engine(&url, transform_engine_body(&request)?)
+                .await?;
+            (images, "png".to_string())
+        };
+
+        Ok(transform_image_output(
+            images,
+            request.response_format.as_deref(),
+            &format,
+        ))
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        if self.config.base.api_key.is_some() {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+
+    /// Images are billed per generation, not per token
+    async fn calculate_cost(
+        &self,
+        _model: &str,
+        _input_tokens: u32,
+        _output_tokens: u32,
+    ) -> Result<f64, Self::Error> {
+        Ok(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_provider() -> StabilityProvider {
+        let mut config = StabilityConfig::new("stability");
+        config.base.api_key = Some("sk-test".to_string());
+        StabilityProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = StabilityConfig::new("stability");
+        config.base.api_key = None;
+        assert!(StabilityProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "stability");
+        assert_eq!(
+            provider.capabilities(),
+            &[ProviderCapability::ImageGeneration]
+        );
+        assert!(!provider.supports_streaming());
+    }
+
+    #[test]
+    fn test_supports_model() {
+        let provider = test_provider();
+        assert!(provider.supports_model("sd3.5-large"));
+        assert!(provider.supports_model("stability/stable-diffusion-xl-1024-v1-0"));
+        assert!(!provider.supports_model("dall-e-3"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        let endpoint = StabilityEndpoint::parse("stable-image-ultra").unwrap();
+        assert_eq!(
+            provider.endpoint(&endpoint.path()),
+            "https://api.stability.ai/v2beta/stable-image/generate/ultra"
+        );
+    }
+
+    #[test]
+    fn test_request_headers() {
+        let provider = test_provider();
+        let headers = provider.get_request_headers();
+        assert!(
+            headers
+                .iter()
+                .any(|(k, v)| k.as_ref() == "Accept" && v.as_ref() == "application/json")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_not_supported() {
+        let provider = test_provider();
+        let result = provider
+            .chat_completion(ChatRequest::new("sd3.5-large"), RequestContext::default())
+            .await;
+        assert!(matches!(result, Err(ProviderError::NotSupported { .. })));
+    }
+}
@@ -0,0 +1,474 @@
+//! Stability AI Request/Response Transformation
+//!
+//! The v2beta endpoints take a multipart form and return one image per call,
+//! sized by `aspect_ratio`. The v1 SDXL engine takes JSON with explicit
+//! `width`/`height`, sampling `steps` and weighted `text_prompts`, and can
+//! return several images at once.
+//!
+//! Parameters with no OpenAI equivalent (`negative_prompt`, `steps`, `seed`,
+//! `cfg_scale`, `style_preset`, ...) are read from the request's extra params.
+
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::ImageGenerationRequest,
+    responses::{ImageData, ImageGenerationResponse},
+};
+
+/// Aspect ratios accepted by the v2beta endpoints
+const ASPECT_RATIOS: &[(&str, f64)] = &[
+    ("21:9", 21.0 / 9.0),
+    ("16:9", 16.0 / 9.0),
+    ("3:2", 3.0 / 2.0),
+    ("5:4", 5.0 / 4.0),
+    ("1:1", 1.0),
+    ("4:5", 4.0 / 5.0),
+    ("2:3", 2.0 / 3.0),
+    ("9:16", 9.0 / 16.0),
+    ("9:21", 9.0 / 21.0),
+];
+
+/// Parameters that only the v1 engines understand
+const ENGINE_ONLY_PARAMS: &[&str] = &["steps", "samples", "sampler", "clip_guidance_preset"];
+
+/// Where an image model is served from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StabilityEndpoint<'a> {
+    /// Stable Diffusion 3.x; the variant is sent as the `model` form field
+    Sd3(&'a str),
+    /// Stable Image Core
+    Core,
+    /// Stable Image Ultra
+    Ultra,
+    /// A v1 generation engine such as `stable-diffusion-xl-1024-v1-0`
+    Engine(&'a str),
+}
+
+impl<'a> StabilityEndpoint<'a> {
+    /// Resolve a model name, ignoring a leading `stability/`
+    pub fn parse(model: &'a str) -> Result<Self, ProviderError> {
+        let model = model.strip_prefix("stability/").unwrap_or(model);
+
+        match model {
+            "stable-image-core" | "core" => Ok(Self::Core),
+            "stable-image-ultra" | "ultra" => Ok(Self::Ultra),
+            _ if model.starts_with("sd3") => Ok(Self::Sd3(model)),
+            _ if model.starts_with("stable-diffusion") => Ok(Self::Engine(model)),
+            _ => Err(ProviderError::model_not_found("stability", model)),
+        }
+    }
+
+    /// Path of the generation endpoint, relative to the API base
+    pub fn path(&self) -> String {
+        match self {
+            Self::Sd3(_) => "/v2beta/stable-image/generate/sd3".to_string(),
+            Self::Core => "/v2beta/stable-image/generate/core".to_string(),
+            Self::Ultra => "/v2beta/stable-image/generate/ultra".to_string(),
+            Self::Engine(engine) => format!("/v1/generation/{}/text-to-image", engine),
+        }
+    }
+
+    /// Whether the endpoint takes multipart form data rather than JSON
+    pub fn is_multipart(&self) -> bool {
+        !matches!(self, Self::Engine(_))
+    }
+}
+
+/// Parse an OpenAI `WIDTHxHEIGHT` size
+fn parse_size(size: &str) -> Result<(u32, u32), ProviderError> {
+    size.split_once('x')
+        .and_then(|(w, h)| Some((w.parse::<u32>().ok()?, h.parse::<u32>().ok()?)))
+        .filter(|(w, h)| *w > 0 && *h > 0)
+        .ok_or_else(|| {
+            ProviderError::invalid_request(
+                "stability",
+                format!("Invalid image size '{}', expected WIDTHxHEIGHT", size),
+            )
+        })
+}
+
+/// Closest supported aspect ratio to a pixel size
+pub fn aspect_ratio(width: u32, height: u32) -> &'static str {
+    let ratio = (width as f64 / height as f64).ln();
+
+    ASPECT_RATIOS
+        .iter()
+        .min_by(|(_, a), (_, b)| {
+            (a.ln() - ratio)
+                .abs()
+                .partial_cmp(&(b.ln() - ratio).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(name, _)| *name)
+        .unwrap_or("1:1")
+}
+
+/// Form values are plain text; JSON strings are sent without their quotes
+fn form_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Image format requested from the v2beta endpoints
+pub fn output_format(request: &ImageGenerationRequest) -> String {
+    request
+        .extra_params
+        .get("output_format")
+        .map(form_value)
+        .unwrap_or_else(|| "png".to_string())
+}
+
+/// Build the form fields for a v2beta request
+///
+/// `size` is mapped to the nearest supported `aspect_ratio` unless one is
+/// given explicitly.
+pub fn transform_form_fields(
+    endpoint: &StabilityEndpoint<'_>,
+    request: &ImageGenerationRequest,
+) -> Result<Vec<(String, String)>, ProviderError> {
+    let mut fields = vec![("prompt".to_string(), request.prompt.clone())];
+
+    if let StabilityEndpoint::Sd3(model) = endpoint {
+        fields.push(("model".to_string(), model.to_string()));
+        fields.push(("mode".to_string(), "text-to-image".to_string()));
+    }
+    if !request.extra_params.contains_key("aspect_ratio") {
+        if let Some(size) = &request.size {
+            let (width, height) = parse_size(size)?;
+            fields.push((
+                "aspect_ratio".to_string(),
+                aspect_ratio(width, height).to_string(),
+            ));
+        }
+    }
+    if let Some(style) = &request.style {
+        if !request.extra_params.contains_key("style_preset") {
+            fields.push(("style_preset".to_string(), style.clone()));
+        }
+    }
+    fields.push(("output_format".to_string(), output_format(request)));
+
+    let mut extra: Vec<(&String, &Value)> = request
+        .extra_params
+        .iter()
+        .filter(|(key, _)| {
+            key.as_str() != "output_format" && !ENGINE_ONLY_PARAMS.contains(&key.as_str())
+        })
+        .collect();
+    // Keep the form stable regardless of map ordering
+    extra.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, value) in extra {
+        fields.push((key.clone(), form_value(value)));
+    }
+
+    Ok(fields)
+}
+
+/// Build the JSON body for a v1 engine request
+///
+/// The negative prompt becomes a second text prompt with weight -1.
+pub fn transform_engine_body(request: &ImageGenerationRequest) -> Result<Value, ProviderError> {
+    let mut text_prompts = vec![json!({ "text": request.prompt, "weight": 1.0 })];
+    if let Some(negative) = request
+        .extra_params
+        .get("negative_prompt")
+        .and_then(|v| v.as_str())
+    {
+        text_prompts.push(json!({ "text": negative, "weight": -1.0 }));
+    }
+
+    let mut body = Map::new();
+    body.insert("text_prompts".to_string(), json!(text_prompts));
+
+    if let Some(size) = &request.size {
+        let (width, height) = parse_size(size)?;
+        body.insert("width".to_string(), json!(width));
+        body.insert("height".to_string(), json!(height));
+    }
+    if let Some(n) = request.n {
+        body.insert("samples".to_string(), json!(n));
+    }
+    if let Some(style) = &request.style {
+        body.insert("style_preset".to_string(), json!(style));
+    }
+    for (key, value) in &request.extra_params {
+        if key != "negative_prompt" && key != "output_format" {
+            body.insert(key.clone(), value.clone());
+        }
+    }
+
+    Ok(Value::Object(body))
+}
+
+/// A generated image as returned by either API
+#[derive(Debug, Clone)]
+pub struct GeneratedImage {
+    pub base64: String,
+    pub seed: Option<u64>,
+}
+
+/// v2beta response with `Accept: application/json`
+#[derive(Debug, Deserialize)]
+pub struct StabilityImageResponse {
+    pub image: String,
+    #[serde(default)]
+    pub finish_reason: Option<String>,
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+/// v1 engine response
+#[derive(Debug, Deserialize)]
+pub struct StabilityArtifactsResponse {
+    pub artifacts: Vec<StabilityArtifact>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StabilityArtifact {
+    pub base64: String,
+    #[serde(default)]
+    pub finish_reason: Option<String>,
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+/// Reject images that were blurred by the safety filter
+fn check_finish_reason(finish_reason: Option<&str>) -> Result<(), ProviderError> {
+    match finish_reason {
+        Some("CONTENT_FILTERED") => Err(ProviderError::content_filtered(
+            "stability",
+            "Generated image was blocked by the content filter",
+            None,
+            Some(true),
+        )),
+        Some("ERROR") => Err(ProviderError::api_error(
+            "stability",
+            500,
+            "Image generation failed",
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl StabilityImageResponse {
+    pub fn into_image(self) -> Result<GeneratedImage, ProviderError> {
+        check_finish_reason(self.finish_reason.as_deref())?;
+        Ok(GeneratedImage {
+            base64: self.image,
+            seed: self.seed,
+        })
+    }
+}
+
+impl StabilityArtifactsResponse {
+    pub fn into_images(self) -> Result<Vec<GeneratedImage>, ProviderError> {
+        self.artifacts
+            .into_iter()
+            .map(|artifact| {
+                check_finish_reason(artifact.finish_reason.as_deref())?;
+                Ok(GeneratedImage {
+                    base64: artifact.base64,
+                    seed: artifact.seed,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Build the OpenAI response
+///
+/// Stability never hosts results, so `response_format: "url"` is answered
+/// with `data:` URLs.
+pub fn transform_image_output(
+    images: Vec<GeneratedImage>,
+    response_format: Option<&str>,
+    format: &str,
+) -> ImageGenerationResponse {
+    let as_url = response_format == Some("url");
+    let mime = match format {
+        "jpeg" | "jpg" => "image/jpeg",
+        "webp" => "image/webp",
+        _ => "image/png",
+    };
+
+    ImageGenerationResponse {
+        created: chrono::Utc::now().timestamp() as u64,
+        data: images
+            .into_iter()
+            .map(|image| ImageData {
+                url: as_url.then(|| format!("data:{};base64,{}", mime, image.base64)),
+                b64_json: (!as_url).then_some(image.base64),
+                revised_prompt: None,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn request(model: &str) -> ImageGenerationRequest {
+        ImageGenerationRequest {
+            prompt: "a lighthouse at dusk".to_string(),
+            model: Some(model.to_string()),
+            n: None,
+            size: None,
+            quality: None,
+            response_format: None,
+            style: None,
+            user: None,
+            extra_params: HashMap::new(),
+        }
+    }
+
+    fn field<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn test_endpoint_parse() {
+        let endpoint = StabilityEndpoint::parse("stability/sd3.5-large").unwrap();
+        assert_eq!(endpoint, StabilityEndpoint::Sd3("sd3.5-large"));
+        assert_eq!(endpoint.path(), "/v2beta/stable-image/generate/sd3");
+        assert!(endpoint.is_multipart());
+
+        assert_eq!(
+            StabilityEndpoint::parse("stable-image-ultra").unwrap(),
+            StabilityEndpoint::Ultra
+        );
+
+        let engine = StabilityEndpoint::parse("stable-diffusion-xl-1024-v1-0").unwrap();
+        assert_eq!(
+            engine.path(),
+            "/v1/generation/stable-diffusion-xl-1024-v1-0/text-to-image"
+        );
+        assert!(!engine.is_multipart());
+
+        assert!(StabilityEndpoint::parse("dall-e-3").is_err());
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(aspect_ratio(1024, 1024), "1:1");
+        assert_eq!(aspect_ratio(1792, 1024), "16:9");
+        assert_eq!(aspect_ratio(1024, 1792), "9:16");
+        assert_eq!(aspect_ratio(1216, 832), "3:2");
+    }
+
+    #[test]
+    fn test_form_fields() {
+        let mut request = request("sd3.5-large");
+        request.size = Some("1792x1024".to_string());
+        request
+            .extra_params
+            .insert("negative_prompt".to_string(), json!("blurry"));
+        request.extra_params.insert("seed".to_string(), json!(42));
+        request.extra_params.insert("steps".to_string(), json!(30));
+
+        let endpoint = StabilityEndpoint::parse("sd3.5-large").unwrap();
+        let fields = transform_form_fields(&endpoint, &request).unwrap();
+
+        assert_eq!(field(&fields, "prompt"), Some("a lighthouse at dusk"));
+        assert_eq!(field(&fields, "model"), Some("sd3.5-large"));
+        assert_eq!(field(&fields, "aspect_ratio"), Some("16:9"));
+        assert_eq!(field(&fields, "output_format"), Some("png"));
+        assert_eq!(field(&fields, "negative_prompt"), Some("blurry"));
+        assert_eq!(field(&fields, "seed"), Some("42"));
+        assert_eq!(field(&fields, "steps"), None);
+    }
+
+    #[test]
+    fn test_form_fields_explicit_aspect_ratio() {
+        let mut request = request("stable-image-core");
+        request.size = Some("1024x1024".to_string());
+        request
+            .extra_params
+            .insert("aspect_ratio".to_string(), json!("21:9"));
+
+        let fields = transform_form_fields(&StabilityEndpoint::Core, &request).unwrap();
+        let ratios: Vec<_> = fields.iter().filter(|(k, _)| k == "aspect_ratio").collect();
+        assert_eq!(ratios.len(), 1);
+        assert_eq!(field(&fields, "aspect_ratio"), Some("21:9"));
+        assert_eq!(field(&fields, "model"), None);
+    }
+
+    #[test]
+    fn test_engine_body() {
+        let mut request = request("stable-diffusion-xl-1024-v1-0");
+        request.n = Some(2);
+        request.size = Some("1152x896".to_string());
+        request
+            .extra_params
+            .insert("negative_prompt".to_string(), json!("blurry"));
+        request.extra_params.insert("steps".to_string(), json!(40));
+
+        let body = transform_engine_body(&request).unwrap();
+        assert_eq!(body["text_prompts"][0]["text"], "a lighthouse at dusk");
+        assert_eq!(body["text_prompts"][1]["text"], "blurry");
+        assert_eq!(body["text_prompts"][1]["weight"], -1.0);
+        assert_eq!(body["width"], 1152);
+        assert_eq!(body["height"], 896);
+        assert_eq!(body["samples"], 2);
+        assert_eq!(body["steps"], 40);
+        assert!(body.get("negative_prompt").is_none());
+
+        request.size = Some("big".to_string());
+        assert!(transform_engine_body(&request).is_err());
+    }
+
+    #[test]
+    fn test_responses() {
+        let v2: StabilityImageResponse = serde_json::from_value(
+            json!({"image": "aGVsbG8=", "finish_reason": "SUCCESS", "seed": 7}),
+        )
+        .unwrap();
+        let image = v2.into_image().unwrap();
+        assert_eq!(image.seed, Some(7));
+
+        let filtered: StabilityImageResponse =
+            serde_json::from_value(json!({"image": "", "finish_reason": "CONTENT_FILTERED"}))
+                .unwrap();
+        assert!(matches!(
+            filtered.into_image(),
+            Err(ProviderError::ContentFiltered { .. })
+        ));
+
+        let v1: StabilityArtifactsResponse = serde_json::from_value(json!({
+            "artifacts": [
+                {"base64": "YQ==", "seed": 1, "finishReason": "SUCCESS"},
+                {"base64": "Yg==", "seed": 2, "finishReason": "SUCCESS"}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(v1.into_images().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_image_output() {
+        let images = vec![GeneratedImage {
+            base64: "aGVsbG8=".to_string(),
+            seed: None,
+        }];
+
+        let response = transform_image_output(images.clone(), None, "png");
+        assert_eq!(response.data[0].b64_json.as_deref(), Some("aGVsbG8="));
+        assert!(response.data[0].url.is_none());
+
+        let response = transform_image_output(images, Some("url"), "jpeg");
+        assert_eq!(
+            response.data[0].url.as_deref(),
+            Some("data:image/jpeg;base64,aGVsbG8=")
+        );
+        assert!(response.data[0].b64_json.is_none());
+    }
+}
@@ -389,7 +389,8 @@ pub mod deepseek_thinking {
     /// Extract thinking usage from DeepSeek response
     pub fn extract_usage(response: &Value) -> Option<ThinkingUsage> {
         response
-            .pointer("/usage/reasoning_tokens")
+            .pointer("/usage/completion_tokens_details/reasoning_tokens")
+            .or_else(|| response.pointer("/usage/reasoning_tokens"))
             .map(|tokens| ThinkingUsage {
                 thinking_tokens: tokens.as_u64().map(|t| t as u32),
                 budget_tokens: None,
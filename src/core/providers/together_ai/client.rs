@@ -0,0 +1,177 @@
+//! Together AI Client
+//!
+//! How the Together AI API departs from OpenAI's. Native sampling options
+//! such as `top_k` or `repetition_penalty` pass through `extra_params`.
+
+use crate::core::providers::base::OpenAICompatibleClient;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::ProviderCapability;
+
+use super::TogetherAIConfig;
+
+/// Together AI API client logic
+#[derive(Debug, Clone)]
+pub struct TogetherAIClient;
+
+impl OpenAICompatibleClient for TogetherAIClient {
+    type Config = TogetherAIConfig;
+
+    const NAME: &'static str = "together_ai";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::Embeddings,
+        ProviderCapability::ToolCalling,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "frequency_penalty",
+        "presence_penalty",
+        "stop",
+        "stream",
+        "tools",
+        "tool_choice",
+        "response_format",
+        "seed",
+        "n",
+        "logprobs",
+        "logit_bias",
+    ];
+
+    fn new(_config: &TogetherAIConfig) -> Result<Self, ProviderError> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::together_ai::TogetherAIProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{
+        ChatMessage, ChatRequest, EmbeddingInput, EmbeddingRequest, MessageContent, MessageRole,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn test_provider() -> TogetherAIProvider {
+        let mut config = TogetherAIConfig::new("together_ai");
+        config.base.api_key = Some("test-key".to_string());
+        TogetherAIProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("together_ai/meta-llama/Llama-3.3-70B-Instruct-Turbo");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(256);
+        request
+            .extra_params
+            .insert("repetition_penalty".to_string(), json!(1.1));
+
+        let body = TogetherAIClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "meta-llama/Llama-3.3-70B-Instruct-Turbo");
+        assert_eq!(body["max_tokens"], 256);
+        assert!(body.get("max_completion_tokens").is_none());
+        assert_eq!(body["repetition_penalty"], 1.1);
+    }
+
+    #[test]
+    fn test_transform_embedding_response() {
+        let raw = json!({
+            "object": "list",
+            "model": "BAAI/bge-large-en-v1.5",
+            "data": [{"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}],
+            "usage": {"prompt_tokens": 4, "total_tokens": 4}
+        });
+
+        let response =
+            TogetherAIClient::transform_embedding_response(raw, "BAAI/bge-large-en-v1.5").unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(response.usage.unwrap().prompt_tokens, 4);
+    }
+
+    #[test]
+    fn test_transform_embedding_request() {
+        let request = EmbeddingRequest {
+            model: "together_ai/BAAI/bge-large-en-v1.5".to_string(),
+            input: EmbeddingInput::Array(vec!["a".to_string(), "b".to_string()]),
+            user: None,
+            encoding_format: None,
+            dimensions: None,
+            task_type: Some("RETRIEVAL_QUERY".to_string()),
+            extra_params: HashMap::new(),
+        };
+
+        let body = TogetherAIClient::transform_embedding_request(&request).unwrap();
+        assert_eq!(body["model"], "BAAI/bge-large-en-v1.5");
+        assert_eq!(body["input"][1], "b");
+        assert!(body.get("task_type").is_none());
+        assert!(body.get("input_type").is_none());
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = TogetherAIConfig::new("together_ai");
+        config.base.api_key = None;
+        assert!(TogetherAIProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "together_ai");
+        assert!(
+            provider
+                .capabilities()
+                .contains(&ProviderCapability::Embeddings)
+        );
+        assert!(provider.supports_model("together_ai/meta-llama/Llama-3.3-70B-Instruct-Turbo"));
+        assert!(!provider.supports_model("gpt-4"));
+    }
+
+    #[test]
+    fn test_supported_models() {
+        let models = test_provider().models().to_vec();
+        assert!(
+            models
+                .iter()
+                .any(|m| m.id == "meta-llama/Llama-3.3-70B-Instruct-Turbo")
+        );
+        assert!(models.iter().all(|m| m.provider == "together_ai"));
+        assert!(
+            models
+                .iter()
+                .any(|m| m.capabilities.contains(&ProviderCapability::Embeddings))
+        );
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://api.together.xyz/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("meta-llama/Llama-3.3-70B-Instruct-Turbo", 1_000_000, 0)
+            .await
+            .unwrap();
+        assert!((cost - 0.88).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,85 @@
+//! Together AI Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(TogetherAIConfig {});
+
+impl TogetherAIConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `TOGETHER_AI_*`, falling back to the `TOGETHER_API_KEY` /
+    /// `TOGETHERAI_API_KEY` names used by Together's own SDKs.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("together_ai");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("TOGETHER_API_KEY")
+                .or_else(|_| std::env::var("TOGETHERAI_API_KEY"))
+                .ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for TogetherAIConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("together_ai")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_together_ai_config_default_api_base() {
+        let config = TogetherAIConfig::new("together_ai");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.together.xyz/v1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_together_ai_validate_missing_api_key() {
+        let mut config = TogetherAIConfig::new("together_ai");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_together_ai_validate_success() {
+        let mut config = TogetherAIConfig::new("together_ai");
+        config.base.api_key = Some("test-key".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_provider_config_trait() {
+        let mut config = TogetherAIConfig::new("together_ai");
+        config.base.api_key = Some("test-key".to_string());
+
+        assert_eq!(config.api_key(), Some("test-key"));
+        assert_eq!(config.api_base(), Some("https://api.together.xyz/v1"));
+        assert_eq!(config.timeout(), std::time::Duration::from_secs(60));
+        assert_eq!(config.max_retries(), 3);
+    }
+}
@@ -0,0 +1,14 @@
+//! Together AI Provider
+//!
+//! OpenAI-compatible chat, streaming and embeddings for models hosted on api.together.xyz
+
+pub mod client;
+pub mod config;
+
+pub use client::TogetherAIClient;
+pub use config::TogetherAIConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// Together AI provider
+pub type TogetherAIProvider = OpenAICompatibleProvider<TogetherAIClient>;
@@ -259,6 +259,7 @@ impl V0ChatHandler {
                     tool_calls: None,
                     tool_call_id: None,
                     function_call: None,
+                    cache_control: None,
                 },
                 finish_reason: choice
                     .finish_reason
@@ -281,6 +282,9 @@ impl V0ChatHandler {
             prompt_tokens_details: None,
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         });
 
         Ok(ChatResponse {
@@ -291,6 +295,7 @@ impl V0ChatHandler {
             system_fingerprint: v0_response.system_fingerprint,
             choices,
             usage,
+            provider_specific_fields: None,
         })
     }
 
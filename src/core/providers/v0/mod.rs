@@ -420,6 +420,7 @@ impl LLMProvider for V0Provider {
                 .map_err(V0Error::JsonError)?,
             usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         Ok(chat_response)
@@ -330,11 +330,13 @@ fn parse_default_batch_response(
                 tool_calls: None,
                 function_call: None,
                 tool_call_id: None,
+                cache_control: None,
             },
             finish_reason: Some(FinishReason::Stop),
             logprobs: None,
         }],
         usage: None,
         system_fingerprint: None,
+        provider_specific_fields: None,
     })
 }
@@ -21,7 +21,11 @@ use std::collections::HashMap;
 use super::{
     VertexAIProviderConfig,
     auth::VertexAuth,
+    context_caching::{CachedContent, ContextCachingHandler, DEFAULT_CACHE_TTL_SECONDS},
+    embeddings::{EmbeddingHandler, parse_embedding_model},
     error::VertexAIError,
+    fine_tuning::FineTuningHandler,
+    image_generation::{self, ImageGenerationHandler, parse_image_model},
     models::VertexAIModel,
     transformers::{GeminiTransformer, PartnerModelTransformer},
 };
@@ -123,6 +127,55 @@ impl VertexAIProvider {
         })
     }
 
+    /// Get a handler for the project's tuning jobs
+    pub fn fine_tuning(&self) -> FineTuningHandler {
+        FineTuningHandler::new(
+            self.config.project_id.clone(),
+            self.config.location.clone(),
+            self.auth.clone(),
+            self.http_client.clone(),
+        )
+    }
+
+    /// Context caching handler sharing this provider's credentials
+    pub fn context_caching(&self) -> ContextCachingHandler {
+        ContextCachingHandler::new(
+            self.config.project_id.clone(),
+            self.config.location.clone(),
+            self.auth.clone(),
+            self.http_client.clone(),
+        )
+    }
+
+    /// Cache the messages, system prompt and tools of a Gemini chat request
+    ///
+    /// Later requests pass the returned cache's name as `cached_content` and
+    /// send only the new messages.
+    pub async fn create_cached_content(
+        &self,
+        request: &ChatRequest,
+        ttl_seconds: Option<u64>,
+    ) -> Result<CachedContent, VertexAIError> {
+        let model = super::parse_vertex_model(&request.model);
+        if !model.is_gemini() {
+            return Err(VertexAIError::UnsupportedFeature(format!(
+                "Context caching is not supported for {}",
+                request.model
+            )));
+        }
+
+        let body = self
+            .gemini_transformer
+            .transform_chat_request(request, &model)?;
+        let cache = CachedContent::from_request_body(
+            model.model_id(),
+            &body,
+            ttl_seconds.unwrap_or(DEFAULT_CACHE_TTL_SECONDS),
+        );
+
+        self.context_caching().create(cache).await
+    }
+
     /// Build the API URL for a given model and endpoint
     fn build_url(&self, model: &VertexAIModel, endpoint: &str, stream: bool) -> String {
         let model_id = model.model_id();
@@ -238,9 +291,18 @@ impl VertexAIProvider {
                 "generateContent"
             };
 
-            let body = self
+            let mut body = self
                 .gemini_transformer
                 .transform_chat_request(&request, &model)?;
+            if let Some(cache_id) = request
+                .extra_params
+                .get("cached_content")
+                .and_then(|v| v.as_str())
+            {
+                body = self
+                    .context_caching()
+                    .transform_with_cache(body, cache_id)?;
+            }
             (endpoint, body)
         } else if model.is_partner_model() {
             // Partner models use different endpoints
@@ -285,72 +347,33 @@ impl VertexAIProvider {
             "text-embedding-004".to_string() // Default embedding model
         };
 
-        let endpoint = "predict";
+        let handler = EmbeddingHandler::new(parse_embedding_model(&model_name));
+        self.predict_embeddings(&handler, &request).await
+    }
+
+    /// Call the `predict` endpoint of the handler's embedding model
+    pub async fn predict_embeddings(
+        &self,
+        handler: &EmbeddingHandler,
+        request: &EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, VertexAIError> {
         let url = format!(
-            "https://{}-aiplatform.googleapis.com/v1/projects/{}/locations/{}/publishers/google/models/{}:{}",
+            "https://{}-aiplatform.googleapis.com/{}/projects/{}/locations/{}/publishers/google/models/{}:predict",
             self.config.location,
+            self.config.api_version,
             self.config.project_id,
             self.config.location,
-            model_name,
-            endpoint
+            handler.model().model_id()
         );
 
-        // Build request body
-        let instances: Vec<Value> = request
-            .input
-            .iter()
-            .map(|text| {
-                serde_json::json!({
-                    "content": text,
-                    "task_type": "RETRIEVAL_DOCUMENT"
-                })
-            })
-            .collect();
-
-        let body = serde_json::json!({
-            "instances": instances
-        });
-
+        let body = handler.transform_request(request)?;
         let response = self.make_request(&url, body).await?;
         let response_body: Value = response
             .json()
             .await
             .map_err(|e| VertexAIError::ResponseParsing(e.to_string()))?;
 
-        // Parse embeddings from response
-        let predictions = response_body["predictions"]
-            .as_array()
-            .ok_or_else(|| VertexAIError::ResponseParsing("Missing predictions".to_string()))?;
-
-        let embeddings = predictions
-            .iter()
-            .enumerate()
-            .map(|(index, pred)| {
-                let values = pred["embeddings"]["values"]
-                    .as_array()
-                    .ok_or_else(|| {
-                        VertexAIError::ResponseParsing("Missing embedding values".to_string())
-                    })?
-                    .iter()
-                    .map(|v| v.as_f64().unwrap_or(0.0) as f32)
-                    .collect();
-
-                Ok(crate::core::types::responses::EmbeddingData {
-                    object: "embedding".to_string(),
-                    index: index as u32,
-                    embedding: values,
-                })
-            })
-            .collect::<Result<Vec<crate::core::types::responses::EmbeddingData>, VertexAIError>>(
-            )?;
-
-        Ok(EmbeddingResponse {
-            object: "list".to_string(),
-            data: embeddings.clone(),
-            model: model_name,
-            usage: None, // Vertex AI doesn't return token usage for embeddings
-            embeddings: Some(embeddings), // Backward compatibility field
-        })
+        handler.transform_response(response_body)
     }
 
     /// Count tokens for a request
@@ -475,49 +498,31 @@ impl LLMProvider for VertexAIProvider {
         request: ImageGenerationRequest,
         _context: RequestContext,
     ) -> Result<ImageGenerationResponse, Self::Error> {
-        // Use Imagen model for image generation
-        let endpoint = "predict";
-        let model = "imagegeneration@006";
-
-        let url = format!(
-            "https://{}-aiplatform.googleapis.com/v1/projects/{}/locations/{}/publishers/google/models/{}:{}",
-            self.config.location, self.config.project_id, self.config.location, model, endpoint
+        let model = parse_image_model(
+            request
+                .model
+                .as_deref()
+                .unwrap_or("imagen-3.0-generate-002"),
         );
 
-        let body = serde_json::json!({
-            "instances": [{
-                "prompt": request.prompt
-            }],
-            "parameters": {
-                "sampleCount": request.n.unwrap_or(1),
-                "aspectRatio": request.size.as_deref().unwrap_or("1:1"),
-            }
-        });
+        let handler = ImageGenerationHandler::new(
+            self.config.project_id.clone(),
+            self.config.location.clone(),
+        );
+        let body = handler.build_request(
+            &model,
+            image_generation::ImageGenerationRequest::from_openai(&request),
+        )?;
 
-        let response = self.make_request(&url, body).await?;
-        let response_body: Value = response
+        let response = self
+            .make_request(&handler.predict_url(&model), body)
+            .await?;
+        let response_body: image_generation::ImageGenerationResponse = response
             .json()
             .await
             .map_err(|e| VertexAIError::ResponseParsing(e.to_string()))?;
 
-        let predictions = response_body["predictions"]
-            .as_array()
-            .ok_or_else(|| VertexAIError::ResponseParsing("Missing predictions".to_string()))?;
-
-        let image_data = predictions
-            .iter()
-            .filter_map(|pred| pred["bytesBase64Encoded"].as_str())
-            .map(|s| crate::core::types::responses::ImageData {
-                url: None,
-                b64_json: Some(s.to_string()),
-                revised_prompt: None,
-            })
-            .collect();
-
-        Ok(ImageGenerationResponse {
-            created: chrono::Utc::now().timestamp() as u64,
-            data: image_data,
-        })
+        handler.transform_response(response_body)
     }
 
     async fn health_check(&self) -> HealthStatus {
@@ -566,6 +571,7 @@ impl LLMProvider for VertexAIProvider {
                 "response_format",
                 "user",
                 "top_k",
+                "cached_content",
             ]
         } else {
             // Partner models have limited OpenAI compatibility
@@ -787,6 +793,9 @@ impl LLMProvider for VertexAIProvider {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }
         });
 
@@ -805,6 +814,7 @@ impl LLMProvider for VertexAIProvider {
                     tool_calls: None, // Handle
                     tool_call_id: None,
                     function_call: None,
+                    cache_control: None,
                 },
                 finish_reason: candidate
                     .get("finishReason")
@@ -821,6 +831,7 @@ impl LLMProvider for VertexAIProvider {
             }],
             usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 
@@ -1,150 +1,373 @@
 //! Vertex AI Context Caching Module
 //!
-//! Support for caching contexts to reduce costs and improve performance
+//! Support for caching large prompt prefixes with the `cachedContents` API.
+//! A cache holds contents, a system instruction and tools; chat requests
+//! reference it through `cached_content` and are billed at the cached input
+//! rate for those tokens.
 
-use super::error::VertexAIError;
+use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::debug;
 
-/// Context cache entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContextCacheEntry {
-    pub cache_id: String,
-    pub model: String,
-    pub content: serde_json::Value,
-    pub ttl_seconds: u64,
-    pub created_at: i64,
-}
+use super::auth::VertexAuth;
+use super::error::VertexAIError;
 
-/// Context caching configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContextCachingConfig {
-    pub enabled: bool,
-    pub max_cache_size: usize,
-    pub default_ttl: u64,
-    pub max_context_length: usize,
+/// Default cache lifetime, in seconds
+pub const DEFAULT_CACHE_TTL_SECONDS: u64 = 3600;
+
+/// Cached content resource
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedContent {
+    /// Resource name, `projects/{project}/locations/{location}/cachedContents/{id}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Full model resource name the cache was created for
+    #[serde(default)]
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contents: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_instruction: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Value>,
+    /// Time to live, e.g. "3600s"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_metadata: Option<CachedContentUsage>,
 }
 
-impl Default for ContextCachingConfig {
-    fn default() -> Self {
+impl CachedContent {
+    /// Build a cache from a transformed Gemini `generateContent` body
+    ///
+    /// Keeps the parts of the body a cache can hold: `contents`,
+    /// `systemInstruction` and `tools`.
+    pub fn from_request_body(model: String, body: &Value, ttl_seconds: u64) -> Self {
         Self {
-            enabled: true,
-            max_cache_size: 1000,
-            default_ttl: 3600, // 1 hour
-            max_context_length: 32768,
+            model,
+            contents: body["contents"].as_array().cloned().unwrap_or_default(),
+            system_instruction: body.get("systemInstruction").cloned(),
+            tools: body.get("tools").cloned(),
+            ttl: Some(format!("{}s", ttl_seconds)),
+            ..Default::default()
         }
     }
+
+    /// Cache ID, the last segment of the resource name
+    pub fn cache_id(&self) -> Option<&str> {
+        self.name
+            .as_deref()
+            .and_then(|name| name.rsplit('/').next())
+    }
+}
+
+/// Token usage of a cache
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedContentUsage {
+    #[serde(default)]
+    pub total_token_count: u32,
+}
+
+/// Page of cached contents
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCachedContentsResponse {
+    #[serde(default)]
+    pub cached_contents: Vec<CachedContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
 }
 
 /// Context caching handler
+#[derive(Debug, Clone)]
 pub struct ContextCachingHandler {
-    config: ContextCachingConfig,
+    project_id: String,
+    location: String,
+    auth: Arc<VertexAuth>,
+    http_client: Client,
 }
 
 impl ContextCachingHandler {
     /// Create new context caching handler
-    pub fn new(config: ContextCachingConfig) -> Self {
-        Self { config }
+    pub fn new(
+        project_id: String,
+        location: String,
+        auth: Arc<VertexAuth>,
+        http_client: Client,
+    ) -> Self {
+        Self {
+            project_id,
+            location,
+            auth,
+            http_client,
+        }
+    }
+
+    /// Regional API host
+    fn host(&self) -> String {
+        if self.location == "global" {
+            "https://aiplatform.googleapis.com".to_string()
+        } else {
+            format!("https://{}-aiplatform.googleapis.com", self.location)
+        }
+    }
+
+    /// URL of the project's `cachedContents` collection
+    fn caches_url(&self) -> String {
+        format!(
+            "{}/v1/projects/{}/locations/{}/cachedContents",
+            self.host(),
+            self.project_id,
+            self.location
+        )
     }
 
-    /// Cache context for reuse
-    pub async fn cache_context(
+    /// Full resource name of a cache, given its ID or resource name
+    pub fn cached_content_name(&self, cache_id: &str) -> Result<String, VertexAIError> {
+        if cache_id.starts_with("projects/") {
+            return Ok(cache_id.to_string());
+        }
+
+        if cache_id.is_empty()
+            || !cache_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(VertexAIError::InvalidRequest(format!(
+                "Invalid cached content ID: {}",
+                cache_id
+            )));
+        }
+
+        Ok(format!(
+            "projects/{}/locations/{}/cachedContents/{}",
+            self.project_id, self.location, cache_id
+        ))
+    }
+
+    /// Full resource name of a Google publisher model
+    pub fn model_name(&self, model_id: &str) -> String {
+        if model_id.starts_with("projects/") {
+            return model_id.to_string();
+        }
+
+        format!(
+            "projects/{}/locations/{}/publishers/google/models/{}",
+            self.project_id, self.location, model_id
+        )
+    }
+
+    /// Send an authenticated request, returning the successful response body
+    async fn send(
         &self,
-        model: &str,
-        content: serde_json::Value,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<Value>,
     ) -> Result<String, VertexAIError> {
-        if !self.config.enabled {
-            return Err(VertexAIError::FeatureDisabled(
-                "context_caching".to_string(),
-            ));
+        let token = self
+            .auth
+            .get_access_token()
+            .await
+            .map_err(|e| VertexAIError::Authentication(e.to_string()))?;
+
+        debug!("Making request to Vertex AI: {} {}", method, url);
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", token));
+        if let Some(body) = body {
+            request = request.json(&body);
         }
 
-        let cache_id = uuid::Uuid::new_v4().to_string();
-        let _entry = ContextCacheEntry {
-            cache_id: cache_id.clone(),
-            model: model.to_string(),
-            content,
-            ttl_seconds: self.config.default_ttl,
-            created_at: chrono::Utc::now().timestamp(),
-        };
+        let response = request
+            .send()
+            .await
+            .map_err(|e| VertexAIError::Network(e.to_string()))?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| VertexAIError::Network(e.to_string()))?;
 
-        // TODO: Implement actual caching storage
-        Ok(cache_id)
+        if !status.is_success() {
+            return Err(VertexAIError::ApiError {
+                status_code: status.as_u16(),
+                message: text,
+            });
+        }
+
+        Ok(text)
     }
 
-    /// Retrieve cached context
-    pub async fn get_cached_context(
-        &self,
-        _cache_id: &str,
-    ) -> Result<Option<ContextCacheEntry>, VertexAIError> {
-        // TODO: Implement cache retrieval
-        Ok(None)
+    /// Create a cache
+    pub async fn create(&self, mut cache: CachedContent) -> Result<CachedContent, VertexAIError> {
+        if cache.contents.is_empty() && cache.system_instruction.is_none() {
+            return Err(VertexAIError::InvalidRequest(
+                "Cached content needs contents or a system instruction".to_string(),
+            ));
+        }
+        cache.model = self.model_name(&cache.model);
+
+        let body = self
+            .send(
+                reqwest::Method::POST,
+                &self.caches_url(),
+                Some(serde_json::to_value(&cache)?),
+            )
+            .await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Get a cache
+    pub async fn get(&self, cache_id: &str) -> Result<CachedContent, VertexAIError> {
+        let url = format!("{}/v1/{}", self.host(), self.cached_content_name(cache_id)?);
+        let body = self.send(reqwest::Method::GET, &url, None).await?;
+        Ok(serde_json::from_str(&body)?)
     }
 
-    /// Check if context can be cached
-    pub fn can_cache_context(&self, content: &serde_json::Value) -> bool {
-        if !self.config.enabled {
-            return false;
+    /// List the project's caches
+    pub async fn list(
+        &self,
+        page_size: Option<u32>,
+        page_token: Option<&str>,
+    ) -> Result<ListCachedContentsResponse, VertexAIError> {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(page_size) = page_size {
+            query.append_pair("pageSize", &page_size.to_string());
+        }
+        if let Some(page_token) = page_token {
+            query.append_pair("pageToken", page_token);
         }
+        let query = query.finish();
+
+        let url = if query.is_empty() {
+            self.caches_url()
+        } else {
+            format!("{}?{}", self.caches_url(), query)
+        };
+
+        let body = self.send(reqwest::Method::GET, &url, None).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
 
-        // Estimate context length
-        let content_str = content.to_string();
-        content_str.len() <= self.config.max_context_length
+    /// Delete a cache
+    pub async fn delete(&self, cache_id: &str) -> Result<(), VertexAIError> {
+        let url = format!("{}/v1/{}", self.host(), self.cached_content_name(cache_id)?);
+        self.send(reqwest::Method::DELETE, &url, None).await?;
+        Ok(())
     }
 
-    /// Transform request to use cached context
+    /// Point a Gemini `generateContent` body at a cache
+    ///
+    /// The cache already carries the system instruction and tools, which the
+    /// API rejects when repeated alongside `cachedContent`.
     pub fn transform_with_cache(
         &self,
-        request: serde_json::Value,
+        request: Value,
         cache_id: &str,
-    ) -> Result<serde_json::Value, VertexAIError> {
+    ) -> Result<Value, VertexAIError> {
         let mut transformed = request;
 
-        // Add cache reference to request
         if let Some(obj) = transformed.as_object_mut() {
+            obj.remove("systemInstruction");
+            obj.remove("tools");
+            obj.remove("toolConfig");
             obj.insert(
                 "cachedContent".to_string(),
-                serde_json::json!({
-                    "name": cache_id
-                }),
+                Value::String(self.cached_content_name(cache_id)?),
             );
         }
 
         Ok(transformed)
     }
-
-    /// Clean expired cache entries
-    pub async fn cleanup_expired_cache(&self) -> Result<usize, VertexAIError> {
-        // TODO: Implement cache cleanup
-        Ok(0)
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::providers::vertex_ai::VertexCredentials;
 
-    #[test]
-    fn test_can_cache_context() {
-        let config = ContextCachingConfig::default();
-        let handler = ContextCachingHandler::new(config);
-
-        let small_content = serde_json::json!({"text": "Hello world"});
-        assert!(handler.can_cache_context(&small_content));
+    fn handler() -> ContextCachingHandler {
+        ContextCachingHandler::new(
+            "my-project".to_string(),
+            "us-central1".to_string(),
+            Arc::new(VertexAuth::new(VertexCredentials::AccessToken(
+                "token".to_string(),
+            ))),
+            Client::new(),
+        )
+    }
 
-        let large_content = serde_json::json!({"text": "x".repeat(50000)});
-        assert!(!handler.can_cache_context(&large_content));
+    #[test]
+    fn test_cached_content_name() {
+        let handler = handler();
+        assert_eq!(
+            handler.cached_content_name("123456").unwrap(),
+            "projects/my-project/locations/us-central1/cachedContents/123456"
+        );
+        assert_eq!(
+            handler
+                .cached_content_name("projects/p/locations/l/cachedContents/1")
+                .unwrap(),
+            "projects/p/locations/l/cachedContents/1"
+        );
+        assert!(handler.cached_content_name("../other").is_err());
     }
 
     #[test]
     fn test_transform_with_cache() {
-        let config = ContextCachingConfig::default();
-        let handler = ContextCachingHandler::new(config);
+        let handler = handler();
 
         let request = serde_json::json!({
-            "messages": [{"role": "user", "content": "Hello"}]
+            "contents": [{"role": "user", "parts": [{"text": "Hello"}]}],
+            "systemInstruction": {"parts": [{"text": "Be brief"}]},
         });
 
         let result = handler.transform_with_cache(request, "cache-123").unwrap();
-        assert!(result.get("cachedContent").is_some());
+        assert_eq!(
+            result["cachedContent"],
+            "projects/my-project/locations/us-central1/cachedContents/cache-123"
+        );
+        assert!(result.get("systemInstruction").is_none());
+        assert!(result.get("contents").is_some());
+    }
+
+    #[test]
+    fn test_cached_content_from_request_body() {
+        let body = serde_json::json!({
+            "contents": [{"role": "user", "parts": [{"text": "A long document"}]}],
+            "systemInstruction": {"parts": [{"text": "Answer from the document"}]},
+            "generationConfig": {"temperature": 0.2},
+        });
+
+        let cache = CachedContent::from_request_body("gemini-1.5-pro-002".to_string(), &body, 600);
+        let value = serde_json::to_value(&cache).unwrap();
+        assert_eq!(value["ttl"], "600s");
+        assert_eq!(value["contents"].as_array().unwrap().len(), 1);
+        assert!(value.get("systemInstruction").is_some());
+        assert!(value.get("generationConfig").is_none());
+
+        let created: CachedContent = serde_json::from_value(serde_json::json!({
+            "name": "projects/p/locations/l/cachedContents/789",
+            "model": "projects/p/locations/l/publishers/google/models/gemini-1.5-pro-002",
+            "usageMetadata": {"totalTokenCount": 40000},
+        }))
+        .unwrap();
+        assert_eq!(created.cache_id(), Some("789"));
+        assert_eq!(created.usage_metadata.unwrap().total_token_count, 40000);
     }
 }
@@ -2,10 +2,12 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use std::collections::HashMap;
 
+use crate::core::providers::vertex_ai::VertexAIProvider;
 use crate::core::types::{
     requests::{EmbeddingInput, EmbeddingRequest},
-    responses::{EmbeddingData, EmbeddingResponse},
+    responses::{EmbeddingData, EmbeddingResponse, Usage},
 };
 
 /// Vertex AI embedding models
@@ -150,6 +152,7 @@ pub struct MultimodalEmbeddingInstance {
 
 /// Image data for multimodal embeddings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ImageData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bytes_base64_encoded: Option<String>,
@@ -161,6 +164,7 @@ pub struct ImageData {
 
 /// Video data for multimodal embeddings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gcs_uri: Option<String>,
@@ -174,6 +178,7 @@ pub struct VideoData {
 
 /// Embedding parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EmbeddingParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_truncate: Option<bool>,
@@ -182,6 +187,7 @@ pub struct EmbeddingParameters {
 }
 
 /// Embedding handler
+#[derive(Debug, Clone)]
 pub struct EmbeddingHandler {
     model: VertexEmbeddingModel,
 }
@@ -192,6 +198,11 @@ impl EmbeddingHandler {
         Self { model }
     }
 
+    /// Model the handler embeds with
+    pub fn model(&self) -> &VertexEmbeddingModel {
+        &self.model
+    }
+
     /// Transform embedding request to Vertex AI format
     pub fn transform_request(
         &self,
@@ -354,21 +365,25 @@ impl EmbeddingHandler {
         })?;
 
         let mut embeddings = Vec::new();
+        let mut token_count = 0u64;
 
         for prediction in predictions {
             let values =
                 if let Some(embedding_values) = prediction["embeddings"]["values"].as_array() {
                     // Standard embedding format
+                    token_count += prediction["embeddings"]["statistics"]["token_count"]
+                        .as_f64()
+                        .unwrap_or(0.0) as u64;
                     embedding_values
-                        .iter()
-                        .filter_map(|v| v.as_f64().map(|f| f as f32))
-                        .collect()
                 } else if let Some(values) = prediction["values"].as_array() {
                     // Alternative format
                     values
-                        .iter()
-                        .filter_map(|v| v.as_f64().map(|f| f as f32))
-                        .collect()
+                } else if let Some(values) = prediction["textEmbedding"]
+                    .as_array()
+                    .or_else(|| prediction["imageEmbedding"].as_array())
+                {
+                    // Multimodal embedding format
+                    values
                 } else {
                     return Err(
                         crate::core::providers::vertex_ai::error::VertexAIError::ResponseParsing(
@@ -377,7 +392,12 @@ impl EmbeddingHandler {
                     );
                 };
 
-            embeddings.push(values);
+            embeddings.push(
+                values
+                    .iter()
+                    .filter_map(|v| v.as_f64().map(|f| f as f32))
+                    .collect::<Vec<f32>>(),
+            );
         }
 
         let embedding_data: Vec<EmbeddingData> = embeddings
@@ -395,9 +415,26 @@ impl EmbeddingHandler {
             data: embedding_data.clone(),
             embeddings: Some(embedding_data),
             model: self.model.model_id(),
-            usage: None, // Vertex AI doesn't return token usage for embeddings
+            usage: Self::usage(token_count, &response["metadata"]),
         })
     }
+
+    /// Usage from the per-prediction token counts and the billed characters
+    ///
+    /// Text embedding models are billed per input character, which Vertex
+    /// reports as `metadata.billableCharacterCount`.
+    fn usage(token_count: u64, metadata: &Value) -> Option<Usage> {
+        let billable_characters = metadata["billableCharacterCount"]
+            .as_u64()
+            .map(|count| count as u32);
+        if token_count == 0 && billable_characters.is_none() {
+            return None;
+        }
+
+        let mut usage = Usage::new(token_count as u32, 0);
+        usage.billable_characters = billable_characters;
+        Some(usage)
+    }
 }
 
 /// Batch embedding handler for processing large numbers of texts
@@ -413,11 +450,15 @@ impl BatchEmbeddingHandler {
     }
 
     /// Process embeddings in batches
+    ///
+    /// Each batch is a separate predict call; the returned usage is the sum
+    /// over all of them.
     pub async fn process_batch(
         &self,
+        provider: &VertexAIProvider,
         inputs: Vec<String>,
-        _task_type: Option<String>,
-    ) -> Result<Vec<Vec<f32>>, crate::core::providers::vertex_ai::error::VertexAIError> {
+        task_type: Option<String>,
+    ) -> Result<EmbeddingResponse, crate::core::providers::vertex_ai::error::VertexAIError> {
         if !self.model.supports_batch() {
             return Err(
                 crate::core::providers::vertex_ai::error::VertexAIError::UnsupportedFeature(
@@ -429,29 +470,108 @@ impl BatchEmbeddingHandler {
             );
         }
 
-        let mut all_embeddings = Vec::new();
+        let handler = EmbeddingHandler::new(self.model.clone());
+        let mut data = Vec::with_capacity(inputs.len());
+        let mut usage: Option<Usage> = None;
 
         // Process in batches
-        for chunk in inputs.chunks(self.batch_size) {
+        for chunk in inputs.chunks(self.batch_size.max(1)) {
             let request = EmbeddingRequest {
                 model: self.model.model_id(),
                 input: crate::core::types::requests::EmbeddingInput::Array(chunk.to_vec()),
                 encoding_format: None,
                 dimensions: None,
                 user: None,
-                task_type: Some("RETRIEVAL_DOCUMENT".to_string()), // Default
+                task_type: Some(
+                    task_type
+                        .clone()
+                        .unwrap_or_else(|| "RETRIEVAL_DOCUMENT".to_string()),
+                ),
+                extra_params: HashMap::new(),
             };
 
-            let handler = EmbeddingHandler::new(self.model.clone());
-            let _vertex_request = handler.transform_request(&request)?;
+            let response = provider.predict_embeddings(&handler, &request).await?;
+
+            for mut embedding in response.data {
+                embedding.index = data.len() as u32;
+                data.push(embedding);
+            }
 
-            // TODO: Make actual API call
-            // For now, return dummy embeddings
-            for _ in chunk {
-                all_embeddings.push(vec![0.0; self.model.dimensions()]);
+            if let Some(batch_usage) = response.usage {
+                let total = usage.get_or_insert_with(|| Usage::new(0, 0));
+                total.prompt_tokens += batch_usage.prompt_tokens;
+                total.total_tokens += batch_usage.total_tokens;
+                if let Some(characters) = batch_usage.billable_characters {
+                    *total.billable_characters.get_or_insert(0) += characters;
+                }
             }
         }
 
-        Ok(all_embeddings)
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data: data.clone(),
+            embeddings: Some(data),
+            model: self.model.model_id(),
+            usage,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_request() {
+        let handler = EmbeddingHandler::new(VertexEmbeddingModel::TextEmbedding004);
+        let request = EmbeddingRequest {
+            model: "text-embedding-004".to_string(),
+            input: EmbeddingInput::Text("hello".to_string()),
+            encoding_format: None,
+            dimensions: Some(256),
+            user: None,
+            task_type: Some("retrieval_query".to_string()),
+            extra_params: HashMap::new(),
+        };
+
+        let body = handler.transform_request(&request).unwrap();
+        assert_eq!(body["instances"][0]["content"], "hello");
+        assert_eq!(body["instances"][0]["task_type"], "RETRIEVAL_QUERY");
+        assert_eq!(body["parameters"]["autoTruncate"], true);
+        assert_eq!(body["parameters"]["outputDimensionality"], 256);
+    }
+
+    #[test]
+    fn test_transform_response_usage() {
+        let handler = EmbeddingHandler::new(VertexEmbeddingModel::TextEmbedding004);
+        let response = json!({
+            "predictions": [
+                {"embeddings": {"statistics": {"truncated": false, "token_count": 4}, "values": [0.1, 0.2]}},
+                {"embeddings": {"statistics": {"truncated": false, "token_count": 6}, "values": [0.3, 0.4]}}
+            ],
+            "metadata": {"billableCharacterCount": 42}
+        });
+
+        let response = handler.transform_response(response).unwrap();
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].index, 1);
+        assert_eq!(response.data[1].embedding, vec![0.3, 0.4]);
+
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.prompt_tokens, 10);
+        assert_eq!(usage.total_tokens, 10);
+        assert_eq!(usage.billable_characters, Some(42));
+    }
+
+    #[test]
+    fn test_transform_multimodal_response() {
+        let handler = EmbeddingHandler::new(VertexEmbeddingModel::MultimodalEmbedding);
+        let response = json!({
+            "predictions": [{"textEmbedding": [0.5, 0.25]}]
+        });
+
+        let response = handler.transform_response(response).unwrap();
+        assert_eq!(response.data[0].embedding, vec![0.5, 0.25]);
+        assert!(response.usage.is_none());
     }
 }
@@ -1,33 +1,55 @@
 //! Vertex AI Fine-tuning Module
+//!
+//! Supervised tuning jobs through the Vertex `tuningJobs` REST API
 
-use serde::{Deserialize, Serialize};
+use reqwest::Client;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::debug;
 
+use super::auth::VertexAuth;
 use super::error::VertexAIError;
+use crate::core::fine_tuning::types as shared;
 
-/// Fine-tuning job
+/// Fine-tuning job (a Vertex `TuningJob` resource)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FineTuningJob {
+    /// Resource name: `projects/{project}/locations/{location}/tuningJobs/{id}`
     pub name: String,
-    pub display_name: String,
+    #[serde(default)]
     pub base_model: String,
+    #[serde(default)]
     pub state: FineTuningState,
+    #[serde(default)]
     pub create_time: String,
     pub start_time: Option<String>,
     pub end_time: Option<String>,
-    pub update_time: String,
-    pub tuned_model: Option<String>,
-    pub training_task: TrainingTask,
+    pub update_time: Option<String>,
+    pub description: Option<String>,
+    pub tuned_model_display_name: Option<String>,
+    pub supervised_tuning_spec: Option<SupervisedTuningSpec>,
+    pub tuned_model: Option<TunedModel>,
+    pub tuning_data_stats: Option<TuningDataStats>,
     pub error: Option<FineTuningError>,
+    #[serde(default)]
     pub labels: HashMap<String, String>,
     pub experiment: Option<String>,
-    pub tuned_model_display_name: Option<String>,
+}
+
+impl FineTuningJob {
+    /// Job ID, the last segment of the resource name
+    pub fn job_id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap_or(&self.name)
+    }
 }
 
 /// Fine-tuning job state
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum FineTuningState {
+    #[default]
     #[serde(rename = "JOB_STATE_UNSPECIFIED")]
     Unspecified,
     #[serde(rename = "JOB_STATE_QUEUED")]
@@ -50,35 +72,40 @@ pub enum FineTuningState {
     Expired,
 }
 
-/// Training task configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TrainingTask {
-    pub inputs: TrainingInputs,
-    pub training_steps: Option<i64>,
-    pub learning_rate_multiplier: Option<f32>,
-    pub adapter_size: Option<AdapterSize>,
-    pub tuning_data_stats: Option<TuningDataStats>,
-}
-
-/// Training inputs
+/// Model produced by a finished job
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TrainingInputs {
-    pub supervised_tuning_spec: SupervisedTuningSpec,
+#[serde(rename_all = "camelCase")]
+pub struct TunedModel {
+    /// Resource name of the tuned model
+    pub model: String,
+    /// Endpoint the tuned model is deployed to
+    pub endpoint: Option<String>,
 }
 
 /// Supervised tuning specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SupervisedTuningSpec {
     pub training_dataset_uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub validation_dataset_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hyper_parameters: Option<SupervisedHyperParameters>,
 }
 
 /// Supervised tuning hyperparameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SupervisedHyperParameters {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_int64"
+    )]
     pub epoch_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub learning_rate_multiplier: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub adapter_size: Option<AdapterSize>,
 }
 
@@ -101,53 +128,57 @@ pub enum AdapterSize {
 
 /// Tuning data statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TuningDataStats {
     pub supervised_tuning_data_stats: Option<SupervisedTuningDataStats>,
 }
 
 /// Supervised tuning data statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SupervisedTuningDataStats {
+    #[serde(default, deserialize_with = "deserialize_int64")]
     pub tuning_dataset_example_count: i64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
     pub total_tuning_character_count: i64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
     pub tuning_step_count: i64,
     pub user_input_token_distribution: Option<DatasetDistribution>,
     pub user_output_token_distribution: Option<DatasetDistribution>,
     pub user_message_per_example_distribution: Option<DatasetDistribution>,
-    pub user_dataset_examples: Vec<SupervisedTuningDatasetExample>,
+    /// Sample examples, as Vertex `Content` objects
+    #[serde(default)]
+    pub user_dataset_examples: Vec<Value>,
 }
 
 /// Dataset distribution
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DatasetDistribution {
-    pub min: f32,
-    pub max: f32,
-    pub mean: f32,
-    pub median: f32,
-    pub p5: f32,
-    pub p95: f32,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub p5: f64,
+    pub p95: f64,
     pub buckets: Vec<DatasetDistributionBucket>,
 }
 
 /// Dataset distribution bucket
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DatasetDistributionBucket {
-    pub count: i64,
-    pub left: f32,
-    pub right: f32,
+    pub count: f64,
+    pub left: f64,
+    pub right: f64,
 }
 
-/// Supervised tuning dataset example
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SupervisedTuningDatasetExample {
-    pub user_input: String,
-    pub user_output: String,
-}
-
-/// Fine-tuning error
+/// Fine-tuning error (a `google.rpc.Status`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FineTuningError {
+    #[serde(default)]
     pub code: i32,
+    #[serde(default)]
     pub message: String,
     pub details: Option<Vec<Value>>,
 }
@@ -165,28 +196,239 @@ pub struct CreateFineTuningJobRequest {
     pub adapter_size: Option<AdapterSize>,
 }
 
+impl CreateFineTuningJobRequest {
+    /// Build the `TuningJob` body of `POST .../tuningJobs`
+    pub fn to_request_body(&self) -> Value {
+        let spec = SupervisedTuningSpec {
+            training_dataset_uri: self.training_dataset_uri.clone(),
+            validation_dataset_uri: self.validation_dataset_uri.clone(),
+            hyper_parameters: Some(SupervisedHyperParameters {
+                epoch_count: self.epoch_count,
+                learning_rate_multiplier: self.learning_rate_multiplier,
+                adapter_size: self.adapter_size.clone(),
+            }),
+        };
+
+        let mut body = serde_json::json!({
+            "baseModel": self.base_model,
+            "supervisedTuningSpec": spec,
+            "description": self.display_name,
+        });
+        if let Some(name) = &self.tuned_model_display_name {
+            body["tunedModelDisplayName"] = Value::String(name.clone());
+        }
+        body
+    }
+}
+
 /// Fine-tuning job list response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ListFineTuningJobsResponse {
+    #[serde(default)]
     pub tuning_jobs: Vec<FineTuningJob>,
     pub next_page_token: Option<String>,
 }
 
+/// Deserialize an int64, which proto3 JSON encodes as a string
+fn deserialize_int64<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Int64 {
+        Number(i64),
+        String(String),
+    }
+
+    match Int64::deserialize(deserializer)? {
+        Int64::Number(n) => Ok(n),
+        Int64::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+fn deserialize_optional_int64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_int64")] i64);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(n)| n))
+}
+
+impl From<FineTuningState> for shared::FineTuningStatus {
+    fn from(state: FineTuningState) -> Self {
+        match state {
+            FineTuningState::Unspecified | FineTuningState::Queued | FineTuningState::Pending => {
+                Self::Queued
+            }
+            FineTuningState::Running | FineTuningState::Cancelling | FineTuningState::Paused => {
+                Self::Running
+            }
+            FineTuningState::Succeeded => Self::Succeeded,
+            FineTuningState::Failed | FineTuningState::Expired => Self::Failed,
+            FineTuningState::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+/// Parse an RFC 3339 timestamp into Unix seconds
+fn unix_timestamp(time: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .ok()
+        .map(|t| t.timestamp())
+}
+
+impl From<FineTuningJob> for shared::FineTuningJob {
+    /// Convert a tuning job into the OpenAI-compatible job object
+    fn from(job: FineTuningJob) -> Self {
+        let (training_file, validation_file, hyperparameters) = match job.supervised_tuning_spec {
+            Some(spec) => (
+                spec.training_dataset_uri,
+                spec.validation_dataset_uri,
+                spec.hyper_parameters
+                    .map(|params| shared::FineTuningHyperparameters {
+                        n_epochs: params.epoch_count.and_then(|n| u32::try_from(n).ok()),
+                        batch_size: None,
+                        learning_rate_multiplier: params.learning_rate_multiplier.map(f64::from),
+                    }),
+            ),
+            None => (String::new(), None, None),
+        };
+
+        Self {
+            id: job.name,
+            object: "fine_tuning.job".to_string(),
+            created_at: unix_timestamp(&job.create_time).unwrap_or_default(),
+            finished_at: job.end_time.as_deref().and_then(unix_timestamp),
+            model: job.base_model,
+            fine_tuned_model: job.tuned_model.map(|tuned| tuned.model),
+            organization_id: None,
+            status: job.state.into(),
+            hyperparameters,
+            training_file,
+            validation_file,
+            result_files: None,
+            trained_tokens: None,
+            suffix: job.tuned_model_display_name,
+            error: job.error.map(|error| shared::FineTuningError {
+                code: error.code.to_string(),
+                message: error.message,
+                param: None,
+            }),
+            estimated_finish: None,
+            integrations: None,
+            seed: None,
+        }
+    }
+}
+
 /// Fine-tuning handler
+#[derive(Debug, Clone)]
 pub struct FineTuningHandler {
     project_id: String,
     location: String,
+    auth: Arc<VertexAuth>,
+    http_client: Client,
 }
 
 impl FineTuningHandler {
     /// Create new fine-tuning handler
-    pub fn new(project_id: String, location: String) -> Self {
+    pub fn new(
+        project_id: String,
+        location: String,
+        auth: Arc<VertexAuth>,
+        http_client: Client,
+    ) -> Self {
         Self {
             project_id,
             location,
+            auth,
+            http_client,
+        }
+    }
+
+    /// Regional API host
+    fn host(&self) -> String {
+        if self.location == "global" {
+            "https://aiplatform.googleapis.com".to_string()
+        } else {
+            format!("https://{}-aiplatform.googleapis.com", self.location)
         }
     }
 
+    /// URL of the project's `tuningJobs` collection
+    fn jobs_url(&self) -> String {
+        format!(
+            "{}/v1/projects/{}/locations/{}/tuningJobs",
+            self.host(),
+            self.project_id,
+            self.location
+        )
+    }
+
+    /// URL of a single job, given its ID or full resource name
+    fn job_url(&self, job_id: &str) -> Result<String, VertexAIError> {
+        if job_id.starts_with("projects/") {
+            return Ok(format!("{}/v1/{}", self.host(), job_id));
+        }
+
+        if job_id.is_empty() || !job_id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(VertexAIError::InvalidRequest(format!(
+                "Invalid tuning job ID: {}",
+                job_id
+            )));
+        }
+
+        Ok(format!("{}/{}", self.jobs_url(), job_id))
+    }
+
+    /// Send an authenticated request, returning the successful response body
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<Value>,
+    ) -> Result<String, VertexAIError> {
+        let token = self
+            .auth
+            .get_access_token()
+            .await
+            .map_err(|e| VertexAIError::Authentication(e.to_string()))?;
+
+        debug!("Making request to Vertex AI: {} {}", method, url);
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", token));
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| VertexAIError::Network(e.to_string()))?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| VertexAIError::Network(e.to_string()))?;
+
+        if !status.is_success() {
+            return Err(VertexAIError::ApiError {
+                status_code: status.as_u16(),
+                message: text,
+            });
+        }
+
+        Ok(text)
+    }
+
     /// Create a fine-tuning job
     pub async fn create_tuning_job(
         &self,
@@ -195,78 +437,68 @@ impl FineTuningHandler {
         // Validate request
         self.validate_tuning_request(&request)?;
 
-        // TODO: Implement actual fine-tuning job creation
-        Ok(FineTuningJob {
-            name: format!(
-                "projects/{}/locations/{}/tuningJobs/{}",
-                self.project_id,
-                self.location,
-                uuid::Uuid::new_v4()
-            ),
-            display_name: request.display_name,
-            base_model: request.base_model,
-            state: FineTuningState::Queued,
-            create_time: chrono::Utc::now().to_rfc3339(),
-            start_time: None,
-            end_time: None,
-            update_time: chrono::Utc::now().to_rfc3339(),
-            tuned_model: None,
-            training_task: TrainingTask {
-                inputs: TrainingInputs {
-                    supervised_tuning_spec: SupervisedTuningSpec {
-                        training_dataset_uri: request.training_dataset_uri,
-                        validation_dataset_uri: request.validation_dataset_uri,
-                        hyper_parameters: Some(SupervisedHyperParameters {
-                            epoch_count: request.epoch_count,
-                            learning_rate_multiplier: request.learning_rate_multiplier,
-                            adapter_size: request.adapter_size.clone(),
-                        }),
-                    },
-                },
-                training_steps: None,
-                learning_rate_multiplier: request.learning_rate_multiplier,
-                adapter_size: request.adapter_size,
-                tuning_data_stats: None,
-            },
-            error: None,
-            labels: HashMap::new(),
-            experiment: None,
-            tuned_model_display_name: request.tuned_model_display_name,
-        })
+        let body = self
+            .send(
+                reqwest::Method::POST,
+                &self.jobs_url(),
+                Some(request.to_request_body()),
+            )
+            .await?;
+        Ok(serde_json::from_str(&body)?)
     }
 
     /// Get fine-tuning job status
-    pub async fn get_tuning_job(&self, _job_id: &str) -> Result<FineTuningJob, VertexAIError> {
-        // TODO: Implement actual job retrieval
-        Err(VertexAIError::UnsupportedFeature(
-            "Fine-tuning job retrieval not yet implemented".to_string(),
-        ))
+    pub async fn get_tuning_job(&self, job_id: &str) -> Result<FineTuningJob, VertexAIError> {
+        let url = self.job_url(job_id)?;
+        let body = self.send(reqwest::Method::GET, &url, None).await?;
+        Ok(serde_json::from_str(&body)?)
     }
 
     /// List fine-tuning jobs
     pub async fn list_tuning_jobs(
         &self,
-        _filter: Option<String>,
-        _page_size: Option<i32>,
-        _page_token: Option<String>,
+        filter: Option<String>,
+        page_size: Option<i32>,
+        page_token: Option<String>,
     ) -> Result<ListFineTuningJobsResponse, VertexAIError> {
-        // TODO: Implement actual job listing
-        Ok(ListFineTuningJobsResponse {
-            tuning_jobs: Vec::new(),
-            next_page_token: None,
-        })
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(filter) = &filter {
+            query.append_pair("filter", filter);
+        }
+        if let Some(page_size) = page_size {
+            query.append_pair("pageSize", &page_size.to_string());
+        }
+        if let Some(page_token) = &page_token {
+            query.append_pair("pageToken", page_token);
+        }
+        let query = query.finish();
+
+        let url = if query.is_empty() {
+            self.jobs_url()
+        } else {
+            format!("{}?{}", self.jobs_url(), query)
+        };
+
+        let body = self.send(reqwest::Method::GET, &url, None).await?;
+        Ok(serde_json::from_str(&body)?)
     }
 
     /// Cancel a fine-tuning job
-    pub async fn cancel_tuning_job(&self, _job_id: &str) -> Result<(), VertexAIError> {
-        // TODO: Implement actual job cancellation
+    ///
+    /// Cancellation is asynchronous; the job moves through
+    /// `JOB_STATE_CANCELLING` before it is cancelled.
+    pub async fn cancel_tuning_job(&self, job_id: &str) -> Result<(), VertexAIError> {
+        let url = format!("{}:cancel", self.job_url(job_id)?);
+        self.send(reqwest::Method::POST, &url, Some(serde_json::json!({})))
+            .await?;
         Ok(())
     }
 
     /// Delete a fine-tuning job
     pub async fn delete_tuning_job(&self, _job_id: &str) -> Result<(), VertexAIError> {
-        // TODO: Implement actual job deletion
-        Ok(())
+        Err(VertexAIError::UnsupportedFeature(
+            "Vertex AI tuning jobs cannot be deleted".to_string(),
+        ))
     }
 
     /// Validate fine-tuning request
@@ -361,10 +593,22 @@ impl FineTuningHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::providers::vertex_ai::VertexCredentials;
+
+    fn handler() -> FineTuningHandler {
+        FineTuningHandler::new(
+            "test".to_string(),
+            "us-central1".to_string(),
+            Arc::new(VertexAuth::new(VertexCredentials::AccessToken(
+                "token".to_string(),
+            ))),
+            Client::new(),
+        )
+    }
 
     #[test]
     fn test_is_tunable_model() {
-        let handler = FineTuningHandler::new("test".to_string(), "us-central1".to_string());
+        let handler = handler();
 
         assert!(handler.is_tunable_model("gemini-1.5-pro-002"));
         assert!(handler.is_tunable_model("text-bison@002"));
@@ -374,7 +618,7 @@ mod tests {
 
     #[test]
     fn test_estimate_tuning_cost() {
-        let handler = FineTuningHandler::new("test".to_string(), "us-central1".to_string());
+        let handler = handler();
 
         let cost = handler.estimate_tuning_cost("gemini-1.5-flash-002", 1000, 3);
         assert!(cost > 0.0);
@@ -384,9 +628,107 @@ mod tests {
         assert!(pro_cost > flash_cost);
     }
 
+    #[test]
+    fn test_job_urls() {
+        let handler = handler();
+        assert_eq!(
+            handler.jobs_url(),
+            "https://us-central1-aiplatform.googleapis.com/v1/projects/test/locations/us-central1/tuningJobs"
+        );
+        assert_eq!(
+            handler.job_url("123").unwrap(),
+            "https://us-central1-aiplatform.googleapis.com/v1/projects/test/locations/us-central1/tuningJobs/123"
+        );
+        assert_eq!(
+            handler
+                .job_url("projects/other/locations/us-central1/tuningJobs/456")
+                .unwrap(),
+            "https://us-central1-aiplatform.googleapis.com/v1/projects/other/locations/us-central1/tuningJobs/456"
+        );
+        assert!(handler.job_url("../datasets").is_err());
+    }
+
+    #[test]
+    fn test_request_body() {
+        let request = CreateFineTuningJobRequest {
+            display_name: "Test Job".to_string(),
+            base_model: "gemini-1.5-flash-002".to_string(),
+            training_dataset_uri: "gs://my-bucket/training.jsonl".to_string(),
+            validation_dataset_uri: None,
+            tuned_model_display_name: Some("my-model".to_string()),
+            epoch_count: Some(3),
+            learning_rate_multiplier: None,
+            adapter_size: Some(AdapterSize::Four),
+        };
+
+        let body = request.to_request_body();
+        assert_eq!(body["baseModel"], "gemini-1.5-flash-002");
+        assert_eq!(body["tunedModelDisplayName"], "my-model");
+        let spec = &body["supervisedTuningSpec"];
+        assert_eq!(spec["trainingDatasetUri"], "gs://my-bucket/training.jsonl");
+        assert!(spec.get("validationDatasetUri").is_none());
+        assert_eq!(spec["hyperParameters"]["epochCount"], 3);
+        assert_eq!(spec["hyperParameters"]["adapterSize"], "ADAPTER_SIZE_FOUR");
+    }
+
+    #[test]
+    fn test_convert_to_shared_job() {
+        let body = r#"{
+            "name": "projects/test/locations/us-central1/tuningJobs/123",
+            "tunedModelDisplayName": "my-model",
+            "baseModel": "gemini-1.5-flash-002",
+            "supervisedTuningSpec": {
+                "trainingDatasetUri": "gs://my-bucket/training.jsonl",
+                "hyperParameters": {
+                    "epochCount": "3",
+                    "learningRateMultiplier": 0.5
+                }
+            },
+            "state": "JOB_STATE_SUCCEEDED",
+            "createTime": "2024-06-01T12:00:00Z",
+            "endTime": "2024-06-01T13:00:00.123456Z",
+            "tunedModel": {
+                "model": "projects/test/locations/us-central1/models/789",
+                "endpoint": "projects/test/locations/us-central1/endpoints/456"
+            },
+            "tuningDataStats": {
+                "supervisedTuningDataStats": {
+                    "tuningDatasetExampleCount": "500",
+                    "tuningStepCount": "63"
+                }
+            }
+        }"#;
+        let job: FineTuningJob = serde_json::from_str(body).unwrap();
+        assert_eq!(job.job_id(), "123");
+
+        let stats = job
+            .tuning_data_stats
+            .as_ref()
+            .and_then(|stats| stats.supervised_tuning_data_stats.as_ref())
+            .unwrap();
+        assert_eq!(stats.tuning_dataset_example_count, 500);
+
+        let shared_job = shared::FineTuningJob::from(job);
+        assert_eq!(shared_job.object, "fine_tuning.job");
+        assert_eq!(shared_job.model, "gemini-1.5-flash-002");
+        assert_eq!(shared_job.status, shared::FineTuningStatus::Succeeded);
+        assert_eq!(shared_job.training_file, "gs://my-bucket/training.jsonl");
+        assert_eq!(
+            shared_job.fine_tuned_model.as_deref(),
+            Some("projects/test/locations/us-central1/models/789")
+        );
+        assert_eq!(shared_job.suffix.as_deref(), Some("my-model"));
+        assert_eq!(shared_job.created_at, 1717243200);
+        assert_eq!(shared_job.finished_at, Some(1717246800));
+
+        let hyperparameters = shared_job.hyperparameters.unwrap();
+        assert_eq!(hyperparameters.n_epochs, Some(3));
+        assert_eq!(hyperparameters.learning_rate_multiplier, Some(0.5));
+    }
+
     #[test]
     fn test_validate_tuning_request() {
-        let handler = FineTuningHandler::new("test".to_string(), "us-central1".to_string());
+        let handler = handler();
 
         let valid_request = CreateFineTuningJobRequest {
             display_name: "Test Job".to_string(),
@@ -6,7 +6,7 @@ use super::error::VertexAIError;
 use serde::{Deserialize, Serialize};
 
 /// Image generation request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ImageGenerationRequest {
     pub prompt: String,
     pub number_of_images: Option<i32>,
@@ -14,31 +14,97 @@ pub struct ImageGenerationRequest {
     pub negative_prompt: Option<String>,
     pub seed: Option<i64>,
     pub guidance_scale: Option<f32>,
-    pub safety_settings: Option<Vec<SafetySetting>>,
+    /// Safety filter level, e.g. "block_medium_and_above"
+    pub safety_setting: Option<String>,
+    /// Whether people may be generated: "dont_allow", "allow_adult" or "allow_all"
+    pub person_generation: Option<String>,
+}
+
+impl ImageGenerationRequest {
+    /// Convert an OpenAI-style request
+    ///
+    /// Imagen options without an OpenAI equivalent (`aspect_ratio`,
+    /// `negative_prompt`, `seed`, `safety_setting`, `person_generation`) are
+    /// read from the request's extra parameters. An OpenAI `size` is mapped to
+    /// the closest aspect ratio.
+    pub fn from_openai(request: &crate::core::types::requests::ImageGenerationRequest) -> Self {
+        let extra_str = |key: &str| {
+            request
+                .extra_params
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+
+        let aspect_ratio = extra_str("aspect_ratio").or_else(|| {
+            request
+                .size
+                .as_deref()
+                .and_then(size_to_aspect_ratio)
+                .map(str::to_string)
+        });
+
+        Self {
+            prompt: request.prompt.clone(),
+            number_of_images: request.n.map(|n| n as i32),
+            aspect_ratio,
+            negative_prompt: extra_str("negative_prompt"),
+            seed: request.extra_params.get("seed").and_then(|v| v.as_i64()),
+            guidance_scale: None,
+            safety_setting: extra_str("safety_setting")
+                .or_else(|| extra_str("safety_filter_level")),
+            person_generation: extra_str("person_generation"),
+        }
+    }
+}
+
+/// Map an OpenAI image size (`WIDTHxHEIGHT`) or a ratio (`W:H`) to an
+/// Imagen aspect ratio
+pub fn size_to_aspect_ratio(size: &str) -> Option<&'static str> {
+    let (width, height) = size.split_once('x').or_else(|| size.split_once(':'))?;
+    let width: f64 = width.trim().parse().ok()?;
+    let height: f64 = height.trim().parse().ok()?;
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+
+    const RATIOS: [(&str, f64); 5] = [
+        ("1:1", 1.0),
+        ("3:4", 0.75),
+        ("4:3", 4.0 / 3.0),
+        ("9:16", 9.0 / 16.0),
+        ("16:9", 16.0 / 9.0),
+    ];
+
+    let ratio = width / height;
+    RATIOS
+        .iter()
+        .min_by(|a, b| (a.1 - ratio).abs().total_cmp(&(b.1 - ratio).abs()))
+        .map(|(name, _)| *name)
 }
 
 /// Image generation response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageGenerationResponse {
+    #[serde(default)]
     pub predictions: Vec<ImagePrediction>,
     pub metadata: Option<serde_json::Value>,
 }
 
 /// Generated image prediction
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ImagePrediction {
     pub bytes_base64_encoded: Option<String>,
+    #[serde(default)]
     pub mime_type: String,
+    /// Rewritten prompt, when prompt enhancement is on
+    pub prompt: Option<String>,
+    /// Why the image was filtered out by responsible-AI checks
+    pub rai_filtered_reason: Option<String>,
     pub safety_ratings: Option<Vec<SafetyRating>>,
 }
 
-/// Safety setting for image generation
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SafetySetting {
-    pub category: String,
-    pub threshold: String,
-}
-
 /// Safety rating for generated content
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SafetyRating {
@@ -54,6 +120,8 @@ pub enum ImageGenerationModel {
     Imagen2,
     /// Imagen 3
     Imagen3,
+    /// Imagen 3 Fast
+    Imagen3Fast,
     /// Custom model
     Custom(String),
 }
@@ -62,26 +130,39 @@ impl ImageGenerationModel {
     /// Get model ID for API calls
     pub fn model_id(&self) -> String {
         match self {
-            Self::Imagen2 => "imagen-2".to_string(),
-            Self::Imagen3 => "imagen-3".to_string(),
+            Self::Imagen2 => "imagegeneration@006".to_string(),
+            Self::Imagen3 => "imagen-3.0-generate-002".to_string(),
+            Self::Imagen3Fast => "imagen-3.0-fast-generate-001".to_string(),
             Self::Custom(id) => id.clone(),
         }
     }
 
     /// Check if model supports aspect ratio
     pub fn supports_aspect_ratio(&self) -> bool {
-        matches!(self, Self::Imagen2 | Self::Imagen3)
+        matches!(self, Self::Imagen2 | Self::Imagen3 | Self::Imagen3Fast)
     }
 
     /// Get supported aspect ratios
     pub fn supported_aspect_ratios(&self) -> Vec<&str> {
         match self {
-            Self::Imagen2 | Self::Imagen3 => vec!["1:1", "9:16", "16:9", "3:4", "4:3"],
+            Self::Imagen2 | Self::Imagen3 | Self::Imagen3Fast => {
+                vec!["1:1", "9:16", "16:9", "3:4", "4:3"]
+            }
             Self::Custom(_) => vec!["1:1"],
         }
     }
 }
 
+/// Parse image generation model string
+pub fn parse_image_model(model: &str) -> ImageGenerationModel {
+    match model {
+        "imagen-2" | "imagegeneration@006" => ImageGenerationModel::Imagen2,
+        "imagen-3" | "imagen-3.0-generate-002" => ImageGenerationModel::Imagen3,
+        "imagen-3-fast" | "imagen-3.0-fast-generate-001" => ImageGenerationModel::Imagen3Fast,
+        _ => ImageGenerationModel::Custom(model.to_string()),
+    }
+}
+
 /// Image generation handler
 pub struct ImageGenerationHandler {
     project_id: String,
@@ -97,25 +178,59 @@ impl ImageGenerationHandler {
         }
     }
 
-    /// Generate images from text prompt
-    pub async fn generate_image(
+    /// URL of the model's `predict` endpoint
+    pub fn predict_url(&self, model: &ImageGenerationModel) -> String {
+        format!(
+            "https://{}-aiplatform.googleapis.com/v1/projects/{}/locations/{}/publishers/google/models/{}:predict",
+            self.location,
+            self.project_id,
+            self.location,
+            model.model_id()
+        )
+    }
+
+    /// Validate a request and build the `predict` body
+    pub fn build_request(
         &self,
         model: &ImageGenerationModel,
         request: ImageGenerationRequest,
-    ) -> Result<ImageGenerationResponse, VertexAIError> {
+    ) -> Result<serde_json::Value, VertexAIError> {
         self.validate_request(model, &request)?;
+        self.transform_request(model, request)
+    }
 
-        // Transform request for Vertex AI format
-        let _vertex_request = self.transform_request(model, request)?;
-
-        // TODO: Implement actual API call
-        Ok(ImageGenerationResponse {
-            predictions: vec![ImagePrediction {
-                bytes_base64_encoded: Some("iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==".to_string()),
-                mime_type: "image/png".to_string(),
-                safety_ratings: None,
-            }],
-            metadata: None,
+    /// Transform a `predict` response into the standard image response
+    pub fn transform_response(
+        &self,
+        response: ImageGenerationResponse,
+    ) -> Result<crate::core::types::responses::ImageGenerationResponse, VertexAIError> {
+        let filtered = response
+            .predictions
+            .iter()
+            .any(|prediction| prediction.rai_filtered_reason.is_some());
+
+        let data: Vec<_> = response
+            .predictions
+            .into_iter()
+            .filter_map(|prediction| {
+                prediction.bytes_base64_encoded.map(|b64| {
+                    crate::core::types::responses::ImageData {
+                        url: None,
+                        b64_json: Some(b64),
+                        revised_prompt: prediction.prompt,
+                    }
+                })
+            })
+            .collect();
+
+        // Every image was removed by the safety filters
+        if data.is_empty() && filtered {
+            return Err(VertexAIError::ContentFiltered);
+        }
+
+        Ok(crate::core::types::responses::ImageGenerationResponse {
+            created: chrono::Utc::now().timestamp() as u64,
+            data,
         })
     }
 
@@ -161,16 +276,16 @@ impl ImageGenerationHandler {
         _model: &ImageGenerationModel,
         request: ImageGenerationRequest,
     ) -> Result<serde_json::Value, VertexAIError> {
-        let mut instances = serde_json::json!([{
+        let instances = serde_json::json!([{
             "prompt": request.prompt
         }]);
 
+        let mut parameters = serde_json::json!({});
+
         if let Some(negative_prompt) = request.negative_prompt {
-            instances[0]["negativePrompt"] = serde_json::Value::String(negative_prompt);
+            parameters["negativePrompt"] = serde_json::Value::String(negative_prompt);
         }
 
-        let mut parameters = serde_json::json!({});
-
         if let Some(count) = request.number_of_images {
             parameters["sampleCount"] = serde_json::Value::Number(count.into());
         }
@@ -187,6 +302,14 @@ impl ImageGenerationHandler {
             parameters["guidanceScale"] = serde_json::json!(guidance_scale);
         }
 
+        if let Some(safety_setting) = request.safety_setting {
+            parameters["safetySetting"] = serde_json::Value::String(safety_setting);
+        }
+
+        if let Some(person_generation) = request.person_generation {
+            parameters["personGeneration"] = serde_json::Value::String(person_generation);
+        }
+
         Ok(serde_json::json!({
             "instances": instances,
             "parameters": parameters
@@ -196,8 +319,9 @@ impl ImageGenerationHandler {
     /// Calculate generation cost
     pub fn calculate_cost(&self, model: &ImageGenerationModel, count: i32) -> f64 {
         let base_cost = match model {
-            ImageGenerationModel::Imagen2 => 0.024, // $0.024 per image
-            ImageGenerationModel::Imagen3 => 0.04,  // $0.04 per image
+            ImageGenerationModel::Imagen2 => 0.024,    // $0.024 per image
+            ImageGenerationModel::Imagen3 => 0.04,     // $0.04 per image
+            ImageGenerationModel::Imagen3Fast => 0.02, // $0.02 per image
             ImageGenerationModel::Custom(_) => 0.024,
         };
 
@@ -211,8 +335,18 @@ mod tests {
 
     #[test]
     fn test_model_id() {
-        assert_eq!(ImageGenerationModel::Imagen2.model_id(), "imagen-2");
-        assert_eq!(ImageGenerationModel::Imagen3.model_id(), "imagen-3");
+        assert_eq!(
+            ImageGenerationModel::Imagen2.model_id(),
+            "imagegeneration@006"
+        );
+        assert_eq!(
+            ImageGenerationModel::Imagen3.model_id(),
+            "imagen-3.0-generate-002"
+        );
+        assert!(matches!(
+            parse_image_model("imagen-3"),
+            ImageGenerationModel::Imagen3
+        ));
     }
 
     #[test]
@@ -235,7 +369,8 @@ mod tests {
             negative_prompt: None,
             seed: None,
             guidance_scale: None,
-            safety_settings: None,
+            safety_setting: None,
+            person_generation: None,
         };
 
         assert!(handler.validate_request(&model, &valid_request).is_ok());
@@ -247,9 +382,82 @@ mod tests {
             negative_prompt: None,
             seed: None,
             guidance_scale: None,
-            safety_settings: None,
+            safety_setting: None,
+            person_generation: None,
         };
 
         assert!(handler.validate_request(&model, &invalid_request).is_err());
     }
+
+    #[test]
+    fn test_from_openai_request() {
+        let mut extra_params = std::collections::HashMap::new();
+        extra_params.insert("negative_prompt".to_string(), serde_json::json!("blurry"));
+        extra_params.insert(
+            "safety_setting".to_string(),
+            serde_json::json!("block_only_high"),
+        );
+        let request = crate::core::types::requests::ImageGenerationRequest {
+            prompt: "A lighthouse".to_string(),
+            model: None,
+            n: Some(2),
+            size: Some("1792x1024".to_string()),
+            quality: None,
+            response_format: None,
+            style: None,
+            user: None,
+            extra_params,
+        };
+
+        let handler = ImageGenerationHandler::new("test".to_string(), "us-central1".to_string());
+        let body = handler
+            .build_request(
+                &ImageGenerationModel::Imagen3,
+                ImageGenerationRequest::from_openai(&request),
+            )
+            .unwrap();
+
+        assert_eq!(body["instances"][0]["prompt"], "A lighthouse");
+        assert_eq!(body["parameters"]["sampleCount"], 2);
+        assert_eq!(body["parameters"]["aspectRatio"], "16:9");
+        assert_eq!(body["parameters"]["negativePrompt"], "blurry");
+        assert_eq!(body["parameters"]["safetySetting"], "block_only_high");
+    }
+
+    #[test]
+    fn test_size_to_aspect_ratio() {
+        assert_eq!(size_to_aspect_ratio("1024x1024"), Some("1:1"));
+        assert_eq!(size_to_aspect_ratio("1024x1792"), Some("9:16"));
+        assert_eq!(size_to_aspect_ratio("4:3"), Some("4:3"));
+        assert_eq!(size_to_aspect_ratio("large"), None);
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let handler = ImageGenerationHandler::new("test".to_string(), "us-central1".to_string());
+        let response: ImageGenerationResponse = serde_json::from_value(serde_json::json!({
+            "predictions": [
+                {"bytesBase64Encoded": "aW1hZ2U=", "mimeType": "image/png", "prompt": "A tall lighthouse"},
+                {"raiFilteredReason": "Filtered for safety"}
+            ]
+        }))
+        .unwrap();
+
+        let response = handler.transform_response(response).unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].b64_json.as_deref(), Some("aW1hZ2U="));
+        assert_eq!(
+            response.data[0].revised_prompt.as_deref(),
+            Some("A tall lighthouse")
+        );
+
+        let all_filtered: ImageGenerationResponse = serde_json::from_value(serde_json::json!({
+            "predictions": [{"raiFilteredReason": "Filtered for safety"}]
+        }))
+        .unwrap();
+        assert!(matches!(
+            handler.transform_response(all_filtered),
+            Err(VertexAIError::ContentFiltered)
+        ));
+    }
 }
@@ -1,9 +1,10 @@
 //! Request/Response transformers for Vertex AI models
 
+use crate::core::providers::shared::SchemaTransformer;
 use crate::core::types::FinishReason;
 use crate::core::types::{
     requests::{ChatMessage, ChatRequest, MessageContent, MessageRole},
-    responses::{ChatChoice, ChatResponse, Usage},
+    responses::{ChatChoice, ChatResponse, PromptTokensDetails, Usage},
 };
 use serde_json::{Value, json};
 
@@ -71,13 +72,10 @@ impl GeminiTransformer {
         };
 
         // Handle JSON mode / response format
-        if let Some(ref format) = request.response_format {
-            if format.response_type == Some("json_object".to_string()) {
-                generation_config.response_mime_type = Some("application/json".to_string());
-                if let Some(ref schema) = format.json_schema {
-                    generation_config.response_schema = Some(serde_json::to_value(schema)?);
-                }
-            }
+        if let Some(format) = request.response_format.as_ref().filter(|f| f.is_json()) {
+            generation_config.response_mime_type = Some("application/json".to_string());
+            generation_config.response_schema =
+                format.schema().map(SchemaTransformer::to_openapi_schema);
         }
 
         // Handle tools/functions
@@ -125,7 +123,7 @@ impl GeminiTransformer {
             MessageContent::Parts(parts) => {
                 parts.iter().map(|part| {
                     match part {
-                        crate::core::types::requests::ContentPart::Text { text } => {
+                        crate::core::types::requests::ContentPart::Text { text, .. } => {
                             Ok(Part::Text { text: text.clone() })
                         }
                         crate::core::types::requests::ContentPart::Image { image_url, source: _source, detail: _detail } => {
@@ -176,10 +174,23 @@ impl GeminiTransformer {
                                 Err(VertexAIError::InvalidRequest("Only base64 images supported".to_string()))
                             }
                         }
-                        crate::core::types::requests::ContentPart::Audio { audio: _audio } => {
-                            // Vertex AI doesn't directly support audio in chat completions
-                            // This would need to be handled via separate audio APIs
-                            Err(VertexAIError::InvalidRequest("Audio content not supported in chat completions".to_string()))
+                        crate::core::types::requests::ContentPart::Audio { audio } => {
+                            // Cloud Storage audio is referenced, anything else is inline base64
+                            if audio.data.starts_with("gs://") {
+                                Ok(Part::FileData {
+                                    file_data: super::common_utils::FileData {
+                                        mime_type: audio.mime_type(),
+                                        file_uri: audio.data.clone(),
+                                    }
+                                })
+                            } else {
+                                Ok(Part::InlineData {
+                                    inline_data: super::common_utils::InlineData {
+                                        mime_type: audio.mime_type(),
+                                        data: audio.data.clone(),
+                                    }
+                                })
+                            }
                         }
                         crate::core::types::requests::ContentPart::Document { .. } => {
                             Err(VertexAIError::InvalidRequest("Document content not supported".to_string()))
@@ -247,9 +258,18 @@ impl GeminiTransformer {
             prompt_tokens: usage_metadata["promptTokenCount"].as_u64().unwrap_or(0) as u32,
             completion_tokens: usage_metadata["candidatesTokenCount"].as_u64().unwrap_or(0) as u32,
             total_tokens: usage_metadata["totalTokenCount"].as_u64().unwrap_or(0) as u32,
-            prompt_tokens_details: None,
+            // Prompt tokens served from a context cache
+            prompt_tokens_details: usage_metadata["cachedContentTokenCount"].as_u64().map(
+                |cached| PromptTokensDetails {
+                    cached_tokens: Some(cached as u32),
+                    audio_tokens: None,
+                },
+            ),
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         });
 
         Ok(ChatResponse {
@@ -267,12 +287,14 @@ impl GeminiTransformer {
                     tool_calls: None,
                     function_call: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 finish_reason,
                 logprobs: None,
             }],
             usage,
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 }
@@ -503,6 +525,9 @@ impl PartnerModelTransformer {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             })
         } else {
             None
@@ -515,6 +540,9 @@ impl PartnerModelTransformer {
             prompt_tokens_details: None,
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         });
 
         if usage.total_tokens == 0 {
@@ -536,12 +564,14 @@ impl PartnerModelTransformer {
                     tool_calls: None,
                     function_call: None,
                     tool_call_id: None,
+                    cache_control: None,
                 },
                 finish_reason: Some(FinishReason::Stop),
                 logprobs: None,
             }],
             usage: Some(usage),
             system_fingerprint: None,
+            provider_specific_fields: None,
         })
     }
 }
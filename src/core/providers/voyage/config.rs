@@ -0,0 +1,64 @@
+//! Voyage AI Configuration
+
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(VoyageConfig {});
+
+impl VoyageConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `VOYAGE_*`, falling back to the `VOYAGE_AI_API_KEY` name.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("voyage");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("VOYAGE_AI_API_KEY").ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for VoyageConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("voyage")
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voyage_config_default_api_base() {
+        let config = VoyageConfig::new("voyage");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://api.voyageai.com/v1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_voyage_validate_missing_api_key() {
+        let mut config = VoyageConfig::new("voyage");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+}
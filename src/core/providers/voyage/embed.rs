@@ -0,0 +1,157 @@
+//! Voyage AI Embeddings Transformation
+
+use serde_json::{Value, json};
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{
+    requests::EmbeddingRequest,
+    responses::{EmbeddingData, EmbeddingResponse, Usage},
+};
+
+/// Strip the routing prefix so `voyage/voyage-3.5` becomes `voyage-3.5`
+pub fn normalize_model(model: &str) -> &str {
+    model.strip_prefix("voyage/").unwrap_or(model)
+}
+
+/// Voyage AI embeddings transformation
+pub struct VoyageEmbedTransformation;
+
+impl VoyageEmbedTransformation {
+    /// Map the unified `task_type` onto Voyage's `input_type`
+    ///
+    /// Accepts both Voyage names and the Vertex-style upper-case task types.
+    /// Anything else leaves `input_type` unset, which embeds the text as-is.
+    pub fn input_type(task_type: Option<&str>) -> Option<&'static str> {
+        match task_type?.to_lowercase().as_str() {
+            "query" | "retrieval_query" => Some("query"),
+            "document" | "retrieval_document" => Some("document"),
+            _ => None,
+        }
+    }
+
+    /// Build the `/v1/embeddings` request body
+    ///
+    /// `encoding_format` is not forwarded: base64 output would not fit the
+    /// float vectors of [`EmbeddingResponse`].
+    pub fn transform_request(request: &EmbeddingRequest) -> Value {
+        let mut body = json!({
+            "model": normalize_model(&request.model),
+            "input": request.input.to_vec(),
+        });
+
+        if let Some(input_type) = Self::input_type(request.task_type.as_deref()) {
+            body["input_type"] = json!(input_type);
+        }
+        if let Some(dimensions) = request.dimensions {
+            body["output_dimension"] = json!(dimensions);
+        }
+
+        body
+    }
+
+    /// Convert a `/v1/embeddings` response
+    ///
+    /// Usage only carries `total_tokens`, which is reported as prompt tokens.
+    pub fn transform_response(
+        response: Value,
+        model: &str,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        let data = response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| ProviderError::response_parsing("voyage", "Missing 'data' in response"))?
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let embedding: Vec<f32> = item
+                    .get("embedding")
+                    .cloned()
+                    .map(serde_json::from_value)
+                    .transpose()
+                    .map_err(|e| ProviderError::response_parsing("voyage", e.to_string()))?
+                    .unwrap_or_default();
+                Ok(EmbeddingData {
+                    object: "embedding".to_string(),
+                    index: item
+                        .get("index")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(i as u64) as u32,
+                    embedding,
+                })
+            })
+            .collect::<Result<Vec<_>, ProviderError>>()?;
+
+        let usage = response
+            .pointer("/usage/total_tokens")
+            .and_then(|t| t.as_u64())
+            .map(|tokens| Usage::new(tokens as u32, 0));
+
+        Ok(EmbeddingResponse {
+            object: "list".to_string(),
+            data,
+            model: normalize_model(model).to_string(),
+            usage,
+            embeddings: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::requests::EmbeddingInput;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_transform_request() {
+        let request = EmbeddingRequest {
+            model: "voyage/voyage-3.5".to_string(),
+            input: EmbeddingInput::Text("hello".to_string()),
+            user: None,
+            encoding_format: Some("float".to_string()),
+            dimensions: Some(512),
+            task_type: Some("RETRIEVAL_DOCUMENT".to_string()),
+            extra_params: HashMap::new(),
+        };
+
+        let body = VoyageEmbedTransformation::transform_request(&request);
+        assert_eq!(body["model"], "voyage-3.5");
+        assert_eq!(body["input"][0], "hello");
+        assert_eq!(body["input_type"], "document");
+        assert_eq!(body["output_dimension"], 512);
+        assert!(body.get("encoding_format").is_none());
+    }
+
+    #[test]
+    fn test_input_type() {
+        assert_eq!(VoyageEmbedTransformation::input_type(None), None);
+        assert_eq!(
+            VoyageEmbedTransformation::input_type(Some("query")),
+            Some("query")
+        );
+        assert_eq!(
+            VoyageEmbedTransformation::input_type(Some("clustering")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "object": "list",
+            "data": [
+                {"object": "embedding", "embedding": [0.1, 0.2], "index": 0},
+                {"object": "embedding", "embedding": [0.3, 0.4], "index": 1}
+            ],
+            "model": "voyage-3.5",
+            "usage": {"total_tokens": 10}
+        });
+
+        let response =
+            VoyageEmbedTransformation::transform_response(raw, "voyage/voyage-3.5").unwrap();
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[1].embedding, vec![0.3, 0.4]);
+        assert_eq!(response.model, "voyage-3.5");
+        assert_eq!(response.usage.unwrap().prompt_tokens, 10);
+    }
+}
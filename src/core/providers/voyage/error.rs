@@ -0,0 +1,63 @@
+//! Voyage AI Error Handling
+
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::traits::error_mapper::trait_def::ErrorMapper;
+
+/// Error mapper for the Voyage AI API
+#[derive(Debug)]
+pub struct VoyageErrorMapper;
+
+impl ErrorMapper<ProviderError> for VoyageErrorMapper {
+    fn map_http_error(&self, status_code: u16, response_body: &str) -> ProviderError {
+        let message = extract_error_message(response_body);
+
+        match status_code {
+            400 | 422 => ProviderError::invalid_request("voyage", message),
+            401 | 403 => ProviderError::authentication("voyage", message),
+            404 => ProviderError::model_not_found("voyage", message),
+            429 => ProviderError::rate_limit("voyage", None),
+            502..=504 => ProviderError::provider_unavailable("voyage", message),
+            _ => ProviderError::api_error("voyage", status_code, message),
+        }
+    }
+}
+
+/// Voyage reports errors as `{"detail": "..."}`
+fn extract_error_message(response_body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_body)
+        .ok()
+        .and_then(|v| v.get("detail").and_then(|m| m.as_str()).map(String::from))
+        .unwrap_or_else(|| response_body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voyage_error_mapper_400() {
+        let err = VoyageErrorMapper.map_http_error(
+            400,
+            r#"{"detail":"Input cannot contain more than 1000 items."}"#,
+        );
+        match err {
+            ProviderError::InvalidRequest { message, .. } => {
+                assert_eq!(message, "Input cannot contain more than 1000 items.")
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_voyage_error_mapper_401() {
+        let err =
+            VoyageErrorMapper.map_http_error(401, r#"{"detail":"Provided API key is invalid."}"#);
+        assert!(matches!(err, ProviderError::Authentication { .. }));
+    }
+
+    #[test]
+    fn test_voyage_error_mapper_503() {
+        let err = VoyageErrorMapper.map_http_error(503, "");
+        assert!(matches!(err, ProviderError::ProviderUnavailable { .. }));
+    }
+}
@@ -0,0 +1,14 @@
+//! Voyage AI Provider
+//!
+//! Embeddings-only vendor: text embeddings via `/v1/embeddings` and document
+//! reranking via `/v1/rerank`. Chat completion is not offered.
+
+pub mod config;
+pub mod embed;
+pub mod error;
+pub mod provider;
+pub mod rerank;
+
+pub use config::VoyageConfig;
+pub use error::VoyageErrorMapper;
+pub use provider::VoyageProvider;
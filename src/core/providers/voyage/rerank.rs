@@ -0,0 +1,153 @@
+//! Voyage AI Rerank Transformation
+
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use super::embed::normalize_model;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::rerank::{RerankRequest, RerankResponse, RerankResult, RerankUsage};
+
+/// Voyage AI rerank transformation
+pub struct VoyageRerankTransformation;
+
+impl VoyageRerankTransformation {
+    /// Build the `/v1/rerank` request body
+    ///
+    /// Voyage calls `top_n` `top_k`; `truncation` passes through
+    /// `extra_params`.
+    pub fn transform_request(request: &RerankRequest) -> Result<Value, ProviderError> {
+        if request.documents.is_empty() {
+            return Err(ProviderError::invalid_request(
+                "voyage",
+                "Rerank requires at least one document",
+            ));
+        }
+
+        let documents: Vec<&str> = request.documents.iter().map(|d| d.get_text()).collect();
+
+        let mut body = json!({
+            "model": normalize_model(&request.model),
+            "query": request.query,
+            "documents": documents,
+        });
+
+        if let Some(top_n) = request.top_n {
+            body["top_k"] = json!(top_n);
+        }
+        if let Some(truncation) = request.extra_params.get("truncation") {
+            body["truncation"] = truncation.clone();
+        }
+
+        Ok(body)
+    }
+
+    /// Convert a `/v1/rerank` response
+    ///
+    /// Documents are re-attached from the request rather than asking Voyage to
+    /// echo them, so structured documents keep their metadata.
+    pub fn transform_response(
+        response: Value,
+        request: &RerankRequest,
+    ) -> Result<RerankResponse, ProviderError> {
+        let return_documents = request.return_documents.unwrap_or(true);
+
+        let results = response
+            .get("data")
+            .and_then(|r| r.as_array())
+            .ok_or_else(|| {
+                ProviderError::response_parsing("voyage", "Missing 'data' in rerank response")
+            })?
+            .iter()
+            .map(|r| {
+                let index = r.get("index").and_then(|i| i.as_u64()).unwrap_or(0) as usize;
+                RerankResult {
+                    index,
+                    relevance_score: r
+                        .get("relevance_score")
+                        .and_then(|s| s.as_f64())
+                        .unwrap_or(0.0),
+                    document: if return_documents {
+                        request.documents.get(index).cloned()
+                    } else {
+                        None
+                    },
+                }
+            })
+            .collect();
+
+        let usage = response
+            .pointer("/usage/total_tokens")
+            .and_then(|t| t.as_u64())
+            .map(|tokens| RerankUsage {
+                total_tokens: Some(tokens as u32),
+                ..Default::default()
+            });
+
+        Ok(RerankResponse {
+            id: format!("rerank-{}", uuid::Uuid::new_v4().simple()),
+            results,
+            model: normalize_model(&request.model).to_string(),
+            usage,
+            meta: HashMap::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rerank::RerankDocument;
+
+    fn request() -> RerankRequest {
+        RerankRequest {
+            model: "voyage/rerank-2.5".to_string(),
+            query: "capital of France".to_string(),
+            documents: vec![
+                RerankDocument::text("Berlin is in Germany"),
+                RerankDocument::text("Paris is the capital of France"),
+            ],
+            top_n: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut req = request();
+        req.extra_params
+            .insert("truncation".to_string(), json!(false));
+
+        let body = VoyageRerankTransformation::transform_request(&req).unwrap();
+        assert_eq!(body["model"], "rerank-2.5");
+        assert_eq!(body["documents"][1], "Paris is the capital of France");
+        assert_eq!(body["top_k"], 1);
+        assert_eq!(body["truncation"], false);
+        assert!(body.get("top_n").is_none());
+    }
+
+    #[test]
+    fn test_transform_request_requires_documents() {
+        let mut req = request();
+        req.documents.clear();
+        assert!(VoyageRerankTransformation::transform_request(&req).is_err());
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let raw = json!({
+            "object": "list",
+            "data": [{"relevance_score": 0.91, "index": 1}],
+            "model": "rerank-2.5",
+            "usage": {"total_tokens": 26}
+        });
+
+        let response = VoyageRerankTransformation::transform_response(raw, &request()).unwrap();
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].index, 1);
+        assert_eq!(
+            response.results[0].document.as_ref().unwrap().get_text(),
+            "Paris is the capital of France"
+        );
+        assert_eq!(response.usage.unwrap().total_tokens, Some(26));
+    }
+}
@@ -20,6 +20,7 @@ pub enum XAIModel {
     Grok2Mini,
     Grok21212,   // Grok 2 (December 2024 update)
     Grok2Vision, // Grok 2 with vision
+    Grok2Image,  // Grok 2 image generation
     // Experimental
     GrokBeta,
     GrokVision,
@@ -50,6 +51,8 @@ pub struct ModelInfo {
     pub output_cost_per_million: f64,
     /// Reasoning tokens cost per million (if applicable)
     pub reasoning_cost_per_million: Option<f64>,
+    /// Cost per generated image (image generation models only)
+    pub output_cost_per_image: Option<f64>,
 }
 
 /// Static model configurations
@@ -71,6 +74,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 3.0,
             output_cost_per_million: 15.0,
             reasoning_cost_per_million: Some(15.0),
+            output_cost_per_image: None,
         },
     );
 
@@ -89,6 +93,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 3.0,
             output_cost_per_million: 15.0,
             reasoning_cost_per_million: Some(10.0),
+            output_cost_per_image: None,
         },
     );
 
@@ -106,6 +111,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 0.3,
             output_cost_per_million: 0.5,
             reasoning_cost_per_million: Some(0.5),
+            output_cost_per_image: None,
         },
     );
 
@@ -123,6 +129,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 5.0,
             output_cost_per_million: 25.0,
             reasoning_cost_per_million: None,
+            output_cost_per_image: None,
         },
     );
 
@@ -141,6 +148,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 2.0,
             output_cost_per_million: 10.0,
             reasoning_cost_per_million: Some(10.0),
+            output_cost_per_image: None,
         },
     );
 
@@ -158,6 +166,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 0.5,
             output_cost_per_million: 2.0,
             reasoning_cost_per_million: None,
+            output_cost_per_image: None,
         },
     );
 
@@ -175,6 +184,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 2.0,
             output_cost_per_million: 10.0,
             reasoning_cost_per_million: Some(10.0),
+            output_cost_per_image: None,
         },
     );
 
@@ -192,6 +202,25 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 2.0,
             output_cost_per_million: 10.0,
             reasoning_cost_per_million: None,
+            output_cost_per_image: None,
+        },
+    );
+
+    configs.insert(
+        "grok-2-image-1212",
+        ModelInfo {
+            model_id: "grok-2-image-1212",
+            display_name: "Grok-2 Image (Dec 2024)",
+            context_length: 1024, // Prompt only
+            max_output_tokens: 0,
+            supports_tools: false,
+            supports_vision: false,
+            supports_web_search: false,
+            supports_reasoning: false,
+            input_cost_per_million: 0.0,
+            output_cost_per_million: 0.0,
+            reasoning_cost_per_million: None,
+            output_cost_per_image: Some(0.07),
         },
     );
 
@@ -210,6 +239,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 5.0,
             output_cost_per_million: 15.0,
             reasoning_cost_per_million: Some(15.0),
+            output_cost_per_image: None,
         },
     );
 
@@ -227,6 +257,7 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
             input_cost_per_million: 5.0,
             output_cost_per_million: 15.0,
             reasoning_cost_per_million: None,
+            output_cost_per_image: None,
         },
     );
 
@@ -237,6 +268,11 @@ static MODEL_CONFIGS: LazyLock<HashMap<&'static str, ModelInfo>> = LazyLock::new
 pub fn get_model_info(model_id: &str) -> Option<&'static ModelInfo> {
     // Handle xai/ prefix
     let model_id = model_id.strip_prefix("xai/").unwrap_or(model_id);
+    // Aliases of the current image model
+    let model_id = match model_id {
+        "grok-2-image" | "grok-2-image-latest" => "grok-2-image-1212",
+        other => other,
+    };
     MODEL_CONFIGS.get(model_id)
 }
 
@@ -245,6 +281,20 @@ pub fn get_available_models() -> Vec<&'static str> {
     MODEL_CONFIGS.keys().copied().collect()
 }
 
+/// Check if a model generates images rather than chat completions
+pub fn is_image_generation_model(model_id: &str) -> bool {
+    get_model_info(model_id)
+        .map(|info| info.output_cost_per_image.is_some())
+        .unwrap_or(false)
+}
+
+/// Check if a model accepts image inputs
+pub fn supports_vision(model_id: &str) -> bool {
+    get_model_info(model_id)
+        .map(|info| info.supports_vision)
+        .unwrap_or(false)
+}
+
 /// Check if a model supports reasoning tokens
 pub fn supports_reasoning_tokens(model_id: &str) -> bool {
     get_model_info(model_id)
@@ -275,6 +325,12 @@ pub fn calculate_cost_with_reasoning(
     Some(input_cost + output_cost + reasoning_cost)
 }
 
+/// Calculate the cost of generated images
+pub fn calculate_image_cost(model_id: &str, images: u32) -> Option<f64> {
+    let cost_per_image = get_model_info(model_id)?.output_cost_per_image?;
+    Some(images as f64 * cost_per_image)
+}
+
 impl XAIModel {
     /// Get the API model ID
     pub fn model_id(&self) -> &'static str {
@@ -287,6 +343,7 @@ impl XAIModel {
             XAIModel::Grok2Mini => "grok-2-mini",
             XAIModel::Grok21212 => "grok-2-1212",
             XAIModel::Grok2Vision => "grok-2-vision-1212",
+            XAIModel::Grok2Image => "grok-2-image-1212",
             XAIModel::GrokBeta => "grok-beta",
             XAIModel::GrokVision => "grok-vision-beta",
         }
@@ -380,6 +437,20 @@ mod tests {
         assert!((cost - expected).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_image_generation_model() {
+        assert!(is_image_generation_model("grok-2-image-1212"));
+        assert!(is_image_generation_model("xai/grok-2-image"));
+        assert!(!is_image_generation_model("grok-2-vision-1212"));
+
+        let cost = calculate_image_cost("grok-2-image-1212", 3).unwrap();
+        assert!((cost - 0.21).abs() < 0.0001);
+        assert!(calculate_image_cost("grok-2", 1).is_none());
+
+        assert!(supports_vision("grok-2-vision-1212"));
+        assert!(!supports_vision("grok-2-mini"));
+    }
+
     #[test]
     fn test_xai_model_enum() {
         assert_eq!(XAIModel::Grok4.model_id(), "grok-4");
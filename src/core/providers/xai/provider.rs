@@ -1,6 +1,8 @@
 //! Main xAI Provider Implementation
 //!
 //! Implements the LLMProvider trait for xAI's Grok models with OpenAI-compatible API.
+//! Vision models accept `image_url` content parts; `grok-2-image` generates
+//! images through `/images/generations`.
 
 use async_trait::async_trait;
 use futures::Stream;
@@ -11,15 +13,23 @@ use tracing::{debug, info};
 
 use super::config::XAIConfig;
 use super::error::{XAIError, XAIErrorMapper};
-use super::model_info::{calculate_cost_with_reasoning, get_available_models, get_model_info};
+use super::model_info::{
+    calculate_cost_with_reasoning, get_available_models, get_model_info, is_image_generation_model,
+    supports_vision,
+};
 use crate::core::providers::base::{GlobalPoolManager, HttpMethod, header};
 use crate::core::traits::{
     ProviderConfig as _, provider::llm_provider::trait_definition::LLMProvider,
 };
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest},
-    responses::{ChatChunk, ChatResponse, CompletionTokensDetails, EmbeddingResponse},
+    content::{ContentPart, ImageUrl},
+    message::MessageContent,
+    requests::{ChatRequest, EmbeddingRequest, ImageGenerationRequest},
+    responses::{
+        ChatChunk, ChatResponse, CompletionTokensDetails, EmbeddingResponse,
+        ImageGenerationResponse,
+    },
 };
 
 /// Static capabilities for xAI provider
@@ -27,8 +37,15 @@ const XAI_CAPABILITIES: &[ProviderCapability] = &[
     ProviderCapability::ChatCompletion,
     ProviderCapability::ChatCompletionStream,
     ProviderCapability::ToolCalling,
+    ProviderCapability::ImageGeneration,
 ];
 
+/// Image model used when a request names none
+const DEFAULT_IMAGE_MODEL: &str = "grok-2-image-1212";
+
+/// Image formats accepted by vision models
+const SUPPORTED_IMAGE_TYPES: &[&str] = &["image/jpeg", "image/jpg", "image/png"];
+
 /// xAI provider implementation
 #[derive(Debug, Clone)]
 pub struct XAIProvider {
@@ -53,13 +70,23 @@ impl XAIProvider {
             .iter()
             .filter_map(|id| get_model_info(id))
             .map(|info| {
-                let mut capabilities = vec![
-                    ProviderCapability::ChatCompletion,
-                    ProviderCapability::ChatCompletionStream,
-                ];
-                if info.supports_tools {
-                    capabilities.push(ProviderCapability::ToolCalling);
-                }
+                let mut metadata = HashMap::new();
+                let capabilities = if let Some(cost_per_image) = info.output_cost_per_image {
+                    metadata.insert(
+                        "cost_per_image".to_string(),
+                        serde_json::Value::from(cost_per_image),
+                    );
+                    vec![ProviderCapability::ImageGeneration]
+                } else {
+                    let mut capabilities = vec![
+                        ProviderCapability::ChatCompletion,
+                        ProviderCapability::ChatCompletionStream,
+                    ];
+                    if info.supports_tools {
+                        capabilities.push(ProviderCapability::ToolCalling);
+                    }
+                    capabilities
+                };
 
                 ModelInfo {
                     id: format!("xai/{}", info.model_id),
@@ -67,7 +94,7 @@ impl XAIProvider {
                     provider: "xai".to_string(),
                     max_context_length: info.context_length,
                     max_output_length: Some(info.max_output_tokens),
-                    supports_streaming: true,
+                    supports_streaming: info.output_cost_per_image.is_none(),
                     supports_tools: info.supports_tools,
                     supports_multimodal: info.supports_vision,
                     input_cost_per_1k_tokens: Some(info.input_cost_per_million / 1000.0),
@@ -76,7 +103,7 @@ impl XAIProvider {
                     capabilities,
                     created_at: None,
                     updated_at: None,
-                    metadata: HashMap::new(),
+                    metadata,
                 }
             })
             .collect();
@@ -135,6 +162,85 @@ impl XAIProvider {
         }
     }
 
+    /// Check image inputs and convert base64 image parts to `image_url` data URLs
+    ///
+    /// Only vision models accept images, and only as JPEG or PNG.
+    fn prepare_image_inputs(request: &mut ChatRequest) -> Result<(), XAIError> {
+        if is_image_generation_model(&request.model) {
+            return Err(XAIError::InvalidRequestError(format!(
+                "{} generates images; use image_generation instead of chat",
+                request.model
+            )));
+        }
+
+        let vision = supports_vision(&request.model);
+        for message in &mut request.messages {
+            let Some(MessageContent::Parts(parts)) = &mut message.content else {
+                continue;
+            };
+
+            for part in parts.iter_mut() {
+                if let ContentPart::Image { source, detail, .. } = part {
+                    *part = ContentPart::ImageUrl {
+                        image_url: ImageUrl {
+                            url: format!("data:{};base64,{}", source.media_type, source.data),
+                            detail: detail.take(),
+                        },
+                    };
+                }
+
+                let ContentPart::ImageUrl { image_url } = part else {
+                    continue;
+                };
+
+                if !vision {
+                    return Err(XAIError::InvalidRequestError(format!(
+                        "Model {} does not accept image inputs; use a vision model such as grok-2-vision-1212",
+                        request.model
+                    )));
+                }
+
+                if let Some(media_type) = image_url
+                    .url
+                    .strip_prefix("data:")
+                    .and_then(|rest| rest.split(';').next())
+                {
+                    if !SUPPORTED_IMAGE_TYPES.contains(&media_type) {
+                        return Err(XAIError::InvalidRequestError(format!(
+                            "Unsupported image type {}; xAI accepts JPEG and PNG",
+                            media_type
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Convert an `/images/generations` response
+    fn transform_image_response(
+        mut response: serde_json::Value,
+    ) -> Result<ImageGenerationResponse, XAIError> {
+        if let Some(error) = response.get("error") {
+            let message = error
+                .get("message")
+                .and_then(|m| m.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| error.to_string());
+            return Err(XAIError::ApiError(message));
+        }
+
+        // xAI omits the creation time
+        if let Some(obj) = response.as_object_mut() {
+            obj.entry("created")
+                .or_insert_with(|| serde_json::json!(chrono::Utc::now().timestamp()));
+        }
+
+        serde_json::from_value(response)
+            .map_err(|e| XAIError::ApiError(format!("Failed to parse image response: {}", e)))
+    }
+
     /// Extract reasoning tokens from response if present
     fn extract_reasoning_tokens(&self, response: &serde_json::Value) -> Option<u32> {
         response
@@ -224,9 +330,11 @@ impl LLMProvider for XAIProvider {
 
     async fn transform_request(
         &self,
-        request: ChatRequest,
+        mut request: ChatRequest,
         _context: RequestContext,
     ) -> Result<serde_json::Value, Self::Error> {
+        Self::prepare_image_inputs(&mut request)?;
+
         // Convert to JSON value
         let mut request_json = serde_json::to_value(&request)
             .map_err(|e| XAIError::InvalidRequestError(e.to_string()))?;
@@ -291,10 +399,11 @@ impl LLMProvider for XAIProvider {
 
     async fn chat_completion(
         &self,
-        request: ChatRequest,
+        mut request: ChatRequest,
         _context: RequestContext,
     ) -> Result<ChatResponse, Self::Error> {
         debug!("xAI chat request: model={}", request.model);
+        Self::prepare_image_inputs(&mut request)?;
 
         // Transform and execute
         let mut request_json = serde_json::to_value(&request)
@@ -346,6 +455,7 @@ impl LLMProvider for XAIProvider {
 
         // Set streaming flag
         request.stream = true;
+        Self::prepare_image_inputs(&mut request)?;
 
         // Get API configuration
         let api_key = self
@@ -409,6 +519,55 @@ impl LLMProvider for XAIProvider {
         ))
     }
 
+    async fn image_generation(
+        &self,
+        request: ImageGenerationRequest,
+        _context: RequestContext,
+    ) -> Result<ImageGenerationResponse, Self::Error> {
+        let model = request.model.as_deref().unwrap_or(DEFAULT_IMAGE_MODEL);
+        let model = model.strip_prefix("xai/").unwrap_or(model);
+        debug!("xAI image request: model={}", model);
+
+        if !is_image_generation_model(model) {
+            return Err(XAIError::InvalidRequestError(format!(
+                "Model {} does not generate images; use grok-2-image",
+                model
+            )));
+        }
+        if request.prompt.is_empty() {
+            return Err(XAIError::InvalidRequestError(
+                "Prompt cannot be empty".to_string(),
+            ));
+        }
+        if let Some(n) = request.n {
+            if n == 0 || n > 10 {
+                return Err(XAIError::InvalidRequestError(
+                    "Number of images must be between 1 and 10".to_string(),
+                ));
+            }
+        }
+        if request.size.is_some() || request.quality.is_some() || request.style.is_some() {
+            debug!("xAI image generation ignores size, quality and style");
+        }
+
+        let mut body = serde_json::json!({
+            "model": model,
+            "prompt": request.prompt,
+        });
+        if let Some(n) = request.n {
+            body["n"] = serde_json::json!(n);
+        }
+        if let Some(response_format) = &request.response_format {
+            body["response_format"] = serde_json::json!(response_format);
+        }
+        if let Some(user) = &request.user {
+            body["user"] = serde_json::json!(user);
+        }
+
+        let response = self.execute_request("/images/generations", body).await?;
+        Self::transform_image_response(response)
+    }
+
     async fn health_check(&self) -> HealthStatus {
         // Simple health check - try to get models list
         let url = format!("{}/models", self.config.get_api_base());
@@ -479,6 +638,109 @@ mod tests {
         assert!(XAI_CAPABILITIES.contains(&ProviderCapability::ChatCompletion));
         assert!(XAI_CAPABILITIES.contains(&ProviderCapability::ChatCompletionStream));
         assert!(XAI_CAPABILITIES.contains(&ProviderCapability::ToolCalling));
+        assert!(XAI_CAPABILITIES.contains(&ProviderCapability::ImageGeneration));
+    }
+
+    fn image_request(model: &str, part: ContentPart) -> ChatRequest {
+        ChatRequest {
+            model: model.to_string(),
+            messages: vec![crate::core::types::chat::ChatMessage {
+                content: Some(MessageContent::Parts(vec![
+                    ContentPart::Text {
+                        text: "What is in this image?".to_string(),
+                        cache_control: None,
+                    },
+                    part,
+                ])),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_prepare_image_inputs() {
+        let url_part = ContentPart::ImageUrl {
+            image_url: ImageUrl {
+                url: "https://example.com/cat.png".to_string(),
+                detail: Some("high".to_string()),
+            },
+        };
+
+        let mut request = image_request("grok-2-vision-1212", url_part.clone());
+        assert!(XAIProvider::prepare_image_inputs(&mut request).is_ok());
+
+        // Text-only models reject images
+        let mut request = image_request("grok-2-mini", url_part);
+        assert!(XAIProvider::prepare_image_inputs(&mut request).is_err());
+
+        // Base64 parts become data URLs
+        let mut request = image_request(
+            "grok-2-vision-1212",
+            ContentPart::Image {
+                source: crate::core::types::content::ImageSource {
+                    media_type: "image/jpeg".to_string(),
+                    data: "aGVsbG8=".to_string(),
+                },
+                detail: None,
+                image_url: None,
+            },
+        );
+        XAIProvider::prepare_image_inputs(&mut request).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["messages"][0]["content"][1]["type"], "image_url");
+        assert_eq!(
+            json["messages"][0]["content"][1]["image_url"]["url"],
+            "data:image/jpeg;base64,aGVsbG8="
+        );
+
+        // Only JPEG and PNG are accepted
+        let mut request = image_request(
+            "grok-2-vision-1212",
+            ContentPart::ImageUrl {
+                image_url: ImageUrl {
+                    url: "data:image/gif;base64,R0lGOD".to_string(),
+                    detail: None,
+                },
+            },
+        );
+        assert!(XAIProvider::prepare_image_inputs(&mut request).is_err());
+    }
+
+    #[test]
+    fn test_transform_image_response() {
+        let response = serde_json::json!({
+            "data": [{
+                "url": "https://imgen.x.ai/xai-imgen/xai-tmp-imgen-1234.jpeg",
+                "revised_prompt": "A photorealistic cat on a windowsill"
+            }]
+        });
+
+        let images = XAIProvider::transform_image_response(response).unwrap();
+        assert_eq!(images.data.len(), 1);
+        assert!(images.created > 0);
+        assert_eq!(
+            images.data[0].revised_prompt.as_deref(),
+            Some("A photorealistic cat on a windowsill")
+        );
+
+        let error = serde_json::json!({"error": {"message": "Invalid prompt"}});
+        assert!(XAIProvider::transform_image_response(error).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_image_model_listed() {
+        let provider = XAIProvider::with_api_key("test_key").await.unwrap();
+        let image_model = provider
+            .models()
+            .iter()
+            .find(|m| m.id == "xai/grok-2-image-1212")
+            .unwrap();
+        assert_eq!(
+            image_model.capabilities,
+            vec![ProviderCapability::ImageGeneration]
+        );
+        assert_eq!(image_model.metadata["cost_per_image"], 0.07);
     }
 
     #[test]
@@ -0,0 +1,148 @@
+//! Zhipu AI Token Signing
+//!
+//! API keys have the form `{id}.{secret}`. Requests are not authenticated with
+//! the key itself but with an HS256 JWT whose payload names the key id and
+//! whose header carries the non-standard `sign_type: SIGN` field.
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use std::sync::{Arc, RwLock};
+
+use crate::core::providers::unified_provider::ProviderError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Lifetime of a signed token
+const TOKEN_TTL_MS: i64 = 3 * 60 * 1000;
+
+/// Tokens are re-signed this long before they expire
+const REFRESH_MARGIN_MS: i64 = 30 * 1000;
+
+/// Split an API key into its id and secret
+pub fn parse_api_key(api_key: &str) -> Option<(&str, &str)> {
+    match api_key.split_once('.') {
+        Some((id, secret)) if !id.is_empty() && !secret.is_empty() => Some((id, secret)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    expires_at_ms: i64,
+}
+
+/// Signs and caches request tokens for one API key
+#[derive(Debug, Clone)]
+pub struct ZhipuTokenSigner {
+    id: String,
+    secret: String,
+    cache: Arc<RwLock<Option<CachedToken>>>,
+}
+
+impl ZhipuTokenSigner {
+    pub fn new(api_key: &str) -> Result<Self, ProviderError> {
+        let (id, secret) = parse_api_key(api_key).ok_or_else(|| {
+            ProviderError::configuration("zhipu", "API key must have the form '{id}.{secret}'")
+        })?;
+
+        Ok(Self {
+            id: id.to_string(),
+            secret: secret.to_string(),
+            cache: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    /// Sign a token issued at `now_ms` (milliseconds since the epoch)
+    pub fn sign(&self, now_ms: i64) -> Result<String, ProviderError> {
+        let header = json!({ "alg": "HS256", "sign_type": "SIGN" });
+        let payload = json!({
+            "api_key": self.id,
+            "exp": now_ms + TOKEN_TTL_MS,
+            "timestamp": now_ms,
+        });
+
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(payload.to_string())
+        );
+
+        let mut mac = HmacSha256::new_from_slice(self.secret.as_bytes())
+            .map_err(|e| ProviderError::configuration("zhipu", format!("HMAC key error: {}", e)))?;
+        mac.update(signing_input.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        Ok(format!("{}.{}", signing_input, signature))
+    }
+
+    /// Current token, re-signed when close to expiry
+    pub fn token(&self) -> Result<String, ProviderError> {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+
+        let cached = self.cache.read().ok().and_then(|cache| cache.clone());
+        if let Some(cached) = cached.filter(|c| c.expires_at_ms - REFRESH_MARGIN_MS > now_ms) {
+            return Ok(cached.token);
+        }
+
+        let token = self.sign(now_ms)?;
+        if let Ok(mut cache) = self.cache.write() {
+            *cache = Some(CachedToken {
+                token: token.clone(),
+                expires_at_ms: now_ms + TOKEN_TTL_MS,
+            });
+        }
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_part(part: &str) -> serde_json::Value {
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(part).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_api_key() {
+        assert_eq!(parse_api_key("abc.def"), Some(("abc", "def")));
+        assert_eq!(parse_api_key("abcdef"), None);
+        assert_eq!(parse_api_key(".def"), None);
+        assert!(ZhipuTokenSigner::new("no-secret").is_err());
+    }
+
+    #[test]
+    fn test_sign() {
+        let signer = ZhipuTokenSigner::new("4f6a2c.s3cr3t").unwrap();
+        let token = signer.sign(1_700_000_000_000).unwrap();
+        let parts: Vec<&str> = token.split('.').collect();
+        assert_eq!(parts.len(), 3);
+
+        let header = decode_part(parts[0]);
+        assert_eq!(header["alg"], "HS256");
+        assert_eq!(header["sign_type"], "SIGN");
+
+        let payload = decode_part(parts[1]);
+        assert_eq!(payload["api_key"], "4f6a2c");
+        assert_eq!(payload["timestamp"], 1_700_000_000_000i64);
+        assert_eq!(payload["exp"], 1_700_000_180_000i64);
+
+        let mut mac = HmacSha256::new_from_slice(b"s3cr3t").unwrap();
+        mac.update(format!("{}.{}", parts[0], parts[1]).as_bytes());
+        mac.verify_slice(&URL_SAFE_NO_PAD.decode(parts[2]).unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_token_is_cached() {
+        let signer = ZhipuTokenSigner::new("4f6a2c.s3cr3t").unwrap();
+        let first = signer.token().unwrap();
+        assert_eq!(signer.token().unwrap(), first);
+
+        // Clones share the cache
+        assert_eq!(signer.clone().token().unwrap(), first);
+    }
+}
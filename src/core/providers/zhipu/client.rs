@@ -0,0 +1,263 @@
+//! Zhipu Client
+//!
+//! How the GLM API departs from OpenAI's chat completions
+
+use serde_json::{Map, Value, json};
+
+use crate::core::providers::base::{OpenAICompatibleClient, openai_compatible};
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::common::ProviderCapability;
+
+use super::{ZhipuConfig, ZhipuTokenSigner};
+
+/// Zhipu API client logic
+#[derive(Debug, Clone)]
+pub struct ZhipuClient {
+    signer: ZhipuTokenSigner,
+}
+
+impl OpenAICompatibleClient for ZhipuClient {
+    type Config = ZhipuConfig;
+
+    const NAME: &'static str = "zhipu";
+
+    const CAPABILITIES: &'static [ProviderCapability] = &[
+        ProviderCapability::ChatCompletion,
+        ProviderCapability::ChatCompletionStream,
+        ProviderCapability::ToolCalling,
+    ];
+
+    const SUPPORTED_PARAMS: &'static [&'static str] = &[
+        "temperature",
+        "max_tokens",
+        "max_completion_tokens",
+        "top_p",
+        "stop",
+        "stream",
+        "tools",
+        "tool_choice",
+        "response_format",
+        "user",
+    ];
+
+    // Rejected by the GLM API instead of ignored
+    const UNSUPPORTED_FIELDS: &'static [&'static str] = &[
+        "frequency_penalty",
+        "presence_penalty",
+        "logit_bias",
+        "logprobs",
+        "top_logprobs",
+        "n",
+        "seed",
+        "parallel_tool_calls",
+        "functions",
+        "function_call",
+    ];
+
+    fn new(config: &ZhipuConfig) -> Result<Self, ProviderError> {
+        let signer = ZhipuTokenSigner::new(config.base.api_key.as_deref().unwrap_or_default())?;
+        Ok(Self { signer })
+    }
+
+    /// Strip the routing prefix so `zhipu/glm-4.5` and `glm/glm-4.5` become `glm-4.5`
+    fn normalize_model(model: &str) -> &str {
+        model
+            .strip_prefix("zhipu/")
+            .or_else(|| model.strip_prefix("glm/"))
+            .unwrap_or(model)
+    }
+
+    /// Sign a fresh token if the cached one is about to expire
+    fn authorization(&self, _config: &ZhipuConfig) -> Result<Option<String>, ProviderError> {
+        Ok(Some(format!("Bearer {}", self.signer.token()?)))
+    }
+
+    /// `user` is sent as `user_id`. GLM only accepts `tool_choice: auto`, so
+    /// `none` is emulated by dropping the tools and anything else becomes
+    /// `auto`.
+    fn prepare_request(body: &mut Map<String, Value>) {
+        if let Some(user) = body.remove("user") {
+            body.entry("user_id").or_insert(user);
+        }
+        if let Some(tool_choice) = body.remove("tool_choice") {
+            if tool_choice == "none" {
+                body.remove("tools");
+            } else {
+                body.insert("tool_choice".to_string(), json!("auto"));
+            }
+        }
+    }
+
+    fn map_http_error(status_code: u16, response_body: &str) -> ProviderError {
+        // Zhipu also answers 429 when the account is out of balance, with
+        // `{"error": {"code": "1113", "message": "..."}}`
+        let out_of_balance = serde_json::from_str::<Value>(response_body)
+            .ok()
+            .is_some_and(|v| v.pointer("/error/code").and_then(|c| c.as_str()) == Some("1113"));
+
+        if status_code == 429 && out_of_balance {
+            return ProviderError::quota_exceeded(
+                Self::NAME,
+                openai_compatible::error_message(response_body),
+            );
+        }
+        openai_compatible::map_http_error(Self::NAME, status_code, response_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::zhipu::ZhipuProvider;
+    use crate::core::traits::provider::llm_provider::trait_definition::LLMProvider;
+    use crate::core::types::requests::{
+        ChatMessage, ChatRequest, MessageContent, MessageRole, ToolChoice,
+    };
+
+    fn test_provider() -> ZhipuProvider {
+        let mut config = ZhipuConfig::new("zhipu");
+        config.base.api_key = Some("4f6a2c.s3cr3t".to_string());
+        ZhipuProvider::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_model() {
+        assert_eq!(ZhipuClient::normalize_model("zhipu/glm-4.5"), "glm-4.5");
+        assert_eq!(
+            ZhipuClient::normalize_model("glm/glm-4.5-air"),
+            "glm-4.5-air"
+        );
+        assert_eq!(ZhipuClient::normalize_model("glm-4-flash"), "glm-4-flash");
+    }
+
+    #[test]
+    fn test_transform_request() {
+        let mut request = ChatRequest::new("glm/glm-4.5");
+        request.messages = vec![ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Text("Hello".to_string())),
+            ..Default::default()
+        }];
+        request.max_completion_tokens = Some(256);
+        request.user = Some("user-42".to_string());
+        request.seed = Some(7);
+        request.tool_choice = Some(ToolChoice::String("required".to_string()));
+
+        let body = ZhipuClient::transform_chat_request(request).unwrap();
+        assert_eq!(body["model"], "glm-4.5");
+        assert_eq!(body["max_tokens"], 256);
+        assert_eq!(body["user_id"], "user-42");
+        assert_eq!(body["tool_choice"], "auto");
+        assert!(body.get("user").is_none());
+        assert!(body.get("seed").is_none());
+    }
+
+    #[test]
+    fn test_transform_request_tool_choice_none() {
+        let mut request = ChatRequest::new("glm-4.5");
+        request.tools = Some(vec![]);
+        request.tool_choice = Some(ToolChoice::String("none".to_string()));
+
+        let body = ZhipuClient::transform_chat_request(request).unwrap();
+        assert!(body.get("tools").is_none());
+        assert!(body.get("tool_choice").is_none());
+    }
+
+    #[test]
+    fn test_transform_response_tool_calls() {
+        let raw = br#"{
+            "id": "20240709163712d1b1e8b2a7f44a7e",
+            "request_id": "20240709163712d1b1e8b2a7f44a7e",
+            "created": 1720514232,
+            "model": "glm-4.5",
+            "choices": [{
+                "index": 0,
+                "finish_reason": "tool_calls",
+                "message": {
+                    "role": "assistant",
+                    "tool_calls": [{
+                        "id": "call_2024070916371295cd8e2b2c8f4a50",
+                        "type": "function",
+                        "index": 0,
+                        "function": {"name": "get_weather", "arguments": "{\"city\":\"Beijing\"}"}
+                    }]
+                }
+            }],
+            "usage": {"prompt_tokens": 140, "completion_tokens": 12, "total_tokens": 152}
+        }"#;
+
+        let response = ZhipuClient::transform_chat_response(raw).unwrap();
+        assert_eq!(response.object, "chat.completion");
+        let tool_calls = response.choices[0].message.tool_calls.as_ref().unwrap();
+        assert_eq!(tool_calls[0].function.name, "get_weather");
+        assert_eq!(response.usage.unwrap().total_tokens, 152);
+    }
+
+    #[test]
+    fn test_error_out_of_balance() {
+        let err = ZhipuClient::map_http_error(429, "");
+        assert!(matches!(err, ProviderError::RateLimit { .. }));
+
+        let err = ZhipuClient::map_http_error(
+            429,
+            r#"{"error":{"code":"1113","message":"您的账户已欠费，请充值后重试。"}}"#,
+        );
+        match err {
+            ProviderError::QuotaExceeded { message, .. } => {
+                assert_eq!(message, "您的账户已欠费，请充值后重试。")
+            }
+            other => panic!("Expected QuotaExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_provider_requires_api_key() {
+        let mut config = ZhipuConfig::new("zhipu");
+        config.base.api_key = None;
+        assert!(ZhipuProvider::new(config).is_err());
+    }
+
+    #[test]
+    fn test_request_headers_carry_signed_token() {
+        let provider = test_provider();
+        let headers = provider.get_request_headers().unwrap();
+        let (_, value) = headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+            .unwrap();
+        let token = value.strip_prefix("Bearer ").unwrap();
+        assert_eq!(token.split('.').count(), 3);
+        assert!(!token.contains("s3cr3t"));
+    }
+
+    #[test]
+    fn test_provider_basics() {
+        let provider = test_provider();
+        assert_eq!(provider.name(), "zhipu");
+        assert!(provider.supports_streaming());
+        assert!(provider.supports_model("zhipu/glm-4.5"));
+        assert!(provider.supports_model("glm/glm-4.5"));
+        assert!(!provider.supports_model("gpt-4"));
+        assert!(provider.models().iter().any(|m| m.id == "glm-4.5"));
+        assert!(provider.models().iter().all(|m| m.provider == "zhipu"));
+    }
+
+    #[test]
+    fn test_endpoint() {
+        let provider = test_provider();
+        assert_eq!(
+            provider.endpoint("/chat/completions"),
+            "https://open.bigmodel.cn/api/paas/v4/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calculate_cost() {
+        let provider = test_provider();
+        let cost = provider
+            .calculate_cost("glm/glm-4.5", 1_000_000, 1_000_000)
+            .await
+            .unwrap();
+        assert!((cost - 2.8).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,87 @@
+//! Zhipu Configuration
+
+use super::auth::parse_api_key;
+use crate::core::traits::ProviderConfig;
+use crate::define_provider_config;
+
+define_provider_config!(ZhipuConfig {});
+
+impl ZhipuConfig {
+    /// Create configuration from environment variables
+    ///
+    /// Reads `ZHIPU_*`, falling back to the `ZHIPUAI_API_KEY` name used by the
+    /// official SDKs.
+    pub fn from_env() -> Self {
+        let mut config = Self::new("zhipu");
+        if config.base.api_key.is_none() {
+            config.base.api_key = std::env::var("ZHIPUAI_API_KEY").ok();
+        }
+        config
+    }
+}
+
+impl ProviderConfig for ZhipuConfig {
+    fn validate(&self) -> Result<(), String> {
+        self.base.validate("zhipu")?;
+
+        match self.base.api_key.as_deref().and_then(parse_api_key) {
+            Some(_) => Ok(()),
+            None => Err("zhipu API key must have the form '{id}.{secret}'".to_string()),
+        }
+    }
+
+    fn api_key(&self) -> Option<&str> {
+        self.base.api_key.as_deref()
+    }
+
+    fn api_base(&self) -> Option<&str> {
+        self.base.api_base.as_deref()
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        self.base.timeout_duration()
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.base.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zhipu_config_default_api_base() {
+        let config = ZhipuConfig::new("zhipu");
+        assert_eq!(
+            config.base.api_base,
+            Some("https://open.bigmodel.cn/api/paas/v4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_zhipu_validate_missing_api_key() {
+        let mut config = ZhipuConfig::new("zhipu");
+        config.base.api_key = None;
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("API key"));
+    }
+
+    #[test]
+    fn test_zhipu_validate_success() {
+        let mut config = ZhipuConfig::new("zhipu");
+        config.base.api_key = Some("4f6a2c.s3cr3t".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_zhipu_validate_key_format() {
+        let mut config = ZhipuConfig::new("zhipu");
+        config.base.api_key = Some("test-key".to_string());
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("{id}.{secret}"));
+    }
+}
@@ -0,0 +1,17 @@
+//! Zhipu AI Provider
+//!
+//! GLM chat, streaming and tool calling via open.bigmodel.cn, authenticated
+//! with short-lived tokens signed from the `{id}.{secret}` API key
+
+pub mod auth;
+pub mod client;
+pub mod config;
+
+pub use auth::ZhipuTokenSigner;
+pub use client::ZhipuClient;
+pub use config::ZhipuConfig;
+
+use crate::core::providers::base::OpenAICompatibleProvider;
+
+/// Zhipu provider
+pub type ZhipuProvider = OpenAICompatibleProvider<ZhipuClient>;
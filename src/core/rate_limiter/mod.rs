@@ -1,10 +1,14 @@
 //! Rate Limiting Implementation
 //!
-//! Provides sliding window rate limiting with support for multiple strategies
+//! Provides sliding window rate limiting with support for multiple strategies,
+//! and per-minute request and token limits of API keys and models counted
+//! in the cache backend, which shares them when it is Redis
 
 mod limiter;
+mod store;
 mod strategies;
 mod types;
+mod usage;
 mod utils;
 
 #[cfg(test)]
@@ -12,7 +16,9 @@ mod tests;
 
 // Re-export public types
 pub use limiter::RateLimiter;
+pub(crate) use store::CounterStore;
 pub use types::RateLimitResult;
+pub use usage::{UsageLimitResult, UsageLimiter};
 
 use crate::config::models::rate_limit::RateLimitConfig;
 use std::sync::Arc;
@@ -0,0 +1,49 @@
+//! Per-minute counters shared through the cache backend
+
+use crate::storage::cache::{CacheBackend, MemoryCache};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Seconds a window's counters are kept
+const COUNTER_TTL: u64 = 120;
+
+/// Counters of one-minute windows
+///
+/// Counters live in the cache backend, so that gateway instances share them
+/// when it is Redis, and in memory when a command fails.
+#[derive(Debug)]
+pub(crate) struct CounterStore {
+    cache: Arc<dyn CacheBackend>,
+    fallback: MemoryCache,
+}
+
+impl CounterStore {
+    pub(crate) fn new(cache: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            cache,
+            fallback: MemoryCache::new(),
+        }
+    }
+
+    /// Add `delta` to a counter of a window and return its new value
+    pub(crate) async fn add(&self, key: &str, window: u64, delta: i64) -> i64 {
+        let key = format!("ratelimit:{}:{}", key, window);
+        match self
+            .cache
+            .increment_with_expiry(&key, delta, COUNTER_TTL)
+            .await
+        {
+            Ok(count) => return count,
+            Err(e) => warn!(
+                "Counting rate limits in memory, {} cache failed: {}",
+                self.cache.name(),
+                e
+            ),
+        }
+
+        self.fallback
+            .increment_with_expiry(&key, delta, COUNTER_TTL)
+            .await
+            .unwrap_or(delta)
+    }
+}
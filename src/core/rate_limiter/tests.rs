@@ -3,7 +3,10 @@
 #[cfg(test)]
 mod tests {
     use super::super::limiter::RateLimiter;
-    use crate::config::models::rate_limit::{RateLimitConfig, RateLimitStrategy};
+    use super::super::usage::UsageLimiter;
+    use crate::config::models::rate_limit::{RateLimitConfig, RateLimitRule, RateLimitStrategy};
+    use crate::storage::cache::MemoryCache;
+    use std::sync::Arc;
     use std::time::Duration;
 
     fn test_config(enabled: bool, rpm: u32) -> RateLimitConfig {
@@ -12,6 +15,7 @@ mod tests {
             default_rpm: rpm,
             default_tpm: 100000,
             strategy: RateLimitStrategy::SlidingWindow,
+            ..Default::default()
         }
     }
 
@@ -75,6 +79,7 @@ mod tests {
             default_rpm: 60, // 1 per second
             default_tpm: 100000,
             strategy: RateLimitStrategy::TokenBucket,
+            ..Default::default()
         };
         let limiter = RateLimiter::new(config);
 
@@ -90,6 +95,7 @@ mod tests {
             default_rpm: 5,
             default_tpm: 100000,
             strategy: RateLimitStrategy::FixedWindow,
+            ..Default::default()
         };
         let limiter = RateLimiter::new(config);
 
@@ -164,4 +170,114 @@ mod tests {
         assert!(result.allowed);
         assert_eq!(result.remaining, 100);
     }
+
+    // ==================== UsageLimiter Tests ====================
+
+    /// 10 seconds into a minute window
+    const NOW: u64 = 1_700_000_050;
+
+    fn usage_limiter() -> UsageLimiter {
+        let config = RateLimitConfig {
+            enabled: true,
+            default_rpm: 3,
+            default_tpm: 1000,
+            limits: vec![
+                RateLimitRule {
+                    api_key: Some("sk-premium-key".to_string()),
+                    rpm: Some(100),
+                    ..Default::default()
+                },
+                RateLimitRule {
+                    model: Some("gpt-4o".to_string()),
+                    tpm: Some(500),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        UsageLimiter::new(&config, Arc::new(MemoryCache::new()))
+    }
+
+    #[tokio::test]
+    async fn test_usage_limiter_requests_per_key() {
+        let limiter = usage_limiter();
+
+        for remaining in [2, 1, 0] {
+            let result = limiter.acquire_at(Some("sk-basic-key"), None, NOW).await;
+            assert!(result.allowed());
+            assert_eq!(result.requests.unwrap().remaining, remaining);
+        }
+
+        let result = limiter.acquire_at(Some("sk-basic-key"), None, NOW).await;
+        assert!(!result.allowed());
+        assert_eq!(result.retry_after_secs(), Some(50));
+        assert!(result.exceeded.unwrap().contains("sk-b...-key"));
+
+        // Other keys and key overrides are counted separately
+        assert!(
+            limiter
+                .acquire_at(Some("sk-other-key"), None, NOW)
+                .await
+                .allowed()
+        );
+        let result = limiter.acquire_at(Some("sk-premium-key"), None, NOW).await;
+        assert_eq!(result.requests.unwrap().limit, 100);
+
+        // Counters reset in the next window
+        let next = NOW + 50;
+        assert!(
+            limiter
+                .acquire_at(Some("sk-basic-key"), None, next)
+                .await
+                .allowed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_usage_limiter_tokens_per_model() {
+        let limiter = usage_limiter();
+
+        let result = limiter
+            .acquire_at(Some("sk-a-key-1"), Some("gpt-4o"), NOW)
+            .await;
+        assert_eq!(result.tokens.unwrap().limit, 500);
+        limiter
+            .record_tokens_at(Some("sk-a-key-1"), Some("gpt-4o"), 400, NOW)
+            .await;
+        limiter
+            .record_tokens_at(Some("sk-a-key-2"), Some("gpt-4o"), 100, NOW)
+            .await;
+
+        // The model's tokens are used up for every key
+        let result = limiter
+            .acquire_at(Some("sk-a-key-3"), Some("gpt-4o"), NOW)
+            .await;
+        assert!(!result.allowed());
+        assert_eq!(result.tokens.unwrap().remaining, 0);
+        assert!(
+            limiter
+                .acquire_at(Some("sk-a-key-3"), Some("gpt-4o-mini"), NOW)
+                .await
+                .allowed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_usage_limiter_refused_requests_not_counted() {
+        let limiter = usage_limiter();
+
+        limiter
+            .record_tokens_at(None, Some("gpt-4o"), 500, NOW)
+            .await;
+        for _ in 0..5 {
+            let result = limiter
+                .acquire_at(Some("sk-basic-key"), Some("gpt-4o"), NOW)
+                .await;
+            assert!(!result.allowed());
+        }
+
+        let result = limiter.acquire_at(Some("sk-basic-key"), None, NOW).await;
+        assert!(result.allowed());
+        assert_eq!(result.requests.unwrap().remaining, 2);
+    }
 }
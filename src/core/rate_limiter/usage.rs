@@ -0,0 +1,242 @@
+//! Requests and tokens per minute of API keys and models
+//!
+//! Usage is counted in one-minute windows aligned to the clock. Requests are
+//! counted as they arrive; tokens are only known once a response is complete,
+//! so a request is refused after a tokens-per-minute limit has been used up.
+
+use super::store::CounterStore;
+use super::types::RateLimitResult;
+use crate::config::models::rate_limit::RateLimitConfig;
+use crate::storage::cache::CacheBackend;
+use crate::utils::auth::crypto::keys::{extract_api_key_prefix, hash_api_key};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::debug;
+
+const WINDOW_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Limits {
+    rpm: Option<u32>,
+    tpm: Option<u32>,
+}
+
+/// API key or model whose usage is limited
+struct Scope {
+    /// Counter name; API keys are hashed so they are never stored
+    counter: String,
+    /// Description for error messages
+    name: String,
+    limits: Limits,
+}
+
+/// Limits applied to a request
+#[derive(Debug, Clone, Default)]
+pub struct UsageLimitResult {
+    /// Most restrictive requests-per-minute limit
+    pub requests: Option<RateLimitResult>,
+    /// Most restrictive tokens-per-minute limit
+    pub tokens: Option<RateLimitResult>,
+    /// Limit the request exceeded, if it was refused
+    pub exceeded: Option<String>,
+}
+
+impl UsageLimitResult {
+    /// Whether the request is allowed
+    pub fn allowed(&self) -> bool {
+        self.exceeded.is_none()
+    }
+
+    /// Seconds until a refused request may be retried
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        [&self.requests, &self.tokens]
+            .into_iter()
+            .flatten()
+            .filter_map(|result| result.retry_after_secs)
+            .max()
+    }
+}
+
+/// Enforces requests and tokens per minute of API keys and models
+#[derive(Debug)]
+pub struct UsageLimiter {
+    default: Limits,
+    /// Limits by API key hash
+    keys: HashMap<String, Limits>,
+    models: HashMap<String, Limits>,
+    store: CounterStore,
+}
+
+impl UsageLimiter {
+    /// Create a limiter counting in the cache backend
+    pub fn new(config: &RateLimitConfig, cache: Arc<dyn CacheBackend>) -> Self {
+        let default = Limits {
+            rpm: Some(config.default_rpm),
+            tpm: Some(config.default_tpm),
+        };
+        let mut keys = HashMap::new();
+        let mut models = HashMap::new();
+        for rule in &config.limits {
+            if let Some(api_key) = &rule.api_key {
+                let limits = Limits {
+                    rpm: rule.rpm.or(default.rpm),
+                    tpm: rule.tpm.or(default.tpm),
+                };
+                keys.insert(hash_api_key(api_key), limits);
+            } else if let Some(model) = &rule.model {
+                let limits = Limits {
+                    rpm: rule.rpm,
+                    tpm: rule.tpm,
+                };
+                models.insert(model.clone(), limits);
+            }
+        }
+
+        Self {
+            default,
+            keys,
+            models,
+            store: CounterStore::new(cache),
+        }
+    }
+
+    /// Count a request against the limits of its API key and model
+    ///
+    /// Refused requests are not counted.
+    pub async fn acquire(&self, api_key: Option<&str>, model: Option<&str>) -> UsageLimitResult {
+        self.acquire_at(api_key, model, unix_now()).await
+    }
+
+    /// Count tokens used by a request against the limits of its API key and
+    /// model
+    pub async fn record_tokens(&self, api_key: Option<&str>, model: Option<&str>, tokens: u64) {
+        self.record_tokens_at(api_key, model, tokens, unix_now())
+            .await;
+    }
+
+    pub(super) async fn acquire_at(
+        &self,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        now: u64,
+    ) -> UsageLimitResult {
+        let window = now / WINDOW_SECS;
+        let reset_after_secs = WINDOW_SECS - now % WINDOW_SECS;
+        let scopes = self.scopes(api_key, model);
+        let mut result = UsageLimitResult::default();
+
+        for scope in &scopes {
+            let Some(limit) = scope.limits.tpm else {
+                continue;
+            };
+            let used = self.store.add(&tpm_counter(scope), window, 0).await;
+            let status = status(used, limit, used < i64::from(limit), reset_after_secs);
+            if !status.allowed && result.exceeded.is_none() {
+                result.exceeded = Some(format!("tokens per minute of {}", scope.name));
+            }
+            result.tokens = Some(most_restrictive(result.tokens, status));
+        }
+
+        let mut counted = Vec::new();
+        for scope in &scopes {
+            let Some(limit) = scope.limits.rpm else {
+                continue;
+            };
+            let counter = format!("{}:rpm", scope.counter);
+            let status = if result.allowed() {
+                let used = self.store.add(&counter, window, 1).await;
+                counted.push(counter);
+                status(used, limit, used <= i64::from(limit), reset_after_secs)
+            } else {
+                let used = self.store.add(&counter, window, 0).await;
+                status(used, limit, used < i64::from(limit), reset_after_secs)
+            };
+            if !status.allowed && result.exceeded.is_none() {
+                result.exceeded = Some(format!("requests per minute of {}", scope.name));
+            }
+            result.requests = Some(most_restrictive(result.requests, status));
+        }
+
+        if let Some(exceeded) = &result.exceeded {
+            for counter in &counted {
+                self.store.add(counter, window, -1).await;
+            }
+            debug!("Rate limit exceeded: {}", exceeded);
+        }
+        result
+    }
+
+    pub(super) async fn record_tokens_at(
+        &self,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        tokens: u64,
+        now: u64,
+    ) {
+        if tokens == 0 {
+            return;
+        }
+
+        let window = now / WINDOW_SECS;
+        for scope in self.scopes(api_key, model) {
+            if scope.limits.tpm.is_some() {
+                self.store
+                    .add(&tpm_counter(&scope), window, tokens as i64)
+                    .await;
+            }
+        }
+    }
+
+    fn scopes(&self, api_key: Option<&str>, model: Option<&str>) -> Vec<Scope> {
+        let mut scopes = Vec::new();
+        if let Some(api_key) = api_key {
+            let hash = hash_api_key(api_key);
+            let limits = self.keys.get(&hash).copied().unwrap_or(self.default);
+            scopes.push(Scope {
+                counter: format!("key:{}", hash),
+                name: format!("API key {}", extract_api_key_prefix(api_key)),
+                limits,
+            });
+        }
+        if let Some((model, limits)) = model.and_then(|model| self.models.get_key_value(model)) {
+            scopes.push(Scope {
+                counter: format!("model:{}", model),
+                name: format!("model {}", model),
+                limits: *limits,
+            });
+        }
+        scopes
+    }
+}
+
+fn unix_now() -> u64 {
+    chrono::Utc::now().timestamp() as u64
+}
+
+fn tpm_counter(scope: &Scope) -> String {
+    format!("{}:tpm", scope.counter)
+}
+
+fn status(used: i64, limit: u32, allowed: bool, reset_after_secs: u64) -> RateLimitResult {
+    let used = used.clamp(0, i64::from(u32::MAX)) as u32;
+    RateLimitResult {
+        allowed,
+        current_count: used,
+        limit,
+        remaining: limit.saturating_sub(used),
+        reset_after_secs,
+        retry_after_secs: (!allowed).then_some(reset_after_secs),
+    }
+}
+
+/// The refused result, or the one with fewer remaining
+fn most_restrictive(current: Option<RateLimitResult>, next: RateLimitResult) -> RateLimitResult {
+    match current {
+        Some(current)
+            if !current.allowed || (next.allowed && current.remaining <= next.remaining) =>
+        {
+            current
+        }
+        _ => next,
+    }
+}
@@ -0,0 +1,174 @@
+//! Response cache shared through the cache backend
+
+use super::memory::MemoryTier;
+use crate::config::models::cache::CacheConfig;
+use crate::core::models::openai::CacheOptions;
+use crate::storage::cache::CacheBackend;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Request fields that do not change the response
+const IGNORED_FIELDS: [&str; 4] = ["user", "cache", "metadata", "stream_options"];
+
+/// Where a response is cached and for how long
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    /// Key of the cached response
+    pub key: String,
+    /// Look up the cached response, unless the request skips it
+    pub lookup: bool,
+    /// Seconds the response is cached
+    pub ttl: u64,
+}
+
+/// Cached responses of exactly matching requests
+///
+/// Responses live in the cache backend, so that gateway instances share them
+/// when it is Redis, behind a process-local LRU tier that serves repeated
+/// requests without a round trip to the backend.
+#[derive(Debug)]
+pub struct ResponseCache {
+    backend: Arc<dyn CacheBackend>,
+    memory: MemoryTier,
+    memory_ttl: Option<u64>,
+    ttl: u64,
+    namespace: Option<String>,
+    per_key: bool,
+}
+
+impl ResponseCache {
+    /// Create a response cache
+    pub fn new(config: &CacheConfig, backend: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            backend,
+            memory: MemoryTier::new(config.max_size),
+            memory_ttl: config.memory_ttl,
+            ttl: config.ttl,
+            namespace: config.namespace.clone(),
+            per_key: config.per_key,
+        }
+    }
+
+    /// Key a request's response is cached under
+    ///
+    /// The key hashes the request without the fields that do not change the
+    /// response, e.g. `user`. It is namespaced by the request's namespace,
+    /// else the configured one, and with `per_key` by its owner, the caller
+    /// the response is cached for. Returns `None` if the request cannot be
+    /// serialized, or with `per_key` if it has no owner to keep it apart for.
+    pub fn key(
+        &self,
+        request: &impl Serialize,
+        options: Option<&CacheOptions>,
+        owner: Option<&str>,
+    ) -> Option<CacheKey> {
+        if self.per_key && owner.is_none() {
+            return None;
+        }
+        let Ok(Value::Object(mut fields)) = serde_json::to_value(request) else {
+            return None;
+        };
+        for field in IGNORED_FIELDS {
+            fields.remove(field);
+        }
+        // Object keys are sorted, so equal requests serialize alike
+        let hash = Sha256::digest(Value::Object(fields).to_string());
+
+        let mut key = "cache".to_string();
+        if let Some(namespace) = options
+            .and_then(|options| options.namespace.as_deref())
+            .or(self.namespace.as_deref())
+        {
+            key.push(':');
+            key.push_str(namespace);
+        }
+        if self.per_key {
+            key.push_str(":key:");
+            key.push_str(owner.unwrap_or_default());
+        }
+        key.push(':');
+        key.push_str(&hex::encode(hash));
+
+        Some(CacheKey {
+            key,
+            lookup: !options.is_some_and(|options| options.no_cache),
+            ttl: options.and_then(|options| options.ttl).unwrap_or(self.ttl),
+        })
+    }
+
+    /// Cached response of a key, unless the request skips the cache
+    pub async fn get<T: DeserializeOwned>(&self, key: &CacheKey) -> Option<T> {
+        if !key.lookup {
+            return None;
+        }
+        let cached = self.get_raw(key).await?;
+        match serde_json::from_str(&cached) {
+            Ok(response) => Some(response),
+            Err(e) => {
+                warn!("Ignoring unreadable cached response {}: {}", key.key, e);
+                None
+            }
+        }
+    }
+
+    /// Cache a response under a key
+    pub async fn set<T: Serialize>(&self, key: &CacheKey, response: &T) {
+        if key.ttl == 0 {
+            return;
+        }
+        let value = match serde_json::to_string(response) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Not caching response {}: {}", key.key, e);
+                return;
+            }
+        };
+
+        self.memory
+            .insert(key.key.clone(), value.clone(), self.memory_ttl(key.ttl));
+        if let Err(e) = self.backend.set(&key.key, &value, Some(key.ttl)).await {
+            warn!(
+                "Caching response in memory only, {} cache failed: {}",
+                self.backend.name(),
+                e
+            );
+        }
+    }
+
+    /// Cached value of a key, in memory or else in the backend
+    async fn get_raw(&self, key: &CacheKey) -> Option<String> {
+        if let Some(cached) = self.memory.get(&key.key) {
+            return Some(cached);
+        }
+
+        match self.backend.get(&key.key).await {
+            Ok(cached) => {
+                let cached = cached?;
+                self.memory
+                    .insert(key.key.clone(), cached.clone(), self.memory_ttl(key.ttl));
+                Some(cached)
+            }
+            Err(e) => {
+                warn!(
+                    "Reading cached responses from memory only, {} cache failed: {}",
+                    self.backend.name(),
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// How long a response with a TTL stays in the in-memory tier
+    fn memory_ttl(&self, ttl: u64) -> Duration {
+        Duration::from_secs(
+            self.memory_ttl
+                .map_or(ttl, |memory_ttl| memory_ttl.min(ttl)),
+        )
+    }
+}
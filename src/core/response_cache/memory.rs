@@ -0,0 +1,53 @@
+//! Process-local tier of the response cache
+
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+/// Least recently used cached values, bounded in number and by their TTL
+#[derive(Debug)]
+pub struct MemoryTier {
+    entries: Mutex<LruCache<String, (Instant, String)>>,
+}
+
+impl MemoryTier {
+    /// Create a tier holding at most `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Value of a key unless it expired, marking it recently used
+    pub fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock();
+        match entries.get(key) {
+            Some((expires_at, value)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Store a value for `ttl`, evicting the least recently used when full
+    pub fn insert(&self, key: String, value: String, ttl: Duration) {
+        if ttl.is_zero() {
+            return;
+        }
+        self.entries.lock().put(key, (Instant::now() + ttl, value));
+    }
+
+    /// Number of values held, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    /// Whether the tier holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
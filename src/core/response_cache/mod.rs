@@ -0,0 +1,17 @@
+//! Exact-match response caching
+//!
+//! Responses are cached under a hash of the request's model, messages and
+//! parameters, in the cache backend, which gateway instances share when it is
+//! Redis. A process-local LRU tier in front of the backend serves repeated
+//! requests without a round trip. Requests control the
+//! cache with a `cache` parameter, as in LiteLLM: `no-cache` skips the cached
+//! response, `ttl` and `namespace` override the configured ones.
+
+mod cache;
+mod memory;
+
+#[cfg(test)]
+mod tests;
+
+pub use cache::{CacheKey, ResponseCache};
+pub use memory::MemoryTier;
@@ -0,0 +1,181 @@
+//! Tests for the response cache
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MemoryTier, ResponseCache};
+    use crate::config::models::cache::CacheConfig;
+    use crate::core::models::openai::{
+        CacheOptions, ChatCompletionRequest, ChatMessage, MessageContent, MessageRole,
+    };
+    use crate::storage::cache::{CacheBackend, MemoryCache};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn test_config() -> CacheConfig {
+        CacheConfig {
+            enabled: true,
+            ttl: 60,
+            max_size: 2,
+            ..Default::default()
+        }
+    }
+
+    fn memory_cache(config: &CacheConfig) -> ResponseCache {
+        ResponseCache::new(config, Arc::new(MemoryCache::new()))
+    }
+
+    fn test_request(content: &str) -> ChatCompletionRequest {
+        ChatCompletionRequest {
+            model: "gpt-4".to_string(),
+            messages: vec![ChatMessage {
+                role: MessageRole::User,
+                content: Some(MessageContent::Text(content.to_string())),
+                name: None,
+                function_call: None,
+                tool_calls: None,
+                tool_call_id: None,
+                audio: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_key_depends_on_request() {
+        let cache = memory_cache(&test_config());
+        let request = test_request("Hello");
+        let key = cache.key(&request, None, None).unwrap();
+        assert!(key.key.starts_with("cache:"));
+        assert!(key.lookup);
+        assert_eq!(key.ttl, 60);
+
+        let mut other = test_request("Hello");
+        other.user = Some("alice".to_string());
+        other.cache = Some(CacheOptions::default());
+        assert_eq!(cache.key(&other, None, None).unwrap(), key);
+
+        other.temperature = Some(0.5);
+        assert_ne!(cache.key(&other, None, None).unwrap().key, key.key);
+        let other = test_request("Goodbye");
+        assert_ne!(cache.key(&other, None, None).unwrap().key, key.key);
+    }
+
+    #[test]
+    fn test_key_namespaces_and_options() {
+        let config = CacheConfig {
+            namespace: Some("prod".to_string()),
+            per_key: true,
+            ..test_config()
+        };
+        let cache = memory_cache(&config);
+        let request = test_request("Hello");
+
+        let key = cache.key(&request, None, Some("key-1")).unwrap();
+        assert!(key.key.starts_with("cache:prod:key:key-1:"));
+        let other = cache.key(&request, None, Some("key-2")).unwrap();
+        assert_ne!(other.key, key.key);
+
+        let options = CacheOptions {
+            no_cache: true,
+            ttl: Some(5),
+            namespace: Some("test".to_string()),
+        };
+        // Callers without an owner are not cached together
+        assert!(cache.key(&request, Some(&options), None).is_none());
+        let key = cache.key(&request, Some(&options), Some("key-1")).unwrap();
+        assert!(key.key.starts_with("cache:test:key:key-1:"));
+        assert!(!key.lookup);
+        assert_eq!(key.ttl, 5);
+    }
+
+    #[test]
+    fn test_cache_options_deserialization() {
+        let json = r#"{"model": "gpt-4", "messages": [], "cache": {"no-cache": true}}"#;
+        let request: ChatCompletionRequest = serde_json::from_str(json).unwrap();
+        let options = request.cache.unwrap();
+        assert!(options.no_cache);
+        assert!(options.ttl.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_and_set_in_memory() {
+        let cache = memory_cache(&test_config());
+        let key = cache.key(&test_request("Hello"), None, None).unwrap();
+        assert!(cache.get::<String>(&key).await.is_none());
+
+        cache.set(&key, &"cached".to_string()).await;
+        assert_eq!(cache.get::<String>(&key).await.as_deref(), Some("cached"));
+
+        let options = CacheOptions {
+            no_cache: true,
+            ..Default::default()
+        };
+        let skipped = cache
+            .key(&test_request("Hello"), Some(&options), None)
+            .unwrap();
+        assert!(cache.get::<String>(&skipped).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_set_respects_ttl_and_size() {
+        let cache = memory_cache(&test_config());
+        let options = CacheOptions {
+            ttl: Some(0),
+            ..Default::default()
+        };
+        let uncached = cache
+            .key(&test_request("Hello"), Some(&options), None)
+            .unwrap();
+        cache.set(&uncached, &"cached".to_string()).await;
+        assert!(cache.get::<String>(&uncached).await.is_none());
+
+        // The least recently used response is evicted from memory, and read
+        // back from the backend
+        let keys: Vec<_> = ["a", "b", "c"]
+            .iter()
+            .map(|content| cache.key(&test_request(content), None, None).unwrap())
+            .collect();
+        cache.set(&keys[0], &"a".to_string()).await;
+        cache.set(&keys[1], &"b".to_string()).await;
+        assert!(cache.get::<String>(&keys[0]).await.is_some());
+        cache.set(&keys[2], &"c".to_string()).await;
+        assert!(cache.get::<String>(&keys[0]).await.is_some());
+        assert_eq!(cache.get::<String>(&keys[1]).await.as_deref(), Some("b"));
+        assert!(cache.get::<String>(&keys[2]).await.is_some());
+    }
+
+    #[test]
+    fn test_memory_tier_expires_entries() {
+        let tier = MemoryTier::new(0);
+        tier.insert("a".to_string(), "1".to_string(), Duration::from_secs(60));
+        assert_eq!(tier.get("a").as_deref(), Some("1"));
+        // A tier holds at least one value
+        tier.insert("b".to_string(), "2".to_string(), Duration::from_secs(60));
+        assert!(tier.get("a").is_none());
+        assert_eq!(tier.len(), 1);
+
+        tier.insert("c".to_string(), "3".to_string(), Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(tier.get("c").is_none());
+        assert!(tier.is_empty());
+
+        tier.insert("d".to_string(), "4".to_string(), Duration::ZERO);
+        assert!(tier.get("d").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_memory_ttl_bounds_memory_tier() {
+        let config = CacheConfig {
+            memory_ttl: Some(0),
+            ..test_config()
+        };
+        // Responses kept out of memory are only cached in the backend
+        let backend = Arc::new(MemoryCache::new());
+        let cache = ResponseCache::new(&config, backend.clone());
+        let key = cache.key(&test_request("Hello"), None, None).unwrap();
+        cache.set(&key, &"cached".to_string()).await;
+        assert_eq!(cache.get::<String>(&key).await.as_deref(), Some("cached"));
+        backend.delete(&key.key).await.unwrap();
+        assert!(cache.get::<String>(&key).await.is_none());
+    }
+}
@@ -0,0 +1,93 @@
+//! Chat completions on the router's deployments
+//!
+//! A chat request is routed by its requirements, re-mapped to each
+//! deployment it is tried on, and sent to the deployment's provider with the
+//! router's retries, fallbacks, cooldowns and hedging. Streams fail over
+//! until they emitted content.
+
+use super::deployment::DeploymentId;
+use super::error::RouterError;
+use super::fallback::ExecutionResult;
+use super::router::Router;
+use super::selection::RouteRequirements;
+use super::streaming::ChunkStream;
+use crate::core::providers::Provider;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::{ChatRequest, ChatResponse, RequestContext};
+use std::sync::Arc;
+
+impl Router {
+    /// Whether the router serves a model, by name or alias, with
+    /// deployments or a traffic split
+    pub fn serves(&self, model_name: &str) -> bool {
+        let resolved_name = self.resolve_model_name(model_name);
+        self.config.traffic_splits.contains_key(&resolved_name)
+            || self
+                .model_index
+                .get(&resolved_name)
+                .is_some_and(|ids| !ids.is_empty())
+    }
+
+    /// Complete a chat request on the deployments of its model, or of its
+    /// fallback models
+    pub async fn chat_completion(
+        &self,
+        request: ChatRequest,
+        context: RequestContext,
+    ) -> Result<ExecutionResult<ChatResponse>, RouterError> {
+        let requirements = RouteRequirements::for_chat(&request);
+        let (request, context) = (&request, &context);
+        self.execute_for(&request.model, &requirements, |deployment_id| async move {
+            let (provider, mapped) = self.deployment_request(&deployment_id, request)?;
+            let response = provider.chat_completion(mapped, context.clone()).await?;
+            let tokens = response
+                .usage
+                .as_ref()
+                .map_or(0, |usage| u64::from(usage.total_tokens));
+            Ok((response, tokens))
+        })
+        .await
+    }
+
+    /// Stream a chat request from the deployments of its model, or of its
+    /// fallback models
+    pub fn chat_completion_stream(
+        self: Arc<Self>,
+        request: ChatRequest,
+        context: RequestContext,
+    ) -> ChunkStream {
+        let requirements = RouteRequirements::for_chat(&request);
+        let model_name = request.model.clone();
+        let router = Arc::clone(&self);
+        self.execute_stream(&model_name, requirements, move |deployment_id| {
+            let (router, request, context) =
+                (Arc::clone(&router), request.clone(), context.clone());
+            async move {
+                let (provider, mapped) = router.deployment_request(&deployment_id, &request)?;
+                provider.chat_completion_stream(mapped, context).await
+            }
+        })
+    }
+
+    /// Provider of a deployment and the request re-mapped to it
+    pub(crate) fn deployment_request(
+        &self,
+        deployment_id: &DeploymentId,
+        request: &ChatRequest,
+    ) -> Result<(Provider, ChatRequest), ProviderError> {
+        let deployment_error = || ProviderError::DeploymentError {
+            provider: "router",
+            deployment: deployment_id.clone(),
+            message: "Deployment not found".to_string(),
+        };
+        let provider = self
+            .get_deployment(deployment_id)
+            .ok_or_else(deployment_error)?
+            .provider
+            .clone();
+        let mapped = self
+            .map_chat_request(deployment_id, request)
+            .ok_or_else(deployment_error)?;
+        Ok((provider, mapped))
+    }
+}
@@ -3,6 +3,9 @@
 //! This module defines configuration types for the router including
 //! routing strategies and router settings.
 
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
 /// Routing strategy enumeration
 ///
 /// Defines how the router selects which deployment to use when multiple deployments
@@ -13,29 +16,56 @@
 /// - **SimpleShuffle**: Weighted random selection (default, good for even distribution)
 /// - **LeastBusy**: Select deployment with fewest active requests (good for balanced load)
 /// - **UsageBased**: Select deployment with lowest TPM usage rate (good for rate limit optimization)
-/// - **LatencyBased**: Select deployment with lowest average latency (good for performance)
-/// - **CostBased**: Select deployment with lowest cost (good for cost optimization)
+/// - **UsageBasedV2**: Like UsageBased, but skips deployments a request would push over their
+///   TPM/RPM, counting usage across gateway instances (good for shared provider limits)
+/// - **LatencyBased**: Select healthy deployment with lowest average time to first token or
+///   latency (good for performance)
+/// - **CostBased**: Select healthy deployment with lowest expected cost per request from the
+///   pricing database (good for cost optimization)
 /// - **RateLimitAware**: Avoid deployments near rate limits (good for avoiding 429s)
 /// - **RoundRobin**: Simple round-robin selection (good for predictable distribution)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///
+/// Strategies are configured by their LiteLLM names, e.g. `lowest-latency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum RoutingStrategy {
     /// Weighted random selection (considers deployment weights)
     #[default]
+    #[serde(rename = "simple-shuffle")]
     SimpleShuffle,
     /// Select deployment with fewest active requests
+    #[serde(rename = "least-busy")]
     LeastBusy,
     /// Select deployment with lowest TPM usage rate
+    #[serde(rename = "usage-based-routing")]
     UsageBased,
-    /// Select deployment with lowest average latency
+    /// Select deployment with lowest TPM usage rate among those with room
+    /// for the request, counting usage of all gateway instances
+    #[serde(rename = "usage-based-routing-v2")]
+    UsageBasedV2,
+    /// Select deployment with lowest average time to first token or latency
+    #[serde(rename = "lowest-latency", alias = "latency-based-routing")]
     LatencyBased,
-    /// Select deployment with lowest cost
+    /// Select deployment with lowest expected cost per request
+    #[serde(rename = "lowest-cost", alias = "cost-based-routing")]
     CostBased,
     /// Avoid deployments near rate limits
+    #[serde(rename = "rate-limit-aware")]
     RateLimitAware,
     /// Simple round-robin selection
+    #[serde(rename = "round-robin")]
     RoundRobin,
 }
 
+impl std::str::FromStr for RoutingStrategy {
+    type Err = String;
+
+    /// Parse a strategy from its LiteLLM name, e.g. `lowest-latency`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .map_err(|_| format!("Unknown routing strategy: {}", name))
+    }
+}
+
 /// Router configuration
 ///
 /// Contains global settings for router behavior including retry policies,
@@ -51,6 +81,15 @@ pub enum RoutingStrategy {
 /// - `timeout_secs`: 60
 /// - `max_fallbacks`: 5
 /// - `enable_pre_call_checks`: true
+/// - `queue_timeout_ms`: 0 (fail at once when all deployments are busy)
+/// - `model_group_strategies`: empty (every model group uses `routing_strategy`)
+/// - `expected_input_tokens`: 1000
+/// - `expected_output_tokens`: 500
+/// - `quality_tier_floors`: empty (no floors)
+/// - `hedge_after_ms`: 0 (no hedged requests)
+/// - `drop_params`: false (send every parameter to the provider)
+/// - `stream_failover_grace_ms`: 0 (fail over streams before any content)
+/// - `traffic_splits`: empty (every model group serves its own model)
 #[derive(Debug, Clone)]
 pub struct RouterConfig {
     /// Routing strategy to use for deployment selection
@@ -62,7 +101,8 @@ pub struct RouterConfig {
     /// Minimum seconds to wait between retries (default: 0)
     pub retry_after_secs: u64,
 
-    /// Number of failures allowed before entering cooldown (default: 3)
+    /// Number of consecutive failures allowed before entering cooldown
+    /// (default: 3)
     pub allowed_fails: u32,
 
     /// Cooldown duration in seconds (default: 5)
@@ -76,6 +116,47 @@ pub struct RouterConfig {
 
     /// Enable pre-call validation checks (default: true)
     pub enable_pre_call_checks: bool,
+
+    /// Milliseconds to wait for a deployment under its max parallel requests
+    /// before failing (default: 0)
+    pub queue_timeout_ms: u64,
+
+    /// Routing strategies overriding `routing_strategy` for model groups,
+    /// by model name
+    pub model_group_strategies: HashMap<String, RoutingStrategy>,
+
+    /// Input tokens of a typical request, used to compare deployment costs
+    /// (default: 1000)
+    pub expected_input_tokens: u32,
+
+    /// Output tokens of a typical request, used to compare deployment costs
+    /// (default: 500)
+    pub expected_output_tokens: u32,
+
+    /// Minimum deployment quality tiers, by model name; deployments below the
+    /// floor or without a tier are never selected for the model group
+    pub quality_tier_floors: HashMap<String, u32>,
+
+    /// Milliseconds after which a request still running is duplicated to a
+    /// second deployment, e.g. the p95 latency; whichever completes first is
+    /// returned and the other is canceled (default: 0, no hedging)
+    pub hedge_after_ms: u64,
+
+    /// Drop OpenAI parameters a deployment's provider does not support
+    /// rather than send them, unless the deployment or request says
+    /// otherwise (default: false)
+    pub drop_params: bool,
+
+    /// Milliseconds from the start of a stream during which it is restarted
+    /// on another deployment if it fails; chunks are held back until then,
+    /// and always until one carries content (default: 0)
+    pub stream_failover_grace_ms: u64,
+
+    /// Models serving a model group's traffic with their percentages, by
+    /// model name, e.g. 95 for `gpt-4o` and 5 for a fine-tuned model being
+    /// rolled out; a user always gets the same model, and traffic assigned
+    /// to a model without deployments stays on the model group
+    pub traffic_splits: HashMap<String, Vec<(String, u32)>>,
 }
 
 impl Default for RouterConfig {
@@ -89,6 +170,25 @@ impl Default for RouterConfig {
             timeout_secs: 60,
             max_fallbacks: 5,
             enable_pre_call_checks: true,
+            queue_timeout_ms: 0,
+            model_group_strategies: HashMap::new(),
+            expected_input_tokens: 1000,
+            expected_output_tokens: 500,
+            quality_tier_floors: HashMap::new(),
+            hedge_after_ms: 0,
+            drop_params: false,
+            stream_failover_grace_ms: 0,
+            traffic_splits: HashMap::new(),
         }
     }
 }
+
+impl RouterConfig {
+    /// Routing strategy of a model group
+    pub fn strategy_for(&self, model_name: &str) -> RoutingStrategy {
+        self.model_group_strategies
+            .get(model_name)
+            .copied()
+            .unwrap_or(self.routing_strategy)
+    }
+}
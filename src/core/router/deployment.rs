@@ -21,6 +21,8 @@
 //! - Cache-friendly: Hot path fields grouped together
 
 use crate::core::providers::Provider;
+use crate::core::types::common::ModelInfo;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU8, AtomicU32, AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -45,6 +47,19 @@ pub enum HealthStatus {
     Cooldown = 4,
 }
 
+impl HealthStatus {
+    /// Name of the status, as reported by health endpoints
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthStatus::Unknown => "unknown",
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Degraded => "degraded",
+            HealthStatus::Unhealthy => "unhealthy",
+            HealthStatus::Cooldown => "cooldown",
+        }
+    }
+}
+
 impl From<u8> for HealthStatus {
     fn from(value: u8) -> Self {
         match value {
@@ -86,6 +101,26 @@ pub struct DeploymentConfig {
 
     /// Priority (lower value = higher priority)
     pub priority: u32,
+
+    /// Quality tier of the deployed model (higher = better, None = unrated)
+    pub quality_tier: Option<u32>,
+
+    /// Request parameters set when a request does not set them, e.g.
+    /// `temperature`; `system_prompt` adds a system message to requests
+    /// without one
+    pub default_params: HashMap<String, serde_json::Value>,
+
+    /// Request parameters overriding the request's; `max_tokens` and
+    /// `max_completion_tokens` cap the request's, and `system_prompt` is
+    /// added as the first message
+    pub forced_params: HashMap<String, serde_json::Value>,
+
+    /// Whether to drop OpenAI parameters the provider does not support,
+    /// overriding the router's `drop_params` (None = router's)
+    pub drop_params: Option<bool>,
+
+    /// Parameters always dropped from requests to the deployment
+    pub additional_drop_params: Vec<String>,
 }
 
 impl Default for DeploymentConfig {
@@ -97,6 +132,11 @@ impl Default for DeploymentConfig {
             weight: 1,
             timeout_secs: 60,
             priority: 0,
+            quality_tier: None,
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
         }
     }
 }
@@ -136,6 +176,9 @@ pub struct DeploymentState {
     /// Failures this minute (for cooldown detection)
     pub fails_this_minute: AtomicU32,
 
+    /// Failures since the last success (for cooldown detection)
+    pub consecutive_fails: AtomicU32,
+
     /// Cooldown end timestamp (unix seconds)
     pub cooldown_until: AtomicU64,
 
@@ -145,6 +188,10 @@ pub struct DeploymentState {
     /// Average latency in microseconds (sliding window)
     pub avg_latency_us: AtomicU64,
 
+    /// Average time to first token of streaming responses in microseconds
+    /// (sliding window)
+    pub avg_ttft_us: AtomicU64,
+
     /// Last minute reset timestamp (unix seconds)
     pub minute_reset_at: AtomicU64,
 }
@@ -162,9 +209,11 @@ impl DeploymentState {
             success_requests: AtomicU64::new(0),
             fail_requests: AtomicU64::new(0),
             fails_this_minute: AtomicU32::new(0),
+            consecutive_fails: AtomicU32::new(0),
             cooldown_until: AtomicU64::new(0),
             last_request_at: AtomicU64::new(0),
             avg_latency_us: AtomicU64::new(0),
+            avg_ttft_us: AtomicU64::new(0),
             minute_reset_at: AtomicU64::new(now),
         }
     }
@@ -204,9 +253,11 @@ impl Clone for DeploymentState {
             success_requests: AtomicU64::new(self.success_requests.load(Ordering::Relaxed)),
             fail_requests: AtomicU64::new(self.fail_requests.load(Ordering::Relaxed)),
             fails_this_minute: AtomicU32::new(self.fails_this_minute.load(Ordering::Relaxed)),
+            consecutive_fails: AtomicU32::new(self.consecutive_fails.load(Ordering::Relaxed)),
             cooldown_until: AtomicU64::new(self.cooldown_until.load(Ordering::Relaxed)),
             last_request_at: AtomicU64::new(self.last_request_at.load(Ordering::Relaxed)),
             avg_latency_us: AtomicU64::new(self.avg_latency_us.load(Ordering::Relaxed)),
+            avg_ttft_us: AtomicU64::new(self.avg_ttft_us.load(Ordering::Relaxed)),
             minute_reset_at: AtomicU64::new(self.minute_reset_at.load(Ordering::Relaxed)),
         }
     }
@@ -302,6 +353,34 @@ impl Deployment {
         matches!(status, HealthStatus::Healthy | HealthStatus::Degraded)
     }
 
+    /// Check if deployment carries every one of the tags
+    pub fn has_tags(&self, tags: &[String]) -> bool {
+        tags.iter().all(|tag| self.tags.contains(tag))
+    }
+
+    /// Context window of the deployment's model, from its provider's model
+    /// info
+    ///
+    /// Returns `None` if the provider does not know the model's context
+    /// window.
+    pub fn max_context_length(&self) -> Option<u32> {
+        self.model_info()
+            .map(|info| info.max_context_length)
+            .filter(|&length| length > 0)
+    }
+
+    /// Provider's information on the deployment's model
+    ///
+    /// The model may carry a provider prefix, e.g. `openai/gpt-4`. Returns
+    /// `None` if the provider does not know the model.
+    pub fn model_info(&self) -> Option<&ModelInfo> {
+        let unprefixed = self.model.split_once('/').map(|(_, model)| model);
+        self.provider
+            .list_models()
+            .iter()
+            .find(|info| info.id == self.model || Some(info.id.as_str()) == unprefixed)
+    }
+
     /// Check if deployment is in cooldown
     ///
     /// Returns true if current time is before cooldown_until timestamp.
@@ -311,6 +390,18 @@ impl Deployment {
         cooldown_until > now
     }
 
+    /// Seconds left in the deployment's cooldown, 0 if it is not in cooldown
+    pub fn cooldown_remaining_secs(&self) -> u64 {
+        let cooldown_until = self.state.cooldown_until.load(Ordering::Relaxed);
+        cooldown_until.saturating_sub(current_timestamp())
+    }
+
+    /// Check if deployment's cooldown has ended and it awaits a probe before
+    /// getting traffic again
+    pub fn is_cooldown_expired(&self) -> bool {
+        self.state.health_status() == HealthStatus::Cooldown && !self.is_in_cooldown()
+    }
+
     /// Record a successful request
     ///
     /// Updates counters and calculates exponential moving average for latency.
@@ -325,20 +416,12 @@ impl Deployment {
         self.state.success_requests.fetch_add(1, Ordering::Relaxed);
         self.state.tpm_current.fetch_add(tokens, Ordering::Relaxed);
         self.state.rpm_current.fetch_add(1, Ordering::Relaxed);
+        self.state.consecutive_fails.store(0, Ordering::Relaxed);
         self.state
             .last_request_at
             .store(current_timestamp(), Ordering::Relaxed);
 
-        // Update average latency using exponential moving average (alpha = 0.2)
-        let current_avg = self.state.avg_latency_us.load(Ordering::Relaxed);
-        let new_avg = if current_avg == 0 {
-            latency_us
-        } else {
-            // EMA: new_avg = alpha * new_value + (1 - alpha) * old_avg
-            // Using alpha = 0.2 = 1/5
-            (latency_us + 4 * current_avg) / 5
-        };
-        self.state.avg_latency_us.store(new_avg, Ordering::Relaxed);
+        update_average(&self.state.avg_latency_us, latency_us);
 
         // If health was Degraded, consider promoting to Healthy
         let current_health = self.state.health.load(Ordering::Relaxed);
@@ -350,6 +433,13 @@ impl Deployment {
         }
     }
 
+    /// Record the time to first token of a streaming response
+    ///
+    /// Updates the exponential moving average used by latency-based routing.
+    pub fn record_ttft(&self, ttft_us: u64) {
+        update_average(&self.state.avg_ttft_us, ttft_us);
+    }
+
     /// Record a failed request
     ///
     /// Increments failure counters. The caller is responsible for deciding
@@ -358,6 +448,7 @@ impl Deployment {
         self.state.total_requests.fetch_add(1, Ordering::Relaxed);
         self.state.fail_requests.fetch_add(1, Ordering::Relaxed);
         self.state.fails_this_minute.fetch_add(1, Ordering::Relaxed);
+        self.state.consecutive_fails.fetch_add(1, Ordering::Relaxed);
         self.state
             .last_request_at
             .store(current_timestamp(), Ordering::Relaxed);
@@ -384,6 +475,18 @@ impl Deployment {
             .health
             .store(HealthStatus::Cooldown as u8, Ordering::Relaxed);
     }
+
+    /// Leave cooldown state
+    ///
+    /// Sets health to Healthy and clears the failures that led to the
+    /// cooldown.
+    pub fn restore(&self) {
+        self.state.cooldown_until.store(0, Ordering::Relaxed);
+        self.state.consecutive_fails.store(0, Ordering::Relaxed);
+        self.state
+            .health
+            .store(HealthStatus::Healthy as u8, Ordering::Relaxed);
+    }
 }
 
 /// Get current Unix timestamp in seconds
@@ -397,6 +500,19 @@ fn current_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Fold a sample into an exponential moving average (alpha = 0.2)
+fn update_average(average: &AtomicU64, sample: u64) {
+    let current_avg = average.load(Ordering::Relaxed);
+    let new_avg = if current_avg == 0 {
+        sample
+    } else {
+        // EMA: new_avg = alpha * new_value + (1 - alpha) * old_avg
+        // Using alpha = 0.2 = 1/5
+        (sample + 4 * current_avg) / 5
+    };
+    average.store(new_avg, Ordering::Relaxed);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -468,6 +584,11 @@ mod tests {
             weight: 2,
             timeout_secs: 120,
             priority: 1,
+            quality_tier: Some(2),
+            default_params: HashMap::new(),
+            forced_params: HashMap::new(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
         };
         assert_eq!(config.tpm_limit, Some(100_000));
         assert_eq!(config.rpm_limit, Some(500));
@@ -49,4 +49,28 @@ pub enum RouterError {
     /// Rate limit exceeded for model
     #[error("Rate limit exceeded for model: {0}")]
     RateLimitExceeded(String),
+
+    /// All deployments are at their max parallel requests
+    #[error("All deployments busy for model: {0}")]
+    AllDeploymentsBusy(String),
+
+    /// The request does not fit in the context window of any deployment
+    #[error("Context window exceeded for model {model}: max {max} tokens, got {tokens} tokens")]
+    ContextWindowExceeded {
+        /// Requested model
+        model: String,
+        /// Largest context window of the model's deployments
+        max: u32,
+        /// Estimated input tokens of the request
+        tokens: u32,
+    },
+
+    /// The request uses a capability no deployment's model supports
+    #[error("Model {model} does not support {capability}")]
+    UnsupportedCapability {
+        /// Requested model
+        model: String,
+        /// Capability the request uses, e.g. `tools` or `images`
+        capability: String,
+    },
 }
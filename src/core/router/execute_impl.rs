@@ -8,8 +8,9 @@ use super::execution::{
     build_execution_result, calculate_retry_delay, infer_cooldown_reason, is_retryable_error,
     provider_error_to_router_error, router_error_to_provider_error,
 };
-use super::fallback::{ExecutionResult, FallbackType};
+use super::fallback::ExecutionResult;
 use super::router::Router;
+use super::selection::RouteRequirements;
 use crate::core::providers::unified_provider::ProviderError;
 
 impl Router {
@@ -21,6 +22,22 @@ impl Router {
         model_name: &str,
         operation: F,
     ) -> Result<(T, DeploymentId, u32, u64), (ProviderError, u32)>
+    where
+        F: Fn(DeploymentId) -> Fut + Clone,
+        Fut: std::future::Future<Output = Result<(T, u64), ProviderError>>,
+    {
+        self.execute_with_retry_for(model_name, &RouteRequirements::default(), operation)
+            .await
+    }
+
+    /// Execute a request for a single model with retry logic on deployments
+    /// meeting the request's requirements
+    async fn execute_with_retry_for<T, F, Fut>(
+        &self,
+        model_name: &str,
+        requirements: &RouteRequirements,
+        operation: F,
+    ) -> Result<(T, DeploymentId, u32, u64), (ProviderError, u32)>
     where
         F: Fn(DeploymentId) -> Fut + Clone,
         Fut: std::future::Future<Output = Result<(T, u64), ProviderError>>,
@@ -29,10 +46,8 @@ impl Router {
         let mut last_error = None;
 
         for attempt in 1..=max_attempts {
-            let start = std::time::Instant::now();
-
             // Try to select a deployment
-            let deployment_id = match self.select_deployment(model_name) {
+            let deployment_id = match self.acquire_deployment_for(model_name, requirements).await {
                 Ok(id) => id,
                 Err(router_err) => {
                     let provider_err = router_error_to_provider_error(router_err);
@@ -48,15 +63,16 @@ impl Router {
                 }
             };
 
-            // Execute the operation
-            let result = operation(deployment_id.clone()).await;
-
-            let latency_us = start.elapsed().as_micros() as u64;
+            // Execute the operation, hedged on a second deployment if slow
+            let (result, deployment_id, latency_us) = self
+                .run_hedged(model_name, requirements, deployment_id, &operation)
+                .await;
 
             match result {
                 Ok((value, tokens_used)) => {
                     self.release_deployment(&deployment_id);
                     self.record_success(&deployment_id, tokens_used, latency_us);
+                    self.record_shared_tokens(&deployment_id, tokens_used).await;
                     return Ok((value, deployment_id, attempt, latency_us));
                 }
                 Err(err) => {
@@ -65,15 +81,17 @@ impl Router {
                     last_error = Some(err.clone());
 
                     if is_retryable_error(&err) && attempt < max_attempts {
-                        if let Some(d) = self.deployments.get(&deployment_id) {
-                            d.record_failure();
-                        }
+                        self.record_retryable_failure(&deployment_id, &err);
                         let delay = calculate_retry_delay(&self.config, attempt);
                         tokio::time::sleep(delay).await;
                         continue;
                     } else {
-                        let cooldown_reason = infer_cooldown_reason(&err);
-                        self.record_failure_with_reason(&deployment_id, cooldown_reason);
+                        // A request too long for the model says nothing
+                        // about the deployment's health
+                        if !matches!(err, ProviderError::ContextLengthExceeded { .. }) {
+                            let cooldown_reason = infer_cooldown_reason(&err);
+                            self.record_failure_with_reason(&deployment_id, cooldown_reason);
+                        }
                         return Err((err, attempt));
                     }
                 }
@@ -92,9 +110,14 @@ impl Router {
     /// Execute a request with full retry and fallback support
     ///
     /// This is the main execution method that implements the complete flow:
-    /// 1. Try the original model with retries
-    /// 2. On failure, try fallback models with retries
+    /// 1. Try the original model, or the model its traffic split assigns the
+    ///    request to, with retries
+    /// 2. Once all its deployments failed or are rate limited, try the
+    ///    fallback models for the kind of failure with retries
     /// 3. Respect max_fallbacks limit
+    ///
+    /// The operation is called with deployments of fallback models too; use
+    /// [`Router::map_chat_request`] to re-map a request to the deployment.
     pub async fn execute<T, F, Fut>(
         &self,
         model_name: &str,
@@ -104,39 +127,69 @@ impl Router {
         F: Fn(DeploymentId) -> Fut + Clone,
         Fut: std::future::Future<Output = Result<(T, u64), ProviderError>>,
     {
-        let start = std::time::Instant::now();
-
-        // Get all models to try (original + fallbacks)
-        let models_to_try = self.get_models_with_fallbacks(model_name, FallbackType::General);
-
-        // Limit fallback attempts
-        let max_models = 1 + self.config.max_fallbacks as usize;
-        let models_to_try: Vec<_> = models_to_try.into_iter().take(max_models).collect();
+        self.execute_for(model_name, &RouteRequirements::default(), operation)
+            .await
+    }
 
-        let mut last_error = None;
+    /// Execute a request with full retry and fallback support on deployments
+    /// meeting the request's requirements
+    ///
+    /// Deployments of fallback models must meet the requirements as well.
+    /// With pre-call checks, a request estimated to exceed the context window
+    /// of every deployment goes straight to the context window fallbacks.
+    pub async fn execute_for<T, F, Fut>(
+        &self,
+        model_name: &str,
+        requirements: &RouteRequirements,
+        operation: F,
+    ) -> Result<ExecutionResult<T>, RouterError>
+    where
+        F: Fn(DeploymentId) -> Fut + Clone,
+        Fut: std::future::Future<Output = Result<(T, u64), ProviderError>>,
+    {
+        let start = std::time::Instant::now();
+        let resolved_name = self.split_model_name(model_name, requirements.user.as_deref());
         let mut total_attempts = 0;
 
-        for (model_idx, model) in models_to_try.iter().enumerate() {
-            let is_fallback = model_idx > 0;
+        let last_error = match self
+            .execute_with_retry_for(&resolved_name, requirements, operation.clone())
+            .await
+        {
+            Ok((result, deployment_id, attempts, _latency_us)) => {
+                return Ok(self.build_result(
+                    result,
+                    deployment_id,
+                    attempts,
+                    &resolved_name,
+                    false,
+                    start,
+                ));
+            }
+            Err((err, attempts)) => {
+                total_attempts += attempts;
+                err
+            }
+        };
+
+        // Fall back on the models configured for the kind of failure
+        let fallback_type = Router::infer_fallback_type(&last_error);
+        let fallbacks = self.get_fallbacks(model_name, fallback_type);
+        let mut last_error = Some(last_error);
 
-            match self.execute_with_retry(model, operation.clone()).await {
+        for model in fallbacks.iter().take(self.config.max_fallbacks as usize) {
+            match self
+                .execute_with_retry_for(model, requirements, operation.clone())
+                .await
+            {
                 Ok((result, deployment_id, attempts, _latency_us)) => {
                     total_attempts += attempts;
-                    let total_latency_us = start.elapsed().as_micros() as u64;
-
-                    let model_used = if let Some(deployment) = self.get_deployment(&deployment_id) {
-                        deployment.model.clone()
-                    } else {
-                        model.clone()
-                    };
-
-                    return Ok(build_execution_result(
+                    return Ok(self.build_result(
                         result,
                         deployment_id,
                         total_attempts,
-                        model_used,
-                        is_fallback,
-                        total_latency_us,
+                        model,
+                        true,
+                        start,
                     ));
                 }
                 Err((err, attempts)) => {
@@ -153,6 +206,35 @@ impl Router {
         }
     }
 
+    /// Execution result of a request served by a deployment of a model
+    fn build_result<T>(
+        &self,
+        result: T,
+        deployment_id: DeploymentId,
+        attempts: u32,
+        model: &str,
+        is_fallback: bool,
+        start: std::time::Instant,
+    ) -> ExecutionResult<T> {
+        let total_latency_us = start.elapsed().as_micros() as u64;
+
+        let model_used = if let Some(deployment) = self.get_deployment(&deployment_id) {
+            deployment.model.clone()
+        } else {
+            model.to_string()
+        };
+
+        build_execution_result(
+            result,
+            deployment_id,
+            attempts,
+            model_used,
+            model.to_string(),
+            is_fallback,
+            total_latency_us,
+        )
+    }
+
     /// Execute a request once without retry or fallback
     ///
     /// This is a simplified execution method for testing or scenarios where
@@ -168,7 +250,7 @@ impl Router {
     {
         let start = std::time::Instant::now();
 
-        let deployment_id = self.select_deployment(model_name)?;
+        let deployment_id = self.acquire_deployment(model_name).await?;
 
         let result = operation(deployment_id.clone()).await;
 
@@ -179,18 +261,21 @@ impl Router {
         match result {
             Ok((value, tokens_used)) => {
                 self.record_success(&deployment_id, tokens_used, latency_us);
+                self.record_shared_tokens(&deployment_id, tokens_used).await;
 
-                let model_used = if let Some(deployment) = self.get_deployment(&deployment_id) {
-                    deployment.model.clone()
-                } else {
-                    model_name.to_string()
-                };
+                let (model_used, model_group) =
+                    if let Some(deployment) = self.get_deployment(&deployment_id) {
+                        (deployment.model.clone(), deployment.model_name.clone())
+                    } else {
+                        (model_name.to_string(), model_name.to_string())
+                    };
 
                 Ok(build_execution_result(
                     value,
                     deployment_id,
                     1,
                     model_used,
+                    model_group,
                     false,
                     latency_us,
                 ))
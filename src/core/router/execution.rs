@@ -103,6 +103,16 @@ pub fn router_error_to_provider_error(err: RouterError) -> ProviderError {
             message: "Deployment not found".to_string(),
         },
         RouterError::RateLimitExceeded(_msg) => ProviderError::rate_limit("router", Some(60)),
+        RouterError::AllDeploymentsBusy(msg) => ProviderError::ProviderUnavailable {
+            provider: "router",
+            message: format!("All deployments busy: {}", msg),
+        },
+        RouterError::ContextWindowExceeded { max, tokens, .. } => {
+            ProviderError::context_length_exceeded("router", max as usize, tokens as usize)
+        }
+        RouterError::UnsupportedCapability { capability, .. } => {
+            ProviderError::not_supported("router", capability)
+        }
     }
 }
 
@@ -111,6 +121,17 @@ pub fn provider_error_to_router_error(err: ProviderError, model_name: &str) -> R
     match err {
         ProviderError::ModelNotFound { model, .. } => RouterError::ModelNotFound(model),
         ProviderError::RateLimit { .. } => RouterError::RateLimitExceeded(model_name.to_string()),
+        ProviderError::ContextLengthExceeded { max, actual, .. } => {
+            RouterError::ContextWindowExceeded {
+                model: model_name.to_string(),
+                max: max as u32,
+                tokens: actual as u32,
+            }
+        }
+        ProviderError::NotSupported { feature, .. } => RouterError::UnsupportedCapability {
+            model: model_name.to_string(),
+            capability: feature,
+        },
         _ => RouterError::NoAvailableDeployment(format!("{}: {}", model_name, err)),
     }
 }
@@ -121,6 +142,7 @@ pub fn build_execution_result<T>(
     deployment_id: DeploymentId,
     attempts: u32,
     model_used: String,
+    model_group: String,
     used_fallback: bool,
     latency_us: u64,
 ) -> ExecutionResult<T> {
@@ -129,6 +151,7 @@ pub fn build_execution_result<T>(
         deployment_id,
         attempts,
         model_used,
+        model_group,
         used_fallback,
         latency_us,
     }
@@ -49,6 +49,9 @@ pub struct ExecutionResult<T> {
     pub attempts: u32,
     /// The actual model that was used (may differ from requested if fallback occurred)
     pub model_used: String,
+    /// The model group that served the request: the requested model, or the
+    /// fallback model it fell back on
+    pub model_group: String,
     /// Whether a fallback model was used (true if not the original model)
     pub used_fallback: bool,
     /// Total execution latency in microseconds (including retries)
@@ -6,15 +6,19 @@
 use super::config::RouterConfig;
 use super::deployment::{Deployment, DeploymentConfig};
 use super::error::RouterError;
+use super::fallback::FallbackConfig;
 use super::router::Router;
 use crate::config::ProviderConfig;
+use crate::config::models::router::RouterConfig as GatewayRouterConfig;
 use crate::core::providers::{Provider, ProviderType};
 
 impl Router {
     /// Create a Router from gateway configuration
     ///
     /// This method initializes a Router with deployments created from provider configurations.
-    /// Each provider in the config becomes a deployment in the router.
+    /// Each provider in the config becomes a deployment in the router, once per
+    /// API key: deployments of a provider's other keys serve the same models
+    /// with their own rate limits, and their IDs end in `-key2`, `-key3`, etc.
     pub async fn from_gateway_config(
         providers: &[ProviderConfig],
         router_config: Option<RouterConfig>,
@@ -33,14 +37,6 @@ impl Router {
             // Build config JSON from provider settings
             let mut settings = provider_config.settings.clone();
 
-            // Add api_key from the config if not in settings
-            if !settings.contains_key("api_key") && !provider_config.api_key.is_empty() {
-                settings.insert(
-                    "api_key".to_string(),
-                    serde_json::Value::String(provider_config.api_key.clone()),
-                );
-            }
-
             // Add base_url if present
             if let Some(ref base_url) = provider_config.base_url {
                 settings.insert(
@@ -49,51 +45,76 @@ impl Router {
                 );
             }
 
-            // Create provider instance
-            let provider = Provider::from_config_async(
-                provider_type.clone(),
-                serde_json::Value::Object(settings.into_iter().collect()),
-            )
-            .await
-            .map_err(|e| {
-                RouterError::DeploymentNotFound(format!(
-                    "Failed to create provider {}: {}",
-                    provider_config.name, e
-                ))
-            })?;
-
-            // Determine which models this deployment serves
-            let models: Vec<String> = if !provider_config.models.is_empty() {
-                provider_config.models.clone()
+            // One set of deployments per API key, unless the key is in settings
+            let keys = if settings.contains_key("api_key") || provider_config.keys().is_empty() {
+                vec![None]
             } else {
-                provider
-                    .list_models()
-                    .iter()
-                    .map(|m| m.id.clone())
-                    .collect()
+                provider_config.keys().into_iter().map(Some).collect()
             };
 
-            // Create deployments
-            if models.is_empty() {
-                // Create a single deployment with provider name
-                let deployment = create_deployment_from_config(
-                    &provider_config.name,
-                    provider.clone(),
-                    &provider_config.name,
-                    provider_config,
-                );
-                router.add_deployment(deployment);
-            } else {
-                // Create one deployment per model
-                for model in models {
-                    let deployment_id = format!("{}-{}", provider_config.name, model);
+            for (index, key) in keys.into_iter().enumerate() {
+                let mut settings = settings.clone();
+                if let Some(key) = key {
+                    settings.insert(
+                        "api_key".to_string(),
+                        serde_json::Value::String(key.to_string()),
+                    );
+                }
+
+                // Create provider instance
+                let provider = Provider::from_config_async(
+                    provider_type.clone(),
+                    serde_json::Value::Object(settings.into_iter().collect()),
+                )
+                .await
+                .map_err(|e| {
+                    RouterError::DeploymentNotFound(format!(
+                        "Failed to create provider {}: {}",
+                        provider_config.name, e
+                    ))
+                })?;
+
+                // Deployments of the first key keep the provider's IDs
+                let key_suffix = if index == 0 {
+                    String::new()
+                } else {
+                    format!("-key{}", index + 1)
+                };
+
+                // Determine which models this deployment serves
+                let models: Vec<String> = if !provider_config.models.is_empty() {
+                    provider_config.models.clone()
+                } else {
+                    provider
+                        .list_models()
+                        .iter()
+                        .map(|m| m.id.clone())
+                        .collect()
+                };
+
+                // Create deployments
+                if models.is_empty() {
+                    // Create a single deployment with provider name
                     let deployment = create_deployment_from_config(
-                        &deployment_id,
+                        &format!("{}{}", provider_config.name, key_suffix),
                         provider.clone(),
-                        &model,
+                        &provider_config.name,
                         provider_config,
                     );
                     router.add_deployment(deployment);
+                } else {
+                    // Create one deployment per model
+                    for model in models {
+                        let deployment_id =
+                            format!("{}-{}{}", provider_config.name, model, key_suffix);
+                        let deployment = create_deployment_from_config(
+                            &deployment_id,
+                            provider.clone(),
+                            &model,
+                            provider_config,
+                        );
+                        router.add_deployment(deployment);
+                    }
                 }
             }
         }
@@ -102,6 +123,59 @@ impl Router {
     }
 }
 
+impl From<&GatewayRouterConfig> for FallbackConfig {
+    /// General and context window fallbacks configured for the gateway
+    ///
+    /// Later entries for the same model replace earlier ones.
+    fn from(config: &GatewayRouterConfig) -> Self {
+        let fallback_config = config.fallbacks.iter().flatten().fold(
+            FallbackConfig::new(),
+            |fallback_config, (model, fallbacks)| {
+                fallback_config.add_general(model, fallbacks.clone())
+            },
+        );
+        config.context_window_fallbacks.iter().flatten().fold(
+            fallback_config,
+            |fallback_config, (model, fallbacks)| {
+                fallback_config.add_context_window(model, fallbacks.clone())
+            },
+        )
+    }
+}
+
+impl From<&GatewayRouterConfig> for RouterConfig {
+    /// Router settings configured for the gateway, with defaults for the rest
+    ///
+    /// Model group strategies with unknown names, which validation rejects,
+    /// are left out.
+    fn from(config: &GatewayRouterConfig) -> Self {
+        let model_group_strategies = config
+            .model_group_strategies
+            .iter()
+            .filter_map(|(model, strategy)| Some((model.clone(), strategy.parse().ok()?)))
+            .collect();
+        let traffic_splits = config
+            .traffic_splits
+            .iter()
+            .map(|(model, splits)| {
+                let splits = splits
+                    .iter()
+                    .map(|(target, percent)| (target.clone(), *percent))
+                    .collect();
+                (model.clone(), splits)
+            })
+            .collect();
+        Self {
+            model_group_strategies,
+            hedge_after_ms: config.hedge_after_ms,
+            drop_params: config.drop_params,
+            stream_failover_grace_ms: config.stream_failover_grace_ms,
+            traffic_splits,
+            ..Default::default()
+        }
+    }
+}
+
 /// Helper function to create deployment from provider config
 fn create_deployment_from_config(
     deployment_id: &str,
@@ -125,17 +199,30 @@ fn create_deployment_from_config(
         } else {
             None
         },
-        weight: config.weight as u32,
+        weight: deployment_weight(config.weight),
         timeout_secs: config.timeout,
         priority: 0,
+        quality_tier: None,
+        default_params: config.default_params.clone(),
+        forced_params: config.forced_params.clone(),
+        drop_params: config.drop_params,
+        additional_drop_params: config.additional_drop_params.clone(),
     };
 
     Deployment::new(
         deployment_id.to_string(),
         provider,
         model.to_string(),
-        model.to_string(),
+        config.model_name.as_deref().unwrap_or(model).to_string(),
     )
     .with_config(deployment_config)
     .with_tags(config.tags.clone())
 }
+
+/// Weight of a deployment from a provider's configured weight
+///
+/// Provider weights may be fractional, so they are scaled to keep their ratios
+/// (0.3 and 0.7 would otherwise both become 0).
+pub(crate) fn deployment_weight(weight: f32) -> u32 {
+    (weight.max(0.0) * 100.0).round() as u32
+}
@@ -0,0 +1,106 @@
+//! Hedged requests
+//!
+//! A request still running after `hedge_after_ms` is duplicated to a second
+//! deployment of the same model. Whichever deployment completes first serves
+//! the request; the other request is canceled by dropping it, and its
+//! deployment is released without recording a failure.
+
+use super::deployment::DeploymentId;
+use super::execution::{infer_cooldown_reason, is_retryable_error};
+use super::router::Router;
+use super::selection::RouteRequirements;
+use crate::core::providers::unified_provider::ProviderError;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+impl Router {
+    /// Run an operation on a deployment, hedging it on a second deployment
+    /// once `hedge_after_ms` passed
+    ///
+    /// Returns the result, the deployment that produced it and its latency.
+    /// That deployment is still acquired; any other one is released. If the
+    /// first request to complete failed, the other one is awaited and the
+    /// failure is recorded against its deployment. Without hedging, or
+    /// without a second deployment meeting the requirements, the operation
+    /// only runs on the given deployment.
+    pub(crate) async fn run_hedged<T, F, Fut>(
+        &self,
+        model_name: &str,
+        requirements: &RouteRequirements,
+        deployment_id: DeploymentId,
+        operation: &F,
+    ) -> (Result<(T, u64), ProviderError>, DeploymentId, u64)
+    where
+        F: Fn(DeploymentId) -> Fut,
+        Fut: Future<Output = Result<(T, u64), ProviderError>>,
+    {
+        let start = Instant::now();
+        let primary = operation(deployment_id.clone());
+        tokio::pin!(primary);
+
+        if self.config.hedge_after_ms > 0 {
+            let delay = Duration::from_millis(self.config.hedge_after_ms);
+            if let Ok(result) = tokio::time::timeout(delay, &mut primary).await {
+                return (result, deployment_id, elapsed_us(start));
+            }
+        } else {
+            let result = primary.await;
+            return (result, deployment_id, elapsed_us(start));
+        }
+
+        // A hedge never waits for a busy deployment
+        let mut hedge_requirements = requirements.clone();
+        hedge_requirements.exclude.push(deployment_id.clone());
+        let Ok(hedge_id) = self.select_deployment_for(model_name, &hedge_requirements) else {
+            let result = primary.await;
+            return (result, deployment_id, elapsed_us(start));
+        };
+        self.record_shared_request(&hedge_id).await;
+        debug!(
+            "Hedging request to deployment {} on deployment {}",
+            deployment_id, hedge_id
+        );
+
+        let hedge_start = Instant::now();
+        let hedge = operation(hedge_id.clone());
+        tokio::pin!(hedge);
+
+        let (primary_first, first) = tokio::select! {
+            result = &mut primary => (true, result),
+            result = &mut hedge => (false, result),
+        };
+        let ((first_id, first_start), (second_id, second_start)) = if primary_first {
+            ((deployment_id, start), (hedge_id, hedge_start))
+        } else {
+            ((hedge_id, hedge_start), (deployment_id, start))
+        };
+
+        match first {
+            Ok(value) => {
+                // The other request is canceled when dropped on return
+                self.release_deployment(&second_id);
+                (Ok(value), first_id, elapsed_us(first_start))
+            }
+            Err(err) => {
+                self.release_deployment(&first_id);
+                if is_retryable_error(&err) {
+                    self.record_retryable_failure(&first_id, &err);
+                } else if !matches!(err, ProviderError::ContextLengthExceeded { .. }) {
+                    self.record_failure_with_reason(&first_id, infer_cooldown_reason(&err));
+                }
+                let result = if primary_first {
+                    hedge.await
+                } else {
+                    primary.await
+                };
+                (result, second_id, elapsed_us(second_start))
+            }
+        }
+    }
+}
+
+/// Microseconds elapsed since a request started
+fn elapsed_us(start: Instant) -> u64 {
+    start.elapsed().as_micros() as u64
+}
@@ -14,12 +14,20 @@
 //! - `router` - Core Router struct and deployment management
 //! - `selection` - Deployment selection logic
 //! - `strategy_impl` - Routing strategy implementations
+//! - `usage` - Deployment usage shared between gateway instances
+//! - `probe` - Re-probing deployments after cooldown
+//! - `hedging` - Hedged requests on a second deployment
+//! - `streaming` - Failover of streaming requests
+//! - `traffic_split` - Traffic splitting between models
+//! - `request_mapping` - Re-mapping requests to the deployment they are routed to
 //! - `execution` - Execution helpers and error conversion
 //! - `execute_impl` - Execute methods with retry and fallback support
+//! - `chat` - Chat completions on the router's deployments
 //! - `gateway_config` - Gateway configuration integration
 //! - `legacy_router` - Legacy Router implementation
 
 // New modular router components
+mod chat;
 pub mod config;
 pub mod deployment;
 pub mod error;
@@ -27,9 +35,15 @@ pub mod execute_impl;
 pub mod execution;
 pub mod fallback;
 pub mod gateway_config;
+mod hedging;
+mod probe;
+mod request_mapping;
 pub mod router;
 pub mod selection;
 pub mod strategy_impl;
+pub mod streaming;
+mod traffic_split;
+mod usage;
 
 // Legacy modules (kept for backwards compatibility)
 pub mod health;
@@ -55,4 +69,6 @@ pub use strategy::types::RoutingStrategy;
 pub use config::{RouterConfig, RoutingStrategy as UnifiedRoutingStrategy};
 pub use error::{CooldownReason, RouterError};
 pub use fallback::{ExecutionResult, FallbackConfig, FallbackType};
-pub use router::Router as UnifiedRouter;
+pub use router::{CooldownEvent, Router as UnifiedRouter};
+pub use selection::RouteRequirements;
+pub use streaming::ChunkStream;
@@ -0,0 +1,88 @@
+//! Re-probing deployments after cooldown
+//!
+//! A deployment whose cooldown has ended gets no traffic until a health check
+//! of its provider passes; a failed check puts it back in cooldown. This
+//! makes cooldown a circuit breaker: open while cooling down, half-open while
+//! awaiting the probe, and closed again once the probe passes.
+
+use super::deployment::DeploymentId;
+use super::router::Router;
+use crate::core::types::health::HealthStatus as ProviderHealth;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How often deployments are checked for an ended cooldown
+const PROBE_INTERVAL: Duration = Duration::from_secs(1);
+
+impl Router {
+    /// Probe every deployment whose cooldown has ended
+    ///
+    /// Deployments whose provider is healthy or degraded get traffic again;
+    /// the others go back in cooldown. Each probe may take as long as the
+    /// deployment's request timeout.
+    pub async fn probe_cooled_down_deployments(&self) {
+        // Deployment entries are not held across awaits
+        let due: Vec<_> = self
+            .deployments
+            .iter()
+            .filter(|entry| entry.is_cooldown_expired())
+            .map(|entry| {
+                (
+                    entry.id.clone(),
+                    entry.provider.clone(),
+                    Duration::from_secs(entry.config.timeout_secs),
+                )
+            })
+            .collect();
+
+        let probes = due.into_iter().map(|(id, provider, timeout)| async move {
+            let status = tokio::time::timeout(timeout, provider.health_check()).await;
+            let passed = matches!(
+                status,
+                Ok(ProviderHealth::Healthy | ProviderHealth::Degraded)
+            );
+            (id, passed)
+        });
+        for (id, passed) in futures::future::join_all(probes).await {
+            self.settle_probe(&id, passed);
+        }
+    }
+
+    /// Restore a probed deployment, or put it back in cooldown
+    pub(crate) fn settle_probe(&self, deployment_id: &DeploymentId, passed: bool) {
+        let Some(deployment) = self.deployments.get(deployment_id) else {
+            return;
+        };
+        if passed {
+            info!(
+                "Deployment {} passed its probe, restoring traffic",
+                deployment_id
+            );
+            deployment.restore();
+        } else {
+            warn!(
+                "Deployment {} failed its probe, cooling down for {}s",
+                deployment_id, self.config.cooldown_time_secs
+            );
+            deployment.enter_cooldown(self.config.cooldown_time_secs);
+        }
+    }
+
+    /// Start background task to probe deployments whose cooldown has ended
+    ///
+    /// The task ends once the router is dropped, e.g. replaced on reload.
+    pub fn start_probe_task(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let router = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PROBE_INTERVAL);
+            loop {
+                interval.tick().await;
+                let Some(router) = router.upgrade() else {
+                    break;
+                };
+                router.probe_cooled_down_deployments().await;
+            }
+        })
+    }
+}
@@ -0,0 +1,255 @@
+//! Re-mapping requests to the deployment they are routed to
+//!
+//! A request falling back to another model group is written for the
+//! provider of the model it asked for. Before it is sent to a deployment of
+//! the fallback model, its model is replaced and its parameters are mapped
+//! to the ones the deployment's provider takes. Other requests only lose the
+//! parameters the provider does not take with `drop_params`, as in LiteLLM.
+//! Every request then gets the default and forced parameters configured for
+//! the deployment.
+
+use super::router::Router;
+use crate::core::types::chat::{ChatMessage, ChatRequest};
+use crate::core::types::message::{MessageContent, MessageRole};
+use serde_json::Value;
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+/// Parameter adding a system message rather than a request field
+const SYSTEM_PROMPT: &str = "system_prompt";
+
+/// Parameters a forced value caps rather than replaces
+const CAPPED_PARAMS: [&str; 2] = ["max_tokens", "max_completion_tokens"];
+
+/// Request parameter overriding whether unsupported parameters are dropped
+const DROP_PARAMS: &str = "drop_params";
+
+/// Request parameter listing more parameters to drop
+const ADDITIONAL_DROP_PARAMS: &str = "additional_drop_params";
+
+/// Request parameters the router reads for routing
+const ROUTING_PARAMS: [&str; 2] = ["tags", "metadata"];
+
+impl Router {
+    /// Chat request re-mapped to a deployment
+    ///
+    /// The model is always replaced by the deployment's model. If the
+    /// deployment serves a fallback model rather than the requested one,
+    /// `max_tokens` and `max_completion_tokens` are translated into the one
+    /// the provider takes, and OpenAI parameters the provider does not take
+    /// are dropped. Other requests only have them dropped with `drop_params`,
+    /// set by the request, else the deployment, else the router. Parameters
+    /// listed in the deployment's and request's `additional_drop_params` are
+    /// always dropped, as are the `tags` and `metadata` the request was routed
+    /// with. The deployment's default and forced parameters are applied last.
+    /// Returns `None` if the deployment does not exist.
+    pub fn map_chat_request(
+        &self,
+        deployment_id: &str,
+        request: &ChatRequest,
+    ) -> Option<ChatRequest> {
+        let deployment = self.deployments.get(deployment_id)?;
+        let mut mapped = request.clone();
+        mapped.model = deployment.model.clone();
+
+        // The gateway's own parameters are never sent to the provider
+        let request_drop_params = mapped
+            .extra_params
+            .remove(DROP_PARAMS)
+            .and_then(|value| value.as_bool());
+        let request_additional_drop_params: Vec<String> = mapped
+            .extra_params
+            .remove(ADDITIONAL_DROP_PARAMS)
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default();
+        for param in ROUTING_PARAMS {
+            mapped.extra_params.remove(param);
+        }
+
+        let is_fallback = deployment.model_name != self.resolve_model_name(&request.model);
+        let drop_params = request_drop_params
+            .or(deployment.config.drop_params)
+            .unwrap_or(self.config.drop_params);
+        if is_fallback || drop_params {
+            let supported = deployment
+                .provider
+                .get_supported_openai_params(&deployment.model);
+            if is_fallback {
+                map_params(&mut mapped, supported);
+            } else {
+                drop_unsupported_params(&mut mapped, supported);
+            }
+        }
+        drop_named_params(
+            &mut mapped,
+            deployment
+                .config
+                .additional_drop_params
+                .iter()
+                .chain(&request_additional_drop_params),
+        );
+        apply_params(
+            &mut mapped,
+            &deployment.config.default_params,
+            &deployment.config.forced_params,
+        );
+        Some(mapped)
+    }
+}
+
+/// Map a request's parameters to the ones a provider supports
+///
+/// Providers that do not list their parameters get them all.
+pub(crate) fn map_params(request: &mut ChatRequest, supported: &[&str]) {
+    if supported.is_empty() {
+        return;
+    }
+    let supports = |param: &str| supported.contains(&param);
+
+    if supports("max_tokens") && !supports("max_completion_tokens") {
+        request.max_tokens = request.max_tokens.or(request.max_completion_tokens.take());
+    } else if supports("max_completion_tokens") && !supports("max_tokens") {
+        request.max_completion_tokens = request.max_completion_tokens.or(request.max_tokens.take());
+    }
+
+    drop_unsupported_params(request, supported);
+}
+
+/// Drop the OpenAI parameters a provider does not support from a request
+///
+/// Providers that do not list their parameters get them all.
+pub(crate) fn drop_unsupported_params(request: &mut ChatRequest, supported: &[&str]) {
+    if supported.is_empty() {
+        return;
+    }
+    let supports = |param: &str| supported.contains(&param);
+
+    macro_rules! drop_unsupported {
+        ($($param:ident),+) => {
+            $(
+                if request.$param.is_some() && !supports(stringify!($param)) {
+                    debug!(
+                        "Dropping {} unsupported by model {}",
+                        stringify!($param),
+                        request.model
+                    );
+                    request.$param = None;
+                }
+            )+
+        };
+    }
+    drop_unsupported!(
+        temperature,
+        max_tokens,
+        max_completion_tokens,
+        top_p,
+        frequency_penalty,
+        presence_penalty,
+        stop,
+        tools,
+        tool_choice,
+        parallel_tool_calls,
+        response_format,
+        user,
+        seed,
+        n,
+        logit_bias,
+        logprobs,
+        top_logprobs
+    );
+}
+
+/// Drop parameters from a request by name
+///
+/// The model and messages are never dropped.
+pub(crate) fn drop_named_params<'a>(
+    request: &mut ChatRequest,
+    names: impl IntoIterator<Item = &'a String>,
+) {
+    let names: Vec<_> = names
+        .into_iter()
+        .filter(|name| !matches!(name.as_str(), "model" | "messages"))
+        .collect();
+    if names.is_empty() {
+        return;
+    }
+    let Ok(Value::Object(mut params)) = serde_json::to_value(&*request) else {
+        return;
+    };
+    for name in names {
+        if params.remove(name).is_some() {
+            debug!("Dropping {} from request to model {}", name, request.model);
+        }
+    }
+    match serde_json::from_value(Value::Object(params)) {
+        Ok(dropped) => *request = dropped,
+        Err(e) => warn!(
+            "Ignoring parameters to drop of model {}: {}",
+            request.model, e
+        ),
+    }
+}
+
+/// Apply a deployment's default and forced parameters to a request
+///
+/// Default parameters are set if the request does not set them, and forced
+/// ones override the request's, except that forced `max_tokens` and
+/// `max_completion_tokens` only lower the request's. A `system_prompt` adds a
+/// system message: a default one to requests without any, a forced one as
+/// the first message. Parameters of the wrong type leave the request as is.
+pub(crate) fn apply_params(
+    request: &mut ChatRequest,
+    defaults: &HashMap<String, Value>,
+    forced: &HashMap<String, Value>,
+) {
+    if defaults.is_empty() && forced.is_empty() {
+        return;
+    }
+    let Ok(Value::Object(mut params)) = serde_json::to_value(&*request) else {
+        return;
+    };
+
+    for (name, value) in defaults.iter().filter(|(name, _)| *name != SYSTEM_PROMPT) {
+        if params.get(name).is_none_or(Value::is_null) {
+            params.insert(name.clone(), value.clone());
+        }
+    }
+    for (name, value) in forced.iter().filter(|(name, _)| *name != SYSTEM_PROMPT) {
+        let capped = match (params.get(name).and_then(Value::as_u64), value.as_u64()) {
+            (Some(requested), Some(cap)) if CAPPED_PARAMS.contains(&name.as_str()) => {
+                requested.min(cap).into()
+            }
+            _ => value.clone(),
+        };
+        params.insert(name.clone(), capped);
+    }
+
+    match serde_json::from_value(Value::Object(params)) {
+        Ok(applied) => *request = applied,
+        Err(e) => warn!(
+            "Ignoring deployment parameters of model {}: {}",
+            request.model, e
+        ),
+    }
+
+    let has_system_message = request
+        .messages
+        .iter()
+        .any(|message| message.role == MessageRole::System);
+    if let Some(prompt) = forced.get(SYSTEM_PROMPT).and_then(Value::as_str) {
+        request.messages.insert(0, system_message(prompt));
+    } else if let Some(prompt) = defaults.get(SYSTEM_PROMPT).and_then(Value::as_str)
+        && !has_system_message
+    {
+        request.messages.insert(0, system_message(prompt));
+    }
+}
+
+/// System message of a prompt
+fn system_message(prompt: &str) -> ChatMessage {
+    ChatMessage {
+        role: MessageRole::System,
+        content: Some(MessageContent::Text(prompt.to_string())),
+        ..Default::default()
+    }
+}
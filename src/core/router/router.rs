@@ -9,11 +9,31 @@ use super::error::CooldownReason;
 use super::execution::infer_cooldown_reason;
 use super::fallback::{FallbackConfig, FallbackType};
 use crate::core::providers::unified_provider::ProviderError;
+use crate::core::rate_limiter::CounterStore;
+use crate::storage::redis::RedisPool;
 use dashmap::DashMap;
 use dashmap::mapref::one::Ref;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use std::time::Duration;
+use tokio::sync::{Notify, broadcast};
+
+/// A deployment entering cooldown
+#[derive(Debug, Clone)]
+pub struct CooldownEvent {
+    /// Deployment ID
+    pub deployment_id: DeploymentId,
+    /// Provider name
+    pub provider: String,
+    /// Actual model name
+    pub model: String,
+    /// User-facing model name
+    pub model_name: String,
+    /// Why the deployment was cooled down
+    pub reason: CooldownReason,
+    /// Cooldown duration in seconds
+    pub cooldown_secs: u64,
+}
 
 /// Unified Router
 ///
@@ -38,6 +58,15 @@ pub struct Router {
 
     /// Round-robin counters (per model, for RoundRobin strategy)
     pub(crate) round_robin_counters: DashMap<String, AtomicUsize>,
+
+    /// Cooldown event broadcaster
+    pub(crate) cooldown_events: broadcast::Sender<CooldownEvent>,
+
+    /// Notified when a deployment finishes a request
+    pub(crate) released: Notify,
+
+    /// Deployment usage shared between gateway instances, if configured
+    pub(crate) usage: Option<CounterStore>,
 }
 
 impl Router {
@@ -50,6 +79,9 @@ impl Router {
             config,
             fallback_config: FallbackConfig::default(),
             round_robin_counters: DashMap::new(),
+            cooldown_events: broadcast::channel(100).0,
+            released: Notify::new(),
+            usage: None,
         }
     }
 
@@ -59,6 +91,15 @@ impl Router {
         self
     }
 
+    /// Share deployment usage through Redis for usage-based routing v2
+    /// (builder pattern)
+    ///
+    /// Without Redis, usage is counted by this instance only.
+    pub fn with_shared_usage(mut self, redis: Arc<RedisPool>) -> Self {
+        self.usage = Some(CounterStore::new(redis));
+        self
+    }
+
     /// Set fallback configuration (runtime method)
     pub fn set_fallback_config(&mut self, config: FallbackConfig) {
         self.fallback_config = config;
@@ -69,6 +110,11 @@ impl Router {
         &self.config
     }
 
+    /// Subscribe to deployments entering cooldown
+    pub fn subscribe_cooldowns(&self) -> broadcast::Receiver<CooldownEvent> {
+        self.cooldown_events.subscribe()
+    }
+
     // ========== Deployment Management ==========
 
     /// Add a deployment to the router
@@ -181,15 +227,39 @@ impl Router {
         }
     }
 
+    /// Record the time to first token of a streaming response
+    pub fn record_ttft(&self, deployment_id: &str, ttft_us: u64) {
+        if let Some(deployment) = self.deployments.get(deployment_id) {
+            deployment.record_ttft(ttft_us);
+        }
+    }
+
     /// Record a failed request
+    ///
+    /// Puts the deployment in cooldown after `allowed_fails` failures in a
+    /// row.
     pub fn record_failure(&self, deployment_id: &str) {
         if let Some(deployment) = self.deployments.get(deployment_id) {
             deployment.record_failure();
 
-            let fails_this_minute = deployment.state.fails_this_minute.load(Relaxed);
-            if fails_this_minute >= self.config.allowed_fails {
-                deployment.enter_cooldown(self.config.cooldown_time_secs);
+            let consecutive_fails = deployment.state.consecutive_fails.load(Relaxed);
+            if consecutive_fails >= self.config.allowed_fails {
+                self.cool_down(&deployment, CooldownReason::ConsecutiveFailures);
+            }
+        }
+    }
+
+    /// Record a failed request that will be retried
+    ///
+    /// A rate limited deployment, e.g. one of several API keys of a provider,
+    /// is put in cooldown at once so retries go to another deployment; other
+    /// failures put it in cooldown after `allowed_fails` in a row.
+    pub fn record_retryable_failure(&self, deployment_id: &str, error: &ProviderError) {
+        match infer_cooldown_reason(error) {
+            CooldownReason::RateLimit => {
+                self.record_failure_with_reason(deployment_id, CooldownReason::RateLimit)
             }
+            _ => self.record_failure(deployment_id),
         }
     }
 
@@ -206,7 +276,7 @@ impl Router {
                 | CooldownReason::Manual => true,
 
                 CooldownReason::ConsecutiveFailures => {
-                    d.state.fails_this_minute.load(Relaxed) >= self.config.allowed_fails
+                    d.state.consecutive_fails.load(Relaxed) >= self.config.allowed_fails
                 }
 
                 CooldownReason::HighFailureRate => {
@@ -217,11 +287,30 @@ impl Router {
             };
 
             if should_cooldown {
-                d.enter_cooldown(self.config.cooldown_time_secs);
+                self.cool_down(&d, reason);
             }
         }
     }
 
+    /// Put a deployment in cooldown, announcing it if it was not already
+    fn cool_down(&self, deployment: &Deployment, reason: CooldownReason) {
+        let already_cooling = deployment.is_in_cooldown();
+        deployment.enter_cooldown(self.config.cooldown_time_secs);
+        if already_cooling {
+            return;
+        }
+
+        // No subscribers is not an error
+        let _ = self.cooldown_events.send(CooldownEvent {
+            deployment_id: deployment.id.clone(),
+            provider: deployment.provider.name().to_string(),
+            model: deployment.model.clone(),
+            model_name: deployment.model_name.clone(),
+            reason,
+            cooldown_secs: self.config.cooldown_time_secs,
+        });
+    }
+
     // ========== Fallback Methods ==========
 
     /// Infer fallback type from a ProviderError
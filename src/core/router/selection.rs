@@ -8,7 +8,48 @@ use super::deployment::{Deployment, DeploymentId};
 use super::error::RouterError;
 use super::router::Router;
 use super::strategy_impl;
+use crate::core::types::chat::ChatRequest;
 use std::sync::atomic::Ordering::Relaxed;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// What a request needs of the deployments it may be routed to
+#[derive(Debug, Clone, Default)]
+pub struct RouteRequirements {
+    /// Tags a deployment must all carry, e.g. `eu-only` for data residency;
+    /// a request is never routed to an untagged deployment instead
+    pub tags: Vec<String>,
+    /// Estimated input tokens, which must fit in a deployment's context
+    /// window when pre-call checks are enabled
+    pub input_tokens: Option<u32>,
+    /// The request offers tools, which a deployment's model must support
+    /// when pre-call checks are enabled
+    pub tools: bool,
+    /// The request contains images, which a deployment's model must support
+    /// when pre-call checks are enabled
+    pub images: bool,
+    /// Deployments the request must not be routed to, e.g. the one a hedged
+    /// request is already waiting on
+    pub exclude: Vec<DeploymentId>,
+    /// User the request is made for, who is kept on the same model when the
+    /// model group's traffic is split
+    pub user: Option<String>,
+}
+
+impl RouteRequirements {
+    /// Requirements of a chat request: its routing tags, estimated input
+    /// tokens, whether it uses tools or images, and its user
+    pub fn for_chat(request: &ChatRequest) -> Self {
+        Self {
+            tags: request.routing_tags(),
+            input_tokens: Some(request.estimate_input_tokens()),
+            tools: request.uses_tools(),
+            images: request.has_images(),
+            exclude: Vec::new(),
+            user: request.routing_user(),
+        }
+    }
+}
 
 impl Router {
     /// Check if deployment is within parallel request limit
@@ -19,6 +60,54 @@ impl Router {
         }
     }
 
+    /// Check if deployment meets the quality tier floor of its model group
+    pub(crate) fn check_quality_floor(&self, model_name: &str, deployment: &Deployment) -> bool {
+        match self.config.quality_tier_floors.get(model_name) {
+            Some(&floor) => deployment
+                .config
+                .quality_tier
+                .is_some_and(|tier| tier >= floor),
+            None => true,
+        }
+    }
+
+    /// Check if a request of the input tokens fits in deployment's context
+    /// window
+    ///
+    /// Deployments whose context window is unknown are assumed to fit it.
+    pub(crate) fn check_context_window(
+        &self,
+        deployment: &Deployment,
+        input_tokens: Option<u32>,
+    ) -> bool {
+        match (input_tokens, deployment.max_context_length()) {
+            (Some(tokens), Some(max)) if self.config.enable_pre_call_checks => tokens <= max,
+            _ => true,
+        }
+    }
+
+    /// Capability a request needs that deployment's model lacks, if any
+    ///
+    /// Deployments whose model is unknown are assumed to have every
+    /// capability.
+    pub(crate) fn missing_capability(
+        &self,
+        deployment: &Deployment,
+        requirements: &RouteRequirements,
+    ) -> Option<&'static str> {
+        if !self.config.enable_pre_call_checks {
+            return None;
+        }
+        let info = deployment.model_info()?;
+        if requirements.tools && !info.supports_tools {
+            Some("tools")
+        } else if requirements.images && !info.supports_multimodal {
+            Some("images")
+        } else {
+            None
+        }
+    }
+
     /// Check if deployment is within rate limits (TPM/RPM)
     pub(crate) fn check_rate_limit(&self, deployment: &Deployment) -> bool {
         let rpm_ok = match deployment.config.rpm_limit {
@@ -34,16 +123,52 @@ impl Router {
         rpm_ok && tpm_ok
     }
 
+    /// Check if a request of the expected input tokens fits in deployment's
+    /// remaining TPM
+    pub(crate) fn check_usage_headroom(&self, deployment: &Deployment) -> bool {
+        match deployment.config.tpm_limit {
+            Some(limit) => {
+                deployment.state.tpm_current.load(Relaxed)
+                    + u64::from(self.config.expected_input_tokens)
+                    <= limit
+            }
+            None => true,
+        }
+    }
+
     /// Select the best deployment for a given model (core routing method)
     ///
+    /// See [`Router::select_deployment_for`].
+    pub fn select_deployment(&self, model_name: &str) -> Result<DeploymentId, RouterError> {
+        self.select_deployment_for(model_name, &RouteRequirements::default())
+    }
+
+    /// Select the best deployment for a given model among the deployments
+    /// meeting a request's requirements
+    ///
     /// # Flow
     ///
     /// 1. Resolve model_name (handle aliases)
     /// 2. Get all deployment IDs for this model
-    /// 3. Filter: healthy + not in cooldown + not rate limited
-    /// 4. Select based on routing strategy
+    /// 3. Filter: not excluded + carries the tags + healthy + not in cooldown +
+    ///    at or above the quality tier floor + context window fits the input
+    ///    tokens + model supports the tools and images used + under max
+    ///    parallel requests + not rate limited (with usage-based routing v2,
+    ///    with TPM left for the request); if only the context window, model
+    ///    capabilities, parallel or rate limits exclude deployments, fail with
+    ///    `ContextWindowExceeded`, `UnsupportedCapability`,
+    ///    `AllDeploymentsBusy` or `RateLimitExceeded`, but with deployments in
+    ///    cooldown, unhealthy or below the floor, fail with
+    ///    `AllDeploymentsInCooldown` or `NoAvailableDeployment` rather than a
+    ///    context window or capability error
+    /// 4. Select based on the model group's routing strategy
     /// 5. Increment active_requests counter
-    pub fn select_deployment(&self, model_name: &str) -> Result<DeploymentId, RouterError> {
+    #[tracing::instrument(name = "router.select_deployment", skip(self))]
+    pub fn select_deployment_for(
+        &self,
+        model_name: &str,
+        requirements: &RouteRequirements,
+    ) -> Result<DeploymentId, RouterError> {
         // 1. Resolve model name (handle aliases)
         let resolved_name = self.resolve_model_name(model_name);
 
@@ -58,20 +183,59 @@ impl Router {
             return Err(RouterError::ModelNotFound(model_name.to_string()));
         }
 
-        // 3. Filter: healthy + not in cooldown + not rate limited
+        // 3. Filter: not excluded + tagged + healthy + not in cooldown + above
+        //    floor + fits + capable + not busy + not rate limited
+        let strategy = self.config.strategy_for(&resolved_name);
+        let mut largest_context = None;
+        let mut unsupported = None;
+        let mut cooling_down = false;
+        let mut unavailable = false;
+        let mut busy = false;
+        let mut rate_limited = false;
         let candidate_ids: Vec<DeploymentId> = deployment_ids
             .iter()
             .filter(|id| {
+                if requirements.exclude.contains(id) {
+                    return false;
+                }
+
                 if let Some(deployment) = self.deployments.get(id.as_str()) {
-                    if !deployment.is_healthy() || deployment.is_in_cooldown() {
+                    if !deployment.has_tags(&requirements.tags) {
+                        return false;
+                    }
+
+                    if deployment.is_in_cooldown() {
+                        cooling_down = true;
+                        return false;
+                    }
+
+                    if !deployment.is_healthy()
+                        || !self.check_quality_floor(&resolved_name, &deployment)
+                    {
+                        unavailable = true;
+                        return false;
+                    }
+
+                    if !self.check_context_window(&deployment, requirements.input_tokens) {
+                        largest_context = largest_context.max(deployment.max_context_length());
+                        return false;
+                    }
+
+                    if let Some(capability) = self.missing_capability(&deployment, requirements) {
+                        unsupported = Some(capability);
                         return false;
                     }
 
                     if !self.check_parallel_limit(&deployment) {
+                        busy = true;
                         return false;
                     }
 
-                    if !self.check_rate_limit(&deployment) {
+                    if !self.check_rate_limit(&deployment)
+                        || strategy == RoutingStrategy::UsageBasedV2
+                            && !self.check_usage_headroom(&deployment)
+                    {
+                        rate_limited = true;
                         return false;
                     }
 
@@ -84,26 +248,61 @@ impl Router {
             .collect();
 
         if candidate_ids.is_empty() {
+            // Busy deployments free up as soon as a request finishes
+            if busy {
+                return Err(RouterError::AllDeploymentsBusy(model_name.to_string()));
+            }
+            // Usable deployments that used up their RPM/TPM free up within the minute
+            if rate_limited {
+                return Err(RouterError::RateLimitExceeded(model_name.to_string()));
+            }
+            // Deployments out of service may well fit and be capable
+            if cooling_down {
+                return Err(RouterError::AllDeploymentsInCooldown(
+                    model_name.to_string(),
+                ));
+            }
+            if unavailable {
+                return Err(RouterError::NoAvailableDeployment(model_name.to_string()));
+            }
+            // Only a model with a larger context window can serve the request
+            if let Some(max) = largest_context {
+                return Err(RouterError::ContextWindowExceeded {
+                    model: model_name.to_string(),
+                    max,
+                    tokens: requirements.input_tokens.unwrap_or_default(),
+                });
+            }
+            // Only a model with the capability can serve the request
+            if let Some(capability) = unsupported {
+                return Err(RouterError::UnsupportedCapability {
+                    model: model_name.to_string(),
+                    capability: capability.to_string(),
+                });
+            }
             return Err(RouterError::NoAvailableDeployment(model_name.to_string()));
         }
 
         // 4. Select based on routing strategy
-        let selected_id = match self.config.routing_strategy {
+        let selected_id = match strategy {
             RoutingStrategy::SimpleShuffle => {
                 strategy_impl::weighted_random(&candidate_ids, &self.deployments)
             }
             RoutingStrategy::LeastBusy => {
                 strategy_impl::least_busy(&candidate_ids, &self.deployments)
             }
-            RoutingStrategy::UsageBased => {
+            RoutingStrategy::UsageBased | RoutingStrategy::UsageBasedV2 => {
                 strategy_impl::lowest_usage(&candidate_ids, &self.deployments)
             }
             RoutingStrategy::LatencyBased => {
                 strategy_impl::lowest_latency(&candidate_ids, &self.deployments)
             }
-            RoutingStrategy::CostBased => {
-                strategy_impl::lowest_cost(&candidate_ids, &self.deployments)
-            }
+            RoutingStrategy::CostBased => strategy_impl::lowest_cost(
+                &candidate_ids,
+                &self.deployments,
+                self.config.expected_input_tokens,
+                self.config.expected_output_tokens,
+            ),
             RoutingStrategy::RateLimitAware => {
                 strategy_impl::rate_limit_aware(&candidate_ids, &self.deployments)
             }
@@ -122,12 +321,48 @@ impl Router {
         Ok(selected_id)
     }
 
+    /// Select a deployment, waiting up to `queue_timeout_ms` for one to fall
+    /// under its max parallel requests
+    ///
+    /// Fails with `AllDeploymentsBusy` once the wait times out. With
+    /// usage-based routing v2, selection uses and counts shared usage.
+    pub async fn acquire_deployment(&self, model_name: &str) -> Result<DeploymentId, RouterError> {
+        self.acquire_deployment_for(model_name, &RouteRequirements::default())
+            .await
+    }
+
+    /// Select a deployment meeting a request's requirements, waiting like
+    /// [`Router::acquire_deployment`]
+    pub async fn acquire_deployment_for(
+        &self,
+        model_name: &str,
+        requirements: &RouteRequirements,
+    ) -> Result<DeploymentId, RouterError> {
+        self.sync_usage(model_name).await;
+        let deadline = Instant::now() + Duration::from_millis(self.config.queue_timeout_ms);
+        loop {
+            // Register before selecting so a release in between is not missed
+            let released = self.released.notified();
+            let result = self.select_deployment_for(model_name, requirements);
+            if let Ok(deployment_id) = &result {
+                self.record_shared_request(deployment_id).await;
+            }
+            if !matches!(result, Err(RouterError::AllDeploymentsBusy(_)))
+                || tokio::time::timeout_at(deadline, released).await.is_err()
+            {
+                return result;
+            }
+        }
+    }
+
     /// Release a deployment after request completion
     ///
-    /// Decrements the active_requests counter for the deployment.
+    /// Decrements the active_requests counter for the deployment and wakes
+    /// requests waiting for a deployment.
     pub fn release_deployment(&self, deployment_id: &str) {
         if let Some(deployment) = self.deployments.get(deployment_id) {
             deployment.state.active_requests.fetch_sub(1, Relaxed);
         }
+        self.released.notify_waiters();
     }
 }
@@ -3,7 +3,9 @@
 //! This module contains the implementation of 7 routing strategies
 //! for selecting deployments.
 
-use super::deployment::{Deployment, DeploymentId};
+use super::deployment::{Deployment, DeploymentId, HealthStatus};
+use crate::core::providers::base::pricing::ModelPricing;
+use crate::core::providers::base::{PricingDatabase, get_pricing_db};
 use dashmap::DashMap;
 use rand::Rng;
 use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
@@ -38,7 +40,7 @@ pub trait StrategySelector {
         deployments: &DashMap<DeploymentId, Deployment>,
     ) -> DeploymentId;
 
-    /// Select a deployment with lowest cost (priority)
+    /// Select a deployment with lowest expected cost
     fn select_lowest_cost(
         &self,
         candidate_ids: &[DeploymentId],
@@ -191,7 +193,9 @@ pub fn lowest_usage(
 
 /// Select deployment with lowest average latency (LatencyBased)
 ///
-/// Selects the deployment with the lowest average latency.
+/// Prefers healthy deployments over degraded ones. Deployments are compared
+/// by average time to first token once every candidate has served a
+/// streaming response, and by average total latency otherwise.
 /// New deployments (latency = 0) are given a chance by treating them
 /// as having average latency.
 pub fn lowest_latency(
@@ -202,14 +206,26 @@ pub fn lowest_latency(
         panic!("lowest_latency called with empty candidates");
     }
 
+    let candidate_ids = &prefer_healthy(candidate_ids, deployments);
+
+    // Mixing TTFT and total latency would favor streaming deployments
+    let use_ttft = candidate_ids.iter().all(|id| {
+        deployments
+            .get(id.as_str())
+            .is_some_and(|d| d.state.avg_ttft_us.load(Relaxed) > 0)
+    });
+    let latency_of = |deployment: &Deployment| {
+        if use_ttft {
+            deployment.state.avg_ttft_us.load(Relaxed)
+        } else {
+            deployment.state.avg_latency_us.load(Relaxed)
+        }
+    };
+
     // Calculate average latency across all candidates (for new deployments)
     let latencies: Vec<u64> = candidate_ids
         .iter()
-        .filter_map(|id| {
-            deployments
-                .get(id.as_str())
-                .map(|d| d.state.avg_latency_us.load(Relaxed))
-        })
+        .filter_map(|id| deployments.get(id.as_str()).map(|d| latency_of(&d)))
         .filter(|&lat| lat > 0)
         .collect();
 
@@ -224,7 +240,7 @@ pub fn lowest_latency(
 
     for id in candidate_ids {
         if let Some(deployment) = deployments.get(id.as_str()) {
-            let mut latency = deployment.state.avg_latency_us.load(Relaxed);
+            let mut latency = latency_of(&deployment);
 
             // Treat new deployments (latency = 0) as having average latency
             if latency == 0 {
@@ -241,25 +257,39 @@ pub fn lowest_latency(
     best_id.clone()
 }
 
-/// Select deployment with lowest cost (CostBased)
+/// Select deployment with lowest expected cost (CostBased)
 ///
-/// Currently uses priority as a cost proxy (lower priority = lower cost).
+/// Prices a request of the expected input and output tokens with each
+/// deployment's model in the pricing database, preferring healthy
+/// deployments over degraded ones. Deployments whose model is not priced are
+/// only chosen when no priced one is left; ties go to the lower priority.
 pub fn lowest_cost(
     candidate_ids: &[DeploymentId],
     deployments: &DashMap<DeploymentId, Deployment>,
+    expected_input_tokens: u32,
+    expected_output_tokens: u32,
 ) -> DeploymentId {
     if candidate_ids.is_empty() {
         panic!("lowest_cost called with empty candidates");
     }
 
+    let candidate_ids = &prefer_healthy(candidate_ids, deployments);
+    let pricing_db = get_pricing_db();
+
     let mut best_id = &candidate_ids[0];
-    let mut best_priority = u32::MAX;
+    let mut best_cost = (f64::INFINITY, u32::MAX);
 
     for id in candidate_ids {
         if let Some(deployment) = deployments.get(id.as_str()) {
-            let priority = deployment.config.priority;
-            if priority < best_priority {
-                best_priority = priority;
+            let cost = model_pricing(pricing_db, &deployment.model)
+                .map(|pricing| {
+                    f64::from(expected_input_tokens) * pricing.input_cost_per_token
+                        + f64::from(expected_output_tokens) * pricing.output_cost_per_token
+                })
+                .unwrap_or(f64::INFINITY);
+            let cost = (cost, deployment.config.priority);
+            if cost < best_cost {
+                best_cost = cost;
                 best_id = id;
             }
         }
@@ -268,6 +298,38 @@ pub fn lowest_cost(
     best_id.clone()
 }
 
+/// Pricing of a deployment's model, with or without its provider prefix
+fn model_pricing<'a>(pricing_db: &'a PricingDatabase, model: &str) -> Option<&'a ModelPricing> {
+    pricing_db.get_model_info(model).or_else(|| {
+        model
+            .split_once('/')
+            .and_then(|(_, model)| pricing_db.get_model_info(model))
+    })
+}
+
+/// Healthy candidates, or all candidates when none is healthy
+///
+/// Degraded deployments are only used when no healthy one is left.
+fn prefer_healthy(
+    candidate_ids: &[DeploymentId],
+    deployments: &DashMap<DeploymentId, Deployment>,
+) -> Vec<DeploymentId> {
+    let healthy: Vec<DeploymentId> = candidate_ids
+        .iter()
+        .filter(|id| {
+            deployments
+                .get(id.as_str())
+                .is_some_and(|d| d.state.health_status() == HealthStatus::Healthy)
+        })
+        .cloned()
+        .collect();
+    if healthy.is_empty() {
+        candidate_ids.to_vec()
+    } else {
+        healthy
+    }
+}
+
 /// Select deployment that is furthest from rate limits (RateLimitAware)
 ///
 /// Calculates distance from rate limit as: (limit - current) / limit
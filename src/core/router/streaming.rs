@@ -0,0 +1,180 @@
+//! Failover of streaming requests
+//!
+//! A stream whose connection dies before it emitted content is restarted on
+//! another deployment: first the model's other deployments, then those of
+//! its fallback models. Chunks are held back until one carries content and
+//! `stream_failover_grace_ms` passed since the stream started, so the client
+//! never sees a broken stream it would have to stitch together. A stream
+//! failing after that ends with the error.
+
+use super::deployment::DeploymentId;
+use super::error::RouterError;
+use super::execution::{infer_cooldown_reason, is_retryable_error, router_error_to_provider_error};
+use super::router::Router;
+use super::selection::RouteRequirements;
+use crate::core::providers::unified_provider::ProviderError;
+use crate::core::types::responses::ChatChunk;
+use futures::{Stream, StreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Stream of chat chunks
+pub type ChunkStream = Pin<Box<dyn Stream<Item = Result<ChatChunk, ProviderError>> + Send>>;
+
+/// Deployment serving a stream, released once the stream ends or is dropped
+struct StreamLease {
+    router: Arc<Router>,
+    deployment_id: DeploymentId,
+}
+
+impl Drop for StreamLease {
+    fn drop(&mut self) {
+        self.router.release_deployment(&self.deployment_id);
+    }
+}
+
+impl Router {
+    /// Execute a streaming request, failing over to other deployments until
+    /// the stream emitted content
+    ///
+    /// The operation opens the stream on a deployment. Each deployment of
+    /// the model is tried once, up to `num_retries` more after the first;
+    /// a non-retryable failure moves straight on to the fallback models for
+    /// the kind of failure, as many as `max_fallbacks`. The operation is
+    /// called with deployments of fallback models too; use
+    /// [`Router::map_chat_request`] to re-map a request to the deployment.
+    pub fn execute_stream<F, Fut>(
+        self: Arc<Self>,
+        model_name: &str,
+        requirements: RouteRequirements,
+        operation: F,
+    ) -> ChunkStream
+    where
+        F: Fn(DeploymentId) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<ChunkStream, ProviderError>> + Send,
+    {
+        let model_name = model_name.to_string();
+        Box::pin(async_stream::stream! {
+            let grace = Duration::from_millis(self.config.stream_failover_grace_ms);
+            let max_attempts = self.config.num_retries + 1;
+            let mut requirements = requirements;
+            let mut chain = vec![self.split_model_name(&model_name, requirements.user.as_deref())];
+            let mut next = 0;
+            let mut attempts = 0;
+            let mut last_error = None;
+
+            while let Some(model) = chain.get(next).cloned() {
+                let acquired = if attempts < max_attempts {
+                    self.acquire_deployment_for(&model, &requirements).await
+                } else {
+                    Err(RouterError::NoAvailableDeployment(model.clone()))
+                };
+                let deployment_id = match acquired {
+                    Ok(id) => id,
+                    Err(router_err) => {
+                        // Out of deployments or attempts for the model
+                        let err = last_error
+                            .get_or_insert_with(|| router_error_to_provider_error(router_err));
+                        if next == 0 {
+                            let fallback_type = Router::infer_fallback_type(err);
+                            chain.extend(
+                                self.get_fallbacks(&model_name, fallback_type)
+                                    .into_iter()
+                                    .take(self.config.max_fallbacks as usize),
+                            );
+                        }
+                        next += 1;
+                        attempts = 0;
+                        continue;
+                    }
+                };
+                attempts += 1;
+                requirements.exclude.push(deployment_id.clone());
+                let lease = StreamLease {
+                    router: self.clone(),
+                    deployment_id: deployment_id.clone(),
+                };
+
+                let start = Instant::now();
+                let mut held = Vec::new();
+                let mut seen_content = false;
+                let mut committed = false;
+                let mut tokens = 0;
+                let failure = match operation(deployment_id.clone()).await {
+                    Err(err) => Some(err),
+                    Ok(mut stream) => {
+                        let mut failure = None;
+                        while let Some(item) = stream.next().await {
+                            let chunk = match item {
+                                Ok(chunk) => chunk,
+                                Err(err) => {
+                                    failure = Some(err);
+                                    break;
+                                }
+                            };
+                            if let Some(usage) = &chunk.usage {
+                                tokens = u64::from(usage.total_tokens);
+                            }
+                            if committed {
+                                yield Ok(chunk);
+                                continue;
+                            }
+                            if !seen_content && chunk.has_content() {
+                                seen_content = true;
+                                let ttft_us = start.elapsed().as_micros() as u64;
+                                self.record_ttft(&deployment_id, ttft_us);
+                            }
+                            held.push(chunk);
+                            if seen_content && start.elapsed() >= grace {
+                                committed = true;
+                                for chunk in held.drain(..) {
+                                    yield Ok(chunk);
+                                }
+                            }
+                        }
+                        failure
+                    }
+                };
+
+                let Some(err) = failure else {
+                    for chunk in held {
+                        yield Ok(chunk);
+                    }
+                    let latency_us = start.elapsed().as_micros() as u64;
+                    self.record_success(&deployment_id, tokens, latency_us);
+                    self.record_shared_tokens(&deployment_id, tokens).await;
+                    return;
+                };
+
+                if is_retryable_error(&err) {
+                    self.record_retryable_failure(&deployment_id, &err);
+                } else if !matches!(err, ProviderError::ContextLengthExceeded { .. }) {
+                    self.record_failure_with_reason(&deployment_id, infer_cooldown_reason(&err));
+                }
+                if committed {
+                    yield Err(err);
+                    return;
+                }
+                drop(lease);
+
+                warn!(
+                    "Stream from deployment {} failed before content, failing over: {}",
+                    deployment_id, err
+                );
+                // Other deployments of the model would fail the same way
+                if !is_retryable_error(&err) {
+                    attempts = max_attempts;
+                }
+                last_error = Some(err);
+            }
+
+            yield Err(last_error.unwrap_or_else(|| ProviderError::Other {
+                provider: "router",
+                message: "Unknown error during stream failover".to_string(),
+            }));
+        })
+    }
+}
@@ -330,3 +330,119 @@ async fn test_multiple_deployments_minute_reset() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_cooldown_event_sent_once() {
+    let router = Router::default();
+    let deployment = create_test_deployment("test-1", "gpt-4").await;
+    router.add_deployment(deployment);
+    let mut events = router.subscribe_cooldowns();
+
+    router.record_failure_with_reason("test-1", CooldownReason::RateLimit);
+    router.record_failure_with_reason("test-1", CooldownReason::RateLimit);
+
+    let event = events.try_recv().unwrap();
+    assert_eq!(event.deployment_id, "test-1");
+    assert_eq!(event.model_name, "gpt-4");
+    assert_eq!(event.reason, CooldownReason::RateLimit);
+    assert!(events.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_success_resets_consecutive_failures() {
+    let config = RouterConfig {
+        allowed_fails: 3,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+
+    router.record_failure("test-1");
+    router.record_failure("test-1");
+    router.record_success("test-1", 100, 50_000);
+    router.record_failure("test-1");
+    router.record_failure("test-1");
+
+    let d = router.get_deployment("test-1").unwrap();
+    assert!(!d.is_in_cooldown());
+    assert_eq!(d.state.consecutive_fails.load(Ordering::Relaxed), 2);
+    assert_eq!(d.state.fails_this_minute.load(Ordering::Relaxed), 4);
+}
+
+#[tokio::test]
+async fn test_retryable_failures_trigger_cooldown() {
+    let config = RouterConfig {
+        num_retries: 2,
+        allowed_fails: 2,
+        cooldown_time_secs: 30,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+
+    let result = router
+        .execute_with_retry("gpt-4", |_| async {
+            Err::<(String, u64), _>(ProviderError::timeout("test", "timed out"))
+        })
+        .await;
+    assert!(result.is_err());
+
+    let d = router.get_deployment("test-1").unwrap();
+    assert!(d.is_in_cooldown());
+    assert!(d.cooldown_remaining_secs() > 0);
+}
+
+#[tokio::test]
+async fn test_expired_cooldown_awaits_probe() {
+    let router = Router::default();
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.record_failure_with_reason("test-1", CooldownReason::Manual);
+
+    // End the cooldown without restoring the deployment
+    router
+        .get_deployment("test-1")
+        .unwrap()
+        .state
+        .cooldown_until
+        .store(0, Ordering::Relaxed);
+    assert!(
+        router
+            .get_deployment("test-1")
+            .unwrap()
+            .is_cooldown_expired()
+    );
+    assert!(router.select_deployment("gpt-4").is_err());
+
+    router.settle_probe(&"test-1".to_string(), true);
+    let d = router.get_deployment("test-1").unwrap();
+    assert_eq!(d.state.health_status(), HealthStatus::Healthy);
+    assert_eq!(d.state.consecutive_fails.load(Ordering::Relaxed), 0);
+    drop(d);
+    assert_eq!(router.select_deployment("gpt-4").unwrap(), "test-1");
+}
+
+#[tokio::test]
+async fn test_failed_probe_restarts_cooldown() {
+    let config = RouterConfig {
+        cooldown_time_secs: 30,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+    deployment.config.timeout_secs = 1;
+    router.add_deployment(deployment);
+    router.record_failure_with_reason("test-1", CooldownReason::Manual);
+    router
+        .get_deployment("test-1")
+        .unwrap()
+        .state
+        .cooldown_until
+        .store(0, Ordering::Relaxed);
+
+    // The test provider's key is rejected, so its health check fails
+    router.probe_cooled_down_deployments().await;
+
+    let d = router.get_deployment("test-1").unwrap();
+    assert!(d.is_in_cooldown());
+    assert_eq!(d.state.health_status(), HealthStatus::Cooldown);
+}
@@ -255,6 +255,23 @@ async fn test_exponential_moving_average() {
     );
 }
 
+#[tokio::test]
+async fn test_record_ttft() {
+    let provider = create_test_provider().await;
+    let deployment = Deployment::new(
+        "test-deployment".to_string(),
+        provider,
+        "gpt-4-turbo".to_string(),
+        "gpt-4".to_string(),
+    );
+
+    deployment.record_ttft(2000);
+    deployment.record_ttft(7000);
+    // EMA = (7000 + 4*2000) / 5 = 3000
+    assert_eq!(deployment.state.avg_ttft_us.load(Ordering::Relaxed), 3000);
+    assert_eq!(deployment.state.avg_latency_us.load(Ordering::Relaxed), 0);
+}
+
 #[test]
 fn test_health_status_conversion() {
     assert_eq!(HealthStatus::from(0), HealthStatus::Unknown);
@@ -4,9 +4,10 @@ use super::router_tests::create_test_deployment;
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::router::config::RouterConfig;
 use crate::core::router::error::RouterError;
-use crate::core::router::execution::is_retryable_error;
+use crate::core::router::execution::{is_retryable_error, router_error_to_provider_error};
 use crate::core::router::fallback::{ExecutionResult, FallbackConfig};
 use crate::core::router::router::Router;
+use crate::core::router::selection::RouteRequirements;
 use std::sync::atomic::Ordering;
 
 #[test]
@@ -266,6 +267,100 @@ async fn test_execute_all_models_fail() {
     ));
 }
 
+#[tokio::test]
+async fn test_execute_falls_back_by_failure() {
+    let config = RouterConfig {
+        num_retries: 0,
+        retry_after_secs: 0,
+        ..Default::default()
+    };
+
+    let fallback_config = FallbackConfig::new()
+        .add_general("gpt-4", vec!["general".to_string()])
+        .add_rate_limit("gpt-4", vec!["rate-limited".to_string()]);
+
+    let router = Router::new(config).with_fallback_config(fallback_config);
+    router.add_deployment(create_test_deployment("test-gpt4", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-general", "general").await);
+    router.add_deployment(create_test_deployment("test-rate-limited", "rate-limited").await);
+
+    let result: ExecutionResult<String> = router
+        .execute("gpt-4", |deployment_id| async move {
+            if deployment_id == "test-gpt4" {
+                Err(ProviderError::rate_limit("test", Some(60)))
+            } else {
+                Ok((deployment_id, 100u64))
+            }
+        })
+        .await
+        .unwrap();
+
+    assert!(result.used_fallback);
+    assert_eq!(result.result, "test-rate-limited");
+    assert_eq!(result.attempts, 2);
+}
+
+#[tokio::test]
+async fn test_execute_context_window_fallback_before_call() {
+    let fallback_config =
+        FallbackConfig::new().add_context_window("small", vec!["large".to_string()]);
+    let router = Router::default().with_fallback_config(fallback_config);
+
+    let mut small = create_test_deployment("test-small", "small").await;
+    small.model = "gpt-4".to_string();
+    let mut large = create_test_deployment("test-large", "large").await;
+    large.model = "gpt-4-turbo".to_string();
+    router.add_deployment(small);
+    router.add_deployment(large);
+
+    let requirements = RouteRequirements {
+        input_tokens: Some(20_000),
+        ..Default::default()
+    };
+    let result: ExecutionResult<String> = router
+        .execute_for("small", &requirements, |deployment_id| async move {
+            assert_eq!(deployment_id, "test-large");
+            Ok((deployment_id, 100u64))
+        })
+        .await
+        .unwrap();
+
+    assert!(result.used_fallback);
+    assert_eq!(result.model_group, "large");
+    assert_eq!(result.model_used, "gpt-4-turbo");
+}
+
+#[tokio::test]
+async fn test_execute_context_window_fallback_after_call() {
+    let config = RouterConfig {
+        num_retries: 0,
+        ..Default::default()
+    };
+    let fallback_config =
+        FallbackConfig::new().add_context_window("small", vec!["large".to_string()]);
+    let router = Router::new(config).with_fallback_config(fallback_config);
+    router.add_deployment(create_test_deployment("test-small", "small").await);
+    router.add_deployment(create_test_deployment("test-large", "large").await);
+
+    let result: ExecutionResult<String> = router
+        .execute("small", |deployment_id| async move {
+            if deployment_id == "test-small" {
+                Err(ProviderError::context_length_exceeded("test", 8192, 20_000))
+            } else {
+                Ok((deployment_id, 100u64))
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.model_group, "large");
+    assert_eq!(result.attempts, 2);
+
+    // The request was too long, not the deployment unhealthy
+    let small = router.get_deployment("test-small").unwrap();
+    assert_eq!(small.state.fails_this_minute.load(Ordering::Relaxed), 0);
+}
+
 #[tokio::test]
 async fn test_execute_respects_max_fallbacks() {
     let config = RouterConfig {
@@ -337,3 +432,456 @@ async fn test_execute_records_metrics() {
         assert!(d.state.avg_latency_us.load(Ordering::Relaxed) > 0);
     }
 }
+
+/// Router hedging requests to a second deployment after 20ms
+async fn hedging_router() -> Router {
+    let router = Router::new(RouterConfig {
+        hedge_after_ms: 20,
+        ..Default::default()
+    });
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-2", "gpt-4").await);
+    router
+}
+
+#[tokio::test]
+async fn test_execute_hedged_returns_first_to_complete() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration;
+
+    let router = hedging_router().await;
+    let calls = Arc::new(AtomicU32::new(0));
+    let completed = Arc::new(AtomicU32::new(0));
+
+    let (calls_clone, completed_clone) = (calls.clone(), completed.clone());
+    let result: ExecutionResult<String> = router
+        .execute("gpt-4", move |deployment_id| {
+            let call = calls_clone.fetch_add(1, Ordering::SeqCst);
+            let completed = completed_clone.clone();
+            async move {
+                // The first request stalls, the hedge answers at once
+                if call == 0 {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                }
+                completed.fetch_add(1, Ordering::SeqCst);
+                Ok((deployment_id, 100u64))
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(result.attempts, 1);
+    assert_eq!(result.result, result.deployment_id);
+
+    // The stalled request was canceled without counting as a failure
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(completed.load(Ordering::SeqCst), 1);
+    for id in ["test-1", "test-2"] {
+        let deployment = router.get_deployment(id).unwrap();
+        assert_eq!(deployment.state.active_requests.load(Ordering::Relaxed), 0);
+        assert_eq!(deployment.state.fail_requests.load(Ordering::Relaxed), 0);
+    }
+}
+
+#[tokio::test]
+async fn test_execute_hedged_only_when_slow() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+
+    let router = hedging_router().await;
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let calls_clone = calls.clone();
+    let result: ExecutionResult<String> = router
+        .execute("gpt-4", move |deployment_id| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            async move { Ok((deployment_id, 100u64)) }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.attempts, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_execute_not_hedged_by_default() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration;
+
+    let router = Router::default();
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-2", "gpt-4").await);
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let calls_clone = calls.clone();
+    let result: ExecutionResult<String> = router
+        .execute("gpt-4", move |deployment_id| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok((deployment_id, 100u64))
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.attempts, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_execute_hedged_awaits_other_after_failure() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration;
+
+    let router = hedging_router().await;
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let calls_clone = calls.clone();
+    let result: ExecutionResult<String> = router
+        .execute("gpt-4", move |deployment_id| {
+            let call = calls_clone.fetch_add(1, Ordering::SeqCst);
+            async move {
+                // The first request fails after the hedge was sent, the
+                // hedge answers later
+                if call == 0 {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    Err(ProviderError::timeout("test", "Request timed out"))
+                } else {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    Ok((deployment_id, 100u64))
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.attempts, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    let failed = if result.deployment_id == "test-1" {
+        "test-2"
+    } else {
+        "test-1"
+    };
+    let deployment = router.get_deployment(failed).unwrap();
+    assert_eq!(deployment.state.fail_requests.load(Ordering::Relaxed), 1);
+    assert_eq!(deployment.state.active_requests.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn test_execute_parks_rate_limited_deployment() {
+    let router = Router::new(RouterConfig {
+        allowed_fails: 3,
+        cooldown_time_secs: 60,
+        ..Default::default()
+    });
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-2", "gpt-4").await);
+
+    for _ in 0..5 {
+        let result: ExecutionResult<String> = router
+            .execute("gpt-4", |deployment_id| async move {
+                if deployment_id == "test-1" {
+                    Err(ProviderError::rate_limit("test", Some(60)))
+                } else {
+                    Ok((deployment_id, 100u64))
+                }
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.result, "test-2");
+    }
+
+    // A single 429 parks the deployment, so it is tried at most once
+    let deployment = router.get_deployment("test-1").unwrap();
+    let fails = deployment.state.fail_requests.load(Ordering::Relaxed);
+    assert!(fails <= 1);
+    assert_eq!(deployment.is_in_cooldown(), fails == 1);
+}
+
+#[tokio::test]
+async fn test_execute_fails_fast_on_unsupported_capability() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+
+    let router = Router::default();
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+    deployment.model = "gpt-4".to_string();
+    router.add_deployment(deployment);
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let requirements = RouteRequirements {
+        images: true,
+        ..Default::default()
+    };
+    let calls_clone = calls.clone();
+    let err = router
+        .execute_for("gpt-4", &requirements, move |deployment_id| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            async move { Ok((deployment_id, 100u64)) }
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        &err,
+        RouterError::UnsupportedCapability { model, capability }
+            if model == "gpt-4" && capability == "images"
+    ));
+    assert_eq!(router_error_to_provider_error(err).http_status(), 405);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+/// Stream chunk carrying content, or only a role if empty
+fn stream_chunk(content: &str) -> crate::core::types::responses::ChatChunk {
+    let delta = if content.is_empty() {
+        serde_json::json!({"role": "assistant"})
+    } else {
+        serde_json::json!({"content": content})
+    };
+    serde_json::from_value(serde_json::json!({
+        "id": "chunk",
+        "object": "chat.completion.chunk",
+        "created": 0,
+        "model": "test",
+        "choices": [{"index": 0, "delta": delta}],
+    }))
+    .unwrap()
+}
+
+/// Stream of chunks, then an error if given
+fn chunk_stream(
+    contents: &[&str],
+    error: Option<ProviderError>,
+) -> crate::core::router::streaming::ChunkStream {
+    let items: Vec<_> = contents
+        .iter()
+        .map(|content| Ok(stream_chunk(content)))
+        .chain(error.map(Err))
+        .collect();
+    Box::pin(futures::stream::iter(items))
+}
+
+/// Contents of a stream's chunks, and its error if any
+async fn collect_stream(
+    mut stream: crate::core::router::streaming::ChunkStream,
+) -> (String, Option<ProviderError>) {
+    use futures::StreamExt;
+
+    let mut text = String::new();
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(chunk) => {
+                for choice in chunk.choices {
+                    text.push_str(choice.delta.content.as_deref().unwrap_or_default());
+                }
+            }
+            Err(err) => return (text, Some(err)),
+        }
+    }
+    (text, None)
+}
+
+#[tokio::test]
+async fn test_execute_stream_fails_over_before_content() {
+    use std::sync::Arc;
+
+    let router = Arc::new(Router::default());
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-2", "gpt-4").await);
+
+    let failed = Arc::new(std::sync::Mutex::new(None));
+    let failed_clone = failed.clone();
+    let stream = router.clone().execute_stream(
+        "gpt-4",
+        RouteRequirements::default(),
+        move |deployment_id| {
+            let failed = failed_clone.clone();
+            async move {
+                let mut failed = failed.lock().unwrap();
+                // The first deployment dies after its role chunk
+                if failed.is_none() {
+                    *failed = Some(deployment_id);
+                    return Ok(chunk_stream(
+                        &[""],
+                        Some(ProviderError::network("test", "Connection reset")),
+                    ));
+                }
+                Ok(chunk_stream(&["", "Hello", " world"], None))
+            }
+        },
+    );
+
+    let (text, error) = collect_stream(stream).await;
+    assert_eq!(text, "Hello world");
+    assert!(error.is_none());
+
+    let failed = failed.lock().unwrap().clone().unwrap();
+    for id in ["test-1", "test-2"] {
+        let deployment = router.get_deployment(id).unwrap();
+        assert_eq!(deployment.state.active_requests.load(Ordering::Relaxed), 0);
+        let fails = u64::from(id == failed);
+        assert_eq!(
+            deployment.state.fail_requests.load(Ordering::Relaxed),
+            fails
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_execute_stream_surfaces_error_after_content() {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+
+    let router = Arc::new(Router::default());
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-2", "gpt-4").await);
+
+    let calls = Arc::new(AtomicU32::new(0));
+    let calls_clone = calls.clone();
+    let stream = router
+        .clone()
+        .execute_stream("gpt-4", RouteRequirements::default(), move |_| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(chunk_stream(
+                    &["Hello"],
+                    Some(ProviderError::network("test", "Connection reset")),
+                ))
+            }
+        });
+
+    let (text, error) = collect_stream(stream).await;
+    assert_eq!(text, "Hello");
+    assert!(matches!(error, Some(ProviderError::Network { .. })));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_execute_stream_holds_content_during_grace() {
+    use std::sync::Arc;
+
+    let router = Arc::new(Router::new(RouterConfig {
+        stream_failover_grace_ms: 60_000,
+        ..Default::default()
+    }));
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-2", "gpt-4").await);
+
+    let failed = Arc::new(std::sync::Mutex::new(false));
+    let failed_clone = failed.clone();
+    let stream = router
+        .clone()
+        .execute_stream("gpt-4", RouteRequirements::default(), move |_| {
+            let failed = failed_clone.clone();
+            async move {
+                // Content within the grace window is discarded on failure
+                if !std::mem::replace(&mut *failed.lock().unwrap(), true) {
+                    return Ok(chunk_stream(
+                        &["Hel"],
+                        Some(ProviderError::network("test", "Connection reset")),
+                    ));
+                }
+                Ok(chunk_stream(&["Hello"], None))
+            }
+        });
+
+    let (text, error) = collect_stream(stream).await;
+    assert_eq!(text, "Hello");
+    assert!(error.is_none());
+}
+
+#[tokio::test]
+async fn test_execute_stream_records_ttft() {
+    use futures::StreamExt;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let router = Arc::new(Router::default());
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+
+    let stream = router
+        .clone()
+        .execute_stream("gpt-4", RouteRequirements::default(), |_| async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            // The rest of the response takes far longer than its first token
+            let rest = futures::stream::once(async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(stream_chunk(" world"))
+            });
+            Ok(chunk_stream(&["", "Hello"], None).chain(rest).boxed())
+        });
+
+    let (text, error) = collect_stream(stream).await;
+    assert_eq!(text, "Hello world");
+    assert!(error.is_none());
+
+    let deployment = router.get_deployment("test-1").unwrap();
+    let ttft_us = deployment.state.avg_ttft_us.load(Ordering::Relaxed);
+    let latency_us = deployment.state.avg_latency_us.load(Ordering::Relaxed);
+    assert!(ttft_us >= 5_000);
+    assert!(ttft_us < latency_us);
+}
+
+#[tokio::test]
+async fn test_execute_stream_falls_back_to_other_model() {
+    use std::sync::Arc;
+
+    let router = Router::new(RouterConfig {
+        num_retries: 1,
+        retry_after_secs: 0,
+        ..Default::default()
+    })
+    .with_fallback_config(
+        FallbackConfig::new().add_general("gpt-4", vec!["gpt-3.5-turbo".to_string()]),
+    );
+    router.add_deployment(create_test_deployment("test-gpt4", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-gpt3.5", "gpt-3.5-turbo").await);
+    let router = Arc::new(router);
+
+    let stream = router.clone().execute_stream(
+        "gpt-4",
+        RouteRequirements::default(),
+        move |deployment_id| async move {
+            if deployment_id.contains("gpt4") {
+                Err(ProviderError::timeout("test", "gpt-4 timed out"))
+            } else {
+                Ok(chunk_stream(&["fallback"], None))
+            }
+        },
+    );
+
+    let (text, error) = collect_stream(stream).await;
+    assert_eq!(text, "fallback");
+    assert!(error.is_none());
+    let deployment = router.get_deployment("test-gpt3.5").unwrap();
+    assert_eq!(deployment.state.active_requests.load(Ordering::Relaxed), 0);
+    assert_eq!(deployment.state.success_requests.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn test_execute_stream_all_deployments_fail() {
+    use std::sync::Arc;
+
+    let router = Arc::new(Router::new(RouterConfig {
+        retry_after_secs: 0,
+        ..Default::default()
+    }));
+    router.add_deployment(create_test_deployment("test-1", "gpt-4").await);
+
+    let stream =
+        router
+            .clone()
+            .execute_stream("gpt-4", RouteRequirements::default(), move |_| async move {
+                Err(ProviderError::network("test", "Connection refused"))
+            });
+
+    let (text, error) = collect_stream(stream).await;
+    assert!(text.is_empty());
+    assert!(matches!(error, Some(ProviderError::Network { .. })));
+}
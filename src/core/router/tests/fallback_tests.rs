@@ -1,8 +1,10 @@
 //! Fallback configuration tests
 
+use super::router_tests::create_test_deployment;
 use crate::core::providers::unified_provider::ProviderError;
 use crate::core::router::fallback::{FallbackConfig, FallbackType};
 use crate::core::router::router::Router;
+use crate::core::types::chat::ChatRequest;
 
 #[test]
 fn test_fallback_config_builder() {
@@ -245,3 +247,215 @@ async fn test_fallback_config_multiple_models() {
     assert_eq!(fallbacks.len(), 1);
     assert_eq!(fallbacks[0], "claude-3-opus-200k");
 }
+
+#[test]
+fn test_fallback_config_from_gateway_config() {
+    let gateway_config: crate::config::models::router::RouterConfig = serde_yaml::from_str(
+        "fallbacks:\n  - gpt-4o: [claude-3-5-sonnet, gemini-1.5-pro]\n  - claude-3-5-sonnet: [gpt-4o]\ncontext_window_fallbacks:\n  - gpt-4: [gpt-4-turbo]\n",
+    )
+    .unwrap();
+    let config = FallbackConfig::from(&gateway_config);
+
+    assert_eq!(
+        config.get_fallbacks_for_type("gpt-4o", FallbackType::General),
+        vec![
+            "claude-3-5-sonnet".to_string(),
+            "gemini-1.5-pro".to_string()
+        ]
+    );
+    assert_eq!(
+        config.get_fallbacks_for_type("claude-3-5-sonnet", FallbackType::General),
+        vec!["gpt-4o".to_string()]
+    );
+    assert_eq!(
+        config.get_fallbacks_for_type("gpt-4", FallbackType::ContextWindow),
+        vec!["gpt-4-turbo".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_map_chat_request_to_fallback_deployment() {
+    let router = Router::default();
+    router.add_deployment(create_test_deployment("test-gpt4", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-fallback", "fallback").await);
+
+    let request = ChatRequest {
+        max_completion_tokens: Some(256),
+        seed: Some(7),
+        tools: Some(vec![]),
+        ..ChatRequest::new("gpt-4").with_temperature(0.2)
+    };
+
+    // Deployments of the requested model get the request as it is
+    let mapped = router.map_chat_request("test-gpt4", &request).unwrap();
+    assert_eq!(mapped.model, "gpt-4-turbo");
+    assert_eq!(mapped.max_completion_tokens, Some(256));
+    assert_eq!(mapped.seed, Some(7));
+
+    // Fallback deployments get the parameters their provider takes
+    let mapped = router.map_chat_request("test-fallback", &request).unwrap();
+    assert_eq!(mapped.model, "fallback-turbo");
+    assert_eq!(mapped.temperature, Some(0.2));
+    assert_eq!(mapped.max_tokens, Some(256));
+    assert!(mapped.max_completion_tokens.is_none());
+    assert!(mapped.seed.is_none());
+    assert!(mapped.tools.is_none());
+
+    assert!(router.map_chat_request("missing", &request).is_none());
+}
+
+#[tokio::test]
+async fn test_map_chat_request_applies_deployment_params() {
+    use crate::core::router::deployment::DeploymentConfig;
+    use crate::core::types::message::MessageRole;
+    use serde_json::json;
+
+    let router = Router::default();
+    let deployment = create_test_deployment("test-gpt4", "gpt-4")
+        .await
+        .with_config(DeploymentConfig {
+            default_params: [
+                ("temperature".to_string(), json!(0)),
+                ("seed".to_string(), json!(7)),
+                ("system_prompt".to_string(), json!("Be brief.")),
+            ]
+            .into(),
+            forced_params: [
+                ("max_tokens".to_string(), json!(1024)),
+                ("user".to_string(), json!("gateway")),
+            ]
+            .into(),
+            ..Default::default()
+        });
+    router.add_deployment(deployment);
+
+    // Defaults fill in what the request does not set, forced values cap or
+    // replace what it does
+    let request = ChatRequest {
+        seed: Some(42),
+        max_tokens: Some(4096),
+        ..ChatRequest::new("gpt-4").add_user_message("Hello")
+    };
+    let mapped = router.map_chat_request("test-gpt4", &request).unwrap();
+    assert_eq!(mapped.temperature, Some(0.0));
+    assert_eq!(mapped.seed, Some(42));
+    assert_eq!(mapped.max_tokens, Some(1024));
+    assert_eq!(mapped.user.as_deref(), Some("gateway"));
+    assert_eq!(mapped.messages.len(), 2);
+    assert_eq!(mapped.messages[0].role, MessageRole::System);
+    assert!(!mapped.extra_params.contains_key("system_prompt"));
+
+    // Requests with their own system message and lower max tokens keep them
+    let request = ChatRequest::new("gpt-4")
+        .add_system_message("Be thorough.")
+        .with_max_tokens(100);
+    let mapped = router.map_chat_request("test-gpt4", &request).unwrap();
+    assert_eq!(mapped.max_tokens, Some(100));
+    assert_eq!(mapped.messages.len(), 1);
+}
+
+#[tokio::test]
+async fn test_map_chat_request_drop_params() {
+    use crate::core::router::config::RouterConfig;
+    use crate::core::router::deployment::DeploymentConfig;
+    use serde_json::json;
+
+    let router = Router::new(RouterConfig {
+        drop_params: true,
+        ..Default::default()
+    });
+    router.add_deployment(create_test_deployment("test-1", "custom").await);
+    let request = ChatRequest {
+        seed: Some(7),
+        ..ChatRequest::new("custom").with_temperature(0.2)
+    };
+
+    // The router drops the parameters the provider does not take
+    let mapped = router.map_chat_request("test-1", &request).unwrap();
+    assert!(mapped.seed.is_none());
+    assert_eq!(mapped.temperature, Some(0.2));
+
+    // Requests may keep them, without sending `drop_params` on
+    let mut keep = request.clone();
+    keep.extra_params
+        .insert("drop_params".to_string(), json!(false));
+    let mapped = router.map_chat_request("test-1", &keep).unwrap();
+    assert_eq!(mapped.seed, Some(7));
+    assert!(mapped.extra_params.is_empty());
+
+    // Deployments override the router
+    let router = Router::default();
+    let deployment = create_test_deployment("test-1", "custom")
+        .await
+        .with_config(DeploymentConfig {
+            drop_params: Some(true),
+            ..Default::default()
+        });
+    router.add_deployment(deployment);
+    let mapped = router.map_chat_request("test-1", &request).unwrap();
+    assert!(mapped.seed.is_none());
+}
+
+#[tokio::test]
+async fn test_map_chat_request_additional_drop_params() {
+    use crate::core::router::deployment::DeploymentConfig;
+    use serde_json::json;
+
+    let router = Router::default();
+    let deployment = create_test_deployment("test-gpt4", "gpt-4")
+        .await
+        .with_config(DeploymentConfig {
+            additional_drop_params: vec!["temperature".to_string()],
+            ..Default::default()
+        });
+    router.add_deployment(deployment);
+
+    let mut request = ChatRequest {
+        seed: Some(7),
+        ..ChatRequest::new("gpt-4").with_temperature(0.2)
+    };
+    request
+        .extra_params
+        .insert("custom_param".to_string(), json!("value"));
+    request.extra_params.insert(
+        "additional_drop_params".to_string(),
+        json!(["custom_param", "messages"]),
+    );
+    request
+        .extra_params
+        .insert("tags".to_string(), json!(["eu-only"]));
+
+    let mapped = router.map_chat_request("test-gpt4", &request).unwrap();
+    assert!(mapped.temperature.is_none());
+    assert_eq!(mapped.seed, Some(7));
+    assert!(mapped.extra_params.is_empty());
+    assert_eq!(mapped.model, "gpt-4-turbo");
+}
+
+#[test]
+fn test_router_config_from_gateway_config() {
+    let gateway_config: crate::config::models::router::RouterConfig = serde_yaml::from_str(
+        "model_group_strategies:\n  gpt-4o: lowest-latency\n\
+         traffic_splits:\n  gpt-4o:\n    gpt-4o: 95\n    ft:gpt-4o:acme: 5\n\
+         hedge_after_ms: 800\n\
+         drop_params: true\n\
+         stream_failover_grace_ms: 300\n",
+    )
+    .unwrap();
+    let config = crate::core::router::config::RouterConfig::from(&gateway_config);
+
+    assert_eq!(
+        config.strategy_for("gpt-4o"),
+        crate::core::router::config::RoutingStrategy::LatencyBased
+    );
+    assert_eq!(
+        config.traffic_splits["gpt-4o"],
+        vec![
+            ("ft:gpt-4o:acme".to_string(), 5),
+            ("gpt-4o".to_string(), 95)
+        ]
+    );
+    assert_eq!(config.hedge_after_ms, 800);
+    assert!(config.drop_params);
+    assert_eq!(config.stream_failover_grace_ms, 300);
+}
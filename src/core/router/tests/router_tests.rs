@@ -167,6 +167,89 @@ async fn test_model_aliases() {
     assert_eq!(deployments2, deployments3);
 }
 
+/// Router splitting gpt-4o traffic 90/10 with a fine-tuned model
+async fn split_router() -> Router {
+    let router = Router::new(RouterConfig {
+        traffic_splits: [(
+            "gpt-4o".to_string(),
+            vec![("gpt-4o".to_string(), 90), ("ft:gpt-4o".to_string(), 10)],
+        )]
+        .into(),
+        ..Default::default()
+    });
+    router.add_deployment(create_test_deployment("test-gpt4o", "gpt-4o").await);
+    router.add_deployment(create_test_deployment("test-ft", "ft:gpt-4o").await);
+    router
+}
+
+#[tokio::test]
+async fn test_traffic_split_by_percentage() {
+    let router = split_router().await;
+    let canary = (0..1000)
+        .filter(|i| router.split_model_name("gpt-4o", Some(&format!("user-{}", i))) == "ft:gpt-4o")
+        .count();
+    assert!((50..150).contains(&canary), "{} canary requests", canary);
+
+    assert_eq!(router.split_model_name("gpt-4", Some("user-1")), "gpt-4");
+}
+
+#[tokio::test]
+async fn test_traffic_split_sticky_by_user() {
+    let router = split_router().await;
+    let other_instance = split_router().await;
+    for i in 0..100 {
+        let user = format!("user-{}", i);
+        let model = router.split_model_name("gpt-4o", Some(&user));
+        for _ in 0..5 {
+            assert_eq!(router.split_model_name("gpt-4o", Some(&user)), model);
+        }
+        // Another instance assigns the user the same model
+        assert_eq!(
+            other_instance.split_model_name("gpt-4o", Some(&user)),
+            model
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_traffic_split_skips_model_without_deployments() {
+    let router = split_router().await;
+    router.remove_deployment("test-ft");
+    for i in 0..100 {
+        let user = format!("user-{}", i);
+        assert_eq!(router.split_model_name("gpt-4o", Some(&user)), "gpt-4o");
+    }
+}
+
+#[tokio::test]
+async fn test_execute_follows_traffic_split() {
+    use crate::core::router::selection::RouteRequirements;
+
+    let router = Router::new(RouterConfig {
+        traffic_splits: [(
+            "gpt-4".to_string(),
+            vec![("gpt-4".to_string(), 0), ("canary".to_string(), 100)],
+        )]
+        .into(),
+        ..Default::default()
+    });
+    router.add_deployment(create_test_deployment("test-gpt4", "gpt-4").await);
+    router.add_deployment(create_test_deployment("test-canary", "canary").await);
+
+    let requirements = RouteRequirements {
+        user: Some("user-1".to_string()),
+        ..Default::default()
+    };
+    let result = router
+        .execute_for("gpt-4", &requirements, |deployment_id| async move {
+            Ok((deployment_id, 100u64))
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.result, "test-canary");
+    assert!(!result.used_fallback);
+}
+
 #[tokio::test]
 async fn test_get_healthy_deployments() {
     use crate::core::router::deployment::HealthStatus;
@@ -266,6 +349,58 @@ async fn test_minute_reset() {
     }
 }
 
+#[tokio::test]
+async fn test_from_gateway_config_deployments_per_api_key() {
+    use crate::config::ProviderConfig;
+
+    let providers = vec![ProviderConfig {
+        name: "openai".to_string(),
+        provider_type: "openai".to_string(),
+        api_key: "sk-test-key-1".to_string(),
+        api_keys: vec!["sk-test-key-2".to_string(), "sk-test-key-3".to_string()],
+        models: vec!["gpt-4".to_string()],
+        rpm: 100,
+        ..Default::default()
+    }];
+    let router = Router::from_gateway_config(&providers, None).await.unwrap();
+
+    let mut deployments = router.list_deployments();
+    deployments.sort();
+    assert_eq!(
+        deployments,
+        vec!["openai-gpt-4", "openai-gpt-4-key2", "openai-gpt-4-key3"]
+    );
+    assert_eq!(router.list_models(), vec!["gpt-4".to_string()]);
+    for id in &deployments {
+        let deployment = router.get_deployment(id).unwrap();
+        assert_eq!(deployment.config.rpm_limit, Some(100));
+    }
+}
+
+#[tokio::test]
+async fn test_from_gateway_config_model_name() {
+    use crate::config::ProviderConfig;
+
+    let provider = |name: &str, model: &str| ProviderConfig {
+        name: name.to_string(),
+        provider_type: "openai".to_string(),
+        api_key: "sk-test-key".to_string(),
+        models: vec![model.to_string()],
+        model_name: Some("gpt-4".to_string()),
+        ..Default::default()
+    };
+    let providers = vec![
+        provider("primary", "gpt-4-0613"),
+        provider("backup", "gpt-4"),
+    ];
+    let router = Router::from_gateway_config(&providers, None).await.unwrap();
+
+    assert_eq!(router.list_models(), vec!["gpt-4".to_string()]);
+    let deployment = router.get_deployment("primary-gpt-4-0613").unwrap();
+    assert_eq!(deployment.model, "gpt-4-0613");
+    assert_eq!(deployment.model_name, "gpt-4");
+}
+
 #[test]
 fn test_routing_strategy_default() {
     assert_eq!(RoutingStrategy::default(), RoutingStrategy::SimpleShuffle);
@@ -283,3 +418,32 @@ fn test_router_config_default() {
     assert_eq!(config.max_fallbacks, 5);
     assert!(config.enable_pre_call_checks);
 }
+
+#[test]
+fn test_routing_strategy_names() {
+    let strategy: RoutingStrategy = serde_json::from_str("\"lowest-latency\"").unwrap();
+    assert_eq!(strategy, RoutingStrategy::LatencyBased);
+    let strategy: RoutingStrategy = serde_json::from_str("\"latency-based-routing\"").unwrap();
+    assert_eq!(strategy, RoutingStrategy::LatencyBased);
+    let strategy: RoutingStrategy = serde_json::from_str("\"lowest-cost\"").unwrap();
+    assert_eq!(strategy, RoutingStrategy::CostBased);
+    let strategy: RoutingStrategy = serde_json::from_str("\"usage-based-routing-v2\"").unwrap();
+    assert_eq!(strategy, RoutingStrategy::UsageBasedV2);
+    assert_eq!(
+        serde_json::to_string(&RoutingStrategy::SimpleShuffle).unwrap(),
+        "\"simple-shuffle\""
+    );
+}
+
+#[test]
+fn test_router_config_strategy_for_model_group() {
+    let config = RouterConfig {
+        model_group_strategies: [("gpt-4".to_string(), RoutingStrategy::LatencyBased)].into(),
+        ..Default::default()
+    };
+    assert_eq!(config.strategy_for("gpt-4"), RoutingStrategy::LatencyBased);
+    assert_eq!(
+        config.strategy_for("gpt-3.5"),
+        RoutingStrategy::SimpleShuffle
+    );
+}
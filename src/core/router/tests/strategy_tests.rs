@@ -1,10 +1,15 @@
 //! Strategy selection tests
 
 use super::router_tests::create_test_deployment;
+use crate::core::rate_limiter::CounterStore;
 use crate::core::router::config::{RouterConfig, RoutingStrategy};
 use crate::core::router::deployment::HealthStatus;
 use crate::core::router::error::RouterError;
+use crate::core::router::execution::router_error_to_provider_error;
 use crate::core::router::router::Router;
+use crate::core::router::selection::RouteRequirements;
+use crate::storage::cache::MemoryCache;
+use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
 #[tokio::test]
@@ -108,6 +113,58 @@ async fn test_select_deployment_usage_based() {
     router.release_deployment(&result);
 }
 
+#[tokio::test]
+async fn test_select_deployment_usage_based_v2_skips_deployments_without_headroom() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::UsageBasedV2,
+        expected_input_tokens: 1000,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+
+    let mut d1 = create_test_deployment("test-1", "gpt-4").await;
+    let mut d2 = create_test_deployment("test-2", "gpt-4").await;
+
+    // test-1 has the lower usage rate but no room for another request
+    d1.config.tpm_limit = Some(2000);
+    d1.state.tpm_current.store(1500, Ordering::Relaxed);
+    d2.config.tpm_limit = Some(10_000);
+    d2.state.tpm_current.store(9000, Ordering::Relaxed);
+
+    router.add_deployment(d1);
+    router.add_deployment(d2);
+
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-2");
+    router.release_deployment(&result);
+
+    router.record_success("test-2", 500, 1000);
+    let result = router.select_deployment("gpt-4");
+    assert!(matches!(result, Err(RouterError::RateLimitExceeded(_))));
+}
+
+#[tokio::test]
+async fn test_acquire_deployment_counts_shared_usage() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::UsageBasedV2,
+        ..Default::default()
+    };
+    let mut router = Router::new(config);
+    router.usage = Some(CounterStore::new(Arc::new(MemoryCache::new())));
+
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+    deployment.config.rpm_limit = Some(1);
+    router.add_deployment(deployment);
+
+    let result = router.acquire_deployment("gpt-4").await.unwrap();
+    router.release_deployment(&result);
+
+    // Local counters only learn of the request from the shared usage
+    router.reset_minute_counters();
+    let result = router.acquire_deployment("gpt-4").await;
+    assert!(matches!(result, Err(RouterError::RateLimitExceeded(_))));
+}
+
 #[tokio::test]
 async fn test_select_deployment_latency_based() {
     let config = RouterConfig {
@@ -137,6 +194,89 @@ async fn test_select_deployment_latency_based() {
     router.release_deployment(&result);
 }
 
+#[tokio::test]
+async fn test_select_deployment_latency_based_prefers_ttft() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::LatencyBased,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+
+    let d1 = create_test_deployment("test-1", "gpt-4").await;
+    let d2 = create_test_deployment("test-2", "gpt-4").await;
+
+    d1.state.avg_latency_us.store(50_000, Ordering::Relaxed);
+    d2.state.avg_latency_us.store(100_000, Ordering::Relaxed);
+    d1.state.avg_ttft_us.store(20_000, Ordering::Relaxed);
+
+    router.add_deployment(d1);
+    router.add_deployment(d2);
+
+    // Until every deployment has a TTFT, total latency decides
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-1");
+    router.release_deployment(&result);
+
+    router.record_ttft("test-2", 5_000);
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-2");
+    router.release_deployment(&result);
+}
+
+#[tokio::test]
+async fn test_select_deployment_latency_based_prefers_healthy() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::LatencyBased,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+
+    let d1 = create_test_deployment("test-1", "gpt-4").await;
+    let d2 = create_test_deployment("test-2", "gpt-4").await;
+
+    d1.state
+        .health
+        .store(HealthStatus::Degraded as u8, Ordering::Relaxed);
+    d2.state
+        .health
+        .store(HealthStatus::Healthy as u8, Ordering::Relaxed);
+
+    d1.state.avg_latency_us.store(10_000, Ordering::Relaxed);
+    d2.state.avg_latency_us.store(50_000, Ordering::Relaxed);
+
+    router.add_deployment(d1);
+    router.add_deployment(d2);
+
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-2");
+    router.release_deployment(&result);
+}
+
+#[tokio::test]
+async fn test_select_deployment_model_group_strategy() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::CostBased,
+        model_group_strategies: [("gpt-4".to_string(), RoutingStrategy::LatencyBased)].into(),
+        ..Default::default()
+    };
+    let router = Router::new(config);
+
+    let mut d1 = create_test_deployment("test-1", "gpt-4").await;
+    let d2 = create_test_deployment("test-2", "gpt-4").await;
+
+    // Cost-based routing would pick the lower priority test-2
+    d1.config.priority = 1;
+    d1.state.avg_latency_us.store(10_000, Ordering::Relaxed);
+    d2.state.avg_latency_us.store(50_000, Ordering::Relaxed);
+
+    router.add_deployment(d1);
+    router.add_deployment(d2);
+
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-1");
+    router.release_deployment(&result);
+}
+
 #[tokio::test]
 async fn test_select_deployment_round_robin() {
     let config = RouterConfig {
@@ -208,6 +348,64 @@ async fn test_select_deployment_cost_based() {
     router.release_deployment(&result);
 }
 
+#[tokio::test]
+async fn test_select_deployment_cost_based_uses_pricing() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::CostBased,
+        ..Default::default()
+    };
+    let router = Router::new(config);
+
+    let mut d1 = create_test_deployment("test-1", "gpt-4").await;
+    let mut d2 = create_test_deployment("test-2", "gpt-4").await;
+    let mut d3 = create_test_deployment("test-3", "gpt-4").await;
+
+    d1.model = "gpt-4".to_string();
+    d2.model = "openai/gpt-3.5-turbo".to_string();
+    d2.config.priority = 5;
+    // Unpriced models are not assumed to be free
+    d3.model = "unpriced-model".to_string();
+
+    router.add_deployment(d1);
+    router.add_deployment(d2);
+    router.add_deployment(d3);
+
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-2");
+    router.release_deployment(&result);
+}
+
+#[tokio::test]
+async fn test_select_deployment_quality_tier_floor() {
+    let config = RouterConfig {
+        routing_strategy: RoutingStrategy::CostBased,
+        quality_tier_floors: [("gpt-4".to_string(), 2)].into(),
+        ..Default::default()
+    };
+    let router = Router::new(config);
+
+    let mut d1 = create_test_deployment("test-1", "gpt-4").await;
+    let mut d2 = create_test_deployment("test-2", "gpt-4").await;
+    let d3 = create_test_deployment("test-3", "gpt-4").await;
+
+    d1.model = "gpt-3.5-turbo".to_string();
+    d1.config.quality_tier = Some(1);
+    d2.model = "gpt-4".to_string();
+    d2.config.quality_tier = Some(3);
+
+    router.add_deployment(d1);
+    router.add_deployment(d2);
+    router.add_deployment(d3);
+
+    let result = router.select_deployment("gpt-4").unwrap();
+    assert_eq!(result, "test-2");
+    router.release_deployment(&result);
+
+    router.remove_deployment("test-2");
+    let result = router.select_deployment("gpt-4");
+    assert!(matches!(result, Err(RouterError::NoAvailableDeployment(_))));
+}
+
 #[tokio::test]
 async fn test_select_deployment_rate_limit_aware() {
     let config = RouterConfig {
@@ -310,7 +508,61 @@ async fn test_select_deployment_respects_rate_limit() {
     router.add_deployment(deployment);
 
     let result = router.select_deployment("gpt-4");
-    assert!(result.is_err());
+    assert!(matches!(result, Err(RouterError::RateLimitExceeded(model)) if model == "gpt-4"));
+
+    router.reset_minute_counters();
+    assert!(router.select_deployment("gpt-4").is_ok());
+}
+
+#[tokio::test]
+async fn test_select_deployment_all_busy() {
+    let router = Router::default();
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+
+    deployment
+        .state
+        .health
+        .store(HealthStatus::Healthy as u8, Ordering::Relaxed);
+    deployment.config.max_parallel_requests = Some(1);
+
+    router.add_deployment(deployment);
+
+    assert!(router.select_deployment("gpt-4").is_ok());
+    let result = router.select_deployment("gpt-4");
+    assert!(matches!(result, Err(RouterError::AllDeploymentsBusy(model)) if model == "gpt-4"));
+
+    // Without a queue timeout, busy deployments fail at once
+    let result = router.acquire_deployment("gpt-4").await;
+    assert!(matches!(result, Err(RouterError::AllDeploymentsBusy(_))));
+}
+
+#[tokio::test]
+async fn test_acquire_deployment_waits_for_release() {
+    let config = RouterConfig {
+        queue_timeout_ms: 5000,
+        ..Default::default()
+    };
+    let router = std::sync::Arc::new(Router::new(config));
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+
+    deployment
+        .state
+        .health
+        .store(HealthStatus::Healthy as u8, Ordering::Relaxed);
+    deployment.config.max_parallel_requests = Some(1);
+
+    router.add_deployment(deployment);
+
+    let first = router.select_deployment("gpt-4").unwrap();
+    let waiting = tokio::spawn({
+        let router = std::sync::Arc::clone(&router);
+        async move { router.acquire_deployment("gpt-4").await }
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    assert!(!waiting.is_finished());
+
+    router.release_deployment(&first);
+    assert_eq!(waiting.await.unwrap().unwrap(), first);
 }
 
 #[tokio::test]
@@ -352,3 +604,185 @@ async fn test_select_deployment_with_alias() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), "test-1");
 }
+
+#[tokio::test]
+async fn test_select_deployment_with_tags() {
+    let router = Router::default();
+    let us = create_test_deployment("test-us", "gpt-4").await;
+    let eu = create_test_deployment("test-eu", "gpt-4")
+        .await
+        .with_tags(vec!["eu-only".to_string(), "fast".to_string()]);
+    for deployment in [&us, &eu] {
+        deployment
+            .state
+            .health
+            .store(HealthStatus::Healthy as u8, Ordering::Relaxed);
+    }
+    router.add_deployment(us);
+    router.add_deployment(eu);
+
+    let eu_only = RouteRequirements {
+        tags: vec!["eu-only".to_string()],
+        ..Default::default()
+    };
+    for _ in 0..10 {
+        let selected = router.select_deployment_for("gpt-4", &eu_only).unwrap();
+        assert_eq!(selected, "test-eu");
+    }
+
+    let apac_only = RouteRequirements {
+        tags: vec!["apac-only".to_string()],
+        ..Default::default()
+    };
+    let result = router.select_deployment_for("gpt-4", &apac_only);
+    assert!(matches!(result, Err(RouterError::NoAvailableDeployment(_))));
+}
+
+#[test]
+fn test_deployment_weight_keeps_fractional_ratios() {
+    use crate::core::router::gateway_config::deployment_weight;
+
+    assert_eq!(deployment_weight(1.0), 100);
+    assert_eq!(deployment_weight(0.3), 30);
+    assert_eq!(deployment_weight(0.7), 70);
+    assert_eq!(deployment_weight(-1.0), 0);
+}
+
+#[tokio::test]
+async fn test_select_deployment_context_window() {
+    let router = Router::default();
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+    deployment.model = "gpt-4".to_string();
+    router.add_deployment(deployment);
+
+    let fits = RouteRequirements {
+        input_tokens: Some(1000),
+        ..Default::default()
+    };
+    assert_eq!(
+        router.select_deployment_for("gpt-4", &fits).unwrap(),
+        "test-1"
+    );
+    router.release_deployment("test-1");
+
+    let too_long = RouteRequirements {
+        input_tokens: Some(20_000),
+        ..Default::default()
+    };
+    let result = router.select_deployment_for("gpt-4", &too_long);
+    assert!(matches!(
+        result,
+        Err(RouterError::ContextWindowExceeded {
+            max: 8192,
+            tokens: 20_000,
+            ..
+        })
+    ));
+
+    let router = Router::new(RouterConfig {
+        enable_pre_call_checks: false,
+        ..Default::default()
+    });
+    let mut deployment = create_test_deployment("test-1", "gpt-4").await;
+    deployment.model = "gpt-4".to_string();
+    router.add_deployment(deployment);
+    assert!(router.select_deployment_for("gpt-4", &too_long).is_ok());
+}
+
+#[tokio::test]
+async fn test_select_deployment_capabilities() {
+    let router = Router::default();
+    let mut text_only = create_test_deployment("test-text", "gpt-4").await;
+    text_only.model = "gpt-4".to_string();
+    let mut vision = create_test_deployment("test-vision", "gpt-4").await;
+    vision.model = "gpt-4o".to_string();
+    router.add_deployment(text_only);
+
+    let with_images = RouteRequirements {
+        tools: true,
+        images: true,
+        ..Default::default()
+    };
+    let result = router.select_deployment_for("gpt-4", &with_images);
+    assert!(matches!(
+        &result,
+        Err(RouterError::UnsupportedCapability { capability, .. }) if capability == "images"
+    ));
+    assert_eq!(
+        router_error_to_provider_error(result.unwrap_err()).http_status(),
+        405
+    );
+
+    router.add_deployment(vision);
+    for _ in 0..10 {
+        let selected = router.select_deployment_for("gpt-4", &with_images).unwrap();
+        assert_eq!(selected, "test-vision");
+        router.release_deployment(&selected);
+    }
+
+    let router = Router::new(RouterConfig {
+        enable_pre_call_checks: false,
+        ..Default::default()
+    });
+    let mut text_only = create_test_deployment("test-text", "gpt-4").await;
+    text_only.model = "gpt-4".to_string();
+    router.add_deployment(text_only);
+    assert!(router.select_deployment_for("gpt-4", &with_images).is_ok());
+}
+
+#[tokio::test]
+async fn test_select_deployment_capable_deployment_out_of_service() {
+    let with_images = RouteRequirements {
+        images: true,
+        ..Default::default()
+    };
+    let too_long = RouteRequirements {
+        input_tokens: Some(20_000),
+        ..Default::default()
+    };
+
+    // A capable deployment cooling down makes this no client error
+    let router = Router::default();
+    let mut text_only = create_test_deployment("test-text", "gpt-4").await;
+    text_only.model = "gpt-4".to_string();
+    let mut vision = create_test_deployment("test-vision", "gpt-4").await;
+    vision.model = "gpt-4o".to_string();
+    vision.enter_cooldown(60);
+    router.add_deployment(text_only);
+    router.add_deployment(vision);
+
+    let result = router.select_deployment_for("gpt-4", &with_images);
+    assert!(matches!(
+        result,
+        Err(RouterError::AllDeploymentsInCooldown(_))
+    ));
+    assert_eq!(
+        router_error_to_provider_error(result.unwrap_err()).http_status(),
+        503
+    );
+    let result = router.select_deployment_for("gpt-4", &too_long);
+    assert!(matches!(
+        result,
+        Err(RouterError::AllDeploymentsInCooldown(_))
+    ));
+
+    // Likewise for an unhealthy one
+    let router = Router::default();
+    let mut text_only = create_test_deployment("test-text", "gpt-4").await;
+    text_only.model = "gpt-4".to_string();
+    let mut vision = create_test_deployment("test-vision", "gpt-4").await;
+    vision.model = "gpt-4o".to_string();
+    vision
+        .state
+        .health
+        .store(HealthStatus::Unhealthy as u8, Ordering::Relaxed);
+    router.add_deployment(text_only);
+    router.add_deployment(vision);
+
+    let result = router.select_deployment_for("gpt-4", &with_images);
+    assert!(matches!(result, Err(RouterError::NoAvailableDeployment(_))));
+    assert_eq!(
+        router_error_to_provider_error(result.unwrap_err()).http_status(),
+        503
+    );
+}
@@ -0,0 +1,65 @@
+//! Traffic splitting between models
+//!
+//! A model group can have its traffic split between models by percentage,
+//! e.g. to roll out a fine-tuned model to 5% of the requests. Requests with
+//! a user are assigned by a stable hash of the model group and the user, so
+//! a user keeps getting the same model across requests and gateway
+//! instances; requests without one are assigned at random. A request
+//! assigned a model without deployments stays on the model group.
+
+use super::router::Router;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+impl Router {
+    /// Model serving a request to a model group, after resolving aliases
+    /// and splitting the group's traffic
+    pub fn split_model_name(&self, model_name: &str, user: Option<&str>) -> String {
+        let resolved_name = self.resolve_model_name(model_name);
+        let Some(splits) = self.config.traffic_splits.get(&resolved_name) else {
+            return resolved_name;
+        };
+        let total: u64 = splits.iter().map(|(_, percent)| u64::from(*percent)).sum();
+        if total == 0 {
+            return resolved_name;
+        }
+
+        let mut bucket = match user {
+            Some(user) => stable_hash(&resolved_name, user) % total,
+            None => rand::thread_rng().gen_range(0..total),
+        };
+        for (model, percent) in splits {
+            let percent = u64::from(*percent);
+            if bucket < percent {
+                let target = self.resolve_model_name(model);
+                if self
+                    .model_index
+                    .get(&target)
+                    .is_some_and(|ids| !ids.is_empty())
+                {
+                    return target;
+                }
+                warn!(
+                    "Traffic split of model {} names model {} without deployments",
+                    resolved_name, target
+                );
+                return resolved_name;
+            }
+            bucket -= percent;
+        }
+        resolved_name
+    }
+}
+
+/// Hash of a user in a model group, the same on every gateway instance
+fn stable_hash(model_name: &str, user: &str) -> u64 {
+    let digest = Sha256::new()
+        .chain_update(model_name)
+        .chain_update([0])
+        .chain_update(user)
+        .finalize();
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes)
+}
@@ -0,0 +1,112 @@
+//! Deployment usage shared between gateway instances
+//!
+//! With usage-based routing v2, the requests and tokens of deployments with
+//! TPM/RPM limits are counted per minute in a shared store (Redis when
+//! configured), so every gateway instance routes on the usage of all of them.
+
+use super::config::RoutingStrategy;
+use super::deployment::Deployment;
+use super::router::Router;
+use std::sync::atomic::Ordering::Relaxed;
+
+/// Length of a usage window in seconds
+const WINDOW_SECS: u64 = 60;
+
+impl Router {
+    /// Load the shared usage of a model group's deployments into their state
+    ///
+    /// Does nothing unless the model group uses usage-based routing v2 and
+    /// usage is shared.
+    pub(crate) async fn sync_usage(&self, model_name: &str) {
+        let Some(usage) = &self.usage else {
+            return;
+        };
+        let resolved_name = self.resolve_model_name(model_name);
+        if self.config.strategy_for(&resolved_name) != RoutingStrategy::UsageBasedV2 {
+            return;
+        }
+
+        let deployment_ids = self
+            .model_index
+            .get(&resolved_name)
+            .map(|ids| ids.clone())
+            .unwrap_or_default();
+        let window = current_window();
+        for id in deployment_ids {
+            // Deployment entries are not held across awaits
+            let Some((rpm_limited, tpm_limited)) = self
+                .deployments
+                .get(&id)
+                .map(|d| (d.config.rpm_limit.is_some(), d.config.tpm_limit.is_some()))
+            else {
+                continue;
+            };
+            if rpm_limited {
+                let rpm = usage.add(&counter(&id, "rpm"), window, 0).await;
+                if let Some(deployment) = self.deployments.get(&id) {
+                    deployment
+                        .state
+                        .rpm_current
+                        .store(rpm.max(0) as u64, Relaxed);
+                }
+            }
+            if tpm_limited {
+                let tpm = usage.add(&counter(&id, "tpm"), window, 0).await;
+                if let Some(deployment) = self.deployments.get(&id) {
+                    deployment
+                        .state
+                        .tpm_current
+                        .store(tpm.max(0) as u64, Relaxed);
+                }
+            }
+        }
+    }
+
+    /// Count a request routed to a deployment in the shared usage
+    ///
+    /// Requests are counted when they are routed, so concurrent requests on
+    /// other instances see them before they complete.
+    pub(crate) async fn record_shared_request(&self, deployment_id: &str) {
+        let Some(usage) = &self.usage else {
+            return;
+        };
+        if self.shares_usage(deployment_id, |d| d.config.rpm_limit.is_some()) {
+            usage
+                .add(&counter(deployment_id, "rpm"), current_window(), 1)
+                .await;
+        }
+    }
+
+    /// Count the tokens a deployment used in the shared usage
+    pub(crate) async fn record_shared_tokens(&self, deployment_id: &str, tokens: u64) {
+        let Some(usage) = &self.usage else {
+            return;
+        };
+        if tokens > 0 && self.shares_usage(deployment_id, |d| d.config.tpm_limit.is_some()) {
+            usage
+                .add(
+                    &counter(deployment_id, "tpm"),
+                    current_window(),
+                    tokens as i64,
+                )
+                .await;
+        }
+    }
+
+    /// Whether a deployment's model group routes on shared usage and the
+    /// deployment has the limit
+    fn shares_usage(&self, deployment_id: &str, limited: impl Fn(&Deployment) -> bool) -> bool {
+        self.deployments.get(deployment_id).is_some_and(|d| {
+            limited(&d) && self.config.strategy_for(&d.model_name) == RoutingStrategy::UsageBasedV2
+        })
+    }
+}
+
+/// Counter of a deployment's usage
+fn counter(deployment_id: &str, kind: &str) -> String {
+    format!("deployment:{}:{}", deployment_id, kind)
+}
+
+fn current_window() -> u64 {
+    chrono::Utc::now().timestamp() as u64 / WINDOW_SECS
+}
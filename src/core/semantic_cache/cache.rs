@@ -3,7 +3,7 @@
 use super::types::{
     CacheData, CacheStats, EmbeddingProvider, SemanticCacheConfig, SemanticCacheEntry,
 };
-use super::utils::{extract_prompt_text, hash_prompt};
+use super::utils::{entry_from_metadata, extract_prompt_text, hash_prompt};
 use super::validation::{is_entry_valid, should_cache_request};
 use crate::core::models::openai::{ChatCompletionRequest, ChatCompletionResponse};
 use crate::storage::vector::VectorStore;
@@ -28,7 +28,7 @@ pub struct SemanticCache {
 
 impl SemanticCache {
     /// Create a new semantic cache
-    pub async fn new(
+    pub fn new(
         config: SemanticCacheConfig,
         vector_store: Arc<dyn VectorStore>,
         embedding_provider: Arc<dyn EmbeddingProvider>,
@@ -46,15 +46,41 @@ impl SemanticCache {
         })
     }
 
+    /// Namespace of a request's entries
+    ///
+    /// As for the response cache: the request's namespace, else the
+    /// configured one, and with `per_key` the owner, the caller the entries
+    /// are cached for. With `per_key`, requests without an owner have none.
+    fn namespace(&self, request: &ChatCompletionRequest, owner: Option<&str>) -> Option<String> {
+        let mut namespace = request
+            .cache
+            .as_ref()
+            .and_then(|options| options.namespace.clone())
+            .or_else(|| self.config.namespace.clone())
+            .unwrap_or_default();
+        if self.config.per_key {
+            namespace.push_str(":key:");
+            namespace.push_str(owner?);
+        }
+        Some(namespace)
+    }
+
     /// Try to get a cached response for the given request
+    ///
+    /// Only responses cached in the request's namespace for the same owner
+    /// are served.
     pub async fn get_cached_response(
         &self,
         request: &ChatCompletionRequest,
+        owner: Option<&str>,
     ) -> Result<Option<ChatCompletionResponse>> {
         // Check if caching is appropriate for this request
         if !should_cache_request(&self.config, request) {
             return Ok(None);
         }
+        let Some(namespace) = self.namespace(request, owner) else {
+            return Ok(None);
+        };
 
         // Generate prompt text for embedding
         let prompt_text = extract_prompt_text(&request.messages);
@@ -83,7 +109,16 @@ impl SemanticCache {
         // Find the best match
         for result in search_results {
             if result.score >= self.config.similarity_threshold as f32 {
-                if let Some(entry) = self.get_cache_entry(&result.id).await? {
+                // Entries cached by other instances are only in the vector store
+                let cached = match self.get_cache_entry(&result.id).await? {
+                    Some(entry) => Some(entry),
+                    None => entry_from_metadata(&result),
+                };
+                if let Some(entry) = cached {
+                    // Only serve responses of the requested model and namespace
+                    if entry.model != request.model || entry.namespace != namespace {
+                        continue;
+                    }
                     // Check if entry is still valid
                     if is_entry_valid(&entry) {
                         // Update access and hit statistics with single lock
@@ -126,16 +161,20 @@ impl SemanticCache {
         Ok(None)
     }
 
-    /// Cache a response for the given request
+    /// Cache a response for the given request, in its namespace for the owner
     pub async fn cache_response(
         &self,
         request: &ChatCompletionRequest,
         response: &ChatCompletionResponse,
+        owner: Option<&str>,
     ) -> Result<()> {
         // Check if caching is appropriate
         if !should_cache_request(&self.config, request) {
             return Ok(());
         }
+        let Some(namespace) = self.namespace(request, owner) else {
+            return Ok(());
+        };
 
         let prompt_text = extract_prompt_text(&request.messages);
 
@@ -156,6 +195,7 @@ impl SemanticCache {
             embedding: embedding.clone(),
             response: response.clone(),
             model: request.model.clone(),
+            namespace,
             created_at: chrono::Utc::now(),
             last_accessed: chrono::Utc::now(),
             access_count: 0,
@@ -177,6 +217,19 @@ impl SemanticCache {
                     "created_at".to_string(),
                     serde_json::to_value(entry.created_at)?,
                 );
+                metadata.insert("model".to_string(), serde_json::to_value(&entry.model)?);
+                metadata.insert(
+                    "namespace".to_string(),
+                    serde_json::to_value(&entry.namespace)?,
+                );
+                metadata.insert(
+                    "ttl_seconds".to_string(),
+                    serde_json::to_value(entry.ttl_seconds)?,
+                );
+                metadata.insert(
+                    "response".to_string(),
+                    serde_json::to_value(&entry.response)?,
+                );
                 metadata
             },
         };
@@ -264,6 +317,32 @@ impl SemanticCache {
         Ok(())
     }
 
+    /// Remove entries past their TTL, returning how many were removed
+    ///
+    /// Entries cached by other instances are left to those instances.
+    pub async fn purge_expired(&self) -> Result<u64> {
+        let expired: Vec<String> = {
+            let mut data = self.cache_data.write().await;
+            let expired: Vec<String> = data
+                .entries
+                .values()
+                .filter(|entry| !is_entry_valid(entry))
+                .map(|entry| entry.id.clone())
+                .collect();
+            for entry_id in &expired {
+                data.entries.remove(entry_id);
+            }
+            expired
+        };
+
+        let purged = expired.len() as u64;
+        if !expired.is_empty() {
+            self.vector_store.delete(expired).await?;
+            debug!("Purged {} expired semantic cache entries", purged);
+        }
+        Ok(purged)
+    }
+
     /// Get cache statistics
     pub async fn get_stats(&self) -> CacheStats {
         self.cache_data.read().await.stats.clone()
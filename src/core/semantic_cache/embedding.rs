@@ -0,0 +1,79 @@
+//! Prompt embeddings from a configured provider
+
+use super::types::EmbeddingProvider;
+use crate::core::providers::ProviderRegistry;
+use crate::core::types::{EmbeddingInput, EmbeddingRequest, RequestContext};
+use crate::utils::error::{GatewayError, Result};
+use arc_swap::ArcSwap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Embeds prompts with an embedding model of a registered provider
+pub struct ProviderEmbeddings {
+    /// Providers of the gateway, replaced when the configuration is reloaded
+    registry: Arc<ArcSwap<ProviderRegistry>>,
+    /// Provider serving the model, by default OpenAI or else Azure
+    provider: Option<String>,
+    /// Embedding model
+    model: String,
+    /// Dimension of the last embedding
+    dimension: AtomicUsize,
+}
+
+impl ProviderEmbeddings {
+    /// Create an embedding provider for a model of a registered provider
+    pub fn new(
+        registry: Arc<ArcSwap<ProviderRegistry>>,
+        provider: Option<String>,
+        model: String,
+    ) -> Self {
+        Self {
+            registry,
+            provider,
+            model,
+            dimension: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingProvider for ProviderEmbeddings {
+    async fn generate_embedding(&self, text: &str) -> Result<Vec<f32>> {
+        let registry = self.registry.load_full();
+        let provider = match &self.provider {
+            Some(name) => registry.get_provider(name),
+            None => registry
+                .get_provider("openai")
+                .or_else(|| registry.get_provider("azure")),
+        }
+        .ok_or_else(|| GatewayError::internal("No provider available for cache embeddings"))?;
+
+        let request = EmbeddingRequest {
+            model: self.model.clone(),
+            input: EmbeddingInput::Text(text.to_string()),
+            user: None,
+            encoding_format: None,
+            dimensions: None,
+            task_type: None,
+            extra_params: HashMap::new(),
+        };
+        let response = provider
+            .embedding(request, RequestContext::new())
+            .await
+            .map_err(|e| GatewayError::internal(format!("Embedding error: {}", e)))?;
+
+        let embedding = response
+            .data
+            .into_iter()
+            .next()
+            .map(|data| data.embedding)
+            .ok_or_else(|| GatewayError::internal("Embedding response has no data"))?;
+        self.dimension.store(embedding.len(), Ordering::Relaxed);
+        Ok(embedding)
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        self.dimension.load(Ordering::Relaxed)
+    }
+}
@@ -1,8 +1,13 @@
 //! Semantic caching for AI responses
 //!
 //! This module provides intelligent caching based on semantic similarity of prompts.
+//! Prompts are embedded with a configured embedding model, and their vectors
+//! stored in the storage layer's vector database together with the response,
+//! which is served to requests for the same model whose prompt is similar
+//! enough.
 
 mod cache;
+mod embedding;
 mod types;
 mod utils;
 mod validation;
@@ -12,4 +17,5 @@ mod tests;
 
 // Re-export main types and structs for backward compatibility
 pub use cache::SemanticCache;
+pub use embedding::ProviderEmbeddings;
 pub use types::{CacheStats, EmbeddingProvider, SemanticCacheConfig, SemanticCacheEntry};
@@ -7,8 +7,10 @@ mod tests {
     use super::super::utils::extract_prompt_text;
     use super::super::validation::should_cache_request;
     use crate::core::models::openai::ChatCompletionRequest;
-    use crate::core::models::openai::{ChatMessage, MessageContent, MessageRole};
-    use crate::storage::vector::VectorStore;
+    use crate::core::models::openai::{
+        ChatChoice, ChatCompletionResponse, ChatMessage, MessageContent, MessageRole,
+    };
+    use crate::storage::vector::{SearchResult, VectorData, VectorStore};
     use crate::utils::error::Result;
     use std::sync::Arc;
 
@@ -79,6 +81,9 @@ mod tests {
             top_logprobs: None,
             modalities: None,
             audio: None,
+            cache: None,
+            tags: None,
+            metadata: None,
         };
 
         // Should cache low temperature request
@@ -104,6 +109,8 @@ mod tests {
             enable_streaming_cache: false,
             min_prompt_length: 10,
             cache_hit_boost: 1.1,
+            namespace: None,
+            per_key: false,
         };
 
         // Create a simple test implementation
@@ -112,7 +119,6 @@ mod tests {
             Arc::new(TestVectorStore),
             Arc::new(TestEmbeddingProvider),
         )
-        .await
         .unwrap()
     }
 
@@ -149,4 +155,215 @@ mod tests {
             1536
         }
     }
+
+    /// Vector store shared by caches, as by gateway instances
+    #[derive(Default)]
+    struct SharedVectorStore {
+        vectors: parking_lot::Mutex<Vec<VectorData>>,
+    }
+
+    #[async_trait::async_trait]
+    impl VectorStore for SharedVectorStore {
+        async fn search(&self, _vector: Vec<f32>, limit: usize) -> Result<Vec<SearchResult>> {
+            Ok(self
+                .vectors
+                .lock()
+                .iter()
+                .take(limit)
+                .map(|data| SearchResult {
+                    id: data.id.clone(),
+                    score: 0.99,
+                    metadata: Some(serde_json::to_value(&data.metadata).unwrap()),
+                    vector: None,
+                })
+                .collect())
+        }
+
+        async fn insert(&self, vectors: Vec<VectorData>) -> Result<()> {
+            self.vectors.lock().extend(vectors);
+            Ok(())
+        }
+
+        async fn delete(&self, ids: Vec<String>) -> Result<()> {
+            self.vectors.lock().retain(|data| !ids.contains(&data.id));
+            Ok(())
+        }
+    }
+
+    fn semantic_request(model: &str, content: &str) -> ChatCompletionRequest {
+        ChatCompletionRequest {
+            model: model.to_string(),
+            messages: vec![ChatMessage {
+                role: MessageRole::User,
+                content: Some(MessageContent::Text(content.to_string())),
+                name: None,
+                function_call: None,
+                tool_calls: None,
+                tool_call_id: None,
+                audio: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn semantic_response(model: &str, content: &str) -> ChatCompletionResponse {
+        ChatCompletionResponse {
+            id: "chatcmpl-1".to_string(),
+            object: "chat.completion".to_string(),
+            created: 0,
+            model: model.to_string(),
+            choices: vec![ChatChoice {
+                index: 0,
+                message: ChatMessage {
+                    role: MessageRole::Assistant,
+                    content: Some(MessageContent::Text(content.to_string())),
+                    name: None,
+                    function_call: None,
+                    tool_calls: None,
+                    tool_call_id: None,
+                    audio: None,
+                },
+                finish_reason: Some("stop".to_string()),
+                logprobs: None,
+            }],
+            usage: None,
+            system_fingerprint: None,
+            provider_specific_fields: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_semantic_cache_serves_similar_prompts_of_same_model() {
+        let store = Arc::new(SharedVectorStore::default());
+        let cache = SemanticCache::new(
+            SemanticCacheConfig::default(),
+            store.clone(),
+            Arc::new(TestEmbeddingProvider),
+        )
+        .unwrap();
+
+        let request = semantic_request("gpt-4", "What is the capital of France?");
+        assert!(
+            cache
+                .get_cached_response(&request, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let response = semantic_response("gpt-4", "Paris");
+        cache
+            .cache_response(&request, &response, None)
+            .await
+            .unwrap();
+
+        let similar = semantic_request("gpt-4", "What's the capital of France?");
+        let cached = cache
+            .get_cached_response(&similar, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.id, response.id);
+        assert_eq!(cache.get_stats().await.hits, 1);
+
+        // Another model's response is never served
+        let other_model = semantic_request("gpt-3.5-turbo", "What is the capital of France?");
+        assert!(
+            cache
+                .get_cached_response(&other_model, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_semantic_cache_per_key_namespaces() {
+        let store = Arc::new(SharedVectorStore::default());
+        let config = SemanticCacheConfig {
+            per_key: true,
+            ..Default::default()
+        };
+        let cache =
+            SemanticCache::new(config, store.clone(), Arc::new(TestEmbeddingProvider)).unwrap();
+
+        let request = semantic_request("gpt-4", "What is the capital of France?");
+        let response = semantic_response("gpt-4", "Paris");
+        cache
+            .cache_response(&request, &response, Some("key-a"))
+            .await
+            .unwrap();
+
+        let similar = semantic_request("gpt-4", "What's the capital of France?");
+        assert!(
+            cache
+                .get_cached_response(&similar, Some("key-b"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            cache
+                .get_cached_response(&similar, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let cached = cache
+            .get_cached_response(&similar, Some("key-a"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.id, response.id);
+    }
+
+    #[tokio::test]
+    async fn test_semantic_cache_serves_responses_cached_by_other_instances() {
+        let store = Arc::new(SharedVectorStore::default());
+        let new_cache = || {
+            SemanticCache::new(
+                SemanticCacheConfig::default(),
+                store.clone(),
+                Arc::new(TestEmbeddingProvider),
+            )
+            .unwrap()
+        };
+        let (cache, other) = (new_cache(), new_cache());
+
+        let request = semantic_request("gpt-4", "What is the capital of France?");
+        let response = semantic_response("gpt-4", "Paris");
+        cache
+            .cache_response(&request, &response, None)
+            .await
+            .unwrap();
+
+        let cached = other
+            .get_cached_response(&request, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.choices.len(), 1);
+        assert_eq!(cached.model, "gpt-4");
+    }
+
+    #[tokio::test]
+    async fn test_semantic_cache_purges_expired_entries() {
+        let store = Arc::new(SharedVectorStore::default());
+        let config = SemanticCacheConfig {
+            default_ttl_seconds: 0,
+            ..Default::default()
+        };
+        let cache =
+            SemanticCache::new(config, store.clone(), Arc::new(TestEmbeddingProvider)).unwrap();
+
+        let request = semantic_request("gpt-4", "What is the capital of France?");
+        let response = semantic_response("gpt-4", "Paris");
+        cache
+            .cache_response(&request, &response, None)
+            .await
+            .unwrap();
+
+        assert_eq!(cache.purge_expired().await.unwrap(), 1);
+        assert!(store.vectors.lock().is_empty());
+        assert_eq!(cache.purge_expired().await.unwrap(), 0);
+    }
 }
@@ -17,6 +17,8 @@ pub struct SemanticCacheEntry {
     pub response: ChatCompletionResponse,
     /// Model used for the response
     pub model: String,
+    /// Namespace the response was cached in, empty for the default one
+    pub namespace: String,
     /// Cache creation timestamp
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Last access timestamp
@@ -46,6 +48,10 @@ pub struct SemanticCacheConfig {
     pub min_prompt_length: usize,
     /// Cache hit boost factor
     pub cache_hit_boost: f64,
+    /// Namespace responses are cached in, unless a request sets its own
+    pub namespace: Option<String>,
+    /// Cache responses in a namespace per API key or user
+    pub per_key: bool,
 }
 
 impl Default for SemanticCacheConfig {
@@ -58,6 +64,8 @@ impl Default for SemanticCacheConfig {
             enable_streaming_cache: false,
             min_prompt_length: 10,
             cache_hit_boost: 1.1,
+            namespace: None,
+            per_key: false,
         }
     }
 }
@@ -1,6 +1,8 @@
 //! Utility functions for semantic caching
 
+use super::types::SemanticCacheEntry;
 use crate::core::models::openai::{ChatMessage, ContentPart, MessageContent};
+use crate::storage::vector::SearchResult;
 
 /// Extract prompt text from messages
 pub fn extract_prompt_text(messages: &[ChatMessage]) -> String {
@@ -32,3 +34,25 @@ pub fn hash_prompt(prompt: &str) -> String {
     hasher.update(prompt.as_bytes());
     format!("{:x}", hasher.finalize())
 }
+
+/// Rebuild a cache entry from the metadata stored with its vector
+///
+/// Returns `None` if the metadata lacks the cached response or its model.
+pub fn entry_from_metadata(result: &SearchResult) -> Option<SemanticCacheEntry> {
+    let metadata = result.metadata.as_ref()?;
+    let field = |name: &str| metadata.get(name).cloned().unwrap_or_default();
+    let created_at = serde_json::from_value(field("created_at")).ok()?;
+    Some(SemanticCacheEntry {
+        id: result.id.clone(),
+        prompt_hash: serde_json::from_value(field("prompt_hash")).unwrap_or_default(),
+        embedding: result.vector.clone().unwrap_or_default(),
+        response: serde_json::from_value(field("response")).ok()?,
+        model: serde_json::from_value(field("model")).ok()?,
+        namespace: serde_json::from_value(field("namespace")).unwrap_or_default(),
+        created_at,
+        last_accessed: created_at,
+        access_count: 0,
+        ttl_seconds: serde_json::from_value(field("ttl_seconds")).unwrap_or_default(),
+        metadata: std::collections::HashMap::new(),
+    })
+}
@@ -2,6 +2,7 @@
 
 use super::types::{ChatCompletionChunk, ChatCompletionChunkChoice, ChatCompletionDelta, Event};
 use crate::core::models::openai::Usage;
+use crate::core::tokenizers::count_text_tokens;
 use crate::core::types::MessageRole;
 use crate::utils::error::Result;
 use actix_web::web;
@@ -127,6 +128,7 @@ impl StreamingHandler {
                 logprobs: None,
             }],
             usage: None,
+            provider_specific_fields: None,
         };
 
         self.is_first_chunk = false;
@@ -179,15 +181,7 @@ impl StreamingHandler {
 
     /// Create the final chunk with usage information
     async fn create_final_chunk(&self) -> Result<Event> {
-        // Calculate actual token counts using the token counter
-        let token_counter = crate::utils::ai::counter::token_counter::TokenCounter::new();
-        let completion_tokens = token_counter
-            .count_completion_tokens(&self.model, &self.accumulated_content)
-            .map(|estimate| estimate.input_tokens)
-            .unwrap_or_else(|_| self.estimate_token_count(&self.accumulated_content));
-
-        // For prompt tokens, we'd need the original request context
-        // For now, use a reasonable estimate based on typical chat requests
+        let completion_tokens = self.estimate_token_count(&self.accumulated_content);
         let prompt_tokens = self.estimate_prompt_tokens();
         let total_tokens = prompt_tokens + completion_tokens;
 
@@ -216,6 +210,7 @@ impl StreamingHandler {
                 logprobs: None,
             }],
             usage: Some(usage),
+            provider_specific_fields: None,
         };
 
         let event = Event::default().data(&serde_json::to_string(&final_chunk)?);
@@ -223,17 +218,13 @@ impl StreamingHandler {
         Ok(event)
     }
 
-    /// Estimate token count from text (simplified)
+    /// Estimate token count from text with the model's tokenizer
     pub(crate) fn estimate_token_count(&self, text: &str) -> u32 {
-        // Very rough estimation: ~4 characters per token
-        (text.len() as f64 / 4.0).ceil() as u32
+        count_text_tokens(&self.model, text)
     }
 
     /// Estimate prompt tokens based on typical chat requests
     fn estimate_prompt_tokens(&self) -> u32 {
-        // This is a rough estimate since we don't have the original request
-        // In a real implementation, we'd store the original prompt tokens
-        // For now, use a reasonable default based on typical usage
         match self.model.as_str() {
             m if m.contains("gpt-4") => 150,
             m if m.contains("gpt-3.5") => 100,
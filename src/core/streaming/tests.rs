@@ -37,13 +37,13 @@ mod tests {
         let handler = StreamingHandler::new("gpt-4".to_string());
 
         // Test token estimation
-        let text = "Hello world"; // 11 chars -> ~3 tokens
+        let text = "Hello world";
         let tokens = handler.estimate_token_count(text);
-        assert_eq!(tokens, 3);
+        assert_eq!(tokens, if cfg!(feature = "tiktoken") { 2 } else { 3 });
 
-        let longer_text = "This is a longer text for testing"; // 34 chars -> ~9 tokens
+        let longer_text = "This is a longer text for testing";
         let tokens = handler.estimate_token_count(longer_text);
-        assert_eq!(tokens, 9);
+        assert_eq!(tokens, if cfg!(feature = "tiktoken") { 7 } else { 9 });
     }
 
     #[tokio::test]
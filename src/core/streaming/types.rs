@@ -3,6 +3,7 @@
 use crate::core::models::openai::Usage;
 use crate::core::types::MessageRole;
 use actix_web::web;
+use std::collections::HashMap;
 
 /// Simple Event structure for SSE compatibility
 #[derive(Debug, Clone, Default)]
@@ -62,6 +63,9 @@ pub struct ChatCompletionChunk {
     pub choices: Vec<ChatCompletionChunkChoice>,
     /// Usage statistics (only in final chunk)
     pub usage: Option<Usage>,
+    /// Provider-specific fields, e.g. Perplexity `citations`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_specific_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Choice in a streaming chat completion chunk
@@ -110,3 +114,33 @@ pub struct FunctionCallDelta {
     /// Incremental function arguments
     pub arguments: Option<String>,
 }
+
+impl From<crate::core::types::responses::ToolCallDelta> for ToolCallDelta {
+    fn from(delta: crate::core::types::responses::ToolCallDelta) -> Self {
+        Self {
+            index: delta.index,
+            id: delta.id,
+            tool_type: delta.tool_type,
+            function: delta.function.map(|f| FunctionCallDelta {
+                name: f.name,
+                arguments: f.arguments,
+            }),
+        }
+    }
+}
+
+impl From<ToolCallDelta> for crate::core::types::responses::ToolCallDelta {
+    fn from(delta: ToolCallDelta) -> Self {
+        Self {
+            index: delta.index,
+            id: delta.id,
+            tool_type: delta.tool_type,
+            function: delta
+                .function
+                .map(|f| crate::core::types::responses::FunctionCallDelta {
+                    name: f.name,
+                    arguments: f.arguments,
+                }),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Token counts of chat messages
+
+use super::Tokenizer;
+use crate::core::types::{ChatMessage, ContentPart, MessageContent};
+
+/// Tokens framing each message, as in OpenAI's chat format
+const TOKENS_PER_MESSAGE: u32 = 3;
+/// Tokens priming the reply
+const TOKENS_PER_REPLY: u32 = 3;
+/// Tokens of a low-detail image
+const TOKENS_PER_IMAGE: u32 = 85;
+/// Tokens assumed for an audio clip, whose length is unknown
+const TOKENS_PER_AUDIO: u32 = 100;
+/// Tokens assumed for a document, whose length is unknown
+const TOKENS_PER_DOCUMENT: u32 = 1000;
+
+/// Number of tokens the messages take up in a prompt to a model
+pub fn token_counter(model: &str, messages: &[ChatMessage]) -> u32 {
+    let tokenizer = Tokenizer::for_model(model);
+    let tokens: u32 = messages
+        .iter()
+        .map(|message| message_tokens(&tokenizer, message))
+        .sum();
+    if messages.is_empty() {
+        0
+    } else {
+        tokens + TOKENS_PER_REPLY
+    }
+}
+
+/// Number of tokens in a text, such as a streamed completion
+pub fn count_text_tokens(model: &str, text: &str) -> u32 {
+    Tokenizer::for_model(model).count(text)
+}
+
+fn message_tokens(tokenizer: &Tokenizer, message: &ChatMessage) -> u32 {
+    let mut tokens = TOKENS_PER_MESSAGE + tokenizer.count(&message.role.to_string());
+    if let Some(name) = &message.name {
+        tokens += tokenizer.count(name) + 1;
+    }
+    tokens += match &message.content {
+        Some(MessageContent::Text(text)) => tokenizer.count(text),
+        Some(MessageContent::Parts(parts)) => {
+            parts.iter().map(|part| part_tokens(tokenizer, part)).sum()
+        }
+        None => 0,
+    };
+    for call in message.tool_calls.iter().flatten() {
+        tokens += tokenizer.count(&call.function.name) + tokenizer.count(&call.function.arguments);
+    }
+    if let Some(call) = &message.function_call {
+        tokens += tokenizer.count(&call.name) + tokenizer.count(&call.arguments);
+    }
+    tokens
+}
+
+fn part_tokens(tokenizer: &Tokenizer, part: &ContentPart) -> u32 {
+    match part {
+        ContentPart::Text { text, .. } => tokenizer.count(text),
+        ContentPart::ImageUrl { .. } | ContentPart::Image { .. } => TOKENS_PER_IMAGE,
+        ContentPart::Audio { .. } => TOKENS_PER_AUDIO,
+        ContentPart::Document { .. } => TOKENS_PER_DOCUMENT,
+        ContentPart::ToolResult { content, .. } => match content {
+            serde_json::Value::String(text) => tokenizer.count(text),
+            content => tokenizer.count(&content.to_string()),
+        },
+        ContentPart::ToolUse { name, input, .. } => {
+            tokenizer.count(name) + tokenizer.count(&input.to_string())
+        }
+    }
+}
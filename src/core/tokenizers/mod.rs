@@ -0,0 +1,16 @@
+//! Token counting with provider-specific tokenizers
+//!
+//! OpenAI-family models are counted exactly with their tiktoken encoding when
+//! the `tiktoken` feature is enabled. Other models, and OpenAI models without
+//! the feature, are estimated from a characters-per-token ratio of their
+//! family. Counts feed the router's pre-call context window checks, TPM rate
+//! limiting of streamed responses and streaming cost estimates.
+
+mod counter;
+mod tokenizer;
+
+#[cfg(test)]
+mod tests;
+
+pub use counter::{count_text_tokens, token_counter};
+pub use tokenizer::Tokenizer;
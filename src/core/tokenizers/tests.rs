@@ -0,0 +1,72 @@
+//! Tests for token counting
+
+use super::*;
+use crate::core::types::{ChatMessage, ContentPart, MessageContent, MessageRole};
+
+fn message(role: MessageRole, text: &str) -> ChatMessage {
+    ChatMessage {
+        role,
+        content: Some(MessageContent::Text(text.to_string())),
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+#[test]
+fn test_openai_models_use_tiktoken() {
+    assert_eq!(Tokenizer::for_model("gpt-4o-mini"), Tokenizer::O200kBase);
+    assert_eq!(Tokenizer::for_model("openai/gpt-4"), Tokenizer::Cl100kBase);
+    assert_eq!(count_text_tokens("gpt-4", "Hello, world!"), 4);
+
+    // Agrees with tiktoken's own count of chat messages
+    let texts = [
+        (MessageRole::System, "You are a helpful assistant."),
+        (MessageRole::User, "What's the weather in Paris?"),
+    ];
+    let messages: Vec<_> = texts
+        .iter()
+        .map(|(role, text)| message(role.clone(), text))
+        .collect();
+    let reference: Vec<_> = texts
+        .iter()
+        .map(|(role, text)| tiktoken_rs::ChatCompletionRequestMessage {
+            role: role.to_string(),
+            content: Some(text.to_string()),
+            ..Default::default()
+        })
+        .collect();
+    assert_eq!(
+        token_counter("gpt-4o", &messages) as usize,
+        tiktoken_rs::num_tokens_from_messages("gpt-4o", &reference).unwrap()
+    );
+}
+
+#[test]
+fn test_other_models_are_estimated() {
+    let tokenizer = Tokenizer::for_model("anthropic/claude-3-5-sonnet");
+    assert!(!tokenizer.is_exact());
+    assert_eq!(tokenizer.count("abcdefg"), 2);
+    assert_eq!(count_text_tokens("gemini-1.5-pro", "abcdefghi"), 3);
+    assert_eq!(count_text_tokens("gemini-1.5-pro", ""), 0);
+}
+
+#[test]
+fn test_token_counter_counts_every_part() {
+    assert_eq!(token_counter("claude-3-opus", &[]), 0);
+
+    let text = token_counter("claude-3-opus", &[message(MessageRole::User, "Describe")]);
+    let with_image = token_counter(
+        "claude-3-opus",
+        &[ChatMessage {
+            content: Some(MessageContent::Parts(vec![
+                ContentPart::Text {
+                    text: "Describe".to_string(),
+                    cache_control: None,
+                },
+                ContentPart::image_url("https://example.com/cat.png"),
+            ])),
+            ..message(MessageRole::User, "")
+        }],
+    );
+    assert_eq!(with_image, text + 85);
+}
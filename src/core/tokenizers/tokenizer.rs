@@ -0,0 +1,61 @@
+//! Tokenizer selection by model
+
+/// Tokenizer of a model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tokenizer {
+    /// tiktoken `o200k_base`, used by GPT-4o, GPT-4.1 and o-series models
+    #[cfg(feature = "tiktoken")]
+    O200kBase,
+    /// tiktoken `cl100k_base`, used by GPT-4 and GPT-3.5
+    #[cfg(feature = "tiktoken")]
+    Cl100kBase,
+    /// Estimate from the average characters per token of a model family
+    Heuristic { chars_per_token: f64 },
+}
+
+impl Tokenizer {
+    /// Tokenizer of a model, which may carry a `<provider>/` prefix
+    pub fn for_model(model: &str) -> Self {
+        let model = model.rsplit('/').next().unwrap_or(model);
+
+        #[cfg(feature = "tiktoken")]
+        match tiktoken_rs::tokenizer::get_tokenizer(model) {
+            Some(tiktoken_rs::tokenizer::Tokenizer::O200kBase) => return Self::O200kBase,
+            Some(tiktoken_rs::tokenizer::Tokenizer::Cl100kBase) => return Self::Cl100kBase,
+            _ => {}
+        }
+
+        let model = model.to_ascii_lowercase();
+        let chars_per_token = if model.contains("claude") {
+            3.5
+        } else if model.contains("llama") || model.contains("mistral") || model.contains("mixtral")
+        {
+            3.8
+        } else {
+            4.0
+        };
+        Self::Heuristic { chars_per_token }
+    }
+
+    /// Whether counts are exact rather than estimated
+    pub fn is_exact(&self) -> bool {
+        !matches!(self, Self::Heuristic { .. })
+    }
+
+    /// Number of tokens in a text
+    pub fn count(&self, text: &str) -> u32 {
+        match self {
+            #[cfg(feature = "tiktoken")]
+            Self::O200kBase => tiktoken_rs::o200k_base_singleton()
+                .encode_ordinary(text)
+                .len() as u32,
+            #[cfg(feature = "tiktoken")]
+            Self::Cl100kBase => tiktoken_rs::cl100k_base_singleton()
+                .encode_ordinary(text)
+                .len() as u32,
+            Self::Heuristic { chars_per_token } => {
+                (text.chars().count() as f64 / chars_per_token).ceil() as u32
+            }
+        }
+    }
+}
@@ -3,18 +3,23 @@
 //! Defines the unified interface for all AI providers
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use futures::Stream;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::pin::Pin;
 
+use crate::core::audio::types::{SpeechRequest, SpeechResponse};
 use crate::core::traits::error_mapper::trait_def::ErrorMapper;
 use crate::core::types::errors::ProviderErrorTrait;
 use crate::core::types::{
     common::{HealthStatus, ModelInfo, ProviderCapability, RequestContext},
-    requests::{ChatRequest, EmbeddingRequest, ImageGenerationRequest},
-    responses::{ChatChunk, ChatResponse, EmbeddingResponse, ImageGenerationResponse},
+    requests::{AudioTranscriptionRequest, ChatRequest, EmbeddingRequest, ImageGenerationRequest},
+    responses::{
+        AudioTranscriptionResponse, ChatChunk, ChatResponse, EmbeddingResponse,
+        ImageGenerationResponse,
+    },
 };
 
 use super::super::config::ProviderConfig;
@@ -419,6 +424,72 @@ pub trait LLMProvider: Send + Sync + Debug + 'static {
         Err(Self::Error::not_supported("image_generation"))
     }
 
+    /// Transcribe audio
+    ///
+    /// Convert speech in an audio file to text
+    ///
+    /// # Parameters
+    /// * `request` - Audio transcription request with the raw file bytes
+    /// * `context` - Request context with metadata
+    ///
+    /// # Returns
+    /// Transcribed text, plus words and segments for `verbose_json`
+    ///
+    /// # Default Implementation
+    /// Returns not supported error
+    ///
+    /// # Supported Models
+    /// - OpenAI Whisper and gpt-4o transcribe models
+    /// - Azure OpenAI Whisper deployments
+    async fn audio_transcription(
+        &self,
+        _request: AudioTranscriptionRequest,
+        _context: RequestContext,
+    ) -> Result<AudioTranscriptionResponse, Self::Error> {
+        Err(Self::Error::not_supported("audio_transcription"))
+    }
+
+    /// Generate speech
+    ///
+    /// Convert text to spoken audio
+    ///
+    /// # Parameters
+    /// * `request` - Speech request with input text, voice, format and speed
+    /// * `context` - Request context with metadata
+    ///
+    /// # Returns
+    /// The complete audio file and its content type
+    ///
+    /// # Default Implementation
+    /// Returns not supported error
+    ///
+    /// # Supported Models
+    /// - OpenAI tts-1, tts-1-hd and gpt-4o-mini-tts
+    /// - Azure OpenAI TTS deployments
+    /// - MiniMax speech models
+    async fn speech(
+        &self,
+        _request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<SpeechResponse, Self::Error> {
+        Err(Self::Error::not_supported("speech"))
+    }
+
+    /// Generate speech as a byte stream
+    ///
+    /// Like `speech`, but yields audio chunks as the provider produces them so
+    /// playback can start before synthesis finishes
+    ///
+    /// # Default Implementation
+    /// Returns not supported error
+    async fn speech_stream(
+        &self,
+        _request: SpeechRequest,
+        _context: RequestContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, Self::Error>> + Send>>, Self::Error> {
+        Err(Self::Error::not_supported("speech_stream"))
+    }
+
     // ==================== Health Monitoring ====================
 
     /// Check provider health status
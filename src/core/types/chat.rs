@@ -1,7 +1,8 @@
 //! Chat request and message types
 
-use super::content::ContentPart;
+use super::content::{CacheControl, ContentPart};
 use super::message::{MessageContent, MessageRole};
+use super::responses::{ChatDelta, FunctionCallDelta};
 use super::thinking::{ThinkingConfig, ThinkingContent};
 use super::tools::{FunctionCall, ResponseFormat, Tool, ToolCall, ToolChoice};
 use serde::{Deserialize, Serialize};
@@ -36,6 +37,9 @@ pub struct ChatMessage {
     /// Function call (backward compatibility)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub function_call: Option<FunctionCall>,
+    /// Prompt cache breakpoint after this message (Anthropic specific)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
 }
 
 impl Default for ChatMessage {
@@ -48,6 +52,7 @@ impl Default for ChatMessage {
             tool_calls: None,
             tool_call_id: None,
             function_call: None,
+            cache_control: None,
         }
     }
 }
@@ -62,6 +67,79 @@ impl ChatMessage {
     pub fn thinking_text(&self) -> Option<&str> {
         self.thinking.as_ref().and_then(|t| t.as_text())
     }
+
+    /// Add a streamed delta to the message
+    ///
+    /// Content, thinking and function arguments are appended. A tool call
+    /// delta adds to the call at its index, so the deltas of parallel calls
+    /// may interleave.
+    pub fn apply_delta(&mut self, delta: ChatDelta) {
+        if let Some(role) = delta.role {
+            self.role = role;
+        }
+
+        if let Some(content) = delta.content.filter(|content| !content.is_empty()) {
+            match &mut self.content {
+                Some(MessageContent::Text(text)) => text.push_str(&content),
+                Some(MessageContent::Parts(parts)) => parts.push(ContentPart::Text {
+                    text: content,
+                    cache_control: None,
+                }),
+                None => self.content = Some(MessageContent::Text(content)),
+            }
+        }
+
+        if let Some(thinking) = delta.thinking.and_then(|thinking| thinking.content) {
+            match &mut self.thinking {
+                Some(ThinkingContent::Text { text, .. })
+                | Some(ThinkingContent::Block { thinking: text, .. }) => text.push_str(&thinking),
+                _ => self.thinking = Some(ThinkingContent::text(thinking)),
+            }
+        }
+
+        for call_delta in delta.tool_calls.into_iter().flatten() {
+            let tool_calls = self.tool_calls.get_or_insert_with(Vec::new);
+            let index = call_delta.index as usize;
+            if tool_calls.len() <= index {
+                tool_calls.resize_with(index + 1, || ToolCall {
+                    id: String::new(),
+                    tool_type: "function".to_string(),
+                    function: FunctionCall {
+                        name: String::new(),
+                        arguments: String::new(),
+                    },
+                });
+            }
+            let call = &mut tool_calls[index];
+            if let Some(id) = call_delta.id {
+                call.id = id;
+            }
+            if let Some(tool_type) = call_delta.tool_type {
+                call.tool_type = tool_type;
+            }
+            if let Some(function) = call_delta.function {
+                apply_function_delta(&mut call.function, function);
+            }
+        }
+
+        if let Some(function) = delta.function_call {
+            let call = self.function_call.get_or_insert_with(|| FunctionCall {
+                name: String::new(),
+                arguments: String::new(),
+            });
+            apply_function_delta(call, function);
+        }
+    }
+}
+
+/// Add a streamed function call delta to a call
+fn apply_function_delta(call: &mut FunctionCall, delta: FunctionCallDelta) {
+    if let Some(name) = delta.name {
+        call.name = name;
+    }
+    if let Some(arguments) = delta.arguments {
+        call.arguments.push_str(&arguments);
+    }
 }
 
 /// Chat request
@@ -223,47 +301,70 @@ impl ChatRequest {
         self
     }
 
-    /// Estimate input token count
+    /// Estimate input token count with the model's tokenizer
     pub fn estimate_input_tokens(&self) -> u32 {
-        let mut total = 0;
-
-        for message in &self.messages {
-            total += 4; // message structure overhead
-
-            if let Some(content) = &message.content {
-                match content {
-                    MessageContent::Text(text) => {
-                        total += (text.len() as f64 / 4.0).ceil() as u32;
-                    }
-                    MessageContent::Parts(parts) => {
-                        for part in parts {
-                            match part {
-                                ContentPart::Text { text } => {
-                                    total += (text.len() as f64 / 4.0).ceil() as u32;
-                                }
-                                ContentPart::ImageUrl { .. } | ContentPart::Image { .. } => {
-                                    total += 85;
-                                }
-                                ContentPart::Audio { .. } => {
-                                    total += 100;
-                                }
-                                ContentPart::Document { .. } => {
-                                    total += 1000;
-                                }
-                                ContentPart::ToolResult { .. } => {
-                                    total += 50;
-                                }
-                                ContentPart::ToolUse { .. } => {
-                                    total += 100;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        crate::core::tokenizers::token_counter(&self.model, &self.messages)
+    }
+
+    /// Check if the request offers tools or functions to the model
+    pub fn uses_tools(&self) -> bool {
+        self.tools.as_ref().is_some_and(|tools| !tools.is_empty())
+            || self
+                .functions
+                .as_ref()
+                .is_some_and(|functions| !functions.is_empty())
+    }
+
+    /// Check if any message of the request contains an image
+    pub fn has_images(&self) -> bool {
+        self.messages.iter().any(|message| match &message.content {
+            Some(MessageContent::Parts(parts)) => parts.iter().any(|part| {
+                matches!(part, ContentPart::ImageUrl { .. } | ContentPart::Image { .. })
+            }),
+            _ => false,
+        })
+    }
+
+    /// Check if any message of the request contains a document
+    pub fn has_documents(&self) -> bool {
+        self.messages.iter().any(|message| match &message.content {
+            Some(MessageContent::Parts(parts)) => parts
+                .iter()
+                .any(|part| matches!(part, ContentPart::Document { .. })),
+            _ => false,
+        })
+    }
+
+    /// User the request is made for, kept on the same model of a traffic
+    /// split
+    ///
+    /// Read from `metadata.user_id`, else the `user` field.
+    pub fn routing_user(&self) -> Option<String> {
+        self.extra_params
+            .get("metadata")
+            .and_then(|metadata| metadata.get("user_id"))
+            .and_then(|user| user.as_str())
+            .map(str::to_string)
+            .or_else(|| self.user.clone())
+    }
 
-        total
+    /// Deployment tags the request must be routed to
+    ///
+    /// Read from a `tags` parameter or from `metadata.tags`, as LiteLLM
+    /// clients send them.
+    pub fn routing_tags(&self) -> Vec<String> {
+        let tags = self.extra_params.get("tags").or_else(|| {
+            self.extra_params
+                .get("metadata")
+                .and_then(|metadata| metadata.get("tags"))
+        });
+        tags.and_then(|tags| tags.as_array())
+            .map(|tags| {
+                tags.iter()
+                    .filter_map(|tag| tag.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 }
 
@@ -287,6 +388,80 @@ mod tests {
         assert!(!msg.has_thinking());
     }
 
+    #[test]
+    fn test_chat_request_routing_tags() {
+        let request: ChatRequest = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4",
+            "messages": [],
+            "tags": ["eu-only"]
+        }))
+        .unwrap();
+        assert_eq!(request.routing_tags(), vec!["eu-only".to_string()]);
+
+        let request: ChatRequest = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4",
+            "messages": [],
+            "metadata": {"tags": ["eu-only", "fast"]}
+        }))
+        .unwrap();
+        assert_eq!(request.routing_tags().len(), 2);
+        assert!(ChatRequest::new("gpt-4").routing_tags().is_empty());
+    }
+
+    #[test]
+    fn test_chat_request_routing_user() {
+        let request: ChatRequest = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4",
+            "messages": [],
+            "user": "user-1",
+            "metadata": {"user_id": "user-2"}
+        }))
+        .unwrap();
+        assert_eq!(request.routing_user().as_deref(), Some("user-2"));
+
+        let request: ChatRequest = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4",
+            "messages": [],
+            "user": "user-1"
+        }))
+        .unwrap();
+        assert_eq!(request.routing_user().as_deref(), Some("user-1"));
+        assert!(ChatRequest::new("gpt-4").routing_user().is_none());
+    }
+
+    #[test]
+    fn test_chat_request_capabilities() {
+        let request: ChatRequest = serde_json::from_value(serde_json::json!({
+            "model": "gpt-4o",
+            "messages": [{
+                "role": "user",
+                "content": [
+                    {"type": "text", "text": "What is in this image?"},
+                    {"type": "image_url", "image_url": {"url": "https://example.com/a.png"}}
+                ]
+            }],
+            "tools": [{"type": "function", "function": {"name": "describe"}}]
+        }))
+        .unwrap();
+        assert!(request.uses_tools());
+        assert!(request.has_images());
+        assert!(!request.has_documents());
+
+        let request = ChatRequest::new("gpt-4").add_user_message("Hello");
+        assert!(!request.uses_tools());
+        assert!(!request.has_images());
+
+        let mut request = ChatRequest::new("claude-3-5-sonnet");
+        request.messages.push(ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(vec![ContentPart::document_url(
+                "https://example.com/report.pdf",
+            )])),
+            ..Default::default()
+        });
+        assert!(request.has_documents());
+    }
+
     #[test]
     fn test_chat_request_new() {
         let request = ChatRequest::new("gpt-4");
@@ -1,5 +1,8 @@
 //! Content part types for multimodal messages
 
+use std::path::Path;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
 use serde::{Deserialize, Serialize};
 
 /// Content part (multimodal support)
@@ -8,15 +11,23 @@ use serde::{Deserialize, Serialize};
 pub enum ContentPart {
     /// Text content
     #[serde(rename = "text")]
-    Text { text: String },
+    Text {
+        text: String,
+        /// Cache control (Anthropic specific)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
 
     /// Image URL
     #[serde(rename = "image_url")]
     ImageUrl { image_url: ImageUrl },
 
-    /// Audio data
-    #[serde(rename = "audio")]
-    Audio { audio: AudioData },
+    /// Audio data, also accepted in OpenAI's `input_audio` form
+    #[serde(rename = "audio", alias = "input_audio")]
+    Audio {
+        #[serde(alias = "input_audio")]
+        audio: AudioData,
+    },
 
     /// Base64 encoded image
     #[serde(rename = "image")]
@@ -94,6 +105,59 @@ pub struct AudioData {
     pub format: Option<String>,
 }
 
+/// Audio formats recognized from file extensions
+const AUDIO_FORMATS: &[&str] = &["wav", "mp3", "flac", "ogg", "aac", "aiff", "m4a", "webm"];
+
+impl AudioData {
+    /// Format as OpenAI's `input_audio` names it, such as `wav`
+    ///
+    /// A media type is reduced to its subtype, and a missing format is
+    /// detected from the data, falling back to `mp3`.
+    pub fn format_name(&self) -> String {
+        match self.format.as_deref() {
+            Some(format) => {
+                let format = format.strip_prefix("audio/").unwrap_or(format);
+                match format {
+                    "mpeg" => "mp3".to_string(),
+                    "x-wav" | "wave" => "wav".to_string(),
+                    _ => format.to_string(),
+                }
+            }
+            None => STANDARD
+                .decode(self.data.get(..16).unwrap_or_default())
+                .ok()
+                .and_then(|bytes| detect_audio_format(&bytes))
+                .unwrap_or("mp3")
+                .to_string(),
+        }
+    }
+
+    /// Media type, such as `audio/wav`
+    pub fn mime_type(&self) -> String {
+        format!("audio/{}", self.format_name())
+    }
+}
+
+/// Detect an audio format from its leading bytes
+pub fn detect_audio_format(bytes: &[u8]) -> Option<&'static str> {
+    let at = |offset: usize, signature: &[u8]| {
+        bytes.get(offset..offset + signature.len()) == Some(signature)
+    };
+    if at(0, b"RIFF") && at(8, b"WAVE") {
+        Some("wav")
+    } else if at(0, b"ID3") || at(0, b"\xFF\xFB") || at(0, b"\xFF\xF3") {
+        Some("mp3")
+    } else if at(0, b"fLaC") {
+        Some("flac")
+    } else if at(0, b"OggS") {
+        Some("ogg")
+    } else if at(0, b"FORM") && at(8, b"AIFF") {
+        Some("aiff")
+    } else {
+        None
+    }
+}
+
 /// Input audio data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputAudio {
@@ -103,21 +167,249 @@ pub struct InputAudio {
     pub format: String,
 }
 
-/// Document source data (Anthropic PDF support)
+/// Document source data, inline or by URL
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentSource {
     /// Media type (application/pdf)
     pub media_type: String,
-    /// Base64 encoded data
+    /// Base64 encoded data, empty for a document given by URL
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub data: String,
+    /// Document URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
 /// Cache control (Anthropic Cache Control)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CacheControl {
     /// Cache type ("ephemeral", "persistent")
     #[serde(rename = "type")]
     pub cache_type: String,
+    /// Cache lifetime, e.g. "5m" or "1h"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+}
+
+impl CacheControl {
+    /// Short-lived cache breakpoint
+    pub fn ephemeral() -> Self {
+        Self {
+            cache_type: "ephemeral".to_string(),
+            ttl: None,
+        }
+    }
+}
+
+impl ContentPart {
+    /// Image part referencing a URL or data URL
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::ImageUrl {
+            image_url: ImageUrl {
+                url: url.into(),
+                detail: None,
+            },
+        }
+    }
+
+    /// Image part from base64 data
+    pub fn image_base64(media_type: &str, data: &str) -> Self {
+        Self::image_url(format!("data:{};base64,{}", media_type, data))
+    }
+
+    /// Image part from raw bytes, with the media type detected from them
+    pub fn image_bytes(bytes: &[u8]) -> Self {
+        let media_type = detect_image_mime(bytes).unwrap_or("application/octet-stream");
+        Self::image_base64(media_type, &STANDARD.encode(bytes))
+    }
+
+    /// Image part from a file, with the media type detected from its
+    /// contents or else its extension
+    pub fn image_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        let media_type = detect_image_mime(&bytes)
+            .or_else(|| image_mime_from_path(path))
+            .unwrap_or("application/octet-stream");
+        Ok(Self::image_base64(media_type, &STANDARD.encode(&bytes)))
+    }
+
+    /// Audio part from base64 data in a format such as `wav` or `mp3`
+    pub fn input_audio(format: &str, data: impl Into<String>) -> Self {
+        Self::Audio {
+            audio: AudioData {
+                data: data.into(),
+                format: Some(format.to_string()),
+            },
+        }
+    }
+
+    /// Audio part from raw bytes, with the format detected from them
+    pub fn audio_bytes(bytes: &[u8]) -> Self {
+        Self::Audio {
+            audio: AudioData {
+                data: STANDARD.encode(bytes),
+                format: detect_audio_format(bytes).map(String::from),
+            },
+        }
+    }
+
+    /// Audio part from a file, with the format detected from its contents or
+    /// else its extension
+    pub fn audio_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        let format = detect_audio_format(&bytes).or_else(|| {
+            let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+            AUDIO_FORMATS
+                .iter()
+                .copied()
+                .find(|format| *format == extension)
+        });
+        Ok(Self::Audio {
+            audio: AudioData {
+                data: STANDARD.encode(&bytes),
+                format: format.map(String::from),
+            },
+        })
+    }
+
+    /// Document part from base64 data
+    pub fn document_base64(media_type: &str, data: impl Into<String>) -> Self {
+        Self::Document {
+            source: DocumentSource {
+                media_type: media_type.to_string(),
+                data: data.into(),
+                url: None,
+            },
+            cache_control: None,
+        }
+    }
+
+    /// PDF document part referencing a URL
+    pub fn document_url(url: impl Into<String>) -> Self {
+        Self::Document {
+            source: DocumentSource {
+                media_type: "application/pdf".to_string(),
+                data: String::new(),
+                url: Some(url.into()),
+            },
+            cache_control: None,
+        }
+    }
+
+    /// Document part from a file, a PDF unless its extension says otherwise
+    pub fn document_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        let media_type = match extension.as_deref() {
+            _ if bytes.starts_with(b"%PDF") => "application/pdf",
+            Some("txt") => "text/plain",
+            Some("md") => "text/markdown",
+            Some("csv") => "text/csv",
+            Some("html") => "text/html",
+            _ => "application/pdf",
+        };
+        Ok(Self::document_base64(media_type, STANDARD.encode(&bytes)))
+    }
+
+    /// The image this part carries, if any
+    pub fn image(&self) -> Option<ImageRef<'_>> {
+        match self {
+            Self::ImageUrl { image_url } => Some(ImageRef::parse(&image_url.url)),
+            Self::Image {
+                source,
+                image_url: Some(image_url),
+                ..
+            } if source.data.is_empty() => Some(ImageRef::parse(&image_url.url)),
+            Self::Image { source, .. } => Some(ImageRef::Base64 {
+                media_type: &source.media_type,
+                data: &source.data,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Image referenced by a content part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageRef<'a> {
+    /// Inline base64 data
+    Base64 { media_type: &'a str, data: &'a str },
+    /// Remote image
+    Url(&'a str),
+}
+
+impl<'a> ImageRef<'a> {
+    /// Parse an image URL, splitting data URLs into their media type and data
+    ///
+    /// A data URL without a media type has it detected from the data.
+    pub fn parse(url: &'a str) -> Self {
+        let Some((header, data)) = url
+            .strip_prefix("data:")
+            .and_then(|rest| rest.split_once(','))
+        else {
+            return Self::Url(url);
+        };
+        let media_type = match header.split(';').next() {
+            Some(media_type) if !media_type.is_empty() => media_type,
+            _ => detect_base64_image_mime(data).unwrap_or("application/octet-stream"),
+        };
+        Self::Base64 { media_type, data }
+    }
+
+    /// OpenAI `image_url` form, a data URL for inline data
+    pub fn to_url(&self) -> String {
+        match self {
+            Self::Base64 { media_type, data } => format!("data:{};base64,{}", media_type, data),
+            Self::Url(url) => url.to_string(),
+        }
+    }
+}
+
+/// Detect an image's media type from its leading bytes
+pub fn detect_image_mime(bytes: &[u8]) -> Option<&'static str> {
+    let at = |offset: usize, signature: &[u8]| {
+        bytes.get(offset..offset + signature.len()) == Some(signature)
+    };
+    if at(0, b"\x89PNG") {
+        Some("image/png")
+    } else if at(0, b"\xFF\xD8\xFF") {
+        Some("image/jpeg")
+    } else if at(0, b"GIF8") {
+        Some("image/gif")
+    } else if at(0, b"RIFF") && at(8, b"WEBP") {
+        Some("image/webp")
+    } else if at(4, b"ftypheic") {
+        Some("image/heic")
+    } else {
+        None
+    }
+}
+
+/// Detect an image's media type from the start of its base64 encoding
+fn detect_base64_image_mime(data: &str) -> Option<&'static str> {
+    // 16 characters decode to the 12 bytes the signatures need
+    let prefix = data.get(..16)?;
+    detect_image_mime(&STANDARD.decode(prefix).ok()?)
+}
+
+/// Guess an image's media type from its file extension
+pub fn image_mime_from_path(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    match extension.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        "heic" => Some("image/heic"),
+        "heif" => Some("image/heif"),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -130,6 +422,7 @@ mod tests {
     fn test_content_part_text_serialization() {
         let part = ContentPart::Text {
             text: "Hello world".to_string(),
+            cache_control: None,
         };
         let json = serde_json::to_value(&part).unwrap();
         assert_eq!(json["type"], "text");
@@ -141,7 +434,7 @@ mod tests {
         let json = r#"{"type": "text", "text": "Test message"}"#;
         let part: ContentPart = serde_json::from_str(json).unwrap();
         match part {
-            ContentPart::Text { text } => assert_eq!(text, "Test message"),
+            ContentPart::Text { text, .. } => assert_eq!(text, "Test message"),
             _ => panic!("Expected Text variant"),
         }
     }
@@ -193,9 +486,11 @@ mod tests {
             source: DocumentSource {
                 media_type: "application/pdf".to_string(),
                 data: "JVBERi0...".to_string(),
+                url: None,
             },
             cache_control: Some(CacheControl {
                 cache_type: "ephemeral".to_string(),
+                ttl: None,
             }),
         };
         let json = serde_json::to_value(&part).unwrap();
@@ -231,10 +526,13 @@ mod tests {
     fn test_content_part_clone() {
         let part = ContentPart::Text {
             text: "clone test".to_string(),
+            cache_control: None,
         };
         let cloned = part.clone();
         match (part, cloned) {
-            (ContentPart::Text { text: a }, ContentPart::Text { text: b }) => assert_eq!(a, b),
+            (ContentPart::Text { text: a, .. }, ContentPart::Text { text: b, .. }) => {
+                assert_eq!(a, b)
+            }
             _ => panic!("Clone mismatch"),
         }
     }
@@ -356,6 +654,72 @@ mod tests {
         assert_eq!(audio.data, cloned.data);
     }
 
+    #[test]
+    fn test_audio_data_format_name() {
+        let audio = |format: Option<&str>, data: &str| AudioData {
+            data: data.to_string(),
+            format: format.map(String::from),
+        };
+        assert_eq!(audio(Some("wav"), "").format_name(), "wav");
+        assert_eq!(audio(Some("audio/mpeg"), "").format_name(), "mp3");
+        assert_eq!(audio(Some("flac"), "").mime_type(), "audio/flac");
+        // "RIFF....WAVE" header
+        assert_eq!(audio(None, "UklGRiQAAABXQVZF").format_name(), "wav");
+        assert_eq!(audio(None, "").format_name(), "mp3");
+    }
+
+    #[test]
+    fn test_content_part_input_audio_deserialization() {
+        let part: ContentPart = serde_json::from_value(serde_json::json!({
+            "type": "input_audio",
+            "input_audio": {"data": "UklGRiQAAABXQVZF", "format": "wav"}
+        }))
+        .unwrap();
+        match part {
+            ContentPart::Audio { audio } => {
+                assert_eq!(audio.data, "UklGRiQAAABXQVZF");
+                assert_eq!(audio.format.as_deref(), Some("wav"));
+            }
+            part => panic!("expected audio part, got {:?}", part),
+        }
+
+        let part = ContentPart::audio_bytes(b"fLaC\0\0\0\x22");
+        let ContentPart::Audio { audio } = part else {
+            panic!("expected audio part");
+        };
+        assert_eq!(audio.format.as_deref(), Some("flac"));
+    }
+
+    #[test]
+    fn test_document_part_helpers() {
+        let json =
+            serde_json::to_value(ContentPart::document_url("https://example.com/a.pdf")).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "document",
+                "source": {"media_type": "application/pdf", "url": "https://example.com/a.pdf"}
+            })
+        );
+
+        let part: ContentPart = serde_json::from_value(json).unwrap();
+        let ContentPart::Document { source, .. } = part else {
+            panic!("expected document part");
+        };
+        assert!(source.data.is_empty());
+        assert_eq!(source.url.as_deref(), Some("https://example.com/a.pdf"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "hello").unwrap();
+        let ContentPart::Document { source, .. } = ContentPart::document_file(&path).unwrap()
+        else {
+            panic!("expected document part");
+        };
+        assert_eq!(source.media_type, "text/plain");
+        assert_eq!(source.data, "aGVsbG8=");
+    }
+
     // ==================== InputAudio Tests ====================
 
     #[test]
@@ -396,6 +760,7 @@ mod tests {
         let doc = DocumentSource {
             media_type: "application/pdf".to_string(),
             data: "pdf_base64".to_string(),
+            url: None,
         };
         assert_eq!(doc.media_type, "application/pdf");
         assert_eq!(doc.data, "pdf_base64");
@@ -406,6 +771,7 @@ mod tests {
         let doc = DocumentSource {
             media_type: "application/pdf".to_string(),
             data: "JVBERi0=".to_string(),
+            url: None,
         };
         let json = serde_json::to_value(&doc).unwrap();
         assert_eq!(json["media_type"], "application/pdf");
@@ -416,6 +782,7 @@ mod tests {
         let doc = DocumentSource {
             media_type: "application/pdf".to_string(),
             data: "data".to_string(),
+            url: None,
         };
         let cloned = doc.clone();
         assert_eq!(doc.media_type, cloned.media_type);
@@ -427,6 +794,7 @@ mod tests {
     fn test_cache_control_structure() {
         let cache = CacheControl {
             cache_type: "ephemeral".to_string(),
+            ttl: None,
         };
         assert_eq!(cache.cache_type, "ephemeral");
     }
@@ -435,6 +803,7 @@ mod tests {
     fn test_cache_control_serialization() {
         let cache = CacheControl {
             cache_type: "persistent".to_string(),
+            ttl: None,
         };
         let json = serde_json::to_value(&cache).unwrap();
         assert_eq!(json["type"], "persistent");
@@ -451,8 +820,60 @@ mod tests {
     fn test_cache_control_clone() {
         let cache = CacheControl {
             cache_type: "ephemeral".to_string(),
+            ttl: None,
         };
         let cloned = cache.clone();
         assert_eq!(cache.cache_type, cloned.cache_type);
     }
+
+    // ==================== Image Helper Tests ====================
+
+    #[test]
+    fn test_image_bytes_detects_media_type() {
+        let part = ContentPart::image_bytes(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10]);
+        match part.image().unwrap() {
+            ImageRef::Base64 { media_type, data } => {
+                assert_eq!(media_type, "image/jpeg");
+                assert_eq!(
+                    STANDARD.decode(data).unwrap(),
+                    [0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10]
+                );
+            }
+            image => panic!("expected base64 image, got {:?}", image),
+        }
+    }
+
+    #[test]
+    fn test_image_file_falls_back_to_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.WEBP");
+        std::fs::write(&path, b"not really an image").unwrap();
+
+        let part = ContentPart::image_file(&path).unwrap();
+        let ContentPart::ImageUrl { image_url } = &part else {
+            panic!("expected image_url part");
+        };
+        assert!(image_url.url.starts_with("data:image/webp;base64,"));
+        assert!(ContentPart::image_file(dir.path().join("missing.png")).is_err());
+    }
+
+    #[test]
+    fn test_image_ref_parse() {
+        assert_eq!(
+            ImageRef::parse("https://example.com/a.gif"),
+            ImageRef::Url("https://example.com/a.gif")
+        );
+        assert_eq!(
+            ImageRef::parse("data:image/gif;base64,R0lGODlh"),
+            ImageRef::Base64 {
+                media_type: "image/gif",
+                data: "R0lGODlh"
+            }
+        );
+        // GIF89a signature, padded to the detection prefix
+        assert_eq!(
+            ImageRef::parse("data:;base64,R0lGODlhAQABAIAAAAAA").to_url(),
+            "data:image/gif;base64,R0lGODlhAQABAIAAAAAA"
+        );
+    }
 }
@@ -1,6 +1,7 @@
 //! Embedding request types
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Embedding request (short form)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +54,9 @@ pub struct EmbeddingRequest {
     /// Task type (for Vertex AI etc)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_type: Option<String>,
+    /// Additional provider-specific parameters
+    #[serde(flatten)]
+    pub extra_params: HashMap<String, serde_json::Value>,
 }
 
 /// Embedding input type
@@ -286,6 +290,7 @@ mod tests {
             encoding_format: None,
             dimensions: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         assert_eq!(request.model, "text-embedding-ada-002");
@@ -300,6 +305,7 @@ mod tests {
             encoding_format: Some("float".to_string()),
             dimensions: Some(512),
             task_type: Some("RETRIEVAL_DOCUMENT".to_string()),
+            extra_params: HashMap::new(),
         };
 
         assert_eq!(request.task_type, Some("RETRIEVAL_DOCUMENT".to_string()));
@@ -314,6 +320,7 @@ mod tests {
             encoding_format: None,
             dimensions: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         let json = serde_json::to_value(&request).unwrap();
@@ -342,6 +349,7 @@ mod tests {
             encoding_format: None,
             dimensions: None,
             task_type: None,
+            extra_params: HashMap::new(),
         };
 
         let cloned = request.clone();
@@ -1,6 +1,7 @@
 //! Image generation request types
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Image request (short form)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,13 +57,19 @@ pub struct ImageGenerationRequest {
     /// User ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
+    /// Additional provider-specific parameters
+    #[serde(flatten)]
+    pub extra_params: HashMap<String, serde_json::Value>,
 }
 
 /// Audio transcription request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AudioTranscriptionRequest {
     /// Audio file data
     pub file: Vec<u8>,
+    /// Original filename; providers infer the audio format from its extension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
     /// Model name
     pub model: String,
     /// Language (ISO-639-1 format)
@@ -77,6 +84,9 @@ pub struct AudioTranscriptionRequest {
     /// Temperature
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
+    /// Timestamp granularities ("word", "segment"), requires `verbose_json`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_granularities: Option<Vec<String>>,
 }
 
 /// Completion request (legacy text completion)
@@ -210,6 +220,7 @@ mod tests {
             response_format: None,
             style: None,
             user: None,
+            extra_params: HashMap::new(),
         };
         assert_eq!(request.prompt, "Generate an image");
     }
@@ -225,6 +236,7 @@ mod tests {
             response_format: Some("b64_json".to_string()),
             style: Some("natural".to_string()),
             user: Some("user456".to_string()),
+            extra_params: HashMap::new(),
         };
         assert_eq!(request.n, Some(4));
         assert_eq!(request.style, Some("natural".to_string()));
@@ -241,6 +253,7 @@ mod tests {
             response_format: None,
             style: None,
             user: None,
+            extra_params: HashMap::new(),
         };
         let json = serde_json::to_value(&request).unwrap();
         assert_eq!(json["prompt"], "Test prompt");
@@ -258,6 +271,8 @@ mod tests {
             prompt: None,
             response_format: None,
             temperature: None,
+            filename: None,
+            timestamp_granularities: None,
         };
         assert_eq!(request.model, "whisper-1");
         assert_eq!(request.file.len(), 4);
@@ -272,6 +287,8 @@ mod tests {
             prompt: Some("A conversation about...".to_string()),
             response_format: Some("json".to_string()),
             temperature: Some(0.2),
+            filename: None,
+            timestamp_granularities: None,
         };
         assert_eq!(request.language, Some("en".to_string()));
         assert!((request.temperature.unwrap() - 0.2).abs() < f32::EPSILON);
@@ -286,6 +303,8 @@ mod tests {
             prompt: None,
             response_format: None,
             temperature: None,
+            filename: None,
+            timestamp_granularities: None,
         };
         let cloned = request.clone();
         assert_eq!(request.file, cloned.file);
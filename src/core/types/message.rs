@@ -56,7 +56,7 @@ impl std::fmt::Display for MessageContent {
                 let texts: Vec<String> = parts
                     .iter()
                     .filter_map(|part| match part {
-                        ContentPart::Text { text } => Some(text.clone()),
+                        ContentPart::Text { text, .. } => Some(text.clone()),
                         _ => None,
                     })
                     .collect();
@@ -66,6 +66,30 @@ impl std::fmt::Display for MessageContent {
     }
 }
 
+impl MessageContent {
+    /// Append a part, turning plain text into a text part first
+    pub fn with_part(self, part: super::content::ContentPart) -> Self {
+        let mut parts = match self {
+            MessageContent::Text(text) if text.is_empty() => Vec::new(),
+            MessageContent::Text(text) => vec![super::content::ContentPart::Text {
+                text,
+                cache_control: None,
+            }],
+            MessageContent::Parts(parts) => parts,
+        };
+        parts.push(part);
+        MessageContent::Parts(parts)
+    }
+
+    /// Images referenced by the content
+    pub fn images(&self) -> Vec<super::content::ImageRef<'_>> {
+        match self {
+            MessageContent::Text(_) => Vec::new(),
+            MessageContent::Parts(parts) => parts.iter().filter_map(|part| part.image()).collect(),
+        }
+    }
+}
+
 impl From<String> for MessageContent {
     fn from(s: String) -> Self {
         Self::Text(s)
@@ -123,6 +147,20 @@ mod tests {
         assert_eq!(content.to_string(), "World");
     }
 
+    #[test]
+    fn test_message_content_with_image_part() {
+        use super::super::content::{ContentPart, ImageRef};
+
+        let content = MessageContent::from("What is this?")
+            .with_part(ContentPart::image_url("https://example.com/cat.png"));
+
+        assert_eq!(content.to_string(), "What is this?");
+        assert_eq!(
+            content.images(),
+            vec![ImageRef::Url("https://example.com/cat.png")]
+        );
+    }
+
     #[test]
     fn test_message_content_serialization() {
         let content = MessageContent::Text("Test message".to_string());
@@ -40,6 +40,10 @@ pub enum ProviderCapability {
     BatchProcessing,
     /// Real-time API
     RealtimeApi,
+    /// Document reranking
+    Rerank,
+    /// Fill-in-the-middle code completion
+    FimCompletion,
 }
 
 /// Model information
@@ -185,6 +189,8 @@ mod tests {
             ProviderCapability::FineTuning,
             ProviderCapability::BatchProcessing,
             ProviderCapability::RealtimeApi,
+            ProviderCapability::Rerank,
+            ProviderCapability::FimCompletion,
         ];
 
         for cap in capabilities {
@@ -8,6 +8,10 @@ pub struct AudioTranscriptionResponse {
     /// Transcription text
     pub text: String,
 
+    /// Task type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+
     /// Language
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
@@ -235,6 +239,7 @@ mod tests {
             duration: None,
             words: None,
             segments: None,
+            task: None,
         };
         assert_eq!(response.text, "Hello world");
         assert!(response.language.is_none());
@@ -264,6 +269,7 @@ mod tests {
             duration: Some(1.0),
             words: Some(vec![word]),
             segments: Some(vec![segment]),
+            task: None,
         };
         assert_eq!(response.language, Some("en".to_string()));
         assert!((response.duration.unwrap() - 1.0).abs() < f64::EPSILON);
@@ -279,6 +285,7 @@ mod tests {
             duration: Some(5.5),
             words: None,
             segments: None,
+            task: None,
         };
         let json = serde_json::to_value(&response).unwrap();
         assert_eq!(json["text"], "Test transcription");
@@ -294,6 +301,7 @@ mod tests {
             duration: None,
             words: None,
             segments: None,
+            task: None,
         };
         let json = serde_json::to_value(&response).unwrap();
         let obj = json.as_object().unwrap();
@@ -323,6 +331,7 @@ mod tests {
             duration: Some(2.0),
             words: None,
             segments: None,
+            task: None,
         };
         let cloned = response.clone();
         assert_eq!(response.text, cloned.text);
@@ -341,6 +350,7 @@ mod tests {
             duration: Some(1.0),
             words: Some(words),
             segments: None,
+            task: None,
         };
         assert_eq!(response.words.as_ref().unwrap().len(), 2);
         assert_eq!(response.words.as_ref().unwrap()[0].word, "hello");
@@ -377,6 +387,7 @@ mod tests {
             duration: Some(10.0),
             words: None,
             segments: Some(segments),
+            task: None,
         };
         assert_eq!(response.segments.as_ref().unwrap().len(), 2);
     }
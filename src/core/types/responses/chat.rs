@@ -1,8 +1,9 @@
 //! Chat response types
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-use super::super::requests::{ChatMessage, MessageContent, ToolCall};
+use super::super::requests::{ChatMessage, MessageContent, MessageRole, ToolCall};
 use super::delta::ChatDelta;
 use super::logprobs::{FinishReason, LogProbs};
 use super::usage::Usage;
@@ -32,6 +33,11 @@ pub struct ChatResponse {
     /// System fingerprint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_fingerprint: Option<String>,
+
+    /// Provider-specific response fields with no OpenAI equivalent
+    /// (e.g. Perplexity `citations` and `search_results`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_specific_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Chat choice
@@ -77,6 +83,11 @@ pub struct ChatChunk {
     /// System fingerprint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_fingerprint: Option<String>,
+
+    /// Provider-specific chunk fields with no OpenAI equivalent
+    /// (e.g. Perplexity `citations`, repeated on every chunk)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_specific_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Streaming choice
@@ -134,6 +145,66 @@ impl ChatResponse {
             .map(|calls| calls.as_slice())
     }
 
+    /// Assemble a response from streamed chunks
+    ///
+    /// The ID, model and creation time are those of the first chunk.
+    pub fn from_chunks(chunks: impl IntoIterator<Item = ChatChunk>) -> Self {
+        let mut chunks = chunks.into_iter().peekable();
+        let mut response = match chunks.peek() {
+            Some(first) => Self {
+                id: first.id.clone(),
+                created: first.created,
+                model: first.model.clone(),
+                ..Default::default()
+            },
+            None => Self::default(),
+        };
+        for chunk in chunks {
+            response.apply_chunk(chunk);
+        }
+        response
+    }
+
+    /// Add a streamed chunk to the choices and usage of the response
+    ///
+    /// Deltas go to the choice with the same index, which is added on its
+    /// first delta.
+    pub fn apply_chunk(&mut self, chunk: ChatChunk) {
+        for stream_choice in chunk.choices {
+            let position = match self
+                .choices
+                .iter()
+                .position(|choice| choice.index == stream_choice.index)
+            {
+                Some(position) => position,
+                None => {
+                    self.choices.push(ChatChoice {
+                        index: stream_choice.index,
+                        message: ChatMessage {
+                            role: MessageRole::Assistant,
+                            ..Default::default()
+                        },
+                        finish_reason: None,
+                        logprobs: None,
+                    });
+                    self.choices.len() - 1
+                }
+            };
+            let choice = &mut self.choices[position];
+            choice.message.apply_delta(stream_choice.delta);
+            if stream_choice.finish_reason.is_some() {
+                choice.finish_reason = stream_choice.finish_reason;
+            }
+        }
+
+        if chunk.usage.is_some() {
+            self.usage = chunk.usage;
+        }
+        if chunk.system_fingerprint.is_some() {
+            self.system_fingerprint = chunk.system_fingerprint;
+        }
+    }
+
     /// Calculate total cost (requires pricing information)
     pub fn calculate_cost(&self, input_cost_per_1k: f64, output_cost_per_1k: f64) -> f64 {
         if let Some(usage) = &self.usage {
@@ -146,6 +217,13 @@ impl ChatResponse {
     }
 }
 
+impl ChatChunk {
+    /// Check if any choice of the chunk carries content
+    pub fn has_content(&self) -> bool {
+        self.choices.iter().any(|choice| choice.delta.has_content())
+    }
+}
+
 impl Default for ChatResponse {
     fn default() -> Self {
         Self {
@@ -156,6 +234,7 @@ impl Default for ChatResponse {
             choices: Vec::new(),
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         }
     }
 }
@@ -174,6 +253,7 @@ mod tests {
             tool_calls: None,
             tool_call_id: None,
             function_call: None,
+            cache_control: None,
         }
     }
 
@@ -196,8 +276,12 @@ mod tests {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
                 thinking_usage: None,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                billable_characters: None,
             }),
             system_fingerprint: None,
+            provider_specific_fields: None,
         }
     }
 
@@ -246,6 +330,7 @@ mod tests {
             ],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let contents = response.all_content();
@@ -284,12 +369,14 @@ mod tests {
                     }]),
                     tool_call_id: None,
                     function_call: None,
+                    cache_control: None,
                 },
                 finish_reason: Some(FinishReason::ToolCalls),
                 logprobs: None,
             }],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         assert!(response.has_tool_calls());
@@ -329,12 +416,14 @@ mod tests {
                     ]),
                     tool_call_id: None,
                     function_call: None,
+                    cache_control: None,
                 },
                 finish_reason: None,
                 logprobs: None,
             }],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let tool_calls = response.first_tool_calls();
@@ -404,6 +493,7 @@ mod tests {
             }],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         assert_eq!(chunk.id, "chunk_123");
@@ -441,9 +531,84 @@ mod tests {
             choices: vec![],
             usage: None,
             system_fingerprint: None,
+            provider_specific_fields: None,
         };
 
         let json = serde_json::to_value(&chunk).unwrap();
         assert_eq!(json["object"], "chat.completion.chunk");
     }
+
+    #[test]
+    fn test_chat_response_from_interleaved_tool_call_chunks() {
+        use super::super::delta::{FunctionCallDelta, ToolCallDelta};
+
+        let chunk = |tool_calls: Vec<ToolCallDelta>, finish_reason| ChatChunk {
+            id: "chatcmpl-stream".to_string(),
+            object: "chat.completion.chunk".to_string(),
+            created: 1234567890,
+            model: "gpt-4o".to_string(),
+            choices: vec![ChatStreamChoice {
+                index: 0,
+                delta: ChatDelta {
+                    role: None,
+                    content: None,
+                    thinking: None,
+                    tool_calls: Some(tool_calls),
+                    function_call: None,
+                },
+                finish_reason,
+                logprobs: None,
+            }],
+            usage: None,
+            system_fingerprint: None,
+            provider_specific_fields: None,
+        };
+        let start = |index, id: &str, name: &str| ToolCallDelta {
+            index,
+            id: Some(id.to_string()),
+            tool_type: Some("function".to_string()),
+            function: Some(FunctionCallDelta {
+                name: Some(name.to_string()),
+                arguments: Some(String::new()),
+            }),
+        };
+        let arguments = |index, arguments: &str| ToolCallDelta {
+            index,
+            id: None,
+            tool_type: None,
+            function: Some(FunctionCallDelta {
+                name: None,
+                arguments: Some(arguments.to_string()),
+            }),
+        };
+
+        let response = ChatResponse::from_chunks([
+            chunk(vec![start(0, "call_a", "get_weather")], None),
+            chunk(vec![arguments(0, r#"{"city": "Pa"#)], None),
+            chunk(vec![start(1, "call_b", "get_time")], None),
+            chunk(
+                vec![arguments(1, r#"{"zone": "#), arguments(0, r#"ris"}"#)],
+                None,
+            ),
+            chunk(
+                vec![arguments(1, r#""CET"}"#)],
+                Some(FinishReason::ToolCalls),
+            ),
+        ]);
+
+        assert_eq!(response.id, "chatcmpl-stream");
+        assert_eq!(response.choices.len(), 1);
+        let choice = &response.choices[0];
+        assert_eq!(choice.message.role, MessageRole::Assistant);
+        assert!(choice.message.content.is_none());
+        assert_eq!(choice.finish_reason, Some(FinishReason::ToolCalls));
+        let calls = response.first_tool_calls().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_a");
+        assert_eq!(calls[0].function.name, "get_weather");
+        assert_eq!(calls[0].function.arguments, r#"{"city": "Paris"}"#);
+        assert_eq!(calls[1].id, "call_b");
+        assert_eq!(calls[1].function.name, "get_time");
+        assert_eq!(calls[1].function.arguments, r#"{"zone": "CET"}"#);
+    }
 }
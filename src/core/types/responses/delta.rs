@@ -43,6 +43,17 @@ impl ChatDelta {
     pub fn thinking_content(&self) -> Option<&str> {
         self.thinking.as_ref().and_then(|t| t.content.as_deref())
     }
+
+    /// Check if this delta carries content for the client, as opposed to
+    /// only a role
+    pub fn has_content(&self) -> bool {
+        self.content
+            .as_ref()
+            .is_some_and(|content| !content.is_empty())
+            || self.thinking.is_some()
+            || self.tool_calls.is_some()
+            || self.function_call.is_some()
+    }
 }
 
 /// Tool call delta
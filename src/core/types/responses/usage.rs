@@ -31,6 +31,18 @@ pub struct Usage {
     /// DeepSeek R1, Gemini thinking).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thinking_usage: Option<ThinkingUsage>,
+
+    /// Prompt tokens written to the prompt cache (Anthropic)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_creation_input_tokens: Option<u32>,
+
+    /// Prompt tokens read from the prompt cache (Anthropic)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_read_input_tokens: Option<u32>,
+
+    /// Input characters billed for the request (Vertex AI embeddings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billable_characters: Option<u32>,
 }
 
 impl Usage {
@@ -42,6 +54,9 @@ impl Usage {
             prompt_tokens_details: None,
             completion_tokens_details: None,
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         }
     }
 
@@ -144,6 +159,9 @@ mod tests {
                 thinking_cost: None,
                 provider: None,
             }),
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         };
 
         assert_eq!(usage.thinking_tokens(), Some(300));
@@ -161,6 +179,9 @@ mod tests {
                 audio_tokens: None,
             }),
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         };
 
         assert_eq!(usage.thinking_tokens(), Some(150));
@@ -232,6 +253,9 @@ mod tests {
                 audio_tokens: Some(10),
             }),
             thinking_usage: None,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
+            billable_characters: None,
         };
 
         assert_eq!(usage.prompt_tokens_details.as_ref().unwrap().cached_tokens, Some(30));
@@ -102,6 +102,35 @@ pub struct ResponseFormat {
     pub response_type: Option<String>,
 }
 
+impl ResponseFormat {
+    /// Whether the response is to be JSON, with or without a schema
+    pub fn is_json(&self) -> bool {
+        matches!(self.format_type.as_str(), "json_object" | "json_schema")
+    }
+
+    /// Schema of a `json_schema` format
+    pub fn schema(&self) -> Option<&serde_json::Value> {
+        if self.format_type != "json_schema" {
+            return None;
+        }
+        self.json_schema.as_ref()?.get("schema")
+    }
+
+    /// Name of a `json_schema` format's schema
+    pub fn schema_name(&self) -> Option<&str> {
+        self.json_schema.as_ref()?.get("name")?.as_str()
+    }
+
+    /// Whether a `json_schema` format asks for strict adherence
+    pub fn is_strict(&self) -> bool {
+        self.json_schema
+            .as_ref()
+            .and_then(|json_schema| json_schema.get("strict"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,5 +223,20 @@ mod tests {
 
         let json = serde_json::to_value(&format).unwrap();
         assert_eq!(json["type"], "json_object");
+        assert!(format.is_json());
+        assert!(format.schema().is_none());
+
+        let format = ResponseFormat {
+            format_type: "json_schema".to_string(),
+            json_schema: Some(json!({
+                "name": "color",
+                "schema": {"type": "object"},
+                "strict": true
+            })),
+            response_type: None,
+        };
+        assert_eq!(format.schema(), Some(&json!({"type": "object"})));
+        assert_eq!(format.schema_name(), Some("color"));
+        assert!(format.is_strict());
     }
 }
@@ -87,7 +87,8 @@ pub use utils::error::{GatewayError, Result};
 // Export core completion functionality (Python LiteLLM compatible)
 pub use core::completion::{
     Choice, CompletionOptions, CompletionResponse, ContentPart, LiteLLMError, Message, Router,
-    Usage, acompletion, assistant_message, completion, completion_stream, system_message,
+    TextCompletionRequest, TextCompletionResponse, Usage, acompletion, assistant_message,
+    completion, completion_stream, speech, system_message, text_completion, transcription,
     user_message,
 };
 
@@ -96,8 +97,13 @@ pub use core::streaming::types::{
     ChatCompletionChunk, ChatCompletionChunkChoice, ChatCompletionDelta,
 };
 
+// Export text-to-speech types
+pub use core::audio::types::{SpeechRequest, SpeechResponse};
+
 // Export unified type system
-pub use core::types::{MessageContent, MessageRole};
+pub use core::types::{
+    AudioTranscriptionRequest, AudioTranscriptionResponse, MessageContent, MessageRole,
+};
 
 // Export core functionality
 pub use core::models::{RequestContext, openai::*};
@@ -121,11 +127,17 @@ pub struct Gateway {
 
 impl Gateway {
     /// Create a new gateway instance
+    ///
+    /// A configuration loaded from a file, `https://` URL or S3 object, e.g.
+    /// with [`Config::load`], is reloaded when it changes.
     pub async fn new(config: Config) -> Result<Self> {
         info!("Creating new gateway instance");
 
-        // Create HTTP server
+        // Create HTTP server, reloading the configuration as it changes
         let server = server::server::HttpServer::new(&config).await?;
+        if let Some(location) = config.location() {
+            server.watch_config(location.clone());
+        }
 
         Ok(Self { config, server })
     }
@@ -4,22 +4,80 @@
 
 #![allow(missing_docs)]
 
+use clap::{Parser, Subcommand};
+use litellm_rs::config::ConfigLocation;
+use litellm_rs::config::check;
+use litellm_rs::core::observability::{ScrubbingWriter, otlp_layer};
 use litellm_rs::server;
 use std::process::ExitCode;
-use tracing::Level;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+#[derive(Parser)]
+#[command(name = "litellm-rs", version, about = "High-performance AI gateway")]
+struct Cli {
+    /// Configuration file, `https://` URL or `s3://<bucket>/<key>` object
+    #[arg(long, env = "LITELLM_CONFIG", default_value = "config/gateway.yaml")]
+    config: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check a configuration file without starting the gateway
+    ValidateConfig {
+        /// Configuration file, `https://` URL or `s3://<bucket>/<key>` object
+        #[arg(required_unless_present = "schema")]
+        path: Option<String>,
+        /// Health check every valid provider
+        #[arg(long)]
+        ping: bool,
+        /// Print the JSON Schema of configuration files instead
+        #[arg(long, conflicts_with_all = ["path", "ping"])]
+        schema: bool,
+    },
+}
 
 #[tokio::main]
 async fn main() -> ExitCode {
-    // Initialize logging system
-    tracing_subscriber::fmt()
-        .with_max_level(Level::INFO)
-        .with_target(false)
-        .with_thread_ids(false)
+    let cli = Cli::parse();
+
+    // Initialize logging system, scrubbing secrets from log lines; spans are
+    // exported once tracing is configured
+    let level = match cli.command {
+        None => LevelFilter::INFO,
+        Some(_) => LevelFilter::WARN,
+    };
+    tracing_subscriber::registry()
+        .with(otlp_layer())
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_thread_ids(false)
+                .with_writer(ScrubbingWriter::new(std::io::stdout)),
+        )
+        .with(level)
         .init();
 
-    // Start server (auto-loads config/gateway.yaml)
-    match server::builder::run_server().await {
-        Ok(()) => ExitCode::SUCCESS,
+    let result = match cli.command {
+        // Start server (loads config/gateway.yaml unless --config is given)
+        None => server::builder::run_server(&cli.config)
+            .await
+            .map(|()| true),
+        Some(Command::ValidateConfig { path, ping, schema }) => match path {
+            Some(path) if !schema => validate_config(&path, ping).await,
+            _ => {
+                println!("{:#}", check::json_schema());
+                Ok(true)
+            }
+        },
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
         Err(e) => {
             // Print error using Display (not Debug) to preserve newlines
             eprintln!("Error: {}", e);
@@ -27,3 +85,37 @@ async fn main() -> ExitCode {
         }
     }
 }
+
+/// Print the checks of a configuration file, returning whether it is valid
+async fn validate_config(path: &str, ping: bool) -> litellm_rs::Result<bool> {
+    let location = ConfigLocation::parse(path)?;
+    let report = check::check_config(&location, ping).await?;
+
+    for provider in &report.providers {
+        let mark = if provider.is_ok() { "✓" } else { "✗" };
+        let mut line = format!("{} {} ({})", mark, provider.name, provider.provider_type);
+        if let Some(error) = &provider.error {
+            line.push_str(&format!(": {}", error));
+        } else if let Some(health) = &provider.health {
+            line.push_str(&format!(": {:?}", health).to_lowercase());
+        }
+        println!("{}", line);
+    }
+
+    let invalid = report.providers.iter().filter(|p| !p.is_ok()).count();
+    if invalid == 0 {
+        println!(
+            "{} is valid ({} providers)",
+            location,
+            report.providers.len()
+        );
+    } else {
+        println!(
+            "{} has {} invalid of {} providers",
+            location,
+            invalid,
+            report.providers.len()
+        );
+    }
+    Ok(report.is_ok())
+}
@@ -33,8 +33,8 @@ impl AlertManager {
     pub async fn new(config: &AlertingConfig) -> Result<Self> {
         let mut notification_channels: Vec<Box<dyn NotificationChannel>> = Vec::new();
 
-        // Add Slack channel if configured
-        if let Some(webhook_url) = &config.slack_webhook {
+        // Add a Slack-compatible channel per webhook
+        for webhook_url in config.webhook_urls() {
             notification_channels.push(Box::new(SlackChannel::new(
                 webhook_url.clone(),
                 None,
@@ -200,8 +200,7 @@ mod tests {
     fn default_alerting_config() -> AlertingConfig {
         AlertingConfig {
             enabled: true,
-            slack_webhook: None,
-            email: None,
+            ..Default::default()
         }
     }
 
@@ -233,7 +232,7 @@ mod tests {
         let config = AlertingConfig {
             enabled: true,
             slack_webhook: Some("https://hooks.slack.com/test".to_string()),
-            email: None,
+            ..Default::default()
         };
 
         let manager = AlertManager::new(&config).await.unwrap();
@@ -94,9 +94,9 @@ impl HealthChecker {
         let database_health = self.check_database().await;
         components.insert("database".to_string(), database_health);
 
-        // Check Redis
-        let redis_health = self.check_redis().await;
-        components.insert("redis".to_string(), redis_health);
+        // Check the cache, in Redis or in memory
+        let cache_health = self.check_cache().await;
+        components.insert(cache_health.name.clone(), cache_health);
 
         // Check file storage
         let file_storage_health = self.check_file_storage().await;
@@ -81,28 +81,30 @@ impl HealthChecker {
         }
     }
 
-    /// Check Redis health
-    pub(super) async fn check_redis(&self) -> ComponentHealth {
+    /// Check health of the cache, named after its backend
+    pub(super) async fn check_cache(&self) -> ComponentHealth {
         let start_time = Instant::now();
 
-        match self.storage.redis().health_check().await {
+        let cache = self.storage.cache();
+        let metadata = HashMap::from([("backend".to_string(), cache.name().into())]);
+        match cache.health_check().await {
             Ok(()) => ComponentHealth {
-                name: "redis".to_string(),
+                name: cache.name().to_string(),
                 healthy: true,
                 status: "healthy".to_string(),
                 last_check: chrono::Utc::now(),
                 response_time_ms: start_time.elapsed().as_millis() as u64,
                 error: None,
-                metadata: HashMap::new(),
+                metadata,
             },
             Err(e) => ComponentHealth {
-                name: "redis".to_string(),
+                name: cache.name().to_string(),
                 healthy: false,
                 status: "unhealthy".to_string(),
                 last_check: chrono::Utc::now(),
                 response_time_ms: start_time.elapsed().as_millis() as u64,
                 error: Some(e.to_string()),
-                metadata: HashMap::new(),
+                metadata,
             },
         }
     }
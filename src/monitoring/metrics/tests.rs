@@ -35,11 +35,13 @@ mod tests {
                 enabled: false,
                 endpoint: None,
                 service_name: "test".to_string(),
+                headers: Default::default(),
             },
             health: crate::config::HealthConfig {
                 path: "/health".to_string(),
                 detailed: true,
             },
+            alerting: Default::default(),
         };
 
         let collector = MetricsCollector::new(&config).await.unwrap();
@@ -44,7 +44,11 @@ impl MonitoringSystem {
         let health = Arc::new(health::checker::HealthChecker::new(storage.clone()).await?);
 
         // Initialize alert manager (if enabled)
-        let alerts = None; // TODO: Add alerting config to MonitoringConfig
+        let alerts = if config.alerting.enabled {
+            Some(Arc::new(alerts::AlertManager::new(&config.alerting).await?))
+        } else {
+            None
+        };
 
         info!("Monitoring system initialized successfully");
 
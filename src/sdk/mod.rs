@@ -22,9 +22,14 @@ pub use types::*;
 /// SDK version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-/// Initialize the SDK with default logging
+/// Initialize the SDK with default logging, scrubbing secrets from log lines
 pub fn init() {
-    tracing_subscriber::fmt::init();
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(crate::core::observability::ScrubbingWriter::new(
+            std::io::stdout,
+        ))
+        .init();
 }
 
 #[cfg(test)]
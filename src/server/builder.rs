@@ -3,22 +3,28 @@
 //! This module provides the ServerBuilder for easier server configuration
 //! and the run_server function for automatic configuration loading.
 
-use crate::config::Config;
+use crate::config::{Config, ConfigLocation};
 use crate::server::server::HttpServer;
+use crate::services::callbacks::CallbackHandler;
 use crate::utils::error::{GatewayError, Result};
+use std::sync::Arc;
 use tracing::info;
 
 /// Server builder for easier configuration
 #[allow(dead_code)]
 pub struct ServerBuilder {
     config: Option<Config>,
+    callbacks: Vec<Arc<dyn CallbackHandler>>,
 }
 
 #[allow(dead_code)]
 impl ServerBuilder {
     /// Create a new server builder
     pub fn new() -> Self {
-        Self { config: None }
+        Self {
+            config: None,
+            callbacks: Vec::new(),
+        }
     }
 
     /// Set configuration
@@ -27,13 +33,23 @@ impl ServerBuilder {
         self
     }
 
+    /// Register a callback handler, run after configured callbacks
+    pub fn with_callback(mut self, handler: Arc<dyn CallbackHandler>) -> Self {
+        self.callbacks.push(handler);
+        self
+    }
+
     /// Build the HTTP server
     pub async fn build(self) -> Result<HttpServer> {
         let config = self
             .config
             .ok_or_else(|| GatewayError::Config("Configuration is required".to_string()))?;
 
-        HttpServer::new(&config).await
+        let server = HttpServer::new(&config).await?;
+        for handler in self.callbacks {
+            server.state().callbacks.register(handler);
+        }
+        Ok(server)
     }
 }
 
@@ -44,15 +60,17 @@ impl Default for ServerBuilder {
 }
 
 /// Run the server with automatic configuration loading
+///
+/// `config_location` is a file path, `https://` URL or `s3://<bucket>/<key>`.
 #[allow(dead_code)]
-pub async fn run_server() -> Result<()> {
+pub async fn run_server(config_location: &str) -> Result<()> {
     info!("🚀 Starting Rust LiteLLM Gateway");
 
-    // Auto-load configuration file
-    let config_path = "config/gateway.yaml";
-    info!("📄 Loading configuration file: {}", config_path);
+    // Auto-load configuration
+    let location = ConfigLocation::parse(config_location)?;
+    info!("📄 Loading configuration: {}", location);
 
-    let config = match Config::from_file(config_path).await {
+    let config = match Config::from_location(&location).await {
         Ok(config) => {
             info!("✅ Configuration file loaded successfully");
             config
@@ -67,8 +85,9 @@ pub async fn run_server() -> Result<()> {
         }
     };
 
-    // Create and start server
+    // Create and start server, reloading the configuration as it changes
     let server = HttpServer::new(&config).await?;
+    server.watch_config(location);
     info!(
         "🌐 Server starting at: http://{}:{}",
         config.server().host,
@@ -1,23 +1,35 @@
 //! Authentication middleware
 
 use crate::auth::AuthMethod;
+use crate::auth::oidc::OidcIdentity;
 use crate::core::models::RequestContext;
 use crate::server::middleware::auth_rate_limiter::get_auth_rate_limiter;
 use crate::server::middleware::helpers::{extract_auth_method, is_public_route};
+use crate::server::middleware::request_log::key_hash;
 use crate::server::state::AppState;
+use crate::utils::error::GatewayError;
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
 use actix_web::{HttpMessage, HttpRequest, web};
 use futures::future::{Ready, ready};
 use std::future::Future;
 use std::pin::Pin;
+use std::rc::Rc;
 use tracing::{debug, warn};
 
-/// Auth middleware for Actix-web
+/// Authenticates the API keys, gateway tokens and sessions requests carry
+///
+/// Authenticated requests get a [`RequestContext`] with the caller's key,
+/// user and team, and the [`User`](crate::core::models::user::types::User)
+/// and [`ApiKey`](crate::core::models::ApiKey) they authenticated as;
+/// requests whose key or team has exhausted its budget are rejected.
+/// Invalid credentials are rejected and count against the client's failed
+/// attempts. Public routes, requests without credentials and those the OIDC
+/// middleware authenticated pass through untouched.
 pub struct AuthMiddleware;
 
 impl<S, B> Transform<S, ServiceRequest> for AuthMiddleware
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -28,18 +40,20 @@ where
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, service: S) -> Self::Future {
-        ready(Ok(AuthMiddlewareService { service }))
+        ready(Ok(AuthMiddlewareService {
+            service: Rc::new(service),
+        }))
     }
 }
 
 /// Service implementation for auth middleware
 pub struct AuthMiddlewareService<S> {
-    service: S,
+    service: Rc<S>,
 }
 
 impl<S, B> Service<ServiceRequest> for AuthMiddlewareService<S>
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -50,16 +64,20 @@ where
     forward_ready!(service);
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
-        let path = req.path().to_string();
-
-        if is_public_route(&path) {
+        let auth_method = extract_auth_method(req.headers());
+        let skip = is_public_route(req.path())
+            || matches!(auth_method, AuthMethod::None)
+            || req.extensions().contains::<OidcIdentity>();
+        let state = req
+            .app_data::<web::Data<AppState>>()
+            .filter(|_| !skip)
+            .cloned();
+        let Some(state) = state else {
             return Box::pin(self.service.call(req));
-        }
+        };
 
-        let auth_method = extract_auth_method(req.headers());
         let client_id = get_client_identifier(&req);
         let rate_limiter = get_auth_rate_limiter();
-
         if let Err(wait_seconds) = rate_limiter.check_allowed(&client_id) {
             return Box::pin(async move {
                 Err(actix_web::error::ErrorTooManyRequests(format!(
@@ -69,39 +87,38 @@ where
             });
         }
 
-        let app_state = req.app_data::<web::Data<AppState>>().cloned();
-        let fut = self.service.call(req);
-
+        let service = Rc::clone(&self.service);
         Box::pin(async move {
-            if let Some(state) = &app_state {
-                match &auth_method {
-                    AuthMethod::ApiKey(_key) => {
-                        // API key validation is handled by the auth system
-                        // For now, just log and allow - actual validation happens in routes
-                        debug!("API key authentication attempt");
-                        rate_limiter.record_success(&client_id);
-                    }
-                    AuthMethod::Jwt(token) => {
-                        // Verify JWT token
-                        match state.auth.jwt().verify_token(token).await {
-                            Ok(_claims) => {
-                                rate_limiter.record_success(&client_id);
-                                debug!("JWT validated successfully");
-                            }
-                            Err(e) => {
-                                rate_limiter.record_failure(&client_id);
-                                warn!("JWT validation error: {}", e);
-                            }
-                        }
-                    }
-                    AuthMethod::None => {
-                        debug!("No auth method provided for protected route");
-                    }
-                    _ => {}
+            let context = req
+                .extensions()
+                .get::<RequestContext>()
+                .cloned()
+                .unwrap_or_default();
+            let result = state
+                .auth
+                .authenticate(auth_method.clone(), context)
+                .await?;
+            if !result.success {
+                rate_limiter.record_failure(&client_id);
+                let error = result.error.as_deref().unwrap_or("Authentication failed");
+                warn!("Authentication failed: {}", error);
+                return Err(GatewayError::unauthorized(error).into());
+            }
+            rate_limiter.record_success(&client_id);
+            check_budgets(&state, &auth_method, &result.context)?;
+
+            debug!("Authenticated request as user {:?}", result.context.user_id);
+            {
+                let mut extensions = req.extensions_mut();
+                extensions.insert(result.context);
+                if let Some(user) = result.user {
+                    extensions.insert(user);
+                }
+                if let Some(api_key) = result.api_key {
+                    extensions.insert(api_key);
                 }
             }
-
-            fut.await
+            service.call(req).await
         })
     }
 }
@@ -114,6 +131,26 @@ pub fn get_request_context(req: &HttpRequest) -> Result<RequestContext, actix_we
         .ok_or_else(|| actix_web::error::ErrorInternalServerError("Missing request context"))
 }
 
+/// Reject requests whose API key or team has exhausted its budget
+fn check_budgets(
+    state: &AppState,
+    auth_method: &AuthMethod,
+    context: &RequestContext,
+) -> Result<(), GatewayError> {
+    let Some(spend) = &state.spend else {
+        return Ok(());
+    };
+    if spend.budgets().is_empty() {
+        return Ok(());
+    }
+
+    let key_hash = key_hash(auth_method);
+    let team_id = context.team_id.map(|id| id.to_string());
+    spend
+        .budgets()
+        .check(key_hash.as_deref(), team_id.as_deref(), None)
+}
+
 /// Extract a client identifier for rate limiting
 fn get_client_identifier(req: &ServiceRequest) -> String {
     let ip = req
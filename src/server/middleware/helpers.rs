@@ -3,6 +3,9 @@
 use crate::auth::AuthMethod;
 use actix_web::http::header::HeaderMap;
 
+/// WebSocket subprotocol prefix browsers use to send the API key
+const API_KEY_PROTOCOL_PREFIX: &str = "openai-insecure-api-key.";
+
 /// Extract authentication method from headers
 pub fn extract_auth_method(headers: &HeaderMap) -> AuthMethod {
     // Check Authorization header
@@ -10,6 +13,10 @@ pub fn extract_auth_method(headers: &HeaderMap) -> AuthMethod {
         if let Ok(auth_str) = auth_header.to_str() {
             if let Some(stripped) = auth_str.strip_prefix("Bearer ") {
                 let token = stripped.to_string();
+                // OpenAI clients send gateway API keys as bearer tokens
+                if token.starts_with("gw-") {
+                    return AuthMethod::ApiKey(token);
+                }
                 return AuthMethod::Jwt(token);
             } else if let Some(stripped) = auth_str.strip_prefix("ApiKey ") {
                 let key = stripped.to_string();
@@ -27,6 +34,22 @@ pub fn extract_auth_method(headers: &HeaderMap) -> AuthMethod {
         }
     }
 
+    // Check Azure-style api-key header
+    if let Some(key) = headers.get("api-key").and_then(|h| h.to_str().ok()) {
+        return AuthMethod::ApiKey(key.trim().to_string());
+    }
+
+    // Browsers cannot set headers on WebSocket upgrades and send the key as
+    // a subprotocol instead
+    if let Some(key) = headers
+        .get_all("sec-websocket-protocol")
+        .filter_map(|h| h.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|protocol| protocol.trim().strip_prefix(API_KEY_PROTOCOL_PREFIX))
+    {
+        return AuthMethod::ApiKey(key.to_string());
+    }
+
     // Check session cookie
     if let Some(cookie_header) = headers.get("cookie") {
         if let Ok(cookie_str) = cookie_header.to_str() {
@@ -62,7 +85,7 @@ pub fn is_public_route(path: &str) -> bool {
 
 /// Check if a route requires admin privileges
 pub fn is_admin_route(path: &str) -> bool {
-    const ADMIN_ROUTES: &[&str] = &["/admin", "/api/admin"];
+    const ADMIN_ROUTES: &[&str] = &["/admin", "/api/admin", "/spend"];
 
     ADMIN_ROUTES.iter().any(|&route| path.starts_with(route))
 }
@@ -0,0 +1,264 @@
+//! Load shedding middleware
+//!
+//! Caps the requests the gateway handles at once so that a slow provider
+//! cannot tie up every worker. Requests over the cap wait in a bounded queue
+//! and are shed with a 503 when the queue is full or they wait too long.
+
+use crate::config::models::server::LoadSheddingConfig;
+use crate::server::state::AppState;
+use crate::utils::error::GatewayError;
+use actix_web::body::{BodySize, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::error::InternalError;
+use actix_web::http::header::{self, HeaderValue};
+use actix_web::{ResponseError, web};
+use futures::future::{Ready, ready};
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+use super::helpers::is_public_route;
+
+/// Seconds shed requests are told to wait before retrying
+const RETRY_AFTER_SECS: u32 = 1;
+
+/// Slots for requests in flight, with a bounded queue for waiting requests
+#[derive(Debug)]
+pub struct LoadShedder {
+    permits: Arc<Semaphore>,
+    max_in_flight: usize,
+    max_queued: usize,
+    queued: AtomicUsize,
+    queue_timeout: Duration,
+}
+
+impl LoadShedder {
+    /// Create a load shedder from its configuration
+    pub fn new(config: &LoadSheddingConfig) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(config.max_in_flight)),
+            max_in_flight: config.max_in_flight,
+            max_queued: config.max_queued,
+            queued: AtomicUsize::new(0),
+            queue_timeout: Duration::from_millis(config.queue_timeout_ms),
+        }
+    }
+
+    /// Wait for a slot, or `None` if the request should be shed
+    ///
+    /// The slot is freed when the permit is dropped.
+    pub async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        if let Ok(permit) = Arc::clone(&self.permits).try_acquire_owned() {
+            return Some(permit);
+        }
+
+        let queued = self
+            .queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued < self.max_queued).then_some(queued + 1)
+            });
+        if queued.is_err() {
+            return None;
+        }
+        let _queued = QueuedGuard(&self.queued);
+
+        tokio::time::timeout(
+            self.queue_timeout,
+            Arc::clone(&self.permits).acquire_owned(),
+        )
+        .await
+        .ok()?
+        .ok()
+    }
+
+    /// Requests currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.max_in_flight - self.permits.available_permits()
+    }
+
+    /// Requests currently waiting for a slot
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Acquire)
+    }
+}
+
+/// Leaves the queue when a waiting request gets a slot, times out or is
+/// cancelled
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Sheds requests once the gateway-wide in-flight limit and queue are full
+///
+/// A request holds its slot until its response body has been sent, so
+/// streaming responses count for as long as they stream. Public routes such
+/// as `/health` are never shed. Requests pass through untouched when load
+/// shedding is disabled.
+pub struct LoadSheddingMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for LoadSheddingMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<PermitBody<B>>;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = LoadSheddingMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(LoadSheddingMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+/// Service implementation for load shedding middleware
+pub struct LoadSheddingMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for LoadSheddingMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<PermitBody<B>>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let shedder = req
+            .app_data::<web::Data<AppState>>()
+            .and_then(|state| state.load_shedder.clone())
+            .filter(|_| !is_public_route(req.path()));
+        let Some(shedder) = shedder else {
+            let fut = self.service.call(req);
+            return Box::pin(async move {
+                let res = fut.await?;
+                Ok(res.map_body(|_, body| PermitBody::new(body, None)))
+            });
+        };
+
+        let service = Rc::clone(&self.service);
+        Box::pin(async move {
+            let Some(permit) = shedder.acquire().await else {
+                warn!(
+                    "Shedding {} {}: {} requests in flight, {} queued",
+                    req.method(),
+                    req.path(),
+                    shedder.in_flight(),
+                    shedder.queued()
+                );
+                let error = GatewayError::service_unavailable(
+                    "Gateway is overloaded, retry the request later",
+                );
+                let mut response = error.error_response();
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, HeaderValue::from(RETRY_AFTER_SECS));
+                return Err(InternalError::from_response(error, response).into());
+            };
+
+            let res = service.call(req).await?;
+            Ok(res.map_body(|_, body| PermitBody::new(body, Some(permit))))
+        })
+    }
+}
+
+pin_project! {
+    /// Response body holding its request's slot until it is dropped
+    pub struct PermitBody<B> {
+        #[pin]
+        body: B,
+        permit: Option<OwnedSemaphorePermit>,
+    }
+}
+
+impl<B> PermitBody<B> {
+    fn new(body: B, permit: Option<OwnedSemaphorePermit>) -> Self {
+        Self { body, permit }
+    }
+}
+
+impl<B: MessageBody> MessageBody for PermitBody<B> {
+    type Error = B::Error;
+
+    fn size(&self) -> BodySize {
+        self.body.size()
+    }
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<web::Bytes, Self::Error>>> {
+        self.project().body.poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shedder(max_in_flight: usize, max_queued: usize) -> LoadShedder {
+        LoadShedder::new(&LoadSheddingConfig {
+            max_in_flight,
+            max_queued,
+            queue_timeout_ms: 50,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_load_shedder_sheds_when_queue_full() {
+        let shedder = shedder(1, 0);
+        let permit = shedder.acquire().await;
+        assert!(permit.is_some());
+        assert_eq!(shedder.in_flight(), 1);
+        assert!(shedder.acquire().await.is_none());
+
+        drop(permit);
+        assert_eq!(shedder.in_flight(), 0);
+        assert!(shedder.acquire().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_load_shedder_queue_timeout() {
+        let shedder = shedder(1, 1);
+        let _permit = shedder.acquire().await.unwrap();
+        assert!(shedder.acquire().await.is_none());
+        assert_eq!(shedder.queued(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_load_shedder_queued_request_gets_released_slot() {
+        let shedder = Arc::new(shedder(1, 1));
+        let permit = shedder.acquire().await.unwrap();
+
+        let waiting = tokio::spawn({
+            let shedder = Arc::clone(&shedder);
+            async move { shedder.acquire().await.is_some() }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(shedder.queued(), 1);
+        drop(permit);
+
+        assert!(waiting.await.unwrap());
+        assert_eq!(shedder.queued(), 0);
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! This module provides various middleware for request processing:
 //! - Authentication and authorization
+//! - OIDC token authentication
 //! - Rate limiting (auth-specific and general)
+//! - Load shedding
 //! - Request ID tracking
+//! - Request audit logging
+//! - Spend tracking
+//! - Trace context propagation
 //! - Metrics collection
 //! - Security headers
 //! - CORS handling
@@ -13,10 +18,15 @@
 mod auth;
 mod auth_rate_limiter;
 mod helpers;
+mod load_shedding;
 mod metrics;
+mod oidc;
 mod rate_limit;
 mod request_id;
+mod request_log;
 mod security;
+mod spend;
+mod trace_context;
 
 #[cfg(test)]
 mod tests;
@@ -25,10 +35,17 @@ mod tests;
 pub use auth::{AuthMiddleware, AuthMiddlewareService, get_request_context};
 pub use auth_rate_limiter::{AuthRateLimiter, get_auth_rate_limiter};
 pub use helpers::{extract_auth_method, is_admin_route, is_api_route, is_public_route};
+pub use load_shedding::{
+    LoadShedder, LoadSheddingMiddleware, LoadSheddingMiddlewareService, PermitBody,
+};
 pub use metrics::{MetricsMiddleware, MetricsMiddlewareService, RequestMetrics};
+pub use oidc::{OidcMiddleware, OidcMiddlewareService};
 pub use rate_limit::{RateLimitMiddleware, RateLimitMiddlewareService};
 pub use request_id::{RequestIdMiddleware, RequestIdMiddlewareService};
+pub use request_log::{RequestLogMiddleware, RequestLogMiddlewareService, RequestUsage};
 pub use security::{
     CorsMiddleware, CorsMiddlewareService, SecurityHeadersMiddleware,
     SecurityHeadersMiddlewareService,
 };
+pub use spend::{DeferredUsage, SpendMiddleware, SpendMiddlewareService};
+pub use trace_context::{TraceContextMiddleware, TraceContextMiddlewareService};
@@ -0,0 +1,107 @@
+//! OIDC authentication middleware
+
+use crate::auth::AuthMethod;
+use crate::auth::oidc::is_oidc_token;
+use crate::core::models::RequestContext;
+use crate::server::state::AppState;
+use crate::utils::error::GatewayError;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::{HttpMessage, web};
+use futures::future::{Ready, ready};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use tracing::{debug, warn};
+
+use super::helpers::{extract_auth_method, is_admin_route, is_public_route};
+
+/// Authenticates bearer tokens issued by the OIDC identity provider
+///
+/// Valid tokens attach a [`RequestContext`] carrying the caller's user and
+/// team, and the [`OidcIdentity`](crate::auth::oidc::OidcIdentity) with their
+/// roles and permissions; invalid ones are rejected. Admin routes require an
+/// admin role. API keys and the gateway's own tokens pass through untouched,
+/// as do all requests when OIDC is not configured.
+pub struct OidcMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for OidcMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = OidcMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(OidcMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+/// Service implementation for OIDC middleware
+pub struct OidcMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for OidcMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let state = req
+            .app_data::<web::Data<AppState>>()
+            .filter(|state| state.auth.oidc_enabled())
+            .cloned();
+        let token = match extract_auth_method(req.headers()) {
+            AuthMethod::Jwt(token) if is_oidc_token(&token) => Some(token),
+            _ => None,
+        };
+        let (Some(state), Some(token)) = (state, token) else {
+            return Box::pin(self.service.call(req));
+        };
+        if is_public_route(req.path()) {
+            return Box::pin(self.service.call(req));
+        }
+
+        let service = Rc::clone(&self.service);
+        Box::pin(async move {
+            let mut context = RequestContext::new();
+            let identity = match state.auth.authenticate_oidc(&token, &mut context).await {
+                Ok(identity) => identity,
+                Err(e) => {
+                    warn!("OIDC authentication failed: {}", e);
+                    return Err(GatewayError::unauthorized("Invalid OIDC token").into());
+                }
+            };
+
+            if is_admin_route(req.path()) && !state.auth.rbac().has_admin_role(&identity.roles) {
+                return Err(GatewayError::forbidden("Admin role required").into());
+            }
+            if let Some(spend) = &state.spend {
+                let team_id = context.team_id.map(|id| id.to_string());
+                spend.budgets().check(None, team_id.as_deref(), None)?;
+            }
+
+            debug!(
+                "Authenticated {} via OIDC with roles {:?}",
+                identity.subject, identity.roles
+            );
+            req.extensions_mut().insert(context);
+            req.extensions_mut().insert(identity);
+            service.call(req).await
+        })
+    }
+}
@@ -1,36 +1,36 @@
 //! Rate limiting middleware
 
+use crate::auth::AuthMethod;
+use crate::core::rate_limiter::{RateLimitResult, UsageLimitResult};
 use crate::server::state::AppState;
-use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
-use actix_web::web;
+use crate::utils::error::GatewayError;
+use actix_web::dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::error::{InternalError, PayloadError};
+use actix_web::http::Method;
+use actix_web::http::header::{self, HeaderMap, HeaderName, HeaderValue};
+use actix_web::{HttpMessage, ResponseError, web};
+use futures::StreamExt;
 use futures::future::{Ready, ready};
+use serde::Deserialize;
 use std::future::Future;
 use std::pin::Pin;
-use std::time::Instant;
-use tracing::{debug, info};
+use std::rc::Rc;
 
-/// Rate limit middleware for Actix-web
-pub struct RateLimitMiddleware {
-    requests_per_minute: u32,
-}
-
-impl RateLimitMiddleware {
-    pub fn new(requests_per_minute: u32) -> Self {
-        Self {
-            requests_per_minute,
-        }
-    }
-}
+use super::helpers::{extract_auth_method, is_public_route};
+use super::request_log::RequestUsage;
 
-impl Default for RateLimitMiddleware {
-    fn default() -> Self {
-        Self::new(60)
-    }
-}
+/// Enforces requests and tokens per minute of API keys and models
+///
+/// The model is read from the JSON request body. Responses carry
+/// `x-ratelimit-*` headers for the most restrictive limit; refused requests
+/// get a 429 with `retry-after`. Tokens are counted from the
+/// [`RequestUsage`] recorded by the handler, unless it is deferred. Requests
+/// pass through untouched when rate limiting is disabled.
+pub struct RateLimitMiddleware;
 
 impl<S, B> Transform<S, ServiceRequest> for RateLimitMiddleware
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -42,21 +42,19 @@ where
 
     fn new_transform(&self, service: S) -> Self::Future {
         ready(Ok(RateLimitMiddlewareService {
-            service,
-            requests_per_minute: self.requests_per_minute,
+            service: Rc::new(service),
         }))
     }
 }
 
 /// Service implementation for rate limit middleware
 pub struct RateLimitMiddlewareService<S> {
-    service: S,
-    requests_per_minute: u32,
+    service: Rc<S>,
 }
 
 impl<S, B> Service<ServiceRequest> for RateLimitMiddlewareService<S>
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -66,37 +64,146 @@ where
 
     forward_ready!(service);
 
-    fn call(&self, req: ServiceRequest) -> Self::Future {
-        let app_state = req.app_data::<web::Data<AppState>>().cloned();
-        let _requests_per_minute = self.requests_per_minute;
-        let start_time = Instant::now();
-        let path = req.path().to_string();
-        let method = req.method().to_string();
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let limiter = req
+            .app_data::<web::Data<AppState>>()
+            .and_then(|state| {
+                let max_body_size = state.config.load().server().max_body_size;
+                state
+                    .rate_limiter
+                    .clone()
+                    .map(|limiter| (limiter, max_body_size))
+            })
+            .filter(|_| !is_public_route(req.path()));
+        let Some((limiter, max_body_size)) = limiter else {
+            return Box::pin(self.service.call(req));
+        };
 
-        let fut = self.service.call(req);
+        let api_key = match extract_auth_method(req.headers()) {
+            AuthMethod::ApiKey(key) | AuthMethod::Jwt(key) => Some(key),
+            AuthMethod::Session(_) | AuthMethod::None => None,
+        };
 
+        let service = Rc::clone(&self.service);
         Box::pin(async move {
-            if let Some(_state) = &app_state {
-                // Rate limiting logic would go here
-                // For now, just log and pass through
-                debug!(
-                    "Rate limit check for {} {} - start: {:?}",
-                    method, path, start_time
-                );
+            let model = request_model(&mut req, max_body_size).await?;
+            let limits = limiter.acquire(api_key.as_deref(), model.as_deref()).await;
+            if let Some(exceeded) = &limits.exceeded {
+                let error = GatewayError::rate_limit(format!("Rate limit exceeded: {}", exceeded));
+                let mut response = error.error_response();
+                set_rate_limit_headers(response.headers_mut(), &limits);
+                if let Some(retry_after) = limits.retry_after_secs() {
+                    response
+                        .headers_mut()
+                        .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+                }
+                return Err(InternalError::from_response(error, response).into());
             }
 
-            let res = fut.await?;
+            let mut res = service.call(req).await?;
+            set_rate_limit_headers(res.headers_mut(), &limits);
 
-            let duration = start_time.elapsed();
-            info!(
-                "{} {} completed in {:?} with status {}",
-                method,
-                path,
-                duration,
-                res.status()
-            );
+            let usage = res
+                .request()
+                .extensions()
+                .get::<RequestUsage>()
+                .filter(|usage| !usage.deferred)
+                .cloned();
+            if let Some(usage) = usage {
+                let tokens = u64::from(usage.prompt_tokens) + u64::from(usage.completion_tokens);
+                let model = usage.model.or(model);
+                limiter
+                    .record_tokens(api_key.as_deref(), model.as_deref(), tokens)
+                    .await;
+            }
 
             Ok(res)
         })
     }
 }
+
+#[derive(Deserialize)]
+struct ModelField {
+    model: Option<String>,
+}
+
+/// Model named in a JSON request body
+///
+/// The body is buffered and put back for the handler.
+async fn request_model(
+    req: &mut ServiceRequest,
+    max_body_size: usize,
+) -> Result<Option<String>, actix_web::Error> {
+    if req.method() != Method::POST || req.content_type() != "application/json" {
+        return Ok(None);
+    }
+
+    let mut payload = req.take_payload();
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > max_body_size {
+            return Err(PayloadError::Overflow.into());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    let body = body.freeze();
+    let model = serde_json::from_slice::<ModelField>(&body)
+        .ok()
+        .and_then(|field| field.model);
+    req.set_payload(Payload::from(body));
+    Ok(model)
+}
+
+fn set_rate_limit_headers(headers: &mut HeaderMap, limits: &UsageLimitResult) {
+    if let Some(requests) = &limits.requests {
+        set_limit_headers(headers, "requests", requests);
+    }
+    if let Some(tokens) = &limits.tokens {
+        set_limit_headers(headers, "tokens", tokens);
+    }
+}
+
+fn set_limit_headers(headers: &mut HeaderMap, kind: &str, result: &RateLimitResult) {
+    let values = [
+        ("limit", result.limit.to_string()),
+        ("remaining", result.remaining.to_string()),
+        ("reset", format!("{}s", result.reset_after_secs)),
+    ];
+    for (name, value) in values {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::try_from(format!("x-ratelimit-{}-{}", name, kind)),
+            HeaderValue::try_from(value),
+        ) {
+            headers.insert(name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_rate_limit_headers() {
+        let limits = UsageLimitResult {
+            requests: Some(RateLimitResult {
+                allowed: true,
+                current_count: 3,
+                limit: 10,
+                remaining: 7,
+                reset_after_secs: 42,
+                retry_after_secs: None,
+            }),
+            ..Default::default()
+        };
+
+        let mut headers = HeaderMap::new();
+        set_rate_limit_headers(&mut headers, &limits);
+        assert_eq!(headers.get("x-ratelimit-limit-requests").unwrap(), "10");
+        assert_eq!(headers.get("x-ratelimit-remaining-requests").unwrap(), "7");
+        assert_eq!(headers.get("x-ratelimit-reset-requests").unwrap(), "42s");
+        assert!(headers.get("x-ratelimit-limit-tokens").is_none());
+    }
+}
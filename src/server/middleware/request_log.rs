@@ -0,0 +1,252 @@
+//! Request audit log middleware
+
+use crate::auth::AuthMethod;
+use crate::core::models::metrics::RequestLogEntry;
+use crate::server::state::AppState;
+use crate::utils::auth::crypto::keys::{extract_api_key_prefix, hash_api_key};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{HttpMessage, HttpRequest, web};
+use futures::future::{Ready, ready};
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Instant;
+use uuid::Uuid;
+
+use super::helpers::{extract_auth_method, is_public_route};
+
+/// Model call details recorded by a handler for the request log
+#[derive(Debug, Clone, Default)]
+pub struct RequestUsage {
+    /// Model requested
+    pub model: Option<String>,
+    /// Provider serving the model
+    pub provider: Option<String>,
+    /// Prompt tokens
+    pub prompt_tokens: u32,
+    /// Completion tokens
+    pub completion_tokens: u32,
+    /// Cost in USD
+    pub cost: f64,
+    /// Error message for failed calls
+    pub error: Option<String>,
+    /// Request payload
+    pub request_payload: Option<Value>,
+    /// Response payload
+    pub response_payload: Option<Value>,
+    /// Charged by the handler once the response completes, see
+    /// [`DeferredUsage`](super::DeferredUsage)
+    pub deferred: bool,
+}
+
+impl RequestUsage {
+    /// Usage of a call to a model, with the provider taken from a
+    /// `<provider>/<model>` name
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: Some(model.to_string()),
+            provider: model
+                .split_once('/')
+                .map(|(provider, _)| provider.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the provider
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Set token usage and cost
+    pub fn with_tokens(mut self, prompt_tokens: u32, completion_tokens: u32, cost: f64) -> Self {
+        self.prompt_tokens = prompt_tokens;
+        self.completion_tokens = completion_tokens;
+        self.cost = cost;
+        self
+    }
+
+    /// Set the error of a failed call
+    pub fn with_error(mut self, error: impl ToString) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    /// Set the request and response payloads
+    pub fn with_payloads(mut self, request: Option<Value>, response: Option<Value>) -> Self {
+        self.request_payload = request;
+        self.response_payload = response;
+        self
+    }
+
+    /// Mark as charged by the handler once the response completes
+    ///
+    /// The spend and rate limit middlewares skip deferred usage; it is only
+    /// logged.
+    pub fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+
+    /// Attach to a request, replacing usage recorded earlier
+    pub fn record(self, req: &HttpRequest) {
+        req.extensions_mut().insert(self);
+    }
+}
+
+/// Writes an audit log entry for every non-public request
+///
+/// Handlers add model, token and cost details by recording a
+/// [`RequestUsage`]; the middleware adds the request ID, masked API key,
+/// status and latency. Requests pass through untouched when request logging
+/// is disabled.
+pub struct RequestLogMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestLogMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = RequestLogMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestLogMiddlewareService { service }))
+    }
+}
+
+/// Service implementation for request log middleware
+pub struct RequestLogMiddlewareService<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestLogMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let logger = req
+            .app_data::<web::Data<AppState>>()
+            .and_then(|state| state.request_logs.clone())
+            .filter(|_| !is_public_route(req.path()));
+        let Some(logger) = logger else {
+            return Box::pin(self.service.call(req));
+        };
+
+        let start = Instant::now();
+        let created_at = chrono::Utc::now();
+        let request_id = ensure_request_id(&mut req);
+        let key_alias = key_alias(extract_auth_method(req.headers()));
+        let method = req.method().to_string();
+        let path = req.path().to_string();
+
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let result = fut.await;
+            let status = match &result {
+                Ok(res) => res.status(),
+                Err(e) => e.as_response_error().status_code(),
+            };
+            let usage = result
+                .as_ref()
+                .ok()
+                .and_then(|res| res.request().extensions().get::<RequestUsage>().cloned())
+                .unwrap_or_default();
+            let error = usage.error.or_else(|| match &result {
+                Err(e) => Some(e.to_string()),
+                Ok(_) if status.is_client_error() || status.is_server_error() => {
+                    status.canonical_reason().map(str::to_string)
+                }
+                Ok(_) => None,
+            });
+
+            logger.log(RequestLogEntry {
+                request_id,
+                key_alias,
+                method,
+                path,
+                model: usage.model,
+                provider: usage.provider,
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+                cost: usage.cost,
+                latency_ms: start.elapsed().as_millis() as u64,
+                status_code: status.as_u16(),
+                error,
+                request_payload: usage.request_payload,
+                response_payload: usage.response_payload,
+                created_at,
+            });
+
+            result
+        })
+    }
+}
+
+/// Request ID from the `x-request-id` header, set to a new ID if missing
+///
+/// Handlers read the same header, so their request context shares the ID.
+fn ensure_request_id(req: &mut ServiceRequest) -> String {
+    let header = HeaderName::from_static("x-request-id");
+    if let Some(id) = req.headers().get(&header).and_then(|v| v.to_str().ok()) {
+        return id.to_string();
+    }
+
+    let id = Uuid::new_v4().to_string();
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        req.headers_mut().insert(header, value);
+    }
+    id
+}
+
+/// Masked credential a request was made with
+pub(super) fn key_alias(auth_method: AuthMethod) -> Option<String> {
+    match auth_method {
+        AuthMethod::ApiKey(key) | AuthMethod::Jwt(key) => Some(extract_api_key_prefix(&key)),
+        AuthMethod::Session(_) | AuthMethod::None => None,
+    }
+}
+
+/// SHA-256 hash of the credential a request was made with
+///
+/// Unlike the masked key, it tells keys with the same prefix and suffix
+/// apart, so budgets are kept by it.
+pub(super) fn key_hash(auth_method: &AuthMethod) -> Option<String> {
+    match auth_method {
+        AuthMethod::ApiKey(key) | AuthMethod::Jwt(key) => Some(hash_api_key(key)),
+        AuthMethod::Session(_) | AuthMethod::None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_usage_provider_from_model() {
+        let usage = RequestUsage::new("anthropic/claude-3-5-sonnet").with_tokens(10, 5, 0.01);
+        assert_eq!(usage.provider.as_deref(), Some("anthropic"));
+        assert_eq!(usage.prompt_tokens, 10);
+        assert!(RequestUsage::new("gpt-4o").provider.is_none());
+    }
+
+    #[test]
+    fn test_key_alias_masks_key() {
+        let alias = key_alias(AuthMethod::ApiKey("gw-1234567890abcdef".to_string()));
+        assert_eq!(alias.as_deref(), Some("gw-1...cdef"));
+        assert!(key_alias(AuthMethod::None).is_none());
+    }
+}
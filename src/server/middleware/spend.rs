@@ -0,0 +1,184 @@
+//! Spend tracking middleware
+
+use crate::auth::AuthMethod;
+use crate::core::models::RequestContext;
+use crate::core::rate_limiter::UsageLimiter;
+use crate::server::state::AppState;
+use crate::services::spend::{SpendEvent, SpendTracker};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::{HttpMessage, HttpRequest, web};
+use futures::future::{Ready, ready};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use super::helpers::{extract_auth_method, is_public_route};
+use super::request_log::{RequestUsage, key_alias, key_hash};
+
+/// Charges successful model calls to the spend tracker
+///
+/// Handlers report the model and tokens by recording a [`RequestUsage`]; the
+/// middleware adds the masked and hashed API key and, when an authentication
+/// layer has attached a [`RequestContext`], the team and user. Deferred usage
+/// is charged by its handler instead. Requests pass through untouched when
+/// spend tracking is disabled.
+pub struct SpendMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for SpendMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = SpendMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(SpendMiddlewareService { service }))
+    }
+}
+
+/// Service implementation for spend middleware
+pub struct SpendMiddlewareService<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for SpendMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let tracker = req
+            .app_data::<web::Data<AppState>>()
+            .and_then(|state| state.spend.clone())
+            .filter(|_| !is_public_route(req.path()));
+        let Some(tracker) = tracker else {
+            return Box::pin(self.service.call(req));
+        };
+
+        let timestamp = chrono::Utc::now();
+        let auth_method = extract_auth_method(req.headers());
+        let key_hash = key_hash(&auth_method);
+        let api_key = key_alias(auth_method);
+
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let res = fut.await?;
+            if !res.status().is_success() {
+                return Ok(res);
+            }
+
+            if let Some(event) = spend_event(&res, api_key, key_hash, timestamp) {
+                tracker.record(event);
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// Spend of a call as recorded by its handler, unless it failed
+fn spend_event<B>(
+    res: &ServiceResponse<B>,
+    api_key: Option<String>,
+    key_hash: Option<String>,
+    timestamp: chrono::DateTime<chrono::Utc>,
+) -> Option<SpendEvent> {
+    let extensions = res.request().extensions();
+    let usage = extensions.get::<RequestUsage>()?;
+    if usage.error.is_some() || usage.deferred {
+        return None;
+    }
+
+    let context = extensions.get::<RequestContext>();
+    Some(SpendEvent {
+        api_key,
+        key_hash,
+        team_id: context.and_then(|c| c.team_id).map(|id| id.to_string()),
+        user_id: context.and_then(|c| c.user_id).map(|id| id.to_string()),
+        model: usage.model.clone()?,
+        provider: usage.provider.clone(),
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        cost: usage.cost,
+        timestamp,
+    })
+}
+
+/// Charges usage known only after the middlewares have run
+///
+/// Streamed completions and realtime sessions only know their tokens once
+/// they end. Their handlers capture the caller with [`new`](Self::new),
+/// record a [`deferred`](RequestUsage::deferred) usage for the request log
+/// and [`charge`](Self::charge) the final usage: to the spend tracker, and
+/// through it the budgets, and against the caller's token limits.
+#[derive(Debug, Clone)]
+pub struct DeferredUsage {
+    spend: Option<Arc<SpendTracker>>,
+    rate_limiter: Option<Arc<UsageLimiter>>,
+    api_key: Option<String>,
+    key_alias: Option<String>,
+    key_hash: Option<String>,
+    team_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeferredUsage {
+    /// Capture the caller of a request
+    pub fn new(state: &AppState, req: &HttpRequest) -> Self {
+        let auth_method = extract_auth_method(req.headers());
+        let api_key = match &auth_method {
+            AuthMethod::ApiKey(key) | AuthMethod::Jwt(key) => Some(key.clone()),
+            AuthMethod::Session(_) | AuthMethod::None => None,
+        };
+        let key_hash = key_hash(&auth_method);
+        let extensions = req.extensions();
+        let context = extensions.get::<RequestContext>();
+
+        Self {
+            spend: state.spend.clone(),
+            rate_limiter: state.rate_limiter.clone(),
+            api_key,
+            key_alias: key_alias(auth_method),
+            key_hash,
+            team_id: context.and_then(|c| c.team_id).map(|id| id.to_string()),
+            user_id: context.and_then(|c| c.user_id).map(|id| id.to_string()),
+        }
+    }
+
+    /// Charge the final usage of the request
+    pub async fn charge(&self, usage: &RequestUsage) {
+        if let Some(limiter) = &self.rate_limiter {
+            let tokens = u64::from(usage.prompt_tokens) + u64::from(usage.completion_tokens);
+            limiter
+                .record_tokens(self.api_key.as_deref(), usage.model.as_deref(), tokens)
+                .await;
+        }
+
+        if let (Some(spend), Some(model)) = (&self.spend, &usage.model) {
+            spend.record(SpendEvent {
+                api_key: self.key_alias.clone(),
+                key_hash: self.key_hash.clone(),
+                team_id: self.team_id.clone(),
+                user_id: self.user_id.clone(),
+                model: model.clone(),
+                provider: usage.provider.clone(),
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+                cost: usage.cost,
+                timestamp: chrono::Utc::now(),
+            });
+        }
+    }
+}
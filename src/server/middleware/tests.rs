@@ -17,6 +17,18 @@ fn test_extract_auth_method_bearer() {
     assert!(matches!(auth_method, AuthMethod::Jwt(token) if token == "token123"));
 }
 
+#[test]
+fn test_extract_auth_method_bearer_api_key() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("authorization"),
+        HeaderValue::from_static("Bearer gw-key123"),
+    );
+
+    let auth_method = extract_auth_method(&headers);
+    assert!(matches!(auth_method, AuthMethod::ApiKey(key) if key == "gw-key123"));
+}
+
 #[test]
 fn test_extract_auth_method_api_key() {
     let mut headers = HeaderMap::new();
@@ -41,6 +53,18 @@ fn test_extract_auth_method_x_api_key() {
     assert!(matches!(auth_method, AuthMethod::ApiKey(key) if key == "key123"));
 }
 
+#[test]
+fn test_extract_auth_method_websocket_subprotocol() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("sec-websocket-protocol"),
+        HeaderValue::from_static("realtime, openai-insecure-api-key.gw-key123"),
+    );
+
+    let auth_method = extract_auth_method(&headers);
+    assert!(matches!(auth_method, AuthMethod::ApiKey(key) if key == "gw-key123"));
+}
+
 #[test]
 fn test_extract_auth_method_session() {
     let mut headers = HeaderMap::new();
@@ -73,6 +97,7 @@ fn test_is_public_route() {
 fn test_is_admin_route() {
     assert!(is_admin_route("/admin/users"));
     assert!(is_admin_route("/api/admin/config"));
+    assert!(is_admin_route("/spend/daily"));
     assert!(!is_admin_route("/api/users"));
     assert!(!is_admin_route("/health"));
 }
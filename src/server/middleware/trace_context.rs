@@ -0,0 +1,78 @@
+//! Trace context middleware
+
+use crate::core::observability::{TRACEPARENT_FIELD, continue_trace};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use futures::future::{Ready, ready};
+use std::future::Future;
+use std::pin::Pin;
+use tracing::Instrument;
+use tracing::field::Empty;
+
+/// Trace context middleware for Actix-web
+///
+/// Runs each request in an `http.request` span that continues the trace of
+/// an incoming W3C `traceparent` header, so handler, provider and storage
+/// spans share one trace.
+pub struct TraceContextMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for TraceContextMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = TraceContextMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(TraceContextMiddlewareService { service }))
+    }
+}
+
+/// Service implementation for trace context middleware
+pub struct TraceContextMiddlewareService<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for TraceContextMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let span = tracing::info_span!(
+            "http.request",
+            otel.kind = "server",
+            http.method = %req.method(),
+            http.target = %req.path(),
+            http.status_code = Empty,
+        );
+        if let Some(traceparent) = req
+            .headers()
+            .get(TRACEPARENT_FIELD)
+            .and_then(|value| value.to_str().ok())
+        {
+            continue_trace(&span, traceparent);
+        }
+
+        let fut = span.in_scope(|| self.service.call(req));
+        Box::pin(
+            async move {
+                let res = fut.await?;
+                tracing::Span::current().record("http.status_code", res.status().as_u16());
+                Ok(res)
+            }
+            .instrument(span),
+        )
+    }
+}
@@ -8,7 +8,7 @@ pub mod routes;
 
 // New modular server components
 pub mod builder;
-mod handlers;
+pub mod reload;
 pub mod server;
 pub mod state;
 pub mod types;
@@ -0,0 +1,456 @@
+//! Configuration hot reload
+//!
+//! The configuration is reloaded when it changes, on SIGHUP, on
+//! `POST /admin/config/reload` and when a refreshed secret changes. The
+//! configuration and every file it includes are checked, files by their
+//! modification time and remote configurations by their ETag. Reloads
+//! apply providers and their model lists, router settings and deployments,
+//! and budgets; other settings are read once at startup and still need a
+//! restart. Providers configured without an API key take the key stored for
+//! them with `PUT /admin/credentials/{provider}`, if encryption is enabled.
+//!
+//! The new configuration is swapped in atomically, so requests in flight
+//! finish with the providers and settings they started with.
+
+use crate::config::secrets::SecretResolver;
+use crate::config::{Config, ConfigLocation, remote, source};
+use crate::core::providers::ProviderRegistry;
+use crate::core::router::UnifiedRouter;
+use crate::server::server::{provider_registry, unified_router};
+use crate::services::alerting::AlertingService;
+use crate::services::spend::SpendTracker;
+use crate::storage::database::Database;
+use crate::storage::redis::RedisPool;
+use crate::utils::error::{GatewayError, Result};
+use arc_swap::ArcSwap;
+use serde::Serialize;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime};
+use tracing::{debug, info, warn};
+
+/// What a reload applied
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ReloadReport {
+    /// Providers registered
+    pub providers: usize,
+    /// Budgets enforced
+    pub budgets: usize,
+}
+
+/// Reloads the configuration into the running gateway
+pub struct ConfigReloader {
+    config: Arc<ArcSwap<Config>>,
+    router: Arc<ArcSwap<ProviderRegistry>>,
+    unified_router: Arc<ArcSwap<UnifiedRouter>>,
+    /// Redis sharing deployment usage, if configured
+    redis: Option<Arc<RedisPool>>,
+    spend: Option<Arc<SpendTracker>>,
+    /// Alerting on the router's cooldowns, if enabled
+    alerting: Option<Arc<AlertingService>>,
+    secrets: Arc<SecretResolver>,
+    /// Database filling providers' stored API keys, if encryption is enabled
+    credentials: Option<Arc<Database>>,
+    /// Configuration file or remote configuration, once watched
+    location: OnceLock<ConfigLocation>,
+    /// Held while a reload runs
+    reloading: tokio::sync::Mutex<()>,
+}
+
+impl ConfigReloader {
+    /// Create a reloader swapping the given configuration, providers and
+    /// router
+    pub fn new(
+        config: Arc<ArcSwap<Config>>,
+        router: Arc<ArcSwap<ProviderRegistry>>,
+        unified_router: Arc<ArcSwap<UnifiedRouter>>,
+        redis: Option<Arc<RedisPool>>,
+        spend: Option<Arc<SpendTracker>>,
+        alerting: Option<Arc<AlertingService>>,
+        secrets: Arc<SecretResolver>,
+    ) -> Self {
+        Self {
+            config,
+            router,
+            unified_router,
+            redis,
+            spend,
+            alerting,
+            secrets,
+            credentials: None,
+            location: OnceLock::new(),
+            reloading: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Fill providers configured without an API key from their keys stored
+    /// in `database`
+    pub fn with_credentials(mut self, database: Option<Arc<Database>>) -> Self {
+        self.credentials = database;
+        self
+    }
+
+    /// Reload from `location` when it changes, on SIGHUP and when a secret
+    /// changes
+    ///
+    /// The configuration is checked every `watch_interval` seconds; 0
+    /// disables checking. Only the first call has an effect.
+    pub fn watch(self: &Arc<Self>, location: impl Into<ConfigLocation>, watch_interval: u64) {
+        let location = location.into();
+        if self.location.set(location.clone()).is_err() {
+            return;
+        }
+
+        if watch_interval > 0 {
+            let reloader = Arc::clone(self);
+            tokio::spawn(async move {
+                let mut watched = Watched::new(&location).await;
+                let mut interval = tokio::time::interval(Duration::from_secs(watch_interval));
+                loop {
+                    interval.tick().await;
+                    if watched.changed().await {
+                        reloader.reload_logged("configuration changed").await;
+                        watched.update_sources(&location).await;
+                    }
+                }
+            });
+        }
+
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+            match signal(SignalKind::hangup()) {
+                Ok(mut hangups) => {
+                    let reloader = Arc::clone(self);
+                    tokio::spawn(async move {
+                        while hangups.recv().await.is_some() {
+                            reloader.reload_logged("SIGHUP").await;
+                        }
+                    });
+                }
+                Err(e) => warn!("Failed to listen for SIGHUP: {}", e),
+            }
+        }
+
+        let reloader = Arc::clone(self);
+        let mut changes = self.secrets.subscribe();
+        tokio::spawn(async move {
+            while changes.changed().await.is_ok() {
+                reloader.reload_logged("secret changed").await;
+            }
+        });
+    }
+
+    /// Reload the configuration now
+    ///
+    /// An invalid configuration leaves the running configuration unchanged.
+    pub async fn reload(&self) -> Result<ReloadReport> {
+        let location = self.location.get().ok_or_else(|| {
+            GatewayError::Config("The configuration was not loaded from a file or URL".to_string())
+        })?;
+        let _reloading = self.reloading.lock().await;
+
+        let mut loaded = Config::from_location(location).await?;
+        self.secrets
+            .resolve_providers(&mut loaded.gateway.providers)
+            .await?;
+        if let Some(database) = &self.credentials {
+            database
+                .fill_provider_credentials(&mut loaded.gateway.providers)
+                .await?;
+        }
+
+        let mut config = Config::clone(&self.config.load());
+        config.gateway.providers = loaded.gateway.providers;
+        config.gateway.router = loaded.gateway.router;
+        config.gateway.storage.spend.budgets = loaded.gateway.storage.spend.budgets;
+
+        let router = provider_registry(&config.gateway.providers).await;
+        let unified_router = Arc::new(unified_router(&config, self.redis.clone()).await);
+        let budgets = &config.gateway.storage.spend.budgets;
+        if let Some(spend) = &self.spend {
+            spend.replace_budgets(budgets).await?;
+        }
+
+        let report = ReloadReport {
+            providers: router.len(),
+            budgets: self.spend.as_ref().map_or(0, |_| budgets.len()),
+        };
+        self.router.store(Arc::new(router));
+        // Deployments start over, out of cooldown
+        unified_router.start_probe_task();
+        if let Some(alerting) = &self.alerting {
+            alerting.watch_cooldowns(&unified_router);
+        }
+        self.unified_router.store(unified_router);
+        self.config.store(Arc::new(config));
+        Ok(report)
+    }
+
+    async fn reload_logged(&self, reason: &str) {
+        match self.reload().await {
+            Ok(report) => info!(
+                "Configuration reloaded ({}): {} providers, {} budgets",
+                reason, report.providers, report.budgets
+            ),
+            Err(e) => warn!("Configuration reload ({}) failed: {}", reason, e),
+        }
+    }
+}
+
+/// A configuration and the files it includes, with their last versions
+struct Watched {
+    versions: Vec<(ConfigLocation, Option<Version>)>,
+}
+
+impl Watched {
+    /// Watch a configuration and the files it includes
+    async fn new(location: &ConfigLocation) -> Self {
+        let mut watched = Self {
+            versions: Vec::new(),
+        };
+        watched.update_sources(location).await;
+        watched
+    }
+
+    /// Resolve the files the configuration includes again, as a change may
+    /// have added or removed some
+    ///
+    /// The watched files are kept while the configuration cannot be loaded,
+    /// so fixing a broken include is still noticed.
+    async fn update_sources(&mut self, location: &ConfigLocation) {
+        let sources = match source::sources(location).await {
+            Ok(sources) => sources,
+            Err(e) => {
+                debug!("Configuration includes not resolved: {}", e);
+                if !self.versions.is_empty() {
+                    return;
+                }
+                vec![location.clone()]
+            }
+        };
+
+        let mut versions = Vec::with_capacity(sources.len());
+        for source in sources {
+            let last = self
+                .versions
+                .iter()
+                .find(|(watched, _)| *watched == source)
+                .map(|(_, last)| last.clone());
+            let current = match last {
+                Some(last) => last,
+                None => version(&source, None).await,
+            };
+            versions.push((source, current));
+        }
+        self.versions = versions;
+    }
+
+    /// Whether any watched location changed since the last check
+    async fn changed(&mut self) -> bool {
+        let mut changed = false;
+        for (location, last) in &mut self.versions {
+            let current = version(location, last.as_ref()).await;
+            if current.is_some() && current != *last {
+                *last = current;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// Version of a configuration, compared to detect changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Version {
+    /// Modification time of a file
+    Modified(SystemTime),
+    /// ETag of a remote configuration
+    ETag(String),
+    /// Hash of a remote configuration served without an ETag
+    Content(u64),
+}
+
+/// Current version of a configuration, if it can be read
+///
+/// Remote configurations are only downloaded when their ETag differs from
+/// the `last` version's.
+async fn version(location: &ConfigLocation, last: Option<&Version>) -> Option<Version> {
+    if let ConfigLocation::File(path) = location {
+        return modified(path).await.map(Version::Modified);
+    }
+
+    let etag = match last {
+        Some(Version::ETag(etag)) => Some(etag.as_str()),
+        _ => None,
+    };
+    match remote::fetch(location, etag).await {
+        Ok(Some(fetched)) => Some(match fetched.etag {
+            Some(etag) => Version::ETag(etag),
+            None => {
+                use std::hash::{DefaultHasher, Hash, Hasher};
+                let mut hasher = DefaultHasher::new();
+                fetched.content.hash(&mut hasher);
+                Version::Content(hasher.finish())
+            }
+        }),
+        Ok(None) => last.cloned(),
+        Err(e) => {
+            debug!("Configuration check failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Modification time of a file, if it can be read
+async fn modified(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SecretsConfig;
+
+    fn config_file(models: &str) -> String {
+        format!(
+            r#"
+server:
+  port: 8080
+
+providers:
+  - name: "openai"
+    provider_type: "openai"
+    api_key: "sk-test-key"
+    models: [{}]
+
+router:
+  model_aliases:
+    default: "gpt-4o"
+
+storage:
+  database:
+    url: "sqlite::memory:"
+  redis:
+    url: "redis://localhost:6379"
+
+auth:
+  jwt_secret: "test-secret-that-is-at-least-32-characters-long-for-security"
+
+monitoring:
+  metrics:
+    enabled: false
+"#,
+            models
+        )
+    }
+
+    fn reloader() -> ConfigReloader {
+        ConfigReloader::new(
+            Arc::new(ArcSwap::from_pointee(Config::default())),
+            Arc::new(ArcSwap::from_pointee(ProviderRegistry::new())),
+            Arc::new(ArcSwap::from_pointee(
+                UnifiedRouter::new(Default::default()),
+            )),
+            None,
+            None,
+            None,
+            Arc::new(SecretResolver::new(&SecretsConfig::default())),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_reload_applies_providers_and_router_settings() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        std::fs::write(&path, config_file(r#""gpt-4o", "gpt-4o-mini""#)).unwrap();
+
+        let reloader = reloader();
+        reloader.location.set(path.clone().into()).unwrap();
+        let report = reloader.reload().await.unwrap();
+        assert_eq!(report.providers, 1);
+        assert_eq!(report.budgets, 0);
+        assert_eq!(reloader.router.load().len(), 1);
+        let router = reloader.unified_router.load_full();
+        assert!(router.serves("gpt-4o-mini"));
+        assert!(router.serves("default"));
+
+        let config = reloader.config.load_full();
+        assert_eq!(config.providers()[0].models.len(), 2);
+        assert_eq!(config.router().model_aliases["default"], "gpt-4o");
+        // Settings read at startup are kept
+        assert_eq!(
+            config.gateway.storage.database.url,
+            Config::default().gateway.storage.database.url
+        );
+
+        // An invalid file leaves the configuration in place
+        std::fs::write(&path, "providers: [").unwrap();
+        assert!(reloader.reload().await.is_err());
+        assert_eq!(reloader.config.load().providers()[0].models.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_remote_version_uses_etag() {
+        use wiremock::matchers::header;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(200).insert_header("etag", "\"v1\""))
+            .mount(&server)
+            .await;
+
+        let location = ConfigLocation::parse(&format!("{}/gateway.yaml", server.uri())).unwrap();
+        let first = version(&location, None).await;
+        assert_eq!(first, Some(Version::ETag("\"v1\"".to_string())));
+        assert_eq!(version(&location, first.as_ref()).await, first);
+    }
+
+    #[tokio::test]
+    async fn test_watch_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        let included = dir.path().join("providers.yaml");
+        std::fs::write(&path, "include: providers.yaml\n").unwrap();
+        std::fs::write(&included, "providers: []\n").unwrap();
+
+        let location = ConfigLocation::File(path.clone());
+        let mut watched = Watched::new(&location).await;
+        assert_eq!(watched.versions.len(), 2);
+        assert!(!watched.changed().await);
+
+        // A change to an included file is noticed
+        let touch = |path: &Path, secs: u64| {
+            std::fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+                .unwrap();
+        };
+        touch(&included, 1_000);
+        assert!(watched.changed().await);
+        assert!(!watched.changed().await);
+
+        // Files included after a change are watched too
+        let added = dir.path().join("router.yaml");
+        std::fs::write(&added, "router: {}\n").unwrap();
+        std::fs::write(&path, "include: [providers.yaml, router.yaml]\n").unwrap();
+        touch(&path, 1_500);
+        assert!(watched.changed().await);
+        watched.update_sources(&location).await;
+        assert_eq!(watched.versions.len(), 3);
+        touch(&added, 2_000);
+        assert!(watched.changed().await);
+    }
+
+    #[tokio::test]
+    async fn test_reload_without_file() {
+        assert!(reloader().reload().await.is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! Access control for authenticated and administrative endpoints
+//!
+//! The authentication middlewares attach the caller's identity to the
+//! request: the OIDC identity, or the user and API key a key, token or
+//! session authenticated as. Handlers that lend the gateway's credentials
+//! require one of them, and administrative handlers require one of them to
+//! be an admin.
+//!
+//! Objects created with the gateway's credentials, such as files and
+//! fine-tuning jobs, are owned by the tenant that created them: the caller's
+//! team, or else its API key or identity. [`Owners`] records them so other
+//! tenants can neither list nor reach them.
+
+use crate::auth::oidc::OidcIdentity;
+use crate::auth::rbac::RbacSystem;
+use crate::core::models::ApiKey;
+use crate::core::models::user::types::User;
+use crate::storage::cache::CacheBackend;
+use crate::utils::error::{GatewayError, Result};
+use actix_web::{HttpMessage, HttpRequest};
+
+/// Permission granting administrative access to an API key
+const ADMIN_PERMISSION: &str = "system.admin";
+
+/// Require the caller to be authenticated
+///
+/// Fails with an authentication error when no middleware attached an
+/// identity to the request. Credentials are not checked again here: the
+/// middlewares already rejected invalid ones.
+pub fn require_authenticated(req: &HttpRequest) -> Result<()> {
+    let extensions = req.extensions();
+    if extensions.contains::<OidcIdentity>()
+        || extensions.contains::<User>()
+        || extensions.contains::<ApiKey>()
+    {
+        return Ok(());
+    }
+    Err(GatewayError::unauthorized("Authentication required"))
+}
+
+/// Require the caller to be authenticated, returning the tenant it acts for
+///
+/// Callers of a team act for their team: an API key's team, or the team of
+/// an OIDC identity. Others act for themselves, by API key, then OIDC
+/// subject, then user.
+pub fn require_tenant(req: &HttpRequest) -> Result<String> {
+    let extensions = req.extensions();
+    let identity = extensions.get::<OidcIdentity>();
+    let api_key = extensions.get::<ApiKey>();
+    let team_id = api_key
+        .and_then(|api_key| api_key.team_id)
+        .or_else(|| identity.and_then(|identity| identity.team_id));
+    if let Some(team_id) = team_id {
+        return Ok(format!("team:{}", team_id));
+    }
+    if let Some(api_key) = api_key {
+        return Ok(format!("key:{}", api_key.metadata.id));
+    }
+    if let Some(identity) = identity {
+        return Ok(format!("oidc:{}", identity.subject));
+    }
+    if let Some(user) = extensions.get::<User>() {
+        return Ok(format!("user:{}", user.metadata.id));
+    }
+    Err(GatewayError::unauthorized("Authentication required"))
+}
+
+/// Owners of one kind of object created through the gateway
+///
+/// Owners are kept in the key-value cache, so instances sharing Redis share
+/// them. Objects without a recorded owner, e.g. created directly with the
+/// provider, are owned by no tenant.
+pub struct Owners<'a> {
+    cache: &'a dyn CacheBackend,
+    kind: &'static str,
+}
+
+impl<'a> Owners<'a> {
+    /// Owners of objects of a kind, e.g. "file"
+    pub fn new(cache: &'a dyn CacheBackend, kind: &'static str) -> Self {
+        Self { cache, kind }
+    }
+
+    fn key(&self, id: &str) -> String {
+        format!("owner:{}:{}", self.kind, id)
+    }
+
+    /// Record the tenant owning an object
+    pub async fn record(&self, id: &str, tenant: &str) -> Result<()> {
+        self.cache.set(&self.key(id), tenant, None).await
+    }
+
+    /// Whether a tenant owns an object
+    pub async fn owns(&self, id: &str, tenant: &str) -> Result<bool> {
+        Ok(self.cache.get(&self.key(id)).await?.as_deref() == Some(tenant))
+    }
+
+    /// Require a tenant to own an object
+    ///
+    /// Fails with a not-found error otherwise, so other tenants' objects are
+    /// indistinguishable from missing ones.
+    pub async fn require(&self, id: &str, tenant: &str) -> Result<()> {
+        if self.owns(id, tenant).await? {
+            return Ok(());
+        }
+        Err(GatewayError::not_found(format!(
+            "No such {}: {}",
+            self.kind, id
+        )))
+    }
+
+    /// Forget the owner of a deleted object
+    pub async fn forget(&self, id: &str) -> Result<()> {
+        self.cache.delete(&self.key(id)).await
+    }
+}
+
+/// Require the caller to be an admin
+///
+/// Fails with an authentication error when the request carries no identity,
+/// and an authorization error when its identity is not an admin: an OIDC
+/// identity without an admin role, a user without one, or an API key
+/// without the `system.admin` permission.
+pub fn require_admin(req: &HttpRequest, rbac: &RbacSystem) -> Result<()> {
+    let extensions = req.extensions();
+    let identity = extensions.get::<OidcIdentity>();
+    let user = extensions.get::<User>();
+    let api_key = extensions.get::<ApiKey>();
+    if identity.is_none() && user.is_none() && api_key.is_none() {
+        return Err(GatewayError::unauthorized("Authentication required"));
+    }
+
+    let is_admin = identity.is_some_and(|identity| rbac.has_admin_role(&identity.roles))
+        || user.is_some_and(|user| rbac.is_admin(user))
+        || api_key.is_some_and(|api_key| {
+            rbac.check_permissions(&api_key.permissions, &[ADMIN_PERMISSION.to_string()])
+        });
+    if !is_admin {
+        return Err(GatewayError::forbidden("Admin access required"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RbacConfig;
+    use crate::core::models::{Metadata, UsageStats};
+    use crate::storage::cache::MemoryCache;
+    use actix_web::test::TestRequest;
+
+    async fn rbac() -> RbacSystem {
+        let config = RbacConfig {
+            enabled: true,
+            default_role: "user".to_string(),
+            admin_roles: vec!["admin".to_string()],
+        };
+        RbacSystem::new(&config).await.unwrap()
+    }
+
+    fn api_key(permissions: &[&str]) -> ApiKey {
+        ApiKey {
+            metadata: Metadata::new(),
+            name: "Test Key".to_string(),
+            key_hash: "hash".to_string(),
+            key_prefix: "gw-test".to_string(),
+            user_id: None,
+            team_id: None,
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+            rate_limits: None,
+            expires_at: None,
+            is_active: true,
+            last_used_at: None,
+            usage_stats: UsageStats::default(),
+        }
+    }
+
+    #[test]
+    fn test_require_authenticated() {
+        let req = TestRequest::default().to_http_request();
+        let err = require_authenticated(&req).unwrap_err();
+        assert!(matches!(err, GatewayError::Unauthorized(_)));
+
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(OidcIdentity {
+            subject: "user-1".to_string(),
+            user_id: None,
+            team: None,
+            team_id: None,
+            roles: Vec::new(),
+            permissions: Vec::new(),
+        });
+        assert!(require_authenticated(&req).is_ok());
+
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(api_key(&["api.chat"]));
+        assert!(require_authenticated(&req).is_ok());
+    }
+
+    fn identity(subject: &str, team_id: Option<uuid::Uuid>) -> OidcIdentity {
+        OidcIdentity {
+            subject: subject.to_string(),
+            user_id: None,
+            team: None,
+            team_id,
+            roles: Vec::new(),
+            permissions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_require_tenant() {
+        let req = TestRequest::default().to_http_request();
+        let err = require_tenant(&req).unwrap_err();
+        assert!(matches!(err, GatewayError::Unauthorized(_)));
+
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(identity("alice", None));
+        assert_eq!(require_tenant(&req).unwrap(), "oidc:alice");
+
+        let team_id = uuid::Uuid::new_v4();
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut()
+            .insert(identity("alice", Some(team_id)));
+        assert_eq!(require_tenant(&req).unwrap(), format!("team:{}", team_id));
+
+        let key = api_key(&["api.chat"]);
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(key.clone());
+        assert_eq!(
+            require_tenant(&req).unwrap(),
+            format!("key:{}", key.metadata.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_owners() {
+        let cache = MemoryCache::new();
+        let files = Owners::new(&cache, "file");
+        files.record("file-1", "key:a").await.unwrap();
+
+        assert!(files.owns("file-1", "key:a").await.unwrap());
+        assert!(!files.owns("file-1", "key:b").await.unwrap());
+        assert!(!files.owns("file-2", "key:a").await.unwrap());
+        let err = files.require("file-1", "key:b").await.unwrap_err();
+        assert!(matches!(err, GatewayError::NotFound(_)));
+
+        // Kinds are kept apart
+        let jobs = Owners::new(&cache, "fine_tuning.job");
+        assert!(!jobs.owns("file-1", "key:a").await.unwrap());
+
+        files.forget("file-1").await.unwrap();
+        assert!(!files.owns("file-1", "key:a").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_require_admin_without_identity() {
+        let req = TestRequest::default().to_http_request();
+        let err = require_admin(&req, &rbac().await).unwrap_err();
+        assert!(matches!(err, GatewayError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn test_require_admin_api_key_permission() {
+        let rbac = rbac().await;
+
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(api_key(&["api.chat"]));
+        let err = require_admin(&req, &rbac).unwrap_err();
+        assert!(matches!(err, GatewayError::Forbidden(_)));
+
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(api_key(&["system.admin"]));
+        assert!(require_admin(&req, &rbac).is_ok());
+    }
+}
@@ -0,0 +1,427 @@
+//! Assistants API endpoints
+//!
+//! `/v1/assistants` and `/v1/threads` (messages, runs, run steps) are passed
+//! through to OpenAI, or to the provider selected by `custom_llm_provider`
+//! (e.g. "azure"), with the gateway's credentials, for authenticated
+//! clients. Token usage of finished runs is recorded once per run.
+//!
+//! Assistants and threads belong to the tenant that created them: other
+//! tenants can neither list them nor reach them or their messages and runs.
+
+use crate::core::providers::base::HttpMethod;
+use crate::server::middleware::RequestUsage;
+use crate::server::routes::access::{Owners, require_tenant};
+use crate::server::routes::errors;
+use crate::server::state::AppState;
+use crate::storage::cache::CacheBackend;
+use crate::utils::error::{GatewayError, Result};
+use actix_web::http::Method;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use lru::LruCache;
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::num::NonZeroUsize;
+use tracing::{error, info};
+
+/// Run statuses after which a run no longer consumes tokens
+const TERMINAL_RUN_STATUSES: &[&str] =
+    &["completed", "failed", "cancelled", "expired", "incomplete"];
+
+/// Kind under which the owners of assistants are recorded
+const ASSISTANT: &str = "assistant";
+
+/// Kind under which the owners of threads are recorded
+const THREAD: &str = "thread";
+
+/// Finished runs remembered by default
+const RECORDED_RUNS_CAPACITY: usize = 10_000;
+
+/// Runs whose usage has been recorded, so polling does not record them twice
+#[derive(Debug)]
+pub struct RecordedRuns {
+    runs: Mutex<LruCache<String, ()>>,
+}
+
+impl RecordedRuns {
+    /// Create a tracker remembering the last `capacity` runs
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            runs: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Remember a run, returning whether it was not recorded before
+    pub fn insert(&self, run_id: String) -> bool {
+        self.runs.lock().put(run_id, ()).is_none()
+    }
+}
+
+impl Default for RecordedRuns {
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(RECORDED_RUNS_CAPACITY).expect("capacity is non-zero"))
+    }
+}
+
+/// Assistants API passthrough endpoint
+///
+/// Handles every method and sub-path of `/v1/assistants` and `/v1/threads`.
+pub async fn assistants_passthrough(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> ActixResult<HttpResponse> {
+    let path = req.path().trim_start_matches("/v1/").to_string();
+    info!("Assistants API request: {} {}", req.method(), path);
+
+    // Requests are made with the gateway's credentials, so only for callers
+    // the authentication middlewares identified
+    let tenant = match require_tenant(&req) {
+        Ok(tenant) => tenant,
+        Err(e) => return Ok(errors::gateway_error_to_response(e)),
+    };
+
+    let method = match *req.method() {
+        Method::GET => HttpMethod::GET,
+        Method::POST => HttpMethod::POST,
+        Method::DELETE => HttpMethod::DELETE,
+        _ => return Ok(HttpResponse::MethodNotAllowed().finish()),
+    };
+
+    let body: Option<Value> = if body.is_empty() {
+        None
+    } else {
+        match serde_json::from_slice(&body) {
+            Ok(body) => Some(body),
+            Err(e) => {
+                return Ok(errors::validation_error(&format!(
+                    "Invalid JSON body: {}",
+                    e
+                )));
+            }
+        }
+    };
+    if body
+        .as_ref()
+        .and_then(|body| body["stream"].as_bool())
+        .unwrap_or(false)
+    {
+        return Ok(errors::validation_error(
+            "Streaming runs are not supported by the gateway",
+        ));
+    }
+
+    // The caller must own the assistant or thread addressed, and the
+    // assistant a run is created with
+    let cache = state.storage.cache();
+    let assistant_id = body.as_ref().and_then(|body| body["assistant_id"].as_str());
+    let addressed = owned_object(&path)
+        .into_iter()
+        .chain(assistant_id.map(|id| (ASSISTANT, id)));
+    for (kind, id) in addressed {
+        if let Err(e) = Owners::new(cache, kind).require(id, &tenant).await {
+            return Ok(errors::gateway_error_to_response(e));
+        }
+    }
+
+    let (provider_name, query) = split_provider_query(req.query_string());
+
+    let router = state.router.load_full();
+    let providers = router.all();
+    let Some(provider) = providers.iter().find(|p| p.name() == provider_name) else {
+        return Ok(errors::gateway_error_to_response(GatewayError::not_found(
+            format!(
+                "No provider configured for the Assistants API: {}",
+                provider_name
+            ),
+        )));
+    };
+
+    match provider
+        .assistants_request(method.clone(), &path, &query, body)
+        .await
+    {
+        Ok(mut response) => {
+            if let Err(e) = record_owners(cache, &method, &path, &mut response, &tenant).await {
+                error!("Failed to record the owners of {}: {}", path, e);
+                return Ok(errors::gateway_error_to_response(e));
+            }
+
+            // Runs finishing in the same response are recorded under the first one's model
+            let mut recorded: Option<(String, u32, u32, f64)> = None;
+            for (run_id, model, prompt_tokens, completion_tokens) in finished_runs(&response) {
+                if !state.recorded_runs.insert(run_id) {
+                    continue;
+                }
+
+                let cost = state
+                    .pricing
+                    .get_cost_per_token(&model)
+                    .map(|(input, output)| {
+                        prompt_tokens as f64 * input + completion_tokens as f64 * output
+                    })
+                    .unwrap_or(0.0);
+                let total = recorded.get_or_insert((model, 0, 0, 0.0));
+                total.1 += prompt_tokens;
+                total.2 += completion_tokens;
+                total.3 += cost;
+            }
+            if let Some((model, prompt_tokens, completion_tokens, cost)) = recorded {
+                RequestUsage::new(&model)
+                    .with_provider(provider_name)
+                    .with_tokens(prompt_tokens, completion_tokens, cost)
+                    .record(&req);
+            }
+
+            Ok(HttpResponse::Ok().json(response))
+        }
+        Err(e) => {
+            error!("Assistants API error: {}", e);
+            Ok(errors::gateway_error_to_response(e.into()))
+        }
+    }
+}
+
+/// Assistant or thread a path addresses: `(kind, ID)`
+///
+/// Messages and runs are reached through their thread, so owning the thread
+/// grants access to them.
+fn owned_object(path: &str) -> Option<(&'static str, &str)> {
+    let mut segments = path.split('/');
+    let kind = match segments.next()? {
+        "assistants" => ASSISTANT,
+        "threads" => THREAD,
+        _ => return None,
+    };
+    match segments.next()? {
+        "" => None,
+        // `POST /v1/threads/runs` creates a thread along with its run
+        "runs" if kind == THREAD => None,
+        id => Some((kind, id)),
+    }
+}
+
+/// Record the owner of objects a response created, forget those it deleted,
+/// and drop the objects of other tenants from lists
+async fn record_owners(
+    cache: &dyn CacheBackend,
+    method: &HttpMethod,
+    path: &str,
+    response: &mut Value,
+    tenant: &str,
+) -> Result<()> {
+    match (method, response["object"].as_str()) {
+        (HttpMethod::POST, Some("assistant")) => {
+            if let Some(id) = response["id"].as_str() {
+                Owners::new(cache, ASSISTANT).record(id, tenant).await?;
+            }
+        }
+        (HttpMethod::POST, Some("thread")) => {
+            if let Some(id) = response["id"].as_str() {
+                Owners::new(cache, THREAD).record(id, tenant).await?;
+            }
+        }
+        (HttpMethod::POST, Some("thread.run")) if owned_object(path).is_none() => {
+            if let Some(id) = response["thread_id"].as_str() {
+                Owners::new(cache, THREAD).record(id, tenant).await?;
+            }
+        }
+        (HttpMethod::DELETE, Some(_)) if response["deleted"] == true => {
+            if let Some((kind, id)) = owned_object(path)
+                && path.trim_end_matches('/').split('/').count() == 2
+            {
+                Owners::new(cache, kind).forget(id).await?;
+            }
+        }
+        (HttpMethod::GET, Some("list")) if path == "assistants" => {
+            let owners = Owners::new(cache, ASSISTANT);
+            if let Some(data) = response["data"].as_array_mut() {
+                let mut owned = Vec::with_capacity(data.len());
+                for assistant in data.drain(..) {
+                    let id = assistant["id"].as_str().unwrap_or_default();
+                    if owners.owns(id, tenant).await? {
+                        owned.push(assistant);
+                    }
+                }
+                *data = owned;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Split `custom_llm_provider` (default "openai") from the forwarded query
+fn split_provider_query(query: &str) -> (String, String) {
+    let mut provider = "openai".to_string();
+    let mut forwarded = url::form_urlencoded::Serializer::new(String::new());
+
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        if key == "custom_llm_provider" {
+            provider = value.into_owned();
+        } else {
+            forwarded.append_pair(&key, &value);
+        }
+    }
+
+    (provider, forwarded.finish())
+}
+
+/// Usage of the finished runs in a response: `(run ID, model, prompt tokens,
+/// completion tokens)`
+fn finished_runs(response: &Value) -> Vec<(String, String, u32, u32)> {
+    let runs = match response["object"].as_str() {
+        Some("thread.run") => std::slice::from_ref(response),
+        Some("list") => response["data"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default(),
+        _ => &[],
+    };
+
+    runs.iter()
+        .filter(|run| run["object"] == "thread.run")
+        .filter(|run| {
+            run["status"]
+                .as_str()
+                .is_some_and(|status| TERMINAL_RUN_STATUSES.contains(&status))
+        })
+        .filter_map(|run| {
+            let usage = run.get("usage").filter(|usage| !usage.is_null())?;
+            Some((
+                run["id"].as_str()?.to_string(),
+                run["model"].as_str().unwrap_or_default().to_string(),
+                usage["prompt_tokens"].as_u64().unwrap_or(0) as u32,
+                usage["completion_tokens"].as_u64().unwrap_or(0) as u32,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::cache::MemoryCache;
+    use serde_json::json;
+
+    #[test]
+    fn test_split_provider_query() {
+        let (provider, query) =
+            split_provider_query("limit=20&custom_llm_provider=azure&order=desc");
+        assert_eq!(provider, "azure");
+        assert_eq!(query, "limit=20&order=desc");
+
+        let (provider, query) = split_provider_query("");
+        assert_eq!(provider, "openai");
+        assert_eq!(query, "");
+    }
+
+    #[test]
+    fn test_owned_object() {
+        assert_eq!(
+            owned_object("assistants/asst_1"),
+            Some((ASSISTANT, "asst_1"))
+        );
+        assert_eq!(
+            owned_object("threads/thread_1/runs/run_1/steps"),
+            Some((THREAD, "thread_1"))
+        );
+        assert_eq!(owned_object("assistants"), None);
+        assert_eq!(owned_object("threads"), None);
+        assert_eq!(owned_object("threads/runs"), None);
+    }
+
+    #[tokio::test]
+    async fn test_record_owners() {
+        let cache = MemoryCache::new();
+        let mut assistant = json!({"id": "asst_1", "object": "assistant"});
+        record_owners(
+            &cache,
+            &HttpMethod::POST,
+            "assistants",
+            &mut assistant,
+            "key:a",
+        )
+        .await
+        .unwrap();
+        let mut run = json!({"id": "run_1", "object": "thread.run", "thread_id": "thread_1"});
+        record_owners(&cache, &HttpMethod::POST, "threads/runs", &mut run, "key:a")
+            .await
+            .unwrap();
+        assert!(
+            Owners::new(&cache, THREAD)
+                .owns("thread_1", "key:a")
+                .await
+                .unwrap()
+        );
+
+        // Other tenants' assistants are dropped from lists
+        let mut list = json!({
+            "object": "list",
+            "data": [{"id": "asst_1", "object": "assistant"}, {"id": "asst_2", "object": "assistant"}]
+        });
+        record_owners(&cache, &HttpMethod::GET, "assistants", &mut list, "key:a")
+            .await
+            .unwrap();
+        assert_eq!(
+            list["data"],
+            json!([{"id": "asst_1", "object": "assistant"}])
+        );
+        record_owners(&cache, &HttpMethod::GET, "assistants", &mut list, "key:b")
+            .await
+            .unwrap();
+        assert_eq!(list["data"], json!([]));
+
+        let mut deleted = json!({"id": "asst_1", "object": "assistant.deleted", "deleted": true});
+        record_owners(
+            &cache,
+            &HttpMethod::DELETE,
+            "assistants/asst_1",
+            &mut deleted,
+            "key:a",
+        )
+        .await
+        .unwrap();
+        assert!(
+            !Owners::new(&cache, ASSISTANT)
+                .owns("asst_1", "key:a")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_recorded_runs() {
+        let runs = RecordedRuns::new(NonZeroUsize::new(1).unwrap());
+        assert!(runs.insert("run_abc123".to_string()));
+        assert!(!runs.insert("run_abc123".to_string()));
+        assert!(runs.insert("run_def456".to_string()));
+        assert!(runs.insert("run_abc123".to_string()));
+    }
+
+    #[test]
+    fn test_finished_runs() {
+        let run = json!({
+            "id": "run_abc123",
+            "object": "thread.run",
+            "status": "completed",
+            "model": "gpt-4o",
+            "usage": {"prompt_tokens": 120, "completion_tokens": 30, "total_tokens": 150}
+        });
+        assert_eq!(
+            finished_runs(&run),
+            vec![("run_abc123".to_string(), "gpt-4o".to_string(), 120, 30)]
+        );
+
+        let in_progress = json!({
+            "id": "run_def456",
+            "object": "thread.run",
+            "status": "in_progress",
+            "model": "gpt-4o",
+            "usage": null
+        });
+        let list = json!({"object": "list", "data": [run, in_progress]});
+        assert_eq!(finished_runs(&list).len(), 1);
+
+        assert!(finished_runs(&json!({"object": "assistant", "id": "asst_1"})).is_empty());
+    }
+}
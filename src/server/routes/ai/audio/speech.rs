@@ -24,6 +24,8 @@ pub struct AudioSpeechRequest {
     pub response_format: Option<String>,
     /// Speed of speech (0.25 to 4.0)
     pub speed: Option<f32>,
+    /// Tone and delivery instructions (gpt-4o-mini-tts only)
+    pub instructions: Option<String>,
 }
 
 fn default_tts_model() -> String {
@@ -32,7 +34,8 @@ fn default_tts_model() -> String {
 
 /// Audio speech endpoint
 ///
-/// OpenAI-compatible text-to-speech API.
+/// OpenAI-compatible text-to-speech API. Audio is streamed back as the
+/// provider produces it.
 pub async fn audio_speech(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -60,14 +63,15 @@ pub async fn audio_speech(
         voice: request.voice.clone(),
         response_format: request.response_format.clone(),
         speed: request.speed,
+        instructions: request.instructions.clone(),
     };
 
-    let audio_service = AudioService::new(state.router.clone());
+    let audio_service = AudioService::new(state.router.load_full());
 
-    match audio_service.speech(speech_request).await {
+    match audio_service.speech_stream(speech_request).await {
         Ok(response) => Ok(HttpResponse::Ok()
             .content_type(response.content_type)
-            .body(response.audio)),
+            .streaming(response.stream)),
         Err(e) => {
             error!("Speech generation error: {}", e);
             Ok(errors::gateway_error_to_response(e))
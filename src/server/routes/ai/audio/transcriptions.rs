@@ -139,7 +139,7 @@ pub async fn audio_transcriptions(
     };
 
     // Create audio service and process request
-    let audio_service = AudioService::new(state.router.clone());
+    let audio_service = AudioService::new(state.router.load_full());
 
     match audio_service.transcribe(transcription_request).await {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
@@ -117,7 +117,7 @@ pub async fn audio_translations(
         temperature,
     };
 
-    let audio_service = AudioService::new(state.router.clone());
+    let audio_service = AudioService::new(state.router.load_full());
 
     match audio_service.translate(translation_request).await {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
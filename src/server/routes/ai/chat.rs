@@ -1,26 +1,38 @@
 //! Chat completions endpoint
 
-use crate::core::completion::{CompletionOptions, completion_stream};
+use crate::auth::oidc::OidcIdentity;
+use crate::core::completion::{CompletionChunk, CompletionOptions, CompletionResponse};
+use crate::core::guardrails::GuardrailPipeline;
 use crate::core::models::RequestContext;
 use crate::core::models::openai::{
-    ChatChoice, ChatCompletionRequest, ChatCompletionResponse, ChatMessage, MessageContent,
-    MessageRole, Usage,
+    ChatChoice, ChatCompletionRequest, ChatCompletionResponse, ChatMessage, FunctionCall,
+    MessageContent, MessageRole, ToolCall, Usage,
 };
-use crate::core::providers::ProviderRegistry;
+use crate::core::observability::{TracedStream, scrub_secrets};
+use crate::core::response_cache::{CacheKey, ResponseCache};
 use crate::core::streaming::types::{
     ChatCompletionChunk, ChatCompletionChunkChoice, ChatCompletionDelta, Event,
 };
+use crate::core::tokenizers::{count_text_tokens, token_counter};
+use crate::server::middleware::{DeferredUsage, RequestUsage};
 use crate::server::routes::errors;
 use crate::server::state::AppState;
+use crate::services::callbacks::{CallContext, CallRequest, GenerationLog};
 use crate::utils::data::validation::RequestValidator;
 use crate::utils::error::GatewayError;
 use actix_web::http::header::{CACHE_CONTROL, CONTENT_TYPE};
-use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use actix_web::{HttpMessage, HttpRequest, HttpResponse, Result as ActixResult, web};
 use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use super::context::get_request_context;
+use super::routing::{complete, complete_stream};
+
+/// Response header telling whether the response came from the cache
+const X_CACHE: &str = "x-cache";
 
 /// Chat completions endpoint
 ///
@@ -46,15 +58,106 @@ pub async fn chat_completions(
         return Ok(errors::validation_error(&e.to_string()));
     }
 
-    // Check if streaming is requested
-    if request.stream.unwrap_or(false) {
+    let request = request.into_inner();
+
+    let owner = cache_owner(&req, &context);
+
+    // Key of the cached response, if caching is enabled
+    let cache = state.response_cache.as_ref().and_then(|cache| {
+        let key = cache.key(&request, request.cache.as_ref(), owner.as_deref())?;
+        Some((Arc::clone(cache), key))
+    });
+
+    // Serve the response of a similar enough prompt, unless the request
+    // streams or skips the cache
+    let stream = request.stream.unwrap_or(false);
+    let semantic_cache = state.semantic_cache.as_ref().filter(|_| {
+        !stream
+            && !request
+                .cache
+                .as_ref()
+                .is_some_and(|options| options.no_cache)
+    });
+    let semantic_request = semantic_cache.map(|_| request.clone());
+    let include_usage = request
+        .stream_options
+        .as_ref()
+        .and_then(|options| options.include_usage)
+        .unwrap_or(false);
+
+    // Callbacks and guardrails see every call, including ones the cache serves
+    let call = match prepare_call(state.get_ref(), request, &context).await {
+        Ok(call) => call,
+        Err(e) => {
+            error!("Chat completion error: {}", e);
+            return Ok(errors::gateway_error_to_response(e));
+        }
+    };
+
+    if stream {
+        if let Some((cache, key)) = &cache
+            && let Some(chunks) = cache.get::<Vec<ChatCompletionChunk>>(key).await
+        {
+            let completion: String = chunks
+                .iter()
+                .flat_map(|chunk| &chunk.choices)
+                .filter_map(|choice| choice.delta.content.as_deref())
+                .collect();
+            let completion_tokens = count_text_tokens(&call.model, &completion);
+            log_cache_hit(
+                state.get_ref(),
+                &context,
+                &call,
+                serde_json::json!({"role": "assistant", "content": completion}),
+                completion_tokens,
+            );
+            return Ok(replay_cached_stream(chunks));
+        }
         // Handle streaming request
-        handle_streaming_chat_completion(state.get_ref(), request.into_inner(), context).await
+        handle_streaming_chat_completion(state.get_ref(), &req, call, include_usage, context, cache)
+            .await
     } else {
-        // Handle non-streaming request
-        // TODO: Implement proper routing through ProviderRegistry
-        match handle_chat_completion_via_pool(&state.router, request.into_inner(), context).await {
-            Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        if let Some((cache, key)) = &cache
+            && let Some(response) = cache.get::<ChatCompletionResponse>(key).await
+        {
+            log_cached_response(state.get_ref(), &context, &call, &response);
+            return Ok(HttpResponse::Ok()
+                .insert_header((X_CACHE, "hit"))
+                .json(response));
+        }
+        if let (Some(semantic_cache), Some(request)) = (semantic_cache, &semantic_request) {
+            match semantic_cache
+                .get_cached_response(request, owner.as_deref())
+                .await
+            {
+                Ok(Some(response)) => {
+                    log_cached_response(state.get_ref(), &context, &call, &response);
+                    return Ok(HttpResponse::Ok()
+                        .insert_header((X_CACHE, "hit"))
+                        .json(response));
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Semantic cache lookup failed: {}", e),
+            }
+        }
+        match handle_chat_completion(state.get_ref(), &req, call, &context).await {
+            Ok(response) => {
+                let mut builder = HttpResponse::Ok();
+                if let Some((cache, key)) = &cache {
+                    cache.set(key, &response).await;
+                }
+                if let (Some(semantic_cache), Some(request)) = (semantic_cache, &semantic_request)
+                    && let Err(e) = semantic_cache
+                        .cache_response(request, &response, owner.as_deref())
+                        .await
+                {
+                    warn!("Failed to cache response semantically: {}", e);
+                }
+                if cache.is_some() || semantic_cache.is_some() {
+                    builder.insert_header((X_CACHE, "miss"));
+                }
+                Ok(builder.json(response))
+            }
             Err(e) => {
                 error!("Chat completion error: {}", e);
                 Ok(errors::gateway_error_to_response(e))
@@ -63,17 +166,374 @@ pub async fn chat_completions(
     }
 }
 
+/// Caller whose responses are cached apart from others' with `per_key`
+///
+/// Callers authenticated without a key are cached per user, and OIDC callers
+/// whose subject is not a user ID per subject.
+fn cache_owner(req: &HttpRequest, context: &RequestContext) -> Option<String> {
+    context
+        .api_key_id
+        .or(context.user_id)
+        .map(|id| id.to_string())
+        .or_else(|| {
+            req.extensions()
+                .get::<OidcIdentity>()
+                .map(|identity| format!("oidc:{}", identity.subject))
+        })
+}
+
+/// Chat completion call, rewritten by the callbacks and checked by the
+/// guardrails
+struct PreparedCall {
+    call: CallContext,
+    model: String,
+    messages: Vec<crate::core::types::ChatMessage>,
+    options: CompletionOptions,
+    guardrails: GuardrailPipeline,
+}
+
+/// Let callbacks rewrite a chat completion call, then check its prompt with
+/// the guardrails of the model group and key
+async fn prepare_call(
+    state: &AppState,
+    request: ChatCompletionRequest,
+    context: &RequestContext,
+) -> Result<PreparedCall, GatewayError> {
+    let (model, messages, options) = to_completion_call(request);
+
+    let call = CallContext::new(context, "chat.completions", &model);
+    let mut call_request = CallRequest {
+        model,
+        messages,
+        options,
+    };
+    state.callbacks.pre_call(&call, &mut call_request).await?;
+    let CallRequest {
+        model,
+        mut messages,
+        options,
+    } = call_request;
+
+    let guardrails = state
+        .guardrails
+        .pipeline(&model, call.api_key_id.as_deref());
+    guardrails.pre_call(&mut messages).await?;
+
+    Ok(PreparedCall {
+        call,
+        model,
+        messages,
+        options,
+        guardrails,
+    })
+}
+
+/// Report a cached chat completion response to the success callbacks
+fn log_cached_response(
+    state: &AppState,
+    context: &RequestContext,
+    call: &PreparedCall,
+    response: &ChatCompletionResponse,
+) {
+    let output = response
+        .choices
+        .first()
+        .map(|choice| serde_json::to_value(&choice.message).unwrap_or_default())
+        .unwrap_or_default();
+    let completion_tokens = response
+        .usage
+        .as_ref()
+        .map_or(0, |usage| usage.completion_tokens);
+    log_cache_hit(state, context, call, output, completion_tokens);
+}
+
+/// Report a completion served from the cache to the success callbacks
+///
+/// No provider was called, so the generation costs nothing.
+fn log_cache_hit(
+    state: &AppState,
+    context: &RequestContext,
+    call: &PreparedCall,
+    output: serde_json::Value,
+    completion_tokens: u32,
+) {
+    if !state.callbacks.is_enabled() {
+        return;
+    }
+    let input = serde_json::to_value(&call.messages).unwrap_or_default();
+    let prompt_tokens = token_counter(&call.model, &call.messages);
+    let mut log = GenerationLog::new(context, "chat.completions", &call.model)
+        .with_content(input, output)
+        .with_usage(prompt_tokens, completion_tokens, 0.0);
+    log.metadata.insert("cache_hit".to_string(), true.into());
+    state.callbacks.log_success(log);
+}
+
+/// Replay the chunks of a cached streaming response as SSE events
+fn replay_cached_stream(chunks: Vec<ChatCompletionChunk>) -> HttpResponse {
+    let events = chunks
+        .iter()
+        .filter_map(|chunk| serde_json::to_string(chunk).ok())
+        .map(|json| Event::default().data(&json).to_bytes())
+        .chain(std::iter::once(Event::default().data("[DONE]").to_bytes()))
+        .map(Ok::<_, GatewayError>)
+        .collect::<Vec<_>>();
+
+    HttpResponse::Ok()
+        .insert_header((CONTENT_TYPE, "text/event-stream"))
+        .insert_header((CACHE_CONTROL, "no-cache"))
+        .insert_header(("Connection", "keep-alive"))
+        .insert_header((X_CACHE, "hit"))
+        .streaming(futures::stream::iter(events))
+}
+
 /// Handle streaming chat completion
 async fn handle_streaming_chat_completion(
-    _state: &AppState,
-    request: ChatCompletionRequest,
-    _context: RequestContext,
+    state: &AppState,
+    req: &HttpRequest,
+    call: PreparedCall,
+    include_usage: bool,
+    context: RequestContext,
+    cache: Option<(Arc<ResponseCache>, CacheKey)>,
 ) -> ActixResult<HttpResponse> {
     info!(
         "Handling streaming chat completion for model: {}",
-        request.model
+        call.model
     );
+    let PreparedCall {
+        call,
+        model,
+        messages,
+        options,
+        guardrails,
+    } = call;
+
+    // Usage is charged once the stream ends, when the completion is known
+    let prompt_tokens = token_counter(&model, &messages);
+    let pricing = state.pricing.get_cost_per_token(&model);
+    let prompt_cost = pricing.map_or(0.0, |(input, _)| prompt_tokens as f64 * input);
+    RequestUsage::new(&model)
+        .with_tokens(prompt_tokens, 0, prompt_cost)
+        .deferred()
+        .record(req);
+    let deferred_usage = DeferredUsage::new(state, req);
+    let input = state
+        .callbacks
+        .is_enabled()
+        .then(|| serde_json::to_value(&messages).unwrap_or_default());
+
+    // Get the streaming response from core layer, checked by the guardrails
+    let stream_result = complete_stream(state, &context, &model, messages, options)
+        .await
+        .map(|stream| guardrails.guard_stream(stream));
+
+    match stream_result {
+        Ok(mut stream) => {
+            let request_id = format!("chatcmpl-{}", Uuid::new_v4());
+            let callbacks = Arc::clone(&state.callbacks);
+            let created = chrono::Utc::now().timestamp() as u64;
+            let span = tracing::info_span!("sse.stream", model = %model);
+            let caching = cache.is_some();
+
+            // Create SSE stream that converts CompletionChunks to SSE events
+            let sse_stream = async_stream::stream! {
+                let mut is_first_chunk = true;
+                let mut completed = true;
+                let mut cached_chunks = Vec::new();
+                let mut completion = String::new();
 
+                while let Some(chunk_result) = stream.next().await {
+                    match chunk_result {
+                        Ok(chunk) => {
+                            callbacks.on_stream_chunk(&call, &chunk).await;
+                            for choice in &chunk.choices {
+                                if let Some(content) = &choice.delta.content {
+                                    completion.push_str(content);
+                                }
+                            }
+
+                            let chat_chunk = to_chat_completion_chunk(
+                                chunk,
+                                &request_id,
+                                created,
+                                &model,
+                                is_first_chunk,
+                            );
+
+                            is_first_chunk = false;
+                            if cache.is_some() {
+                                cached_chunks.push(chat_chunk.clone());
+                            }
+
+                            // Serialize to SSE event
+                            match serde_json::to_string(&chat_chunk) {
+                                Ok(json) => {
+                                    let event = Event::default().data(&json);
+                                    yield Ok::<_, GatewayError>(event.to_bytes());
+                                }
+                                Err(e) => {
+                                    error!("Failed to serialize chunk: {}", e);
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            error!("Stream error: {}", e);
+                            callbacks.log_failure(&call, &e);
+                            let error_event = Event::default()
+                                .event("error")
+                                .data(&format!("{{\"error\": \"{}\"}}", scrub_secrets(&e.to_string())));
+                            yield Ok::<_, GatewayError>(error_event.to_bytes());
+                            completed = false;
+                            break;
+                        }
+                    }
+                }
+
+                // Only cache streams the provider completed
+                if completed && let Some((cache, key)) = &cache {
+                    cache.set(key, &cached_chunks).await;
+                }
+
+                // Charge the streamed tokens, which the provider bills even
+                // for streams that failed midway
+                let completion_tokens = count_text_tokens(&model, &completion);
+                let cost = prompt_cost
+                    + pricing.map_or(0.0, |(_, output)| completion_tokens as f64 * output);
+                let usage = RequestUsage::new(&model)
+                    .with_tokens(prompt_tokens, completion_tokens, cost);
+                deferred_usage.charge(&usage).await;
+                if completed && let Some(input) = input {
+                    let output = serde_json::json!({"role": "assistant", "content": completion});
+                    callbacks.log_success(
+                        GenerationLog::new(&context, "chat.completions", &model)
+                            .with_content(input, output)
+                            .with_usage(prompt_tokens, completion_tokens, cost),
+                    );
+                }
+
+                // Report the usage in a final chunk without choices, as OpenAI does
+                if completed && include_usage {
+                    let usage_chunk = ChatCompletionChunk {
+                        id: request_id.clone(),
+                        object: "chat.completion.chunk".to_string(),
+                        created,
+                        model: model.clone(),
+                        system_fingerprint: None,
+                        choices: Vec::new(),
+                        usage: Some(Usage {
+                            prompt_tokens,
+                            completion_tokens,
+                            total_tokens: prompt_tokens + completion_tokens,
+                            prompt_tokens_details: None,
+                            completion_tokens_details: None,
+                        }),
+                        provider_specific_fields: None,
+                    };
+                    if let Ok(json) = serde_json::to_string(&usage_chunk) {
+                        yield Ok::<_, GatewayError>(Event::default().data(&json).to_bytes());
+                    }
+                }
+
+                // Send [DONE] event
+                let done_event = Event::default().data("[DONE]");
+                yield Ok::<_, GatewayError>(done_event.to_bytes());
+            };
+
+            let mut builder = HttpResponse::Ok();
+            builder
+                .insert_header((CONTENT_TYPE, "text/event-stream"))
+                .insert_header((CACHE_CONTROL, "no-cache"))
+                .insert_header(("Connection", "keep-alive"));
+            if caching {
+                builder.insert_header((X_CACHE, "miss"));
+            }
+            Ok(builder.streaming(TracedStream::new(sse_stream, span)))
+        }
+        Err(e) => {
+            error!("Failed to create streaming response: {}", e);
+            state.callbacks.log_failure(&call, &e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// Handle non-streaming chat completion
+async fn handle_chat_completion(
+    state: &AppState,
+    req: &HttpRequest,
+    call: PreparedCall,
+    context: &RequestContext,
+) -> Result<ChatCompletionResponse, GatewayError> {
+    let PreparedCall {
+        call,
+        model,
+        messages,
+        options,
+        guardrails,
+    } = call;
+
+    let log_payloads = state
+        .request_logs
+        .as_ref()
+        .is_some_and(|logs| logs.log_payloads());
+    let input = (state.callbacks.is_enabled() || log_payloads)
+        .then(|| serde_json::to_value(&messages).unwrap_or_default());
+    let result = match complete(state, context, &model, messages, options).await {
+        Ok(mut response) => guardrails
+            .check_response(&mut response)
+            .await
+            .map(|()| response),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(response) => {
+            let output = input.as_ref().map(|_| {
+                let message = response.choices.first().map(|choice| &choice.message);
+                serde_json::to_value(message).unwrap_or_default()
+            });
+            let (prompt_tokens, completion_tokens) =
+                response.usage.as_ref().map_or((0, 0), |usage| {
+                    (usage.prompt_tokens, usage.completion_tokens)
+                });
+            let cost = state
+                .pricing
+                .get_cost_per_token(&model)
+                .map_or(0.0, |(input, output)| {
+                    prompt_tokens as f64 * input + completion_tokens as f64 * output
+                });
+            if state.callbacks.is_enabled() {
+                state.callbacks.log_success(
+                    GenerationLog::new(context, "chat.completions", &model)
+                        .with_content(
+                            input.clone().unwrap_or_default(),
+                            output.clone().unwrap_or_default(),
+                        )
+                        .with_usage(prompt_tokens, completion_tokens, cost),
+                );
+            }
+            RequestUsage::new(&model)
+                .with_tokens(prompt_tokens, completion_tokens, cost)
+                .with_payloads(input, output)
+                .record(req);
+            Ok(to_chat_completion_response(response))
+        }
+        Err(e) => {
+            state.callbacks.log_failure(&call, &e);
+            RequestUsage::new(&model).with_error(&e).record(req);
+            Err(e)
+        }
+    }
+}
+
+/// Model, messages and options of a chat completion request
+fn to_completion_call(
+    request: ChatCompletionRequest,
+) -> (
+    String,
+    Vec<crate::core::types::ChatMessage>,
+    CompletionOptions,
+) {
     // Convert ChatCompletionRequest messages to core Message format
     let messages: Vec<crate::core::types::ChatMessage> = request
         .messages
@@ -96,7 +556,10 @@ async fn handle_streaming_chat_completion(
                         .into_iter()
                         .map(|p| match p {
                             crate::core::models::openai::ContentPart::Text { text } => {
-                                crate::core::types::ContentPart::Text { text }
+                                crate::core::types::ContentPart::Text {
+                                    text,
+                                    cache_control: None,
+                                }
                             }
                             crate::core::models::openai::ContentPart::ImageUrl { image_url } => {
                                 crate::core::types::ContentPart::ImageUrl {
@@ -106,12 +569,13 @@ async fn handle_streaming_chat_completion(
                                     },
                                 }
                             }
-                            crate::core::models::openai::ContentPart::Audio { audio } => {
-                                // For audio, we'll use text as a fallback since audio types differ
-                                crate::core::types::ContentPart::Text {
-                                    text: format!("[audio: {:?}]", audio),
-                                }
-                            }
+                            crate::core::models::openai::ContentPart::Audio { audio }
+                            | crate::core::models::openai::ContentPart::InputAudio {
+                                input_audio: audio,
+                            } => crate::core::types::ContentPart::input_audio(
+                                &audio.format,
+                                audio.data,
+                            ),
                         })
                         .collect();
                     crate::core::types::MessageContent::Parts(converted_parts)
@@ -148,11 +612,20 @@ async fn handle_streaming_chat_completion(
                 tool_calls,
                 tool_call_id: msg.tool_call_id,
                 function_call,
+                cache_control: None,
             }
         })
         .collect();
 
-    // Build completion options from request
+    // Routing tags and metadata ride along for the router
+    let mut extra_params = HashMap::new();
+    if let Some(tags) = request.tags {
+        extra_params.insert("tags".to_string(), serde_json::json!(tags));
+    }
+    if let Some(metadata) = request.metadata {
+        extra_params.insert("metadata".to_string(), serde_json::json!(metadata));
+    }
+
     let options = CompletionOptions {
         temperature: request.temperature,
         max_tokens: request.max_tokens,
@@ -160,159 +633,295 @@ async fn handle_streaming_chat_completion(
         frequency_penalty: request.frequency_penalty,
         presence_penalty: request.presence_penalty,
         stop: request.stop,
-        stream: true,
+        stream: request.stream.unwrap_or(false),
+        response_format: request
+            .response_format
+            .map(|format| crate::core::types::ResponseFormat {
+                format_type: format.format_type,
+                json_schema: format.json_schema,
+                response_type: None,
+            }),
         user: request.user,
         seed: request.seed.map(|s| s as i32),
         n: request.n,
         logprobs: request.logprobs,
         top_logprobs: request.top_logprobs,
+        tools: request
+            .tools
+            .and_then(|tools| serde_json::from_value(serde_json::to_value(tools).ok()?).ok()),
+        tool_choice: request
+            .tool_choice
+            .and_then(|choice| serde_json::from_value(serde_json::to_value(choice).ok()?).ok()),
+        extra_params,
         ..Default::default()
     };
 
-    // Get the streaming response from core layer
-    let stream_result = completion_stream(&request.model, messages, Some(options)).await;
+    (request.model, messages, options)
+}
 
-    match stream_result {
-        Ok(mut stream) => {
-            let request_id = format!("chatcmpl-{}", Uuid::new_v4());
-            let model = request.model.clone();
-            let created = chrono::Utc::now().timestamp() as u64;
+/// OpenAI chat completion of a completion response
+fn to_chat_completion_response(response: CompletionResponse) -> ChatCompletionResponse {
+    ChatCompletionResponse {
+        id: response.id,
+        object: response.object,
+        created: response.created as u64,
+        model: response.model,
+        system_fingerprint: None,
+        choices: response
+            .choices
+            .into_iter()
+            .map(|choice| ChatChoice {
+                index: choice.index,
+                message: to_openai_message(choice.message),
+                logprobs: None,
+                finish_reason: choice.finish_reason.and_then(|reason| {
+                    serde_json::to_value(reason)
+                        .ok()
+                        .and_then(|v| v.as_str().map(str::to_string))
+                }),
+            })
+            .collect(),
+        usage: response.usage.map(|usage| Usage {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+            prompt_tokens_details: None,
+            completion_tokens_details: None,
+        }),
+        provider_specific_fields: response.provider_specific_fields,
+    }
+}
 
-            // Create SSE stream that converts CompletionChunks to SSE events
-            let sse_stream = async_stream::stream! {
-                let mut is_first_chunk = true;
+/// OpenAI chunk of a streamed chunk, with the assistant role on the first one
+fn to_chat_completion_chunk(
+    chunk: CompletionChunk,
+    request_id: &str,
+    created: u64,
+    model: &str,
+    is_first_chunk: bool,
+) -> ChatCompletionChunk {
+    ChatCompletionChunk {
+        id: request_id.to_string(),
+        object: "chat.completion.chunk".to_string(),
+        created,
+        model: model.to_string(),
+        system_fingerprint: None,
+        choices: chunk
+            .choices
+            .into_iter()
+            .map(|c| ChatCompletionChunkChoice {
+                index: c.index,
+                delta: ChatCompletionDelta {
+                    role: is_first_chunk.then_some(crate::core::types::MessageRole::Assistant),
+                    content: c.delta.content,
+                    tool_calls: c
+                        .delta
+                        .tool_calls
+                        .map(|calls| calls.into_iter().map(Into::into).collect()),
+                },
+                finish_reason: c.finish_reason.and_then(|fr| {
+                    serde_json::to_value(fr)
+                        .ok()
+                        .and_then(|v| v.as_str().map(str::to_string))
+                }),
+                logprobs: None,
+            })
+            .collect(),
+        usage: None,
+        provider_specific_fields: chunk.provider_specific_fields,
+    }
+}
 
-                while let Some(chunk_result) = stream.next().await {
-                    match chunk_result {
-                        Ok(chunk) => {
-                            // Convert CompletionChunk to ChatCompletionChunk (OpenAI format)
-                            let chat_chunk = ChatCompletionChunk {
-                                id: request_id.clone(),
-                                object: "chat.completion.chunk".to_string(),
-                                created,
-                                model: model.clone(),
-                                system_fingerprint: None,
-                                choices: chunk.choices.into_iter().map(|c| {
-                                    ChatCompletionChunkChoice {
-                                        index: c.index,
-                                        delta: ChatCompletionDelta {
-                                            role: if is_first_chunk {
-                                                Some(crate::core::types::MessageRole::Assistant)
-                                            } else {
-                                                None
-                                            },
-                                            content: c.delta.content,
-                                            tool_calls: None,
-                                        },
-                                        finish_reason: c.finish_reason.map(|fr| format!("{:?}", fr).to_lowercase()),
-                                        logprobs: None,
-                                    }
-                                }).collect(),
-                                usage: None,
-                            };
+/// OpenAI message of a completed message
+fn to_openai_message(message: crate::core::types::ChatMessage) -> ChatMessage {
+    let function_call = |call: crate::core::types::FunctionCall| FunctionCall {
+        name: call.name,
+        arguments: call.arguments,
+    };
+    ChatMessage {
+        role: match message.role {
+            crate::core::types::MessageRole::System => MessageRole::System,
+            crate::core::types::MessageRole::User => MessageRole::User,
+            crate::core::types::MessageRole::Assistant => MessageRole::Assistant,
+            crate::core::types::MessageRole::Tool => MessageRole::Tool,
+            crate::core::types::MessageRole::Function => MessageRole::Function,
+        },
+        content: message
+            .content
+            .map(|content| MessageContent::Text(content.to_string())),
+        name: message.name,
+        function_call: message.function_call.map(function_call),
+        tool_calls: message.tool_calls.map(|calls| {
+            calls
+                .into_iter()
+                .map(|call| ToolCall {
+                    id: call.id,
+                    tool_type: call.tool_type,
+                    function: function_call(call.function),
+                })
+                .collect()
+        }),
+        tool_call_id: message.tool_call_id,
+        audio: None,
+    }
+}
 
-                            is_first_chunk = false;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::models::cache::CacheConfig;
+    use crate::core::completion::{Choice, StreamChoice, StreamDelta};
+    use crate::storage::cache::MemoryCache;
+    use actix_web::test::TestRequest;
+    use serde_json::json;
+    use std::collections::HashMap;
 
-                            // Serialize to SSE event
-                            match serde_json::to_string(&chat_chunk) {
-                                Ok(json) => {
-                                    let event = Event::default().data(&json);
-                                    yield Ok::<_, GatewayError>(event.to_bytes());
-                                }
-                                Err(e) => {
-                                    error!("Failed to serialize chunk: {}", e);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            error!("Stream error: {}", e);
-                            let error_event = Event::default()
-                                .event("error")
-                                .data(&format!("{{\"error\": \"{}\"}}", e));
-                            yield Ok::<_, GatewayError>(error_event.to_bytes());
-                            break;
-                        }
-                    }
-                }
+    fn search_fields() -> HashMap<String, serde_json::Value> {
+        HashMap::from([
+            ("citations".to_string(), json!(["https://example.com"])),
+            (
+                "search_results".to_string(),
+                json!([{"title": "Example", "url": "https://example.com"}]),
+            ),
+        ])
+    }
 
-                // Send [DONE] event
-                let done_event = Event::default().data("[DONE]");
-                yield Ok::<_, GatewayError>(done_event.to_bytes());
-            };
+    #[test]
+    fn test_response_serializes_provider_specific_fields() {
+        let response = CompletionResponse {
+            id: "chatcmpl-123".to_string(),
+            object: "chat.completion".to_string(),
+            created: 0,
+            model: "perplexity/sonar".to_string(),
+            choices: vec![Choice {
+                index: 0,
+                message: crate::core::types::ChatMessage {
+                    role: crate::core::types::MessageRole::Assistant,
+                    content: Some(crate::core::types::MessageContent::Text(
+                        "Example [1]".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                finish_reason: None,
+            }],
+            usage: None,
+            provider_specific_fields: Some(search_fields()),
+        };
 
-            Ok(HttpResponse::Ok()
-                .insert_header((CONTENT_TYPE, "text/event-stream"))
-                .insert_header((CACHE_CONTROL, "no-cache"))
-                .insert_header(("Connection", "keep-alive"))
-                .streaming(sse_stream))
-        }
-        Err(e) => {
-            error!("Failed to create streaming response: {}", e);
-            Ok(errors::gateway_error_to_response(e))
-        }
+        let json = serde_json::to_value(to_chat_completion_response(response)).unwrap();
+        assert_eq!(
+            json["provider_specific_fields"]["citations"],
+            json!(["https://example.com"])
+        );
+        assert_eq!(
+            json["provider_specific_fields"]["search_results"][0]["title"],
+            "Example"
+        );
     }
-}
 
-/// Handle chat completion via provider pool
-pub async fn handle_chat_completion_via_pool(
-    pool: &ProviderRegistry,
-    request: ChatCompletionRequest,
-    _context: RequestContext,
-) -> Result<ChatCompletionResponse, GatewayError> {
-    // Simplified working implementation
+    #[test]
+    fn test_cache_owner_of_oidc_subjects() {
+        let config = CacheConfig {
+            enabled: true,
+            per_key: true,
+            ..Default::default()
+        };
+        let cache = ResponseCache::new(&config, Arc::new(MemoryCache::new()));
+        let request = ChatCompletionRequest {
+            model: "gpt-4".to_string(),
+            ..Default::default()
+        };
+        let context = RequestContext::default();
 
-    // Get the appropriate provider based on model
-    let _provider = if request.model.starts_with("claude") {
-        // Use Anthropic provider for Claude models
-        pool.get_provider("anthropic")
-            .ok_or_else(|| GatewayError::internal("Anthropic provider not available"))?
-    } else {
-        // Use OpenAI provider for all other models
-        pool.get_provider("openai")
-            .ok_or_else(|| GatewayError::internal("OpenAI provider not available"))?
-    };
+        let key = |subject: &str| {
+            let req = TestRequest::default().to_http_request();
+            req.extensions_mut().insert(OidcIdentity {
+                subject: subject.to_string(),
+                user_id: None,
+                team: None,
+                team_id: None,
+                roles: Vec::new(),
+                permissions: Vec::new(),
+            });
+            let owner = cache_owner(&req, &context);
+            cache.key(&request, None, owner.as_deref()).unwrap().key
+        };
+        assert_ne!(key("alice@example.com"), key("bob@example.com"));
+        assert_eq!(key("alice@example.com"), key("alice@example.com"));
 
-    // For now, return a mock response to test the system
-    let response = ChatCompletionResponse {
-        id: format!("chatcmpl-{}", Uuid::new_v4()),
-        object: "chat.completion".to_string(),
-        created: chrono::Utc::now().timestamp() as u64,
-        model: request.model.clone(),
-        choices: vec![ChatChoice {
-            index: 0,
-            message: ChatMessage {
-                role: MessageRole::Assistant,
-                content: Some(MessageContent::Text(format!(
-                    "Hello! I'm {} responding through the gateway. You said: {:?}",
-                    request.model,
-                    request
-                        .messages
-                        .last()
-                        .and_then(|m| m.content.as_ref())
-                        .map(|c| match c {
-                            MessageContent::Text(t) => t.as_str(),
-                            _ => "[non-text content]",
-                        })
-                        .unwrap_or("[no message]")
-                ))),
-                name: None,
-                function_call: None,
-                tool_calls: None,
-                tool_call_id: None,
-                audio: None,
-            },
-            finish_reason: Some("stop".to_string()),
-            logprobs: None,
-        }],
-        usage: Some(Usage {
-            prompt_tokens: 10,
-            completion_tokens: 20,
-            total_tokens: 30,
-            prompt_tokens_details: None,
-            completion_tokens_details: None,
-        }),
-        system_fingerprint: Some(format!("fp_{}", uuid::Uuid::new_v4().simple())),
-    };
+        // Callers without an identity are not cached with `per_key`
+        let req = TestRequest::default().to_http_request();
+        let owner = cache_owner(&req, &context);
+        assert!(cache.key(&request, None, owner.as_deref()).is_none());
+    }
+
+    #[test]
+    fn test_completion_call_keeps_routing_tags_and_metadata() {
+        let request: ChatCompletionRequest = serde_json::from_value(json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "tags": ["eu-only"],
+            "metadata": {"user_id": "user-42", "tags": ["ignored"]},
+        }))
+        .unwrap();
 
-    Ok(response)
+        let (model, messages, options) = to_completion_call(request);
+        let chat_request =
+            crate::core::completion::convert_to_chat_completion_request(&model, messages, options)
+                .unwrap();
+        assert_eq!(chat_request.routing_tags(), vec!["eu-only".to_string()]);
+        assert_eq!(chat_request.routing_user().as_deref(), Some("user-42"));
+
+        // Requests without them have nothing to route on
+        let request: ChatCompletionRequest = serde_json::from_value(json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "Hello"}],
+        }))
+        .unwrap();
+        let (_, _, options) = to_completion_call(request);
+        assert!(options.extra_params.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_serializes_provider_specific_fields() {
+        let chunk = CompletionChunk {
+            id: "chunk-1".to_string(),
+            object: "chat.completion.chunk".to_string(),
+            created: 0,
+            model: "sonar".to_string(),
+            choices: vec![StreamChoice {
+                index: 0,
+                delta: StreamDelta {
+                    content: Some("Example".to_string()),
+                    ..Default::default()
+                },
+                finish_reason: None,
+            }],
+            provider_specific_fields: Some(search_fields()),
+        };
+
+        let chat_chunk = to_chat_completion_chunk(chunk.clone(), "chatcmpl-1", 0, "sonar", true);
+        let json = serde_json::to_value(chat_chunk).unwrap();
+        assert_eq!(json["choices"][0]["delta"]["role"], "assistant");
+        assert_eq!(
+            json["provider_specific_fields"]["citations"],
+            json!(["https://example.com"])
+        );
+
+        // Chunks without them serialize as before
+        let chunk = CompletionChunk {
+            provider_specific_fields: None,
+            ..chunk
+        };
+        let json = serde_json::to_value(to_chat_completion_chunk(
+            chunk,
+            "chatcmpl-1",
+            0,
+            "sonar",
+            false,
+        ))
+        .unwrap();
+        assert!(json.get("provider_specific_fields").is_none());
+        assert!(json["choices"][0]["delta"]["role"].is_null());
+    }
 }
@@ -1,15 +1,16 @@
 //! Text completions endpoint (legacy)
 //!
-//! This endpoint converts legacy text completions to chat completions format
-//! since most modern providers only support chat completions.
+//! Models with a native completions endpoint are passed through; prompts for
+//! chat-only models are converted to chat completions.
 
-use crate::core::completion::{CompletionOptions, completion};
+use crate::core::completion::{TextCompletionRequest, text_completion};
 use crate::core::models::RequestContext;
 use crate::core::models::openai::{CompletionRequest, CompletionResponse};
 use crate::core::providers::ProviderRegistry;
-use crate::core::types::{ChatMessage, MessageContent, MessageRole};
+use crate::server::middleware::RequestUsage;
 use crate::server::routes::errors;
 use crate::server::state::AppState;
+use crate::services::callbacks::{CallContext, GenerationLog};
 use crate::utils::error::GatewayError;
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
 use tracing::{error, info};
@@ -19,7 +20,6 @@ use super::context::get_request_context;
 /// Text completions endpoint (legacy)
 ///
 /// OpenAI-compatible text completions API for backward compatibility.
-/// Internally converts to chat completions format.
 pub async fn completions(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -30,11 +30,58 @@ pub async fn completions(
     // Get request context from middleware
     let context = get_request_context(&req)?;
 
+    let log_payloads = state
+        .request_logs
+        .as_ref()
+        .is_some_and(|logs| logs.log_payloads());
+    let input = (state.callbacks.is_enabled() || log_payloads)
+        .then(|| serde_json::to_value(&request.prompt).unwrap_or_default());
+    let call = CallContext::new(&context, "completions", &request.model);
+    let model = request.model.clone();
+
     // Route request through the core router
-    match handle_completion_via_pool(&state.router, request.into_inner(), context).await {
-        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+    match handle_completion_via_pool(
+        &state.router.load_full(),
+        request.into_inner(),
+        context.clone(),
+    )
+    .await
+    {
+        Ok(response) => {
+            let (prompt_tokens, completion_tokens) = response
+                .usage
+                .as_ref()
+                .map_or((0, 0), |u| (u.prompt_tokens, u.completion_tokens));
+            let cost = state
+                .pricing
+                .get_cost_per_token(&response.model)
+                .map_or(0.0, |(input, output)| {
+                    prompt_tokens as f64 * input + completion_tokens as f64 * output
+                });
+            let output = input.as_ref().map(|_| {
+                let texts: Vec<_> = response.choices.iter().map(|c| c.text.clone()).collect();
+                serde_json::Value::from(texts)
+            });
+            if state.callbacks.is_enabled() {
+                state.callbacks.log_success(
+                    GenerationLog::new(&context, "completions", &response.model)
+                        .with_content(
+                            input.clone().unwrap_or_default(),
+                            output.clone().unwrap_or_default(),
+                        )
+                        .with_usage(prompt_tokens, completion_tokens, cost),
+                );
+            }
+            RequestUsage::new(&response.model)
+                .with_tokens(prompt_tokens, completion_tokens, cost)
+                .with_payloads(input, output)
+                .record(&req);
+            Ok(HttpResponse::Ok().json(response))
+        }
         Err(e) => {
             error!("Text completion error: {}", e);
+            state.callbacks.log_failure(&call, &e);
+            RequestUsage::new(&model).with_error(&e).record(&req);
             Ok(errors::gateway_error_to_response(e))
         }
     }
@@ -42,68 +89,50 @@ pub async fn completions(
 
 /// Handle completion via provider pool
 ///
-/// Converts legacy text completion request to chat completion format
+/// Models with a native completions endpoint are passed through; chat-only
+/// models receive the prompt as a user message.
 pub async fn handle_completion_via_pool(
     _pool: &ProviderRegistry,
     request: CompletionRequest,
     _context: RequestContext,
 ) -> Result<CompletionResponse, GatewayError> {
-    // Convert prompt to chat message
-    let messages = vec![ChatMessage {
-        role: MessageRole::User,
-        content: Some(MessageContent::Text(request.prompt.clone())),
-        thinking: None,
-        name: None,
-        tool_calls: None,
-        tool_call_id: None,
-        function_call: None,
-    }];
-
-    // Build completion options
-    let options = CompletionOptions {
+    let request = TextCompletionRequest {
+        model: request.model,
+        prompt: request.prompt,
         temperature: request.temperature.map(|t| t as f32),
         max_tokens: request.max_tokens,
         top_p: request.top_p.map(|t| t as f32),
         frequency_penalty: request.frequency_penalty.map(|f| f as f32),
         presence_penalty: request.presence_penalty.map(|p| p as f32),
         stop: request.stop,
+        n: request.n,
         stream: false,
         user: request.user,
-        n: request.n,
-        ..Default::default()
     };
 
-    // Call completion API
-    let response = completion(&request.model, messages, Some(options))
+    let response = text_completion(request)
         .await
         .map_err(|e| GatewayError::internal(format!("Completion error: {}", e)))?;
 
-    // Convert chat completion response to text completion format
-    let text = response
-        .choices
-        .first()
-        .and_then(|c| c.message.content.as_ref())
-        .map(|c| c.to_string())
-        .unwrap_or_default();
-
-    // Convert finish reason to string
-    let finish_reason = response
-        .choices
-        .first()
-        .and_then(|c| c.finish_reason.as_ref())
-        .map(|fr| format!("{:?}", fr).to_lowercase());
-
     Ok(CompletionResponse {
         id: response.id,
-        object: "text_completion".to_string(),
+        object: response.object,
         created: response.created as u64,
         model: response.model,
-        choices: vec![crate::core::models::openai::CompletionChoice {
-            text,
-            index: 0,
-            logprobs: None,
-            finish_reason,
-        }],
+        choices: response
+            .choices
+            .into_iter()
+            .map(|choice| crate::core::models::openai::CompletionChoice {
+                text: choice.text,
+                index: choice.index,
+                logprobs: None,
+                finish_reason: choice.finish_reason.and_then(|fr| {
+                    serde_json::to_value(fr)
+                        .ok()
+                        .and_then(|v| v.as_str().map(str::to_string))
+                }),
+            })
+            .collect(),
         usage: response.usage.map(|u| crate::core::models::openai::Usage {
             prompt_tokens: u.prompt_tokens,
             completion_tokens: u.completion_tokens,
@@ -4,14 +4,19 @@ use crate::core::models::ApiKey;
 use crate::core::models::RequestContext;
 use crate::core::models::user::types::User;
 use actix_web::http::header::HeaderMap;
-use actix_web::{HttpRequest, Result as ActixResult};
+use actix_web::{HttpMessage, HttpRequest, Result as ActixResult};
 use tracing::debug;
 
 /// Get request context from headers and middleware extensions
+///
+/// Authenticated requests carry the context the authentication middleware
+/// attached, with the caller's key, user and team.
 pub fn get_request_context(req: &HttpRequest) -> ActixResult<RequestContext> {
-    // In a real implementation, this would extract the context from request extensions
-    // that were set by the authentication middleware
-    let mut context = RequestContext::new();
+    let mut context = req
+        .extensions()
+        .get::<RequestContext>()
+        .cloned()
+        .unwrap_or_default();
 
     // Extract request ID
     if let Some(request_id) = req.headers().get("x-request-id") {
@@ -73,6 +78,21 @@ mod tests {
         // assert!(check_permission(Some(&user), None, "chat"));
     }
 
+    #[test]
+    fn test_get_request_context_from_extensions() {
+        let req = actix_web::test::TestRequest::default().to_http_request();
+        let authenticated = RequestContext {
+            api_key_id: Some(uuid::Uuid::new_v4()),
+            team_id: Some(uuid::Uuid::new_v4()),
+            ..Default::default()
+        };
+        req.extensions_mut().insert(authenticated.clone());
+
+        let context = get_request_context(&req).unwrap();
+        assert_eq!(context.api_key_id, authenticated.api_key_id);
+        assert_eq!(context.team_id, authenticated.team_id);
+    }
+
     #[tokio::test]
     async fn test_log_api_usage() {
         // This would require actual state in a real test
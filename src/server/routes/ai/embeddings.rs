@@ -8,6 +8,7 @@ use crate::server::routes::errors;
 use crate::server::state::AppState;
 use crate::utils::error::GatewayError;
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use std::collections::HashMap;
 use tracing::{error, info};
 
 use super::context::get_request_context;
@@ -26,7 +27,8 @@ pub async fn embeddings(
     let context = get_request_context(&req)?;
 
     // Route request through the core router
-    match handle_embedding_via_pool(&state.router, request.into_inner(), context).await {
+    match handle_embedding_via_pool(&state.router.load_full(), request.into_inner(), context).await
+    {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
         Err(e) => {
             error!("Embedding error: {}", e);
@@ -65,6 +67,7 @@ pub async fn handle_embedding_via_pool(
         encoding_format: None,
         dimensions: None,
         task_type: None,
+        extra_params: HashMap::new(),
     };
 
     // Convert RequestContext to core type
@@ -0,0 +1,284 @@
+//! Files endpoints
+//!
+//! OpenAI-compatible `/v1/files` API. Files are stored with the provider that
+//! will consume them, selected by `custom_llm_provider` (defaults to OpenAI).
+//! Files belong to the tenant that uploaded them, and are hidden from others.
+
+use crate::core::files::{FileListQuery, FileUploadRequest, FilesService};
+use crate::server::routes::access::{Owners, require_tenant};
+use crate::server::routes::{ApiResponse, errors};
+use crate::server::state::AppState;
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use futures::StreamExt;
+use serde::Deserialize;
+use tracing::{error, info};
+
+/// Kind under which the owners of files are recorded
+pub(crate) const FILE: &str = "file";
+
+/// Provider selection shared by every files endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct FilesProviderQuery {
+    /// Provider hosting the files, e.g. "openai" or "azure"
+    pub custom_llm_provider: Option<String>,
+}
+
+/// Query parameters of `GET /v1/files`
+#[derive(Debug, Default, Deserialize)]
+pub struct ListFilesQuery {
+    /// Provider hosting the files
+    pub custom_llm_provider: Option<String>,
+    /// Only return files with this purpose
+    pub purpose: Option<String>,
+    /// Page size
+    pub limit: Option<u32>,
+    /// Cursor: the last file ID of the previous page
+    pub after: Option<String>,
+    /// Sort order by `created_at`
+    pub order: Option<String>,
+}
+
+/// Upload file endpoint
+///
+/// Accepts multipart/form-data with `file` and `purpose` fields.
+pub async fn upload_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<FilesProviderQuery>,
+    mut payload: Multipart,
+) -> ActixResult<HttpResponse> {
+    info!("File upload request");
+
+    let tenant = require_tenant(&req)?;
+
+    let mut file_data: Option<Vec<u8>> = None;
+    let mut filename = String::from("file.jsonl");
+    let mut purpose = String::new();
+    let mut provider = query.into_inner().custom_llm_provider;
+
+    while let Some(item) = payload.next().await {
+        let mut field = match item {
+            Ok(f) => f,
+            Err(e) => {
+                error!("Error reading multipart field: {}", e);
+                return Ok(
+                    HttpResponse::BadRequest().json(ApiResponse::<()>::error(format!(
+                        "Invalid multipart data: {}",
+                        e
+                    ))),
+                );
+            }
+        };
+
+        let field_name = match field.name() {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+
+        match field_name.as_str() {
+            "file" => {
+                if let Some(cd) = field.content_disposition() {
+                    if let Some(fname) = cd.get_filename() {
+                        filename = fname.to_string();
+                    }
+                }
+
+                let mut data = Vec::new();
+                while let Some(chunk) = field.next().await {
+                    match chunk {
+                        Ok(bytes) => data.extend_from_slice(&bytes),
+                        Err(e) => {
+                            error!("Error reading file chunk: {}", e);
+                            return Ok(HttpResponse::BadRequest()
+                                .json(ApiResponse::<()>::error("Error reading file".to_string())));
+                        }
+                    }
+                }
+                file_data = Some(data);
+            }
+            "purpose" => {
+                if let Some(Ok(bytes)) = field.next().await {
+                    purpose = String::from_utf8_lossy(&bytes).to_string();
+                }
+            }
+            "custom_llm_provider" => {
+                if let Some(Ok(bytes)) = field.next().await {
+                    provider = Some(String::from_utf8_lossy(&bytes).to_string());
+                }
+            }
+            _ => {
+                // Skip unknown fields
+                while field.next().await.is_some() {}
+            }
+        }
+    }
+
+    let file = match file_data {
+        Some(data) if !data.is_empty() => data,
+        _ => {
+            return Ok(HttpResponse::BadRequest()
+                .json(ApiResponse::<()>::error("No file provided".to_string())));
+        }
+    };
+
+    let upload_request = FileUploadRequest {
+        file,
+        filename,
+        purpose,
+    };
+
+    let files_service = FilesService::new(state.router.load_full());
+
+    match files_service
+        .upload(upload_request, provider.as_deref())
+        .await
+    {
+        Ok(file) => {
+            let owners = Owners::new(state.storage.cache(), FILE);
+            if let Err(e) = owners.record(&file.id, &tenant).await {
+                error!("Failed to record the owner of file {}: {}", file.id, e);
+                return Ok(errors::gateway_error_to_response(e));
+            }
+            Ok(HttpResponse::Ok().json(file))
+        }
+        Err(e) => {
+            error!("File upload error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// List files endpoint
+pub async fn list_files(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ListFilesQuery>,
+) -> ActixResult<HttpResponse> {
+    let tenant = require_tenant(&req)?;
+
+    let query = query.into_inner();
+    let list_query = FileListQuery {
+        purpose: query.purpose,
+        limit: query.limit,
+        after: query.after,
+        order: query.order,
+    };
+
+    let files_service = FilesService::new(state.router.load_full());
+
+    match files_service
+        .list(list_query, query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(mut files) => {
+            let owners = Owners::new(state.storage.cache(), FILE);
+            let mut owned = Vec::with_capacity(files.data.len());
+            for file in files.data {
+                match owners.owns(&file.id, &tenant).await {
+                    Ok(true) => owned.push(file),
+                    Ok(false) => {}
+                    Err(e) => return Ok(errors::gateway_error_to_response(e)),
+                }
+            }
+            files.data = owned;
+            Ok(HttpResponse::Ok().json(files))
+        }
+        Err(e) => {
+            error!("File list error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// Retrieve file endpoint
+pub async fn retrieve_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<FilesProviderQuery>,
+) -> ActixResult<HttpResponse> {
+    let tenant = require_tenant(&req)?;
+    let owners = Owners::new(state.storage.cache(), FILE);
+    if let Err(e) = owners.require(&path, &tenant).await {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let files_service = FilesService::new(state.router.load_full());
+
+    match files_service
+        .retrieve(&path, query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(file) => Ok(HttpResponse::Ok().json(file)),
+        Err(e) => {
+            error!("File retrieve error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// Delete file endpoint
+pub async fn delete_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<FilesProviderQuery>,
+) -> ActixResult<HttpResponse> {
+    let tenant = require_tenant(&req)?;
+    let owners = Owners::new(state.storage.cache(), FILE);
+    if let Err(e) = owners.require(&path, &tenant).await {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let files_service = FilesService::new(state.router.load_full());
+
+    match files_service
+        .delete(&path, query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(deleted) => {
+            if deleted.deleted
+                && let Err(e) = owners.forget(&path).await
+            {
+                error!("Failed to forget the owner of file {}: {}", path, e);
+            }
+            Ok(HttpResponse::Ok().json(deleted))
+        }
+        Err(e) => {
+            error!("File delete error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// File content endpoint
+///
+/// Returns the raw file bytes, e.g. the JSONL output of a finished batch.
+pub async fn file_content(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<FilesProviderQuery>,
+) -> ActixResult<HttpResponse> {
+    let tenant = require_tenant(&req)?;
+    let owners = Owners::new(state.storage.cache(), FILE);
+    if let Err(e) = owners.require(&path, &tenant).await {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let files_service = FilesService::new(state.router.load_full());
+
+    match files_service
+        .content(&path, query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(content) => Ok(HttpResponse::Ok()
+            .content_type(content.content_type)
+            .body(content.content)),
+        Err(e) => {
+            error!("File content error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
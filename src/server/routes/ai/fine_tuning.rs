@@ -0,0 +1,219 @@
+//! Fine-tuning endpoints
+//!
+//! OpenAI-compatible `/v1/fine_tuning/jobs` API. Jobs run on the provider
+//! selected by `custom_llm_provider` (defaults to OpenAI), which must also
+//! hold the training files. Jobs belong to the tenant that created them, and
+//! are hidden from others.
+
+use crate::core::fine_tuning::{FineTuningJobRequest, FineTuningListQuery, FineTuningService};
+use crate::server::routes::access::{Owners, require_tenant};
+use crate::server::routes::ai::files::FILE;
+use crate::server::routes::errors;
+use crate::server::state::AppState;
+use crate::utils::error::Result;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use serde::Deserialize;
+use tracing::{error, info};
+
+/// Kind under which the owners of fine-tuning jobs are recorded
+const FINE_TUNING_JOB: &str = "fine_tuning.job";
+
+/// Fine-tuning job creation body
+#[derive(Debug, Deserialize)]
+pub struct CreateFineTuningJobBody {
+    /// Provider to run the job on
+    pub custom_llm_provider: Option<String>,
+    /// Job parameters
+    #[serde(flatten)]
+    pub request: FineTuningJobRequest,
+}
+
+/// Query parameters of the fine-tuning endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct FineTuningQuery {
+    /// Provider running the jobs, e.g. "openai" or "azure"
+    pub custom_llm_provider: Option<String>,
+    /// Cursor: the last ID of the previous page
+    pub after: Option<String>,
+    /// Page size
+    pub limit: Option<u32>,
+}
+
+impl FineTuningQuery {
+    fn list_query(&self) -> FineTuningListQuery {
+        FineTuningListQuery {
+            after: self.after.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+/// Require the caller's tenant to own a job
+async fn require_job(state: &AppState, req: &HttpRequest, job_id: &str) -> Result<()> {
+    let tenant = require_tenant(req)?;
+    Owners::new(state.storage.cache(), FINE_TUNING_JOB)
+        .require(job_id, &tenant)
+        .await
+}
+
+/// Create fine-tuning job endpoint
+pub async fn create_fine_tuning_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<FineTuningQuery>,
+    body: web::Json<CreateFineTuningJobBody>,
+) -> ActixResult<HttpResponse> {
+    info!(
+        "Fine-tuning job creation request: model={}",
+        body.request.model
+    );
+
+    let tenant = require_tenant(&req)?;
+
+    let body = body.into_inner();
+    // Jobs may only train on files of the same tenant
+    let files = Owners::new(state.storage.cache(), FILE);
+    for file_id in
+        std::iter::once(&body.request.training_file).chain(body.request.validation_file.as_ref())
+    {
+        if let Err(e) = files.require(file_id, &tenant).await {
+            return Ok(errors::gateway_error_to_response(e));
+        }
+    }
+    let provider = body
+        .custom_llm_provider
+        .or_else(|| query.into_inner().custom_llm_provider);
+
+    let fine_tuning_service = FineTuningService::new(state.router.load_full());
+
+    match fine_tuning_service
+        .create(body.request, provider.as_deref())
+        .await
+    {
+        Ok(job) => {
+            let owners = Owners::new(state.storage.cache(), FINE_TUNING_JOB);
+            if let Err(e) = owners.record(&job.id, &tenant).await {
+                error!("Failed to record the owner of job {}: {}", job.id, e);
+                return Ok(errors::gateway_error_to_response(e));
+            }
+            Ok(HttpResponse::Ok().json(job))
+        }
+        Err(e) => {
+            error!("Fine-tuning job creation error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// List fine-tuning jobs endpoint
+pub async fn list_fine_tuning_jobs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<FineTuningQuery>,
+) -> ActixResult<HttpResponse> {
+    let tenant = require_tenant(&req)?;
+
+    let fine_tuning_service = FineTuningService::new(state.router.load_full());
+
+    match fine_tuning_service
+        .list(query.list_query(), query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(mut jobs) => {
+            let owners = Owners::new(state.storage.cache(), FINE_TUNING_JOB);
+            let mut owned = Vec::with_capacity(jobs.data.len());
+            for job in jobs.data {
+                match owners.owns(&job.id, &tenant).await {
+                    Ok(true) => owned.push(job),
+                    Ok(false) => {}
+                    Err(e) => return Ok(errors::gateway_error_to_response(e)),
+                }
+            }
+            jobs.data = owned;
+            Ok(HttpResponse::Ok().json(jobs))
+        }
+        Err(e) => {
+            error!("Fine-tuning job list error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// Retrieve fine-tuning job endpoint
+pub async fn retrieve_fine_tuning_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<FineTuningQuery>,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = require_job(&state, &req, &path).await {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let fine_tuning_service = FineTuningService::new(state.router.load_full());
+
+    match fine_tuning_service
+        .retrieve(&path, query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(job) => Ok(HttpResponse::Ok().json(job)),
+        Err(e) => {
+            error!("Fine-tuning job retrieve error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// Cancel fine-tuning job endpoint
+pub async fn cancel_fine_tuning_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<FineTuningQuery>,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = require_job(&state, &req, &path).await {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let fine_tuning_service = FineTuningService::new(state.router.load_full());
+
+    match fine_tuning_service
+        .cancel(&path, query.custom_llm_provider.as_deref())
+        .await
+    {
+        Ok(job) => Ok(HttpResponse::Ok().json(job)),
+        Err(e) => {
+            error!("Fine-tuning job cancel error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
+
+/// List fine-tuning job events endpoint
+pub async fn list_fine_tuning_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<FineTuningQuery>,
+) -> ActixResult<HttpResponse> {
+    if let Err(e) = require_job(&state, &req, &path).await {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let fine_tuning_service = FineTuningService::new(state.router.load_full());
+
+    match fine_tuning_service
+        .list_events(
+            &path,
+            query.list_query(),
+            query.custom_llm_provider.as_deref(),
+        )
+        .await
+    {
+        Ok(events) => Ok(HttpResponse::Ok().json(events)),
+        Err(e) => {
+            error!("Fine-tuning job events error: {}", e);
+            Ok(errors::gateway_error_to_response(e))
+        }
+    }
+}
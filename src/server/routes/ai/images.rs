@@ -8,6 +8,7 @@ use crate::server::routes::errors;
 use crate::server::state::AppState;
 use crate::utils::error::GatewayError;
 use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use std::collections::HashMap;
 use tracing::{error, info};
 
 use super::context::get_request_context;
@@ -26,7 +27,9 @@ pub async fn image_generations(
     let context = get_request_context(&req)?;
 
     // Route request through the core router
-    match handle_image_generation_via_pool(&state.router, request.into_inner(), context).await {
+    match handle_image_generation_via_pool(&state.router.load_full(), request.into_inner(), context)
+        .await
+    {
         Ok(response) => Ok(HttpResponse::Ok().json(response)),
         Err(e) => {
             error!("Image generation error: {}", e);
@@ -51,6 +54,7 @@ pub async fn handle_image_generation_via_pool(
         user: request.user,
         quality: None,
         style: None,
+        extra_params: HashMap::new(),
     };
 
     // Create core context
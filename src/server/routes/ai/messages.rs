@@ -0,0 +1,1107 @@
+//! Anthropic Messages API endpoint
+//!
+//! `/v1/messages` accepts Anthropic wire requests, translates them into the
+//! unified chat format and routes them to any configured provider, so clients
+//! built on the Anthropic SDK can use non-Anthropic models. Responses, stream
+//! events and errors are returned in Anthropic's format.
+
+use crate::core::completion::{CompletionOptions, CompletionResponse, CompletionStream};
+use crate::core::models::RequestContext;
+use crate::core::observability::{TracedStream, scrub_secrets};
+use crate::core::streaming::types::Event;
+use crate::core::tokenizers::{count_text_tokens, token_counter};
+use crate::core::types::responses::{FunctionCallDelta, ToolCallDelta};
+use crate::core::types::{
+    AnthropicMetadata, CacheControl, ChatMessage, ContentPart, FinishReason, FunctionCall,
+    FunctionChoice, FunctionDefinition, ImageUrl, MessageContent, MessageRole, ThinkingContent,
+    Tool, ToolCall, ToolChoice, ToolType,
+};
+use crate::server::middleware::{DeferredUsage, RequestUsage};
+use crate::server::state::AppState;
+use crate::services::callbacks::{CallContext, CallRequest, GenerationLog};
+use crate::utils::error::GatewayError;
+use actix_web::http::StatusCode;
+use actix_web::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use actix_web::{HttpRequest, HttpResponse, ResponseError, Result as ActixResult, web};
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use super::context::{get_request_context, log_api_usage};
+use super::routing::{complete, complete_stream};
+
+/// Anthropic Messages API request
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessagesRequest {
+    /// Model name, optionally provider-prefixed (e.g. "openai/gpt-4o")
+    pub model: String,
+    pub messages: Vec<InputMessage>,
+    pub max_tokens: u32,
+    #[serde(default)]
+    pub system: Option<SystemPrompt>,
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub top_p: Option<f32>,
+    /// Accepted for compatibility; most providers have no equivalent
+    #[serde(default)]
+    pub top_k: Option<u32>,
+    #[serde(default)]
+    pub stop_sequences: Option<Vec<String>>,
+    #[serde(default)]
+    pub stream: bool,
+    #[serde(default)]
+    pub tools: Option<Vec<ToolDefinition>>,
+    #[serde(default)]
+    pub tool_choice: Option<ToolChoiceParam>,
+    #[serde(default)]
+    pub metadata: Option<AnthropicMetadata>,
+}
+
+/// System prompt, as a string or text blocks
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum SystemPrompt {
+    Text(String),
+    Blocks(Vec<TextBlock>),
+}
+
+/// Text block of a system prompt
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextBlock {
+    pub text: String,
+    #[serde(default)]
+    pub cache_control: Option<CacheControl>,
+}
+
+/// Input message
+#[derive(Debug, Clone, Deserialize)]
+pub struct InputMessage {
+    pub role: InputRole,
+    pub content: InputContent,
+}
+
+/// Input message role
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputRole {
+    User,
+    Assistant,
+}
+
+/// Message content, as a string or content blocks
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum InputContent {
+    Text(String),
+    Blocks(Vec<ContentBlock>),
+}
+
+/// Input content block
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlock {
+    Text {
+        text: String,
+        #[serde(default)]
+        cache_control: Option<CacheControl>,
+    },
+    Image {
+        source: ImageBlockSource,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+    },
+    ToolResult {
+        tool_use_id: String,
+        #[serde(default)]
+        content: Option<ToolResultContent>,
+        #[serde(default)]
+        is_error: Option<bool>,
+    },
+    Thinking {
+        thinking: String,
+        #[serde(default)]
+        signature: Option<String>,
+    },
+    /// Block types without a unified equivalent (e.g. `redacted_thinking`)
+    #[serde(other)]
+    Unsupported,
+}
+
+/// Image block source
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImageBlockSource {
+    Base64 { media_type: String, data: String },
+    Url { url: String },
+}
+
+/// Tool result content, as a string or text blocks
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ToolResultContent {
+    Text(String),
+    Blocks(Vec<Value>),
+}
+
+/// Tool definition
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub input_schema: Value,
+}
+
+/// Tool choice
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolChoiceParam {
+    Auto,
+    Any,
+    None,
+    Tool { name: String },
+}
+
+impl MessagesRequest {
+    /// Convert to the model, messages and options for `completion`
+    fn into_completion(self) -> (String, Vec<ChatMessage>, CompletionOptions) {
+        let mut messages = Vec::new();
+
+        match self.system {
+            Some(SystemPrompt::Text(text)) => messages.push(ChatMessage {
+                role: MessageRole::System,
+                content: Some(MessageContent::Text(text)),
+                ..Default::default()
+            }),
+            Some(SystemPrompt::Blocks(blocks)) => messages.push(ChatMessage {
+                role: MessageRole::System,
+                content: Some(MessageContent::Parts(
+                    blocks
+                        .into_iter()
+                        .map(|block| ContentPart::Text {
+                            text: block.text,
+                            cache_control: block.cache_control,
+                        })
+                        .collect(),
+                )),
+                ..Default::default()
+            }),
+            None => {}
+        }
+
+        for message in self.messages {
+            match message.role {
+                InputRole::User => push_user_message(&mut messages, message.content),
+                InputRole::Assistant => messages.push(assistant_message(message.content)),
+            }
+        }
+
+        let tools = self.tools.map(|tools| {
+            tools
+                .into_iter()
+                .map(|tool| Tool {
+                    tool_type: ToolType::Function,
+                    function: FunctionDefinition {
+                        name: tool.name,
+                        description: tool.description,
+                        parameters: Some(tool.input_schema),
+                    },
+                })
+                .collect()
+        });
+
+        let tool_choice = self.tool_choice.map(|choice| match choice {
+            ToolChoiceParam::Auto => ToolChoice::String("auto".to_string()),
+            ToolChoiceParam::Any => ToolChoice::String("required".to_string()),
+            ToolChoiceParam::None => ToolChoice::String("none".to_string()),
+            ToolChoiceParam::Tool { name } => ToolChoice::Specific {
+                choice_type: "function".to_string(),
+                function: Some(FunctionChoice { name }),
+            },
+        });
+
+        let options = CompletionOptions {
+            temperature: self.temperature,
+            max_tokens: Some(self.max_tokens),
+            top_p: self.top_p,
+            stop: self.stop_sequences,
+            stream: self.stream,
+            tools,
+            tool_choice,
+            user: self.metadata.and_then(|metadata| metadata.user_id),
+            ..Default::default()
+        };
+
+        (self.model, messages, options)
+    }
+}
+
+/// Append a user turn
+///
+/// Tool results become tool messages, ahead of any other content in the turn.
+fn push_user_message(messages: &mut Vec<ChatMessage>, content: InputContent) {
+    let blocks = match content {
+        InputContent::Text(text) => {
+            messages.push(ChatMessage {
+                role: MessageRole::User,
+                content: Some(MessageContent::Text(text)),
+                ..Default::default()
+            });
+            return;
+        }
+        InputContent::Blocks(blocks) => blocks,
+    };
+
+    let mut parts = Vec::new();
+    for block in blocks {
+        match block {
+            ContentBlock::Text {
+                text,
+                cache_control,
+            } => parts.push(ContentPart::Text {
+                text,
+                cache_control,
+            }),
+            ContentBlock::Image { source } => {
+                let url = match source {
+                    ImageBlockSource::Base64 { media_type, data } => {
+                        format!("data:{};base64,{}", media_type, data)
+                    }
+                    ImageBlockSource::Url { url } => url,
+                };
+                parts.push(ContentPart::ImageUrl {
+                    image_url: ImageUrl { url, detail: None },
+                });
+            }
+            ContentBlock::ToolResult {
+                tool_use_id,
+                content,
+                ..
+            } => messages.push(ChatMessage {
+                role: MessageRole::Tool,
+                content: Some(MessageContent::Text(tool_result_text(content))),
+                tool_call_id: Some(tool_use_id),
+                ..Default::default()
+            }),
+            _ => {}
+        }
+    }
+
+    if !parts.is_empty() {
+        messages.push(ChatMessage {
+            role: MessageRole::User,
+            content: Some(MessageContent::Parts(parts)),
+            ..Default::default()
+        });
+    }
+}
+
+/// Convert an assistant turn, with `tool_use` blocks as tool calls
+fn assistant_message(content: InputContent) -> ChatMessage {
+    let blocks = match content {
+        InputContent::Text(text) => {
+            return ChatMessage {
+                role: MessageRole::Assistant,
+                content: Some(MessageContent::Text(text)),
+                ..Default::default()
+            };
+        }
+        InputContent::Blocks(blocks) => blocks,
+    };
+
+    let mut text = String::new();
+    let mut tool_calls = Vec::new();
+    let mut thinking = None;
+    for block in blocks {
+        match block {
+            ContentBlock::Text { text: t, .. } => text.push_str(&t),
+            ContentBlock::ToolUse { id, name, input } => tool_calls.push(ToolCall {
+                id,
+                tool_type: "function".to_string(),
+                function: FunctionCall {
+                    name,
+                    arguments: input.to_string(),
+                },
+            }),
+            ContentBlock::Thinking {
+                thinking: text,
+                signature,
+            } => thinking = Some(ThinkingContent::Text { text, signature }),
+            _ => {}
+        }
+    }
+
+    ChatMessage {
+        role: MessageRole::Assistant,
+        content: (!text.is_empty()).then_some(MessageContent::Text(text)),
+        thinking,
+        tool_calls: (!tool_calls.is_empty()).then_some(tool_calls),
+        ..Default::default()
+    }
+}
+
+/// Flatten tool result content to text
+fn tool_result_text(content: Option<ToolResultContent>) -> String {
+    match content {
+        Some(ToolResultContent::Text(text)) => text,
+        Some(ToolResultContent::Blocks(blocks)) => blocks
+            .iter()
+            .filter_map(|block| block["text"].as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => String::new(),
+    }
+}
+
+/// Anthropic stop reason for a finish reason
+fn stop_reason(finish_reason: Option<&FinishReason>) -> &'static str {
+    match finish_reason {
+        Some(FinishReason::Length) => "max_tokens",
+        Some(FinishReason::ToolCalls | FinishReason::FunctionCall) => "tool_use",
+        Some(FinishReason::ContentFilter) => "refusal",
+        Some(FinishReason::Stop) | None => "end_turn",
+    }
+}
+
+/// Anthropic message ID, keeping the upstream ID when it already is one
+fn message_id(id: &str) -> String {
+    if id.starts_with("msg_") {
+        id.to_string()
+    } else {
+        format!("msg_{}", Uuid::new_v4().simple())
+    }
+}
+
+/// Convert a completion response to an Anthropic message
+fn to_anthropic_message(model: &str, response: &CompletionResponse) -> Value {
+    let choice = response.choices.first();
+    let mut content = Vec::new();
+
+    if let Some(message) = choice.map(|choice| &choice.message) {
+        if let Some(text) = message.content.as_ref().map(|c| c.to_string()) {
+            if !text.is_empty() {
+                content.push(json!({"type": "text", "text": text}));
+            }
+        }
+        for tool_call in message.tool_calls.iter().flatten() {
+            content.push(json!({
+                "type": "tool_use",
+                "id": tool_call.id,
+                "name": tool_call.function.name,
+                "input": serde_json::from_str::<Value>(&tool_call.function.arguments)
+                    .unwrap_or(json!({})),
+            }));
+        }
+    }
+
+    // Anthropic's input_tokens excludes cached prompt tokens
+    let usage = response.usage.as_ref();
+    let cache_creation = usage.and_then(|u| u.cache_creation_input_tokens);
+    let cache_read = usage.and_then(|u| {
+        u.cache_read_input_tokens.or_else(|| {
+            u.prompt_tokens_details
+                .as_ref()
+                .and_then(|details| details.cached_tokens)
+        })
+    });
+    let input_tokens = usage.map_or(0, |u| {
+        u.prompt_tokens
+            .saturating_sub(cache_creation.unwrap_or(0))
+            .saturating_sub(cache_read.unwrap_or(0))
+    });
+
+    let mut anthropic_usage = json!({
+        "input_tokens": input_tokens,
+        "output_tokens": usage.map_or(0, |u| u.completion_tokens),
+    });
+    if let Some(tokens) = cache_creation {
+        anthropic_usage["cache_creation_input_tokens"] = json!(tokens);
+    }
+    if let Some(tokens) = cache_read {
+        anthropic_usage["cache_read_input_tokens"] = json!(tokens);
+    }
+
+    let mut message = json!({
+        "id": message_id(&response.id),
+        "type": "message",
+        "role": "assistant",
+        "model": model,
+        "content": content,
+        "stop_reason": stop_reason(choice.and_then(|c| c.finish_reason.as_ref())),
+        "stop_sequence": null,
+        "usage": anthropic_usage,
+    });
+    // Findings of annotating guardrails
+    if let Some(guardrails) = response
+        .provider_specific_fields
+        .as_ref()
+        .and_then(|fields| fields.get("guardrails"))
+    {
+        message["guardrails"] = guardrails.clone();
+    }
+    message
+}
+
+/// Anthropic error body
+fn error_body(status: StatusCode, message: &str) -> Value {
+    let error_type = match status.as_u16() {
+        400 => "invalid_request_error",
+        401 => "authentication_error",
+        403 => "permission_error",
+        404 => "not_found_error",
+        413 => "request_too_large",
+        429 => "rate_limit_error",
+        529 => "overloaded_error",
+        _ => "api_error",
+    };
+
+    json!({
+        "type": "error",
+        "error": {"type": error_type, "message": scrub_secrets(message)},
+    })
+}
+
+/// Status and Anthropic error body of a gateway error
+///
+/// Server errors are masked, so their details are only logged.
+fn client_error(error: &GatewayError) -> (StatusCode, Value) {
+    let status = error.error_response().status();
+    let message = if status.is_server_error() {
+        "Internal server error".to_string()
+    } else {
+        error.to_string()
+    };
+    (status, error_body(status, &message))
+}
+
+/// Anthropic error response for a gateway error
+fn error_response(error: &GatewayError) -> HttpResponse {
+    let (status, body) = client_error(error);
+    HttpResponse::build(status).json(body)
+}
+
+/// Anthropic Messages endpoint
+pub async fn messages(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    request: web::Json<MessagesRequest>,
+) -> ActixResult<HttpResponse> {
+    let context = get_request_context(&req)?;
+    info!("Messages request for model: {}", request.model);
+
+    if request.messages.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(error_body(
+            StatusCode::BAD_REQUEST,
+            "messages: at least one message is required",
+        )));
+    }
+    if request.max_tokens == 0 {
+        return Ok(HttpResponse::BadRequest().json(error_body(
+            StatusCode::BAD_REQUEST,
+            "max_tokens: must be greater than or equal to 1",
+        )));
+    }
+
+    let (model, messages, options) = request.into_inner().into_completion();
+
+    let call = CallContext::new(&context, "messages", &model);
+    let mut call_request = CallRequest {
+        model,
+        messages,
+        options,
+    };
+    if let Err(e) = state.callbacks.pre_call(&call, &mut call_request).await {
+        return Ok(error_response(&e));
+    }
+    let CallRequest {
+        model,
+        mut messages,
+        options,
+    } = call_request;
+
+    // Check the prompt with the guardrails of the model group and key
+    let guardrails = state
+        .guardrails
+        .pipeline(&model, call.api_key_id.as_deref());
+    if let Err(e) = guardrails.pre_call(&mut messages).await {
+        return Ok(error_response(&e));
+    }
+
+    if options.stream {
+        // Usage is charged once the stream ends, when the completion is known
+        let prompt_tokens = token_counter(&model, &messages);
+        let prompt_cost = state
+            .pricing
+            .get_cost_per_token(&model)
+            .map_or(0.0, |(input, _)| prompt_tokens as f64 * input);
+        RequestUsage::new(&model)
+            .with_tokens(prompt_tokens, 0, prompt_cost)
+            .deferred()
+            .record(&req);
+        let input = state
+            .callbacks
+            .is_enabled()
+            .then(|| serde_json::to_value(&messages).unwrap_or_default());
+        let stream = match complete_stream(&state, &context, &model, messages, options).await {
+            Ok(stream) => guardrails.guard_stream(stream),
+            Err(e) => {
+                error!("Failed to create messages stream: {}", e);
+                state.callbacks.log_failure(&call, &e);
+                return Ok(error_response(&e));
+            }
+        };
+        let streamed = StreamedCall {
+            call,
+            context,
+            input,
+            prompt_tokens,
+            usage: DeferredUsage::new(&state, &req),
+        };
+        return stream_messages(&state, model, stream, streamed);
+    }
+
+    let log_payloads = state
+        .request_logs
+        .as_ref()
+        .is_some_and(|logs| logs.log_payloads());
+    let input = (state.callbacks.is_enabled() || log_payloads)
+        .then(|| serde_json::to_value(&messages).unwrap_or_default());
+
+    let result = match complete(&state, &context, &model, messages, options).await {
+        Ok(mut response) => guardrails
+            .check_response(&mut response)
+            .await
+            .map(|()| response),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(response) => {
+            let output = input.as_ref().map(|_| {
+                let message = response.choices.first().map(|choice| &choice.message);
+                serde_json::to_value(message).unwrap_or_default()
+            });
+            let (prompt_tokens, completion_tokens, cost) = match &response.usage {
+                Some(usage) => {
+                    let cost = state
+                        .pricing
+                        .get_cost_per_token(&model)
+                        .map(|(input, output)| {
+                            usage.prompt_tokens as f64 * input
+                                + usage.completion_tokens as f64 * output
+                        })
+                        .unwrap_or(0.0);
+                    log_api_usage(&context, &model, usage.total_tokens, cost).await;
+                    (usage.prompt_tokens, usage.completion_tokens, cost)
+                }
+                None => (0, 0, 0.0),
+            };
+            if state.callbacks.is_enabled() {
+                state.callbacks.log_success(
+                    GenerationLog::new(&context, "messages", &model)
+                        .with_content(
+                            input.clone().unwrap_or_default(),
+                            output.clone().unwrap_or_default(),
+                        )
+                        .with_usage(prompt_tokens, completion_tokens, cost),
+                );
+            }
+            RequestUsage::new(&model)
+                .with_tokens(prompt_tokens, completion_tokens, cost)
+                .with_payloads(input, output)
+                .record(&req);
+
+            Ok(HttpResponse::Ok().json(to_anthropic_message(&model, &response)))
+        }
+        Err(e) => {
+            error!("Messages error: {}", e);
+            state.callbacks.log_failure(&call, &e);
+            RequestUsage::new(&model).with_error(&e).record(&req);
+            Ok(error_response(&e))
+        }
+    }
+}
+
+/// Call of a streamed message, settled once the stream ends
+struct StreamedCall {
+    call: CallContext,
+    context: RequestContext,
+    /// Prompt, when the callbacks log it
+    input: Option<Value>,
+    prompt_tokens: u32,
+    usage: DeferredUsage,
+}
+
+/// Content blocks of a streamed message, opened as the deltas arrive
+///
+/// Text deltas go to a `text` block, and the deltas of each tool call to a
+/// `tool_use` block of its own, each block closed as the next one opens.
+#[derive(Debug, Default)]
+struct StreamedBlocks {
+    /// Blocks opened so far; the last one is open unless `closed`
+    count: usize,
+    closed: bool,
+    /// Stream index of the tool call of the open block, if a `tool_use` one
+    tool_index: Option<u32>,
+    text: String,
+    tool_calls: Vec<ToolCall>,
+}
+
+impl StreamedBlocks {
+    fn is_open(&self) -> bool {
+        self.count > 0 && !self.closed
+    }
+
+    fn index(&self) -> usize {
+        self.count - 1
+    }
+
+    /// Close the open block and open another
+    fn open(&mut self, events: &mut Vec<Value>, content_block: Value) {
+        self.close(events);
+        self.count += 1;
+        self.closed = false;
+        events.push(json!({
+            "type": "content_block_start",
+            "index": self.index(),
+            "content_block": content_block,
+        }));
+    }
+
+    fn close(&mut self, events: &mut Vec<Value>) {
+        if self.is_open() {
+            events.push(json!({"type": "content_block_stop", "index": self.index()}));
+            self.closed = true;
+        }
+    }
+
+    /// Events of a text delta
+    fn text(&mut self, text: &str) -> Vec<Value> {
+        let mut events = Vec::new();
+        if !self.is_open() || self.tool_index.is_some() {
+            self.tool_index = None;
+            self.open(&mut events, json!({"type": "text", "text": ""}));
+        }
+        self.text.push_str(text);
+        events.push(json!({
+            "type": "content_block_delta",
+            "index": self.index(),
+            "delta": {"type": "text_delta", "text": text},
+        }));
+        events
+    }
+
+    /// Events of a tool call delta
+    ///
+    /// A delta with an ID, or of another call, starts a call.
+    fn tool_call(&mut self, delta: ToolCallDelta) -> Vec<Value> {
+        let mut events = Vec::new();
+        let function = delta.function.unwrap_or(FunctionCallDelta {
+            name: None,
+            arguments: None,
+        });
+        if delta.id.is_some() || !self.is_open() || self.tool_index != Some(delta.index) {
+            let id = delta
+                .id
+                .unwrap_or_else(|| format!("toolu_{}", Uuid::new_v4().simple()));
+            let name = function.name.unwrap_or_default();
+            self.tool_index = Some(delta.index);
+            self.open(
+                &mut events,
+                json!({"type": "tool_use", "id": id, "name": name, "input": {}}),
+            );
+            self.tool_calls.push(ToolCall {
+                id,
+                tool_type: "function".to_string(),
+                function: FunctionCall {
+                    name,
+                    arguments: String::new(),
+                },
+            });
+        }
+        if let Some(arguments) = function.arguments.filter(|a| !a.is_empty()) {
+            if let Some(tool_call) = self.tool_calls.last_mut() {
+                tool_call.function.arguments.push_str(&arguments);
+            }
+            events.push(json!({
+                "type": "content_block_delta",
+                "index": self.index(),
+                "delta": {"type": "input_json_delta", "partial_json": arguments},
+            }));
+        }
+        events
+    }
+
+    /// Events closing the message's blocks, opening an empty text block if
+    /// none was opened
+    fn finish(&mut self) -> Vec<Value> {
+        let mut events = Vec::new();
+        if self.count == 0 {
+            self.open(&mut events, json!({"type": "text", "text": ""}));
+        }
+        self.close(&mut events);
+        events
+    }
+
+    /// The streamed message, as the assistant message of a completion
+    fn message(&self) -> ChatMessage {
+        ChatMessage {
+            role: MessageRole::Assistant,
+            content: Some(MessageContent::Text(self.text.clone())),
+            tool_calls: (!self.tool_calls.is_empty()).then(|| self.tool_calls.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Tokens of the streamed text and tool calls
+    fn completion_tokens(&self, model: &str) -> u32 {
+        let mut completion = self.text.clone();
+        for tool_call in &self.tool_calls {
+            completion.push_str(&tool_call.function.name);
+            completion.push_str(&tool_call.function.arguments);
+        }
+        count_text_tokens(model, &completion)
+    }
+}
+
+/// Stream a completion as Anthropic message events
+///
+/// Usage is estimated with the model's tokenizer, since providers do not
+/// report it on every stream, and charged once the stream ends.
+fn stream_messages(
+    state: &AppState,
+    model: String,
+    mut stream: CompletionStream,
+    streamed: StreamedCall,
+) -> ActixResult<HttpResponse> {
+    let callbacks = Arc::clone(&state.callbacks);
+    let pricing = state.pricing.get_cost_per_token(&model);
+    let StreamedCall {
+        call,
+        context,
+        input,
+        prompt_tokens,
+        usage: deferred_usage,
+    } = streamed;
+
+    let event = |data: Value| {
+        let name = data["type"].as_str().unwrap_or_default().to_string();
+        Event::default().event(&name).data(&data.to_string())
+    };
+    let span = tracing::info_span!("sse.stream", model = %model);
+
+    let sse_stream = async_stream::stream! {
+        yield Ok::<_, GatewayError>(event(json!({
+            "type": "message_start",
+            "message": {
+                "id": message_id(""),
+                "type": "message",
+                "role": "assistant",
+                "model": model,
+                "content": [],
+                "stop_reason": null,
+                "stop_sequence": null,
+                "usage": {"input_tokens": prompt_tokens, "output_tokens": 0},
+            },
+        })).to_bytes());
+
+        let mut blocks = StreamedBlocks::default();
+        let mut finish_reason = None;
+        let mut completed = true;
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(chunk) => {
+                    callbacks.on_stream_chunk(&call, &chunk).await;
+                    for choice in chunk.choices {
+                        let mut events = Vec::new();
+                        if let Some(text) = choice.delta.content.filter(|t| !t.is_empty()) {
+                            events.extend(blocks.text(&text));
+                        }
+                        for tool_call in choice.delta.tool_calls.into_iter().flatten() {
+                            events.extend(blocks.tool_call(tool_call));
+                        }
+                        for data in events {
+                            yield Ok(event(data).to_bytes());
+                        }
+                        if choice.finish_reason.is_some() {
+                            finish_reason = choice.finish_reason;
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!("Messages stream error: {}", e);
+                    callbacks.log_failure(&call, &e);
+                    let (_, body) = client_error(&e);
+                    yield Ok(event(body).to_bytes());
+                    completed = false;
+                    break;
+                }
+            }
+        }
+
+        // Charge the streamed tokens, which the provider bills even for
+        // streams that failed midway
+        let completion_tokens = blocks.completion_tokens(&model);
+        let cost = pricing.map_or(0.0, |(input, output)| {
+            prompt_tokens as f64 * input + completion_tokens as f64 * output
+        });
+        let usage = RequestUsage::new(&model)
+            .with_tokens(prompt_tokens, completion_tokens, cost);
+        deferred_usage.charge(&usage).await;
+        if !completed {
+            return;
+        }
+        if let Some(input) = input {
+            let output = serde_json::to_value(blocks.message()).unwrap_or_default();
+            callbacks.log_success(
+                GenerationLog::new(&context, "messages", &model)
+                    .with_content(input, output)
+                    .with_usage(prompt_tokens, completion_tokens, cost),
+            );
+        }
+
+        for data in blocks.finish() {
+            yield Ok(event(data).to_bytes());
+        }
+        yield Ok(event(json!({
+            "type": "message_delta",
+            "delta": {
+                "stop_reason": stop_reason(finish_reason.as_ref()),
+                "stop_sequence": null,
+            },
+            "usage": {"output_tokens": completion_tokens},
+        })).to_bytes());
+        yield Ok(event(json!({"type": "message_stop"})).to_bytes());
+    };
+
+    Ok(HttpResponse::Ok()
+        .insert_header((CONTENT_TYPE, "text/event-stream"))
+        .insert_header((CACHE_CONTROL, "no-cache"))
+        .insert_header(("Connection", "keep-alive"))
+        .streaming(TracedStream::new(sse_stream, span)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::completion::Choice;
+    use crate::core::types::Usage;
+
+    #[test]
+    fn test_messages_request_conversion() {
+        let request: MessagesRequest = serde_json::from_value(json!({
+            "model": "openai/gpt-4o",
+            "max_tokens": 1024,
+            "system": [{"type": "text", "text": "You are terse.", "cache_control": {"type": "ephemeral"}}],
+            "stop_sequences": ["\n\nHuman:"],
+            "metadata": {"user_id": "user-1"},
+            "tools": [{
+                "name": "get_weather",
+                "description": "Current weather",
+                "input_schema": {"type": "object", "properties": {"city": {"type": "string"}}}
+            }],
+            "tool_choice": {"type": "any"},
+            "messages": [
+                {"role": "user", "content": [
+                    {"type": "text", "text": "Weather in Paris?"},
+                    {"type": "image", "source": {"type": "base64", "media_type": "image/png", "data": "iVBORw0KGgo="}}
+                ]},
+                {"role": "assistant", "content": [
+                    {"type": "text", "text": "Checking."},
+                    {"type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {"city": "Paris"}}
+                ]},
+                {"role": "user", "content": [
+                    {"type": "tool_result", "tool_use_id": "toolu_01", "content": [{"type": "text", "text": "18C, sunny"}]},
+                    {"type": "text", "text": "Thanks"}
+                ]}
+            ]
+        }))
+        .unwrap();
+
+        let (model, messages, options) = request.into_completion();
+        assert_eq!(model, "openai/gpt-4o");
+        assert_eq!(options.max_tokens, Some(1024));
+        assert_eq!(options.user.as_deref(), Some("user-1"));
+        assert_eq!(options.stop, Some(vec!["\n\nHuman:".to_string()]));
+        assert_eq!(
+            options.tools.as_ref().unwrap()[0].function.name,
+            "get_weather"
+        );
+        assert!(matches!(options.tool_choice, Some(ToolChoice::String(ref c)) if c == "required"));
+
+        let roles: Vec<_> = messages.iter().map(|m| m.role.clone()).collect();
+        assert_eq!(
+            roles,
+            vec![
+                MessageRole::System,
+                MessageRole::User,
+                MessageRole::Assistant,
+                MessageRole::Tool,
+                MessageRole::User,
+            ]
+        );
+
+        let Some(MessageContent::Parts(parts)) = &messages[1].content else {
+            panic!("expected content parts");
+        };
+        assert!(matches!(
+            &parts[1],
+            ContentPart::ImageUrl { image_url } if image_url.url == "data:image/png;base64,iVBORw0KGgo="
+        ));
+
+        let tool_calls = messages[2].tool_calls.as_ref().unwrap();
+        assert_eq!(tool_calls[0].id, "toolu_01");
+        assert_eq!(tool_calls[0].function.arguments, r#"{"city":"Paris"}"#);
+
+        assert_eq!(messages[3].tool_call_id.as_deref(), Some("toolu_01"));
+        assert_eq!(
+            messages[3].content.as_ref().unwrap().to_string(),
+            "18C, sunny"
+        );
+    }
+
+    #[test]
+    fn test_to_anthropic_message() {
+        let response = CompletionResponse {
+            id: "chatcmpl-123".to_string(),
+            object: "chat.completion".to_string(),
+            created: 0,
+            model: "gpt-4o".to_string(),
+            choices: vec![Choice {
+                index: 0,
+                message: ChatMessage {
+                    role: MessageRole::Assistant,
+                    content: Some(MessageContent::Text("Let me check.".to_string())),
+                    tool_calls: Some(vec![ToolCall {
+                        id: "call_1".to_string(),
+                        tool_type: "function".to_string(),
+                        function: FunctionCall {
+                            name: "get_weather".to_string(),
+                            arguments: r#"{"city":"Paris"}"#.to_string(),
+                        },
+                    }]),
+                    ..Default::default()
+                },
+                finish_reason: Some(FinishReason::ToolCalls),
+            }],
+            usage: Some(Usage {
+                prompt_tokens: 120,
+                completion_tokens: 15,
+                total_tokens: 135,
+                cache_read_input_tokens: Some(100),
+                ..Default::default()
+            }),
+            provider_specific_fields: None,
+        };
+
+        let message = to_anthropic_message("openai/gpt-4o", &response);
+        assert!(message["id"].as_str().unwrap().starts_with("msg_"));
+        assert_eq!(message["model"], "openai/gpt-4o");
+        assert_eq!(message["stop_reason"], "tool_use");
+        assert_eq!(message["content"][0]["text"], "Let me check.");
+        assert_eq!(message["content"][1]["type"], "tool_use");
+        assert_eq!(message["content"][1]["input"]["city"], "Paris");
+        assert_eq!(message["usage"]["input_tokens"], 20);
+        assert_eq!(message["usage"]["cache_read_input_tokens"], 100);
+        assert_eq!(message["usage"]["output_tokens"], 15);
+    }
+
+    fn tool_call_delta(
+        index: u32,
+        id: Option<&str>,
+        name: Option<&str>,
+        arguments: &str,
+    ) -> ToolCallDelta {
+        ToolCallDelta {
+            index,
+            id: id.map(str::to_string),
+            tool_type: id.map(|_| "function".to_string()),
+            function: Some(FunctionCallDelta {
+                name: name.map(str::to_string),
+                arguments: Some(arguments.to_string()),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_streamed_blocks() {
+        let mut blocks = StreamedBlocks::default();
+        let mut events = blocks.text("Checking.");
+        events.extend(blocks.tool_call(tool_call_delta(
+            0,
+            Some("call_1"),
+            Some("get_weather"),
+            "",
+        )));
+        events.extend(blocks.tool_call(tool_call_delta(0, None, None, r#"{"city":"#)));
+        events.extend(blocks.tool_call(tool_call_delta(0, None, None, r#""Paris"}"#)));
+        events.extend(blocks.tool_call(tool_call_delta(1, Some("call_2"), Some("get_time"), "{}")));
+        events.extend(blocks.finish());
+
+        let types: Vec<_> = events.iter().map(|e| e["type"].as_str().unwrap()).collect();
+        assert_eq!(
+            types,
+            vec![
+                "content_block_start",
+                "content_block_delta",
+                "content_block_stop",
+                "content_block_start",
+                "content_block_delta",
+                "content_block_delta",
+                "content_block_stop",
+                "content_block_start",
+                "content_block_delta",
+                "content_block_stop",
+            ]
+        );
+        assert_eq!(events[3]["index"], 1);
+        assert_eq!(events[3]["content_block"]["type"], "tool_use");
+        assert_eq!(events[3]["content_block"]["id"], "call_1");
+        assert_eq!(events[3]["content_block"]["name"], "get_weather");
+        assert_eq!(events[4]["delta"]["type"], "input_json_delta");
+        assert_eq!(events[4]["delta"]["partial_json"], r#"{"city":"#);
+        assert_eq!(events[7]["index"], 2);
+
+        let message = blocks.message();
+        let tool_calls = message.tool_calls.unwrap();
+        assert_eq!(tool_calls[0].function.arguments, r#"{"city":"Paris"}"#);
+        assert_eq!(tool_calls[1].function.name, "get_time");
+
+        // A message without content still has a text block
+        let mut blocks = StreamedBlocks::default();
+        let types: Vec<_> = blocks.finish().iter().map(|e| e["type"].clone()).collect();
+        assert_eq!(types, vec!["content_block_start", "content_block_stop"]);
+    }
+
+    #[test]
+    fn test_client_error_masks_server_errors() {
+        let (status, body) =
+            client_error(&GatewayError::Internal("db password leaked".to_string()));
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["error"]["message"], "Internal server error");
+
+        let (status, body) = client_error(&GatewayError::unauthorized("Invalid API key"));
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(
+            body["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("Invalid API key")
+        );
+    }
+
+    #[test]
+    fn test_error_body() {
+        let body = error_body(StatusCode::TOO_MANY_REQUESTS, "slow down");
+        assert_eq!(body["type"], "error");
+        assert_eq!(body["error"]["type"], "rate_limit_error");
+        assert_eq!(stop_reason(Some(&FinishReason::Length)), "max_tokens");
+    }
+}
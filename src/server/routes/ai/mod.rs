@@ -5,15 +5,22 @@
 #![allow(dead_code)]
 
 // Module declarations
+mod assistants;
 mod audio;
 mod chat;
 mod completions;
 mod context;
 mod embeddings;
+mod files;
+mod fine_tuning;
 mod images;
+mod messages;
 mod models;
+mod realtime;
+mod routing;
 
 // Public re-exports for backward compatibility
+pub use assistants::{RecordedRuns, assistants_passthrough};
 pub use audio::{audio_speech, audio_transcriptions, audio_translations};
 pub use chat::chat_completions;
 pub use completions::completions;
@@ -22,23 +29,56 @@ pub use context::{
     log_api_usage,
 };
 pub use embeddings::embeddings;
+pub use files::{delete_file, file_content, list_files, retrieve_file, upload_file};
+pub use fine_tuning::{
+    cancel_fine_tuning_job, create_fine_tuning_job, list_fine_tuning_events, list_fine_tuning_jobs,
+    retrieve_fine_tuning_job,
+};
 pub use images::image_generations;
+pub use messages::messages;
 pub use models::{get_model, list_models};
+pub use realtime::{RealtimeSessionGuard, RealtimeSessions, realtime};
 
 use actix_web::web;
 
+/// Scope of the AI API routes
+pub const SCOPE: &str = "/v1";
+
 /// Configure AI API routes
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
-        web::scope("/v1")
+        web::scope(SCOPE)
             // Chat completions
             .route("/chat/completions", web::post().to(chat_completions))
+            // Anthropic Messages API
+            .route("/messages", web::post().to(messages))
             // Text completions (legacy)
             .route("/completions", web::post().to(completions))
             // Embeddings
             .route("/embeddings", web::post().to(embeddings))
             // Image generation
             .route("/images/generations", web::post().to(image_generations))
+            // Files
+            .route("/files", web::post().to(upload_file))
+            .route("/files", web::get().to(list_files))
+            .route("/files/{file_id}", web::get().to(retrieve_file))
+            .route("/files/{file_id}", web::delete().to(delete_file))
+            .route("/files/{file_id}/content", web::get().to(file_content))
+            // Fine-tuning
+            .route("/fine_tuning/jobs", web::post().to(create_fine_tuning_job))
+            .route("/fine_tuning/jobs", web::get().to(list_fine_tuning_jobs))
+            .route(
+                "/fine_tuning/jobs/{job_id}",
+                web::get().to(retrieve_fine_tuning_job),
+            )
+            .route(
+                "/fine_tuning/jobs/{job_id}/cancel",
+                web::post().to(cancel_fine_tuning_job),
+            )
+            .route(
+                "/fine_tuning/jobs/{job_id}/events",
+                web::get().to(list_fine_tuning_events),
+            )
             // Models
             .route("/models", web::get().to(list_models))
             .route("/models/{model_id}", web::get().to(get_model))
@@ -48,7 +88,20 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
                 web::post().to(audio_transcriptions),
             )
             .route("/audio/translations", web::post().to(audio_translations))
-            .route("/audio/speech", web::post().to(audio_speech)),
+            .route("/audio/speech", web::post().to(audio_speech))
+            // Realtime (WebSocket)
+            .route("/realtime", web::get().to(realtime))
+            // Assistants API (passthrough)
+            .route("/assistants", web::route().to(assistants_passthrough))
+            .route(
+                "/assistants/{tail:.*}",
+                web::route().to(assistants_passthrough),
+            )
+            .route("/threads", web::route().to(assistants_passthrough))
+            .route(
+                "/threads/{tail:.*}",
+                web::route().to(assistants_passthrough),
+            ),
     );
 }
 
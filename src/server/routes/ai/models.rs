@@ -1,27 +1,65 @@
 //! Model listing and retrieval endpoints
 
+use crate::config::RouterConfig;
 use crate::core::models::openai::{Model, ModelListResponse};
 use crate::core::providers::ProviderRegistry;
 use crate::server::routes::ApiResponse;
 use crate::server::state::AppState;
 use crate::utils::error::GatewayError;
-use actix_web::{HttpResponse, Result as ActixResult, web};
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use tracing::{debug, error};
 
+use super::context::get_request_context;
+
+/// Owner reported for aliases whose target is not served by any provider
+const ALIAS_OWNER: &str = "litellm";
+
+/// Query parameters for model listing
+#[derive(Debug, Default, Deserialize)]
+pub struct ModelListQuery {
+    /// Only list models of this provider
+    pub provider: Option<String>,
+}
+
 /// List available models
 ///
-/// Returns a list of available AI models across all configured providers.
-pub async fn list_models(state: web::Data<AppState>) -> ActixResult<HttpResponse> {
+/// Returns the models of all configured providers plus configured aliases,
+/// optionally for one provider. Callers authenticated as a team with a model
+/// allowlist only see the models their team may use.
+pub async fn list_models(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ModelListQuery>,
+) -> ActixResult<HttpResponse> {
     debug!("Listing available models");
 
-    // TODO: Implement proper model listing through ProviderRegistry
-    match get_models_from_pool(&state.router).await {
+    let team_id = authenticated_team(&req)?;
+
+    match get_models_from_pool(&state.router.load_full()).await {
         Ok(models) => {
-            let response = ModelListResponse {
+            let config = state.config.load();
+            let router_config = config.router();
+            let data = with_aliases(models, &router_config.model_aliases)
+                .into_iter()
+                .filter(|model| {
+                    query
+                        .provider
+                        .as_ref()
+                        .is_none_or(|provider| &model.owned_by == provider)
+                })
+                .filter(|model| {
+                    team_id
+                        .as_ref()
+                        .is_none_or(|team_id| team_allows(router_config, team_id, model))
+                })
+                .collect();
+
+            Ok(HttpResponse::Ok().json(ModelListResponse {
                 object: "list".to_string(),
-                data: models,
-            };
-            Ok(HttpResponse::Ok().json(response))
+                data,
+            }))
         }
         Err(e) => {
             error!("Failed to list models: {}", e);
@@ -40,8 +78,15 @@ pub async fn get_model(
 ) -> ActixResult<HttpResponse> {
     debug!("Getting model info for: {}", model_id);
 
-    // TODO: Implement proper model retrieval through ProviderRegistry
-    match get_model_from_pool(&state.router, &model_id).await {
+    let model = get_models_from_pool(&state.router.load_full())
+        .await
+        .map(|models| {
+            with_aliases(models, &state.config.load().router().model_aliases)
+                .into_iter()
+                .find(|model| model.id == *model_id)
+        });
+
+    match model {
         Ok(Some(model)) => Ok(HttpResponse::Ok().json(model)),
         Ok(None) => {
             Ok(HttpResponse::NotFound().json(ApiResponse::<()>::error("Error".to_string())))
@@ -55,18 +100,25 @@ pub async fn get_model(
 }
 
 /// Get all models from provider pool
+///
+/// A model served by several providers is listed once, for the first.
 pub async fn get_models_from_pool(pool: &ProviderRegistry) -> Result<Vec<Model>, GatewayError> {
     let mut all_models = Vec::new();
+    let mut seen = HashSet::new();
+    let created = chrono::Utc::now().timestamp() as u64;
 
     // Get models from all providers
     let providers = pool.get_all_providers();
     for provider in providers {
         let models = provider.list_models();
         for model_info in models {
+            if !seen.insert(model_info.id.clone()) {
+                continue;
+            }
             all_models.push(Model {
                 id: model_info.id.clone(),
                 object: "model".to_string(),
-                created: chrono::Utc::now().timestamp() as u64,
+                created,
                 owned_by: model_info.provider.clone(),
             });
         }
@@ -77,9 +129,148 @@ pub async fn get_models_from_pool(pool: &ProviderRegistry) -> Result<Vec<Model>,
 
 /// Get specific model from provider pool
 pub async fn get_model_from_pool(
-    _pool: &ProviderRegistry,
-    _model_id: &str,
+    pool: &ProviderRegistry,
+    model_id: &str,
 ) -> Result<Option<Model>, GatewayError> {
-    // TODO: Get specific model from providers in pool
-    Ok(None) // Return None for now
+    Ok(get_models_from_pool(pool)
+        .await?
+        .into_iter()
+        .find(|model| model.id == model_id))
+}
+
+/// Append configured aliases, owned by their target's provider
+fn with_aliases(mut models: Vec<Model>, aliases: &HashMap<String, String>) -> Vec<Model> {
+    let mut aliases: Vec<_> = aliases
+        .iter()
+        .filter(|(alias, _)| !models.iter().any(|model| &model.id == *alias))
+        .collect();
+    aliases.sort();
+
+    let created = chrono::Utc::now().timestamp() as u64;
+    let alias_models: Vec<Model> = aliases
+        .into_iter()
+        .map(|(alias, target)| {
+            let (prefix, name) = target.split_once('/').unwrap_or(("", target));
+            let owned_by = models
+                .iter()
+                .find(|model| model.id == *target || (model.id == name && model.owned_by == prefix))
+                .map(|model| model.owned_by.clone())
+                .unwrap_or_else(|| ALIAS_OWNER.to_string());
+            Model {
+                id: alias.clone(),
+                object: "model".to_string(),
+                created,
+                owned_by,
+            }
+        })
+        .collect();
+
+    models.extend(alias_models);
+    models
+}
+
+/// Whether a team may use a model, by its ID or `<provider>/<ID>`
+fn team_allows(config: &RouterConfig, team_id: &str, model: &Model) -> bool {
+    config.team_allows(team_id, &model.id)
+        || config.team_allows(team_id, &format!("{}/{}", model.owned_by, model.id))
+}
+
+/// Team of the authenticated caller
+///
+/// Taken from the context the authentication middlewares attached, which
+/// already rejected invalid credentials; anonymous callers get `None`.
+fn authenticated_team(req: &HttpRequest) -> ActixResult<Option<String>> {
+    let context = get_request_context(req)?;
+    Ok(context.team_id.map(|id| id.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::HttpMessage;
+
+    fn model(id: &str, owned_by: &str) -> Model {
+        Model {
+            id: id.to_string(),
+            object: "model".to_string(),
+            created: 0,
+            owned_by: owned_by.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_with_aliases() {
+        let models = vec![
+            model("gpt-4o", "openai"),
+            model("claude-3-5-sonnet", "anthropic"),
+        ];
+        let aliases = HashMap::from([
+            (
+                "smart".to_string(),
+                "anthropic/claude-3-5-sonnet".to_string(),
+            ),
+            ("fast".to_string(), "gpt-4o".to_string()),
+            ("local".to_string(), "ollama/llama3".to_string()),
+            ("gpt-4o".to_string(), "azure/gpt-4o".to_string()),
+        ]);
+
+        let models = with_aliases(models, &aliases);
+        let owners: Vec<_> = models
+            .iter()
+            .map(|m| (m.id.as_str(), m.owned_by.as_str()))
+            .collect();
+        assert_eq!(
+            owners,
+            vec![
+                ("gpt-4o", "openai"),
+                ("claude-3-5-sonnet", "anthropic"),
+                ("fast", "openai"),
+                ("local", ALIAS_OWNER),
+                ("smart", "anthropic"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_team_allows() {
+        let config = RouterConfig {
+            team_models: HashMap::from([(
+                "team-a".to_string(),
+                vec!["openai/*".to_string(), "claude-3-5-sonnet".to_string()],
+            )]),
+            ..Default::default()
+        };
+
+        assert!(team_allows(&config, "team-a", &model("gpt-4o", "openai")));
+        assert!(team_allows(
+            &config,
+            "team-a",
+            &model("claude-3-5-sonnet", "anthropic")
+        ));
+        assert!(!team_allows(
+            &config,
+            "team-a",
+            &model("claude-3-opus", "anthropic")
+        ));
+        assert!(team_allows(
+            &config,
+            "team-b",
+            &model("claude-3-opus", "anthropic")
+        ));
+    }
+
+    #[test]
+    fn test_authenticated_team() {
+        let req = actix_web::test::TestRequest::default().to_http_request();
+        assert_eq!(authenticated_team(&req).unwrap(), None);
+
+        // Set by the OIDC middleware for token callers, like the key middleware
+        let team_id = uuid::Uuid::new_v4();
+        req.extensions_mut()
+            .insert(crate::core::models::RequestContext {
+                team_id: Some(team_id),
+                ..Default::default()
+            });
+        assert_eq!(authenticated_team(&req).unwrap(), Some(team_id.to_string()));
+    }
 }
@@ -0,0 +1,399 @@
+//! Realtime API endpoint
+//!
+//! `/v1/realtime` upgrades to a WebSocket and proxies the session to the
+//! OpenAI or Azure realtime API. Upstream credentials are injected by the
+//! gateway; clients authenticate with a gateway API key, which is also the
+//! unit of the concurrent session limit. Keys sent as the
+//! `openai-insecure-api-key.<key>` subprotocol are authenticated, and their
+//! budgets and rate limits checked, by the middleware like any other key. The
+//! usage of a session is charged to its key when it closes.
+
+use crate::core::models::RequestContext;
+use crate::core::providers::openai::realtime::RealtimeConnection;
+use crate::server::middleware::{DeferredUsage, RequestUsage};
+use crate::server::routes::errors;
+use crate::server::state::AppState;
+use crate::utils::error::GatewayError;
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use actix_ws::{AggregatedMessage, AggregatedMessageStream, CloseCode, CloseReason, Session};
+use dashmap::DashMap;
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message as UpstreamMessage;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tracing::{debug, info, warn};
+
+use super::context::{get_request_context, log_api_usage};
+
+/// Largest message forwarded in either direction (audio chunks are base64)
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+type UpstreamSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Query parameters of `/v1/realtime`
+#[derive(Debug, Deserialize)]
+pub struct RealtimeQuery {
+    /// Model, optionally prefixed with the provider (`azure/<deployment>`)
+    pub model: String,
+}
+
+/// Open realtime sessions per API key
+#[derive(Debug)]
+pub struct RealtimeSessions {
+    max_per_key: usize,
+    active: DashMap<String, usize>,
+}
+
+impl RealtimeSessions {
+    /// Create a tracker allowing `max_per_key` sessions per key (0 for no limit)
+    pub fn new(max_per_key: usize) -> Self {
+        Self {
+            max_per_key,
+            active: DashMap::new(),
+        }
+    }
+
+    /// Reserve a session for a key, or `None` when the key is at its limit
+    pub fn acquire(self: &Arc<Self>, key: &str) -> Option<RealtimeSessionGuard> {
+        let mut count = self.active.entry(key.to_string()).or_insert(0);
+        if self.max_per_key > 0 && *count >= self.max_per_key {
+            return None;
+        }
+        *count += 1;
+
+        Some(RealtimeSessionGuard {
+            sessions: Arc::clone(self),
+            key: key.to_string(),
+        })
+    }
+
+    /// Number of open sessions of a key
+    pub fn active(&self, key: &str) -> usize {
+        self.active.get(key).map(|count| *count).unwrap_or(0)
+    }
+
+    fn release(&self, key: &str) {
+        self.active.remove_if_mut(key, |_, count| {
+            *count = count.saturating_sub(1);
+            *count == 0
+        });
+    }
+}
+
+/// Reserved realtime session, released when dropped
+#[derive(Debug)]
+pub struct RealtimeSessionGuard {
+    sessions: Arc<RealtimeSessions>,
+    key: String,
+}
+
+impl Drop for RealtimeSessionGuard {
+    fn drop(&mut self) {
+        self.sessions.release(&self.key);
+    }
+}
+
+/// Usage accounting of a realtime session
+#[derive(Debug)]
+struct RealtimeSessionStats {
+    model: String,
+    started_at: Instant,
+    client_messages: u64,
+    server_messages: u64,
+    input_tokens: u32,
+    output_tokens: u32,
+}
+
+impl RealtimeSessionStats {
+    fn new(model: String) -> Self {
+        Self {
+            model,
+            started_at: Instant::now(),
+            client_messages: 0,
+            server_messages: 0,
+            input_tokens: 0,
+            output_tokens: 0,
+        }
+    }
+
+    /// Count a server event, adding the usage of completed responses
+    fn record_server_event(&mut self, event: &str) {
+        self.server_messages += 1;
+
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(event) else {
+            return;
+        };
+        if event["type"] != "response.done" {
+            return;
+        }
+
+        let usage = &event["response"]["usage"];
+        self.input_tokens += usage["input_tokens"].as_u64().unwrap_or(0) as u32;
+        self.output_tokens += usage["output_tokens"].as_u64().unwrap_or(0) as u32;
+    }
+}
+
+/// Realtime API endpoint
+///
+/// OpenAI-compatible `GET /v1/realtime?model=...` WebSocket upgrade.
+pub async fn realtime(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Payload,
+    query: web::Query<RealtimeQuery>,
+) -> ActixResult<HttpResponse> {
+    let context = get_request_context(&req)?;
+    let model = query.into_inner().model;
+    info!("Realtime session request for model: {}", model);
+
+    // Upstream credentials are only lent to authenticated keys; the auth
+    // middleware accepts them from headers or the subprotocol
+    let Some(key_id) = context.api_key_id.map(|id| id.to_string()) else {
+        return Ok(errors::unauthorized_error("API key required"));
+    };
+    let Some(guard) = state.realtime_sessions.acquire(&key_id) else {
+        return Ok(errors::gateway_error_to_response(GatewayError::rate_limit(
+            "Too many concurrent realtime sessions for this API key",
+        )));
+    };
+
+    // Connect upstream before upgrading so failures are reported over HTTP
+    let upstream = match connect_upstream(&state, &model).await {
+        Ok(upstream) => upstream,
+        Err(e) => {
+            warn!("Realtime upstream connection failed: {}", e);
+            return Ok(errors::gateway_error_to_response(e));
+        }
+    };
+
+    // The session is charged when it closes, after the middlewares have run
+    RequestUsage::new(&model).deferred().record(&req);
+    let usage = DeferredUsage::new(&state, &req);
+
+    let (mut response, session, client_stream) = actix_ws::handle(&req, body)?;
+    if requested_protocols(&req).any(|protocol| protocol == "realtime") {
+        response.headers_mut().insert(
+            HeaderName::from_static("sec-websocket-protocol"),
+            HeaderValue::from_static("realtime"),
+        );
+    }
+
+    let client_stream = client_stream
+        .max_frame_size(MAX_MESSAGE_SIZE)
+        .aggregate_continuations()
+        .max_continuation_size(MAX_MESSAGE_SIZE);
+
+    actix_web::rt::spawn(async move {
+        let mut stats = RealtimeSessionStats::new(model);
+        proxy_session(session, client_stream, upstream, &mut stats).await;
+        drop(guard);
+        finish_session(&state, &context, &usage, stats).await;
+    });
+
+    Ok(response)
+}
+
+/// Subprotocols offered in `Sec-WebSocket-Protocol`
+fn requested_protocols(req: &HttpRequest) -> impl Iterator<Item = &str> {
+    req.headers()
+        .get_all("sec-websocket-protocol")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+}
+
+/// Open the upstream session of a model
+///
+/// `azure/<deployment>` models go to Azure; unprefixed models go to OpenAI.
+async fn connect_upstream(state: &AppState, model: &str) -> Result<UpstreamSocket, GatewayError> {
+    let (provider_name, upstream_model) = match model.split_once('/') {
+        Some((provider, model)) => (provider, model),
+        None => ("openai", model),
+    };
+
+    let router = state.router.load_full();
+    let providers = router.all();
+    let provider = providers
+        .iter()
+        .find(|p| p.name() == provider_name)
+        .ok_or_else(|| {
+            GatewayError::not_found(format!(
+                "No provider configured for realtime: {}",
+                provider_name
+            ))
+        })?;
+
+    let connection = provider.realtime_connection(upstream_model).await?;
+    let request = upstream_request(connection)?;
+
+    debug!(provider = %provider_name, model = %upstream_model, "Connecting realtime upstream");
+
+    let (socket, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(|e| GatewayError::network(format!("Realtime upstream error: {}", e)))?;
+    Ok(socket)
+}
+
+/// Build the upstream handshake request, injecting the provider credentials
+fn upstream_request(
+    connection: RealtimeConnection,
+) -> Result<tokio_tungstenite::tungstenite::handshake::client::Request, GatewayError> {
+    let mut request = connection
+        .url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| GatewayError::internal(format!("Invalid realtime URL: {}", e)))?;
+
+    for (name, value) in connection.headers {
+        let name = HeaderName::try_from(name)
+            .map_err(|e| GatewayError::internal(format!("Invalid header name: {}", e)))?;
+        let value = HeaderValue::try_from(value)
+            .map_err(|e| GatewayError::internal(format!("Invalid header value: {}", e)))?;
+        request.headers_mut().insert(name, value);
+    }
+
+    Ok(request)
+}
+
+/// Forward messages both ways until either side closes
+async fn proxy_session(
+    mut session: Session,
+    mut client_stream: AggregatedMessageStream,
+    upstream: UpstreamSocket,
+    stats: &mut RealtimeSessionStats,
+) {
+    let (mut upstream_tx, mut upstream_rx) = upstream.split();
+
+    let close_reason = loop {
+        tokio::select! {
+            message = client_stream.recv() => {
+                let message = match message {
+                    Some(Ok(AggregatedMessage::Text(text))) => {
+                        UpstreamMessage::Text(text.to_string())
+                    }
+                    Some(Ok(AggregatedMessage::Binary(bytes))) => {
+                        UpstreamMessage::Binary(bytes.to_vec())
+                    }
+                    Some(Ok(AggregatedMessage::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break None;
+                        }
+                        continue;
+                    }
+                    Some(Ok(AggregatedMessage::Pong(_))) => continue,
+                    Some(Ok(AggregatedMessage::Close(_))) | Some(Err(_)) | None => {
+                        let _ = upstream_tx.send(UpstreamMessage::Close(None)).await;
+                        break None;
+                    }
+                };
+
+                stats.client_messages += 1;
+                if upstream_tx.send(message).await.is_err() {
+                    break Some(CloseReason::from(CloseCode::Error));
+                }
+            }
+            message = upstream_rx.next() => {
+                let sent = match message {
+                    Some(Ok(UpstreamMessage::Text(text))) => {
+                        stats.record_server_event(&text);
+                        session.text(text).await
+                    }
+                    Some(Ok(UpstreamMessage::Binary(bytes))) => {
+                        stats.server_messages += 1;
+                        session.binary(bytes).await
+                    }
+                    Some(Ok(UpstreamMessage::Close(frame))) => {
+                        break frame.map(|frame| CloseReason {
+                            code: CloseCode::from(u16::from(frame.code)),
+                            description: Some(frame.reason.into_owned()),
+                        });
+                    }
+                    // Pings are answered by tungstenite
+                    Some(Ok(_)) => continue,
+                    Some(Err(_)) | None => break Some(CloseReason::from(CloseCode::Error)),
+                };
+
+                if sent.is_err() {
+                    let _ = upstream_tx.send(UpstreamMessage::Close(None)).await;
+                    return;
+                }
+            }
+        }
+    };
+
+    let _ = session.close(close_reason).await;
+}
+
+/// Log and charge the usage of a finished session
+async fn finish_session(
+    state: &AppState,
+    context: &RequestContext,
+    usage: &DeferredUsage,
+    stats: RealtimeSessionStats,
+) {
+    let tokens = stats.input_tokens + stats.output_tokens;
+    let cost = state
+        .pricing
+        .get_cost_per_token(&stats.model)
+        .map(|(input, output)| {
+            stats.input_tokens as f64 * input + stats.output_tokens as f64 * output
+        })
+        .unwrap_or(0.0);
+
+    info!(
+        model = %stats.model,
+        duration_ms = stats.started_at.elapsed().as_millis() as u64,
+        client_messages = stats.client_messages,
+        server_messages = stats.server_messages,
+        input_tokens = stats.input_tokens,
+        output_tokens = stats.output_tokens,
+        "Realtime session closed"
+    );
+
+    log_api_usage(context, &stats.model, tokens, cost).await;
+    usage
+        .charge(&RequestUsage::new(&stats.model).with_tokens(
+            stats.input_tokens,
+            stats.output_tokens,
+            cost,
+        ))
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_limit_per_key() {
+        let sessions = Arc::new(RealtimeSessions::new(2));
+
+        let first = sessions.acquire("sk-a").unwrap();
+        let _second = sessions.acquire("sk-a").unwrap();
+        assert!(sessions.acquire("sk-a").is_none());
+        assert!(sessions.acquire("sk-b").is_some());
+        assert_eq!(sessions.active("sk-a"), 2);
+
+        drop(first);
+        assert_eq!(sessions.active("sk-a"), 1);
+        assert!(sessions.acquire("sk-a").is_some());
+    }
+
+    #[test]
+    fn test_record_server_event() {
+        let mut stats = RealtimeSessionStats::new("gpt-4o-realtime-preview".to_string());
+        stats.record_server_event(r#"{"type": "response.audio.delta", "delta": "AAAA"}"#);
+        stats.record_server_event(
+            r#"{"type": "response.done", "response": {"usage": {"total_tokens": 30, "input_tokens": 10, "output_tokens": 20}}}"#,
+        );
+
+        assert_eq!(stats.server_messages, 2);
+        assert_eq!(stats.input_tokens, 10);
+        assert_eq!(stats.output_tokens, 20);
+    }
+}
@@ -0,0 +1,124 @@
+//! Routing chat requests to providers
+//!
+//! Models the gateway's router serves, by name, alias or traffic split, go to
+//! its deployments with retries, fallbacks, cooldowns and hedging. Other
+//! models, e.g. `openai/gpt-4o`, go to the provider their prefix names.
+
+use crate::core::completion::{
+    CompletionOptions, CompletionResponse, CompletionStream, complete_with_schema, completion,
+    completion_stream, convert_chat_chunk_to_completion_chunk,
+    convert_from_chat_completion_response, convert_to_chat_completion_request,
+};
+use crate::core::models::RequestContext;
+use crate::core::router::execution::router_error_to_provider_error;
+use crate::core::types::ChatMessage;
+use crate::server::state::AppState;
+use crate::utils::error::{GatewayError, Result};
+use futures::StreamExt;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Complete a chat on the router's deployments of its model, or on the
+/// provider its prefix names
+pub(super) async fn complete(
+    state: &AppState,
+    context: &RequestContext,
+    model: &str,
+    messages: Vec<ChatMessage>,
+    options: CompletionOptions,
+) -> Result<CompletionResponse> {
+    let router = state.unified_router.load_full();
+    if !router.serves(model) {
+        return completion(model, messages, Some(options)).await;
+    }
+
+    let request = convert_to_chat_completion_request(model, messages, options.clone())?;
+    let context = router_context(context);
+    complete_with_schema(request, &options, |request| {
+        let router = Arc::clone(&router);
+        let context = context.clone();
+        async move {
+            let execution = router
+                .chat_completion(request, context)
+                .await
+                .map_err(router_error_to_provider_error)?;
+            convert_from_chat_completion_response(execution.result)
+        }
+    })
+    .await
+}
+
+/// Stream a chat from the router's deployments of its model, or from the
+/// provider its prefix names
+pub(super) async fn complete_stream(
+    state: &AppState,
+    context: &RequestContext,
+    model: &str,
+    messages: Vec<ChatMessage>,
+    options: CompletionOptions,
+) -> Result<CompletionStream> {
+    let router = state.unified_router.load_full();
+    if !router.serves(model) {
+        return completion_stream(model, messages, Some(options)).await;
+    }
+
+    let request = convert_to_chat_completion_request(model, messages, options)?;
+    let stream = router
+        .chat_completion_stream(request, router_context(context))
+        .map(|chunk| {
+            chunk
+                .map(convert_chat_chunk_to_completion_chunk)
+                .map_err(GatewayError::from)
+        });
+    Ok(Box::pin(stream))
+}
+
+/// Router context of a request, carrying who made it to the deployments
+fn router_context(context: &RequestContext) -> crate::core::types::RequestContext {
+    let mut metadata = HashMap::new();
+    if let Some(team_id) = context.team_id {
+        metadata.insert("team_id".to_string(), json!(team_id.to_string()));
+    }
+    if let Some(api_key_id) = context.api_key_id {
+        metadata.insert("api_key_id".to_string(), json!(api_key_id.to_string()));
+    }
+
+    crate::core::types::RequestContext {
+        request_id: context.request_id.clone(),
+        user_id: context.user_id.map(|id| id.to_string()),
+        client_ip: context.client_ip.clone(),
+        user_agent: context.user_agent.clone(),
+        headers: context.headers.clone(),
+        start_time: context.timestamp.into(),
+        metadata,
+        trace_id: context.trace_id.clone(),
+        span_id: context.span_id.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_router_context_keeps_the_caller() {
+        let mut context = RequestContext::new();
+        context.user_id = Some(Uuid::new_v4());
+        context.team_id = Some(Uuid::new_v4());
+        context.client_ip = Some("10.0.0.1".to_string());
+        context.trace_id = Some("trace-1".to_string());
+
+        let routed = router_context(&context);
+        assert_eq!(routed.request_id, context.request_id);
+        assert_eq!(routed.user_id, context.user_id.map(|id| id.to_string()));
+        assert_eq!(routed.client_ip.as_deref(), Some("10.0.0.1"));
+        assert_eq!(routed.trace_id.as_deref(), Some("trace-1"));
+        assert_eq!(
+            routed.metadata["team_id"],
+            json!(context.team_id.unwrap().to_string())
+        );
+        assert!(!routed.metadata.contains_key("api_key_id"));
+    }
+}
@@ -29,10 +29,13 @@ pub use user::{get_authenticated_user, get_current_user};
 
 use actix_web::web;
 
+/// Scope of the authentication routes
+pub const SCOPE: &str = "/auth";
+
 /// Configure authentication routes
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
-        web::scope("/auth")
+        web::scope(SCOPE)
             .route("/register", web::post().to(register))
             .route("/login", web::post().to(login))
             .route("/logout", web::post().to(logout))
@@ -0,0 +1,23 @@
+//! Configuration reload endpoint
+
+use crate::server::routes::access::require_admin;
+use crate::server::routes::{ADMIN_SCOPE, ApiResponse};
+use crate::server::state::AppState;
+use crate::utils::error::Result;
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// Reload the configuration file and report what was applied
+/// POST /admin/config/reload
+pub async fn reload_config(data: web::Data<AppState>, req: HttpRequest) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let report = data.reloader.reload().await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(report)))
+}
+
+/// Configure configuration routes
+pub fn configure_config_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope(&format!("{}/config", ADMIN_SCOPE))
+            .route("/reload", web::post().to(reload_config)),
+    );
+}
@@ -0,0 +1,81 @@
+//! Stored provider credential endpoints
+//!
+//! Stored API keys are encrypted at rest and fill the key of providers
+//! configured without one, at startup and on every configuration reload.
+
+use crate::server::routes::access::require_admin;
+use crate::server::routes::{ADMIN_SCOPE, ApiResponse};
+use crate::server::state::AppState;
+use crate::utils::error::{GatewayError, Result};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+/// Body of a stored credential
+#[derive(Debug, Deserialize)]
+pub struct StoreCredentialRequest {
+    /// Provider API key
+    pub api_key: String,
+}
+
+/// Store the API key of a configured provider, replacing any stored before
+/// PUT /admin/credentials/{provider}
+pub async fn store_credential(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<StoreCredentialRequest>,
+) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let provider = path.into_inner();
+    if !data
+        .config()
+        .gateway
+        .providers
+        .iter()
+        .any(|p| p.name == provider)
+    {
+        return Err(GatewayError::not_found(format!(
+            "No such provider: {}",
+            provider
+        )));
+    }
+    if body.api_key.is_empty() {
+        return Err(GatewayError::bad_request("api_key must not be empty"));
+    }
+
+    data.storage
+        .database
+        .store_provider_credential(&provider, &body.api_key)
+        .await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        serde_json::json!({ "provider": provider, "stored": true }),
+    )))
+}
+
+/// Delete the stored API key of a provider
+/// DELETE /admin/credentials/{provider}
+pub async fn delete_credential(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let provider = path.into_inner();
+    let deleted = data
+        .storage
+        .database
+        .delete_provider_credential(&provider)
+        .await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        serde_json::json!({ "provider": provider, "deleted": deleted }),
+    )))
+}
+
+/// Configure credential routes
+pub fn configure_credential_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope(&format!("{}/credentials", ADMIN_SCOPE))
+            .route("/{provider}", web::put().to(store_credential))
+            .route("/{provider}", web::delete().to(delete_credential)),
+    );
+}
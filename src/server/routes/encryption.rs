@@ -0,0 +1,27 @@
+//! Encryption key rotation endpoint
+
+use crate::server::routes::access::require_admin;
+use crate::server::routes::{ADMIN_SCOPE, ApiResponse};
+use crate::server::state::AppState;
+use crate::utils::error::Result;
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// Re-encrypt stored values with the active key and report how many rows
+/// changed
+/// POST /admin/encryption/rotate
+pub async fn rotate_encryption(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let rotation = data.storage.database.rotate_encryption().await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(rotation)))
+}
+
+/// Configure encryption routes
+pub fn configure_encryption_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope(&format!("{}/encryption", ADMIN_SCOPE))
+            .route("/rotate", web::post().to(rotate_encryption)),
+    );
+}
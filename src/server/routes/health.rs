@@ -2,25 +2,51 @@
 //!
 //! This module provides health check and system status endpoints.
 
-#![allow(dead_code)]
-
+use crate::core::types::common::HealthStatus as ProviderStatus;
 use crate::server::routes::ApiResponse;
 use crate::server::state::AppState;
 use actix_web::{HttpResponse, Result as ActixResult, web};
+use parking_lot::Mutex;
 use std::borrow::Cow;
+use std::sync::atomic::Ordering::Relaxed;
+use std::time::{Duration, Instant};
+
+use tracing::{debug, error, warn};
+
+/// How long a provider health check may take before it counts as failed
+const PROVIDER_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long provider health results are reused
+const PROVIDER_CHECK_TTL: Duration = Duration::from_secs(10);
+
+/// Last provider health results and when they were taken
+static PROVIDER_CHECK_CACHE: Mutex<Option<(Instant, ProviderHealthStatus)>> = Mutex::new(None);
+
+/// Scope of the health check routes
+pub const SCOPE: &str = "/health";
+
+/// Path of the system status route
+pub const STATUS_PATH: &str = "/status";
+
+/// Path of the version route
+pub const VERSION_PATH: &str = "/version";
 
-use tracing::{debug, error};
+/// Path of the metrics route
+pub const METRICS_PATH: &str = "/metrics";
 
 /// Configure health check routes
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
-        web::scope("/health")
+        web::scope(SCOPE)
             .route("", web::get().to(health_check))
+            .route("/liveness", web::get().to(liveness))
+            .route("/readiness", web::get().to(readiness))
+            .route("/deployments", web::get().to(deployment_health))
             .route("/detailed", web::get().to(detailed_health_check)),
     )
-    .route("/status", web::get().to(system_status))
-    .route("/version", web::get().to(version_info))
-    .route("/metrics", web::get().to(metrics));
+    .route(STATUS_PATH, web::get().to(system_status))
+    .route(VERSION_PATH, web::get().to(version_info))
+    .route(METRICS_PATH, web::get().to(metrics));
 }
 
 /// Basic health check endpoint
@@ -39,6 +65,51 @@ pub async fn health_check(_state: web::Data<AppState>) -> ActixResult<HttpRespon
     Ok(HttpResponse::Ok().json(ApiResponse::success(health_status)))
 }
 
+/// Liveness probe endpoint
+///
+/// Reports that the process is up and serving requests, without checking
+/// any dependency.
+async fn liveness() -> HttpResponse {
+    HttpResponse::Ok().json(ApiResponse::success(HealthStatus {
+        status: Cow::Borrowed("alive"),
+        timestamp: chrono::Utc::now(),
+        version: Cow::Borrowed(env!("CARGO_PKG_VERSION")),
+    }))
+}
+
+/// Readiness probe endpoint
+///
+/// Checks every configured provider concurrently and returns the breakdown.
+/// Responds with 503 unless at least one provider can serve requests.
+async fn readiness(state: web::Data<AppState>) -> ActixResult<HttpResponse> {
+    debug!("Readiness check requested");
+
+    let providers = check_provider_health(&state).await?;
+    let ready = providers.healthy_providers > 0;
+    let readiness = ReadinessStatus {
+        status: Cow::Borrowed(if ready { "ready" } else { "not_ready" }),
+        timestamp: chrono::Utc::now(),
+        providers,
+    };
+
+    let mut response = if ready {
+        HttpResponse::Ok()
+    } else {
+        HttpResponse::ServiceUnavailable()
+    };
+    Ok(response.json(ApiResponse::success(readiness)))
+}
+
+/// Deployment health endpoint
+///
+/// Returns the health of every router deployment, including deployments in
+/// cooldown and how long they have left.
+async fn deployment_health(state: web::Data<AppState>) -> HttpResponse {
+    debug!("Deployment health requested");
+
+    HttpResponse::Ok().json(ApiResponse::success(check_deployment_health(&state)))
+}
+
 /// Detailed health check endpoint
 ///
 /// Returns comprehensive health information including storage, authentication,
@@ -47,11 +118,12 @@ async fn detailed_health_check(state: web::Data<AppState>) -> ActixResult<HttpRe
     debug!("Detailed health check requested");
 
     // Check storage health
-    let storage_health = if state.config.storage().database.url.is_empty() {
+    let storage_health = if state.config.load().storage().database.url.is_empty() {
         crate::storage::StorageHealthStatus {
             overall: false,
             database: false,
-            redis: false,
+            cache: false,
+            cache_backend: state.storage.cache().name(),
             files: false,
             vector: false,
         }
@@ -62,7 +134,8 @@ async fn detailed_health_check(state: web::Data<AppState>) -> ActixResult<HttpRe
             Err(_) => crate::storage::StorageHealthStatus {
                 overall: false,
                 database: false,
-                redis: false,
+                cache: false,
+                cache_backend: state.storage.cache().name(),
                 files: false,
                 vector: false,
             },
@@ -82,6 +155,8 @@ async fn detailed_health_check(state: web::Data<AppState>) -> ActixResult<HttpRe
         }
     };
 
+    let deployment_health = check_deployment_health(&state);
+
     let detailed_status = DetailedHealthStatus {
         status: if storage_health.overall && provider_health.healthy_providers > 0 {
             Cow::Borrowed("healthy")
@@ -93,6 +168,7 @@ async fn detailed_health_check(state: web::Data<AppState>) -> ActixResult<HttpRe
         uptime_seconds: get_uptime_seconds(),
         storage: storage_health,
         providers: provider_health,
+        deployments: deployment_health,
         memory_usage: get_memory_usage(),
         cpu_usage: get_cpu_usage(),
     };
@@ -106,6 +182,7 @@ async fn detailed_health_check(state: web::Data<AppState>) -> ActixResult<HttpRe
 async fn system_status(state: web::Data<AppState>) -> ActixResult<HttpResponse> {
     debug!("System status requested");
 
+    let config = state.config.load();
     let system_status = SystemStatus {
         service_name: Cow::Borrowed("Rust LiteLLM Gateway"),
         version: Cow::Borrowed(env!("CARGO_PKG_VERSION")),
@@ -118,12 +195,12 @@ async fn system_status(state: web::Data<AppState>) -> ActixResult<HttpResponse>
             .map(Cow::Owned)
             .unwrap_or(Cow::Borrowed("development")),
         config: SystemConfig {
-            server_host: state.config.server().host.clone(),
-            server_port: state.config.server().port,
-            auth_enabled: state.config.auth().enable_jwt || state.config.auth().enable_api_key,
-            rate_limiting_enabled: state.config.gateway.rate_limit.enabled,
-            caching_enabled: state.config.gateway.cache.enabled,
-            providers_count: state.config.providers().len(),
+            server_host: config.server().host.clone(),
+            server_port: config.server().port,
+            auth_enabled: config.auth().enable_jwt || config.auth().enable_api_key,
+            rate_limiting_enabled: config.gateway.rate_limit.enabled,
+            caching_enabled: config.gateway.cache.enabled,
+            providers_count: config.providers().len(),
         },
     };
 
@@ -155,6 +232,7 @@ async fn metrics(state: web::Data<AppState>) -> ActixResult<HttpResponse> {
 
     // TODO: Implement proper Prometheus metrics
     // For now, return basic metrics in Prometheus format
+    let retention = state.retention.totals();
     let metrics = format!(
         r#"# HELP gateway_uptime_seconds Total uptime of the gateway in seconds
 # TYPE gateway_uptime_seconds counter
@@ -171,11 +249,20 @@ gateway_cpu_usage_percent {}
 # HELP gateway_providers_total Total number of configured providers
 # TYPE gateway_providers_total gauge
 gateway_providers_total {}
+
+# HELP gateway_retention_removed_total Rows and cache entries removed by the retention job
+# TYPE gateway_retention_removed_total counter
+gateway_retention_removed_total{{kind="request_logs"}} {}
+gateway_retention_removed_total{{kind="spend"}} {}
+gateway_retention_removed_total{{kind="cache_entries"}} {}
 "#,
         get_uptime_seconds(),
         get_memory_usage(),
         get_cpu_usage(),
-        state.config.providers().len()
+        state.config.load().providers().len(),
+        retention.request_logs,
+        retention.spend,
+        retention.cache_entries
     );
 
     Ok(HttpResponse::Ok()
@@ -200,10 +287,19 @@ struct DetailedHealthStatus {
     uptime_seconds: u64,
     storage: crate::storage::StorageHealthStatus,
     providers: ProviderHealthStatus,
+    deployments: DeploymentHealthStatus,
     memory_usage: u64,
     cpu_usage: f64,
 }
 
+/// Readiness status
+#[derive(Debug, Clone, serde::Serialize)]
+struct ReadinessStatus {
+    status: Cow<'static, str>,
+    timestamp: chrono::DateTime<chrono::Utc>,
+    providers: ProviderHealthStatus,
+}
+
 /// Provider health status
 #[derive(Debug, Clone, serde::Serialize)]
 struct ProviderHealthStatus {
@@ -222,6 +318,27 @@ struct ProviderHealth {
     error_message: Option<String>,
 }
 
+/// Router deployment health status
+#[derive(Debug, Clone, serde::Serialize)]
+struct DeploymentHealthStatus {
+    available_deployments: usize,
+    total_deployments: usize,
+    deployment_details: Vec<DeploymentHealth>,
+}
+
+/// Individual router deployment health
+#[derive(Debug, Clone, serde::Serialize)]
+struct DeploymentHealth {
+    id: String,
+    model_name: String,
+    model: String,
+    provider: String,
+    status: &'static str,
+    cooldown_remaining_secs: u64,
+    consecutive_failures: u32,
+    active_requests: u32,
+}
+
 /// System status information
 #[derive(Debug, Clone, serde::Serialize)]
 struct SystemStatus {
@@ -258,40 +375,115 @@ struct VersionInfo {
 }
 
 /// Check provider health
+///
+/// Runs `health_check` on every registered provider concurrently, each with a
+/// timeout. Results are cached for a few seconds so that frequent probes do
+/// not hammer upstream APIs. Degraded providers count as healthy.
 async fn check_provider_health(
     state: &AppState,
 ) -> Result<ProviderHealthStatus, crate::utils::error::GatewayError> {
-    let mut provider_details = Vec::new();
-    let mut healthy_count = 0;
-
-    for provider_config in state.config.providers() {
-        let start_time = std::time::Instant::now();
+    if let Some((checked_at, status)) = PROVIDER_CHECK_CACHE.lock().as_ref()
+        && checked_at.elapsed() < PROVIDER_CHECK_TTL
+    {
+        return Ok(status.clone());
+    }
 
-        // TODO: Implement actual provider health checks
-        // For now, assume all providers are healthy
-        let status: Cow<'static, str> = Cow::Borrowed("healthy");
+    let router = state.router.load_full();
+    let checks = router.all().into_iter().map(|provider| async move {
+        let start_time = Instant::now();
+        let result = tokio::time::timeout(PROVIDER_CHECK_TIMEOUT, provider.health_check()).await;
         let response_time = start_time.elapsed().as_millis() as u64;
 
-        if status == "healthy" {
-            healthy_count += 1;
-        }
+        let (status, error_message) = match result {
+            Ok(status) => (provider_status(&status), None),
+            Err(_) => {
+                warn!("Health check for provider {} timed out", provider.name());
+                (
+                    Cow::Borrowed("unhealthy"),
+                    Some(format!(
+                        "Health check timed out after {}s",
+                        PROVIDER_CHECK_TIMEOUT.as_secs()
+                    )),
+                )
+            }
+        };
 
-        provider_details.push(ProviderHealth {
-            name: provider_config.name.clone(),
+        ProviderHealth {
+            name: provider.name().to_string(),
             status,
             response_time_ms: Some(response_time),
             last_check: chrono::Utc::now(),
-            error_message: None,
-        });
+            error_message,
+        }
+    });
+    let mut provider_details = futures::future::join_all(checks).await;
+    provider_details.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let status = ProviderHealthStatus {
+        healthy_providers: provider_details
+            .iter()
+            .filter(|provider| is_available(&provider.status))
+            .count(),
+        total_providers: provider_details.len(),
+        provider_details,
+    };
+
+    *PROVIDER_CHECK_CACHE.lock() = Some((Instant::now(), status.clone()));
+    Ok(status)
+}
+
+/// Check router deployment health
+///
+/// Reads the state the router keeps for its deployments; no requests are
+/// made. Deployments whose cooldown ended but that have not passed their
+/// probe yet are reported in cooldown.
+fn check_deployment_health(state: &AppState) -> DeploymentHealthStatus {
+    let router = state.unified_router.load();
+    let mut deployment_details: Vec<_> = router
+        .list_deployments()
+        .into_iter()
+        .filter_map(|id| {
+            router
+                .get_deployment(&id)
+                .map(|deployment| DeploymentHealth {
+                    id: deployment.id.clone(),
+                    model_name: deployment.model_name.clone(),
+                    model: deployment.model.clone(),
+                    provider: deployment.provider.name().to_string(),
+                    status: deployment.state.health_status().as_str(),
+                    cooldown_remaining_secs: deployment.cooldown_remaining_secs(),
+                    consecutive_failures: deployment.state.consecutive_fails.load(Relaxed),
+                    active_requests: deployment.state.active_requests.load(Relaxed),
+                })
+        })
+        .collect();
+    deployment_details.sort_by(|a, b| a.id.cmp(&b.id));
+
+    DeploymentHealthStatus {
+        available_deployments: deployment_details
+            .iter()
+            .filter(|deployment| is_available(deployment.status))
+            .count(),
+        total_deployments: deployment_details.len(),
+        deployment_details,
     }
+}
 
-    Ok(ProviderHealthStatus {
-        healthy_providers: healthy_count,
-        total_providers: state.config.providers().len(),
-        provider_details,
+/// Name of a provider health status
+fn provider_status(status: &ProviderStatus) -> Cow<'static, str> {
+    Cow::Borrowed(match status {
+        ProviderStatus::Healthy => "healthy",
+        ProviderStatus::Degraded => "degraded",
+        ProviderStatus::Unhealthy => "unhealthy",
+        ProviderStatus::Unknown => "unknown",
     })
 }
 
+/// Whether a provider with this status can serve requests
+fn is_available(status: &str) -> bool {
+    matches!(status, "healthy" | "degraded")
+}
+
 /// Get system uptime in seconds
 fn get_uptime_seconds() -> u64 {
     // This is a simplified implementation
@@ -363,6 +555,24 @@ mod tests {
         assert_eq!(provider_health.total_providers, 3);
     }
 
+    #[test]
+    fn test_provider_status_availability() {
+        assert!(is_available(&provider_status(&ProviderStatus::Healthy)));
+        assert!(is_available(&provider_status(&ProviderStatus::Degraded)));
+        assert!(!is_available(&provider_status(&ProviderStatus::Unhealthy)));
+        assert!(!is_available(&provider_status(&ProviderStatus::Unknown)));
+    }
+
+    #[test]
+    fn test_deployment_status_availability() {
+        use crate::core::router::HealthStatus as DeploymentStatus;
+
+        assert!(is_available(DeploymentStatus::Healthy.as_str()));
+        assert!(is_available(DeploymentStatus::Degraded.as_str()));
+        assert!(!is_available(DeploymentStatus::Cooldown.as_str()));
+        assert!(!is_available(DeploymentStatus::Unhealthy.as_str()));
+    }
+
     #[test]
     fn test_version_info() {
         let version_info = VersionInfo {
@@ -4,13 +4,37 @@
 
 #![allow(dead_code)]
 
+pub mod access;
 pub mod ai;
 pub mod auth;
+pub mod config;
+pub mod credentials;
+pub mod encryption;
 pub mod health;
+pub mod pass_through;
 pub mod pricing;
+pub mod retention;
+pub mod spend;
 
 use actix_web::HttpResponse;
 
+/// Prefix of the administrative scopes
+pub const ADMIN_SCOPE: &str = "/admin";
+
+/// Path prefixes of the gateway's own routes, which pass-through endpoints
+/// may not take
+pub const RESERVED_PREFIXES: &[&str] = &[
+    ai::SCOPE,
+    auth::SCOPE,
+    health::SCOPE,
+    health::STATUS_PATH,
+    health::VERSION_PATH,
+    health::METRICS_PATH,
+    pricing::SCOPE,
+    spend::SCOPE,
+    ADMIN_SCOPE,
+];
+
 /// Standard API response structure
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ApiResponse<T> {
@@ -264,6 +288,9 @@ pub mod errors {
             GatewayError::Conflict(msg) => (actix_web::http::StatusCode::CONFLICT, msg),
             GatewayError::Validation(msg) => (actix_web::http::StatusCode::BAD_REQUEST, msg),
             GatewayError::RateLimit(msg) => (actix_web::http::StatusCode::TOO_MANY_REQUESTS, msg),
+            GatewayError::BudgetExceeded(msg) => {
+                (actix_web::http::StatusCode::TOO_MANY_REQUESTS, msg)
+            }
             _ => (
                 actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
                 "Internal server error".to_string(),
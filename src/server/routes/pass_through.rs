@@ -0,0 +1,728 @@
+//! Provider pass-through endpoints
+//!
+//! Each configured `pass_through` endpoint forwards every request under its
+//! path to a provider's native API with the gateway's credentials for that
+//! provider, for features the unified API does not model. Callers
+//! authenticate with the gateway, and token usage reported in JSON responses
+//! and event streams is logged and charged with its cost.
+
+use crate::config::{PassThroughEndpoint, ProviderConfig};
+use crate::core::observability::{TRACEPARENT_FIELD, TracedStream, current_traceparent};
+use crate::core::providers::base::GlobalPoolManager;
+use crate::core::providers::bedrock::{
+    AwsCredentialChain, CredentialSource, ResolvedCredentials, SigV4Signer,
+};
+use crate::server::middleware::{DeferredUsage, RequestUsage};
+use crate::server::routes::access::require_authenticated;
+use crate::server::routes::ai::{get_request_context, log_api_usage};
+use crate::server::routes::{ApiResponse, errors};
+use crate::server::state::AppState;
+use crate::services::callbacks::GenerationLog;
+use crate::services::pricing::PricingService;
+use crate::utils::error::GatewayError;
+use actix_web::{HttpRequest, HttpResponse, Result as ActixResult, web};
+use futures::StreamExt;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+use tracing::{error, info};
+
+/// HTTP client shared by all pass-through endpoints
+static POOL: LazyLock<GlobalPoolManager> = LazyLock::new(GlobalPoolManager::default);
+
+/// AWS credential chains of Bedrock providers, so that temporary
+/// credentials are reused until they expire
+static AWS_CREDENTIALS: LazyLock<
+    parking_lot::Mutex<HashMap<AwsChainKey, Arc<AwsCredentialChain>>>,
+> = LazyLock::new(Default::default);
+
+/// Settings a Bedrock provider's credential chain is built from:
+/// `(provider, region, access key ID, profile)`
+type AwsChainKey = (String, String, Option<String>, Option<String>);
+
+/// Headers never copied between the caller and the provider
+///
+/// Caller credentials stay at the gateway, and hop-by-hop headers are set by
+/// each connection.
+const SKIPPED_HEADERS: &[&str] = &[
+    "authorization",
+    "x-api-key",
+    "api-key",
+    "x-goog-api-key",
+    "cookie",
+    "host",
+    "connection",
+    "keep-alive",
+    "content-length",
+    "transfer-encoding",
+    "accept-encoding",
+    "content-encoding",
+];
+
+/// Token usage locations in provider-native responses: `(input, output)`
+const USAGE_POINTERS: &[(&str, &str)] = &[
+    // OpenAI-compatible
+    ("/usage/prompt_tokens", "/usage/completion_tokens"),
+    // Anthropic
+    ("/usage/input_tokens", "/usage/output_tokens"),
+    // Bedrock Converse
+    ("/usage/inputTokens", "/usage/outputTokens"),
+    // Gemini
+    (
+        "/usageMetadata/promptTokenCount",
+        "/usageMetadata/candidatesTokenCount",
+    ),
+    // Cohere v2 and v1
+    (
+        "/usage/billed_units/input_tokens",
+        "/usage/billed_units/output_tokens",
+    ),
+    (
+        "/meta/billed_units/input_tokens",
+        "/meta/billed_units/output_tokens",
+    ),
+];
+
+/// Configure one route scope per pass-through endpoint
+pub fn configure_routes(cfg: &mut web::ServiceConfig, endpoints: &[PassThroughEndpoint]) {
+    for endpoint in endpoints {
+        cfg.service(
+            web::scope(&endpoint.path)
+                .app_data(web::Data::new(endpoint.clone()))
+                .default_service(web::route().to(pass_through)),
+        );
+    }
+}
+
+/// Pass-through endpoint
+///
+/// Handles every method and sub-path under the endpoint's prefix.
+pub async fn pass_through(
+    state: web::Data<AppState>,
+    endpoint: web::Data<PassThroughEndpoint>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> ActixResult<HttpResponse> {
+    let context = get_request_context(&req)?;
+    let tail = req
+        .path()
+        .strip_prefix(endpoint.path.as_str())
+        .unwrap_or("");
+    info!(
+        "Pass-through request: {} {}{}",
+        req.method(),
+        endpoint.path,
+        tail
+    );
+
+    if endpoint.auth
+        && let Err(e) = require_authenticated(&req)
+    {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    if let Some(spend) = &state.spend
+        && let Err(e) = spend.budgets().check(None, None, Some(&endpoint.provider))
+    {
+        return Ok(errors::gateway_error_to_response(e));
+    }
+
+    let config = state.config.load_full();
+    let Some(provider) = config
+        .providers()
+        .iter()
+        .find(|p| p.name == endpoint.provider)
+    else {
+        return Ok(errors::gateway_error_to_response(GatewayError::not_found(
+            format!(
+                "Pass-through provider not configured: {}",
+                endpoint.provider
+            ),
+        )));
+    };
+
+    let Some(target) = endpoint
+        .target
+        .clone()
+        .or_else(|| provider.base_url.clone())
+        .or_else(|| default_target(provider))
+    else {
+        return Ok(errors::internal_error(&format!(
+            "No upstream URL for pass-through endpoint {}",
+            endpoint.path
+        )));
+    };
+
+    let mut url = format!("{}{}", target.trim_end_matches('/'), tail);
+    if !req.query_string().is_empty() {
+        url.push('?');
+        url.push_str(req.query_string());
+    }
+
+    let mut headers: Vec<(String, String)> = req
+        .headers()
+        .iter()
+        .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    headers.extend(endpoint.headers.clone());
+    if let Some(traceparent) = current_traceparent() {
+        headers.retain(|(name, _)| !name.eq_ignore_ascii_case(TRACEPARENT_FIELD));
+        headers.push((TRACEPARENT_FIELD.to_string(), traceparent));
+    }
+    match credential_headers(provider, req.method().as_str(), &url, &headers, &body).await {
+        Ok(credentials) => headers.extend(credentials),
+        Err(e) => {
+            error!("Pass-through signing error: {}", e);
+            return Ok(errors::internal_error(&e));
+        }
+    }
+
+    let method = match reqwest::Method::from_bytes(req.method().as_str().as_bytes()) {
+        Ok(method) => method,
+        Err(_) => return Ok(HttpResponse::MethodNotAllowed().finish()),
+    };
+    let mut upstream = POOL.client().request(method, &url);
+    for (name, value) in &headers {
+        upstream = upstream.header(name, value);
+    }
+    if !body.is_empty() {
+        upstream = upstream.body(body.clone());
+    }
+
+    let response = match upstream.send().await {
+        Ok(response) => response,
+        Err(e) => {
+            error!(
+                "Pass-through request to {} failed: {}",
+                endpoint.provider, e
+            );
+            return Ok(
+                HttpResponse::BadGateway().json(ApiResponse::<()>::error(format!(
+                    "Upstream request failed: {}",
+                    e
+                ))),
+            );
+        }
+    };
+
+    let status = actix_web::http::StatusCode::from_u16(response.status().as_u16())
+        .unwrap_or(actix_web::http::StatusCode::BAD_GATEWAY);
+    let mut builder = HttpResponse::build(status);
+    for (name, value) in response.headers() {
+        if !SKIPPED_HEADERS.contains(&name.as_str()) {
+            if let Ok(value) = value.to_str() {
+                builder.insert_header((name.as_str(), value));
+            }
+        }
+    }
+
+    let is_event_stream = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    if is_event_stream {
+        // Usage is charged once the stream ends, from the usage its events report
+        let model = request_model(&body, &Value::Null, tail);
+        RequestUsage::new(&model)
+            .with_provider(&endpoint.provider)
+            .deferred()
+            .record(&req);
+        let deferred_usage = DeferredUsage::new(&state, &req);
+        let pricing = Arc::clone(&state.pricing);
+        let callbacks = Arc::clone(&state.callbacks);
+        let provider_name = endpoint.provider.clone();
+        let provider_type = provider.provider_type.clone();
+        let name = format!("{}{}", endpoint.path, tail);
+        let span = tracing::info_span!("sse.stream", provider = %endpoint.provider);
+
+        let stream = async_stream::stream! {
+            let mut upstream = response.bytes_stream();
+            let mut usage = StreamUsage::default();
+            while let Some(chunk) = upstream.next().await {
+                match chunk {
+                    Ok(bytes) => {
+                        usage.scan(&bytes);
+                        yield Ok(bytes);
+                    }
+                    Err(e) => {
+                        yield Err(GatewayError::network(e.to_string()));
+                        break;
+                    }
+                }
+            }
+
+            // Tokens are billed even for streams that failed midway
+            let Some((prompt_tokens, completion_tokens)) = usage.tokens() else {
+                return;
+            };
+            let model = if model.is_empty() {
+                usage.model.unwrap_or_default()
+            } else {
+                model
+            };
+            let cost = usage_cost(
+                &pricing,
+                &provider_type,
+                &model,
+                prompt_tokens,
+                completion_tokens,
+            );
+            log_api_usage(&context, &model, prompt_tokens + completion_tokens, cost).await;
+            callbacks.log_success(
+                GenerationLog::new(&context, name, &model)
+                    .with_usage(prompt_tokens, completion_tokens, cost),
+            );
+            let usage = RequestUsage::new(&model)
+                .with_provider(&provider_name)
+                .with_tokens(prompt_tokens, completion_tokens, cost);
+            deferred_usage.charge(&usage).await;
+        };
+        return Ok(builder.streaming(TracedStream::new(stream, span)));
+    }
+
+    let bytes = match response.bytes().await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!(
+                "Pass-through response from {} failed: {}",
+                endpoint.provider, e
+            );
+            return Ok(
+                HttpResponse::BadGateway().json(ApiResponse::<()>::error(format!(
+                    "Upstream response failed: {}",
+                    e
+                ))),
+            );
+        }
+    };
+
+    if status.is_success() {
+        if let Ok(response) = serde_json::from_slice::<Value>(&bytes) {
+            if let Some((prompt_tokens, completion_tokens)) = response_usage(&response) {
+                let model = request_model(&body, &response, tail);
+                let cost = usage_cost(
+                    &state.pricing,
+                    &provider.provider_type,
+                    &model,
+                    prompt_tokens,
+                    completion_tokens,
+                );
+                log_api_usage(&context, &model, prompt_tokens + completion_tokens, cost).await;
+
+                if state.callbacks.is_enabled() {
+                    let input = serde_json::from_slice(&body).unwrap_or_default();
+                    let name = format!("{}{}", endpoint.path, tail);
+                    state.callbacks.log_success(
+                        GenerationLog::new(&context, name, &model)
+                            .with_content(input, response.clone())
+                            .with_usage(prompt_tokens, completion_tokens, cost),
+                    );
+                }
+
+                let mut usage = RequestUsage::new(&model)
+                    .with_provider(&endpoint.provider)
+                    .with_tokens(prompt_tokens, completion_tokens, cost);
+                if state
+                    .request_logs
+                    .as_ref()
+                    .is_some_and(|logs| logs.log_payloads())
+                {
+                    usage = usage.with_payloads(serde_json::from_slice(&body).ok(), Some(response));
+                }
+                usage.record(&req);
+            }
+        }
+    }
+
+    Ok(builder.body(bytes))
+}
+
+/// Public API of a provider type, when the provider has no base URL
+fn default_target(provider: &ProviderConfig) -> Option<String> {
+    let target = match provider.provider_type.as_str() {
+        "openai" => "https://api.openai.com",
+        "anthropic" => "https://api.anthropic.com",
+        "gemini" | "google" => "https://generativelanguage.googleapis.com",
+        "cohere" => "https://api.cohere.com",
+        "mistral" => "https://api.mistral.ai",
+        "groq" => "https://api.groq.com/openai",
+        "bedrock" => {
+            return Some(format!(
+                "https://bedrock-runtime.{}.amazonaws.com",
+                aws_region(provider)
+            ));
+        }
+        _ => return None,
+    };
+    Some(target.to_string())
+}
+
+/// Provider API key, from the config or its settings
+fn api_key(provider: &ProviderConfig) -> String {
+    if provider.api_key.is_empty() {
+        setting(provider, "api_key").unwrap_or_default()
+    } else {
+        provider.api_key.clone()
+    }
+}
+
+/// String setting of a provider
+fn setting(provider: &ProviderConfig, key: &str) -> Option<String> {
+    provider
+        .settings
+        .get(key)
+        .and_then(|value| value.as_str())
+        .map(String::from)
+}
+
+/// AWS region of a Bedrock provider
+fn aws_region(provider: &ProviderConfig) -> String {
+    setting(provider, "aws_region_name")
+        .or_else(|| setting(provider, "aws_region"))
+        .unwrap_or_else(|| "us-east-1".to_string())
+}
+
+/// Credential chain of a Bedrock provider
+///
+/// Keys in the provider's settings are used as they are; otherwise
+/// credentials come from the environment, the `aws_profile_name` profile,
+/// web identity, or the container or instance metadata.
+fn aws_credential_chain(provider: &ProviderConfig) -> Arc<AwsCredentialChain> {
+    let region = aws_region(provider);
+    let access_key_id = setting(provider, "aws_access_key_id");
+    let profile = setting(provider, "aws_profile_name");
+    let key = (
+        provider.name.clone(),
+        region.clone(),
+        access_key_id.clone(),
+        profile.clone(),
+    );
+
+    let mut chains = AWS_CREDENTIALS.lock();
+    let chain = chains.entry(key).or_insert_with(|| {
+        let static_credentials = access_key_id
+            .zip(setting(provider, "aws_secret_access_key"))
+            .map(|(access_key_id, secret_access_key)| {
+                ResolvedCredentials::new(
+                    access_key_id,
+                    secret_access_key,
+                    setting(provider, "aws_session_token"),
+                    CredentialSource::Static,
+                )
+            });
+        Arc::new(AwsCredentialChain::new(region, static_credentials, profile))
+    });
+    Arc::clone(chain)
+}
+
+/// Headers carrying the gateway's credentials for a provider
+///
+/// Bedrock requests are signed with SigV4 over the final URL, headers and
+/// body, with credentials from the provider's credential chain; other
+/// providers take their API key in the header they expect.
+async fn credential_headers(
+    provider: &ProviderConfig,
+    method: &str,
+    url: &str,
+    headers: &[(String, String)],
+    body: &[u8],
+) -> Result<Vec<(String, String)>, String> {
+    let key = api_key(provider);
+    let headers = match provider.provider_type.as_str() {
+        "anthropic" => vec![
+            ("x-api-key".to_string(), key),
+            ("anthropic-version".to_string(), "2023-06-01".to_string()),
+        ],
+        "gemini" | "google" => vec![("x-goog-api-key".to_string(), key)],
+        "azure" => vec![("api-key".to_string(), key)],
+        "bedrock" => {
+            let credentials = aws_credential_chain(provider)
+                .credentials()
+                .await
+                .map_err(|e| format!("No AWS credentials for {}: {}", provider.name, e))?;
+            let signer = SigV4Signer::new(
+                credentials.access_key_id,
+                credentials.secret_access_key,
+                credentials.session_token,
+                aws_region(provider),
+            );
+            let signed: HashMap<String, String> = headers
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                .cloned()
+                .collect();
+            signer
+                .sign_request(
+                    method,
+                    url,
+                    &signed,
+                    &String::from_utf8_lossy(body),
+                    chrono::Utc::now(),
+                )?
+                .into_iter()
+                .filter(|(name, _)| !name.eq_ignore_ascii_case("host"))
+                .collect()
+        }
+        _ => vec![("authorization".to_string(), format!("Bearer {}", key))],
+    };
+    Ok(headers)
+}
+
+/// Input and output tokens reported in a provider-native response
+fn response_usage(response: &Value) -> Option<(u32, u32)> {
+    USAGE_POINTERS.iter().find_map(|(input, output)| {
+        let input_tokens = response.pointer(input)?.as_u64()?;
+        let output_tokens = response
+            .pointer(output)
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+        Some((input_tokens as u32, output_tokens as u32))
+    })
+}
+
+/// Usage reported by the events of a provider-native event stream
+///
+/// Providers report usage cumulatively (Gemini), in a final event (OpenAI
+/// with `include_usage`) or split between events (Anthropic's
+/// `message_start` and `message_delta`), so the highest count seen is kept.
+#[derive(Debug, Default)]
+struct StreamUsage {
+    /// Incomplete line of the last chunk
+    line: Vec<u8>,
+    prompt_tokens: Option<u32>,
+    completion_tokens: Option<u32>,
+    /// Model the events name
+    model: Option<String>,
+}
+
+impl StreamUsage {
+    /// Scan a chunk of the stream for the usage of its `data:` events
+    fn scan(&mut self, chunk: &[u8]) {
+        self.line.extend_from_slice(chunk);
+        while let Some(end) = self.line.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.line.drain(..=end).collect();
+            let Some(data) = line.strip_prefix(b"data:") else {
+                continue;
+            };
+            if let Ok(event) = serde_json::from_slice::<Value>(data) {
+                self.event(&event);
+            }
+        }
+    }
+
+    fn event(&mut self, event: &Value) {
+        // Anthropic's `message_start` nests the message
+        for event in [event, &event["message"]] {
+            let (input, output) = match response_usage(event) {
+                Some((input, output)) => (Some(input), Some(output)),
+                None => (
+                    None,
+                    event
+                        .pointer("/usage/output_tokens")
+                        .and_then(Value::as_u64)
+                        .map(|tokens| tokens as u32),
+                ),
+            };
+            if input.is_some() {
+                self.prompt_tokens = self.prompt_tokens.max(input);
+            }
+            if output.is_some() {
+                self.completion_tokens = self.completion_tokens.max(output);
+            }
+            if self.model.is_none() {
+                self.model = event["model"].as_str().map(String::from);
+            }
+        }
+    }
+
+    /// Input and output tokens, if the stream reported any
+    fn tokens(&self) -> Option<(u32, u32)> {
+        if self.prompt_tokens.is_none() && self.completion_tokens.is_none() {
+            return None;
+        }
+        Some((
+            self.prompt_tokens.unwrap_or(0),
+            self.completion_tokens.unwrap_or(0),
+        ))
+    }
+}
+
+/// Cost of a pass-through call, priced by model name or by
+/// `<provider type>/<model>`
+fn usage_cost(
+    pricing: &PricingService,
+    provider_type: &str,
+    model: &str,
+    prompt_tokens: u32,
+    completion_tokens: u32,
+) -> f64 {
+    pricing
+        .get_cost_per_token(model)
+        .or_else(|| pricing.get_cost_per_token(&format!("{}/{}", provider_type, model)))
+        .map(|(input, output)| prompt_tokens as f64 * input + completion_tokens as f64 * output)
+        .unwrap_or(0.0)
+}
+
+/// Model of a pass-through call, from the request or response body, or from
+/// the path (`models/<model>:<method>` or `model/<model>/<action>`)
+fn request_model(body: &[u8], response: &Value, path: &str) -> String {
+    let from_body = serde_json::from_slice::<Value>(body)
+        .ok()
+        .and_then(|body| body["model"].as_str().map(String::from));
+    let from_response = || response["model"].as_str().map(String::from);
+    let from_path = || {
+        let mut segments = path.split('/');
+        segments
+            .by_ref()
+            .find(|segment| *segment == "models" || *segment == "model")?;
+        let model = segments.next()?;
+        let model = model.split(':').next().unwrap_or(model);
+        Some(
+            url::form_urlencoded::parse(model.as_bytes())
+                .map(|(key, value)| format!("{}{}", key, value))
+                .collect::<String>(),
+        )
+    };
+
+    from_body
+        .or_else(from_response)
+        .or_else(from_path)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn provider(provider_type: &str) -> ProviderConfig {
+        ProviderConfig {
+            name: provider_type.to_string(),
+            provider_type: provider_type.to_string(),
+            api_key: "secret".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_response_usage() {
+        let gemini = json!({"usageMetadata": {"promptTokenCount": 12, "candidatesTokenCount": 30}});
+        assert_eq!(response_usage(&gemini), Some((12, 30)));
+
+        let cohere = json!({"meta": {"billed_units": {"input_tokens": 5, "output_tokens": 7}}});
+        assert_eq!(response_usage(&cohere), Some((5, 7)));
+
+        let bedrock = json!({"usage": {"inputTokens": 3, "outputTokens": 4, "totalTokens": 7}});
+        assert_eq!(response_usage(&bedrock), Some((3, 4)));
+
+        assert_eq!(response_usage(&json!({"data": []})), None);
+    }
+
+    #[test]
+    fn test_stream_usage() {
+        // Anthropic, with an event split across chunks
+        let mut usage = StreamUsage::default();
+        usage.scan(b"event: message_start\ndata: {\"type\":\"message_start\",\"message\":{\"model\":\"claude-3-5-haiku\",");
+        usage.scan(b"\"usage\":{\"input_tokens\":25,\"output_tokens\":1}}}\n\n");
+        usage.scan(b"event: message_delta\ndata: {\"type\":\"message_delta\",\"usage\":{\"output_tokens\":15}}\n\n");
+        assert_eq!(usage.tokens(), Some((25, 15)));
+        assert_eq!(usage.model.as_deref(), Some("claude-3-5-haiku"));
+
+        // OpenAI, with the usage in the last chunk
+        let mut usage = StreamUsage::default();
+        usage.scan(b"data: {\"model\":\"gpt-4o\",\"choices\":[]}\n\n");
+        usage.scan(b"data: {\"choices\":[],\"usage\":{\"prompt_tokens\":9,\"completion_tokens\":3}}\n\ndata: [DONE]\n\n");
+        assert_eq!(usage.tokens(), Some((9, 3)));
+
+        // Gemini, with cumulative counts
+        let mut usage = StreamUsage::default();
+        usage.scan(b"data: {\"usageMetadata\":{\"promptTokenCount\":7,\"candidatesTokenCount\":2}}\r\n\r\n");
+        usage.scan(b"data: {\"usageMetadata\":{\"promptTokenCount\":7,\"candidatesTokenCount\":11}}\r\n\r\n");
+        assert_eq!(usage.tokens(), Some((7, 11)));
+
+        assert_eq!(StreamUsage::default().tokens(), None);
+    }
+
+    #[test]
+    fn test_request_model() {
+        let body = br#"{"model": "command-r-plus", "message": "hi"}"#;
+        assert_eq!(
+            request_model(body, &json!({}), "/v1/chat"),
+            "command-r-plus"
+        );
+
+        assert_eq!(
+            request_model(
+                b"",
+                &json!({}),
+                "/v1beta/models/gemini-1.5-pro:generateContent"
+            ),
+            "gemini-1.5-pro"
+        );
+        assert_eq!(
+            request_model(
+                b"",
+                &json!({}),
+                "/model/anthropic.claude-3-haiku-20240307-v1%3A0/invoke"
+            ),
+            "anthropic.claude-3-haiku-20240307-v1:0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_credential_headers() {
+        let headers = credential_headers(&provider("gemini"), "POST", "", &[], b"")
+            .await
+            .unwrap();
+        assert_eq!(
+            headers,
+            vec![("x-goog-api-key".to_string(), "secret".to_string())]
+        );
+
+        let headers = credential_headers(&provider("cohere"), "POST", "", &[], b"")
+            .await
+            .unwrap();
+        assert_eq!(headers[0].1, "Bearer secret");
+        assert_eq!(
+            default_target(&provider("cohere")).as_deref(),
+            Some("https://api.cohere.com")
+        );
+
+        let mut bedrock = provider("bedrock");
+        bedrock
+            .settings
+            .insert("aws_access_key_id".to_string(), json!("AKIDEXAMPLE"));
+        bedrock
+            .settings
+            .insert("aws_secret_access_key".to_string(), json!("wJalrXUtnFEMI"));
+        let url = "https://bedrock-runtime.us-east-1.amazonaws.com/model/amazon.titan-text-express-v1/invoke";
+        let headers = credential_headers(
+            &bedrock,
+            "POST",
+            url,
+            &[("content-type".to_string(), "application/json".to_string())],
+            br#"{"inputText": "hi"}"#,
+        )
+        .await
+        .unwrap();
+        assert!(headers.iter().any(|(name, value)| name == "Authorization"
+            && value.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/")));
+        assert!(!headers.iter().any(|(name, _)| name == "host"));
+    }
+
+    #[test]
+    fn test_aws_credential_chain_is_reused() {
+        let mut bedrock = provider("bedrock");
+        bedrock.name = "bedrock-reused".to_string();
+        let chain = aws_credential_chain(&bedrock);
+        assert!(Arc::ptr_eq(&chain, &aws_credential_chain(&bedrock)));
+
+        // Changed settings build a new chain
+        bedrock
+            .settings
+            .insert("aws_profile_name".to_string(), json!("prod"));
+        assert!(!Arc::ptr_eq(&chain, &aws_credential_chain(&bedrock)));
+    }
+}
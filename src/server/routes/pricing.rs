@@ -180,10 +180,13 @@ pub async fn calculate_cost(
     }
 }
 
+/// Scope of the pricing endpoints
+pub const SCOPE: &str = "/api/v1/pricing";
+
 /// Configure pricing endpoints
 pub fn configure_pricing_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
-        web::scope("/api/v1/pricing")
+        web::scope(SCOPE)
             .route("/refresh", web::post().to(refresh_pricing))
             .route("/stats", web::get().to(get_pricing_stats))
             .route("/model/{model_name}", web::get().to(get_model_pricing))
@@ -0,0 +1,23 @@
+//! Data retention endpoint
+
+use crate::server::routes::access::require_admin;
+use crate::server::routes::{ADMIN_SCOPE, ApiResponse};
+use crate::server::state::AppState;
+use crate::utils::error::Result;
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// Remove data past retention now and report what was removed
+/// POST /admin/retention/run
+pub async fn run_retention(data: web::Data<AppState>, req: HttpRequest) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let report = data.retention.run().await;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(report)))
+}
+
+/// Configure retention routes
+pub fn configure_retention_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope(&format!("{}/retention", ADMIN_SCOPE))
+            .route("/run", web::post().to(run_retention)),
+    );
+}
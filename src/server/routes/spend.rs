@@ -0,0 +1,68 @@
+//! Spend reporting endpoints
+//!
+//! Both endpoints accept `start_date` and `end_date` (`YYYY-MM-DD`, inclusive)
+//! plus optional `api_key`, `team_id`, `user_id` and `model` filters. Without
+//! a `start_date`, the last 30 days are reported. Only admins may read spend.
+
+use crate::core::models::metrics::SpendFilter;
+use crate::server::routes::ApiResponse;
+use crate::server::routes::access::require_admin;
+use crate::server::state::AppState;
+use crate::services::spend::SpendTracker;
+use crate::utils::error::{GatewayError, Result};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// Days reported when no start date is given
+const DEFAULT_DAYS: i64 = 30;
+
+/// Spend per day
+/// GET /spend/daily
+pub async fn get_daily_spend(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<SpendFilter>,
+) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let filter = with_default_range(query.into_inner());
+    let daily = tracker(&data)?.daily_spend(&filter).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(daily)))
+}
+
+/// Spend per API key, highest first
+/// GET /spend/keys
+pub async fn get_key_spend(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<SpendFilter>,
+) -> Result<HttpResponse> {
+    require_admin(&req, data.auth.rbac())?;
+    let filter = with_default_range(query.into_inner());
+    let keys = tracker(&data)?.key_spend(&filter).await?;
+    Ok(HttpResponse::Ok().json(ApiResponse::success(keys)))
+}
+
+fn tracker(data: &AppState) -> Result<&SpendTracker> {
+    data.spend
+        .as_deref()
+        .ok_or_else(|| GatewayError::NotFound("Spend tracking is not enabled".to_string()))
+}
+
+fn with_default_range(mut filter: SpendFilter) -> SpendFilter {
+    if filter.start_date.is_none() {
+        let today = chrono::Utc::now().date_naive();
+        filter.start_date = Some(today - chrono::Duration::days(DEFAULT_DAYS - 1));
+    }
+    filter
+}
+
+/// Scope of the spend routes
+pub const SCOPE: &str = "/spend";
+
+/// Configure spend routes
+pub fn configure_spend_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope(SCOPE)
+            .route("/daily", web::get().to(get_daily_spend))
+            .route("/keys", web::get().to(get_key_spend)),
+    );
+}
@@ -2,11 +2,17 @@
 //!
 //! This module provides the HttpServer struct and its core methods.
 
-use crate::config::{Config, ServerConfig};
-use crate::server::handlers::health_check;
+use crate::config::secrets::SecretResolver;
+use crate::config::{Config, ProviderConfig, ServerConfig};
+use crate::core::router::UnifiedRouter;
+use crate::server::middleware::{
+    AuthMiddleware, LoadSheddingMiddleware, OidcMiddleware, RateLimitMiddleware,
+    RequestLogMiddleware, SpendMiddleware, TraceContextMiddleware,
+};
 use crate::server::routes;
 use crate::server::state::AppState;
 use crate::services::pricing::PricingService;
+use crate::storage::redis::RedisPool;
 use crate::utils::error::{GatewayError, Result};
 use actix_cors::Cors;
 use actix_web::{
@@ -32,47 +38,54 @@ impl HttpServer {
     pub async fn new(config: &Config) -> Result<Self> {
         info!("Creating HTTP server");
 
+        let tracing_config = &config.gateway.monitoring.tracing;
+        if tracing_config.enabled
+            && let Some(endpoint) = &tracing_config.endpoint
+        {
+            crate::core::observability::init_exporter(
+                endpoint,
+                &tracing_config.service_name,
+                tracing_config.headers.clone(),
+            );
+            info!("Exporting traces to {}", endpoint);
+        }
+
         let storage = crate::storage::StorageLayer::new(&config.gateway.storage).await?;
+        let storage_config = &config.gateway.storage;
+        if (storage_config.request_logs.enabled
+            || storage_config.spend.enabled
+            || storage_config.encryption.enabled)
+            && let Err(e) = storage.migrate().await
+        {
+            warn!(
+                "Request logs, spend and credentials may not be stored: {}",
+                e
+            );
+        }
         let auth =
             crate::auth::AuthSystem::new(&config.gateway.auth, Arc::new(storage.clone())).await?;
-        let mut router = crate::core::providers::ProviderRegistry::new();
-
-        // Initialize providers from config
-        if !config.gateway.providers.is_empty() {
-            for provider_config in &config.gateway.providers {
-                let provider_type: crate::core::providers::ProviderType =
-                    provider_config.provider_type.as_str().into();
-
-                let mut settings = provider_config.settings.clone();
-                // Add api_key from config if not in settings
-                if !settings.contains_key("api_key") && !provider_config.api_key.is_empty() {
-                    settings.insert(
-                        "api_key".to_string(),
-                        serde_json::Value::String(provider_config.api_key.clone()),
-                    );
-                }
 
-                match crate::core::providers::Provider::from_config_async(
-                    provider_type.clone(),
-                    serde_json::Value::Object(settings.into_iter().collect()),
-                )
+        // Replace secret references in provider credentials
+        let secrets = Arc::new(SecretResolver::new(&config.gateway.secrets));
+        let mut config = config.clone();
+        secrets
+            .resolve_providers(&mut config.gateway.providers)
+            .await?;
+        if storage_config.encryption.enabled {
+            match storage
+                .database
+                .fill_provider_credentials(&mut config.gateway.providers)
                 .await
-                {
-                    Ok(provider) => {
-                        router.register(provider);
-                        info!("Registered provider: {}", provider_config.name);
-                    }
-                    Err(e) => {
-                        warn!(
-                            "Failed to initialize provider {}: {}",
-                            provider_config.name, e
-                        );
-                    }
-                }
+            {
+                Ok(0) => {}
+                Ok(filled) => info!("Loaded {} stored provider credentials", filled),
+                Err(e) => warn!("Stored provider credentials were not loaded: {}", e),
             }
-        } else {
-            debug!("No providers configured, gateway will route based on model prefix");
         }
+        secrets.start();
+
+        let router = provider_registry(&config.gateway.providers).await;
+        let unified_router = unified_router(&config, storage.redis.clone()).await;
 
         let pricing = Arc::new(PricingService::new(Some(
             "config/model_prices_extended.json".to_string(),
@@ -81,7 +94,15 @@ impl HttpServer {
         let pricing_clone: Arc<PricingService> = Arc::clone(&pricing);
         let _pricing_task = pricing_clone.start_auto_refresh_task();
 
-        let state = AppState::new(config.clone(), auth, router, storage, pricing);
+        let state = AppState::new(
+            config.clone(),
+            auth,
+            router,
+            unified_router,
+            storage,
+            pricing,
+            secrets,
+        );
 
         Ok(Self {
             config: config.gateway.server.clone(),
@@ -103,7 +124,8 @@ impl HttpServer {
     > {
         info!("Setting up routes and middleware");
 
-        let cors_config = &state.config.gateway.server.cors;
+        let config = state.config.load_full();
+        let cors_config = &config.gateway.server.cors;
         let mut cors = Cors::default();
 
         if cors_config.enabled {
@@ -143,14 +165,29 @@ impl HttpServer {
             }
         }
 
+        let pass_through = config.gateway.pass_through.clone();
+
         App::new()
             .app_data(state)
+            .wrap(RateLimitMiddleware)
+            .wrap(AuthMiddleware)
+            .wrap(OidcMiddleware)
+            .wrap(LoadSheddingMiddleware)
             .wrap(cors)
             .wrap(Logger::default())
+            .wrap(RequestLogMiddleware)
+            .wrap(SpendMiddleware)
+            .wrap(TraceContextMiddleware)
             .wrap(DefaultHeaders::new().add(("Server", "LiteLLM-RS")))
-            .route("/health", web::get().to(health_check))
+            .configure(routes::health::configure_routes)
             .configure(routes::ai::configure_routes)
             .configure(routes::pricing::configure_pricing_routes)
+            .configure(routes::spend::configure_spend_routes)
+            .configure(routes::retention::configure_retention_routes)
+            .configure(routes::encryption::configure_encryption_routes)
+            .configure(routes::credentials::configure_credential_routes)
+            .configure(routes::config::configure_config_routes)
+            .configure(move |cfg| routes::pass_through::configure_routes(cfg, &pass_through))
     }
 
     /// Start the HTTP server
@@ -177,6 +214,13 @@ impl HttpServer {
         Ok(())
     }
 
+    /// Reload the configuration from `location` when it changes, on SIGHUP
+    /// and on `POST /admin/config/reload`
+    pub fn watch_config(&self, location: impl Into<crate::config::ConfigLocation>) {
+        let watch_interval = self.config.config_watch_interval;
+        self.state.reloader.watch(location, watch_interval);
+    }
+
     /// Get server configuration
     pub fn config(&self) -> &ServerConfig {
         &self.config
@@ -187,3 +231,62 @@ impl HttpServer {
         &self.state
     }
 }
+
+/// Register the configured providers
+///
+/// Providers that fail to initialize are logged and left out.
+pub(crate) async fn provider_registry(
+    providers: &[ProviderConfig],
+) -> crate::core::providers::ProviderRegistry {
+    let mut router = crate::core::providers::ProviderRegistry::new();
+    if providers.is_empty() {
+        debug!("No providers configured, gateway will route based on model prefix");
+    }
+
+    for provider_config in providers {
+        match crate::core::providers::Provider::from_provider_config(provider_config).await {
+            Ok(provider) => {
+                router.register(provider);
+                info!("Registered provider: {}", provider_config.name);
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to initialize provider {}: {}",
+                    provider_config.name, e
+                );
+            }
+        }
+    }
+    router
+}
+
+/// Router over the deployments of the configured providers, with the
+/// gateway's router settings
+///
+/// If a provider fails to initialize, the router is left without
+/// deployments and every model is served by prefix instead.
+pub(crate) async fn unified_router(
+    config: &Config,
+    redis: Option<Arc<RedisPool>>,
+) -> UnifiedRouter {
+    let router_config = config.router();
+    let router =
+        match UnifiedRouter::from_gateway_config(config.providers(), Some(router_config.into()))
+            .await
+        {
+            Ok(router) => router,
+            Err(e) => {
+                warn!("Router has no deployments: {}", e);
+                UnifiedRouter::new(router_config.into())
+            }
+        };
+    for (alias, model_name) in &router_config.model_aliases {
+        router.add_model_alias(alias, model_name);
+    }
+
+    let router = router.with_fallback_config(router_config.into());
+    match redis {
+        Some(redis) => router.with_shared_usage(redis),
+        None => router,
+    }
+}
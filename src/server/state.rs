@@ -3,8 +3,24 @@
 //! This module provides the AppState struct and its implementations.
 
 use crate::config::Config;
+use crate::config::secrets::SecretResolver;
+use crate::core::guardrails::Guardrails;
+use crate::core::rate_limiter::UsageLimiter;
+use crate::core::response_cache::ResponseCache;
+use crate::core::router::UnifiedRouter;
+use crate::core::semantic_cache::{ProviderEmbeddings, SemanticCache, SemanticCacheConfig};
+use crate::server::middleware::LoadShedder;
+use crate::server::reload::ConfigReloader;
+use crate::server::routes::ai::{RealtimeSessions, RecordedRuns};
+use crate::services::alerting::AlertingService;
+use crate::services::callbacks::CallbackManager;
 use crate::services::pricing::PricingService;
+use crate::services::request_logs::RequestLogger;
+use crate::services::retention::RetentionService;
+use crate::services::spend::{BudgetGuard, SpendTracker};
+use arc_swap::ArcSwap;
 use std::sync::Arc;
+use tracing::{error, warn};
 
 /// HTTP server state shared across handlers
 ///
@@ -14,18 +30,46 @@ use std::sync::Arc;
 #[derive(Clone)]
 #[allow(dead_code)]
 pub struct AppState {
-    /// Gateway configuration (shared read-only)
-    pub config: Arc<Config>,
+    /// Gateway configuration, replaced when it is reloaded
+    pub config: Arc<ArcSwap<Config>>,
     /// Authentication system
     pub auth: Arc<crate::auth::AuthSystem>,
-    /// Request router (legacy ProviderRegistry)
-    pub router: Arc<crate::core::providers::ProviderRegistry>,
-    /// Unified router (new UnifiedRouter implementation)
-    pub unified_router: Option<Arc<crate::core::router::UnifiedRouter>>,
+    /// Request router (legacy ProviderRegistry), replaced when the
+    /// configuration is reloaded
+    pub router: Arc<ArcSwap<crate::core::providers::ProviderRegistry>>,
+    /// Router over the configured deployments, replaced when the
+    /// configuration is reloaded
+    pub unified_router: Arc<ArcSwap<UnifiedRouter>>,
     /// Storage layer
     pub storage: Arc<crate::storage::StorageLayer>,
     /// Unified pricing service
     pub pricing: Arc<PricingService>,
+    /// Open `/v1/realtime` sessions per API key
+    pub realtime_sessions: Arc<RealtimeSessions>,
+    /// Assistants API runs whose usage has been recorded
+    pub recorded_runs: Arc<RecordedRuns>,
+    /// Logging callbacks
+    pub callbacks: Arc<CallbackManager>,
+    /// Guardrails run around provider calls
+    pub guardrails: Arc<Guardrails>,
+    /// Request audit log, if enabled
+    pub request_logs: Option<Arc<RequestLogger>>,
+    /// Spend tracker, if enabled
+    pub spend: Option<Arc<SpendTracker>>,
+    /// Budget and anomaly alerting, if enabled
+    pub alerting: Option<Arc<AlertingService>>,
+    /// Per-key and per-model rate limiter, if enabled
+    pub rate_limiter: Option<Arc<UsageLimiter>>,
+    /// Gateway-wide in-flight request limit, if enabled
+    pub load_shedder: Option<Arc<LoadShedder>>,
+    /// Exact-match response cache, if enabled
+    pub response_cache: Option<Arc<ResponseCache>>,
+    /// Semantic response cache, if enabled
+    pub semantic_cache: Option<Arc<SemanticCache>>,
+    /// Data retention job
+    pub retention: Arc<RetentionService>,
+    /// Configuration hot reload
+    pub reloader: Arc<ConfigReloader>,
 }
 
 impl AppState {
@@ -34,41 +78,222 @@ impl AppState {
         config: Config,
         auth: crate::auth::AuthSystem,
         router: crate::core::providers::ProviderRegistry,
+        unified_router: UnifiedRouter,
         storage: crate::storage::StorageLayer,
         pricing: Arc<PricingService>,
+        secrets: Arc<SecretResolver>,
     ) -> Self {
-        Self {
-            config: Arc::new(config),
-            auth: Arc::new(auth),
-            router: Arc::new(router),
-            unified_router: None,
-            storage: Arc::new(storage),
-            pricing,
+        let realtime_sessions = Arc::new(RealtimeSessions::new(
+            config.server().realtime_max_sessions_per_key,
+        ));
+        let callbacks = Arc::new(CallbackManager::new(&config.gateway.callbacks));
+        let guardrails = guardrails(&config);
+        let request_logs = request_logger(&config, &storage);
+        let rate_limiter = rate_limiter(&config, &storage);
+        let load_shedder = load_shedder(&config);
+        let response_cache = response_cache(&config, &storage);
+        let router = Arc::new(ArcSwap::from_pointee(router));
+        let semantic_cache = semantic_cache(&config, &storage, &router);
+        let retention = retention(&config, &storage, semantic_cache.as_ref());
+        let alerting = alerting(&config, &callbacks);
+        let spend = spend_tracker(&config, &storage, &pricing, alerting.clone());
+        // Registered even without provider budgets, which a reload may add
+        if let Some(spend) = &spend {
+            callbacks.register(Arc::new(BudgetGuard::new(Arc::clone(spend.budgets()))));
         }
-    }
-
-    /// Create a new AppState with unified router
-    pub fn new_with_unified_router(
-        config: Config,
-        auth: crate::auth::AuthSystem,
-        router: crate::core::providers::ProviderRegistry,
-        unified_router: crate::core::router::UnifiedRouter,
-        storage: crate::storage::StorageLayer,
-        pricing: Arc<PricingService>,
-    ) -> Self {
+        let unified_router = Arc::new(unified_router);
+        unified_router.start_probe_task();
+        if let Some(alerting) = &alerting {
+            alerting.watch_cooldowns(&unified_router);
+        }
+        let unified_router = Arc::new(ArcSwap::new(unified_router));
+        let credentials = config
+            .gateway
+            .storage
+            .encryption
+            .enabled
+            .then(|| Arc::clone(&storage.database));
+        let config = Arc::new(ArcSwap::from_pointee(config));
+        let reloader = Arc::new(
+            ConfigReloader::new(
+                Arc::clone(&config),
+                Arc::clone(&router),
+                Arc::clone(&unified_router),
+                storage.redis.clone(),
+                spend.clone(),
+                alerting.clone(),
+                secrets,
+            )
+            .with_credentials(credentials),
+        );
         Self {
-            config: Arc::new(config),
+            config,
             auth: Arc::new(auth),
-            router: Arc::new(router),
-            unified_router: Some(Arc::new(unified_router)),
+            router,
+            unified_router,
             storage: Arc::new(storage),
             pricing,
+            realtime_sessions,
+            recorded_runs: Arc::new(RecordedRuns::default()),
+            callbacks,
+            guardrails,
+            request_logs,
+            spend,
+            alerting,
+            rate_limiter,
+            load_shedder,
+            response_cache,
+            semantic_cache,
+            retention,
+            reloader,
         }
     }
 
-    /// Get gateway configuration
+    /// Get the current gateway configuration
     #[allow(dead_code)] // May be used by handlers
-    pub fn config(&self) -> &Config {
-        &self.config
+    pub fn config(&self) -> Arc<Config> {
+        self.config.load_full()
+    }
+}
+
+/// Configured guardrails
+///
+/// Loaded configurations were validated by creating their guardrails, so
+/// creating them again only fails for configurations built in code.
+fn guardrails(config: &Config) -> Arc<Guardrails> {
+    let guardrails = Guardrails::from_config(&config.gateway.guardrails).unwrap_or_else(|e| {
+        error!("Guardrails disabled: {}", e);
+        Guardrails::default()
+    });
+    Arc::new(guardrails)
+}
+
+/// Request logger writing to the storage layer's database, if enabled
+fn request_logger(
+    config: &Config,
+    storage: &crate::storage::StorageLayer,
+) -> Option<Arc<RequestLogger>> {
+    let request_logs = &config.gateway.storage.request_logs;
+    request_logs.enabled.then(|| {
+        Arc::new(RequestLogger::new(
+            request_logs.clone(),
+            Arc::clone(&storage.database),
+        ))
+    })
+}
+
+/// Rate limiter counting in the storage layer's cache, if enabled
+fn rate_limiter(
+    config: &Config,
+    storage: &crate::storage::StorageLayer,
+) -> Option<Arc<UsageLimiter>> {
+    let rate_limit = &config.gateway.rate_limit;
+    rate_limit
+        .enabled
+        .then(|| Arc::new(UsageLimiter::new(rate_limit, Arc::clone(&storage.cache))))
+}
+
+/// Response cache in the storage layer's cache, if enabled
+fn response_cache(
+    config: &Config,
+    storage: &crate::storage::StorageLayer,
+) -> Option<Arc<ResponseCache>> {
+    let cache = &config.gateway.cache;
+    cache
+        .enabled
+        .then(|| Arc::new(ResponseCache::new(cache, Arc::clone(&storage.cache))))
+}
+
+/// Semantic cache in the storage layer's vector database, if enabled
+fn semantic_cache(
+    config: &Config,
+    storage: &crate::storage::StorageLayer,
+    router: &Arc<ArcSwap<crate::core::providers::ProviderRegistry>>,
+) -> Option<Arc<SemanticCache>> {
+    let cache = &config.gateway.cache;
+    if !cache.enabled || !cache.semantic_cache {
+        return None;
+    }
+    let Some(vector) = &storage.vector else {
+        warn!("Semantic cache disabled, no vector database is configured");
+        return None;
+    };
+
+    let semantic_config = SemanticCacheConfig {
+        similarity_threshold: cache.similarity_threshold,
+        max_cache_size: cache.max_size,
+        default_ttl_seconds: cache.ttl,
+        embedding_model: cache.embedding_model.clone(),
+        namespace: cache.namespace.clone(),
+        per_key: cache.per_key,
+        ..Default::default()
+    };
+    let embeddings = ProviderEmbeddings::new(
+        Arc::clone(router),
+        cache.embedding_provider.clone(),
+        cache.embedding_model.clone(),
+    );
+    match SemanticCache::new(
+        semantic_config,
+        Arc::clone(vector) as Arc<_>,
+        Arc::new(embeddings),
+    ) {
+        Ok(semantic_cache) => Some(Arc::new(semantic_cache)),
+        Err(e) => {
+            warn!("Semantic cache disabled: {}", e);
+            None
+        }
     }
 }
+
+/// Retention job pruning the storage layer and caches, started on its interval
+fn retention(
+    config: &Config,
+    storage: &crate::storage::StorageLayer,
+    semantic_cache: Option<&Arc<SemanticCache>>,
+) -> Arc<RetentionService> {
+    let retention = Arc::new(RetentionService::new(
+        &config.gateway.storage,
+        Arc::clone(&storage.database),
+        Arc::clone(&storage.cache),
+        semantic_cache.cloned(),
+    ));
+    retention.start();
+    retention
+}
+
+/// Load shedder limiting requests in flight, if enabled
+fn load_shedder(config: &Config) -> Option<Arc<LoadShedder>> {
+    let load_shedding = &config.server().load_shedding;
+    load_shedding
+        .is_enabled()
+        .then(|| Arc::new(LoadShedder::new(load_shedding)))
+}
+
+/// Spend tracker writing to the storage layer's database, if enabled
+fn spend_tracker(
+    config: &Config,
+    storage: &crate::storage::StorageLayer,
+    pricing: &Arc<PricingService>,
+    alerting: Option<Arc<AlertingService>>,
+) -> Option<Arc<SpendTracker>> {
+    let spend = &config.gateway.storage.spend;
+    spend.enabled.then(|| {
+        Arc::new(SpendTracker::new(
+            spend,
+            Arc::clone(&storage.database),
+            Arc::clone(pricing),
+            alerting,
+        ))
+    })
+}
+
+/// Alerting service, if enabled, registered to watch provider error rates
+fn alerting(config: &Config, callbacks: &CallbackManager) -> Option<Arc<AlertingService>> {
+    let alerting = &config.gateway.monitoring.alerting;
+    alerting.enabled.then(|| {
+        let service = Arc::new(AlertingService::new(alerting.clone()));
+        callbacks.register(Arc::clone(&service) as Arc<_>);
+        service
+    })
+}
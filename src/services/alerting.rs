@@ -0,0 +1,333 @@
+//! Budget and anomaly alerting
+//!
+//! Alerts are posted as Slack-compatible payloads to every configured
+//! webhook. Three things raise them: spend crossing a fraction of a budget,
+//! a provider's error rate spiking within a window, and the router cooling
+//! down a deployment. Alerts are sent in the background, so raising one never
+//! delays a request.
+
+use crate::config::AlertingConfig;
+use crate::core::router::{CooldownEvent, UnifiedRouter};
+use crate::monitoring::alerts::{NotificationChannel, SlackChannel};
+use crate::monitoring::types::{Alert, AlertSeverity};
+use crate::services::callbacks::{CallContext, CallbackHandler, GenerationLog};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, warn};
+
+/// Requests and failures of one provider in the current window
+#[derive(Debug)]
+struct ErrorWindow {
+    start: Instant,
+    requests: u64,
+    failures: u64,
+    alerted: bool,
+}
+
+/// Sends alerts to the configured webhooks
+#[derive(Debug)]
+pub struct AlertingService {
+    config: AlertingConfig,
+    channels: Arc<[SlackChannel]>,
+    error_windows: Mutex<HashMap<String, ErrorWindow>>,
+}
+
+impl AlertingService {
+    /// Create a service posting to the configured webhooks
+    pub fn new(config: AlertingConfig) -> Self {
+        let channels = config
+            .webhook_urls()
+            .map(|url| {
+                SlackChannel::new(
+                    url.clone(),
+                    None,
+                    Some("Gateway Alert".to_string()),
+                    AlertSeverity::Info,
+                )
+            })
+            .collect();
+        let mut config = config;
+        config.budget_thresholds.sort_by(|a, b| a.total_cmp(b));
+
+        Self {
+            config,
+            channels,
+            error_windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Alert if spend moving from `before` to `after` crosses a threshold
+    ///
+    /// Only the highest threshold crossed is reported.
+    pub fn budget_spend(&self, budget: &str, before: f64, after: f64, max_budget: f64) {
+        if let Some(alert) = self.budget_alert(budget, before, after, max_budget) {
+            self.send(alert);
+        }
+    }
+
+    /// Count a provider call, alerting if the provider's error rate spikes
+    ///
+    /// Each provider alerts at most once per window.
+    pub fn record_call(&self, provider: &str, failed: bool) {
+        if let Some(alert) = self.error_rate_alert(provider, failed, Instant::now()) {
+            self.send(alert);
+        }
+    }
+
+    /// Alert whenever the router cools down a deployment
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn watch_cooldowns(self: &Arc<Self>, router: &UnifiedRouter) {
+        if !self.config.cooldown_alerts {
+            return;
+        }
+
+        let mut events = router.subscribe_cooldowns();
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => service.send(cooldown_alert(&event)),
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("Skipped {} cooldown alerts", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    fn budget_alert(
+        &self,
+        budget: &str,
+        before: f64,
+        after: f64,
+        max_budget: f64,
+    ) -> Option<Alert> {
+        let threshold = self
+            .config
+            .budget_thresholds
+            .iter()
+            .rev()
+            .find(|threshold| {
+                before < *threshold * max_budget && after >= *threshold * max_budget
+            })?;
+
+        let severity = if *threshold >= 1.0 {
+            AlertSeverity::Critical
+        } else {
+            AlertSeverity::Warning
+        };
+        Some(alert(
+            severity,
+            format!("{} reached {:.0}% of its budget", budget, threshold * 100.0),
+            format!(
+                "{} has spent ${:.2} of its ${:.2} budget",
+                budget, after, max_budget
+            ),
+            "budget",
+            serde_json::json!({
+                "budget": budget,
+                "threshold": threshold,
+                "spend": after,
+                "max_budget": max_budget,
+            }),
+        ))
+    }
+
+    fn error_rate_alert(&self, provider: &str, failed: bool, now: Instant) -> Option<Alert> {
+        let window_length = Duration::from_secs(self.config.error_rate_window);
+        let mut windows = self.error_windows.lock();
+        let window = windows
+            .entry(provider.to_string())
+            .or_insert_with(|| ErrorWindow {
+                start: now,
+                requests: 0,
+                failures: 0,
+                alerted: false,
+            });
+        if now.duration_since(window.start) >= window_length {
+            *window = ErrorWindow {
+                start: now,
+                requests: 0,
+                failures: 0,
+                alerted: false,
+            };
+        }
+
+        window.requests += 1;
+        if failed {
+            window.failures += 1;
+        }
+
+        let rate = window.failures as f64 / window.requests as f64;
+        if window.alerted
+            || window.requests < self.config.error_rate_min_requests
+            || rate < self.config.error_rate_threshold
+        {
+            return None;
+        }
+        window.alerted = true;
+
+        Some(alert(
+            AlertSeverity::Critical,
+            format!("{} error rate at {:.0}%", provider, rate * 100.0),
+            format!(
+                "{} of {} calls to {} failed in the last {} seconds",
+                window.failures, window.requests, provider, self.config.error_rate_window
+            ),
+            "error_rate",
+            serde_json::json!({
+                "provider": provider,
+                "requests": window.requests,
+                "failures": window.failures,
+                "error_rate": rate,
+            }),
+        ))
+    }
+
+    /// Post an alert to every webhook in the background
+    fn send(&self, alert: Alert) {
+        warn!("{}: {}", alert.title, alert.description);
+        if self.channels.is_empty() {
+            return;
+        }
+
+        let channels = Arc::clone(&self.channels);
+        tokio::spawn(async move {
+            for channel in channels.iter() {
+                match channel.send(&alert).await {
+                    Ok(()) => debug!("Alert {} sent via {}", alert.id, channel.name()),
+                    Err(e) => warn!("Failed to send alert via {}: {}", channel.name(), e),
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for AlertingService {
+    fn name(&self) -> &str {
+        "alerting"
+    }
+
+    async fn post_call(&self, log: &GenerationLog) {
+        self.record_call(model_provider(&log.model), false);
+    }
+
+    async fn on_failure(&self, call: &CallContext, _error: &str) {
+        self.record_call(model_provider(&call.model), true);
+    }
+}
+
+/// Provider prefix of a `<provider>/<model>` name, or the model itself
+fn model_provider(model: &str) -> &str {
+    model
+        .split_once('/')
+        .map_or(model, |(provider, _)| provider)
+}
+
+fn cooldown_alert(event: &CooldownEvent) -> Alert {
+    alert(
+        AlertSeverity::Warning,
+        format!("Deployment {} cooled down", event.deployment_id),
+        format!(
+            "{} deployment {} of {} ({}) is cooling down for {} seconds: {:?}",
+            event.provider,
+            event.deployment_id,
+            event.model_name,
+            event.model,
+            event.cooldown_secs,
+            event.reason
+        ),
+        "router",
+        serde_json::json!({
+            "deployment_id": event.deployment_id,
+            "provider": event.provider,
+            "model": event.model,
+            "model_name": event.model_name,
+            "reason": format!("{:?}", event.reason),
+            "cooldown_secs": event.cooldown_secs,
+        }),
+    )
+}
+
+fn alert(
+    severity: AlertSeverity,
+    title: String,
+    description: String,
+    source: &str,
+    metadata: serde_json::Value,
+) -> Alert {
+    Alert {
+        id: uuid::Uuid::new_v4().to_string(),
+        severity,
+        title,
+        description,
+        timestamp: chrono::Utc::now(),
+        source: source.to_string(),
+        metadata,
+        resolved: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> AlertingService {
+        AlertingService::new(AlertingConfig {
+            enabled: true,
+            error_rate_min_requests: 4,
+            error_rate_window: 60,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_budget_alert_reports_highest_threshold_crossed() {
+        let service = service();
+
+        assert!(service.budget_alert("Team a", 0.0, 4.0, 10.0).is_none());
+
+        let alert = service.budget_alert("Team a", 4.0, 9.0, 10.0).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Warning);
+        assert_eq!(alert.metadata["threshold"], 0.8);
+
+        assert!(service.budget_alert("Team a", 9.0, 9.5, 10.0).is_none());
+
+        let alert = service.budget_alert("Team a", 9.5, 10.0, 10.0).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+    }
+
+    #[test]
+    fn test_error_rate_alert_once_per_window() {
+        let service = service();
+        let start = Instant::now();
+
+        assert!(service.error_rate_alert("openai", true, start).is_none());
+        assert!(service.error_rate_alert("openai", true, start).is_none());
+        assert!(service.error_rate_alert("openai", false, start).is_none());
+        assert!(service.error_rate_alert("anthropic", true, start).is_none());
+
+        let alert = service.error_rate_alert("openai", true, start).unwrap();
+        assert_eq!(alert.metadata["failures"], 3);
+        assert!(service.error_rate_alert("openai", true, start).is_none());
+
+        let later = start + Duration::from_secs(61);
+        for _ in 0..3 {
+            assert!(service.error_rate_alert("openai", true, later).is_none());
+        }
+        assert!(service.error_rate_alert("openai", true, later).is_some());
+    }
+
+    #[test]
+    fn test_model_provider() {
+        assert_eq!(model_provider("anthropic/claude-3-5-sonnet"), "anthropic");
+        assert_eq!(model_provider("gpt-4o"), "gpt-4o");
+    }
+}
@@ -0,0 +1,363 @@
+//! Datadog LLM Observability callback
+//!
+//! Each call becomes an LLM span submitted to the LLM Observability intake
+//! (`POST /api/intake/llm-obs/v1/trace/spans`); request, token and cost
+//! counts are submitted alongside as metrics (`POST /api/v2/series`).
+
+use super::{CallContext, CallbackHandler, GenerationLog};
+use crate::config::DatadogConfig;
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Dropped spans between queue-full warnings
+const DROP_WARN_INTERVAL: u64 = 1000;
+
+/// Datadog metric type for counts
+const METRIC_TYPE_COUNT: u8 = 1;
+
+/// Ships LLM spans and metrics to Datadog in the background
+///
+/// Spans are queued without waiting; when the queue is full they are dropped,
+/// so a slow or unreachable intake never delays a request.
+#[derive(Debug)]
+pub struct DatadogLogger {
+    config: DatadogConfig,
+    sender: mpsc::Sender<Value>,
+    dropped: AtomicU64,
+}
+
+impl DatadogLogger {
+    /// Create a logger and start its background sender
+    pub fn new(config: DatadogConfig) -> Self {
+        let (sender, receiver) = mpsc::channel(config.queue_size.max(1));
+        let sender_task = IntakeSender {
+            client: reqwest::Client::new(),
+            api_key: config.api_key.clone(),
+            site: config.site.clone(),
+            ml_app: config.ml_app.clone(),
+            tags: global_tags(&config),
+            batch_size: config.batch_size.max(1),
+            flush_interval: Duration::from_secs(config.flush_interval.max(1)),
+        };
+        tokio::spawn(sender_task.run(receiver));
+
+        Self {
+            config,
+            sender,
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Queue a span, dropping it if the queue is full
+    fn enqueue(&self, span: Value) {
+        if self.sender.try_send(span).is_err() {
+            let dropped = self.dropped.fetch_add(1, Ordering::Relaxed);
+            if dropped.is_multiple_of(DROP_WARN_INTERVAL) {
+                warn!(
+                    "Datadog queue is full, {} spans dropped so far",
+                    dropped + 1
+                );
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for DatadogLogger {
+    fn name(&self) -> &str {
+        "datadog"
+    }
+
+    async fn post_call(&self, log: &GenerationLog) {
+        self.enqueue(success_span(log, self.config.log_content));
+    }
+
+    async fn on_failure(&self, call: &CallContext, error: &str) {
+        self.enqueue(failure_span(call, error));
+    }
+}
+
+/// Background task batching spans
+struct IntakeSender {
+    client: reqwest::Client,
+    api_key: String,
+    site: String,
+    ml_app: String,
+    tags: Vec<String>,
+    batch_size: usize,
+    flush_interval: Duration,
+}
+
+impl IntakeSender {
+    async fn run(self, mut receiver: mpsc::Receiver<Value>) {
+        let mut batch = Vec::new();
+        let mut interval = tokio::time::interval(self.flush_interval);
+
+        loop {
+            tokio::select! {
+                span = receiver.recv() => match span {
+                    Some(span) => {
+                        batch.push(span);
+                        if batch.len() >= self.batch_size {
+                            self.send(std::mem::take(&mut batch)).await;
+                        }
+                    }
+                    None => break,
+                },
+                _ = interval.tick() => {
+                    if !batch.is_empty() {
+                        self.send(std::mem::take(&mut batch)).await;
+                    }
+                }
+            }
+        }
+
+        if !batch.is_empty() {
+            self.send(batch).await;
+        }
+    }
+
+    async fn send(&self, spans: Vec<Value>) {
+        let series = metric_series(&spans, &self.tags, chrono::Utc::now().timestamp());
+        let count = spans.len();
+        let payload = json!({
+            "data": {
+                "type": "span",
+                "attributes": {
+                    "ml_app": self.ml_app,
+                    "tags": self.tags,
+                    "spans": spans,
+                },
+            },
+        });
+
+        self.post("api/intake/llm-obs/v1/trace/spans", &payload)
+            .await;
+        self.post("api/v2/series", &json!({ "series": series }))
+            .await;
+        debug!("Sent {} spans to Datadog", count);
+    }
+
+    async fn post(&self, path: &str, body: &Value) {
+        let url = format!("https://api.{}/{}", self.site, path);
+        let result = self
+            .client
+            .post(&url)
+            .header("DD-API-KEY", &self.api_key)
+            .json(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                warn!(
+                    "Datadog submission to {} failed with {}: {}",
+                    path, status, body
+                );
+            }
+            Err(e) => warn!("Datadog submission to {} failed: {}", path, e),
+        }
+    }
+}
+
+/// Tags applied to every span and metric
+fn global_tags(config: &DatadogConfig) -> Vec<String> {
+    let mut tags = vec![format!("ml_app:{}", config.ml_app)];
+    if let Some(env) = &config.env {
+        tags.push(format!("env:{}", env));
+    }
+    tags
+}
+
+/// Random span or trace ID
+fn span_id() -> String {
+    Uuid::new_v4().as_u64_pair().0.to_string()
+}
+
+/// Provider prefix of a `<provider>/<model>` name
+fn model_provider(model: &str) -> Option<&str> {
+    model.split_once('/').map(|(provider, _)| provider)
+}
+
+fn nanos(time: chrono::DateTime<chrono::Utc>) -> i64 {
+    time.timestamp_nanos_opt().unwrap_or_default()
+}
+
+/// LLM span for a successful generation
+fn success_span(log: &GenerationLog, log_content: bool) -> Value {
+    let (input, output) = if log_content {
+        (log.input.clone(), log.output.clone())
+    } else {
+        (Value::Null, Value::Null)
+    };
+    let start_ns = nanos(log.start_time);
+
+    let mut tags = vec![format!("endpoint:{}", log.name)];
+    if let Some(Value::String(team_id)) = log.metadata.get("team_id") {
+        tags.push(format!("team_id:{}", team_id));
+    }
+    if let Some(user_id) = &log.user_id {
+        tags.push(format!("user_id:{}", user_id));
+    }
+
+    json!({
+        "trace_id": span_id(),
+        "span_id": span_id(),
+        "parent_id": "undefined",
+        "name": log.name,
+        "start_ns": start_ns,
+        "duration": (nanos(log.end_time) - start_ns).max(0),
+        "status": "ok",
+        "tags": tags,
+        "meta": {
+            "kind": "llm",
+            "model_name": log.model,
+            "model_provider": model_provider(&log.model),
+            "input": { "value": input.to_string() },
+            "output": { "value": output.to_string() },
+            "metadata": { "request_id": log.request_id, "cost": log.cost },
+        },
+        "metrics": {
+            "input_tokens": log.prompt_tokens,
+            "output_tokens": log.completion_tokens,
+            "total_tokens": log.prompt_tokens + log.completion_tokens,
+            "cost": log.cost,
+        },
+    })
+}
+
+/// LLM span for a failed call
+fn failure_span(call: &CallContext, error: &str) -> Value {
+    let start_ns = nanos(call.start_time);
+
+    let mut tags = vec![format!("endpoint:{}", call.endpoint)];
+    if let Some(team_id) = &call.team_id {
+        tags.push(format!("team_id:{}", team_id));
+    }
+    if let Some(user_id) = &call.user_id {
+        tags.push(format!("user_id:{}", user_id));
+    }
+
+    json!({
+        "trace_id": span_id(),
+        "span_id": span_id(),
+        "parent_id": "undefined",
+        "name": call.endpoint,
+        "start_ns": start_ns,
+        "duration": (nanos(chrono::Utc::now()) - start_ns).max(0),
+        "status": "error",
+        "tags": tags,
+        "meta": {
+            "kind": "llm",
+            "model_name": call.model,
+            "model_provider": model_provider(&call.model),
+            "error": { "message": error },
+            "metadata": { "request_id": call.request_id },
+        },
+        "metrics": {},
+    })
+}
+
+/// Request, token and cost counts of a batch, per model and status
+fn metric_series(spans: &[Value], tags: &[String], timestamp: i64) -> Vec<Value> {
+    let mut totals: BTreeMap<(&str, &str), [f64; 4]> = BTreeMap::new();
+    for span in spans {
+        let model = span["meta"]["model_name"].as_str().unwrap_or("unknown");
+        let status = span["status"].as_str().unwrap_or("ok");
+        let metrics = &span["metrics"];
+        let total = totals.entry((model, status)).or_default();
+        total[0] += 1.0;
+        total[1] += metrics["input_tokens"].as_f64().unwrap_or(0.0);
+        total[2] += metrics["output_tokens"].as_f64().unwrap_or(0.0);
+        total[3] += metrics["cost"].as_f64().unwrap_or(0.0);
+    }
+
+    let names = [
+        "litellm.requests",
+        "litellm.tokens.input",
+        "litellm.tokens.output",
+        "litellm.cost",
+    ];
+    totals
+        .into_iter()
+        .flat_map(|((model, status), values)| {
+            let mut series_tags = tags.to_vec();
+            series_tags.push(format!("model:{}", model));
+            series_tags.push(format!("status:{}", status));
+            names.into_iter().zip(values).map(move |(name, value)| {
+                json!({
+                    "metric": name,
+                    "type": METRIC_TYPE_COUNT,
+                    "points": [{ "timestamp": timestamp, "value": value }],
+                    "tags": series_tags,
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::RequestContext;
+
+    fn generation_log() -> GenerationLog {
+        let context = RequestContext::new();
+        GenerationLog::new(&context, "messages", "anthropic/claude-3-5-sonnet")
+            .with_content(json!([{"role": "user", "content": "Hi"}]), json!("Hello"))
+            .with_usage(10, 5, 0.001)
+    }
+
+    #[test]
+    fn test_success_span() {
+        let span = success_span(&generation_log(), true);
+        assert_eq!(span["status"], "ok");
+        assert_eq!(span["meta"]["kind"], "llm");
+        assert_eq!(span["meta"]["model_provider"], "anthropic");
+        assert_eq!(span["meta"]["output"]["value"], "\"Hello\"");
+        assert_eq!(span["metrics"]["total_tokens"], 15);
+        assert!(span["duration"].as_i64().unwrap() >= 0);
+
+        let span = success_span(&generation_log(), false);
+        assert_eq!(span["meta"]["input"]["value"], "null");
+    }
+
+    #[test]
+    fn test_metric_series() {
+        let context = RequestContext::new();
+        let call = CallContext::new(&context, "messages", "anthropic/claude-3-5-sonnet");
+        let spans = vec![
+            success_span(&generation_log(), true),
+            success_span(&generation_log(), true),
+            failure_span(&call, "rate limited"),
+        ];
+
+        let series = metric_series(&spans, &["env:test".to_string()], 0);
+        assert_eq!(series.len(), 8);
+        let ok_requests = series
+            .iter()
+            .find(|s| {
+                s["metric"] == "litellm.requests"
+                    && s["tags"].as_array().unwrap().contains(&json!("status:ok"))
+            })
+            .unwrap();
+        assert_eq!(ok_requests["points"][0]["value"], 2.0);
+        let ok_input = series
+            .iter()
+            .find(|s| {
+                s["metric"] == "litellm.tokens.input"
+                    && s["tags"].as_array().unwrap().contains(&json!("status:ok"))
+            })
+            .unwrap();
+        assert_eq!(ok_input["points"][0]["value"], 20.0);
+    }
+}
@@ -0,0 +1,83 @@
+//! Callback handler trait
+
+use super::GenerationLog;
+use crate::core::completion::{CompletionChunk, CompletionOptions, Message};
+use crate::core::models::RequestContext;
+use crate::utils::error::Result;
+use async_trait::async_trait;
+
+/// Hooks run around each model call
+///
+/// Every method has a no-op default, so handlers implement only the hooks
+/// they need. Register handlers with
+/// [`CallbackManager::register`](super::CallbackManager::register) or
+/// [`ServerBuilder::with_callback`](crate::server::builder::ServerBuilder::with_callback).
+///
+/// `pre_call` and `on_stream_chunk` run on the request path, in registration
+/// order; `post_call` and `on_failure` run in the background.
+#[async_trait]
+pub trait CallbackHandler: Send + Sync {
+    /// Name used in logs
+    fn name(&self) -> &str;
+
+    /// Inspect or rewrite a chat call before it is sent
+    ///
+    /// Returning an error rejects the request with that error's status.
+    async fn pre_call(&self, _call: &CallContext, _request: &mut CallRequest) -> Result<()> {
+        Ok(())
+    }
+
+    /// A call finished successfully
+    async fn post_call(&self, _log: &GenerationLog) {}
+
+    /// A streamed call produced a chunk
+    async fn on_stream_chunk(&self, _call: &CallContext, _chunk: &CompletionChunk) {}
+
+    /// A call failed
+    async fn on_failure(&self, _call: &CallContext, _error: &str) {}
+}
+
+/// Who made a call and where
+#[derive(Debug, Clone)]
+pub struct CallContext {
+    /// Gateway request ID
+    pub request_id: String,
+    /// Endpoint serving the call, e.g. "chat.completions"
+    pub endpoint: String,
+    /// Requested model
+    pub model: String,
+    /// Authenticated user
+    pub user_id: Option<String>,
+    /// Team of the caller
+    pub team_id: Option<String>,
+    /// Gateway API key used
+    pub api_key_id: Option<String>,
+    /// When the request started
+    pub start_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl CallContext {
+    /// Context of a call made for a request
+    pub fn new(context: &RequestContext, endpoint: impl Into<String>, model: &str) -> Self {
+        Self {
+            request_id: context.request_id.clone(),
+            endpoint: endpoint.into(),
+            model: model.to_string(),
+            user_id: context.user_id.map(|id| id.to_string()),
+            team_id: context.team_id.map(|id| id.to_string()),
+            api_key_id: context.api_key_id.map(|id| id.to_string()),
+            start_time: context.timestamp,
+        }
+    }
+}
+
+/// Chat call that `pre_call` hooks may rewrite
+#[derive(Debug, Clone)]
+pub struct CallRequest {
+    /// Model to call
+    pub model: String,
+    /// Conversation
+    pub messages: Vec<Message>,
+    /// Call options
+    pub options: CompletionOptions,
+}
@@ -0,0 +1,252 @@
+//! Langfuse logging callback
+//!
+//! Each generation becomes a Langfuse trace holding one generation
+//! observation, sent through the batch ingestion API
+//! (`POST /api/public/ingestion`).
+
+use super::{CallbackHandler, GenerationLog};
+use crate::config::{LangfuseConfig, LangfuseProject};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Events buffered before new ones are dropped
+const QUEUE_SIZE: usize = 10_000;
+
+/// Ships generation logs to Langfuse in the background
+#[derive(Debug)]
+pub struct LangfuseLogger {
+    config: LangfuseConfig,
+    sender: mpsc::Sender<(LangfuseProject, Vec<Value>)>,
+}
+
+impl LangfuseLogger {
+    /// Create a logger and start its background sender
+    pub fn new(config: LangfuseConfig) -> Self {
+        let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+        let sender_task = IngestionSender {
+            client: reqwest::Client::new(),
+            host: config.host.clone(),
+            batch_size: config.batch_size.max(1),
+            flush_interval: Duration::from_secs(config.flush_interval.max(1)),
+        };
+        tokio::spawn(sender_task.run(receiver));
+
+        Self { config, sender }
+    }
+
+    /// Queue a generation for its API key's project, or the global project
+    pub fn log(&self, log: &GenerationLog) {
+        let Some(project) = self.project_for(log.api_key_id.as_deref()) else {
+            return;
+        };
+
+        let events = ingestion_events(log, self.config.log_content);
+        if self.sender.try_send((project, events)).is_err() {
+            warn!("Langfuse queue is full, dropping trace {}", log.request_id);
+        }
+    }
+
+    /// Project a request's events are sent to
+    fn project_for(&self, api_key_id: Option<&str>) -> Option<LangfuseProject> {
+        api_key_id
+            .and_then(|id| self.config.api_keys.get(id).cloned())
+            .or_else(|| self.config.global_project())
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for LangfuseLogger {
+    fn name(&self) -> &str {
+        "langfuse"
+    }
+
+    async fn post_call(&self, log: &GenerationLog) {
+        self.log(log);
+    }
+}
+
+/// Background task batching events per project
+struct IngestionSender {
+    client: reqwest::Client,
+    host: String,
+    batch_size: usize,
+    flush_interval: Duration,
+}
+
+impl IngestionSender {
+    async fn run(self, mut receiver: mpsc::Receiver<(LangfuseProject, Vec<Value>)>) {
+        let mut batches: HashMap<LangfuseProject, Vec<Value>> = HashMap::new();
+        let mut interval = tokio::time::interval(self.flush_interval);
+
+        loop {
+            tokio::select! {
+                message = receiver.recv() => match message {
+                    Some((project, events)) => {
+                        let batch = batches.entry(project.clone()).or_default();
+                        batch.extend(events);
+                        if batch.len() >= self.batch_size {
+                            let batch = std::mem::take(batch);
+                            self.send(&project, batch).await;
+                        }
+                    }
+                    None => break,
+                },
+                _ = interval.tick() => {
+                    for (project, batch) in batches.iter_mut() {
+                        if !batch.is_empty() {
+                            let batch = std::mem::take(batch);
+                            self.send(project, batch).await;
+                        }
+                    }
+                }
+            }
+        }
+
+        for (project, batch) in batches {
+            if !batch.is_empty() {
+                self.send(&project, batch).await;
+            }
+        }
+    }
+
+    async fn send(&self, project: &LangfuseProject, batch: Vec<Value>) {
+        let host = project.host.as_deref().unwrap_or(&self.host);
+        let url = format!("{}/api/public/ingestion", host.trim_end_matches('/'));
+        let count = batch.len();
+
+        let result = self
+            .client
+            .post(&url)
+            .basic_auth(&project.public_key, Some(&project.secret_key))
+            .json(&json!({ "batch": batch }))
+            .send()
+            .await;
+
+        // Ingestion answers 207 with per-event errors on partial failure
+        match result {
+            Ok(response) if response.status().is_success() => {
+                debug!("Sent {} events to Langfuse", count);
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                warn!("Langfuse ingestion failed with {}: {}", status, body);
+            }
+            Err(e) => warn!("Langfuse ingestion request failed: {}", e),
+        }
+    }
+}
+
+/// Trace and generation events for one generation
+fn ingestion_events(log: &GenerationLog, log_content: bool) -> Vec<Value> {
+    let (input, output) = if log_content {
+        (log.input.clone(), log.output.clone())
+    } else {
+        (Value::Null, Value::Null)
+    };
+    let metadata = json!(log.metadata);
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let trace = json!({
+        "id": Uuid::new_v4().to_string(),
+        "timestamp": now,
+        "type": "trace-create",
+        "body": {
+            "id": log.request_id,
+            "timestamp": log.start_time.to_rfc3339(),
+            "name": log.name,
+            "userId": log.user_id,
+            "input": input,
+            "output": output,
+            "metadata": metadata,
+        },
+    });
+
+    let generation = json!({
+        "id": Uuid::new_v4().to_string(),
+        "timestamp": now,
+        "type": "generation-create",
+        "body": {
+            "id": Uuid::new_v4().to_string(),
+            "traceId": log.request_id,
+            "name": log.name,
+            "startTime": log.start_time.to_rfc3339(),
+            "endTime": log.end_time.to_rfc3339(),
+            "model": log.model,
+            "input": input,
+            "output": output,
+            "usage": {
+                "input": log.prompt_tokens,
+                "output": log.completion_tokens,
+                "total": log.prompt_tokens + log.completion_tokens,
+                "unit": "TOKENS",
+                "totalCost": log.cost,
+            },
+            "metadata": metadata,
+        },
+    });
+
+    vec![trace, generation]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::RequestContext;
+
+    fn generation_log() -> GenerationLog {
+        let mut context = RequestContext::new();
+        context.request_id = "req-1".to_string();
+        GenerationLog::new(&context, "messages", "claude-3-5-sonnet")
+            .with_content(json!([{"role": "user", "content": "Hi"}]), json!("Hello"))
+            .with_usage(10, 5, 0.001)
+    }
+
+    #[test]
+    fn test_ingestion_events() {
+        let events = ingestion_events(&generation_log(), true);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["type"], "trace-create");
+        assert_eq!(events[0]["body"]["id"], "req-1");
+        assert_eq!(events[1]["type"], "generation-create");
+        assert_eq!(events[1]["body"]["traceId"], "req-1");
+        assert_eq!(events[1]["body"]["model"], "claude-3-5-sonnet");
+        assert_eq!(events[1]["body"]["usage"]["total"], 15);
+        assert_eq!(events[1]["body"]["output"], "Hello");
+    }
+
+    #[test]
+    fn test_ingestion_events_without_content() {
+        let events = ingestion_events(&generation_log(), false);
+        assert!(events[0]["body"]["input"].is_null());
+        assert!(events[1]["body"]["output"].is_null());
+        assert_eq!(events[1]["body"]["usage"]["input"], 10);
+    }
+
+    #[tokio::test]
+    async fn test_project_for_api_key() {
+        let project = LangfuseProject {
+            public_key: "pk-lf-key".to_string(),
+            secret_key: "sk-lf-key".to_string(),
+            host: None,
+        };
+        let logger = LangfuseLogger::new(LangfuseConfig {
+            public_key: Some("pk-lf-global".to_string()),
+            secret_key: Some("sk-lf-global".to_string()),
+            api_keys: HashMap::from([("key-1".to_string(), project.clone())]),
+            ..Default::default()
+        });
+
+        assert_eq!(logger.project_for(Some("key-1")), Some(project));
+        assert_eq!(
+            logger.project_for(Some("key-2")).unwrap().public_key,
+            "pk-lf-global"
+        );
+        assert_eq!(logger.project_for(None).unwrap().public_key, "pk-lf-global");
+    }
+}
@@ -0,0 +1,254 @@
+//! Logging callbacks
+//!
+//! Handlers implementing [`CallbackHandler`] hook into each model call to
+//! log, rewrite or reject it. Routes report calls through [`CallbackManager`];
+//! success and failure hooks run in the background, so logging never delays
+//! a response. Built-in integrations such as Langfuse and Datadog are handlers
+//! too.
+
+pub mod datadog;
+pub mod handler;
+pub mod langfuse;
+
+pub use datadog::DatadogLogger;
+pub use handler::{CallContext, CallRequest, CallbackHandler};
+pub use langfuse::LangfuseLogger;
+
+use crate::config::CallbacksConfig;
+use crate::core::completion::CompletionChunk;
+use crate::core::models::RequestContext;
+use crate::utils::error::{GatewayError, Result};
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// A finished generation, as reported to logging callbacks
+#[derive(Debug, Clone)]
+pub struct GenerationLog {
+    /// Gateway request ID, used as the trace ID
+    pub request_id: String,
+    /// Endpoint that served the request, e.g. "messages"
+    pub name: String,
+    /// Model the request was made for
+    pub model: String,
+    /// Prompt as sent by the caller
+    pub input: Value,
+    /// Completion returned to the caller
+    pub output: Value,
+    /// Prompt tokens
+    pub prompt_tokens: u32,
+    /// Completion tokens
+    pub completion_tokens: u32,
+    /// Cost in USD
+    pub cost: f64,
+    /// When the request started
+    pub start_time: chrono::DateTime<chrono::Utc>,
+    /// When the response was complete
+    pub end_time: chrono::DateTime<chrono::Utc>,
+    /// Authenticated user
+    pub user_id: Option<String>,
+    /// Gateway API key used
+    pub api_key_id: Option<String>,
+    /// Additional metadata
+    pub metadata: HashMap<String, Value>,
+}
+
+impl GenerationLog {
+    /// Start a log for a request, ending now
+    pub fn new(
+        context: &RequestContext,
+        name: impl Into<String>,
+        model: impl Into<String>,
+    ) -> Self {
+        let mut metadata = HashMap::new();
+        if let Some(team_id) = context.team_id {
+            metadata.insert("team_id".to_string(), team_id.to_string().into());
+        }
+        if let Some(user_agent) = &context.user_agent {
+            metadata.insert("user_agent".to_string(), user_agent.clone().into());
+        }
+
+        Self {
+            request_id: context.request_id.clone(),
+            name: name.into(),
+            model: model.into(),
+            input: Value::Null,
+            output: Value::Null,
+            prompt_tokens: 0,
+            completion_tokens: 0,
+            cost: 0.0,
+            start_time: context.timestamp,
+            end_time: chrono::Utc::now(),
+            user_id: context.user_id.map(|id| id.to_string()),
+            api_key_id: context.api_key_id.map(|id| id.to_string()),
+            metadata,
+        }
+    }
+
+    /// Set the prompt and completion
+    pub fn with_content(mut self, input: Value, output: Value) -> Self {
+        self.input = input;
+        self.output = output;
+        self
+    }
+
+    /// Set token usage and cost
+    pub fn with_usage(mut self, prompt_tokens: u32, completion_tokens: u32, cost: f64) -> Self {
+        self.prompt_tokens = prompt_tokens;
+        self.completion_tokens = completion_tokens;
+        self.cost = cost;
+        self
+    }
+}
+
+/// Runs registered callback handlers
+#[derive(Default)]
+pub struct CallbackManager {
+    handlers: RwLock<Vec<Arc<dyn CallbackHandler>>>,
+}
+
+impl CallbackManager {
+    /// Create callbacks from configuration
+    ///
+    /// Must be called from within a Tokio runtime when any callback is
+    /// configured, since each starts a background sender.
+    pub fn new(config: &CallbacksConfig) -> Self {
+        let manager = Self::default();
+        if let Some(langfuse) = &config.langfuse {
+            manager.register(Arc::new(LangfuseLogger::new(langfuse.clone())));
+        }
+        if let Some(datadog) = &config.datadog {
+            manager.register(Arc::new(DatadogLogger::new(datadog.clone())));
+        }
+        manager
+    }
+
+    /// Register a handler, run after those already registered
+    pub fn register(&self, handler: Arc<dyn CallbackHandler>) {
+        debug!("Registered callback handler: {}", handler.name());
+        self.handlers.write().push(handler);
+    }
+
+    /// Whether any handler is registered
+    pub fn is_enabled(&self) -> bool {
+        !self.handlers.read().is_empty()
+    }
+
+    /// Handlers, cloned so no lock is held across hooks
+    fn handlers(&self) -> Vec<Arc<dyn CallbackHandler>> {
+        self.handlers.read().clone()
+    }
+
+    /// Run `pre_call` hooks, stopping at the first rejection
+    pub async fn pre_call(&self, call: &CallContext, request: &mut CallRequest) -> Result<()> {
+        for handler in self.handlers() {
+            if let Err(e) = handler.pre_call(call, request).await {
+                info!(
+                    "Callback {} rejected request {}: {}",
+                    handler.name(),
+                    call.request_id,
+                    e
+                );
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `on_stream_chunk` hooks
+    pub async fn on_stream_chunk(&self, call: &CallContext, chunk: &CompletionChunk) {
+        for handler in self.handlers() {
+            handler.on_stream_chunk(call, chunk).await;
+        }
+    }
+
+    /// Report a successful generation
+    pub fn log_success(&self, log: GenerationLog) {
+        let log = Arc::new(log);
+        for handler in self.handlers() {
+            let log = Arc::clone(&log);
+            tokio::spawn(async move { handler.post_call(&log).await });
+        }
+    }
+
+    /// Report a failed call
+    pub fn log_failure(&self, call: &CallContext, error: &GatewayError) {
+        let call = Arc::new(call.clone());
+        let error: Arc<str> = error.to_string().into();
+        for handler in self.handlers() {
+            let (call, error) = (Arc::clone(&call), Arc::clone(&error));
+            tokio::spawn(async move { handler.on_failure(&call, &error).await });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Rewrites the model and rejects empty conversations
+    struct Rewriter {
+        post_calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl CallbackHandler for Rewriter {
+        fn name(&self) -> &str {
+            "rewriter"
+        }
+
+        async fn pre_call(&self, _call: &CallContext, request: &mut CallRequest) -> Result<()> {
+            if request.messages.is_empty() {
+                return Err(GatewayError::validation("empty conversation"));
+            }
+            request.model = "gpt-4o-mini".to_string();
+            Ok(())
+        }
+
+        async fn post_call(&self, _log: &GenerationLog) {
+            self.post_calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn call_request(messages: Vec<crate::core::completion::Message>) -> CallRequest {
+        CallRequest {
+            model: "gpt-4o".to_string(),
+            messages,
+            options: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_callback_manager_hooks() {
+        let post_calls = Arc::new(AtomicUsize::new(0));
+        let manager = CallbackManager::default();
+        assert!(!manager.is_enabled());
+        manager.register(Arc::new(Rewriter {
+            post_calls: Arc::clone(&post_calls),
+        }));
+        assert!(manager.is_enabled());
+
+        let context = RequestContext::new();
+        let call = CallContext::new(&context, "chat.completions", "gpt-4o");
+
+        let mut request = call_request(vec![crate::core::completion::user_message("Hi")]);
+        manager.pre_call(&call, &mut request).await.unwrap();
+        assert_eq!(request.model, "gpt-4o-mini");
+
+        let mut request = call_request(vec![]);
+        assert!(manager.pre_call(&call, &mut request).await.is_err());
+
+        manager.log_success(GenerationLog::new(&context, "chat.completions", "gpt-4o"));
+        for _ in 0..100 {
+            if post_calls.load(Ordering::SeqCst) == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(post_calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,7 +2,12 @@
 //!
 //! This module contains business logic and service implementations
 
+pub mod alerting;
+pub mod callbacks;
 pub mod pricing;
+pub mod request_logs;
+pub mod retention;
+pub mod spend;
 
 pub use pricing::{
     CostRange, CostResult, CostType, ModelInfo, PricingEventType, PricingService,
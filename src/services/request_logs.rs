@@ -0,0 +1,113 @@
+//! Request audit logging
+//!
+//! Entries are queued by the request log middleware and written to the
+//! `request_logs` table in batches by a background task, so the database
+//! never sits on the request path. Entries older than the retention period
+//! are deleted by the retention service.
+
+use crate::config::RequestLogConfig;
+use crate::core::models::metrics::RequestLogEntry;
+use crate::core::observability::{RedactionConfig, redact_json_value, scrub_secrets};
+use crate::storage::database::Database;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Entries buffered before new ones are dropped
+const QUEUE_SIZE: usize = 10_000;
+
+/// Maximum seconds an entry waits before it is written
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Writes request log entries to the database in the background
+#[derive(Debug)]
+pub struct RequestLogger {
+    config: RequestLogConfig,
+    redaction: RedactionConfig,
+    sender: mpsc::Sender<RequestLogEntry>,
+}
+
+impl RequestLogger {
+    /// Create a logger and start its writer task
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn new(config: RequestLogConfig, database: Arc<Database>) -> Self {
+        let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+        tokio::spawn(write_entries(database, receiver, config.batch_size.max(1)));
+
+        Self {
+            config,
+            redaction: RedactionConfig::default(),
+            sender,
+        }
+    }
+
+    /// Whether request and response payloads are stored
+    pub fn log_payloads(&self) -> bool {
+        self.config.log_payloads
+    }
+
+    /// Queue an entry, redacting its payloads and error
+    pub fn log(&self, mut entry: RequestLogEntry) {
+        if let Some(error) = &mut entry.error
+            && let Cow::Owned(scrubbed) = scrub_secrets(error)
+        {
+            *error = scrubbed;
+        }
+        if self.config.log_payloads {
+            for payload in [&mut entry.request_payload, &mut entry.response_payload]
+                .into_iter()
+                .flatten()
+            {
+                redact_json_value(payload, &self.redaction);
+            }
+        } else {
+            entry.request_payload = None;
+            entry.response_payload = None;
+        }
+
+        if self.sender.try_send(entry).is_err() {
+            warn!("Request log queue is full, dropping entry");
+        }
+    }
+}
+
+/// Write queued entries in batches
+async fn write_entries(
+    database: Arc<Database>,
+    mut receiver: mpsc::Receiver<RequestLogEntry>,
+    batch_size: usize,
+) {
+    let mut batch = Vec::with_capacity(batch_size);
+    let mut interval = tokio::time::interval(FLUSH_INTERVAL);
+
+    loop {
+        tokio::select! {
+            entry = receiver.recv() => match entry {
+                Some(entry) => {
+                    batch.push(entry);
+                    if batch.len() >= batch_size {
+                        flush(&database, &mut batch).await;
+                    }
+                }
+                None => break,
+            },
+            _ = interval.tick() => flush(&database, &mut batch).await,
+        }
+    }
+
+    flush(&database, &mut batch).await;
+}
+
+async fn flush(database: &Database, batch: &mut Vec<RequestLogEntry>) {
+    if batch.is_empty() {
+        return;
+    }
+    match database.insert_request_logs(batch).await {
+        Ok(()) => debug!("Wrote {} request log entries", batch.len()),
+        Err(e) => warn!("Failed to write {} request log entries: {}", batch.len(), e),
+    }
+    batch.clear();
+}
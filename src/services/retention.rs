@@ -0,0 +1,287 @@
+//! Data retention
+//!
+//! A background task deletes request log entries and daily spend rows older
+//! than their retention periods and purges expired cache entries. Rows are
+//! deleted in batches, so no single statement locks a large part of a table.
+//! Runs can also be triggered from `POST /admin/retention/run`.
+
+use crate::config::StorageConfig;
+use crate::core::semantic_cache::SemanticCache;
+use crate::storage::cache::CacheBackend;
+use crate::storage::database::Database;
+use crate::utils::error::Result;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// Rows and entries removed, by kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RetentionReport {
+    /// Request log entries deleted
+    pub request_logs: u64,
+    /// Daily spend rows deleted
+    pub spend: u64,
+    /// Expired cache entries purged
+    pub cache_entries: u64,
+}
+
+/// Removes stored data past its retention period
+pub struct RetentionService {
+    database: Arc<Database>,
+    cache: Arc<dyn CacheBackend>,
+    semantic_cache: Option<Arc<SemanticCache>>,
+    /// Days request log entries are kept; 0 keeps them forever
+    request_log_days: u32,
+    /// Days daily spend rows are kept; 0 keeps them forever
+    spend_days: u32,
+    batch_size: u64,
+    purge_expired_cache: bool,
+    interval: u64,
+    /// Held during a run, so manual and scheduled runs never overlap
+    running: Mutex<()>,
+    /// Totals removed since startup
+    totals: [AtomicU64; 3],
+}
+
+impl RetentionService {
+    /// Create a service pruning the given database and caches
+    ///
+    /// Request logs and spend are only pruned when they are enabled.
+    pub fn new(
+        config: &StorageConfig,
+        database: Arc<Database>,
+        cache: Arc<dyn CacheBackend>,
+        semantic_cache: Option<Arc<SemanticCache>>,
+    ) -> Self {
+        let request_logs = &config.request_logs;
+        let spend = &config.spend;
+        Self {
+            database,
+            cache,
+            semantic_cache,
+            request_log_days: if request_logs.enabled {
+                request_logs.retention_days
+            } else {
+                0
+            },
+            spend_days: if spend.enabled {
+                spend.retention_days
+            } else {
+                0
+            },
+            batch_size: config.retention.batch_size.max(1),
+            purge_expired_cache: config.retention.purge_expired_cache,
+            interval: config.retention.interval,
+            running: Mutex::new(()),
+            totals: Default::default(),
+        }
+    }
+
+    /// Start the task running retention on the configured interval, if any
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn start(self: &Arc<Self>) {
+        if self.interval == 0 {
+            return;
+        }
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(service.interval));
+            loop {
+                interval.tick().await;
+                service.run().await;
+            }
+        });
+    }
+
+    /// Remove everything past retention now
+    ///
+    /// Failures are logged and leave the remaining rows to the next run.
+    pub async fn run(&self) -> RetentionReport {
+        let _running = self.running.lock().await;
+        let mut report = RetentionReport::default();
+
+        if self.request_log_days > 0 {
+            let cutoff = chrono::Utc::now() - chrono::Duration::days(self.request_log_days.into());
+            report.request_logs =
+                delete_in_batches("request log entries", self.batch_size, |limit| {
+                    self.database.delete_request_logs_before(cutoff, limit)
+                })
+                .await;
+        }
+
+        if self.spend_days > 0 {
+            let cutoff =
+                chrono::Utc::now().date_naive() - chrono::Duration::days(self.spend_days.into());
+            report.spend = delete_in_batches("spend rows", self.batch_size, |limit| {
+                self.database.delete_spend_before(cutoff, limit)
+            })
+            .await;
+        }
+
+        if self.purge_expired_cache {
+            match self.cache.purge_expired().await {
+                Ok(purged) => report.cache_entries += purged,
+                Err(e) => warn!("Failed to purge expired cache entries: {}", e),
+            }
+            if let Some(semantic_cache) = &self.semantic_cache {
+                match semantic_cache.purge_expired().await {
+                    Ok(purged) => report.cache_entries += purged,
+                    Err(e) => warn!("Failed to purge expired semantic cache entries: {}", e),
+                }
+            }
+        }
+
+        let removed = [report.request_logs, report.spend, report.cache_entries];
+        for (total, removed) in self.totals.iter().zip(removed) {
+            total.fetch_add(removed, Relaxed);
+        }
+        if report != RetentionReport::default() {
+            info!(
+                "Retention removed {} request log entries, {} spend rows and {} cache entries",
+                report.request_logs, report.spend, report.cache_entries
+            );
+        }
+        report
+    }
+
+    /// Totals removed since startup
+    pub fn totals(&self) -> RetentionReport {
+        let [request_logs, spend, cache_entries] = &self.totals;
+        RetentionReport {
+            request_logs: request_logs.load(Relaxed),
+            spend: spend.load(Relaxed),
+            cache_entries: cache_entries.load(Relaxed),
+        }
+    }
+}
+
+/// Call `delete` with `batch_size` until it deletes fewer rows, returning the
+/// rows deleted
+async fn delete_in_batches<F, Fut>(what: &str, batch_size: u64, mut delete: F) -> u64
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<u64>>,
+{
+    let mut deleted = 0;
+    loop {
+        match delete(batch_size).await {
+            Ok(batch) => {
+                deleted += batch;
+                if batch < batch_size {
+                    break;
+                }
+            }
+            Err(e) => {
+                warn!("Failed to delete {} past retention: {}", what, e);
+                break;
+            }
+        }
+    }
+    deleted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DatabaseConfig;
+    use crate::core::models::metrics::{RequestLogEntry, SpendRecord};
+    use crate::storage::cache::MemoryCache;
+
+    async fn database() -> Arc<Database> {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+        let database = Database::new(&config).await.unwrap();
+        database.migrate().await.unwrap();
+        Arc::new(database)
+    }
+
+    fn log_entry(days_ago: i64) -> RequestLogEntry {
+        RequestLogEntry {
+            request_id: uuid::Uuid::new_v4().to_string(),
+            key_alias: None,
+            method: "POST".to_string(),
+            path: "/v1/chat/completions".to_string(),
+            model: Some("gpt-4o".to_string()),
+            provider: None,
+            prompt_tokens: 10,
+            completion_tokens: 5,
+            cost: 0.001,
+            latency_ms: 100,
+            status_code: 200,
+            error: None,
+            request_payload: None,
+            response_payload: None,
+            created_at: chrono::Utc::now() - chrono::Duration::days(days_ago),
+        }
+    }
+
+    fn spend_record(days_ago: i64) -> SpendRecord {
+        SpendRecord {
+            date: chrono::Utc::now().date_naive() - chrono::Duration::days(days_ago),
+            api_key: None,
+            key_hash: None,
+            team_id: None,
+            user_id: None,
+            model: "gpt-4o".to_string(),
+            spend: 0.5,
+            prompt_tokens: 100,
+            completion_tokens: 50,
+            requests: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_removes_data_past_retention_in_batches() {
+        let database = database().await;
+        let logs: Vec<_> = [40, 35, 31, 1].into_iter().map(log_entry).collect();
+        database.insert_request_logs(&logs).await.unwrap();
+        let spend: Vec<_> = [100, 95, 0].into_iter().map(spend_record).collect();
+        database.upsert_spend(&spend).await.unwrap();
+
+        let mut config = StorageConfig::default();
+        config.request_logs.enabled = true;
+        config.spend.enabled = true;
+        config.spend.retention_days = 90;
+        config.retention.batch_size = 2;
+        let cache = Arc::new(MemoryCache::new());
+        let service = RetentionService::new(&config, Arc::clone(&database), cache, None);
+
+        let report = service.run().await;
+        assert_eq!(report.request_logs, 3);
+        assert_eq!(report.spend, 2);
+        assert_eq!(database.list_request_logs(10).await.unwrap().len(), 1);
+
+        assert_eq!(service.run().await, RetentionReport::default());
+        assert_eq!(service.totals(), report);
+    }
+
+    #[tokio::test]
+    async fn test_run_keeps_data_of_disabled_features() {
+        let database = database().await;
+        database
+            .insert_request_logs(&[log_entry(40)])
+            .await
+            .unwrap();
+
+        let config = StorageConfig::default();
+        let service = RetentionService::new(
+            &config,
+            Arc::clone(&database),
+            Arc::new(MemoryCache::new()),
+            None,
+        );
+
+        assert_eq!(service.run().await.request_logs, 0);
+        assert_eq!(database.list_request_logs(10).await.unwrap().len(), 1);
+    }
+}
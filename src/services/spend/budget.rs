@@ -0,0 +1,463 @@
+//! Budget enforcement
+//!
+//! Each budget keeps the spend of its current window in memory. Spend is
+//! loaded from the `daily_spend` table at startup, grows as the tracker prices
+//! calls and drops to zero when the next window starts.
+
+use super::tracker::SpendEvent;
+use crate::config::{BudgetConfig, BudgetWindow};
+use crate::core::models::metrics::SpendFilter;
+use crate::services::alerting::AlertingService;
+use crate::services::callbacks::{CallContext, CallRequest, CallbackHandler};
+use crate::storage::database::Database;
+use crate::utils::auth::crypto::keys::{extract_api_key_prefix, hash_api_key};
+use crate::utils::error::{GatewayError, Result};
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use parking_lot::Mutex;
+use std::fmt;
+use std::sync::Arc;
+use tracing::info;
+
+/// What a budget limits
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BudgetTarget {
+    /// API key, by the SHA-256 hash of the key; the masked key is only shown
+    ApiKey { hash: String, alias: String },
+    /// Team ID
+    Team(String),
+    /// Provider name, the prefix of `<provider>/<model>` names
+    Provider(String),
+}
+
+impl fmt::Display for BudgetTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetTarget::ApiKey { alias, .. } => write!(f, "API key {}", alias),
+            BudgetTarget::Team(team) => write!(f, "Team {}", team),
+            BudgetTarget::Provider(provider) => write!(f, "Provider {}", provider),
+        }
+    }
+}
+
+/// Spend in the window starting on `start`
+#[derive(Debug)]
+struct WindowSpend {
+    start: NaiveDate,
+    spent: f64,
+}
+
+#[derive(Debug)]
+struct Budget {
+    target: BudgetTarget,
+    max_budget: f64,
+    window: BudgetWindow,
+    state: Mutex<WindowSpend>,
+}
+
+impl Budget {
+    /// Add spend incurred on `date`, ignoring spend outside the current window
+    ///
+    /// Returns the window's spend before and after.
+    fn add(&self, date: NaiveDate, today: NaiveDate, cost: f64) -> (f64, f64) {
+        let mut state = self.state.lock();
+        self.roll(&mut state, today);
+        let before = state.spent;
+        if self.window.start(date) == state.start {
+            state.spent += cost;
+        }
+        (before, state.spent)
+    }
+
+    /// Error if the current window's spend has reached the limit
+    fn check(&self, today: NaiveDate) -> Result<()> {
+        let mut state = self.state.lock();
+        self.roll(&mut state, today);
+        if state.spent < self.max_budget {
+            return Ok(());
+        }
+
+        Err(GatewayError::BudgetExceeded(format!(
+            "{} has spent ${:.2} of its ${:.2} {} budget; resets on {}",
+            self.target,
+            state.spent,
+            self.max_budget,
+            window_label(self.window),
+            self.window.next_start(today)
+        )))
+    }
+
+    /// Start a new window if the current one has ended
+    fn roll(&self, state: &mut WindowSpend, today: NaiveDate) {
+        let start = self.window.start(today);
+        if start > state.start {
+            if state.spent > 0.0 {
+                info!("{} {} budget reset", self.target, window_label(self.window));
+            }
+            *state = WindowSpend { start, spent: 0.0 };
+        }
+    }
+}
+
+/// Budgets for API keys, teams and providers
+#[derive(Debug)]
+pub struct BudgetManager {
+    budgets: ArcSwap<Vec<Budget>>,
+    alerting: Option<Arc<AlertingService>>,
+}
+
+impl BudgetManager {
+    /// Create budgets from configuration, with no spend yet
+    ///
+    /// API keys are masked the way the spend tracker records them.
+    pub fn new(configs: &[BudgetConfig]) -> Self {
+        Self {
+            budgets: ArcSwap::from_pointee(budgets(configs)),
+            alerting: None,
+        }
+    }
+
+    /// Replace the budgets with newly configured ones
+    ///
+    /// Budgets configured again for the same target and window keep their
+    /// spend; the spend of new ones is loaded from the database.
+    pub async fn replace(&self, configs: &[BudgetConfig], database: &Database) -> Result<()> {
+        let current = self.budgets.load_full();
+        let mut budgets = budgets(configs);
+        let mut added = Vec::new();
+        for (index, budget) in budgets.iter_mut().enumerate() {
+            let kept = current
+                .iter()
+                .find(|old| old.target == budget.target && old.window == budget.window);
+            match kept {
+                Some(old) => {
+                    let old = old.state.lock();
+                    *budget.state.get_mut() = WindowSpend {
+                        start: old.start,
+                        spent: old.spent,
+                    };
+                }
+                None => added.push(index),
+            }
+        }
+        load_spend(added.into_iter().map(|index| &budgets[index]), database).await?;
+
+        self.budgets.store(Arc::new(budgets));
+        Ok(())
+    }
+
+    /// Alert as spend crosses the configured fractions of each budget
+    pub fn with_alerting(mut self, alerting: Arc<AlertingService>) -> Self {
+        self.alerting = Some(alerting);
+        self
+    }
+
+    /// Whether no budgets are configured
+    pub fn is_empty(&self) -> bool {
+        self.budgets.load().is_empty()
+    }
+
+    /// Whether any budget limits a provider
+    pub fn has_provider_budgets(&self) -> bool {
+        self.budgets
+            .load()
+            .iter()
+            .any(|budget| matches!(budget.target, BudgetTarget::Provider(_)))
+    }
+
+    /// Add stored spend of the current windows
+    pub async fn load(&self, database: &Database) -> Result<()> {
+        load_spend(self.budgets.load_full().iter(), database).await
+    }
+
+    /// Charge a priced call to the budgets it falls under
+    pub fn add(&self, event: &SpendEvent, cost: f64) {
+        let today = chrono::Utc::now().date_naive();
+        let date = event.timestamp.date_naive();
+        let provider = event
+            .provider
+            .as_deref()
+            .or_else(|| model_provider(&event.model));
+        for budget in self.budgets.load().iter() {
+            let applies = match &budget.target {
+                BudgetTarget::ApiKey { hash, .. } => event.key_hash.as_ref() == Some(hash),
+                BudgetTarget::Team(team) => event.team_id.as_ref() == Some(team),
+                BudgetTarget::Provider(name) => provider == Some(name.as_str()),
+            };
+            if !applies {
+                continue;
+            }
+
+            let (before, after) = budget.add(date, today, cost);
+            if let Some(alerting) = &self.alerting {
+                alerting.budget_spend(&budget.target.to_string(), before, after, budget.max_budget);
+            }
+        }
+    }
+
+    /// Error if the API key, given by its hash, team or provider has
+    /// exhausted a budget
+    pub fn check(
+        &self,
+        key_hash: Option<&str>,
+        team_id: Option<&str>,
+        provider: Option<&str>,
+    ) -> Result<()> {
+        let today = chrono::Utc::now().date_naive();
+        for budget in self.budgets.load().iter() {
+            let target = match &budget.target {
+                BudgetTarget::ApiKey { hash, .. } => key_hash == Some(hash.as_str()),
+                BudgetTarget::Team(team) => team_id == Some(team.as_str()),
+                BudgetTarget::Provider(name) => provider == Some(name.as_str()),
+            };
+            if target {
+                budget.check(today)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Budgets of a configuration, with no spend yet
+fn budgets(configs: &[BudgetConfig]) -> Vec<Budget> {
+    let today = chrono::Utc::now().date_naive();
+    configs
+        .iter()
+        .filter_map(|config| {
+            let target = if let Some(api_key) = &config.api_key {
+                BudgetTarget::ApiKey {
+                    hash: hash_api_key(api_key),
+                    alias: extract_api_key_prefix(api_key),
+                }
+            } else if let Some(team_id) = &config.team_id {
+                BudgetTarget::Team(team_id.clone())
+            } else {
+                BudgetTarget::Provider(config.provider.clone()?)
+            };
+            Some(Budget {
+                target,
+                max_budget: config.max_budget,
+                window: config.window,
+                state: Mutex::new(WindowSpend {
+                    start: config.window.start(today),
+                    spent: 0.0,
+                }),
+            })
+        })
+        .collect()
+}
+
+/// Add stored spend of the current windows to budgets
+async fn load_spend<'a>(
+    budgets: impl IntoIterator<Item = &'a Budget>,
+    database: &Database,
+) -> Result<()> {
+    let today = chrono::Utc::now().date_naive();
+    for budget in budgets {
+        let mut filter = SpendFilter {
+            start_date: Some(budget.window.start(today)),
+            ..Default::default()
+        };
+        match &budget.target {
+            BudgetTarget::ApiKey { hash, .. } => filter.key_hash = Some(hash.clone()),
+            BudgetTarget::Team(team) => filter.team_id = Some(team.clone()),
+            BudgetTarget::Provider(_) => {}
+        }
+
+        for record in database.list_spend(&filter).await? {
+            if let BudgetTarget::Provider(provider) = &budget.target
+                && model_provider(&record.model) != Some(provider.as_str())
+            {
+                continue;
+            }
+            budget.add(record.date, today, record.spend);
+        }
+    }
+    Ok(())
+}
+
+fn window_label(window: BudgetWindow) -> &'static str {
+    match window {
+        BudgetWindow::Day => "daily",
+        BudgetWindow::Week => "weekly",
+        BudgetWindow::Month => "monthly",
+    }
+}
+
+/// Provider prefix of a `<provider>/<model>` name
+fn model_provider(model: &str) -> Option<&str> {
+    model.split_once('/').map(|(provider, _)| provider)
+}
+
+/// Rejects calls to providers that have exhausted their budget
+///
+/// The auth middleware cannot see which model a request is for, so provider
+/// budgets are checked before each call instead.
+pub struct BudgetGuard {
+    budgets: Arc<BudgetManager>,
+}
+
+impl BudgetGuard {
+    /// Guard calls against the given budgets
+    pub fn new(budgets: Arc<BudgetManager>) -> Self {
+        Self { budgets }
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for BudgetGuard {
+    fn name(&self) -> &str {
+        "budgets"
+    }
+
+    async fn pre_call(&self, _call: &CallContext, request: &mut CallRequest) -> Result<()> {
+        self.budgets
+            .check(None, None, model_provider(&request.model))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(api_key: Option<&str>, provider: Option<&str>, window: BudgetWindow) -> BudgetConfig {
+        BudgetConfig {
+            api_key: api_key.map(str::to_string),
+            team_id: None,
+            provider: provider.map(str::to_string),
+            max_budget: 1.0,
+            window,
+        }
+    }
+
+    fn event(api_key: &str, model: &str, days_ago: u64) -> SpendEvent {
+        SpendEvent {
+            api_key: Some(extract_api_key_prefix(api_key)),
+            key_hash: Some(hash_api_key(api_key)),
+            team_id: None,
+            user_id: None,
+            model: model.to_string(),
+            provider: None,
+            prompt_tokens: 10,
+            completion_tokens: 5,
+            cost: 0.0,
+            timestamp: chrono::Utc::now() - chrono::Days::new(days_ago),
+        }
+    }
+
+    #[test]
+    fn test_budget_exhausted_by_key_and_provider() {
+        let key = "gw-1234567890abcdef";
+        let hash = hash_api_key(key);
+        let manager = BudgetManager::new(&[
+            budget(Some(key), None, BudgetWindow::Day),
+            budget(None, Some("anthropic"), BudgetWindow::Month),
+        ]);
+        assert!(manager.has_provider_budgets());
+
+        manager.add(&event(key, "gpt-4o", 0), 0.6);
+        assert!(manager.check(Some(&hash), None, None).is_ok());
+
+        manager.add(&event(key, "gpt-4o", 0), 0.6);
+        let error = manager.check(Some(&hash), None, None).unwrap_err();
+        assert!(matches!(error, GatewayError::BudgetExceeded(_)));
+        assert!(
+            manager
+                .check(Some(&hash_api_key("gw-other")), None, None)
+                .is_ok()
+        );
+        assert!(manager.check(None, None, Some("anthropic")).is_ok());
+
+        manager.add(&event("gw-other", "anthropic/claude-3-5-sonnet", 0), 1.0);
+        assert!(manager.check(None, None, Some("anthropic")).is_err());
+    }
+
+    #[test]
+    fn test_budget_not_shared_by_keys_with_same_alias() {
+        let key = "gw-1234567890abcdef";
+        let other = "gw-1234-other-cdef";
+        assert_eq!(extract_api_key_prefix(key), extract_api_key_prefix(other));
+        let manager = BudgetManager::new(&[budget(Some(key), None, BudgetWindow::Day)]);
+
+        manager.add(&event(other, "gpt-4o", 0), 5.0);
+        assert!(manager.check(Some(&hash_api_key(key)), None, None).is_ok());
+
+        manager.add(&event(key, "gpt-4o", 0), 5.0);
+        assert!(manager.check(Some(&hash_api_key(key)), None, None).is_err());
+        assert!(
+            manager
+                .check(Some(&hash_api_key(other)), None, None)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_budget_ignores_spend_outside_window() {
+        let key = "gw-1234567890abcdef";
+        let hash = hash_api_key(key);
+        let manager = BudgetManager::new(&[budget(Some(key), None, BudgetWindow::Day)]);
+
+        manager.add(&event(key, "gpt-4o", 1), 5.0);
+        assert!(manager.check(Some(&hash), None, None).is_ok());
+    }
+
+    #[test]
+    fn test_budget_resets_in_new_window() {
+        let today = chrono::Utc::now().date_naive();
+        let budget = Budget {
+            target: BudgetTarget::Team("team-a".to_string()),
+            max_budget: 1.0,
+            window: BudgetWindow::Week,
+            state: Mutex::new(WindowSpend {
+                start: BudgetWindow::Week.start(today) - chrono::Days::new(7),
+                spent: 5.0,
+            }),
+        };
+
+        assert!(budget.check(today).is_ok());
+        assert_eq!(budget.state.lock().spent, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_replace_keeps_spend_of_unchanged_budgets() {
+        let config = crate::config::DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+        let database = Database::new(&config).await.unwrap();
+        database.migrate().await.unwrap();
+
+        let key = "gw-1234567890abcdef";
+        let hash = hash_api_key(key);
+        let manager = BudgetManager::new(&[budget(Some(key), None, BudgetWindow::Day)]);
+        manager.add(&event(key, "gpt-4o", 0), 1.5);
+        assert!(manager.check(Some(&hash), None, None).is_err());
+
+        // The key's budget keeps its spend, the provider budget starts empty
+        manager
+            .replace(
+                &[
+                    budget(Some(key), None, BudgetWindow::Day),
+                    budget(None, Some("openai"), BudgetWindow::Month),
+                ],
+                &database,
+            )
+            .await
+            .unwrap();
+        assert!(manager.has_provider_budgets());
+        assert!(manager.check(Some(&hash), None, None).is_err());
+        assert!(manager.check(None, None, Some("openai")).is_ok());
+
+        // A budget with another window starts over
+        manager
+            .replace(&[budget(Some(key), None, BudgetWindow::Week)], &database)
+            .await
+            .unwrap();
+        assert!(!manager.has_provider_budgets());
+        assert!(manager.check(Some(&hash), None, None).is_ok());
+    }
+}
@@ -0,0 +1,17 @@
+//! Spend tracking
+//!
+//! The cost of each model call is priced with the unified pricing service and
+//! added to per-day totals by API key, team, user and model. Totals are kept
+//! in memory for a short interval and then added to the `daily_spend` table,
+//! so the database sees one write per group rather than one per request.
+//!
+//! Budgets cap the spend of an API key, team or provider per day, week or
+//! month. The auth middleware rejects requests from exhausted keys and teams,
+//! and [`BudgetGuard`] rejects calls to exhausted providers.
+
+mod budget;
+mod report;
+mod tracker;
+
+pub use budget::{BudgetGuard, BudgetManager};
+pub use tracker::{SpendEvent, SpendTracker};
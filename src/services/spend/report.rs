@@ -0,0 +1,85 @@
+//! Spend reports
+
+use crate::core::models::metrics::{DailySpend, KeySpend, SpendRecord, SpendTotals};
+use std::collections::BTreeMap;
+
+/// Label for spend made without an API key
+const NO_KEY: &str = "unknown";
+
+/// Totals per day, oldest first
+pub(super) fn daily_spend(records: &[SpendRecord]) -> Vec<DailySpend> {
+    let mut days: BTreeMap<_, DailySpend> = BTreeMap::new();
+    for record in records {
+        let day = days.entry(record.date).or_insert_with(|| DailySpend {
+            date: record.date,
+            totals: SpendTotals::default(),
+            models: BTreeMap::new(),
+        });
+        day.totals.add(record);
+        *day.models.entry(record.model.clone()).or_default() += record.spend;
+    }
+    days.into_values().collect()
+}
+
+/// Totals per API key, highest spend first
+pub(super) fn key_spend(records: &[SpendRecord]) -> Vec<KeySpend> {
+    let mut keys: BTreeMap<&str, SpendTotals> = BTreeMap::new();
+    for record in records {
+        let key = record.api_key.as_deref().unwrap_or(NO_KEY);
+        keys.entry(key).or_default().add(record);
+    }
+
+    let mut keys: Vec<KeySpend> = keys
+        .into_iter()
+        .map(|(api_key, totals)| KeySpend {
+            api_key: api_key.to_string(),
+            totals,
+        })
+        .collect();
+    keys.sort_by(|a, b| b.totals.spend.total_cmp(&a.totals.spend));
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn record(day: u32, api_key: Option<&str>, model: &str, spend: f64) -> SpendRecord {
+        SpendRecord {
+            date: NaiveDate::from_ymd_opt(2024, 3, day).unwrap(),
+            api_key: api_key.map(str::to_string),
+            key_hash: None,
+            team_id: None,
+            user_id: None,
+            model: model.to_string(),
+            spend,
+            prompt_tokens: 100,
+            completion_tokens: 50,
+            requests: 2,
+        }
+    }
+
+    #[test]
+    fn test_daily_and_key_spend() {
+        let records = vec![
+            record(2, Some("gw-a...aaaa"), "gpt-4o", 1.0),
+            record(1, Some("gw-a...aaaa"), "gpt-4o", 0.5),
+            record(1, Some("gw-b...bbbb"), "claude-3-5-sonnet", 2.0),
+            record(1, None, "gpt-4o", 0.25),
+        ];
+
+        let daily = daily_spend(&records);
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].date, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(daily[0].totals.spend, 2.75);
+        assert_eq!(daily[0].totals.requests, 6);
+        assert_eq!(daily[0].models["gpt-4o"], 0.75);
+
+        let keys = key_spend(&records);
+        let names: Vec<_> = keys.iter().map(|k| k.api_key.as_str()).collect();
+        assert_eq!(names, ["gw-b...bbbb", "gw-a...aaaa", "unknown"]);
+        assert_eq!(keys[1].totals.spend, 1.5);
+        assert_eq!(keys[1].totals.prompt_tokens, 200);
+    }
+}
@@ -0,0 +1,273 @@
+//! Spend tracker
+
+use super::budget::BudgetManager;
+use super::report;
+use crate::config::{BudgetConfig, SpendConfig};
+use crate::core::models::metrics::{DailySpend, KeySpend, SpendFilter, SpendRecord};
+use crate::services::alerting::AlertingService;
+use crate::services::pricing::PricingService;
+use crate::storage::database::Database;
+use crate::utils::error::Result;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Events buffered before new ones are dropped
+const QUEUE_SIZE: usize = 10_000;
+
+/// A model call to charge
+#[derive(Debug, Clone)]
+pub struct SpendEvent {
+    /// Masked API key
+    pub api_key: Option<String>,
+    /// SHA-256 hash of the API key
+    pub key_hash: Option<String>,
+    /// Team ID
+    pub team_id: Option<String>,
+    /// User ID
+    pub user_id: Option<String>,
+    /// Model
+    pub model: String,
+    /// Provider serving the model
+    pub provider: Option<String>,
+    /// Prompt tokens
+    pub prompt_tokens: u32,
+    /// Completion tokens
+    pub completion_tokens: u32,
+    /// Cost reported by the handler, used when the pricing service does not
+    /// know the model
+    pub cost: f64,
+    /// When the call was made
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Records spend in the background and answers spend queries
+#[derive(Debug)]
+pub struct SpendTracker {
+    database: Arc<Database>,
+    budgets: Arc<BudgetManager>,
+    sender: mpsc::Sender<SpendEvent>,
+}
+
+impl SpendTracker {
+    /// Create a tracker and start its writer task
+    ///
+    /// Budget threshold alerts go to `alerting`, if given. Must be called from
+    /// within a Tokio runtime.
+    pub fn new(
+        config: &SpendConfig,
+        database: Arc<Database>,
+        pricing: Arc<PricingService>,
+        alerting: Option<Arc<AlertingService>>,
+    ) -> Self {
+        let mut budgets = BudgetManager::new(&config.budgets);
+        if let Some(alerting) = alerting {
+            budgets = budgets.with_alerting(alerting);
+        }
+        let budgets = Arc::new(budgets);
+        if !budgets.is_empty() {
+            let (budgets, database) = (Arc::clone(&budgets), Arc::clone(&database));
+            tokio::spawn(async move {
+                if let Err(e) = budgets.load(&database).await {
+                    warn!("Failed to load spend for budgets: {}", e);
+                }
+            });
+        }
+
+        let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+        tokio::spawn(write_spend(
+            Arc::clone(&database),
+            pricing,
+            Arc::clone(&budgets),
+            receiver,
+            Duration::from_secs(config.flush_interval.max(1)),
+        ));
+
+        Self {
+            database,
+            budgets,
+            sender,
+        }
+    }
+
+    /// Budgets enforced against the tracked spend
+    pub fn budgets(&self) -> &Arc<BudgetManager> {
+        &self.budgets
+    }
+
+    /// Enforce newly configured budgets, keeping the spend of unchanged ones
+    pub async fn replace_budgets(&self, budgets: &[BudgetConfig]) -> Result<()> {
+        self.budgets.replace(budgets, &self.database).await
+    }
+
+    /// Queue a call to be charged
+    pub fn record(&self, event: SpendEvent) {
+        if self.sender.try_send(event).is_err() {
+            warn!("Spend queue is full, dropping event");
+        }
+    }
+
+    /// Stored spend rows matching a filter
+    pub async fn records(&self, filter: &SpendFilter) -> Result<Vec<SpendRecord>> {
+        self.database.list_spend(filter).await
+    }
+
+    /// Spend per day matching a filter, oldest first
+    pub async fn daily_spend(&self, filter: &SpendFilter) -> Result<Vec<DailySpend>> {
+        Ok(report::daily_spend(&self.records(filter).await?))
+    }
+
+    /// Spend per API key matching a filter, highest first
+    pub async fn key_spend(&self, filter: &SpendFilter) -> Result<Vec<KeySpend>> {
+        Ok(report::key_spend(&self.records(filter).await?))
+    }
+}
+
+/// Group a spend record is added to
+type SpendGroup = (
+    NaiveDate,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    String,
+);
+
+/// Price queued events, charge them to budgets and write the totals at every
+/// interval
+async fn write_spend(
+    database: Arc<Database>,
+    pricing: Arc<PricingService>,
+    budgets: Arc<BudgetManager>,
+    mut receiver: mpsc::Receiver<SpendEvent>,
+    flush_interval: Duration,
+) {
+    let mut pending: HashMap<SpendGroup, SpendRecord> = HashMap::new();
+    let mut interval = tokio::time::interval(flush_interval);
+
+    loop {
+        tokio::select! {
+            event = receiver.recv() => match event {
+                Some(event) => {
+                    let cost = price(&pricing, &event).await;
+                    budgets.add(&event, cost);
+                    add_event(&mut pending, event, cost);
+                }
+                None => break,
+            },
+            _ = interval.tick() => flush(&database, &mut pending).await,
+        }
+    }
+
+    flush(&database, &mut pending).await;
+}
+
+/// Cost of an event according to the pricing service
+async fn price(pricing: &PricingService, event: &SpendEvent) -> f64 {
+    let unprefixed = event.model.split_once('/').map(|(_, model)| model);
+    for model in std::iter::once(event.model.as_str()).chain(unprefixed) {
+        if pricing.get_model_info(model).is_none() {
+            continue;
+        }
+        match pricing
+            .calculate_completion_cost(
+                model,
+                event.prompt_tokens,
+                event.completion_tokens,
+                None,
+                None,
+                None,
+            )
+            .await
+        {
+            Ok(cost) => return cost.total_cost,
+            Err(e) => debug!("Failed to price {}: {}", model, e),
+        }
+    }
+    event.cost
+}
+
+/// Add an event to the pending totals
+fn add_event(pending: &mut HashMap<SpendGroup, SpendRecord>, event: SpendEvent, cost: f64) {
+    let date = event.timestamp.date_naive();
+    let group = (
+        date,
+        event.api_key.clone(),
+        event.key_hash.clone(),
+        event.team_id.clone(),
+        event.user_id.clone(),
+        event.model.clone(),
+    );
+    let record = pending.entry(group).or_insert_with(|| SpendRecord {
+        date,
+        api_key: event.api_key,
+        key_hash: event.key_hash,
+        team_id: event.team_id,
+        user_id: event.user_id,
+        model: event.model,
+        spend: 0.0,
+        prompt_tokens: 0,
+        completion_tokens: 0,
+        requests: 0,
+    });
+    record.spend += cost;
+    record.prompt_tokens += u64::from(event.prompt_tokens);
+    record.completion_tokens += u64::from(event.completion_tokens);
+    record.requests += 1;
+}
+
+async fn flush(database: &Database, pending: &mut HashMap<SpendGroup, SpendRecord>) {
+    if pending.is_empty() {
+        return;
+    }
+    let records: Vec<SpendRecord> = pending.drain().map(|(_, record)| record).collect();
+    match database.upsert_spend(&records).await {
+        Ok(()) => debug!("Wrote {} spend records", records.len()),
+        Err(e) => warn!("Failed to write {} spend records: {}", records.len(), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(model: &str, api_key: Option<&str>) -> SpendEvent {
+        SpendEvent {
+            api_key: api_key.map(str::to_string),
+            key_hash: None,
+            team_id: None,
+            user_id: None,
+            model: model.to_string(),
+            provider: None,
+            prompt_tokens: 10,
+            completion_tokens: 5,
+            cost: 0.01,
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_add_event_groups_by_key_and_model() {
+        let mut pending = HashMap::new();
+        add_event(&mut pending, event("gpt-4o", Some("gw-1...cdef")), 0.01);
+        add_event(&mut pending, event("gpt-4o", Some("gw-1...cdef")), 0.02);
+        add_event(&mut pending, event("gpt-4o", None), 0.01);
+        add_event(
+            &mut pending,
+            event("gpt-4o-mini", Some("gw-1...cdef")),
+            0.01,
+        );
+
+        assert_eq!(pending.len(), 3);
+        let record = pending
+            .values()
+            .find(|r| r.model == "gpt-4o" && r.api_key.is_some())
+            .unwrap();
+        assert_eq!(record.requests, 2);
+        assert_eq!(record.prompt_tokens, 20);
+        assert!((record.spend - 0.03).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,160 @@
+//! Cache backend trait and its Redis implementation
+
+use crate::storage::redis::RedisPool;
+use crate::utils::error::Result;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+/// Key-value cache with lists, sets and hashes
+#[async_trait::async_trait]
+pub trait CacheBackend: Debug + Send + Sync {
+    /// Name of the backend
+    fn name(&self) -> &'static str;
+
+    /// Get a string value
+    async fn get(&self, key: &str) -> Result<Option<String>>;
+
+    /// Set a string value with optional TTL in seconds
+    async fn set(&self, key: &str, value: &str, ttl: Option<u64>) -> Result<()>;
+
+    /// Delete a key
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// Check if a key exists
+    async fn exists(&self, key: &str) -> Result<bool>;
+
+    /// Get multiple string values
+    async fn mget(&self, keys: &[String]) -> Result<Vec<Option<String>>>;
+
+    /// Set multiple string values with optional TTL in seconds
+    async fn mset(&self, pairs: &[(String, String)], ttl: Option<u64>) -> Result<()>;
+
+    /// Increment an integer value by delta, set its TTL and return the new value
+    async fn increment_with_expiry(&self, key: &str, delta: i64, ttl: u64) -> Result<i64>;
+
+    /// Push a value to the head of a list
+    async fn list_push(&self, key: &str, value: &str) -> Result<()>;
+
+    /// Pop a value from the tail of a list
+    async fn list_pop(&self, key: &str) -> Result<Option<String>>;
+
+    /// Get list length
+    async fn list_length(&self, key: &str) -> Result<usize>;
+
+    /// Add member to set
+    async fn set_add(&self, key: &str, member: &str) -> Result<()>;
+
+    /// Remove member from set
+    async fn set_remove(&self, key: &str, member: &str) -> Result<()>;
+
+    /// Get all members of set
+    async fn set_members(&self, key: &str) -> Result<Vec<String>>;
+
+    /// Set hash field value
+    async fn hash_set(&self, key: &str, field: &str, value: &str) -> Result<()>;
+
+    /// Get hash field value
+    async fn hash_get(&self, key: &str, field: &str) -> Result<Option<String>>;
+
+    /// Delete hash field
+    async fn hash_delete(&self, key: &str, field: &str) -> Result<()>;
+
+    /// Get all hash fields and values
+    async fn hash_get_all(&self, key: &str) -> Result<HashMap<String, String>>;
+
+    /// Drop expired keys, returning how many were dropped
+    ///
+    /// Backends that expire keys themselves drop none.
+    async fn purge_expired(&self) -> Result<u64> {
+        Ok(0)
+    }
+
+    /// Health check
+    async fn health_check(&self) -> Result<()>;
+
+    /// Close connections
+    async fn close(&self) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl CacheBackend for RedisPool {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        RedisPool::get(self, key).await
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Option<u64>) -> Result<()> {
+        RedisPool::set(self, key, value, ttl).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        RedisPool::delete(self, key).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        RedisPool::exists(self, key).await
+    }
+
+    async fn mget(&self, keys: &[String]) -> Result<Vec<Option<String>>> {
+        RedisPool::mget(self, keys).await
+    }
+
+    async fn mset(&self, pairs: &[(String, String)], ttl: Option<u64>) -> Result<()> {
+        RedisPool::mset(self, pairs, ttl).await
+    }
+
+    async fn increment_with_expiry(&self, key: &str, delta: i64, ttl: u64) -> Result<i64> {
+        RedisPool::increment_with_expiry(self, key, delta, ttl).await
+    }
+
+    async fn list_push(&self, key: &str, value: &str) -> Result<()> {
+        RedisPool::list_push(self, key, value).await
+    }
+
+    async fn list_pop(&self, key: &str) -> Result<Option<String>> {
+        RedisPool::list_pop(self, key).await
+    }
+
+    async fn list_length(&self, key: &str) -> Result<usize> {
+        RedisPool::list_length(self, key).await
+    }
+
+    async fn set_add(&self, key: &str, member: &str) -> Result<()> {
+        RedisPool::set_add(self, key, member).await
+    }
+
+    async fn set_remove(&self, key: &str, member: &str) -> Result<()> {
+        RedisPool::set_remove(self, key, member).await
+    }
+
+    async fn set_members(&self, key: &str) -> Result<Vec<String>> {
+        RedisPool::set_members(self, key).await
+    }
+
+    async fn hash_set(&self, key: &str, field: &str, value: &str) -> Result<()> {
+        RedisPool::hash_set(self, key, field, value).await
+    }
+
+    async fn hash_get(&self, key: &str, field: &str) -> Result<Option<String>> {
+        RedisPool::hash_get(self, key, field).await
+    }
+
+    async fn hash_delete(&self, key: &str, field: &str) -> Result<()> {
+        RedisPool::hash_delete(self, key, field).await
+    }
+
+    async fn hash_get_all(&self, key: &str) -> Result<HashMap<String, String>> {
+        RedisPool::hash_get_all(self, key).await
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        RedisPool::health_check(self).await
+    }
+
+    async fn close(&self) -> Result<()> {
+        RedisPool::close(self).await
+    }
+}
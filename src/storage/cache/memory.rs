@@ -0,0 +1,340 @@
+//! In-memory cache used without Redis
+
+use super::backend::CacheBackend;
+use crate::utils::error::{GatewayError, Result};
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+/// Value of a key
+#[derive(Debug)]
+enum Value {
+    String(String),
+    List(VecDeque<String>),
+    Set(HashSet<String>),
+    Hash(HashMap<String, String>),
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    expires_at: Option<Instant>,
+}
+
+impl Entry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Debug)]
+struct Entries {
+    map: LruCache<String, Entry>,
+}
+
+impl Entries {
+    /// Live entry of a key, dropping it if it expired and otherwise marking it
+    /// recently used
+    fn live(&mut self, key: &str) -> Option<&mut Entry> {
+        if self.map.peek(key)?.is_expired(Instant::now()) {
+            self.map.pop(key);
+            return None;
+        }
+        self.map.get_mut(key)
+    }
+
+    /// Value of a key, created with `default` if missing
+    ///
+    /// When the cache is full, the least recently used key is evicted, as
+    /// Redis does under `allkeys-lru`.
+    fn value_or_insert(&mut self, key: &str, default: fn() -> Value) -> &mut Value {
+        if self.live(key).is_none() {
+            self.map.put(
+                key.to_string(),
+                Entry {
+                    value: default(),
+                    expires_at: None,
+                },
+            );
+        }
+        &mut self
+            .map
+            .get_mut(key)
+            .expect("entry was just inserted")
+            .value
+    }
+
+    fn set(&mut self, key: &str, value: &str, ttl: Option<u64>) {
+        if ttl == Some(0) {
+            self.map.pop(key);
+            return;
+        }
+        *self.value_or_insert(key, || Value::String(String::new())) =
+            Value::String(value.to_string());
+        self.expire(key, ttl);
+    }
+
+    fn expire(&mut self, key: &str, ttl: Option<u64>) {
+        if let Some(entry) = self.map.peek_mut(key) {
+            entry.expires_at = ttl.map(|ttl| Instant::now() + Duration::from_secs(ttl));
+        }
+    }
+
+    /// Drop every expired entry, returning how many were dropped
+    fn sweep(&mut self) -> usize {
+        let now = Instant::now();
+        let expired: Vec<String> = self
+            .map
+            .iter()
+            .filter(|(_, entry)| entry.is_expired(now))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            self.map.pop(key);
+        }
+        expired.len()
+    }
+
+    /// Drop a key whose collection became empty, as Redis does
+    fn remove_if_empty(&mut self, key: &str) {
+        let empty = match self.map.peek(key).map(|entry| &entry.value) {
+            Some(Value::List(list)) => list.is_empty(),
+            Some(Value::Set(set)) => set.is_empty(),
+            Some(Value::Hash(hash)) => hash.is_empty(),
+            _ => false,
+        };
+        if empty {
+            self.map.pop(key);
+        }
+    }
+}
+
+fn wrong_type(key: &str) -> GatewayError {
+    GatewayError::Cache(format!(
+        "WRONGTYPE Operation against key {} holding the wrong kind of value",
+        key
+    ))
+}
+
+/// Cache held in process memory
+///
+/// Mirrors the Redis semantics the gateway relies on, including TTLs, so the
+/// gateway behaves alike without Redis, except that instances do not share
+/// cached values. The number of keys is bounded, evicting the least recently
+/// used.
+#[derive(Debug)]
+pub struct MemoryCache {
+    entries: Mutex<Entries>,
+}
+
+impl Default for MemoryCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryCache {
+    /// Number of keys held by [`MemoryCache::new`]
+    pub const DEFAULT_MAX_ENTRIES: usize = 100_000;
+
+    /// Create an empty cache holding at most [`Self::DEFAULT_MAX_ENTRIES`] keys
+    pub fn new() -> Self {
+        Self::with_max_entries(Self::DEFAULT_MAX_ENTRIES)
+    }
+
+    /// Create an empty cache holding at most `max_entries` keys
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        let capacity = NonZeroUsize::new(max_entries).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(Entries {
+                map: LruCache::new(capacity),
+            }),
+        }
+    }
+
+    /// Number of keys held, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.entries.lock().map.len()
+    }
+
+    /// Whether the cache holds no keys
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheBackend for MemoryCache {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let mut entries = self.entries.lock();
+        Ok(match entries.live(key).map(|entry| &entry.value) {
+            Some(Value::String(value)) => Some(value.clone()),
+            _ => None,
+        })
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Option<u64>) -> Result<()> {
+        self.entries.lock().set(key, value, ttl);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.entries.lock().map.pop(key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        Ok(self.entries.lock().live(key).is_some())
+    }
+
+    async fn mget(&self, keys: &[String]) -> Result<Vec<Option<String>>> {
+        let mut values = Vec::with_capacity(keys.len());
+        for key in keys {
+            values.push(self.get(key).await?);
+        }
+        Ok(values)
+    }
+
+    async fn mset(&self, pairs: &[(String, String)], ttl: Option<u64>) -> Result<()> {
+        let mut entries = self.entries.lock();
+        for (key, value) in pairs {
+            entries.set(key, value, ttl);
+        }
+        Ok(())
+    }
+
+    async fn increment_with_expiry(&self, key: &str, delta: i64, ttl: u64) -> Result<i64> {
+        let mut entries = self.entries.lock();
+        let new_value = {
+            let Value::String(value) =
+                entries.value_or_insert(key, || Value::String(String::new()))
+            else {
+                return Err(wrong_type(key));
+            };
+            let current = if value.is_empty() {
+                0
+            } else {
+                value.parse::<i64>().map_err(|_| {
+                    GatewayError::Cache(format!("Value of key {} is not an integer", key))
+                })?
+            };
+            let new_value = current.checked_add(delta).ok_or_else(|| {
+                GatewayError::Cache(format!("Increment of key {} overflows", key))
+            })?;
+            *value = new_value.to_string();
+            new_value
+        };
+        entries.expire(key, Some(ttl));
+        Ok(new_value)
+    }
+
+    async fn list_push(&self, key: &str, value: &str) -> Result<()> {
+        let mut entries = self.entries.lock();
+        match entries.value_or_insert(key, || Value::List(VecDeque::new())) {
+            Value::List(list) => list.push_front(value.to_string()),
+            _ => return Err(wrong_type(key)),
+        }
+        Ok(())
+    }
+
+    async fn list_pop(&self, key: &str) -> Result<Option<String>> {
+        let mut entries = self.entries.lock();
+        let value = match entries.live(key).map(|entry| &mut entry.value) {
+            Some(Value::List(list)) => list.pop_back(),
+            Some(_) => return Err(wrong_type(key)),
+            None => None,
+        };
+        entries.remove_if_empty(key);
+        Ok(value)
+    }
+
+    async fn list_length(&self, key: &str) -> Result<usize> {
+        match self.entries.lock().live(key).map(|entry| &entry.value) {
+            Some(Value::List(list)) => Ok(list.len()),
+            Some(_) => Err(wrong_type(key)),
+            None => Ok(0),
+        }
+    }
+
+    async fn set_add(&self, key: &str, member: &str) -> Result<()> {
+        let mut entries = self.entries.lock();
+        match entries.value_or_insert(key, || Value::Set(HashSet::new())) {
+            Value::Set(set) => set.insert(member.to_string()),
+            _ => return Err(wrong_type(key)),
+        };
+        Ok(())
+    }
+
+    async fn set_remove(&self, key: &str, member: &str) -> Result<()> {
+        let mut entries = self.entries.lock();
+        match entries.live(key).map(|entry| &mut entry.value) {
+            Some(Value::Set(set)) => set.remove(member),
+            Some(_) => return Err(wrong_type(key)),
+            None => false,
+        };
+        entries.remove_if_empty(key);
+        Ok(())
+    }
+
+    async fn set_members(&self, key: &str) -> Result<Vec<String>> {
+        match self.entries.lock().live(key).map(|entry| &entry.value) {
+            Some(Value::Set(set)) => Ok(set.iter().cloned().collect()),
+            Some(_) => Err(wrong_type(key)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn hash_set(&self, key: &str, field: &str, value: &str) -> Result<()> {
+        let mut entries = self.entries.lock();
+        match entries.value_or_insert(key, || Value::Hash(HashMap::new())) {
+            Value::Hash(hash) => hash.insert(field.to_string(), value.to_string()),
+            _ => return Err(wrong_type(key)),
+        };
+        Ok(())
+    }
+
+    async fn hash_get(&self, key: &str, field: &str) -> Result<Option<String>> {
+        match self.entries.lock().live(key).map(|entry| &entry.value) {
+            Some(Value::Hash(hash)) => Ok(hash.get(field).cloned()),
+            Some(_) => Err(wrong_type(key)),
+            None => Ok(None),
+        }
+    }
+
+    async fn hash_delete(&self, key: &str, field: &str) -> Result<()> {
+        let mut entries = self.entries.lock();
+        match entries.live(key).map(|entry| &mut entry.value) {
+            Some(Value::Hash(hash)) => hash.remove(field),
+            Some(_) => return Err(wrong_type(key)),
+            None => None,
+        };
+        entries.remove_if_empty(key);
+        Ok(())
+    }
+
+    async fn hash_get_all(&self, key: &str) -> Result<HashMap<String, String>> {
+        match self.entries.lock().live(key).map(|entry| &entry.value) {
+            Some(Value::Hash(hash)) => Ok(hash.clone()),
+            Some(_) => Err(wrong_type(key)),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    async fn purge_expired(&self) -> Result<u64> {
+        Ok(self.entries.lock().sweep() as u64)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<()> {
+        Ok(())
+    }
+}
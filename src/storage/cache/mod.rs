@@ -0,0 +1,13 @@
+//! Key-value cache backends
+//!
+//! This module provides the cache used by the storage layer: Redis when it is
+//! enabled and reachable, and an in-memory cache otherwise.
+
+mod backend;
+mod memory;
+#[cfg(test)]
+mod tests;
+
+// Re-export public types and traits
+pub use backend::CacheBackend;
+pub use memory::MemoryCache;
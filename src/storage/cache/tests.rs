@@ -0,0 +1,110 @@
+//! Cache backend tests
+
+use super::{CacheBackend, MemoryCache};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_memory_cache_strings() {
+    let cache = MemoryCache::new();
+    assert_eq!(cache.get("a").await.unwrap(), None);
+
+    cache.set("a", "1", None).await.unwrap();
+    assert_eq!(cache.get("a").await.unwrap().as_deref(), Some("1"));
+    assert!(cache.exists("a").await.unwrap());
+
+    let pairs = [
+        ("b".to_string(), "2".to_string()),
+        ("c".to_string(), "3".to_string()),
+    ];
+    cache.mset(&pairs, Some(60)).await.unwrap();
+    let keys = ["a", "b", "x"].map(str::to_string);
+    assert_eq!(
+        cache.mget(&keys).await.unwrap(),
+        vec![Some("1".to_string()), Some("2".to_string()), None]
+    );
+
+    cache.delete("a").await.unwrap();
+    assert!(!cache.exists("a").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_memory_cache_expiry() {
+    let cache = MemoryCache::new();
+    cache.set("a", "1", Some(0)).await.unwrap();
+    assert!(!cache.exists("a").await.unwrap());
+
+    cache.set("a", "1", Some(1)).await.unwrap();
+    assert!(cache.exists("a").await.unwrap());
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(cache.get("a").await.unwrap(), None);
+
+    cache.set("b", "2", Some(1)).await.unwrap();
+    cache.set("c", "3", None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(cache.purge_expired().await.unwrap(), 1);
+    assert!(cache.exists("c").await.unwrap());
+
+    // Counters start over once expired
+    assert_eq!(cache.increment_with_expiry("n", 2, 60).await.unwrap(), 2);
+    assert_eq!(cache.increment_with_expiry("n", -3, 60).await.unwrap(), -1);
+    assert_eq!(cache.get("n").await.unwrap().as_deref(), Some("-1"));
+    cache.set("s", "text", None).await.unwrap();
+    assert!(cache.increment_with_expiry("s", 1, 60).await.is_err());
+}
+
+#[tokio::test]
+async fn test_memory_cache_collections() {
+    let cache = MemoryCache::new();
+    cache.list_push("list", "first").await.unwrap();
+    cache.list_push("list", "second").await.unwrap();
+    assert_eq!(cache.list_length("list").await.unwrap(), 2);
+    assert_eq!(
+        cache.list_pop("list").await.unwrap().as_deref(),
+        Some("first")
+    );
+    assert_eq!(
+        cache.list_pop("list").await.unwrap().as_deref(),
+        Some("second")
+    );
+    // Emptied collections no longer exist
+    assert!(!cache.exists("list").await.unwrap());
+
+    cache.set_add("set", "a").await.unwrap();
+    cache.set_add("set", "a").await.unwrap();
+    cache.set_add("set", "b").await.unwrap();
+    let mut members = cache.set_members("set").await.unwrap();
+    members.sort();
+    assert_eq!(members, vec!["a", "b"]);
+    cache.set_remove("set", "a").await.unwrap();
+    assert_eq!(cache.set_members("set").await.unwrap(), vec!["b"]);
+
+    cache.hash_set("hash", "field", "value").await.unwrap();
+    assert_eq!(
+        cache.hash_get("hash", "field").await.unwrap().as_deref(),
+        Some("value")
+    );
+    assert_eq!(cache.hash_get_all("hash").await.unwrap().len(), 1);
+    cache.hash_delete("hash", "field").await.unwrap();
+    assert!(!cache.exists("hash").await.unwrap());
+
+    // Collections of the wrong kind are refused, like Redis
+    assert!(cache.list_push("set", "a").await.is_err());
+    assert!(cache.hash_get("set", "a").await.is_err());
+    assert_eq!(cache.get("set").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_memory_cache_evicts_least_recently_used() {
+    let cache = MemoryCache::with_max_entries(2);
+    cache.set("a", "1", None).await.unwrap();
+    cache.set("b", "2", None).await.unwrap();
+
+    // Reading `a` makes `b` the least recently used
+    assert_eq!(cache.get("a").await.unwrap().as_deref(), Some("1"));
+    cache.set_add("c", "x").await.unwrap();
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.exists("a").await.unwrap());
+    assert!(!cache.exists("b").await.unwrap());
+    assert_eq!(cache.set_members("c").await.unwrap(), vec!["x"]);
+}
@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Daily spend database model
+///
+/// One row per day, API key, team, user and model. Missing keys, teams and
+/// users are stored as empty strings so the unique index covers them.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "daily_spend")]
+pub struct Model {
+    /// Row ID
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// Day the spend was incurred (UTC)
+    pub date: Date,
+
+    /// Masked API key, empty if none
+    pub api_key: String,
+
+    /// SHA-256 hash of the API key, empty if none
+    pub key_hash: String,
+
+    /// Team ID, empty if none
+    pub team_id: String,
+
+    /// User ID, empty if none
+    pub user_id: String,
+
+    /// Model
+    pub model: String,
+
+    /// Cost in USD
+    pub spend: f64,
+
+    /// Prompt tokens
+    pub prompt_tokens: i64,
+
+    /// Completion tokens
+    pub completion_tokens: i64,
+
+    /// Number of requests
+    pub requests: i64,
+
+    /// Last update timestamp
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+/// Daily spend entity relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,14 +1,23 @@
 /// Batch entity module
 pub mod batch;
+/// Daily spend entity module
+pub mod daily_spend;
 /// Password reset token entity module
 pub mod password_reset_token;
+/// Provider credential entity module
+pub mod provider_credential;
+/// Request log entity module
+pub mod request_log;
 /// User entity module
 pub mod user;
 /// User session entity module
 pub mod user_session;
 
 pub use batch::Entity as Batch;
+pub use daily_spend::Entity as DailySpend;
 pub use password_reset_token::Entity as PasswordResetToken;
+pub use provider_credential::Entity as ProviderCredential;
+pub use request_log::Entity as RequestLog;
 pub use user::Entity as User;
 // UserSession is available but not currently used
 #[allow(unused_imports)]
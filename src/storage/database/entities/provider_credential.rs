@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Provider credential database model
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "provider_credentials")]
+pub struct Model {
+    /// Provider name
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub provider: String,
+
+    /// API key, encrypted when encryption is enabled
+    pub api_key: String,
+
+    /// Credential creation timestamp
+    pub created_at: DateTimeWithTimeZone,
+
+    /// Credential update timestamp
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+/// Provider credential entity relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
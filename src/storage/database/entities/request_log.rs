@@ -0,0 +1,65 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Request audit log database model
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "request_logs")]
+pub struct Model {
+    /// Log entry ID
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// Gateway request ID
+    pub request_id: String,
+
+    /// Masked API key the request was made with (optional)
+    pub key_alias: Option<String>,
+
+    /// HTTP method
+    pub method: String,
+
+    /// Request path
+    pub path: String,
+
+    /// Requested model (optional)
+    pub model: Option<String>,
+
+    /// Provider serving the model (optional)
+    pub provider: Option<String>,
+
+    /// Prompt tokens
+    pub prompt_tokens: i32,
+
+    /// Completion tokens
+    pub completion_tokens: i32,
+
+    /// Total tokens
+    pub total_tokens: i32,
+
+    /// Cost in USD
+    pub cost: f64,
+
+    /// Latency until the response started, in milliseconds
+    pub latency_ms: i64,
+
+    /// HTTP status code
+    pub status_code: i32,
+
+    /// Error message for failed calls (optional)
+    pub error: Option<String>,
+
+    /// Redacted request payload (JSON, optional)
+    pub request_payload: Option<String>,
+
+    /// Redacted response payload (JSON, optional)
+    pub response_payload: Option<String>,
+
+    /// Log entry creation timestamp
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Request log entity relations
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,146 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RequestLogs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RequestLogs::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RequestLogs::RequestId).string().not_null())
+                    .col(ColumnDef::new(RequestLogs::KeyAlias).string().null())
+                    .col(ColumnDef::new(RequestLogs::Method).string().not_null())
+                    .col(ColumnDef::new(RequestLogs::Path).string().not_null())
+                    .col(ColumnDef::new(RequestLogs::Model).string().null())
+                    .col(ColumnDef::new(RequestLogs::Provider).string().null())
+                    .col(
+                        ColumnDef::new(RequestLogs::PromptTokens)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(RequestLogs::CompletionTokens)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(RequestLogs::TotalTokens)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(RequestLogs::Cost)
+                            .double()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(RequestLogs::LatencyMs)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RequestLogs::StatusCode).integer().not_null())
+                    .col(ColumnDef::new(RequestLogs::Error).text().null())
+                    .col(ColumnDef::new(RequestLogs::RequestPayload).text().null())
+                    .col(ColumnDef::new(RequestLogs::ResponsePayload).text().null())
+                    .col(
+                        ColumnDef::new(RequestLogs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Retention deletes and time-range queries
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_request_logs_created_at")
+                    .table(RequestLogs::Table)
+                    .col(RequestLogs::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_request_logs_request_id")
+                    .table(RequestLogs::Table)
+                    .col(RequestLogs::RequestId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Per-key and per-model usage queries
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_request_logs_key_alias_created_at")
+                    .table(RequestLogs::Table)
+                    .col(RequestLogs::KeyAlias)
+                    .col(RequestLogs::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_request_logs_model")
+                    .table(RequestLogs::Table)
+                    .col(RequestLogs::Model)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RequestLogs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RequestLogs {
+    Table,
+    Id,
+    RequestId,
+    KeyAlias,
+    Method,
+    Path,
+    Model,
+    Provider,
+    PromptTokens,
+    CompletionTokens,
+    TotalTokens,
+    Cost,
+    LatencyMs,
+    StatusCode,
+    Error,
+    RequestPayload,
+    ResponsePayload,
+    CreatedAt,
+}
@@ -0,0 +1,150 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DailySpend::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DailySpend::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(DailySpend::Date).date().not_null())
+                    .col(
+                        ColumnDef::new(DailySpend::ApiKey)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::KeyHash)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::TeamId)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::UserId)
+                            .string()
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(ColumnDef::new(DailySpend::Model).string().not_null())
+                    .col(
+                        ColumnDef::new(DailySpend::Spend)
+                            .double()
+                            .not_null()
+                            .default(0.0),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::PromptTokens)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::CompletionTokens)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::Requests)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(DailySpend::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Target of the spend upsert
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_daily_spend_unique")
+                    .table(DailySpend::Table)
+                    .col(DailySpend::Date)
+                    .col(DailySpend::ApiKey)
+                    .col(DailySpend::KeyHash)
+                    .col(DailySpend::TeamId)
+                    .col(DailySpend::UserId)
+                    .col(DailySpend::Model)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Per-key queries
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_daily_spend_api_key_date")
+                    .table(DailySpend::Table)
+                    .col(DailySpend::ApiKey)
+                    .col(DailySpend::Date)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Loading the spend of key budgets
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_daily_spend_key_hash_date")
+                    .table(DailySpend::Table)
+                    .col(DailySpend::KeyHash)
+                    .col(DailySpend::Date)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DailySpend::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DailySpend {
+    Table,
+    Id,
+    Date,
+    ApiKey,
+    KeyHash,
+    TeamId,
+    UserId,
+    Model,
+    Spend,
+    PromptTokens,
+    CompletionTokens,
+    Requests,
+    UpdatedAt,
+}
@@ -4,6 +4,9 @@ mod m20240101_000001_create_users_table;
 mod m20240101_000002_create_password_reset_tokens_table;
 mod m20240101_000003_create_batches_table;
 mod m20240101_000004_create_user_sessions_table;
+mod m20240301_000001_create_request_logs_table;
+mod m20240301_000002_create_daily_spend_table;
+mod m20240401_000001_create_provider_credentials_table;
 
 /// Database migrator for SeaORM
 pub struct Migrator;
@@ -16,6 +19,9 @@ impl MigratorTrait for Migrator {
             Box::new(m20240101_000002_create_password_reset_tokens_table::Migration),
             Box::new(m20240101_000003_create_batches_table::Migration),
             Box::new(m20240101_000004_create_user_sessions_table::Migration),
+            Box::new(m20240301_000001_create_request_logs_table::Migration),
+            Box::new(m20240301_000002_create_daily_spend_table::Migration),
+            Box::new(m20240401_000001_create_provider_credentials_table::Migration),
         ]
     }
 }
@@ -18,6 +18,7 @@ impl SeaOrmDatabase {
 
     /// Store request metrics
     #[allow(dead_code)] // Reserved for future metrics storage functionality
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "store_metrics"))]
     pub async fn store_metrics(
         &self,
         _metrics: &crate::core::models::metrics::RequestMetrics,
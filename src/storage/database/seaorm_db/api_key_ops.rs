@@ -5,6 +5,7 @@ use super::types::SeaOrmDatabase;
 
 impl SeaOrmDatabase {
     /// Create a new API key
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "create_api_key"))]
     pub async fn create_api_key(
         &self,
         _api_key: &crate::core::models::ApiKey,
@@ -98,6 +99,7 @@ impl SeaOrmDatabase {
     }
 
     /// Update API key usage statistics
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "update_api_key_usage"))]
     pub async fn update_api_key_usage(
         &self,
         _key_id: uuid::Uuid,
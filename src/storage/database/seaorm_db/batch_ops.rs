@@ -7,6 +7,7 @@ use super::types::SeaOrmDatabase;
 
 impl SeaOrmDatabase {
     /// Create a new batch
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "create_batch"))]
     pub async fn create_batch(&self, batch: &crate::core::batch::BatchRequest) -> Result<String> {
         debug!("Creating batch: {}", batch.batch_id);
 
@@ -51,6 +52,7 @@ impl SeaOrmDatabase {
     }
 
     /// Update batch status
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "update_batch_status"))]
     pub async fn update_batch_status(&self, batch_id: &str, status: &str) -> Result<()> {
         debug!("Updating batch status: {} -> {}", batch_id, status);
 
@@ -179,6 +181,7 @@ impl SeaOrmDatabase {
     }
 
     /// Store batch results
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "store_batch_results"))]
     pub async fn store_batch_results(
         &self,
         _batch_id: &str,
@@ -190,6 +193,7 @@ impl SeaOrmDatabase {
     }
 
     /// Update batch progress
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "update_batch_progress"))]
     pub async fn update_batch_progress(
         &self,
         batch_id: &str,
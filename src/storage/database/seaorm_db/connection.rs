@@ -11,17 +11,29 @@ use super::types::{DatabaseBackendType, SeaOrmDatabase};
 
 impl SeaOrmDatabase {
     /// Create a new database connection with automatic SQLite fallback
+    ///
+    /// `sqlite://` URLs select SQLite, with the same migrations and entities
+    /// as PostgreSQL.
     pub async fn new(config: &DatabaseConfig) -> Result<Self> {
+        if config.is_sqlite() {
+            let db = Self::connect_sqlite(config).await?;
+            info!("Database connection established (SQLite)");
+            return Ok(Self {
+                db,
+                backend_type: DatabaseBackendType::SQLite,
+                encryption: None,
+            });
+        }
+
         // Try primary database connection first
         match Self::try_connect(&config.url, config).await {
             Ok(db) => {
-                let backend_type = if config.url.starts_with("sqlite") {
-                    DatabaseBackendType::SQLite
-                } else {
-                    DatabaseBackendType::PostgreSQL
-                };
-                info!("Database connection established ({:?})", backend_type);
-                Ok(Self { db, backend_type })
+                info!("Database connection established (PostgreSQL)");
+                Ok(Self {
+                    db,
+                    backend_type: DatabaseBackendType::PostgreSQL,
+                    encryption: None,
+                })
             }
             Err(e) => {
                 // If PostgreSQL connection fails, try SQLite fallback
@@ -39,6 +51,41 @@ impl SeaOrmDatabase {
         }
     }
 
+    /// Connect to a SQLite database, creating its file if missing
+    async fn connect_sqlite(config: &DatabaseConfig) -> Result<DatabaseConnection> {
+        let in_memory = config.url.contains(":memory:") || config.url.contains("mode=memory");
+        if in_memory {
+            // Every connection would open its own empty in-memory database
+            let config = DatabaseConfig {
+                max_connections: 1,
+                ..config.clone()
+            };
+            return Self::try_connect(&config.url, &config).await;
+        }
+
+        let path = config
+            .url
+            .trim_start_matches("sqlite:")
+            .trim_start_matches("//");
+        let path = path.split('?').next().unwrap_or_default();
+        if let Some(dir) = std::path::Path::new(path).parent()
+            && !dir.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                GatewayError::Internal(format!("Failed to create database directory: {}", e))
+            })?;
+        }
+
+        let url = if config.url.contains("mode=") {
+            config.url.clone()
+        } else if config.url.contains('?') {
+            format!("{}&mode=rwc", config.url)
+        } else {
+            format!("{}?mode=rwc", config.url)
+        };
+        Self::try_connect(&url, config).await
+    }
+
     /// Try to connect to a database
     async fn try_connect(url: &str, config: &DatabaseConfig) -> Result<DatabaseConnection> {
         let mut opt = ConnectOptions::new(url.to_string());
@@ -85,6 +132,7 @@ impl SeaOrmDatabase {
         Ok(Self {
             db,
             backend_type: DatabaseBackendType::SQLite,
+            encryption: None,
         })
     }
 
@@ -0,0 +1,130 @@
+use crate::storage::encryption::Keyring;
+use crate::utils::error::{GatewayError, Result};
+use sea_orm::*;
+use serde::Serialize;
+use tracing::info;
+
+use super::super::entities::{self, provider_credential, request_log};
+use super::types::SeaOrmDatabase;
+
+/// Rows re-encrypted per query
+const ROTATION_BATCH_SIZE: u64 = 500;
+
+/// Rows re-encrypted with the active key, by table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct EncryptionRotation {
+    /// Request log entries
+    pub request_logs: u64,
+    /// Provider credentials
+    pub provider_credentials: u64,
+}
+
+impl SeaOrmDatabase {
+    /// Encrypt sensitive columns with the given keys, if any
+    pub fn with_encryption(mut self, keyring: Option<Keyring>) -> Self {
+        self.encryption = keyring;
+        self
+    }
+
+    /// Encrypt a sensitive value for storage, if encryption is enabled
+    pub(super) fn encrypt_value(&self, value: String) -> Result<String> {
+        match &self.encryption {
+            Some(keyring) => keyring.encrypt(&value),
+            None => Ok(value),
+        }
+    }
+
+    /// Decrypt a stored sensitive value
+    pub(super) fn decrypt_value(&self, value: String) -> Result<String> {
+        match &self.encryption {
+            Some(keyring) => keyring.decrypt(&value),
+            None if Keyring::is_encrypted(&value) => Err(GatewayError::Crypto(
+                "Value is encrypted but encryption is not enabled".to_string(),
+            )),
+            None => Ok(value),
+        }
+    }
+
+    /// Re-encrypt values not encrypted with the active key
+    ///
+    /// Covers values encrypted with older keys and values stored before
+    /// encryption was enabled. Older keys can be removed once this succeeds.
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "rotate_encryption"))]
+    pub async fn rotate_encryption(&self) -> Result<EncryptionRotation> {
+        let keyring = self
+            .encryption
+            .as_ref()
+            .ok_or_else(|| GatewayError::Config("Encryption is not enabled".to_string()))?;
+        let reencrypt = |value: String| keyring.encrypt(&keyring.decrypt(&value)?);
+        let current = format!("{}%", keyring.active_prefix());
+        let mut rotation = EncryptionRotation::default();
+
+        let stale_payload = |column: request_log::Column| {
+            column.is_not_null().and(column.not_like(current.as_str()))
+        };
+        loop {
+            let models = entities::RequestLog::find()
+                .filter(
+                    Condition::any()
+                        .add(stale_payload(request_log::Column::RequestPayload))
+                        .add(stale_payload(request_log::Column::ResponsePayload)),
+                )
+                .limit(ROTATION_BATCH_SIZE)
+                .all(&self.db)
+                .await
+                .map_err(GatewayError::Database)?;
+            let batch = models.len() as u64;
+
+            for model in models {
+                request_log::ActiveModel {
+                    id: Unchanged(model.id),
+                    request_payload: Set(model.request_payload.map(reencrypt).transpose()?),
+                    response_payload: Set(model.response_payload.map(reencrypt).transpose()?),
+                    ..Default::default()
+                }
+                .update(&self.db)
+                .await
+                .map_err(GatewayError::Database)?;
+            }
+
+            rotation.request_logs += batch;
+            if batch < ROTATION_BATCH_SIZE {
+                break;
+            }
+        }
+
+        loop {
+            let models = entities::ProviderCredential::find()
+                .filter(provider_credential::Column::ApiKey.not_like(current.as_str()))
+                .limit(ROTATION_BATCH_SIZE)
+                .all(&self.db)
+                .await
+                .map_err(GatewayError::Database)?;
+            let batch = models.len() as u64;
+
+            for model in models {
+                provider_credential::ActiveModel {
+                    provider: Unchanged(model.provider),
+                    api_key: Set(reencrypt(model.api_key)?),
+                    ..Default::default()
+                }
+                .update(&self.db)
+                .await
+                .map_err(GatewayError::Database)?;
+            }
+
+            rotation.provider_credentials += batch;
+            if batch < ROTATION_BATCH_SIZE {
+                break;
+            }
+        }
+
+        info!(
+            "Re-encrypted {} request log entries and {} provider credentials with key {}",
+            rotation.request_logs,
+            rotation.provider_credentials,
+            keyring.active_key()
+        );
+        Ok(rotation)
+    }
+}
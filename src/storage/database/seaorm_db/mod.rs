@@ -3,9 +3,14 @@ mod analytics_ops;
 mod api_key_ops;
 mod batch_ops;
 mod connection;
+mod encryption_ops;
+mod provider_credential_ops;
+mod request_log_ops;
+mod spend_ops;
 mod token_ops;
 mod types;
 mod user_ops;
 
 // Re-export public types
+pub use encryption_ops::EncryptionRotation;
 pub use types::{DatabaseBackendType, DatabaseStats, SeaOrmDatabase};
@@ -0,0 +1,85 @@
+use crate::config::ProviderConfig;
+use crate::utils::error::{GatewayError, Result};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use tracing::debug;
+
+use super::super::entities::{self, provider_credential};
+use super::types::SeaOrmDatabase;
+
+impl SeaOrmDatabase {
+    /// Store a provider's API key, replacing any stored before
+    ///
+    /// Keys are only stored encrypted, so encryption must be enabled.
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "store_provider_credential"))]
+    pub async fn store_provider_credential(&self, provider: &str, api_key: &str) -> Result<()> {
+        if self.encryption.is_none() {
+            return Err(GatewayError::Config(
+                "Provider credentials are only stored with encryption enabled".to_string(),
+            ));
+        }
+        debug!("Storing credential of provider {}", provider);
+
+        let now = chrono::Utc::now();
+        let model = provider_credential::ActiveModel {
+            provider: Set(provider.to_string()),
+            api_key: Set(self.encrypt_value(api_key.to_string())?),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+        };
+
+        entities::ProviderCredential::insert(model)
+            .on_conflict(
+                OnConflict::column(provider_credential::Column::Provider)
+                    .update_columns([
+                        provider_credential::Column::ApiKey,
+                        provider_credential::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(())
+    }
+
+    /// Get a provider's stored API key
+    pub async fn get_provider_credential(&self, provider: &str) -> Result<Option<String>> {
+        let model = entities::ProviderCredential::find_by_id(provider.to_string())
+            .one(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        model
+            .map(|model| self.decrypt_value(model.api_key))
+            .transpose()
+    }
+
+    /// Delete a provider's stored API key, returning whether one was stored
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "delete_provider_credential"))]
+    pub async fn delete_provider_credential(&self, provider: &str) -> Result<bool> {
+        let result = entities::ProviderCredential::delete_by_id(provider.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Fill the API key of every provider configured without one from its
+    /// stored credential, returning how many were filled
+    pub async fn fill_provider_credentials(
+        &self,
+        providers: &mut [ProviderConfig],
+    ) -> Result<usize> {
+        let mut filled = 0;
+        for provider in providers.iter_mut().filter(|p| p.api_key.is_empty()) {
+            if let Some(api_key) = self.get_provider_credential(&provider.name).await? {
+                provider.api_key = api_key;
+                filled += 1;
+            }
+        }
+        Ok(filled)
+    }
+}
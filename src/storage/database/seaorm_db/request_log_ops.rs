@@ -0,0 +1,134 @@
+use crate::core::models::metrics::RequestLogEntry;
+use crate::utils::error::{GatewayError, Result};
+use sea_orm::*;
+use tracing::{debug, warn};
+
+use super::super::entities::{self, request_log};
+use super::types::SeaOrmDatabase;
+
+impl SeaOrmDatabase {
+    /// Store request log entries
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "insert_request_logs"))]
+    pub async fn insert_request_logs(&self, entries: &[RequestLogEntry]) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        debug!("Storing {} request log entries", entries.len());
+
+        let mut models = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let encrypt_payload = |payload: &Option<serde_json::Value>| {
+                payload
+                    .as_ref()
+                    .map(|payload| self.encrypt_value(payload.to_string()))
+                    .transpose()
+            };
+            models.push(request_log::ActiveModel {
+                id: Set(uuid::Uuid::new_v4().to_string()),
+                request_id: Set(entry.request_id.clone()),
+                key_alias: Set(entry.key_alias.clone()),
+                method: Set(entry.method.clone()),
+                path: Set(entry.path.clone()),
+                model: Set(entry.model.clone()),
+                provider: Set(entry.provider.clone()),
+                prompt_tokens: Set(entry.prompt_tokens as i32),
+                completion_tokens: Set(entry.completion_tokens as i32),
+                total_tokens: Set(entry.total_tokens() as i32),
+                cost: Set(entry.cost),
+                latency_ms: Set(entry.latency_ms as i64),
+                status_code: Set(entry.status_code as i32),
+                error: Set(entry.error.clone()),
+                request_payload: Set(encrypt_payload(&entry.request_payload)?),
+                response_payload: Set(encrypt_payload(&entry.response_payload)?),
+                created_at: Set(entry.created_at.into()),
+            });
+        }
+
+        entities::RequestLog::insert_many(models)
+            .exec(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(())
+    }
+
+    /// List the most recent request log entries
+    pub async fn list_request_logs(&self, limit: u64) -> Result<Vec<RequestLogEntry>> {
+        let models = entities::RequestLog::find()
+            .order_by_desc(request_log::Column::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(models
+            .into_iter()
+            .map(|model| RequestLogEntry {
+                request_id: model.request_id,
+                key_alias: model.key_alias,
+                method: model.method,
+                path: model.path,
+                model: model.model,
+                provider: model.provider,
+                prompt_tokens: model.prompt_tokens as u32,
+                completion_tokens: model.completion_tokens as u32,
+                cost: model.cost,
+                latency_ms: model.latency_ms as u64,
+                status_code: model.status_code as u16,
+                error: model.error,
+                request_payload: self.decode_payload(model.request_payload),
+                response_payload: self.decode_payload(model.response_payload),
+                created_at: model.created_at.into(),
+            })
+            .collect())
+    }
+
+    /// Decrypt and parse a stored payload, dropping it if that fails
+    fn decode_payload(&self, payload: Option<String>) -> Option<serde_json::Value> {
+        let payload = match self.decrypt_value(payload?) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("Failed to decrypt request log payload: {}", e);
+                return None;
+            }
+        };
+        serde_json::from_str(&payload).ok()
+    }
+
+    /// Delete up to `limit` request log entries created before a cutoff
+    ///
+    /// Returns the number of entries deleted; fewer than `limit` means none
+    /// are left before the cutoff.
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "delete_request_logs"))]
+    pub async fn delete_request_logs_before(
+        &self,
+        cutoff: chrono::DateTime<chrono::Utc>,
+        limit: u64,
+    ) -> Result<u64> {
+        debug!(
+            "Deleting up to {} request logs created before {}",
+            limit, cutoff
+        );
+
+        let ids: Vec<String> = entities::RequestLog::find()
+            .select_only()
+            .column(request_log::Column::Id)
+            .filter(request_log::Column::CreatedAt.lt(cutoff))
+            .limit(limit)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = entities::RequestLog::delete_many()
+            .filter(request_log::Column::Id.is_in(ids))
+            .exec(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,151 @@
+use crate::core::models::metrics::{SpendFilter, SpendRecord};
+use crate::utils::error::{GatewayError, Result};
+use chrono::NaiveDate;
+use sea_orm::sea_query::{Alias, Expr, OnConflict};
+use sea_orm::*;
+use tracing::debug;
+
+use super::super::entities::{self, daily_spend};
+use super::types::SeaOrmDatabase;
+
+impl SeaOrmDatabase {
+    /// Add spend to the daily totals
+    ///
+    /// Records for a day, key, team, user and model that already has a row
+    /// are added to it.
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "upsert_spend"))]
+    pub async fn upsert_spend(&self, records: &[SpendRecord]) -> Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        debug!("Storing {} spend records", records.len());
+
+        let now = chrono::Utc::now();
+        let models = records.iter().map(|record| daily_spend::ActiveModel {
+            id: NotSet,
+            date: Set(record.date),
+            api_key: Set(record.api_key.clone().unwrap_or_default()),
+            key_hash: Set(record.key_hash.clone().unwrap_or_default()),
+            team_id: Set(record.team_id.clone().unwrap_or_default()),
+            user_id: Set(record.user_id.clone().unwrap_or_default()),
+            model: Set(record.model.clone()),
+            spend: Set(record.spend),
+            prompt_tokens: Set(record.prompt_tokens as i64),
+            completion_tokens: Set(record.completion_tokens as i64),
+            requests: Set(record.requests as i64),
+            updated_at: Set(now.into()),
+        });
+
+        let mut on_conflict = OnConflict::columns([
+            daily_spend::Column::Date,
+            daily_spend::Column::ApiKey,
+            daily_spend::Column::KeyHash,
+            daily_spend::Column::TeamId,
+            daily_spend::Column::UserId,
+            daily_spend::Column::Model,
+        ]);
+        for column in [
+            daily_spend::Column::Spend,
+            daily_spend::Column::PromptTokens,
+            daily_spend::Column::CompletionTokens,
+            daily_spend::Column::Requests,
+        ] {
+            on_conflict.value(
+                column,
+                Expr::col((daily_spend::Entity, column))
+                    .add(Expr::col((Alias::new("excluded"), column))),
+            );
+        }
+        on_conflict.update_column(daily_spend::Column::UpdatedAt);
+
+        entities::DailySpend::insert_many(models)
+            .on_conflict(on_conflict)
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(())
+    }
+
+    /// List daily spend rows matching a filter, oldest first
+    pub async fn list_spend(&self, filter: &SpendFilter) -> Result<Vec<SpendRecord>> {
+        let mut query = entities::DailySpend::find();
+        if let Some(start) = filter.start_date {
+            query = query.filter(daily_spend::Column::Date.gte(start));
+        }
+        if let Some(end) = filter.end_date {
+            query = query.filter(daily_spend::Column::Date.lte(end));
+        }
+        if let Some(api_key) = &filter.api_key {
+            query = query.filter(daily_spend::Column::ApiKey.eq(api_key));
+        }
+        if let Some(key_hash) = &filter.key_hash {
+            query = query.filter(daily_spend::Column::KeyHash.eq(key_hash));
+        }
+        if let Some(team_id) = &filter.team_id {
+            query = query.filter(daily_spend::Column::TeamId.eq(team_id));
+        }
+        if let Some(user_id) = &filter.user_id {
+            query = query.filter(daily_spend::Column::UserId.eq(user_id));
+        }
+        if let Some(model) = &filter.model {
+            query = query.filter(daily_spend::Column::Model.eq(model));
+        }
+
+        let models = query
+            .order_by_asc(daily_spend::Column::Date)
+            .all(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        let non_empty = |s: String| (!s.is_empty()).then_some(s);
+        Ok(models
+            .into_iter()
+            .map(|model| SpendRecord {
+                date: model.date,
+                api_key: non_empty(model.api_key),
+                key_hash: non_empty(model.key_hash),
+                team_id: non_empty(model.team_id),
+                user_id: non_empty(model.user_id),
+                model: model.model,
+                spend: model.spend,
+                prompt_tokens: model.prompt_tokens as u64,
+                completion_tokens: model.completion_tokens as u64,
+                requests: model.requests as u64,
+            })
+            .collect())
+    }
+
+    /// Delete up to `limit` daily spend rows dated before a cutoff
+    ///
+    /// Returns the number of rows deleted; fewer than `limit` means none are
+    /// left before the cutoff.
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "delete_spend"))]
+    pub async fn delete_spend_before(&self, cutoff: NaiveDate, limit: u64) -> Result<u64> {
+        debug!(
+            "Deleting up to {} spend rows dated before {}",
+            limit, cutoff
+        );
+
+        let ids: Vec<i32> = entities::DailySpend::find()
+            .select_only()
+            .column(daily_spend::Column::Id)
+            .filter(daily_spend::Column::Date.lt(cutoff))
+            .limit(limit)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = entities::DailySpend::delete_many()
+            .filter(daily_spend::Column::Id.is_in(ids))
+            .exec(&self.db)
+            .await
+            .map_err(GatewayError::Database)?;
+
+        Ok(result.rows_affected)
+    }
+}
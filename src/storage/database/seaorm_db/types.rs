@@ -1,3 +1,4 @@
+use crate::storage::encryption::Keyring;
 use sea_orm::DatabaseConnection;
 
 /// SeaORM-based database implementation
@@ -6,6 +7,8 @@ pub struct SeaOrmDatabase {
     pub(super) db: DatabaseConnection,
     /// Backend type indicator
     pub(super) backend_type: DatabaseBackendType,
+    /// Keys encrypting sensitive columns (None stores them unencrypted)
+    pub(super) encryption: Option<Keyring>,
 }
 
 /// Database backend type indicator
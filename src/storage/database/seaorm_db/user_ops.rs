@@ -46,6 +46,7 @@ impl SeaOrmDatabase {
     }
 
     /// Create a new user
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "create_user"))]
     pub async fn create_user(&self, user: &User) -> Result<User> {
         debug!("Creating user: {}", user.username);
 
@@ -61,6 +62,7 @@ impl SeaOrmDatabase {
     }
 
     /// Update user password
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "update_user_password"))]
     pub async fn update_user_password(
         &self,
         user_id: uuid::Uuid,
@@ -86,6 +88,7 @@ impl SeaOrmDatabase {
     }
 
     /// Update user last login
+    #[tracing::instrument(name = "db.write", skip_all, fields(db.operation = "update_user_last_login"))]
     pub async fn update_user_last_login(&self, user_id: uuid::Uuid) -> Result<()> {
         debug!("Updating last login for user: {}", user_id);
 
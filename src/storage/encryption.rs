@@ -0,0 +1,218 @@
+//! Encryption of sensitive values at rest
+//!
+//! Values are encrypted with AES-256-GCM and stored as `enc:<key id>:<data>`,
+//! so the key that decrypts a value is still known after the active key is
+//! rotated. Values without the prefix were stored before encryption was
+//! enabled and are read as they are.
+
+use crate::config::EncryptionConfig;
+use crate::utils::auth::crypto::encryption::{decrypt_data, encrypt_data};
+use crate::utils::error::{GatewayError, Result};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Prefix of encrypted values
+const PREFIX: &str = "enc:";
+
+/// Keys encrypting values at rest, one of them active
+#[derive(Clone)]
+pub struct Keyring {
+    active: String,
+    keys: HashMap<String, Vec<u8>>,
+}
+
+impl fmt::Debug for Keyring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ids: Vec<&String> = self.keys.keys().collect();
+        ids.sort();
+        f.debug_struct("Keyring")
+            .field("active", &self.active)
+            .field("keys", &ids)
+            .finish()
+    }
+}
+
+impl Keyring {
+    /// Create a keyring from key IDs and key material
+    pub fn new(
+        active: impl Into<String>,
+        keys: impl IntoIterator<Item = (String, Vec<u8>)>,
+    ) -> Result<Self> {
+        let active = active.into();
+        let keys: HashMap<String, Vec<u8>> = keys.into_iter().collect();
+        if !keys.contains_key(&active) {
+            return Err(GatewayError::Config(format!(
+                "Active encryption key {} is not configured",
+                active
+            )));
+        }
+        Ok(Self { active, keys })
+    }
+
+    /// Load the configured keys, if encryption is enabled
+    ///
+    /// Key files are read once, with surrounding whitespace trimmed, and
+    /// KMS-encrypted keys are decrypted once.
+    pub async fn from_config(config: &EncryptionConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let mut keys = Vec::with_capacity(config.keys.len());
+        for key in &config.keys {
+            let material = match (&key.key, &key.key_file, &key.kms) {
+                (Some(material), _, _) => material.clone().into_bytes(),
+                (None, Some(path), _) => std::fs::read_to_string(path)
+                    .map_err(|e| {
+                        GatewayError::Config(format!(
+                            "Failed to read encryption key {} from {}: {}",
+                            key.id, path, e
+                        ))
+                    })?
+                    .trim()
+                    .as_bytes()
+                    .to_vec(),
+                (None, None, Some(kms)) => super::kms::decrypt_data_key(&key.id, kms).await?,
+                (None, None, None) => {
+                    return Err(GatewayError::Config(format!(
+                        "Encryption key {} has no key material",
+                        key.id
+                    )));
+                }
+            };
+            keys.push((key.id.clone(), material));
+        }
+
+        Self::new(config.active_key.clone(), keys).map(Some)
+    }
+
+    /// Whether a stored value is encrypted
+    pub fn is_encrypted(value: &str) -> bool {
+        value.starts_with(PREFIX)
+    }
+
+    /// ID of the key new values are encrypted with
+    pub fn active_key(&self) -> &str {
+        &self.active
+    }
+
+    /// Prefix of values encrypted with the active key
+    pub fn active_prefix(&self) -> String {
+        format!("{}{}:", PREFIX, self.active)
+    }
+
+    /// Encrypt a value with the active key
+    pub fn encrypt(&self, value: &str) -> Result<String> {
+        let data = encrypt_data(&self.keys[&self.active], value)?;
+        Ok(format!("{}{}", self.active_prefix(), data))
+    }
+
+    /// Decrypt a stored value, returning values stored unencrypted as is
+    pub fn decrypt(&self, value: &str) -> Result<String> {
+        let Some(encrypted) = value.strip_prefix(PREFIX) else {
+            return Ok(value.to_string());
+        };
+        let (id, data) = encrypted
+            .split_once(':')
+            .ok_or_else(|| GatewayError::Crypto("Malformed encrypted value".to_string()))?;
+        let key = self
+            .keys
+            .get(id)
+            .ok_or_else(|| GatewayError::Crypto(format!("Unknown encryption key {}", id)))?;
+        decrypt_data(key, data)
+    }
+
+    /// Whether a stored value is encrypted with the active key
+    pub fn is_current(&self, value: &str) -> bool {
+        value.starts_with(&self.active_prefix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::EncryptionKeyConfig;
+
+    fn keyring(active: &str) -> Keyring {
+        Keyring::new(
+            active,
+            [
+                (
+                    "v1".to_string(),
+                    b"first-key-material-of-32-bytes!!".to_vec(),
+                ),
+                (
+                    "v2".to_string(),
+                    b"second-key-material-of-32-bytes!".to_vec(),
+                ),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let keyring = keyring("v1");
+        let encrypted = keyring.encrypt("sk-secret").unwrap();
+        assert!(encrypted.starts_with("enc:v1:"));
+        assert!(!encrypted.contains("sk-secret"));
+        assert!(keyring.is_current(&encrypted));
+        assert_eq!(keyring.decrypt(&encrypted).unwrap(), "sk-secret");
+    }
+
+    #[test]
+    fn test_decrypt_after_rotation() {
+        let encrypted = keyring("v1").encrypt("sk-secret").unwrap();
+
+        let rotated = keyring("v2");
+        assert!(!rotated.is_current(&encrypted));
+        assert_eq!(rotated.decrypt(&encrypted).unwrap(), "sk-secret");
+
+        let removed = Keyring::new("v2", [("v2".to_string(), b"key".to_vec())]).unwrap();
+        assert!(removed.decrypt(&encrypted).is_err());
+    }
+
+    #[test]
+    fn test_plaintext_values_are_read_as_is() {
+        let keyring = keyring("v1");
+        assert_eq!(keyring.decrypt("{\"a\":1}").unwrap(), "{\"a\":1}");
+        assert!(!keyring.is_current("{\"a\":1}"));
+        assert!(keyring.decrypt("enc:malformed").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_from_config_reads_key_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("key");
+        std::fs::write(&path, "file-key-material-of-32-bytes!!!\n").unwrap();
+        let config = EncryptionConfig {
+            enabled: true,
+            active_key: "file".to_string(),
+            keys: vec![EncryptionKeyConfig {
+                id: "file".to_string(),
+                key: None,
+                key_file: Some(path.to_string_lossy().into_owned()),
+                kms: None,
+            }],
+        };
+
+        let keyring = Keyring::from_config(&config).await.unwrap().unwrap();
+        let encrypted = keyring.encrypt("value").unwrap();
+        let inline = Keyring::new(
+            "file",
+            [(
+                "file".to_string(),
+                b"file-key-material-of-32-bytes!!!".to_vec(),
+            )],
+        )
+        .unwrap();
+        assert_eq!(inline.decrypt(&encrypted).unwrap(), "value");
+
+        assert!(
+            Keyring::from_config(&EncryptionConfig::default())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::config::S3Config;
 use crate::utils::error::{GatewayError, Result};
+use std::time::Duration;
 #[cfg(feature = "s3")]
 use tracing::debug;
 use tracing::info;
@@ -15,12 +16,27 @@ use aws_sdk_s3 as aws_s3;
 
 use super::types::FileMetadata;
 
+/// Object metadata holding the original filename
+#[cfg(feature = "s3")]
+const FILENAME_METADATA: &str = "filename";
+/// Object metadata holding the SHA-256 checksum of the content
+#[cfg(feature = "s3")]
+const CHECKSUM_METADATA: &str = "checksum";
+
 /// S3 file storage
+///
+/// Files are stored as objects keyed by their ID under an optional prefix, with
+/// the original filename and checksum kept in the object metadata.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct S3Storage {
     bucket: String,
     region: String,
+    /// Key prefix without surrounding slashes, empty for the bucket root
+    prefix: String,
+    server_side_encryption: Option<String>,
+    sse_kms_key_id: Option<String>,
+    presigned_url_ttl: Duration,
     #[cfg(feature = "s3")]
     client: Option<aws_s3::Client>,
     #[cfg(not(feature = "s3"))]
@@ -37,61 +53,111 @@ impl S3Storage {
         );
 
         #[cfg(feature = "s3")]
-        {
-            use aws_s3::config::Region;
-
-            let region = Region::new(config.region.clone());
-            let aws_config = aws_config::defaults(aws_config::BehaviorVersion::latest())
-                .region(region)
-                .load()
-                .await;
+        let client = {
+            use aws_s3::config::{Credentials, Region};
 
-            let client = aws_s3::Client::new(&aws_config);
+            let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
+                .region(Region::new(config.region.clone()));
+            if !config.access_key_id.is_empty() {
+                loader = loader.credentials_provider(Credentials::new(
+                    &config.access_key_id,
+                    &config.secret_access_key,
+                    None,
+                    None,
+                    "gateway-config",
+                ));
+            }
+            if let Some(endpoint) = &config.endpoint {
+                loader = loader.endpoint_url(endpoint);
+            }
+            let aws_config = loader.load().await;
 
-            Ok(Self {
-                bucket: config.bucket.clone(),
-                region: config.region.clone(),
-                client: Some(client),
-            })
-        }
+            // S3-compatible services generally only support path-style addressing
+            let s3_config = aws_s3::config::Builder::from(&aws_config)
+                .force_path_style(config.endpoint.is_some())
+                .build();
+            Some(aws_s3::Client::from_conf(s3_config))
+        };
 
         #[cfg(not(feature = "s3"))]
-        {
-            Ok(Self {
-                bucket: config.bucket.clone(),
-                region: config.region.clone(),
-                client: None,
-            })
+        let client = None;
+
+        Ok(Self {
+            bucket: config.bucket.clone(),
+            region: config.region.clone(),
+            prefix: config
+                .prefix
+                .as_deref()
+                .unwrap_or_default()
+                .trim_matches('/')
+                .to_string(),
+            server_side_encryption: config.server_side_encryption.clone(),
+            sse_kms_key_id: config.sse_kms_key_id.clone(),
+            presigned_url_ttl: Duration::from_secs(config.presigned_url_ttl),
+            client,
+        })
+    }
+
+    /// Object key of a file
+    pub(crate) fn object_key(&self, file_id: &str) -> String {
+        if self.prefix.is_empty() {
+            file_id.to_string()
+        } else {
+            format!("{}/{}", self.prefix, file_id)
         }
     }
 
+    /// File ID of an object key under the prefix
+    pub(crate) fn file_id(&self, key: &str) -> Option<String> {
+        let file_id = if self.prefix.is_empty() {
+            key
+        } else {
+            key.strip_prefix(&self.prefix)?.strip_prefix('/')?
+        };
+        (!file_id.is_empty()).then(|| file_id.to_string())
+    }
+
+    #[cfg(feature = "s3")]
+    fn client(&self) -> Result<&aws_s3::Client> {
+        self.client
+            .as_ref()
+            .ok_or_else(|| GatewayError::FileStorage("S3 client not initialized".to_string()))
+    }
+
     /// Store a file to S3
     #[allow(unused_variables)]
     pub async fn store(&self, filename: &str, content: &[u8]) -> Result<String> {
         #[cfg(feature = "s3")]
         {
-            if let Some(client) = &self.client {
-                use aws_s3::primitives::ByteStream;
+            use super::local::LocalStorage;
+            use aws_s3::primitives::ByteStream;
+            use aws_s3::types::ServerSideEncryption;
+            use sha2::{Digest, Sha256};
 
-                let file_id = Uuid::new_v4().to_string();
-                let key = format!("{}/{}", file_id, filename);
+            let client = self.client()?;
+            let file_id = Uuid::new_v4().to_string();
+            let key = self.object_key(&file_id);
 
-                client
-                    .put_object()
-                    .bucket(&self.bucket)
-                    .key(&key)
-                    .body(ByteStream::from(content.to_vec()))
-                    .send()
-                    .await
-                    .map_err(|e| GatewayError::FileStorage(format!("S3 upload failed: {}", e)))?;
-
-                debug!("File uploaded to S3: {}", key);
-                Ok(file_id)
-            } else {
-                Err(GatewayError::FileStorage(
-                    "S3 client not initialized".to_string(),
-                ))
-            }
+            client
+                .put_object()
+                .bucket(&self.bucket)
+                .key(&key)
+                .content_type(LocalStorage::detect_content_type(filename))
+                .metadata(FILENAME_METADATA, filename)
+                .metadata(CHECKSUM_METADATA, hex::encode(Sha256::digest(content)))
+                .set_server_side_encryption(
+                    self.server_side_encryption
+                        .as_deref()
+                        .map(ServerSideEncryption::from),
+                )
+                .set_ssekms_key_id(self.sse_kms_key_id.clone())
+                .body(ByteStream::from(content.to_vec()))
+                .send()
+                .await
+                .map_err(|e| GatewayError::FileStorage(format!("S3 upload failed: {}", e)))?;
+
+            debug!("File uploaded to S3: {}", key);
+            Ok(file_id)
         }
 
         #[cfg(not(feature = "s3"))]
@@ -107,25 +173,26 @@ impl S3Storage {
     pub async fn get(&self, file_id: &str) -> Result<Vec<u8>> {
         #[cfg(feature = "s3")]
         {
-            if let Some(client) = &self.client {
-                let result = client
-                    .get_object()
-                    .bucket(&self.bucket)
-                    .key(file_id)
-                    .send()
-                    .await
-                    .map_err(|e| GatewayError::FileStorage(format!("S3 download failed: {}", e)))?;
-
-                let bytes = result.body.collect().await.map_err(|e| {
-                    GatewayError::FileStorage(format!("Failed to read S3 content: {}", e))
+            let result = self
+                .client()?
+                .get_object()
+                .bucket(&self.bucket)
+                .key(self.object_key(file_id))
+                .send()
+                .await
+                .map_err(|e| {
+                    if e.as_service_error().is_some_and(|e| e.is_no_such_key()) {
+                        GatewayError::NotFound(format!("File not found: {}", file_id))
+                    } else {
+                        GatewayError::FileStorage(format!("S3 download failed: {}", e))
+                    }
                 })?;
 
-                Ok(bytes.to_vec())
-            } else {
-                Err(GatewayError::FileStorage(
-                    "S3 client not initialized".to_string(),
-                ))
-            }
+            let bytes = result.body.collect().await.map_err(|e| {
+                GatewayError::FileStorage(format!("Failed to read S3 content: {}", e))
+            })?;
+
+            Ok(bytes.to_vec())
         }
 
         #[cfg(not(feature = "s3"))]
@@ -141,22 +208,132 @@ impl S3Storage {
     pub async fn delete(&self, file_id: &str) -> Result<()> {
         #[cfg(feature = "s3")]
         {
-            if let Some(client) = &self.client {
-                client
-                    .delete_object()
+            self.client()?
+                .delete_object()
+                .bucket(&self.bucket)
+                .key(self.object_key(file_id))
+                .send()
+                .await
+                .map_err(|e| GatewayError::FileStorage(format!("S3 deletion failed: {}", e)))?;
+
+            debug!("File deleted from S3: {}", file_id);
+            Ok(())
+        }
+
+        #[cfg(not(feature = "s3"))]
+        {
+            Err(GatewayError::FileStorage(
+                "S3 feature not enabled".to_string(),
+            ))
+        }
+    }
+
+    /// Check if a file exists
+    pub async fn exists(&self, file_id: &str) -> Result<bool> {
+        match self.metadata(file_id).await {
+            Ok(_) => Ok(true),
+            Err(GatewayError::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get file metadata from the object metadata
+    #[allow(unused_variables)]
+    pub async fn metadata(&self, file_id: &str) -> Result<FileMetadata> {
+        #[cfg(feature = "s3")]
+        {
+            let head = self
+                .client()?
+                .head_object()
+                .bucket(&self.bucket)
+                .key(self.object_key(file_id))
+                .send()
+                .await
+                .map_err(|e| {
+                    if e.as_service_error().is_some_and(|e| e.is_not_found()) {
+                        GatewayError::NotFound(format!("File not found: {}", file_id))
+                    } else {
+                        GatewayError::FileStorage(format!("S3 head request failed: {}", e))
+                    }
+                })?;
+
+            let object_metadata = head.metadata();
+            let value = |name: &str| {
+                object_metadata
+                    .and_then(|metadata| metadata.get(name))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            let created_at = head
+                .last_modified()
+                .and_then(|time| chrono::DateTime::from_timestamp(time.secs(), time.subsec_nanos()))
+                .unwrap_or_else(chrono::Utc::now);
+
+            Ok(FileMetadata {
+                id: file_id.to_string(),
+                filename: value(FILENAME_METADATA),
+                content_type: head
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string(),
+                size: head.content_length().unwrap_or_default().max(0) as u64,
+                created_at,
+                checksum: value(CHECKSUM_METADATA),
+            })
+        }
+
+        #[cfg(not(feature = "s3"))]
+        {
+            Err(GatewayError::FileStorage(
+                "S3 feature not enabled".to_string(),
+            ))
+        }
+    }
+
+    /// List file IDs starting with a prefix
+    #[allow(unused_variables)]
+    pub async fn list(&self, prefix: Option<&str>, limit: Option<usize>) -> Result<Vec<String>> {
+        #[cfg(feature = "s3")]
+        {
+            let client = self.client()?;
+            let key_prefix = self.object_key(prefix.unwrap_or_default());
+            let mut files = Vec::new();
+            let mut continuation_token = None;
+
+            loop {
+                let remaining = limit.map(|limit| limit.saturating_sub(files.len()));
+                if remaining == Some(0) {
+                    break;
+                }
+
+                let output = client
+                    .list_objects_v2()
                     .bucket(&self.bucket)
-                    .key(file_id)
+                    .prefix(&key_prefix)
+                    .set_max_keys(remaining.map(|remaining| remaining.min(1000) as i32))
+                    .set_continuation_token(continuation_token.take())
                     .send()
                     .await
-                    .map_err(|e| GatewayError::FileStorage(format!("S3 deletion failed: {}", e)))?;
-
-                debug!("File deleted from S3: {}", file_id);
-                Ok(())
-            } else {
-                Err(GatewayError::FileStorage(
-                    "S3 client not initialized".to_string(),
-                ))
+                    .map_err(|e| GatewayError::FileStorage(format!("S3 listing failed: {}", e)))?;
+
+                files.extend(
+                    output
+                        .contents()
+                        .iter()
+                        .filter_map(|object| object.key())
+                        .filter_map(|key| self.file_id(key)),
+                );
+
+                match output.next_continuation_token() {
+                    Some(token) => continuation_token = Some(token.to_string()),
+                    None => break,
+                }
             }
+
+            if let Some(limit) = limit {
+                files.truncate(limit);
+            }
+            Ok(files)
         }
 
         #[cfg(not(feature = "s3"))]
@@ -167,35 +344,63 @@ impl S3Storage {
         }
     }
 
-    /// Check if file exists (placeholder implementation)
-    pub async fn exists(&self, _file_id: &str) -> Result<bool> {
-        Err(GatewayError::FileStorage(
-            "S3 storage not implemented yet".to_string(),
-        ))
-    }
+    /// Presigned URL downloading a file without credentials
+    #[allow(unused_variables)]
+    pub async fn presigned_url(
+        &self,
+        file_id: &str,
+        expires_in: Option<Duration>,
+    ) -> Result<String> {
+        #[cfg(feature = "s3")]
+        {
+            use aws_s3::presigning::PresigningConfig;
 
-    /// Get file metadata (placeholder implementation)
-    pub async fn metadata(&self, _file_id: &str) -> Result<FileMetadata> {
-        Err(GatewayError::FileStorage(
-            "S3 storage not implemented yet".to_string(),
-        ))
-    }
+            let config = PresigningConfig::expires_in(expires_in.unwrap_or(self.presigned_url_ttl))
+                .map_err(|e| {
+                    GatewayError::FileStorage(format!("Invalid presigned URL expiry: {}", e))
+                })?;
+            let request = self
+                .client()?
+                .get_object()
+                .bucket(&self.bucket)
+                .key(self.object_key(file_id))
+                .presigned(config)
+                .await
+                .map_err(|e| GatewayError::FileStorage(format!("S3 presigning failed: {}", e)))?;
+
+            Ok(request.uri().to_string())
+        }
 
-    /// List files (placeholder implementation)
-    pub async fn list(&self, _prefix: Option<&str>, _limit: Option<usize>) -> Result<Vec<String>> {
-        Err(GatewayError::FileStorage(
-            "S3 storage not implemented yet".to_string(),
-        ))
+        #[cfg(not(feature = "s3"))]
+        {
+            Err(GatewayError::FileStorage(
+                "S3 feature not enabled".to_string(),
+            ))
+        }
     }
 
-    /// Health check (placeholder implementation)
+    /// Health check that the bucket is reachable
     pub async fn health_check(&self) -> Result<()> {
-        Err(GatewayError::FileStorage(
-            "S3 storage not implemented yet".to_string(),
-        ))
+        #[cfg(feature = "s3")]
+        {
+            self.client()?
+                .head_bucket()
+                .bucket(&self.bucket)
+                .send()
+                .await
+                .map_err(|e| GatewayError::FileStorage(format!("S3 bucket unreachable: {}", e)))?;
+            Ok(())
+        }
+
+        #[cfg(not(feature = "s3"))]
+        {
+            Err(GatewayError::FileStorage(
+                "S3 feature not enabled".to_string(),
+            ))
+        }
     }
 
-    /// Close storage (placeholder implementation)
+    /// Close storage (no-op for S3 storage)
     pub async fn close(&self) -> Result<()> {
         Ok(())
     }
@@ -2,6 +2,7 @@
 
 use crate::config::FileStorageConfig;
 use crate::utils::error::{GatewayError, Result};
+use std::time::Duration;
 use tracing::info;
 
 use super::local::LocalStorage;
@@ -83,6 +84,20 @@ impl FileStorage {
         }
     }
 
+    /// Presigned URL downloading a file, for object storage only
+    pub async fn presigned_url(
+        &self,
+        file_id: &str,
+        expires_in: Option<Duration>,
+    ) -> Result<String> {
+        match self {
+            FileStorage::Local(_) => Err(GatewayError::FileStorage(
+                "Presigned URLs are not supported by local storage".to_string(),
+            )),
+            FileStorage::S3(storage) => storage.presigned_url(file_id, expires_in).await,
+        }
+    }
+
     /// Health check
     pub async fn health_check(&self) -> Result<()> {
         match self {
@@ -3,6 +3,8 @@
 #[cfg(test)]
 mod tests {
     use super::super::local::LocalStorage;
+    use super::super::s3::S3Storage;
+    use crate::config::S3Config;
     use tempfile::TempDir;
 
     #[tokio::test]
@@ -47,4 +49,30 @@ mod tests {
             "application/octet-stream"
         );
     }
+
+    fn s3_config(prefix: Option<&str>) -> S3Config {
+        let mut config: S3Config =
+            serde_yaml::from_str("bucket: files\nregion: us-east-1").unwrap();
+        config.prefix = prefix.map(str::to_string);
+        config
+    }
+
+    #[tokio::test]
+    async fn test_s3_object_keys() {
+        let storage = S3Storage::new(&s3_config(Some("/gateway/files/")))
+            .await
+            .unwrap();
+        assert_eq!(storage.object_key("file-1"), "gateway/files/file-1");
+        assert_eq!(
+            storage.file_id("gateway/files/file-1").as_deref(),
+            Some("file-1")
+        );
+        assert!(storage.file_id("gateway/files/").is_none());
+        assert!(storage.file_id("gateway/filesfile-1").is_none());
+        assert!(storage.file_id("other/file-1").is_none());
+
+        let storage = S3Storage::new(&s3_config(None)).await.unwrap();
+        assert_eq!(storage.object_key("file-1"), "file-1");
+        assert_eq!(storage.file_id("file-1").as_deref(), Some("file-1"));
+    }
 }
@@ -0,0 +1,172 @@
+//! Data encryption keys managed by AWS KMS
+//!
+//! A KMS-managed key is configured as the `CiphertextBlob` KMS returned when
+//! the data key was generated. It is decrypted once at startup with a
+//! SigV4-signed `Decrypt` call, using credentials from the AWS credential
+//! chain (environment, profile, web identity or instance metadata).
+
+use crate::config::KmsKeyConfig;
+use crate::core::providers::bedrock::{AwsCredentialChain, SigV4Signer};
+use crate::utils::error::{GatewayError, Result};
+use base64::Engine;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Region of KMS keys configured without one
+fn default_region() -> String {
+    std::env::var("AWS_REGION")
+        .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+        .unwrap_or_else(|_| "us-east-1".to_string())
+}
+
+/// Decrypt a KMS-encrypted data key
+pub async fn decrypt_data_key(id: &str, config: &KmsKeyConfig) -> Result<Vec<u8>> {
+    let region = config.region.clone().unwrap_or_else(default_region);
+    let chain = AwsCredentialChain::new(region.as_str(), None, config.profile.clone());
+    decrypt_with(id, config, &region, &chain).await
+}
+
+/// Decrypt a KMS-encrypted data key with credentials from `chain`
+async fn decrypt_with(
+    id: &str,
+    config: &KmsKeyConfig,
+    region: &str,
+    chain: &AwsCredentialChain,
+) -> Result<Vec<u8>> {
+    let error = |message: String| {
+        GatewayError::Config(format!(
+            "Failed to decrypt encryption key {} with KMS: {}",
+            id, message
+        ))
+    };
+
+    let credentials = chain
+        .credentials()
+        .await
+        .map_err(|e| error(e.to_string()))?;
+    let url = config
+        .endpoint
+        .clone()
+        .unwrap_or_else(|| format!("https://kms.{}.amazonaws.com/", region));
+
+    let mut request = serde_json::json!({ "CiphertextBlob": config.ciphertext });
+    if let Some(key_id) = &config.key_id {
+        request["KeyId"] = Value::String(key_id.clone());
+    }
+    let body = request.to_string();
+    let headers = HashMap::from([
+        (
+            "content-type".to_string(),
+            "application/x-amz-json-1.1".to_string(),
+        ),
+        (
+            "x-amz-target".to_string(),
+            "TrentService.Decrypt".to_string(),
+        ),
+    ]);
+    let signed = SigV4Signer::new(
+        credentials.access_key_id,
+        credentials.secret_access_key,
+        credentials.session_token,
+        region.to_string(),
+    )
+    .with_service("kms")
+    .sign_request("POST", &url, &headers, &body, chrono::Utc::now())
+    .map_err(error)?;
+
+    let request = signed
+        .into_iter()
+        .filter(|(name, _)| !name.eq_ignore_ascii_case("host"))
+        .fold(
+            reqwest::Client::new().post(&url),
+            |request, (name, value)| request.header(name, value),
+        )
+        .body(body);
+    let response = request.send().await.map_err(|e| error(e.to_string()))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(error(format!("status {}", status.as_u16())));
+    }
+    let response: Value = response.json().await.map_err(|e| error(e.to_string()))?;
+
+    let plaintext = response
+        .get("Plaintext")
+        .and_then(Value::as_str)
+        .ok_or_else(|| error("response has no plaintext".to_string()))?;
+    base64::engine::general_purpose::STANDARD
+        .decode(plaintext)
+        .map_err(|e| error(format!("invalid base64 plaintext: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::providers::bedrock::{CredentialSource, ResolvedCredentials};
+    use wiremock::matchers::{body_partial_json, header, header_exists, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn chain() -> AwsCredentialChain {
+        AwsCredentialChain::new(
+            "eu-west-1",
+            Some(ResolvedCredentials::new(
+                "AKIDEXAMPLE",
+                "secret",
+                None,
+                CredentialSource::Static,
+            )),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_data_key() {
+        let server = MockServer::start().await;
+        let plaintext = b"kms-key-material-of-32-bytes!!!!";
+        Mock::given(method("POST"))
+            .and(header("x-amz-target", "TrentService.Decrypt"))
+            .and(header_exists("authorization"))
+            .and(body_partial_json(serde_json::json!({
+                "CiphertextBlob": "AQIDBA==",
+                "KeyId": "alias/gateway"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "KeyId": "arn:aws:kms:eu-west-1:111122223333:key/example",
+                "Plaintext": base64::engine::general_purpose::STANDARD.encode(plaintext)
+            })))
+            .mount(&server)
+            .await;
+
+        let config = KmsKeyConfig {
+            ciphertext: "AQIDBA==".to_string(),
+            key_id: Some("alias/gateway".to_string()),
+            region: Some("eu-west-1".to_string()),
+            profile: None,
+            endpoint: Some(format!("{}/", server.uri())),
+        };
+        let key = decrypt_with("v1", &config, "eu-west-1", &chain())
+            .await
+            .unwrap();
+        assert_eq!(key, plaintext);
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_data_key_failure() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let config = KmsKeyConfig {
+            ciphertext: "AQIDBA==".to_string(),
+            key_id: None,
+            region: None,
+            profile: None,
+            endpoint: Some(format!("{}/", server.uri())),
+        };
+        let error = decrypt_with("v1", &config, "eu-west-1", &chain())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("encryption key v1"));
+    }
+}
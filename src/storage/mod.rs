@@ -2,10 +2,16 @@
 //!
 //! This module provides data persistence and caching functionality.
 
+/// Key-value cache module
+pub mod cache;
 /// Database storage module
 pub mod database;
+/// Encryption of sensitive values at rest
+pub mod encryption;
 /// File storage module
 pub mod files;
+/// Data encryption keys managed by AWS KMS
+pub mod kms;
 /// Redis cache module
 pub mod redis;
 /// Optimized Redis cache module
@@ -23,8 +29,10 @@ use tracing::{debug, info, warn};
 pub struct StorageLayer {
     /// Database connection pool
     pub database: Arc<database::Database>,
-    /// Redis connection pool
-    pub redis: Arc<redis::RedisPool>,
+    /// Redis connection pool (None when Redis is disabled or unreachable)
+    pub redis: Option<Arc<redis::RedisPool>>,
+    /// Key-value cache, in Redis or else in memory
+    pub cache: Arc<dyn cache::CacheBackend>,
     /// File storage backend
     pub files: Arc<files::FileStorage>,
     /// Vector database client (optional)
@@ -40,29 +48,41 @@ impl StorageLayer {
 
         // Initialize database
         debug!("Connecting to database");
-        let database = Arc::new(database::Database::new(&config.database).await?);
+        let keyring = encryption::Keyring::from_config(&config.encryption).await?;
+        let database = Arc::new(
+            database::Database::new(&config.database)
+                .await?
+                .with_encryption(keyring),
+        );
 
         // Initialize Redis (optional with graceful degradation)
-        debug!("Creating Redis connection pool");
-        let redis = match redis::RedisPool::new(&config.redis).await {
-            Ok(pool) => {
-                info!("Redis connection established");
-                Arc::new(pool)
-            }
-            Err(e) => {
-                warn!(
-                    "Redis connection failed: {}. Gateway will operate without caching.",
-                    e
-                );
-                // Create a no-op Redis pool wrapper
-                Arc::new(redis::RedisPool::create_noop())
+        let redis = if config.redis.enabled {
+            debug!("Creating Redis connection pool");
+            match redis::RedisPool::new(&config.redis).await {
+                Ok(pool) => {
+                    info!("Redis connection established");
+                    Some(Arc::new(pool))
+                }
+                Err(e) => {
+                    warn!(
+                        "Redis connection failed: {}. Gateway will cache in memory.",
+                        e
+                    );
+                    None
+                }
             }
+        } else {
+            info!("Redis disabled, caching in memory");
+            None
+        };
+        let cache: Arc<dyn cache::CacheBackend> = match &redis {
+            Some(redis) => redis.clone(),
+            None => Arc::new(cache::MemoryCache::new()),
         };
 
-        // Initialize file storage (using default config for now)
+        // Initialize file storage
         debug!("Initializing file storage");
-        let default_file_config = crate::config::models::file_storage::FileStorageConfig::default();
-        let files = Arc::new(files::FileStorage::new(&default_file_config).await?);
+        let files = Arc::new(files::FileStorage::new(&config.files).await?);
 
         // Initialize vector database (optional)
         let vector = if let Some(ref vector_config) = config.vector_db {
@@ -87,6 +107,7 @@ impl StorageLayer {
         Ok(Self {
             database,
             redis,
+            cache,
             files,
             vector,
         })
@@ -104,7 +125,8 @@ impl StorageLayer {
     pub async fn health_check(&self) -> Result<StorageHealthStatus> {
         let mut status = StorageHealthStatus {
             database: false,
-            redis: false,
+            cache: false,
+            cache_backend: self.cache.name(),
             files: false,
             vector: false,
             overall: false,
@@ -118,11 +140,11 @@ impl StorageLayer {
             }
         }
 
-        // Check Redis health
-        match self.redis.health_check().await {
-            Ok(_) => status.redis = true,
+        // Check cache health
+        match self.cache.health_check().await {
+            Ok(_) => status.cache = true,
             Err(e) => {
-                warn!("Redis health check failed: {}", e);
+                warn!("Cache health check failed: {}", e);
             }
         }
 
@@ -147,7 +169,7 @@ impl StorageLayer {
         }
 
         // Overall health is true if all configured backends are healthy
-        status.overall = status.database && status.redis && status.files && status.vector;
+        status.overall = status.database && status.cache && status.files && status.vector;
 
         Ok(status)
     }
@@ -159,8 +181,8 @@ impl StorageLayer {
         // Database connections will be closed when Arc is dropped
         // self.database.close().await?;
 
-        // Close Redis connections
-        self.redis.close().await?;
+        // Close cache connections
+        self.cache.close().await?;
 
         // Close file storage
         self.files.close().await?;
@@ -179,9 +201,14 @@ impl StorageLayer {
         &self.database
     }
 
-    /// Get Redis pool
-    pub fn redis(&self) -> &redis::RedisPool {
-        &self.redis
+    /// Get Redis pool (if available)
+    pub fn redis(&self) -> Option<&redis::RedisPool> {
+        self.redis.as_deref()
+    }
+
+    /// Get key-value cache
+    pub fn cache(&self) -> &dyn cache::CacheBackend {
+        self.cache.as_ref()
     }
 
     /// Get file storage
@@ -199,7 +226,10 @@ impl StorageLayer {
 
     /// Get a Redis connection
     pub async fn redis_conn(&self) -> Result<redis::RedisConnection> {
-        self.redis.get_connection().await
+        self.redis()
+            .ok_or_else(|| GatewayError::Config("Redis not configured".to_string()))?
+            .get_connection()
+            .await
     }
 
     /// Store file and return file ID
@@ -251,80 +281,80 @@ impl StorageLayer {
 
     /// Cache operations
     pub async fn cache_get(&self, key: &str) -> Result<Option<String>> {
-        self.redis.get(key).await
+        self.cache.get(key).await
     }
 
     /// Set cache value with optional TTL
     pub async fn cache_set(&self, key: &str, value: &str, ttl: Option<u64>) -> Result<()> {
-        self.redis.set(key, value, ttl).await
+        self.cache.set(key, value, ttl).await
     }
 
     /// Delete cache key
     pub async fn cache_delete(&self, key: &str) -> Result<()> {
-        self.redis.delete(key).await
+        self.cache.delete(key).await
     }
 
     /// Check if cache key exists
     pub async fn cache_exists(&self, key: &str) -> Result<bool> {
-        self.redis.exists(key).await
+        self.cache.exists(key).await
     }
 
     /// Batch cache operations
     pub async fn cache_mget(&self, keys: &[String]) -> Result<Vec<Option<String>>> {
-        self.redis.mget(keys).await
+        self.cache.mget(keys).await
     }
 
     /// Set multiple cache values with optional TTL
     pub async fn cache_mset(&self, pairs: &[(String, String)], ttl: Option<u64>) -> Result<()> {
-        self.redis.mset(pairs, ttl).await
+        self.cache.mset(pairs, ttl).await
     }
 
     /// List operations
     /// Push value to list
     pub async fn list_push(&self, key: &str, value: &str) -> Result<()> {
-        self.redis.list_push(key, value).await
+        self.cache.list_push(key, value).await
     }
 
     /// Pop value from list
     pub async fn list_pop(&self, key: &str) -> Result<Option<String>> {
-        self.redis.list_pop(key).await
+        self.cache.list_pop(key).await
     }
 
     /// Get list length
     pub async fn list_length(&self, key: &str) -> Result<usize> {
-        self.redis.list_length(key).await
+        self.cache.list_length(key).await
     }
 
     /// Set operations
     /// Add member to set
     pub async fn set_add(&self, key: &str, member: &str) -> Result<()> {
-        self.redis.set_add(key, member).await
+        self.cache.set_add(key, member).await
     }
 
     /// Remove member from set
     pub async fn set_remove(&self, key: &str, member: &str) -> Result<()> {
-        self.redis.set_remove(key, member).await
+        self.cache.set_remove(key, member).await
     }
 
     /// Get all members of set
     pub async fn set_members(&self, key: &str) -> Result<Vec<String>> {
-        self.redis.set_members(key).await
+        self.cache.set_members(key).await
     }
 
     /// Hash operations
     /// Set hash field value
     pub async fn hash_set(&self, key: &str, field: &str, value: &str) -> Result<()> {
-        self.redis.hash_set(key, field, value).await
+        self.cache.hash_set(key, field, value).await
     }
 
     /// Get hash field value
     pub async fn hash_get(&self, key: &str, field: &str) -> Result<Option<String>> {
-        self.redis.hash_get(key, field).await
+        self.cache.hash_get(key, field).await
     }
 
     /// Delete hash field
     pub async fn hash_delete(&self, key: &str, field: &str) -> Result<()> {
-        self.redis.hash_delete(key, field).await
+        self.cache.hash_delete(key, field).await
     }
 
     /// Get all hash fields and values
@@ -332,18 +362,24 @@ impl StorageLayer {
         &self,
         key: &str,
     ) -> Result<std::collections::HashMap<String, String>> {
-        self.redis.hash_get_all(key).await
+        self.cache.hash_get_all(key).await
     }
 
     /// Pub/Sub operations
+    /// Publish message to channel, dropped without Redis
     pub async fn publish(&self, channel: &str, message: &str) -> Result<()> {
-        self.redis.publish(channel, message).await
+        match self.redis() {
+            Some(redis) => redis.publish(channel, message).await,
+            None => Ok(()),
+        }
     }
 
-    /// Subscribe to Redis channels
     /// Subscribe to Redis channels for pub/sub messaging
     pub async fn subscribe(&self, channels: &[String]) -> Result<redis::Subscription> {
-        self.redis.subscribe(channels).await
+        self.redis()
+            .ok_or_else(|| GatewayError::Config("Redis not configured".to_string()))?
+            .subscribe(channels)
+            .await
     }
 }
 
@@ -352,8 +388,10 @@ impl StorageLayer {
 pub struct StorageHealthStatus {
     /// Database health status
     pub database: bool,
-    /// Redis health status
-    pub redis: bool,
+    /// Cache health status
+    pub cache: bool,
+    /// Cache backend, `redis` or `memory`
+    pub cache_backend: &'static str,
     /// File storage health status
     pub files: bool,
     /// Vector storage health status
@@ -383,8 +421,14 @@ mod tests {
                 max_connections: 10,
                 connection_timeout: 5,
                 cluster: false,
+                ..Default::default()
             },
             vector_db: None,
+            files: Default::default(),
+            request_logs: Default::default(),
+            spend: Default::default(),
+            retention: Default::default(),
+            encryption: Default::default(),
         };
 
         // This test would require actual database connections
@@ -392,4 +436,27 @@ mod tests {
         assert_eq!(config.database.url, "postgresql://localhost:5432/test");
         assert_eq!(config.redis.url, "redis://localhost:6379");
     }
+
+    #[tokio::test]
+    async fn test_storage_layer_without_redis() {
+        let files = tempfile::TempDir::new().unwrap();
+        let mut config = StorageConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.redis.enabled = false;
+        config.files.local_path = Some(files.path().to_string_lossy().into_owned());
+
+        let storage = StorageLayer::new(&config).await.unwrap();
+        assert!(storage.redis().is_none());
+        assert_eq!(storage.cache().name(), "memory");
+        assert!(storage.redis_conn().await.is_err());
+
+        storage.cache_set("key", "value", Some(60)).await.unwrap();
+        assert_eq!(
+            storage.cache_get("key").await.unwrap().as_deref(),
+            Some("value")
+        );
+        let health = storage.health_check().await.unwrap();
+        assert!(health.cache);
+        assert_eq!(health.cache_backend, "memory");
+    }
 }
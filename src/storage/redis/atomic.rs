@@ -9,65 +9,54 @@ use redis::AsyncCommands;
 impl RedisPool {
     /// Increment key value by delta
     pub async fn increment(&self, key: &str, delta: i64) -> Result<i64> {
-        if self.noop_mode {
-            return Ok(delta);
-        }
+        let mut conn = self.get_connection().await?;
+        let c = &mut conn.conn;
+        let new_value: i64 = c.incr(key, delta).await.map_err(GatewayError::Redis)?;
+        Ok(new_value)
+    }
 
+    /// Increment key value by delta and set its TTL in one transaction
+    pub async fn increment_with_expiry(&self, key: &str, delta: i64, ttl: u64) -> Result<i64> {
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let new_value: i64 = c.incr(key, delta).await.map_err(GatewayError::Redis)?;
-            Ok(new_value)
-        } else {
-            Ok(delta)
-        }
+        let c = &mut conn.conn;
+        let (new_value,): (i64,) = redis::pipe()
+            .atomic()
+            .incr(key, delta)
+            .expire(key, ttl as i64)
+            .ignore()
+            .query_async(c)
+            .await
+            .map_err(GatewayError::Redis)?;
+        Ok(new_value)
     }
 
     /// Decrement a key by a delta value
     pub async fn decrement(&self, key: &str, delta: i64) -> Result<i64> {
-        if self.noop_mode {
-            return Ok(-delta);
-        }
-
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let new_value: i64 = c.decr(key, delta).await.map_err(GatewayError::Redis)?;
-            Ok(new_value)
-        } else {
-            Ok(-delta)
-        }
+        let c = &mut conn.conn;
+        let new_value: i64 = c.decr(key, delta).await.map_err(GatewayError::Redis)?;
+        Ok(new_value)
     }
 
     /// Get Redis info
     pub async fn info(&self) -> Result<String> {
-        if self.noop_mode {
-            return Ok("Redis unavailable (no-op mode)".to_string());
-        }
-
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let info: String = redis::cmd("INFO")
-                .query_async(c)
-                .await
-                .map_err(GatewayError::Redis)?;
-            Ok(info)
-        } else {
-            Ok("Redis unavailable".to_string())
-        }
+        let c = &mut conn.conn;
+        let info: String = redis::cmd("INFO")
+            .query_async(c)
+            .await
+            .map_err(GatewayError::Redis)?;
+        Ok(info)
     }
 
     /// Flush database (use with caution)
     pub async fn flush_db(&self) -> Result<()> {
-        if self.noop_mode {
-            return Ok(());
-        }
-
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let _: () = redis::cmd("FLUSHDB")
-                .query_async(c)
-                .await
-                .map_err(GatewayError::Redis)?;
-        }
+        let c = &mut conn.conn;
+        let _: () = redis::cmd("FLUSHDB")
+            .query_async(c)
+            .await
+            .map_err(GatewayError::Redis)?;
         Ok(())
     }
 }
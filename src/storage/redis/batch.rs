@@ -9,41 +9,33 @@ use redis::AsyncCommands;
 impl RedisPool {
     /// Get multiple keys at once
     pub async fn mget(&self, keys: &[String]) -> Result<Vec<Option<String>>> {
-        if self.noop_mode {
-            return Ok(vec![None; keys.len()]);
-        }
-
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let values: Vec<Option<String>> = c.mget(keys).await.map_err(GatewayError::Redis)?;
-            Ok(values)
-        } else {
-            Ok(vec![None; keys.len()])
-        }
+        let c = &mut conn.conn;
+        let values: Vec<Option<String>> = c.mget(keys).await.map_err(GatewayError::Redis)?;
+        Ok(values)
     }
 
     /// Set multiple key-value pairs with optional TTL
     pub async fn mset(&self, pairs: &[(String, String)], ttl: Option<u64>) -> Result<()> {
-        if self.noop_mode || pairs.is_empty() {
+        if pairs.is_empty() {
             return Ok(());
         }
 
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            // Use atomic pipeline for better performance and consistency
-            let mut pipe = redis::pipe();
-            pipe.atomic();
+        let c = &mut conn.conn;
+        // Use atomic pipeline for better performance and consistency
+        let mut pipe = redis::pipe();
+        pipe.atomic();
 
-            for (key, value) in pairs {
-                if let Some(ttl_seconds) = ttl {
-                    pipe.set_ex(key, value, ttl_seconds);
-                } else {
-                    pipe.set(key, value);
-                }
+        for (key, value) in pairs {
+            if let Some(ttl_seconds) = ttl {
+                pipe.set_ex(key, value, ttl_seconds);
+            } else {
+                pipe.set(key, value);
             }
-
-            let _: () = pipe.query_async(c).await.map_err(GatewayError::Redis)?;
         }
+
+        let _: () = pipe.query_async(c).await.map_err(GatewayError::Redis)?;
         Ok(())
     }
 }
@@ -5,6 +5,7 @@
 //! ## Module Structure
 //!
 //! - `pool` - Connection pool and core connection management
+//! - `topology` - Standalone, cluster and Sentinel connections
 //! - `cache` - Basic cache operations (get, set, delete, exists, expire, ttl)
 //! - `batch` - Batch operations (mget, mset)
 //! - `collections` - List and Set operations
@@ -25,6 +26,7 @@ mod pool;
 mod pubsub;
 #[cfg(test)]
 mod tests;
+mod topology;
 
 // Re-export public types
 pub use pool::{RedisConnection, RedisPool};
@@ -2,27 +2,28 @@
 //!
 //! This module provides Redis connectivity, connection pooling, and health checks.
 
+use super::topology::{ConnectionKind, Connector};
 use crate::config::RedisConfig;
 use crate::utils::error::{GatewayError, Result};
-use redis::{Client, aio::MultiplexedConnection};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info};
 
-/// Redis connection pool (supports no-op mode when Redis is unavailable)
+/// Redis connection pool
 #[derive(Debug, Clone)]
 pub struct RedisPool {
-    /// Redis client (None in no-op mode)
-    pub(crate) client: Option<Client>,
-    /// Connection manager (None in no-op mode)
-    pub(crate) connection_manager: Option<MultiplexedConnection>,
+    /// Client of the configured topology
+    pub(crate) connector: Arc<Connector>,
+    /// Current connection, replaced on topology refresh
+    pub(crate) connection_manager: Arc<RwLock<ConnectionKind>>,
     /// Configuration
     pub(crate) config: RedisConfig,
-    /// Whether this is a no-op pool (Redis unavailable)
-    pub(crate) noop_mode: bool,
 }
 
 /// Redis connection wrapper
 pub struct RedisConnection {
-    pub(crate) conn: Option<MultiplexedConnection>,
+    pub(crate) conn: ConnectionKind,
 }
 
 impl RedisPool {
@@ -31,66 +32,44 @@ impl RedisPool {
         info!("Creating Redis connection pool");
         debug!("Redis URL: {}", Self::sanitize_url(&config.url));
 
-        let client = Client::open(config.url.as_str()).map_err(GatewayError::Redis)?;
+        let connector = Arc::new(Connector::new(config).map_err(GatewayError::Redis)?);
+        let connection = connector.connect().await.map_err(GatewayError::Redis)?;
+        let connection_manager = Arc::new(RwLock::new(connection));
 
-        let connection_manager = client
-            .get_multiplexed_async_connection()
-            .await
-            .map_err(GatewayError::Redis)?;
+        if !matches!(*connector, Connector::Standalone(_)) && config.topology_refresh_interval > 0 {
+            connector.clone().spawn_refresh(
+                Arc::downgrade(&connection_manager),
+                Duration::from_secs(config.topology_refresh_interval),
+            );
+        }
 
-        info!("Redis connection pool created successfully");
+        info!(
+            "Redis connection pool created successfully ({} mode)",
+            connector.mode()
+        );
         Ok(Self {
-            client: Some(client),
-            connection_manager: Some(connection_manager),
+            connector,
+            connection_manager,
             config: config.clone(),
-            noop_mode: false,
         })
     }
 
-    /// Create a no-op Redis pool (for when Redis is unavailable)
-    pub fn create_noop() -> Self {
-        info!("Creating no-op Redis pool (Redis unavailable)");
-        Self {
-            client: None,
-            connection_manager: None,
-            config: RedisConfig {
-                url: String::new(),
-                enabled: false,
-                max_connections: 0,
-                connection_timeout: 0,
-                cluster: false,
-            },
-            noop_mode: true,
-        }
-    }
-
-    /// Check if this is a no-op pool
-    pub fn is_noop(&self) -> bool {
-        self.noop_mode
-    }
-
     /// Get a connection from the pool
     pub async fn get_connection(&self) -> Result<RedisConnection> {
         Ok(RedisConnection {
-            conn: self.connection_manager.clone(),
+            conn: self.connection_manager.read().clone(),
         })
     }
 
     /// Health check
     pub async fn health_check(&self) -> Result<()> {
-        if self.noop_mode {
-            debug!("Redis health check skipped (no-op mode)");
-            return Ok(());
-        }
-
         debug!("Performing Redis health check");
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let _: String = redis::cmd("PING")
-                .query_async(c)
-                .await
-                .map_err(GatewayError::Redis)?;
-        }
+        let c = &mut conn.conn;
+        let _: String = redis::cmd("PING")
+            .query_async(c)
+            .await
+            .map_err(GatewayError::Redis)?;
 
         debug!("Redis health check passed");
         Ok(())
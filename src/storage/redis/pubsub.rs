@@ -18,17 +18,12 @@ pub struct Subscription {
 impl RedisPool {
     /// Publish message to channel
     pub async fn publish(&self, channel: &str, message: &str) -> Result<()> {
-        if self.noop_mode {
-            return Ok(());
-        }
-
         let mut conn = self.get_connection().await?;
-        if let Some(ref mut c) = conn.conn {
-            let _: () = c
-                .publish(channel, message)
-                .await
-                .map_err(GatewayError::Redis)?;
-        }
+        let c = &mut conn.conn;
+        let _: () = c
+            .publish(channel, message)
+            .await
+            .map_err(GatewayError::Redis)?;
         Ok(())
     }
 
@@ -1,7 +1,8 @@
 //! Redis module tests
 
 use super::pool::RedisPool;
-use crate::config::RedisConfig;
+use super::topology::Connector;
+use crate::config::{RedisConfig, RedisSentinelConfig};
 
 #[test]
 fn test_sanitize_url() {
@@ -19,6 +20,7 @@ async fn test_redis_pool_creation() {
         max_connections: 10,
         connection_timeout: 5,
         cluster: false,
+        ..Default::default()
     };
 
     // This test would require an actual Redis instance
@@ -26,3 +28,30 @@ async fn test_redis_pool_creation() {
     assert_eq!(config.url, "redis://localhost:6379");
     assert_eq!(config.max_connections, 10);
 }
+
+#[test]
+fn test_connector_modes() {
+    let config = RedisConfig::default();
+    assert_eq!(Connector::new(&config).unwrap().mode(), "standalone");
+
+    let config = RedisConfig {
+        cluster: true,
+        nodes: vec![
+            "redis://node1:6379".to_string(),
+            "redis://node2:6379".to_string(),
+        ],
+        read_from_replicas: true,
+        ..Default::default()
+    };
+    assert_eq!(Connector::new(&config).unwrap().mode(), "cluster");
+
+    let config = RedisConfig {
+        url: "redis://:secret@localhost:6379/2".to_string(),
+        sentinel: Some(RedisSentinelConfig {
+            master_name: "mymaster".to_string(),
+            nodes: vec!["redis://sentinel:26379".to_string()],
+        }),
+        ..Default::default()
+    };
+    assert_eq!(Connector::new(&config).unwrap().mode(), "sentinel");
+}
@@ -0,0 +1,180 @@
+//! Redis deployment topologies
+//!
+//! This module connects to a standalone server, a cluster or the master of a
+//! Sentinel-managed deployment, and periodically reconnects to pick up topology changes.
+
+use crate::config::RedisConfig;
+use parking_lot::RwLock;
+use redis::aio::{ConnectionLike, MultiplexedConnection};
+use redis::cluster::{ClusterClient, ClusterClientBuilder};
+use redis::cluster_async::ClusterConnection;
+use redis::sentinel::{SentinelClient, SentinelNodeConnectionInfo, SentinelServerType};
+use redis::{
+    Client, Cmd, ConnectionAddr, IntoConnectionInfo, Pipeline, RedisFuture, RedisResult, TlsMode,
+    Value,
+};
+use std::fmt;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Connection to any supported topology
+#[derive(Clone)]
+pub enum ConnectionKind {
+    /// Connection to a standalone server or a Sentinel-managed master
+    Single(MultiplexedConnection),
+    /// Connection routing commands across cluster nodes
+    Cluster(ClusterConnection),
+}
+
+impl fmt::Debug for ConnectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Single(conn) => f.debug_tuple("Single").field(conn).finish(),
+            Self::Cluster(_) => f.debug_tuple("Cluster").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl ConnectionLike for ConnectionKind {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        match self {
+            Self::Single(conn) => conn.req_packed_command(cmd),
+            Self::Cluster(conn) => conn.req_packed_command(cmd),
+        }
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        match self {
+            Self::Single(conn) => conn.req_packed_commands(cmd, offset, count),
+            Self::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        match self {
+            Self::Single(conn) => conn.get_db(),
+            Self::Cluster(conn) => conn.get_db(),
+        }
+    }
+}
+
+/// Client of the configured topology
+pub enum Connector {
+    /// Standalone server
+    Standalone(Client),
+    /// Cluster reached through its seed nodes
+    Cluster(ClusterClient),
+    /// Master resolved through Sentinel, which needs exclusive access to resolve
+    Sentinel(tokio::sync::Mutex<SentinelClient>),
+}
+
+impl fmt::Debug for Connector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Connector").field(&self.mode()).finish()
+    }
+}
+
+impl Connector {
+    /// Create a client for the topology of a configuration
+    pub fn new(config: &RedisConfig) -> RedisResult<Self> {
+        if let Some(sentinel) = &config.sentinel {
+            // The URL supplies credentials and database of the master
+            let info = config.url.as_str().into_connection_info()?;
+            let tls_mode = match info.addr {
+                ConnectionAddr::TcpTls { insecure: true, .. } => Some(TlsMode::Insecure),
+                ConnectionAddr::TcpTls { .. } => Some(TlsMode::Secure),
+                _ => None,
+            };
+            let node_connection_info = SentinelNodeConnectionInfo {
+                tls_mode,
+                redis_connection_info: Some(info.redis),
+            };
+            let client = SentinelClient::build(
+                sentinel.nodes.clone(),
+                sentinel.master_name.clone(),
+                Some(node_connection_info),
+                SentinelServerType::Master,
+            )?;
+            return Ok(Self::Sentinel(tokio::sync::Mutex::new(client)));
+        }
+
+        if config.cluster {
+            let nodes = if config.nodes.is_empty() {
+                vec![config.url.clone()]
+            } else {
+                config.nodes.clone()
+            };
+            let mut builder = ClusterClientBuilder::new(nodes)
+                .connection_timeout(Duration::from_secs(config.connection_timeout));
+            if config.read_from_replicas {
+                builder = builder.read_from_replicas();
+            }
+            return Ok(Self::Cluster(builder.build()?));
+        }
+
+        Ok(Self::Standalone(Client::open(config.url.as_str())?))
+    }
+
+    /// Name of the topology
+    pub fn mode(&self) -> &'static str {
+        match self {
+            Self::Standalone(_) => "standalone",
+            Self::Cluster(_) => "cluster",
+            Self::Sentinel(_) => "sentinel",
+        }
+    }
+
+    /// Open a connection, resolving the current cluster slots or Sentinel master
+    pub async fn connect(&self) -> RedisResult<ConnectionKind> {
+        match self {
+            Self::Standalone(client) => client
+                .get_multiplexed_async_connection()
+                .await
+                .map(ConnectionKind::Single),
+            Self::Cluster(client) => client
+                .get_async_connection()
+                .await
+                .map(ConnectionKind::Cluster),
+            Self::Sentinel(client) => client
+                .lock()
+                .await
+                .get_async_connection()
+                .await
+                .map(ConnectionKind::Single),
+        }
+    }
+
+    /// Periodically replace a connection with a fresh one until it is dropped
+    ///
+    /// A cluster connection only refreshes its slots when redirected, and a Sentinel
+    /// connection stays with a master that has been demoted by a failover.
+    pub fn spawn_refresh(
+        self: Arc<Self>,
+        connection: Weak<RwLock<ConnectionKind>>,
+        interval: Duration,
+    ) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let Some(connection) = connection.upgrade() else {
+                    break;
+                };
+                match self.connect().await {
+                    Ok(fresh) => {
+                        *connection.write() = fresh;
+                        debug!("Redis {} topology refreshed", self.mode());
+                    }
+                    Err(e) => warn!("Redis {} topology refresh failed: {}", self.mode(), e),
+                }
+            }
+        });
+    }
+}
@@ -6,7 +6,7 @@ use tracing::info;
 
 use super::pinecone::PineconeStore;
 use super::qdrant::QdrantStore;
-use super::types::{SearchResult, VectorPoint};
+use super::types::{SearchResult, VectorData, VectorPoint, VectorStore};
 use super::weaviate::WeaviateStore;
 
 /// Vector store backend enum
@@ -128,3 +128,32 @@ impl VectorStoreBackend {
         }
     }
 }
+
+/// The configured backend as a vector store for the semantic cache
+#[async_trait::async_trait]
+impl VectorStore for VectorStoreBackend {
+    async fn search(&self, vector: Vec<f32>, limit: usize) -> Result<Vec<SearchResult>> {
+        VectorStoreBackend::search(self, &vector, limit, None).await
+    }
+
+    async fn insert(&self, vectors: Vec<VectorData>) -> Result<()> {
+        let points: Vec<VectorPoint> = vectors
+            .into_iter()
+            .map(|data| VectorPoint {
+                id: data.id,
+                vector: data.vector,
+                metadata: Some(serde_json::Value::Object(
+                    data.metadata.into_iter().collect(),
+                )),
+            })
+            .collect();
+        self.batch_store(&points).await
+    }
+
+    async fn delete(&self, ids: Vec<String>) -> Result<()> {
+        for id in ids {
+            VectorStoreBackend::delete(self, &id).await?;
+        }
+        Ok(())
+    }
+}
@@ -3,18 +3,38 @@
 #[cfg(test)]
 mod tests {
     use crate::core::models::openai::{ChatMessage, MessageContent, MessageRole};
+    use crate::core::tokenizers::{Tokenizer, count_text_tokens};
     use crate::utils::ai::counter::token_counter::TokenCounter;
 
     #[test]
     fn test_text_token_estimation() {
         let counter = TokenCounter::new();
-        let config = counter.get_model_config("gpt-3.5-turbo").unwrap();
+        let tokenizer = Tokenizer::for_model("gpt-3.5-turbo");
 
-        let tokens = counter.estimate_text_tokens(config, "Hello, world!");
+        let tokens = counter.estimate_text_tokens(&tokenizer, "Hello, world!");
         assert!(tokens > 0);
         assert!(tokens < 10); // Should be reasonable for short text
     }
 
+    #[test]
+    fn test_completion_tokens_use_model_tokenizer() {
+        let counter = TokenCounter::new();
+        let prompt = "The quick brown fox jumps over the lazy dog.";
+
+        for model in ["gpt-4o", "claude-3-opus"] {
+            let estimate = counter.count_completion_tokens(model, prompt).unwrap();
+            let config = counter.get_model_config(model).unwrap();
+            assert_eq!(
+                estimate.input_tokens,
+                config.request_overhead + count_text_tokens(model, prompt)
+            );
+            assert_eq!(
+                estimate.is_approximate,
+                !Tokenizer::for_model(model).is_exact()
+            );
+        }
+    }
+
     #[test]
     fn test_chat_token_counting() {
         let counter = TokenCounter::new();
@@ -32,7 +52,10 @@ mod tests {
             .count_chat_tokens("gpt-3.5-turbo", &messages)
             .unwrap();
         assert!(estimate.input_tokens > 0);
-        assert!(estimate.is_approximate);
+        assert_eq!(
+            estimate.is_approximate,
+            !Tokenizer::for_model("gpt-3.5-turbo").is_exact()
+        );
     }
 
     #[test]
@@ -1,7 +1,12 @@
 //! Token counting implementation
+//!
+//! Text is counted with the model's tokenizer from [`crate::core::tokenizers`];
+//! the model configurations add message and request overheads and context
+//! windows.
 
 use super::types::{ModelTokenConfig, TokenEstimate};
 use crate::core::models::openai::{ChatMessage, ContentPart, MessageContent};
+use crate::core::tokenizers::Tokenizer;
 use crate::utils::error::{GatewayError, Result};
 use std::collections::HashMap;
 
@@ -28,18 +33,19 @@ impl TokenCounter {
         messages: &[ChatMessage],
     ) -> Result<TokenEstimate> {
         let config = self.get_model_config(model)?;
+        let tokenizer = Tokenizer::for_model(model);
         let mut total_tokens = config.request_overhead;
 
         for message in messages {
-            total_tokens += self.count_message_tokens(config, message)?;
+            total_tokens += self.count_message_tokens(config, &tokenizer, message)?;
         }
 
         Ok(TokenEstimate {
             input_tokens: total_tokens,
             output_tokens: None,
             total_tokens,
-            is_approximate: true,
-            confidence: 0.85, // Reasonable confidence for estimation
+            is_approximate: !tokenizer.is_exact(),
+            confidence: confidence(&tokenizer, 0.85), // Reasonable confidence for estimation
         })
     }
 
@@ -48,36 +54,37 @@ impl TokenCounter {
     fn count_message_tokens(
         &self,
         config: &ModelTokenConfig,
+        tokenizer: &Tokenizer,
         message: &ChatMessage,
     ) -> Result<u32> {
         let mut tokens = config.message_overhead;
 
         // Count role tokens
-        tokens += self.estimate_text_tokens(config, &ToString::to_string(&message.role));
+        tokens += self.estimate_text_tokens(tokenizer, &ToString::to_string(&message.role));
 
         // Count content tokens
         if let Some(content) = &message.content {
-            tokens += self.count_content_tokens(config, content)?;
+            tokens += self.count_content_tokens(tokenizer, content)?;
         }
 
         // Count name tokens if present
         if let Some(name) = &message.name {
-            tokens += self.estimate_text_tokens(config, name);
+            tokens += self.estimate_text_tokens(tokenizer, name);
         }
 
         // Count function call tokens if present
         if let Some(function_call) = &message.function_call {
-            tokens += self.estimate_text_tokens(config, &function_call.name);
-            tokens += self.estimate_text_tokens(config, &function_call.arguments);
+            tokens += self.estimate_text_tokens(tokenizer, &function_call.name);
+            tokens += self.estimate_text_tokens(tokenizer, &function_call.arguments);
         }
 
         // Count tool calls tokens if present
         if let Some(tool_calls) = &message.tool_calls {
             for tool_call in tool_calls {
-                tokens += self.estimate_text_tokens(config, &tool_call.id);
-                tokens += self.estimate_text_tokens(config, &tool_call.tool_type);
-                tokens += self.estimate_text_tokens(config, &tool_call.function.name);
-                tokens += self.estimate_text_tokens(config, &tool_call.function.arguments);
+                tokens += self.estimate_text_tokens(tokenizer, &tool_call.id);
+                tokens += self.estimate_text_tokens(tokenizer, &tool_call.tool_type);
+                tokens += self.estimate_text_tokens(tokenizer, &tool_call.function.name);
+                tokens += self.estimate_text_tokens(tokenizer, &tool_call.function.arguments);
             }
         }
 
@@ -86,17 +93,13 @@ impl TokenCounter {
 
     /// Count tokens in message content
     #[allow(dead_code)]
-    fn count_content_tokens(
-        &self,
-        config: &ModelTokenConfig,
-        content: &MessageContent,
-    ) -> Result<u32> {
+    fn count_content_tokens(&self, tokenizer: &Tokenizer, content: &MessageContent) -> Result<u32> {
         match content {
-            MessageContent::Text(text) => Ok(self.estimate_text_tokens(config, text)),
+            MessageContent::Text(text) => Ok(self.estimate_text_tokens(tokenizer, text)),
             MessageContent::Parts(parts) => {
                 let mut tokens = 0;
                 for part in parts {
-                    tokens += self.count_content_part_tokens(config, part)?;
+                    tokens += self.count_content_part_tokens(tokenizer, part)?;
                 }
                 Ok(tokens)
             }
@@ -105,19 +108,15 @@ impl TokenCounter {
 
     /// Count tokens in a content part
     #[allow(dead_code)]
-    fn count_content_part_tokens(
-        &self,
-        config: &ModelTokenConfig,
-        part: &ContentPart,
-    ) -> Result<u32> {
+    fn count_content_part_tokens(&self, tokenizer: &Tokenizer, part: &ContentPart) -> Result<u32> {
         match part {
-            ContentPart::Text { text } => Ok(self.estimate_text_tokens(config, text)),
+            ContentPart::Text { text } => Ok(self.estimate_text_tokens(tokenizer, text)),
             ContentPart::ImageUrl { image_url: _ } => {
                 // Images typically use a fixed number of tokens
                 // This is a simplified estimation
                 Ok(85) // Base tokens for image processing
             }
-            ContentPart::Audio { audio: _ } => {
+            ContentPart::Audio { audio: _ } | ContentPart::InputAudio { input_audio: _ } => {
                 // Audio tokens depend on duration, but we don't have that info
                 // Use a reasonable default
                 Ok(100)
@@ -125,31 +124,23 @@ impl TokenCounter {
         }
     }
 
-    /// Estimate tokens for text content
-    pub(super) fn estimate_text_tokens(&self, config: &ModelTokenConfig, text: &str) -> u32 {
-        if text.is_empty() {
-            return 0;
-        }
-
-        // Simple character-based estimation
-        let char_count = text.chars().count() as f64;
-        let estimated_tokens = (char_count / config.chars_per_token).ceil() as u32;
-
-        // Add some buffer for special tokens and encoding overhead
-        (estimated_tokens as f64 * 1.1).ceil() as u32
+    /// Count tokens for text content with the model's tokenizer
+    pub(super) fn estimate_text_tokens(&self, tokenizer: &Tokenizer, text: &str) -> u32 {
+        tokenizer.count(text)
     }
 
     /// Count tokens in completion request
     pub fn count_completion_tokens(&self, model: &str, prompt: &str) -> Result<TokenEstimate> {
         let config = self.get_model_config(model)?;
-        let input_tokens = config.request_overhead + self.estimate_text_tokens(config, prompt);
+        let tokenizer = Tokenizer::for_model(model);
+        let input_tokens = config.request_overhead + self.estimate_text_tokens(&tokenizer, prompt);
 
         Ok(TokenEstimate {
             input_tokens,
             output_tokens: None,
             total_tokens: input_tokens,
-            is_approximate: true,
-            confidence: 0.8,
+            is_approximate: !tokenizer.is_exact(),
+            confidence: confidence(&tokenizer, 0.8),
         })
     }
 
@@ -157,18 +148,19 @@ impl TokenCounter {
     #[allow(dead_code)]
     pub fn count_embedding_tokens(&self, model: &str, input: &[String]) -> Result<TokenEstimate> {
         let config = self.get_model_config(model)?;
+        let tokenizer = Tokenizer::for_model(model);
         let mut total_tokens = config.request_overhead;
 
         for text in input {
-            total_tokens += self.estimate_text_tokens(config, text);
+            total_tokens += self.estimate_text_tokens(&tokenizer, text);
         }
 
         Ok(TokenEstimate {
             input_tokens: total_tokens,
             output_tokens: None,
             total_tokens,
-            is_approximate: true,
-            confidence: 0.9, // Embeddings are more predictable
+            is_approximate: !tokenizer.is_exact(),
+            confidence: confidence(&tokenizer, 0.9), // Embeddings are more predictable
         })
     }
 
@@ -268,3 +260,8 @@ impl Default for TokenCounter {
         Self::new()
     }
 }
+
+/// Confidence of a count, certain when the tokenizer is exact
+fn confidence(tokenizer: &Tokenizer, estimated: f64) -> f64 {
+    if tokenizer.is_exact() { 1.0 } else { estimated }
+}
@@ -231,62 +231,143 @@ impl JsonOps {
         result
     }
 
+    /// Validate data against a JSON schema
+    ///
+    /// Supports the keywords structured outputs use: `type` (one or several),
+    /// `enum`, `const`, `properties`, `required`, `additionalProperties`,
+    /// `items`, `anyOf` and local `$ref`s into `$defs` or `definitions`. The
+    /// error names the path of the first invalid value, such as `$.items[0]`.
     pub fn validate_json_schema(data: &Value, schema: &Value) -> Result<(), ProviderError> {
-        match (data, schema) {
-            (_, Value::Object(schema_map)) => {
-                if let Some(type_value) = schema_map.get("type") {
-                    if let Some(expected_type) = type_value.as_str() {
-                        let data_type = match data {
-                            Value::Null => "null",
-                            Value::Bool(_) => "boolean",
-                            Value::Number(_) => "number",
-                            Value::String(_) => "string",
-                            Value::Array(_) => "array",
-                            Value::Object(_) => "object",
-                        };
-
-                        if data_type != expected_type {
-                            return Err(ProviderError::InvalidRequest {
-                                provider: "unknown",
-                                message: format!(
-                                    "Expected type '{}', got '{}'",
-                                    expected_type, data_type
-                                ),
-                            });
+        Self::validate_schema_at(data, schema, schema, "$").map_err(|message| {
+            ProviderError::InvalidRequest {
+                provider: "unknown",
+                message,
+            }
+        })
+    }
+
+    fn validate_schema_at(
+        data: &Value,
+        schema: &Value,
+        root: &Value,
+        path: &str,
+    ) -> Result<(), String> {
+        let Value::Object(schema_map) = schema else {
+            return match schema {
+                Value::Bool(false) => Err(format!("{}: no value is allowed", path)),
+                _ => Ok(()),
+            };
+        };
+
+        if let Some(reference) = schema_map.get("$ref").and_then(Value::as_str) {
+            let target = reference
+                .strip_prefix("#/")
+                .and_then(|pointer| root.pointer(&format!("/{}", pointer)))
+                .or((reference == "#").then_some(root))
+                .ok_or_else(|| format!("{}: unresolved reference {}", path, reference))?;
+            Self::validate_schema_at(data, target, root, path)?;
+        }
+
+        let data_type = match data {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+        let is_type = |expected: &str| {
+            expected == data_type
+                || (expected == "integer"
+                    && data.as_number().is_some_and(|n| {
+                        n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+                    }))
+        };
+        let expected_types: Vec<&str> = match schema_map.get("type") {
+            Some(Value::String(expected)) => vec![expected.as_str()],
+            Some(Value::Array(expected)) => expected.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !expected_types.is_empty() && !expected_types.iter().any(|expected| is_type(expected)) {
+            return Err(format!(
+                "{}: expected type '{}', got '{}'",
+                path,
+                expected_types.join("' or '"),
+                data_type
+            ));
+        }
+
+        if let Some(Value::Array(allowed)) = schema_map.get("enum")
+            && !allowed.contains(data)
+        {
+            return Err(format!(
+                "{}: {} is not one of {}",
+                path,
+                data,
+                Value::Array(allowed.clone())
+            ));
+        }
+        if let Some(expected) = schema_map.get("const")
+            && expected != data
+        {
+            return Err(format!("{}: expected {}, got {}", path, expected, data));
+        }
+
+        if let Some(Value::Array(options)) = schema_map.get("anyOf")
+            && !options
+                .iter()
+                .any(|option| Self::validate_schema_at(data, option, root, path).is_ok())
+        {
+            return Err(format!("{}: matches none of the anyOf schemas", path));
+        }
+
+        match data {
+            Value::Object(data_map) => {
+                if let Some(Value::Array(required)) = schema_map.get("required") {
+                    for prop_name in required.iter().filter_map(Value::as_str) {
+                        if !data_map.contains_key(prop_name) {
+                            return Err(format!(
+                                "{}: required property '{}' is missing",
+                                path, prop_name
+                            ));
                         }
                     }
                 }
 
-                if let (Value::Object(data_map), Some(Value::Object(properties))) =
-                    (data, schema_map.get("properties"))
-                {
-                    for (prop_name, prop_schema) in properties {
-                        if let Some(prop_data) = data_map.get(prop_name) {
-                            Self::validate_json_schema(prop_data, prop_schema)?;
+                let properties = schema_map.get("properties").and_then(Value::as_object);
+                for (prop_name, prop_data) in data_map {
+                    let prop_path = format!("{}.{}", path, prop_name);
+                    match properties.and_then(|properties| properties.get(prop_name)) {
+                        Some(prop_schema) => {
+                            Self::validate_schema_at(prop_data, prop_schema, root, &prop_path)?
                         }
-                    }
-
-                    if let Some(Value::Array(required)) = schema_map.get("required") {
-                        for required_prop in required {
-                            if let Some(prop_name) = required_prop.as_str() {
-                                if !data_map.contains_key(prop_name) {
-                                    return Err(ProviderError::InvalidRequest {
-                                        provider: "unknown",
-                                        message: format!(
-                                            "Required property '{}' is missing",
-                                            prop_name
-                                        ),
-                                    });
-                                }
+                        None => match schema_map.get("additionalProperties") {
+                            Some(Value::Bool(false)) => {
+                                return Err(format!(
+                                    "{}: additional property '{}' is not allowed",
+                                    path, prop_name
+                                ));
                             }
-                        }
+                            Some(additional) => {
+                                Self::validate_schema_at(prop_data, additional, root, &prop_path)?
+                            }
+                            None => {}
+                        },
                     }
                 }
-
-                Ok(())
             }
-            _ => Ok(()),
+            Value::Array(items) => {
+                if let Some(item_schema) = schema_map.get("items") {
+                    for (index, item) in items.iter().enumerate() {
+                        let item_path = format!("{}[{}]", path, index);
+                        Self::validate_schema_at(item, item_schema, root, &item_path)?;
+                    }
+                }
+            }
+            _ => {}
         }
+
+        Ok(())
     }
 }
 
@@ -686,4 +767,40 @@ mod tests {
         });
         assert!(JsonOps::validate_json_schema(&data, &schema).is_ok());
     }
+
+    #[test]
+    fn test_validate_json_schema_structured_output() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "steps": {"type": "array", "items": {"$ref": "#/$defs/step"}},
+                "status": {"type": "string", "enum": ["done", "failed"]},
+                "note": {"type": ["string", "null"]}
+            },
+            "required": ["steps", "status", "note"],
+            "additionalProperties": false,
+            "$defs": {
+                "step": {
+                    "type": "object",
+                    "properties": {"count": {"type": "integer"}},
+                    "required": ["count"]
+                }
+            }
+        });
+        let valid =
+            json!({"steps": [{"count": 1}, {"count": 2.0}], "status": "done", "note": null});
+        assert!(JsonOps::validate_json_schema(&valid, &schema).is_ok());
+
+        let error = |data: Value| {
+            JsonOps::validate_json_schema(&data, &schema)
+                .unwrap_err()
+                .to_string()
+        };
+        let invalid = json!({"steps": [{"count": 1.5}], "status": "done", "note": null});
+        assert!(error(invalid).contains("$.steps[0].count: expected type 'integer', got 'number'"));
+        let invalid = json!({"steps": [], "status": "pending", "note": null});
+        assert!(error(invalid).contains("$.status: \"pending\" is not one of"));
+        let invalid = json!({"steps": [], "status": "done", "note": null, "extra": 1});
+        assert!(error(invalid).contains("additional property 'extra' is not allowed"));
+    }
 }
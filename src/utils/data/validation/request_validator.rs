@@ -181,7 +181,7 @@ impl RequestValidator {
                     }
                 }
             }
-            ContentPart::Audio { audio } => {
+            ContentPart::Audio { audio } | ContentPart::InputAudio { input_audio: audio } => {
                 Self::validate_audio_data(&audio.data)?;
                 Self::validate_audio_format(&audio.format)?;
             }
@@ -1,6 +1,7 @@
 //! HTTP response handling for errors
 
 use super::types::GatewayError;
+use crate::core::observability::scrub_secrets;
 use crate::core::providers::unified_provider::ProviderError;
 use actix_web::{HttpResponse, ResponseError};
 
@@ -79,6 +80,11 @@ impl ResponseError for GatewayError {
                 "RATE_LIMIT_EXCEEDED",
                 self.to_string(),
             ),
+            GatewayError::BudgetExceeded(_) => (
+                actix_web::http::StatusCode::TOO_MANY_REQUESTS,
+                "BUDGET_EXCEEDED",
+                self.to_string(),
+            ),
             GatewayError::Validation(_) => (
                 actix_web::http::StatusCode::BAD_REQUEST,
                 "VALIDATION_ERROR",
@@ -174,6 +180,11 @@ impl ResponseError for GatewayError {
                 "NO_HEALTHY_PROVIDERS",
                 self.to_string(),
             ),
+            GatewayError::SchemaValidation(_) => (
+                actix_web::http::StatusCode::BAD_GATEWAY,
+                "SCHEMA_VALIDATION_ERROR",
+                self.to_string(),
+            ),
             _ => (
                 actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
                 "INTERNAL_ERROR",
@@ -184,7 +195,8 @@ impl ResponseError for GatewayError {
         let error_response = ErrorResponse {
             error: ErrorDetail {
                 code: error_code.to_string(),
-                message,
+                // Provider error bodies may echo credentials
+                message: scrub_secrets(&message).into_owned(),
                 timestamp: chrono::Utc::now().timestamp(),
                 request_id: None, // This should be set by middleware
             },
@@ -1,5 +1,6 @@
 //! Error types for the Gateway
 
+use crate::core::completion::SchemaValidationError;
 use crate::core::providers::unified_provider::ProviderError;
 use thiserror::Error;
 
@@ -54,6 +55,10 @@ pub enum GatewayError {
     #[error("Rate limit exceeded: {0}")]
     RateLimit(String),
 
+    /// Budget exhausted for the current window
+    #[error("Budget exceeded: {0}")]
+    BudgetExceeded(String),
+
     /// Validation errors
     #[error("Validation error: {0}")]
     Validation(String),
@@ -188,4 +193,8 @@ pub enum GatewayError {
     /// Email service errors
     #[error("Email error: {0}")]
     Email(String),
+
+    /// Completions not matching the JSON schema of their request
+    #[error(transparent)]
+    SchemaValidation(#[from] SchemaValidationError),
 }
@@ -1,4 +1,5 @@
 use super::types::{LogEntry, LogLevel};
+use crate::core::observability::ScrubbingWriter;
 use crate::core::providers::unified_provider::ProviderError;
 use std::collections::HashMap;
 use std::env;
@@ -54,6 +55,7 @@ impl LoggingUtils {
             .with_thread_ids(true)
             .with_file(true)
             .with_line_number(true)
+            .with_writer(ScrubbingWriter::new(std::io::stdout))
             .init();
 
         Ok(())
@@ -97,6 +97,53 @@ mod tests {
         assert!(batches.unwrap().is_empty());
     }
 
+    /// Test a SQLite file database is created where the URL points
+    #[tokio::test]
+    async fn test_sqlite_file_database() {
+        let dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let path = dir.path().join("nested").join("gateway.db");
+        let config = DatabaseConfig {
+            url: format!("sqlite://{}", path.display()),
+            max_connections: 2,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+
+        let db = Database::new(&config)
+            .await
+            .expect("Failed to create database");
+        assert!(db.is_sqlite_fallback());
+        db.migrate().await.expect("Migration failed");
+        assert!(path.exists());
+
+        // The tables are there for a new connection to the same file
+        let db = Database::new(&config)
+            .await
+            .expect("Failed to reopen database");
+        assert!(db.health_check().await.is_ok());
+    }
+
+    /// Test an in-memory SQLite database keeps its tables across a pool
+    #[tokio::test]
+    async fn test_sqlite_memory_database_pool() {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 5,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+
+        let db = Database::new(&config)
+            .await
+            .expect("Failed to create database");
+        db.migrate().await.expect("Migration failed");
+        for _ in 0..5 {
+            assert!(db.health_check().await.is_ok());
+        }
+    }
+
     /// Test database statistics
     #[tokio::test]
     async fn test_database_stats() {
@@ -116,4 +163,228 @@ mod tests {
         // Just verify we can get stats (size is always >= 0 as usize)
         let _ = stats.size;
     }
+
+    /// Test request log insert, listing and retention
+    #[tokio::test]
+    async fn test_request_log_operations() {
+        use litellm_rs::core::models::metrics::RequestLogEntry;
+
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+
+        let db = Database::new(&config)
+            .await
+            .expect("Failed to create database");
+        db.migrate().await.expect("Migration failed");
+
+        let now = chrono::Utc::now();
+        let entry = |request_id: &str, created_at| RequestLogEntry {
+            request_id: request_id.to_string(),
+            key_alias: Some("gw-1...cdef".to_string()),
+            method: "POST".to_string(),
+            path: "/v1/messages".to_string(),
+            model: Some("anthropic/claude-3-5-sonnet".to_string()),
+            provider: Some("anthropic".to_string()),
+            prompt_tokens: 10,
+            completion_tokens: 5,
+            cost: 0.001,
+            latency_ms: 120,
+            status_code: 200,
+            error: None,
+            request_payload: None,
+            response_payload: None,
+            created_at,
+        };
+        db.insert_request_logs(&[
+            entry("old", now - chrono::Duration::days(40)),
+            entry("new", now),
+        ])
+        .await
+        .expect("Failed to insert request logs");
+
+        let logs = db.list_request_logs(10).await.unwrap();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].request_id, "new");
+        assert_eq!(logs[0].total_tokens(), 15);
+
+        let deleted = db
+            .delete_request_logs_before(now - chrono::Duration::days(30), 100)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        let logs = db.list_request_logs(10).await.unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].request_id, "new");
+    }
+
+    /// Test spend upsert adds to existing daily totals
+    #[tokio::test]
+    async fn test_spend_operations() {
+        use litellm_rs::core::models::metrics::{SpendFilter, SpendRecord};
+
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+
+        let db = Database::new(&config)
+            .await
+            .expect("Failed to create database");
+        db.migrate().await.expect("Migration failed");
+
+        let day = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let record = |api_key: Option<&str>, spend| SpendRecord {
+            date: day,
+            api_key: api_key.map(str::to_string),
+            key_hash: None,
+            team_id: None,
+            user_id: None,
+            model: "gpt-4o".to_string(),
+            spend,
+            prompt_tokens: 100,
+            completion_tokens: 50,
+            requests: 1,
+        };
+        db.upsert_spend(&[record(Some("gw-a...aaaa"), 0.5), record(None, 0.25)])
+            .await
+            .expect("Failed to store spend");
+        db.upsert_spend(&[record(Some("gw-a...aaaa"), 1.0)])
+            .await
+            .expect("Failed to add spend");
+
+        let records = db.list_spend(&SpendFilter::default()).await.unwrap();
+        assert_eq!(records.len(), 2);
+
+        let filter = SpendFilter {
+            api_key: Some("gw-a...aaaa".to_string()),
+            ..Default::default()
+        };
+        let records = db.list_spend(&filter).await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].spend, 1.5);
+        assert_eq!(records[0].prompt_tokens, 200);
+        assert_eq!(records[0].requests, 2);
+        assert!(records[0].team_id.is_none());
+
+        let filter = SpendFilter {
+            start_date: day.succ_opt(),
+            ..Default::default()
+        };
+        assert!(db.list_spend(&filter).await.unwrap().is_empty());
+
+        assert_eq!(db.delete_spend_before(day, 100).await.unwrap(), 0);
+        assert_eq!(
+            db.delete_spend_before(day.succ_opt().unwrap(), 1)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            db.list_spend(&SpendFilter::default()).await.unwrap().len(),
+            1
+        );
+    }
+
+    /// Test provider credentials and logged payloads are encrypted and rotated
+    #[tokio::test]
+    async fn test_encryption_at_rest() {
+        use litellm_rs::core::models::metrics::RequestLogEntry;
+        use litellm_rs::storage::encryption::Keyring;
+
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: 5,
+            ssl: false,
+            enabled: true,
+        };
+        let keyring = |active: &str| {
+            Keyring::new(
+                active,
+                [
+                    (
+                        "v1".to_string(),
+                        b"first-key-material-of-32-bytes!!".to_vec(),
+                    ),
+                    (
+                        "v2".to_string(),
+                        b"second-key-material-of-32-bytes!".to_vec(),
+                    ),
+                ],
+            )
+            .unwrap()
+        };
+
+        let db = Database::new(&config)
+            .await
+            .expect("Failed to create database")
+            .with_encryption(Some(keyring("v1")));
+        db.migrate().await.expect("Migration failed");
+
+        db.store_provider_credential("openai", "sk-first")
+            .await
+            .expect("Failed to store credential");
+        db.store_provider_credential("openai", "sk-secret")
+            .await
+            .expect("Failed to replace credential");
+        assert_eq!(
+            db.get_provider_credential("openai")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("sk-secret")
+        );
+
+        db.insert_request_logs(&[RequestLogEntry {
+            request_id: "req".to_string(),
+            key_alias: None,
+            method: "POST".to_string(),
+            path: "/v1/chat/completions".to_string(),
+            model: None,
+            provider: None,
+            prompt_tokens: 0,
+            completion_tokens: 0,
+            cost: 0.0,
+            latency_ms: 1,
+            status_code: 200,
+            error: None,
+            request_payload: Some(serde_json::json!({"prompt": "hello"})),
+            response_payload: None,
+            created_at: chrono::Utc::now(),
+        }])
+        .await
+        .expect("Failed to insert request logs");
+
+        let db = db.with_encryption(Some(keyring("v2")));
+        let rotation = db.rotate_encryption().await.expect("Rotation failed");
+        assert_eq!(rotation.provider_credentials, 1);
+        assert_eq!(rotation.request_logs, 1);
+        assert_eq!(db.rotate_encryption().await.unwrap().request_logs, 0);
+
+        let logs = db.list_request_logs(10).await.unwrap();
+        assert_eq!(
+            logs[0].request_payload,
+            Some(serde_json::json!({"prompt": "hello"}))
+        );
+
+        let db = db.with_encryption(None);
+        let logs = db.list_request_logs(10).await.unwrap();
+        assert!(logs[0].request_payload.is_none());
+        assert!(
+            db.store_provider_credential("openai", "sk-plain")
+                .await
+                .is_err()
+        );
+        assert!(db.get_provider_credential("openai").await.is_err());
+        assert!(db.delete_provider_credential("openai").await.unwrap());
+        assert!(!db.delete_provider_credential("openai").await.unwrap());
+    }
 }
@@ -172,6 +172,40 @@ mod tests {
         assert_eq!(provider.name(), "cloudflare");
     }
 
+    /// Test creating Azure provider with Azure AD (managed identity) credentials
+    #[tokio::test]
+    async fn test_azure_provider_from_config_with_managed_identity() {
+        let config = json!({
+            "api_base": "https://test.openai.azure.com",
+            "azure_ad_token_provider": "managed_identity"
+        });
+
+        let result = Provider::from_config_async(ProviderType::Azure, config).await;
+        assert!(
+            result.is_ok(),
+            "Failed to create Azure provider: {:?}",
+            result.err()
+        );
+
+        let provider = result.unwrap();
+        assert_eq!(provider.name(), "azure");
+    }
+
+    /// Test Azure provider fails with an unknown token provider
+    #[tokio::test]
+    async fn test_azure_fails_with_unknown_token_provider() {
+        let config = json!({
+            "api_base": "https://test.openai.azure.com",
+            "azure_ad_token_provider": "device_code"
+        });
+
+        let result = Provider::from_config_async(ProviderType::Azure, config).await;
+        assert!(
+            result.is_err(),
+            "Should fail with an unknown token provider"
+        );
+    }
+
     /// Test provider creation fails with missing api_key
     #[tokio::test]
     async fn test_provider_creation_fails_without_api_key() {
@@ -197,6 +197,11 @@ mod tests {
             weight: 2,
             timeout_secs: 120,
             priority: 1,
+            quality_tier: None,
+            default_params: Default::default(),
+            forced_params: Default::default(),
+            drop_params: None,
+            additional_drop_params: Vec::new(),
         };
 
         assert_eq!(config.tpm_limit, Some(100_000));